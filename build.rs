@@ -0,0 +1,22 @@
+fn main() {
+    #[cfg(feature = "ffi")]
+    generate_ffi_header();
+}
+
+/// Regenerate `include/lotusx.h` from `src/ffi.rs`
+#[cfg(feature = "ffi")]
+fn generate_ffi_header() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let crate_dir =
+        std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", crate_dir))
+        .expect("cbindgen.toml is valid");
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(format!("{}/src/ffi.rs", crate_dir))
+        .generate()
+        .expect("src/ffi.rs parses")
+        .write_to_file(format!("{}/include/lotusx.h", crate_dir));
+}
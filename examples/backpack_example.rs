@@ -1,6 +1,6 @@
 use lotusx::core::{
     config::ExchangeConfig,
-    traits::{AccountInfo, MarketDataSource},
+    traits::{AccountInfo, RestMarketData},
     types::KlineInterval,
 };
 use lotusx::exchanges::backpack::build_connector;
@@ -30,7 +30,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 1: Get available markets
     println!("\n📊 Getting available markets...");
-    match MarketDataSource::get_markets(&backpack).await {
+    match RestMarketData::get_markets(&backpack).await {
         Ok(markets) => {
             println!("Found {} markets:", markets.len());
             for (i, market) in markets.iter().take(5).enumerate() {
@@ -45,9 +45,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 2: Get historical klines
     println!("\n📈 Getting SOL-USDC 1h klines...");
-    match MarketDataSource::get_klines(
+    match RestMarketData::get_klines(
         &backpack,
-        "SOL_USDC".to_string(),
+        "SOL_USDC".into(),
         KlineInterval::Hours1,
         Some(5),
         None,
@@ -119,7 +119,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let symbols = vec!["SOL_USDC".to_string()];
     let subscription_types = vec![
         SubscriptionType::Ticker,
-        SubscriptionType::OrderBook { depth: Some(5) },
+        SubscriptionType::OrderBook { depth: Some(5), mode: OrderBookMode::Snapshot },
         SubscriptionType::Trades,
     ];
 
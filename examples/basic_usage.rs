@@ -1,5 +1,5 @@
 use lotusx::core::config::ExchangeConfig;
-use lotusx::core::traits::MarketDataSource;
+use lotusx::core::traits::RestMarketData;
 use lotusx::exchanges::binance::build_connector;
 
 #[tokio::main]
@@ -16,7 +16,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 1: Get all available markets
     println!("=== Getting Markets ===");
-    match MarketDataSource::get_markets(&binance).await {
+    match RestMarketData::get_markets(&binance).await {
         Ok(markets) => {
             println!("Successfully fetched {} markets", markets.len());
 
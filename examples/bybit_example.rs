@@ -1,5 +1,5 @@
 use lotusx::core::config::ExchangeConfig;
-use lotusx::core::traits::{AccountInfo, MarketDataSource};
+use lotusx::core::traits::{AccountInfo, RestMarketData, StreamingMarketData};
 use lotusx::core::types::{KlineInterval, SubscriptionType};
 use lotusx::exchanges::bybit::build_connector;
 
@@ -28,7 +28,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 1. Market Data - Get all available markets
     println!("\n🏪 1. Getting Spot Markets:");
-    match MarketDataSource::get_markets(&bybit_spot).await {
+    match RestMarketData::get_markets(&bybit_spot).await {
         Ok(markets) => {
             println!("✅ Found {} spot markets", markets.len());
             println!("📝 Sample markets:");
@@ -51,9 +51,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let test_symbols = vec!["BTCUSDT", "ETHUSDT", "ADAUSDT"];
 
     for symbol in &test_symbols {
-        match MarketDataSource::get_klines(
+        match RestMarketData::get_klines(
             &bybit_spot,
-            (*symbol).to_string(),
+            (*symbol).into(),
             KlineInterval::Minutes1,
             Some(5),
             None,
@@ -96,9 +96,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     match timeout(
         Duration::from_secs(10),
-        MarketDataSource::subscribe_market_data(
+        StreamingMarketData::subscribe_market_data(
             &bybit_spot,
-            vec!["BTCUSDT".to_string()],
+            vec!["BTCUSDT".into()],
             subscription_types.clone(),
             None,
         ),
@@ -157,7 +157,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 1. Perpetual Markets
     println!("\n🏪 1. Getting Perpetual Markets:");
-    match MarketDataSource::get_markets(&bybit_perp).await {
+    match RestMarketData::get_markets(&bybit_perp).await {
         Ok(markets) => {
             println!("✅ Found {} perpetual markets", markets.len());
             println!("📝 Sample perpetual contracts:");
@@ -179,9 +179,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n📈 2. Getting Perpetual K-lines (Fixed API):");
 
     for symbol in &test_symbols {
-        match MarketDataSource::get_klines(
+        match RestMarketData::get_klines(
             &bybit_perp,
-            (*symbol).to_string(),
+            (*symbol).into(),
             KlineInterval::Hours1,
             Some(3),
             None,
@@ -216,9 +216,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     match timeout(
         Duration::from_secs(10),
-        MarketDataSource::subscribe_market_data(
+        StreamingMarketData::subscribe_market_data(
             &bybit_perp,
-            vec!["BTCUSDT".to_string()],
+            vec!["BTCUSDT".into()],
             subscription_types,
             None,
         ),
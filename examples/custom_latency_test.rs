@@ -1,7 +1,8 @@
-use lotusx::utils::exchange_factory::{ExchangeFactory, ExchangeTestConfigBuilder, ExchangeType};
 use lotusx::utils::latency_testing::{
-    calculate_liquidity_score, calculate_market_impact, ExchangePerformance, LatencyTester,
+    calculate_liquidity_score, calculate_market_impact, test_configs_from_env, ExchangePerformance,
+    ExchangeTestConfigBuilder, LatencyTester,
 };
+use lotusx::ExchangeId;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -10,8 +11,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 1: Quick test with only specific exchanges
     let custom_configs = ExchangeTestConfigBuilder::new()
-        .add_exchange("Binance Spot".to_string(), ExchangeType::Binance, false)
-        .add_exchange("Hyperliquid".to_string(), ExchangeType::Hyperliquid, false)
+        .add_exchange("Binance Spot".to_string(), ExchangeId::Binance, false)
+        .add_exchange("Hyperliquid".to_string(), ExchangeId::Hyperliquid, false)
         .with_symbols(vec!["BTC".to_string(), "ETH".to_string()]) // Applies to last added (Hyperliquid)
         .build();
 
@@ -31,11 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("{}", "-".repeat(30 + exchange_config.name.len()));
 
         // Create the exchange connector
-        let client = match ExchangeFactory::create_connector(
-            &exchange_config.exchange_type,
-            None,
-            exchange_config.testnet,
-        ) {
+        let client = match exchange_config.connector() {
             Ok(client) => client,
             Err(e) => {
                 println!(
@@ -56,7 +53,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             markets_metrics: markets_metrics.clone(),
             klines_metrics: markets_metrics.clone(), // Simplified for demo
             websocket_connection_time: std::time::Duration::from_millis(100),
-            websocket_first_message: std::time::Duration::from_millis(1000),
+            websocket_first_message: std::time::Duration::from_secs(1),
             websocket_success_rate: 1.0,
             tick_to_trade_latency: std::time::Duration::from_millis(50),
             market_impact_bps: calculate_market_impact(&markets_metrics),
@@ -67,14 +64,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Example 2: Show how to test all available exchanges
-    println!("\n\n🔧 All Available Exchange Types:");
-    for exchange_type in ExchangeFactory::get_available_exchanges() {
-        println!("  - {}", exchange_type);
+    println!("\n\n🔧 All Available Exchanges:");
+    for exchange in ExchangeId::ALL {
+        println!("  - {}", exchange);
     }
 
     // Example 3: Show environment-based configuration
     println!("\n🌍 Environment-based Configurations:");
-    let env_configs = ExchangeFactory::get_test_configs_from_env();
+    let env_configs = test_configs_from_env();
     for config in env_configs {
         println!(
             "  - {} (requires auth: {})",
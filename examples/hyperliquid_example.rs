@@ -1,9 +1,9 @@
 use lotusx::core::config::ExchangeConfig;
 use lotusx::core::kernel::RestClient;
-use lotusx::core::traits::{AccountInfo, MarketDataSource, OrderPlacer};
+use lotusx::core::traits::{AccountInfo, OrderPlacer, RestMarketData, StreamingMarketData};
 use lotusx::core::types::{
-    conversion, KlineInterval, MarketDataType, OrderRequest, OrderSide, OrderType,
-    SubscriptionType, TimeInForce, WebSocketConfig,
+    conversion, BackpressurePolicy, KlineInterval, MarketDataType, OrderBookMode, OrderRequest,
+    OrderSide, OrderType, SubscriptionType, TimeInForce, WebSocketConfig,
 };
 use lotusx::exchanges::hyperliquid::{build_hyperliquid_connector, HyperliquidBuilder};
 use std::error::Error;
@@ -39,13 +39,7 @@ async fn demo_market_data() -> Result<(), Box<dyn Error>> {
 
     // Get klines/candlestick data
     match connector
-        .get_klines(
-            "BTC".to_string(),
-            KlineInterval::Hours1,
-            Some(10),
-            None,
-            None,
-        )
+        .get_klines("BTC".into(), KlineInterval::Hours1, Some(10), None, None)
         .await
     {
         Ok(klines) => {
@@ -152,6 +146,9 @@ async fn demo_order_management(
         price: Some(conversion::string_to_price("20000")), // Low price to avoid accidental execution
         time_in_force: Some(TimeInForce::GTC),
         stop_price: None,
+        reduce_only: None,
+        close_position: None,
+        client_order_id: None,
     };
 
     match connector.place_order(test_order).await {
@@ -162,7 +159,7 @@ async fn demo_order_management(
 
             // Try to cancel the order
             match connector
-                .cancel_order("BTC".to_string(), response.order_id.clone())
+                .cancel_order("BTC".into(), response.order_id.clone())
                 .await
             {
                 Ok(_) => println!("✓ Order cancelled successfully"),
@@ -233,10 +230,13 @@ async fn demo_websocket_streaming() -> Result<(), Box<dyn Error>> {
     println!("🔗 WebSocket URL: {}", ws_connector.get_websocket_url());
 
     // Set up subscription parameters
-    let symbols = vec!["BTC".to_string(), "ETH".to_string()];
+    let symbols = vec!["BTC".into(), "ETH".into()];
     let subscription_types = vec![
         SubscriptionType::Ticker,
-        SubscriptionType::OrderBook { depth: Some(10) },
+        SubscriptionType::OrderBook {
+            depth: Some(10),
+            mode: OrderBookMode::Snapshot,
+        },
         SubscriptionType::Trades,
     ];
 
@@ -244,6 +244,9 @@ async fn demo_websocket_streaming() -> Result<(), Box<dyn Error>> {
         auto_reconnect: true,
         max_reconnect_attempts: Some(5),
         ping_interval: Some(30),
+        closed_bars_only: false,
+        connection_events: None,
+        backpressure: BackpressurePolicy::DropOldest,
     };
 
     match ws_connector
@@ -315,6 +318,9 @@ fn handle_websocket_message(data: MarketDataType) {
                 kline.close_price
             );
         }
+        MarketDataType::MarkPrice(mark) => {
+            println!("🎯 Mark: {} = ${}", mark.symbol, mark.mark_price);
+        }
     }
 }
 
@@ -328,7 +334,7 @@ async fn demo_builder_features() -> Result<(), Box<dyn Error>> {
     let advanced_connector = HyperliquidBuilder::new(advanced_config)
         .with_vault_address("0x1234567890abcdef1234567890abcdef12345678".to_string())
         .with_mainnet(false) // Use testnet
-        .build_rest_only()?;
+        .build_with_websocket()?;
 
     println!("✓ Advanced connector built with custom configuration");
     println!(
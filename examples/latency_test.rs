@@ -4,10 +4,9 @@
     clippy::suboptimal_flops
 )]
 
-use lotusx::utils::exchange_factory::ExchangeFactory;
 use lotusx::utils::latency_testing::{
-    calculate_liquidity_score, calculate_market_impact, ExchangePerformance, LatencyTestConfig,
-    LatencyTester,
+    calculate_liquidity_score, calculate_market_impact, default_test_configs,
+    test_configs_from_env, ExchangePerformance, LatencyTestConfig, LatencyTester,
 };
 use std::time::Duration;
 
@@ -44,10 +43,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Get exchange configurations - automatically detects available credentials
     let exchange_configs = if std::env::args().any(|arg| arg == "--all") {
-        ExchangeFactory::get_test_configs_from_env()
+        test_configs_from_env()
     } else {
         // Use default exchanges that don't require credentials
-        ExchangeFactory::get_default_test_configs()
+        default_test_configs()
     };
 
     println!("\n🎯 Testing {} exchanges:", exchange_configs.len());
@@ -62,11 +61,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("{}", "-".repeat(30 + exchange_config.name.len()));
 
         // Create the exchange connector
-        let client = match ExchangeFactory::create_connector(
-            &exchange_config.exchange_type,
-            None, // Use default read-only config
-            exchange_config.testnet,
-        ) {
+        let client = match exchange_config.connector() {
             Ok(client) => client,
             Err(e) => {
                 println!(
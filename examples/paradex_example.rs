@@ -1,8 +1,10 @@
 use lotusx::core::config::ExchangeConfig;
-use lotusx::core::traits::{AccountInfo, FundingRateSource, MarketDataSource, OrderPlacer};
+use lotusx::core::traits::{
+    AccountInfo, FundingRateSource, OrderPlacer, RestMarketData, StreamingMarketData,
+};
 use lotusx::core::types::{
-    conversion, KlineInterval, OrderRequest, OrderSide, OrderType, SubscriptionType, TimeInForce,
-    WebSocketConfig,
+    conversion, BackpressurePolicy, KlineInterval, OrderBookMode, OrderRequest, OrderSide,
+    OrderType, SubscriptionType, TimeInForce, WebSocketConfig,
 };
 use lotusx::exchanges::paradex::{
     build_connector, build_connector_with_reconnection, build_connector_with_websocket,
@@ -45,7 +47,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     // Get klines/candlestick data
     match connector
         .get_klines(
-            "BTC-USD".to_string(),
+            "BTC-USD".into(),
             KlineInterval::Hours1,
             Some(10),
             None,
@@ -91,7 +93,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     }
 
     // Get funding rates for specific symbols
-    let symbols = vec!["BTC-USD".to_string(), "ETH-USD".to_string()];
+    let symbols = vec!["BTC-USD".into(), "ETH-USD".into()];
     match connector.get_funding_rates(Some(symbols)).await {
         Ok(rates) => {
             println!("✓ Retrieved funding rates for specific symbols:");
@@ -112,7 +114,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     // Get funding rate history
     match connector
         .get_funding_rate_history(
-            "BTC-USD".to_string(),
+            "BTC-USD".into(),
             None,    // start_time
             None,    // end_time
             Some(5), // limit to last 5 records
@@ -192,6 +194,9 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 price: Some(conversion::string_to_price("20000")), // Low price to avoid accidental execution
                 time_in_force: Some(TimeInForce::GTC),
                 stop_price: None,
+                reduce_only: None,
+                close_position: None,
+                client_order_id: None,
             };
 
             match auth_connector.place_order(test_order).await {
@@ -202,7 +207,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
                     // Try to cancel the order
                     match auth_connector
-                        .cancel_order("BTC-USD".to_string(), response.order_id)
+                        .cancel_order("BTC-USD".into(), response.order_id)
                         .await
                     {
                         Ok(_) => println!("✓ Order cancelled successfully"),
@@ -225,10 +230,13 @@ async fn main() -> Result<(), Box<dyn Error>> {
             println!("✓ WebSocket connector created");
             println!("🔗 WebSocket URL: {}", ws_connector.get_websocket_url());
 
-            let symbols = vec!["BTC-USD".to_string(), "ETH-USD".to_string()];
+            let symbols = vec!["BTC-USD".into(), "ETH-USD".into()];
             let subscription_types = vec![
                 SubscriptionType::Ticker,
-                SubscriptionType::OrderBook { depth: Some(10) },
+                SubscriptionType::OrderBook {
+                    depth: Some(10),
+                    mode: OrderBookMode::Snapshot,
+                },
                 SubscriptionType::Trades,
                 SubscriptionType::Klines {
                     interval: KlineInterval::Minutes1,
@@ -239,6 +247,9 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 auto_reconnect: true,
                 max_reconnect_attempts: Some(5),
                 ping_interval: Some(30),
+                closed_bars_only: false,
+                connection_events: None,
+                backpressure: BackpressurePolicy::DropOldest,
             };
 
             match ws_connector
@@ -283,6 +294,12 @@ async fn main() -> Result<(), Box<dyn Error>> {
                                         kline.close_price
                                     );
                                 }
+                                lotusx::core::types::MarketDataType::MarkPrice(mark) => {
+                                    println!(
+                                        "🎯 Mark: {} = ${} (funding {:?})",
+                                        mark.symbol, mark.mark_price, mark.funding_rate
+                                    );
+                                }
                             }
 
                             if count >= 3 {
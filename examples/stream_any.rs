@@ -0,0 +1,161 @@
+//! Stream market data from any venue through the exchange factory
+//!
+//! ```bash
+//! cargo run --features examples --example stream_any -- --exchange bybit_perp --count 50
+//! cargo run --features examples --example stream_any -- --exchange hyperliquid --symbol ETH
+//! ```
+//!
+//! Connects without credentials, fetches the market list over REST, then prints the
+//! first `--count` ticker, trade and order book updates for `--symbol`.
+
+use lotusx::core::config::ExchangeConfig;
+use lotusx::core::types::{MarketDataType, OrderBookMode, SubscriptionType};
+use lotusx::{build_connector, ExchangeId};
+use std::time::Duration;
+use tokio::time::timeout;
+
+/// Command-line options
+struct StreamArgs {
+    exchange: ExchangeId,
+    symbol: Option<String>,
+    count: usize,
+    timeout_secs: u64,
+    testnet: bool,
+}
+
+impl StreamArgs {
+    fn from_args() -> Result<Self, Box<dyn std::error::Error>> {
+        let mut parsed = Self {
+            exchange: ExchangeId::Binance,
+            symbol: None,
+            count: 20,
+            timeout_secs: 30,
+            testnet: false,
+        };
+        let mut args = std::env::args().skip(1);
+
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for {}", flag))
+            };
+            match flag.as_str() {
+                "--exchange" => parsed.exchange = value()?.parse()?,
+                "--symbol" => parsed.symbol = Some(value()?),
+                "--count" => parsed.count = value()?.parse()?,
+                "--timeout" => parsed.timeout_secs = value()?.parse()?,
+                "--testnet" => parsed.testnet = true,
+                other => return Err(format!("Unknown argument: {}", other).into()),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+/// A liquid symbol in each venue's native format
+const fn default_symbol(exchange: ExchangeId) -> &'static str {
+    match exchange {
+        ExchangeId::Binance
+        | ExchangeId::BinanceMargin
+        | ExchangeId::BinancePerp
+        | ExchangeId::Bybit
+        | ExchangeId::BybitPerp => "BTCUSDT",
+        ExchangeId::Backpack => "SOL_USDC",
+        ExchangeId::Hyperliquid => "BTC",
+        ExchangeId::Okx => "BTC-USDT",
+        ExchangeId::OkxSwap => "BTC-USDT-SWAP",
+        ExchangeId::Coinbase | ExchangeId::Dydx | ExchangeId::Paradex => "BTC-USD",
+        ExchangeId::Gateio | ExchangeId::GateioPerp => "BTC_USDT",
+        ExchangeId::Kraken => "BTC/USD",
+    }
+}
+
+fn describe(data: &MarketDataType) -> String {
+    match data {
+        MarketDataType::Ticker(ticker) => {
+            format!("ticker  {} last={}", ticker.symbol, ticker.price)
+        }
+        MarketDataType::Trade(trade) => format!(
+            "trade   {} {} @ {} (buyer maker: {})",
+            trade.symbol, trade.quantity, trade.price, trade.is_buyer_maker
+        ),
+        MarketDataType::OrderBook(book) => format!(
+            "book    {} bid={} ask={} ({}x{} levels)",
+            book.symbol,
+            book.bids
+                .first()
+                .map_or_else(|| "-".to_string(), |level| level.price.to_string()),
+            book.asks
+                .first()
+                .map_or_else(|| "-".to_string(), |level| level.price.to_string()),
+            book.bids.len(),
+            book.asks.len()
+        ),
+        MarketDataType::Kline(kline) => format!(
+            "kline   {} {} close={} final={}",
+            kline.symbol, kline.interval, kline.close_price, kline.final_bar
+        ),
+        MarketDataType::MarkPrice(mark) => format!(
+            "mark    {} mark={} funding={}",
+            mark.symbol,
+            mark.mark_price,
+            mark.funding_rate
+                .map_or_else(|| "-".to_string(), |rate| rate.to_string())
+        ),
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = StreamArgs::from_args()?;
+    let symbol = args
+        .symbol
+        .clone()
+        .unwrap_or_else(|| default_symbol(args.exchange).to_string());
+
+    println!("📡 Streaming {} on {}", symbol, args.exchange);
+    let connector = build_connector(
+        args.exchange,
+        ExchangeConfig::read_only().testnet(args.testnet),
+    )?;
+    println!("🔗 WebSocket URL: {}", connector.get_websocket_url());
+
+    match connector.get_markets().await {
+        Ok(markets) => println!("📊 {} markets available", markets.len()),
+        Err(e) => println!("⚠️  Failed to fetch markets: {}", e),
+    }
+
+    let subscriptions = vec![
+        SubscriptionType::Ticker,
+        SubscriptionType::Trades,
+        SubscriptionType::OrderBook {
+            depth: Some(5),
+            mode: OrderBookMode::Snapshot,
+        },
+    ];
+    let mut stream = connector
+        .subscribe_market_data(vec![symbol.into()], subscriptions, None)
+        .await?;
+
+    let per_message = Duration::from_secs(args.timeout_secs);
+    let mut received = 0;
+    while received < args.count {
+        match timeout(per_message, stream.recv()).await {
+            Ok(Some(data)) => {
+                received += 1;
+                println!("{:>4}  {}", received, describe(&data));
+            }
+            Ok(None) => {
+                println!("🔌 Stream closed by the connector");
+                break;
+            }
+            Err(_) => {
+                println!("⏰ No data for {}s, giving up", args.timeout_secs);
+                break;
+            }
+        }
+    }
+
+    println!("✅ Received {} messages", received);
+    Ok(())
+}
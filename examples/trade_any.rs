@@ -0,0 +1,123 @@
+//! Place and cancel a resting limit order on any venue through the exchange factory
+//!
+//! ```bash
+//! cargo run --features examples --example trade_any -- \
+//!     --exchange binance_perp --symbol BTCUSDT --price 10000 --quantity 0.001
+//! ```
+//!
+//! Credentials are read from `{EXCHANGE}_API_KEY` / `{EXCHANGE}_SECRET_KEY` (spot, margin
+//! and perp share one prefix per venue). Testnet is always enabled; pick a price
+//! far from the market so the order rests until it is cancelled.
+
+use lotusx::core::config::ExchangeConfig;
+use lotusx::core::types::{conversion, OrderRequest, OrderSide, OrderType, TimeInForce};
+use lotusx::{build_connector, ExchangeId};
+
+/// Command-line options
+struct TradeArgs {
+    exchange: ExchangeId,
+    symbol: String,
+    side: OrderSide,
+    price: String,
+    quantity: String,
+}
+
+impl TradeArgs {
+    fn from_args() -> Result<Self, Box<dyn std::error::Error>> {
+        let mut exchange = None;
+        let mut symbol = None;
+        let mut price = None;
+        let mut quantity = None;
+        let mut side = OrderSide::Buy;
+        let mut args = std::env::args().skip(1);
+
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for {}", flag))
+            };
+            match flag.as_str() {
+                "--exchange" => exchange = Some(value()?.parse()?),
+                "--symbol" => symbol = Some(value()?),
+                "--price" => price = Some(value()?),
+                "--quantity" => quantity = Some(value()?),
+                "--side" => {
+                    side = match value()?.to_lowercase().as_str() {
+                        "buy" => OrderSide::Buy,
+                        "sell" => OrderSide::Sell,
+                        other => return Err(format!("Invalid side: {}", other).into()),
+                    }
+                }
+                other => return Err(format!("Unknown argument: {}", other).into()),
+            }
+        }
+
+        Ok(Self {
+            exchange: exchange.ok_or("--exchange is required")?,
+            symbol: symbol.ok_or("--symbol is required")?,
+            side,
+            price: price.ok_or("--price is required")?,
+            quantity: quantity.ok_or("--quantity is required")?,
+        })
+    }
+
+    fn order(&self) -> OrderRequest {
+        OrderRequest {
+            symbol: conversion::string_to_symbol(&self.symbol),
+            side: self.side.clone(),
+            order_type: OrderType::Limit,
+            quantity: conversion::string_to_quantity(&self.quantity),
+            price: Some(conversion::string_to_price(&self.price)),
+            time_in_force: Some(TimeInForce::GTC),
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        }
+    }
+}
+
+const fn env_prefix(exchange: ExchangeId) -> &'static str {
+    match exchange {
+        ExchangeId::Binance | ExchangeId::BinanceMargin | ExchangeId::BinancePerp => "BINANCE",
+        ExchangeId::Bybit | ExchangeId::BybitPerp => "BYBIT",
+        ExchangeId::Backpack => "BACKPACK",
+        ExchangeId::Coinbase => "COINBASE",
+        ExchangeId::Dydx => "DYDX",
+        ExchangeId::Gateio | ExchangeId::GateioPerp => "GATEIO",
+        ExchangeId::Hyperliquid => "HYPERLIQUID",
+        ExchangeId::Kraken => "KRAKEN",
+        ExchangeId::Okx | ExchangeId::OkxSwap => "OKX",
+        ExchangeId::Paradex => "PARADEX",
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = TradeArgs::from_args()?;
+    let config = ExchangeConfig::from_env(env_prefix(args.exchange))?.testnet(true);
+    let connector = build_connector(args.exchange, config)?;
+
+    println!("🛒 Trading on {} (testnet)", args.exchange);
+    if !connector.supports_trading() {
+        return Err(format!("{} connector cannot sign orders", args.exchange).into());
+    }
+
+    let order = args.order();
+    println!(
+        "📝 Placing {:?} {} {} @ {}",
+        order.side, args.quantity, args.symbol, args.price
+    );
+    let response = connector.place_order(order).await?;
+    println!(
+        "✅ Order {} accepted with status {}",
+        response.order_id, response.status
+    );
+
+    connector
+        .cancel_order(args.symbol.as_str().into(), response.order_id.clone())
+        .await?;
+    println!("🗑️  Order {} cancelled", response.order_id);
+
+    Ok(())
+}
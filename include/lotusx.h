@@ -0,0 +1,195 @@
+/* lotusx C API. Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#ifndef LOTUSX_H
+#define LOTUSX_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Subscribe to tickers
+#define LOTUSX_CHANNEL_TICKER 1
+
+// Subscribe to public trades
+#define LOTUSX_CHANNEL_TRADES (1 << 1)
+
+// Subscribe to order book updates
+#define LOTUSX_CHANNEL_ORDER_BOOK (1 << 2)
+
+// Subscribe to aggregated trades, a lighter alternative to `LOTUSX_CHANNEL_TRADES`
+#define LOTUSX_CHANNEL_AGG_TRADES (1 << 3)
+
+// Subscribe to order book deltas, only the levels that changed, instead of snapshots
+#define LOTUSX_CHANNEL_ORDER_BOOK_DELTA (1 << 4)
+
+// Subscribe to mark price and funding rate updates on perpetual venues
+#define LOTUSX_CHANNEL_MARK_PRICE (1 << 5)
+
+typedef enum LotusxDataKind {
+  LOTUSX_DATA_KIND_TICKER = 0,
+  LOTUSX_DATA_KIND_TRADE = 1,
+  LOTUSX_DATA_KIND_ORDER_BOOK = 2,
+  LOTUSX_DATA_KIND_KLINE = 3,
+  LOTUSX_DATA_KIND_MARK_PRICE = 4,
+} LotusxDataKind;
+
+typedef enum LotusxOrderType {
+  LOTUSX_ORDER_TYPE_MARKET = 0,
+  LOTUSX_ORDER_TYPE_LIMIT = 1,
+} LotusxOrderType;
+
+typedef enum LotusxSide {
+  LOTUSX_SIDE_BUY = 0,
+  LOTUSX_SIDE_SELL = 1,
+} LotusxSide;
+
+// Result of every call
+typedef enum LotusxStatus {
+  LOTUSX_STATUS_OK = 0,
+  LOTUSX_STATUS_INVALID_ARGUMENT = 1,
+  LOTUSX_STATUS_AUTH = 2,
+  LOTUSX_STATUS_NETWORK = 3,
+  LOTUSX_STATUS_RATE_LIMITED = 4,
+  LOTUSX_STATUS_EXCHANGE = 5,
+  LOTUSX_STATUS_NOT_SUPPORTED = 6,
+  LOTUSX_STATUS_STREAM_CLOSED = 7,
+  LOTUSX_STATUS_BUFFER_TOO_SMALL = 8,
+  LOTUSX_STATUS_INTERNAL = 9,
+} LotusxStatus;
+
+typedef enum LotusxTimeInForce {
+  LOTUSX_TIME_IN_FORCE_GTC = 0,
+  LOTUSX_TIME_IN_FORCE_IOC = 1,
+  LOTUSX_TIME_IN_FORCE_FOK = 2,
+} LotusxTimeInForce;
+
+// Opaque connector handle
+typedef struct LotusxConnector LotusxConnector;
+
+// One market data update, flattened to a fixed layout
+//
+// Tickers fill `price` (last), `quantity` (24h volume) and `timestamp` (close time).
+// Trades fill `price`, `quantity`, `is_buyer_maker` and `timestamp`. Order books fill
+// the best bid and ask. Klines fill `price` (close), `quantity` (volume), `timestamp`
+// (open time) and `final_bar`. Mark prices fill `price` (mark), `quantity` (funding
+// rate) and `timestamp`. Unused fields are zero.
+typedef struct LotusxMarketData {
+  enum LotusxDataKind kind;
+  // NUL-terminated, truncated to fit
+  char symbol[32];
+  // Milliseconds since the Unix epoch
+  int64_t timestamp;
+  double price;
+  double quantity;
+  double bid_price;
+  double bid_quantity;
+  double ask_price;
+  double ask_quantity;
+  bool is_buyer_maker;
+  bool final_bar;
+} LotusxMarketData;
+
+// Order to place; amounts are decimal strings so no precision is lost
+typedef struct LotusxOrder {
+  const char *symbol;
+  enum LotusxSide side;
+  enum LotusxOrderType order_type;
+  const char *quantity;
+  // Limit price, ignored (and may be null) for market orders
+  const char *price;
+  enum LotusxTimeInForce time_in_force;
+} LotusxOrder;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Create a connector for `exchange` (e.g. `"binance_perp"`)
+//
+// Pass null keys for a market-data-only connector; both keys enable trading.
+//
+// # Safety
+//
+// String arguments must be null or valid NUL-terminated strings, and `out` must be a
+// valid pointer. Free the handle with `lotusx_connector_free`.
+enum LotusxStatus lotusx_connector_new(const char *exchange,
+                                       const char *api_key,
+                                       const char *secret_key,
+                                       bool testnet,
+                                       struct LotusxConnector **out);
+
+// Close the connector, its streams and background tasks
+//
+// # Safety
+//
+// `handle` must be null or a pointer returned by `lotusx_connector_new` that has not
+// been freed yet.
+void lotusx_connector_free(struct LotusxConnector *handle);
+
+// Subscribe to the `LOTUSX_CHANNEL_*` bitmask for `symbols`
+//
+// Replaces any previous subscription on this handle. `depth` applies to order books;
+// pass 0 for the venue default.
+//
+// # Safety
+//
+// `handle` must be a live connector and `symbols` must point to `symbol_count` valid
+// NUL-terminated strings.
+enum LotusxStatus lotusx_subscribe(struct LotusxConnector *handle,
+                                   const char *const *symbols,
+                                   size_t symbol_count,
+                                   uint32_t channels,
+                                   uint32_t depth);
+
+// Drain up to `capacity` updates into `out`, waiting at most `timeout_ms` for the first
+//
+// `written` receives the number of updates copied; zero with `Ok` means the wait timed
+// out. Returns `StreamClosed` once the stream has ended and is empty.
+//
+// # Safety
+//
+// `handle` must be a live connector, `out` must have room for `capacity` entries and
+// `written` must be a valid pointer.
+enum LotusxStatus lotusx_poll_market_data(struct LotusxConnector *handle,
+                                          struct LotusxMarketData *out,
+                                          size_t capacity,
+                                          uint64_t timeout_ms,
+                                          size_t *written);
+
+// Place an order and copy the venue's order id into `order_id`
+//
+// # Safety
+//
+// `handle` must be a live connector, `order` must point to a valid `LotusxOrder` and
+// `order_id` must have room for `order_id_len` bytes.
+enum LotusxStatus lotusx_place_order(struct LotusxConnector *handle,
+                                     const struct LotusxOrder *order,
+                                     char *order_id,
+                                     size_t order_id_len);
+
+// Cancel an order by venue order id
+//
+// # Safety
+//
+// `handle` must be a live connector and both strings valid NUL-terminated strings.
+enum LotusxStatus lotusx_cancel_order(struct LotusxConnector *handle,
+                                      const char *symbol,
+                                      const char *order_id);
+
+// Copy the calling thread's last error message into `buf`
+//
+// Returns the full message length excluding the NUL terminator, or 0 if the last call
+// succeeded. The copy is truncated when `len` is too small.
+//
+// # Safety
+//
+// `buf` must be null or have room for `len` bytes.
+size_t lotusx_last_error(char *buf, size_t len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* LOTUSX_H */
@@ -0,0 +1,315 @@
+use crate::core::types::{MarketDataType, OrderBook, OrderBookMode, Price, Quantity, Symbol};
+use std::collections::{BTreeMap, HashMap};
+use tokio::sync::{broadcast, mpsc};
+
+/// One venue's quantity at a consolidated price level
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VenueLiquidity {
+    pub exchange: String,
+    pub quantity: Quantity,
+}
+
+/// Price level summed across venues
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregatedLevel {
+    pub price: Price,
+    /// Total over every venue quoting the price
+    pub quantity: Quantity,
+    /// Venues quoting the price, by exchange name
+    pub venues: Vec<VenueLiquidity>,
+}
+
+/// Order books of one symbol from several venues, merged by price
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsolidatedBook {
+    pub symbol: Symbol,
+    /// Best first
+    pub bids: Vec<AggregatedLevel>,
+    /// Best first
+    pub asks: Vec<AggregatedLevel>,
+}
+
+impl ConsolidatedBook {
+    pub fn best_bid(&self) -> Option<&AggregatedLevel> {
+        self.bids.first()
+    }
+
+    pub fn best_ask(&self) -> Option<&AggregatedLevel> {
+        self.asks.first()
+    }
+
+    /// Whether some venue bids above another's ask, an arbitrage before fees
+    pub fn is_crossed(&self) -> bool {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(bid), Some(ask)) => bid.price > ask.price,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct VenueBook {
+    bids: BTreeMap<Price, Quantity>,
+    asks: BTreeMap<Price, Quantity>,
+}
+
+/// Merges the order books venues publish for the same canonical symbol
+///
+/// Each venue's book is held separately, maintained as its feed delivers it, and the
+/// consolidated book is rebuilt from the top `depth` levels of every venue.
+#[derive(Debug)]
+pub struct BookAggregator {
+    depth: usize,
+    feeds: HashMap<String, OrderBookMode>,
+    books: HashMap<Symbol, BTreeMap<String, VenueBook>>,
+}
+
+impl Default for BookAggregator {
+    fn default() -> Self {
+        Self::new(50)
+    }
+}
+
+impl BookAggregator {
+    /// Consolidate `depth` levels per side
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth: depth.max(1),
+            feeds: HashMap::new(),
+            books: HashMap::new(),
+        }
+    }
+
+    /// How `exchange` delivers its books; venues default to whole-book snapshots
+    #[must_use]
+    pub fn with_feed(mut self, exchange: impl Into<String>, mode: OrderBookMode) -> Self {
+        self.feeds.insert(exchange.into(), mode);
+        self
+    }
+
+    /// Apply an update from `exchange`, returning the symbol's consolidated book
+    pub fn apply(&mut self, exchange: &str, update: &OrderBook) -> ConsolidatedBook {
+        let venues = self.books.entry(update.symbol.clone()).or_default();
+        let book = venues.entry(exchange.to_string()).or_default();
+        if self.feeds.get(exchange).copied().unwrap_or_default() != OrderBookMode::Delta {
+            book.bids.clear();
+            book.asks.clear();
+        }
+        for (levels, entries) in [
+            (&mut book.bids, &update.bids),
+            (&mut book.asks, &update.asks),
+        ] {
+            for level in entries {
+                if level.quantity.value().is_zero() {
+                    levels.remove(&level.price);
+                } else {
+                    levels.insert(level.price, level.quantity);
+                }
+            }
+        }
+        consolidate(self.depth, &update.symbol, venues)
+    }
+
+    /// Drop everything `exchange` quoted, e.g. after its feed ended; returns the
+    /// symbols whose consolidated book changed
+    pub fn remove_venue(&mut self, exchange: &str) -> Vec<Symbol> {
+        let mut changed = Vec::new();
+        self.books.retain(|symbol, venues| {
+            if venues.remove(exchange).is_some() {
+                changed.push(symbol.clone());
+            }
+            !venues.is_empty()
+        });
+        changed
+    }
+
+    /// Consolidated book of `symbol`, `None` until a venue has quoted it
+    pub fn book(&self, symbol: &Symbol) -> Option<ConsolidatedBook> {
+        let venues = self.books.get(symbol)?;
+        Some(consolidate(self.depth, symbol, venues))
+    }
+}
+
+/// Merge the top `depth` levels of every venue, keeping the best `depth` per side
+fn consolidate(
+    depth: usize,
+    symbol: &Symbol,
+    venues: &BTreeMap<String, VenueBook>,
+) -> ConsolidatedBook {
+    let mut bids = BTreeMap::new();
+    let mut asks = BTreeMap::new();
+    for (exchange, book) in venues {
+        let venue_bids = book.bids.iter().rev().take(depth);
+        let venue_asks = book.asks.iter().take(depth);
+        for (merged, levels) in [
+            (&mut bids, venue_bids.collect::<Vec<_>>()),
+            (&mut asks, venue_asks.collect()),
+        ] {
+            for (&price, &quantity) in levels {
+                let level = merged.entry(price).or_insert_with(|| AggregatedLevel {
+                    price,
+                    quantity: Quantity::ZERO,
+                    venues: Vec::new(),
+                });
+                level.quantity = Quantity::new(level.quantity.value() + quantity.value());
+                level.venues.push(VenueLiquidity {
+                    exchange: exchange.clone(),
+                    quantity,
+                });
+            }
+        }
+    }
+    ConsolidatedBook {
+        symbol: symbol.clone(),
+        bids: bids.into_values().rev().take(depth).collect(),
+        asks: asks.into_values().take(depth).collect(),
+    }
+}
+
+/// Run a `BookAggregator` over the market data streams of several venues
+///
+/// Streams are the receivers of each venue's `subscribe_market_data`, paired with the
+/// exchange name their levels are attributed to; updates other than order books are
+/// skipped. Every book update publishes the symbol's consolidated book. When a venue's
+/// stream ends its levels are removed and the affected books republished, so a dead
+/// feed never leaves stale liquidity behind. The task exits once every stream has ended.
+pub fn run_book_aggregator(
+    mut aggregator: BookAggregator,
+    streams: Vec<(String, mpsc::Receiver<MarketDataType>)>,
+) -> broadcast::Sender<ConsolidatedBook> {
+    let (tx, _) = broadcast::channel(1024);
+    let events = tx.clone();
+
+    // `None` marks the end of a venue's stream
+    let (updates_tx, mut updates) = mpsc::channel::<(String, Option<OrderBook>)>(1024);
+    for (exchange, mut stream) in streams {
+        let updates_tx = updates_tx.clone();
+        tokio::spawn(async move {
+            while let Some(data) = stream.recv().await {
+                if let MarketDataType::OrderBook(book) = data {
+                    if updates_tx
+                        .send((exchange.clone(), Some(book)))
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+            let _ = updates_tx.send((exchange, None)).await;
+        });
+    }
+    drop(updates_tx);
+
+    tokio::spawn(async move {
+        while let Some((exchange, update)) = updates.recv().await {
+            match update {
+                Some(book) => {
+                    let _ = events.send(aggregator.apply(&exchange, &book));
+                }
+                None => {
+                    for symbol in aggregator.remove_venue(&exchange) {
+                        let book = aggregator
+                            .book(&symbol)
+                            .unwrap_or_else(|| ConsolidatedBook {
+                                symbol,
+                                bids: Vec::new(),
+                                asks: Vec::new(),
+                            });
+                        let _ = events.send(book);
+                    }
+                }
+            }
+        }
+    });
+
+    tx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::book;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_merges_levels_with_venue_attribution() {
+        let symbol = Symbol::new("BTC", "USDT").unwrap();
+        let mut aggregator = BookAggregator::new(2).with_feed("okx", OrderBookMode::Delta);
+        aggregator.apply(
+            "binance",
+            &book(&symbol, &[("100", "1"), ("99", "2")], &[("101", "1")]),
+        );
+        aggregator.apply(
+            "okx",
+            &book(&symbol, &[("100", "3"), ("98", "5")], &[("102", "4")]),
+        );
+        // A delta removing one okx level and crossing binance's ask
+        let merged = aggregator.apply("okx", &book(&symbol, &[("102", "1"), ("98", "0")], &[]));
+
+        let prices = |levels: &[AggregatedLevel]| {
+            levels
+                .iter()
+                .map(|level| level.price.value())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            prices(&merged.bids),
+            [Decimal::from(102), Decimal::from(100)]
+        );
+        assert_eq!(
+            prices(&merged.asks),
+            [Decimal::from(101), Decimal::from(102)]
+        );
+        let top = &merged.bids[1];
+        assert_eq!(top.quantity.value(), Decimal::from(4));
+        assert_eq!(
+            top.venues,
+            [
+                VenueLiquidity {
+                    exchange: "binance".to_string(),
+                    quantity: Quantity::new(Decimal::ONE),
+                },
+                VenueLiquidity {
+                    exchange: "okx".to_string(),
+                    quantity: Quantity::new(Decimal::from(3)),
+                },
+            ]
+        );
+        assert!(merged.is_crossed());
+
+        // A binance snapshot replaces its book outright
+        let merged = aggregator.apply("binance", &book(&symbol, &[("97", "1")], &[("103", "1")]));
+        assert_eq!(
+            prices(&merged.asks),
+            [Decimal::from(102), Decimal::from(103)]
+        );
+        assert!(!merged.is_crossed());
+    }
+
+    #[tokio::test]
+    async fn test_removes_a_venue_whose_stream_ends() {
+        let symbol = Symbol::new("ETH", "USDT").unwrap();
+        let (binance_tx, binance_rx) = mpsc::channel(8);
+        let (okx_tx, okx_rx) = mpsc::channel(8);
+        let events = run_book_aggregator(
+            BookAggregator::default(),
+            vec![
+                ("binance".to_string(), binance_rx),
+                ("okx".to_string(), okx_rx),
+            ],
+        );
+        let mut books = events.subscribe();
+
+        let update = |bids| MarketDataType::OrderBook(book(&symbol, bids, &[]));
+        binance_tx.send(update(&[("10", "1")])).await.unwrap();
+        assert_eq!(books.recv().await.unwrap().bids.len(), 1);
+        okx_tx.send(update(&[("11", "1")])).await.unwrap();
+        assert_eq!(books.recv().await.unwrap().bids.len(), 2);
+
+        drop(okx_tx);
+        let merged = books.recv().await.unwrap();
+        assert_eq!(merged.bids.len(), 1);
+        assert_eq!(merged.bids[0].venues[0].exchange, "binance");
+    }
+}
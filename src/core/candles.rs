@@ -0,0 +1,290 @@
+use crate::core::types::{Kline, MarketDataType, Symbol, Trade, Volume};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::MissedTickBehavior;
+
+/// Builds OHLCV candles of any interval, sub-minute included, from trades
+///
+/// Bars are aligned to the Unix epoch and keyed by symbol. Every trade yields the
+/// updated bar with `final_bar` unset; a bar is emitted once more with `final_bar` set
+/// when the first trade of a later bar arrives or, through `close_elapsed`, once its
+/// close time has passed. Intervals without trades produce no bar, and trades older
+/// than a closed bar are dropped.
+#[derive(Debug, Clone)]
+pub struct CandleBuilder {
+    interval_millis: i64,
+    label: String,
+    close_delay: Duration,
+    closed_bars_only: bool,
+    open: HashMap<Symbol, Kline>,
+    // Open time of the last bar closed per symbol, so late trades cannot reopen it
+    closed: HashMap<Symbol, i64>,
+}
+
+impl CandleBuilder {
+    /// Build bars of `interval`, at least one millisecond
+    pub fn new(interval: Duration) -> Self {
+        let interval_millis = i64::try_from(interval.as_millis())
+            .unwrap_or(i64::MAX)
+            .max(1);
+        Self {
+            interval_millis,
+            label: interval_label(interval_millis),
+            close_delay: Duration::ZERO,
+            closed_bars_only: false,
+            open: HashMap::new(),
+            closed: HashMap::new(),
+        }
+    }
+
+    /// Wait `delay` past a bar's close time before `close_elapsed` closes it, leaving
+    /// room for trades the venue publishes late
+    #[must_use]
+    pub const fn with_close_delay(mut self, delay: Duration) -> Self {
+        self.close_delay = delay;
+        self
+    }
+
+    /// Emit bars only once they close
+    #[must_use]
+    pub const fn with_closed_bars_only(mut self, closed_bars_only: bool) -> Self {
+        self.closed_bars_only = closed_bars_only;
+        self
+    }
+
+    /// Interval as it appears in `Kline::interval`, e.g. `15s` or `1m`
+    pub fn interval(&self) -> &str {
+        &self.label
+    }
+
+    /// Apply `trade`, returning the bars it closed followed by the bar it updated
+    pub fn on_trade(&mut self, trade: &Trade) -> Vec<Kline> {
+        let open_time = trade.time - trade.time.rem_euclid(self.interval_millis);
+        if self
+            .closed
+            .get(&trade.symbol)
+            .is_some_and(|&closed| open_time <= closed)
+        {
+            return Vec::new();
+        }
+
+        let mut bars = Vec::new();
+        if let Some(bar) = self.open.get_mut(&trade.symbol) {
+            if open_time < bar.open_time {
+                return bars;
+            }
+            if open_time == bar.open_time {
+                bar.high_price = bar.high_price.max(trade.price);
+                bar.low_price = bar.low_price.min(trade.price);
+                bar.close_price = trade.price;
+                bar.volume = Volume::new(bar.volume.value() + trade.quantity.value());
+                bar.number_of_trades += 1;
+                if !self.closed_bars_only {
+                    bars.push(bar.clone());
+                }
+                return bars;
+            }
+        }
+        if let Some(bar) = self.close(&trade.symbol) {
+            bars.push(bar);
+        }
+
+        let bar = Kline {
+            symbol: trade.symbol.clone(),
+            open_time,
+            close_time: open_time.saturating_add(self.interval_millis - 1),
+            interval: self.label.clone(),
+            open_price: trade.price,
+            high_price: trade.price,
+            low_price: trade.price,
+            close_price: trade.price,
+            volume: Volume::new(trade.quantity.value()),
+            number_of_trades: 1,
+            final_bar: false,
+        };
+        if !self.closed_bars_only {
+            bars.push(bar.clone());
+        }
+        self.open.insert(trade.symbol.clone(), bar);
+        bars
+    }
+
+    /// Close every bar whose close time, plus the close delay, is before `now`
+    /// (milliseconds), returning them with `final_bar` set
+    pub fn close_elapsed(&mut self, now: i64) -> Vec<Kline> {
+        let delay = i64::try_from(self.close_delay.as_millis()).unwrap_or(i64::MAX);
+        let elapsed: Vec<Symbol> = self
+            .open
+            .values()
+            .filter(|bar| bar.close_time.saturating_add(delay) < now)
+            .map(|bar| bar.symbol.clone())
+            .collect();
+        elapsed
+            .iter()
+            .filter_map(|symbol| self.close(symbol))
+            .collect()
+    }
+
+    /// Bar still forming for `symbol`
+    pub fn open_bar(&self, symbol: &Symbol) -> Option<&Kline> {
+        self.open.get(symbol)
+    }
+
+    fn close(&mut self, symbol: &Symbol) -> Option<Kline> {
+        let mut bar = self.open.remove(symbol)?;
+        bar.final_bar = true;
+        self.closed.insert(symbol.clone(), bar.open_time);
+        Some(bar)
+    }
+}
+
+/// `Kline::interval` of a bar `millis` long, in the largest whole unit
+fn interval_label(millis: i64) -> String {
+    const UNITS: [(i64, &str); 4] = [
+        (86_400_000, "d"),
+        (3_600_000, "h"),
+        (60_000, "m"),
+        (1_000, "s"),
+    ];
+    UNITS
+        .iter()
+        .find(|(unit, _)| millis % unit == 0)
+        .map_or_else(
+            || format!("{}ms", millis),
+            |(unit, suffix)| format!("{}{}", millis / unit, suffix),
+        )
+}
+
+/// Run a `CandleBuilder` over a market data stream
+///
+/// Everything `stream` delivers is forwarded, with the candles built from its trades
+/// sent as `MarketDataType::Kline` after the trade that produced them. Bars are also
+/// closed by the local clock, so the final bar of a quiet market arrives without waiting
+/// for the next trade. The task exits when `stream` ends or the receiver is dropped;
+/// bars still open at that point are never finalized.
+pub fn run_candle_builder(
+    mut builder: CandleBuilder,
+    mut stream: mpsc::Receiver<MarketDataType>,
+) -> mpsc::Receiver<MarketDataType> {
+    let (tx, rx) = mpsc::channel(1000);
+    let tick = Duration::from_millis(u64::try_from(builder.interval_millis).unwrap_or(u64::MAX))
+        .min(Duration::from_secs(1));
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(tick);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            let bars = tokio::select! {
+                data = stream.recv() => {
+                    let Some(data) = data else { return };
+                    let bars = match &data {
+                        MarketDataType::Trade(trade) => builder.on_trade(trade),
+                        _ => Vec::new(),
+                    };
+                    if tx.send(data).await.is_err() {
+                        return;
+                    }
+                    bars
+                }
+                _ = ticker.tick() => builder.close_elapsed(chrono::Utc::now().timestamp_millis()),
+            };
+            for bar in bars {
+                if tx.send(MarketDataType::Kline(bar)).await.is_err() {
+                    return; // Receiver dropped
+                }
+            }
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use rust_decimal::Decimal;
+
+    fn trade(symbol: &Symbol, time: i64, price: i64, quantity: i64) -> Trade {
+        Trade {
+            id: time,
+            time,
+            ..test_support::trade(symbol, &price.to_string(), &quantity.to_string())
+        }
+    }
+
+    #[test]
+    fn test_builds_sub_minute_bars_and_finalizes_them() {
+        let symbol = Symbol::new("BTC", "USDT").unwrap();
+        let mut builder = CandleBuilder::new(Duration::from_secs(15));
+        assert_eq!(builder.interval(), "15s");
+
+        builder.on_trade(&trade(&symbol, 30_001, 100, 1));
+        builder.on_trade(&trade(&symbol, 31_000, 104, 2));
+        let updated = builder.on_trade(&trade(&symbol, 44_999, 98, 1));
+        assert_eq!(updated.len(), 1);
+        assert!(!updated[0].final_bar);
+
+        let bars = builder.on_trade(&trade(&symbol, 61_000, 101, 3));
+        let (closed, opened) = (&bars[0], &bars[1]);
+        assert!(closed.final_bar);
+        assert_eq!((closed.open_time, closed.close_time), (30_000, 44_999));
+        assert_eq!(closed.open_price.value(), Decimal::from(100));
+        assert_eq!(closed.high_price.value(), Decimal::from(104));
+        assert_eq!(closed.low_price.value(), Decimal::from(98));
+        assert_eq!(closed.close_price.value(), Decimal::from(98));
+        assert_eq!(closed.volume.value(), Decimal::from(4));
+        assert_eq!(closed.number_of_trades, 3);
+        assert_eq!(opened.open_time, 60_000);
+        assert!(!opened.final_bar);
+
+        // A late trade cannot reopen a closed bar
+        assert!(builder.on_trade(&trade(&symbol, 40_000, 1, 1)).is_empty());
+
+        assert!(builder.close_elapsed(74_999).is_empty());
+        let closed = builder.close_elapsed(75_000);
+        assert_eq!(closed.len(), 1);
+        assert!(closed[0].final_bar);
+        assert!(builder.open_bar(&symbol).is_none());
+        assert!(builder.on_trade(&trade(&symbol, 70_000, 1, 1)).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_forwards_stream_with_closed_bars_only() {
+        let symbol = Symbol::new("ETH", "USDT").unwrap();
+        let (tx, rx) = mpsc::channel(8);
+        let builder = CandleBuilder::new(Duration::from_secs(60)).with_closed_bars_only(true);
+        let mut output = run_candle_builder(builder, rx);
+
+        // A day ahead, so the local clock closes nothing
+        let day = 86_400_000;
+        let open_time = (chrono::Utc::now().timestamp_millis() / day + 1) * day;
+        tx.send(MarketDataType::Trade(trade(&symbol, open_time, 10, 1)))
+            .await
+            .unwrap();
+        tx.send(MarketDataType::Trade(trade(
+            &symbol,
+            open_time + 60_000,
+            11,
+            1,
+        )))
+        .await
+        .unwrap();
+        assert!(matches!(
+            output.recv().await,
+            Some(MarketDataType::Trade(_))
+        ));
+        assert!(matches!(
+            output.recv().await,
+            Some(MarketDataType::Trade(_))
+        ));
+        match output.recv().await {
+            Some(MarketDataType::Kline(bar)) => {
+                assert_eq!((bar.open_time, bar.interval.as_str()), (open_time, "1m"));
+                assert!(bar.final_bar);
+            }
+            other => panic!("expected a closed bar, got {:?}", other),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::core::kernel::{Profile, ProxyConfig, TlsConfig, WsConfig};
+use crate::core::types::RoundingPolicy;
 use secrecy::{ExposeSecret, Secret};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::env;
@@ -10,6 +12,14 @@ pub struct ExchangeConfig {
     pub secret_key: Secret<String>,
     pub testnet: bool,
     pub base_url: Option<String>,
+    /// Rounding applied when fitting prices and quantities to this venue's precision
+    pub rounding_policy: RoundingPolicy,
+    /// Transport and risk defaults builders apply
+    pub profile: Profile,
+    /// Proxy for REST and WebSocket connections; not serialized
+    pub proxy: Option<ProxyConfig>,
+    /// Certificate verification for REST and WebSocket connections; not serialized
+    pub tls: Option<TlsConfig>,
     // HFT optimization: cache expensive operations
     has_credentials_cache: OnceLock<bool>,
 }
@@ -21,11 +31,13 @@ impl Serialize for ExchangeConfig {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("ExchangeConfig", 4)?;
+        let mut state = serializer.serialize_struct("ExchangeConfig", 6)?;
         state.serialize_field("api_key", "[REDACTED]")?;
         state.serialize_field("secret_key", "[REDACTED]")?;
         state.serialize_field("testnet", &self.testnet)?;
         state.serialize_field("base_url", &self.base_url)?;
+        state.serialize_field("rounding_policy", &self.rounding_policy)?;
+        state.serialize_field("profile", &self.profile)?;
         state.end()
     }
 }
@@ -42,6 +54,10 @@ impl<'de> Deserialize<'de> for ExchangeConfig {
             secret_key: String,
             testnet: bool,
             base_url: Option<String>,
+            #[serde(default)]
+            rounding_policy: RoundingPolicy,
+            #[serde(default)]
+            profile: Profile,
         }
 
         let helper = ExchangeConfigHelper::deserialize(deserializer)?;
@@ -50,6 +66,10 @@ impl<'de> Deserialize<'de> for ExchangeConfig {
             secret_key: Secret::new(helper.secret_key),
             testnet: helper.testnet,
             base_url: helper.base_url,
+            rounding_policy: helper.rounding_policy,
+            profile: helper.profile,
+            proxy: None,
+            tls: None,
             has_credentials_cache: OnceLock::new(),
         })
     }
@@ -62,6 +82,10 @@ impl Default for ExchangeConfig {
             secret_key: Secret::new(String::new()),
             testnet: false,
             base_url: None,
+            rounding_policy: RoundingPolicy::default(),
+            profile: Profile::default(),
+            proxy: None,
+            tls: None,
             has_credentials_cache: OnceLock::new(),
         }
     }
@@ -76,6 +100,10 @@ impl ExchangeConfig {
             secret_key: Secret::new(secret_key),
             testnet: false,
             base_url: None,
+            rounding_policy: RoundingPolicy::default(),
+            profile: Profile::default(),
+            proxy: None,
+            tls: None,
             has_credentials_cache: OnceLock::new(),
         }
     }
@@ -87,11 +115,20 @@ impl ExchangeConfig {
     /// - `{EXCHANGE}_SECRET_KEY` (e.g., `BINANCE_SECRET_KEY`)
     /// - `{EXCHANGE}_TESTNET` (optional, defaults to false)
     /// - `{EXCHANGE}_BASE_URL` (optional)
+    /// - `{EXCHANGE}_ROUNDING_POLICY` (optional: `half_up`, `down` or `bankers`; defaults to `down`)
+    /// - `{EXCHANGE}_PROFILE` (optional: `standard`, `hft`, `research` or `conservative`;
+    ///   defaults to `standard`)
+    /// - `{EXCHANGE}_PROXY` (optional: `http://`, `socks5://` or `socks5h://` proxy URL)
+    /// - `{EXCHANGE}_CA_BUNDLE` (optional: PEM file of extra root certificates to trust)
     pub fn from_env(exchange_prefix: &str) -> Result<Self, ConfigError> {
         let api_key_var = format!("{}_API_KEY", exchange_prefix.to_uppercase());
         let secret_key_var = format!("{}_SECRET_KEY", exchange_prefix.to_uppercase());
         let testnet_var = format!("{}_TESTNET", exchange_prefix.to_uppercase());
         let base_url_var = format!("{}_BASE_URL", exchange_prefix.to_uppercase());
+        let rounding_policy_var = format!("{}_ROUNDING_POLICY", exchange_prefix.to_uppercase());
+        let profile_var = format!("{}_PROFILE", exchange_prefix.to_uppercase());
+        let proxy_var = format!("{}_PROXY", exchange_prefix.to_uppercase());
+        let ca_bundle_var = format!("{}_CA_BUNDLE", exchange_prefix.to_uppercase());
 
         let api_key = env::var(&api_key_var)
             .map_err(|_| ConfigError::MissingEnvironmentVariable(api_key_var))?;
@@ -106,11 +143,41 @@ impl ExchangeConfig {
 
         let base_url = env::var(&base_url_var).ok();
 
+        let rounding_policy = match env::var(&rounding_policy_var) {
+            Ok(value) => value.parse().map_err(|e| {
+                ConfigError::InvalidConfiguration(format!("{}: {}", rounding_policy_var, e))
+            })?,
+            Err(_) => RoundingPolicy::default(),
+        };
+
+        let profile = match env::var(&profile_var) {
+            Ok(value) => value.parse().map_err(|e| {
+                ConfigError::InvalidConfiguration(format!("{}: {}", profile_var, e))
+            })?,
+            Err(_) => Profile::default(),
+        };
+
+        let proxy = env::var(&proxy_var)
+            .ok()
+            .map(|url| ProxyConfig::new(&url))
+            .transpose()
+            .map_err(|e| ConfigError::InvalidConfiguration(format!("{}: {}", proxy_var, e)))?;
+
+        let tls = env::var(&ca_bundle_var)
+            .ok()
+            .map(|path| TlsConfig::default().with_ca_bundle(path))
+            .transpose()
+            .map_err(|e| ConfigError::InvalidConfiguration(format!("{}: {}", ca_bundle_var, e)))?;
+
         Ok(Self {
             api_key: Secret::new(api_key),
             secret_key: Secret::new(secret_key),
             testnet,
             base_url,
+            rounding_policy,
+            profile,
+            proxy,
+            tls,
             has_credentials_cache: OnceLock::new(),
         })
     }
@@ -239,6 +306,29 @@ impl ExchangeConfig {
             secret_key: Secret::new(String::new()),
             testnet: false,
             base_url: None,
+            rounding_policy: RoundingPolicy::default(),
+            profile: Profile::default(),
+            proxy: None,
+            tls: None,
+            has_credentials_cache: OnceLock::new(),
+        }
+    }
+
+    /// Drop any credentials, keeping network settings (testnet, base URL)
+    ///
+    /// Builders given the result never construct a signer, so partial or malformed
+    /// credentials cannot make public market data setup fail.
+    #[must_use]
+    pub fn without_credentials(self) -> Self {
+        Self {
+            api_key: Secret::new(String::new()),
+            secret_key: Secret::new(String::new()),
+            testnet: self.testnet,
+            base_url: self.base_url,
+            rounding_policy: self.rounding_policy,
+            profile: self.profile,
+            proxy: self.proxy,
+            tls: self.tls,
             has_credentials_cache: OnceLock::new(),
         }
     }
@@ -252,7 +342,9 @@ impl ExchangeConfig {
         })
     }
 
-    /// Set testnet mode
+    /// Select the venue's testnet or sandbox endpoints
+    ///
+    /// Builders of venues without one fail with a `ConfigurationError`.
     #[must_use]
     pub fn testnet(mut self, testnet: bool) -> Self {
         self.testnet = testnet;
@@ -268,6 +360,43 @@ impl ExchangeConfig {
         self
     }
 
+    /// Set how prices and quantities are rounded to this venue's precision
+    #[must_use]
+    pub const fn rounding_policy(mut self, policy: RoundingPolicy) -> Self {
+        self.rounding_policy = policy;
+        self
+    }
+
+    /// Select the transport and risk defaults builders apply
+    #[must_use]
+    pub const fn profile(mut self, profile: Profile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Route this connector's REST and WebSocket traffic through `proxy`
+    #[must_use]
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Verify this connector's TLS connections with `tls`
+    #[must_use]
+    pub fn tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// WebSocket settings of the profile, with this config's proxy and TLS
+    pub fn ws_config(&self) -> WsConfig {
+        WsConfig {
+            proxy: self.proxy.clone(),
+            tls: self.tls.clone(),
+            ..self.profile.ws_config()
+        }
+    }
+
     /// Get API key (use carefully - exposes secret)
     pub fn api_key(&self) -> &str {
         self.api_key.expose_secret()
@@ -1,5 +1,50 @@
+use std::fmt;
 use thiserror::Error;
 
+/// Venue-independent reason a request was rejected
+///
+/// Strategy code can match on these instead of each venue's codes and messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    InsufficientBalance,
+    InvalidSymbol,
+    RateLimited,
+    OrderNotFound,
+    /// Order value or size below the venue's minimum
+    MinNotional,
+    /// Post-only order would have taken liquidity
+    PostOnlyReject,
+    /// Price outside the venue's tick size or band
+    InvalidPrice,
+    /// Quantity outside the venue's lot size or limits
+    InvalidQuantity,
+    DuplicateOrder,
+    Unauthorized,
+    Unknown,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::InsufficientBalance => "insufficient balance",
+            Self::InvalidSymbol => "invalid symbol",
+            Self::RateLimited => "rate limited",
+            Self::OrderNotFound => "order not found",
+            Self::MinNotional => "below minimum notional",
+            Self::PostOnlyReject => "post-only order would take",
+            Self::InvalidPrice => "invalid price",
+            Self::InvalidQuantity => "invalid quantity",
+            Self::DuplicateOrder => "duplicate order",
+            Self::Unauthorized => "unauthorized",
+            Self::Unknown => "unknown",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Per-venue table mapping an error code and message to an `ErrorKind`
+pub type ErrorKindFn = fn(i32, &str) -> Option<ErrorKind>;
+
 /// Core exchange error type - simplified and focused
 #[derive(Error, Debug)]
 pub enum ExchangeError {
@@ -12,6 +57,14 @@ pub enum ExchangeError {
     #[error("API error: {code} - {message}")]
     ApiError { code: i32, message: String },
 
+    /// Venue error recognised by the venue's `ErrorKindFn`; `code` is the venue's own
+    #[error("Rejected ({kind}): {code} - {message}")]
+    Rejected {
+        kind: ErrorKind,
+        code: i32,
+        message: String,
+    },
+
     #[error("Authentication error: {0}")]
     AuthError(String),
 
@@ -42,6 +95,18 @@ pub enum ExchangeError {
     #[error("WebSocket connection closed: {0}")]
     WebSocketClosed(String),
 
+    #[error("Subscription not acknowledged: {}", streams.join(", "))]
+    SubscriptionTimeout { streams: Vec<String> },
+
+    /// Book kept from a stream no longer matches the venue's; `expected` is the venue's
+    /// checksum, or the next update id where the venue publishes no checksum
+    #[error("Order book checksum mismatch on {stream}: expected {expected}, computed {computed}")]
+    ChecksumMismatch {
+        stream: String,
+        expected: i64,
+        computed: i64,
+    },
+
     #[error("Serialization error: {0}")]
     SerializationError(String),
 
@@ -57,6 +122,10 @@ pub enum ExchangeError {
     #[error("Parse error: {0}")]
     ParseError(String),
 
+    /// Every attempt the retry policy allowed failed; `last` is the final one's error
+    #[error("Gave up after {attempts} attempts: {last}")]
+    RetriesExhausted { attempts: u32, last: Box<Self> },
+
     #[error("Feature not supported: {0}")]
     NotSupported(String),
 
@@ -95,6 +164,37 @@ impl ExchangeError {
         }
     }
 
+    /// Promote an `ApiError` that `kinds` recognises to `Rejected`
+    ///
+    /// A JSON body carrying `code` and `msg` or `message`, as Binance returns with HTTP
+    /// errors, is looked up by the venue code rather than the HTTP status.
+    #[must_use]
+    pub fn classify(self, kinds: ErrorKindFn) -> Self {
+        let Self::ApiError { code, message } = self else {
+            return self;
+        };
+        let (venue_code, venue_message) =
+            venue_code(&message).unwrap_or_else(|| (code, message.clone()));
+        kinds(venue_code, &venue_message).map_or(Self::ApiError { code, message }, |kind| {
+            Self::Rejected {
+                kind,
+                code: venue_code,
+                message: venue_message,
+            }
+        })
+    }
+
+    /// Semantic kind of the error, `Unknown` for venue errors no table recognised
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::RetriesExhausted { last, .. } => last.kind(),
+            Self::Rejected { kind, .. } => *kind,
+            Self::RateLimitExceeded(_) => ErrorKind::RateLimited,
+            Self::AuthError(_) | Self::AuthenticationRequired => ErrorKind::Unauthorized,
+            _ => ErrorKind::Unknown,
+        }
+    }
+
     /// Check if the error is retryable
     pub fn is_retryable(&self) -> bool {
         matches!(
@@ -124,6 +224,8 @@ impl ExchangeError {
             Self::NetworkError(_) | Self::HttpError(_) => "Network error - check connection",
             Self::ConnectionTimeout(_) => "Connection timeout - try again",
             Self::WebSocketClosed(_) => "Connection closed - reconnecting",
+            Self::SubscriptionTimeout { .. } => "Subscription not confirmed - check stream names",
+            Self::ChecksumMismatch { .. } => "Order book out of sync - resubscribing",
             Self::InvalidParameters(_) => "Invalid parameters",
             Self::ConfigError(_) | Self::ConfigurationError(_) => "Configuration error",
             Self::JsonError(_)
@@ -133,6 +235,14 @@ impl ExchangeError {
             Self::WebSocketError(_) => "WebSocket error",
             Self::InvalidResponseFormat(_) => "Invalid response format",
             Self::ApiError { .. } => "API error",
+            Self::Rejected { kind, .. } => match kind {
+                ErrorKind::InsufficientBalance => "Insufficient balance",
+                ErrorKind::OrderNotFound => "Order not found",
+                ErrorKind::RateLimited => "Rate limit exceeded - please wait",
+                ErrorKind::Unauthorized => "Authentication failed - check credentials",
+                _ => "Request rejected by exchange",
+            },
+            Self::RetriesExhausted { last, .. } => last.user_message(),
             Self::NotSupported(_) => "Feature not supported",
             Self::Other(_) => "An error occurred",
         }
@@ -165,3 +275,57 @@ impl From<ExchangeError> for String {
         err.to_string()
     }
 }
+
+/// Venue code and message of a JSON error body such as `{"code":-2010,"msg":"..."}`
+fn venue_code(body: &str) -> Option<(i32, String)> {
+    let value: serde_json::Value = serde_json::from_str(body).ok()?;
+    let code = value.get("code")?;
+    let code = code
+        .as_i64()
+        .or_else(|| code.as_str().and_then(|code| code.parse().ok()))
+        .and_then(|code| i32::try_from(code).ok())?;
+    let message = value
+        .get("msg")
+        .or_else(|| value.get("message"))
+        .and_then(serde_json::Value::as_str)?;
+    Some((code, message.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(code: i32, message: &str) -> Option<ErrorKind> {
+        match code {
+            -2010 if message.contains("insufficient balance") => {
+                Some(ErrorKind::InsufficientBalance)
+            }
+            -2013 => Some(ErrorKind::OrderNotFound),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_classify_reads_the_venue_code_of_a_json_body() {
+        let error = ExchangeError::api_error(
+            400,
+            r#"{"code":-2010,"msg":"Account has insufficient balance for requested action."}"#
+                .to_string(),
+        )
+        .classify(kinds);
+
+        assert_eq!(error.kind(), ErrorKind::InsufficientBalance);
+        assert!(matches!(error, ExchangeError::Rejected { code: -2010, .. }));
+
+        let unknown =
+            ExchangeError::api_error(400, r#"{"code":-4046,"msg":"No need"}"#.to_string())
+                .classify(kinds);
+        // Unrecognised errors keep the HTTP status and raw body
+        assert!(matches!(unknown, ExchangeError::ApiError { code: 400, .. }));
+        assert_eq!(unknown.kind(), ErrorKind::Unknown);
+        assert_eq!(
+            ExchangeError::rate_limit_exceeded(String::new()).kind(),
+            ErrorKind::RateLimited
+        );
+    }
+}
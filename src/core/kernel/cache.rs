@@ -0,0 +1,440 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::rate_limit::RateLimitStatus;
+use crate::core::kernel::rest::RestClient;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+tokio::task_local! {
+    static BYPASS: ();
+}
+
+/// Run `request` with every `CachedRest` read going to the network
+///
+/// Fresh responses still replace cached ones. Applies to the calling task only, not to
+/// tasks it spawns.
+pub async fn bypass_cache<F: Future>(request: F) -> F::Output {
+    BYPASS.scope((), request).await
+}
+
+fn bypassing() -> bool {
+    BYPASS.try_with(|()| ()).is_ok()
+}
+
+/// Which GET endpoints `CachedRest` caches, and for how long
+///
+/// A rule applies when the endpoint path ends with its suffix, ignoring case; the first
+/// matching rule wins. Endpoints no rule matches are never cached.
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    capacity: usize,
+    rules: Vec<(String, Duration)>,
+}
+
+impl Default for CacheConfig {
+    /// 256 entries; market listings 10 min, tickers 1 s, funding 30 s
+    fn default() -> Self {
+        let mut config = Self::new(256);
+        for suffix in [
+            "/exchangeinfo",
+            "/instruments",
+            "/instruments-info",
+            "/assetpairs",
+            "/markets",
+            "/market/products",
+        ] {
+            config = config.with_ttl(suffix, Duration::from_secs(600));
+        }
+        for suffix in [
+            "/ticker",
+            "/tickers",
+            "/ticker/24hr",
+            "/ticker/price",
+            "/ticker/bookticker",
+            "/markets/summary",
+        ] {
+            config = config.with_ttl(suffix, Duration::from_secs(1));
+        }
+        for suffix in [
+            "/premiumindex",
+            "/fundingrate",
+            "/fundingrates",
+            "/fundinginfo",
+            "/funding-rate",
+            "/funding/history",
+        ] {
+            config = config.with_ttl(suffix, Duration::from_secs(30));
+        }
+        config
+    }
+}
+
+impl CacheConfig {
+    /// Hold at most `capacity` responses, with no endpoint cached yet
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Cache endpoints ending with `suffix` for `ttl`; earlier rules take precedence
+    #[must_use]
+    pub fn with_ttl(mut self, suffix: impl Into<String>, ttl: Duration) -> Self {
+        self.rules.push((suffix.into().to_lowercase(), ttl));
+        self
+    }
+
+    fn ttl(&self, endpoint: &str) -> Option<Duration> {
+        let endpoint = endpoint.to_lowercase();
+        self.rules
+            .iter()
+            .find(|(suffix, _)| endpoint.ends_with(suffix.as_str()))
+            .map(|(_, ttl)| *ttl)
+            .filter(|ttl| !ttl.is_zero())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    endpoint: String,
+    query: String,
+    authenticated: bool,
+}
+
+impl CacheKey {
+    fn new(endpoint: &str, query_params: &[(&str, &str)], authenticated: bool) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            query: query_params
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join("&"),
+            authenticated,
+        }
+    }
+}
+
+struct CacheEntry {
+    value: Value,
+    expires_at: Instant,
+    last_used: u64,
+}
+
+/// Hit and miss counters of a `CachedRest`
+#[derive(Debug, Default)]
+pub struct CacheStats {
+    pub hits: AtomicU64,
+    pub misses: AtomicU64,
+}
+
+/// Least-recently-used store of responses with per-entry expiry
+struct ResponseCache {
+    config: CacheConfig,
+    entries: Mutex<(HashMap<CacheKey, CacheEntry>, u64)>,
+}
+
+impl ResponseCache {
+    fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new((HashMap::new(), 0)),
+        }
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<Value> {
+        let mut guard = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let (entries, clock) = &mut *guard;
+        *clock += 1;
+        let entry = entries.get_mut(key)?;
+        if entry.expires_at <= Instant::now() {
+            entries.remove(key);
+            drop(guard);
+            return None;
+        }
+        entry.last_used = *clock;
+        let value = entry.value.clone();
+        drop(guard);
+        Some(value)
+    }
+
+    fn insert(&self, key: CacheKey, value: Value, ttl: Duration) {
+        let mut guard = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let (entries, clock) = &mut *guard;
+        *clock += 1;
+        if !entries.contains_key(&key) && entries.len() >= self.config.capacity {
+            let now = Instant::now();
+            entries.retain(|_, entry| entry.expires_at > now);
+            if entries.len() >= self.config.capacity {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        entries.insert(
+            key,
+            CacheEntry {
+                value,
+                expires_at: Instant::now() + ttl,
+                last_used: *clock,
+            },
+        );
+        drop(guard);
+    }
+
+    fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .0
+            .clear();
+    }
+}
+
+/// `RestClient` wrapper that serves repeated idempotent GETs from memory
+///
+/// Only GETs matching a `CacheConfig` rule are cached, and errors never are. Clones
+/// share one cache, so every component of a connector reads through it. Wrap a call
+/// in `bypass_cache` to force a network read.
+#[derive(Clone)]
+pub struct CachedRest<R: RestClient> {
+    inner: R,
+    cache: Arc<ResponseCache>,
+    stats: Arc<CacheStats>,
+}
+
+impl<R: RestClient> std::fmt::Debug for CachedRest<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachedRest")
+            .field("config", &self.cache.config)
+            .field("stats", &self.stats)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: RestClient> CachedRest<R> {
+    pub fn new(inner: R, config: CacheConfig) -> Self {
+        Self {
+            inner,
+            cache: Arc::new(ResponseCache::new(config)),
+            stats: Arc::new(CacheStats::default()),
+        }
+    }
+
+    /// Get the hit and miss counters
+    pub fn stats(&self) -> Arc<CacheStats> {
+        self.stats.clone()
+    }
+
+    /// Drop every cached response
+    pub fn clear(&self) {
+        self.cache.clear();
+    }
+
+    async fn cached_get(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        let Some(ttl) = self.cache.config.ttl(endpoint) else {
+            return self.inner.get(endpoint, query_params, authenticated).await;
+        };
+        let key = CacheKey::new(endpoint, query_params, authenticated);
+        if !bypassing() {
+            if let Some(value) = self.cache.get(&key) {
+                self.stats.hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(value);
+            }
+        }
+
+        self.stats.misses.fetch_add(1, Ordering::Relaxed);
+        let value = self
+            .inner
+            .get(endpoint, query_params, authenticated)
+            .await?;
+        self.cache.insert(key, value.clone(), ttl);
+        Ok(value)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> RestClient for CachedRest<R> {
+    async fn get(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.cached_get(endpoint, query_params, authenticated).await
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        if self.cache.config.ttl(endpoint).is_none() {
+            return self
+                .inner
+                .get_json(endpoint, query_params, authenticated)
+                .await;
+        }
+        let value = self
+            .cached_get(endpoint, query_params, authenticated)
+            .await?;
+        serde_json::from_value(value).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to deserialize JSON: {}", e))
+        })
+    }
+
+    async fn post(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.inner.post(endpoint, body, authenticated).await
+    }
+
+    async fn post_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.inner.post_json(endpoint, body, authenticated).await
+    }
+
+    async fn put(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.inner.put(endpoint, body, authenticated).await
+    }
+
+    async fn put_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.inner.put_json(endpoint, body, authenticated).await
+    }
+
+    async fn delete(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.inner
+            .delete(endpoint, query_params, authenticated)
+            .await
+    }
+
+    async fn delete_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.inner
+            .delete_json(endpoint, query_params, authenticated)
+            .await
+    }
+
+    async fn signed_request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        body: &[u8],
+    ) -> Result<Value, ExchangeError> {
+        self.inner
+            .signed_request(method, endpoint, query_params, body)
+            .await
+    }
+
+    async fn signed_request_json<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        body: &[u8],
+    ) -> Result<T, ExchangeError> {
+        self.inner
+            .signed_request_json(method, endpoint, query_params, body)
+            .await
+    }
+
+    fn can_sign(&self) -> bool {
+        self.inner.can_sign()
+    }
+
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.inner.rate_limit_status()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_ttls_match_endpoint_suffix() {
+        let config = CacheConfig::default();
+        assert_eq!(
+            config.ttl("/api/v3/exchangeInfo"),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(
+            config.ttl("/fapi/v1/ticker/24hr"),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            config.ttl("/fapi/v1/premiumIndex"),
+            Some(Duration::from_secs(30))
+        );
+        // Candles live under the products path but are not a listing
+        assert_eq!(
+            config.ttl("/api/v3/brokerage/market/products/BTC-USD/candles"),
+            None
+        );
+        assert_eq!(config.ttl("/api/v3/order"), None);
+    }
+
+    #[test]
+    fn test_entries_expire_and_evict_least_recently_used() {
+        let cache = ResponseCache::new(CacheConfig::new(2));
+        let key = |endpoint: &str| CacheKey::new(endpoint, &[("symbol", "BTCUSDT")], false);
+
+        cache.insert(key("/a"), Value::from(1), Duration::from_secs(10));
+        cache.insert(key("/b"), Value::from(2), Duration::from_secs(10));
+        assert_eq!(cache.get(&key("/a")), Some(Value::from(1)));
+
+        // `/b` is now the least recently used
+        cache.insert(key("/c"), Value::from(3), Duration::from_secs(10));
+        assert_eq!(cache.get(&key("/b")), None);
+        assert_eq!(cache.get(&key("/a")), Some(Value::from(1)));
+
+        cache.insert(key("/a"), Value::from(4), Duration::ZERO);
+        assert_eq!(cache.get(&key("/a")), None);
+        assert_eq!(cache.get(&key("/c")), Some(Value::from(3)));
+    }
+}
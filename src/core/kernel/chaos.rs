@@ -0,0 +1,456 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::codec::WsCodec;
+use crate::core::kernel::rate_limit::RateLimitStatus;
+use crate::core::kernel::rest::RestClient;
+use crate::core::kernel::ws::{WsControl, WsSession};
+use async_trait::async_trait;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::protocol::Message;
+use tracing::debug;
+
+/// Fault-injection settings for the kernel transports
+///
+/// All randomness is driven by `seed`, so a given configuration produces the same
+/// sequence of faults on every run.
+#[derive(Debug, Clone)]
+pub struct ChaosConfig {
+    /// Seed for the fault RNG
+    pub seed: u64,
+    /// Probability (0.0..=1.0) of silently dropping an inbound WebSocket message
+    pub ws_drop_rate: f64,
+    /// Force a disconnect after this many inbound WebSocket messages
+    pub ws_disconnect_every: Option<u64>,
+    /// Fixed delay added before every REST request
+    pub rest_delay: Option<Duration>,
+    /// Probability (0.0..=1.0) of failing a REST request with a network error
+    pub rest_failure_rate: f64,
+}
+
+impl Default for ChaosConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            ws_drop_rate: 0.0,
+            ws_disconnect_every: None,
+            rest_delay: None,
+            rest_failure_rate: 0.0,
+        }
+    }
+}
+
+impl ChaosConfig {
+    /// Create a configuration that injects no faults
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Self::default()
+        }
+    }
+
+    /// Drop the given fraction of inbound WebSocket messages
+    pub fn with_ws_drop_rate(mut self, rate: f64) -> Self {
+        self.ws_drop_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Force a disconnect every `messages` inbound WebSocket messages
+    pub fn with_ws_disconnect_every(mut self, messages: u64) -> Self {
+        self.ws_disconnect_every = Some(messages.max(1));
+        self
+    }
+
+    /// Delay every REST request by `delay`
+    pub fn with_rest_delay(mut self, delay: Duration) -> Self {
+        self.rest_delay = Some(delay);
+        self
+    }
+
+    /// Fail the given fraction of REST requests
+    pub fn with_rest_failure_rate(mut self, rate: f64) -> Self {
+        self.rest_failure_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// Counters for the faults injected so far
+#[derive(Debug, Default)]
+pub struct ChaosStats {
+    pub dropped_messages: AtomicU64,
+    pub forced_disconnects: AtomicU64,
+    pub delayed_requests: AtomicU64,
+    pub failed_requests: AtomicU64,
+}
+
+/// Seeded fault decision engine shared by the chaos transports
+#[derive(Debug)]
+pub struct FaultInjector {
+    config: ChaosConfig,
+    rng: Mutex<StdRng>,
+    messages_seen: AtomicU64,
+    stats: Arc<ChaosStats>,
+}
+
+impl FaultInjector {
+    pub fn new(config: ChaosConfig) -> Self {
+        Self {
+            rng: Mutex::new(StdRng::seed_from_u64(config.seed)),
+            config,
+            messages_seen: AtomicU64::new(0),
+            stats: Arc::new(ChaosStats::default()),
+        }
+    }
+
+    /// Get the fault counters
+    pub fn stats(&self) -> Arc<ChaosStats> {
+        self.stats.clone()
+    }
+
+    fn roll(&self, probability: f64) -> bool {
+        if probability <= 0.0 {
+            return false;
+        }
+        self.rng
+            .lock()
+            .is_ok_and(|mut rng| rng.gen_bool(probability))
+    }
+
+    /// Decide whether the next inbound WebSocket message should be dropped
+    pub fn should_drop_message(&self) -> bool {
+        let drop = self.roll(self.config.ws_drop_rate);
+        if drop {
+            self.stats.dropped_messages.fetch_add(1, Ordering::Relaxed);
+        }
+        drop
+    }
+
+    /// Decide whether the connection should be forced down after this message
+    pub fn should_disconnect(&self) -> bool {
+        let Some(every) = self.config.ws_disconnect_every else {
+            return false;
+        };
+        let seen = self.messages_seen.fetch_add(1, Ordering::Relaxed) + 1;
+        let disconnect = seen % every == 0;
+        if disconnect {
+            self.stats
+                .forced_disconnects
+                .fetch_add(1, Ordering::Relaxed);
+        }
+        disconnect
+    }
+
+    /// Apply the configured REST delay and failure rate
+    pub async fn before_request(&self, endpoint: &str) -> Result<(), ExchangeError> {
+        if let Some(delay) = self.config.rest_delay {
+            self.stats.delayed_requests.fetch_add(1, Ordering::Relaxed);
+            tokio::time::sleep(delay).await;
+        }
+        if self.roll(self.config.rest_failure_rate) {
+            self.stats.failed_requests.fetch_add(1, Ordering::Relaxed);
+            debug!("chaos: failing request to {}", endpoint);
+            return Err(ExchangeError::NetworkError(format!(
+                "chaos: injected failure for {}",
+                endpoint
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// `RestClient` wrapper that delays and fails requests according to a `ChaosConfig`
+#[derive(Debug, Clone)]
+pub struct ChaosRest<R: RestClient> {
+    inner: R,
+    injector: Arc<FaultInjector>,
+}
+
+impl<R: RestClient> ChaosRest<R> {
+    pub fn new(inner: R, config: ChaosConfig) -> Self {
+        Self {
+            inner,
+            injector: Arc::new(FaultInjector::new(config)),
+        }
+    }
+
+    /// Get the fault counters
+    pub fn stats(&self) -> Arc<ChaosStats> {
+        self.injector.stats()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> RestClient for ChaosRest<R> {
+    async fn get(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.injector.before_request(endpoint).await?;
+        self.inner.get(endpoint, query_params, authenticated).await
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.injector.before_request(endpoint).await?;
+        self.inner
+            .get_json(endpoint, query_params, authenticated)
+            .await
+    }
+
+    async fn post(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.injector.before_request(endpoint).await?;
+        self.inner.post(endpoint, body, authenticated).await
+    }
+
+    async fn post_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.injector.before_request(endpoint).await?;
+        self.inner.post_json(endpoint, body, authenticated).await
+    }
+
+    async fn put(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.injector.before_request(endpoint).await?;
+        self.inner.put(endpoint, body, authenticated).await
+    }
+
+    async fn put_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.injector.before_request(endpoint).await?;
+        self.inner.put_json(endpoint, body, authenticated).await
+    }
+
+    async fn delete(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.injector.before_request(endpoint).await?;
+        self.inner
+            .delete(endpoint, query_params, authenticated)
+            .await
+    }
+
+    async fn delete_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.injector.before_request(endpoint).await?;
+        self.inner
+            .delete_json(endpoint, query_params, authenticated)
+            .await
+    }
+
+    async fn signed_request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        body: &[u8],
+    ) -> Result<Value, ExchangeError> {
+        self.injector.before_request(endpoint).await?;
+        self.inner
+            .signed_request(method, endpoint, query_params, body)
+            .await
+    }
+
+    async fn signed_request_json<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        body: &[u8],
+    ) -> Result<T, ExchangeError> {
+        self.injector.before_request(endpoint).await?;
+        self.inner
+            .signed_request_json(method, endpoint, query_params, body)
+            .await
+    }
+
+    fn can_sign(&self) -> bool {
+        self.inner.can_sign()
+    }
+
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.inner.rate_limit_status()
+    }
+}
+
+/// `WsSession` wrapper that drops messages and forces disconnects
+///
+/// Wrap the raw session and put `ReconnectWs` on the outside to exercise the
+/// reconnect/resubscribe path:
+/// `ReconnectWs::new(ChaosWs::new(TungsteniteWs::new(..), config))`.
+pub struct ChaosWs<C: WsCodec, T: WsSession<C>> {
+    inner: T,
+    injector: FaultInjector,
+    _codec: std::marker::PhantomData<C>,
+}
+
+impl<C: WsCodec, T: WsSession<C>> ChaosWs<C, T> {
+    pub fn new(inner: T, config: ChaosConfig) -> Self {
+        Self {
+            inner,
+            injector: FaultInjector::new(config),
+            _codec: std::marker::PhantomData,
+        }
+    }
+
+    /// Get the fault counters
+    pub fn stats(&self) -> Arc<ChaosStats> {
+        self.injector.stats()
+    }
+
+    async fn force_disconnect<M>(&mut self) -> Option<Result<M, ExchangeError>> {
+        debug!("chaos: forcing WebSocket disconnect");
+        let _ = self.inner.close().await;
+        Some(Err(ExchangeError::WebSocketClosed(
+            "chaos: forced disconnect".to_string(),
+        )))
+    }
+}
+
+#[async_trait]
+impl<C: WsCodec, T: WsSession<C>> WsSession<C> for ChaosWs<C, T> {
+    async fn connect(&mut self) -> Result<(), ExchangeError> {
+        self.inner.connect().await
+    }
+
+    async fn send_raw(&mut self, msg: Message) -> Result<(), ExchangeError> {
+        self.inner.send_raw(msg).await
+    }
+
+    async fn next_raw(&mut self) -> Option<Result<Message, ExchangeError>> {
+        loop {
+            let msg = self.inner.next_raw().await?;
+            if msg.is_ok() {
+                if self.injector.should_disconnect() {
+                    return self.force_disconnect().await;
+                }
+                if self.injector.should_drop_message() {
+                    continue;
+                }
+            }
+            return Some(msg);
+        }
+    }
+
+    async fn close(&mut self) -> Result<(), ExchangeError> {
+        self.inner.close().await
+    }
+
+    fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
+    async fn subscribe(
+        &mut self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<(), ExchangeError> {
+        self.inner.subscribe(streams).await
+    }
+
+    async fn unsubscribe(
+        &mut self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<(), ExchangeError> {
+        self.inner.unsubscribe(streams).await
+    }
+
+    async fn next_message(&mut self) -> Option<Result<C::Message, ExchangeError>> {
+        loop {
+            let msg = self.inner.next_message().await?;
+            if msg.is_ok() {
+                if self.injector.should_disconnect() {
+                    return self.force_disconnect().await;
+                }
+                if self.injector.should_drop_message() {
+                    continue;
+                }
+            }
+            return Some(msg);
+        }
+    }
+
+    async fn send_bulk(&mut self, messages: &[Message]) -> Result<(), ExchangeError> {
+        self.inner.send_bulk(messages).await
+    }
+
+    async fn configure_low_latency(&mut self) -> Result<(), ExchangeError> {
+        self.inner.configure_low_latency().await
+    }
+
+    async fn ping(&mut self) -> Result<(), ExchangeError> {
+        self.inner.ping().await
+    }
+
+    fn control(&self) -> WsControl {
+        self.inner.control()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fault_sequence_is_deterministic() {
+        let config = ChaosConfig::new(42).with_ws_drop_rate(0.3);
+        let first = FaultInjector::new(config.clone());
+        let second = FaultInjector::new(config);
+
+        let a: Vec<bool> = (0..100).map(|_| first.should_drop_message()).collect();
+        let b: Vec<bool> = (0..100).map(|_| second.should_drop_message()).collect();
+        assert_eq!(a, b);
+        assert!(a.iter().any(|d| *d) && a.iter().any(|d| !*d));
+    }
+
+    #[test]
+    fn test_forced_disconnect_cadence() {
+        let injector = FaultInjector::new(ChaosConfig::new(0).with_ws_disconnect_every(3));
+        let pattern: Vec<bool> = (0..6).map(|_| injector.should_disconnect()).collect();
+        assert_eq!(pattern, vec![false, false, true, false, false, true]);
+        assert_eq!(
+            injector.stats().forced_disconnects.load(Ordering::Relaxed),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rest_failure_rate_one_always_fails() {
+        let injector = FaultInjector::new(ChaosConfig::new(7).with_rest_failure_rate(1.0));
+        let result = injector.before_request("/api/v3/ping").await;
+        assert!(matches!(result, Err(ExchangeError::NetworkError(_))));
+        assert_eq!(injector.stats().failed_requests.load(Ordering::Relaxed), 1);
+    }
+}
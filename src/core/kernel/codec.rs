@@ -1,6 +1,16 @@
 use crate::core::errors::ExchangeError;
 use tokio_tungstenite::tungstenite::Message;
 
+/// Session facts a codec reads off a raw message, such as a connection id in an ack
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ControlFrame {
+    pub connection_id: Option<String>,
+    /// Reply to a keepalive sent with `WsSession::ping`
+    pub pong: bool,
+    /// Venue confirmed a subscription request
+    pub subscribed: bool,
+}
+
 /// Codec trait for handling exchange-specific WebSocket message encoding/decoding
 ///
 /// This trait defines the contract for converting between raw WebSocket messages
@@ -34,6 +44,42 @@ pub trait WsCodec: Send + Sync + 'static {
         streams: &[impl AsRef<str> + Send + Sync],
     ) -> Result<Message, ExchangeError>;
 
+    /// Encode the login message for a private stream
+    ///
+    /// Sent by the session right after every (re)connect, before any subscription.
+    /// Public codecs keep the default and send nothing.
+    fn encode_auth(&self) -> Result<Option<Message>, ExchangeError> {
+        Ok(None)
+    }
+
+    /// Encode an application-level keepalive
+    ///
+    /// Venues that expect a JSON or text ping override this; the default sends a
+    /// WebSocket ping frame.
+    fn encode_ping(&self) -> Option<Message> {
+        None
+    }
+
+    /// Whether the venue confirms subscriptions with a frame `control_frame` flags as
+    /// `subscribed`; sessions then wait for it instead of assuming success
+    fn acknowledges_subscriptions(&self) -> bool {
+        false
+    }
+
+    /// Inspect a raw message for session control information before it is decoded
+    fn control_frame(&self, _message: &Message) -> Option<ControlFrame> {
+        None
+    }
+
+    /// Venue timestamp of a decoded message, in seconds, milliseconds, microseconds or
+    /// nanoseconds since the epoch
+    ///
+    /// Sessions time the message's arrival against it for `FeedLatency`; codecs whose
+    /// messages carry no timestamp keep the default.
+    fn exchange_time(&self, _message: &Self::Message) -> Option<i64> {
+        None
+    }
+
     /// Decode a raw WebSocket message into a typed message
     ///
     /// This method should only handle data messages. Control messages (ping, pong, close)
@@ -0,0 +1,215 @@
+use crate::core::kernel::rate_limit::{rate_limit_status, RateLimitStatus};
+use crate::core::kernel::ws::{WsControl, WsControlInfo};
+use reqwest::{Method, StatusCode};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::time::Duration;
+
+/// Requests kept per exchange for `Diagnostics::recent_requests`
+const RECENT_REQUESTS: usize = 256;
+/// Characters of an error kept per endpoint; HTML error pages can be large
+const MAX_ERROR_CHARS: usize = 512;
+
+/// A REST request as the transport saw it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestSample {
+    pub method: Method,
+    pub endpoint: String,
+    /// `None` when the request failed before a response arrived
+    pub status: Option<u16>,
+    pub latency: Duration,
+    /// When the request completed, in milliseconds
+    pub completed_at: i64,
+}
+
+/// Latest failure of an endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointError {
+    /// `None` when the request failed before a response arrived
+    pub status: Option<u16>,
+    /// Transport error or response body, truncated
+    pub message: String,
+    /// When the request failed, in milliseconds
+    pub failed_at: i64,
+}
+
+/// Point-in-time view of an exchange's REST and WebSocket transports
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics {
+    /// Latest requests, oldest first
+    pub recent_requests: Vec<RequestSample>,
+    /// Latest failure of every endpoint that has failed
+    pub last_errors: HashMap<String, EndpointError>,
+    /// Usage the venue last reported; `remaining` is the headroom left in the window
+    pub rate_limit: Option<RateLimitStatus>,
+    /// Control info of every WebSocket session still alive
+    pub websockets: Vec<WsControlInfo>,
+}
+
+impl Diagnostics {
+    /// Latency below which `percentile` percent of recent requests completed
+    pub fn latency_percentile(&self, percentile: u8) -> Option<Duration> {
+        let mut latencies: Vec<Duration> = self
+            .recent_requests
+            .iter()
+            .map(|request| request.latency)
+            .collect();
+        latencies.sort_unstable();
+        let last = latencies.len().checked_sub(1)?;
+        Some(latencies[last * usize::from(percentile.min(100)) / 100])
+    }
+}
+
+/// Recent requests, errors and WebSocket sessions of one exchange
+///
+/// Clones share the registry. `ReqwestRest` and `TungsteniteWs` report into
+/// `DiagnosticsRegistry::for_exchange` of their exchange, so every client of the venue
+/// in this process shows up in one `Diagnostics`.
+#[derive(Debug, Clone)]
+pub struct DiagnosticsRegistry {
+    exchange_name: String,
+    state: Arc<Mutex<RegistryState>>,
+}
+
+#[derive(Debug, Default)]
+struct RegistryState {
+    requests: VecDeque<RequestSample>,
+    last_errors: HashMap<String, EndpointError>,
+    sessions: Vec<Weak<Mutex<WsControlInfo>>>,
+}
+
+impl DiagnosticsRegistry {
+    /// Registry shared by every client of `exchange_name` in this process
+    pub fn for_exchange(exchange_name: &str) -> Self {
+        static SHARED: OnceLock<Mutex<HashMap<String, DiagnosticsRegistry>>> = OnceLock::new();
+
+        SHARED
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(exchange_name.to_string())
+            .or_insert_with(|| Self {
+                exchange_name: exchange_name.to_string(),
+                state: Arc::default(),
+            })
+            .clone()
+    }
+
+    /// Record a completed request, and its failure when `error` is set
+    pub(crate) fn record_request(
+        &self,
+        method: &Method,
+        endpoint: &str,
+        status: Option<StatusCode>,
+        latency: Duration,
+        error: Option<&str>,
+    ) {
+        let now = chrono::Utc::now().timestamp_millis();
+        let status = status.map(|status| status.as_u16());
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.requests.len() == RECENT_REQUESTS {
+            state.requests.pop_front();
+        }
+        state.requests.push_back(RequestSample {
+            method: method.clone(),
+            endpoint: endpoint.to_string(),
+            status,
+            latency,
+            completed_at: now,
+        });
+        if let Some(error) = error {
+            state.last_errors.insert(
+                endpoint.to_string(),
+                EndpointError {
+                    status,
+                    message: error.chars().take(MAX_ERROR_CHARS).collect(),
+                    failed_at: now,
+                },
+            );
+        }
+    }
+
+    /// Report `control` until its session is dropped
+    pub(crate) fn register_session(&self, control: &WsControl) {
+        let session = control.downgrade();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.sessions.retain(|known| known.strong_count() > 0);
+        if !state.sessions.iter().any(|known| known.ptr_eq(&session)) {
+            state.sessions.push(session);
+        }
+    }
+
+    pub fn snapshot(&self) -> Diagnostics {
+        let (recent_requests, last_errors, sessions) = {
+            let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            (
+                state.requests.iter().cloned().collect(),
+                state.last_errors.clone(),
+                state.sessions.clone(),
+            )
+        };
+        Diagnostics {
+            recent_requests,
+            last_errors,
+            rate_limit: rate_limit_status(&self.exchange_name),
+            websockets: sessions
+                .iter()
+                .filter_map(Weak::upgrade)
+                .map(|info| info.lock().unwrap_or_else(|e| e.into_inner()).clone())
+                .collect(),
+        }
+    }
+}
+
+/// Diagnostics of every client of `exchange_name` in this process
+pub fn diagnostics(exchange_name: &str) -> Diagnostics {
+    DiagnosticsRegistry::for_exchange(exchange_name).snapshot()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_recent_requests_and_last_error_per_endpoint() {
+        let registry = DiagnosticsRegistry::for_exchange("diagnostics-test");
+        for i in 0..=RECENT_REQUESTS as u64 {
+            registry.record_request(
+                &Method::GET,
+                "/ticker",
+                Some(StatusCode::OK),
+                Duration::from_millis(i),
+                None,
+            );
+        }
+        registry.record_request(
+            &Method::POST,
+            "/order",
+            Some(StatusCode::BAD_REQUEST),
+            Duration::from_millis(5),
+            Some(&"x".repeat(MAX_ERROR_CHARS * 2)),
+        );
+        let control = WsControl::default();
+        registry.register_session(&control);
+        registry.register_session(&control);
+
+        let snapshot = diagnostics("diagnostics-test");
+        assert_eq!(snapshot.recent_requests.len(), RECENT_REQUESTS);
+        assert_eq!(
+            snapshot.recent_requests[0].latency,
+            Duration::from_millis(2)
+        );
+        assert_eq!(
+            snapshot.latency_percentile(100),
+            Some(Duration::from_millis(RECENT_REQUESTS as u64))
+        );
+        let error = &snapshot.last_errors["/order"];
+        assert_eq!(error.status, Some(400));
+        assert_eq!(error.message.len(), MAX_ERROR_CHARS);
+        assert!(!snapshot.last_errors.contains_key("/ticker"));
+        assert_eq!(snapshot.websockets.len(), 1);
+
+        drop(control);
+        assert!(registry.snapshot().websockets.is_empty());
+    }
+}
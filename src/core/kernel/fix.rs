@@ -0,0 +1,1039 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::OrderPlacer;
+use crate::core::types::{
+    AccountEvent, AccountFill, OrderRequest, OrderResponse, OrderSide, OrderType, OrderUpdate,
+    Price, Quantity, Symbol, TimeInForce,
+};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+
+const SOH: u8 = 0x01;
+const BEGIN_STRING: &str = "FIX.4.4";
+/// How long to wait for the venue to answer a logon, order or cancel
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Pending key of the logon reply
+const LOGON_KEY: &str = "\u{0}logon";
+
+pub mod tags {
+    pub const AVG_PX: u32 = 6;
+    pub const BEGIN_SEQ_NO: u32 = 7;
+    pub const CL_ORD_ID: u32 = 11;
+    pub const COMMISSION: u32 = 12;
+    pub const CUM_QTY: u32 = 14;
+    pub const EXEC_ID: u32 = 17;
+    pub const LAST_PX: u32 = 31;
+    pub const LAST_QTY: u32 = 32;
+    pub const MSG_SEQ_NUM: u32 = 34;
+    pub const MSG_TYPE: u32 = 35;
+    pub const NEW_SEQ_NO: u32 = 36;
+    pub const ORDER_ID: u32 = 37;
+    pub const ORDER_QTY: u32 = 38;
+    pub const ORD_STATUS: u32 = 39;
+    pub const ORD_TYPE: u32 = 40;
+    pub const ORIG_CL_ORD_ID: u32 = 41;
+    pub const POSS_DUP_FLAG: u32 = 43;
+    pub const PRICE: u32 = 44;
+    pub const REF_SEQ_NUM: u32 = 45;
+    pub const SENDER_COMP_ID: u32 = 49;
+    pub const SENDING_TIME: u32 = 52;
+    pub const SIDE: u32 = 54;
+    pub const SYMBOL: u32 = 55;
+    pub const TARGET_COMP_ID: u32 = 56;
+    pub const TEXT: u32 = 58;
+    pub const TIME_IN_FORCE: u32 = 59;
+    pub const TRANSACT_TIME: u32 = 60;
+    pub const RAW_DATA_LENGTH: u32 = 95;
+    pub const RAW_DATA: u32 = 96;
+    pub const ENCRYPT_METHOD: u32 = 98;
+    pub const STOP_PX: u32 = 99;
+    pub const HEART_BT_INT: u32 = 108;
+    pub const TEST_REQ_ID: u32 = 112;
+    pub const GAP_FILL_FLAG: u32 = 123;
+    pub const RESET_SEQ_NUM_FLAG: u32 = 141;
+    pub const EXEC_TYPE: u32 = 150;
+    pub const USERNAME: u32 = 553;
+    pub const PASSWORD: u32 = 554;
+    pub const AGGRESSOR_INDICATOR: u32 = 1057;
+}
+
+pub mod msg_type {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const RESEND_REQUEST: &str = "2";
+    pub const REJECT: &str = "3";
+    pub const SEQUENCE_RESET: &str = "4";
+    pub const LOGOUT: &str = "5";
+    pub const EXECUTION_REPORT: &str = "8";
+    pub const ORDER_CANCEL_REJECT: &str = "9";
+    pub const LOGON: &str = "A";
+    pub const NEW_ORDER_SINGLE: &str = "D";
+    pub const ORDER_CANCEL_REQUEST: &str = "F";
+    pub const BUSINESS_MESSAGE_REJECT: &str = "j";
+}
+
+/// FIX message: its type and body fields, without the standard header and trailer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixMessage {
+    pub msg_type: String,
+    pub fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    pub fn new(msg_type: impl Into<String>) -> Self {
+        Self {
+            msg_type: msg_type.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_field(mut self, tag: u32, value: impl Into<String>) -> Self {
+        self.fields.push((tag, value.into()));
+        self
+    }
+
+    /// First value of `tag`
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(t, _)| *t == tag)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn decimal(&self, tag: u32) -> Result<Option<Decimal>, ExchangeError> {
+        self.get(tag)
+            .map(|v| {
+                Decimal::from_str(v).map_err(|e| {
+                    ExchangeError::DeserializationError(format!("Invalid FIX tag {}: {}", tag, e))
+                })
+            })
+            .transpose()
+    }
+
+    /// Wire form, with `header` fields placed after `MsgType`
+    pub fn encode(&self, header: &[(u32, String)]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (tag, value) in std::iter::once(&(tags::MSG_TYPE, self.msg_type.clone()))
+            .chain(header)
+            .chain(&self.fields)
+        {
+            body.extend_from_slice(format!("{}={}", tag, value).as_bytes());
+            body.push(SOH);
+        }
+
+        let mut message = format!("8={}\u{1}9={}\u{1}", BEGIN_STRING, body.len()).into_bytes();
+        message.extend_from_slice(&body);
+        let checksum = message.iter().map(|b| u32::from(*b)).sum::<u32>() % 256;
+        message.extend_from_slice(format!("10={:03}\u{1}", checksum).as_bytes());
+        message
+    }
+
+    /// Parse one complete frame, verifying its checksum; header fields are kept in `fields`
+    pub fn decode(frame: &[u8]) -> Result<Self, ExchangeError> {
+        let invalid =
+            |reason: &str| ExchangeError::ParseError(format!("Invalid FIX frame: {}", reason));
+        let trailer = frame
+            .len()
+            .checked_sub(7)
+            .filter(|&start| frame[start..].starts_with(b"10="))
+            .ok_or_else(|| invalid("missing checksum"))?;
+        let expected = frame[..trailer].iter().map(|b| u32::from(*b)).sum::<u32>() % 256;
+        let checksum = std::str::from_utf8(&frame[trailer + 3..frame.len() - 1])
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .ok_or_else(|| invalid("bad checksum"))?;
+        if checksum != expected {
+            return Err(invalid("checksum mismatch"));
+        }
+
+        let text = std::str::from_utf8(&frame[..trailer]).map_err(|_| invalid("not UTF-8"))?;
+        let mut msg_type = None;
+        let mut fields = Vec::new();
+        for field in text.split('\u{1}').filter(|f| !f.is_empty()) {
+            let (tag, value) = field.split_once('=').ok_or_else(|| invalid(field))?;
+            let tag: u32 = tag.parse().map_err(|_| invalid(field))?;
+            match tag {
+                8 | 9 => {}
+                tags::MSG_TYPE => msg_type = Some(value.to_string()),
+                _ => fields.push((tag, value.to_string())),
+            }
+        }
+        Ok(Self {
+            msg_type: msg_type.ok_or_else(|| invalid("missing MsgType"))?,
+            fields,
+        })
+    }
+}
+
+/// Length of the first complete frame in `buf`, if one has arrived
+fn frame_len(buf: &[u8]) -> Result<Option<usize>, ExchangeError> {
+    let prefix = format!("8={}\u{1}9=", BEGIN_STRING);
+    if buf.len() < prefix.len() {
+        return Ok(None);
+    }
+    if !buf.starts_with(prefix.as_bytes()) {
+        return Err(ExchangeError::ParseError(
+            "FIX frame does not start with BeginString".to_string(),
+        ));
+    }
+    let Some(end) = buf[prefix.len()..].iter().position(|b| *b == SOH) else {
+        return Ok(None);
+    };
+    let body_len: usize = std::str::from_utf8(&buf[prefix.len()..prefix.len() + end])
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| ExchangeError::ParseError("Invalid FIX BodyLength".to_string()))?;
+    // Body, then the 7 byte `10=NNN<SOH>` trailer
+    let total = prefix.len() + end + 1 + body_len + 7;
+    Ok((buf.len() >= total).then_some(total))
+}
+
+fn fix_timestamp() -> String {
+    chrono::Utc::now().format("%Y%m%d-%H:%M:%S%.3f").to_string()
+}
+
+fn parse_fix_timestamp(value: &str) -> Option<i64> {
+    chrono::NaiveDateTime::parse_from_str(value, "%Y%m%d-%H:%M:%S%.f")
+        .ok()
+        .map(|t| t.and_utc().timestamp_millis())
+}
+
+/// Standard header of an outgoing Logon, for signing
+#[derive(Debug, Clone)]
+pub struct FixLogonContext<'a> {
+    pub sending_time: &'a str,
+    pub msg_seq_num: u64,
+    pub sender_comp_id: &'a str,
+    pub target_comp_id: &'a str,
+}
+
+/// Venue authentication, added to the Logon message
+pub trait FixLogon: Send + Sync {
+    fn logon_fields(
+        &self,
+        context: &FixLogonContext<'_>,
+    ) -> Result<Vec<(u32, String)>, ExchangeError>;
+}
+
+/// Username/Password logon, as used by Kraken and most FIX 4.4 venues
+pub struct PasswordLogon {
+    username: String,
+    password: String,
+}
+
+impl PasswordLogon {
+    pub fn new(username: String, password: String) -> Self {
+        Self { username, password }
+    }
+}
+
+impl FixLogon for PasswordLogon {
+    fn logon_fields(
+        &self,
+        _context: &FixLogonContext<'_>,
+    ) -> Result<Vec<(u32, String)>, ExchangeError> {
+        Ok(vec![
+            (tags::USERNAME, self.username.clone()),
+            (tags::PASSWORD, self.password.clone()),
+        ])
+    }
+}
+
+/// FIX order-entry session settings
+#[derive(Debug, Clone)]
+pub struct FixConfig {
+    pub host: String,
+    pub port: u16,
+    pub sender_comp_id: String,
+    pub target_comp_id: String,
+    pub heartbeat_interval: Duration,
+    /// Joins base and quote in `Symbol (55)`, e.g. `-` for `BTC-USD` or `/` for `XBT/USD`
+    pub symbol_separator: String,
+}
+
+impl FixConfig {
+    pub fn new(
+        host: impl Into<String>,
+        port: u16,
+        sender_comp_id: impl Into<String>,
+        target_comp_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            host: host.into(),
+            port,
+            sender_comp_id: sender_comp_id.into(),
+            target_comp_id: target_comp_id.into(),
+            heartbeat_interval: Duration::from_secs(30),
+            symbol_separator: "-".to_string(),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_heartbeat_interval(mut self, interval: Duration) -> Self {
+        self.heartbeat_interval = interval;
+        self
+    }
+
+    #[must_use]
+    pub fn with_symbol_separator(mut self, separator: impl Into<String>) -> Self {
+        self.symbol_separator = separator.into();
+        self
+    }
+
+    fn to_symbol(&self, fix_symbol: &str) -> Symbol {
+        fix_symbol
+            .split_once(self.symbol_separator.as_str())
+            .and_then(|(base, quote)| Symbol::new(base, quote).ok())
+            .unwrap_or_else(|| crate::core::types::conversion::string_to_symbol(fix_symbol))
+    }
+}
+
+/// Waiters for the venue's answer, keyed by `ClOrdID`
+#[derive(Default)]
+struct Pending {
+    waiters: HashMap<String, oneshot::Sender<FixMessage>>,
+    /// `MsgSeqNum` of each waiter's request, to route session-level rejects
+    seq_nums: HashMap<u64, String>,
+}
+
+/// Order known to the session: its `ClOrdID` and FIX side, keyed by `OrderID`
+type KnownOrders = HashMap<String, (String, String)>;
+
+struct Outbound {
+    message: FixMessage,
+    /// `ClOrdID` whose answer a caller awaits
+    await_key: Option<String>,
+}
+
+/// FIX 4.4 order-entry session
+///
+/// A background task owns the connection: it stamps the standard header and sequence
+/// numbers, sends heartbeats, answers test requests, and gap-fills resend requests rather
+/// than replaying old orders. Sequence numbers reset at every logon. Execution reports
+/// are published as `AccountEvent`s.
+pub struct FixSession {
+    config: FixConfig,
+    outbound: mpsc::Sender<Outbound>,
+    pending: Arc<Mutex<Pending>>,
+    orders: Arc<Mutex<KnownOrders>>,
+    events: Mutex<Option<mpsc::Receiver<AccountEvent>>>,
+}
+
+impl FixSession {
+    /// Connect over TLS and log on
+    pub async fn connect(
+        config: FixConfig,
+        logon: Arc<dyn FixLogon>,
+    ) -> Result<Self, ExchangeError> {
+        let tcp = tokio::net::TcpStream::connect((config.host.as_str(), config.port))
+            .await
+            .map_err(|e| ExchangeError::NetworkError(format!("FIX connect failed: {}", e)))?;
+        let connector = tokio_native_tls::native_tls::TlsConnector::new()
+            .map_err(|e| ExchangeError::NetworkError(format!("TLS setup failed: {}", e)))?;
+        let stream = tokio_native_tls::TlsConnector::from(connector)
+            .connect(&config.host, tcp)
+            .await
+            .map_err(|e| ExchangeError::NetworkError(format!("TLS handshake failed: {}", e)))?;
+        Self::start(stream, config, logon).await
+    }
+
+    /// Log on over an established stream
+    pub async fn start<S>(
+        stream: S,
+        config: FixConfig,
+        logon: Arc<dyn FixLogon>,
+    ) -> Result<Self, ExchangeError>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let (outbound, outbound_rx) = mpsc::channel(100);
+        let (events_tx, events_rx) = mpsc::channel(1000);
+        let pending = Arc::new(Mutex::new(Pending::default()));
+        let orders = Arc::new(Mutex::new(KnownOrders::new()));
+
+        let (logon_tx, logon_rx) = oneshot::channel();
+        lock(&pending)
+            .waiters
+            .insert(LOGON_KEY.to_string(), logon_tx);
+
+        let task = SessionTask {
+            config: config.clone(),
+            logon,
+            next_seq: 1,
+            pending: pending.clone(),
+            orders: orders.clone(),
+            events: events_tx,
+        };
+        tokio::spawn(task.run(stream, outbound_rx));
+
+        let reply = tokio::time::timeout(RESPONSE_TIMEOUT, logon_rx)
+            .await
+            .map_err(|_| ExchangeError::ConnectionTimeout("FIX logon timed out".to_string()))?
+            .map_err(|_| ExchangeError::WebSocketClosed("FIX session closed".to_string()))?;
+        if reply.msg_type != msg_type::LOGON {
+            return Err(ExchangeError::AuthError(format!(
+                "FIX logon refused: {}",
+                reply.get(tags::TEXT).unwrap_or("no reason given")
+            )));
+        }
+
+        Ok(Self {
+            config,
+            outbound,
+            pending,
+            orders,
+            events: Mutex::new(Some(events_rx)),
+        })
+    }
+
+    /// Order and fill events from execution reports; can be taken once
+    pub fn take_events(&self) -> Option<mpsc::Receiver<AccountEvent>> {
+        lock(&self.events).take()
+    }
+
+    /// Whether the session is still logged on
+    pub fn is_connected(&self) -> bool {
+        !self.outbound.is_closed()
+    }
+
+    /// Send a Logout; the session ends once the venue confirms
+    pub async fn logout(&self) -> Result<(), ExchangeError> {
+        self.send(FixMessage::new(msg_type::LOGOUT), None).await
+    }
+
+    async fn send(
+        &self,
+        message: FixMessage,
+        await_key: Option<String>,
+    ) -> Result<(), ExchangeError> {
+        self.outbound
+            .send(Outbound { message, await_key })
+            .await
+            .map_err(|_| ExchangeError::WebSocketClosed("FIX session closed".to_string()))
+    }
+
+    /// Send `message` and wait for the answer to its `ClOrdID`
+    async fn request(&self, message: FixMessage) -> Result<FixMessage, ExchangeError> {
+        let key = message
+            .get(tags::CL_ORD_ID)
+            .ok_or_else(|| ExchangeError::InvalidParameters("Missing ClOrdID".to_string()))?
+            .to_string();
+        let (tx, rx) = oneshot::channel();
+        lock(&self.pending).waiters.insert(key.clone(), tx);
+        if let Err(e) = self.send(message, Some(key.clone())).await {
+            lock(&self.pending).waiters.remove(&key);
+            return Err(e);
+        }
+
+        match tokio::time::timeout(RESPONSE_TIMEOUT, rx).await {
+            Ok(Ok(reply)) => Ok(reply),
+            Ok(Err(_)) => Err(ExchangeError::WebSocketClosed(
+                "FIX session closed".to_string(),
+            )),
+            Err(_) => {
+                lock(&self.pending).waiters.remove(&key);
+                Err(ExchangeError::ConnectionTimeout(format!(
+                    "No FIX response for {}",
+                    key
+                )))
+            }
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn new_cl_ord_id() -> String {
+    format!(
+        "lx{}{:06x}",
+        chrono::Utc::now().timestamp_millis(),
+        rand::random::<u32>() & 0x00ff_ffff
+    )
+}
+
+const fn fix_side(side: &OrderSide) -> &'static str {
+    match side {
+        OrderSide::Buy => "1",
+        OrderSide::Sell => "2",
+    }
+}
+
+fn ord_status_name(status: &str) -> &str {
+    match status {
+        "0" => "NEW",
+        "1" => "PARTIALLY_FILLED",
+        "2" => "FILLED",
+        "4" => "CANCELED",
+        "6" => "PENDING_CANCEL",
+        "8" => "REJECTED",
+        "A" => "PENDING_NEW",
+        "C" => "EXPIRED",
+        other => other,
+    }
+}
+
+/// Reject reason carried by a reply, or `None` when the request was accepted
+fn rejection(reply: &FixMessage) -> Option<String> {
+    let rejected = match reply.msg_type.as_str() {
+        msg_type::EXECUTION_REPORT => reply.get(tags::EXEC_TYPE) == Some("8"),
+        msg_type::ORDER_CANCEL_REJECT | msg_type::REJECT | msg_type::BUSINESS_MESSAGE_REJECT => {
+            true
+        }
+        _ => false,
+    };
+    rejected.then(|| {
+        reply
+            .get(tags::TEXT)
+            .unwrap_or("no reason given")
+            .to_string()
+    })
+}
+
+#[async_trait]
+impl OrderPlacer for FixSession {
+    fn supports_trading(&self) -> bool {
+        self.is_connected()
+    }
+
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let ord_type = match order.order_type {
+            OrderType::Market => "1",
+            OrderType::Limit => "2",
+            OrderType::StopLoss => "3",
+            OrderType::StopLossLimit => "4",
+            OrderType::TakeProfit | OrderType::TakeProfitLimit => {
+                return Err(ExchangeError::NotSupported(
+                    "FIX 4.4 has no take-profit order type".to_string(),
+                ))
+            }
+        };
+        let cl_ord_id = order.client_order_id.clone().unwrap_or_else(new_cl_ord_id);
+        let mut message = FixMessage::new(msg_type::NEW_ORDER_SINGLE)
+            .with_field(tags::CL_ORD_ID, cl_ord_id.clone())
+            .with_field(
+                tags::SYMBOL,
+                order.symbol.joined(&self.config.symbol_separator),
+            )
+            .with_field(tags::SIDE, fix_side(&order.side))
+            .with_field(tags::TRANSACT_TIME, fix_timestamp())
+            .with_field(tags::ORDER_QTY, order.quantity.to_string())
+            .with_field(tags::ORD_TYPE, ord_type);
+        if let Some(price) = order.price {
+            message = message.with_field(tags::PRICE, price.to_string());
+        }
+        if let Some(stop_price) = order.stop_price {
+            message = message.with_field(tags::STOP_PX, stop_price.to_string());
+        }
+        if let Some(tif) = &order.time_in_force {
+            let tif = match tif {
+                TimeInForce::GTC => "1",
+                TimeInForce::IOC => "3",
+                TimeInForce::FOK => "4",
+            };
+            message = message.with_field(tags::TIME_IN_FORCE, tif);
+        }
+
+        let reply = self.request(message).await?;
+        if let Some(reason) = rejection(&reply) {
+            return Err(ExchangeError::InvalidParameters(format!(
+                "FIX order rejected: {}",
+                reason
+            )));
+        }
+
+        Ok(OrderResponse {
+            order_id: reply.get(tags::ORDER_ID).unwrap_or_default().to_string(),
+            client_order_id: cl_ord_id,
+            symbol: order.symbol,
+            side: order.side,
+            order_type: order.order_type,
+            quantity: order.quantity,
+            price: order.price,
+            status: ord_status_name(reply.get(tags::ORD_STATUS).unwrap_or_default()).to_string(),
+            timestamp: reply
+                .get(tags::TRANSACT_TIME)
+                .and_then(parse_fix_timestamp)
+                .unwrap_or_else(|| chrono::Utc::now().timestamp_millis()),
+            extensions: None,
+        })
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let known = lock(&self.orders).get(&order_id).cloned();
+        let mut message = FixMessage::new(msg_type::ORDER_CANCEL_REQUEST)
+            .with_field(tags::CL_ORD_ID, new_cl_ord_id())
+            .with_field(tags::ORDER_ID, order_id.clone())
+            .with_field(
+                tags::ORIG_CL_ORD_ID,
+                known
+                    .as_ref()
+                    .map_or(order_id.as_str(), |(c, _)| c.as_str()),
+            )
+            .with_field(tags::SYMBOL, symbol.joined(&self.config.symbol_separator))
+            .with_field(tags::TRANSACT_TIME, fix_timestamp());
+        if let Some((_, side)) = known {
+            message = message.with_field(tags::SIDE, side);
+        }
+
+        let reply = self.request(message).await?;
+        rejection(&reply).map_or(Ok(()), |reason| {
+            Err(ExchangeError::InvalidParameters(format!(
+                "FIX cancel of {} rejected: {}",
+                order_id, reason
+            )))
+        })
+    }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        let known = lock(&self.orders)
+            .iter()
+            .find(|(_, (cl_ord_id, _))| *cl_ord_id == client_order_id)
+            .map(|(order_id, (_, side))| (order_id.clone(), side.clone()));
+        let mut message = FixMessage::new(msg_type::ORDER_CANCEL_REQUEST)
+            .with_field(tags::CL_ORD_ID, new_cl_ord_id())
+            .with_field(tags::ORIG_CL_ORD_ID, client_order_id.clone())
+            .with_field(tags::SYMBOL, symbol.joined(&self.config.symbol_separator))
+            .with_field(tags::TRANSACT_TIME, fix_timestamp());
+        if let Some((order_id, side)) = known {
+            message = message
+                .with_field(tags::ORDER_ID, order_id)
+                .with_field(tags::SIDE, side);
+        }
+
+        let reply = self.request(message).await?;
+        rejection(&reply).map_or(Ok(()), |reason| {
+            Err(ExchangeError::InvalidParameters(format!(
+                "FIX cancel of {} rejected: {}",
+                client_order_id, reason
+            )))
+        })
+    }
+}
+
+struct SessionTask {
+    config: FixConfig,
+    logon: Arc<dyn FixLogon>,
+    next_seq: u64,
+    pending: Arc<Mutex<Pending>>,
+    orders: Arc<Mutex<KnownOrders>>,
+    events: mpsc::Sender<AccountEvent>,
+}
+
+impl SessionTask {
+    async fn run<S>(mut self, stream: S, mut outbound: mpsc::Receiver<Outbound>)
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let (mut reader, mut writer) = tokio::io::split(stream);
+        if let Err(e) = self.send_logon(&mut writer).await {
+            tracing::warn!(error = %e, "FIX logon failed");
+            return;
+        }
+
+        let interval = self.config.heartbeat_interval;
+        let mut ticker = tokio::time::interval(interval / 2);
+        let mut last_sent = Instant::now();
+        let mut last_received = Instant::now();
+        let mut test_request_sent = false;
+        let mut buf = Vec::with_capacity(8192);
+        let mut chunk = [0u8; 4096];
+
+        loop {
+            tokio::select! {
+                read = reader.read(&mut chunk) => {
+                    match read {
+                        Ok(0) | Err(_) => return,
+                        Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                    }
+                    last_received = Instant::now();
+                    test_request_sent = false;
+                    loop {
+                        let len = match frame_len(&buf) {
+                            Ok(Some(len)) => len,
+                            Ok(None) => break,
+                            Err(e) => {
+                                tracing::warn!(error = %e, "Closing FIX session on garbled stream");
+                                return;
+                            }
+                        };
+                        let frame: Vec<u8> = buf.drain(..len).collect();
+                        match FixMessage::decode(&frame) {
+                            Ok(message) => {
+                                if !self.handle_inbound(message, &mut writer).await {
+                                    return;
+                                }
+                                last_sent = Instant::now();
+                            }
+                            Err(e) => tracing::warn!(error = %e, "Dropping FIX message"),
+                        }
+                    }
+                }
+                command = outbound.recv() => {
+                    let Some(Outbound { message, await_key }) = command else {
+                        return; // Session dropped
+                    };
+                    if let Some(key) = await_key {
+                        lock(&self.pending).seq_nums.insert(self.next_seq, key);
+                    }
+                    if self.send(&mut writer, &message).await.is_err() {
+                        return;
+                    }
+                    last_sent = Instant::now();
+                }
+                _ = ticker.tick() => {
+                    if last_received.elapsed() > interval * 3 {
+                        tracing::warn!("FIX counterparty unresponsive, closing session");
+                        return;
+                    }
+                    if last_received.elapsed() > interval * 2 && !test_request_sent {
+                        let test = FixMessage::new(msg_type::TEST_REQUEST)
+                            .with_field(tags::TEST_REQ_ID, fix_timestamp());
+                        if self.send(&mut writer, &test).await.is_err() {
+                            return;
+                        }
+                        test_request_sent = true;
+                        last_sent = Instant::now();
+                    } else if last_sent.elapsed() >= interval {
+                        if self.send(&mut writer, &FixMessage::new(msg_type::HEARTBEAT)).await.is_err() {
+                            return;
+                        }
+                        last_sent = Instant::now();
+                    }
+                }
+            }
+        }
+    }
+
+    fn header(&self, sending_time: &str) -> Vec<(u32, String)> {
+        vec![
+            (tags::SENDER_COMP_ID, self.config.sender_comp_id.clone()),
+            (tags::TARGET_COMP_ID, self.config.target_comp_id.clone()),
+            (tags::MSG_SEQ_NUM, self.next_seq.to_string()),
+            (tags::SENDING_TIME, sending_time.to_string()),
+        ]
+    }
+
+    async fn write<W: AsyncWrite + Unpin>(
+        &mut self,
+        writer: &mut W,
+        message: &FixMessage,
+        header: &[(u32, String)],
+    ) -> Result<(), ExchangeError> {
+        writer
+            .write_all(&message.encode(header))
+            .await
+            .map_err(|e| ExchangeError::NetworkError(format!("FIX write failed: {}", e)))?;
+        self.next_seq += 1;
+        Ok(())
+    }
+
+    async fn send<W: AsyncWrite + Unpin>(
+        &mut self,
+        writer: &mut W,
+        message: &FixMessage,
+    ) -> Result<(), ExchangeError> {
+        let header = self.header(&fix_timestamp());
+        self.write(writer, message, &header).await
+    }
+
+    async fn send_logon<W: AsyncWrite + Unpin>(
+        &mut self,
+        writer: &mut W,
+    ) -> Result<(), ExchangeError> {
+        let sending_time = fix_timestamp();
+        let header = self.header(&sending_time);
+        let context = FixLogonContext {
+            sending_time: &sending_time,
+            msg_seq_num: self.next_seq,
+            sender_comp_id: &self.config.sender_comp_id,
+            target_comp_id: &self.config.target_comp_id,
+        };
+        let mut logon = FixMessage::new(msg_type::LOGON)
+            .with_field(tags::ENCRYPT_METHOD, "0")
+            .with_field(
+                tags::HEART_BT_INT,
+                self.config.heartbeat_interval.as_secs().to_string(),
+            )
+            .with_field(tags::RESET_SEQ_NUM_FLAG, "Y");
+        logon.fields.extend(self.logon.logon_fields(&context)?);
+        self.write(writer, &logon, &header).await
+    }
+
+    /// Handle one inbound message; `false` ends the session
+    async fn handle_inbound<W: AsyncWrite + Unpin>(
+        &mut self,
+        message: FixMessage,
+        writer: &mut W,
+    ) -> bool {
+        let reply = match message.msg_type.as_str() {
+            msg_type::LOGON => {
+                self.complete(LOGON_KEY, message);
+                None
+            }
+            msg_type::LOGOUT => {
+                // Answers our Logout, or refuses the logon
+                self.complete(LOGON_KEY, message);
+                return false;
+            }
+            msg_type::TEST_REQUEST => {
+                let mut heartbeat = FixMessage::new(msg_type::HEARTBEAT);
+                if let Some(id) = message.get(tags::TEST_REQ_ID) {
+                    heartbeat = heartbeat.with_field(tags::TEST_REQ_ID, id);
+                }
+                Some(heartbeat)
+            }
+            msg_type::RESEND_REQUEST => {
+                // Gap-fill instead of replaying stale orders
+                let begin = message.get(tags::BEGIN_SEQ_NO).unwrap_or("1").to_string();
+                let gap_fill = FixMessage::new(msg_type::SEQUENCE_RESET)
+                    .with_field(tags::GAP_FILL_FLAG, "Y")
+                    .with_field(tags::NEW_SEQ_NO, self.next_seq.to_string());
+                let mut header = self.header(&fix_timestamp());
+                header[2].1 = begin;
+                header.push((tags::POSS_DUP_FLAG, "Y".to_string()));
+                let next_seq = self.next_seq;
+                let sent = self.write(writer, &gap_fill, &header).await.is_ok();
+                self.next_seq = next_seq;
+                return sent;
+            }
+            msg_type::EXECUTION_REPORT => {
+                self.publish(&message);
+                if let Some(key) = message.get(tags::CL_ORD_ID).map(str::to_string) {
+                    self.complete(&key, message);
+                }
+                None
+            }
+            msg_type::ORDER_CANCEL_REJECT => {
+                if let Some(key) = message.get(tags::CL_ORD_ID).map(str::to_string) {
+                    self.complete(&key, message);
+                }
+                None
+            }
+            msg_type::REJECT | msg_type::BUSINESS_MESSAGE_REJECT => {
+                let key = message
+                    .get(tags::REF_SEQ_NUM)
+                    .and_then(|seq| seq.parse().ok())
+                    .and_then(|seq: u64| lock(&self.pending).seq_nums.remove(&seq));
+                if let Some(key) = key {
+                    self.complete(&key, message);
+                } else {
+                    tracing::warn!(text = ?message.get(tags::TEXT), "FIX reject");
+                }
+                None
+            }
+            _ => None,
+        };
+
+        match reply {
+            Some(reply) => self.send(writer, &reply).await.is_ok(),
+            None => true,
+        }
+    }
+
+    fn complete(&self, key: &str, message: FixMessage) {
+        let waiter = {
+            let mut pending = lock(&self.pending);
+            pending.seq_nums.retain(|_, k| k != key);
+            pending.waiters.remove(key)
+        };
+        if let Some(waiter) = waiter {
+            let _ = waiter.send(message);
+        }
+    }
+
+    /// Map an execution report onto order and fill events
+    fn publish(&self, report: &FixMessage) {
+        let (Some(order_id), Some(side)) = (report.get(tags::ORDER_ID), report.get(tags::SIDE))
+        else {
+            return;
+        };
+        if let Some(cl_ord_id) = report.get(tags::CL_ORD_ID) {
+            // Cancel acks carry the cancel's ClOrdID; keep the order's own
+            lock(&self.orders)
+                .entry(order_id.to_string())
+                .or_insert_with(|| (cl_ord_id.to_string(), side.to_string()));
+        }
+
+        match self.report_events(report, order_id, side) {
+            Ok(events) => {
+                for event in events {
+                    if self.events.try_send(event).is_err() {
+                        tracing::warn!("FIX event receiver full or dropped");
+                    }
+                }
+            }
+            Err(e) => tracing::warn!(error = %e, "Dropping malformed execution report"),
+        }
+    }
+
+    fn report_events(
+        &self,
+        report: &FixMessage,
+        order_id: &str,
+        side: &str,
+    ) -> Result<Vec<AccountEvent>, ExchangeError> {
+        let symbol = self
+            .config
+            .to_symbol(report.get(tags::SYMBOL).unwrap_or_default());
+        let side = if side == "2" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        };
+        let timestamp = report
+            .get(tags::TRANSACT_TIME)
+            .and_then(parse_fix_timestamp)
+            .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
+
+        let mut events = vec![AccountEvent::Order(OrderUpdate {
+            symbol: symbol.clone(),
+            order_id: order_id.to_string(),
+            client_order_id: report.get(tags::CL_ORD_ID).unwrap_or_default().to_string(),
+            side: side.clone(),
+            status: ord_status_name(report.get(tags::ORD_STATUS).unwrap_or_default()).to_string(),
+            price: report.decimal(tags::PRICE)?.map(Price::new),
+            quantity: Quantity::new(report.decimal(tags::ORDER_QTY)?.unwrap_or_default()),
+            filled_quantity: Quantity::new(report.decimal(tags::CUM_QTY)?.unwrap_or_default()),
+            timestamp,
+        })];
+
+        let last_qty = report.decimal(tags::LAST_QTY)?.unwrap_or_default();
+        if report.get(tags::EXEC_TYPE) == Some("F") && !last_qty.is_zero() {
+            events.push(AccountEvent::Fill(AccountFill {
+                symbol,
+                order_id: order_id.to_string(),
+                trade_id: report.get(tags::EXEC_ID).unwrap_or_default().to_string(),
+                side,
+                price: Price::new(report.decimal(tags::LAST_PX)?.unwrap_or_default()),
+                quantity: Quantity::new(last_qty),
+                fee: report.decimal(tags::COMMISSION)?.unwrap_or_default(),
+                fee_asset: None,
+                is_maker: report.get(tags::AGGRESSOR_INDICATOR) == Some("N"),
+                timestamp,
+            }));
+        }
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::DuplexStream;
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let message = FixMessage::new(msg_type::HEARTBEAT).with_field(tags::TEST_REQ_ID, "t1");
+        let header = vec![(tags::SENDER_COMP_ID, "ME".to_string())];
+        let wire = message.encode(&header);
+
+        let text = String::from_utf8(wire.clone())
+            .unwrap()
+            .replace('\u{1}', "|");
+        assert_eq!(text, "8=FIX.4.4|9=18|35=0|49=ME|112=t1|10=139|");
+        assert_eq!(frame_len(&wire).unwrap(), Some(wire.len()));
+        assert_eq!(frame_len(&wire[..wire.len() - 1]).unwrap(), None);
+
+        let decoded = FixMessage::decode(&wire).unwrap();
+        assert_eq!(decoded.msg_type, "0");
+        assert_eq!(decoded.get(tags::TEST_REQ_ID), Some("t1"));
+
+        let mut corrupt = wire;
+        corrupt[20] = b'X';
+        assert!(FixMessage::decode(&corrupt).is_err());
+    }
+
+    async fn read_message(stream: &mut DuplexStream, buf: &mut Vec<u8>) -> FixMessage {
+        loop {
+            if let Some(len) = frame_len(buf).unwrap() {
+                let frame: Vec<u8> = buf.drain(..len).collect();
+                return FixMessage::decode(&frame).unwrap();
+            }
+            let mut chunk = [0u8; 1024];
+            let n = stream.read(&mut chunk).await.unwrap();
+            assert!(n > 0, "session closed");
+            buf.extend_from_slice(&chunk[..n]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session_logs_on_and_places_order() {
+        let (client, mut venue) = tokio::io::duplex(64 * 1024);
+        let venue_task = tokio::spawn(async move {
+            let mut buf = Vec::new();
+            let logon = read_message(&mut venue, &mut buf).await;
+            assert_eq!(logon.msg_type, msg_type::LOGON);
+            assert_eq!(logon.get(tags::USERNAME), Some("user"));
+            venue
+                .write_all(&FixMessage::new(msg_type::LOGON).encode(&[]))
+                .await
+                .unwrap();
+
+            let order = read_message(&mut venue, &mut buf).await;
+            assert_eq!(order.msg_type, msg_type::NEW_ORDER_SINGLE);
+            assert_eq!(order.get(tags::SYMBOL), Some("XBT/USD"));
+            assert_eq!(order.get(tags::MSG_SEQ_NUM), Some("2"));
+            let report = FixMessage::new(msg_type::EXECUTION_REPORT)
+                .with_field(tags::ORDER_ID, "o-1")
+                .with_field(tags::CL_ORD_ID, order.get(tags::CL_ORD_ID).unwrap())
+                .with_field(tags::EXEC_ID, "e-1")
+                .with_field(tags::EXEC_TYPE, "F")
+                .with_field(tags::ORD_STATUS, "2")
+                .with_field(tags::SYMBOL, "XBT/USD")
+                .with_field(tags::SIDE, "1")
+                .with_field(tags::ORDER_QTY, "0.5")
+                .with_field(tags::CUM_QTY, "0.5")
+                .with_field(tags::LAST_QTY, "0.5")
+                .with_field(tags::LAST_PX, "30000");
+            venue.write_all(&report.encode(&[])).await.unwrap();
+            venue
+        });
+
+        let config = FixConfig::new("localhost", 0, "ME", "VENUE").with_symbol_separator("/");
+        let logon = Arc::new(PasswordLogon::new("user".to_string(), "pass".to_string()));
+        let session = FixSession::start(client, config, logon).await.unwrap();
+        let mut events = session.take_events().unwrap();
+
+        let response = session
+            .place_order(OrderRequest {
+                symbol: Symbol::new("XBT", "USD").unwrap(),
+                side: OrderSide::Buy,
+                order_type: OrderType::Market,
+                quantity: Quantity::new(Decimal::new(5, 1)),
+                price: None,
+                time_in_force: None,
+                stop_price: None,
+                reduce_only: None,
+                close_position: None,
+                client_order_id: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.order_id, "o-1");
+        assert_eq!(response.status, "FILLED");
+
+        let AccountEvent::Order(update) = events.recv().await.unwrap() else {
+            panic!("expected an order update");
+        };
+        assert_eq!(update.filled_quantity.to_string(), "0.5");
+        let AccountEvent::Fill(fill) = events.recv().await.unwrap() else {
+            panic!("expected a fill");
+        };
+        assert_eq!(fill.trade_id, "e-1");
+        assert_eq!(fill.price.to_string(), "30000");
+        venue_task.await.unwrap();
+    }
+}
@@ -0,0 +1,90 @@
+use reqwest::{Method, StatusCode};
+use std::time::Duration;
+
+/// Seconds from sending a REST request to reading its body, by exchange, method and endpoint
+pub const REST_REQUEST_DURATION: &str = "lotusx_rest_request_duration_seconds";
+/// REST responses and transport failures, by exchange, method, endpoint and status
+///
+/// `status` is the HTTP status code, or `error` when no response arrived.
+pub const REST_REQUESTS: &str = "lotusx_rest_requests_total";
+/// WebSocket data frames received, by exchange; take its rate for messages per second
+pub const WS_MESSAGES: &str = "lotusx_ws_messages_received_total";
+/// WebSocket connections opened after the first, by exchange
+pub const WS_RECONNECTS: &str = "lotusx_ws_reconnects_total";
+/// WebSocket frames the codec failed to decode, by exchange
+pub const WS_DECODE_ERRORS: &str = "lotusx_ws_decode_errors_total";
+
+/// Path segments at least this long that contain a digit are taken for ids
+const MIN_ID_LEN: usize = 8;
+
+/// `endpoint` with order ids and similar path parameters replaced by `{id}`
+///
+/// Keeps the `endpoint` label bounded when venues put ids in the path, as Gate.io
+/// and Coinbase do for cancels.
+pub(crate) fn endpoint_label(endpoint: &str) -> String {
+    endpoint
+        .split('/')
+        .map(|segment| {
+            if segment.len() >= MIN_ID_LEN && segment.bytes().any(|b| b.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Record one REST attempt; `status` is `None` when the request failed in transport
+pub(crate) fn record_rest_request(
+    exchange: &str,
+    method: &Method,
+    endpoint: &str,
+    status: Option<StatusCode>,
+    elapsed: Duration,
+) {
+    let labels = [
+        ("exchange", exchange.to_string()),
+        ("method", method.to_string()),
+        ("endpoint", endpoint_label(endpoint)),
+    ];
+    metrics::histogram!(REST_REQUEST_DURATION, &labels).record(elapsed.as_secs_f64());
+    let status = status.map_or_else(|| "error".to_string(), |s| s.as_u16().to_string());
+    let [exchange, method, endpoint] = labels;
+    metrics::counter!(
+        REST_REQUESTS,
+        &[exchange, method, endpoint, ("status", status)]
+    )
+    .increment(1);
+}
+
+pub(crate) fn record_ws_message(exchange: &str) {
+    metrics::counter!(WS_MESSAGES, "exchange" => exchange.to_string()).increment(1);
+}
+
+pub(crate) fn record_ws_reconnect(exchange: &str) {
+    metrics::counter!(WS_RECONNECTS, "exchange" => exchange.to_string()).increment(1);
+}
+
+pub(crate) fn record_ws_decode_error(exchange: &str) {
+    metrics::counter!(WS_DECODE_ERRORS, "exchange" => exchange.to_string()).increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_label_masks_ids_only() {
+        assert_eq!(
+            endpoint_label("/api/v4/spot/orders/12345678901"),
+            "/api/v4/spot/orders/{id}"
+        );
+        assert_eq!(
+            endpoint_label("/api/v3/brokerage/orders/historical/0a1b2c3d-4e5f"),
+            "/api/v3/brokerage/orders/historical/{id}"
+        );
+        assert_eq!(endpoint_label("/fapi/v1/klines"), "/fapi/v1/klines");
+        assert_eq!(endpoint_label("/0/public/Ticker"), "/0/public/Ticker");
+    }
+}
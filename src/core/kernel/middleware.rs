@@ -0,0 +1,124 @@
+use crate::core::errors::ExchangeError;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Outgoing request as request hooks see it, before rate limiting and signing
+///
+/// Hooks may rewrite any field; query parameters and headers added here are signed
+/// like the caller's own.
+#[derive(Debug, Clone)]
+pub struct RestRequest {
+    pub method: Method,
+    pub endpoint: String,
+    pub query_params: Vec<(String, String)>,
+    /// Sent before the signer's headers, which win on a clash
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    pub authenticated: bool,
+    /// Whether sending the request twice is harmless, so failures in transit and 5xx
+    /// replies may be retried; true for every method but POST and PATCH
+    ///
+    /// Order placements go out as POST and are never retried, since a request that
+    /// timed out may still have been executed.
+    pub idempotent: bool,
+    /// Retries allowed for this request; `None` keeps the client's `max_retries`
+    pub max_retries: Option<u32>,
+}
+
+impl RestRequest {
+    pub(crate) fn new(
+        method: Method,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        body: &[u8],
+        authenticated: bool,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            query_params: query_params
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect(),
+            headers: Vec::new(),
+            body: body.to_vec(),
+            idempotent: method != Method::POST && method != Method::PATCH,
+            method,
+            authenticated,
+            max_retries: None,
+        }
+    }
+
+    /// Add a header, e.g. a broker id for a rebate program
+    pub fn insert_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.headers.push((name.into(), value.into()));
+    }
+
+    /// Add a query parameter
+    pub fn insert_query_param(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.query_params.push((key.into(), value.into()));
+    }
+
+    pub(crate) fn borrowed_query_params(&self) -> Vec<(&str, &str)> {
+        self.query_params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect()
+    }
+}
+
+/// Response as response hooks see it; throttled responses that are retried show up too
+#[derive(Debug)]
+pub struct RestResponse<'a> {
+    pub method: &'a Method,
+    pub endpoint: &'a str,
+    pub status: StatusCode,
+    pub headers: &'a HeaderMap,
+    pub body: &'a str,
+    /// Time from sending the request to reading the whole body
+    pub elapsed: Duration,
+}
+
+/// Observes or rewrites a request; an error aborts it before anything is sent
+pub type RequestHook = Arc<dyn Fn(&mut RestRequest) -> Result<(), ExchangeError> + Send + Sync>;
+
+/// Observes a response before it is parsed
+pub type ResponseHook = Arc<dyn Fn(&RestResponse<'_>) + Send + Sync>;
+
+/// Hooks `ReqwestRest` runs around every request, in the order they were added
+#[derive(Clone, Default)]
+pub struct RestHooks {
+    request: Vec<RequestHook>,
+    response: Vec<ResponseHook>,
+}
+
+impl fmt::Debug for RestHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RestHooks")
+            .field("request", &self.request.len())
+            .field("response", &self.response.len())
+            .finish()
+    }
+}
+
+impl RestHooks {
+    pub fn add_request_hook(&mut self, hook: RequestHook) {
+        self.request.push(hook);
+    }
+
+    pub fn add_response_hook(&mut self, hook: ResponseHook) {
+        self.response.push(hook);
+    }
+
+    pub(crate) fn on_request(&self, request: &mut RestRequest) -> Result<(), ExchangeError> {
+        self.request.iter().try_for_each(|hook| hook(request))
+    }
+
+    pub(crate) fn on_response(&self, response: &RestResponse<'_>) {
+        for hook in &self.response {
+            hook(response);
+        }
+    }
+}
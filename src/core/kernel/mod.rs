@@ -12,6 +12,15 @@
 /// - `RestClient`: Unified HTTP client interface
 /// - `WsSession`: WebSocket connection management
 /// - `ReconnectWs`: Automatic reconnection wrapper
+/// - `RateLimiter`: Request budget applied by `ReqwestRest`, with `TokenBucket` presets
+/// - `RateLimitStatus`: Usage the venue reports in response headers, per client or per exchange
+/// - `Diagnostics`: Recent request latencies, last error per endpoint, rate-limit usage and
+///   WebSocket sessions of an exchange, reported by both transports
+/// - `CachedRest`: Short-lived cache of idempotent GETs shared by a connector's components
+/// - `Profile`: Coordinated WebSocket, REST, rate-limit and risk defaults selected on builders
+/// - `TimeSync`: Exchange clock offset applied to signed request timestamps
+/// - `RestHooks`: Caller hooks that rewrite requests before signing and observe responses
+/// - `ProxyConfig` / `TlsConfig`: HTTP or SOCKS5 proxy and custom CA trust, per client
 ///
 /// ## Authentication
 /// - `Signer`: Pluggable authentication interface
@@ -22,6 +31,19 @@
 /// ## Message Handling
 /// - `WsCodec`: Exchange-specific message encoding/decoding
 ///
+/// ## Fault Injection (`chaos` feature)
+/// - `ChaosRest`: Delays and fails REST requests
+/// - `ChaosWs`: Drops WebSocket messages and forces disconnects
+///
+/// ## Metrics (`metrics` feature)
+/// - `ReqwestRest` records request latency and status per endpoint through the `metrics`
+///   facade, and `TungsteniteWs` counts messages, reconnects and decode errors; install
+///   any recorder, such as `metrics-exporter-prometheus`, to export them
+///
+/// ## Order Entry (`fix` feature)
+/// - `FixSession`: FIX 4.4 order-entry session implementing `OrderPlacer`
+/// - `FixLogon`: Venue logon fields, with `PasswordLogon` for username/password venues
+///
 /// # Key Principles
 ///
 /// 1. **Transport Only**: The kernel contains NO exchange-specific logic
@@ -194,13 +216,45 @@
 ///     }
 /// }
 /// ```
+pub mod cache;
+#[cfg(feature = "chaos")]
+pub mod chaos;
 pub mod codec;
+pub mod diagnostics;
+#[cfg(feature = "fix")]
+pub mod fix;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod middleware;
+pub mod profile;
+pub mod rate_limit;
 pub mod rest;
+pub mod retry;
 pub mod signer;
+pub mod time_sync;
+pub mod transport;
 pub mod ws;
 
 // Re-export key types for convenience
-pub use codec::WsCodec;
+pub use cache::{bypass_cache, CacheConfig, CacheStats, CachedRest};
+#[cfg(feature = "chaos")]
+pub use chaos::{ChaosConfig, ChaosRest, ChaosStats, ChaosWs};
+pub use codec::{ControlFrame, WsCodec};
+pub use diagnostics::{
+    diagnostics, Diagnostics, DiagnosticsRegistry, EndpointError, RequestSample,
+};
+#[cfg(feature = "fix")]
+pub use fix::{FixConfig, FixLogon, FixLogonContext, FixMessage, FixSession, PasswordLogon};
+pub use middleware::{RequestHook, ResponseHook, RestHooks, RestRequest, RestResponse};
+pub use profile::Profile;
+pub use rate_limit::{
+    budgeted_rate_limiter, rate_limit_status, RateLimitStatus, RateLimiter, TokenBucket,
+};
 pub use rest::{ReqwestRest, RestClient, RestClientBuilder, RestClientConfig};
+pub use retry::RetryPolicy;
 pub use signer::{Ed25519Signer, HmacExchangeType, HmacSigner, JwtSigner, SignatureResult, Signer};
-pub use ws::{ReconnectWs, TungsteniteWs, WsSession};
+pub use time_sync::TimeSync;
+pub use transport::{ProxyConfig, TlsConfig};
+pub use ws::{
+    FeedLatency, ReconnectWs, TungsteniteWs, WsConfig, WsControl, WsControlInfo, WsSession,
+};
@@ -0,0 +1,158 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::ws::WsConfig;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Coordinated transport and risk defaults for a kind of session
+///
+/// A profile sets the WebSocket config, REST timeout and retries, the share of the
+/// venue's rate limit a client may use, and the price band risk checks start from.
+/// Builders apply the profile carried by `ExchangeConfig`; explicit builder settings
+/// still override it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Profile {
+    /// The library's historical defaults
+    #[default]
+    Standard,
+    /// Low-latency trading: fail fast instead of retrying stale requests, tight price bands
+    Hft,
+    /// Bulk market data: patient timeouts and retries, half the rate limit so trading
+    /// processes on the same IP keep headroom
+    Research,
+    /// Cautious trading: moderate timeouts, reserved rate limit headroom, tight price bands
+    Conservative,
+}
+
+impl Profile {
+    pub fn ws_config(self) -> WsConfig {
+        match self {
+            Self::Standard => WsConfig {
+                max_reconnect_attempts: 10,
+                reconnect_delay_ms: 2_000,
+                ..WsConfig::default()
+            },
+            Self::Hft => WsConfig::hft_optimized(),
+            Self::Research => WsConfig {
+                connect_timeout_ms: 30_000,
+                message_buffer_size: 8192,
+                max_reconnect_attempts: 50,
+                reconnect_delay_ms: 5_000,
+                subscribe_ack_timeout_ms: 10_000,
+                subscribe_retries: 5,
+                ..WsConfig::default()
+            },
+            Self::Conservative => WsConfig {
+                max_reconnect_attempts: 10,
+                reconnect_delay_ms: 2_000,
+                subscribe_retries: 3,
+                ..WsConfig::default()
+            },
+        }
+    }
+
+    /// REST request timeout in seconds
+    pub const fn rest_timeout_seconds(self) -> u64 {
+        match self {
+            Self::Standard => 30,
+            Self::Hft => 5,
+            Self::Research => 60,
+            Self::Conservative => 15,
+        }
+    }
+
+    pub const fn rest_max_retries(self) -> u32 {
+        match self {
+            Self::Standard => 3,
+            Self::Hft => 0,
+            Self::Research => 5,
+            Self::Conservative => 2,
+        }
+    }
+
+    /// Percentage of the venue's published rate limit a client may spend
+    pub const fn rate_limit_budget_percent(self) -> u32 {
+        match self {
+            Self::Standard | Self::Hft => 100,
+            Self::Research => 50,
+            Self::Conservative => 70,
+        }
+    }
+
+    /// Largest deviation from the reference price risk checks allow, as a fraction
+    pub fn max_price_deviation(self) -> Decimal {
+        match self {
+            Self::Standard | Self::Research => Decimal::new(10, 2),
+            Self::Hft => Decimal::new(2, 2),
+            Self::Conservative => Decimal::new(3, 2),
+        }
+    }
+}
+
+impl FromStr for Profile {
+    type Err = ExchangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "standard" => Ok(Self::Standard),
+            "hft" => Ok(Self::Hft),
+            "research" => Ok(Self::Research),
+            "conservative" => Ok(Self::Conservative),
+            _ => Err(ExchangeError::ConfigurationError(format!(
+                "Unknown profile: {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::kernel::RestClientConfig;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_standard_profile_keeps_historical_defaults() {
+        let config =
+            RestClientConfig::new("https://api.binance.com".to_string(), "binance".to_string());
+        let profiled = config.clone().with_profile(Profile::Standard);
+
+        assert_eq!(profiled.timeout_seconds, config.timeout_seconds);
+        assert_eq!(profiled.max_retries, config.max_retries);
+        assert!(Arc::ptr_eq(
+            profiled.rate_limiter.as_ref().unwrap(),
+            config.rate_limiter.as_ref().unwrap()
+        ));
+        assert_eq!(
+            "Conservative".parse::<Profile>().unwrap(),
+            Profile::Conservative
+        );
+        assert!("turbo".parse::<Profile>().is_err());
+    }
+
+    #[test]
+    fn test_research_profile_takes_a_share_of_the_limit() {
+        let standard =
+            RestClientConfig::new("https://api.bybit.com".to_string(), "bybit".to_string());
+        let research = standard.clone().with_profile(Profile::Research);
+        let again = RestClientConfig::new(
+            "https://api.bybit.com".to_string(),
+            "bybit_perp".to_string(),
+        )
+        .with_profile(Profile::Research);
+
+        assert_eq!(research.timeout_seconds, 60);
+        let research_limiter = research.rate_limiter.unwrap();
+        assert!(!Arc::ptr_eq(
+            &research_limiter,
+            standard.rate_limiter.as_ref().unwrap()
+        ));
+        // Spot and perp still share one bucket per share of the IP budget
+        assert!(Arc::ptr_eq(
+            &research_limiter,
+            again.rate_limiter.as_ref().unwrap()
+        ));
+    }
+}
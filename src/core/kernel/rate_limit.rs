@@ -0,0 +1,465 @@
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Request budget consulted by `ReqwestRest` before every request
+///
+/// Clones of a client share one limiter, so the budget covers the whole connector.
+#[async_trait]
+pub trait RateLimiter: Send + Sync + fmt::Debug {
+    /// Wait until the request fits in the budget, then spend its weight
+    async fn acquire(&self, method: &Method, endpoint: &str, query_params: &[(&str, &str)]);
+
+    /// Sync with the usage the venue reports in response headers
+    fn observe(&self, _headers: &HeaderMap) {}
+
+    /// Hold every request for `retry_after`, after the venue answered 429 or 418
+    fn pause(&self, retry_after: Duration);
+
+    /// The venue's allowance per window, for venues that report only what was used
+    fn limit(&self) -> Option<u32> {
+        None
+    }
+}
+
+/// Rate-limit usage reported in a venue's response headers
+///
+/// Binance reports weight used (`X-MBX-USED-WEIGHT-1M`) and orders placed
+/// (`X-MBX-ORDER-COUNT-*`); Bybit reports the endpoint limit, what is left of it and
+/// when it resets (`X-Bapi-Limit*`). Fields a venue does not report are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitStatus {
+    /// Weight or requests spent in the current window
+    pub used: Option<u32>,
+    /// Allowance of the current window
+    pub limit: Option<u32>,
+    /// Weight or requests left in the current window
+    pub remaining: Option<u32>,
+    /// Orders placed in the venue's order-count window
+    pub order_count: Option<u32>,
+    /// When the window resets, in milliseconds
+    pub reset_at: Option<i64>,
+    /// When the headers were received, in milliseconds
+    pub observed_at: i64,
+}
+
+impl RateLimitStatus {
+    /// Parse the known rate-limit headers, `None` if the response carries none
+    pub fn from_headers(headers: &HeaderMap, observed_at: i64) -> Option<Self> {
+        let number = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let count = |name: &str| number(name).map(|n| u32::try_from(n).unwrap_or(u32::MAX));
+        // Binance names the window in the header, e.g. `-10s` or `-1m`
+        let prefixed = |prefix: &str| {
+            headers
+                .keys()
+                .filter(|name| name.as_str().starts_with(prefix))
+                .find_map(|name| count(name.as_str()))
+        };
+
+        let limit = count("x-bapi-limit");
+        let remaining = count("x-bapi-limit-status");
+        let used = count("x-mbx-used-weight-1m")
+            .or_else(|| prefixed("x-mbx-used-weight-"))
+            .or_else(|| Some(limit?.saturating_sub(remaining?)));
+        let status = Self {
+            used,
+            limit,
+            remaining,
+            order_count: prefixed("x-mbx-order-count-"),
+            reset_at: number("x-bapi-limit-reset-timestamp").and_then(|t| i64::try_from(t).ok()),
+            observed_at,
+        };
+
+        (status.used.is_some() || status.remaining.is_some() || status.order_count.is_some())
+            .then_some(status)
+    }
+}
+
+/// Latest usage seen by any client of `exchange_name` in this process
+///
+/// Clients of one exchange share the slot, like they share the default limiter.
+pub fn rate_limit_status(exchange_name: &str) -> Option<RateLimitStatus> {
+    *rate_limit_status_slot(exchange_name)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Slot `ReqwestRest` records the usage of `exchange_name` in
+pub(crate) fn rate_limit_status_slot(exchange_name: &str) -> Arc<Mutex<Option<RateLimitStatus>>> {
+    type Slots = Mutex<HashMap<String, Arc<Mutex<Option<RateLimitStatus>>>>>;
+    static SLOTS: OnceLock<Slots> = OnceLock::new();
+
+    SLOTS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(exchange_name.to_string())
+        .or_default()
+        .clone()
+}
+
+/// Weight of a request, from its method, endpoint and query parameters
+pub type WeightFn = fn(&Method, &str, &[(&str, &str)]) -> u32;
+
+/// Weight-based token bucket
+///
+/// Holds `capacity` weight, refilled evenly over `period`. Requests weigh 1 unless a
+/// weight function is set.
+pub struct TokenBucket {
+    /// The venue's allowance, before any budget share
+    limit: u32,
+    capacity: f64,
+    refill_per_second: f64,
+    weigh: WeightFn,
+    /// Header carrying the venue's count of weight used in the current window
+    used_weight_header: Option<&'static str>,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    refilled_at: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl fmt::Debug for TokenBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenBucket")
+            .field("capacity", &self.capacity)
+            .field("refill_per_second", &self.refill_per_second)
+            .field("used_weight_header", &self.used_weight_header)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TokenBucket {
+    pub fn new(capacity: u32, period: Duration) -> Self {
+        let limit = capacity.max(1);
+        let capacity = f64::from(limit);
+        Self {
+            limit,
+            capacity,
+            refill_per_second: capacity / period.as_secs_f64().max(f64::EPSILON),
+            weigh: |_, _, _| 1,
+            used_weight_header: None,
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                refilled_at: Instant::now(),
+                paused_until: None,
+            }),
+        }
+    }
+
+    #[must_use]
+    pub fn with_weights(mut self, weigh: WeightFn) -> Self {
+        self.weigh = weigh;
+        self
+    }
+
+    #[must_use]
+    pub fn with_used_weight_header(mut self, header: &'static str) -> Self {
+        self.used_weight_header = Some(header);
+        self
+    }
+
+    /// Spend at most `percent` of the bucket, leaving the rest of the venue's limit to
+    /// other clients on the same IP
+    #[must_use]
+    pub fn with_budget_percent(mut self, percent: u32) -> Self {
+        let share = f64::from(percent.clamp(1, 100)) / 100.0;
+        self.capacity = (self.capacity * share).max(1.0);
+        self.refill_per_second *= share;
+        self.state
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .tokens = self.capacity;
+        self
+    }
+
+    /// Binance spot: 6000 request weight per minute per IP
+    pub fn binance_spot() -> Self {
+        Self::new(6000, Duration::from_secs(60))
+            .with_weights(binance_spot_weight)
+            .with_used_weight_header("x-mbx-used-weight-1m")
+    }
+
+    /// Binance USD-M futures: 2400 request weight per minute per IP
+    pub fn binance_futures() -> Self {
+        Self::new(2400, Duration::from_secs(60))
+            .with_weights(binance_futures_weight)
+            .with_used_weight_header("x-mbx-used-weight-1m")
+    }
+
+    /// Bybit: 600 requests per 5 seconds per IP
+    pub fn bybit() -> Self {
+        Self::new(600, Duration::from_secs(5))
+    }
+
+    /// Hyperliquid: 1200 weight per minute per IP; info requests mostly weigh 20
+    pub fn hyperliquid() -> Self {
+        Self::new(1200, Duration::from_secs(60)).with_weights(|_, endpoint, _| {
+            if endpoint.ends_with("/info") {
+                20
+            } else {
+                1
+            }
+        })
+    }
+
+    /// Spend `weight` if available, otherwise return how long to wait
+    fn try_spend(&self, weight: f64) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        if let Some(until) = state.paused_until {
+            if until > now {
+                return Some(until - now);
+            }
+            state.paused_until = None;
+        }
+
+        let elapsed = now.duration_since(state.refilled_at).as_secs_f64();
+        state.tokens = elapsed
+            .mul_add(self.refill_per_second, state.tokens)
+            .min(self.capacity);
+        state.refilled_at = now;
+
+        if state.tokens >= weight {
+            state.tokens -= weight;
+            None
+        } else {
+            Some(Duration::from_secs_f64(
+                (weight - state.tokens) / self.refill_per_second,
+            ))
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimiter for TokenBucket {
+    async fn acquire(&self, method: &Method, endpoint: &str, query_params: &[(&str, &str)]) {
+        // A request heavier than the whole bucket would otherwise wait forever
+        let weight = f64::from((self.weigh)(method, endpoint, query_params)).min(self.capacity);
+        while let Some(wait) = self.try_spend(weight) {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn observe(&self, headers: &HeaderMap) {
+        let Some(used) = self
+            .used_weight_header
+            .and_then(|name| headers.get(name))
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<f64>().ok())
+        else {
+            return;
+        };
+        // Other processes on the same IP spend from the venue's budget too
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.tokens = state.tokens.min(self.capacity - used);
+    }
+
+    fn pause(&self, retry_after: Duration) {
+        let until = Instant::now() + retry_after;
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.paused_until = Some(
+            state
+                .paused_until
+                .map_or(until, |current| current.max(until)),
+        );
+        state.tokens = 0.0;
+    }
+
+    fn limit(&self) -> Option<u32> {
+        Some(self.limit)
+    }
+}
+
+fn query_value<'a>(query_params: &[(&'a str, &'a str)], key: &str) -> Option<&'a str> {
+    query_params
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| *v)
+}
+
+fn limit_param(query_params: &[(&str, &str)]) -> Option<u32> {
+    query_value(query_params, "limit").and_then(|limit| limit.parse().ok())
+}
+
+/// Request weights of the Binance spot REST API
+pub fn binance_spot_weight(method: &Method, endpoint: &str, query_params: &[(&str, &str)]) -> u32 {
+    let has_symbol = query_value(query_params, "symbol").is_some();
+    match endpoint {
+        "/api/v3/depth" => match limit_param(query_params).unwrap_or(100) {
+            0..=100 => 5,
+            101..=500 => 25,
+            501..=1000 => 50,
+            _ => 250,
+        },
+        "/api/v3/klines" => 2,
+        "/api/v3/exchangeInfo" | "/api/v3/account" | "/api/v3/allOrders" | "/api/v3/myTrades" => 20,
+        "/api/v3/ticker/24hr" if has_symbol => 2,
+        "/api/v3/ticker/24hr" | "/api/v3/openOrders" if !has_symbol => 80,
+        "/api/v3/openOrders" => 6,
+        "/api/v3/order" if method == Method::GET => 4,
+        _ => 1,
+    }
+}
+
+/// Request weights of the Binance USD-M futures REST API
+pub fn binance_futures_weight(
+    _method: &Method,
+    endpoint: &str,
+    query_params: &[(&str, &str)],
+) -> u32 {
+    let has_symbol = query_value(query_params, "symbol").is_some();
+    match endpoint {
+        "/fapi/v1/depth" => match limit_param(query_params).unwrap_or(500) {
+            0..=50 => 2,
+            51..=100 => 5,
+            101..=500 => 10,
+            _ => 20,
+        },
+        "/fapi/v1/klines" => match limit_param(query_params).unwrap_or(500) {
+            0..=99 => 1,
+            100..=499 => 2,
+            500..=1000 => 5,
+            _ => 10,
+        },
+        "/fapi/v1/ticker/24hr" | "/fapi/v1/openOrders" if !has_symbol => 40,
+        "/fapi/v1/premiumIndex" if !has_symbol => 10,
+        "/fapi/v2/account"
+        | "/fapi/v3/account"
+        | "/fapi/v2/balance"
+        | "/fapi/v2/positionRisk"
+        | "/fapi/v1/allOrders"
+        | "/fapi/v1/userTrades"
+        | "/fapi/v1/batchOrders" => 5,
+        _ => 1,
+    }
+}
+
+/// Limiter applied by default to a client for `exchange_name`
+///
+/// Venues limit per IP, so every client of one exchange in the process shares a single
+/// bucket. Venues whose limits are per endpoint or per account get none; their 429s are
+/// still honoured by `ReqwestRest`.
+pub fn default_rate_limiter(exchange_name: &str) -> Option<Arc<dyn RateLimiter>> {
+    budgeted_rate_limiter(exchange_name, 100)
+}
+
+/// Limiter for `exchange_name` spending at most `percent` of the venue's limit
+///
+/// Clients asking for the same share of one venue share a bucket.
+pub fn budgeted_rate_limiter(exchange_name: &str, percent: u32) -> Option<Arc<dyn RateLimiter>> {
+    static SHARED: OnceLock<Mutex<HashMap<String, Arc<dyn RateLimiter>>>> = OnceLock::new();
+
+    let (key, build): (&str, fn() -> TokenBucket) = match exchange_name {
+        "binance" => ("binance", TokenBucket::binance_spot),
+        "binance_perp" => ("binance_perp", TokenBucket::binance_futures),
+        // Spot and perp draw from one IP budget
+        "bybit" | "bybit_perp" => ("bybit", TokenBucket::bybit),
+        "hyperliquid" => ("hyperliquid", TokenBucket::hyperliquid),
+        _ => return None,
+    };
+
+    let percent = percent.clamp(1, 100);
+    let key = if percent == 100 {
+        key.to_string()
+    } else {
+        format!("{}@{}", key, percent)
+    };
+    let mut shared = SHARED
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    Some(
+        shared
+            .entry(key)
+            .or_insert_with(|| Arc::new(build().with_budget_percent(percent)))
+            .clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[tokio::test]
+    async fn test_bucket_waits_for_refill_and_pause() {
+        let bucket = TokenBucket::new(2, Duration::from_millis(100));
+        let started = Instant::now();
+        for _ in 0..3 {
+            bucket.acquire(&Method::GET, "/", &[]).await;
+        }
+        assert!(started.elapsed() >= Duration::from_millis(40));
+
+        bucket.pause(Duration::from_millis(80));
+        let paused = Instant::now();
+        bucket.acquire(&Method::GET, "/", &[]).await;
+        assert!(paused.elapsed() >= Duration::from_millis(80));
+    }
+
+    #[test]
+    fn test_binance_weights_and_used_weight_header() {
+        assert_eq!(
+            binance_spot_weight(&Method::GET, "/api/v3/depth", &[("limit", "1000")]),
+            50
+        );
+        assert_eq!(
+            binance_spot_weight(&Method::GET, "/api/v3/openOrders", &[]),
+            80
+        );
+        assert_eq!(
+            binance_spot_weight(&Method::GET, "/api/v3/openOrders", &[("symbol", "BTCUSDT")]),
+            6
+        );
+        assert_eq!(
+            binance_futures_weight(&Method::GET, "/fapi/v1/klines", &[]),
+            5
+        );
+
+        let bucket = TokenBucket::binance_spot();
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("5999"));
+        bucket.observe(&headers);
+        assert!(bucket.try_spend(1.0).is_none());
+        assert!(bucket.try_spend(1.0).is_some());
+    }
+
+    #[test]
+    fn test_rate_limit_status_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("42"));
+        headers.insert("x-mbx-order-count-10s", HeaderValue::from_static("3"));
+        let status = RateLimitStatus::from_headers(&headers, 1).unwrap();
+        assert_eq!(status.used, Some(42));
+        assert_eq!(status.order_count, Some(3));
+        assert_eq!(status.limit, None);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-bapi-limit", HeaderValue::from_static("10"));
+        headers.insert("x-bapi-limit-status", HeaderValue::from_static("7"));
+        headers.insert(
+            "x-bapi-limit-reset-timestamp",
+            HeaderValue::from_static("1700000000000"),
+        );
+        let status = RateLimitStatus::from_headers(&headers, 1).unwrap();
+        assert_eq!(status.used, Some(3));
+        assert_eq!(status.remaining, Some(7));
+        assert_eq!(status.reset_at, Some(1_700_000_000_000));
+
+        assert!(RateLimitStatus::from_headers(&HeaderMap::new(), 1).is_none());
+    }
+}
@@ -1,13 +1,31 @@
-use crate::core::errors::ExchangeError;
+use crate::core::errors::{ErrorKindFn, ExchangeError};
+use crate::core::kernel::diagnostics::DiagnosticsRegistry;
+#[cfg(feature = "metrics")]
+use crate::core::kernel::metrics;
+use crate::core::kernel::middleware::{RestHooks, RestRequest, RestResponse};
+use crate::core::kernel::profile::Profile;
+use crate::core::kernel::rate_limit::{
+    budgeted_rate_limiter, default_rate_limiter, rate_limit_status_slot, RateLimitStatus,
+    RateLimiter,
+};
+use crate::core::kernel::retry::RetryPolicy;
 use crate::core::kernel::signer::Signer;
+use crate::core::kernel::time_sync::TimeSync;
+use crate::core::kernel::transport::{ProxyConfig, TlsConfig};
 use async_trait::async_trait;
-use reqwest::{Client, Method, Response};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Client, Method, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
-use tracing::{instrument, trace};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{instrument, trace, warn};
+
+/// Wait assumed when a 429 or 418 carries no `Retry-After`
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+/// Longer waits, such as a 418 IP ban, are returned as errors instead of slept through
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
 
 /// REST client trait for making HTTP requests
 ///
@@ -179,6 +197,19 @@ pub trait RestClient: Send + Sync {
         query_params: &[(&str, &str)],
         body: &[u8],
     ) -> Result<T, ExchangeError>;
+
+    /// Whether authenticated requests can be signed
+    ///
+    /// Clients built without credentials return `false`; authenticated calls on them
+    /// fail with `AuthError` before reaching the network.
+    fn can_sign(&self) -> bool {
+        true
+    }
+
+    /// Latest rate-limit usage the venue reported in response headers
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        None
+    }
 }
 
 /// Configuration for the REST client
@@ -192,8 +223,20 @@ pub struct RestClientConfig {
     pub timeout_seconds: u64,
     /// Maximum number of retries for failed requests
     pub max_retries: u32,
+    /// Backoff between retries of idempotent requests that failed in transit or with a 5xx
+    pub retry_policy: RetryPolicy,
     /// User agent string to include in requests
     pub user_agent: String,
+    /// Budget every request waits for; defaults to the venue's published IP limit
+    pub rate_limiter: Option<Arc<dyn RateLimiter>>,
+    /// Table classifying the venue's error responses into `ExchangeError::Rejected`
+    pub error_kinds: Option<ErrorKindFn>,
+    /// Clock offset applied to signed timestamps; defaults to the exchange's shared one
+    pub time_sync: Option<TimeSync>,
+    /// Proxy every request is sent through
+    pub proxy: Option<ProxyConfig>,
+    /// Certificate verification; the system store when `None`
+    pub tls: Option<TlsConfig>,
 }
 
 impl RestClientConfig {
@@ -205,10 +248,16 @@ impl RestClientConfig {
     pub fn new(base_url: String, exchange_name: String) -> Self {
         Self {
             base_url,
+            rate_limiter: default_rate_limiter(&exchange_name),
             exchange_name,
             timeout_seconds: 30,
             max_retries: 3,
+            retry_policy: RetryPolicy::default(),
             user_agent: "LotusX/1.0".to_string(),
+            error_kinds: None,
+            time_sync: None,
+            proxy: None,
+            tls: None,
         }
     }
 
@@ -224,17 +273,64 @@ impl RestClientConfig {
         self
     }
 
+    /// Set the backoff between retries
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Set the user agent string
     pub fn with_user_agent(mut self, user_agent: String) -> Self {
         self.user_agent = user_agent;
         self
     }
+
+    /// Replace the default rate limiter, e.g. to share one budget across API keys
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<dyn RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Apply `profile`'s timeout, retries and share of the venue's rate limit
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        self.timeout_seconds = profile.rest_timeout_seconds();
+        self.max_retries = profile.rest_max_retries();
+        self.rate_limiter =
+            budgeted_rate_limiter(&self.exchange_name, profile.rate_limit_budget_percent());
+        self
+    }
+
+    /// Classify error responses with the venue's `kinds` table
+    pub fn with_error_kinds(mut self, kinds: ErrorKindFn) -> Self {
+        self.error_kinds = Some(kinds);
+        self
+    }
+
+    /// Sign with `time_sync` instead of the offset shared by the exchange's clients
+    pub fn with_time_sync(mut self, time_sync: TimeSync) -> Self {
+        self.time_sync = Some(time_sync);
+        self
+    }
+
+    /// Route requests through `proxy` and verify the venue with `tls`, where set
+    pub fn with_network(mut self, proxy: Option<ProxyConfig>, tls: Option<TlsConfig>) -> Self {
+        self.proxy = proxy;
+        self.tls = tls;
+        self
+    }
+
+    /// Send requests without client-side limiting; 429s are still honoured
+    pub fn without_rate_limiter(mut self) -> Self {
+        self.rate_limiter = None;
+        self
+    }
 }
 
 /// Builder for creating REST client instances
 pub struct RestClientBuilder {
     config: RestClientConfig,
     signer: Option<Arc<dyn Signer>>,
+    hooks: RestHooks,
 }
 
 impl RestClientBuilder {
@@ -246,6 +342,7 @@ impl RestClientBuilder {
         Self {
             config,
             signer: None,
+            hooks: RestHooks::default(),
         }
     }
 
@@ -258,23 +355,54 @@ impl RestClientBuilder {
         self
     }
 
+    /// Run `hook` on every request before it is rate limited and signed
+    pub fn with_request_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&mut RestRequest) -> Result<(), ExchangeError> + Send + Sync + 'static,
+    {
+        self.hooks.add_request_hook(Arc::new(hook));
+        self
+    }
+
+    /// Run `hook` on every response before it is parsed
+    pub fn with_response_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&RestResponse<'_>) + Send + Sync + 'static,
+    {
+        self.hooks.add_response_hook(Arc::new(hook));
+        self
+    }
+
     /// Build the REST client
     ///
     /// # Returns
     /// A new `ReqwestRest` instance
     pub fn build(self) -> Result<ReqwestRest, ExchangeError> {
-        let client = Client::builder()
+        let mut builder = Client::builder()
             .timeout(std::time::Duration::from_secs(self.config.timeout_seconds))
-            .user_agent(&self.config.user_agent)
-            .build()
-            .map_err(|e| {
-                ExchangeError::ConfigurationError(format!("Failed to build HTTP client: {}", e))
-            })?;
+            .user_agent(&self.config.user_agent);
+        if let Some(proxy) = &self.config.proxy {
+            builder = builder.proxy(proxy.to_reqwest()?);
+        }
+        if let Some(tls) = &self.config.tls {
+            builder = tls.apply_to_reqwest(builder)?;
+        }
+        let client = builder.build().map_err(|e| {
+            ExchangeError::ConfigurationError(format!("Failed to build HTTP client: {}", e))
+        })?;
 
         Ok(ReqwestRest {
             client,
+            rate_limit_status: rate_limit_status_slot(&self.config.exchange_name),
+            diagnostics: DiagnosticsRegistry::for_exchange(&self.config.exchange_name),
+            time_sync: self
+                .config
+                .time_sync
+                .clone()
+                .unwrap_or_else(|| TimeSync::for_exchange(&self.config.exchange_name)),
             config: self.config,
             signer: self.signer,
+            hooks: self.hooks,
         })
     }
 }
@@ -285,6 +413,10 @@ pub struct ReqwestRest {
     client: Client,
     config: RestClientConfig,
     signer: Option<Arc<dyn Signer>>,
+    rate_limit_status: Arc<Mutex<Option<RateLimitStatus>>>,
+    diagnostics: DiagnosticsRegistry,
+    time_sync: TimeSync,
+    hooks: RestHooks,
 }
 
 impl std::fmt::Debug for ReqwestRest {
@@ -292,6 +424,7 @@ impl std::fmt::Debug for ReqwestRest {
         f.debug_struct("ReqwestRest")
             .field("config", &self.config)
             .field("has_signer", &self.signer.is_some())
+            .field("hooks", &self.hooks)
             .finish_non_exhaustive()
     }
 }
@@ -317,14 +450,37 @@ impl ReqwestRest {
             .build()
     }
 
-    /// Get the current timestamp in milliseconds
-    fn get_timestamp() -> Result<u64, ExchangeError> {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map(|d| d.as_millis() as u64)
+    /// Get the current exchange time in milliseconds for signing
+    fn get_timestamp(&self) -> Result<u64, ExchangeError> {
+        u64::try_from(self.time_sync.now_millis())
             .map_err(|e| ExchangeError::Other(format!("Failed to get timestamp: {}", e)))
     }
 
+    /// Run `hook` on every request before it is rate limited and signed
+    ///
+    /// Hooks added to a client are not seen by clones made before.
+    pub fn with_request_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&mut RestRequest) -> Result<(), ExchangeError> + Send + Sync + 'static,
+    {
+        self.hooks.add_request_hook(Arc::new(hook));
+        self
+    }
+
+    /// Run `hook` on every response before it is parsed
+    pub fn with_response_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&RestResponse<'_>) + Send + Sync + 'static,
+    {
+        self.hooks.add_response_hook(Arc::new(hook));
+        self
+    }
+
+    /// Clock offset this client signs with
+    pub fn time_sync(&self) -> &TimeSync {
+        &self.time_sync
+    }
+
     /// Build the full URL for an endpoint
     fn build_url(&self, endpoint: &str) -> String {
         format!("{}{}", self.config.base_url, endpoint)
@@ -340,13 +496,12 @@ impl ReqwestRest {
     }
 
     /// Handle the response and extract JSON
-    #[instrument(skip(self, response), fields(exchange = %self.config.exchange_name, status = %response.status()))]
-    async fn handle_response(&self, response: Response) -> Result<Value, ExchangeError> {
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| {
-            ExchangeError::NetworkError(format!("Failed to read response body: {}", e))
-        })?;
-
+    #[instrument(skip(self, response_text), fields(exchange = %self.config.exchange_name, status = %status))]
+    fn handle_response(
+        &self,
+        status: StatusCode,
+        response_text: String,
+    ) -> Result<Value, ExchangeError> {
         trace!("Response body: {}", response_text);
 
         if status.is_success() {
@@ -354,14 +509,26 @@ impl ReqwestRest {
                 ExchangeError::DeserializationError(format!("Failed to parse JSON response: {}", e))
             })
         } else {
-            Err(ExchangeError::ApiError {
+            let error = ExchangeError::ApiError {
                 code: status.as_u16() as i32,
                 message: response_text,
+            };
+            Err(match self.config.error_kinds {
+                Some(kinds) => error.classify(kinds),
+                None => error,
             })
         }
     }
 
     /// Make a request with the given parameters
+    ///
+    /// Request hooks run once, then the rate limiter is waited for. A 429 or 418 pauses
+    /// the limiter for the `Retry-After` period and is retried up to `max_retries` times,
+    /// re-signing each attempt, unless the venue asks for a longer wait than
+    /// `MAX_RETRY_AFTER`. Idempotent requests that fail in transit or with a 5xx are
+    /// retried from the same budget after the `retry_policy` backoff, ending in
+    /// `RetriesExhausted` once it runs out. Response hooks see every response, retried
+    /// ones included.
     #[instrument(skip(self, body), fields(exchange = %self.config.exchange_name, method = %method, endpoint = %endpoint))]
     async fn make_request(
         &self,
@@ -371,15 +538,204 @@ impl ReqwestRest {
         body: &[u8],
         authenticated: bool,
     ) -> Result<Value, ExchangeError> {
+        let mut request = RestRequest::new(method, endpoint, query_params, body, authenticated);
+        self.hooks.on_request(&mut request)?;
+        let query_params = request.borrowed_query_params();
+        let max_retries = request.max_retries.unwrap_or(self.config.max_retries);
+
+        let mut attempt = 0;
+        loop {
+            if let Some(limiter) = &self.config.rate_limiter {
+                limiter
+                    .acquire(&request.method, &request.endpoint, &query_params)
+                    .await;
+            }
+            let started = Instant::now();
+            let response = self.send_request(&request, &query_params).await;
+            let response = match response {
+                Ok(response) => response,
+                Err(error) => {
+                    self.record_request(&request, None, started, Some(&error.to_string()));
+                    let retryable = error.is_retryable();
+                    self.retry_or_fail(&request, error, retryable, &mut attempt, max_retries)
+                        .await?;
+                    continue;
+                }
+            };
+            self.observe_rate_limit(response.headers());
+
+            let status = response.status();
+            let headers = response.headers().clone();
+            let response_text = response.text().await;
+            let response_text = match response_text {
+                Ok(response_text) => {
+                    let error = (!status.is_success()).then_some(response_text.as_str());
+                    self.record_request(&request, Some(status), started, error);
+                    response_text
+                }
+                Err(e) => {
+                    let error =
+                        ExchangeError::NetworkError(format!("Failed to read response body: {}", e));
+                    self.record_request(&request, Some(status), started, Some(&error.to_string()));
+                    self.retry_or_fail(&request, error, true, &mut attempt, max_retries)
+                        .await?;
+                    continue;
+                }
+            };
+            self.hooks.on_response(&RestResponse {
+                method: &request.method,
+                endpoint: &request.endpoint,
+                status,
+                headers: &headers,
+                body: &response_text,
+                elapsed: started.elapsed(),
+            });
+
+            if status != StatusCode::TOO_MANY_REQUESTS && status.as_u16() != 418 {
+                match self.handle_response(status, response_text) {
+                    Err(error) if status.is_server_error() => {
+                        self.retry_or_fail(&request, error, true, &mut attempt, max_retries)
+                            .await?;
+                        continue;
+                    }
+                    result => return result,
+                }
+            }
+            let retry_after = Self::retry_after(&headers);
+            if let Some(limiter) = &self.config.rate_limiter {
+                limiter.pause(retry_after);
+            }
+            if attempt >= max_retries || retry_after > MAX_RETRY_AFTER {
+                return Err(ExchangeError::RateLimitExceeded(format!(
+                    "HTTP {}; retry after {:?}",
+                    status.as_u16(),
+                    retry_after
+                )));
+            }
+            attempt += 1;
+            warn!(
+                status = status.as_u16(),
+                ?retry_after,
+                attempt,
+                "Rate limited, retrying"
+            );
+            tokio::time::sleep(retry_after).await;
+        }
+    }
+
+    /// Report a completed request to metrics and the exchange's `DiagnosticsRegistry`
+    fn record_request(
+        &self,
+        request: &RestRequest,
+        status: Option<StatusCode>,
+        started: Instant,
+        error: Option<&str>,
+    ) {
+        #[cfg(feature = "metrics")]
+        metrics::record_rest_request(
+            &self.config.exchange_name,
+            &request.method,
+            &request.endpoint,
+            status,
+            started.elapsed(),
+        );
+        self.diagnostics.record_request(
+            &request.method,
+            &request.endpoint,
+            status,
+            started.elapsed(),
+            error,
+        );
+    }
+
+    /// Feed the venue's usage headers to the limiter and `rate_limit_status`
+    fn observe_rate_limit(&self, headers: &HeaderMap) {
+        if let Some(limiter) = &self.config.rate_limiter {
+            limiter.observe(headers);
+        }
+        if let Some(mut status) =
+            RateLimitStatus::from_headers(headers, chrono::Utc::now().timestamp_millis())
+        {
+            // Binance reports only the weight used; the limiter knows the allowance
+            if status.limit.is_none() {
+                status.limit = self.config.rate_limiter.as_ref().and_then(|l| l.limit());
+                status.remaining = status
+                    .limit
+                    .zip(status.used)
+                    .map(|(limit, used)| limit.saturating_sub(used));
+            }
+            *self
+                .rate_limit_status
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()) = Some(status);
+        }
+    }
+
+    /// Wait out the backoff before retrying after `error`, or hand back the error to
+    /// give up with
+    async fn retry_or_fail(
+        &self,
+        request: &RestRequest,
+        error: ExchangeError,
+        retryable: bool,
+        attempt: &mut u32,
+        max_retries: u32,
+    ) -> Result<(), ExchangeError> {
+        if !retryable || !request.idempotent {
+            return Err(error);
+        }
+        if *attempt >= max_retries {
+            return Err(if *attempt == 0 {
+                error
+            } else {
+                ExchangeError::RetriesExhausted {
+                    attempts: *attempt + 1,
+                    last: Box::new(error),
+                }
+            });
+        }
+        *attempt += 1;
+        let backoff = self.config.retry_policy.backoff(*attempt);
+        warn!(%error, ?backoff, attempt = *attempt, "Request failed, retrying");
+        tokio::time::sleep(backoff).await;
+        Ok(())
+    }
+
+    /// `Retry-After` in seconds; HTTP-date values fall back to the default
+    fn retry_after(headers: &HeaderMap) -> Duration {
+        headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map_or(DEFAULT_RETRY_AFTER, Duration::from_secs)
+    }
+
+    /// Build, sign and send a single request
+    async fn send_request(
+        &self,
+        rest_request: &RestRequest,
+        query_params: &[(&str, &str)],
+    ) -> Result<Response, ExchangeError> {
+        let RestRequest {
+            method,
+            endpoint,
+            body,
+            ..
+        } = rest_request;
         let url = self.build_url(endpoint);
         let mut request = self.client.request(method.clone(), &url);
 
+        // Hook headers go first so the signer's own take precedence
+        for (key, value) in &rest_request.headers {
+            request = request.header(key, value);
+        }
+
         let query_string = Self::create_query_string(query_params);
 
         // Handle authentication if required
-        if authenticated {
+        if rest_request.authenticated {
             if let Some(signer) = &self.signer {
-                let timestamp = Self::get_timestamp()?;
+                let timestamp = self.get_timestamp()?;
                 let (headers, signed_params) = signer.sign_request(
                     method.as_str(),
                     endpoint,
@@ -413,15 +769,13 @@ impl ReqwestRest {
         if !body.is_empty() {
             request = request
                 .header("Content-Type", "application/json")
-                .body(body.to_vec());
+                .body(body.clone());
         }
 
-        let response = request
+        request
             .send()
             .await
-            .map_err(|e| ExchangeError::NetworkError(format!("Request failed: {}", e)))?;
-
-        self.handle_response(response).await
+            .map_err(|e| ExchangeError::NetworkError(format!("Request failed: {}", e)))
     }
 }
 
@@ -594,6 +948,17 @@ impl RestClient for ReqwestRest {
                 })
             })
     }
+
+    fn can_sign(&self) -> bool {
+        self.signer.is_some()
+    }
+
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        *self
+            .rate_limit_status
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
 }
 
 /// No-op signer for testing or non-authenticated requests
@@ -626,3 +991,125 @@ impl Signer for NoopSigner {
         Ok((headers, signed_params))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// HTTP server answering each connection with the next canned response
+    async fn serve(replies: Vec<&'static str>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            for reply in replies {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await.unwrap();
+                socket.write_all(reply.as_bytes()).await.unwrap();
+            }
+        });
+        url
+    }
+
+    fn client(url: String, max_retries: u32) -> ReqwestRest {
+        let config = RestClientConfig::new(url, "test".to_string())
+            .with_max_retries(max_retries)
+            .with_retry_policy(RetryPolicy::exponential(
+                Duration::from_millis(1),
+                Duration::from_millis(10),
+            ));
+        RestClientBuilder::new(config).build().unwrap()
+    }
+
+    const THROTTLED: &str =
+        "HTTP/1.1 429 X\r\nretry-after: 0\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+    const UNAVAILABLE: &str = "HTTP/1.1 503 X\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+    const OK: &str =
+        "HTTP/1.1 200 OK\r\ncontent-length: 11\r\nconnection: close\r\n\r\n{\"ok\":true}";
+
+    #[tokio::test]
+    async fn test_hooks_rewrite_requests_and_see_responses() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let received = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket.write_all(OK.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_lowercase()
+        });
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let config = RestClientConfig::new(url, "test".to_string());
+        let rest = RestClientBuilder::new(config)
+            .with_request_hook(|request| {
+                request.insert_header("X-Broker-Id", "lotusx");
+                request.insert_query_param("source", "hook");
+                Ok(())
+            })
+            .with_response_hook(move |response| {
+                sink.lock()
+                    .unwrap()
+                    .push((response.endpoint.to_string(), response.status.as_u16()));
+            })
+            .build()
+            .unwrap();
+
+        rest.get("/ping", &[("a", "1")], false).await.unwrap();
+        let request = received.await.unwrap();
+        assert!(request.starts_with("get /ping?a=1&source=hook "));
+        assert!(request.contains("x-broker-id: lotusx"));
+        assert_eq!(*seen.lock().unwrap(), vec![("/ping".to_string(), 200)]);
+
+        let rest = rest.with_request_hook(|_| Err(ExchangeError::Other("blocked".to_string())));
+        let error = rest.get("/ping", &[], false).await.unwrap_err();
+        assert!(matches!(error, ExchangeError::Other(_)));
+    }
+
+    #[tokio::test]
+    async fn test_retries_after_429() {
+        let url = serve(vec![THROTTLED, OK]).await;
+        let value = client(url, 3).get("/ping", &[], false).await.unwrap();
+        assert_eq!(value["ok"], true);
+
+        let url = serve(vec![THROTTLED]).await;
+        let error = client(url, 0).get("/ping", &[], false).await.unwrap_err();
+        assert!(matches!(error, ExchangeError::RateLimitExceeded(_)));
+    }
+
+    #[tokio::test]
+    async fn test_retries_server_errors_of_idempotent_requests_only() {
+        let url = serve(vec![UNAVAILABLE, OK]).await;
+        let value = client(url, 3).get("/ping", &[], false).await.unwrap();
+        assert_eq!(value["ok"], true);
+
+        let url = serve(vec![UNAVAILABLE, UNAVAILABLE]).await;
+        let error = client(url, 1).get("/ping", &[], false).await.unwrap_err();
+        assert!(matches!(
+            error,
+            ExchangeError::RetriesExhausted { attempts: 2, ref last }
+                if matches!(**last, ExchangeError::ApiError { code: 503, .. })
+        ));
+
+        // A second reply would satisfy a retry; the order must go out only once
+        let url = serve(vec![UNAVAILABLE, OK]).await;
+        let body = serde_json::json!({"symbol": "BTCUSDT"});
+        let error = client(url, 3)
+            .post("/order", &body, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, ExchangeError::ApiError { code: 503, .. }));
+
+        // Hooks can mark a POST that only reads, such as Hyperliquid's `/info`, idempotent
+        let url = serve(vec![UNAVAILABLE, OK]).await;
+        let rest = client(url, 3).with_request_hook(|request| {
+            request.idempotent = true;
+            Ok(())
+        });
+        let value = rest.post("/order", &body, false).await.unwrap();
+        assert_eq!(value["ok"], true);
+    }
+}
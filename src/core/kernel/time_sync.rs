@@ -0,0 +1,153 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::RestMarketData;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Marks a `TimeSync` that has never been synced
+const NEVER_SYNCED: i64 = i64::MIN;
+
+/// Offset of an exchange's clock from the local one, applied to signed request timestamps
+///
+/// Clones share the offset. `ReqwestRest` signs with `TimeSync::for_exchange` of its
+/// exchange, so syncing that handle corrects every client of the venue, which avoids
+/// rejections such as Binance `-1021` when the local clock drifts.
+#[derive(Debug, Clone, Default)]
+pub struct TimeSync {
+    state: Arc<TimeSyncState>,
+}
+
+#[derive(Debug)]
+struct TimeSyncState {
+    offset_ms: AtomicI64,
+    last_synced_ms: AtomicI64,
+}
+
+impl Default for TimeSyncState {
+    fn default() -> Self {
+        Self {
+            offset_ms: AtomicI64::new(0),
+            last_synced_ms: AtomicI64::new(NEVER_SYNCED),
+        }
+    }
+}
+
+impl TimeSync {
+    /// Standalone offset, starting at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Offset shared by every client of `exchange_name` in this process
+    pub fn for_exchange(exchange_name: &str) -> Self {
+        static SHARED: OnceLock<Mutex<HashMap<String, TimeSync>>> = OnceLock::new();
+
+        SHARED
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(exchange_name.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Milliseconds to add to the local clock to get exchange time
+    pub fn offset_millis(&self) -> i64 {
+        self.state.offset_ms.load(Ordering::Relaxed)
+    }
+
+    /// Local time in milliseconds of the last successful sync
+    pub fn last_synced(&self) -> Option<i64> {
+        Some(self.state.last_synced_ms.load(Ordering::Relaxed)).filter(|&t| t != NEVER_SYNCED)
+    }
+
+    /// Current exchange time in milliseconds, as far as the offset knows
+    pub fn now_millis(&self) -> i64 {
+        chrono::Utc::now().timestamp_millis() + self.offset_millis()
+    }
+
+    /// Set the offset from a server time read between `sent_at` and `received_at`
+    ///
+    /// The server is assumed to have stamped the reply halfway through the round trip.
+    /// Returns the new offset.
+    pub fn record(&self, server_time: i64, sent_at: i64, received_at: i64) -> i64 {
+        let offset = server_time - (sent_at + (received_at - sent_at) / 2);
+        self.state.offset_ms.store(offset, Ordering::Relaxed);
+        self.state
+            .last_synced_ms
+            .store(received_at, Ordering::Relaxed);
+        offset
+    }
+
+    /// Fetch `source`'s server time once and update the offset
+    pub async fn sync<S: RestMarketData + Sync + ?Sized>(
+        &self,
+        source: &S,
+    ) -> Result<i64, ExchangeError> {
+        let sent_at = chrono::Utc::now().timestamp_millis();
+        let server_time = source.get_server_time().await?;
+        let received_at = chrono::Utc::now().timestamp_millis();
+        let offset = self.record(server_time, sent_at, received_at);
+        debug!(
+            offset_ms = offset,
+            rtt_ms = received_at - sent_at,
+            "Clock offset updated"
+        );
+        Ok(offset)
+    }
+
+    /// Sync now and then every `interval` until the task is aborted
+    ///
+    /// Failed syncs are logged and keep the previous offset.
+    pub fn spawn_refresh<S>(&self, source: Arc<S>, interval: Duration) -> JoinHandle<()>
+    where
+        S: RestMarketData + Send + Sync + ?Sized + 'static,
+    {
+        let time_sync = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = time_sync.sync(source.as_ref()).await {
+                    warn!(error = %e, "Clock sync failed, keeping previous offset");
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_assumes_server_stamped_mid_round_trip() {
+        let time_sync = TimeSync::new();
+        assert_eq!(time_sync.last_synced(), None);
+
+        // Server is 1s ahead; the request took 100ms
+        assert_eq!(time_sync.record(11_050, 10_000, 10_100), 1_000);
+        assert_eq!(time_sync.offset_millis(), 1_000);
+        assert_eq!(time_sync.last_synced(), Some(10_100));
+
+        assert_eq!(time_sync.record(9_550, 10_000, 10_100), -500);
+    }
+
+    #[test]
+    fn test_exchange_offsets_are_shared_per_venue() {
+        let first = TimeSync::for_exchange("time_sync_test_venue");
+        first.record(2_000, 1_000, 1_000);
+
+        assert_eq!(
+            TimeSync::for_exchange("time_sync_test_venue").offset_millis(),
+            1_000
+        );
+        assert_eq!(
+            TimeSync::for_exchange("time_sync_other_venue").offset_millis(),
+            0
+        );
+    }
+}
@@ -1,10 +1,21 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::codec::WsCodec;
+use crate::core::kernel::diagnostics::DiagnosticsRegistry;
+#[cfg(feature = "metrics")]
+use crate::core::kernel::metrics;
+use crate::core::kernel::profile::Profile;
+use crate::core::kernel::transport::{self, ProxyConfig, TlsConfig};
+use crate::core::types::{conversion, ConnectionEvent};
 use async_trait::async_trait;
 use futures_util::{SinkExt, StreamExt};
-use std::time::Duration;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc;
 use tokio::time::sleep;
-use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
+use tokio_tungstenite::tungstenite::protocol::Message;
 use tracing::{error, instrument, warn};
 
 /// HFT-optimized WebSocket configuration
@@ -20,6 +31,16 @@ pub struct WsConfig {
     pub max_reconnect_attempts: u32,
     /// Reconnection delay in milliseconds
     pub reconnect_delay_ms: u64,
+    /// How long to wait for the venue to confirm a subscription, for codecs that
+    /// acknowledge them
+    pub subscribe_ack_timeout_ms: u64,
+    /// Times an unconfirmed subscription is re-sent before failing with
+    /// `ExchangeError::SubscriptionTimeout`
+    pub subscribe_retries: u32,
+    /// Proxy the connection is tunnelled through
+    pub proxy: Option<ProxyConfig>,
+    /// Certificate verification for `wss://`; the system store when `None`
+    pub tls: Option<TlsConfig>,
 }
 
 impl Default for WsConfig {
@@ -30,6 +51,10 @@ impl Default for WsConfig {
             message_buffer_size: 1024,     // 1024 messages buffer
             max_reconnect_attempts: 5,
             reconnect_delay_ms: 1_000, // 1 second
+            subscribe_ack_timeout_ms: 5_000,
+            subscribe_retries: 2,
+            proxy: None,
+            tls: None,
         }
     }
 }
@@ -43,10 +68,88 @@ impl WsConfig {
             message_buffer_size: 4096,     // 4096 messages buffer
             max_reconnect_attempts: 10,
             reconnect_delay_ms: 100, // 100ms reconnect delay
+            subscribe_ack_timeout_ms: 2_000,
+            subscribe_retries: 1,
+            proxy: None,
+            tls: None,
         }
     }
 }
 
+/// Snapshot of a session's control plane, for operational dashboards
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WsControlInfo {
+    pub connected: bool,
+    /// Id the venue assigned to the current connection (Bybit, OKX)
+    pub connection_id: Option<String>,
+    /// Streams subscribed on the current connection
+    pub subscriptions: usize,
+    /// Round trip of the latest keepalive sent with `WsSession::ping`
+    pub keepalive_rtt: Option<Duration>,
+    pub reconnects: u32,
+    /// Monotonic time the latest message was decoded
+    pub last_received_at: Option<Instant>,
+    /// Latency of messages the codec reads a venue timestamp from
+    pub feed_latency: FeedLatency,
+}
+
+/// Exchange-to-receipt latency of decoded messages, from the venue's own timestamps
+///
+/// Taken against this host's wall clock, so clock offset from the venue is included
+/// and a sample can be negative; compare it across venues and over time rather than
+/// read it as an absolute delay.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeedLatency {
+    pub samples: u64,
+    pub last_us: i64,
+    pub min_us: i64,
+    pub max_us: i64,
+    total_us: i64,
+}
+
+impl FeedLatency {
+    /// Mean over every sample, `None` before the first
+    pub fn mean_us(&self) -> Option<i64> {
+        let samples = i64::try_from(self.samples).ok().filter(|&n| n > 0)?;
+        Some(self.total_us / samples)
+    }
+
+    fn record(&mut self, latency_us: i64) {
+        if self.samples == 0 {
+            self.min_us = latency_us;
+            self.max_us = latency_us;
+        }
+        self.samples += 1;
+        self.last_us = latency_us;
+        self.min_us = self.min_us.min(latency_us);
+        self.max_us = self.max_us.max(latency_us);
+        self.total_us = self.total_us.saturating_add(latency_us);
+    }
+}
+
+/// Shared handle to a session's control info, updated by the session as it runs
+///
+/// Clones observe the same session, so a handle taken before the session is moved into
+/// a reader task stays live.
+#[derive(Debug, Clone, Default)]
+pub struct WsControl {
+    info: Arc<Mutex<WsControlInfo>>,
+}
+
+impl WsControl {
+    pub fn snapshot(&self) -> WsControlInfo {
+        self.info.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn update(&self, f: impl FnOnce(&mut WsControlInfo)) {
+        f(&mut self.info.lock().unwrap_or_else(|e| e.into_inner()));
+    }
+
+    pub(crate) fn downgrade(&self) -> Weak<Mutex<WsControlInfo>> {
+        Arc::downgrade(&self.info)
+    }
+}
+
 /// WebSocket session trait - pure transport layer
 #[async_trait]
 pub trait WsSession<C: WsCodec>: Send + Sync {
@@ -85,6 +188,16 @@ pub trait WsSession<C: WsCodec>: Send + Sync {
 
     /// HFT optimization: set socket options for low latency
     async fn configure_low_latency(&mut self) -> Result<(), ExchangeError>;
+
+    /// Send a keepalive, timing the round trip when the session tracks control info
+    async fn ping(&mut self) -> Result<(), ExchangeError> {
+        self.send_raw(Message::Ping(Vec::new())).await
+    }
+
+    /// Control info handle; sessions that do not track it return a detached one
+    fn control(&self) -> WsControl {
+        WsControl::default()
+    }
 }
 
 /// Tungstenite-based WebSocket implementation with HFT optimizations
@@ -109,10 +222,15 @@ pub struct TungsteniteWs<C: WsCodec> {
     exchange_name: String,
     codec: C,
     config: WsConfig,
-    // HFT optimization: message buffer for batch processing
-    message_buffer: Vec<Message>,
+    // Messages read while waiting for a subscription ack, delivered before new reads
+    message_buffer: VecDeque<Message>,
     // HFT optimization: connection statistics
     connection_stats: ConnectionStats,
+    control: WsControl,
+    ping_sent_at: Option<Instant>,
+    awaiting_ack: bool,
+    /// Extra handshake headers, e.g. an API key for feeds that require one
+    headers: Vec<(String, String)>,
 }
 
 /// Connection statistics for monitoring HFT performance
@@ -124,6 +242,8 @@ pub struct ConnectionStats {
     pub bytes_received: u64,
     pub reconnection_count: u32,
     pub last_heartbeat: Option<std::time::Instant>,
+    /// Also published on the session's `WsControl`
+    pub feed_latency: FeedLatency,
 }
 
 impl<C: WsCodec> TungsteniteWs<C> {
@@ -142,8 +262,12 @@ impl<C: WsCodec> TungsteniteWs<C> {
             exchange_name,
             codec,
             config: WsConfig::default(),
-            message_buffer: Vec::new(),
+            message_buffer: VecDeque::new(),
             connection_stats: ConnectionStats::default(),
+            control: WsControl::default(),
+            ping_sent_at: None,
+            awaiting_ack: false,
+            headers: Vec::new(),
         }
     }
 
@@ -157,8 +281,12 @@ impl<C: WsCodec> TungsteniteWs<C> {
             exchange_name,
             codec,
             config: WsConfig::hft_optimized(),
-            message_buffer: Vec::with_capacity(4096),
+            message_buffer: VecDeque::with_capacity(4096),
             connection_stats: ConnectionStats::default(),
+            control: WsControl::default(),
+            ping_sent_at: None,
+            awaiting_ack: false,
+            headers: Vec::new(),
         }
     }
 
@@ -168,10 +296,146 @@ impl<C: WsCodec> TungsteniteWs<C> {
         self
     }
 
+    /// Send `name: value` with the WebSocket handshake
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Report control info through `control` instead of a fresh handle
+    pub fn with_control(mut self, control: WsControl) -> Self {
+        self.control = control;
+        self
+    }
+
     /// Get connection statistics
     pub fn stats(&self) -> &ConnectionStats {
         &self.connection_stats
     }
+
+    fn mark_disconnected(&mut self) {
+        self.connected = false;
+        self.control.update(|info| info.connected = false);
+    }
+
+    /// Record what the codec and transport reveal about the session
+    fn observe_control(&mut self, message: &Message) {
+        let frame = if matches!(message, Message::Pong(_)) {
+            Some(super::codec::ControlFrame {
+                pong: true,
+                ..super::codec::ControlFrame::default()
+            })
+        } else {
+            self.codec.control_frame(message)
+        };
+        let Some(frame) = frame else {
+            return;
+        };
+        if frame.subscribed {
+            self.awaiting_ack = false;
+        }
+        let rtt = if frame.pong {
+            self.ping_sent_at.take().map(|sent| sent.elapsed())
+        } else {
+            None
+        };
+        self.control.update(|info| {
+            if frame.connection_id.is_some() {
+                info.connection_id = frame.connection_id;
+            }
+            if rtt.is_some() {
+                info.keepalive_rtt = rtt;
+            }
+        });
+    }
+
+    /// Record the receipt of a decoded message and its latency from `exchange_time`
+    fn observe_receipt(&mut self, exchange_time: Option<i64>) {
+        let received_at = Instant::now();
+        let latency_us = exchange_time.map(|time| {
+            let now_us = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |now| i64::try_from(now.as_micros()).unwrap_or(i64::MAX));
+            now_us - conversion::timestamp_to_micros(time)
+        });
+        if let Some(latency_us) = latency_us {
+            self.connection_stats.feed_latency.record(latency_us);
+        }
+        let feed_latency = self.connection_stats.feed_latency;
+        self.control.update(|info| {
+            info.last_received_at = Some(received_at);
+            info.feed_latency = feed_latency;
+        });
+    }
+
+    /// Read from the socket, answering pings and skipping pongs
+    async fn read_raw(&mut self) -> Option<Result<Message, ExchangeError>> {
+        if !self.connected {
+            return Some(Err(ExchangeError::NetworkError(
+                "WebSocket not connected".to_string(),
+            )));
+        }
+
+        loop {
+            let read = self.read.as_mut()?;
+
+            match read.next().await {
+                Some(Ok(message)) => {
+                    self.observe_control(&message);
+                    // Handle control messages at transport level only
+                    match &message {
+                        Message::Close(_) => {
+                            self.mark_disconnected();
+                            return Some(Ok(message));
+                        }
+                        Message::Ping(data) => {
+                            // Auto-respond to pings at transport level
+                            let pong = Message::Pong(data.clone());
+                            if let Err(e) = self.send_raw(pong).await {
+                                warn!("Failed to send pong response: {}", e);
+                            }
+                        }
+                        // Ignore pong messages, continue to next
+                        Message::Pong(_) => {}
+                        _ => return Some(Ok(message)),
+                    }
+                }
+                Some(Err(e)) => {
+                    self.mark_disconnected();
+                    return Some(Err(ExchangeError::NetworkError(format!(
+                        "WebSocket error: {}",
+                        e
+                    ))));
+                }
+                None => {
+                    self.mark_disconnected();
+                    return None;
+                }
+            }
+        }
+    }
+
+    /// Read until the codec flags a subscription ack, buffering everything read
+    ///
+    /// Returns whether the ack arrived before the configured timeout.
+    async fn await_subscribe_ack(&mut self) -> Result<bool, ExchangeError> {
+        self.awaiting_ack = true;
+        let deadline = tokio::time::Instant::now()
+            + Duration::from_millis(self.config.subscribe_ack_timeout_ms);
+        while self.awaiting_ack {
+            match tokio::time::timeout_at(deadline, self.read_raw()).await {
+                Ok(Some(Ok(message))) => self.message_buffer.push_back(message),
+                Ok(Some(Err(e))) => return Err(e),
+                Ok(None) => {
+                    return Err(ExchangeError::WebSocketClosed(
+                        "Connection closed while subscribing".to_string(),
+                    ))
+                }
+                Err(_) => return Ok(false),
+            }
+        }
+        Ok(true)
+    }
 }
 
 #[async_trait]
@@ -180,17 +444,32 @@ impl<C: WsCodec> WsSession<C> for TungsteniteWs<C> {
     async fn connect(&mut self) -> Result<(), ExchangeError> {
         let connect_timeout = Duration::from_millis(self.config.connect_timeout_ms);
 
-        // HFT optimization: use timeout for connection
-        let connection_future = tokio::time::timeout(connect_timeout, connect_async(&self.url));
-
-        let (ws_stream, _) = connection_future
-            .await
-            .map_err(|_| {
-                ExchangeError::ConnectionTimeout("WebSocket connection timeout".to_string())
-            })?
-            .map_err(|e| {
-                ExchangeError::NetworkError(format!("WebSocket connection failed: {}", e))
+        let mut request = self.url.as_str().into_client_request().map_err(|e| {
+            ExchangeError::ConfigurationError(format!("Invalid WebSocket URL: {}", e))
+        })?;
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
+                ExchangeError::ConfigurationError(format!("Invalid header name: {}", e))
+            })?;
+            let value = HeaderValue::from_str(value).map_err(|e| {
+                ExchangeError::ConfigurationError(format!("Invalid header value: {}", e))
             })?;
+            request.headers_mut().insert(name, value);
+        }
+
+        // HFT optimization: use timeout for connection
+        let connection_future = tokio::time::timeout(
+            connect_timeout,
+            transport::connect_ws(
+                request,
+                self.config.proxy.as_ref(),
+                self.config.tls.as_ref(),
+            ),
+        );
+
+        let ws_stream = connection_future.await.map_err(|_| {
+            ExchangeError::ConnectionTimeout("WebSocket connection timeout".to_string())
+        })??;
 
         let (write, read) = ws_stream.split();
         self.write = Some(write);
@@ -200,6 +479,24 @@ impl<C: WsCodec> WsSession<C> for TungsteniteWs<C> {
         // Update connection statistics
         self.connection_stats.reconnection_count += 1;
         self.connection_stats.last_heartbeat = Some(std::time::Instant::now());
+        let reconnects = self.connection_stats.reconnection_count - 1;
+        #[cfg(feature = "metrics")]
+        if reconnects > 0 {
+            metrics::record_ws_reconnect(&self.exchange_name);
+        }
+        self.ping_sent_at = None;
+        self.control.update(|info| {
+            *info = WsControlInfo {
+                connected: true,
+                reconnects,
+                ..WsControlInfo::default()
+            };
+        });
+        DiagnosticsRegistry::for_exchange(&self.exchange_name).register_session(&self.control);
+
+        if let Some(auth) = self.codec.encode_auth()? {
+            self.send_raw(auth).await?;
+        }
 
         Ok(())
     }
@@ -216,60 +513,23 @@ impl<C: WsCodec> WsSession<C> for TungsteniteWs<C> {
             ExchangeError::NetworkError("WebSocket write stream not available".to_string())
         })?;
 
-        write.send(msg).await.map_err(|e| {
-            self.connected = false;
-            ExchangeError::NetworkError(format!("Failed to send WebSocket message: {}", e))
-        })?;
+        if let Err(e) = write.send(msg).await {
+            self.mark_disconnected();
+            return Err(ExchangeError::NetworkError(format!(
+                "Failed to send WebSocket message: {}",
+                e
+            )));
+        }
 
         Ok(())
     }
 
     #[instrument(skip(self), fields(exchange = %self.exchange_name))]
     async fn next_raw(&mut self) -> Option<Result<Message, ExchangeError>> {
-        if !self.connected {
-            return Some(Err(ExchangeError::NetworkError(
-                "WebSocket not connected".to_string(),
-            )));
-        }
-
-        let read = self.read.as_mut()?;
-
-        match read.next().await {
-            Some(Ok(message)) => {
-                // Handle control messages at transport level only
-                match &message {
-                    Message::Close(_) => {
-                        self.connected = false;
-                        Some(Ok(message))
-                    }
-                    Message::Ping(data) => {
-                        // Auto-respond to pings at transport level
-                        let pong = Message::Pong(data.clone());
-                        if let Err(e) = self.send_raw(pong).await {
-                            warn!("Failed to send pong response: {}", e);
-                        }
-                        // Continue to next message
-                        self.next_raw().await
-                    }
-                    Message::Pong(_) => {
-                        // Ignore pong messages, continue to next
-                        self.next_raw().await
-                    }
-                    _ => Some(Ok(message)),
-                }
-            }
-            Some(Err(e)) => {
-                self.connected = false;
-                Some(Err(ExchangeError::NetworkError(format!(
-                    "WebSocket error: {}",
-                    e
-                ))))
-            }
-            None => {
-                self.connected = false;
-                None
-            }
+        if let Some(message) = self.message_buffer.pop_front() {
+            return Some(Ok(message));
         }
+        self.read_raw().await
     }
 
     #[instrument(skip(self), fields(exchange = %self.exchange_name))]
@@ -277,7 +537,8 @@ impl<C: WsCodec> WsSession<C> for TungsteniteWs<C> {
         if let Some(write) = self.write.as_mut() {
             let _ = write.send(Message::Close(None)).await;
         }
-        self.connected = false;
+        self.mark_disconnected();
+        self.message_buffer.clear();
         self.write = None;
         self.read = None;
         Ok(())
@@ -297,7 +558,26 @@ impl<C: WsCodec> WsSession<C> for TungsteniteWs<C> {
         }
 
         let message = self.codec.encode_subscription(streams)?;
-        self.send_raw(message).await
+        self.send_raw(message.clone()).await?;
+        if self.codec.acknowledges_subscriptions() {
+            let mut retries = 0;
+            while !self.await_subscribe_ack().await? {
+                if retries == self.config.subscribe_retries {
+                    return Err(ExchangeError::SubscriptionTimeout {
+                        streams: streams.iter().map(|s| s.as_ref().to_string()).collect(),
+                    });
+                }
+                retries += 1;
+                warn!(
+                    "Subscription not acknowledged, retrying ({}/{})",
+                    retries, self.config.subscribe_retries
+                );
+                self.send_raw(message.clone()).await?;
+            }
+        }
+        self.control
+            .update(|info| info.subscriptions += streams.len());
+        Ok(())
     }
 
     #[instrument(skip(self, streams), fields(exchange = %self.exchange_name, stream_count = streams.len()))]
@@ -310,7 +590,10 @@ impl<C: WsCodec> WsSession<C> for TungsteniteWs<C> {
         }
 
         let message = self.codec.encode_unsubscription(streams)?;
-        self.send_raw(message).await
+        self.send_raw(message).await?;
+        self.control
+            .update(|info| info.subscriptions = info.subscriptions.saturating_sub(streams.len()));
+        Ok(())
     }
 
     #[instrument(skip(self), fields(exchange = %self.exchange_name))]
@@ -326,11 +609,21 @@ impl<C: WsCodec> WsSession<C> for TungsteniteWs<C> {
                         continue;
                     }
 
+                    #[cfg(feature = "metrics")]
+                    metrics::record_ws_message(&self.exchange_name);
+
                     // Decode the message using the codec
                     match self.codec.decode_message(raw_msg) {
-                        Ok(Some(decoded)) => return Some(Ok(decoded)),
+                        Ok(Some(decoded)) => {
+                            self.observe_receipt(self.codec.exchange_time(&decoded));
+                            return Some(Ok(decoded));
+                        }
                         Ok(None) => {} // Codec chose to ignore this message
-                        Err(e) => return Some(Err(e)),
+                        Err(e) => {
+                            #[cfg(feature = "metrics")]
+                            metrics::record_ws_decode_error(&self.exchange_name);
+                            return Some(Err(e));
+                        }
                     }
                 }
                 Some(Err(e)) => return Some(Err(e)),
@@ -391,15 +684,37 @@ impl<C: WsCodec> WsSession<C> for TungsteniteWs<C> {
 
         Ok(())
     }
+
+    async fn ping(&mut self) -> Result<(), ExchangeError> {
+        let ping = self
+            .codec
+            .encode_ping()
+            .unwrap_or_else(|| Message::Ping(Vec::new()));
+        self.send_raw(ping).await?;
+        self.ping_sent_at = Some(Instant::now());
+        Ok(())
+    }
+
+    fn control(&self) -> WsControl {
+        self.control.clone()
+    }
 }
 
+/// Disconnect reason when the session was found down before use
+const NOT_CONNECTED: &str = "Not connected";
+
 /// Wrapper that adds automatic reconnection capabilities
+///
+/// Reports its connection state as `ConnectionEvent`s when given a sender with
+/// `with_connection_events`.
 pub struct ReconnectWs<C: WsCodec, T: WsSession<C>> {
     inner: T,
     max_reconnect_attempts: u32,
     reconnect_delay: Duration,
     auto_resubscribe: bool,
-    subscribed_streams: Vec<String>,
+    /// Streams of every subscribe request still open, replayed request by request
+    subscribed_streams: Vec<Vec<String>>,
+    events: Option<mpsc::UnboundedSender<ConnectionEvent>>,
     _codec: std::marker::PhantomData<C>,
 }
 
@@ -415,6 +730,7 @@ impl<C: WsCodec, T: WsSession<C>> ReconnectWs<C, T> {
             reconnect_delay: Duration::from_secs(1),
             auto_resubscribe: true,
             subscribed_streams: Vec::new(),
+            events: None,
             _codec: std::marker::PhantomData,
         }
     }
@@ -431,13 +747,37 @@ impl<C: WsCodec, T: WsSession<C>> ReconnectWs<C, T> {
         self
     }
 
+    /// Take reconnection attempts and delay from `profile`
+    pub fn with_profile(self, profile: Profile) -> Self {
+        let config = profile.ws_config();
+        self.with_max_reconnect_attempts(config.max_reconnect_attempts)
+            .with_reconnect_delay(Duration::from_millis(config.reconnect_delay_ms))
+    }
+
     /// Enable or disable automatic resubscription after reconnection
     pub fn with_auto_resubscribe(mut self, auto_resubscribe: bool) -> Self {
         self.auto_resubscribe = auto_resubscribe;
         self
     }
 
-    async fn attempt_reconnect(&mut self) -> Result<(), ExchangeError> {
+    /// Report connects, drops and reconnects on `events`
+    pub fn with_connection_events(
+        mut self,
+        events: Option<mpsc::UnboundedSender<ConnectionEvent>>,
+    ) -> Self {
+        self.events = events;
+        self
+    }
+
+    fn emit(&self, event: ConnectionEvent) {
+        if let Some(events) = &self.events {
+            // The consumer may have stopped listening; the stream carries on regardless
+            let _ = events.send(event);
+        }
+    }
+
+    async fn attempt_reconnect(&mut self, reason: String) -> Result<(), ExchangeError> {
+        self.emit(ConnectionEvent::Disconnected { reason });
         let mut attempts = 0;
         let mut delay = self.reconnect_delay;
 
@@ -446,11 +786,16 @@ impl<C: WsCodec, T: WsSession<C>> ReconnectWs<C, T> {
 
             match self.inner.connect().await {
                 Ok(_) => {
-                    if self.auto_resubscribe && !self.subscribed_streams.is_empty() {
-                        let streams: Vec<&str> =
-                            self.subscribed_streams.iter().map(|s| s.as_str()).collect();
-                        if let Err(e) = self.inner.subscribe(&streams).await {
-                            warn!("Failed to resubscribe after reconnection: {}", e);
+                    self.emit(ConnectionEvent::Reconnected { attempt: attempts });
+                    if self.auto_resubscribe {
+                        // Venues that take one channel per request need them replayed apart
+                        for streams in self.subscribed_streams.clone() {
+                            if let Err(e) = self.inner.subscribe(&streams).await {
+                                warn!("Failed to resubscribe after reconnection: {}", e);
+                                self.emit(ConnectionEvent::ResubscriptionFailed {
+                                    reason: e.to_string(),
+                                });
+                            }
                         }
                     }
                     return Ok(());
@@ -470,17 +815,34 @@ impl<C: WsCodec, T: WsSession<C>> ReconnectWs<C, T> {
             self.max_reconnect_attempts
         )))
     }
+
+    /// Resubscribe `stream` so the venue sends a fresh book snapshot
+    async fn resync_stream(&mut self, stream: &str) {
+        warn!(stream, "Order book checksum mismatch, resubscribing");
+        let result = match self.inner.unsubscribe(&[stream]).await {
+            Ok(()) => self.inner.subscribe(&[stream]).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            warn!("Failed to resubscribe after checksum mismatch: {}", e);
+            self.emit(ConnectionEvent::ResubscriptionFailed {
+                reason: e.to_string(),
+            });
+        }
+    }
 }
 
 #[async_trait]
 impl<C: WsCodec, T: WsSession<C>> WsSession<C> for ReconnectWs<C, T> {
     async fn connect(&mut self) -> Result<(), ExchangeError> {
-        self.inner.connect().await
+        self.inner.connect().await?;
+        self.emit(ConnectionEvent::Connected);
+        Ok(())
     }
 
     async fn send_raw(&mut self, msg: Message) -> Result<(), ExchangeError> {
         if !self.inner.is_connected() {
-            self.attempt_reconnect().await?;
+            self.attempt_reconnect(NOT_CONNECTED.to_string()).await?;
         }
         self.inner.send_raw(msg).await
     }
@@ -488,23 +850,24 @@ impl<C: WsCodec, T: WsSession<C>> WsSession<C> for ReconnectWs<C, T> {
     async fn next_raw(&mut self) -> Option<Result<Message, ExchangeError>> {
         loop {
             if !self.inner.is_connected() {
-                if let Err(e) = self.attempt_reconnect().await {
+                if let Err(e) = self.attempt_reconnect(NOT_CONNECTED.to_string()).await {
                     return Some(Err(e));
                 }
             }
 
             match self.inner.next_raw().await {
                 Some(Ok(msg)) => return Some(Ok(msg)),
-                Some(Err(_e)) => {
+                Some(Err(e)) => {
                     // Connection error, try to reconnect
-                    if let Err(reconnect_err) = self.attempt_reconnect().await {
+                    if let Err(reconnect_err) = self.attempt_reconnect(e.to_string()).await {
                         return Some(Err(reconnect_err));
                     }
                     // Continue the loop to try receiving again
                 }
                 None => {
                     // Connection closed, try to reconnect
-                    if let Err(reconnect_err) = self.attempt_reconnect().await {
+                    let reason = "Connection closed".to_string();
+                    if let Err(reconnect_err) = self.attempt_reconnect(reason).await {
                         return Some(Err(reconnect_err));
                     }
                     // Continue the loop to try receiving again
@@ -526,7 +889,20 @@ impl<C: WsCodec, T: WsSession<C>> WsSession<C> for ReconnectWs<C, T> {
         streams: &[impl AsRef<str> + Send + Sync],
     ) -> Result<(), ExchangeError> {
         // Store streams as strings for resubscription
-        self.subscribed_streams = streams.iter().map(|s| s.as_ref().to_string()).collect();
+        let request: Vec<String> = streams
+            .iter()
+            .map(|s| s.as_ref().to_string())
+            .filter(|s| {
+                !self
+                    .subscribed_streams
+                    .iter()
+                    .flatten()
+                    .any(|open| open == s)
+            })
+            .collect();
+        if !request.is_empty() {
+            self.subscribed_streams.push(request);
+        }
         self.inner.subscribe(streams).await
     }
 
@@ -537,31 +913,42 @@ impl<C: WsCodec, T: WsSession<C>> WsSession<C> for ReconnectWs<C, T> {
         // Remove from subscribed streams
         let streams_to_remove: Vec<String> =
             streams.iter().map(|s| s.as_ref().to_string()).collect();
+        for request in &mut self.subscribed_streams {
+            request.retain(|s| !streams_to_remove.contains(s));
+        }
         self.subscribed_streams
-            .retain(|s| !streams_to_remove.contains(s));
+            .retain(|request| !request.is_empty());
         self.inner.unsubscribe(streams).await
     }
 
     async fn next_message(&mut self) -> Option<Result<C::Message, ExchangeError>> {
         loop {
             if !self.inner.is_connected() {
-                if let Err(e) = self.attempt_reconnect().await {
+                if let Err(e) = self.attempt_reconnect(NOT_CONNECTED.to_string()).await {
                     return Some(Err(e));
                 }
             }
 
             match self.inner.next_message().await {
                 Some(Ok(msg)) => return Some(Ok(msg)),
-                Some(Err(_e)) => {
+                // The connection is fine; only the one book needs a new snapshot
+                Some(Err(e @ ExchangeError::ChecksumMismatch { .. })) => {
+                    if let ExchangeError::ChecksumMismatch { stream, .. } = &e {
+                        self.resync_stream(stream).await;
+                    }
+                    return Some(Err(e));
+                }
+                Some(Err(e)) => {
                     // Connection error, try to reconnect
-                    if let Err(reconnect_err) = self.attempt_reconnect().await {
+                    if let Err(reconnect_err) = self.attempt_reconnect(e.to_string()).await {
                         return Some(Err(reconnect_err));
                     }
                     // Continue the loop to try receiving again
                 }
                 None => {
                     // Connection closed, try to reconnect
-                    if let Err(reconnect_err) = self.attempt_reconnect().await {
+                    let reason = "Connection closed".to_string();
+                    if let Err(reconnect_err) = self.attempt_reconnect(reason).await {
                         return Some(Err(reconnect_err));
                     }
                     // Continue the loop to try receiving again
@@ -573,7 +960,7 @@ impl<C: WsCodec, T: WsSession<C>> WsSession<C> for ReconnectWs<C, T> {
     /// HFT optimization: bulk send messages for reduced syscalls
     async fn send_bulk(&mut self, messages: &[Message]) -> Result<(), ExchangeError> {
         if !self.inner.is_connected() {
-            self.attempt_reconnect().await?;
+            self.attempt_reconnect(NOT_CONNECTED.to_string()).await?;
         }
         self.inner.send_bulk(messages).await
     }
@@ -581,8 +968,322 @@ impl<C: WsCodec, T: WsSession<C>> WsSession<C> for ReconnectWs<C, T> {
     /// HFT optimization: configure for low latency
     async fn configure_low_latency(&mut self) -> Result<(), ExchangeError> {
         if !self.inner.is_connected() {
-            self.attempt_reconnect().await?;
+            self.attempt_reconnect(NOT_CONNECTED.to_string()).await?;
         }
         self.inner.configure_low_latency().await
     }
+
+    async fn ping(&mut self) -> Result<(), ExchangeError> {
+        if !self.inner.is_connected() {
+            self.attempt_reconnect(NOT_CONNECTED.to_string()).await?;
+        }
+        self.inner.ping().await
+    }
+
+    fn control(&self) -> WsControl {
+        self.inner.control()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::kernel::codec::ControlFrame;
+    use tokio::net::TcpListener;
+
+    /// Answers `ping` with `pong` and tags every reply with a connection id
+    struct TextPingCodec;
+
+    impl WsCodec for TextPingCodec {
+        type Message = String;
+
+        fn encode_subscription(
+            &self,
+            streams: &[impl AsRef<str> + Send + Sync],
+        ) -> Result<Message, ExchangeError> {
+            Ok(Message::Text(format!("sub {}", streams.len())))
+        }
+
+        fn encode_unsubscription(
+            &self,
+            streams: &[impl AsRef<str> + Send + Sync],
+        ) -> Result<Message, ExchangeError> {
+            Ok(Message::Text(format!("unsub {}", streams.len())))
+        }
+
+        fn encode_ping(&self) -> Option<Message> {
+            Some(Message::Text("ping".to_string()))
+        }
+
+        fn acknowledges_subscriptions(&self) -> bool {
+            true
+        }
+
+        fn control_frame(&self, message: &Message) -> Option<ControlFrame> {
+            let Message::Text(text) = message else {
+                return None;
+            };
+            let (reply, conn_id) = text.split_once('@')?;
+            Some(ControlFrame {
+                connection_id: Some(conn_id.to_string()),
+                pong: reply == "pong",
+                subscribed: reply == "ack",
+            })
+        }
+
+        fn exchange_time(&self, message: &String) -> Option<i64> {
+            message.strip_prefix("time@")?.parse().ok()
+        }
+
+        fn decode_message(&self, message: Message) -> Result<Option<String>, ExchangeError> {
+            let text = message.into_text().ok();
+            if let Some(stream) = text.as_deref().and_then(|t| t.strip_prefix("gap@")) {
+                return Err(ExchangeError::ChecksumMismatch {
+                    stream: stream.to_string(),
+                    expected: 2,
+                    computed: 3,
+                });
+            }
+            Ok(text)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_control_tracks_conn_id_subscriptions_and_rtt() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let reply = if text == "ping" { "pong" } else { "ack" };
+                ws.send(Message::Text(format!("{}@conn-1", reply)))
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let session = TungsteniteWs::new(url, "test".to_string(), TextPingCodec);
+        let control = session.control();
+        let mut ws = ReconnectWs::new(session);
+        ws.connect().await.unwrap();
+        ws.subscribe(&["a", "b"]).await.unwrap();
+        assert_eq!(ws.next_message().await.unwrap().unwrap(), "ack@conn-1");
+        ws.ping().await.unwrap();
+        assert_eq!(ws.next_message().await.unwrap().unwrap(), "pong@conn-1");
+
+        let info = control.snapshot();
+        assert!(info.connected);
+        assert_eq!(info.connection_id.as_deref(), Some("conn-1"));
+        assert_eq!(info.subscriptions, 2);
+        assert!(info.keepalive_rtt.is_some());
+        assert_eq!(info.reconnects, 0);
+    }
+
+    #[tokio::test]
+    async fn test_unacknowledged_subscribe_retries_then_times_out() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (sent_tx, mut sent_rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let _ = sent_tx.send(text);
+            }
+        });
+
+        let config = WsConfig {
+            subscribe_ack_timeout_ms: 50,
+            subscribe_retries: 1,
+            ..WsConfig::default()
+        };
+        let mut ws = TungsteniteWs::new(url, "test".to_string(), TextPingCodec).with_config(config);
+        ws.connect().await.unwrap();
+        let err = ws.subscribe(&["a"]).await.unwrap_err();
+
+        assert!(
+            matches!(err, ExchangeError::SubscriptionTimeout { ref streams } if streams == &["a"])
+        );
+        assert_eq!(sent_rx.recv().await.unwrap(), "sub 1");
+        assert_eq!(sent_rx.recv().await.unwrap(), "sub 1");
+        assert_eq!(ws.control().snapshot().subscriptions, 0);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_reports_connection_events() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            // First connection acknowledges the subscription, then drops
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            ws.next().await;
+            ws.send(Message::Text("ack@conn-1".to_string()))
+                .await
+                .unwrap();
+            drop(ws);
+
+            // Second one ignores the resubscription but still streams
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            ws.next().await;
+            ws.next().await;
+            ws.send(Message::Text("data@conn-2".to_string()))
+                .await
+                .unwrap();
+            while ws.next().await.is_some() {}
+        });
+
+        let config = WsConfig {
+            subscribe_ack_timeout_ms: 50,
+            subscribe_retries: 1,
+            ..WsConfig::default()
+        };
+        let session =
+            TungsteniteWs::new(url, "test".to_string(), TextPingCodec).with_config(config);
+        let (events_tx, mut events) = mpsc::unbounded_channel();
+        let mut ws = ReconnectWs::new(session)
+            .with_reconnect_delay(Duration::from_millis(10))
+            .with_connection_events(Some(events_tx));
+        ws.connect().await.unwrap();
+        ws.subscribe(&["a"]).await.unwrap();
+        assert_eq!(ws.next_message().await.unwrap().unwrap(), "ack@conn-1");
+        assert_eq!(ws.next_message().await.unwrap().unwrap(), "data@conn-2");
+
+        assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Connected);
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            ConnectionEvent::Disconnected { .. }
+        ));
+        assert_eq!(
+            events.recv().await.unwrap(),
+            ConnectionEvent::Reconnected { attempt: 1 }
+        );
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            ConnectionEvent::ResubscriptionFailed { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replays_every_open_request() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (sent_tx, mut sent_rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            // First connection acknowledges both requests and drops after the unsubscribe
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                if text.starts_with("unsub") {
+                    break;
+                }
+                ws.send(Message::Text("ack@conn-1".to_string()))
+                    .await
+                    .unwrap();
+            }
+            drop(ws);
+
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            for _ in 0..2 {
+                let Some(Ok(Message::Text(text))) = ws.next().await else {
+                    return;
+                };
+                let _ = sent_tx.send(text);
+                ws.send(Message::Text("ack@conn-2".to_string()))
+                    .await
+                    .unwrap();
+            }
+            ws.send(Message::Text("data@conn-2".to_string()))
+                .await
+                .unwrap();
+            while ws.next().await.is_some() {}
+        });
+
+        let session = TungsteniteWs::new(url, "test".to_string(), TextPingCodec);
+        let mut ws = ReconnectWs::new(session).with_reconnect_delay(Duration::from_millis(10));
+        ws.connect().await.unwrap();
+        ws.subscribe(&["a", "b"]).await.unwrap();
+        ws.subscribe(&["c"]).await.unwrap();
+        ws.unsubscribe(&["b"]).await.unwrap();
+        while ws.next_message().await.unwrap().unwrap() != "data@conn-2" {}
+
+        // `a` and `c` went out in separate requests, and `b` stayed closed
+        assert_eq!(sent_rx.recv().await.unwrap(), "sub 1");
+        assert_eq!(sent_rx.recv().await.unwrap(), "sub 1");
+    }
+
+    #[tokio::test]
+    async fn test_checksum_mismatch_resubscribes_the_stream() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (sent_tx, mut sent_rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            ws.next().await;
+            ws.send(Message::Text("ack@conn-1".to_string()))
+                .await
+                .unwrap();
+            ws.send(Message::Text("gap@b".to_string())).await.unwrap();
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                if text.starts_with("sub") {
+                    ws.send(Message::Text("ack@conn-1".to_string()))
+                        .await
+                        .unwrap();
+                }
+                let _ = sent_tx.send(text);
+            }
+        });
+
+        let session = TungsteniteWs::new(url, "test".to_string(), TextPingCodec);
+        let control = session.control();
+        let mut ws = ReconnectWs::new(session);
+        ws.connect().await.unwrap();
+        ws.subscribe(&["a", "b"]).await.unwrap();
+        assert_eq!(ws.next_message().await.unwrap().unwrap(), "ack@conn-1");
+        let err = ws.next_message().await.unwrap().unwrap_err();
+
+        assert!(matches!(err, ExchangeError::ChecksumMismatch { ref stream, .. } if stream == "b"));
+        assert_eq!(sent_rx.recv().await.unwrap(), "unsub 1");
+        assert_eq!(sent_rx.recv().await.unwrap(), "sub 1");
+        assert_eq!(control.snapshot().reconnects, 0);
+        assert_eq!(control.snapshot().subscriptions, 2);
+    }
+
+    #[tokio::test]
+    async fn test_feed_latency_normalizes_venue_time_units() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+            // The same instant in milliseconds, then nanoseconds
+            for time in [now.as_millis(), now.as_nanos()] {
+                ws.send(Message::Text(format!("time@{}", time)))
+                    .await
+                    .unwrap();
+            }
+            ws.send(Message::Text("untimed".to_string())).await.unwrap();
+            while ws.next().await.is_some() {}
+        });
+
+        let mut ws = TungsteniteWs::new(url, "test".to_string(), TextPingCodec);
+        ws.connect().await.unwrap();
+        for _ in 0..3 {
+            ws.next_message().await.unwrap().unwrap();
+        }
+
+        let info = ws.control().snapshot();
+        assert!(info.last_received_at.is_some());
+        let latency = info.feed_latency;
+        assert_eq!(latency, ws.stats().feed_latency);
+        assert_eq!(latency.samples, 2);
+        assert!((0..5_000_000).contains(&latency.min_us));
+        assert!(latency.max_us < 5_000_000);
+        assert!(latency.mean_us().is_some());
+    }
 }
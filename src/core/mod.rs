@@ -1,5 +1,9 @@
+pub mod aggregator;
+pub mod candles;
 pub mod config;
 pub mod errors;
 pub mod kernel;
+pub mod streaming;
+pub mod symbol;
 pub mod traits;
 pub mod types;
@@ -0,0 +1,870 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{ReconnectWs, TungsteniteWs, WsCodec, WsSession};
+pub use crate::core::types::BackpressurePolicy;
+use crate::core::types::{
+    ConnectionEvent, KlineInterval, MarketDataType, SubscriptionType, Symbol, WebSocketConfig,
+};
+use futures_util::stream::{self, StreamExt};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::{mpsc, oneshot, OnceCell};
+use tracing::{debug, info, warn};
+
+/// Updates buffered per stream before the slowest subscriber starts lagging
+const STREAM_BUFFER: usize = 1024;
+/// Capacity of each subscriber's receiver
+const SUBSCRIBER_BUFFER: usize = 1000;
+
+/// Exchange-specific side of a `StreamHub`
+pub trait StreamRouter: Send + 'static {
+    type Codec: WsCodec;
+
+    /// Venue stream id carrying `subscription` for `symbol`, `None` when the venue
+    /// has no such stream and the subscription is skipped
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError>;
+
+    /// Split streams into the (un)subscribe requests the venue takes; one by default
+    fn request_groups(&self, streams: Vec<String>) -> Vec<Vec<String>> {
+        vec![streams]
+    }
+
+    /// Updates carried by a decoded message; heartbeats and acks yield none
+    fn convert(&self, message: <Self::Codec as WsCodec>::Message) -> Vec<MarketDataType>;
+
+    /// Symbol text updates are matched on; venues keyed by coin alone override this
+    fn route_symbol(&self, symbol: &Symbol) -> String {
+        symbol.to_string()
+    }
+}
+
+/// Kind of update a stream carries, for matching updates to subscriptions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RouteKind {
+    Ticker,
+    OrderBook,
+    Trade,
+    /// `None` for bars whose interval the hub cannot read; those go to every kline stream
+    Kline(Option<KlineInterval>),
+    MarkPrice,
+}
+
+impl RouteKind {
+    const fn of_subscription(subscription: &SubscriptionType) -> Self {
+        match subscription {
+            SubscriptionType::Ticker => Self::Ticker,
+            SubscriptionType::OrderBook { .. } => Self::OrderBook,
+            SubscriptionType::Trades | SubscriptionType::AggTrades => Self::Trade,
+            SubscriptionType::Klines { interval } => Self::Kline(Some(*interval)),
+            SubscriptionType::MarkPrice => Self::MarkPrice,
+        }
+    }
+
+    fn of_update(update: &MarketDataType) -> Self {
+        match update {
+            MarketDataType::Ticker(_) => Self::Ticker,
+            MarketDataType::OrderBook(_) => Self::OrderBook,
+            MarketDataType::Trade(_) => Self::Trade,
+            MarketDataType::Kline(kline) => {
+                Self::Kline(KlineInterval::from_venue_str(&kline.interval))
+            }
+            MarketDataType::MarkPrice(_) => Self::MarkPrice,
+        }
+    }
+}
+
+type RouteKey = (String, RouteKind);
+
+/// A subscriber's share of one stream
+type RouteSubscription = (RouteKey, String, broadcast::Receiver<MarketDataType>);
+
+type EventSender = mpsc::UnboundedSender<ConnectionEvent>;
+
+/// One venue stream and the channel its updates are fanned out on
+struct Route {
+    stream: String,
+    tx: broadcast::Sender<MarketDataType>,
+    subscribers: usize,
+}
+
+enum HubCommand {
+    Subscribe {
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        events: Option<EventSender>,
+        reply: oneshot::Sender<Result<(u64, Vec<RouteSubscription>), ExchangeError>>,
+    },
+    Release {
+        subscriber: u64,
+        streams: Vec<(RouteKey, String)>,
+    },
+    ActiveStreams {
+        reply: oneshot::Sender<Vec<String>>,
+    },
+}
+
+/// One WebSocket shared by every subscriber of an exchange
+///
+/// The hub subscribes each venue stream once, however many callers ask for it, and
+/// unsubscribes it when the last receiver interested in it is dropped. Updates are
+/// fanned out on a `broadcast` channel per stream, so a slow subscriber never holds up
+/// the socket or the others; what happens when it falls behind is its own
+/// `BackpressurePolicy`. Connection events are fanned out the same way, to every
+/// subscriber that asked for them. Clones of the handle talk to the same session.
+///
+/// Streams that decode to the same symbol and kind of update share deliveries, so
+/// on a venue where `Trades` and `AggTrades` are separate streams a subscriber to
+/// either sees both while both are open.
+#[derive(Clone, Debug)]
+pub struct StreamHub {
+    commands: mpsc::Sender<HubCommand>,
+}
+
+impl StreamHub {
+    /// Connect `ws` and run the hub in a background task
+    ///
+    /// The hub stops, ending every subscriber's stream, when the session ends or once
+    /// every handle and receiver is dropped.
+    pub async fn start<S, W>(ws: W, router: S) -> Result<Self, ExchangeError>
+    where
+        S: StreamRouter,
+        W: WsSession<S::Codec> + 'static,
+    {
+        Self::launch(ws, router, None).await
+    }
+
+    /// `start` for a session reporting its connection state on `events`, which the
+    /// hub passes on to every subscriber that asks for connection events
+    pub async fn start_with_connection_events<S, W>(
+        ws: W,
+        router: S,
+        events: mpsc::UnboundedReceiver<ConnectionEvent>,
+    ) -> Result<Self, ExchangeError>
+    where
+        S: StreamRouter,
+        W: WsSession<S::Codec> + 'static,
+    {
+        Self::launch(ws, router, Some(events)).await
+    }
+
+    async fn launch<S, W>(
+        mut ws: W,
+        router: S,
+        mut events: Option<mpsc::UnboundedReceiver<ConnectionEvent>>,
+    ) -> Result<Self, ExchangeError>
+    where
+        S: StreamRouter,
+        W: WsSession<S::Codec> + 'static,
+    {
+        ws.connect().await?;
+        // Nobody has subscribed yet; later subscribers are told they joined a live session
+        if let Some(events) = &mut events {
+            while events.try_recv().is_ok() {}
+        }
+        let (commands, command_rx) = mpsc::channel(100);
+        let hub = HubTask {
+            ws,
+            router,
+            routes: HashMap::new(),
+            command_rx,
+            events,
+            listeners: HashMap::new(),
+            next_subscriber: 0,
+            connected: true,
+            _codec: PhantomData,
+        };
+        tokio::spawn(hub.run());
+        Ok(Self { commands })
+    }
+
+    /// Receive `subscription_types` for `symbols`, subscribing the venue streams that
+    /// are not open yet
+    pub async fn subscribe(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        policy: BackpressurePolicy,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.open(symbols, subscription_types, policy, false, None)
+            .await
+    }
+
+    /// `subscribe` for `StreamingMarketData::subscribe_market_data`
+    ///
+    /// Takes the backpressure policy and connection events sender from `config`, and
+    /// skips open bars when it asks for closed ones only.
+    pub async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<&WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        let config = config.cloned().unwrap_or_default();
+        self.open(
+            symbols,
+            subscription_types,
+            config.backpressure,
+            config.closed_bars_only,
+            config.connection_events,
+        )
+        .await
+    }
+
+    async fn open(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        policy: BackpressurePolicy,
+        closed_bars_only: bool,
+        events: Option<EventSender>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        let (reply, response) = oneshot::channel();
+        self.send(HubCommand::Subscribe {
+            symbols,
+            subscription_types,
+            events,
+            reply,
+        })
+        .await?;
+        let (subscriber, routes) = response.await.map_err(|_| Self::stopped())??;
+
+        let (tx, rx) = mpsc::channel(SUBSCRIBER_BUFFER);
+        let streams: Vec<(RouteKey, String)> = routes
+            .iter()
+            .map(|(key, stream, _)| (key.clone(), stream.clone()))
+            .collect();
+        let mut updates = stream::select_all(routes.into_iter().map(|(_, _, route_rx)| {
+            Box::pin(stream::unfold(route_rx, |mut route_rx| async move {
+                match route_rx.recv().await {
+                    Err(RecvError::Closed) => None,
+                    update => Some((update, route_rx)),
+                }
+            }))
+        }));
+
+        let commands = self.commands.clone();
+        tokio::spawn(async move {
+            loop {
+                let update = tokio::select! {
+                    () = tx.closed() => break,
+                    update = updates.next() => update,
+                };
+                match update {
+                    Some(Ok(update)) if closed_bars_only && update.is_open_bar() => {}
+                    Some(Ok(update)) => {
+                        if tx.send(update).await.is_err() {
+                            break; // Receiver dropped
+                        }
+                    }
+                    Some(Err(RecvError::Lagged(missed))) => {
+                        warn!(missed, ?policy, "Stream subscriber lagging");
+                        if policy == BackpressurePolicy::Disconnect {
+                            break;
+                        }
+                    }
+                    // Every stream closed: the hub stopped
+                    Some(Err(RecvError::Closed)) | None => break,
+                }
+            }
+            let _ = commands
+                .send(HubCommand::Release {
+                    subscriber,
+                    streams,
+                })
+                .await;
+        });
+
+        Ok(rx)
+    }
+
+    /// Venue streams currently subscribed
+    pub async fn active_streams(&self) -> Result<Vec<String>, ExchangeError> {
+        let (reply, response) = oneshot::channel();
+        self.send(HubCommand::ActiveStreams { reply }).await?;
+        response.await.map_err(|_| Self::stopped())
+    }
+
+    async fn send(&self, command: HubCommand) -> Result<(), ExchangeError> {
+        self.commands
+            .send(command)
+            .await
+            .map_err(|_| Self::stopped())
+    }
+
+    fn stopped() -> ExchangeError {
+        ExchangeError::WebSocketError("Stream hub stopped".to_string())
+    }
+}
+
+/// A connector's `StreamHub`, started on its first subscription
+///
+/// The hub runs over a `ReconnectWs` that retries forever and replays every open
+/// subscription, so a dropped socket does not end the streams of every subscriber.
+#[derive(Debug, Default)]
+pub struct SharedHub {
+    hub: OnceCell<StreamHub>,
+}
+
+impl SharedHub {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The hub, if a subscription has started it
+    pub fn get(&self) -> Option<&StreamHub> {
+        self.hub.get()
+    }
+
+    /// The running hub, started over the session `session` builds if there is none yet
+    pub async fn get_or_start<S: StreamRouter>(
+        &self,
+        session: impl FnOnce() -> TungsteniteWs<S::Codec> + Send,
+        router: S,
+    ) -> Result<&StreamHub, ExchangeError> {
+        self.hub
+            .get_or_try_init(|| async {
+                let (events_tx, events) = mpsc::unbounded_channel();
+                let reconnect_ws = ReconnectWs::new(session())
+                    .with_auto_resubscribe(true)
+                    .with_max_reconnect_attempts(u32::MAX)
+                    .with_connection_events(Some(events_tx));
+                StreamHub::start_with_connection_events(reconnect_ws, router, events).await
+            })
+            .await
+    }
+}
+
+/// Background task owning the session
+struct HubTask<S: StreamRouter, W> {
+    ws: W,
+    router: S,
+    routes: HashMap<RouteKey, Vec<Route>>,
+    command_rx: mpsc::Receiver<HubCommand>,
+    /// Connection state reported by the session, if it reports any
+    events: Option<mpsc::UnboundedReceiver<ConnectionEvent>>,
+    /// Connection events senders of live subscribers, by subscriber id
+    listeners: HashMap<u64, EventSender>,
+    next_subscriber: u64,
+    connected: bool,
+    _codec: PhantomData<S::Codec>,
+}
+
+impl<S: StreamRouter, W: WsSession<S::Codec>> HubTask<S, W> {
+    async fn run(mut self) {
+        info!("Stream hub started");
+        loop {
+            tokio::select! {
+                command = self.command_rx.recv() => match command {
+                    Some(HubCommand::Subscribe { symbols, subscription_types, events, reply }) => {
+                        let result = self
+                            .subscribe(&symbols, &subscription_types)
+                            .await
+                            .map(|routes| (self.add_listener(events), routes));
+                        let _ = reply.send(result);
+                    }
+                    Some(HubCommand::Release { subscriber, streams }) => {
+                        self.listeners.remove(&subscriber);
+                        self.release(streams).await;
+                    }
+                    Some(HubCommand::ActiveStreams { reply }) => {
+                        let _ = reply.send(self.active_streams());
+                    }
+                    None => break, // Every handle dropped
+                },
+                message = self.ws.next_message() => match message {
+                    Some(Ok(message)) => {
+                        for update in self.router.convert(message) {
+                            self.dispatch(update);
+                        }
+                    }
+                    Some(Err(e)) => warn!(error = %e, "Stream hub WebSocket error"),
+                    None => {
+                        warn!("Stream hub WebSocket ended");
+                        break;
+                    }
+                },
+                event = Self::next_event(&mut self.events) => match event {
+                    Some(event) => self.broadcast_event(&event),
+                    None => self.events = None,
+                },
+            }
+        }
+        let _ = self.ws.close().await;
+    }
+
+    async fn next_event(
+        events: &mut Option<mpsc::UnboundedReceiver<ConnectionEvent>>,
+    ) -> Option<ConnectionEvent> {
+        match events {
+            Some(events) => events.recv().await,
+            None => std::future::pending().await,
+        }
+    }
+
+    /// Register a subscriber, greeting its sender if the session is up
+    fn add_listener(&mut self, events: Option<EventSender>) -> u64 {
+        let subscriber = self.next_subscriber;
+        self.next_subscriber += 1;
+        if let Some(events) = events {
+            if self.connected {
+                let _ = events.send(ConnectionEvent::Connected);
+            }
+            self.listeners.insert(subscriber, events);
+        }
+        subscriber
+    }
+
+    fn broadcast_event(&mut self, event: &ConnectionEvent) {
+        match event {
+            ConnectionEvent::Disconnected { .. } => self.connected = false,
+            ConnectionEvent::Connected | ConnectionEvent::Reconnected { .. } => {
+                self.connected = true;
+            }
+            ConnectionEvent::ResubscriptionFailed { .. } => {}
+        }
+        // A consumer may have stopped listening while its stream carries on
+        for events in self.listeners.values() {
+            let _ = events.send(event.clone());
+        }
+    }
+
+    async fn subscribe(
+        &mut self,
+        symbols: &[Symbol],
+        subscription_types: &[SubscriptionType],
+    ) -> Result<Vec<RouteSubscription>, ExchangeError> {
+        let mut wanted: Vec<(RouteKey, String)> = Vec::new();
+        for symbol in symbols {
+            for subscription in subscription_types {
+                let key = (
+                    self.router.route_symbol(symbol),
+                    RouteKind::of_subscription(subscription),
+                );
+                let Some(stream) = self.router.stream_id(symbol, subscription)? else {
+                    continue;
+                };
+                if !wanted.iter().any(|(k, s)| *k == key && *s == stream) {
+                    wanted.push((key, stream));
+                }
+            }
+        }
+
+        // Kinds of update sharing one venue stream, e.g. tickers carrying mark prices,
+        // subscribe it once
+        let mut new_streams: Vec<String> = Vec::new();
+        for (_, stream) in &wanted {
+            if !self.is_open(stream) && !new_streams.contains(stream) {
+                new_streams.push(stream.clone());
+            }
+        }
+        for request in self.router.request_groups(new_streams) {
+            self.ws.subscribe(&request).await?;
+            debug!(streams = ?request, "Stream hub subscribed");
+        }
+
+        Ok(wanted
+            .into_iter()
+            .map(|(key, stream)| {
+                let routes = self.routes.entry(key.clone()).or_default();
+                let index = routes
+                    .iter()
+                    .position(|r| r.stream == stream)
+                    .unwrap_or_else(|| {
+                        routes.push(Route {
+                            stream: stream.clone(),
+                            tx: broadcast::channel(STREAM_BUFFER).0,
+                            subscribers: 0,
+                        });
+                        routes.len() - 1
+                    });
+                routes[index].subscribers += 1;
+                let rx = routes[index].tx.subscribe();
+                (key, stream, rx)
+            })
+            .collect())
+    }
+
+    async fn release(&mut self, streams: Vec<(RouteKey, String)>) {
+        let mut closed = Vec::new();
+        for (key, stream) in streams {
+            let Some(routes) = self.routes.get_mut(&key) else {
+                continue;
+            };
+            if let Some(index) = routes.iter().position(|r| r.stream == stream) {
+                routes[index].subscribers -= 1;
+                if routes[index].subscribers == 0 {
+                    let stream = routes.remove(index).stream;
+                    if !closed.contains(&stream) {
+                        closed.push(stream);
+                    }
+                }
+            }
+            if routes.is_empty() {
+                self.routes.remove(&key);
+            }
+        }
+        closed.retain(|stream| !self.is_open(stream));
+        if closed.is_empty() {
+            return;
+        }
+        for request in self.router.request_groups(closed) {
+            match self.ws.unsubscribe(&request).await {
+                Ok(()) => debug!(streams = ?request, "Stream hub unsubscribed"),
+                Err(e) => warn!(error = %e, "Stream hub failed to unsubscribe"),
+            }
+        }
+    }
+
+    /// Whether any route still carries `stream`
+    fn is_open(&self, stream: &str) -> bool {
+        self.routes.values().flatten().any(|r| r.stream == stream)
+    }
+
+    fn dispatch(&self, update: MarketDataType) {
+        let key = (
+            self.router.route_symbol(update.symbol()),
+            RouteKind::of_update(&update),
+        );
+        if key.1 == RouteKind::Kline(None) {
+            for ((symbol, kind), routes) in &self.routes {
+                if *symbol == key.0 && matches!(kind, RouteKind::Kline(_)) {
+                    for route in routes {
+                        let _ = route.tx.send(update.clone());
+                    }
+                }
+            }
+        } else if let Some(routes) = self.routes.get(&key) {
+            for route in routes {
+                // Fails only when no subscriber is left, which `release` is about to clean up
+                let _ = route.tx.send(update.clone());
+            }
+        }
+    }
+
+    fn active_streams(&self) -> Vec<String> {
+        let mut streams: Vec<String> = self
+            .routes
+            .values()
+            .flatten()
+            .map(|route| route.stream.clone())
+            .collect();
+        streams.sort();
+        streams.dedup();
+        streams
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{conversion, MarkPrice};
+    use crate::test_support;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tokio_tungstenite::tungstenite::Message;
+
+    struct TestCodec;
+
+    impl WsCodec for TestCodec {
+        type Message = MarketDataType;
+
+        fn encode_subscription(
+            &self,
+            _streams: &[impl AsRef<str> + Send + Sync],
+        ) -> Result<Message, ExchangeError> {
+            Ok(Message::Text(String::new()))
+        }
+
+        fn encode_unsubscription(
+            &self,
+            _streams: &[impl AsRef<str> + Send + Sync],
+        ) -> Result<Message, ExchangeError> {
+            Ok(Message::Text(String::new()))
+        }
+
+        fn decode_message(
+            &self,
+            _message: Message,
+        ) -> Result<Option<MarketDataType>, ExchangeError> {
+            Ok(None)
+        }
+    }
+
+    /// Session fed from a channel that logs (un)subscribe calls
+    struct TestWs {
+        updates: mpsc::UnboundedReceiver<MarketDataType>,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl WsSession<TestCodec> for TestWs {
+        async fn connect(&mut self) -> Result<(), ExchangeError> {
+            Ok(())
+        }
+
+        async fn send_raw(&mut self, _msg: Message) -> Result<(), ExchangeError> {
+            Ok(())
+        }
+
+        async fn next_raw(&mut self) -> Option<Result<Message, ExchangeError>> {
+            None
+        }
+
+        async fn close(&mut self) -> Result<(), ExchangeError> {
+            Ok(())
+        }
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        async fn subscribe(
+            &mut self,
+            streams: &[impl AsRef<str> + Send + Sync],
+        ) -> Result<(), ExchangeError> {
+            self.log
+                .lock()
+                .unwrap()
+                .extend(streams.iter().map(|s| format!("+{}", s.as_ref())));
+            Ok(())
+        }
+
+        async fn unsubscribe(
+            &mut self,
+            streams: &[impl AsRef<str> + Send + Sync],
+        ) -> Result<(), ExchangeError> {
+            self.log
+                .lock()
+                .unwrap()
+                .extend(streams.iter().map(|s| format!("-{}", s.as_ref())));
+            Ok(())
+        }
+
+        async fn next_message(&mut self) -> Option<Result<MarketDataType, ExchangeError>> {
+            self.updates.recv().await.map(Ok)
+        }
+
+        async fn send_bulk(&mut self, _messages: &[Message]) -> Result<(), ExchangeError> {
+            Ok(())
+        }
+
+        async fn configure_low_latency(&mut self) -> Result<(), ExchangeError> {
+            Ok(())
+        }
+    }
+
+    struct TestRouter;
+
+    impl StreamRouter for TestRouter {
+        type Codec = TestCodec;
+
+        fn stream_id(
+            &self,
+            symbol: &Symbol,
+            subscription: &SubscriptionType,
+        ) -> Result<Option<String>, ExchangeError> {
+            // Mark prices ride on the ticker stream, as on Bybit
+            Ok(Some(match subscription {
+                SubscriptionType::MarkPrice => format!("{}@Ticker", symbol),
+                subscription => format!("{}@{:?}", symbol, subscription),
+            }))
+        }
+
+        fn convert(&self, message: MarketDataType) -> Vec<MarketDataType> {
+            vec![message]
+        }
+    }
+
+    fn ticker(symbol: &str) -> MarketDataType {
+        let symbol = conversion::string_to_symbol(symbol);
+        MarketDataType::Ticker(test_support::ticker(&symbol, "1"))
+    }
+
+    fn mark_price(symbol: &str) -> MarketDataType {
+        MarketDataType::MarkPrice(MarkPrice {
+            symbol: conversion::string_to_symbol(symbol),
+            mark_price: conversion::string_to_price("1"),
+            index_price: None,
+            funding_rate: None,
+            next_funding_time: None,
+            timestamp: 0,
+        })
+    }
+
+    async fn hub() -> (
+        StreamHub,
+        mpsc::UnboundedSender<MarketDataType>,
+        Arc<Mutex<Vec<String>>>,
+    ) {
+        let (updates_tx, updates) = mpsc::unbounded_channel();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let ws = TestWs {
+            updates,
+            log: log.clone(),
+        };
+        (
+            StreamHub::start(ws, TestRouter).await.unwrap(),
+            updates_tx,
+            log,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_shares_streams_and_unsubscribes_after_last_receiver() {
+        let (hub, updates, log) = hub().await;
+        let btc = conversion::string_to_symbol("BTCUSDT");
+        let eth = conversion::string_to_symbol("ETHUSDT");
+
+        let mut first = hub
+            .subscribe(
+                vec![btc.clone()],
+                vec![SubscriptionType::Ticker],
+                BackpressurePolicy::DropOldest,
+            )
+            .await
+            .unwrap();
+        let mut second = hub
+            .subscribe(
+                vec![btc, eth],
+                vec![SubscriptionType::Ticker],
+                BackpressurePolicy::DropOldest,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["+BTCUSDT@Ticker", "+ETHUSDT@Ticker"]
+        );
+
+        updates.send(ticker("ETHUSDT")).unwrap();
+        updates.send(ticker("BTCUSDT")).unwrap();
+        let received = first.recv().await.unwrap();
+        assert_eq!(received.symbol().to_string(), "BTCUSDT");
+        assert_eq!(second.recv().await.unwrap().symbol().to_string(), "ETHUSDT");
+        assert_eq!(second.recv().await.unwrap().symbol().to_string(), "BTCUSDT");
+
+        drop(second);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(hub.active_streams().await.unwrap(), vec!["BTCUSDT@Ticker"]);
+        drop(first);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(hub.active_streams().await.unwrap().is_empty());
+        assert_eq!(
+            log.lock().unwrap()[2..],
+            ["-ETHUSDT@Ticker".to_string(), "-BTCUSDT@Ticker".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kinds_sharing_a_stream_subscribe_it_once() {
+        let (hub, updates, log) = hub().await;
+        let mut rx = hub
+            .subscribe(
+                vec![conversion::string_to_symbol("BTCUSDT")],
+                vec![SubscriptionType::Ticker, SubscriptionType::MarkPrice],
+                BackpressurePolicy::DropOldest,
+            )
+            .await
+            .unwrap();
+        assert_eq!(hub.active_streams().await.unwrap(), vec!["BTCUSDT@Ticker"]);
+
+        updates.send(ticker("BTCUSDT")).unwrap();
+        updates.send(mark_price("BTCUSDT")).unwrap();
+        assert!(matches!(rx.recv().await, Some(MarketDataType::Ticker(_))));
+        assert!(matches!(
+            rx.recv().await,
+            Some(MarketDataType::MarkPrice(_))
+        ));
+
+        drop(rx);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["+BTCUSDT@Ticker", "-BTCUSDT@Ticker"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connection_events_reach_every_subscriber() {
+        let (_updates_tx, updates) = mpsc::unbounded_channel();
+        let ws = TestWs {
+            updates,
+            log: Arc::new(Mutex::new(Vec::new())),
+        };
+        let (session_events, events) = mpsc::unbounded_channel();
+        let hub = StreamHub::start_with_connection_events(ws, TestRouter, events)
+            .await
+            .unwrap();
+
+        let mut listeners = Vec::new();
+        let mut streams = Vec::new();
+        for symbol in ["BTCUSDT", "ETHUSDT"] {
+            let (events_tx, events_rx) = mpsc::unbounded_channel();
+            let config = WebSocketConfig::default().with_connection_events(events_tx);
+            streams.push(
+                hub.subscribe_market_data(
+                    vec![conversion::string_to_symbol(symbol)],
+                    vec![SubscriptionType::Ticker],
+                    Some(&config),
+                )
+                .await
+                .unwrap(),
+            );
+            listeners.push(events_rx);
+        }
+        for listener in &mut listeners {
+            assert_eq!(listener.recv().await.unwrap(), ConnectionEvent::Connected);
+        }
+
+        let dropped = ConnectionEvent::Disconnected {
+            reason: "reset".to_string(),
+        };
+        session_events.send(dropped.clone()).unwrap();
+        for listener in &mut listeners {
+            assert_eq!(listener.recv().await.unwrap(), dropped);
+        }
+
+        // A released subscriber hears nothing more
+        drop(streams.remove(0));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        session_events
+            .send(ConnectionEvent::Reconnected { attempt: 1 })
+            .unwrap();
+        assert_eq!(
+            listeners[1].recv().await.unwrap(),
+            ConnectionEvent::Reconnected { attempt: 1 }
+        );
+        assert!(listeners[0].recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_policy_ends_a_lagging_stream() {
+        let (hub, updates, _) = hub().await;
+        let mut rx = hub
+            .subscribe(
+                vec![conversion::string_to_symbol("BTCUSDT")],
+                vec![SubscriptionType::Ticker],
+                BackpressurePolicy::Disconnect,
+            )
+            .await
+            .unwrap();
+
+        for _ in 0..(STREAM_BUFFER + SUBSCRIBER_BUFFER) * 2 {
+            updates.send(ticker("BTCUSDT")).unwrap();
+        }
+        let mut received = 0;
+        while tokio::time::timeout(Duration::from_secs(1), rx.recv())
+            .await
+            .unwrap()
+            .is_some()
+        {
+            received += 1;
+        }
+        assert!(received < (STREAM_BUFFER + SUBSCRIBER_BUFFER) * 2);
+    }
+}
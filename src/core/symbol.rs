@@ -0,0 +1,448 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::RestMarketData;
+use crate::core::types::{ContractStyle, Market, TypesError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// Suffixes venues append to perpetual symbols, e.g. `BTC-USD-PERP` or `BTC-USDT-SWAP`
+const PERPETUAL_SUFFIXES: [&str; 3] = ["-PERP", "_PERP", "-SWAP"];
+
+/// Kind of instrument a symbol names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum InstrumentType {
+    #[default]
+    Spot,
+    Perpetual,
+}
+
+/// Canonical, venue-independent symbol
+///
+/// `instrument_type` tells a spot pair from a perpetual of the same assets but takes no
+/// part in equality or hashing, so `"BTCUSDT".into()` matches what a perp venue reports.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Symbol {
+    pub base: String,
+    pub quote: String,
+    #[serde(default)]
+    pub instrument_type: InstrumentType,
+}
+
+impl Symbol {
+    /// Create a new spot symbol from base and quote assets
+    pub fn new(base: impl Into<String>, quote: impl Into<String>) -> Result<Self, TypesError> {
+        let base = base.into();
+        let quote = quote.into();
+
+        if base.is_empty() || quote.is_empty() {
+            return Err(TypesError::InvalidSymbol(
+                "Base and quote cannot be empty".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            base,
+            quote,
+            instrument_type: InstrumentType::Spot,
+        })
+    }
+
+    /// Create symbol from string like "BTCUSDT"
+    pub fn from_string(s: &str) -> Result<Self, TypesError> {
+        // Simple pattern matching for common quote currencies
+        if let Some(base) = s.strip_suffix("USDT") {
+            return Self::new(base, "USDT");
+        }
+        if let Some(base) = s.strip_suffix("USDC") {
+            return Self::new(base, "USDC");
+        }
+        if let Some(base) = s.strip_suffix("BTC") {
+            return Self::new(base, "BTC");
+        }
+        if let Some(base) = s.strip_suffix("ETH") {
+            return Self::new(base, "ETH");
+        }
+        if let Some(base) = s.strip_suffix("USD") {
+            return Self::new(base, "USD");
+        }
+
+        Err(TypesError::InvalidSymbol(format!(
+            "Cannot parse symbol: {}",
+            s
+        )))
+    }
+
+    /// A bare coin with no quote, e.g. `BTC`
+    fn bare(base: &str) -> Self {
+        Self {
+            base: base.to_string(),
+            quote: String::new(),
+            instrument_type: InstrumentType::Spot,
+        }
+    }
+
+    /// The same assets as another kind of instrument
+    #[must_use]
+    pub fn with_instrument_type(mut self, instrument_type: InstrumentType) -> Self {
+        self.instrument_type = instrument_type;
+        self
+    }
+
+    pub fn is_perpetual(&self) -> bool {
+        self.instrument_type == InstrumentType::Perpetual
+    }
+
+    /// Get as string reference
+    pub fn as_str(&self) -> String {
+        format!("{}{}", self.base, self.quote)
+    }
+
+    /// Base and quote joined by `separator`, e.g. `BTC-USDT` for OKX
+    ///
+    /// Symbols with no quote (bare coins, unrecognised pairs) are returned unchanged.
+    pub fn joined(&self, separator: &str) -> String {
+        if self.quote.is_empty() {
+            self.base.clone()
+        } else {
+            format!("{}{}{}", self.base, separator, self.quote)
+        }
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base && self.quote == other.quote
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.base.hash(state);
+        self.quote.hash(state);
+    }
+}
+
+/// Lenient conversion for venue spellings, used by the connector traits
+///
+/// A perpetual suffix (`-PERP`, `_PERP`, `-SWAP`) is dropped and marks the symbol
+/// perpetual. `BTC-USDT`, `BTC_USDT` and `BTC/USDT` then split at the separator,
+/// concatenated pairs such as `BTCUSDT` split at a known quote asset, and anything else
+/// becomes a bare base asset. Every connector renders the result back in its own
+/// format, so a pair written for one venue works on another. Use `FromStr` to reject
+/// unrecognised input instead.
+impl From<&str> for Symbol {
+    fn from(s: &str) -> Self {
+        if let Some(pair) = PERPETUAL_SUFFIXES
+            .iter()
+            .find_map(|suffix| s.strip_suffix(suffix))
+        {
+            return Self::from(pair).with_instrument_type(InstrumentType::Perpetual);
+        }
+        if let Some((base, quote)) = s.split_once(['-', '_', '/']) {
+            if let Ok(symbol) = Self::new(base, quote) {
+                return symbol;
+            }
+        }
+        Self::from_string(s).unwrap_or_else(|_| Self::bare(s))
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<&String> for Symbol {
+    fn from(s: &String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.base, self.quote)
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = TypesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_string(s)
+    }
+}
+
+/// How a venue spells symbols
+///
+/// See `ExchangeId::symbol_convention` for every venue's entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolConvention {
+    /// Between base and quote; empty for concatenated pairs such as `BTCUSDT`
+    pub separator: &'static str,
+    /// Appended to perpetual symbols, e.g. `-PERP`; empty when they carry none
+    pub perpetual_suffix: &'static str,
+    /// Quote of every market on venues that name markets by coin alone, e.g. `BTC`
+    pub implied_quote: Option<&'static str>,
+    /// Instrument type of symbols without a perpetual suffix
+    pub instrument_type: InstrumentType,
+}
+
+impl SymbolConvention {
+    /// Render `symbol` the way the venue spells it
+    ///
+    /// Perpetual venues always get the suffix; venues that list both kinds add it for
+    /// perpetual symbols only.
+    pub fn to_exchange_symbol(&self, symbol: &Symbol) -> String {
+        if self.implied_quote.is_some() {
+            return symbol.base.clone();
+        }
+        let mut rendered = symbol.joined(self.separator);
+        let perpetual = symbol.is_perpetual() || self.instrument_type == InstrumentType::Perpetual;
+        if perpetual && !symbol.quote.is_empty() && !rendered.ends_with(self.perpetual_suffix) {
+            rendered.push_str(self.perpetual_suffix);
+        }
+        rendered
+    }
+
+    /// Check that the venue can spell `symbol`
+    ///
+    /// `From<&str>` never fails, so a mistyped pair such as `BTCUSTD` arrives as a bare
+    /// asset. Connectors call this before sending to reject it, and assets with
+    /// characters no venue uses, as `InvalidParameters`.
+    pub fn validate(&self, symbol: &Symbol) -> Result<(), ExchangeError> {
+        let invalid = |reason: &str| {
+            Err(ExchangeError::InvalidParameters(format!(
+                "Invalid symbol {}: {}",
+                symbol.joined("/"),
+                reason
+            )))
+        };
+        if symbol.base.is_empty() {
+            return invalid("missing base asset");
+        }
+        if symbol.quote.is_empty() && self.implied_quote.is_none() {
+            return invalid("no recognised quote asset");
+        }
+        let asset = |asset: &str| asset.chars().all(|c| c.is_ascii_alphanumeric());
+        if !asset(&symbol.base) || !asset(&symbol.quote) {
+            return invalid("assets must be alphanumeric");
+        }
+        Ok(())
+    }
+
+    /// Render `symbol` after checking it with `validate`
+    pub fn try_to_exchange_symbol(&self, symbol: &Symbol) -> Result<String, ExchangeError> {
+        self.validate(symbol)?;
+        Ok(self.to_exchange_symbol(symbol))
+    }
+
+    /// Parse a symbol the venue sent
+    ///
+    /// Unrecognised spellings come back as a bare base asset, which renders back
+    /// unchanged.
+    pub fn from_exchange_symbol(&self, exchange_symbol: &str) -> Symbol {
+        let (pair, instrument_type) = match exchange_symbol.strip_suffix(self.perpetual_suffix) {
+            Some(pair) if !self.perpetual_suffix.is_empty() => (pair, InstrumentType::Perpetual),
+            _ => (exchange_symbol, self.instrument_type),
+        };
+        let symbol = match self.implied_quote {
+            Some(quote) => Symbol::new(pair, quote).ok(),
+            None if self.separator.is_empty() => Symbol::from_string(pair).ok(),
+            None => pair
+                .split_once(self.separator)
+                .and_then(|(base, quote)| Symbol::new(base, quote).ok()),
+        };
+        symbol
+            .unwrap_or_else(|| Symbol::bare(pair))
+            .with_instrument_type(instrument_type)
+    }
+}
+
+/// Symbol translation table of one venue, built from its market list
+///
+/// Exact for every listed market, including pairs the convention alone cannot split
+/// (`BTCFDUSD`); anything else falls back to the convention.
+#[derive(Debug, Clone)]
+pub struct SymbolTable {
+    convention: SymbolConvention,
+    to_exchange: HashMap<Symbol, String>,
+    from_exchange: HashMap<String, Symbol>,
+}
+
+impl SymbolTable {
+    /// An empty table that translates by `convention` alone
+    pub fn new(convention: SymbolConvention) -> Self {
+        Self {
+            convention,
+            to_exchange: HashMap::new(),
+            from_exchange: HashMap::new(),
+        }
+    }
+
+    /// A table covering `markets`
+    pub fn from_markets(convention: SymbolConvention, markets: &[Market]) -> Self {
+        let mut table = Self::new(convention);
+        for market in markets {
+            let symbol = match market.contract_style {
+                ContractStyle::Spot => market.symbol.clone(),
+                ContractStyle::Linear | ContractStyle::Inverse => market
+                    .symbol
+                    .clone()
+                    .with_instrument_type(InstrumentType::Perpetual),
+            };
+            let exchange_symbol = convention.to_exchange_symbol(&symbol);
+            table.insert(exchange_symbol, symbol);
+        }
+        table
+    }
+
+    /// Fetch the venue's markets and build a table from them
+    pub async fn load<S: RestMarketData + ?Sized + Sync>(
+        convention: SymbolConvention,
+        source: &S,
+    ) -> Result<Self, ExchangeError> {
+        let markets = source.get_markets().await?;
+        Ok(Self::from_markets(convention, &markets))
+    }
+
+    /// Map `exchange_symbol` to `symbol` in both directions
+    pub fn insert(&mut self, exchange_symbol: String, symbol: Symbol) {
+        self.to_exchange
+            .insert(symbol.clone(), exchange_symbol.clone());
+        self.from_exchange.insert(exchange_symbol, symbol);
+    }
+
+    pub fn convention(&self) -> SymbolConvention {
+        self.convention
+    }
+
+    /// Number of listed markets
+    pub fn len(&self) -> usize {
+        self.from_exchange.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.from_exchange.is_empty()
+    }
+
+    /// The venue's spelling of `symbol`
+    pub fn to_exchange_symbol(&self, symbol: &Symbol) -> String {
+        self.to_exchange
+            .get(symbol)
+            .cloned()
+            .unwrap_or_else(|| self.convention.to_exchange_symbol(symbol))
+    }
+
+    /// The venue's spelling of a listed `symbol`
+    ///
+    /// An empty table checks the convention alone.
+    pub fn try_to_exchange_symbol(&self, symbol: &Symbol) -> Result<String, ExchangeError> {
+        if let Some(exchange_symbol) = self.to_exchange.get(symbol) {
+            return Ok(exchange_symbol.clone());
+        }
+        if !self.is_empty() {
+            return Err(ExchangeError::InvalidParameters(format!(
+                "Symbol {} is not listed",
+                symbol.joined("/")
+            )));
+        }
+        self.convention.try_to_exchange_symbol(symbol)
+    }
+
+    /// The canonical symbol of a venue spelling
+    pub fn from_exchange_symbol(&self, exchange_symbol: &str) -> Symbol {
+        self.from_exchange
+            .get(exchange_symbol)
+            .cloned()
+            .unwrap_or_else(|| self.convention.from_exchange_symbol(exchange_symbol))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARADEX: SymbolConvention = SymbolConvention {
+        separator: "-",
+        perpetual_suffix: "-PERP",
+        implied_quote: None,
+        instrument_type: InstrumentType::Perpetual,
+    };
+
+    #[test]
+    fn test_convention_round_trips() {
+        let symbol = PARADEX.from_exchange_symbol("BTC-USD-PERP");
+        assert_eq!(
+            (symbol.base.as_str(), symbol.quote.as_str()),
+            ("BTC", "USD")
+        );
+        assert!(symbol.is_perpetual());
+        assert_eq!(PARADEX.to_exchange_symbol(&symbol), "BTC-USD-PERP");
+        // Spellings from other venues render the same way
+        assert_eq!(PARADEX.to_exchange_symbol(&"BTCUSD".into()), "BTC-USD-PERP");
+        assert_eq!(
+            PARADEX.to_exchange_symbol(&"BTC-USD-PERP".into()),
+            "BTC-USD-PERP"
+        );
+    }
+
+    #[test]
+    fn test_table_prefers_listed_markets() {
+        let concatenated = SymbolConvention {
+            separator: "",
+            perpetual_suffix: "",
+            implied_quote: None,
+            instrument_type: InstrumentType::Spot,
+        };
+        let mut table = SymbolTable::new(concatenated);
+        // `FDUSD` is not a quote the convention knows
+        assert_eq!(table.from_exchange_symbol("BTCFDUSD").quote, "USD");
+
+        table.insert("BTCFDUSD".to_string(), Symbol::new("BTC", "FDUSD").unwrap());
+        assert_eq!(table.from_exchange_symbol("BTCFDUSD").quote, "FDUSD");
+        assert_eq!(
+            table.to_exchange_symbol(&Symbol::new("BTC", "FDUSD").unwrap()),
+            "BTCFDUSD"
+        );
+        assert_eq!(table.to_exchange_symbol(&"ETHUSDT".into()), "ETHUSDT");
+    }
+
+    #[test]
+    fn test_validation_rejects_what_the_venue_cannot_spell() {
+        assert_eq!(
+            PARADEX.try_to_exchange_symbol(&"BTC-USD".into()).unwrap(),
+            "BTC-USD-PERP"
+        );
+        for typo in ["BTCUSTD", "BTC USD", "-USD"] {
+            assert!(matches!(
+                PARADEX.try_to_exchange_symbol(&typo.into()),
+                Err(ExchangeError::InvalidParameters(_))
+            ));
+        }
+
+        // Venues naming markets by coin need no quote
+        let by_coin = SymbolConvention {
+            implied_quote: Some("USD"),
+            ..PARADEX
+        };
+        assert!(by_coin.validate(&"BTC".into()).is_ok());
+    }
+
+    #[test]
+    fn test_loaded_table_rejects_unlisted_symbols() {
+        let mut table = SymbolTable::new(PARADEX);
+        assert!(table.try_to_exchange_symbol(&"ETH-USD".into()).is_ok());
+
+        table.insert("BTC-USD-PERP".to_string(), "BTC-USD".into());
+        assert_eq!(
+            table.try_to_exchange_symbol(&"BTCUSD".into()).unwrap(),
+            "BTC-USD-PERP"
+        );
+        assert!(table.try_to_exchange_symbol(&"ETH-USD".into()).is_err());
+    }
+}
@@ -1,22 +1,99 @@
 use crate::core::{
     errors::ExchangeError,
+    kernel::Diagnostics,
     types::{
-        Balance, FundingRate, Kline, KlineInterval, Market, MarketDataType, OrderRequest,
-        OrderResponse, Position, SubscriptionType, WebSocketConfig,
+        AccountEvent, AccountFill, AccountSummary, AccountType, Balance, BorrowRate, FundingRate,
+        InterestRecord, Kline, KlineInterval, LeverageBracket, MarginMode, Market, MarketDataType,
+        MarketStatusUpdate, Order, OrderBook, OrderBookL3Event, OrderPage, OrderRequest,
+        OrderResponse, Position, SubscriptionType, Symbol, Ticker, Trade, UserDataChannel,
+        WebSocketConfig, WithdrawalRecord, WithdrawalRequest,
     },
 };
 use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::time::Duration;
 use tokio::sync::mpsc;
 
+/// Market data available over REST
+///
+/// Symbols are taken as `Symbol` and rendered in each venue's format by the
+/// connector, so `"BTCUSDT".into()` and `"BTC-USDT".into()` reach the same
+/// instrument everywhere. A symbol the venue cannot spell, or one missing from
+/// a loaded market list, is rejected with `InvalidParameters` before any request
+/// is sent.
 #[async_trait]
-pub trait MarketDataSource {
+pub trait RestMarketData {
     /// Get all available markets/trading pairs
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError>;
 
+    /// Get historical k-lines/candlestick data
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError>;
+
+    /// Exchange server time in milliseconds, for measuring local clock skew
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        Err(ExchangeError::NotSupported(
+            "Server time not available on this exchange".to_string(),
+        ))
+    }
+}
+
+/// One-shot market snapshots over REST, for callers that need no WebSocket
+#[async_trait]
+pub trait MarketSnapshotSource {
+    /// Get the rolling 24h ticker of a symbol
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError>;
+
+    /// Get the order book, cut to `depth` levels per side
+    ///
+    /// `None` returns the venue's default depth. Venues that only serve fixed depths are
+    /// asked for the nearest one that covers `depth`.
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError>;
+
+    /// Get up to `limit` of the most recent public trades, oldest first
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError>;
+}
+
+/// Bulk download of the public trade tape, e.g. to build a tick database for backtests
+#[async_trait]
+pub trait HistoricalTradeSource {
+    /// Get every public trade on `symbol` between `start_time` and `end_time` inclusive,
+    /// in milliseconds, oldest first
+    ///
+    /// Pages through the venue's history until the range is covered. Venues that only
+    /// serve their latest trades return the part of the range inside that window.
+    async fn get_historical_trades(
+        &self,
+        symbol: Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<Trade>, ExchangeError>;
+}
+
+/// Market data pushed over WebSocket
+///
+/// REST-only connectors do not implement this trait, so code that needs live data can
+/// say so in its bounds instead of discovering it through a runtime error.
+#[async_trait]
+pub trait StreamingMarketData {
     /// Subscribe to market data via WebSocket
     async fn subscribe_market_data(
         &self,
-        symbols: Vec<String>,
+        symbols: Vec<Symbol>,
         subscription_types: Vec<SubscriptionType>,
         config: Option<WebSocketConfig>,
     ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError>;
@@ -24,24 +101,85 @@ pub trait MarketDataSource {
     /// Get WebSocket endpoint URL for market data
     fn get_websocket_url(&self) -> String;
 
-    /// Get historical k-lines/candlestick data
-    async fn get_klines(
+    /// Whether the venue offers an order-level (L3, market-by-order) feed
+    ///
+    /// Served by Coinbase through the Exchange feed's `full` channel.
+    fn supports_order_book_l3(&self) -> bool {
+        false
+    }
+
+    /// Subscribe to order-level book events for venues that publish them
+    async fn subscribe_order_book_l3(
         &self,
-        symbol: String,
-        interval: KlineInterval,
-        limit: Option<u32>,
-        start_time: Option<i64>,
-        end_time: Option<i64>,
-    ) -> Result<Vec<Kline>, ExchangeError>;
+        _symbols: Vec<Symbol>,
+    ) -> Result<mpsc::Receiver<OrderBookL3Event>, ExchangeError> {
+        Err(ExchangeError::NotSupported(
+            "L3 order book feed not available on this exchange".to_string(),
+        ))
+    }
+
+    /// Whether the venue pushes instrument status changes, e.g. halts and listings
+    ///
+    /// Served by OKX through the `instruments` channel.
+    fn supports_market_status(&self) -> bool {
+        false
+    }
+
+    /// Subscribe to instrument status changes for venues that push them
+    ///
+    /// Feed the updates to `MarketStatusTracker`, or use `watch_market_status`.
+    async fn subscribe_market_status(
+        &self,
+    ) -> Result<mpsc::Receiver<MarketStatusUpdate>, ExchangeError> {
+        Err(ExchangeError::NotSupported(
+            "Market status feed not available on this exchange".to_string(),
+        ))
+    }
 }
 
+/// Full market data access: REST queries plus streaming
+///
+/// Implemented automatically for every type that implements both halves.
+pub trait MarketDataSource: RestMarketData + StreamingMarketData {}
+
+impl<T: RestMarketData + StreamingMarketData + ?Sized> MarketDataSource for T {}
+
+/// Order entry
+///
+/// Symbols are rendered and checked as for [`RestMarketData`].
 #[async_trait]
 pub trait OrderPlacer {
     /// Place a new order
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError>;
 
     /// Cancel an existing order
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError>;
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError>;
+
+    /// Cancel an order by the `client_order_id` it was placed with
+    async fn cancel_order_by_client_id(
+        &self,
+        _symbol: Symbol,
+        _client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        Err(ExchangeError::NotSupported(
+            "Cancelling by client order id is not supported".to_string(),
+        ))
+    }
+
+    /// Place several orders, returning one result per order in request order
+    ///
+    /// Connectors with a native batch endpoint send as few requests as the venue allows;
+    /// the default places the orders concurrently one by one.
+    async fn place_orders(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Vec<Result<OrderResponse, ExchangeError>>
+    where
+        Self: Sync,
+    {
+        futures_util::future::join_all(orders.into_iter().map(|order| self.place_order(order)))
+            .await
+    }
 
     /// Modify an existing order
     async fn modify_order(
@@ -55,6 +193,11 @@ pub trait OrderPlacer {
             "Order modification not supported".to_string(),
         ))
     }
+
+    /// Whether orders can be placed; `false` for connectors built without credentials
+    fn supports_trading(&self) -> bool {
+        true
+    }
 }
 
 #[async_trait]
@@ -63,6 +206,57 @@ pub trait AccountInfo {
 
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError>;
     async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError>;
+
+    /// Whether account data can be queried; `false` for connectors built without credentials
+    fn supports_account(&self) -> bool {
+        true
+    }
+}
+
+/// Trait for account-wide margin totals (DERIVATIVES ACCOUNTS ONLY)
+#[async_trait]
+pub trait AccountSummarySource {
+    /// Get total equity, available and required margin, and unrealized profit and loss
+    async fn get_account_summary(&self) -> Result<AccountSummary, ExchangeError>;
+}
+
+/// Trait for looking up the account's orders (AUTHENTICATED CONNECTORS ONLY)
+#[async_trait]
+pub trait OrderQuery {
+    /// Get open orders, optionally for one symbol
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError>;
+
+    /// Get a single order, open or recently closed
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError>;
+}
+
+/// Trait for the account's closed orders (AUTHENTICATED CONNECTORS ONLY)
+#[async_trait]
+pub trait OrderHistorySource {
+    /// Get filled, cancelled, rejected and expired orders on `symbol`
+    ///
+    /// Pass a page's `next_cursor` back with the same range to fetch the following page.
+    /// Cursors are venue-specific; page order follows the venue.
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError>;
+}
+
+/// Private WebSocket stream of the account's own activity (AUTHENTICATED CONNECTORS ONLY)
+#[async_trait]
+pub trait UserDataStream {
+    /// Subscribe to order, fill, balance and position updates
+    ///
+    /// Events outside `channels` are dropped. The stream re-authenticates after a
+    /// reconnect; updates missed while disconnected are not replayed.
+    async fn subscribe_user_data(
+        &self,
+        channels: Vec<UserDataChannel>,
+    ) -> Result<mpsc::Receiver<AccountEvent>, ExchangeError>;
 }
 
 /// Trait for funding rate operations (PERPETUAL EXCHANGES ONLY)
@@ -71,7 +265,7 @@ pub trait FundingRateSource {
     /// Get current funding rates for one or more symbols
     async fn get_funding_rates(
         &self,
-        symbols: Option<Vec<String>>,
+        symbols: Option<Vec<Symbol>>,
     ) -> Result<Vec<FundingRate>, ExchangeError>;
 
     /// Get all available funding rates from the exchange
@@ -80,11 +274,118 @@ pub trait FundingRateSource {
     /// Get historical funding rates for a symbol
     async fn get_funding_rate_history(
         &self,
-        symbol: String,
+        symbol: Symbol,
         start_time: Option<i64>,
         end_time: Option<i64>,
         limit: Option<u32>,
     ) -> Result<Vec<FundingRate>, ExchangeError>;
+
+    /// Current rates for `symbols`, or every symbol, with derivable fields filled in
+    ///
+    /// See `FundingRate::fill_derived_fields`.
+    async fn poll_funding_rates(
+        &self,
+        symbols: Option<Vec<Symbol>>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let mut rates = match symbols {
+            Some(symbols) => self.get_funding_rates(Some(symbols)).await?,
+            None => self.get_all_funding_rates().await?,
+        };
+        rates.iter_mut().for_each(FundingRate::fill_derived_fields);
+        Ok(rates)
+    }
+}
+
+/// Trait for margin borrowing costs (MARGIN-ENABLED ACCOUNTS ONLY)
+#[async_trait]
+pub trait BorrowRateSource {
+    /// Get the current borrow rates for an asset
+    async fn get_borrow_rates(&self, asset: &str) -> Result<Vec<BorrowRate>, ExchangeError>;
+
+    /// Get interest charged on the account's margin loans, optionally for one asset
+    async fn get_interest_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<InterestRecord>, ExchangeError>;
+}
+
+/// Trait for moving funds off the venue (WITHDRAWAL-ENABLED API KEYS ONLY)
+#[async_trait]
+pub trait WithdrawalSource {
+    /// Request a withdrawal, returning the venue's withdrawal id
+    async fn withdraw(&self, request: WithdrawalRequest) -> Result<String, ExchangeError>;
+
+    /// Get past withdrawals, optionally for one asset
+    async fn get_withdrawal_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<WithdrawalRecord>, ExchangeError>;
+}
+
+/// Trait for moving funds between the account's own wallets (TRANSFER-ENABLED API KEYS ONLY)
+#[async_trait]
+pub trait AssetTransfer {
+    /// Move `amount` of `asset` from one wallet to another, returning the venue's transfer id
+    async fn transfer(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        from: AccountType,
+        to: AccountType,
+    ) -> Result<String, ExchangeError>;
+}
+
+/// Trait for the account's own executions
+#[async_trait]
+pub trait TradeHistorySource {
+    /// Get the account's fills on `symbol`, oldest first
+    async fn get_my_trades(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AccountFill>, ExchangeError>;
+}
+
+/// Trait for leverage and margin-mode settings of perpetual positions
+#[async_trait]
+pub trait PositionManager {
+    /// Set the leverage used for new positions on `symbol`
+    async fn set_leverage(&self, symbol: Symbol, leverage: u32) -> Result<(), ExchangeError>;
+
+    /// Switch `symbol` between cross and isolated margin
+    async fn set_margin_mode(&self, symbol: Symbol, mode: MarginMode) -> Result<(), ExchangeError>;
+
+    /// Get the leverage tiers of `symbol`, smallest positions first
+    async fn get_leverage_brackets(
+        &self,
+        symbol: Symbol,
+    ) -> Result<Vec<LeverageBracket>, ExchangeError>;
+
+    /// Flatten the open position on `symbol` with a reduce-only market order
+    ///
+    /// Returns `None` when there is no position to close.
+    async fn close_position(&self, symbol: Symbol) -> Result<Option<OrderResponse>, ExchangeError>;
+}
+
+/// Trait for cancelling every open order, now or once the client goes quiet
+#[async_trait]
+pub trait KillSwitch {
+    /// Cancel all open orders on `symbol`, or on every symbol for `None`
+    async fn cancel_all_orders(&self, symbol: Option<Symbol>) -> Result<(), ExchangeError>;
+
+    /// Arm the venue's dead-man's switch to cancel all orders after `timeout`
+    ///
+    /// Call it again before the timeout elapses to keep orders alive; `Duration::ZERO`
+    /// disarms it where the venue allows.
+    async fn set_cancel_on_disconnect(&self, timeout: Duration) -> Result<(), ExchangeError>;
 }
 
 // BACKWARD-COMPATIBLE trait composition (NON-BREAKING APPROACH)
@@ -95,6 +396,18 @@ pub trait FundingRateConnector: MarketDataSource + FundingRateSource {}
 #[async_trait]
 pub trait PerpetualExchangeConnector: ExchangeConnector + FundingRateSource {}
 
+/// Trait for inspecting a connector's transports while it runs
+pub trait DiagnosticsSource {
+    /// Recent request latencies, last error per endpoint, rate-limit headroom and
+    /// WebSocket sessions of the connector's exchange
+    fn diagnostics(&self) -> Diagnostics;
+}
+
 // Optional: Keep a composite trait for convenience when you need all functionality
 #[async_trait]
-pub trait ExchangeConnector: MarketDataSource + OrderPlacer + AccountInfo {}
+pub trait ExchangeConnector: MarketDataSource + OrderPlacer + AccountInfo + Send + Sync {}
+
+impl<T: MarketDataSource + OrderPlacer + AccountInfo + Send + Sync + ?Sized> ExchangeConnector
+    for T
+{
+}
@@ -1,9 +1,12 @@
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::fmt;
 use std::str::FromStr;
 use thiserror::Error;
 
+pub use crate::core::symbol::{InstrumentType, Symbol};
+
 /// Simple typed errors for the types subsystem
 #[derive(Error, Debug)]
 pub enum TypesError {
@@ -19,73 +22,6 @@ pub enum TypesError {
     ParseError(String),
 }
 
-/// Type-safe symbol representation - simplified
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
-pub struct Symbol {
-    pub base: String,
-    pub quote: String,
-}
-
-impl Symbol {
-    /// Create a new symbol from base and quote assets
-    pub fn new(base: impl Into<String>, quote: impl Into<String>) -> Result<Self, TypesError> {
-        let base = base.into();
-        let quote = quote.into();
-
-        if base.is_empty() || quote.is_empty() {
-            return Err(TypesError::InvalidSymbol(
-                "Base and quote cannot be empty".to_string(),
-            ));
-        }
-
-        Ok(Self { base, quote })
-    }
-
-    /// Create symbol from string like "BTCUSDT"
-    pub fn from_string(s: &str) -> Result<Self, TypesError> {
-        // Simple pattern matching for common quote currencies
-        if let Some(base) = s.strip_suffix("USDT") {
-            return Self::new(base, "USDT");
-        }
-        if let Some(base) = s.strip_suffix("USDC") {
-            return Self::new(base, "USDC");
-        }
-        if let Some(base) = s.strip_suffix("BTC") {
-            return Self::new(base, "BTC");
-        }
-        if let Some(base) = s.strip_suffix("ETH") {
-            return Self::new(base, "ETH");
-        }
-        if let Some(base) = s.strip_suffix("USD") {
-            return Self::new(base, "USD");
-        }
-
-        Err(TypesError::InvalidSymbol(format!(
-            "Cannot parse symbol: {}",
-            s
-        )))
-    }
-
-    /// Get as string reference
-    pub fn as_str(&self) -> String {
-        format!("{}{}", self.base, self.quote)
-    }
-}
-
-impl fmt::Display for Symbol {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.base, self.quote)
-    }
-}
-
-impl FromStr for Symbol {
-    type Err = TypesError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::from_string(s)
-    }
-}
-
 /// Type-safe price representation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
 #[serde(transparent)]
@@ -201,8 +137,13 @@ impl fmt::Display for Volume {
 }
 
 /// Simple conversion helpers
+///
+/// The `try_*` functions are for venue payloads: malformed numbers become
+/// `ExchangeError::DeserializationError`. The infallible `string_to_*` versions read
+/// bad input as zero and are meant for trusted literals only.
 pub mod conversion {
-    use super::{Decimal, FromStr, Price, Quantity, Symbol, Volume};
+    use super::{Decimal, FromStr, OrderBookEntry, Price, Quantity, Symbol, Volume};
+    use crate::core::errors::ExchangeError;
 
     pub fn string_to_symbol(s: &str) -> Symbol {
         Symbol::from_string(s).unwrap_or_else(|_| Symbol::new(s, "USD").unwrap_or_default())
@@ -223,19 +164,302 @@ pub mod conversion {
     pub fn string_to_decimal(s: &str) -> Decimal {
         s.parse().unwrap_or(Decimal::ZERO)
     }
+
+    pub fn try_string_to_price(s: &str) -> Result<Price, ExchangeError> {
+        parse(s, "price").map(Price::new)
+    }
+
+    pub fn try_string_to_quantity(s: &str) -> Result<Quantity, ExchangeError> {
+        parse(s, "quantity").map(Quantity::new)
+    }
+
+    pub fn try_string_to_volume(s: &str) -> Result<Volume, ExchangeError> {
+        parse(s, "volume").map(Volume::new)
+    }
+
+    pub fn try_string_to_decimal(s: &str) -> Result<Decimal, ExchangeError> {
+        parse(s, "decimal")
+    }
+
+    /// Parse book levels sent as `[price, quantity, ...]` string arrays
+    pub fn try_book_levels<L: AsRef<[String]>>(
+        levels: &[L],
+    ) -> Result<Vec<OrderBookEntry>, ExchangeError> {
+        levels
+            .iter()
+            .map(|level| match level.as_ref() {
+                [price, quantity, ..] => Ok(OrderBookEntry {
+                    price: try_string_to_price(price)?,
+                    quantity: try_string_to_quantity(quantity)?,
+                }),
+                _ => Err(ExchangeError::DeserializationError(
+                    "Book level without price and quantity".to_string(),
+                )),
+            })
+            .collect()
+    }
+
+    /// Epoch timestamp in seconds, milliseconds, microseconds or nanoseconds, as
+    /// microseconds
+    ///
+    /// The unit is told from the magnitude, which is unambiguous for any time after 1973.
+    pub const fn timestamp_to_micros(timestamp: i64) -> i64 {
+        match timestamp.unsigned_abs() {
+            0..=99_999_999_999 => timestamp.saturating_mul(1_000_000),
+            100_000_000_000..=99_999_999_999_999 => timestamp.saturating_mul(1_000),
+            100_000_000_000_000..=99_999_999_999_999_999 => timestamp,
+            _ => timestamp / 1_000,
+        }
+    }
+
+    /// Epoch timestamp in any unit, as the milliseconds every core type carries
+    pub const fn timestamp_to_millis(timestamp: i64) -> i64 {
+        timestamp_to_micros(timestamp) / 1_000
+    }
+
+    /// Parse plain or scientific notation (some venues send `1e-8`)
+    fn parse(s: &str, what: &str) -> Result<Decimal, ExchangeError> {
+        let trimmed = s.trim();
+        Decimal::from_str(trimmed)
+            .or_else(|_| Decimal::from_scientific(trimmed))
+            .map_err(|e| {
+                ExchangeError::DeserializationError(format!("Invalid {} {:?}: {}", what, s, e))
+            })
+    }
+}
+
+/// How prices and quantities are brought to a venue's precision
+///
+/// Venues that truncate reject or silently shave values rounded up, which leaves dust
+/// behind; `Down` never exceeds the requested amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingPolicy {
+    /// Round half away from zero
+    HalfUp,
+    /// Truncate toward zero
+    #[default]
+    Down,
+    /// Round half to even
+    Bankers,
+}
+
+impl RoundingPolicy {
+    const fn strategy(self) -> RoundingStrategy {
+        match self {
+            Self::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            Self::Down => RoundingStrategy::ToZero,
+            Self::Bankers => RoundingStrategy::MidpointNearestEven,
+        }
+    }
+
+    /// Round `value` to `precision` decimal places; negative precision rounds to tens,
+    /// hundreds and so on
+    pub fn round(self, value: Decimal, precision: i32) -> Decimal {
+        if let Ok(decimal_places) = u32::try_from(precision) {
+            return value.round_dp_with_strategy(decimal_places, self.strategy());
+        }
+        let step = Decimal::from(10_i64.pow(precision.unsigned_abs().min(18)));
+        (value / step).round_dp_with_strategy(0, self.strategy()) * step
+    }
+}
+
+impl FromStr for RoundingPolicy {
+    type Err = TypesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "half_up" => Ok(Self::HalfUp),
+            "down" => Ok(Self::Down),
+            "bankers" => Ok(Self::Bankers),
+            _ => Err(TypesError::ParseError(format!(
+                "Unknown rounding policy: {}",
+                s
+            ))),
+        }
+    }
 }
 
 // Core data structures
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Market {
     pub symbol: Symbol,
-    pub status: String,
+    pub status: MarketStatus,
     pub base_precision: i32,
     pub quote_precision: i32,
     pub min_qty: Option<Quantity>,
     pub max_qty: Option<Quantity>,
     pub min_price: Option<Price>,
     pub max_price: Option<Price>,
+    #[serde(default)]
+    pub contract_style: ContractStyle,
+    /// Value of one contract: base units for linear contracts, quote units (e.g. USD)
+    /// for inverse contracts. `None` means one unit.
+    #[serde(default)]
+    pub contract_size: Option<Decimal>,
+    /// Venue-specific data with no unified field, e.g. Hyperliquid `oid`/`cloid` or OKX
+    /// `ordId`/`tag`; shapes differ per venue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Value>,
+}
+
+impl Market {
+    pub const fn is_inverse(&self) -> bool {
+        matches!(self.contract_style, ContractStyle::Inverse)
+    }
+
+    fn contract_multiplier(&self) -> Decimal {
+        self.contract_size.unwrap_or(Decimal::ONE)
+    }
+
+    /// Round `price` to the market's quote precision
+    pub fn round_price(&self, price: Price, policy: RoundingPolicy) -> Price {
+        Price::new(policy.round(price.value(), self.quote_precision))
+    }
+
+    /// Round `quantity` to the market's base precision
+    pub fn round_quantity(&self, quantity: Quantity, policy: RoundingPolicy) -> Quantity {
+        Quantity::new(policy.round(quantity.value(), self.base_precision))
+    }
+
+    /// Round an order's quantity, price and stop price to the market's precision
+    pub fn round_order(&self, mut order: OrderRequest, policy: RoundingPolicy) -> OrderRequest {
+        order.quantity = self.round_quantity(order.quantity, policy);
+        order.price = order.price.map(|price| self.round_price(price, policy));
+        order.stop_price = order
+            .stop_price
+            .map(|price| self.round_price(price, policy));
+        order
+    }
+
+    /// Convert a contract count into base-asset quantity at `price`
+    ///
+    /// Inverse contracts have a fixed quote value, so their base exposure depends on price.
+    pub fn contracts_to_base(&self, contracts: Quantity, price: Price) -> Quantity {
+        let quantity = contracts.value() * self.contract_multiplier();
+        match self.contract_style {
+            ContractStyle::Inverse if price.value().is_zero() => Quantity::ZERO,
+            ContractStyle::Inverse => Quantity::new(quantity / price.value()),
+            ContractStyle::Spot | ContractStyle::Linear => Quantity::new(quantity),
+        }
+    }
+
+    /// Convert a base-asset quantity into a (fractional) contract count at `price`
+    pub fn base_to_contracts(&self, base: Quantity, price: Price) -> Quantity {
+        let multiplier = self.contract_multiplier();
+        if multiplier.is_zero() {
+            return Quantity::ZERO;
+        }
+        match self.contract_style {
+            ContractStyle::Inverse => Quantity::new(base.value() * price.value() / multiplier),
+            ContractStyle::Spot | ContractStyle::Linear => Quantity::new(base.value() / multiplier),
+        }
+    }
+
+    /// Notional value of a contract count in the quote currency
+    pub fn contract_notional(&self, contracts: Quantity, price: Price) -> Decimal {
+        let quantity = contracts.value() * self.contract_multiplier();
+        match self.contract_style {
+            ContractStyle::Inverse => quantity,
+            ContractStyle::Spot | ContractStyle::Linear => quantity * price.value(),
+        }
+    }
+}
+
+/// How an instrument is quoted and settled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum ContractStyle {
+    /// Spot pair, no contract multiplier
+    #[default]
+    Spot,
+    /// Quoted and settled in the quote asset (USDT-margined perpetuals)
+    Linear,
+    /// Quoted in USD but settled in the base asset (coin-margined contracts)
+    Inverse,
+}
+
+/// Typed instrument trading status
+///
+/// Venues use different vocabularies ("TRADING", "live", "Trading", "HALT", "suspend", ...);
+/// `from_venue_str` maps them onto a common set so strategies can react uniformly.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MarketStatus {
+    /// Open for normal trading
+    Trading,
+    /// Listed but not yet open (pre-launch, pre-open auction)
+    PreTrading,
+    /// Session has ended for the day
+    PostTrading,
+    /// Trading temporarily halted or suspended
+    Halted,
+    /// Only a subset of order types accepted (cancel-only, post-only, reduce-only)
+    Restricted,
+    /// Closed, delisted, or expired
+    Closed,
+    /// Status string the mapping does not recognize
+    Unknown(String),
+}
+
+impl MarketStatus {
+    /// Map a venue-specific status string onto a `MarketStatus`
+    pub fn from_venue_str(status: &str) -> Self {
+        let normalized: String = status
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        match normalized.as_str() {
+            "trading" | "live" | "open" | "online" | "active" | "tradable" => Self::Trading,
+            "pretrading" | "pendingtrading" | "prelaunch" | "preopen" | "auction" => {
+                Self::PreTrading
+            }
+            "posttrading" | "endofday" => Self::PostTrading,
+            "halt" | "halted" | "break" | "suspend" | "suspended" | "settling" | "delivering"
+            | "paused" => Self::Halted,
+            "cancelonly" | "limitonly" | "postonly" | "reduceonly" => Self::Restricted,
+            "close" | "closed" | "delisted" | "delivered" | "expired" => Self::Closed,
+            _ => Self::Unknown(status.to_string()),
+        }
+    }
+
+    /// Whether new orders can be placed normally
+    pub fn is_trading(&self) -> bool {
+        matches!(self, Self::Trading)
+    }
+
+    /// Whether trading is currently interrupted (halted or restricted)
+    pub fn is_halted(&self) -> bool {
+        matches!(self, Self::Halted | Self::Restricted)
+    }
+}
+
+impl Default for MarketStatus {
+    fn default() -> Self {
+        Self::Unknown(String::new())
+    }
+}
+
+impl fmt::Display for MarketStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Trading => write!(f, "TRADING"),
+            Self::PreTrading => write!(f, "PRE_TRADING"),
+            Self::PostTrading => write!(f, "POST_TRADING"),
+            Self::Halted => write!(f, "HALTED"),
+            Self::Restricted => write!(f, "RESTRICTED"),
+            Self::Closed => write!(f, "CLOSED"),
+            Self::Unknown(status) => write!(f, "{}", status),
+        }
+    }
+}
+
+/// Status change pushed by a venue for a single instrument
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketStatusUpdate {
+    pub symbol: Symbol,
+    pub status: MarketStatus,
+    pub timestamp: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -280,6 +504,23 @@ pub struct OrderRequest {
     pub price: Option<Price>,
     pub time_in_force: Option<TimeInForce>,
     pub stop_price: Option<Price>,
+    /// Only shrink an open position, never open or flip one (perp venues)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    /// Close the whole position when the order fills, whatever its quantity
+    ///
+    /// Binance sends `closePosition`, which only stop and take-profit market orders
+    /// take; Bybit pairs `reduceOnly` with `closeOnTrigger`. Venues without such a
+    /// flag send the order reduce-only for its quantity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_position: Option<bool>,
+    /// Caller-chosen id, sent as the venue's client order id and echoed in
+    /// `OrderResponse::client_order_id`; a retry with the same id cannot double-place
+    ///
+    /// Venues constrain the format: Binance, Bybit and OKX take short alphanumeric
+    /// strings, Hyperliquid a 128-bit hex `0x...`, Backpack and dYdX a `u32`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -293,6 +534,71 @@ pub struct OrderResponse {
     pub price: Option<Price>,
     pub status: String,
     pub timestamp: i64,
+    /// Venue-specific data with no unified field, e.g. Hyperliquid `oid`/`cloid` or OKX
+    /// `ordId`/`tag`; shapes differ per venue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Value>,
+}
+
+/// Lifecycle state of an order, normalized across venues
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    Rejected,
+    Expired,
+    Unknown,
+}
+
+impl OrderStatus {
+    /// Map a venue status string such as Binance `PARTIALLY_FILLED`, Bybit
+    /// `PartiallyFilled`, OKX `partially_filled` or Hyperliquid `open`
+    pub fn from_venue(status: &str) -> Self {
+        let normalized = status.replace('_', "").to_ascii_lowercase();
+        match normalized.as_str() {
+            "new" | "live" | "open" | "untriggered" | "triggered" => Self::New,
+            "partiallyfilled" => Self::PartiallyFilled,
+            "filled" => Self::Filled,
+            "rejected" => Self::Rejected,
+            "expired" | "expiredinmatch" => Self::Expired,
+            "deactivated" => Self::Canceled,
+            s if s.ends_with("canceled") || s.ends_with("cancelled") => Self::Canceled,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Whether the order can still trade
+    pub const fn is_open(self) -> bool {
+        matches!(self, Self::New | Self::PartiallyFilled)
+    }
+}
+
+/// One of the account's orders as reported by `OrderQuery`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Order {
+    pub order_id: String,
+    pub client_order_id: String,
+    pub symbol: Symbol,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub status: OrderStatus,
+    /// Limit price, `None` for market orders
+    pub price: Option<Price>,
+    pub quantity: Quantity,
+    pub filled_quantity: Quantity,
+    pub average_price: Option<Price>,
+    pub created_time: i64,
+    pub updated_time: i64,
+}
+
+/// One page of `OrderHistorySource::get_order_history`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderPage {
+    pub orders: Vec<Order>,
+    /// Opaque cursor for the next page, `None` once the range is exhausted
+    pub next_cursor: Option<String>,
 }
 
 // WebSocket Market Data Types
@@ -311,7 +617,7 @@ pub struct Ticker {
     pub count: i64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OrderBookEntry {
     pub price: Price,
     pub quantity: Quantity,
@@ -325,6 +631,87 @@ pub struct OrderBook {
     pub last_update_id: i64,
 }
 
+impl OrderBook {
+    /// Keep at most `depth` levels per side; `None` keeps them all
+    #[must_use]
+    pub fn truncated(mut self, depth: Option<u32>) -> Self {
+        if let Some(depth) = depth.and_then(|depth| usize::try_from(depth).ok()) {
+            self.bids.truncate(depth);
+            self.asks.truncate(depth);
+        }
+        self
+    }
+
+    /// CRC32 of the top `levels` per side, as OKX checksums its books
+    ///
+    /// Levels are interleaved best first as `bid_px:bid_sz:ask_px:ask_sz`, a side that
+    /// runs out contributing nothing. Prices and sizes print with the scale they were
+    /// parsed with, so books built from the venue's strings reproduce its checksum.
+    pub fn crc32(&self, levels: usize) -> u32 {
+        let mut fields = Vec::with_capacity(levels * 4);
+        for i in 0..levels {
+            for side in [&self.bids, &self.asks] {
+                if let Some(level) = side.get(i) {
+                    fields.push(level.price.to_string());
+                    fields.push(level.quantity.to_string());
+                }
+            }
+        }
+        crc32fast::hash(fields.join(":").as_bytes())
+    }
+}
+
+/// Single resting order in an order-level (L3) book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct L3Order {
+    pub order_id: String,
+    pub side: OrderSide,
+    pub price: Price,
+    pub quantity: Quantity,
+    pub timestamp: i64,
+}
+
+/// Order-level (L3, market-by-order) book snapshot
+///
+/// Orders at each price level are listed in queue (time priority) order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBookL3 {
+    pub symbol: Symbol,
+    pub bids: Vec<L3Order>,
+    pub asks: Vec<L3Order>,
+    pub sequence: i64,
+}
+
+/// Change to a single order in an L3 book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum L3Update {
+    /// New order joins the back of its price level
+    Open(L3Order),
+    /// Remaining size changed without losing queue priority
+    Change {
+        order_id: String,
+        quantity: Quantity,
+    },
+    /// Resting order traded against; `quantity` is the filled amount
+    Match {
+        order_id: String,
+        quantity: Quantity,
+    },
+    /// Order left the book (filled or cancelled)
+    Done { order_id: String },
+    /// Sequenced message that leaves the book as is, e.g. an order the matching engine
+    /// received but has not rested yet
+    Unchanged { order_id: String },
+}
+
+/// Sequenced L3 update for one symbol
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBookL3Event {
+    pub symbol: Symbol,
+    pub sequence: i64,
+    pub update: L3Update,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Trade {
     pub symbol: Symbol,
@@ -350,6 +737,20 @@ pub struct Kline {
     pub final_bar: bool,
 }
 
+/// Mark price and funding pushed by a perpetual venue
+///
+/// Fields a venue leaves out of its stream stay `None`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkPrice {
+    pub symbol: Symbol,
+    pub mark_price: Price,
+    pub index_price: Option<Price>,
+    /// Rate of the funding period in progress, as a fraction per period
+    pub funding_rate: Option<Decimal>,
+    pub next_funding_time: Option<i64>,
+    pub timestamp: i64,
+}
+
 /// Kline interval enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum KlineInterval {
@@ -410,6 +811,70 @@ impl KlineInterval {
             Self::Months1 => "M".to_string(),
         }
     }
+
+    /// Bar length in milliseconds; months are counted as 30 days
+    pub const fn duration_millis(&self) -> i64 {
+        const MINUTE: i64 = 60_000;
+        match self {
+            Self::Minutes1 => MINUTE,
+            Self::Minutes3 => 3 * MINUTE,
+            Self::Minutes5 => 5 * MINUTE,
+            Self::Minutes15 => 15 * MINUTE,
+            Self::Minutes30 => 30 * MINUTE,
+            Self::Hours1 => 60 * MINUTE,
+            Self::Hours2 => 120 * MINUTE,
+            Self::Hours4 => 240 * MINUTE,
+            Self::Hours6 => 360 * MINUTE,
+            Self::Hours8 => 480 * MINUTE,
+            Self::Hours12 => 720 * MINUTE,
+            Self::Days1 => 1_440 * MINUTE,
+            Self::Days3 => 3 * 1_440 * MINUTE,
+            Self::Weeks1 => 7 * 1_440 * MINUTE,
+            Self::Months1 => 30 * 1_440 * MINUTE,
+        }
+    }
+
+    /// Last millisecond of the bar opening at `open_time`
+    ///
+    /// Converters use this for `Kline::close_time` so it is inclusive on every venue.
+    /// Monthly bars end with the calendar month rather than after 30 days.
+    pub fn close_time(&self, open_time: i64) -> i64 {
+        if matches!(self, Self::Months1) {
+            if let Some(next) = chrono::DateTime::from_timestamp_millis(open_time)
+                .and_then(|open| open.checked_add_months(chrono::Months::new(1)))
+            {
+                return next.timestamp_millis() - 1;
+            }
+        }
+        open_time.saturating_add(self.duration_millis() - 1)
+    }
+
+    /// Map a venue interval string onto a `KlineInterval`
+    ///
+    /// Understands Binance style (`1m`, `4h`, `1M`), Bybit minute counts (`240`, `D`),
+    /// OKX bars and channels (`4H`, `candle1Dutc`) and Backpack's `1month`.
+    pub fn from_venue_str(interval: &str) -> Option<Self> {
+        let interval = interval.strip_prefix("candle").unwrap_or(interval);
+        let interval = interval.strip_suffix("utc").unwrap_or(interval);
+        Some(match interval {
+            "1m" | "1" => Self::Minutes1,
+            "3m" | "3" => Self::Minutes3,
+            "5m" | "5" => Self::Minutes5,
+            "15m" | "15" => Self::Minutes15,
+            "30m" | "30" => Self::Minutes30,
+            "1h" | "1H" | "60" => Self::Hours1,
+            "2h" | "2H" | "120" => Self::Hours2,
+            "4h" | "4H" | "240" => Self::Hours4,
+            "6h" | "6H" | "360" => Self::Hours6,
+            "8h" | "8H" | "480" => Self::Hours8,
+            "12h" | "12H" | "720" => Self::Hours12,
+            "1d" | "1D" | "D" => Self::Days1,
+            "3d" | "3D" => Self::Days3,
+            "1w" | "1W" | "W" => Self::Weeks1,
+            "1M" | "M" | "1month" => Self::Months1,
+            _ => return None,
+        })
+    }
 }
 
 impl fmt::Display for KlineInterval {
@@ -435,20 +900,143 @@ impl fmt::Display for KlineInterval {
     }
 }
 
+/// Streamed market data; times are Unix milliseconds, whatever unit the venue sent
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MarketDataType {
     Ticker(Ticker),
     OrderBook(OrderBook),
     Trade(Trade),
     Kline(Kline),
+    MarkPrice(MarkPrice),
+}
+
+impl MarketDataType {
+    /// Kline update for a bar that is still forming
+    pub const fn is_open_bar(&self) -> bool {
+        matches!(self, Self::Kline(kline) if !kline.final_bar)
+    }
+
+    pub const fn symbol(&self) -> &Symbol {
+        match self {
+            Self::Ticker(ticker) => &ticker.symbol,
+            Self::OrderBook(book) => &book.symbol,
+            Self::Trade(trade) => &trade.symbol,
+            Self::Kline(kline) => &kline.symbol,
+            Self::MarkPrice(mark) => &mark.symbol,
+        }
+    }
+
+    /// Time the venue stamped the update with, `None` for books and klines
+    ///
+    /// A kline's times bound its bar rather than date the update.
+    pub const fn exchange_time(&self) -> Option<i64> {
+        match self {
+            Self::Ticker(ticker) => Some(ticker.close_time),
+            Self::Trade(trade) => Some(trade.time),
+            Self::MarkPrice(mark) => Some(mark.timestamp),
+            Self::OrderBook(_) | Self::Kline(_) => None,
+        }
+    }
+
+    /// Bring every time to milliseconds, for converters handed seconds, microseconds
+    /// or nanoseconds
+    pub fn normalize_timestamps(&mut self) {
+        let times = match self {
+            Self::Ticker(ticker) => vec![&mut ticker.open_time, &mut ticker.close_time],
+            Self::Trade(trade) => vec![&mut trade.time],
+            Self::Kline(kline) => vec![&mut kline.open_time, &mut kline.close_time],
+            Self::MarkPrice(mark) => {
+                let mut times = vec![&mut mark.timestamp];
+                times.extend(mark.next_funding_time.as_mut());
+                times
+            }
+            Self::OrderBook(_) => Vec::new(),
+        };
+        for time in times {
+            *time = conversion::timestamp_to_millis(*time);
+        }
+    }
+}
+
+/// How order book updates are delivered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OrderBookMode {
+    /// Every update is the whole book, up to the requested depth per side
+    #[default]
+    Snapshot,
+    /// Only the levels that changed, usually after an initial snapshot; a zero
+    /// quantity removes the level
+    Delta,
+    /// Whole-book snapshots the venue conflates over a longer window, for consumers
+    /// that want a current view with fewer messages
+    Aggregated,
 }
 
 #[derive(Debug, Clone)]
 pub enum SubscriptionType {
     Ticker,
-    OrderBook { depth: Option<u32> },
+    /// Order book updates
+    ///
+    /// `Some(depth)` asks for at least that many levels, rounded up to the nearest
+    /// level the venue offers; `None` asks for the deepest book the mode offers.
+    /// Current mapping:
+    ///
+    /// | Venue | `Snapshot` | `Delta` | `Aggregated` |
+    /// |---|---|---|---|
+    /// | Binance spot | `depth5`/`10`/`20@100ms` | `depth@100ms` | `depth5`/`10`/`20` every 1000ms |
+    /// | Binance perp | `depth5`/`10`/`20@100ms` | `depth@100ms` | `depth5`/`10`/`20@500ms` |
+    /// | Binance SBE | `depth20` | `depth` | `depth20` |
+    /// | OKX | `books5` up to 5 levels, else `books` | `books` | as `Snapshot` |
+    /// | Gate.io spot | `spot.order_book` at 5/10/20/50/100 levels @100ms | `spot.order_book_update` @100ms | `spot.order_book` @1000ms |
+    /// | Gate.io perp | `futures.order_book` at 1/5/10/20/50/100 levels | `futures.order_book_update` at 20/50/100 levels | as `Snapshot` |
+    /// | Paradex | requested depth | venue default | as `Snapshot` |
+    ///
+    /// Bybit (`orderbook.1`/`.50`/`.200`) and Kraken (`book` at 10/25/100/500/1000
+    /// levels) push a snapshot followed by deltas whatever the mode. Backpack, Coinbase
+    /// and Hyperliquid publish a single book channel and ignore both depth and mode.
+    OrderBook {
+        depth: Option<u32>,
+        mode: OrderBookMode,
+    },
     Trades,
-    Klines { interval: KlineInterval },
+    /// Trades merged per taker order and price, delivered as `MarketDataType::Trade`
+    ///
+    /// Far fewer messages than `Trades` on busy symbols. Binance spot/perp use
+    /// `aggTrade`, whose trade ids are aggregate ids; OKX `trades` is already
+    /// aggregated. Venues without an aggregated feed fall back to raw trades.
+    AggTrades,
+    Klines {
+        interval: KlineInterval,
+    },
+    /// Mark price with the current funding rate, delivered as `MarketDataType::MarkPrice`
+    ///
+    /// Served by Binance perp `markPrice@1s`, Bybit perp `tickers`, Backpack `markPrice`
+    /// and Paradex `markets_summary`. Other venues skip it, except Hyperliquid, which
+    /// rejects it as not supported.
+    MarkPrice,
+}
+
+impl SubscriptionType {
+    /// Smallest of the venue's ascending `levels` that covers `requested`, or the
+    /// deepest one if none does
+    pub fn depth_level(requested: u32, levels: &[u32]) -> u32 {
+        levels
+            .iter()
+            .copied()
+            .find(|level| *level >= requested)
+            .or_else(|| levels.last().copied())
+            .unwrap_or(requested)
+    }
+}
+
+/// What happens to a subscriber that falls more than a stream buffer behind
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BackpressurePolicy {
+    /// Skip the updates it missed and carry on from the oldest one still buffered
+    #[default]
+    DropOldest,
+    /// End its stream, e.g. for order books that cannot be trusted after a gap
+    Disconnect,
 }
 
 #[derive(Debug, Clone)]
@@ -456,6 +1044,76 @@ pub struct WebSocketConfig {
     pub auto_reconnect: bool,
     pub ping_interval: Option<u64>,
     pub max_reconnect_attempts: Option<u32>,
+    /// Only deliver klines for closed bars, dropping intrabar updates
+    ///
+    /// Relies on the venue's own closed-bar flag (`final_bar`); venues that do not
+    /// publish one report every update as final, so nothing is dropped there.
+    pub closed_bars_only: bool,
+    /// Receives the subscription's connection events alongside its market data
+    ///
+    /// Where subscriptions share one session, every subscription with a sender hears
+    /// about it, starting with `Connected` if it joins a live session.
+    pub connection_events: Option<tokio::sync::mpsc::UnboundedSender<ConnectionEvent>>,
+    /// What a shared session does when this subscription falls behind
+    pub backpressure: BackpressurePolicy,
+}
+
+impl Default for WebSocketConfig {
+    fn default() -> Self {
+        Self {
+            auto_reconnect: true,
+            ping_interval: None,
+            max_reconnect_attempts: None,
+            closed_bars_only: false,
+            connection_events: None,
+            backpressure: BackpressurePolicy::default(),
+        }
+    }
+}
+
+impl WebSocketConfig {
+    #[must_use]
+    pub const fn with_closed_bars_only(mut self, closed_bars_only: bool) -> Self {
+        self.closed_bars_only = closed_bars_only;
+        self
+    }
+
+    /// Report connection events of the subscription on `events`
+    #[must_use]
+    pub fn with_connection_events(
+        mut self,
+        events: tokio::sync::mpsc::UnboundedSender<ConnectionEvent>,
+    ) -> Self {
+        self.connection_events = Some(events);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_backpressure(mut self, policy: BackpressurePolicy) -> Self {
+        self.backpressure = policy;
+        self
+    }
+}
+
+/// Change in the state of a streaming connection
+///
+/// Updates published while the connection was down are lost, so consumers keeping
+/// state from the stream should resynchronize after `Reconnected`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConnectionEvent {
+    Connected,
+    /// Data may be missing from here until the next `Reconnected`
+    Disconnected {
+        reason: String,
+    },
+    /// `attempt` counts the tries since the disconnect, starting at one
+    Reconnected {
+        attempt: u32,
+    },
+    /// Reconnected, but the streams could not be subscribed again and stay silent
+    ResubscriptionFailed {
+        reason: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -473,6 +1131,14 @@ pub enum PositionSide {
     Both,
 }
 
+/// How a derivatives position is margined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum MarginMode {
+    Cross,
+    Isolated,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
     pub symbol: Symbol,
@@ -482,6 +1148,154 @@ pub struct Position {
     pub unrealized_pnl: Decimal,
     pub liquidation_price: Option<Price>,
     pub leverage: Decimal,
+    /// Cross or isolated, where the venue reports it
+    #[serde(default)]
+    pub margin_mode: Option<MarginMode>,
+    /// Margin backing the position, in the settlement asset
+    #[serde(default)]
+    pub margin: Option<Decimal>,
+    /// Venue-specific data with no unified field, e.g. Hyperliquid `oid`/`cloid` or OKX
+    /// `ordId`/`tag`; shapes differ per venue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Value>,
+}
+
+impl Position {
+    /// Reduce-only market order that flattens the position, `None` when it is flat
+    ///
+    /// `Both` positions are signed, negative for short.
+    pub fn close_order(&self) -> Option<OrderRequest> {
+        let amount = self.position_amount.value();
+        if amount.is_zero() {
+            return None;
+        }
+        let long = match self.position_side {
+            PositionSide::Long => true,
+            PositionSide::Short => false,
+            PositionSide::Both => amount > Decimal::ZERO,
+        };
+        let side = if long {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        };
+        Some(OrderRequest {
+            symbol: self.symbol.clone(),
+            side,
+            order_type: OrderType::Market,
+            quantity: Quantity::new(amount.abs()),
+            price: None,
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: Some(true),
+            close_position: None,
+            client_order_id: None,
+        })
+    }
+}
+
+/// Equity and margin totals of a derivatives account
+///
+/// Amounts are in `currency`, the venue's valuation asset for the whole account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountSummary {
+    /// e.g. `USDT` on Binance USDⓈ-M, `USD` on unified or USD-settled accounts
+    pub currency: String,
+    /// Wallet balance plus unrealized profit and loss
+    pub total_equity: Decimal,
+    /// Margin free to open new positions
+    pub available_margin: Decimal,
+    /// Margin committed to open positions and orders
+    pub initial_margin: Decimal,
+    /// Margin the positions must keep to avoid liquidation
+    pub maintenance_margin: Decimal,
+    pub unrealized_pnl: Decimal,
+    pub timestamp: i64,
+}
+
+impl AccountSummary {
+    /// Maintenance margin over equity, liquidation starting at one; `None` without equity
+    pub fn margin_ratio(&self) -> Option<Decimal> {
+        (self.total_equity > Decimal::ZERO).then(|| self.maintenance_margin / self.total_equity)
+    }
+}
+
+/// Tier of a perpetual's leverage schedule
+///
+/// Bounds are in the venue's position unit: notional on most venues, contracts on OKX.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeverageBracket {
+    pub max_leverage: Decimal,
+    /// Position size the tier starts at
+    pub floor: Decimal,
+    /// Position size the tier ends at, `None` where the venue leaves the last tier open
+    pub cap: Option<Decimal>,
+    pub maintenance_margin_rate: Decimal,
+}
+
+/// Private account stream requested from `subscribe_user_data`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UserDataChannel {
+    Orders,
+    Fills,
+    Balances,
+    Positions,
+}
+
+impl UserDataChannel {
+    pub const ALL: [Self; 4] = [Self::Orders, Self::Fills, Self::Balances, Self::Positions];
+}
+
+/// State of one of the account's orders after a change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderUpdate {
+    pub symbol: Symbol,
+    pub order_id: String,
+    pub client_order_id: String,
+    pub side: OrderSide,
+    /// Venue status string, as in `OrderResponse::status`
+    pub status: String,
+    pub price: Option<Price>,
+    pub quantity: Quantity,
+    pub filled_quantity: Quantity,
+    pub timestamp: i64,
+}
+
+/// Execution of one of the account's orders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountFill {
+    pub symbol: Symbol,
+    pub order_id: String,
+    pub trade_id: String,
+    pub side: OrderSide,
+    pub price: Price,
+    pub quantity: Quantity,
+    pub fee: Decimal,
+    pub fee_asset: Option<String>,
+    pub is_maker: bool,
+    pub timestamp: i64,
+}
+
+/// Event delivered on a private user-data stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AccountEvent {
+    Order(OrderUpdate),
+    Fill(AccountFill),
+    /// Latest balance of one asset
+    Balance(Balance),
+    /// Latest state of one position; a zero amount means it was closed
+    Position(Position),
+}
+
+impl AccountEvent {
+    pub const fn channel(&self) -> UserDataChannel {
+        match self {
+            Self::Order(_) => UserDataChannel::Orders,
+            Self::Fill(_) => UserDataChannel::Fills,
+            Self::Balance(_) => UserDataChannel::Balances,
+            Self::Position(_) => UserDataChannel::Positions,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -495,4 +1309,188 @@ pub struct FundingRate {
     pub mark_price: Option<Price>,
     pub index_price: Option<Price>,
     pub timestamp: i64,
+    /// Time between funding settlements (1h on Hyperliquid, 8h on most CEXes, 4h for
+    /// some volatile contracts)
+    #[serde(default)]
+    pub funding_interval: Option<std::time::Duration>,
+}
+
+impl FundingRate {
+    /// Seconds in the 365-day year used for annualization
+    pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+    /// Simple (non-compounded) annualized rate, e.g. `0.0001` every 8h → `0.1095`
+    ///
+    /// Returns `None` unless both the rate and the funding interval are known.
+    pub fn annualized(&self) -> Option<Decimal> {
+        let rate = self.funding_rate?;
+        let seconds = self.funding_interval?.as_secs();
+        if seconds == 0 {
+            return None;
+        }
+        Some(rate * Decimal::from(Self::SECONDS_PER_YEAR) / Decimal::from(seconds))
+    }
+
+    /// Fill settlement fields the venue left out from the ones it reported
+    ///
+    /// The interval comes from the gap between the last and next settlement. Missing
+    /// settlement times come from each other and the interval, or else from the interval
+    /// boundary after `timestamp`, as venues settle on UTC-aligned boundaries.
+    pub fn fill_derived_fields(&mut self) {
+        if self.funding_interval.is_none() {
+            if let (Some(last), Some(next)) = (self.funding_time, self.next_funding_time) {
+                self.funding_interval = u64::try_from(next - last)
+                    .ok()
+                    .filter(|ms| *ms > 0)
+                    .map(std::time::Duration::from_millis);
+            }
+        }
+
+        let Some(interval) = self
+            .funding_interval
+            .and_then(|interval| i64::try_from(interval.as_millis()).ok())
+            .filter(|ms| *ms > 0)
+        else {
+            return;
+        };
+        if self.next_funding_time.is_none() {
+            self.next_funding_time = Some(match self.funding_time {
+                Some(last) => last + interval,
+                None => (self.timestamp.div_euclid(interval) + 1) * interval,
+            });
+        }
+        if self.funding_time.is_none() {
+            self.funding_time = self.next_funding_time.map(|next| next - interval);
+        }
+    }
+}
+
+/// Cost of borrowing an asset on margin
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BorrowRate {
+    pub asset: String,
+    /// Interest charged per `interval`, e.g. `0.00001` hourly
+    pub rate: Decimal,
+    pub interval: std::time::Duration,
+    pub timestamp: i64,
+}
+
+impl BorrowRate {
+    /// Simple (non-compounded) annualized rate, comparable with `FundingRate::annualized`
+    pub fn annualized(&self) -> Option<Decimal> {
+        let seconds = self.interval.as_secs();
+        if seconds == 0 {
+            return None;
+        }
+        Some(self.rate * Decimal::from(FundingRate::SECONDS_PER_YEAR) / Decimal::from(seconds))
+    }
+}
+
+/// Interest charged on a margin loan
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InterestRecord {
+    pub asset: String,
+    pub interest: Decimal,
+    /// Amount borrowed when the interest accrued, where the venue reports it
+    pub principal: Option<Decimal>,
+    /// Rate per interval applied, where the venue reports it
+    pub rate: Option<Decimal>,
+    pub timestamp: i64,
+}
+
+/// Funds to send to an external address
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WithdrawalRequest {
+    pub asset: String,
+    pub amount: Decimal,
+    pub address: String,
+    /// Chain to send on, e.g. `ETH` or `TRX`; the venue's default when `None`
+    pub network: Option<String>,
+    /// Memo or tag required by some chains
+    pub address_tag: Option<String>,
+}
+
+impl WithdrawalRequest {
+    pub fn new(asset: impl Into<String>, amount: Decimal, address: impl Into<String>) -> Self {
+        Self {
+            asset: asset.into(),
+            amount,
+            address: address.into(),
+            network: None,
+            address_tag: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_network(mut self, network: impl Into<String>) -> Self {
+        self.network = Some(network.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_address_tag(mut self, tag: impl Into<String>) -> Self {
+        self.address_tag = Some(tag.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WithdrawalStatus {
+    /// Awaiting confirmation, review or approval
+    Pending,
+    /// Sent to the chain
+    Processing,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl WithdrawalStatus {
+    /// Whether the withdrawal can no longer change
+    pub const fn is_final(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// A withdrawal as reported by the venue
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WithdrawalRecord {
+    pub id: String,
+    pub asset: String,
+    pub amount: Decimal,
+    pub fee: Option<Decimal>,
+    pub address: String,
+    pub network: Option<String>,
+    /// On-chain transaction hash once broadcast
+    pub tx_id: Option<String>,
+    pub status: WithdrawalStatus,
+    /// When the withdrawal was requested, in milliseconds since the Unix epoch
+    pub timestamp: i64,
+}
+
+/// A wallet within one venue account, the source or destination of an internal transfer
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum AccountType {
+    Spot,
+    /// Cross or isolated margin
+    Margin,
+    /// Linear (USD-margined) futures and perpetuals
+    Futures,
+    /// Deposits, withdrawals and earn products
+    Funding,
+    /// A single account trading spot and derivatives, e.g. Bybit's UTA
+    Unified,
+}
+
+impl fmt::Display for AccountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Spot => "spot",
+            Self::Margin => "margin",
+            Self::Futures => "futures",
+            Self::Funding => "funding",
+            Self::Unified => "unified",
+        };
+        f.write_str(name)
+    }
 }
@@ -4,6 +4,8 @@ use crate::core::{
     kernel::{Ed25519Signer, RestClientBuilder, RestClientConfig, TungsteniteWs},
 };
 use crate::exchanges::backpack::{codec::BackpackCodec, connector::BackpackConnector};
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
 use std::sync::Arc;
 
 /// Create a Backpack connector with REST-only support
@@ -11,15 +13,10 @@ pub fn build_connector(
     config: ExchangeConfig,
 ) -> Result<BackpackConnector<crate::core::kernel::ReqwestRest, ()>, ExchangeError> {
     // Create REST client with Backpack configuration
-    let rest_config = RestClientConfig::new(
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.backpack.exchange".to_string()),
-        "backpack".to_string(),
-    )
-    .with_timeout(30)
-    .with_max_retries(3);
+    let endpoints = resolve_endpoints(ExchangeId::Backpack, &config)?;
+    let rest_config = RestClientConfig::new(endpoints.rest, "backpack".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -42,15 +39,10 @@ pub fn build_connector_with_websocket(
     ExchangeError,
 > {
     // Create REST client with Backpack configuration
-    let rest_config = RestClientConfig::new(
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.backpack.exchange".to_string()),
-        "backpack".to_string(),
-    )
-    .with_timeout(30)
-    .with_max_retries(3);
+    let endpoints = resolve_endpoints(ExchangeId::Backpack, &config)?;
+    let rest_config = RestClientConfig::new(endpoints.rest, "backpack".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -63,13 +55,23 @@ pub fn build_connector_with_websocket(
     let rest = rest_builder.build()?;
 
     // Create WebSocket client
-    let ws_url = "wss://ws.backpack.exchange".to_string();
     let codec = BackpackCodec::new();
-    let ws = TungsteniteWs::new(ws_url, "backpack".to_string(), codec);
+    let ws = TungsteniteWs::new(endpoints.ws, "backpack".to_string(), codec)
+        .with_config(config.ws_config());
 
     Ok(BackpackConnector::new(rest, ws, config))
 }
 
+/// Create a Backpack connector for public market data, ignoring any credentials in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<
+    BackpackConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<BackpackCodec>>,
+    ExchangeError,
+> {
+    build_connector_with_websocket(config.without_credentials())
+}
+
 /// Create a Backpack connector with WebSocket and auto-reconnection support
 pub fn build_connector_with_reconnection(
     config: ExchangeConfig,
@@ -81,15 +83,10 @@ pub fn build_connector_with_reconnection(
     ExchangeError,
 > {
     // Create REST client with Backpack configuration
-    let rest_config = RestClientConfig::new(
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.backpack.exchange".to_string()),
-        "backpack".to_string(),
-    )
-    .with_timeout(30)
-    .with_max_retries(3);
+    let endpoints = resolve_endpoints(ExchangeId::Backpack, &config)?;
+    let rest_config = RestClientConfig::new(endpoints.rest, "backpack".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -102,12 +99,11 @@ pub fn build_connector_with_reconnection(
     let rest = rest_builder.build()?;
 
     // Create WebSocket client with auto-reconnection
-    let ws_url = "wss://ws.backpack.exchange".to_string();
     let codec = BackpackCodec::new();
-    let base_ws = TungsteniteWs::new(ws_url, "backpack".to_string(), codec);
+    let base_ws = TungsteniteWs::new(endpoints.ws, "backpack".to_string(), codec)
+        .with_config(config.ws_config());
     let reconnect_ws = crate::core::kernel::ReconnectWs::new(base_ws)
-        .with_max_reconnect_attempts(10)
-        .with_reconnect_delay(std::time::Duration::from_secs(2))
+        .with_profile(config.profile)
         .with_auto_resubscribe(true);
 
     Ok(BackpackConnector::new(rest, reconnect_ws, config))
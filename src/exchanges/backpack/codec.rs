@@ -1,10 +1,11 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::WsCodec;
+use crate::exchanges::backpack::signer::BackpackAuth;
 use crate::exchanges::backpack::types::{
     BackpackWebSocketBookTicker, BackpackWebSocketKline, BackpackWebSocketLiquidation,
     BackpackWebSocketMarkPrice, BackpackWebSocketOpenInterest, BackpackWebSocketOrderBook,
-    BackpackWebSocketRFQ, BackpackWebSocketRFQUpdate, BackpackWebSocketTicker,
-    BackpackWebSocketTrade,
+    BackpackWebSocketOrderUpdate, BackpackWebSocketPositionUpdate, BackpackWebSocketRFQ,
+    BackpackWebSocketRFQUpdate, BackpackWebSocketTicker, BackpackWebSocketTrade,
 };
 use serde_json::{json, Value};
 use tokio_tungstenite::tungstenite::Message;
@@ -22,6 +23,8 @@ pub enum BackpackMessage {
     BookTicker(BackpackWebSocketBookTicker),
     RFQ(BackpackWebSocketRFQ),
     RFQUpdate(BackpackWebSocketRFQUpdate),
+    OrderUpdate(BackpackWebSocketOrderUpdate),
+    PositionUpdate(BackpackWebSocketPositionUpdate),
     Ping {
         ping: i64,
     },
@@ -67,6 +70,23 @@ impl BackpackCodec {
         })
     }
 
+    /// Parse a private `account.*` stream message, which arrives wrapped with its stream name
+    fn parse_account_message(value: &Value) -> Option<BackpackMessage> {
+        let stream = value.get("stream")?.as_str()?;
+        let data = value.get("data")?.clone();
+        if stream.starts_with("account.orderUpdate") {
+            serde_json::from_value(data)
+                .ok()
+                .map(BackpackMessage::OrderUpdate)
+        } else if stream.starts_with("account.positionUpdate") {
+            serde_json::from_value(data)
+                .ok()
+                .map(BackpackMessage::PositionUpdate)
+        } else {
+            None
+        }
+    }
+
     /// Parse incoming WebSocket message into typed `BackpackMessage`
     fn parse_websocket_message(&self, value: &Value) -> BackpackMessage {
         // Handle subscription confirmations
@@ -96,6 +116,10 @@ impl BackpackCodec {
             }
         }
 
+        if let Some(message) = Self::parse_account_message(value) {
+            return message;
+        }
+
         // Handle stream data - determine message type by event type
         if let Some(event_type) = value.get("e").and_then(|e| e.as_str()) {
             match event_type {
@@ -195,6 +219,19 @@ impl WsCodec for BackpackCodec {
         Ok(Message::Text(msg.to_string()))
     }
 
+    /// Event times, in microseconds
+    fn exchange_time(&self, message: &Self::Message) -> Option<i64> {
+        match message {
+            BackpackMessage::Ticker(ticker) => Some(ticker.E),
+            BackpackMessage::OrderBook(book) => Some(book.E),
+            BackpackMessage::Trade(trade) => Some(trade.E),
+            BackpackMessage::Kline(kline) => Some(kline.E),
+            BackpackMessage::MarkPrice(mark) => Some(mark.E),
+            BackpackMessage::BookTicker(ticker) => Some(ticker.E),
+            _ => None,
+        }
+    }
+
     fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
         match message {
             Message::Text(text) => {
@@ -214,3 +251,39 @@ impl Default for BackpackCodec {
         Self::new()
     }
 }
+
+/// Codec for private `account.*` streams, whose subscriptions carry an ED25519 signature
+pub struct BackpackPrivateCodec {
+    auth: BackpackAuth,
+}
+
+impl BackpackPrivateCodec {
+    pub const fn new(auth: BackpackAuth) -> Self {
+        Self { auth }
+    }
+}
+
+impl WsCodec for BackpackPrivateCodec {
+    type Message = BackpackMessage;
+
+    fn encode_subscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        // Signed afresh on every (re)subscription, as the signature expires with its window
+        let mut msg = BackpackCodec.build_subscription_message(streams);
+        msg["signature"] = json!(self.auth.create_websocket_signature()?);
+        Ok(Message::Text(msg.to_string()))
+    }
+
+    fn encode_unsubscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        BackpackCodec.encode_unsubscription(streams)
+    }
+
+    fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
+        BackpackCodec.decode_message(message)
+    }
+}
@@ -1,11 +1,14 @@
 use crate::core::{
     errors::ExchangeError,
     kernel::RestClient,
-    traits::AccountInfo,
-    types::{Balance, Position},
+    traits::{AccountInfo, AssetTransfer, TradeHistorySource},
+    types::{conversion, AccountFill, AccountType, Balance, Position, Symbol},
 };
+use crate::exchanges::backpack::conversions::SYMBOLS;
+use crate::exchanges::backpack::conversions::{convert_fill, convert_position_response};
 use crate::exchanges::backpack::rest::BackpackRestClient;
 use async_trait::async_trait;
+use rust_decimal::Decimal;
 use tracing::instrument;
 
 /// Account implementation for Backpack
@@ -27,6 +30,10 @@ impl<R: RestClient> Account<R> {
 
 #[async_trait]
 impl<R: RestClient> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     #[instrument(skip(self), fields(exchange = "backpack"))]
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         let balance_map = self.rest.get_balances().await?;
@@ -35,49 +42,71 @@ impl<R: RestClient> AccountInfo for Account<R> {
         let balances: Vec<Balance> = balance_map
             .0
             .into_iter()
-            .map(|(asset, asset_balance)| Balance {
-                asset,
-                free: crate::core::types::conversion::string_to_quantity(&asset_balance.available),
-                locked: crate::core::types::conversion::string_to_quantity(&asset_balance.locked),
+            .map(|(asset, asset_balance)| {
+                Ok(Balance {
+                    asset,
+                    free: conversion::try_string_to_quantity(&asset_balance.available)?,
+                    locked: conversion::try_string_to_quantity(&asset_balance.locked)?,
+                })
             })
-            .collect();
+            .collect::<Result<_, ExchangeError>>()?;
 
         Ok(balances)
     }
 
     #[instrument(skip(self), fields(exchange = "backpack"))]
     async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
-        let position_responses = self.rest.get_positions().await?;
+        self.rest
+            .get_positions()
+            .await?
+            .iter()
+            .map(convert_position_response)
+            .collect()
+    }
+}
 
-        // Convert Vec<BackpackPositionResponse> to Vec<Position>
-        let positions: Vec<Position> = position_responses
-            .into_iter()
-            .map(|pos_resp| Position {
-                symbol: crate::core::types::conversion::string_to_symbol(&pos_resp.symbol),
-                position_side: {
-                    let net_qty: f64 = pos_resp.net_quantity.parse().unwrap_or(0.0);
-                    if net_qty > 0.0 {
-                        crate::core::types::PositionSide::Long
-                    } else if net_qty < 0.0 {
-                        crate::core::types::PositionSide::Short
-                    } else {
-                        crate::core::types::PositionSide::Both
-                    }
-                },
-                entry_price: crate::core::types::conversion::string_to_price(&pos_resp.entry_price),
-                position_amount: crate::core::types::conversion::string_to_quantity(
-                    &pos_resp.net_quantity,
-                ),
-                unrealized_pnl: crate::core::types::conversion::string_to_decimal(
-                    &pos_resp.pnl_unrealized,
-                ),
-                liquidation_price: Some(crate::core::types::conversion::string_to_price(
-                    &pos_resp.est_liquidation_price,
-                )),
-                leverage: crate::core::types::conversion::string_to_decimal("1.0"), // Default leverage if not available
-            })
-            .collect();
+#[async_trait]
+impl<R: RestClient> TradeHistorySource for Account<R> {
+    #[instrument(skip(self), fields(exchange = "backpack", symbol = %symbol))]
+    async fn get_my_trades(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AccountFill>, ExchangeError> {
+        let fills = self
+            .rest
+            .get_fills(
+                Some(&SYMBOLS.try_to_exchange_symbol(&symbol)?),
+                start_time,
+                end_time,
+                limit,
+            )
+            .await?;
+        let mut fills = fills
+            .iter()
+            .map(convert_fill)
+            .collect::<Result<Vec<_>, _>>()?;
+        fills.sort_by_key(|fill| fill.timestamp);
+        Ok(fills)
+    }
+}
 
-        Ok(positions)
+#[async_trait]
+impl<R: RestClient> AssetTransfer for Account<R> {
+    /// Spot, margin and perpetuals all draw on one collateral pool, so there is
+    /// nothing to move between
+    async fn transfer(
+        &self,
+        _asset: &str,
+        _amount: Decimal,
+        from: AccountType,
+        to: AccountType,
+    ) -> Result<String, ExchangeError> {
+        Err(ExchangeError::NotSupported(format!(
+            "Backpack has a single collateral account; cannot transfer from {} to {}",
+            from, to
+        )))
     }
 }
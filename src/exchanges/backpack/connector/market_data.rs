@@ -1,27 +1,92 @@
 use crate::core::{
     errors::ExchangeError,
-    kernel::{RestClient, WsSession},
-    traits::MarketDataSource,
+    kernel::{RestClient, TungsteniteWs, WsConfig, WsSession},
+    streaming::{SharedHub, StreamRouter},
+    traits::{FundingRateSource, MarketSnapshotSource, RestMarketData, StreamingMarketData},
     types::{
-        conversion, Kline, KlineInterval, Market, MarketDataType, Price, Quantity,
-        SubscriptionType, Symbol, WebSocketConfig,
+        conversion, ContractStyle, FundingRate, Kline, KlineInterval, Market, MarketDataType,
+        MarketStatus, OrderBook, Price, Quantity, SubscriptionType, Symbol, Ticker, Trade,
+        WebSocketConfig,
     },
 };
-use crate::exchanges::backpack::{codec::BackpackCodec, rest::BackpackRestClient};
+use crate::exchanges::backpack::conversions::SYMBOLS;
+use crate::exchanges::backpack::conversions::{
+    convert_depth_response, convert_funding_rate, convert_mark_price, convert_ticker_response,
+    convert_trade_response, parse_kline_time,
+};
+use crate::exchanges::backpack::{
+    codec::{BackpackCodec, BackpackMessage},
+    create_backpack_stream_identifiers,
+    rest::BackpackRestClient,
+};
+use crate::exchanges::factory::ExchangeId;
 use async_trait::async_trait;
 use rust_decimal::Decimal;
+use std::collections::HashMap;
 use tokio::sync::mpsc;
 
+/// Routes Backpack updates by venue symbol, which tells spot and perpetual apart
+struct BackpackRouter;
+
+impl StreamRouter for BackpackRouter {
+    type Codec = BackpackCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        Ok(create_backpack_stream_identifiers(
+            &[SYMBOLS.try_to_exchange_symbol(symbol)?],
+            std::slice::from_ref(subscription),
+        )
+        .pop())
+    }
+
+    fn convert(&self, message: BackpackMessage) -> Vec<MarketDataType> {
+        match convert_backpack_message_to_market_data(message) {
+            Ok(update) => update
+                .map(|mut market_data| {
+                    // Backpack stamps events in microseconds
+                    market_data.normalize_timestamps();
+                    market_data
+                })
+                .into_iter()
+                .collect(),
+            Err(e) => {
+                tracing::warn!(error = %e, "Dropping malformed Backpack message");
+                Vec::new()
+            }
+        }
+    }
+
+    fn route_symbol(&self, symbol: &Symbol) -> String {
+        SYMBOLS.to_exchange_symbol(symbol)
+    }
+}
+
 /// Market data implementation for Backpack
 pub struct MarketData<R: RestClient, W = ()> {
     rest: BackpackRestClient<R>,
-    #[allow(dead_code)] // May be used for future WebSocket functionality
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
     ws: Option<W>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
 }
 
 impl<R: RestClient + Clone, W> MarketData<R, W> {
     fn ws_url(&self) -> String {
-        "wss://ws.backpack.exchange".to_string()
+        ExchangeId::Backpack.endpoints().mainnet.ws.to_string()
     }
 }
 
@@ -31,6 +96,8 @@ impl<R: RestClient + Clone, W: WsSession<BackpackCodec>> MarketData<R, W> {
         Self {
             rest: BackpackRestClient::new(rest.clone()),
             ws,
+            hub: SharedHub::new(),
+            ws_config: WsConfig::default(),
         }
     }
 }
@@ -41,138 +108,79 @@ impl<R: RestClient + Clone> MarketData<R, ()> {
         Self {
             rest: BackpackRestClient::new(rest.clone()),
             ws: None,
+            hub: SharedHub::new(),
+            ws_config: WsConfig::default(),
         }
     }
 }
 
 #[async_trait]
-impl<R: RestClient + Clone, W: WsSession<BackpackCodec>> MarketDataSource for MarketData<R, W> {
+impl<R: RestClient + Clone, W: WsSession<BackpackCodec>> RestMarketData for MarketData<R, W> {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         let markets = self.rest.get_markets().await?;
 
         Ok(markets
             .into_iter()
-            .map(|m| Market {
-                symbol: Symbol::new(m.base_symbol, m.quote_symbol)
-                    .unwrap_or_else(|_| Symbol::default()),
-                status: m.order_book_state,
-                base_precision: 8,  // Default precision
-                quote_precision: 8, // Default precision
-                min_qty: m
-                    .filters
-                    .as_ref()
-                    .and_then(|f| f.quantity.as_ref())
-                    .and_then(|q| q.min_quantity.as_ref())
-                    .map(|s| conversion::string_to_quantity(s))
-                    .or_else(|| Some(Quantity::new(Decimal::from(0)))),
-                max_qty: m
-                    .filters
-                    .as_ref()
-                    .and_then(|f| f.quantity.as_ref())
-                    .and_then(|q| q.max_quantity.as_ref())
-                    .map(|s| conversion::string_to_quantity(s))
-                    .or_else(|| Some(Quantity::new(Decimal::from(999_999_999)))),
-                min_price: m
-                    .filters
-                    .as_ref()
-                    .and_then(|f| f.price.as_ref())
-                    .and_then(|p| p.min_price.as_ref())
-                    .map(|s| conversion::string_to_price(s))
-                    .or_else(|| Some(Price::new(Decimal::from(0)))),
-                max_price: m
-                    .filters
-                    .as_ref()
-                    .and_then(|f| f.price.as_ref())
-                    .and_then(|p| p.max_price.as_ref())
-                    .map(|s| conversion::string_to_price(s))
-                    .or_else(|| Some(Price::new(Decimal::from(999_999_999)))),
+            .map(|m| {
+                Ok(Market {
+                    symbol: Symbol::new(m.base_symbol, m.quote_symbol)
+                        .unwrap_or_else(|_| Symbol::default()),
+                    status: MarketStatus::from_venue_str(&m.order_book_state),
+                    base_precision: 8,  // Default precision
+                    quote_precision: 8, // Default precision
+                    min_qty: m
+                        .filters
+                        .as_ref()
+                        .and_then(|f| f.quantity.as_ref())
+                        .and_then(|q| q.min_quantity.as_ref())
+                        .map(|s| conversion::try_string_to_quantity(s))
+                        .transpose()?
+                        .or_else(|| Some(Quantity::new(Decimal::from(0)))),
+                    max_qty: m
+                        .filters
+                        .as_ref()
+                        .and_then(|f| f.quantity.as_ref())
+                        .and_then(|q| q.max_quantity.as_ref())
+                        .map(|s| conversion::try_string_to_quantity(s))
+                        .transpose()?
+                        .or_else(|| Some(Quantity::new(Decimal::from(999_999_999)))),
+                    min_price: m
+                        .filters
+                        .as_ref()
+                        .and_then(|f| f.price.as_ref())
+                        .and_then(|p| p.min_price.as_ref())
+                        .map(|s| conversion::try_string_to_price(s))
+                        .transpose()?
+                        .or_else(|| Some(Price::new(Decimal::from(0)))),
+                    max_price: m
+                        .filters
+                        .as_ref()
+                        .and_then(|f| f.price.as_ref())
+                        .and_then(|p| p.max_price.as_ref())
+                        .map(|s| conversion::try_string_to_price(s))
+                        .transpose()?
+                        .or_else(|| Some(Price::new(Decimal::from(999_999_999)))),
+                    contract_style: ContractStyle::Spot,
+                    contract_size: None,
+                    extensions: None,
+                })
             })
-            .collect())
-    }
-
-    async fn subscribe_market_data(
-        &self,
-        symbols: Vec<String>,
-        subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        // Use the helper to create stream identifiers
-        let streams = crate::exchanges::backpack::create_backpack_stream_identifiers(
-            &symbols,
-            &subscription_types,
-        );
-
-        // Create WebSocket URL
-        let ws_url = self.ws_url();
-
-        // Use kernel WebSocket implementation with BackpackCodec
-        let codec = crate::exchanges::backpack::codec::BackpackCodec::new();
-        let ws_session =
-            crate::core::kernel::ws::TungsteniteWs::new(ws_url, "backpack".to_string(), codec);
-
-        // Add reconnection wrapper for production reliability
-        let mut reconnect_ws = crate::core::kernel::ws::ReconnectWs::new(ws_session)
-            .with_auto_resubscribe(true)
-            .with_max_reconnect_attempts(u32::MAX);
-
-        // Connect and subscribe
-        reconnect_ws.connect().await.map_err(|e| {
-            ExchangeError::Other(format!(
-                "Failed to connect to WebSocket for symbols: {:?}, error: {}",
-                symbols, e
-            ))
-        })?;
-
-        if !streams.is_empty() {
-            let stream_refs: Vec<&str> = streams.iter().map(|s| s.as_str()).collect();
-            reconnect_ws.subscribe(&stream_refs).await.map_err(|e| {
-                ExchangeError::Other(format!(
-                    "Failed to subscribe to streams: {:?}, error: {}",
-                    streams, e
-                ))
-            })?;
-        }
-
-        // Create channel for messages
-        let (tx, rx) = mpsc::channel(1000);
-
-        // Spawn task to handle messages
-        tokio::spawn(async move {
-            while let Some(result) = reconnect_ws.next_message().await {
-                match result {
-                    Ok(backpack_message) => {
-                        // Convert BackpackMessage to MarketDataType
-                        if let Some(market_data) =
-                            convert_backpack_message_to_market_data(backpack_message)
-                        {
-                            if tx.send(market_data).await.is_err() {
-                                break; // Receiver dropped
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("WebSocket error: {:?}", e);
-                        // Continue processing to handle reconnection
-                    }
-                }
-            }
-        });
-
-        Ok(rx)
+            .collect::<Result<_, ExchangeError>>()?)
     }
 
-    fn get_websocket_url(&self) -> String {
-        self.ws_url()
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
     }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let interval_str = interval.to_backpack_format();
         let klines = self
             .rest
@@ -181,91 +189,120 @@ impl<R: RestClient + Clone, W: WsSession<BackpackCodec>> MarketDataSource for Ma
 
         Ok(klines
             .into_iter()
-            .map(|k| Kline {
-                symbol: conversion::string_to_symbol(&symbol),
-                open_time: k.start.parse::<i64>().unwrap_or(0),
-                close_time: k.end.parse::<i64>().unwrap_or(0),
-                interval: interval_str.clone(),
-                open_price: conversion::string_to_price(&k.open),
-                high_price: conversion::string_to_price(&k.high),
-                low_price: conversion::string_to_price(&k.low),
-                close_price: conversion::string_to_price(&k.close),
-                volume: conversion::string_to_volume(&k.volume),
-                number_of_trades: k.trades.parse::<i64>().unwrap_or(0),
-                final_bar: true, // Backpack doesn't indicate if bar is final
+            .map(|k| {
+                // `end` is the next bar's open, so derive an inclusive close instead
+                let open_time = parse_kline_time(&k.start);
+                Ok(Kline {
+                    symbol: SYMBOLS.from_exchange_symbol(&symbol),
+                    open_time,
+                    close_time: interval.close_time(open_time),
+                    interval: interval_str.clone(),
+                    open_price: conversion::try_string_to_price(&k.open)?,
+                    high_price: conversion::try_string_to_price(&k.high)?,
+                    low_price: conversion::try_string_to_price(&k.low)?,
+                    close_price: conversion::try_string_to_price(&k.close)?,
+                    volume: conversion::try_string_to_volume(&k.volume)?,
+                    number_of_trades: k.trades.parse::<i64>().unwrap_or(0),
+                    final_bar: true, // Backpack doesn't indicate if bar is final
+                })
             })
-            .collect())
+            .collect::<Result<_, ExchangeError>>()?)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: WsSession<BackpackCodec>> StreamingMarketData for MarketData<R, W> {
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(self.ws_url(), "backpack".to_string(), BackpackCodec::new())
+                        .with_config(self.ws_config.clone())
+                },
+                BackpackRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.ws_url()
     }
 }
 
 #[async_trait]
-impl<R: RestClient + Clone> MarketDataSource for MarketData<R, ()> {
+impl<R: RestClient + Clone> RestMarketData for MarketData<R, ()> {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         let markets = self.rest.get_markets().await?;
 
         Ok(markets
             .into_iter()
-            .map(|m| Market {
-                symbol: Symbol::new(m.base_symbol, m.quote_symbol)
-                    .unwrap_or_else(|_| Symbol::default()),
-                status: m.order_book_state,
-                base_precision: 8,  // Default precision
-                quote_precision: 8, // Default precision
-                min_qty: m
-                    .filters
-                    .as_ref()
-                    .and_then(|f| f.quantity.as_ref())
-                    .and_then(|q| q.min_quantity.as_ref())
-                    .map(|s| conversion::string_to_quantity(s))
-                    .or_else(|| Some(Quantity::new(Decimal::from(0)))),
-                max_qty: m
-                    .filters
-                    .as_ref()
-                    .and_then(|f| f.quantity.as_ref())
-                    .and_then(|q| q.max_quantity.as_ref())
-                    .map(|s| conversion::string_to_quantity(s))
-                    .or_else(|| Some(Quantity::new(Decimal::from(999_999_999)))),
-                min_price: m
-                    .filters
-                    .as_ref()
-                    .and_then(|f| f.price.as_ref())
-                    .and_then(|p| p.min_price.as_ref())
-                    .map(|s| conversion::string_to_price(s))
-                    .or_else(|| Some(Price::new(Decimal::from(0)))),
-                max_price: m
-                    .filters
-                    .as_ref()
-                    .and_then(|f| f.price.as_ref())
-                    .and_then(|p| p.max_price.as_ref())
-                    .map(|s| conversion::string_to_price(s))
-                    .or_else(|| Some(Price::new(Decimal::from(999_999_999)))),
+            .map(|m| {
+                Ok(Market {
+                    symbol: Symbol::new(m.base_symbol, m.quote_symbol)
+                        .unwrap_or_else(|_| Symbol::default()),
+                    status: MarketStatus::from_venue_str(&m.order_book_state),
+                    base_precision: 8,  // Default precision
+                    quote_precision: 8, // Default precision
+                    min_qty: m
+                        .filters
+                        .as_ref()
+                        .and_then(|f| f.quantity.as_ref())
+                        .and_then(|q| q.min_quantity.as_ref())
+                        .map(|s| conversion::try_string_to_quantity(s))
+                        .transpose()?
+                        .or_else(|| Some(Quantity::new(Decimal::from(0)))),
+                    max_qty: m
+                        .filters
+                        .as_ref()
+                        .and_then(|f| f.quantity.as_ref())
+                        .and_then(|q| q.max_quantity.as_ref())
+                        .map(|s| conversion::try_string_to_quantity(s))
+                        .transpose()?
+                        .or_else(|| Some(Quantity::new(Decimal::from(999_999_999)))),
+                    min_price: m
+                        .filters
+                        .as_ref()
+                        .and_then(|f| f.price.as_ref())
+                        .and_then(|p| p.min_price.as_ref())
+                        .map(|s| conversion::try_string_to_price(s))
+                        .transpose()?
+                        .or_else(|| Some(Price::new(Decimal::from(0)))),
+                    max_price: m
+                        .filters
+                        .as_ref()
+                        .and_then(|f| f.price.as_ref())
+                        .and_then(|p| p.max_price.as_ref())
+                        .map(|s| conversion::try_string_to_price(s))
+                        .transpose()?
+                        .or_else(|| Some(Price::new(Decimal::from(999_999_999)))),
+                    contract_style: ContractStyle::Spot,
+                    contract_size: None,
+                    extensions: None,
+                })
             })
-            .collect())
+            .collect::<Result<_, ExchangeError>>()?)
     }
 
-    async fn subscribe_market_data(
-        &self,
-        _symbols: Vec<String>,
-        _subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        Err(ExchangeError::WebSocketError(
-            "WebSocket not available in REST-only mode".to_string(),
-        ))
-    }
-
-    fn get_websocket_url(&self) -> String {
-        self.ws_url()
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
     }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let interval_str = interval.to_backpack_format();
         let klines = self
             .rest
@@ -274,20 +311,145 @@ impl<R: RestClient + Clone> MarketDataSource for MarketData<R, ()> {
 
         Ok(klines
             .into_iter()
-            .map(|k| Kline {
-                symbol: conversion::string_to_symbol(&symbol),
-                open_time: k.start.parse::<i64>().unwrap_or(0),
-                close_time: k.end.parse::<i64>().unwrap_or(0),
-                interval: interval_str.clone(),
-                open_price: conversion::string_to_price(&k.open),
-                high_price: conversion::string_to_price(&k.high),
-                low_price: conversion::string_to_price(&k.low),
-                close_price: conversion::string_to_price(&k.close),
-                volume: conversion::string_to_volume(&k.volume),
-                number_of_trades: k.trades.parse::<i64>().unwrap_or(0),
-                final_bar: true, // Backpack doesn't indicate if bar is final
+            .map(|k| {
+                // `end` is the next bar's open, so derive an inclusive close instead
+                let open_time = parse_kline_time(&k.start);
+                Ok(Kline {
+                    symbol: SYMBOLS.from_exchange_symbol(&symbol),
+                    open_time,
+                    close_time: interval.close_time(open_time),
+                    interval: interval_str.clone(),
+                    open_price: conversion::try_string_to_price(&k.open)?,
+                    high_price: conversion::try_string_to_price(&k.high)?,
+                    low_price: conversion::try_string_to_price(&k.low)?,
+                    close_price: conversion::try_string_to_price(&k.close)?,
+                    volume: conversion::try_string_to_volume(&k.volume)?,
+                    number_of_trades: k.trades.parse::<i64>().unwrap_or(0),
+                    final_bar: true, // Backpack doesn't indicate if bar is final
+                })
             })
-            .collect())
+            .collect::<Result<_, ExchangeError>>()?)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> MarketSnapshotSource for MarketData<R, W> {
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let ticker = self
+            .rest
+            .get_ticker(&SYMBOLS.try_to_exchange_symbol(&symbol)?)
+            .await?;
+        convert_ticker_response(&ticker, symbol, chrono::Utc::now().timestamp_millis())
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let book = self
+            .rest
+            .get_order_book(&SYMBOLS.try_to_exchange_symbol(&symbol)?, depth)
+            .await?;
+        Ok(convert_depth_response(&book, symbol)?.truncated(depth))
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let mut trades = self
+            .rest
+            .get_trades(&SYMBOLS.try_to_exchange_symbol(&symbol)?, limit)
+            .await?
+            .iter()
+            .map(|trade| convert_trade_response(trade, symbol.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        trades.sort_by_key(|trade| (trade.time, trade.id));
+        Ok(trades)
+    }
+}
+
+/// Most settled intervals `/api/v1/fundingRates` returns in one page
+const FUNDING_HISTORY_PAGE: u32 = 1000;
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> FundingRateSource for MarketData<R, W> {
+    async fn get_funding_rates(
+        &self,
+        symbols: Option<Vec<Symbol>>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        // One symbol is a single filtered request; otherwise fetch every market and filter
+        let single = symbols
+            .as_ref()
+            .filter(|symbols| symbols.len() == 1)
+            .map(|symbols| SYMBOLS.try_to_exchange_symbol(&symbols[0]))
+            .transpose()?;
+        let (marks, markets) = tokio::try_join!(
+            self.rest.get_mark_prices(single.as_deref()),
+            self.rest.get_markets()
+        )?;
+        let intervals: HashMap<String, i64> = markets
+            .into_iter()
+            .filter_map(|market| Some((market.symbol, market.funding_interval?)))
+            .collect();
+
+        marks
+            .iter()
+            .map(|mark| convert_mark_price(mark, intervals.get(&mark.symbol).copied()))
+            .filter(|rate| {
+                rate.as_ref().map_or(true, |rate| {
+                    symbols
+                        .as_ref()
+                        .map_or(true, |symbols| symbols.contains(&rate.symbol))
+                })
+            })
+            .collect()
+    }
+
+    async fn get_all_funding_rates(&self) -> Result<Vec<FundingRate>, ExchangeError> {
+        self.get_funding_rates(None).await
+    }
+
+    /// Settled rates, oldest first
+    ///
+    /// Backpack takes no time range, so one page is fetched and filtered locally: a
+    /// range reaching further back than the newest 1000 intervals comes back truncated,
+    /// and `limit` keeps the most recent of the matching intervals.
+    async fn get_funding_rate_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let page = if start_time.is_some() || end_time.is_some() {
+            FUNDING_HISTORY_PAGE
+        } else {
+            limit.unwrap_or(100).min(FUNDING_HISTORY_PAGE)
+        };
+        let history = self
+            .rest
+            .get_funding_rate_history(&SYMBOLS.try_to_exchange_symbol(&symbol)?, Some(page), None)
+            .await?;
+
+        let mut rates = history
+            .iter()
+            .map(convert_funding_rate)
+            .filter(|rate| {
+                rate.as_ref().map_or(true, |rate| {
+                    start_time.map_or(true, |start| rate.timestamp >= start)
+                        && end_time.map_or(true, |end| rate.timestamp <= end)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        rates.sort_by_key(|rate| rate.timestamp);
+        if let Some(limit) = limit {
+            let excess = rates.len().saturating_sub(limit as usize);
+            rates.drain(..excess);
+        }
+        Ok(rates)
     }
 }
 
@@ -320,18 +482,19 @@ impl BackpackKlineInterval for KlineInterval {
 }
 
 /// Convert `BackpackMessage` to `MarketDataType`
+#[allow(clippy::too_many_lines)]
 fn convert_backpack_message_to_market_data(
-    message: crate::exchanges::backpack::codec::BackpackMessage,
-) -> Option<MarketDataType> {
+    message: BackpackMessage,
+) -> Result<Option<MarketDataType>, ExchangeError> {
     use crate::core::types::conversion;
 
-    match message {
+    Ok(match message {
         crate::exchanges::backpack::codec::BackpackMessage::Ticker(ticker) => {
-            let symbol = conversion::string_to_symbol(&ticker.s);
-            let price = conversion::string_to_price(&ticker.c);
+            let symbol = SYMBOLS.from_exchange_symbol(&ticker.s);
+            let price = conversion::try_string_to_price(&ticker.c)?;
             // Backpack doesn't provide price change directly, calculate from open and close
-            let open_price = conversion::string_to_price(&ticker.o);
-            let close_price = conversion::string_to_price(&ticker.c);
+            let open_price = conversion::try_string_to_price(&ticker.o)?;
+            let close_price = conversion::try_string_to_price(&ticker.c)?;
             let price_change =
                 crate::core::types::Price::new(close_price.value() - open_price.value());
             let price_change_percent = if open_price.value().is_zero() {
@@ -340,10 +503,10 @@ fn convert_backpack_message_to_market_data(
                 (close_price.value() - open_price.value()) / open_price.value()
                     * rust_decimal::Decimal::from(100)
             };
-            let high_price = conversion::string_to_price(&ticker.h);
-            let low_price = conversion::string_to_price(&ticker.l);
-            let volume = conversion::string_to_volume(&ticker.v);
-            let quote_volume = conversion::string_to_volume(&ticker.V);
+            let high_price = conversion::try_string_to_price(&ticker.h)?;
+            let low_price = conversion::try_string_to_price(&ticker.l)?;
+            let volume = conversion::try_string_to_volume(&ticker.v)?;
+            let quote_volume = conversion::try_string_to_volume(&ticker.V)?;
 
             Some(MarketDataType::Ticker(crate::core::types::Ticker {
                 symbol,
@@ -360,9 +523,9 @@ fn convert_backpack_message_to_market_data(
             }))
         }
         crate::exchanges::backpack::codec::BackpackMessage::Trade(trade) => {
-            let symbol = conversion::string_to_symbol(&trade.s);
-            let price = conversion::string_to_price(&trade.p);
-            let quantity = conversion::string_to_quantity(&trade.q);
+            let symbol = SYMBOLS.from_exchange_symbol(&trade.s);
+            let price = conversion::try_string_to_price(&trade.p)?;
+            let quantity = conversion::try_string_to_quantity(&trade.q)?;
 
             Some(MarketDataType::Trade(crate::core::types::Trade {
                 symbol,
@@ -374,24 +537,28 @@ fn convert_backpack_message_to_market_data(
             }))
         }
         crate::exchanges::backpack::codec::BackpackMessage::OrderBook(orderbook) => {
-            let symbol = conversion::string_to_symbol(&orderbook.s);
+            let symbol = SYMBOLS.from_exchange_symbol(&orderbook.s);
 
             let bids = orderbook
                 .b
                 .iter()
-                .map(|bid| crate::core::types::OrderBookEntry {
-                    price: conversion::string_to_price(&bid[0]),
-                    quantity: conversion::string_to_quantity(&bid[1]),
+                .map(|bid| {
+                    Ok(crate::core::types::OrderBookEntry {
+                        price: conversion::try_string_to_price(&bid[0])?,
+                        quantity: conversion::try_string_to_quantity(&bid[1])?,
+                    })
                 })
-                .collect();
+                .collect::<Result<_, ExchangeError>>()?;
             let asks = orderbook
                 .a
                 .iter()
-                .map(|ask| crate::core::types::OrderBookEntry {
-                    price: conversion::string_to_price(&ask[0]),
-                    quantity: conversion::string_to_quantity(&ask[1]),
+                .map(|ask| {
+                    Ok(crate::core::types::OrderBookEntry {
+                        price: conversion::try_string_to_price(&ask[0])?,
+                        quantity: conversion::try_string_to_quantity(&ask[1])?,
+                    })
                 })
-                .collect();
+                .collect::<Result<_, ExchangeError>>()?;
 
             Some(MarketDataType::OrderBook(crate::core::types::OrderBook {
                 symbol,
@@ -401,12 +568,12 @@ fn convert_backpack_message_to_market_data(
             }))
         }
         crate::exchanges::backpack::codec::BackpackMessage::Kline(kline) => {
-            let symbol = conversion::string_to_symbol(&kline.s);
-            let open_price = conversion::string_to_price(&kline.o);
-            let high_price = conversion::string_to_price(&kline.h);
-            let low_price = conversion::string_to_price(&kline.l);
-            let close_price = conversion::string_to_price(&kline.c);
-            let volume = conversion::string_to_volume(&kline.v);
+            let symbol = SYMBOLS.from_exchange_symbol(&kline.s);
+            let open_price = conversion::try_string_to_price(&kline.o)?;
+            let high_price = conversion::try_string_to_price(&kline.h)?;
+            let low_price = conversion::try_string_to_price(&kline.l)?;
+            let close_price = conversion::try_string_to_price(&kline.c)?;
+            let volume = conversion::try_string_to_volume(&kline.v)?;
 
             Some(MarketDataType::Kline(crate::core::types::Kline {
                 symbol,
@@ -422,6 +589,16 @@ fn convert_backpack_message_to_market_data(
                 final_bar: kline.X,
             }))
         }
+        crate::exchanges::backpack::codec::BackpackMessage::MarkPrice(mark) => {
+            Some(MarketDataType::MarkPrice(crate::core::types::MarkPrice {
+                symbol: SYMBOLS.from_exchange_symbol(&mark.s),
+                mark_price: conversion::try_string_to_price(&mark.p)?,
+                index_price: Some(conversion::try_string_to_price(&mark.i)?),
+                funding_rate: Some(conversion::try_string_to_decimal(&mark.f)?),
+                next_funding_time: Some(mark.n),
+                timestamp: mark.E,
+            }))
+        }
         _ => None, // Ignore other message types for now
-    }
+    })
 }
@@ -1,49 +1,63 @@
 use crate::core::errors::ExchangeError;
-use crate::core::traits::{AccountInfo, MarketDataSource, OrderPlacer};
+use crate::core::traits::{
+    AccountInfo, AssetTransfer, DiagnosticsSource, FundingRateSource, MarketSnapshotSource,
+    OrderHistorySource, OrderPlacer, RestMarketData, StreamingMarketData, TradeHistorySource,
+    UserDataStream,
+};
 use crate::core::types::{
-    Balance, Kline, KlineInterval, Market, MarketDataType, OrderRequest, OrderResponse, Position,
-    SubscriptionType, WebSocketConfig,
+    AccountEvent, AccountFill, AccountType, Balance, FundingRate, Kline, KlineInterval, Market,
+    MarketDataType, OrderBook, OrderPage, OrderRequest, OrderResponse, Position, SubscriptionType,
+    Symbol, Ticker, Trade, UserDataChannel, WebSocketConfig,
+};
+use crate::core::{
+    config::ExchangeConfig, kernel::diagnostics, kernel::Diagnostics, kernel::RestClient,
+    kernel::WsSession,
 };
-use crate::core::{config::ExchangeConfig, kernel::RestClient, kernel::WsSession};
 use crate::exchanges::backpack::codec::BackpackCodec;
 use async_trait::async_trait;
+use rust_decimal::Decimal;
 use tokio::sync::mpsc;
 
 pub mod account;
 pub mod market_data;
 pub mod trading;
+pub mod user_data;
 
 pub use account::Account;
 pub use market_data::MarketData;
 pub use trading::Trading;
+pub use user_data::UserData;
 
 /// Backpack connector that composes all sub-trait implementations
 pub struct BackpackConnector<R: RestClient, W = ()> {
     pub market: MarketData<R, W>,
     pub trading: Trading<R>,
     pub account: Account<R>,
+    pub user_data: UserData,
 }
 
 impl<R: RestClient + Clone + Send + Sync, W: WsSession<BackpackCodec> + Send + Sync>
     BackpackConnector<R, W>
 {
     /// Create a new Backpack connector with WebSocket support
-    pub fn new(rest: R, ws: W, _config: ExchangeConfig) -> Self {
+    pub fn new(rest: R, ws: W, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::<R, W>::new(&rest, Some(ws)),
+            market: MarketData::<R, W>::new(&rest, Some(ws)).with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
+            user_data: UserData::new(&config),
         }
     }
 }
 
 impl<R: RestClient + Clone + Send + Sync> BackpackConnector<R, ()> {
     /// Create a new Backpack connector without WebSocket support
-    pub fn new_without_ws(rest: R, _config: ExchangeConfig) -> Self {
+    pub fn new_without_ws(rest: R, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::<R, ()>::new(&rest, None),
+            market: MarketData::<R, ()>::new(&rest, None).with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
+            user_data: UserData::new(&config),
         }
     }
 }
@@ -51,16 +65,38 @@ impl<R: RestClient + Clone + Send + Sync> BackpackConnector<R, ()> {
 // Implement traits for the connector by delegating to sub-components
 
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync, W: WsSession<BackpackCodec> + Send + Sync>
-    MarketDataSource for BackpackConnector<R, W>
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<BackpackCodec> + Send + Sync> RestMarketData
+    for BackpackConnector<R, W>
 {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         self.market.get_markets().await
     }
 
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        self.market
+            .get_klines(symbol, interval, limit, start_time, end_time)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<BackpackCodec> + Send + Sync>
+    StreamingMarketData for BackpackConnector<R, W>
+{
     async fn subscribe_market_data(
         &self,
-        symbols: Vec<String>,
+        symbols: Vec<Symbol>,
         subscription_types: Vec<SubscriptionType>,
         config: Option<WebSocketConfig>,
     ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
@@ -72,10 +108,21 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<BackpackCodec> + Send + S
     fn get_websocket_url(&self) -> String {
         self.market.get_websocket_url()
     }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> RestMarketData for BackpackConnector<R, ()> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.market.get_markets().await
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
+    }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
@@ -88,53 +135,106 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<BackpackCodec> + Send + S
 }
 
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync> MarketDataSource for BackpackConnector<R, ()> {
-    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
-        self.market.get_markets().await
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for BackpackConnector<R, W>
+{
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        self.market.get_ticker(symbol).await
     }
 
-    async fn subscribe_market_data(
+    async fn get_order_book_snapshot(
         &self,
-        _symbols: Vec<String>,
-        _subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        Err(ExchangeError::WebSocketError(
-            "WebSocket not available in REST-only mode".to_string(),
-        ))
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        self.market.get_order_book_snapshot(symbol, depth).await
     }
 
-    fn get_websocket_url(&self) -> String {
-        self.market.get_websocket_url()
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        self.market.get_recent_trades(symbol, limit).await
     }
+}
 
-    async fn get_klines(
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
+    for BackpackConnector<R, W>
+{
+    async fn get_funding_rates(
         &self,
-        symbol: String,
-        interval: KlineInterval,
-        limit: Option<u32>,
+        symbols: Option<Vec<Symbol>>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        self.market.get_funding_rates(symbols).await
+    }
+
+    async fn get_all_funding_rates(&self) -> Result<Vec<FundingRate>, ExchangeError> {
+        self.market.get_all_funding_rates().await
+    }
+
+    async fn get_funding_rate_history(
+        &self,
+        symbol: Symbol,
         start_time: Option<i64>,
         end_time: Option<i64>,
-    ) -> Result<Vec<Kline>, ExchangeError> {
+        limit: Option<u32>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
         self.market
-            .get_klines(symbol, interval, limit, start_time, end_time)
+            .get_funding_rate_history(symbol, start_time, end_time, limit)
             .await
     }
 }
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderPlacer for BackpackConnector<R, W> {
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
         self.trading.place_order(order).await
     }
 
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
         self.trading.cancel_order(symbol, order_id).await
     }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderHistorySource
+    for BackpackConnector<R, W>
+{
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        self.trading
+            .get_order_history(symbol, start_time, end_time, cursor)
+            .await
+    }
 }
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo for BackpackConnector<R, W> {
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         self.account.get_account_balance().await
     }
@@ -143,3 +243,53 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo for Backpa
         self.account.get_positions().await
     }
 }
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AssetTransfer
+    for BackpackConnector<R, W>
+{
+    async fn transfer(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        from: AccountType,
+        to: AccountType,
+    ) -> Result<String, ExchangeError> {
+        self.account.transfer(asset, amount, from, to).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> TradeHistorySource
+    for BackpackConnector<R, W>
+{
+    async fn get_my_trades(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AccountFill>, ExchangeError> {
+        self.account
+            .get_my_trades(symbol, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> UserDataStream
+    for BackpackConnector<R, W>
+{
+    async fn subscribe_user_data(
+        &self,
+        channels: Vec<UserDataChannel>,
+    ) -> Result<mpsc::Receiver<AccountEvent>, ExchangeError> {
+        self.user_data.subscribe_user_data(channels).await
+    }
+}
+
+impl<R: RestClient, W> DiagnosticsSource for BackpackConnector<R, W> {
+    fn diagnostics(&self) -> Diagnostics {
+        diagnostics("backpack")
+    }
+}
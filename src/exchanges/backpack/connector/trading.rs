@@ -1,9 +1,10 @@
 use crate::core::{
     errors::ExchangeError,
     kernel::RestClient,
-    traits::OrderPlacer,
-    types::{OrderRequest, OrderResponse},
+    traits::{OrderHistorySource, OrderPlacer},
+    types::{OrderPage, OrderRequest, OrderResponse, Symbol},
 };
+use crate::exchanges::backpack::conversions::{convert_order, SYMBOLS};
 use crate::exchanges::backpack::rest::BackpackRestClient;
 use async_trait::async_trait;
 use serde_json::json;
@@ -28,36 +29,48 @@ impl<R: RestClient> Trading<R> {
 
 #[async_trait]
 impl<R: RestClient> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     #[instrument(skip(self), fields(exchange = "backpack"))]
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
         // Convert OrderRequest to Backpack API format
-        let order_json = json!({
-            "symbol": order.symbol.as_str(),
+        let mut order_json = json!({
+            "symbol": SYMBOLS.try_to_exchange_symbol(&order.symbol)?,
             "side": order.side,
             "type": order.order_type,
             "quantity": order.quantity.to_string(),
             "price": order.price.map(|p| p.to_string()),
             "timeInForce": order.time_in_force,
         });
+        if let Some(client_order_id) = &order.client_order_id {
+            order_json["clientId"] = json!(parse_client_id(client_order_id)?);
+        }
 
         let response = self.rest.place_order(&order_json).await?;
 
         // Convert Backpack response to core OrderResponse
         Ok(OrderResponse {
             order_id: response.order_id.to_string(),
-            client_order_id: response.client_order_id.unwrap_or_default(),
-            symbol: crate::core::types::conversion::string_to_symbol(&response.symbol),
+            client_order_id: response
+                .client_order_id
+                .or(order.client_order_id)
+                .unwrap_or_default(),
+            symbol: SYMBOLS.from_exchange_symbol(&response.symbol),
             side: order.side,
             order_type: order.order_type,
             quantity: order.quantity,
             price: order.price,
             status: response.status,
             timestamp: response.timestamp,
+            extensions: None,
         })
     }
 
     #[instrument(skip(self), fields(exchange = "backpack", symbol = %symbol, order_id = %order_id))]
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let order_id_i64: i64 = order_id
             .parse()
             .map_err(|_| ExchangeError::Other(format!("Invalid order ID format: {}", order_id)))?;
@@ -66,4 +79,80 @@ impl<R: RestClient> OrderPlacer for Trading<R> {
             .await?;
         Ok(())
     }
+
+    #[instrument(skip(self), fields(exchange = "backpack", symbol = %symbol, client_order_id = %client_order_id))]
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        let client_id = parse_client_id(&client_order_id)?.to_string();
+        self.rest
+            .cancel_order(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                None,
+                Some(&client_id),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Backpack client ids are `u32`s
+fn parse_client_id(client_order_id: &str) -> Result<u32, ExchangeError> {
+    client_order_id.parse().map_err(|_| {
+        ExchangeError::InvalidParameters(format!(
+            "Backpack client order ids are 32-bit unsigned integers: {}",
+            client_order_id
+        ))
+    })
+}
+
+/// Largest page `history/orders` returns
+const ORDER_HISTORY_LIMIT: u32 = 1000;
+
+#[async_trait]
+impl<R: RestClient> OrderHistorySource for Trading<R> {
+    /// Pages run newest first; the cursor is the offset of the next page. Backpack
+    /// takes no time range, so it is applied to each fetched page.
+    #[instrument(skip(self), fields(exchange = "backpack"))]
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        let offset = cursor
+            .as_deref()
+            .map(str::parse::<u32>)
+            .transpose()
+            .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid cursor: {}", e)))?
+            .unwrap_or(0);
+        let raw = self
+            .rest
+            .get_order_history(
+                Some(&SYMBOLS.try_to_exchange_symbol(&symbol)?),
+                Some(ORDER_HISTORY_LIMIT),
+                Some(offset),
+            )
+            .await?;
+
+        let next_cursor = (raw.len() == ORDER_HISTORY_LIMIT as usize)
+            .then(|| (offset + ORDER_HISTORY_LIMIT).to_string());
+        let mut orders = Vec::with_capacity(raw.len());
+        for order in &raw {
+            let order = convert_order(order)?;
+            if start_time.is_some_and(|start| order.created_time < start)
+                || end_time.is_some_and(|end| order.created_time > end)
+            {
+                continue;
+            }
+            orders.push(order);
+        }
+        Ok(OrderPage {
+            orders,
+            next_cursor,
+        })
+    }
 }
@@ -0,0 +1,121 @@
+use crate::core::{
+    config::ExchangeConfig,
+    errors::ExchangeError,
+    kernel::{ReconnectWs, TungsteniteWs, WsControl, WsSession},
+    traits::UserDataStream,
+    types::{AccountEvent, UserDataChannel},
+};
+use crate::exchanges::backpack::codec::{BackpackMessage, BackpackPrivateCodec};
+use crate::exchanges::backpack::conversions::{convert_order_update, convert_position_update};
+use crate::exchanges::backpack::signer::BackpackAuth;
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+/// User data stream implementation for Backpack
+pub struct UserData {
+    config: ExchangeConfig,
+    control: WsControl,
+}
+
+impl UserData {
+    pub fn new(config: &ExchangeConfig) -> Self {
+        Self {
+            config: config.clone(),
+            control: WsControl::default(),
+        }
+    }
+
+    /// Control info of the most recently opened user data stream
+    pub fn control(&self) -> WsControl {
+        self.control.clone()
+    }
+
+    fn ws_url(&self) -> String {
+        "wss://ws.backpack.exchange".to_string()
+    }
+}
+
+/// Private stream carrying `channel`; Backpack has no balance stream
+const fn channel_stream(channel: UserDataChannel) -> Option<&'static str> {
+    match channel {
+        UserDataChannel::Orders | UserDataChannel::Fills => Some("account.orderUpdate"),
+        UserDataChannel::Positions => Some("account.positionUpdate"),
+        UserDataChannel::Balances => None,
+    }
+}
+
+fn convert_message(message: BackpackMessage) -> Result<Vec<AccountEvent>, ExchangeError> {
+    match message {
+        BackpackMessage::OrderUpdate(update) => convert_order_update(&update),
+        BackpackMessage::PositionUpdate(update) => Ok(vec![AccountEvent::Position(
+            convert_position_update(&update)?,
+        )]),
+        _ => Ok(Vec::new()),
+    }
+}
+
+#[async_trait]
+impl UserDataStream for UserData {
+    async fn subscribe_user_data(
+        &self,
+        channels: Vec<UserDataChannel>,
+    ) -> Result<mpsc::Receiver<AccountEvent>, ExchangeError> {
+        if !self.config.has_credentials() {
+            return Err(ExchangeError::AuthenticationRequired);
+        }
+        let auth = BackpackAuth::new(&self.config)?;
+
+        let mut streams: Vec<&str> = channels.iter().filter_map(|c| channel_stream(*c)).collect();
+        streams.sort_unstable();
+        streams.dedup();
+        if streams.is_empty() {
+            return Err(ExchangeError::NotSupported(
+                "Backpack has no private stream for the requested channels".to_string(),
+            ));
+        }
+
+        // Each subscription is signed, so resubscribing after a reconnect re-authenticates
+        let ws_session = TungsteniteWs::new(
+            self.ws_url(),
+            "backpack".to_string(),
+            BackpackPrivateCodec::new(auth),
+        )
+        .with_config(self.config.ws_config())
+        .with_control(self.control.clone());
+        let mut reconnect_ws = ReconnectWs::new(ws_session)
+            .with_auto_resubscribe(true)
+            .with_max_reconnect_attempts(u32::MAX);
+        reconnect_ws.connect().await.map_err(|e| {
+            ExchangeError::Other(format!("Failed to connect user data stream: {}", e))
+        })?;
+        reconnect_ws.subscribe(&streams).await?;
+
+        let (tx, rx) = mpsc::channel(1000);
+        tokio::spawn(async move {
+            while let Some(result) = reconnect_ws.next_message().await {
+                let converted = match result {
+                    Ok(message) => convert_message(message),
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Backpack user data stream error");
+                        continue;
+                    }
+                };
+                match converted {
+                    Ok(events) => {
+                        for event in events {
+                            if channels.contains(&event.channel()) && tx.send(event).await.is_err()
+                            {
+                                return; // Receiver dropped
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Dropping malformed Backpack account event");
+                    }
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
@@ -1,107 +1,455 @@
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
 use crate::core::types::{
-    conversion, Balance, Kline, Market, MarketDataType, OrderBook, OrderBookEntry, Position,
-    PositionSide, Symbol, Ticker, Trade,
+    conversion, AccountEvent, AccountFill, Balance, ContractStyle, FundingRate, Kline, MarginMode,
+    Market, MarketDataType, MarketStatus, Order, OrderBook, OrderBookEntry, OrderSide, OrderStatus,
+    OrderType, OrderUpdate, Position, PositionSide, Price, Quantity, Symbol, Ticker, Trade,
 };
 use crate::exchanges::backpack::types::{
-    BackpackBalance, BackpackMarket, BackpackOrderBook, BackpackPosition, BackpackRestKline,
-    BackpackTicker, BackpackTrade, BackpackWebSocketKline, BackpackWebSocketOrderBook,
-    BackpackWebSocketTicker, BackpackWebSocketTrade,
+    BackpackBalance, BackpackDepthResponse, BackpackFill, BackpackFundingRate, BackpackMarkPrice,
+    BackpackMarket, BackpackOrder, BackpackOrderBook, BackpackPosition, BackpackPositionResponse,
+    BackpackRestKline, BackpackTicker, BackpackTickerResponse, BackpackTrade,
+    BackpackTradeResponse, BackpackWebSocketKline, BackpackWebSocketOrderBook,
+    BackpackWebSocketOrderUpdate, BackpackWebSocketPositionUpdate, BackpackWebSocketTicker,
+    BackpackWebSocketTrade,
 };
+use crate::exchanges::factory::ExchangeId;
+use rust_decimal::Decimal;
+use serde_json::json;
+use std::time::Duration;
+
+/// Backpack symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::Backpack.symbol_convention();
+
+/// Convert Backpack fill to core fill
+pub fn convert_fill(fill: &BackpackFill) -> Result<AccountFill, ExchangeError> {
+    Ok(AccountFill {
+        symbol: SYMBOLS.from_exchange_symbol(&fill.symbol),
+        order_id: fill.order_id.to_string(),
+        trade_id: fill.trade_id.map(|id| id.to_string()).unwrap_or_default(),
+        side: if fill.is_buyer {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        },
+        price: conversion::try_string_to_price(&fill.price)?,
+        quantity: conversion::try_string_to_quantity(&fill.quantity)?,
+        fee: conversion::try_string_to_decimal(&fill.commission)?,
+        fee_asset: Some(fill.commission_asset.clone()),
+        is_maker: fill.is_maker,
+        timestamp: fill.time,
+    })
+}
 
 /// Convert Backpack market to core Market type
-pub fn convert_market(backpack_market: BackpackMarket) -> Market {
-    Market {
+pub fn convert_market(backpack_market: BackpackMarket) -> Result<Market, ExchangeError> {
+    Ok(Market {
         symbol: Symbol::new(backpack_market.base_asset, backpack_market.quote_asset)
-            .unwrap_or_else(|_| conversion::string_to_symbol(&backpack_market.symbol)),
-        status: backpack_market.status,
+            .unwrap_or_else(|_| SYMBOLS.from_exchange_symbol(&backpack_market.symbol)),
+        status: MarketStatus::from_venue_str(&backpack_market.status),
         base_precision: backpack_market.base_precision,
         quote_precision: backpack_market.quote_precision,
-        min_qty: Some(conversion::string_to_quantity(&backpack_market.min_qty)),
-        max_qty: Some(conversion::string_to_quantity(&backpack_market.max_qty)),
-        min_price: Some(conversion::string_to_price(&backpack_market.min_price)),
-        max_price: Some(conversion::string_to_price(&backpack_market.max_price)),
-    }
+        min_qty: Some(conversion::try_string_to_quantity(
+            &backpack_market.min_qty,
+        )?),
+        max_qty: Some(conversion::try_string_to_quantity(
+            &backpack_market.max_qty,
+        )?),
+        min_price: Some(conversion::try_string_to_price(&backpack_market.min_price)?),
+        max_price: Some(conversion::try_string_to_price(&backpack_market.max_price)?),
+        contract_style: ContractStyle::Spot,
+        contract_size: None,
+        extensions: None,
+    })
 }
 
 /// Convert Backpack balance to core Balance type
-pub fn convert_balance(backpack_balance: BackpackBalance) -> Balance {
-    Balance {
+pub fn convert_balance(backpack_balance: BackpackBalance) -> Result<Balance, ExchangeError> {
+    Ok(Balance {
         asset: backpack_balance.asset,
-        free: conversion::string_to_quantity(&backpack_balance.free),
-        locked: conversion::string_to_quantity(&backpack_balance.locked),
-    }
+        free: conversion::try_string_to_quantity(&backpack_balance.free)?,
+        locked: conversion::try_string_to_quantity(&backpack_balance.locked)?,
+    })
 }
 
 /// Convert Backpack position to core Position type
-pub fn convert_position(backpack_position: BackpackPosition) -> Position {
-    Position {
-        symbol: conversion::string_to_symbol(&backpack_position.symbol),
+pub fn convert_position(backpack_position: BackpackPosition) -> Result<Position, ExchangeError> {
+    Ok(Position {
+        symbol: SYMBOLS.from_exchange_symbol(&backpack_position.symbol),
         position_side: match backpack_position.side.as_str() {
             "LONG" => PositionSide::Long,
             "SHORT" => PositionSide::Short,
             _ => PositionSide::Both,
         },
-        entry_price: conversion::string_to_price(&backpack_position.entry_price),
-        position_amount: conversion::string_to_quantity(&backpack_position.size),
-        unrealized_pnl: conversion::string_to_decimal(&backpack_position.unrealized_pnl),
-        liquidation_price: Some(conversion::string_to_price(
-            &backpack_position.liquidation_price,
-        )),
-        leverage: conversion::string_to_decimal(&backpack_position.leverage),
+        entry_price: conversion::try_string_to_price(&backpack_position.entry_price)?,
+        position_amount: conversion::try_string_to_quantity(&backpack_position.size)?,
+        unrealized_pnl: conversion::try_string_to_decimal(&backpack_position.unrealized_pnl)?,
+        // Empty when the position has no liquidation price
+        liquidation_price: conversion::try_string_to_price(&backpack_position.liquidation_price)
+            .ok(),
+        leverage: conversion::try_string_to_decimal(&backpack_position.leverage)?,
+        margin_mode: None,
+        margin: None,
+        extensions: None,
+    })
+}
+
+/// Convert an open position of `/api/v1/position` to core Position type
+///
+/// Backpack margins every position from the account's shared collateral, so the mode
+/// is always cross; leverage is the inverse of the initial margin fraction.
+pub fn convert_position_response(
+    response: &BackpackPositionResponse,
+) -> Result<Position, ExchangeError> {
+    let net_quantity = conversion::try_string_to_quantity(&response.net_quantity)?;
+    let initial_margin_fraction = response
+        .imf
+        .as_deref()
+        .map(conversion::try_string_to_decimal)
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(Position {
+        symbol: SYMBOLS.from_exchange_symbol(&response.symbol),
+        position_side: match net_quantity.value().cmp(&Decimal::ZERO) {
+            std::cmp::Ordering::Greater => PositionSide::Long,
+            std::cmp::Ordering::Less => PositionSide::Short,
+            std::cmp::Ordering::Equal => PositionSide::Both,
+        },
+        entry_price: conversion::try_string_to_price(&response.entry_price)?,
+        position_amount: net_quantity,
+        unrealized_pnl: conversion::try_string_to_decimal(&response.pnl_unrealized)?,
+        // Empty when the position has no liquidation price
+        liquidation_price: conversion::try_string_to_price(&response.est_liquidation_price).ok(),
+        leverage: if initial_margin_fraction.is_zero() {
+            Decimal::ONE
+        } else {
+            Decimal::ONE / initial_margin_fraction
+        },
+        margin_mode: Some(MarginMode::Cross),
+        margin: None,
+        extensions: Some(json!({
+            "positionId": response.position_id,
+            "markPrice": response.mark_price,
+            "breakEvenPrice": response.break_even_price,
+            "pnlRealized": response.pnl_realized,
+            "cumulativeFundingPayment": response.cumulative_funding_payment,
+        })),
+    })
+}
+
+/// Convert a Backpack mark price to the funding rate of the current interval
+///
+/// `funding_interval` is the market's `fundingInterval` in milliseconds, when known.
+pub fn convert_mark_price(
+    mark: &BackpackMarkPrice,
+    funding_interval: Option<i64>,
+) -> Result<FundingRate, ExchangeError> {
+    Ok(FundingRate {
+        symbol: SYMBOLS.from_exchange_symbol(&mark.symbol),
+        funding_rate: Some(conversion::try_string_to_decimal(&mark.funding_rate)?),
+        previous_funding_rate: None,
+        next_funding_rate: None,
+        funding_time: None,
+        next_funding_time: Some(mark.next_funding_timestamp),
+        mark_price: Some(conversion::try_string_to_price(&mark.mark_price)?),
+        index_price: Some(conversion::try_string_to_price(&mark.index_price)?),
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        funding_interval: funding_interval
+            .and_then(|ms| u64::try_from(ms).ok())
+            .map(Duration::from_millis),
+    })
+}
+
+/// Convert a settled Backpack funding interval to core `FundingRate` type
+pub fn convert_funding_rate(rate: &BackpackFundingRate) -> Result<FundingRate, ExchangeError> {
+    let funding_time = parse_kline_time(&rate.interval_end_timestamp);
+    Ok(FundingRate {
+        symbol: SYMBOLS.from_exchange_symbol(&rate.symbol),
+        funding_rate: Some(conversion::try_string_to_decimal(&rate.funding_rate)?),
+        previous_funding_rate: None,
+        next_funding_rate: None,
+        funding_time: Some(funding_time),
+        next_funding_time: None,
+        mark_price: None,
+        index_price: None,
+        timestamp: funding_time,
+        funding_interval: None,
+    })
+}
+
+/// Convert an entry of Backpack's order history to core Order type
+///
+/// History entries carry no update time; both times are the creation time.
+pub fn convert_order(order: &BackpackOrder) -> Result<Order, ExchangeError> {
+    let optional_decimal = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(conversion::try_string_to_decimal)
+            .transpose()
+    };
+    let filled = optional_decimal(&order.executed_quantity)?.unwrap_or_default();
+    let filled_quote = optional_decimal(&order.executed_quote_quantity)?.unwrap_or_default();
+    let created_time = parse_kline_time(&order.created_at);
+
+    Ok(Order {
+        order_id: order.id.clone(),
+        client_order_id: order.client_id.map(|id| id.to_string()).unwrap_or_default(),
+        symbol: SYMBOLS.from_exchange_symbol(&order.symbol),
+        side: if order.side == "Ask" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        order_type: match (order.order_type.as_str(), order.trigger_price.is_some()) {
+            ("Market", false) => OrderType::Market,
+            ("Market", true) => OrderType::StopLoss,
+            (_, true) => OrderType::StopLossLimit,
+            (_, false) => OrderType::Limit,
+        },
+        status: OrderStatus::from_venue(&order.status),
+        price: order
+            .price
+            .as_deref()
+            .map(conversion::try_string_to_price)
+            .transpose()?,
+        // Orders sized in the quote asset carry no base quantity
+        quantity: Quantity::new(optional_decimal(&order.quantity)?.unwrap_or(filled)),
+        filled_quantity: Quantity::new(filled),
+        average_price: (!filled.is_zero()).then(|| Price::new(filled_quote / filled)),
+        created_time,
+        updated_time: created_time,
+    })
+}
+
+/// Convert Backpack private order update to an order update, plus a fill for `orderFill`
+pub fn convert_order_update(
+    update: &BackpackWebSocketOrderUpdate,
+) -> Result<Vec<AccountEvent>, ExchangeError> {
+    let symbol = SYMBOLS.from_exchange_symbol(&update.s);
+    let side = if update.S == "Ask" {
+        OrderSide::Sell
+    } else {
+        OrderSide::Buy
+    };
+    // Engine timestamps are in microseconds
+    let timestamp = update.T / 1000;
+
+    let mut events = vec![AccountEvent::Order(OrderUpdate {
+        symbol: symbol.clone(),
+        order_id: update.i.clone(),
+        client_order_id: update.c.map(|id| id.to_string()).unwrap_or_default(),
+        side: side.clone(),
+        status: update.X.clone(),
+        price: update
+            .p
+            .as_deref()
+            .map(conversion::try_string_to_price)
+            .transpose()?,
+        // Orders sized in the quote asset carry no base quantity
+        quantity: update
+            .q
+            .as_deref()
+            .map(conversion::try_string_to_quantity)
+            .transpose()?
+            .unwrap_or_else(|| Quantity::new(Decimal::ZERO)),
+        filled_quantity: conversion::try_string_to_quantity(&update.z)?,
+        timestamp,
+    })];
+
+    if update.e == "orderFill" {
+        let (Some(price), Some(quantity)) = (&update.L, &update.l) else {
+            return Err(ExchangeError::ParseError(
+                "Order fill without price or quantity".to_string(),
+            ));
+        };
+        events.push(AccountEvent::Fill(AccountFill {
+            symbol,
+            order_id: update.i.clone(),
+            trade_id: update.t.map(|id| id.to_string()).unwrap_or_default(),
+            side,
+            price: conversion::try_string_to_price(price)?,
+            quantity: conversion::try_string_to_quantity(quantity)?,
+            fee: update
+                .n
+                .as_deref()
+                .map(conversion::try_string_to_decimal)
+                .transpose()?
+                .unwrap_or_default(),
+            fee_asset: update.N.clone(),
+            is_maker: update.m.unwrap_or(false),
+            timestamp,
+        }));
     }
+    Ok(events)
+}
+
+/// Convert Backpack private position update to core Position type
+pub fn convert_position_update(
+    update: &BackpackWebSocketPositionUpdate,
+) -> Result<Position, ExchangeError> {
+    let net_quantity = conversion::try_string_to_quantity(&update.q)?;
+    let initial_margin_fraction = conversion::try_string_to_decimal(&update.f)?;
+
+    Ok(Position {
+        symbol: SYMBOLS.from_exchange_symbol(&update.s),
+        position_side: match net_quantity.value().cmp(&Decimal::ZERO) {
+            std::cmp::Ordering::Greater => PositionSide::Long,
+            std::cmp::Ordering::Less => PositionSide::Short,
+            std::cmp::Ordering::Equal => PositionSide::Both,
+        },
+        entry_price: conversion::try_string_to_price(&update.B)?,
+        position_amount: net_quantity,
+        unrealized_pnl: conversion::try_string_to_decimal(&update.P)?,
+        liquidation_price: update
+            .l
+            .as_deref()
+            .and_then(|price| conversion::try_string_to_price(price).ok()),
+        leverage: if initial_margin_fraction.is_zero() {
+            Decimal::ONE
+        } else {
+            Decimal::ONE / initial_margin_fraction
+        },
+        margin_mode: None,
+        margin: None,
+        extensions: None,
+    })
 }
 
 /// Convert Backpack ticker to core Ticker type
-pub fn convert_ticker(backpack_ticker: BackpackTicker) -> Ticker {
-    Ticker {
-        symbol: conversion::string_to_symbol(&backpack_ticker.symbol),
-        price: conversion::string_to_price(&backpack_ticker.price),
-        price_change: conversion::string_to_price(&backpack_ticker.price_change),
-        price_change_percent: conversion::string_to_decimal(&backpack_ticker.price_change_percent),
-        high_price: conversion::string_to_price(&backpack_ticker.high_price),
-        low_price: conversion::string_to_price(&backpack_ticker.low_price),
-        volume: conversion::string_to_volume(&backpack_ticker.volume),
-        quote_volume: conversion::string_to_volume(&backpack_ticker.quote_volume),
+pub fn convert_ticker(backpack_ticker: BackpackTicker) -> Result<Ticker, ExchangeError> {
+    Ok(Ticker {
+        symbol: SYMBOLS.from_exchange_symbol(&backpack_ticker.symbol),
+        price: conversion::try_string_to_price(&backpack_ticker.price)?,
+        price_change: conversion::try_string_to_price(&backpack_ticker.price_change)?,
+        price_change_percent: conversion::try_string_to_decimal(
+            &backpack_ticker.price_change_percent,
+        )?,
+        high_price: conversion::try_string_to_price(&backpack_ticker.high_price)?,
+        low_price: conversion::try_string_to_price(&backpack_ticker.low_price)?,
+        volume: conversion::try_string_to_volume(&backpack_ticker.volume)?,
+        quote_volume: conversion::try_string_to_volume(&backpack_ticker.quote_volume)?,
         open_time: backpack_ticker.open_time,
         close_time: backpack_ticker.close_time,
         count: backpack_ticker.count,
-    }
+    })
 }
 
 /// Convert Backpack order book to core `OrderBook` type
-pub fn convert_order_book(backpack_order_book: BackpackOrderBook) -> OrderBook {
-    OrderBook {
-        symbol: conversion::string_to_symbol(&backpack_order_book.symbol),
+pub fn convert_order_book(
+    backpack_order_book: BackpackOrderBook,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: SYMBOLS.from_exchange_symbol(&backpack_order_book.symbol),
         bids: backpack_order_book
             .bids
             .into_iter()
-            .map(|b| OrderBookEntry {
-                price: conversion::string_to_price(&b.price),
-                quantity: conversion::string_to_quantity(&b.quantity),
+            .map(|b| {
+                Ok(OrderBookEntry {
+                    price: conversion::try_string_to_price(&b.price)?,
+                    quantity: conversion::try_string_to_quantity(&b.quantity)?,
+                })
             })
-            .collect(),
+            .collect::<Result<_, ExchangeError>>()?,
         asks: backpack_order_book
             .asks
             .into_iter()
-            .map(|a| OrderBookEntry {
-                price: conversion::string_to_price(&a.price),
-                quantity: conversion::string_to_quantity(&a.quantity),
+            .map(|a| {
+                Ok(OrderBookEntry {
+                    price: conversion::try_string_to_price(&a.price)?,
+                    quantity: conversion::try_string_to_quantity(&a.quantity)?,
+                })
             })
-            .collect(),
+            .collect::<Result<_, ExchangeError>>()?,
         last_update_id: backpack_order_book.last_update_id,
-    }
+    })
 }
 
 /// Convert Backpack trade to core Trade type
-pub fn convert_trade(backpack_trade: BackpackTrade) -> Trade {
-    Trade {
-        symbol: conversion::string_to_symbol(""), // Symbol not available in trade data
+pub fn convert_trade(backpack_trade: BackpackTrade) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: SYMBOLS.from_exchange_symbol(""), // Symbol not available in trade data
         id: backpack_trade.id,
-        price: conversion::string_to_price(&backpack_trade.price),
-        quantity: conversion::string_to_quantity(&backpack_trade.quantity),
+        price: conversion::try_string_to_price(&backpack_trade.price)?,
+        quantity: conversion::try_string_to_quantity(&backpack_trade.quantity)?,
         time: backpack_trade.time,
         is_buyer_maker: backpack_trade.is_buyer_maker,
-    }
+    })
+}
+
+/// Convert Backpack REST 24h ticker to core Ticker type, as of `timestamp`
+pub fn convert_ticker_response(
+    ticker: &BackpackTickerResponse,
+    symbol: Symbol,
+    timestamp: i64,
+) -> Result<Ticker, ExchangeError> {
+    Ok(Ticker {
+        symbol,
+        price: conversion::try_string_to_price(&ticker.last_price)?,
+        price_change: conversion::try_string_to_price(&ticker.price_change)?,
+        // Reported as a fraction, `0.01` being 1%
+        price_change_percent: conversion::try_string_to_decimal(&ticker.price_change_percent)?
+            * Decimal::ONE_HUNDRED,
+        high_price: conversion::try_string_to_price(&ticker.high)?,
+        low_price: conversion::try_string_to_price(&ticker.low)?,
+        volume: conversion::try_string_to_volume(&ticker.volume)?,
+        quote_volume: conversion::try_string_to_volume(&ticker.quote_volume)?,
+        open_time: timestamp - 24 * 60 * 60 * 1000,
+        close_time: timestamp,
+        count: ticker.trades.parse().unwrap_or(0),
+    })
+}
+
+/// Convert Backpack REST depth to core `OrderBook` type, best levels first
+pub fn convert_depth_response(
+    depth: &BackpackDepthResponse,
+    symbol: Symbol,
+) -> Result<OrderBook, ExchangeError> {
+    let mut bids = conversion::try_book_levels(&depth.bids)?;
+    let mut asks = conversion::try_book_levels(&depth.asks)?;
+    // Both sides are listed in ascending price
+    bids.sort_by_key(|level| std::cmp::Reverse(level.price));
+    asks.sort_by_key(|level| level.price);
+    Ok(OrderBook {
+        symbol,
+        bids,
+        asks,
+        last_update_id: depth.last_update_id.parse().unwrap_or(0),
+    })
+}
+
+/// Convert Backpack REST trade to core Trade type
+pub fn convert_trade_response(
+    trade: &BackpackTradeResponse,
+    symbol: Symbol,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol,
+        id: trade.id,
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: conversion::try_string_to_quantity(&trade.quantity)?,
+        time: trade.timestamp,
+        is_buyer_maker: trade.is_buyer_maker,
+    })
+}
+
+/// Parse a Backpack kline boundary or funding interval end, sent as epoch milliseconds
+/// or a UTC `YYYY-MM-DD HH:MM:SS` timestamp
+pub fn parse_kline_time(value: &str) -> i64 {
+    value
+        .parse::<i64>()
+        .ok()
+        .or_else(|| {
+            [
+                "%Y-%m-%d %H:%M:%S",
+                "%Y-%m-%dT%H:%M:%S",
+                "%Y-%m-%dT%H:%M:%S%.f",
+            ]
+            .iter()
+            .find_map(|format| chrono::NaiveDateTime::parse_from_str(value, format).ok())
+            .map(|time| time.and_utc().timestamp_millis())
+        })
+        .unwrap_or(0)
 }
 
 /// Convert Backpack REST kline to core Kline type
@@ -109,109 +457,120 @@ pub fn convert_rest_kline(
     backpack_kline: BackpackRestKline,
     symbol: String,
     interval: String,
-) -> Kline {
-    Kline {
-        symbol: conversion::string_to_symbol(&symbol),
+) -> Result<Kline, ExchangeError> {
+    Ok(Kline {
+        symbol: SYMBOLS.from_exchange_symbol(&symbol),
         open_time: backpack_kline.open_time,
         close_time: backpack_kline.close_time,
         interval,
-        open_price: conversion::string_to_price(&backpack_kline.open),
-        high_price: conversion::string_to_price(&backpack_kline.high),
-        low_price: conversion::string_to_price(&backpack_kline.low),
-        close_price: conversion::string_to_price(&backpack_kline.close),
-        volume: conversion::string_to_volume(&backpack_kline.volume),
+        open_price: conversion::try_string_to_price(&backpack_kline.open)?,
+        high_price: conversion::try_string_to_price(&backpack_kline.high)?,
+        low_price: conversion::try_string_to_price(&backpack_kline.low)?,
+        close_price: conversion::try_string_to_price(&backpack_kline.close)?,
+        volume: conversion::try_string_to_volume(&backpack_kline.volume)?,
         number_of_trades: backpack_kline.number_of_trades,
         final_bar: true, // Always true for historical data
-    }
+    })
 }
 
 /// Convert Backpack WebSocket ticker to core Ticker type
-pub fn convert_ws_ticker(backpack_ws_ticker: BackpackWebSocketTicker) -> Ticker {
-    Ticker {
-        symbol: conversion::string_to_symbol(&backpack_ws_ticker.s),
-        price: conversion::string_to_price(&backpack_ws_ticker.c),
-        price_change: conversion::string_to_price("0"), // Not available in WebSocket
-        price_change_percent: conversion::string_to_decimal("0"), // Not available in WebSocket
-        high_price: conversion::string_to_price(&backpack_ws_ticker.h),
-        low_price: conversion::string_to_price(&backpack_ws_ticker.l),
-        volume: conversion::string_to_volume(&backpack_ws_ticker.v),
-        quote_volume: conversion::string_to_volume(&backpack_ws_ticker.V),
+pub fn convert_ws_ticker(
+    backpack_ws_ticker: BackpackWebSocketTicker,
+) -> Result<Ticker, ExchangeError> {
+    Ok(Ticker {
+        symbol: SYMBOLS.from_exchange_symbol(&backpack_ws_ticker.s),
+        price: conversion::try_string_to_price(&backpack_ws_ticker.c)?,
+        price_change: Price::ZERO,           // Not available in WebSocket
+        price_change_percent: Decimal::ZERO, // Not available in WebSocket
+        high_price: conversion::try_string_to_price(&backpack_ws_ticker.h)?,
+        low_price: conversion::try_string_to_price(&backpack_ws_ticker.l)?,
+        volume: conversion::try_string_to_volume(&backpack_ws_ticker.v)?,
+        quote_volume: conversion::try_string_to_volume(&backpack_ws_ticker.V)?,
         open_time: 0, // Not available in WebSocket
         close_time: backpack_ws_ticker.E,
         count: backpack_ws_ticker.n,
-    }
+    })
 }
 
 /// Convert Backpack WebSocket order book to core `OrderBook` type
-pub fn convert_ws_order_book(backpack_ws_order_book: BackpackWebSocketOrderBook) -> OrderBook {
-    OrderBook {
-        symbol: conversion::string_to_symbol(&backpack_ws_order_book.s),
+pub fn convert_ws_order_book(
+    backpack_ws_order_book: BackpackWebSocketOrderBook,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: SYMBOLS.from_exchange_symbol(&backpack_ws_order_book.s),
         bids: backpack_ws_order_book
             .b
             .into_iter()
-            .map(|b| OrderBookEntry {
-                price: conversion::string_to_price(&b[0]),
-                quantity: conversion::string_to_quantity(&b[1]),
+            .map(|b| {
+                Ok(OrderBookEntry {
+                    price: conversion::try_string_to_price(&b[0])?,
+                    quantity: conversion::try_string_to_quantity(&b[1])?,
+                })
             })
-            .collect(),
+            .collect::<Result<_, ExchangeError>>()?,
         asks: backpack_ws_order_book
             .a
             .into_iter()
-            .map(|a| OrderBookEntry {
-                price: conversion::string_to_price(&a[0]),
-                quantity: conversion::string_to_quantity(&a[1]),
+            .map(|a| {
+                Ok(OrderBookEntry {
+                    price: conversion::try_string_to_price(&a[0])?,
+                    quantity: conversion::try_string_to_quantity(&a[1])?,
+                })
             })
-            .collect(),
+            .collect::<Result<_, ExchangeError>>()?,
         last_update_id: backpack_ws_order_book.u,
-    }
+    })
 }
 
 /// Convert Backpack WebSocket trade to core Trade type
-pub fn convert_ws_trade(backpack_ws_trade: BackpackWebSocketTrade) -> Trade {
-    Trade {
-        symbol: conversion::string_to_symbol(&backpack_ws_trade.s),
+pub fn convert_ws_trade(backpack_ws_trade: BackpackWebSocketTrade) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: SYMBOLS.from_exchange_symbol(&backpack_ws_trade.s),
         id: backpack_ws_trade.t,
-        price: conversion::string_to_price(&backpack_ws_trade.p),
-        quantity: conversion::string_to_quantity(&backpack_ws_trade.q),
+        price: conversion::try_string_to_price(&backpack_ws_trade.p)?,
+        quantity: conversion::try_string_to_quantity(&backpack_ws_trade.q)?,
         time: backpack_ws_trade.T,
         is_buyer_maker: backpack_ws_trade.m,
-    }
+    })
 }
 
 /// Convert Backpack WebSocket kline to core Kline type
-pub fn convert_ws_kline(backpack_ws_kline: BackpackWebSocketKline, interval: String) -> Kline {
-    Kline {
-        symbol: conversion::string_to_symbol(&backpack_ws_kline.s),
+pub fn convert_ws_kline(
+    backpack_ws_kline: BackpackWebSocketKline,
+    interval: String,
+) -> Result<Kline, ExchangeError> {
+    Ok(Kline {
+        symbol: SYMBOLS.from_exchange_symbol(&backpack_ws_kline.s),
         open_time: backpack_ws_kline.t,
         close_time: backpack_ws_kline.T,
         interval,
-        open_price: conversion::string_to_price(&backpack_ws_kline.o),
-        high_price: conversion::string_to_price(&backpack_ws_kline.h),
-        low_price: conversion::string_to_price(&backpack_ws_kline.l),
-        close_price: conversion::string_to_price(&backpack_ws_kline.c),
-        volume: conversion::string_to_volume(&backpack_ws_kline.v),
+        open_price: conversion::try_string_to_price(&backpack_ws_kline.o)?,
+        high_price: conversion::try_string_to_price(&backpack_ws_kline.h)?,
+        low_price: conversion::try_string_to_price(&backpack_ws_kline.l)?,
+        close_price: conversion::try_string_to_price(&backpack_ws_kline.c)?,
+        volume: conversion::try_string_to_volume(&backpack_ws_kline.v)?,
         number_of_trades: backpack_ws_kline.n,
         final_bar: backpack_ws_kline.X,
-    }
+    })
 }
 
 /// Convert Backpack WebSocket message to core `MarketDataType`
 pub fn convert_ws_message(
     backpack_ws_message: crate::exchanges::backpack::types::BackpackWebSocketMessage,
-) -> Option<MarketDataType> {
-    match backpack_ws_message {
+) -> Result<Option<MarketDataType>, ExchangeError> {
+    Ok(match backpack_ws_message {
         crate::exchanges::backpack::types::BackpackWebSocketMessage::Ticker(ticker) => {
-            Some(MarketDataType::Ticker(convert_ws_ticker(ticker)))
+            Some(MarketDataType::Ticker(convert_ws_ticker(ticker)?))
         }
         crate::exchanges::backpack::types::BackpackWebSocketMessage::OrderBook(orderbook) => {
-            Some(MarketDataType::OrderBook(convert_ws_order_book(orderbook)))
+            Some(MarketDataType::OrderBook(convert_ws_order_book(orderbook)?))
         }
         crate::exchanges::backpack::types::BackpackWebSocketMessage::Trade(trade) => {
-            Some(MarketDataType::Trade(convert_ws_trade(trade)))
+            Some(MarketDataType::Trade(convert_ws_trade(trade)?))
         }
         crate::exchanges::backpack::types::BackpackWebSocketMessage::Kline(kline) => Some(
-            MarketDataType::Kline(convert_ws_kline(kline, "1m".to_string())),
+            MarketDataType::Kline(convert_ws_kline(kline, "1m".to_string())?),
         ),
         _ => None, // Ignore other message types
-    }
+    })
 }
@@ -12,6 +12,7 @@ pub use builder::{
     build_connector,
     build_connector_with_reconnection,
     build_connector_with_websocket,
+    build_public,
     // Legacy compatibility exports
     create_backpack_connector,
     create_backpack_connector_with_reconnection,
@@ -39,10 +40,11 @@ pub fn create_backpack_stream_identifiers(
                 crate::core::types::SubscriptionType::Ticker => {
                     streams.push(format!("ticker.{}", symbol));
                 }
-                crate::core::types::SubscriptionType::OrderBook { depth: _ } => {
+                crate::core::types::SubscriptionType::OrderBook { .. } => {
                     streams.push(format!("depth.{}", symbol));
                 }
-                crate::core::types::SubscriptionType::Trades => {
+                crate::core::types::SubscriptionType::Trades
+                | crate::core::types::SubscriptionType::AggTrades => {
                     streams.push(format!("trade.{}", symbol));
                 }
                 crate::core::types::SubscriptionType::Klines { interval } => {
@@ -52,6 +54,9 @@ pub fn create_backpack_stream_identifiers(
                         symbol
                     ));
                 }
+                crate::core::types::SubscriptionType::MarkPrice => {
+                    streams.push(format!("markPrice.{}", symbol));
+                }
             }
         }
     }
@@ -2,8 +2,8 @@ use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
 use crate::exchanges::backpack::types::{
     BackpackBalanceMap, BackpackDepthResponse, BackpackFill, BackpackFundingRate,
-    BackpackKlineResponse, BackpackMarketResponse, BackpackOrder, BackpackOrderResponse,
-    BackpackPositionResponse, BackpackTickerResponse, BackpackTradeResponse,
+    BackpackKlineResponse, BackpackMarkPrice, BackpackMarketResponse, BackpackOrder,
+    BackpackOrderResponse, BackpackPositionResponse, BackpackTickerResponse, BackpackTradeResponse,
 };
 use serde_json::Value;
 
@@ -17,6 +17,11 @@ impl<R: RestClient> BackpackRestClient<R> {
         Self { client }
     }
 
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.client.can_sign()
+    }
+
     /// Get all markets
     pub async fn get_markets(&self) -> Result<Vec<BackpackMarketResponse>, ExchangeError> {
         self.client.get_json("/api/v1/markets", &[], false).await
@@ -60,6 +65,11 @@ impl<R: RestClient> BackpackRestClient<R> {
         self.client.get_json("/api/v1/trades", &params, false).await
     }
 
+    /// Get server time in milliseconds
+    pub async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.client.get_json("/api/v1/time", &[], false).await
+    }
+
     /// Get klines/candlestick data
     pub async fn get_klines(
         &self,
@@ -87,38 +97,39 @@ impl<R: RestClient> BackpackRestClient<R> {
         self.client.get_json("/api/v1/klines", &params, false).await
     }
 
-    /// Get funding rates
-    pub async fn get_funding_rates(&self) -> Result<Vec<BackpackFundingRate>, ExchangeError> {
+    /// Get mark prices with the current interval's estimated funding rate
+    pub async fn get_mark_prices(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<Vec<BackpackMarkPrice>, ExchangeError> {
+        let params: Vec<(&str, &str)> = symbol.map(|s| ("symbol", s)).into_iter().collect();
         self.client
-            .get_json("/api/v1/funding/rates", &[], false)
+            .get_json("/api/v1/markPrices", &params, false)
             .await
     }
 
-    /// Get funding rate history
+    /// Get settled funding rates of `symbol`, newest first
+    ///
+    /// The endpoint pages by offset and takes no time range.
     pub async fn get_funding_rate_history(
         &self,
         symbol: &str,
-        start_time: Option<i64>,
-        end_time: Option<i64>,
         limit: Option<u32>,
+        offset: Option<u32>,
     ) -> Result<Vec<BackpackFundingRate>, ExchangeError> {
-        let start_str = start_time.map(|t| t.to_string());
-        let end_str = end_time.map(|t| t.to_string());
         let limit_str = limit.map(|l| l.to_string());
+        let offset_str = offset.map(|o| o.to_string());
         let mut params = vec![("symbol", symbol)];
 
-        if let Some(ref start) = start_str {
-            params.push(("startTime", start.as_str()));
-        }
-        if let Some(ref end) = end_str {
-            params.push(("endTime", end.as_str()));
-        }
         if let Some(ref limit) = limit_str {
             params.push(("limit", limit.as_str()));
         }
+        if let Some(ref offset) = offset_str {
+            params.push(("offset", offset.as_str()));
+        }
 
         self.client
-            .get_json("/api/v1/funding/rates/history", &params, false)
+            .get_json("/api/v1/fundingRates", &params, false)
             .await
     }
 
@@ -129,16 +140,20 @@ impl<R: RestClient> BackpackRestClient<R> {
 
     /// Get account positions (requires authentication)
     pub async fn get_positions(&self) -> Result<Vec<BackpackPositionResponse>, ExchangeError> {
-        self.client.get_json("/api/v1/positions", &[], true).await
+        self.client.get_json("/api/v1/position", &[], true).await
     }
 
-    /// Get order history (requires authentication)
+    /// Get the account's past orders, newest first (requires authentication)
+    ///
+    /// The endpoint pages by offset and takes no time range.
     pub async fn get_order_history(
         &self,
         symbol: Option<&str>,
         limit: Option<u32>,
+        offset: Option<u32>,
     ) -> Result<Vec<BackpackOrder>, ExchangeError> {
         let limit_str = limit.map(|l| l.to_string());
+        let offset_str = offset.map(|o| o.to_string());
         let mut params = vec![];
 
         if let Some(symbol) = symbol {
@@ -147,8 +162,13 @@ impl<R: RestClient> BackpackRestClient<R> {
         if let Some(ref limit) = limit_str {
             params.push(("limit", limit.as_str()));
         }
+        if let Some(ref offset) = offset_str {
+            params.push(("offset", offset.as_str()));
+        }
 
-        self.client.get_json("/api/v1/orders", &params, true).await
+        self.client
+            .get_json("/wapi/v1/history/orders", &params, true)
+            .await
     }
 
     /// Place an order (requires authentication)
@@ -170,7 +190,7 @@ impl<R: RestClient> BackpackRestClient<R> {
             params.push(("orderId", order_id.as_str()));
         }
         if let Some(client_order_id) = client_order_id {
-            params.push(("clientOrderId", client_order_id));
+            params.push(("clientId", client_order_id));
         }
 
         self.client
@@ -182,14 +202,24 @@ impl<R: RestClient> BackpackRestClient<R> {
     pub async fn get_fills(
         &self,
         symbol: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
         limit: Option<u32>,
     ) -> Result<Vec<BackpackFill>, ExchangeError> {
+        let start_str = start_time.map(|t| t.to_string());
+        let end_str = end_time.map(|t| t.to_string());
         let limit_str = limit.map(|l| l.to_string());
         let mut params = vec![];
 
         if let Some(symbol) = symbol {
             params.push(("symbol", symbol));
         }
+        if let Some(ref start) = start_str {
+            params.push(("from", start.as_str()));
+        }
+        if let Some(ref end) = end_str {
+            params.push(("to", end.as_str()));
+        }
         if let Some(ref limit) = limit_str {
             params.push(("limit", limit.as_str()));
         }
@@ -104,6 +104,26 @@ impl BackpackAuth {
         Ok(serde_json::to_string(&auth_message)?)
     }
 
+    /// Create the `signature` field of a private stream subscription
+    ///
+    /// Returns the verifying key, signature, timestamp and window, in that order.
+    pub fn create_websocket_signature(&self) -> Result<[String; 4], ExchangeError> {
+        let timestamp = Self::get_timestamp()?;
+        let window = 5000; // Default window in milliseconds
+        let signature = self.generate_signature("subscribe", "", timestamp, window)?;
+        let api_key = self
+            .verifying_key
+            .as_ref()
+            .ok_or_else(|| ExchangeError::AuthError("No verifying key available".to_string()))?;
+
+        Ok([
+            base64::engine::general_purpose::STANDARD.encode(api_key.to_bytes()),
+            signature,
+            timestamp.to_string(),
+            window.to_string(),
+        ])
+    }
+
     /// Create signed headers for REST API requests
     pub fn create_signed_headers(
         &self,
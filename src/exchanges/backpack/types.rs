@@ -83,13 +83,15 @@ pub struct BackpackTrade {
     pub is_best_match: Option<bool>,
 }
 
+/// Entry of `/api/v1/markPrices`; the funding rate is the estimate for the current interval
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct BackpackMarkPrice {
     pub symbol: String,
     pub mark_price: String,
     pub index_price: String,
-    pub estimated_funding_rate: String,
-    pub next_funding_time: i64,
+    pub funding_rate: String,
+    pub next_funding_timestamp: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -98,12 +100,13 @@ pub struct BackpackOpenInterest {
     pub open_interest: String,
 }
 
+/// Settled funding interval of `/api/v1/fundingRates`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct BackpackFundingRate {
     pub symbol: String,
     pub funding_rate: String,
-    pub funding_time: i64,
-    pub next_funding_time: i64,
+    pub interval_end_timestamp: String,
 }
 
 // Account Types
@@ -128,29 +131,34 @@ pub struct BackpackPosition {
     pub margin_type: String,
 }
 
+/// Entry of `/wapi/v1/history/orders`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct BackpackOrder {
-    pub order_id: i64,
-    pub client_order_id: Option<String>,
+    pub id: String,
+    #[serde(default)]
+    pub client_id: Option<u32>,
     pub symbol: String,
+    /// `Bid` or `Ask`
     pub side: String,
     pub order_type: String,
-    pub quantity: String,
+    #[serde(default)]
+    pub quantity: Option<String>,
+    #[serde(default)]
+    pub quote_quantity: Option<String>,
+    #[serde(default)]
     pub price: Option<String>,
-    pub stop_price: Option<String>,
-    pub time_in_force: String,
+    #[serde(default)]
+    pub trigger_price: Option<String>,
+    #[serde(default)]
+    pub time_in_force: Option<String>,
     pub status: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
-    pub avg_price: String,
-    pub created_time: i64,
-    pub updated_time: i64,
-    pub working_type: Option<String>,
-    pub price_protect: Option<bool>,
-    pub close_position: Option<bool>,
-    pub activation_price: Option<String>,
-    pub callback_rate: Option<String>,
-    pub realized_pnl: Option<String>,
+    #[serde(default)]
+    pub executed_quantity: Option<String>,
+    #[serde(default)]
+    pub executed_quote_quantity: Option<String>,
+    /// UTC time such as `2024-06-10T03:00:00.123`
+    pub created_at: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -166,6 +174,8 @@ pub struct BackpackFill {
     pub is_buyer: bool,
     pub is_maker: bool,
     pub is_best_match: Option<bool>,
+    #[serde(default)]
+    pub trade_id: Option<i64>,
 }
 
 // Request Types
@@ -340,6 +350,46 @@ pub struct BackpackWebSocketRFQUpdate {
     pub T: i64,            // Engine timestamp
 }
 
+/// Payload of the private `account.orderUpdate` stream; times are in microseconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct BackpackWebSocketOrderUpdate {
+    pub e: String,         // Event type: orderAccepted, orderFill, orderCancelled, ...
+    pub E: i64,            // Event time
+    pub s: String,         // Symbol
+    pub c: Option<u64>,    // Client ID
+    pub S: String,         // Side: Bid or Ask
+    pub q: Option<String>, // Quantity
+    pub p: Option<String>, // Price
+    pub X: String,         // Order status
+    pub i: String,         // Order ID
+    pub t: Option<i64>,    // Trade ID, fills only
+    pub l: Option<String>, // Fill quantity
+    pub z: String,         // Executed quantity
+    pub L: Option<String>, // Fill price
+    pub m: Option<bool>,   // Whether the fill was maker
+    pub n: Option<String>, // Fee
+    pub N: Option<String>, // Fee symbol
+    pub T: i64,            // Engine timestamp
+}
+
+/// Payload of the private `account.positionUpdate` stream; times are in microseconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct BackpackWebSocketPositionUpdate {
+    pub e: String,         // Event type: positionOpened, positionAdjusted, positionClosed
+    pub E: i64,            // Event time
+    pub s: String,         // Symbol
+    pub B: String,         // Entry price
+    pub f: String,         // Initial margin fraction
+    pub M: String,         // Mark price
+    pub q: String,         // Net quantity, negative when short
+    pub P: String,         // Unrealized PnL
+    pub l: Option<String>, // Estimated liquidation price
+    pub i: String,         // Position ID
+    pub T: i64,            // Engine timestamp
+}
+
 // Response Types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackpackOrderResponse {
@@ -508,6 +558,17 @@ pub struct BackpackPositionResponse {
     pub est_liquidation_price: String,
     #[serde(rename = "positionId")]
     pub position_id: String,
+    #[serde(rename = "breakEvenPrice", default)]
+    pub break_even_price: Option<String>,
+    /// Initial margin fraction, the inverse of the leverage
+    #[serde(default)]
+    pub imf: Option<String>,
+    #[serde(rename = "netExposureNotional", default)]
+    pub net_exposure_notional: Option<String>,
+    #[serde(rename = "pnlRealized", default)]
+    pub pnl_realized: Option<String>,
+    #[serde(rename = "cumulativeFundingPayment", default)]
+    pub cumulative_funding_payment: Option<String>,
 }
 
 // WebSocket Message Types
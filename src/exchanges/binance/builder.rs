@@ -3,27 +3,23 @@ use crate::core::errors::ExchangeError;
 use crate::core::kernel::{RestClientBuilder, RestClientConfig, TungsteniteWs};
 use crate::exchanges::binance::{
     codec::BinanceCodec, connector::BinanceConnector, signer::BinanceSigner,
+    types::binance_error_kind,
 };
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
 use std::sync::Arc;
 
 /// Create a Binance connector with REST-only support
 pub fn build_connector(
     config: ExchangeConfig,
 ) -> Result<BinanceConnector<crate::core::kernel::ReqwestRest, ()>, ExchangeError> {
-    // Determine base URL
-    let base_url = if config.testnet {
-        "https://testnet.binance.vision".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.binance.com".to_string())
-    };
+    let endpoints = resolve_endpoints(ExchangeId::Binance, &config)?;
 
     // Build REST client
-    let rest_config = RestClientConfig::new(base_url, "binance".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let rest_config = RestClientConfig::new(endpoints.rest, "binance".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone())
+        .with_error_kinds(binance_error_kind);
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -48,20 +44,13 @@ pub fn build_connector_with_websocket(
     BinanceConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<BinanceCodec>>,
     ExchangeError,
 > {
-    // Determine base URL
-    let base_url = if config.testnet {
-        "https://testnet.binance.vision".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.binance.com".to_string())
-    };
+    let endpoints = resolve_endpoints(ExchangeId::Binance, &config)?;
 
     // Build REST client
-    let rest_config = RestClientConfig::new(base_url, "binance".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let rest_config = RestClientConfig::new(endpoints.rest, "binance".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone())
+        .with_error_kinds(binance_error_kind);
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -77,17 +66,22 @@ pub fn build_connector_with_websocket(
     let rest = rest_builder.build()?;
 
     // Create WebSocket client
-    let ws_url = if config.testnet {
-        "wss://testnet.binance.vision/ws".to_string()
-    } else {
-        "wss://stream.binance.com:443/ws".to_string()
-    };
-
-    let ws = TungsteniteWs::new(ws_url, "binance".to_string(), BinanceCodec);
+    let ws = TungsteniteWs::new(endpoints.ws, "binance".to_string(), BinanceCodec)
+        .with_config(config.ws_config());
 
     Ok(BinanceConnector::new(rest, ws, config))
 }
 
+/// Create a Binance connector for public market data, ignoring any credentials in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<
+    BinanceConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<BinanceCodec>>,
+    ExchangeError,
+> {
+    build_connector_with_websocket(config.without_credentials())
+}
+
 /// Create a Binance connector with WebSocket and auto-reconnection support
 pub fn build_connector_with_reconnection(
     config: ExchangeConfig,
@@ -98,20 +92,13 @@ pub fn build_connector_with_reconnection(
     >,
     ExchangeError,
 > {
-    // Determine base URL
-    let base_url = if config.testnet {
-        "https://testnet.binance.vision".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.binance.com".to_string())
-    };
+    let endpoints = resolve_endpoints(ExchangeId::Binance, &config)?;
 
     // Build REST client
-    let rest_config = RestClientConfig::new(base_url, "binance".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let rest_config = RestClientConfig::new(endpoints.rest, "binance".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone())
+        .with_error_kinds(binance_error_kind);
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -127,16 +114,10 @@ pub fn build_connector_with_reconnection(
     let rest = rest_builder.build()?;
 
     // Create WebSocket client with auto-reconnection
-    let ws_url = if config.testnet {
-        "wss://testnet.binance.vision/ws".to_string()
-    } else {
-        "wss://stream.binance.com:443/ws".to_string()
-    };
-
-    let base_ws = TungsteniteWs::new(ws_url, "binance".to_string(), BinanceCodec);
+    let base_ws = TungsteniteWs::new(endpoints.ws, "binance".to_string(), BinanceCodec)
+        .with_config(config.ws_config());
     let reconnect_ws = crate::core::kernel::ReconnectWs::new(base_ws)
-        .with_max_reconnect_attempts(10)
-        .with_reconnect_delay(std::time::Duration::from_secs(2))
+        .with_profile(config.profile)
         .with_auto_resubscribe(true);
 
     Ok(BinanceConnector::new(rest, reconnect_ws, config))
@@ -1,5 +1,6 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::WsCodec;
+use crate::core::types::OrderBookMode;
 use serde_json::{json, Value};
 use tokio_tungstenite::tungstenite::Message;
 
@@ -9,6 +10,8 @@ pub enum BinanceMessage {
     OrderBook(super::types::BinanceWebSocketOrderBook),
     Trade(super::types::BinanceWebSocketTrade),
     Kline(super::types::BinanceWebSocketKline),
+    ExecutionReport(super::types::BinanceExecutionReport),
+    AccountPosition(super::types::BinanceAccountPosition),
     Unknown,
 }
 
@@ -43,6 +46,14 @@ impl WsCodec for BinanceCodec {
         Ok(Message::Text(unsubscription.to_string()))
     }
 
+    fn exchange_time(&self, message: &Self::Message) -> Option<i64> {
+        match message {
+            BinanceMessage::Ticker(ticker) => Some(ticker.close_time),
+            BinanceMessage::Trade(trade) => Some(trade.time),
+            _ => None,
+        }
+    }
+
     fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
         let text = match message {
             Message::Text(text) => text,
@@ -96,11 +107,28 @@ impl BinanceCodec {
                 })?;
             Ok(BinanceMessage::Ticker(ticker))
         } else if stream.contains("@depth") {
+            let parse_error = |e: serde_json::Error| {
+                ExchangeError::DeserializationError(format!("Failed to parse orderbook: {}", e))
+            };
+            // Partial depth snapshots carry neither an event type nor the symbol
+            if data.get("e").is_none() {
+                let partial: super::types::BinanceWebSocketPartialDepth =
+                    serde_json::from_value(data.clone()).map_err(parse_error)?;
+                let symbol = stream.split('@').next().unwrap_or_default();
+                return Ok(BinanceMessage::OrderBook(partial.into_order_book(symbol)));
+            }
             let orderbook: super::types::BinanceWebSocketOrderBook =
+                serde_json::from_value(data.clone()).map_err(parse_error)?;
+            Ok(BinanceMessage::OrderBook(orderbook))
+        } else if stream.contains("@aggTrade") {
+            let trade: super::types::BinanceWebSocketAggTrade =
                 serde_json::from_value(data.clone()).map_err(|e| {
-                    ExchangeError::DeserializationError(format!("Failed to parse orderbook: {}", e))
+                    ExchangeError::DeserializationError(format!(
+                        "Failed to parse aggregate trade: {}",
+                        e
+                    ))
                 })?;
-            Ok(BinanceMessage::OrderBook(orderbook))
+            Ok(BinanceMessage::Trade(trade.into_trade()))
         } else if stream.contains("@trade") {
             let trade: super::types::BinanceWebSocketTrade = serde_json::from_value(data.clone())
                 .map_err(|e| {
@@ -151,6 +179,16 @@ impl BinanceCodec {
                     })?;
                 Ok(BinanceMessage::Trade(trade))
             }
+            "aggTrade" => {
+                let trade: super::types::BinanceWebSocketAggTrade =
+                    serde_json::from_value(data.clone()).map_err(|e| {
+                        ExchangeError::DeserializationError(format!(
+                            "Failed to parse aggregate trade: {}",
+                            e
+                        ))
+                    })?;
+                Ok(BinanceMessage::Trade(trade.into_trade()))
+            }
             "kline" => {
                 let kline: super::types::BinanceWebSocketKline =
                     serde_json::from_value(data.clone()).map_err(|e| {
@@ -158,11 +196,34 @@ impl BinanceCodec {
                     })?;
                 Ok(BinanceMessage::Kline(kline))
             }
+            "executionReport" => {
+                let report: super::types::BinanceExecutionReport =
+                    serde_json::from_value(data.clone()).map_err(|e| {
+                        ExchangeError::DeserializationError(format!(
+                            "Failed to parse execution report: {}",
+                            e
+                        ))
+                    })?;
+                Ok(BinanceMessage::ExecutionReport(report))
+            }
+            "outboundAccountPosition" => {
+                let position: super::types::BinanceAccountPosition =
+                    serde_json::from_value(data.clone()).map_err(|e| {
+                        ExchangeError::DeserializationError(format!(
+                            "Failed to parse account position: {}",
+                            e
+                        ))
+                    })?;
+                Ok(BinanceMessage::AccountPosition(position))
+            }
             _ => Ok(BinanceMessage::Unknown),
         }
     }
 }
 
+/// Partial book depth streams offered by Binance
+const BINANCE_DEPTH_LEVELS: [u32; 3] = [5, 10, 20];
+
 /// Create Binance stream identifiers for WebSocket subscriptions
 pub fn create_binance_stream_identifiers(
     symbols: &[String],
@@ -177,16 +238,25 @@ pub fn create_binance_stream_identifiers(
                 crate::core::types::SubscriptionType::Ticker => {
                     streams.push(format!("{}@ticker", lower_symbol));
                 }
-                crate::core::types::SubscriptionType::OrderBook { depth } => {
-                    if let Some(d) = depth {
-                        streams.push(format!("{}@depth{}@100ms", lower_symbol, d));
-                    } else {
-                        streams.push(format!("{}@depth@100ms", lower_symbol));
-                    }
+                crate::core::types::SubscriptionType::OrderBook { depth, mode } => {
+                    let level = depth.map_or(20, |d| {
+                        crate::core::types::SubscriptionType::depth_level(d, &BINANCE_DEPTH_LEVELS)
+                    });
+                    streams.push(match mode {
+                        OrderBookMode::Snapshot => {
+                            format!("{}@depth{}@100ms", lower_symbol, level)
+                        }
+                        OrderBookMode::Delta => format!("{}@depth@100ms", lower_symbol),
+                        // Without a speed suffix partial books are pushed every 1000ms
+                        OrderBookMode::Aggregated => format!("{}@depth{}", lower_symbol, level),
+                    });
                 }
                 crate::core::types::SubscriptionType::Trades => {
                     streams.push(format!("{}@trade", lower_symbol));
                 }
+                crate::core::types::SubscriptionType::AggTrades => {
+                    streams.push(format!("{}@aggTrade", lower_symbol));
+                }
                 crate::core::types::SubscriptionType::Klines { interval } => {
                     streams.push(format!(
                         "{}@kline_{}",
@@ -194,6 +264,8 @@ pub fn create_binance_stream_identifiers(
                         interval.to_binance_format()
                     ));
                 }
+                // Spot has no mark price
+                crate::core::types::SubscriptionType::MarkPrice => {}
             }
         }
     }
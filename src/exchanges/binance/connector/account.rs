@@ -1,11 +1,21 @@
 use crate::core::{
     errors::ExchangeError,
     kernel::RestClient,
-    traits::AccountInfo,
-    types::{Balance, Position},
+    traits::{AccountInfo, AssetTransfer, BorrowRateSource, TradeHistorySource, WithdrawalSource},
+    types::{
+        conversion, AccountFill, AccountType, Balance, BorrowRate, InterestRecord, Position,
+        Symbol, WithdrawalRecord, WithdrawalRequest,
+    },
+};
+use crate::exchanges::binance::conversions::SYMBOLS;
+use crate::exchanges::binance::conversions::{
+    binance_transfer_type, convert_binance_borrow_rate, convert_binance_interest_record,
+    convert_binance_my_trade, convert_binance_withdraw_record,
 };
 use crate::exchanges::binance::rest::BinanceRestClient;
 use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde_json::json;
 use tracing::instrument;
 
 /// Account implementation for Binance
@@ -27,29 +37,26 @@ impl<R: RestClient> Account<R> {
 
 #[async_trait]
 impl<R: RestClient> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     #[instrument(skip(self), fields(exchange = "binance"))]
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         let account_info = self.rest.get_account_info().await?;
 
-        let balances = account_info
-            .balances
-            .into_iter()
-            .filter_map(|balance| {
-                // Parse balances safely without panicking
-                let free: f64 = balance.free.parse().unwrap_or(0.0);
-                let locked: f64 = balance.locked.parse().unwrap_or(0.0);
-
-                if free > 0.0 || locked > 0.0 {
-                    Some(Balance {
-                        asset: balance.asset,
-                        free: crate::core::types::conversion::string_to_quantity(&balance.free),
-                        locked: crate::core::types::conversion::string_to_quantity(&balance.locked),
-                    })
-                } else {
-                    None
-                }
-            })
-            .collect();
+        let mut balances = Vec::new();
+        for balance in account_info.balances {
+            let free = conversion::try_string_to_quantity(&balance.free)?;
+            let locked = conversion::try_string_to_quantity(&balance.locked)?;
+            if !free.value().is_zero() || !locked.value().is_zero() {
+                balances.push(Balance {
+                    asset: balance.asset,
+                    free,
+                    locked,
+                });
+            }
+        }
 
         Ok(balances)
     }
@@ -61,3 +68,116 @@ impl<R: RestClient> AccountInfo for Account<R> {
         Ok(vec![])
     }
 }
+
+#[async_trait]
+impl<R: RestClient> BorrowRateSource for Account<R> {
+    #[instrument(skip(self), fields(exchange = "binance"))]
+    async fn get_borrow_rates(&self, asset: &str) -> Result<Vec<BorrowRate>, ExchangeError> {
+        let rates = self.rest.get_next_hourly_interest_rate(asset).await?;
+        let now = chrono::Utc::now().timestamp_millis();
+        rates
+            .iter()
+            .map(|rate| convert_binance_borrow_rate(rate, now))
+            .collect()
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance"))]
+    async fn get_interest_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<InterestRecord>, ExchangeError> {
+        let history = self
+            .rest
+            .get_interest_history(asset, start_time, end_time, limit)
+            .await?;
+        history
+            .rows
+            .iter()
+            .map(convert_binance_interest_record)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> WithdrawalSource for Account<R> {
+    #[instrument(skip(self), fields(exchange = "binance"))]
+    async fn withdraw(&self, request: WithdrawalRequest) -> Result<String, ExchangeError> {
+        let mut body = json!({
+            "coin": request.asset,
+            "address": request.address,
+            "amount": request.amount.to_string(),
+        });
+        if let Some(network) = request.network {
+            body["network"] = json!(network);
+        }
+        if let Some(tag) = request.address_tag {
+            body["addressTag"] = json!(tag);
+        }
+
+        Ok(self.rest.withdraw(&body).await?.id)
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance"))]
+    async fn get_withdrawal_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<WithdrawalRecord>, ExchangeError> {
+        let records = self
+            .rest
+            .get_withdraw_history(asset, start_time, end_time, limit)
+            .await?;
+        records
+            .iter()
+            .map(convert_binance_withdraw_record)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> AssetTransfer for Account<R> {
+    #[instrument(skip(self), fields(exchange = "binance"))]
+    async fn transfer(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        from: AccountType,
+        to: AccountType,
+    ) -> Result<String, ExchangeError> {
+        let body = json!({
+            "type": binance_transfer_type(from, to)?,
+            "asset": asset,
+            "amount": amount.to_string(),
+        });
+
+        Ok(self.rest.transfer(&body).await?.tran_id.to_string())
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> TradeHistorySource for Account<R> {
+    #[instrument(skip(self), fields(exchange = "binance", symbol = %symbol))]
+    async fn get_my_trades(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AccountFill>, ExchangeError> {
+        let trades = self
+            .rest
+            .get_my_trades(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                start_time,
+                end_time,
+                limit,
+            )
+            .await?;
+        trades.iter().map(convert_binance_my_trade).collect()
+    }
+}
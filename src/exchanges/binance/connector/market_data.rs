@@ -1,32 +1,83 @@
 use crate::core::{
     errors::ExchangeError,
-    kernel::{RestClient, WsSession},
-    traits::MarketDataSource,
-    types::{Kline, KlineInterval, Market, MarketDataType, SubscriptionType, WebSocketConfig},
+    kernel::{RestClient, TungsteniteWs, WsConfig, WsSession},
+    streaming::{SharedHub, StreamRouter},
+    traits::{HistoricalTradeSource, MarketSnapshotSource, RestMarketData, StreamingMarketData},
+    types::{
+        Kline, KlineInterval, Market, MarketDataType, OrderBook, SubscriptionType, Symbol, Ticker,
+        Trade, WebSocketConfig,
+    },
 };
+use crate::exchanges::binance::conversions::SYMBOLS;
 use crate::exchanges::binance::{
-    codec::{BinanceCodec, BinanceMessage},
-    conversions::{convert_binance_market, convert_binance_rest_kline},
+    codec::{create_binance_stream_identifiers, BinanceCodec, BinanceMessage},
+    conversions::{
+        convert_binance_agg_trade, convert_binance_market, convert_binance_rest_kline,
+        convert_binance_rest_order_book, convert_binance_rest_ticker, convert_binance_rest_trade,
+    },
     rest::BinanceRestClient,
 };
+use crate::exchanges::factory::ExchangeId;
 use async_trait::async_trait;
 use tokio::sync::mpsc;
 
+/// Routes Binance combined-stream updates
+struct BinanceRouter;
+
+impl StreamRouter for BinanceRouter {
+    type Codec = BinanceCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        Ok(create_binance_stream_identifiers(
+            &[SYMBOLS.try_to_exchange_symbol(symbol)?],
+            std::slice::from_ref(subscription),
+        )
+        .pop())
+    }
+
+    fn convert(&self, message: BinanceMessage) -> Vec<MarketDataType> {
+        match convert_binance_message_to_market_data(message) {
+            Ok(update) => update.into_iter().collect(),
+            Err(e) => {
+                tracing::warn!(error = %e, "Dropping malformed Binance message");
+                Vec::new()
+            }
+        }
+    }
+}
+
 /// Market data implementation for Binance
 pub struct MarketData<R: RestClient, W = ()> {
     rest: BinanceRestClient<R>,
-    #[allow(dead_code)] // May be used for future WebSocket functionality
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
     ws: Option<W>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
     testnet: bool,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
 }
 
 impl<R: RestClient + Clone, W> MarketData<R, W> {
     fn ws_url(&self) -> String {
-        if self.testnet {
-            "wss://testnet.binance.vision/ws".to_string()
-        } else {
-            "wss://stream.binance.com:443/ws".to_string()
-        }
+        let endpoints = ExchangeId::Binance.endpoints();
+        endpoints
+            .network(self.testnet)
+            .unwrap_or(endpoints.mainnet)
+            .ws
+            .to_string()
     }
 }
 
@@ -36,7 +87,9 @@ impl<R: RestClient + Clone, W: WsSession<BinanceCodec>> MarketData<R, W> {
         Self {
             rest: BinanceRestClient::new(rest.clone()),
             ws,
+            hub: SharedHub::new(),
             testnet,
+            ws_config: WsConfig::default(),
         }
     }
 }
@@ -47,108 +100,38 @@ impl<R: RestClient + Clone> MarketData<R, ()> {
         Self {
             rest: BinanceRestClient::new(rest.clone()),
             ws: None,
+            hub: SharedHub::new(),
             testnet,
+            ws_config: WsConfig::default(),
         }
     }
 }
 
 #[async_trait]
-impl<R: RestClient + Clone, W: WsSession<BinanceCodec>> MarketDataSource for MarketData<R, W> {
+impl<R: RestClient + Clone, W: WsSession<BinanceCodec>> RestMarketData for MarketData<R, W> {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         let exchange_info = self.rest.get_exchange_info().await?;
         let markets = exchange_info
             .symbols
             .into_iter()
             .map(convert_binance_market)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| ExchangeError::Other(format!("Failed to convert market: {}", e)))?;
+            .collect::<Result<Vec<_>, _>>()?;
         Ok(markets)
     }
 
-    async fn subscribe_market_data(
-        &self,
-        symbols: Vec<String>,
-        subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        // Use the codec helper to create stream identifiers
-        let streams = crate::exchanges::binance::codec::create_binance_stream_identifiers(
-            &symbols,
-            &subscription_types,
-        );
-
-        // Create WebSocket URL
-        let ws_url = self.ws_url();
-        let full_url = build_binance_stream_url(&ws_url, &streams);
-
-        // Use kernel WebSocket implementation
-        let codec = crate::exchanges::binance::codec::BinanceCodec;
-        let ws_session =
-            crate::core::kernel::ws::TungsteniteWs::new(full_url, "binance".to_string(), codec);
-
-        // Add reconnection wrapper for production reliability
-        let mut reconnect_ws = crate::core::kernel::ws::ReconnectWs::new(ws_session)
-            .with_auto_resubscribe(true)
-            .with_max_reconnect_attempts(u32::MAX);
-
-        // Connect and subscribe
-        reconnect_ws.connect().await.map_err(|e| {
-            ExchangeError::Other(format!(
-                "Failed to connect to WebSocket for symbols: {:?}, error: {}",
-                symbols, e
-            ))
-        })?;
-
-        if !streams.is_empty() {
-            let stream_refs: Vec<&str> = streams.iter().map(|s| s.as_str()).collect();
-            reconnect_ws.subscribe(&stream_refs).await.map_err(|e| {
-                ExchangeError::Other(format!(
-                    "Failed to subscribe to streams: {:?}, error: {}",
-                    streams, e
-                ))
-            })?;
-        }
-
-        // Create channel for messages
-        let (tx, rx) = mpsc::channel(1000);
-
-        // Spawn task to handle messages
-        tokio::spawn(async move {
-            while let Some(result) = reconnect_ws.next_message().await {
-                match result {
-                    Ok(binance_message) => {
-                        // Convert BinanceMessage to MarketDataType
-                        if let Some(market_data) =
-                            convert_binance_message_to_market_data(binance_message)
-                        {
-                            if tx.send(market_data).await.is_err() {
-                                break; // Receiver dropped
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("WebSocket error: {:?}", e);
-                        // Continue processing to handle reconnection
-                    }
-                }
-            }
-        });
-
-        Ok(rx)
-    }
-
-    fn get_websocket_url(&self) -> String {
-        self.ws_url()
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
     }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let klines = self
             .rest
             .get_klines(&symbol, interval, limit, start_time, end_time)
@@ -157,48 +140,67 @@ impl<R: RestClient + Clone, W: WsSession<BinanceCodec>> MarketDataSource for Mar
         let converted_klines = klines
             .into_iter()
             .map(|k| convert_binance_rest_kline(&k, &symbol, &interval.to_string()))
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         Ok(converted_klines)
     }
 }
 
 #[async_trait]
-impl<R: RestClient + Clone> MarketDataSource for MarketData<R, ()> {
+impl<R: RestClient + Clone, W: WsSession<BinanceCodec>> StreamingMarketData for MarketData<R, W> {
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(
+                        combined_stream_url(&self.ws_url()),
+                        "binance".to_string(),
+                        BinanceCodec,
+                    )
+                    .with_config(self.ws_config.clone())
+                },
+                BinanceRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.ws_url()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone> RestMarketData for MarketData<R, ()> {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         let exchange_info = self.rest.get_exchange_info().await?;
         let markets = exchange_info
             .symbols
             .into_iter()
             .map(convert_binance_market)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| ExchangeError::Other(format!("Failed to convert market: {}", e)))?;
+            .collect::<Result<Vec<_>, _>>()?;
         Ok(markets)
     }
 
-    async fn subscribe_market_data(
-        &self,
-        _symbols: Vec<String>,
-        _subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        Err(ExchangeError::WebSocketError(
-            "WebSocket not available in REST-only mode".to_string(),
-        ))
-    }
-
-    fn get_websocket_url(&self) -> String {
-        self.ws_url()
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
     }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let klines = self
             .rest
             .get_klines(&symbol, interval, limit, start_time, end_time)
@@ -207,41 +209,121 @@ impl<R: RestClient + Clone> MarketDataSource for MarketData<R, ()> {
         let converted_klines = klines
             .into_iter()
             .map(|k| convert_binance_rest_kline(&k, &symbol, &interval.to_string()))
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         Ok(converted_klines)
     }
 }
 
-/// Helper function to build Binance WebSocket URLs for combined streams
-fn build_binance_stream_url(base_url: &str, streams: &[String]) -> String {
-    if streams.is_empty() {
-        return base_url.to_string();
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> MarketSnapshotSource for MarketData<R, W> {
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let ticker = self
+            .rest
+            .get_ticker(&SYMBOLS.try_to_exchange_symbol(&symbol)?)
+            .await?;
+        convert_binance_rest_ticker(&ticker)
     }
 
-    // For combined streams, Binance expects /ws/stream?streams=...
-    let base = base_url
-        .strip_suffix("/ws")
-        .map_or(base_url, |stripped| stripped);
-    format!("{}/stream?streams={}", base, streams.join("/"))
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let book = self.rest.get_order_book(&symbol, depth).await?;
+        Ok(convert_binance_rest_order_book(&book, &symbol)?.truncated(depth))
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        self.rest
+            .get_recent_trades(&symbol, limit)
+            .await?
+            .iter()
+            .map(|trade| convert_binance_rest_trade(trade, &symbol))
+            .collect()
+    }
+}
+
+/// Longest `startTime`..`endTime` span `/api/v3/aggTrades` accepts
+const AGG_TRADES_WINDOW_MS: i64 = 60 * 60 * 1000;
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> HistoricalTradeSource for MarketData<R, W> {
+    /// Walks the range an hour at a time until the first trade, then pages by
+    /// aggregate id
+    async fn get_historical_trades(
+        &self,
+        symbol: Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let mut trades = Vec::new();
+
+        let mut window_start = start_time;
+        let mut page = loop {
+            if window_start > end_time {
+                return Ok(trades);
+            }
+            let window_end = (window_start + AGG_TRADES_WINDOW_MS - 1).min(end_time);
+            let page = self
+                .rest
+                .get_agg_trades(&symbol, None, Some(window_start), Some(window_end))
+                .await?;
+            if !page.is_empty() {
+                break page;
+            }
+            window_start = window_end + 1;
+        };
+
+        while let Some(last) = page.last() {
+            let next_id = last.aggregate_id + 1;
+            for trade in &page {
+                if trade.time > end_time {
+                    return Ok(trades);
+                }
+                trades.push(convert_binance_agg_trade(trade, &symbol)?);
+            }
+            page = self
+                .rest
+                .get_agg_trades(&symbol, Some(next_id), None, None)
+                .await?;
+        }
+
+        Ok(trades)
+    }
+}
+
+/// Combined-stream endpoint, whose messages name the stream they came from
+fn combined_stream_url(ws_url: &str) -> String {
+    format!("{}/stream", ws_url.strip_suffix("/ws").unwrap_or(ws_url))
 }
 
 /// Convert `BinanceMessage` to `MarketDataType`
-fn convert_binance_message_to_market_data(message: BinanceMessage) -> Option<MarketDataType> {
+fn convert_binance_message_to_market_data(
+    message: BinanceMessage,
+) -> Result<Option<MarketDataType>, ExchangeError> {
     use crate::core::types::conversion;
 
     match message {
         BinanceMessage::Ticker(ticker) => {
-            let symbol = conversion::string_to_symbol(&ticker.symbol);
-            let price = conversion::string_to_price(&ticker.price);
-            let price_change = conversion::string_to_price(&ticker.price_change);
-            let price_change_percent = conversion::string_to_decimal(&ticker.price_change_percent);
-            let high_price = conversion::string_to_price(&ticker.high_price);
-            let low_price = conversion::string_to_price(&ticker.low_price);
-            let volume = conversion::string_to_volume(&ticker.volume);
-            let quote_volume = conversion::string_to_volume(&ticker.quote_volume);
-
-            Some(MarketDataType::Ticker(crate::core::types::Ticker {
+            let symbol = SYMBOLS.from_exchange_symbol(&ticker.symbol);
+            let price = conversion::try_string_to_price(&ticker.price)?;
+            let price_change = conversion::try_string_to_price(&ticker.price_change)?;
+            let price_change_percent =
+                conversion::try_string_to_decimal(&ticker.price_change_percent)?;
+            let high_price = conversion::try_string_to_price(&ticker.high_price)?;
+            let low_price = conversion::try_string_to_price(&ticker.low_price)?;
+            let volume = conversion::try_string_to_volume(&ticker.volume)?;
+            let quote_volume = conversion::try_string_to_volume(&ticker.quote_volume)?;
+
+            Ok(Some(MarketDataType::Ticker(crate::core::types::Ticker {
                 symbol,
                 price,
                 price_change,
@@ -253,58 +335,64 @@ fn convert_binance_message_to_market_data(message: BinanceMessage) -> Option<Mar
                 open_time: ticker.open_time,
                 close_time: ticker.close_time,
                 count: ticker.count,
-            }))
+            })))
         }
         BinanceMessage::OrderBook(orderbook) => {
-            let symbol = conversion::string_to_symbol(&orderbook.symbol);
+            let symbol = SYMBOLS.from_exchange_symbol(&orderbook.symbol);
 
             let bids = orderbook
                 .bids
                 .iter()
-                .map(|bid| crate::core::types::OrderBookEntry {
-                    price: conversion::string_to_price(&bid[0]),
-                    quantity: conversion::string_to_quantity(&bid[1]),
+                .map(|bid| {
+                    Ok(crate::core::types::OrderBookEntry {
+                        price: conversion::try_string_to_price(&bid[0])?,
+                        quantity: conversion::try_string_to_quantity(&bid[1])?,
+                    })
                 })
-                .collect();
+                .collect::<Result<_, ExchangeError>>()?;
             let asks = orderbook
                 .asks
                 .iter()
-                .map(|ask| crate::core::types::OrderBookEntry {
-                    price: conversion::string_to_price(&ask[0]),
-                    quantity: conversion::string_to_quantity(&ask[1]),
+                .map(|ask| {
+                    Ok(crate::core::types::OrderBookEntry {
+                        price: conversion::try_string_to_price(&ask[0])?,
+                        quantity: conversion::try_string_to_quantity(&ask[1])?,
+                    })
                 })
-                .collect();
-
-            Some(MarketDataType::OrderBook(crate::core::types::OrderBook {
-                symbol,
-                bids,
-                asks,
-                last_update_id: orderbook.final_update_id,
-            }))
+                .collect::<Result<_, ExchangeError>>()?;
+
+            Ok(Some(MarketDataType::OrderBook(
+                crate::core::types::OrderBook {
+                    symbol,
+                    bids,
+                    asks,
+                    last_update_id: orderbook.final_update_id,
+                },
+            )))
         }
         BinanceMessage::Trade(trade) => {
-            let symbol = conversion::string_to_symbol(&trade.symbol);
-            let price = conversion::string_to_price(&trade.price);
-            let quantity = conversion::string_to_quantity(&trade.quantity);
+            let symbol = SYMBOLS.from_exchange_symbol(&trade.symbol);
+            let price = conversion::try_string_to_price(&trade.price)?;
+            let quantity = conversion::try_string_to_quantity(&trade.quantity)?;
 
-            Some(MarketDataType::Trade(crate::core::types::Trade {
+            Ok(Some(MarketDataType::Trade(crate::core::types::Trade {
                 symbol,
                 id: trade.id,
                 price,
                 quantity,
                 time: trade.time,
                 is_buyer_maker: trade.is_buyer_maker,
-            }))
+            })))
         }
         BinanceMessage::Kline(kline) => {
-            let symbol = conversion::string_to_symbol(&kline.symbol);
-            let open_price = conversion::string_to_price(&kline.kline.open_price);
-            let high_price = conversion::string_to_price(&kline.kline.high_price);
-            let low_price = conversion::string_to_price(&kline.kline.low_price);
-            let close_price = conversion::string_to_price(&kline.kline.close_price);
-            let volume = conversion::string_to_volume(&kline.kline.volume);
-
-            Some(MarketDataType::Kline(crate::core::types::Kline {
+            let symbol = SYMBOLS.from_exchange_symbol(&kline.symbol);
+            let open_price = conversion::try_string_to_price(&kline.kline.open_price)?;
+            let high_price = conversion::try_string_to_price(&kline.kline.high_price)?;
+            let low_price = conversion::try_string_to_price(&kline.kline.low_price)?;
+            let close_price = conversion::try_string_to_price(&kline.kline.close_price)?;
+            let volume = conversion::try_string_to_volume(&kline.kline.volume)?;
+
+            Ok(Some(MarketDataType::Kline(crate::core::types::Kline {
                 symbol,
                 open_time: kline.kline.open_time,
                 close_time: kline.kline.close_time,
@@ -316,8 +404,11 @@ fn convert_binance_message_to_market_data(message: BinanceMessage) -> Option<Mar
                 volume,
                 number_of_trades: kline.kline.number_of_trades,
                 final_bar: kline.kline.final_bar,
-            }))
+            })))
         }
-        BinanceMessage::Unknown => None,
+        // Private events only arrive on user data streams
+        BinanceMessage::ExecutionReport(_)
+        | BinanceMessage::AccountPosition(_)
+        | BinanceMessage::Unknown => Ok(None),
     }
 }
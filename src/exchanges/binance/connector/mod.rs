@@ -1,27 +1,40 @@
 use crate::core::errors::ExchangeError;
-use crate::core::traits::{AccountInfo, MarketDataSource, OrderPlacer};
+use crate::core::traits::{
+    AccountInfo, AssetTransfer, BorrowRateSource, DiagnosticsSource, HistoricalTradeSource,
+    MarketSnapshotSource, OrderHistorySource, OrderPlacer, OrderQuery, RestMarketData,
+    StreamingMarketData, TradeHistorySource, UserDataStream, WithdrawalSource,
+};
 use crate::core::types::{
-    Balance, Kline, KlineInterval, Market, MarketDataType, OrderRequest, OrderResponse, Position,
-    SubscriptionType, WebSocketConfig,
+    AccountEvent, AccountFill, AccountType, Balance, BorrowRate, InterestRecord, Kline,
+    KlineInterval, Market, MarketDataType, Order, OrderBook, OrderPage, OrderRequest,
+    OrderResponse, Position, SubscriptionType, Symbol, Ticker, Trade, UserDataChannel,
+    WebSocketConfig, WithdrawalRecord, WithdrawalRequest,
+};
+use crate::core::{
+    config::ExchangeConfig, kernel::diagnostics, kernel::Diagnostics, kernel::RestClient,
+    kernel::WsSession,
 };
-use crate::core::{config::ExchangeConfig, kernel::RestClient, kernel::WsSession};
 use crate::exchanges::binance::codec::BinanceCodec;
 use async_trait::async_trait;
+use rust_decimal::Decimal;
 use tokio::sync::mpsc;
 
 pub mod account;
 pub mod market_data;
 pub mod trading;
+pub mod user_data;
 
 pub use account::Account;
 pub use market_data::MarketData;
 pub use trading::Trading;
+pub use user_data::UserData;
 
 /// Binance connector that composes all sub-trait implementations
 pub struct BinanceConnector<R: RestClient, W = ()> {
     pub market: MarketData<R, W>,
     pub trading: Trading<R>,
     pub account: Account<R>,
+    pub user_data: UserData<R>,
 }
 
 impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinanceCodec> + Send + Sync>
@@ -30,9 +43,11 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinanceCodec> + Send + Sy
     /// Create a new Binance connector with WebSocket support
     pub fn new(rest: R, ws: W, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::<R, W>::new(&rest, Some(ws), config.testnet),
+            market: MarketData::<R, W>::new(&rest, Some(ws), config.testnet)
+                .with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
+            user_data: UserData::new(&rest, config.testnet).with_ws_config(config.ws_config()),
         }
     }
 }
@@ -41,9 +56,11 @@ impl<R: RestClient + Clone + Send + Sync> BinanceConnector<R, ()> {
     /// Create a new Binance connector without WebSocket support
     pub fn new_without_ws(rest: R, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::<R, ()>::new(&rest, None, config.testnet),
+            market: MarketData::<R, ()>::new(&rest, None, config.testnet)
+                .with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
+            user_data: UserData::new(&rest, config.testnet).with_ws_config(config.ws_config()),
         }
     }
 }
@@ -51,16 +68,38 @@ impl<R: RestClient + Clone + Send + Sync> BinanceConnector<R, ()> {
 // Implement traits for the connector by delegating to sub-components
 
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinanceCodec> + Send + Sync> MarketDataSource
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinanceCodec> + Send + Sync> RestMarketData
     for BinanceConnector<R, W>
 {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         self.market.get_markets().await
     }
 
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        self.market
+            .get_klines(symbol, interval, limit, start_time, end_time)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinanceCodec> + Send + Sync>
+    StreamingMarketData for BinanceConnector<R, W>
+{
     async fn subscribe_market_data(
         &self,
-        symbols: Vec<String>,
+        symbols: Vec<Symbol>,
         subscription_types: Vec<SubscriptionType>,
         config: Option<WebSocketConfig>,
     ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
@@ -72,10 +111,21 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinanceCodec> + Send + Sy
     fn get_websocket_url(&self) -> String {
         self.market.get_websocket_url()
     }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> RestMarketData for BinanceConnector<R, ()> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.market.get_markets().await
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
+    }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
@@ -88,53 +138,105 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinanceCodec> + Send + Sy
 }
 
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync> MarketDataSource for BinanceConnector<R, ()> {
-    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
-        self.market.get_markets().await
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for BinanceConnector<R, W>
+{
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        self.market.get_ticker(symbol).await
     }
 
-    async fn subscribe_market_data(
+    async fn get_order_book_snapshot(
         &self,
-        _symbols: Vec<String>,
-        _subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        Err(ExchangeError::WebSocketError(
-            "WebSocket not available in REST-only mode".to_string(),
-        ))
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        self.market.get_order_book_snapshot(symbol, depth).await
     }
 
-    fn get_websocket_url(&self) -> String {
-        self.market.get_websocket_url()
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        self.market.get_recent_trades(symbol, limit).await
     }
+}
 
-    async fn get_klines(
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> HistoricalTradeSource
+    for BinanceConnector<R, W>
+{
+    async fn get_historical_trades(
         &self,
-        symbol: String,
-        interval: KlineInterval,
-        limit: Option<u32>,
-        start_time: Option<i64>,
-        end_time: Option<i64>,
-    ) -> Result<Vec<Kline>, ExchangeError> {
+        symbol: Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<Trade>, ExchangeError> {
         self.market
-            .get_klines(symbol, interval, limit, start_time, end_time)
+            .get_historical_trades(symbol, start_time, end_time)
             .await
     }
 }
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderPlacer for BinanceConnector<R, W> {
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
         self.trading.place_order(order).await
     }
 
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
         self.trading.cancel_order(symbol, order_id).await
     }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderQuery for BinanceConnector<R, W> {
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        self.trading.get_open_orders(symbol).await
+    }
+
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        self.trading.get_order(symbol, order_id).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderHistorySource
+    for BinanceConnector<R, W>
+{
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        self.trading
+            .get_order_history(symbol, start_time, end_time, cursor)
+            .await
+    }
 }
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo for BinanceConnector<R, W> {
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         self.account.get_account_balance().await
     }
@@ -143,3 +245,93 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo for Binanc
         self.account.get_positions().await
     }
 }
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> BorrowRateSource
+    for BinanceConnector<R, W>
+{
+    async fn get_borrow_rates(&self, asset: &str) -> Result<Vec<BorrowRate>, ExchangeError> {
+        self.account.get_borrow_rates(asset).await
+    }
+
+    async fn get_interest_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<InterestRecord>, ExchangeError> {
+        self.account
+            .get_interest_history(asset, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> WithdrawalSource
+    for BinanceConnector<R, W>
+{
+    async fn withdraw(&self, request: WithdrawalRequest) -> Result<String, ExchangeError> {
+        self.account.withdraw(request).await
+    }
+
+    async fn get_withdrawal_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<WithdrawalRecord>, ExchangeError> {
+        self.account
+            .get_withdrawal_history(asset, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AssetTransfer for BinanceConnector<R, W> {
+    async fn transfer(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        from: AccountType,
+        to: AccountType,
+    ) -> Result<String, ExchangeError> {
+        self.account.transfer(asset, amount, from, to).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> TradeHistorySource
+    for BinanceConnector<R, W>
+{
+    async fn get_my_trades(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AccountFill>, ExchangeError> {
+        self.account
+            .get_my_trades(symbol, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync> UserDataStream
+    for BinanceConnector<R, W>
+{
+    async fn subscribe_user_data(
+        &self,
+        channels: Vec<UserDataChannel>,
+    ) -> Result<mpsc::Receiver<AccountEvent>, ExchangeError> {
+        self.user_data.subscribe_user_data(channels).await
+    }
+}
+
+impl<R: RestClient, W> DiagnosticsSource for BinanceConnector<R, W> {
+    fn diagnostics(&self) -> Diagnostics {
+        diagnostics("binance")
+    }
+}
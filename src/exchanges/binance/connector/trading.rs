@@ -1,10 +1,13 @@
 use crate::core::{
     errors::ExchangeError,
     kernel::RestClient,
-    traits::OrderPlacer,
-    types::{OrderRequest, OrderResponse, OrderSide, OrderType, TimeInForce},
+    traits::{OrderHistorySource, OrderPlacer, OrderQuery},
+    types::{
+        Order, OrderPage, OrderRequest, OrderResponse, OrderSide, OrderType, Symbol, TimeInForce,
+    },
 };
-use crate::exchanges::binance::rest::BinanceRestClient;
+use crate::exchanges::binance::conversions::SYMBOLS;
+use crate::exchanges::binance::{conversions::convert_binance_order, rest::BinanceRestClient};
 use async_trait::async_trait;
 use serde_json::json;
 use tracing::instrument;
@@ -80,11 +83,15 @@ fn string_to_order_type(s: &str) -> OrderType {
 
 #[async_trait]
 impl<R: RestClient> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     #[instrument(skip(self), fields(exchange = "binance"))]
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
         // Convert core OrderRequest to JSON for Binance API
         let mut order_json = json!({
-            "symbol": order.symbol.as_str(),
+            "symbol": SYMBOLS.try_to_exchange_symbol(&order.symbol)?,
             "side": order_side_to_string(&order.side),
             "type": order_type_to_string(&order.order_type),
             "quantity": order.quantity.to_string(),
@@ -105,26 +112,32 @@ impl<R: RestClient> OrderPlacer for Trading<R> {
             order_json["stopPrice"] = json!(stop_price.to_string());
         }
 
+        if let Some(client_order_id) = order.client_order_id {
+            order_json["newClientOrderId"] = json!(client_order_id);
+        }
+
         let response = self.rest.place_order(&order_json).await?;
 
         // Convert Binance response to core OrderResponse
         Ok(OrderResponse {
             order_id: response.order_id.to_string(),
             client_order_id: response.client_order_id,
-            symbol: crate::core::types::conversion::string_to_symbol(&response.symbol),
+            symbol: SYMBOLS.from_exchange_symbol(&response.symbol),
             side: string_to_order_side(&response.side),
             order_type: string_to_order_type(&response.order_type),
-            quantity: crate::core::types::conversion::string_to_quantity(&response.quantity),
-            price: Some(crate::core::types::conversion::string_to_price(
+            quantity: crate::core::types::conversion::try_string_to_quantity(&response.quantity)?,
+            price: Some(crate::core::types::conversion::try_string_to_price(
                 &response.price,
-            )),
+            )?),
             status: response.status,
             timestamp: response.timestamp as i64,
+            extensions: None,
         })
     }
 
     #[instrument(skip(self), fields(exchange = "binance", symbol = %symbol, order_id = %order_id))]
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let order_id_u64: u64 = order_id
             .parse()
             .map_err(|_| ExchangeError::Other(format!("Invalid order ID format: {}", order_id)))?;
@@ -133,4 +146,97 @@ impl<R: RestClient> OrderPlacer for Trading<R> {
             .await?;
         Ok(())
     }
+
+    #[instrument(skip(self), fields(exchange = "binance", symbol = %symbol, client_order_id = %client_order_id))]
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.rest
+            .cancel_order(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                None,
+                Some(&client_order_id),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> OrderQuery for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "binance"))]
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        let symbol = symbol
+            .map(|s| SYMBOLS.try_to_exchange_symbol(&s))
+            .transpose()?;
+        self.rest
+            .get_open_orders(symbol.as_deref())
+            .await?
+            .iter()
+            .map(convert_binance_order)
+            .collect()
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance", symbol = %symbol, order_id = %order_id))]
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        let order_id: u64 = order_id
+            .parse()
+            .map_err(|_| ExchangeError::Other(format!("Invalid order ID format: {}", order_id)))?;
+        let order = self
+            .rest
+            .get_order(&SYMBOLS.try_to_exchange_symbol(&symbol)?, order_id)
+            .await?;
+        convert_binance_order(&order)
+    }
+}
+
+/// Largest page `allOrders` returns
+const ORDER_HISTORY_LIMIT: u32 = 1000;
+
+#[async_trait]
+impl<R: RestClient> OrderHistorySource for Trading<R> {
+    /// Pages run oldest first; the cursor is the next order id to fetch
+    #[instrument(skip(self), fields(exchange = "binance", symbol = %symbol))]
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        let from_id = cursor
+            .map(|cursor| {
+                cursor.parse::<u64>().map_err(|_| {
+                    ExchangeError::InvalidParameters(format!("Invalid cursor: {}", cursor))
+                })
+            })
+            .transpose()?;
+        // An order id cursor replaces the start of the range
+        let start_time = start_time.filter(|_| from_id.is_none());
+        let raw = self
+            .rest
+            .get_all_orders(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                from_id,
+                start_time,
+                end_time,
+                ORDER_HISTORY_LIMIT,
+            )
+            .await?;
+
+        let next_cursor = (raw.len() == ORDER_HISTORY_LIMIT as usize)
+            .then(|| raw.last().map(|order| (order.order_id + 1).to_string()))
+            .flatten();
+        let orders = raw
+            .iter()
+            .map(convert_binance_order)
+            .filter(|order| order.as_ref().map_or(true, |order| !order.status.is_open()))
+            .collect::<Result<_, _>>()?;
+        Ok(OrderPage {
+            orders,
+            next_cursor,
+        })
+    }
 }
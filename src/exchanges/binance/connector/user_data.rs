@@ -0,0 +1,127 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::{ReconnectWs, RestClient, TungsteniteWs, WsConfig, WsControl, WsSession},
+    traits::UserDataStream,
+    types::{AccountEvent, UserDataChannel},
+};
+use crate::exchanges::binance::codec::{BinanceCodec, BinanceMessage};
+use crate::exchanges::binance::conversions::{
+    convert_binance_account_position, convert_binance_execution_report,
+};
+use crate::exchanges::binance::rest::BinanceRestClient;
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Listen keys expire after 60 minutes without a keepalive
+const LISTEN_KEY_KEEPALIVE: Duration = Duration::from_secs(30 * 60);
+
+/// User data stream implementation for Binance
+pub struct UserData<R: RestClient> {
+    rest: R,
+    testnet: bool,
+    ws_config: WsConfig,
+    control: WsControl,
+}
+
+impl<R: RestClient + Clone> UserData<R> {
+    pub fn new(rest: &R, testnet: bool) -> Self {
+        Self {
+            rest: rest.clone(),
+            testnet,
+            ws_config: WsConfig::default(),
+            control: WsControl::default(),
+        }
+    }
+
+    /// Open the stream with `config`, e.g. to route it through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
+
+    /// Control info of the most recently opened user data stream
+    pub fn control(&self) -> WsControl {
+        self.control.clone()
+    }
+
+    fn ws_url(&self) -> &'static str {
+        if self.testnet {
+            "wss://testnet.binance.vision/ws"
+        } else {
+            "wss://stream.binance.com:443/ws"
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + 'static> UserDataStream for UserData<R> {
+    async fn subscribe_user_data(
+        &self,
+        channels: Vec<UserDataChannel>,
+    ) -> Result<mpsc::Receiver<AccountEvent>, ExchangeError> {
+        let rest = BinanceRestClient::new(self.rest.clone());
+        let listen_key = rest.create_listen_key().await?.listen_key;
+
+        // The listen key in the URL authenticates the stream, so reconnects need no login
+        let url = format!("{}/{}", self.ws_url(), listen_key);
+        let ws_session = TungsteniteWs::new(url, "binance".to_string(), BinanceCodec)
+            .with_config(self.ws_config.clone())
+            .with_control(self.control.clone());
+        let mut reconnect_ws = ReconnectWs::new(ws_session).with_max_reconnect_attempts(u32::MAX);
+        reconnect_ws.connect().await.map_err(|e| {
+            ExchangeError::Other(format!("Failed to connect user data stream: {}", e))
+        })?;
+
+        let (tx, rx) = mpsc::channel(1000);
+        tokio::spawn(async move {
+            let mut keepalive = tokio::time::interval(LISTEN_KEY_KEEPALIVE);
+            keepalive.tick().await;
+
+            loop {
+                let result = tokio::select! {
+                    _ = keepalive.tick() => {
+                        if let Err(e) = rest.keepalive_listen_key(&listen_key).await {
+                            tracing::warn!(error = %e, "Failed to keep Binance listen key alive");
+                        }
+                        continue;
+                    }
+                    result = reconnect_ws.next_message() => result,
+                };
+                let Some(result) = result else {
+                    break;
+                };
+
+                let converted = match result {
+                    Ok(BinanceMessage::ExecutionReport(report)) => {
+                        convert_binance_execution_report(&report)
+                    }
+                    Ok(BinanceMessage::AccountPosition(position)) => {
+                        convert_binance_account_position(&position)
+                    }
+                    Ok(_) => continue,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Binance user data stream error");
+                        continue;
+                    }
+                };
+                match converted {
+                    Ok(events) => {
+                        for event in events {
+                            if channels.contains(&event.channel()) && tx.send(event).await.is_err()
+                            {
+                                return; // Receiver dropped
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Dropping malformed Binance account event");
+                    }
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
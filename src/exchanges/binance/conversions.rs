@@ -1,14 +1,22 @@
 use super::types as binance_types;
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
 use crate::core::types::{
-    conversion, Kline, Market, MarketDataType, OrderBook, OrderBookEntry, OrderSide, OrderType,
-    Symbol, Ticker, TimeInForce, Trade,
+    conversion, AccountEvent, AccountFill, AccountType, Balance, BorrowRate, ContractStyle,
+    InterestRecord, Kline, Market, MarketDataType, MarketStatus, Order, OrderBook, OrderBookEntry,
+    OrderSide, OrderStatus, OrderType, OrderUpdate, Price, Symbol, Ticker, TimeInForce, Trade,
+    WithdrawalRecord, WithdrawalStatus,
 };
+use crate::exchanges::factory::ExchangeId;
 use serde_json::Value;
 
+/// Binance symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::Binance.symbol_convention();
+
 /// Convert binance market to core market type
 pub fn convert_binance_market(
     binance_market: binance_types::BinanceMarket,
-) -> Result<Market, String> {
+) -> Result<Market, ExchangeError> {
     let mut min_qty = None;
     let mut max_qty = None;
     let mut min_price = None;
@@ -18,18 +26,18 @@ pub fn convert_binance_market(
         match filter.filter_type.as_str() {
             "LOT_SIZE" => {
                 if let Some(min_q) = &filter.min_qty {
-                    min_qty = Some(conversion::string_to_quantity(min_q));
+                    min_qty = Some(conversion::try_string_to_quantity(min_q)?);
                 }
                 if let Some(max_q) = &filter.max_qty {
-                    max_qty = Some(conversion::string_to_quantity(max_q));
+                    max_qty = Some(conversion::try_string_to_quantity(max_q)?);
                 }
             }
             "PRICE_FILTER" => {
                 if let Some(min_p) = &filter.min_price {
-                    min_price = Some(conversion::string_to_price(min_p));
+                    min_price = Some(conversion::try_string_to_price(min_p)?);
                 }
                 if let Some(max_p) = &filter.max_price {
-                    max_price = Some(conversion::string_to_price(max_p));
+                    max_price = Some(conversion::try_string_to_price(max_p)?);
                 }
             }
             _ => {}
@@ -37,17 +45,20 @@ pub fn convert_binance_market(
     }
 
     let symbol = Symbol::new(binance_market.base_asset, binance_market.quote_asset)
-        .unwrap_or_else(|_| conversion::string_to_symbol(&binance_market.symbol));
+        .unwrap_or_else(|_| SYMBOLS.from_exchange_symbol(&binance_market.symbol));
 
     Ok(Market {
         symbol,
-        status: binance_market.status,
+        status: MarketStatus::from_venue_str(&binance_market.status),
         base_precision: binance_market.base_asset_precision,
         quote_precision: binance_market.quote_precision,
         min_qty,
         max_qty,
         min_price,
         max_price,
+        contract_style: ContractStyle::Spot,
+        contract_size: None,
+        extensions: None,
     })
 }
 
@@ -85,22 +96,284 @@ pub fn convert_binance_rest_kline(
     kline: &binance_types::BinanceRestKline,
     symbol: &str,
     interval: &str,
-) -> Kline {
-    Kline {
-        symbol: conversion::string_to_symbol(symbol),
+) -> Result<Kline, ExchangeError> {
+    Ok(Kline {
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
         open_time: kline.open_time,
         close_time: kline.close_time,
         interval: interval.to_string(),
-        open_price: conversion::string_to_price(&kline.open_price),
-        high_price: conversion::string_to_price(&kline.high_price),
-        low_price: conversion::string_to_price(&kline.low_price),
-        close_price: conversion::string_to_price(&kline.close_price),
-        volume: conversion::string_to_volume(&kline.volume),
+        open_price: conversion::try_string_to_price(&kline.open_price)?,
+        high_price: conversion::try_string_to_price(&kline.high_price)?,
+        low_price: conversion::try_string_to_price(&kline.low_price)?,
+        close_price: conversion::try_string_to_price(&kline.close_price)?,
+        volume: conversion::try_string_to_volume(&kline.volume)?,
         number_of_trades: kline.number_of_trades,
         final_bar: true, // REST klines are always final
+    })
+}
+
+/// Convert binance REST 24h ticker to core ticker type
+pub fn convert_binance_rest_ticker(
+    ticker: &binance_types::BinanceRestTicker,
+) -> Result<Ticker, ExchangeError> {
+    Ok(Ticker {
+        symbol: SYMBOLS.from_exchange_symbol(&ticker.symbol),
+        price: conversion::try_string_to_price(&ticker.last_price)?,
+        price_change: conversion::try_string_to_price(&ticker.price_change)?,
+        price_change_percent: conversion::try_string_to_decimal(&ticker.price_change_percent)?,
+        high_price: conversion::try_string_to_price(&ticker.high_price)?,
+        low_price: conversion::try_string_to_price(&ticker.low_price)?,
+        volume: conversion::try_string_to_volume(&ticker.volume)?,
+        quote_volume: conversion::try_string_to_volume(&ticker.quote_volume)?,
+        open_time: ticker.open_time,
+        close_time: ticker.close_time,
+        count: ticker.count,
+    })
+}
+
+/// Convert binance REST depth to core order book type
+pub fn convert_binance_rest_order_book(
+    book: &binance_types::BinanceRestOrderBook,
+    symbol: &str,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
+        bids: conversion::try_book_levels(&book.bids)?,
+        asks: conversion::try_book_levels(&book.asks)?,
+        last_update_id: book.last_update_id,
+    })
+}
+
+/// Convert binance REST trade to core trade type
+pub fn convert_binance_rest_trade(
+    trade: &binance_types::BinanceRestTrade,
+    symbol: &str,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
+        id: trade.id,
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: conversion::try_string_to_quantity(&trade.qty)?,
+        time: trade.time,
+        is_buyer_maker: trade.is_buyer_maker,
+    })
+}
+
+/// Convert binance aggregate trade to core trade, identified by its aggregate id
+pub fn convert_binance_agg_trade(
+    trade: &binance_types::BinanceRestAggTrade,
+    symbol: &str,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
+        id: trade.aggregate_id,
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: conversion::try_string_to_quantity(&trade.quantity)?,
+        time: trade.time,
+        is_buyer_maker: trade.is_buyer_maker,
+    })
+}
+
+/// Convert binance margin next-hourly rate to core borrow rate, observed at `timestamp`
+pub fn convert_binance_borrow_rate(
+    rate: &binance_types::BinanceNextHourlyInterestRate,
+    timestamp: i64,
+) -> Result<BorrowRate, ExchangeError> {
+    Ok(BorrowRate {
+        asset: rate.asset.clone(),
+        rate: conversion::try_string_to_decimal(&rate.next_hourly_interest_rate)?,
+        interval: std::time::Duration::from_secs(3600),
+        timestamp,
+    })
+}
+
+/// Convert binance margin interest record to core interest record
+pub fn convert_binance_interest_record(
+    record: &binance_types::BinanceInterestRecord,
+) -> Result<InterestRecord, ExchangeError> {
+    Ok(InterestRecord {
+        asset: record.asset.clone(),
+        interest: conversion::try_string_to_decimal(&record.interest)?,
+        principal: Some(conversion::try_string_to_decimal(&record.principal)?),
+        rate: Some(conversion::try_string_to_decimal(&record.interest_rate)?),
+        timestamp: record.interest_accured_time,
+    })
+}
+
+/// Map binance withdrawal status code to core withdrawal status
+pub const fn convert_binance_withdraw_status(status: i32) -> WithdrawalStatus {
+    match status {
+        1 => WithdrawalStatus::Cancelled,
+        3 | 5 => WithdrawalStatus::Failed,
+        4 => WithdrawalStatus::Processing,
+        6 => WithdrawalStatus::Completed,
+        _ => WithdrawalStatus::Pending,
+    }
+}
+
+/// Convert binance withdrawal record to core withdrawal record
+pub fn convert_binance_withdraw_record(
+    record: &binance_types::BinanceWithdrawRecord,
+) -> Result<WithdrawalRecord, ExchangeError> {
+    let timestamp = chrono::NaiveDateTime::parse_from_str(&record.apply_time, "%Y-%m-%d %H:%M:%S")
+        .map_err(|e| ExchangeError::ParseError(format!("Invalid withdrawal time: {}", e)))?
+        .and_utc()
+        .timestamp_millis();
+
+    Ok(WithdrawalRecord {
+        id: record.id.clone(),
+        asset: record.coin.clone(),
+        amount: conversion::try_string_to_decimal(&record.amount)?,
+        fee: Some(conversion::try_string_to_decimal(&record.transaction_fee)?),
+        address: record.address.clone(),
+        network: record.network.clone(),
+        tx_id: record.tx_id.clone().filter(|tx_id| !tx_id.is_empty()),
+        status: convert_binance_withdraw_status(record.status),
+        timestamp,
+    })
+}
+
+/// Universal transfer type for moving funds between two wallets, e.g. `MAIN_UMFUTURE`
+pub fn binance_transfer_type(from: AccountType, to: AccountType) -> Result<String, ExchangeError> {
+    let wallet = |account: AccountType| match account {
+        AccountType::Spot => "MAIN",
+        AccountType::Margin => "MARGIN",
+        AccountType::Futures => "UMFUTURE",
+        AccountType::Funding => "FUNDING",
+        AccountType::Unified => "PORTFOLIO_MARGIN",
+    };
+    if from == to {
+        return Err(ExchangeError::InvalidParameters(format!(
+            "Cannot transfer from the {} wallet to itself",
+            from
+        )));
+    }
+    Ok(format!("{}_{}", wallet(from), wallet(to)))
+}
+
+/// Convert binance account trade to core fill
+pub fn convert_binance_my_trade(
+    trade: &binance_types::BinanceMyTrade,
+) -> Result<AccountFill, ExchangeError> {
+    Ok(AccountFill {
+        symbol: SYMBOLS.from_exchange_symbol(&trade.symbol),
+        order_id: trade.order_id.to_string(),
+        trade_id: trade.id.to_string(),
+        side: if trade.is_buyer {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        },
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: conversion::try_string_to_quantity(&trade.qty)?,
+        fee: conversion::try_string_to_decimal(&trade.commission)?,
+        fee_asset: Some(trade.commission_asset.clone()),
+        is_maker: trade.is_maker,
+        timestamp: trade.time,
+    })
+}
+
+/// Convert binance order type string to core order type
+pub fn convert_binance_order_type(order_type: &str) -> OrderType {
+    match order_type {
+        "MARKET" => OrderType::Market,
+        "STOP_LOSS" => OrderType::StopLoss,
+        "STOP_LOSS_LIMIT" => OrderType::StopLossLimit,
+        "TAKE_PROFIT" => OrderType::TakeProfit,
+        "TAKE_PROFIT_LIMIT" => OrderType::TakeProfitLimit,
+        _ => OrderType::Limit,
     }
 }
 
+/// Convert binance queried order to core order
+pub fn convert_binance_order(order: &binance_types::BinanceOrder) -> Result<Order, ExchangeError> {
+    let price = conversion::try_string_to_price(&order.price)?;
+    let filled_quantity = conversion::try_string_to_quantity(&order.executed_qty)?;
+    let quote_quantity = conversion::try_string_to_decimal(&order.cummulative_quote_qty)?;
+    // Spot orders only report the filled notional, so derive the average from it
+    let average_price = (!filled_quantity.value().is_zero())
+        .then(|| Price::new(quote_quantity / filled_quantity.value()));
+
+    Ok(Order {
+        order_id: order.order_id.to_string(),
+        client_order_id: order.client_order_id.clone(),
+        symbol: SYMBOLS.from_exchange_symbol(&order.symbol),
+        side: if order.side == "SELL" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        order_type: convert_binance_order_type(&order.order_type),
+        status: OrderStatus::from_venue(&order.status),
+        // Market orders report a zero price
+        price: (!price.value().is_zero()).then_some(price),
+        quantity: conversion::try_string_to_quantity(&order.orig_qty)?,
+        filled_quantity,
+        average_price,
+        created_time: order.time,
+        updated_time: order.update_time,
+    })
+}
+
+/// Convert binance execution report to an order update, plus a fill for trades
+pub fn convert_binance_execution_report(
+    report: &binance_types::BinanceExecutionReport,
+) -> Result<Vec<AccountEvent>, ExchangeError> {
+    let symbol = SYMBOLS.from_exchange_symbol(&report.symbol);
+    let side = if report.side == "SELL" {
+        OrderSide::Sell
+    } else {
+        OrderSide::Buy
+    };
+    // Market orders report a zero price
+    let price = conversion::try_string_to_price(&report.price)?;
+
+    let mut events = vec![AccountEvent::Order(OrderUpdate {
+        symbol: symbol.clone(),
+        order_id: report.order_id.to_string(),
+        client_order_id: report.client_order_id.clone(),
+        side: side.clone(),
+        status: report.status.clone(),
+        price: (!price.value().is_zero()).then_some(price),
+        quantity: conversion::try_string_to_quantity(&report.quantity)?,
+        filled_quantity: conversion::try_string_to_quantity(&report.cumulative_quantity)?,
+        timestamp: report.transaction_time,
+    })];
+
+    if report.execution_type == "TRADE" {
+        events.push(AccountEvent::Fill(AccountFill {
+            symbol,
+            order_id: report.order_id.to_string(),
+            trade_id: report.trade_id.to_string(),
+            side,
+            price: conversion::try_string_to_price(&report.last_price)?,
+            quantity: conversion::try_string_to_quantity(&report.last_quantity)?,
+            fee: conversion::try_string_to_decimal(&report.commission)?,
+            fee_asset: report.commission_asset.clone(),
+            is_maker: report.is_maker,
+            timestamp: report.transaction_time,
+        }));
+    }
+    Ok(events)
+}
+
+/// Convert binance account position update to one balance event per asset
+pub fn convert_binance_account_position(
+    position: &binance_types::BinanceAccountPosition,
+) -> Result<Vec<AccountEvent>, ExchangeError> {
+    position
+        .balances
+        .iter()
+        .map(|balance| {
+            Ok(AccountEvent::Balance(Balance {
+                asset: balance.asset.clone(),
+                free: conversion::try_string_to_quantity(&balance.free)?,
+                locked: conversion::try_string_to_quantity(&balance.locked)?,
+            }))
+        })
+        .collect()
+}
+
 /// Parse websocket message from binance
 #[allow(clippy::too_many_lines)]
 pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
@@ -111,15 +384,17 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
                     serde_json::from_value::<binance_types::BinanceWebSocketTicker>(data.clone())
                 {
                     // Convert string fields to proper types using conversion helpers
-                    let symbol = conversion::string_to_symbol(&ticker.symbol);
-                    let price = conversion::string_to_price(&ticker.price);
-                    let price_change = conversion::string_to_price(&ticker.price_change);
+                    let symbol = SYMBOLS.from_exchange_symbol(&ticker.symbol);
+                    let price = conversion::try_string_to_price(&ticker.price).ok()?;
+                    let price_change =
+                        conversion::try_string_to_price(&ticker.price_change).ok()?;
                     let price_change_percent =
-                        conversion::string_to_decimal(&ticker.price_change_percent);
-                    let high_price = conversion::string_to_price(&ticker.high_price);
-                    let low_price = conversion::string_to_price(&ticker.low_price);
-                    let volume = conversion::string_to_volume(&ticker.volume);
-                    let quote_volume = conversion::string_to_volume(&ticker.quote_volume);
+                        conversion::try_string_to_decimal(&ticker.price_change_percent).ok()?;
+                    let high_price = conversion::try_string_to_price(&ticker.high_price).ok()?;
+                    let low_price = conversion::try_string_to_price(&ticker.low_price).ok()?;
+                    let volume = conversion::try_string_to_volume(&ticker.volume).ok()?;
+                    let quote_volume =
+                        conversion::try_string_to_volume(&ticker.quote_volume).ok()?;
 
                     return Some(MarketDataType::Ticker(Ticker {
                         symbol,
@@ -139,25 +414,29 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
                 if let Ok(depth) =
                     serde_json::from_value::<binance_types::BinanceWebSocketOrderBook>(data.clone())
                 {
-                    let symbol = conversion::string_to_symbol(&depth.symbol);
+                    let symbol = SYMBOLS.from_exchange_symbol(&depth.symbol);
 
                     let bids = depth
                         .bids
                         .into_iter()
-                        .map(|b| OrderBookEntry {
-                            price: conversion::string_to_price(&b[0]),
-                            quantity: conversion::string_to_quantity(&b[1]),
+                        .map(|b| {
+                            Some(OrderBookEntry {
+                                price: conversion::try_string_to_price(&b[0]).ok()?,
+                                quantity: conversion::try_string_to_quantity(&b[1]).ok()?,
+                            })
                         })
-                        .collect();
+                        .collect::<Option<Vec<_>>>()?;
 
                     let asks = depth
                         .asks
                         .into_iter()
-                        .map(|a| OrderBookEntry {
-                            price: conversion::string_to_price(&a[0]),
-                            quantity: conversion::string_to_quantity(&a[1]),
+                        .map(|a| {
+                            Some(OrderBookEntry {
+                                price: conversion::try_string_to_price(&a[0]).ok()?,
+                                quantity: conversion::try_string_to_quantity(&a[1]).ok()?,
+                            })
                         })
-                        .collect();
+                        .collect::<Option<Vec<_>>>()?;
 
                     return Some(MarketDataType::OrderBook(OrderBook {
                         symbol,
@@ -170,9 +449,9 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
                 if let Ok(trade) =
                     serde_json::from_value::<binance_types::BinanceWebSocketTrade>(data.clone())
                 {
-                    let symbol = conversion::string_to_symbol(&trade.symbol);
-                    let price = conversion::string_to_price(&trade.price);
-                    let quantity = conversion::string_to_quantity(&trade.quantity);
+                    let symbol = SYMBOLS.from_exchange_symbol(&trade.symbol);
+                    let price = conversion::try_string_to_price(&trade.price).ok()?;
+                    let quantity = conversion::try_string_to_quantity(&trade.quantity).ok()?;
 
                     return Some(MarketDataType::Trade(Trade {
                         symbol,
@@ -187,12 +466,16 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
                 if let Ok(kline_data) =
                     serde_json::from_value::<binance_types::BinanceWebSocketKline>(data.clone())
                 {
-                    let symbol = conversion::string_to_symbol(&kline_data.symbol);
-                    let open_price = conversion::string_to_price(&kline_data.kline.open_price);
-                    let high_price = conversion::string_to_price(&kline_data.kline.high_price);
-                    let low_price = conversion::string_to_price(&kline_data.kline.low_price);
-                    let close_price = conversion::string_to_price(&kline_data.kline.close_price);
-                    let volume = conversion::string_to_volume(&kline_data.kline.volume);
+                    let symbol = SYMBOLS.from_exchange_symbol(&kline_data.symbol);
+                    let open_price =
+                        conversion::try_string_to_price(&kline_data.kline.open_price).ok()?;
+                    let high_price =
+                        conversion::try_string_to_price(&kline_data.kline.high_price).ok()?;
+                    let low_price =
+                        conversion::try_string_to_price(&kline_data.kline.low_price).ok()?;
+                    let close_price =
+                        conversion::try_string_to_price(&kline_data.kline.close_price).ok()?;
+                    let volume = conversion::try_string_to_volume(&kline_data.kline.volume).ok()?;
 
                     return Some(MarketDataType::Kline(Kline {
                         symbol,
@@ -213,3 +496,164 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_convert_withdraw_record() {
+        let record: binance_types::BinanceWithdrawRecord =
+            serde_json::from_value(serde_json::json!({
+                "id": "b6ae22b3aa844210a7041aee7589627c",
+                "amount": "8.91000000",
+                "transactionFee": "0.004",
+                "coin": "USDT",
+                "status": 6,
+                "address": "0x94df8b352de7f46f64b01d3666bf6e936e44ce60",
+                "txId": "0xb5ef8c13b968a406cc62a93a8bd80f9e9a906ef1b3fcf20a2e48573c17659268",
+                "applyTime": "2019-10-12 11:12:02",
+                "network": "ETH",
+                "transferType": 0
+            }))
+            .unwrap();
+
+        let converted = convert_binance_withdraw_record(&record).unwrap();
+        assert_eq!(converted.amount, Decimal::new(891, 2));
+        assert_eq!(converted.fee, Some(Decimal::new(4, 3)));
+        assert_eq!(converted.status, WithdrawalStatus::Completed);
+        assert_eq!(converted.timestamp, 1_570_878_722_000);
+        assert_eq!(
+            convert_binance_withdraw_status(2),
+            WithdrawalStatus::Pending
+        );
+        assert!(!convert_binance_withdraw_status(4).is_final());
+    }
+
+    #[test]
+    fn test_convert_my_trade() {
+        let trade: binance_types::BinanceMyTrade = serde_json::from_value(serde_json::json!({
+            "symbol": "BNBBTC",
+            "id": 28457,
+            "orderId": 100_234,
+            "orderListId": -1,
+            "price": "4.00000100",
+            "qty": "12.00000000",
+            "quoteQty": "48.000012",
+            "commission": "10.10000000",
+            "commissionAsset": "BNB",
+            "time": 1_499_865_549_590_i64,
+            "isBuyer": false,
+            "isMaker": true,
+            "isBestMatch": true
+        }))
+        .unwrap();
+
+        let fill = convert_binance_my_trade(&trade).unwrap();
+        assert_eq!(fill.trade_id, "28457");
+        assert_eq!(fill.order_id, "100234");
+        assert!(matches!(fill.side, OrderSide::Sell));
+        assert_eq!(fill.quantity.value(), Decimal::new(12, 0));
+        assert_eq!(fill.fee, Decimal::new(101, 1));
+        assert_eq!(fill.fee_asset.as_deref(), Some("BNB"));
+        assert!(fill.is_maker);
+    }
+
+    #[test]
+    fn test_convert_execution_report_trade() {
+        let report: binance_types::BinanceExecutionReport =
+            serde_json::from_value(serde_json::json!({
+                "e": "executionReport",
+                "E": 1_499_405_658_658_i64,
+                "s": "ETHBTC",
+                "c": "mUvoqJxFIILMdfAW5iGSOW",
+                "S": "BUY",
+                "o": "LIMIT",
+                "f": "GTC",
+                "q": "1.00000000",
+                "p": "0.10264410",
+                "x": "TRADE",
+                "X": "PARTIALLY_FILLED",
+                "i": 4_293_153,
+                "l": "0.40000000",
+                "z": "0.40000000",
+                "L": "0.10264000",
+                "n": "0.00040000",
+                "N": "ETH",
+                "T": 1_499_405_658_657_i64,
+                "t": 1_024,
+                "m": true
+            }))
+            .unwrap();
+
+        let events = convert_binance_execution_report(&report).unwrap();
+        assert_eq!(events.len(), 2);
+        let AccountEvent::Order(update) = &events[0] else {
+            panic!("expected order update");
+        };
+        assert_eq!(update.order_id, "4293153");
+        assert_eq!(update.status, "PARTIALLY_FILLED");
+        assert_eq!(update.filled_quantity.value(), Decimal::new(4, 1));
+        let AccountEvent::Fill(fill) = &events[1] else {
+            panic!("expected fill");
+        };
+        assert_eq!(fill.trade_id, "1024");
+        assert_eq!(fill.price.value(), Decimal::new(10_264_000, 8));
+        assert_eq!(fill.fee_asset.as_deref(), Some("ETH"));
+        assert!(fill.is_maker);
+    }
+
+    #[test]
+    fn test_convert_queried_order() {
+        let order: binance_types::BinanceOrder = serde_json::from_value(serde_json::json!({
+            "symbol": "LTCBTC",
+            "orderId": 1,
+            "orderListId": -1,
+            "clientOrderId": "myOrder1",
+            "price": "0.1",
+            "origQty": "1.0",
+            "executedQty": "0.5",
+            "cummulativeQuoteQty": "0.05",
+            "status": "PARTIALLY_FILLED",
+            "timeInForce": "GTC",
+            "type": "LIMIT",
+            "side": "BUY",
+            "time": 1_499_827_319_559_i64,
+            "updateTime": 1_499_827_319_600_i64
+        }))
+        .unwrap();
+
+        let converted = convert_binance_order(&order).unwrap();
+        assert_eq!(converted.status, OrderStatus::PartiallyFilled);
+        assert!(converted.status.is_open());
+        assert_eq!(converted.filled_quantity.value(), Decimal::new(5, 1));
+        assert_eq!(
+            converted.average_price.map(|p| p.value()),
+            Some(Decimal::new(1, 1))
+        );
+        // Other venues' spellings normalize to the same states
+        assert_eq!(OrderStatus::from_venue("Cancelled"), OrderStatus::Canceled);
+        assert_eq!(OrderStatus::from_venue("live"), OrderStatus::New);
+        assert_eq!(
+            OrderStatus::from_venue("marginCanceled"),
+            OrderStatus::Canceled
+        );
+    }
+
+    #[test]
+    fn test_binance_transfer_type() {
+        assert_eq!(
+            binance_transfer_type(AccountType::Spot, AccountType::Futures).unwrap(),
+            "MAIN_UMFUTURE"
+        );
+        assert_eq!(
+            binance_transfer_type(AccountType::Funding, AccountType::Margin).unwrap(),
+            "FUNDING_MARGIN"
+        );
+        assert!(matches!(
+            binance_transfer_type(AccountType::Spot, AccountType::Spot),
+            Err(ExchangeError::InvalidParameters(_))
+        ));
+    }
+}
@@ -6,12 +6,15 @@ pub mod types;
 pub mod builder;
 pub mod connector;
 pub mod rest;
+#[cfg(feature = "sbe")]
+pub mod sbe;
 
 // Re-export main components
 pub use builder::{
     build_connector,
     build_connector_with_reconnection,
     build_connector_with_websocket,
+    build_public,
     // Legacy compatibility exports
     create_binance_connector,
     create_binance_connector_with_reconnection,
@@ -23,8 +26,8 @@ pub use connector::{Account, BinanceConnector, MarketData, Trading};
 pub use types::{
     BinanceAccountInfo, BinanceBalance, BinanceExchangeInfo, BinanceFilter, BinanceKlineData,
     BinanceMarket, BinanceOrderRequest, BinanceOrderResponse, BinanceRestKline,
-    BinanceWebSocketKline, BinanceWebSocketOrderBook, BinanceWebSocketTicker,
-    BinanceWebSocketTrade,
+    BinanceWebSocketAggTrade, BinanceWebSocketKline, BinanceWebSocketOrderBook,
+    BinanceWebSocketTicker, BinanceWebSocketTrade,
 };
 
 /// Helper function to create WebSocket stream identifiers for Binance
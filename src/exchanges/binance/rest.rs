@@ -2,9 +2,13 @@ use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
 use crate::core::types::KlineInterval;
 use crate::exchanges::binance::types::{
-    BinanceAccountInfo, BinanceExchangeInfo, BinanceOrderResponse, BinanceRestKline,
+    BinanceAccountInfo, BinanceExchangeInfo, BinanceInterestHistory, BinanceListenKey,
+    BinanceMyTrade, BinanceNextHourlyInterestRate, BinanceOrder, BinanceOrderResponse,
+    BinanceRestAggTrade, BinanceRestKline, BinanceRestOrderBook, BinanceRestTicker,
+    BinanceRestTrade, BinanceServerTime, BinanceTransferResponse, BinanceWithdrawRecord,
+    BinanceWithdrawResponse,
 };
-use serde_json::Value;
+use serde_json::{json, Value};
 
 /// Thin typed wrapper around `RestClient` for Binance API
 pub struct BinanceRestClient<R: RestClient> {
@@ -16,6 +20,11 @@ impl<R: RestClient> BinanceRestClient<R> {
         Self { client }
     }
 
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.client.can_sign()
+    }
+
     /// Get exchange information
     pub async fn get_exchange_info(&self) -> Result<BinanceExchangeInfo, ExchangeError> {
         self.client
@@ -23,6 +32,12 @@ impl<R: RestClient> BinanceRestClient<R> {
             .await
     }
 
+    /// Get server time in milliseconds
+    pub async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let time: BinanceServerTime = self.client.get_json("/api/v3/time", &[], false).await?;
+        Ok(time.server_time)
+    }
+
     /// Get klines/candlestick data
     pub async fn get_klines(
         &self,
@@ -55,16 +70,281 @@ impl<R: RestClient> BinanceRestClient<R> {
         self.client.get_json("/api/v3/klines", &params, false).await
     }
 
+    /// Get the rolling 24h ticker of a symbol
+    pub async fn get_ticker(&self, symbol: &str) -> Result<BinanceRestTicker, ExchangeError> {
+        let params = [("symbol", symbol)];
+        self.client
+            .get_json("/api/v3/ticker/24hr", &params, false)
+            .await
+    }
+
+    /// Get the order book; Binance defaults to 100 levels and serves up to 5000
+    pub async fn get_order_book(
+        &self,
+        symbol: &str,
+        limit: Option<u32>,
+    ) -> Result<BinanceRestOrderBook, ExchangeError> {
+        let limit_str = limit.map(|l| l.to_string());
+        let mut params = vec![("symbol", symbol)];
+        if let Some(ref limit) = limit_str {
+            params.push(("limit", limit.as_str()));
+        }
+        self.client.get_json("/api/v3/depth", &params, false).await
+    }
+
+    /// Get recent trades, oldest first; Binance defaults to 500 and serves up to 1000
+    pub async fn get_recent_trades(
+        &self,
+        symbol: &str,
+        limit: Option<u32>,
+    ) -> Result<Vec<BinanceRestTrade>, ExchangeError> {
+        let limit_str = limit.map(|l| l.to_string());
+        let mut params = vec![("symbol", symbol)];
+        if let Some(ref limit) = limit_str {
+            params.push(("limit", limit.as_str()));
+        }
+        self.client.get_json("/api/v3/trades", &params, false).await
+    }
+
+    /// Get up to 1000 aggregate trades, from `from_id` or within an hour-long time range
+    pub async fn get_agg_trades(
+        &self,
+        symbol: &str,
+        from_id: Option<i64>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<BinanceRestAggTrade>, ExchangeError> {
+        let mut params = vec![("symbol", symbol), ("limit", "1000")];
+
+        let from_id_str;
+        let start_time_str;
+        let end_time_str;
+
+        if let Some(from_id) = from_id {
+            from_id_str = from_id.to_string();
+            params.push(("fromId", from_id_str.as_str()));
+        }
+        if let Some(start_time) = start_time {
+            start_time_str = start_time.to_string();
+            params.push(("startTime", start_time_str.as_str()));
+        }
+        if let Some(end_time) = end_time {
+            end_time_str = end_time.to_string();
+            params.push(("endTime", end_time_str.as_str()));
+        }
+
+        self.client
+            .get_json("/api/v3/aggTrades", &params, false)
+            .await
+    }
+
     /// Get account information
     pub async fn get_account_info(&self) -> Result<BinanceAccountInfo, ExchangeError> {
         self.client.get_json("/api/v3/account", &[], true).await
     }
 
+    /// Get the next hourly cross-margin borrow rate for comma-separated `assets`
+    pub async fn get_next_hourly_interest_rate(
+        &self,
+        assets: &str,
+    ) -> Result<Vec<BinanceNextHourlyInterestRate>, ExchangeError> {
+        let params = [("assets", assets), ("isIsolated", "FALSE")];
+        self.client
+            .get_json("/sapi/v1/margin/next-hourly-interest-rate", &params, true)
+            .await
+    }
+
+    /// Get margin interest history
+    pub async fn get_interest_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        size: Option<u32>,
+    ) -> Result<BinanceInterestHistory, ExchangeError> {
+        let mut params = Vec::new();
+
+        let start_time_str;
+        let end_time_str;
+        let size_str;
+
+        if let Some(asset) = asset {
+            params.push(("asset", asset));
+        }
+        if let Some(start_time) = start_time {
+            start_time_str = start_time.to_string();
+            params.push(("startTime", start_time_str.as_str()));
+        }
+        if let Some(end_time) = end_time {
+            end_time_str = end_time.to_string();
+            params.push(("endTime", end_time_str.as_str()));
+        }
+        if let Some(size) = size {
+            size_str = size.to_string();
+            params.push(("size", size_str.as_str()));
+        }
+
+        self.client
+            .get_json("/sapi/v1/margin/interestHistory", &params, true)
+            .await
+    }
+
+    /// Submit a withdrawal
+    pub async fn withdraw(
+        &self,
+        request: &Value,
+    ) -> Result<BinanceWithdrawResponse, ExchangeError> {
+        self.client
+            .post_json("/sapi/v1/capital/withdraw/apply", request, true)
+            .await
+    }
+
+    /// Move funds between the account's wallets
+    pub async fn transfer(
+        &self,
+        request: &Value,
+    ) -> Result<BinanceTransferResponse, ExchangeError> {
+        self.client
+            .post_json("/sapi/v1/asset/transfer", request, true)
+            .await
+    }
+
+    /// Get withdrawal history
+    pub async fn get_withdraw_history(
+        &self,
+        coin: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<BinanceWithdrawRecord>, ExchangeError> {
+        let mut params = Vec::new();
+
+        let start_time_str;
+        let end_time_str;
+        let limit_str;
+
+        if let Some(coin) = coin {
+            params.push(("coin", coin));
+        }
+        if let Some(start_time) = start_time {
+            start_time_str = start_time.to_string();
+            params.push(("startTime", start_time_str.as_str()));
+        }
+        if let Some(end_time) = end_time {
+            end_time_str = end_time.to_string();
+            params.push(("endTime", end_time_str.as_str()));
+        }
+        if let Some(limit) = limit {
+            limit_str = limit.to_string();
+            params.push(("limit", limit_str.as_str()));
+        }
+
+        self.client
+            .get_json("/sapi/v1/capital/withdraw/history", &params, true)
+            .await
+    }
+
+    /// Get the account's trades on a symbol
+    pub async fn get_my_trades(
+        &self,
+        symbol: &str,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<BinanceMyTrade>, ExchangeError> {
+        let mut params = vec![("symbol", symbol)];
+
+        let start_time_str;
+        let end_time_str;
+        let limit_str;
+
+        if let Some(start_time) = start_time {
+            start_time_str = start_time.to_string();
+            params.push(("startTime", start_time_str.as_str()));
+        }
+        if let Some(end_time) = end_time {
+            end_time_str = end_time.to_string();
+            params.push(("endTime", end_time_str.as_str()));
+        }
+        if let Some(limit) = limit {
+            limit_str = limit.to_string();
+            params.push(("limit", limit_str.as_str()));
+        }
+
+        self.client
+            .get_json("/api/v3/myTrades", &params, true)
+            .await
+    }
+
+    /// Open a user data stream, returning its listen key
+    pub async fn create_listen_key(&self) -> Result<BinanceListenKey, ExchangeError> {
+        self.client
+            .post_json("/api/v3/userDataStream", &json!({}), true)
+            .await
+    }
+
+    /// Extend a listen key's validity by 60 minutes
+    pub async fn keepalive_listen_key(&self, listen_key: &str) -> Result<(), ExchangeError> {
+        let endpoint = format!("/api/v3/userDataStream?listenKey={}", listen_key);
+        self.client.put(&endpoint, &json!({}), true).await?;
+        Ok(())
+    }
+
     /// Place an order
     pub async fn place_order(&self, order: &Value) -> Result<BinanceOrderResponse, ExchangeError> {
         self.client.post_json("/api/v3/order", order, true).await
     }
 
+    /// Get open orders, for one symbol or all of them
+    pub async fn get_open_orders(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<Vec<BinanceOrder>, ExchangeError> {
+        let params: Vec<(&str, &str)> = symbol.map(|s| ("symbol", s)).into_iter().collect();
+        self.client
+            .get_json("/api/v3/openOrders", &params, true)
+            .await
+    }
+
+    /// Get orders from `order_id` on, oldest first, or within a time range
+    pub async fn get_all_orders(
+        &self,
+        symbol: &str,
+        order_id: Option<u64>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: u32,
+    ) -> Result<Vec<BinanceOrder>, ExchangeError> {
+        let order_id = order_id.map(|id| id.to_string());
+        let start_time = start_time.map(|t| t.to_string());
+        let end_time = end_time.map(|t| t.to_string());
+        let limit = limit.to_string();
+        let mut params = vec![("symbol", symbol), ("limit", limit.as_str())];
+        if let Some(order_id) = &order_id {
+            params.push(("orderId", order_id));
+        }
+        if let Some(start_time) = &start_time {
+            params.push(("startTime", start_time));
+        }
+        if let Some(end_time) = &end_time {
+            params.push(("endTime", end_time));
+        }
+        self.client
+            .get_json("/api/v3/allOrders", &params, true)
+            .await
+    }
+
+    /// Get a single order by id
+    pub async fn get_order(
+        &self,
+        symbol: &str,
+        order_id: u64,
+    ) -> Result<BinanceOrder, ExchangeError> {
+        let order_id = order_id.to_string();
+        let params = [("symbol", symbol), ("orderId", order_id.as_str())];
+        self.client.get_json("/api/v3/order", &params, true).await
+    }
+
     /// Cancel an order
     pub async fn cancel_order(
         &self,
@@ -0,0 +1,538 @@
+use crate::core::config::ExchangeConfig;
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{
+    ReconnectWs, ReqwestRest, RestClient, RestClientBuilder, RestClientConfig, TungsteniteWs,
+    WsCodec, WsConfig, WsSession,
+};
+use crate::core::traits::{RestMarketData, StreamingMarketData};
+use crate::core::types::{
+    conversion, Kline, KlineInterval, Market, MarketDataType, OrderBook, OrderBookEntry,
+    OrderBookMode, Price, Quantity, SubscriptionType, Symbol, Trade, WebSocketConfig,
+};
+use crate::exchanges::binance::codec::BinanceCodec;
+use crate::exchanges::binance::connector::MarketData;
+use crate::exchanges::binance::conversions::SYMBOLS;
+use crate::exchanges::binance::types::binance_error_kind;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+
+/// SBE market data streams; the handshake must carry an Ed25519 API key
+pub const SBE_STREAM_URL: &str = "wss://stream-sbe.binance.com:9443/ws";
+
+const SCHEMA_ID: u16 = 1;
+const HEADER_LEN: usize = 8;
+
+const TRADES_TEMPLATE: u16 = 10000;
+const BEST_BID_ASK_TEMPLATE: u16 = 10001;
+const DEPTH_SNAPSHOT_TEMPLATE: u16 = 10002;
+const DEPTH_DIFF_TEMPLATE: u16 = 10003;
+
+/// Best bid and ask from the `@bestBidAsk` stream
+#[derive(Debug, Clone)]
+pub struct SbeBestBidAsk {
+    pub symbol: Symbol,
+    pub event_time_us: i64,
+    pub book_update_id: i64,
+    pub bid: OrderBookEntry,
+    pub ask: OrderBookEntry,
+}
+
+/// Decoded SBE stream event
+#[derive(Debug, Clone)]
+pub enum SbeMessage {
+    Trades(Vec<Trade>),
+    BestBidAsk(SbeBestBidAsk),
+    /// `@depth20` top of book
+    DepthSnapshot(OrderBook),
+    /// `@depth` diff; `last_update_id` is the last update id it covers
+    DepthDiff {
+        book: OrderBook,
+        first_update_id: i64,
+    },
+}
+
+impl SbeMessage {
+    pub fn into_market_data(self) -> Vec<MarketDataType> {
+        match self {
+            Self::Trades(trades) => trades.into_iter().map(MarketDataType::Trade).collect(),
+            Self::DepthSnapshot(book) | Self::DepthDiff { book, .. } => {
+                vec![MarketDataType::OrderBook(book)]
+            }
+            Self::BestBidAsk(_) => Vec::new(),
+        }
+    }
+}
+
+/// Little-endian cursor over one SBE message
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    const fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], ExchangeError> {
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| ExchangeError::ParseError("Truncated SBE message".to_string()))?;
+        self.pos += N;
+        let mut out = [0u8; N];
+        out.copy_from_slice(bytes);
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8, ExchangeError> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn i8(&mut self) -> Result<i8, ExchangeError> {
+        Ok(i8::from_le_bytes(self.take()?))
+    }
+
+    fn u16(&mut self) -> Result<u16, ExchangeError> {
+        Ok(u16::from_le_bytes(self.take()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, ExchangeError> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    fn i64(&mut self) -> Result<i64, ExchangeError> {
+        Ok(i64::from_le_bytes(self.take()?))
+    }
+
+    /// Skip to the end of a block that started at `start`, for fields added by newer
+    /// schema versions
+    fn end_block(&mut self, start: usize, block_length: u16) -> Result<(), ExchangeError> {
+        let end = start + usize::from(block_length);
+        if end < self.pos || end > self.buf.len() {
+            return Err(ExchangeError::ParseError(
+                "Invalid SBE block length".to_string(),
+            ));
+        }
+        self.pos = end;
+        Ok(())
+    }
+
+    fn var_string8(&mut self) -> Result<String, ExchangeError> {
+        let len = usize::from(self.u8()?);
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| ExchangeError::ParseError("Truncated SBE string".to_string()))?;
+        self.pos += len;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|e| ExchangeError::ParseError(format!("Invalid SBE string: {}", e)))
+    }
+
+    /// Price levels in a `groupSize16Encoding` group
+    fn levels(&mut self, price_exp: i8, qty_exp: i8) -> Result<Vec<OrderBookEntry>, ExchangeError> {
+        let block_length = self.u16()?;
+        let count = self.u16()?;
+        (0..count)
+            .map(|_| {
+                let start = self.pos;
+                let price = scaled(self.i64()?, price_exp);
+                let quantity = scaled(self.i64()?, qty_exp);
+                self.end_block(start, block_length)?;
+                Ok(OrderBookEntry {
+                    price: Price::new(price),
+                    quantity: Quantity::new(quantity),
+                })
+            })
+            .collect()
+    }
+}
+
+/// `mantissa * 10^exponent`
+fn scaled(mantissa: i64, exponent: i8) -> Decimal {
+    if exponent <= 0 {
+        Decimal::new(mantissa, u32::from(exponent.unsigned_abs()))
+    } else {
+        Decimal::from(mantissa) * Decimal::from(10_i64.pow(u32::from(exponent.unsigned_abs())))
+    }
+}
+
+/// Decode one binary frame of the Binance spot SBE stream schema
+pub fn decode_sbe_message(buf: &[u8]) -> Result<Option<SbeMessage>, ExchangeError> {
+    let mut r = Reader::new(buf);
+    let block_length = r.u16()?;
+    let template_id = r.u16()?;
+    let schema_id = r.u16()?;
+    let _version = r.u16()?;
+    if schema_id != SCHEMA_ID {
+        return Err(ExchangeError::ParseError(format!(
+            "Unexpected SBE schema {}",
+            schema_id
+        )));
+    }
+
+    let start = HEADER_LEN;
+    let message = match template_id {
+        TRADES_TEMPLATE => {
+            let _event_time = r.i64()?;
+            let transact_time_us = r.i64()?;
+            let (price_exp, qty_exp) = (r.i8()?, r.i8()?);
+            r.end_block(start, block_length)?;
+
+            let entry_length = r.u16()?;
+            let count = r.u32()?;
+            let mut raw = Vec::with_capacity(count as usize);
+            for _ in 0..count {
+                let entry = r.pos;
+                let id = r.i64()?;
+                let price = scaled(r.i64()?, price_exp);
+                let quantity = scaled(r.i64()?, qty_exp);
+                let is_buyer_maker = r.u8()? == 1;
+                r.end_block(entry, entry_length)?;
+                raw.push((id, price, quantity, is_buyer_maker));
+            }
+            let symbol = SYMBOLS.from_exchange_symbol(&r.var_string8()?);
+            SbeMessage::Trades(
+                raw.into_iter()
+                    .map(|(id, price, quantity, is_buyer_maker)| Trade {
+                        symbol: symbol.clone(),
+                        id,
+                        price: Price::new(price),
+                        quantity: Quantity::new(quantity),
+                        time: conversion::timestamp_to_millis(transact_time_us),
+                        is_buyer_maker,
+                    })
+                    .collect(),
+            )
+        }
+        BEST_BID_ASK_TEMPLATE => {
+            let event_time_us = r.i64()?;
+            let book_update_id = r.i64()?;
+            let (price_exp, qty_exp) = (r.i8()?, r.i8()?);
+            let bid = OrderBookEntry {
+                price: Price::new(scaled(r.i64()?, price_exp)),
+                quantity: Quantity::new(scaled(r.i64()?, qty_exp)),
+            };
+            let ask = OrderBookEntry {
+                price: Price::new(scaled(r.i64()?, price_exp)),
+                quantity: Quantity::new(scaled(r.i64()?, qty_exp)),
+            };
+            r.end_block(start, block_length)?;
+            SbeMessage::BestBidAsk(SbeBestBidAsk {
+                symbol: SYMBOLS.from_exchange_symbol(&r.var_string8()?),
+                event_time_us,
+                book_update_id,
+                bid,
+                ask,
+            })
+        }
+        DEPTH_SNAPSHOT_TEMPLATE => {
+            let _event_time = r.i64()?;
+            let book_update_id = r.i64()?;
+            let (price_exp, qty_exp) = (r.i8()?, r.i8()?);
+            r.end_block(start, block_length)?;
+            let bids = r.levels(price_exp, qty_exp)?;
+            let asks = r.levels(price_exp, qty_exp)?;
+            SbeMessage::DepthSnapshot(OrderBook {
+                symbol: SYMBOLS.from_exchange_symbol(&r.var_string8()?),
+                bids,
+                asks,
+                last_update_id: book_update_id,
+            })
+        }
+        DEPTH_DIFF_TEMPLATE => {
+            let _event_time = r.i64()?;
+            let first_update_id = r.i64()?;
+            let last_update_id = r.i64()?;
+            let (price_exp, qty_exp) = (r.i8()?, r.i8()?);
+            r.end_block(start, block_length)?;
+            let bids = r.levels(price_exp, qty_exp)?;
+            let asks = r.levels(price_exp, qty_exp)?;
+            SbeMessage::DepthDiff {
+                book: OrderBook {
+                    symbol: SYMBOLS.from_exchange_symbol(&r.var_string8()?),
+                    bids,
+                    asks,
+                    last_update_id,
+                },
+                first_update_id,
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(message))
+}
+
+/// Codec for the SBE streams; subscriptions use the same JSON requests as the JSON streams
+pub struct BinanceSbeCodec;
+
+impl WsCodec for BinanceSbeCodec {
+    type Message = SbeMessage;
+
+    fn encode_subscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        BinanceCodec.encode_subscription(streams)
+    }
+
+    fn encode_unsubscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        BinanceCodec.encode_unsubscription(streams)
+    }
+
+    fn exchange_time(&self, message: &Self::Message) -> Option<i64> {
+        match message {
+            SbeMessage::Trades(trades) => trades.last().map(|trade| trade.time),
+            SbeMessage::BestBidAsk(best) => Some(best.event_time_us),
+            SbeMessage::DepthSnapshot(_) | SbeMessage::DepthDiff { .. } => None,
+        }
+    }
+
+    fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
+        match message {
+            Message::Binary(data) => decode_sbe_message(&data),
+            // Subscription acks arrive as JSON text
+            _ => Ok(None),
+        }
+    }
+}
+
+/// SBE stream names; only trades and order books are published in SBE
+pub fn create_sbe_stream_identifiers(
+    symbols: &[String],
+    subscription_types: &[SubscriptionType],
+) -> Result<Vec<String>, ExchangeError> {
+    let mut streams = Vec::new();
+    for symbol in symbols {
+        let symbol = symbol.to_lowercase();
+        for subscription in subscription_types {
+            streams.push(match subscription {
+                SubscriptionType::Trades => format!("{}@trade", symbol),
+                SubscriptionType::OrderBook {
+                    mode: OrderBookMode::Delta,
+                    ..
+                } => format!("{}@depth", symbol),
+                SubscriptionType::OrderBook { .. } => format!("{}@depth20", symbol),
+                other => {
+                    return Err(ExchangeError::NotSupported(format!(
+                        "{:?} is not available on the Binance SBE feed",
+                        other
+                    )))
+                }
+            });
+        }
+    }
+    Ok(streams)
+}
+
+/// Binance spot market data with SBE streaming
+///
+/// REST queries go through the regular JSON API; subscriptions use the lower-latency
+/// SBE feed. Access requires an Ed25519 API key.
+pub struct SbeMarketData<R: RestClient> {
+    rest: MarketData<R, ()>,
+    api_key: String,
+    ws_url: String,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient + Clone> SbeMarketData<R> {
+    pub fn new(rest: &R, api_key: String) -> Self {
+        Self {
+            rest: MarketData::<R, ()>::new(rest, None, false),
+            api_key,
+            ws_url: SBE_STREAM_URL.to_string(),
+            ws_config: WsConfig::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_ws_url(mut self, ws_url: impl Into<String>) -> Self {
+        self.ws_url = ws_url.into();
+        self
+    }
+
+    /// Open the stream with `config`, e.g. to route it through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> RestMarketData for SbeMarketData<R> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.rest.get_markets().await
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        self.rest
+            .get_klines(symbol, interval, limit, start_time, end_time)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> StreamingMarketData for SbeMarketData<R> {
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        let symbols: Vec<String> = symbols.iter().map(ToString::to_string).collect();
+        let streams = create_sbe_stream_identifiers(&symbols, &subscription_types)?;
+
+        let ws_session = TungsteniteWs::new(
+            self.ws_url.clone(),
+            "binance_sbe".to_string(),
+            BinanceSbeCodec,
+        )
+        .with_config(self.ws_config.clone())
+        .with_header("X-MBX-APIKEY", self.api_key.clone());
+        let mut reconnect_ws = ReconnectWs::new(ws_session)
+            .with_auto_resubscribe(true)
+            .with_max_reconnect_attempts(u32::MAX)
+            .with_connection_events(config.as_ref().and_then(|c| c.connection_events.clone()));
+        reconnect_ws
+            .connect()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("Failed to connect to SBE stream: {}", e)))?;
+        reconnect_ws.subscribe(&streams).await?;
+
+        let (tx, rx) = mpsc::channel(1000);
+        tokio::spawn(async move {
+            while let Some(result) = reconnect_ws.next_message().await {
+                match result {
+                    Ok(message) => {
+                        for data in message.into_market_data() {
+                            if tx.send(data).await.is_err() {
+                                return; // Receiver dropped
+                            }
+                        }
+                    }
+                    Err(e) => tracing::warn!(error = %e, "Binance SBE stream error"),
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.ws_url.clone()
+    }
+}
+
+/// Build SBE market data from `config`; the API key authorises the stream connection
+pub fn build_sbe_market_data(
+    config: ExchangeConfig,
+) -> Result<SbeMarketData<ReqwestRest>, ExchangeError> {
+    if !config.has_credentials() {
+        return Err(ExchangeError::AuthenticationRequired);
+    }
+    let base_url = config
+        .base_url
+        .clone()
+        .unwrap_or_else(|| "https://api.binance.com".to_string());
+    let rest_config = RestClientConfig::new(base_url, "binance".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone())
+        .with_error_kinds(binance_error_kind);
+    let rest = RestClientBuilder::new(rest_config).build()?;
+    Ok(SbeMarketData::new(&rest, config.api_key().to_string()).with_ws_config(config.ws_config()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(block_length: u16, template_id: u16) -> Vec<u8> {
+        [block_length, template_id, SCHEMA_ID, 0]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect()
+    }
+
+    fn symbol(buf: &mut Vec<u8>, name: &str) {
+        buf.push(name.len() as u8);
+        buf.extend_from_slice(name.as_bytes());
+    }
+
+    #[test]
+    fn test_decode_trades() {
+        let mut buf = header(18, TRADES_TEMPLATE);
+        buf.extend_from_slice(&1_700_000_000_000_123_i64.to_le_bytes());
+        buf.extend_from_slice(&1_700_000_000_000_000_i64.to_le_bytes());
+        buf.extend_from_slice(&[(-2_i8).to_le_bytes()[0], (-4_i8).to_le_bytes()[0]]);
+        buf.extend_from_slice(&25_u16.to_le_bytes());
+        buf.extend_from_slice(&1_u32.to_le_bytes());
+        buf.extend_from_slice(&42_i64.to_le_bytes());
+        buf.extend_from_slice(&6_512_345_i64.to_le_bytes());
+        buf.extend_from_slice(&1_500_i64.to_le_bytes());
+        buf.push(1);
+        symbol(&mut buf, "BTCUSDT");
+
+        let Some(SbeMessage::Trades(trades)) = decode_sbe_message(&buf).unwrap() else {
+            panic!("expected trades");
+        };
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].id, 42);
+        assert_eq!(trades[0].price.to_string(), "65123.45");
+        assert_eq!(trades[0].quantity.to_string(), "0.1500");
+        assert_eq!(trades[0].time, 1_700_000_000_000);
+        assert!(trades[0].is_buyer_maker);
+        assert_eq!(trades[0].symbol.to_string(), "BTCUSDT");
+    }
+
+    #[test]
+    fn test_decode_depth_diff_skips_unknown_fields() {
+        // Root block two bytes longer than this decoder knows about
+        let mut buf = header(28, DEPTH_DIFF_TEMPLATE);
+        buf.extend_from_slice(&0_i64.to_le_bytes());
+        buf.extend_from_slice(&100_i64.to_le_bytes());
+        buf.extend_from_slice(&105_i64.to_le_bytes());
+        buf.extend_from_slice(&[(-1_i8).to_le_bytes()[0], 0, 0xff, 0xff]);
+        // One bid, no asks
+        for level in [Some((650_000_i64, 3_i64)), None] {
+            buf.extend_from_slice(&16_u16.to_le_bytes());
+            buf.extend_from_slice(&u16::from(level.is_some()).to_le_bytes());
+            if let Some((price, qty)) = level {
+                buf.extend_from_slice(&price.to_le_bytes());
+                buf.extend_from_slice(&qty.to_le_bytes());
+            }
+        }
+        symbol(&mut buf, "BTCUSDT");
+
+        let Some(SbeMessage::DepthDiff {
+            book,
+            first_update_id,
+        }) = decode_sbe_message(&buf).unwrap()
+        else {
+            panic!("expected a depth diff");
+        };
+        assert_eq!(first_update_id, 100);
+        assert_eq!(book.last_update_id, 105);
+        assert_eq!(book.bids[0].price.to_string(), "65000.0");
+        assert_eq!(book.bids[0].quantity.to_string(), "3");
+        assert!(book.asks.is_empty());
+        assert!(decode_sbe_message(&buf[..20]).is_err());
+    }
+}
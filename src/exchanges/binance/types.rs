@@ -1,3 +1,4 @@
+use crate::core::errors::ErrorKind;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
@@ -29,6 +30,12 @@ pub struct BinanceFilter {
     pub max_qty: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceServerTime {
+    pub server_time: i64,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BinanceExchangeInfo {
     pub symbols: Vec<BinanceMarket>,
@@ -68,6 +75,25 @@ pub struct BinanceOrderResponse {
     pub timestamp: i32,
 }
 
+/// Order as returned by the order query endpoints
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub price: String,
+    pub orig_qty: String,
+    pub executed_qty: String,
+    pub cummulative_quote_qty: String,
+    pub status: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub side: String,
+    pub time: i64,
+    pub update_time: i64,
+}
+
 // WebSocket Types
 #[derive(Debug, Clone, Deserialize)]
 pub struct BinanceWebSocketTicker {
@@ -110,6 +136,28 @@ pub struct BinanceWebSocketOrderBook {
     pub asks: Vec<[String; 2]>,
 }
 
+/// Payload of the `<symbol>@depth<levels>` partial book streams
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceWebSocketPartialDepth {
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: i64,
+    pub bids: Vec<[String; 2]>,
+    pub asks: Vec<[String; 2]>,
+}
+
+impl BinanceWebSocketPartialDepth {
+    /// Attach the symbol taken from the stream name
+    pub fn into_order_book(self, symbol: &str) -> BinanceWebSocketOrderBook {
+        BinanceWebSocketOrderBook {
+            symbol: symbol.to_uppercase(),
+            first_update_id: self.last_update_id,
+            final_update_id: self.last_update_id,
+            bids: self.bids,
+            asks: self.asks,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct BinanceWebSocketTrade {
     #[serde(rename = "s")]
@@ -126,6 +174,38 @@ pub struct BinanceWebSocketTrade {
     pub is_buyer_maker: bool,
 }
 
+/// Payload of the `<symbol>@aggTrade` stream, which merges fills of one taker order
+/// at one price
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceWebSocketAggTrade {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "a")]
+    pub aggregate_id: i64,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "q")]
+    pub quantity: String,
+    #[serde(rename = "T")]
+    pub time: i64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+impl BinanceWebSocketAggTrade {
+    /// Reported as a trade whose id is the aggregate trade id
+    pub fn into_trade(self) -> BinanceWebSocketTrade {
+        BinanceWebSocketTrade {
+            symbol: self.symbol,
+            id: self.aggregate_id,
+            price: self.price,
+            quantity: self.quantity,
+            time: self.time,
+            is_buyer_maker: self.is_buyer_maker,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct BinanceWebSocketKline {
     #[serde(rename = "s")]
@@ -199,3 +279,216 @@ pub struct BinanceRestKline {
     #[serde(rename = "11")]
     pub ignore: String,
 }
+
+// REST API snapshot types
+/// Reply of `/api/v3/ticker/24hr` for one symbol
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceRestTicker {
+    pub symbol: String,
+    pub price_change: String,
+    pub price_change_percent: String,
+    pub last_price: String,
+    pub high_price: String,
+    pub low_price: String,
+    pub volume: String,
+    pub quote_volume: String,
+    pub open_time: i64,
+    pub close_time: i64,
+    pub count: i64,
+}
+
+/// Reply of `/api/v3/depth`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceRestOrderBook {
+    pub last_update_id: i64,
+    pub bids: Vec<[String; 2]>,
+    pub asks: Vec<[String; 2]>,
+}
+
+/// Entry of `/api/v3/trades`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceRestTrade {
+    pub id: i64,
+    pub price: String,
+    pub qty: String,
+    pub time: i64,
+    pub is_buyer_maker: bool,
+}
+
+/// Entry of `/api/v3/aggTrades`
+#[derive(Debug, Deserialize)]
+pub struct BinanceRestAggTrade {
+    #[serde(rename = "a")]
+    pub aggregate_id: i64,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "q")]
+    pub quantity: String,
+    #[serde(rename = "T")]
+    pub time: i64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+// Margin borrowing types
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceNextHourlyInterestRate {
+    pub asset: String,
+    pub next_hourly_interest_rate: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceInterestHistory {
+    pub rows: Vec<BinanceInterestRecord>,
+    pub total: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceInterestRecord {
+    pub asset: String,
+    pub interest: String,
+    // Binance's spelling
+    pub interest_accured_time: i64,
+    pub principal: String,
+    pub interest_rate: String,
+    #[serde(rename = "type")]
+    pub interest_type: String,
+}
+
+// Withdrawal types
+#[derive(Debug, Deserialize)]
+pub struct BinanceWithdrawResponse {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BinanceTransferResponse {
+    #[serde(rename = "tranId")]
+    pub tran_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceWithdrawRecord {
+    pub id: String,
+    pub amount: String,
+    pub transaction_fee: String,
+    pub coin: String,
+    /// 0 email sent, 1 cancelled, 2 awaiting approval, 3 rejected, 4 processing,
+    /// 5 failure, 6 completed
+    pub status: i32,
+    pub address: String,
+    #[serde(default)]
+    pub tx_id: Option<String>,
+    /// UTC `YYYY-MM-DD HH:MM:SS`
+    pub apply_time: String,
+    #[serde(default)]
+    pub network: Option<String>,
+}
+
+/// Entry of `/api/v3/myTrades`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceMyTrade {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub price: String,
+    pub qty: String,
+    pub commission: String,
+    pub commission_asset: String,
+    pub time: i64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+}
+
+// User data stream types
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceListenKey {
+    pub listen_key: String,
+}
+
+/// `executionReport` event from the user data stream
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceExecutionReport {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "q")]
+    pub quantity: String,
+    #[serde(rename = "p")]
+    pub price: String,
+    /// Why the report was sent: `NEW`, `TRADE`, `CANCELED`, `EXPIRED`, ...
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    #[serde(rename = "X")]
+    pub status: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l")]
+    pub last_quantity: String,
+    #[serde(rename = "z")]
+    pub cumulative_quantity: String,
+    #[serde(rename = "L")]
+    pub last_price: String,
+    #[serde(rename = "n")]
+    pub commission: String,
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    /// -1 unless the report is a trade
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+}
+
+/// `outboundAccountPosition` event, sent with the balances an update changed
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceAccountPosition {
+    #[serde(rename = "u")]
+    pub update_time: i64,
+    #[serde(rename = "B")]
+    pub balances: Vec<BinanceWebSocketBalance>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceWebSocketBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "f")]
+    pub free: String,
+    #[serde(rename = "l")]
+    pub locked: String,
+}
+
+/// Classify a Binance spot error code and message
+pub fn binance_error_kind(code: i32, message: &str) -> Option<ErrorKind> {
+    match code {
+        -1003 | -1015 => Some(ErrorKind::RateLimited),
+        -1022 | -2014 | -2015 => Some(ErrorKind::Unauthorized),
+        -1121 => Some(ErrorKind::InvalidSymbol),
+        -2013 => Some(ErrorKind::OrderNotFound),
+        -2011 if message.contains("Unknown order") => Some(ErrorKind::OrderNotFound),
+        -2010 if message.contains("insufficient balance") => Some(ErrorKind::InsufficientBalance),
+        -2010 if message.contains("immediately match and take") => Some(ErrorKind::PostOnlyReject),
+        -2010 if message.contains("Duplicate order") => Some(ErrorKind::DuplicateOrder),
+        -1013 if message.contains("NOTIONAL") => Some(ErrorKind::MinNotional),
+        -1013 if message.contains("PRICE_FILTER") || message.contains("PERCENT_PRICE") => {
+            Some(ErrorKind::InvalidPrice)
+        }
+        -1013 if message.contains("LOT_SIZE") => Some(ErrorKind::InvalidQuantity),
+        _ => None,
+    }
+}
@@ -0,0 +1,72 @@
+use crate::core::config::ExchangeConfig;
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{ReqwestRest, RestClientBuilder, RestClientConfig, TungsteniteWs};
+use crate::exchanges::binance::{codec::BinanceCodec, signer::BinanceSigner};
+use crate::exchanges::binance_margin::{
+    connector::BinanceMarginConnector, types::binance_margin_error_kind,
+};
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
+use std::sync::Arc;
+
+/// Build the REST client, sharing the spot connector's rate limits and clock offset
+fn build_rest(rest_url: String, config: &ExchangeConfig) -> Result<ReqwestRest, ExchangeError> {
+    let rest_config = RestClientConfig::new(rest_url, "binance".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone())
+        .with_error_kinds(binance_margin_error_kind);
+
+    let mut rest_builder = RestClientBuilder::new(rest_config);
+
+    // Add authentication if credentials are provided
+    if config.has_credentials() {
+        let signer = Arc::new(BinanceSigner::new(
+            config.api_key().to_string(),
+            config.secret_key().to_string(),
+        ));
+        rest_builder = rest_builder.with_signer(signer);
+    }
+
+    rest_builder.build()
+}
+
+/// Create a Binance margin connector with REST-only support
+pub fn build_connector(
+    config: ExchangeConfig,
+) -> Result<BinanceMarginConnector<ReqwestRest, ()>, ExchangeError> {
+    let endpoints = resolve_endpoints(ExchangeId::BinanceMargin, &config)?;
+    let rest = build_rest(endpoints.rest, &config)?;
+
+    Ok(BinanceMarginConnector::new_without_ws(rest, config))
+}
+
+/// Create a Binance margin connector with WebSocket support
+pub fn build_connector_with_websocket(
+    config: ExchangeConfig,
+) -> Result<BinanceMarginConnector<ReqwestRest, TungsteniteWs<BinanceCodec>>, ExchangeError> {
+    let endpoints = resolve_endpoints(ExchangeId::BinanceMargin, &config)?;
+    let rest = build_rest(endpoints.rest, &config)?;
+
+    // Create WebSocket client
+    let ws = TungsteniteWs::new(endpoints.ws, "binance".to_string(), BinanceCodec)
+        .with_config(config.ws_config());
+
+    Ok(BinanceMarginConnector::new(rest, ws, config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::traits::OrderPlacer;
+    use crate::core::types::MarginMode;
+
+    #[test]
+    fn test_builds_without_a_testnet() {
+        let connector = build_connector(ExchangeConfig::read_only())
+            .unwrap()
+            .with_margin_mode(MarginMode::Isolated);
+        assert!(!connector.supports_trading());
+        // Binance's spot testnet serves no `/sapi` margin endpoints
+        assert!(build_connector(ExchangeConfig::read_only().testnet(true)).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::RestClient,
+    traits::AccountInfo,
+    types::{conversion, Balance, MarginMode, Position, Symbol},
+};
+use crate::exchanges::binance::conversions::SYMBOLS;
+use crate::exchanges::binance_margin::conversions::{
+    convert_isolated_balances, convert_margin_balances,
+};
+use crate::exchanges::binance_margin::rest::BinanceMarginRestClient;
+use crate::exchanges::binance_margin::types::{
+    BinanceIsolatedAccountLimit, BinanceIsolatedMarginAccount, BinanceMarginAccount,
+};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+/// Account implementation for Binance margin
+///
+/// Balances are those of the cross margin account, or of every isolated pair summed
+/// per asset in isolated mode. Loans take an isolated pair's symbol, or `None` for the
+/// cross margin account, whatever the mode.
+pub struct Account<R: RestClient> {
+    rest: BinanceMarginRestClient<R>,
+    mode: MarginMode,
+}
+
+impl<R: RestClient> Account<R> {
+    /// Create a new account manager for cross margin
+    pub fn new(rest: &R) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            rest: BinanceMarginRestClient::new(rest.clone()),
+            mode: MarginMode::Cross,
+        }
+    }
+
+    /// Report the isolated margin pairs instead of the cross margin account
+    #[must_use]
+    pub const fn with_margin_mode(mut self, mode: MarginMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Get the cross margin account with its loans and margin level
+    #[instrument(skip(self), fields(exchange = "binance_margin"))]
+    pub async fn get_margin_account(&self) -> Result<BinanceMarginAccount, ExchangeError> {
+        self.rest.get_account().await
+    }
+
+    /// Get isolated margin pairs, every one for an empty `symbols` or up to 5 of them
+    #[instrument(skip(self), fields(exchange = "binance_margin"))]
+    pub async fn get_isolated_account(
+        &self,
+        symbols: &[Symbol],
+    ) -> Result<BinanceIsolatedMarginAccount, ExchangeError> {
+        let symbols = symbols
+            .iter()
+            .map(|symbol| SYMBOLS.try_to_exchange_symbol(symbol))
+            .collect::<Result<Vec<_>, _>>()?
+            .join(",");
+        self.rest
+            .get_isolated_account((!symbols.is_empty()).then_some(symbols.as_str()))
+            .await
+    }
+
+    /// Get how much of `asset` can still be borrowed
+    #[instrument(skip(self), fields(exchange = "binance_margin"))]
+    pub async fn get_max_borrowable(
+        &self,
+        asset: &str,
+        isolated_symbol: Option<Symbol>,
+    ) -> Result<Decimal, ExchangeError> {
+        let isolated_symbol = isolated_symbol
+            .map(|symbol| SYMBOLS.try_to_exchange_symbol(&symbol))
+            .transpose()?;
+        let max = self
+            .rest
+            .get_max_borrowable(asset, isolated_symbol.as_deref())
+            .await?;
+        conversion::try_string_to_decimal(&max.amount)
+    }
+
+    /// Borrow `amount` of `asset`, returning the venue's transaction id
+    #[instrument(skip(self), fields(exchange = "binance_margin"))]
+    pub async fn borrow(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        isolated_symbol: Option<Symbol>,
+    ) -> Result<String, ExchangeError> {
+        self.borrow_repay(asset, amount, isolated_symbol, "BORROW")
+            .await
+    }
+
+    /// Repay `amount` of `asset`, interest first, returning the venue's transaction id
+    #[instrument(skip(self), fields(exchange = "binance_margin"))]
+    pub async fn repay(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        isolated_symbol: Option<Symbol>,
+    ) -> Result<String, ExchangeError> {
+        self.borrow_repay(asset, amount, isolated_symbol, "REPAY")
+            .await
+    }
+
+    async fn borrow_repay(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        isolated_symbol: Option<Symbol>,
+        kind: &str,
+    ) -> Result<String, ExchangeError> {
+        let isolated_symbol = isolated_symbol
+            .map(|symbol| SYMBOLS.try_to_exchange_symbol(&symbol))
+            .transpose()?;
+        let transaction = self
+            .rest
+            .borrow_repay(asset, &amount.to_string(), isolated_symbol.as_deref(), kind)
+            .await?;
+        Ok(transaction.tran_id.to_string())
+    }
+
+    /// Open the isolated margin pair of `symbol`, or re-enable a disabled one
+    #[instrument(skip(self), fields(exchange = "binance_margin", symbol = %symbol))]
+    pub async fn enable_isolated_pair(&self, symbol: Symbol) -> Result<(), ExchangeError> {
+        let toggle = self
+            .rest
+            .enable_isolated_pair(&SYMBOLS.try_to_exchange_symbol(&symbol)?)
+            .await?;
+        pair_toggled(toggle.success, &toggle.symbol, "enable")
+    }
+
+    /// Disable the isolated margin pair of `symbol`, freeing one of the account's slots
+    #[instrument(skip(self), fields(exchange = "binance_margin", symbol = %symbol))]
+    pub async fn disable_isolated_pair(&self, symbol: Symbol) -> Result<(), ExchangeError> {
+        let toggle = self
+            .rest
+            .disable_isolated_pair(&SYMBOLS.try_to_exchange_symbol(&symbol)?)
+            .await?;
+        pair_toggled(toggle.success, &toggle.symbol, "disable")
+    }
+
+    /// Get how many isolated margin pairs are enabled, and the most that may be
+    #[instrument(skip(self), fields(exchange = "binance_margin"))]
+    pub async fn get_isolated_account_limit(
+        &self,
+    ) -> Result<BinanceIsolatedAccountLimit, ExchangeError> {
+        self.rest.get_isolated_account_limit().await
+    }
+}
+
+fn pair_toggled(success: bool, symbol: &str, action: &str) -> Result<(), ExchangeError> {
+    if success {
+        Ok(())
+    } else {
+        Err(ExchangeError::Other(format!(
+            "Binance did not {} isolated margin pair {}",
+            action, symbol
+        )))
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_margin"))]
+    async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
+        match self.mode {
+            MarginMode::Cross => convert_margin_balances(&self.rest.get_account().await?),
+            MarginMode::Isolated => {
+                convert_isolated_balances(&self.rest.get_isolated_account(None).await?)
+            }
+        }
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_margin"))]
+    async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
+        // Margin holds assets and loans rather than positions; see `get_margin_account`
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,257 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::{
+    AccountInfo, DiagnosticsSource, HistoricalTradeSource, MarketSnapshotSource, OrderPlacer,
+    OrderQuery, RestMarketData, StreamingMarketData,
+};
+use crate::core::types::{
+    Balance, Kline, KlineInterval, MarginMode, Market, MarketDataType, Order, OrderBook,
+    OrderRequest, OrderResponse, Position, SubscriptionType, Symbol, Ticker, Trade,
+    WebSocketConfig,
+};
+use crate::core::{
+    config::ExchangeConfig, kernel::diagnostics, kernel::Diagnostics, kernel::RestClient,
+    kernel::WsSession,
+};
+use crate::exchanges::binance::{codec::BinanceCodec, connector::MarketData};
+use crate::exchanges::binance_margin::types::BinanceSideEffect;
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+pub mod account;
+pub mod trading;
+
+pub use account::Account;
+pub use trading::Trading;
+
+/// Binance margin connector that composes all sub-trait implementations
+///
+/// Market data is the spot market's, served by the spot connector's `MarketData`.
+pub struct BinanceMarginConnector<R: RestClient, W = ()> {
+    pub market: MarketData<R, W>,
+    pub trading: Trading<R>,
+    pub account: Account<R>,
+}
+
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinanceCodec> + Send + Sync>
+    BinanceMarginConnector<R, W>
+{
+    /// Create a new Binance margin connector with WebSocket support
+    pub fn new(rest: R, ws: W, config: ExchangeConfig) -> Self {
+        Self {
+            market: MarketData::<R, W>::new(&rest, Some(ws), config.testnet)
+                .with_ws_config(config.ws_config()),
+            trading: Trading::new(&rest),
+            account: Account::new(&rest),
+        }
+    }
+}
+
+impl<R: RestClient + Clone + Send + Sync> BinanceMarginConnector<R, ()> {
+    /// Create a new Binance margin connector without WebSocket support
+    pub fn new_without_ws(rest: R, config: ExchangeConfig) -> Self {
+        Self {
+            market: MarketData::<R, ()>::new(&rest, None, config.testnet)
+                .with_ws_config(config.ws_config()),
+            trading: Trading::new(&rest),
+            account: Account::new(&rest),
+        }
+    }
+}
+
+impl<R: RestClient, W> BinanceMarginConnector<R, W> {
+    /// Trade and report isolated margin pairs instead of the cross margin account
+    #[must_use]
+    pub fn with_margin_mode(self, mode: MarginMode) -> Self {
+        Self {
+            market: self.market,
+            trading: self.trading.with_margin_mode(mode),
+            account: self.account.with_margin_mode(mode),
+        }
+    }
+
+    /// Borrow or repay with every order placed, e.g. `BinanceSideEffect::AutoBorrowRepay`
+    #[must_use]
+    pub fn with_side_effect(self, side_effect: BinanceSideEffect) -> Self {
+        Self {
+            trading: self.trading.with_side_effect(side_effect),
+            ..self
+        }
+    }
+}
+
+// Implement traits for the connector by delegating to sub-components
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinanceCodec> + Send + Sync> RestMarketData
+    for BinanceMarginConnector<R, W>
+{
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.market.get_markets().await
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        self.market
+            .get_klines(symbol, interval, limit, start_time, end_time)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinanceCodec> + Send + Sync>
+    StreamingMarketData for BinanceMarginConnector<R, W>
+{
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.market
+            .subscribe_market_data(symbols, subscription_types, config)
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.market.get_websocket_url()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> RestMarketData for BinanceMarginConnector<R, ()> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.market.get_markets().await
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        self.market
+            .get_klines(symbol, interval, limit, start_time, end_time)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for BinanceMarginConnector<R, W>
+{
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        self.market.get_ticker(symbol).await
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        self.market.get_order_book_snapshot(symbol, depth).await
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        self.market.get_recent_trades(symbol, limit).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> HistoricalTradeSource
+    for BinanceMarginConnector<R, W>
+{
+    async fn get_historical_trades(
+        &self,
+        symbol: Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        self.market
+            .get_historical_trades(symbol, start_time, end_time)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderPlacer
+    for BinanceMarginConnector<R, W>
+{
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        self.trading.place_order(order).await
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        self.trading.cancel_order(symbol, order_id).await
+    }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderQuery
+    for BinanceMarginConnector<R, W>
+{
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        self.trading.get_open_orders(symbol).await
+    }
+
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        self.trading.get_order(symbol, order_id).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo
+    for BinanceMarginConnector<R, W>
+{
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
+    async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
+        self.account.get_account_balance().await
+    }
+
+    async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
+        self.account.get_positions().await
+    }
+}
+
+impl<R: RestClient, W> DiagnosticsSource for BinanceMarginConnector<R, W> {
+    /// Shared with the spot connector, which calls the same hosts under the same limits
+    fn diagnostics(&self) -> Diagnostics {
+        diagnostics("binance")
+    }
+}
@@ -0,0 +1,138 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::RestClient,
+    traits::{OrderPlacer, OrderQuery},
+    types::{MarginMode, Order, OrderRequest, OrderResponse, Symbol},
+};
+use crate::exchanges::binance::conversions::{convert_binance_order, SYMBOLS};
+use crate::exchanges::binance_margin::conversions::{
+    convert_margin_order_response, margin_order_to_json,
+};
+use crate::exchanges::binance_margin::rest::BinanceMarginRestClient;
+use crate::exchanges::binance_margin::types::BinanceSideEffect;
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Trading implementation for Binance margin
+///
+/// Orders go to the cross margin account, or in isolated mode to the isolated pair of
+/// their symbol, and borrow or repay as the side effect says.
+pub struct Trading<R: RestClient> {
+    rest: BinanceMarginRestClient<R>,
+    mode: MarginMode,
+    side_effect: BinanceSideEffect,
+}
+
+impl<R: RestClient> Trading<R> {
+    /// Create a new trading engine for cross margin without side effects
+    pub fn new(rest: &R) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            rest: BinanceMarginRestClient::new(rest.clone()),
+            mode: MarginMode::Cross,
+            side_effect: BinanceSideEffect::default(),
+        }
+    }
+
+    /// Trade the isolated pair of each order's symbol instead of the cross account
+    #[must_use]
+    pub const fn with_margin_mode(mut self, mode: MarginMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Borrow or repay with every order placed, e.g. `BinanceSideEffect::MarginBuy`
+    #[must_use]
+    pub const fn with_side_effect(mut self, side_effect: BinanceSideEffect) -> Self {
+        self.side_effect = side_effect;
+        self
+    }
+
+    const fn isolated(&self) -> bool {
+        matches!(self.mode, MarginMode::Isolated)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_margin"))]
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let body = margin_order_to_json(&order, self.isolated(), self.side_effect)?;
+        let response = self.rest.place_order(&body).await?;
+        convert_margin_order_response(&response, &order)
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_margin", symbol = %symbol, order_id = %order_id))]
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let order_id: u64 = order_id
+            .parse()
+            .map_err(|_| ExchangeError::Other(format!("Invalid order ID format: {}", order_id)))?;
+        self.rest
+            .cancel_order(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                self.isolated(),
+                Some(order_id),
+                None,
+            )
+            .await
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_margin", symbol = %symbol, client_order_id = %client_order_id))]
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.rest
+            .cancel_order(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                self.isolated(),
+                None,
+                Some(&client_order_id),
+            )
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> OrderQuery for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "binance_margin"))]
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        if self.isolated() && symbol.is_none() {
+            return Err(ExchangeError::InvalidParameters(
+                "Binance isolated margin lists open orders one symbol at a time".to_string(),
+            ));
+        }
+        let symbol = symbol
+            .map(|s| SYMBOLS.try_to_exchange_symbol(&s))
+            .transpose()?;
+        self.rest
+            .get_open_orders(symbol.as_deref(), self.isolated())
+            .await?
+            .iter()
+            .map(convert_binance_order)
+            .collect()
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_margin", symbol = %symbol, order_id = %order_id))]
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        let order_id: u64 = order_id
+            .parse()
+            .map_err(|_| ExchangeError::Other(format!("Invalid order ID format: {}", order_id)))?;
+        let order = self
+            .rest
+            .get_order(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                self.isolated(),
+                order_id,
+            )
+            .await?;
+        convert_binance_order(&order)
+    }
+}
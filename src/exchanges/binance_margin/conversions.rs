@@ -0,0 +1,264 @@
+use super::types as margin_types;
+use crate::core::errors::ExchangeError;
+use crate::core::types::{
+    conversion, Balance, OrderRequest, OrderResponse, OrderSide, OrderType, Quantity, TimeInForce,
+};
+use crate::exchanges::binance::conversions::{convert_binance_order_type, SYMBOLS};
+use margin_types::BinanceSideEffect;
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+const fn order_type_str(order_type: &OrderType) -> &'static str {
+    match order_type {
+        OrderType::Market => "MARKET",
+        OrderType::Limit => "LIMIT",
+        OrderType::StopLoss => "STOP_LOSS",
+        OrderType::StopLossLimit => "STOP_LOSS_LIMIT",
+        OrderType::TakeProfit => "TAKE_PROFIT",
+        OrderType::TakeProfitLimit => "TAKE_PROFIT_LIMIT",
+    }
+}
+
+/// Body of a `/sapi/v1/margin/order` request
+pub fn margin_order_to_json(
+    order: &OrderRequest,
+    isolated: bool,
+    side_effect: BinanceSideEffect,
+) -> Result<Value, ExchangeError> {
+    let mut body = json!({
+        "symbol": SYMBOLS.try_to_exchange_symbol(&order.symbol)?,
+        "isIsolated": if isolated { "TRUE" } else { "FALSE" },
+        "side": match order.side {
+            OrderSide::Buy => "BUY",
+            OrderSide::Sell => "SELL",
+        },
+        "type": order_type_str(&order.order_type),
+        "quantity": order.quantity.to_string(),
+        "sideEffectType": side_effect.as_str(),
+        "newOrderRespType": "RESULT",
+    });
+    if let Some(price) = order.price {
+        body["price"] = json!(price.to_string());
+    }
+    // Market and stop-market orders reject a time in force
+    if matches!(
+        order.order_type,
+        OrderType::Limit | OrderType::StopLossLimit | OrderType::TakeProfitLimit
+    ) {
+        let tif = match order.time_in_force {
+            Some(TimeInForce::IOC) => "IOC",
+            Some(TimeInForce::FOK) => "FOK",
+            Some(TimeInForce::GTC) | None => "GTC",
+        };
+        body["timeInForce"] = json!(tif);
+    }
+    if let Some(stop_price) = order.stop_price {
+        body["stopPrice"] = json!(stop_price.to_string());
+    }
+    if let Some(client_order_id) = &order.client_order_id {
+        body["newClientOrderId"] = json!(client_order_id);
+    }
+    Ok(body)
+}
+
+/// Convert a margin order acknowledgement, filling what it omits from `order`
+pub fn convert_margin_order_response(
+    response: &margin_types::BinanceMarginOrderResponse,
+    order: &OrderRequest,
+) -> Result<OrderResponse, ExchangeError> {
+    let quantity = response
+        .orig_qty
+        .as_deref()
+        .map(conversion::try_string_to_quantity)
+        .transpose()?
+        .unwrap_or(order.quantity);
+    // Market orders report a zero price
+    let price = response
+        .price
+        .as_deref()
+        .map(conversion::try_string_to_price)
+        .transpose()?
+        .filter(|price| !price.value().is_zero())
+        .or(order.price);
+    let extensions = response.margin_buy_borrow_amount.as_ref().map(|amount| {
+        json!({
+            "marginBuyBorrowAmount": amount,
+            "marginBuyBorrowAsset": response.margin_buy_borrow_asset,
+        })
+    });
+
+    Ok(OrderResponse {
+        order_id: response.order_id.to_string(),
+        client_order_id: response.client_order_id.clone(),
+        symbol: SYMBOLS.from_exchange_symbol(&response.symbol),
+        side: match response.side.as_deref() {
+            Some("SELL") => OrderSide::Sell,
+            Some(_) => OrderSide::Buy,
+            None => order.side.clone(),
+        },
+        order_type: response
+            .order_type
+            .as_deref()
+            .map_or_else(|| order.order_type.clone(), convert_binance_order_type),
+        quantity,
+        price,
+        status: response.status.clone().unwrap_or_else(|| "NEW".to_string()),
+        timestamp: response.transact_time,
+        extensions,
+    })
+}
+
+fn balance(asset: &str, free: &str, locked: &str) -> Result<Option<Balance>, ExchangeError> {
+    let free = conversion::try_string_to_quantity(free)?;
+    let locked = conversion::try_string_to_quantity(locked)?;
+    Ok(
+        (!free.value().is_zero() || !locked.value().is_zero()).then(|| Balance {
+            asset: asset.to_string(),
+            free,
+            locked,
+        }),
+    )
+}
+
+/// Non-zero balances of the cross margin account
+pub fn convert_margin_balances(
+    account: &margin_types::BinanceMarginAccount,
+) -> Result<Vec<Balance>, ExchangeError> {
+    account
+        .user_assets
+        .iter()
+        .filter_map(|asset| balance(&asset.asset, &asset.free, &asset.locked).transpose())
+        .collect()
+}
+
+/// Non-zero balances of every isolated margin pair, summed per asset
+///
+/// An asset held as collateral in several pairs is reported once; its free amount is
+/// still only usable by the pair holding it.
+pub fn convert_isolated_balances(
+    account: &margin_types::BinanceIsolatedMarginAccount,
+) -> Result<Vec<Balance>, ExchangeError> {
+    let mut totals: BTreeMap<&str, (Decimal, Decimal)> = BTreeMap::new();
+    for asset in account
+        .assets
+        .iter()
+        .flat_map(|pair| [&pair.base_asset, &pair.quote_asset])
+    {
+        if let Some(balance) = balance(&asset.asset, &asset.free, &asset.locked)? {
+            let total = totals.entry(&asset.asset).or_default();
+            total.0 += balance.free.value();
+            total.1 += balance.locked.value();
+        }
+    }
+    Ok(totals
+        .into_iter()
+        .map(|(asset, (free, locked))| Balance {
+            asset: asset.to_string(),
+            free: Quantity::new(free),
+            locked: Quantity::new(locked),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Price;
+
+    #[test]
+    fn test_margin_order_carries_mode_and_side_effect() {
+        let order = OrderRequest {
+            symbol: "BTC-USDT".into(),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: Quantity::new(Decimal::new(5, 1)),
+            price: None,
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: Some("m-1".to_string()),
+        };
+        let body = margin_order_to_json(&order, true, BinanceSideEffect::AutoBorrowRepay).unwrap();
+        assert_eq!(body["symbol"], "BTCUSDT");
+        assert_eq!(body["isIsolated"], "TRUE");
+        assert_eq!(body["sideEffectType"], "AUTO_BORROW_REPAY");
+        assert_eq!(body["newClientOrderId"], "m-1");
+        assert!(body.get("timeInForce").is_none());
+
+        // An ACK response falls back to the request
+        let response: margin_types::BinanceMarginOrderResponse = serde_json::from_value(json!({
+            "symbol": "BTCUSDT",
+            "orderId": 28,
+            "clientOrderId": "m-1",
+            "isIsolated": true,
+            "transactTime": 1_507_725_176_595_i64,
+            "marginBuyBorrowAmount": "5",
+            "marginBuyBorrowAsset": "USDT"
+        }))
+        .unwrap();
+        let converted = convert_margin_order_response(&response, &order).unwrap();
+        assert_eq!(converted.order_id, "28");
+        assert_eq!(converted.quantity, order.quantity);
+        assert_eq!(converted.timestamp, 1_507_725_176_595);
+        assert_eq!(
+            converted.extensions.unwrap()["marginBuyBorrowAsset"],
+            "USDT"
+        );
+
+        let limit = OrderRequest {
+            order_type: OrderType::Limit,
+            price: Some(Price::new(Decimal::from(40_000))),
+            client_order_id: None,
+            ..order
+        };
+        let body = margin_order_to_json(&limit, false, BinanceSideEffect::default()).unwrap();
+        assert_eq!(body["isIsolated"], "FALSE");
+        assert_eq!(body["sideEffectType"], "NO_SIDE_EFFECT");
+        assert_eq!(body["timeInForce"], "GTC");
+        assert_eq!(body["price"], "40000");
+    }
+
+    #[test]
+    fn test_isolated_balances_sum_across_pairs() {
+        let asset = |asset: &str, free: &str| {
+            json!({
+                "asset": asset,
+                "borrowEnabled": true,
+                "repayEnabled": true,
+                "free": free,
+                "locked": "0",
+                "borrowed": "0",
+                "interest": "0",
+                "netAsset": free,
+                "totalAsset": free
+            })
+        };
+        let pair = |symbol: &str, base: &str, base_free: &str, quote_free: &str| {
+            json!({
+                "symbol": symbol,
+                "baseAsset": asset(base, base_free),
+                "quoteAsset": asset("USDT", quote_free),
+                "isolatedCreated": true,
+                "enabled": true,
+                "marginLevel": "999",
+                "marginLevelStatus": "EXCESSIVE",
+                "liquidatePrice": "0",
+                "tradeEnabled": true
+            })
+        };
+        let account: margin_types::BinanceIsolatedMarginAccount = serde_json::from_value(json!({
+            "assets": [
+                pair("BTCUSDT", "BTC", "0.1", "100"),
+                pair("ETHUSDT", "ETH", "0", "50.5"),
+            ]
+        }))
+        .unwrap();
+
+        let balances = convert_isolated_balances(&account).unwrap();
+        let assets: Vec<&str> = balances.iter().map(|b| b.asset.as_str()).collect();
+        assert_eq!(assets, ["BTC", "USDT"]);
+        assert_eq!(balances[1].free.value(), Decimal::new(1505, 1));
+    }
+}
@@ -0,0 +1,17 @@
+// Market data, WebSocket codec and signing are the spot connector's
+pub mod conversions;
+pub mod rest;
+pub mod types;
+
+pub mod builder;
+pub mod connector;
+
+// Re-export main components
+pub use builder::{build_connector, build_connector_with_websocket};
+pub use connector::{Account, BinanceMarginConnector, Trading};
+pub use rest::BinanceMarginRestClient;
+pub use types::{
+    binance_margin_error_kind, BinanceIsolatedAccountLimit, BinanceIsolatedAsset,
+    BinanceIsolatedMarginAccount, BinanceIsolatedPair, BinanceMarginAccount, BinanceMarginAsset,
+    BinanceMarginOrderResponse, BinanceSideEffect,
+};
@@ -0,0 +1,192 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::RestClient;
+use crate::exchanges::binance::types::BinanceOrder;
+use crate::exchanges::binance_margin::types::{
+    BinanceIsolatedAccountLimit, BinanceIsolatedMarginAccount, BinanceIsolatedPairToggle,
+    BinanceMarginAccount, BinanceMarginOrderResponse, BinanceMarginTransaction,
+    BinanceMaxBorrowable,
+};
+use serde_json::{json, Value};
+
+/// `isIsolated` parameter of the margin endpoints
+const fn is_isolated(isolated: bool) -> &'static str {
+    if isolated {
+        "TRUE"
+    } else {
+        "FALSE"
+    }
+}
+
+/// Thin typed wrapper around `RestClient` for the Binance margin (`/sapi`) API
+///
+/// Endpoints taking an `isolated_symbol` act on that isolated margin pair, or on the
+/// cross margin account for `None`.
+pub struct BinanceMarginRestClient<R: RestClient> {
+    client: R,
+}
+
+impl<R: RestClient> BinanceMarginRestClient<R> {
+    pub fn new(client: R) -> Self {
+        Self { client }
+    }
+
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.client.can_sign()
+    }
+
+    /// Get the cross margin account
+    pub async fn get_account(&self) -> Result<BinanceMarginAccount, ExchangeError> {
+        self.client
+            .get_json("/sapi/v1/margin/account", &[], true)
+            .await
+    }
+
+    /// Get isolated margin pairs, every one or up to 5 comma-separated `symbols`
+    pub async fn get_isolated_account(
+        &self,
+        symbols: Option<&str>,
+    ) -> Result<BinanceIsolatedMarginAccount, ExchangeError> {
+        let params: Vec<(&str, &str)> = symbols.map(|s| ("symbols", s)).into_iter().collect();
+        self.client
+            .get_json("/sapi/v1/margin/isolated/account", &params, true)
+            .await
+    }
+
+    /// Get how much of `asset` the account can still borrow
+    pub async fn get_max_borrowable(
+        &self,
+        asset: &str,
+        isolated_symbol: Option<&str>,
+    ) -> Result<BinanceMaxBorrowable, ExchangeError> {
+        let mut params = vec![("asset", asset)];
+        if let Some(symbol) = isolated_symbol {
+            params.push(("isolatedSymbol", symbol));
+        }
+        self.client
+            .get_json("/sapi/v1/margin/maxBorrowable", &params, true)
+            .await
+    }
+
+    /// Borrow or repay `amount` of `asset`; `kind` is `BORROW` or `REPAY`
+    pub async fn borrow_repay(
+        &self,
+        asset: &str,
+        amount: &str,
+        isolated_symbol: Option<&str>,
+        kind: &str,
+    ) -> Result<BinanceMarginTransaction, ExchangeError> {
+        let mut body = json!({
+            "asset": asset,
+            "amount": amount,
+            "isIsolated": is_isolated(isolated_symbol.is_some()),
+            "type": kind,
+        });
+        if let Some(symbol) = isolated_symbol {
+            body["symbol"] = json!(symbol);
+        }
+        self.client
+            .post_json("/sapi/v1/margin/borrow-repay", &body, true)
+            .await
+    }
+
+    /// Open the isolated margin account of `symbol`, or re-enable it
+    pub async fn enable_isolated_pair(
+        &self,
+        symbol: &str,
+    ) -> Result<BinanceIsolatedPairToggle, ExchangeError> {
+        self.client
+            .post_json(
+                "/sapi/v1/margin/isolated/account",
+                &json!({ "symbol": symbol }),
+                true,
+            )
+            .await
+    }
+
+    /// Disable the isolated margin account of `symbol`; it must hold no debt
+    pub async fn disable_isolated_pair(
+        &self,
+        symbol: &str,
+    ) -> Result<BinanceIsolatedPairToggle, ExchangeError> {
+        let params = [("symbol", symbol)];
+        self.client
+            .delete_json("/sapi/v1/margin/isolated/account", &params, true)
+            .await
+    }
+
+    /// Get how many isolated margin pairs are enabled, and the most that may be
+    pub async fn get_isolated_account_limit(
+        &self,
+    ) -> Result<BinanceIsolatedAccountLimit, ExchangeError> {
+        self.client
+            .get_json("/sapi/v1/margin/isolated/accountLimit", &[], true)
+            .await
+    }
+
+    /// Place a margin order
+    pub async fn place_order(
+        &self,
+        order: &Value,
+    ) -> Result<BinanceMarginOrderResponse, ExchangeError> {
+        self.client
+            .post_json("/sapi/v1/margin/order", order, true)
+            .await
+    }
+
+    /// Cancel a margin order by order id or original client order id
+    pub async fn cancel_order(
+        &self,
+        symbol: &str,
+        isolated: bool,
+        order_id: Option<u64>,
+        orig_client_order_id: Option<&str>,
+    ) -> Result<(), ExchangeError> {
+        let order_id = order_id.map(|id| id.to_string());
+        let mut params = vec![("symbol", symbol), ("isIsolated", is_isolated(isolated))];
+        if let Some(order_id) = &order_id {
+            params.push(("orderId", order_id));
+        }
+        if let Some(orig_client_order_id) = orig_client_order_id {
+            params.push(("origClientOrderId", orig_client_order_id));
+        }
+        let _: Value = self
+            .client
+            .delete_json("/sapi/v1/margin/order", &params, true)
+            .await?;
+        Ok(())
+    }
+
+    /// Get open margin orders; isolated margin needs a symbol
+    pub async fn get_open_orders(
+        &self,
+        symbol: Option<&str>,
+        isolated: bool,
+    ) -> Result<Vec<BinanceOrder>, ExchangeError> {
+        let mut params = vec![("isIsolated", is_isolated(isolated))];
+        if let Some(symbol) = symbol {
+            params.push(("symbol", symbol));
+        }
+        self.client
+            .get_json("/sapi/v1/margin/openOrders", &params, true)
+            .await
+    }
+
+    /// Get a single margin order by id
+    pub async fn get_order(
+        &self,
+        symbol: &str,
+        isolated: bool,
+        order_id: u64,
+    ) -> Result<BinanceOrder, ExchangeError> {
+        let order_id = order_id.to_string();
+        let params = [
+            ("symbol", symbol),
+            ("isIsolated", is_isolated(isolated)),
+            ("orderId", order_id.as_str()),
+        ];
+        self.client
+            .get_json("/sapi/v1/margin/order", &params, true)
+            .await
+    }
+}
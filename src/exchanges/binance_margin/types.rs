@@ -0,0 +1,170 @@
+use crate::core::errors::ErrorKind;
+use crate::exchanges::binance::types::binance_error_kind;
+use serde::Deserialize;
+
+/// How a margin order borrows and repays, Binance's `sideEffectType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BinanceSideEffect {
+    /// Trade only the assets already in the margin account
+    #[default]
+    NoSideEffect,
+    /// Borrow whatever the order needs beyond the free balance
+    MarginBuy,
+    /// Repay debt in the received asset with the order's proceeds
+    AutoRepay,
+    /// Borrow what the order needs and repay it when the order is cancelled
+    AutoBorrowRepay,
+}
+
+impl BinanceSideEffect {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::NoSideEffect => "NO_SIDE_EFFECT",
+            Self::MarginBuy => "MARGIN_BUY",
+            Self::AutoRepay => "AUTO_REPAY",
+            Self::AutoBorrowRepay => "AUTO_BORROW_REPAY",
+        }
+    }
+}
+
+/// Asset of the cross margin account
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceMarginAsset {
+    pub asset: String,
+    pub free: String,
+    pub locked: String,
+    pub borrowed: String,
+    pub interest: String,
+    pub net_asset: String,
+}
+
+/// Cross margin account, from `/sapi/v1/margin/account`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceMarginAccount {
+    pub borrow_enabled: bool,
+    pub trade_enabled: bool,
+    pub transfer_enabled: bool,
+    pub margin_level: String,
+    pub total_asset_of_btc: String,
+    pub total_liability_of_btc: String,
+    pub total_net_asset_of_btc: String,
+    pub user_assets: Vec<BinanceMarginAsset>,
+}
+
+/// Base or quote side of an isolated margin pair
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceIsolatedAsset {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    pub repay_enabled: bool,
+    pub free: String,
+    pub locked: String,
+    pub borrowed: String,
+    pub interest: String,
+    pub net_asset: String,
+    pub total_asset: String,
+}
+
+/// One isolated margin pair and its own collateral
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceIsolatedPair {
+    pub symbol: String,
+    pub base_asset: BinanceIsolatedAsset,
+    pub quote_asset: BinanceIsolatedAsset,
+    pub isolated_created: bool,
+    /// Pairs disabled by the account still hold their balances but cannot trade
+    #[serde(default)]
+    pub enabled: bool,
+    pub margin_level: String,
+    pub margin_level_status: String,
+    pub liquidate_price: String,
+    pub trade_enabled: bool,
+}
+
+/// Isolated margin pairs, from `/sapi/v1/margin/isolated/account`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceIsolatedMarginAccount {
+    pub assets: Vec<BinanceIsolatedPair>,
+    #[serde(default)]
+    pub total_asset_of_btc: Option<String>,
+    #[serde(default)]
+    pub total_liability_of_btc: Option<String>,
+    #[serde(default)]
+    pub total_net_asset_of_btc: Option<String>,
+}
+
+/// Result of enabling or disabling an isolated margin pair
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceIsolatedPairToggle {
+    pub success: bool,
+    pub symbol: String,
+}
+
+/// How many isolated margin pairs the account has enabled and may enable
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceIsolatedAccountLimit {
+    pub enabled_account: u32,
+    pub max_account: u32,
+}
+
+/// Amount the account can still borrow of an asset
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceMaxBorrowable {
+    pub amount: String,
+    /// Limit of the account's VIP level
+    #[serde(default)]
+    pub borrow_limit: Option<String>,
+}
+
+/// Transaction id of a borrow or repayment
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceMarginTransaction {
+    pub tran_id: u64,
+}
+
+/// Margin order as `/sapi/v1/margin/order` acknowledges it
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceMarginOrderResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub transact_time: i64,
+    /// Only in `RESULT` and `FULL` responses
+    #[serde(default)]
+    pub price: Option<String>,
+    #[serde(default)]
+    pub orig_qty: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default, rename = "type")]
+    pub order_type: Option<String>,
+    #[serde(default)]
+    pub side: Option<String>,
+    #[serde(default)]
+    pub is_isolated: bool,
+    /// Amount borrowed by a `MARGIN_BUY` or `AUTO_BORROW_REPAY` order
+    #[serde(default)]
+    pub margin_buy_borrow_amount: Option<String>,
+    #[serde(default)]
+    pub margin_buy_borrow_asset: Option<String>,
+}
+
+/// Classify a Binance margin error code and message
+pub fn binance_margin_error_kind(code: i32, message: &str) -> Option<ErrorKind> {
+    match code {
+        // Balance is not enough, or the loan exceeds what may be borrowed
+        -3041 | -3006 => Some(ErrorKind::InsufficientBalance),
+        // Not a margin pair, or no isolated account for it
+        -3028 | -11001 => Some(ErrorKind::InvalidSymbol),
+        _ => binance_error_kind(code, message),
+    }
+}
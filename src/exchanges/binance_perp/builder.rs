@@ -2,28 +2,26 @@ use crate::core::config::ExchangeConfig;
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::{RestClientBuilder, RestClientConfig, TungsteniteWs};
 use crate::exchanges::binance_perp::{
-    codec::BinancePerpCodec, connector::BinancePerpConnector, signer::BinancePerpSigner,
+    codec::BinancePerpCodec,
+    connector::BinancePerpConnector,
+    signer::BinancePerpSigner,
+    types::{binance_perp_error_kind, BinanceFuturesApi},
 };
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
 use std::sync::Arc;
 
 /// Create a Binance Perpetual connector with REST-only support
 pub fn build_connector(
     config: ExchangeConfig,
 ) -> Result<BinancePerpConnector<crate::core::kernel::ReqwestRest, ()>, ExchangeError> {
-    // Determine base URL
-    let base_url = if config.testnet {
-        "https://testnet.binancefuture.com".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://fapi.binance.com".to_string())
-    };
+    let endpoints = resolve_endpoints(ExchangeId::BinancePerp, &config)?;
 
     // Build REST client
-    let rest_config = RestClientConfig::new(base_url, "binance_perp".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let rest_config = RestClientConfig::new(endpoints.rest, "binance_perp".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone())
+        .with_error_kinds(binance_perp_error_kind);
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -48,20 +46,13 @@ pub fn build_connector_with_websocket(
     BinancePerpConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<BinancePerpCodec>>,
     ExchangeError,
 > {
-    // Determine base URL
-    let base_url = if config.testnet {
-        "https://testnet.binancefuture.com".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://fapi.binance.com".to_string())
-    };
+    let endpoints = resolve_endpoints(ExchangeId::BinancePerp, &config)?;
 
     // Build REST client
-    let rest_config = RestClientConfig::new(base_url, "binance_perp".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let rest_config = RestClientConfig::new(endpoints.rest, "binance_perp".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone())
+        .with_error_kinds(binance_perp_error_kind);
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -77,17 +68,22 @@ pub fn build_connector_with_websocket(
     let rest = rest_builder.build()?;
 
     // Create WebSocket client
-    let ws_url = if config.testnet {
-        "wss://stream.binancefuture.com/ws".to_string()
-    } else {
-        "wss://fstream.binance.com/ws".to_string()
-    };
-
-    let ws = TungsteniteWs::new(ws_url, "binance_perp".to_string(), BinancePerpCodec);
+    let ws = TungsteniteWs::new(endpoints.ws, "binance_perp".to_string(), BinancePerpCodec)
+        .with_config(config.ws_config());
 
     Ok(BinancePerpConnector::new(rest, ws, config))
 }
 
+/// Create a Binance USDⓈ-M connector for public market data, ignoring any credentials in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<
+    BinancePerpConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<BinancePerpCodec>>,
+    ExchangeError,
+> {
+    build_connector_with_websocket(config.without_credentials())
+}
+
 /// Create a Binance Perpetual connector with WebSocket and auto-reconnection support
 pub fn build_connector_with_reconnection(
     config: ExchangeConfig,
@@ -98,20 +94,13 @@ pub fn build_connector_with_reconnection(
     >,
     ExchangeError,
 > {
-    // Determine base URL
-    let base_url = if config.testnet {
-        "https://testnet.binancefuture.com".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://fapi.binance.com".to_string())
-    };
+    let endpoints = resolve_endpoints(ExchangeId::BinancePerp, &config)?;
 
     // Build REST client
-    let rest_config = RestClientConfig::new(base_url, "binance_perp".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let rest_config = RestClientConfig::new(endpoints.rest, "binance_perp".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone())
+        .with_error_kinds(binance_perp_error_kind);
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -127,21 +116,69 @@ pub fn build_connector_with_reconnection(
     let rest = rest_builder.build()?;
 
     // Create WebSocket client with auto-reconnection
-    let ws_url = if config.testnet {
-        "wss://stream.binancefuture.com/ws".to_string()
-    } else {
-        "wss://fstream.binance.com/ws".to_string()
-    };
-
-    let base_ws = TungsteniteWs::new(ws_url, "binance_perp".to_string(), BinancePerpCodec);
+    let base_ws = TungsteniteWs::new(endpoints.ws, "binance_perp".to_string(), BinancePerpCodec)
+        .with_config(config.ws_config());
     let reconnect_ws = crate::core::kernel::ReconnectWs::new(base_ws)
-        .with_max_reconnect_attempts(10)
-        .with_reconnect_delay(std::time::Duration::from_secs(2))
+        .with_profile(config.profile)
         .with_auto_resubscribe(true);
 
     Ok(BinancePerpConnector::new(rest, reconnect_ws, config))
 }
 
+/// Create a Binance COIN-M (inverse) futures connector with REST-only support
+pub fn build_coin_margined_connector(
+    config: ExchangeConfig,
+) -> Result<BinancePerpConnector<crate::core::kernel::ReqwestRest, ()>, ExchangeError> {
+    let rest = build_rest_client(&config, BinanceFuturesApi::CoinM)?;
+    Ok(BinancePerpConnector::new_without_ws(rest, config).with_api(BinanceFuturesApi::CoinM))
+}
+
+/// Create a Binance COIN-M (inverse) futures connector with WebSocket support
+pub fn build_coin_margined_connector_with_websocket(
+    config: ExchangeConfig,
+) -> Result<
+    BinancePerpConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<BinancePerpCodec>>,
+    ExchangeError,
+> {
+    let api = BinanceFuturesApi::CoinM;
+    let rest = build_rest_client(&config, api)?;
+    let ws = TungsteniteWs::new(
+        api.ws_url(config.testnet).to_string(),
+        "binance_perp".to_string(),
+        BinancePerpCodec,
+    )
+    .with_config(config.ws_config());
+
+    Ok(BinancePerpConnector::new(rest, ws, config).with_api(api))
+}
+
+fn build_rest_client(
+    config: &ExchangeConfig,
+    api: BinanceFuturesApi,
+) -> Result<crate::core::kernel::ReqwestRest, ExchangeError> {
+    let base_url = config
+        .base_url
+        .clone()
+        .unwrap_or_else(|| api.rest_base_url(config.testnet).to_string());
+
+    let rest_config = RestClientConfig::new(base_url, "binance_perp".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone())
+        .with_error_kinds(binance_perp_error_kind);
+
+    let mut rest_builder = RestClientBuilder::new(rest_config);
+
+    if config.has_credentials() {
+        let signer = Arc::new(BinancePerpSigner::new(
+            config.api_key().to_string(),
+            config.secret_key().to_string(),
+        ));
+        rest_builder = rest_builder.with_signer(signer);
+    }
+
+    rest_builder.build()
+}
+
 /// Legacy function for backward compatibility
 pub fn create_binance_perp_connector(
     config: ExchangeConfig,
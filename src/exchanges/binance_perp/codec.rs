@@ -1,5 +1,6 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::WsCodec;
+use crate::core::types::OrderBookMode;
 use serde_json::{json, Value};
 use tokio_tungstenite::tungstenite::Message;
 
@@ -9,7 +10,7 @@ pub enum BinancePerpMessage {
     OrderBook(super::types::BinancePerpWebSocketOrderBook),
     Trade(super::types::BinancePerpWebSocketTrade),
     Kline(super::types::BinancePerpWebSocketKline),
-    FundingRate(super::types::BinancePerpFundingRate),
+    MarkPrice(super::types::BinancePerpWebSocketMarkPrice),
     Unknown,
 }
 
@@ -44,6 +45,15 @@ impl WsCodec for BinancePerpCodec {
         Ok(Message::Text(unsubscription.to_string()))
     }
 
+    fn exchange_time(&self, message: &Self::Message) -> Option<i64> {
+        match message {
+            BinancePerpMessage::Ticker(ticker) => Some(ticker.close_time),
+            BinancePerpMessage::Trade(trade) => Some(trade.time),
+            BinancePerpMessage::MarkPrice(mark) => Some(mark.event_time),
+            _ => None,
+        }
+    }
+
     fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
         let text = match message {
             Message::Text(text) => text,
@@ -103,6 +113,15 @@ impl BinancePerpCodec {
                     ExchangeError::DeserializationError(format!("Failed to parse orderbook: {}", e))
                 })?;
             Ok(BinancePerpMessage::OrderBook(orderbook))
+        } else if stream.contains("@aggTrade") {
+            let trade: super::types::BinancePerpWebSocketAggTrade =
+                serde_json::from_value(data.clone()).map_err(|e| {
+                    ExchangeError::DeserializationError(format!(
+                        "Failed to parse aggregate trade: {}",
+                        e
+                    ))
+                })?;
+            Ok(BinancePerpMessage::Trade(trade.into_trade()))
         } else if stream.contains("@trade") {
             let trade: super::types::BinancePerpWebSocketTrade =
                 serde_json::from_value(data.clone()).map_err(|e| {
@@ -116,15 +135,14 @@ impl BinancePerpCodec {
                 })?;
             Ok(BinancePerpMessage::Kline(kline))
         } else if stream.contains("@markPrice") {
-            // Handle funding rate data if it contains funding rate info
-            #[allow(clippy::option_if_let_else)]
-            if let Ok(funding_rate) =
-                serde_json::from_value::<super::types::BinancePerpFundingRate>(data.clone())
-            {
-                Ok(BinancePerpMessage::FundingRate(funding_rate))
-            } else {
-                Ok(BinancePerpMessage::Unknown)
-            }
+            let mark_price: super::types::BinancePerpWebSocketMarkPrice =
+                serde_json::from_value(data.clone()).map_err(|e| {
+                    ExchangeError::DeserializationError(format!(
+                        "Failed to parse mark price: {}",
+                        e
+                    ))
+                })?;
+            Ok(BinancePerpMessage::MarkPrice(mark_price))
         } else {
             Ok(BinancePerpMessage::Unknown)
         }
@@ -163,6 +181,16 @@ impl BinancePerpCodec {
                     })?;
                 Ok(BinancePerpMessage::Trade(trade))
             }
+            "aggTrade" => {
+                let trade: super::types::BinancePerpWebSocketAggTrade =
+                    serde_json::from_value(data.clone()).map_err(|e| {
+                        ExchangeError::DeserializationError(format!(
+                            "Failed to parse aggregate trade: {}",
+                            e
+                        ))
+                    })?;
+                Ok(BinancePerpMessage::Trade(trade.into_trade()))
+            }
             "kline" => {
                 let kline: super::types::BinancePerpWebSocketKline =
                     serde_json::from_value(data.clone()).map_err(|e| {
@@ -171,20 +199,23 @@ impl BinancePerpCodec {
                 Ok(BinancePerpMessage::Kline(kline))
             }
             "markPriceUpdate" => {
-                #[allow(clippy::option_if_let_else)]
-                if let Ok(funding_rate) =
-                    serde_json::from_value::<super::types::BinancePerpFundingRate>(data.clone())
-                {
-                    Ok(BinancePerpMessage::FundingRate(funding_rate))
-                } else {
-                    Ok(BinancePerpMessage::Unknown)
-                }
+                let mark_price: super::types::BinancePerpWebSocketMarkPrice =
+                    serde_json::from_value(data.clone()).map_err(|e| {
+                        ExchangeError::DeserializationError(format!(
+                            "Failed to parse mark price: {}",
+                            e
+                        ))
+                    })?;
+                Ok(BinancePerpMessage::MarkPrice(mark_price))
             }
             _ => Ok(BinancePerpMessage::Unknown),
         }
     }
 }
 
+/// Partial book depth streams offered by Binance USD-M futures
+const BINANCE_DEPTH_LEVELS: [u32; 3] = [5, 10, 20];
+
 /// Create Binance Perpetual stream identifiers for WebSocket subscriptions
 pub fn create_binance_perp_stream_identifiers(
     symbols: &[String],
@@ -199,16 +230,27 @@ pub fn create_binance_perp_stream_identifiers(
                 crate::core::types::SubscriptionType::Ticker => {
                     streams.push(format!("{}@ticker", lower_symbol));
                 }
-                crate::core::types::SubscriptionType::OrderBook { depth } => {
-                    if let Some(d) = depth {
-                        streams.push(format!("{}@depth{}@100ms", lower_symbol, d));
-                    } else {
-                        streams.push(format!("{}@depth@100ms", lower_symbol));
-                    }
+                crate::core::types::SubscriptionType::OrderBook { depth, mode } => {
+                    let level = depth.map_or(20, |d| {
+                        crate::core::types::SubscriptionType::depth_level(d, &BINANCE_DEPTH_LEVELS)
+                    });
+                    streams.push(match mode {
+                        OrderBookMode::Snapshot => {
+                            format!("{}@depth{}@100ms", lower_symbol, level)
+                        }
+                        OrderBookMode::Delta => format!("{}@depth@100ms", lower_symbol),
+                        // The slowest speed futures partial books are pushed at
+                        OrderBookMode::Aggregated => {
+                            format!("{}@depth{}@500ms", lower_symbol, level)
+                        }
+                    });
                 }
                 crate::core::types::SubscriptionType::Trades => {
                     streams.push(format!("{}@trade", lower_symbol));
                 }
+                crate::core::types::SubscriptionType::AggTrades => {
+                    streams.push(format!("{}@aggTrade", lower_symbol));
+                }
                 crate::core::types::SubscriptionType::Klines { interval } => {
                     streams.push(format!(
                         "{}@kline_{}",
@@ -216,15 +258,12 @@ pub fn create_binance_perp_stream_identifiers(
                         interval.to_binance_format()
                     ));
                 }
+                crate::core::types::SubscriptionType::MarkPrice => {
+                    streams.push(format!("{}@markPrice@1s", lower_symbol));
+                }
             }
         }
     }
 
-    // Add funding rate streams for perpetual futures
-    for symbol in symbols {
-        let lower_symbol = symbol.to_lowercase();
-        streams.push(format!("{}@markPrice", lower_symbol));
-    }
-
     streams
 }
@@ -1,12 +1,17 @@
 use crate::core::{
     errors::ExchangeError,
     kernel::RestClient,
-    traits::AccountInfo,
-    types::{Balance, Position},
+    traits::{AccountInfo, AccountSummarySource, TradeHistorySource},
+    types::{AccountFill, AccountSummary, Balance, Position, Symbol},
 };
+use crate::exchanges::binance_perp::conversions::SYMBOLS;
 use crate::exchanges::binance_perp::{
-    conversions::{convert_binance_perp_balance, convert_binance_perp_position},
+    conversions::{
+        convert_binance_perp_account_summary, convert_binance_perp_balance,
+        convert_binance_perp_position, convert_binance_perp_user_trade,
+    },
     rest::BinancePerpRestClient,
+    types::BinanceFuturesApi,
 };
 use async_trait::async_trait;
 use tracing::instrument;
@@ -26,10 +31,21 @@ impl<R: RestClient> Account<R> {
             rest: BinancePerpRestClient::new(rest.clone()),
         }
     }
+
+    /// Target a different futures API family (USDⓈ-M by default)
+    #[must_use]
+    pub fn with_api(mut self, api: BinanceFuturesApi) -> Self {
+        self.rest = self.rest.with_api(api);
+        self
+    }
 }
 
 #[async_trait]
 impl<R: RestClient> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     #[instrument(skip(self), fields(exchange = "binance_perp"))]
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         let account_info = self.rest.get_account_info().await?;
@@ -38,10 +54,12 @@ impl<R: RestClient> AccountInfo for Account<R> {
             .iter()
             .map(convert_binance_perp_balance)
             .filter(|balance| {
-                balance.free.value() > rust_decimal::Decimal::ZERO
-                    || balance.locked.value() > rust_decimal::Decimal::ZERO
+                balance.as_ref().map_or(true, |balance| {
+                    balance.free.value() > rust_decimal::Decimal::ZERO
+                        || balance.locked.value() > rust_decimal::Decimal::ZERO
+                })
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         Ok(balances)
     }
 
@@ -51,8 +69,49 @@ impl<R: RestClient> AccountInfo for Account<R> {
         let converted_positions = positions
             .iter()
             .map(convert_binance_perp_position)
-            .filter(|position| position.position_amount.value() != rust_decimal::Decimal::ZERO)
-            .collect();
+            .filter(|position| {
+                position.as_ref().map_or(true, |position| {
+                    position.position_amount.value() != rust_decimal::Decimal::ZERO
+                })
+            })
+            .collect::<Result<_, _>>()?;
         Ok(converted_positions)
     }
 }
+
+#[async_trait]
+impl<R: RestClient> AccountSummarySource for Account<R> {
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    async fn get_account_summary(&self) -> Result<AccountSummary, ExchangeError> {
+        if self.rest.api() == BinanceFuturesApi::CoinM {
+            return Err(ExchangeError::NotSupported(
+                "COIN-M accounts are margined per asset; use get_account_balance".to_string(),
+            ));
+        }
+        let account_info = self.rest.get_account_info().await?;
+        convert_binance_perp_account_summary(&account_info)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> TradeHistorySource for Account<R> {
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    async fn get_my_trades(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AccountFill>, ExchangeError> {
+        let trades = self
+            .rest
+            .get_user_trades(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                start_time,
+                end_time,
+                limit,
+            )
+            .await?;
+        trades.iter().map(convert_binance_perp_user_trade).collect()
+    }
+}
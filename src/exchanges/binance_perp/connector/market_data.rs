@@ -1,48 +1,106 @@
 use crate::core::{
     errors::ExchangeError,
-    kernel::{RestClient, WsSession},
-    traits::{FundingRateSource, MarketDataSource},
+    kernel::{RestClient, TungsteniteWs, WsConfig, WsSession},
+    streaming::{SharedHub, StreamRouter},
+    traits::{
+        FundingRateSource, HistoricalTradeSource, MarketSnapshotSource, RestMarketData,
+        StreamingMarketData,
+    },
     types::{
-        FundingRate, Kline, KlineInterval, Market, MarketDataType, SubscriptionType,
-        WebSocketConfig,
+        FundingRate, Kline, KlineInterval, Market, MarketDataType, OrderBook, SubscriptionType,
+        Symbol, Ticker, Trade, WebSocketConfig,
     },
 };
+use crate::exchanges::binance_perp::conversions::SYMBOLS;
 use crate::exchanges::binance_perp::{
-    codec::BinancePerpCodec,
-    conversions::{convert_binance_perp_market, convert_binance_perp_rest_kline},
+    codec::{create_binance_perp_stream_identifiers, BinancePerpCodec, BinancePerpMessage},
+    conversions::{
+        convert_binance_perp_agg_trade, convert_binance_perp_market,
+        convert_binance_perp_rest_kline, convert_binance_perp_rest_order_book,
+        convert_binance_perp_rest_ticker, convert_binance_perp_rest_trade,
+    },
     rest::BinancePerpRestClient,
+    types::BinanceFuturesApi,
 };
 use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Duration;
 use tokio::sync::mpsc;
-use tracing::instrument;
+use tracing::{instrument, warn};
+
+/// Routes Binance Perpetual combined-stream updates
+struct BinancePerpRouter;
+
+impl StreamRouter for BinancePerpRouter {
+    type Codec = BinancePerpCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        Ok(create_binance_perp_stream_identifiers(
+            &[SYMBOLS.try_to_exchange_symbol(symbol)?],
+            std::slice::from_ref(subscription),
+        )
+        .pop())
+    }
+
+    fn convert(&self, message: BinancePerpMessage) -> Vec<MarketDataType> {
+        match convert_binance_perp_message_to_market_data(message) {
+            Ok(update) => update.into_iter().collect(),
+            Err(e) => {
+                warn!(error = %e, "Dropping malformed Binance Perp message");
+                Vec::new()
+            }
+        }
+    }
+}
 
 /// Market data implementation for Binance Perpetual
 pub struct MarketData<R: RestClient, W = ()> {
     rest: BinancePerpRestClient<R>,
-    #[allow(dead_code)] // May be used for future WebSocket functionality
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
     ws: Option<W>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
     testnet: bool,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
 }
 
 impl<R: RestClient + Clone, W> MarketData<R, W> {
     fn ws_url(&self) -> String {
-        if self.testnet {
-            "wss://stream.binancefuture.com/ws".to_string()
-        } else {
-            "wss://fstream.binance.com/ws".to_string()
-        }
+        self.rest.api().ws_url(self.testnet).to_string()
+    }
+
+    /// Target a different futures API family (USDⓈ-M by default)
+    #[must_use]
+    pub fn with_api(mut self, api: BinanceFuturesApi) -> Self {
+        self.rest = self.rest.with_api(api);
+        self
     }
 
     /// Convert Binance Perpetual funding rate to core type
     fn convert_funding_rate(
         &self,
         binance_rate: &crate::exchanges::binance_perp::types::BinancePerpFundingRate,
+        intervals: &HashMap<String, Duration>,
     ) -> FundingRate {
         FundingRate {
-            symbol: crate::core::types::conversion::string_to_symbol(&binance_rate.symbol),
-            funding_rate: Some(crate::core::types::conversion::string_to_decimal(
+            symbol: SYMBOLS.from_exchange_symbol(&binance_rate.symbol),
+            funding_rate: crate::core::types::conversion::try_string_to_decimal(
                 &binance_rate.funding_rate,
-            )),
+            )
+            .ok(),
             previous_funding_rate: None,
             next_funding_rate: None,
             funding_time: Some(binance_rate.funding_time),
@@ -53,6 +111,7 @@ impl<R: RestClient + Clone, W> MarketData<R, W> {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
                 .as_millis() as i64,
+            funding_interval: Some(funding_interval_for(intervals, &binance_rate.symbol)),
         }
     }
 
@@ -61,26 +120,52 @@ impl<R: RestClient + Clone, W> MarketData<R, W> {
         &self,
         binance_rate: &crate::exchanges::binance_perp::types::BinancePerpFundingRate,
         premium_index: &crate::exchanges::binance_perp::types::BinancePerpPremiumIndex,
+        intervals: &HashMap<String, Duration>,
     ) -> FundingRate {
         FundingRate {
-            symbol: crate::core::types::conversion::string_to_symbol(&binance_rate.symbol),
-            funding_rate: Some(crate::core::types::conversion::string_to_decimal(
+            symbol: SYMBOLS.from_exchange_symbol(&binance_rate.symbol),
+            funding_rate: crate::core::types::conversion::try_string_to_decimal(
                 &binance_rate.funding_rate,
-            )),
+            )
+            .ok(),
             previous_funding_rate: None,
             next_funding_rate: None,
             funding_time: Some(binance_rate.funding_time),
             next_funding_time: Some(premium_index.next_funding_time),
-            mark_price: Some(crate::core::types::conversion::string_to_price(
+            mark_price: crate::core::types::conversion::try_string_to_price(
                 &premium_index.mark_price,
-            )),
-            index_price: Some(crate::core::types::conversion::string_to_price(
+            )
+            .ok(),
+            index_price: crate::core::types::conversion::try_string_to_price(
                 &premium_index.index_price,
-            )),
+            )
+            .ok(),
             timestamp: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
                 .as_millis() as i64,
+            funding_interval: Some(funding_interval_for(intervals, &binance_rate.symbol)),
+        }
+    }
+}
+
+impl<R: RestClient + Clone, W: Sync> MarketData<R, W> {
+    /// Funding interval per symbol, falling back to 8h when the venue lists no adjustment
+    async fn funding_intervals(&self) -> HashMap<String, Duration> {
+        match self.rest.get_funding_info().await {
+            Ok(info) => info
+                .into_iter()
+                .map(|i| {
+                    (
+                        i.symbol,
+                        Duration::from_secs(i.funding_interval_hours * 3600),
+                    )
+                })
+                .collect(),
+            Err(e) => {
+                warn!("Failed to fetch funding intervals, assuming 8h: {}", e);
+                HashMap::new()
+            }
         }
     }
 }
@@ -91,7 +176,9 @@ impl<R: RestClient + Clone, W: WsSession<BinancePerpCodec>> MarketData<R, W> {
         Self {
             rest: BinancePerpRestClient::new(rest.clone()),
             ws,
+            hub: SharedHub::new(),
             testnet,
+            ws_config: WsConfig::default(),
         }
     }
 }
@@ -102,110 +189,38 @@ impl<R: RestClient + Clone> MarketData<R, ()> {
         Self {
             rest: BinancePerpRestClient::new(rest.clone()),
             ws: None,
+            hub: SharedHub::new(),
             testnet,
+            ws_config: WsConfig::default(),
         }
     }
 }
 
 #[async_trait]
-impl<R: RestClient + Clone, W: WsSession<BinancePerpCodec>> MarketDataSource for MarketData<R, W> {
+impl<R: RestClient + Clone, W: WsSession<BinancePerpCodec>> RestMarketData for MarketData<R, W> {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         let exchange_info = self.rest.get_exchange_info().await?;
         let markets = exchange_info
             .symbols
             .into_iter()
             .map(convert_binance_perp_market)
-            .collect();
+            .collect::<Result<_, _>>()?;
         Ok(markets)
     }
 
-    async fn subscribe_market_data(
-        &self,
-        symbols: Vec<String>,
-        subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        // Use the codec helper to create stream identifiers
-        let streams = crate::exchanges::binance_perp::codec::create_binance_perp_stream_identifiers(
-            &symbols,
-            &subscription_types,
-        );
-
-        // Create WebSocket URL
-        let ws_url = self.ws_url();
-        let full_url = build_binance_stream_url(&ws_url, &streams);
-
-        // Use kernel WebSocket implementation with BinancePerpCodec
-        let codec = crate::exchanges::binance_perp::codec::BinancePerpCodec;
-        let ws_session = crate::core::kernel::ws::TungsteniteWs::new(
-            full_url,
-            "binance_perp".to_string(),
-            codec,
-        );
-
-        // Add reconnection wrapper for production reliability
-        let mut reconnect_ws = crate::core::kernel::ws::ReconnectWs::new(ws_session)
-            .with_auto_resubscribe(true)
-            .with_max_reconnect_attempts(u32::MAX);
-
-        // Connect and subscribe
-        reconnect_ws.connect().await.map_err(|e| {
-            ExchangeError::Other(format!(
-                "Failed to connect to WebSocket for symbols: {:?}, error: {}",
-                symbols, e
-            ))
-        })?;
-
-        if !streams.is_empty() {
-            let stream_refs: Vec<&str> = streams.iter().map(|s| s.as_str()).collect();
-            reconnect_ws.subscribe(&stream_refs).await.map_err(|e| {
-                ExchangeError::Other(format!(
-                    "Failed to subscribe to streams: {:?}, error: {}",
-                    streams, e
-                ))
-            })?;
-        }
-
-        // Create channel for messages
-        let (tx, rx) = mpsc::channel(1000);
-
-        // Spawn task to handle messages
-        tokio::spawn(async move {
-            while let Some(result) = reconnect_ws.next_message().await {
-                match result {
-                    Ok(binance_perp_message) => {
-                        // Convert BinancePerpMessage to MarketDataType
-                        if let Some(market_data) =
-                            convert_binance_perp_message_to_market_data(binance_perp_message)
-                        {
-                            if tx.send(market_data).await.is_err() {
-                                break; // Receiver dropped
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("WebSocket error: {:?}", e);
-                        // Continue processing to handle reconnection
-                    }
-                }
-            }
-        });
-
-        Ok(rx)
-    }
-
-    fn get_websocket_url(&self) -> String {
-        self.ws_url()
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
     }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let klines = self
             .rest
             .get_klines(&symbol, interval, limit, start_time, end_time)
@@ -214,52 +229,74 @@ impl<R: RestClient + Clone, W: WsSession<BinancePerpCodec>> MarketDataSource for
         let converted_klines = klines
             .into_iter()
             .map(|k| {
-                let mut kline = convert_binance_perp_rest_kline(&k);
-                kline.symbol = crate::core::types::conversion::string_to_symbol(&symbol);
+                let mut kline = convert_binance_perp_rest_kline(&k)?;
+                kline.symbol = SYMBOLS.from_exchange_symbol(&symbol);
                 kline.interval = interval.to_string();
-                kline
+                Ok(kline)
             })
-            .collect();
+            .collect::<Result<_, ExchangeError>>()?;
 
         Ok(converted_klines)
     }
 }
 
 #[async_trait]
-impl<R: RestClient + Clone> MarketDataSource for MarketData<R, ()> {
+impl<R: RestClient + Clone, W: WsSession<BinancePerpCodec>> StreamingMarketData
+    for MarketData<R, W>
+{
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(
+                        combined_stream_url(&self.ws_url()),
+                        "binance_perp".to_string(),
+                        BinancePerpCodec,
+                    )
+                    .with_config(self.ws_config.clone())
+                },
+                BinancePerpRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.ws_url()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone> RestMarketData for MarketData<R, ()> {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         let exchange_info = self.rest.get_exchange_info().await?;
         let markets = exchange_info
             .symbols
             .into_iter()
             .map(convert_binance_perp_market)
-            .collect();
+            .collect::<Result<_, _>>()?;
         Ok(markets)
     }
 
-    async fn subscribe_market_data(
-        &self,
-        _symbols: Vec<String>,
-        _subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        Err(ExchangeError::WebSocketError(
-            "WebSocket not available in REST-only mode".to_string(),
-        ))
-    }
-
-    fn get_websocket_url(&self) -> String {
-        self.ws_url()
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
     }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let klines = self
             .rest
             .get_klines(&symbol, interval, limit, start_time, end_time)
@@ -268,24 +305,120 @@ impl<R: RestClient + Clone> MarketDataSource for MarketData<R, ()> {
         let converted_klines = klines
             .into_iter()
             .map(|k| {
-                let mut kline = convert_binance_perp_rest_kline(&k);
-                kline.symbol = crate::core::types::conversion::string_to_symbol(&symbol);
+                let mut kline = convert_binance_perp_rest_kline(&k)?;
+                kline.symbol = SYMBOLS.from_exchange_symbol(&symbol);
                 kline.interval = interval.to_string();
-                kline
+                Ok(kline)
             })
-            .collect();
+            .collect::<Result<_, ExchangeError>>()?;
 
         Ok(converted_klines)
     }
 }
 
+/// Depths served by `/fapi/v1/depth`
+const BOOK_DEPTHS: [u32; 7] = [5, 10, 20, 50, 100, 500, 1000];
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> MarketSnapshotSource for MarketData<R, W> {
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let ticker = self
+            .rest
+            .get_ticker(&SYMBOLS.try_to_exchange_symbol(&symbol)?)
+            .await?;
+        convert_binance_perp_rest_ticker(&ticker)
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let limit = depth.map(|depth| SubscriptionType::depth_level(depth, &BOOK_DEPTHS));
+        let book = self.rest.get_order_book(&symbol, limit).await?;
+        Ok(convert_binance_perp_rest_order_book(&book, &symbol)?.truncated(depth))
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        self.rest
+            .get_recent_trades(&symbol, limit)
+            .await?
+            .iter()
+            .map(|trade| convert_binance_perp_rest_trade(trade, &symbol))
+            .collect()
+    }
+}
+
+/// Longest `startTime`..`endTime` span `/fapi/v1/aggTrades` accepts
+const AGG_TRADES_WINDOW_MS: i64 = 60 * 60 * 1000;
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> HistoricalTradeSource for MarketData<R, W> {
+    /// Walks the range an hour at a time until the first trade, then pages by
+    /// aggregate id
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    async fn get_historical_trades(
+        &self,
+        symbol: Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let mut trades = Vec::new();
+
+        let mut window_start = start_time;
+        let mut page = loop {
+            if window_start > end_time {
+                return Ok(trades);
+            }
+            let window_end = (window_start + AGG_TRADES_WINDOW_MS - 1).min(end_time);
+            let page = self
+                .rest
+                .get_agg_trades(&symbol, None, Some(window_start), Some(window_end))
+                .await?;
+            if !page.is_empty() {
+                break page;
+            }
+            window_start = window_end + 1;
+        };
+
+        while let Some(last) = page.last() {
+            let next_id = last.aggregate_id + 1;
+            for trade in &page {
+                if trade.time > end_time {
+                    return Ok(trades);
+                }
+                trades.push(convert_binance_perp_agg_trade(trade, &symbol)?);
+            }
+            page = self
+                .rest
+                .get_agg_trades(&symbol, Some(next_id), None, None)
+                .await?;
+        }
+
+        Ok(trades)
+    }
+}
+
 #[async_trait]
 impl<R: RestClient + Clone, W: Send + Sync> FundingRateSource for MarketData<R, W> {
     #[instrument(skip(self), fields(exchange = "binance_perp"))]
     async fn get_funding_rates(
         &self,
-        symbols: Option<Vec<String>>,
+        symbols: Option<Vec<Symbol>>,
     ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let symbols: Option<Vec<String>> =
+            symbols.map(|symbols| symbols.iter().map(ToString::to_string).collect());
+        let intervals = self.funding_intervals().await;
         if let Some(symbols) = symbols {
             let mut all_rates = Vec::new();
             for symbol in symbols {
@@ -294,8 +427,11 @@ impl<R: RestClient + Clone, W: Send + Sync> FundingRateSource for MarketData<R,
                     self.rest.get_funding_rate(&symbol),
                     self.rest.get_premium_index(&symbol)
                 )?;
-                all_rates
-                    .push(self.convert_funding_rate_with_premium(&funding_rate, &premium_index));
+                all_rates.push(self.convert_funding_rate_with_premium(
+                    &funding_rate,
+                    &premium_index,
+                    &intervals,
+                ));
             }
             Ok(all_rates)
         } else {
@@ -304,73 +440,81 @@ impl<R: RestClient + Clone, W: Send + Sync> FundingRateSource for MarketData<R,
             // So we'll just use the basic conversion for now
             Ok(rates
                 .iter()
-                .map(|rate| self.convert_funding_rate(rate))
+                .map(|rate| self.convert_funding_rate(rate, &intervals))
                 .collect())
         }
     }
 
     #[instrument(skip(self), fields(exchange = "binance_perp"))]
     async fn get_all_funding_rates(&self) -> Result<Vec<FundingRate>, ExchangeError> {
-        let rates = self.rest.get_all_funding_rates().await?;
+        let (rates, intervals) =
+            tokio::join!(self.rest.get_all_funding_rates(), self.funding_intervals());
+        let rates = rates?;
         // For performance reasons with getting all funding rates, we'll use basic conversion
         // Individual funding rate requests will use the premium index for complete data
         Ok(rates
             .iter()
-            .map(|rate| self.convert_funding_rate(rate))
+            .map(|rate| self.convert_funding_rate(rate, &intervals))
             .collect())
     }
 
     #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
     async fn get_funding_rate_history(
         &self,
-        symbol: String,
+        symbol: Symbol,
         start_time: Option<i64>,
         end_time: Option<i64>,
         limit: Option<u32>,
     ) -> Result<Vec<FundingRate>, ExchangeError> {
-        let rates = self
-            .rest
-            .get_funding_rate_history(&symbol, start_time, end_time, limit)
-            .await?;
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let (rates, intervals) = tokio::join!(
+            self.rest
+                .get_funding_rate_history(&symbol, start_time, end_time, limit),
+            self.funding_intervals()
+        );
+        let rates = rates?;
 
         Ok(rates
             .iter()
-            .map(|rate| self.convert_funding_rate(rate))
+            .map(|rate| self.convert_funding_rate(rate, &intervals))
             .collect())
     }
 }
 
-/// Helper function to build Binance WebSocket URLs for combined streams
-fn build_binance_stream_url(base_url: &str, streams: &[String]) -> String {
-    if streams.is_empty() {
-        return base_url.to_string();
-    }
+const DEFAULT_FUNDING_INTERVAL: Duration = Duration::from_secs(8 * 60 * 60);
+
+fn funding_interval_for(intervals: &HashMap<String, Duration>, symbol: &str) -> Duration {
+    intervals
+        .get(symbol)
+        .copied()
+        .unwrap_or(DEFAULT_FUNDING_INTERVAL)
+}
 
-    // For combined streams, Binance expects /ws/stream?streams=...
-    let base = base_url
-        .strip_suffix("/ws")
-        .map_or(base_url, |stripped| stripped);
-    format!("{}/stream?streams={}", base, streams.join("/"))
+/// Combined-stream endpoint, whose messages name the stream they came from
+fn combined_stream_url(ws_url: &str) -> String {
+    format!("{}/stream", ws_url.strip_suffix("/ws").unwrap_or(ws_url))
 }
 
 /// Convert `BinancePerpMessage` to `MarketDataType`
+#[allow(clippy::too_many_lines)]
 fn convert_binance_perp_message_to_market_data(
-    message: crate::exchanges::binance_perp::codec::BinancePerpMessage,
-) -> Option<MarketDataType> {
+    message: BinancePerpMessage,
+) -> Result<Option<MarketDataType>, ExchangeError> {
     use crate::core::types::conversion;
 
     match message {
         crate::exchanges::binance_perp::codec::BinancePerpMessage::Ticker(ticker) => {
-            let symbol = conversion::string_to_symbol(&ticker.symbol);
-            let price = conversion::string_to_price(&ticker.price);
-            let price_change = conversion::string_to_price(&ticker.price_change);
-            let price_change_percent = conversion::string_to_decimal(&ticker.price_change_percent);
-            let high_price = conversion::string_to_price(&ticker.high_price);
-            let low_price = conversion::string_to_price(&ticker.low_price);
-            let volume = conversion::string_to_volume(&ticker.volume);
-            let quote_volume = conversion::string_to_volume(&ticker.quote_volume);
-
-            Some(MarketDataType::Ticker(crate::core::types::Ticker {
+            let symbol = SYMBOLS.from_exchange_symbol(&ticker.symbol);
+            let price = conversion::try_string_to_price(&ticker.price)?;
+            let price_change = conversion::try_string_to_price(&ticker.price_change)?;
+            let price_change_percent =
+                conversion::try_string_to_decimal(&ticker.price_change_percent)?;
+            let high_price = conversion::try_string_to_price(&ticker.high_price)?;
+            let low_price = conversion::try_string_to_price(&ticker.low_price)?;
+            let volume = conversion::try_string_to_volume(&ticker.volume)?;
+            let quote_volume = conversion::try_string_to_volume(&ticker.quote_volume)?;
+
+            Ok(Some(MarketDataType::Ticker(crate::core::types::Ticker {
                 symbol,
                 price,
                 price_change,
@@ -382,58 +526,64 @@ fn convert_binance_perp_message_to_market_data(
                 open_time: ticker.open_time,
                 close_time: ticker.close_time,
                 count: ticker.count,
-            }))
+            })))
         }
         crate::exchanges::binance_perp::codec::BinancePerpMessage::OrderBook(orderbook) => {
-            let symbol = conversion::string_to_symbol(&orderbook.symbol);
+            let symbol = SYMBOLS.from_exchange_symbol(&orderbook.symbol);
 
             let bids = orderbook
                 .bids
                 .iter()
-                .map(|bid| crate::core::types::OrderBookEntry {
-                    price: conversion::string_to_price(&bid[0]),
-                    quantity: conversion::string_to_quantity(&bid[1]),
+                .map(|bid| {
+                    Ok(crate::core::types::OrderBookEntry {
+                        price: conversion::try_string_to_price(&bid[0])?,
+                        quantity: conversion::try_string_to_quantity(&bid[1])?,
+                    })
                 })
-                .collect();
+                .collect::<Result<_, ExchangeError>>()?;
             let asks = orderbook
                 .asks
                 .iter()
-                .map(|ask| crate::core::types::OrderBookEntry {
-                    price: conversion::string_to_price(&ask[0]),
-                    quantity: conversion::string_to_quantity(&ask[1]),
+                .map(|ask| {
+                    Ok(crate::core::types::OrderBookEntry {
+                        price: conversion::try_string_to_price(&ask[0])?,
+                        quantity: conversion::try_string_to_quantity(&ask[1])?,
+                    })
                 })
-                .collect();
-
-            Some(MarketDataType::OrderBook(crate::core::types::OrderBook {
-                symbol,
-                bids,
-                asks,
-                last_update_id: orderbook.final_update_id,
-            }))
+                .collect::<Result<_, ExchangeError>>()?;
+
+            Ok(Some(MarketDataType::OrderBook(
+                crate::core::types::OrderBook {
+                    symbol,
+                    bids,
+                    asks,
+                    last_update_id: orderbook.final_update_id,
+                },
+            )))
         }
         crate::exchanges::binance_perp::codec::BinancePerpMessage::Trade(trade) => {
-            let symbol = conversion::string_to_symbol(&trade.symbol);
-            let price = conversion::string_to_price(&trade.price);
-            let quantity = conversion::string_to_quantity(&trade.quantity);
+            let symbol = SYMBOLS.from_exchange_symbol(&trade.symbol);
+            let price = conversion::try_string_to_price(&trade.price)?;
+            let quantity = conversion::try_string_to_quantity(&trade.quantity)?;
 
-            Some(MarketDataType::Trade(crate::core::types::Trade {
+            Ok(Some(MarketDataType::Trade(crate::core::types::Trade {
                 symbol,
                 id: trade.id,
                 price,
                 quantity,
                 time: trade.time,
                 is_buyer_maker: trade.is_buyer_maker,
-            }))
+            })))
         }
         crate::exchanges::binance_perp::codec::BinancePerpMessage::Kline(kline) => {
-            let symbol = conversion::string_to_symbol(&kline.symbol);
-            let open_price = conversion::string_to_price(&kline.kline.open_price);
-            let high_price = conversion::string_to_price(&kline.kline.high_price);
-            let low_price = conversion::string_to_price(&kline.kline.low_price);
-            let close_price = conversion::string_to_price(&kline.kline.close_price);
-            let volume = conversion::string_to_volume(&kline.kline.volume);
-
-            Some(MarketDataType::Kline(crate::core::types::Kline {
+            let symbol = SYMBOLS.from_exchange_symbol(&kline.symbol);
+            let open_price = conversion::try_string_to_price(&kline.kline.open_price)?;
+            let high_price = conversion::try_string_to_price(&kline.kline.high_price)?;
+            let low_price = conversion::try_string_to_price(&kline.kline.low_price)?;
+            let close_price = conversion::try_string_to_price(&kline.kline.close_price)?;
+            let volume = conversion::try_string_to_volume(&kline.kline.volume)?;
+
+            Ok(Some(MarketDataType::Kline(crate::core::types::Kline {
                 symbol,
                 open_time: kline.kline.open_time,
                 close_time: kline.kline.close_time,
@@ -445,8 +595,18 @@ fn convert_binance_perp_message_to_market_data(
                 volume,
                 number_of_trades: kline.kline.number_of_trades,
                 final_bar: kline.kline.final_bar,
-            }))
+            })))
         }
-        _ => None, // Ignore unknown and funding rate messages for market data
+        crate::exchanges::binance_perp::codec::BinancePerpMessage::MarkPrice(mark) => Ok(Some(
+            MarketDataType::MarkPrice(crate::core::types::MarkPrice {
+                symbol: SYMBOLS.from_exchange_symbol(&mark.symbol),
+                mark_price: conversion::try_string_to_price(&mark.mark_price)?,
+                index_price: Some(conversion::try_string_to_price(&mark.index_price)?),
+                funding_rate: Some(conversion::try_string_to_decimal(&mark.funding_rate)?),
+                next_funding_time: Some(mark.next_funding_time),
+                timestamp: mark.event_time,
+            }),
+        )),
+        crate::exchanges::binance_perp::codec::BinancePerpMessage::Unknown => Ok(None),
     }
 }
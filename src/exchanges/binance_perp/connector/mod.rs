@@ -1,12 +1,21 @@
 use crate::core::errors::ExchangeError;
-use crate::core::traits::{AccountInfo, FundingRateSource, MarketDataSource, OrderPlacer};
+use crate::core::traits::{
+    AccountInfo, AccountSummarySource, DiagnosticsSource, FundingRateSource, HistoricalTradeSource,
+    KillSwitch, MarketSnapshotSource, OrderHistorySource, OrderPlacer, OrderQuery, PositionManager,
+    RestMarketData, StreamingMarketData, TradeHistorySource,
+};
 use crate::core::types::{
-    Balance, FundingRate, Kline, KlineInterval, Market, MarketDataType, OrderRequest,
-    OrderResponse, Position, SubscriptionType, WebSocketConfig,
+    AccountFill, AccountSummary, Balance, FundingRate, Kline, KlineInterval, LeverageBracket,
+    MarginMode, Market, MarketDataType, Order, OrderBook, OrderPage, OrderRequest, OrderResponse,
+    Position, SubscriptionType, Symbol, Ticker, Trade, WebSocketConfig,
+};
+use crate::core::{
+    config::ExchangeConfig, kernel::diagnostics, kernel::Diagnostics, kernel::RestClient,
+    kernel::WsSession,
 };
-use crate::core::{config::ExchangeConfig, kernel::RestClient, kernel::WsSession};
-use crate::exchanges::binance_perp::codec::BinancePerpCodec;
+use crate::exchanges::binance_perp::{codec::BinancePerpCodec, types::BinanceFuturesApi};
 use async_trait::async_trait;
+use std::time::Duration;
 use tokio::sync::mpsc;
 
 pub mod account;
@@ -30,7 +39,8 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinancePerpCodec> + Send
     /// Create a new Binance Perpetual connector with WebSocket support
     pub fn new(rest: R, ws: W, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::<R, W>::new(&rest, Some(ws), config.testnet),
+            market: MarketData::<R, W>::new(&rest, Some(ws), config.testnet)
+                .with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
         }
@@ -41,41 +51,46 @@ impl<R: RestClient + Clone + Send + Sync> BinancePerpConnector<R, ()> {
     /// Create a new Binance Perpetual connector without WebSocket support
     pub fn new_without_ws(rest: R, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::<R, ()>::new(&rest, None, config.testnet),
+            market: MarketData::<R, ()>::new(&rest, None, config.testnet)
+                .with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
         }
     }
 }
 
+impl<R: RestClient, W> BinancePerpConnector<R, W> {
+    /// Target a different futures API family, e.g. `BinanceFuturesApi::CoinM`
+    #[must_use]
+    pub fn with_api(self, api: BinanceFuturesApi) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            market: self.market.with_api(api),
+            trading: self.trading.with_api(api),
+            account: self.account.with_api(api),
+        }
+    }
+}
+
 // Implement traits for the connector by delegating to sub-components
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinancePerpCodec> + Send + Sync>
-    MarketDataSource for BinancePerpConnector<R, W>
+    RestMarketData for BinancePerpConnector<R, W>
 {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         self.market.get_markets().await
     }
 
-    async fn subscribe_market_data(
-        &self,
-        symbols: Vec<String>,
-        subscription_types: Vec<SubscriptionType>,
-        config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        self.market
-            .subscribe_market_data(symbols, subscription_types, config)
-            .await
-    }
-
-    fn get_websocket_url(&self) -> String {
-        self.market.get_websocket_url()
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
     }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
@@ -88,14 +103,12 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinancePerpCodec> + Send
 }
 
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync> MarketDataSource for BinancePerpConnector<R, ()> {
-    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
-        self.market.get_markets().await
-    }
-
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<BinancePerpCodec> + Send + Sync>
+    StreamingMarketData for BinancePerpConnector<R, W>
+{
     async fn subscribe_market_data(
         &self,
-        symbols: Vec<String>,
+        symbols: Vec<Symbol>,
         subscription_types: Vec<SubscriptionType>,
         config: Option<WebSocketConfig>,
     ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
@@ -107,10 +120,21 @@ impl<R: RestClient + Clone + Send + Sync> MarketDataSource for BinancePerpConnec
     fn get_websocket_url(&self) -> String {
         self.market.get_websocket_url()
     }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> RestMarketData for BinancePerpConnector<R, ()> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.market.get_markets().await
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
+    }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
@@ -128,7 +152,7 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
 {
     async fn get_funding_rates(
         &self,
-        symbols: Option<Vec<String>>,
+        symbols: Option<Vec<Symbol>>,
     ) -> Result<Vec<FundingRate>, ExchangeError> {
         self.market.get_funding_rates(symbols).await
     }
@@ -139,7 +163,7 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
 
     async fn get_funding_rate_history(
         &self,
-        symbol: String,
+        symbol: Symbol,
         start_time: Option<i64>,
         end_time: Option<i64>,
         limit: Option<u32>,
@@ -150,23 +174,119 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
     }
 }
 
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for BinancePerpConnector<R, W>
+{
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        self.market.get_ticker(symbol).await
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        self.market.get_order_book_snapshot(symbol, depth).await
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        self.market.get_recent_trades(symbol, limit).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> HistoricalTradeSource
+    for BinancePerpConnector<R, W>
+{
+    async fn get_historical_trades(
+        &self,
+        symbol: Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        self.market
+            .get_historical_trades(symbol, start_time, end_time)
+            .await
+    }
+}
+
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderPlacer
     for BinancePerpConnector<R, W>
 {
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
         self.trading.place_order(order).await
     }
 
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn place_orders(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Vec<Result<OrderResponse, ExchangeError>> {
+        self.trading.place_orders(orders).await
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
         self.trading.cancel_order(symbol, order_id).await
     }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderQuery
+    for BinancePerpConnector<R, W>
+{
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        self.trading.get_open_orders(symbol).await
+    }
+
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        self.trading.get_order(symbol, order_id).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderHistorySource
+    for BinancePerpConnector<R, W>
+{
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        self.trading
+            .get_order_history(symbol, start_time, end_time, cursor)
+            .await
+    }
 }
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo
     for BinancePerpConnector<R, W>
 {
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         self.account.get_account_balance().await
     }
@@ -175,3 +295,72 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo
         self.account.get_positions().await
     }
 }
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountSummarySource
+    for BinancePerpConnector<R, W>
+{
+    async fn get_account_summary(&self) -> Result<AccountSummary, ExchangeError> {
+        self.account.get_account_summary().await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> TradeHistorySource
+    for BinancePerpConnector<R, W>
+{
+    async fn get_my_trades(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AccountFill>, ExchangeError> {
+        self.account
+            .get_my_trades(symbol, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> PositionManager
+    for BinancePerpConnector<R, W>
+{
+    async fn set_leverage(&self, symbol: Symbol, leverage: u32) -> Result<(), ExchangeError> {
+        self.trading.set_leverage(symbol, leverage).await
+    }
+
+    async fn set_margin_mode(&self, symbol: Symbol, mode: MarginMode) -> Result<(), ExchangeError> {
+        self.trading.set_margin_mode(symbol, mode).await
+    }
+
+    async fn get_leverage_brackets(
+        &self,
+        symbol: Symbol,
+    ) -> Result<Vec<LeverageBracket>, ExchangeError> {
+        self.trading.get_leverage_brackets(symbol).await
+    }
+
+    async fn close_position(&self, symbol: Symbol) -> Result<Option<OrderResponse>, ExchangeError> {
+        self.trading.close_position(symbol).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> KillSwitch
+    for BinancePerpConnector<R, W>
+{
+    async fn cancel_all_orders(&self, symbol: Option<Symbol>) -> Result<(), ExchangeError> {
+        self.trading.cancel_all_orders(symbol).await
+    }
+
+    async fn set_cancel_on_disconnect(&self, timeout: Duration) -> Result<(), ExchangeError> {
+        self.trading.set_cancel_on_disconnect(timeout).await
+    }
+}
+
+impl<R: RestClient, W> DiagnosticsSource for BinancePerpConnector<R, W> {
+    fn diagnostics(&self) -> Diagnostics {
+        diagnostics("binance_perp")
+    }
+}
@@ -1,12 +1,29 @@
 use crate::core::{
     errors::ExchangeError,
     kernel::RestClient,
-    traits::OrderPlacer,
-    types::{OrderRequest, OrderResponse, OrderSide, OrderType, TimeInForce},
+    traits::{KillSwitch, OrderHistorySource, OrderPlacer, OrderQuery, PositionManager},
+    types::{
+        LeverageBracket, MarginMode, Order, OrderPage, OrderRequest, OrderResponse, OrderSide,
+        OrderType, Symbol, TimeInForce,
+    },
+};
+use crate::exchanges::binance_perp::conversions::SYMBOLS;
+use crate::exchanges::binance_perp::{
+    conversions::{
+        convert_binance_perp_leverage_bracket, convert_binance_perp_order,
+        convert_binance_perp_position,
+    },
+    rest::BinancePerpRestClient,
+    types::{
+        binance_perp_error_kind, BinanceFuturesApi, BinancePerpBatchOrderResult,
+        BinancePerpOrderResponse,
+    },
 };
-use crate::exchanges::binance_perp::rest::BinancePerpRestClient;
 use async_trait::async_trait;
-use serde_json::json;
+use futures_util::future::join_all;
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+use std::time::Duration;
 use tracing::instrument;
 
 /// Trading implementation for Binance Perpetual
@@ -14,6 +31,9 @@ pub struct Trading<R: RestClient> {
     rest: BinancePerpRestClient<R>,
 }
 
+/// Binance caps `batchOrders` at five orders per request
+const MAX_BATCH_ORDERS: usize = 5;
+
 impl<R: RestClient> Trading<R> {
     /// Create a new trading engine
     pub fn new(rest: &R) -> Self
@@ -24,6 +44,13 @@ impl<R: RestClient> Trading<R> {
             rest: BinancePerpRestClient::new(rest.clone()),
         }
     }
+
+    /// Target a different futures API family (USDⓈ-M by default)
+    #[must_use]
+    pub fn with_api(mut self, api: BinanceFuturesApi) -> Self {
+        self.rest = self.rest.with_api(api);
+        self
+    }
 }
 
 fn order_side_to_string(side: &OrderSide) -> String {
@@ -78,53 +105,156 @@ fn string_to_order_type(s: &str) -> OrderType {
     }
 }
 
-#[async_trait]
-impl<R: RestClient> OrderPlacer for Trading<R> {
-    #[instrument(skip(self), fields(exchange = "binance_perp"))]
-    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
-        // Convert core OrderRequest to JSON for Binance API
-        let mut order_json = json!({
-            "symbol": order.symbol.as_str(),
-            "side": order_side_to_string(&order.side),
-            "type": order_type_to_string(&order.order_type),
-            "quantity": order.quantity.to_string(),
-        });
-
-        // Add optional fields
-        if let Some(price) = order.price {
-            order_json["price"] = json!(price.to_string());
+fn order_to_json(order: &OrderRequest) -> Result<Value, ExchangeError> {
+    let mut order_json = json!({
+        "symbol": SYMBOLS.try_to_exchange_symbol(&order.symbol)?,
+        "side": order_side_to_string(&order.side),
+        "type": order_type_to_string(&order.order_type),
+        "quantity": order.quantity.to_string(),
+    });
+
+    // Add optional fields
+    if let Some(price) = order.price {
+        order_json["price"] = json!(price.to_string());
+    }
+
+    if let Some(tif) = &order.time_in_force {
+        order_json["timeInForce"] = json!(time_in_force_to_string(tif));
+    } else {
+        order_json["timeInForce"] = json!("GTC");
+    }
+
+    if let Some(stop_price) = order.stop_price {
+        order_json["stopPrice"] = json!(stop_price.to_string());
+    }
+
+    if let Some(client_order_id) = &order.client_order_id {
+        order_json["newClientOrderId"] = json!(client_order_id);
+    }
+
+    // `closePosition` takes neither a quantity nor `reduceOnly`
+    if order.close_position == Some(true) {
+        if let Some(fields) = order_json.as_object_mut() {
+            fields.remove("quantity");
         }
+        order_json["closePosition"] = json!("true");
+    } else if order.reduce_only == Some(true) {
+        order_json["reduceOnly"] = json!("true");
+    }
 
-        if let Some(tif) = order.time_in_force {
-            order_json["timeInForce"] = json!(time_in_force_to_string(&tif));
-        } else {
-            order_json["timeInForce"] = json!("GTC");
+    Ok(order_json)
+}
+
+fn convert_order_response(
+    response: BinancePerpOrderResponse,
+) -> Result<OrderResponse, ExchangeError> {
+    Ok(OrderResponse {
+        order_id: response.order_id.to_string(),
+        client_order_id: response.client_order_id,
+        symbol: SYMBOLS.from_exchange_symbol(&response.symbol),
+        side: string_to_order_side(&response.side),
+        order_type: string_to_order_type(&response.order_type),
+        quantity: crate::core::types::conversion::try_string_to_quantity(&response.orig_qty)?,
+        price: Some(crate::core::types::conversion::try_string_to_price(
+            &response.price,
+        )?),
+        status: response.status,
+        timestamp: response.update_time,
+        extensions: None,
+    })
+}
+
+fn convert_batch_result(
+    result: BinancePerpBatchOrderResult,
+) -> Result<OrderResponse, ExchangeError> {
+    match result {
+        BinancePerpBatchOrderResult::Order(response) => convert_order_response(response),
+        BinancePerpBatchOrderResult::Error { code, msg } => {
+            Err(ExchangeError::api_error(code, msg).classify(binance_perp_error_kind))
         }
+    }
+}
 
-        if let Some(stop_price) = order.stop_price {
-            order_json["stopPrice"] = json!(stop_price.to_string());
+impl<R: RestClient> Trading<R> {
+    /// Symbols with open orders, or just `symbol` when given
+    async fn order_symbols(&self, symbol: Option<Symbol>) -> Result<Vec<String>, ExchangeError> {
+        if let Some(symbol) = symbol {
+            return Ok(vec![SYMBOLS.try_to_exchange_symbol(&symbol)?]);
         }
+        let symbols: BTreeSet<String> = self
+            .rest
+            .get_open_orders(None)
+            .await?
+            .into_iter()
+            .map(|order| order.symbol)
+            .collect();
+        Ok(symbols.into_iter().collect())
+    }
 
-        let response = self.rest.place_order(&order_json).await?;
-
-        // Convert Binance response to core OrderResponse
-        Ok(OrderResponse {
-            order_id: response.order_id.to_string(),
-            client_order_id: response.client_order_id,
-            symbol: crate::core::types::conversion::string_to_symbol(&response.symbol),
-            side: string_to_order_side(&response.side),
-            order_type: string_to_order_type(&response.order_type),
-            quantity: crate::core::types::conversion::string_to_quantity(&response.orig_qty),
-            price: Some(crate::core::types::conversion::string_to_price(
-                &response.price,
-            )),
-            status: response.status,
-            timestamp: response.update_time,
-        })
+    async fn place_chunk(
+        &self,
+        orders: &[OrderRequest],
+    ) -> Vec<Result<OrderResponse, ExchangeError>> {
+        // A symbol the venue cannot spell fails the chunk before anything is sent
+        let placed = match orders
+            .iter()
+            .map(order_to_json)
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(batch) => self.rest.place_batch_orders(&batch).await,
+            Err(e) => Err(e),
+        };
+        let message = match placed {
+            Ok(results) if results.len() == orders.len() => {
+                return results.into_iter().map(convert_batch_result).collect();
+            }
+            Ok(results) => format!(
+                "Batch reply has {} entries for {} orders",
+                results.len(),
+                orders.len()
+            ),
+            // The whole request failed, so every order in it did
+            Err(e) => e.to_string(),
+        };
+        orders
+            .iter()
+            .map(|_| Err(ExchangeError::Other(message.clone())))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let response = self.rest.place_order(&order_to_json(&order)?).await?;
+        convert_order_response(response)
+    }
+
+    #[instrument(skip(self, orders), fields(exchange = "binance_perp", count = orders.len()))]
+    async fn place_orders(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Vec<Result<OrderResponse, ExchangeError>> {
+        // Chunks go out concurrently; join_all keeps them in request order
+        join_all(
+            orders
+                .chunks(MAX_BATCH_ORDERS)
+                .map(|chunk| self.place_chunk(chunk)),
+        )
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
     }
 
     #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol, order_id = %order_id))]
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let order_id_u64: u64 = order_id
             .parse()
             .map_err(|_| ExchangeError::Other(format!("Invalid order ID format: {}", order_id)))?;
@@ -133,4 +263,235 @@ impl<R: RestClient> OrderPlacer for Trading<R> {
             .await?;
         Ok(())
     }
+
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol, client_order_id = %client_order_id))]
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.rest
+            .cancel_order(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                None,
+                Some(&client_order_id),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> OrderQuery for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        let symbol = symbol
+            .map(|s| SYMBOLS.try_to_exchange_symbol(&s))
+            .transpose()?;
+        self.rest
+            .get_open_orders(symbol.as_deref())
+            .await?
+            .iter()
+            .map(convert_binance_perp_order)
+            .collect()
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol, order_id = %order_id))]
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        let order_id: u64 = order_id
+            .parse()
+            .map_err(|_| ExchangeError::Other(format!("Invalid order ID format: {}", order_id)))?;
+        let order = self
+            .rest
+            .get_order(&SYMBOLS.try_to_exchange_symbol(&symbol)?, order_id)
+            .await?;
+        convert_binance_perp_order(&order)
+    }
+}
+
+/// Largest page `allOrders` returns
+const ORDER_HISTORY_LIMIT: u32 = 1000;
+
+#[async_trait]
+impl<R: RestClient> OrderHistorySource for Trading<R> {
+    /// Pages run oldest first; the cursor is the next order id to fetch
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        let from_id = cursor
+            .map(|cursor| {
+                cursor.parse::<u64>().map_err(|_| {
+                    ExchangeError::InvalidParameters(format!("Invalid cursor: {}", cursor))
+                })
+            })
+            .transpose()?;
+        // An order id cursor replaces the start of the range
+        let start_time = start_time.filter(|_| from_id.is_none());
+        let raw = self
+            .rest
+            .get_all_orders(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                from_id,
+                start_time,
+                end_time,
+                ORDER_HISTORY_LIMIT,
+            )
+            .await?;
+
+        let next_cursor = (raw.len() == ORDER_HISTORY_LIMIT as usize)
+            .then(|| raw.last().map(|order| (order.order_id + 1).to_string()))
+            .flatten();
+        let orders = raw
+            .iter()
+            .map(convert_binance_perp_order)
+            .filter(|order| order.as_ref().map_or(true, |order| !order.status.is_open()))
+            .collect::<Result<_, _>>()?;
+        Ok(OrderPage {
+            orders,
+            next_cursor,
+        })
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> PositionManager for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    async fn set_leverage(&self, symbol: Symbol, leverage: u32) -> Result<(), ExchangeError> {
+        self.rest
+            .set_leverage(&SYMBOLS.try_to_exchange_symbol(&symbol)?, leverage)
+            .await?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    async fn set_margin_mode(&self, symbol: Symbol, mode: MarginMode) -> Result<(), ExchangeError> {
+        let margin_type = match mode {
+            MarginMode::Cross => "CROSSED",
+            MarginMode::Isolated => "ISOLATED",
+        };
+        match self
+            .rest
+            .set_margin_type(&SYMBOLS.try_to_exchange_symbol(&symbol)?, margin_type)
+            .await
+        {
+            // -4046: the symbol already uses this margin type
+            Err(ExchangeError::ApiError { message, .. }) if message.contains("-4046") => Ok(()),
+            result => result.map(|_| ()),
+        }
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    async fn get_leverage_brackets(
+        &self,
+        symbol: Symbol,
+    ) -> Result<Vec<LeverageBracket>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let entry = self
+            .rest
+            .get_leverage_brackets(&symbol)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| ExchangeError::Other(format!("No leverage brackets for {}", symbol)))?;
+        Ok(entry
+            .brackets
+            .iter()
+            .map(convert_binance_perp_leverage_bracket)
+            .collect())
+    }
+
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    async fn close_position(&self, symbol: Symbol) -> Result<Option<OrderResponse>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let positions = self.rest.get_positions().await?;
+        for position in positions
+            .iter()
+            .filter(|position| position.symbol == symbol)
+        {
+            if let Some(order) = convert_binance_perp_position(position)?.close_order() {
+                return self.place_order(order).await.map(Some);
+            }
+        }
+        Ok(None)
+    }
+}
+
+// Binance cancels and arms countdowns per symbol, so account-wide calls fan out
+#[async_trait]
+impl<R: RestClient> KillSwitch for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    async fn cancel_all_orders(&self, symbol: Option<Symbol>) -> Result<(), ExchangeError> {
+        let symbols = self.order_symbols(symbol).await?;
+        join_all(
+            symbols
+                .iter()
+                .map(|symbol| self.rest.cancel_all_orders(symbol)),
+        )
+        .await
+        .into_iter()
+        .try_for_each(|result| result.map(|_| ()))
+    }
+
+    /// Arms the countdown on every symbol that has open orders when called
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    async fn set_cancel_on_disconnect(&self, timeout: Duration) -> Result<(), ExchangeError> {
+        let countdown_ms = u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX);
+        let symbols = self.order_symbols(None).await?;
+        join_all(
+            symbols
+                .iter()
+                .map(|symbol| self.rest.countdown_cancel_all(symbol, countdown_ms)),
+        )
+        .await
+        .into_iter()
+        .try_for_each(|result| result.map(|_| ()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{Position, PositionSide, Price, Quantity};
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_close_order_is_reduce_only_and_close_position_drops_quantity() {
+        let position = Position {
+            symbol: "BTCUSDT".into(),
+            position_side: PositionSide::Short,
+            entry_price: Price::new(Decimal::from(40_000)),
+            position_amount: Quantity::new(Decimal::new(-25, 3)),
+            unrealized_pnl: Decimal::ZERO,
+            liquidation_price: None,
+            leverage: Decimal::TEN,
+            margin_mode: None,
+            margin: None,
+            extensions: None,
+        };
+        let order = position.close_order().unwrap();
+        assert!(matches!(order.side, OrderSide::Buy));
+        let body = order_to_json(&order).unwrap();
+        assert_eq!(body["type"], "MARKET");
+        assert_eq!(body["quantity"], "0.025");
+        assert_eq!(body["reduceOnly"], "true");
+
+        let stop = OrderRequest {
+            order_type: OrderType::StopLoss,
+            stop_price: Some(Price::new(Decimal::from(42_000))),
+            reduce_only: None,
+            close_position: Some(true),
+            client_order_id: Some("stop-1".to_string()),
+            ..order
+        };
+        let body = order_to_json(&stop).unwrap();
+        assert_eq!(body["closePosition"], "true");
+        assert_eq!(body["newClientOrderId"], "stop-1");
+        assert!(body.get("quantity").is_none());
+        assert!(body.get("reduceOnly").is_none());
+    }
 }
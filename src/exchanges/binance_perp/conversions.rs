@@ -1,23 +1,43 @@
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
 use crate::core::types::{
     conversion::{
-        string_to_decimal, string_to_price, string_to_quantity, string_to_symbol, string_to_volume,
+        try_book_levels, try_string_to_decimal, try_string_to_price, try_string_to_quantity,
+        try_string_to_volume,
     },
-    Balance, Kline, Market, MarketDataType, OrderBook, OrderBookEntry, Position, PositionSide,
-    Ticker, Trade,
+    AccountFill, AccountSummary, Balance, ContractStyle, Kline, LeverageBracket, Market,
+    MarketDataType, MarketStatus, Order, OrderBook, OrderBookEntry, OrderSide, OrderStatus,
+    OrderType, Position, PositionSide, Ticker, Trade, Volume,
 };
 use crate::exchanges::binance_perp::types::{
-    BinancePerpBalance, BinancePerpMarket, BinancePerpPosition, BinancePerpRestKline,
+    BinancePerpAccountInfo, BinancePerpBalance, BinancePerpLeverageBracket, BinancePerpMarket,
+    BinancePerpOrder, BinancePerpPosition, BinancePerpRestAggTrade, BinancePerpRestKline,
+    BinancePerpRestOrderBook, BinancePerpRestTicker, BinancePerpRestTrade, BinancePerpUserTrade,
     BinancePerpWebSocketKline, BinancePerpWebSocketOrderBook, BinancePerpWebSocketTicker,
     BinancePerpWebSocketTrade,
 };
+use crate::exchanges::factory::ExchangeId;
 use rust_decimal::Decimal;
 use tracing::warn;
 
+/// Binance USDⓈ-M futures symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::BinancePerp.symbol_convention();
+
 /// Convert Binance Perpetual market to core Market type
-pub fn convert_binance_perp_market(binance_market: BinancePerpMarket) -> Market {
-    Market {
-        symbol: string_to_symbol(&binance_market.symbol),
-        status: binance_market.status,
+pub fn convert_binance_perp_market(
+    binance_market: BinancePerpMarket,
+) -> Result<Market, ExchangeError> {
+    // COIN-M contracts are margined in the base asset and sized in USD
+    let (contract_style, contract_size) =
+        if binance_market.margin_asset.as_deref() == Some(binance_market.base_asset.as_str()) {
+            (ContractStyle::Inverse, binance_market.contract_size)
+        } else {
+            (ContractStyle::Linear, None)
+        };
+
+    Ok(Market {
+        symbol: SYMBOLS.from_exchange_symbol(&binance_market.symbol),
+        status: MarketStatus::from_venue_str(&binance_market.status),
         base_precision: binance_market.base_asset_precision,
         quote_precision: binance_market.quote_precision,
         min_qty: binance_market
@@ -25,76 +45,241 @@ pub fn convert_binance_perp_market(binance_market: BinancePerpMarket) -> Market
             .iter()
             .find(|f| f.filter_type == "LOT_SIZE")
             .and_then(|f| f.min_qty.as_ref())
-            .map(|s| string_to_quantity(s)),
+            .map(|s| try_string_to_quantity(s))
+            .transpose()?,
         max_qty: binance_market
             .filters
             .iter()
             .find(|f| f.filter_type == "LOT_SIZE")
             .and_then(|f| f.max_qty.as_ref())
-            .map(|s| string_to_quantity(s)),
+            .map(|s| try_string_to_quantity(s))
+            .transpose()?,
         min_price: binance_market
             .filters
             .iter()
             .find(|f| f.filter_type == "PRICE_FILTER")
             .and_then(|f| f.min_price.as_ref())
-            .map(|s| string_to_price(s)),
+            .map(|s| try_string_to_price(s))
+            .transpose()?,
         max_price: binance_market
             .filters
             .iter()
             .find(|f| f.filter_type == "PRICE_FILTER")
             .and_then(|f| f.max_price.as_ref())
-            .map(|s| string_to_price(s)),
-    }
+            .map(|s| try_string_to_price(s))
+            .transpose()?,
+        contract_style,
+        contract_size,
+        extensions: None,
+    })
 }
 
 /// Convert Binance Perpetual balance to core Balance type
-pub fn convert_binance_perp_balance(binance_balance: &BinancePerpBalance) -> Balance {
-    let free = string_to_quantity(&binance_balance.available_balance);
-    let total = string_to_quantity(&binance_balance.balance);
+pub fn convert_binance_perp_balance(
+    binance_balance: &BinancePerpBalance,
+) -> Result<Balance, ExchangeError> {
+    let free = try_string_to_quantity(&binance_balance.available_balance)?;
+    let total = try_string_to_quantity(&binance_balance.balance)?;
     let locked = crate::core::types::Quantity::new(total.value() - free.value());
 
-    Balance {
+    Ok(Balance {
         asset: binance_balance.asset.clone(),
         free,
         locked,
-    }
+    })
+}
+
+/// Convert USDⓈ-M account totals to core account summary
+///
+/// Totals are in USDT, or in USD value of every asset in multi-assets mode.
+pub fn convert_binance_perp_account_summary(
+    info: &BinancePerpAccountInfo,
+) -> Result<AccountSummary, ExchangeError> {
+    Ok(AccountSummary {
+        currency: "USDT".to_string(),
+        total_equity: try_string_to_decimal(&info.total_margin_balance)?,
+        available_margin: try_string_to_decimal(&info.available_balance)?,
+        initial_margin: try_string_to_decimal(&info.total_initial_margin)?,
+        maintenance_margin: try_string_to_decimal(&info.total_maint_margin)?,
+        unrealized_pnl: try_string_to_decimal(&info.total_unrealized_pnl)?,
+        timestamp: info.update_time,
+    })
 }
 
 /// Convert Binance Perpetual position to core Position type
-pub fn convert_binance_perp_position(binance_position: &BinancePerpPosition) -> Position {
-    let position_amount = string_to_quantity(&binance_position.position_amt);
+pub fn convert_binance_perp_position(
+    binance_position: &BinancePerpPosition,
+) -> Result<Position, ExchangeError> {
+    let position_amount = try_string_to_quantity(&binance_position.position_amt)?;
     let position_side = match position_amount.value().cmp(&Decimal::ZERO) {
         std::cmp::Ordering::Greater => PositionSide::Long,
         std::cmp::Ordering::Less => PositionSide::Short,
         std::cmp::Ordering::Equal => PositionSide::Both,
     };
 
-    Position {
-        symbol: string_to_symbol(&binance_position.symbol),
+    Ok(Position {
+        symbol: SYMBOLS.from_exchange_symbol(&binance_position.symbol),
         position_side,
-        entry_price: string_to_price(&binance_position.entry_price),
+        entry_price: try_string_to_price(&binance_position.entry_price)?,
         position_amount,
-        unrealized_pnl: string_to_decimal(&binance_position.un_realized_pnl),
-        liquidation_price: Some(string_to_price(&binance_position.liquidation_price)),
-        leverage: string_to_decimal(&binance_position.leverage),
+        unrealized_pnl: try_string_to_decimal(&binance_position.un_realized_pnl)?,
+        liquidation_price: Some(try_string_to_price(&binance_position.liquidation_price)?),
+        leverage: try_string_to_decimal(&binance_position.leverage)?,
+        margin_mode: None,
+        margin: None,
+        extensions: None,
+    })
+}
+
+/// Convert Binance Perpetual account trade to core fill
+pub fn convert_binance_perp_user_trade(
+    trade: &BinancePerpUserTrade,
+) -> Result<AccountFill, ExchangeError> {
+    Ok(AccountFill {
+        symbol: SYMBOLS.from_exchange_symbol(&trade.symbol),
+        order_id: trade.order_id.to_string(),
+        trade_id: trade.id.to_string(),
+        side: if trade.side == "SELL" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        price: try_string_to_price(&trade.price)?,
+        quantity: try_string_to_quantity(&trade.qty)?,
+        fee: try_string_to_decimal(&trade.commission)?,
+        fee_asset: Some(trade.commission_asset.clone()),
+        is_maker: trade.maker,
+        timestamp: trade.time,
+    })
+}
+
+/// Convert Binance Perpetual leverage bracket to core bracket
+pub fn convert_binance_perp_leverage_bracket(
+    bracket: &BinancePerpLeverageBracket,
+) -> LeverageBracket {
+    LeverageBracket {
+        max_leverage: Decimal::from(bracket.initial_leverage),
+        floor: bracket.notional_floor,
+        cap: Some(bracket.notional_cap),
+        maintenance_margin_rate: bracket.maint_margin_ratio,
     }
 }
 
+/// Convert binance perp queried order to core order
+pub fn convert_binance_perp_order(order: &BinancePerpOrder) -> Result<Order, ExchangeError> {
+    let price = try_string_to_price(&order.price)?;
+    let average_price = try_string_to_price(&order.avg_price)?;
+    Ok(Order {
+        order_id: order.order_id.to_string(),
+        client_order_id: order.client_order_id.clone(),
+        symbol: SYMBOLS.from_exchange_symbol(&order.symbol),
+        side: if order.side == "SELL" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        // Futures name the market variants of stop orders explicitly
+        order_type: match order.order_type.as_str() {
+            "MARKET" => OrderType::Market,
+            "STOP" => OrderType::StopLossLimit,
+            "STOP_MARKET" | "TRAILING_STOP_MARKET" => OrderType::StopLoss,
+            "TAKE_PROFIT" => OrderType::TakeProfitLimit,
+            "TAKE_PROFIT_MARKET" => OrderType::TakeProfit,
+            _ => OrderType::Limit,
+        },
+        status: OrderStatus::from_venue(&order.status),
+        // Unfilled orders and market orders report zero prices
+        price: (!price.value().is_zero()).then_some(price),
+        quantity: try_string_to_quantity(&order.orig_qty)?,
+        filled_quantity: try_string_to_quantity(&order.executed_qty)?,
+        average_price: (!average_price.value().is_zero()).then_some(average_price),
+        created_time: order.time,
+        updated_time: order.update_time,
+    })
+}
+
 /// Convert Binance Perpetual REST kline to core Kline type
-pub fn convert_binance_perp_rest_kline(binance_kline: &BinancePerpRestKline) -> Kline {
-    Kline {
-        symbol: string_to_symbol(""), // Symbol should be set by caller
+pub fn convert_binance_perp_rest_kline(
+    binance_kline: &BinancePerpRestKline,
+) -> Result<Kline, ExchangeError> {
+    Ok(Kline {
+        symbol: SYMBOLS.from_exchange_symbol(""), // Symbol should be set by caller
         open_time: binance_kline.open_time,
         close_time: binance_kline.close_time,
         interval: String::new(), // Interval should be set by caller
-        open_price: string_to_price(&binance_kline.open_price),
-        high_price: string_to_price(&binance_kline.high_price),
-        low_price: string_to_price(&binance_kline.low_price),
-        close_price: string_to_price(&binance_kline.close_price),
-        volume: string_to_volume(&binance_kline.volume),
+        open_price: try_string_to_price(&binance_kline.open_price)?,
+        high_price: try_string_to_price(&binance_kline.high_price)?,
+        low_price: try_string_to_price(&binance_kline.low_price)?,
+        close_price: try_string_to_price(&binance_kline.close_price)?,
+        volume: try_string_to_volume(&binance_kline.volume)?,
         number_of_trades: binance_kline.number_of_trades,
         final_bar: true, // REST klines are always final
-    }
+    })
+}
+
+/// Convert Binance Perpetual REST 24h ticker to core Ticker type
+pub fn convert_binance_perp_rest_ticker(
+    ticker: &BinancePerpRestTicker,
+) -> Result<Ticker, ExchangeError> {
+    Ok(Ticker {
+        symbol: SYMBOLS.from_exchange_symbol(&ticker.symbol),
+        price: try_string_to_price(&ticker.last_price)?,
+        price_change: try_string_to_price(&ticker.price_change)?,
+        price_change_percent: try_string_to_decimal(&ticker.price_change_percent)?,
+        high_price: try_string_to_price(&ticker.high_price)?,
+        low_price: try_string_to_price(&ticker.low_price)?,
+        volume: try_string_to_volume(&ticker.volume)?,
+        quote_volume: ticker
+            .quote_volume
+            .as_deref()
+            .map_or(Ok(Volume::ZERO), try_string_to_volume)?,
+        open_time: ticker.open_time,
+        close_time: ticker.close_time,
+        count: ticker.count,
+    })
+}
+
+/// Convert Binance Perpetual REST depth to core `OrderBook` type
+pub fn convert_binance_perp_rest_order_book(
+    book: &BinancePerpRestOrderBook,
+    symbol: &str,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
+        bids: try_book_levels(&book.bids)?,
+        asks: try_book_levels(&book.asks)?,
+        last_update_id: book.last_update_id,
+    })
+}
+
+/// Convert Binance Perpetual REST trade to core Trade type
+pub fn convert_binance_perp_rest_trade(
+    trade: &BinancePerpRestTrade,
+    symbol: &str,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
+        id: trade.id,
+        price: try_string_to_price(&trade.price)?,
+        quantity: try_string_to_quantity(&trade.qty)?,
+        time: trade.time,
+        is_buyer_maker: trade.is_buyer_maker,
+    })
+}
+
+/// Convert Binance Perpetual aggregate trade to core Trade, identified by its aggregate id
+pub fn convert_binance_perp_agg_trade(
+    trade: &BinancePerpRestAggTrade,
+    symbol: &str,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
+        id: trade.aggregate_id,
+        price: try_string_to_price(&trade.price)?,
+        quantity: try_string_to_quantity(&trade.quantity)?,
+        time: trade.time,
+        is_buyer_maker: trade.is_buyer_maker,
+    })
 }
 
 /// Parse WebSocket message and convert to core `MarketDataType`
@@ -104,14 +289,14 @@ pub fn parse_websocket_message(message: serde_json::Value) -> Option<MarketDataT
     // Try to parse as different WebSocket message types
     if let Ok(ticker) = serde_json::from_str::<BinancePerpWebSocketTicker>(&message_str) {
         Some(MarketDataType::Ticker(Ticker {
-            symbol: string_to_symbol(&ticker.symbol),
-            price: string_to_price(&ticker.price),
-            price_change: string_to_price(&ticker.price_change),
-            price_change_percent: string_to_decimal(&ticker.price_change_percent),
-            high_price: string_to_price(&ticker.high_price),
-            low_price: string_to_price(&ticker.low_price),
-            volume: string_to_volume(&ticker.volume),
-            quote_volume: string_to_volume(&ticker.quote_volume),
+            symbol: SYMBOLS.from_exchange_symbol(&ticker.symbol),
+            price: try_string_to_price(&ticker.price).ok()?,
+            price_change: try_string_to_price(&ticker.price_change).ok()?,
+            price_change_percent: try_string_to_decimal(&ticker.price_change_percent).ok()?,
+            high_price: try_string_to_price(&ticker.high_price).ok()?,
+            low_price: try_string_to_price(&ticker.low_price).ok()?,
+            volume: try_string_to_volume(&ticker.volume).ok()?,
+            quote_volume: try_string_to_volume(&ticker.quote_volume).ok()?,
             open_time: ticker.open_time,
             close_time: ticker.close_time,
             count: ticker.count,
@@ -120,45 +305,49 @@ pub fn parse_websocket_message(message: serde_json::Value) -> Option<MarketDataT
         serde_json::from_str::<BinancePerpWebSocketOrderBook>(&message_str)
     {
         Some(MarketDataType::OrderBook(OrderBook {
-            symbol: string_to_symbol(&order_book.symbol),
+            symbol: SYMBOLS.from_exchange_symbol(&order_book.symbol),
             bids: order_book
                 .bids
                 .iter()
-                .map(|[price, quantity]| OrderBookEntry {
-                    price: string_to_price(price),
-                    quantity: string_to_quantity(quantity),
+                .map(|[price, quantity]| {
+                    Some(OrderBookEntry {
+                        price: try_string_to_price(price).ok()?,
+                        quantity: try_string_to_quantity(quantity).ok()?,
+                    })
                 })
-                .collect(),
+                .collect::<Option<_>>()?,
             asks: order_book
                 .asks
                 .iter()
-                .map(|[price, quantity]| OrderBookEntry {
-                    price: string_to_price(price),
-                    quantity: string_to_quantity(quantity),
+                .map(|[price, quantity]| {
+                    Some(OrderBookEntry {
+                        price: try_string_to_price(price).ok()?,
+                        quantity: try_string_to_quantity(quantity).ok()?,
+                    })
                 })
-                .collect(),
+                .collect::<Option<_>>()?,
             last_update_id: order_book.final_update_id,
         }))
     } else if let Ok(trade) = serde_json::from_str::<BinancePerpWebSocketTrade>(&message_str) {
         Some(MarketDataType::Trade(Trade {
-            symbol: string_to_symbol(&trade.symbol),
+            symbol: SYMBOLS.from_exchange_symbol(&trade.symbol),
             id: trade.id,
-            price: string_to_price(&trade.price),
-            quantity: string_to_quantity(&trade.quantity),
+            price: try_string_to_price(&trade.price).ok()?,
+            quantity: try_string_to_quantity(&trade.quantity).ok()?,
             time: trade.time,
             is_buyer_maker: trade.is_buyer_maker,
         }))
     } else if let Ok(kline) = serde_json::from_str::<BinancePerpWebSocketKline>(&message_str) {
         Some(MarketDataType::Kline(Kline {
-            symbol: string_to_symbol(&kline.symbol),
+            symbol: SYMBOLS.from_exchange_symbol(&kline.symbol),
             open_time: kline.kline.open_time,
             close_time: kline.kline.close_time,
             interval: kline.kline.interval,
-            open_price: string_to_price(&kline.kline.open_price),
-            high_price: string_to_price(&kline.kline.high_price),
-            low_price: string_to_price(&kline.kline.low_price),
-            close_price: string_to_price(&kline.kline.close_price),
-            volume: string_to_volume(&kline.kline.volume),
+            open_price: try_string_to_price(&kline.kline.open_price).ok()?,
+            high_price: try_string_to_price(&kline.kline.high_price).ok()?,
+            low_price: try_string_to_price(&kline.kline.low_price).ok()?,
+            close_price: try_string_to_price(&kline.kline.close_price).ok()?,
+            volume: try_string_to_volume(&kline.kline.volume).ok()?,
             number_of_trades: kline.kline.number_of_trades,
             final_bar: kline.kline.final_bar,
         }))
@@ -11,9 +11,12 @@ pub mod connector; // compose sub-traits // fluent builder → concrete connecto
 
 // Re-export main types for easier importing
 pub use builder::{
+    build_coin_margined_connector,
+    build_coin_margined_connector_with_websocket,
     build_connector,
     build_connector_with_reconnection,
     build_connector_with_websocket,
+    build_public,
     // Legacy exports for backward compatibility
     create_binance_perp_connector,
     create_binance_perp_connector_with_reconnection,
@@ -2,9 +2,11 @@ use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
 use crate::core::types::KlineInterval;
 use crate::exchanges::binance_perp::types::{
-    BinancePerpBalance, BinancePerpExchangeInfo, BinancePerpFundingRate, BinancePerpOrderResponse,
-    BinancePerpPosition, BinancePerpPremiumIndex, BinancePerpRestKline,
-    BinancePerpWebSocketOrderBook, BinancePerpWebSocketTicker, BinancePerpWebSocketTrade,
+    BinanceFuturesApi, BinancePerpBalance, BinancePerpBatchOrderResult, BinancePerpExchangeInfo,
+    BinancePerpFundingInfo, BinancePerpFundingRate, BinancePerpLeverageBrackets, BinancePerpOrder,
+    BinancePerpOrderResponse, BinancePerpPosition, BinancePerpPremiumIndex,
+    BinancePerpRestAggTrade, BinancePerpRestKline, BinancePerpRestOrderBook, BinancePerpRestTicker,
+    BinancePerpRestTrade, BinancePerpServerTime, BinancePerpUserTrade,
 };
 use serde_json::Value;
 use tracing::instrument;
@@ -12,41 +14,61 @@ use tracing::instrument;
 /// REST API operations for Binance Perpetual
 pub struct BinancePerpRestClient<R: RestClient> {
     rest: R,
+    api: BinanceFuturesApi,
 }
 
 impl<R: RestClient> BinancePerpRestClient<R> {
     /// Create a new REST client wrapper
     pub fn new(rest: R) -> Self {
-        Self { rest }
+        Self {
+            rest,
+            api: BinanceFuturesApi::default(),
+        }
+    }
+
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    /// Target a different futures API family (USDⓈ-M by default)
+    pub const fn with_api(mut self, api: BinanceFuturesApi) -> Self {
+        self.api = api;
+        self
+    }
+
+    pub const fn api(&self) -> BinanceFuturesApi {
+        self.api
     }
 
     /// Get exchange information
     #[instrument(skip(self), fields(exchange = "binance_perp"))]
     pub async fn get_exchange_info(&self) -> Result<BinancePerpExchangeInfo, ExchangeError> {
         self.rest
-            .get_json("/fapi/v1/exchangeInfo", &[], false)
+            .get_json(&self.api.endpoint("/fapi/v1/exchangeInfo"), &[], false)
             .await
     }
 
     /// Get ticker for a specific symbol
     #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
-    pub async fn get_ticker(
-        &self,
-        symbol: &str,
-    ) -> Result<BinancePerpWebSocketTicker, ExchangeError> {
+    pub async fn get_ticker(&self, symbol: &str) -> Result<BinancePerpRestTicker, ExchangeError> {
         let params = [("symbol", symbol)];
-        self.rest
-            .get_json("/fapi/v1/ticker/24hr", &params, false)
-            .await
+        let endpoint = self.api.endpoint("/fapi/v1/ticker/24hr");
+        match self.api {
+            BinanceFuturesApi::UsdM => self.rest.get_json(&endpoint, &params, false).await,
+            BinanceFuturesApi::CoinM => {
+                first_for_symbol(self.rest.get_json(&endpoint, &params, false).await?, symbol)
+            }
+        }
     }
 
-    /// Get order book for a specific symbol
+    /// Get order book for a specific symbol; `limit` must be 5, 10, 20, 50, 100, 500 or 1000
     #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
     pub async fn get_order_book(
         &self,
         symbol: &str,
         limit: Option<u32>,
-    ) -> Result<BinancePerpWebSocketOrderBook, ExchangeError> {
+    ) -> Result<BinancePerpRestOrderBook, ExchangeError> {
         let limit_str = limit.map(|l| l.to_string());
         let mut params = vec![("symbol", symbol)];
 
@@ -54,16 +76,18 @@ impl<R: RestClient> BinancePerpRestClient<R> {
             params.push(("limit", limit.as_str()));
         }
 
-        self.rest.get_json("/fapi/v1/depth", &params, false).await
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v1/depth"), &params, false)
+            .await
     }
 
-    /// Get recent trades for a specific symbol
+    /// Get recent trades for a specific symbol, oldest first
     #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
-    pub async fn get_trades(
+    pub async fn get_recent_trades(
         &self,
         symbol: &str,
         limit: Option<u32>,
-    ) -> Result<Vec<BinancePerpWebSocketTrade>, ExchangeError> {
+    ) -> Result<Vec<BinancePerpRestTrade>, ExchangeError> {
         let limit_str = limit.map(|l| l.to_string());
         let mut params = vec![("symbol", symbol)];
 
@@ -72,10 +96,53 @@ impl<R: RestClient> BinancePerpRestClient<R> {
         }
 
         self.rest
-            .get_json("/fapi/v1/aggTrades", &params, false)
+            .get_json(&self.api.endpoint("/fapi/v1/trades"), &params, false)
+            .await
+    }
+
+    /// Get up to 1000 aggregate trades, from `from_id` or within an hour-long time range
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    pub async fn get_agg_trades(
+        &self,
+        symbol: &str,
+        from_id: Option<i64>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<BinancePerpRestAggTrade>, ExchangeError> {
+        let mut params = vec![("symbol", symbol), ("limit", "1000")];
+
+        let from_id_str;
+        let start_time_str;
+        let end_time_str;
+
+        if let Some(from_id) = from_id {
+            from_id_str = from_id.to_string();
+            params.push(("fromId", from_id_str.as_str()));
+        }
+        if let Some(start_time) = start_time {
+            start_time_str = start_time.to_string();
+            params.push(("startTime", start_time_str.as_str()));
+        }
+        if let Some(end_time) = end_time {
+            end_time_str = end_time.to_string();
+            params.push(("endTime", end_time_str.as_str()));
+        }
+
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v1/aggTrades"), &params, false)
             .await
     }
 
+    /// Get server time in milliseconds
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    pub async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let time: BinancePerpServerTime = self
+            .rest
+            .get_json(&self.api.endpoint("/fapi/v1/time"), &[], false)
+            .await?;
+        Ok(time.server_time)
+    }
+
     /// Get klines for a specific symbol
     #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol, interval = %interval))]
     pub async fn get_klines(
@@ -103,7 +170,9 @@ impl<R: RestClient> BinancePerpRestClient<R> {
             params.push(("endTime", end_time.as_str()));
         }
 
-        self.rest.get_json("/fapi/v1/klines", &params, false).await
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v1/klines"), &params, false)
+            .await
     }
 
     /// Get funding rate for a specific symbol
@@ -114,7 +183,7 @@ impl<R: RestClient> BinancePerpRestClient<R> {
     ) -> Result<BinancePerpFundingRate, ExchangeError> {
         let params = [("symbol", symbol)];
         self.rest
-            .get_json("/fapi/v1/fundingRate", &params, false)
+            .get_json(&self.api.endpoint("/fapi/v1/fundingRate"), &params, false)
             .await
     }
 
@@ -123,7 +192,17 @@ impl<R: RestClient> BinancePerpRestClient<R> {
     pub async fn get_all_funding_rates(
         &self,
     ) -> Result<Vec<BinancePerpFundingRate>, ExchangeError> {
-        self.rest.get_json("/fapi/v1/fundingRate", &[], false).await
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v1/fundingRate"), &[], false)
+            .await
+    }
+
+    /// Get funding interval adjustments (symbols not listed use the default 8h interval)
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    pub async fn get_funding_info(&self) -> Result<Vec<BinancePerpFundingInfo>, ExchangeError> {
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v1/fundingInfo"), &[], false)
+            .await
     }
 
     /// Get premium index for a specific symbol
@@ -133,9 +212,13 @@ impl<R: RestClient> BinancePerpRestClient<R> {
         symbol: &str,
     ) -> Result<BinancePerpPremiumIndex, ExchangeError> {
         let params = [("symbol", symbol)];
-        self.rest
-            .get_json("/fapi/v1/premiumIndex", &params, false)
-            .await
+        let endpoint = self.api.endpoint("/fapi/v1/premiumIndex");
+        match self.api {
+            BinanceFuturesApi::UsdM => self.rest.get_json(&endpoint, &params, false).await,
+            BinanceFuturesApi::CoinM => {
+                first_for_symbol(self.rest.get_json(&endpoint, &params, false).await?, symbol)
+            }
+        }
     }
 
     /// Get account information (authenticated)
@@ -143,19 +226,25 @@ impl<R: RestClient> BinancePerpRestClient<R> {
     pub async fn get_account_info(
         &self,
     ) -> Result<crate::exchanges::binance_perp::types::BinancePerpAccountInfo, ExchangeError> {
-        self.rest.get_json("/fapi/v2/account", &[], true).await
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v2/account"), &[], true)
+            .await
     }
 
     /// Get account balance (authenticated)
     #[instrument(skip(self), fields(exchange = "binance_perp"))]
     pub async fn get_balance(&self) -> Result<Vec<BinancePerpBalance>, ExchangeError> {
-        self.rest.get_json("/fapi/v2/balance", &[], true).await
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v2/balance"), &[], true)
+            .await
     }
 
     /// Get account positions (authenticated)
     #[instrument(skip(self), fields(exchange = "binance_perp"))]
     pub async fn get_positions(&self) -> Result<Vec<BinancePerpPosition>, ExchangeError> {
-        self.rest.get_json("/fapi/v2/positionRisk", &[], true).await
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v2/positionRisk"), &[], true)
+            .await
     }
 
     /// Place a new order (authenticated)
@@ -164,7 +253,82 @@ impl<R: RestClient> BinancePerpRestClient<R> {
         &self,
         body: &Value,
     ) -> Result<BinancePerpOrderResponse, ExchangeError> {
-        self.rest.post_json("/fapi/v1/order", body, true).await
+        self.rest
+            .post_json(&self.api.endpoint("/fapi/v1/order"), body, true)
+            .await
+    }
+
+    /// Place up to five orders in one request (authenticated)
+    #[instrument(skip(self, orders), fields(exchange = "binance_perp", count = orders.len()))]
+    pub async fn place_batch_orders(
+        &self,
+        orders: &[Value],
+    ) -> Result<Vec<BinancePerpBatchOrderResult>, ExchangeError> {
+        let batch = serde_json::to_string(orders).map_err(|e| {
+            ExchangeError::SerializationError(format!("Failed to serialize batch orders: {}", e))
+        })?;
+        self.rest
+            .post_json(
+                &self.api.endpoint("/fapi/v1/batchOrders"),
+                &serde_json::json!({ "batchOrders": batch }),
+                true,
+            )
+            .await
+    }
+
+    /// Get open orders, for one symbol or all of them (authenticated)
+    #[instrument(skip(self), fields(exchange = "binance_perp"))]
+    pub async fn get_open_orders(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<Vec<BinancePerpOrder>, ExchangeError> {
+        let params: Vec<(&str, &str)> = symbol.map(|s| ("symbol", s)).into_iter().collect();
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v1/openOrders"), &params, true)
+            .await
+    }
+
+    /// Get orders from `order_id` on, oldest first, or within a time range (authenticated)
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    pub async fn get_all_orders(
+        &self,
+        symbol: &str,
+        order_id: Option<u64>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: u32,
+    ) -> Result<Vec<BinancePerpOrder>, ExchangeError> {
+        let order_id = order_id.map(|id| id.to_string());
+        let start_time = start_time.map(|t| t.to_string());
+        let end_time = end_time.map(|t| t.to_string());
+        let limit = limit.to_string();
+        let mut params = vec![("symbol", symbol), ("limit", limit.as_str())];
+        if let Some(order_id) = &order_id {
+            params.push(("orderId", order_id));
+        }
+        if let Some(start_time) = &start_time {
+            params.push(("startTime", start_time));
+        }
+        if let Some(end_time) = &end_time {
+            params.push(("endTime", end_time));
+        }
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v1/allOrders"), &params, true)
+            .await
+    }
+
+    /// Get a single order by id (authenticated)
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    pub async fn get_order(
+        &self,
+        symbol: &str,
+        order_id: u64,
+    ) -> Result<BinancePerpOrder, ExchangeError> {
+        let order_id = order_id.to_string();
+        let params = [("symbol", symbol), ("orderId", order_id.as_str())];
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v1/order"), &params, true)
+            .await
     }
 
     /// Cancel an order (authenticated)
@@ -185,7 +349,87 @@ impl<R: RestClient> BinancePerpRestClient<R> {
             params.push(("origClientOrderId", orig_client_order_id));
         }
 
-        self.rest.delete_json("/fapi/v1/order", &params, true).await
+        self.rest
+            .delete_json(&self.api.endpoint("/fapi/v1/order"), &params, true)
+            .await
+    }
+
+    /// Cancel every open order on a symbol (authenticated)
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    pub async fn cancel_all_orders(&self, symbol: &str) -> Result<Value, ExchangeError> {
+        self.rest
+            .delete_json(
+                &self.api.endpoint("/fapi/v1/allOpenOrders"),
+                &[("symbol", symbol)],
+                true,
+            )
+            .await
+    }
+
+    /// Cancel every open order on a symbol unless called again within `countdown_ms`
+    ///
+    /// A countdown of zero disarms it (authenticated).
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    pub async fn countdown_cancel_all(
+        &self,
+        symbol: &str,
+        countdown_ms: u64,
+    ) -> Result<Value, ExchangeError> {
+        self.rest
+            .post_json(
+                &self.api.endpoint("/fapi/v1/countdownCancelAll"),
+                &serde_json::json!({ "symbol": symbol, "countdownTime": countdown_ms }),
+                true,
+            )
+            .await
+    }
+
+    /// Change the initial leverage of a symbol (authenticated)
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    pub async fn set_leverage(&self, symbol: &str, leverage: u32) -> Result<Value, ExchangeError> {
+        self.rest
+            .post_json(
+                &self.api.endpoint("/fapi/v1/leverage"),
+                &serde_json::json!({ "symbol": symbol, "leverage": leverage }),
+                true,
+            )
+            .await
+    }
+
+    /// Change the margin type of a symbol to `ISOLATED` or `CROSSED` (authenticated)
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    pub async fn set_margin_type(
+        &self,
+        symbol: &str,
+        margin_type: &str,
+    ) -> Result<Value, ExchangeError> {
+        self.rest
+            .post_json(
+                &self.api.endpoint("/fapi/v1/marginType"),
+                &serde_json::json!({ "symbol": symbol, "marginType": margin_type }),
+                true,
+            )
+            .await
+    }
+
+    /// Get the leverage brackets of a symbol (authenticated)
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    pub async fn get_leverage_brackets(
+        &self,
+        symbol: &str,
+    ) -> Result<Vec<BinancePerpLeverageBrackets>, ExchangeError> {
+        // COIN-M brackets are per pair, e.g. `BTCUSD` for `BTCUSD_PERP`
+        let params = match self.api {
+            BinanceFuturesApi::UsdM => [("symbol", symbol)],
+            BinanceFuturesApi::CoinM => [("pair", symbol.split('_').next().unwrap_or(symbol))],
+        };
+        self.rest
+            .get_json(
+                &self.api.endpoint("/fapi/v1/leverageBracket"),
+                &params,
+                true,
+            )
+            .await
     }
 
     /// Get historical funding rates for a symbol
@@ -214,7 +458,45 @@ impl<R: RestClient> BinancePerpRestClient<R> {
         }
 
         self.rest
-            .get_json("/fapi/v1/fundingRate", &params, false)
+            .get_json(&self.api.endpoint("/fapi/v1/fundingRate"), &params, false)
+            .await
+    }
+
+    /// Get the account's trades on a symbol
+    #[instrument(skip(self), fields(exchange = "binance_perp", symbol = %symbol))]
+    pub async fn get_user_trades(
+        &self,
+        symbol: &str,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<BinancePerpUserTrade>, ExchangeError> {
+        let start_time_str = start_time.map(|t| t.to_string());
+        let end_time_str = end_time.map(|t| t.to_string());
+        let limit_str = limit.map(|l| l.to_string());
+
+        let mut params = vec![("symbol", symbol)];
+
+        if let Some(ref start_time) = start_time_str {
+            params.push(("startTime", start_time.as_str()));
+        }
+        if let Some(ref end_time) = end_time_str {
+            params.push(("endTime", end_time.as_str()));
+        }
+        if let Some(ref limit) = limit_str {
+            params.push(("limit", limit.as_str()));
+        }
+
+        self.rest
+            .get_json(&self.api.endpoint("/fapi/v1/userTrades"), &params, true)
             .await
     }
 }
+
+/// COIN-M answers single-symbol queries with a one-element array
+fn first_for_symbol<T>(items: Vec<T>, symbol: &str) -> Result<T, ExchangeError> {
+    items
+        .into_iter()
+        .next()
+        .ok_or_else(|| ExchangeError::Other(format!("No data returned for {}", symbol)))
+}
@@ -1,6 +1,54 @@
+use crate::core::errors::ErrorKind;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use thiserror::Error;
 
+/// Binance futures API family served by the perpetual connector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BinanceFuturesApi {
+    /// USDⓈ-M futures (`/fapi`), margined and settled in USDT/USDC
+    #[default]
+    UsdM,
+    /// COIN-M futures (`/dapi`), quoted in USD and settled in the base coin
+    CoinM,
+}
+
+impl BinanceFuturesApi {
+    pub const fn rest_base_url(self, testnet: bool) -> &'static str {
+        match (self, testnet) {
+            (_, true) => "https://testnet.binancefuture.com",
+            (Self::UsdM, false) => "https://fapi.binance.com",
+            (Self::CoinM, false) => "https://dapi.binance.com",
+        }
+    }
+
+    pub const fn ws_url(self, testnet: bool) -> &'static str {
+        match (self, testnet) {
+            (Self::UsdM, true) => "wss://stream.binancefuture.com/ws",
+            (Self::UsdM, false) => "wss://fstream.binance.com/ws",
+            (Self::CoinM, true) => "wss://dstream.binancefuture.com/ws",
+            (Self::CoinM, false) => "wss://dstream.binance.com/ws",
+        }
+    }
+
+    /// Map a USDⓈ-M endpoint onto this API family
+    ///
+    /// COIN-M serves every endpoint the connector uses under `/dapi/v1`, including the
+    /// account endpoints that are `/fapi/v2` on USDⓈ-M.
+    pub fn endpoint(self, path: &'static str) -> Cow<'static, str> {
+        match self {
+            Self::UsdM => Cow::Borrowed(path),
+            Self::CoinM => path
+                .strip_prefix("/fapi/v1/")
+                .or_else(|| path.strip_prefix("/fapi/v2/"))
+                .map_or(Cow::Borrowed(path), |rest| {
+                    Cow::Owned(format!("/dapi/v1/{}", rest))
+                }),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum BinancePerpError {
     #[error("Authentication failed: {message}, symbol={symbol:?}")]
@@ -120,12 +168,18 @@ pub struct BinancePerpMarket {
     pub base_asset: String,
     #[serde(rename = "quoteAsset")]
     pub quote_asset: String,
+    #[serde(alias = "contractStatus")] // COIN-M naming
     pub status: String,
     #[serde(rename = "baseAssetPrecision")]
     pub base_asset_precision: i32,
     #[serde(rename = "quotePrecision")]
     pub quote_precision: i32,
     pub filters: Vec<BinancePerpFilter>,
+    #[serde(rename = "marginAsset", default)]
+    pub margin_asset: Option<String>,
+    /// USD value of one contract (COIN-M only)
+    #[serde(rename = "contractSize", default)]
+    pub contract_size: Option<Decimal>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -142,6 +196,12 @@ pub struct BinancePerpFilter {
     pub max_qty: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinancePerpServerTime {
+    pub server_time: i64,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BinancePerpExchangeInfo {
     pub symbols: Vec<BinancePerpMarket>,
@@ -181,6 +241,33 @@ pub struct BinancePerpOrderResponse {
     pub update_time: i64,
 }
 
+/// Order as returned by the order query endpoints
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinancePerpOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub price: String,
+    pub avg_price: String,
+    pub orig_qty: String,
+    pub executed_qty: String,
+    pub status: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub side: String,
+    pub time: i64,
+    pub update_time: i64,
+}
+
+/// One entry of a `batchOrders` reply; rejected orders come back as an error object in place
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BinancePerpBatchOrderResult {
+    Order(BinancePerpOrderResponse),
+    Error { code: i32, msg: String },
+}
+
 // WebSocket Types for Perpetual Futures
 #[derive(Debug, Clone, Deserialize)]
 pub struct BinancePerpWebSocketTicker {
@@ -240,6 +327,38 @@ pub struct BinancePerpWebSocketTrade {
     pub is_buyer_maker: bool,
 }
 
+/// Payload of the `<symbol>@aggTrade` stream, which merges fills of one taker order
+/// at one price
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinancePerpWebSocketAggTrade {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "a")]
+    pub aggregate_id: i64,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "q")]
+    pub quantity: String,
+    #[serde(rename = "T")]
+    pub time: i64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+impl BinancePerpWebSocketAggTrade {
+    /// Reported as a trade whose id is the aggregate trade id
+    pub fn into_trade(self) -> BinancePerpWebSocketTrade {
+        BinancePerpWebSocketTrade {
+            symbol: self.symbol,
+            id: self.aggregate_id,
+            price: self.price,
+            quantity: self.quantity,
+            time: self.time,
+            is_buyer_maker: self.is_buyer_maker,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct BinancePerpWebSocketKline {
     #[serde(rename = "s")]
@@ -272,11 +391,27 @@ pub struct BinancePerpKlineData {
     pub final_bar: bool,
 }
 
+/// `<symbol>@markPrice@1s` push, which also carries the live funding rate
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinancePerpWebSocketMarkPrice {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p")]
+    pub mark_price: String,
+    #[serde(rename = "i")]
+    pub index_price: String,
+    #[serde(rename = "r")]
+    pub funding_rate: String,
+    #[serde(rename = "T")]
+    pub next_funding_time: i64,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BinancePerpAccountInfo {
     pub assets: Vec<BinancePerpBalance>,
-    pub positions: Vec<BinancePerpPosition>,
     #[serde(rename = "canTrade")]
     pub can_trade: bool,
     #[serde(rename = "canWithdraw")]
@@ -285,20 +420,26 @@ pub struct BinancePerpAccountInfo {
     pub can_deposit: bool,
     #[serde(rename = "updateTime")]
     pub update_time: i64,
-    #[serde(rename = "totalInitialMargin")]
+    // Account totals are USDⓈ-M only; COIN-M margins each asset separately
+    #[serde(rename = "totalInitialMargin", default)]
     pub total_initial_margin: String,
-    #[serde(rename = "totalMaintMargin")]
+    #[serde(rename = "totalMaintMargin", default)]
     pub total_maint_margin: String,
-    #[serde(rename = "totalWalletBalance")]
+    #[serde(rename = "totalWalletBalance", default)]
     pub total_wallet_balance: String,
-    #[serde(rename = "totalUnrealizedPnl")]
+    #[serde(rename = "totalUnrealizedProfit", default)]
     pub total_unrealized_pnl: String,
+    #[serde(rename = "totalMarginBalance", default)]
+    pub total_margin_balance: String,
+    #[serde(rename = "availableBalance", default)]
+    pub available_balance: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BinancePerpBalance {
     pub asset: String,
+    #[serde(alias = "walletBalance")]
     pub balance: String,
     #[serde(rename = "availableBalance")]
     pub available_balance: String,
@@ -312,13 +453,47 @@ pub struct BinancePerpPosition {
     pub position_amt: String,
     #[serde(rename = "entryPrice")]
     pub entry_price: String,
-    #[serde(rename = "unRealizedPnl")]
+    #[serde(rename = "unRealizedPnl", alias = "unRealizedProfit")]
     pub un_realized_pnl: String,
     #[serde(rename = "liquidationPrice")]
     pub liquidation_price: String,
     pub leverage: String,
 }
 
+/// Entry of `/fapi/v1/leverageBracket`
+#[derive(Debug, Deserialize)]
+pub struct BinancePerpLeverageBrackets {
+    pub brackets: Vec<BinancePerpLeverageBracket>,
+}
+
+/// Notional tier on USDⓈ-M, quantity tier on COIN-M
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinancePerpLeverageBracket {
+    pub initial_leverage: u32,
+    #[serde(alias = "qtyCap")]
+    pub notional_cap: Decimal,
+    #[serde(alias = "qtyFloor")]
+    pub notional_floor: Decimal,
+    pub maint_margin_ratio: Decimal,
+}
+
+/// Entry of `/fapi/v1/userTrades`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinancePerpUserTrade {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub side: String,
+    pub price: String,
+    pub qty: String,
+    pub commission: String,
+    pub commission_asset: String,
+    pub maker: bool,
+    pub time: i64,
+}
+
 // Funding Rate Types
 #[derive(Debug, Clone, Deserialize)]
 pub struct BinancePerpFundingRate {
@@ -329,6 +504,14 @@ pub struct BinancePerpFundingRate {
     pub funding_time: i64,
 }
 
+/// Entry from `/fapi/v1/fundingInfo`; only symbols with adjusted settings are listed
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinancePerpFundingInfo {
+    pub symbol: String,
+    #[serde(rename = "fundingIntervalHours")]
+    pub funding_interval_hours: u64,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BinancePerpPremiumIndex {
     pub symbol: String,
@@ -347,6 +530,60 @@ pub struct BinancePerpPremiumIndex {
     pub time: i64,
 }
 
+// REST API snapshot types
+/// Reply of `/fapi/v1/ticker/24hr` for one symbol; COIN-M reports no quote volume
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinancePerpRestTicker {
+    pub symbol: String,
+    pub price_change: String,
+    pub price_change_percent: String,
+    pub last_price: String,
+    pub high_price: String,
+    pub low_price: String,
+    pub volume: String,
+    #[serde(default)]
+    pub quote_volume: Option<String>,
+    pub open_time: i64,
+    pub close_time: i64,
+    pub count: i64,
+}
+
+/// Reply of `/fapi/v1/depth`
+#[derive(Debug, Deserialize)]
+pub struct BinancePerpRestOrderBook {
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: i64,
+    pub bids: Vec<[String; 2]>,
+    pub asks: Vec<[String; 2]>,
+}
+
+/// Entry of `/fapi/v1/trades`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinancePerpRestTrade {
+    pub id: i64,
+    pub price: String,
+    pub qty: String,
+    pub time: i64,
+    pub is_buyer_maker: bool,
+}
+
+/// Entry of `/fapi/v1/aggTrades`
+#[derive(Debug, Deserialize)]
+pub struct BinancePerpRestAggTrade {
+    #[serde(rename = "a")]
+    pub aggregate_id: i64,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "q")]
+    pub quantity: String,
+    #[serde(rename = "T")]
+    pub time: i64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
 // REST API K-line Types
 #[derive(Debug, Deserialize)]
 pub struct BinancePerpRestKline {
@@ -375,3 +612,171 @@ pub struct BinancePerpRestKline {
     #[serde(rename = "11")]
     pub ignore: String,
 }
+
+/// Classify a Binance futures error code and message
+pub fn binance_perp_error_kind(code: i32, message: &str) -> Option<ErrorKind> {
+    match code {
+        -1003 | -1015 => Some(ErrorKind::RateLimited),
+        -1022 | -2014 | -2015 => Some(ErrorKind::Unauthorized),
+        -1121 => Some(ErrorKind::InvalidSymbol),
+        -2013 => Some(ErrorKind::OrderNotFound),
+        -2011 if message.contains("Unknown order") => Some(ErrorKind::OrderNotFound),
+        -2018 | -2019 => Some(ErrorKind::InsufficientBalance),
+        -4164 => Some(ErrorKind::MinNotional),
+        -5022 => Some(ErrorKind::PostOnlyReject),
+        -4013 | -4014 | -4016 => Some(ErrorKind::InvalidPrice),
+        -4003 | -4005 => Some(ErrorKind::InvalidQuantity),
+        -4116 => Some(ErrorKind::DuplicateOrder),
+        -1013 if message.contains("NOTIONAL") => Some(ErrorKind::MinNotional),
+        -1013 if message.contains("PRICE_FILTER") => Some(ErrorKind::InvalidPrice),
+        -1013 if message.contains("LOT_SIZE") => Some(ErrorKind::InvalidQuantity),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{conversion, ContractStyle};
+    use crate::exchanges::binance_perp::conversions::{
+        convert_binance_perp_leverage_bracket, convert_binance_perp_market,
+    };
+
+    #[test]
+    fn test_batch_order_result_parsing() {
+        let json = r#"[
+            {"orderId": 42, "origClientOrderId": "a1", "symbol": "BTCUSDT", "side": "BUY",
+             "type": "LIMIT", "origQty": "0.010", "price": "50000", "status": "NEW",
+             "updateTime": 1700000000000},
+            {"code": -2019, "msg": "Margin is insufficient."}
+        ]"#;
+        let results: Vec<BinancePerpBatchOrderResult> = serde_json::from_str(json).unwrap();
+        assert!(
+            matches!(&results[0], BinancePerpBatchOrderResult::Order(order) if order.order_id == 42)
+        );
+        assert!(matches!(
+            &results[1],
+            BinancePerpBatchOrderResult::Error { code: -2019, .. }
+        ));
+    }
+
+    #[test]
+    fn test_mark_price_stream_decoding() {
+        use crate::core::kernel::WsCodec;
+        use crate::exchanges::binance_perp::codec::{BinancePerpCodec, BinancePerpMessage};
+        use tokio_tungstenite::tungstenite::Message;
+
+        let frame = r#"{"stream": "btcusdt@markPrice@1s", "data": {"e": "markPriceUpdate",
+            "E": 1562305380000, "s": "BTCUSDT", "p": "11794.15000000", "i": "11784.62659091",
+            "P": "11784.25641265", "r": "0.00038167", "T": 1562306400000}}"#;
+        let message = BinancePerpCodec
+            .decode_message(Message::Text(frame.to_string()))
+            .unwrap();
+
+        let Some(BinancePerpMessage::MarkPrice(mark)) = message else {
+            panic!("expected a mark price update");
+        };
+        assert_eq!(mark.symbol, "BTCUSDT");
+        assert_eq!(mark.funding_rate, "0.00038167");
+        assert_eq!(mark.next_funding_time, 1_562_306_400_000);
+    }
+
+    #[test]
+    fn test_agg_trade_parsing() {
+        use crate::exchanges::binance_perp::conversions::convert_binance_perp_agg_trade;
+
+        let json = r#"[{"a": 26129, "p": "0.01633102", "q": "4.70443515", "f": 27781,
+            "l": 27781, "T": 1498793709153, "m": true}]"#;
+        let trades: Vec<BinancePerpRestAggTrade> = serde_json::from_str(json).unwrap();
+        let trade = convert_binance_perp_agg_trade(&trades[0], "BTCUSDT").unwrap();
+
+        assert_eq!(trade.id, 26129);
+        assert_eq!(trade.time, 1_498_793_709_153);
+        assert_eq!(trade.quantity, conversion::string_to_quantity("4.70443515"));
+        assert!(trade.is_buyer_maker);
+    }
+
+    #[test]
+    fn test_account_summary_parsing() {
+        use crate::exchanges::binance_perp::conversions::convert_binance_perp_account_summary;
+
+        let json = r#"{"feeTier": 0, "canTrade": true, "canDeposit": true,
+            "canWithdraw": true, "updateTime": 0, "totalInitialMargin": "0.33683000",
+            "totalMaintMargin": "0.02695000", "totalWalletBalance": "103.12345678",
+            "totalUnrealizedProfit": "0.00000000", "totalMarginBalance": "103.12345678",
+            "availableBalance": "102.78662678", "maxWithdrawAmount": "102.78662678",
+            "assets": [{"asset": "USDT", "walletBalance": "103.12345678",
+                "availableBalance": "102.78662678"}],
+            "positions": [{"symbol": "BTCUSDT", "initialMargin": "0", "positionAmt": "0"}]}"#;
+        let info: BinancePerpAccountInfo = serde_json::from_str(json).unwrap();
+        let summary = convert_binance_perp_account_summary(&info).unwrap();
+
+        assert_eq!(summary.currency, "USDT");
+        assert_eq!(summary.total_equity.to_string(), "103.12345678");
+        assert_eq!(summary.available_margin.to_string(), "102.78662678");
+        assert_eq!(summary.maintenance_margin.to_string(), "0.02695000");
+        assert!(summary.margin_ratio().unwrap() < rust_decimal::Decimal::ONE);
+    }
+
+    #[test]
+    fn test_coin_m_endpoints() {
+        let api = BinanceFuturesApi::CoinM;
+        assert_eq!(api.endpoint("/fapi/v1/depth"), "/dapi/v1/depth");
+        assert_eq!(
+            api.endpoint("/fapi/v2/positionRisk"),
+            "/dapi/v1/positionRisk"
+        );
+        assert_eq!(
+            BinanceFuturesApi::UsdM.endpoint("/fapi/v2/account"),
+            "/fapi/v2/account"
+        );
+    }
+
+    #[test]
+    fn test_leverage_bracket_parsing() {
+        let json = r#"[{"symbol": "BTCUSDT", "brackets": [
+            {"bracket": 1, "initialLeverage": 125, "notionalCap": 50000, "notionalFloor": 0,
+             "maintMarginRatio": 0.004, "cum": 0.0},
+            {"bracket": 2, "initialLeverage": 100, "notionalCap": 600000, "notionalFloor": 50000,
+             "maintMarginRatio": 0.005, "cum": 50.0}
+        ]}]"#;
+        let entries: Vec<BinancePerpLeverageBrackets> = serde_json::from_str(json).unwrap();
+        let second = convert_binance_perp_leverage_bracket(&entries[0].brackets[1]);
+
+        assert_eq!(second.max_leverage, Decimal::from(100));
+        assert_eq!(second.floor, Decimal::from(50_000));
+        assert_eq!(second.cap, Some(Decimal::from(600_000)));
+        assert_eq!(second.maintenance_margin_rate, Decimal::new(5, 3));
+    }
+
+    #[test]
+    fn test_coin_m_market_is_inverse() {
+        let raw = serde_json::json!({
+            "symbol": "BTCUSD_PERP",
+            "baseAsset": "BTC",
+            "quoteAsset": "USD",
+            "marginAsset": "BTC",
+            "contractStatus": "TRADING",
+            "contractSize": 100,
+            "baseAssetPrecision": 8,
+            "quotePrecision": 8,
+            "filters": []
+        });
+        let market: BinancePerpMarket = serde_json::from_value(raw).unwrap();
+        let market = convert_binance_perp_market(market).unwrap();
+
+        assert_eq!(market.contract_style, ContractStyle::Inverse);
+        assert!(market.status.is_trading());
+
+        // 10 contracts of $100 at $50,000 = 0.02 BTC
+        let contracts = conversion::string_to_quantity("10");
+        let price = conversion::string_to_price("50000");
+        let base = market.contracts_to_base(contracts, price);
+        assert_eq!(base, conversion::string_to_quantity("0.02"));
+        assert_eq!(market.base_to_contracts(base, price), contracts);
+        assert_eq!(
+            market.contract_notional(contracts, price),
+            Decimal::from(1000)
+        );
+    }
+}
@@ -3,23 +3,19 @@ use crate::core::errors::ExchangeError;
 use crate::core::kernel::{ReqwestRest, RestClientBuilder, RestClientConfig};
 use crate::exchanges::bybit::connector::BybitConnector;
 use crate::exchanges::bybit::signer::BybitSigner;
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
 use std::sync::Arc;
 
 /// Create a Bybit connector with REST-only support
 pub fn build_connector(
     config: ExchangeConfig,
 ) -> Result<BybitConnector<ReqwestRest, ()>, ExchangeError> {
-    let base_url = config.base_url.clone().unwrap_or_else(|| {
-        if config.testnet {
-            "https://api-testnet.bybit.com".to_string()
-        } else {
-            "https://api.bybit.com".to_string()
-        }
-    });
-
-    let rest_config = RestClientConfig::new(base_url, "bybit".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let endpoints = resolve_endpoints(ExchangeId::Bybit, &config)?;
+
+    let rest_config = RestClientConfig::new(endpoints.rest, "bybit".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -35,6 +31,15 @@ pub fn build_connector(
     Ok(BybitConnector::new_without_ws(rest, config))
 }
 
+/// Create a Bybit spot connector for public market data, ignoring any credentials in `config`
+///
+/// REST-only until spot WebSocket support lands.
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<BybitConnector<ReqwestRest, ()>, ExchangeError> {
+    build_connector(config.without_credentials())
+}
+
 /// Build connector with WebSocket support (placeholder)
 pub fn build_connector_with_websocket(
     _config: ExchangeConfig,
@@ -83,14 +88,12 @@ pub fn build_bybit_spot_connector(
     sandbox: bool,
     config: ExchangeConfig,
 ) -> Result<BybitConnector<ReqwestRest>, ExchangeError> {
-    let base_url = if sandbox {
-        "https://api-testnet.bybit.com"
-    } else {
-        "https://api.bybit.com"
-    };
+    let config = config.testnet(sandbox);
+    let endpoints = resolve_endpoints(ExchangeId::Bybit, &config)?;
 
     let signer = std::sync::Arc::new(BybitSigner::new(api_key, api_secret));
-    let rest_config = RestClientConfig::new(base_url.to_string(), "bybit".to_string());
+    let rest_config = RestClientConfig::new(endpoints.rest, "bybit".to_string())
+        .with_network(config.proxy.clone(), config.tls.clone());
 
     let rest_client = RestClientBuilder::new(rest_config)
         .with_signer(signer)
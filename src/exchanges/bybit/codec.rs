@@ -1,10 +1,14 @@
 use crate::core::errors::ExchangeError;
-use crate::core::kernel::WsCodec;
+use crate::core::kernel::{ControlFrame, WsCodec};
+use crate::exchanges::bybit::signer::BybitSigner;
 use crate::exchanges::bybit::types::{
-    BybitWebSocketKline, BybitWebSocketOrderBook, BybitWebSocketTicker, BybitWebSocketTrade,
+    BybitAccountList, BybitWebSocketExecution, BybitWebSocketKline, BybitWebSocketOrder,
+    BybitWebSocketOrderBook, BybitWebSocketPosition, BybitWebSocketTicker, BybitWebSocketTrade,
 };
 use serde::{Deserialize, Serialize};
-use serde_json::{self, Value};
+use serde_json::{self, json, Value};
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
 use tokio_tungstenite::tungstenite::Message;
 
 /// Bybit WebSocket message types
@@ -23,6 +27,23 @@ pub enum BybitWsEvent {
     Kline {
         data: BybitWebSocketKline,
     },
+    Order {
+        data: Vec<BybitWebSocketOrder>,
+    },
+    Execution {
+        data: Vec<BybitWebSocketExecution>,
+    },
+    Wallet {
+        data: Vec<BybitAccountList>,
+    },
+    Position {
+        data: Vec<BybitWebSocketPosition>,
+    },
+    /// Reply to the private stream login
+    Auth {
+        success: bool,
+        ret_msg: String,
+    },
     Pong {
         req_id: String,
     },
@@ -39,8 +60,57 @@ struct BybitSubscription {
     req_id: Option<String>,
 }
 
+/// Order book update ids by topic, shared by the spot and derivatives codecs
+///
+/// Bybit publishes no book checksum, so order book deltas are checked for gaps in
+/// their update ids instead. A gap is returned as `ExchangeError::ChecksumMismatch`
+/// naming the topic, which `ReconnectWs` resubscribes for a fresh snapshot; deltas in
+/// between are dropped.
+#[derive(Debug, Default)]
+pub struct BookUpdateIds {
+    /// Last book update id by topic, `orderbook.50.BTCUSDT`
+    update_ids: Mutex<HashMap<String, i64>>,
+}
+
+impl BookUpdateIds {
+    fn update_ids(&self) -> MutexGuard<'_, HashMap<String, i64>> {
+        self.update_ids.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Track a book message's update id, `Ok(false)` if it is a delta awaiting a snapshot
+    ///
+    /// Snapshots, and the `u = 1` Bybit sends after a service restart, reset the sequence.
+    pub fn verify(&self, topic: &str, message: &Value) -> Result<bool, ExchangeError> {
+        let Some(update_id) = message.pointer("/data/u").and_then(Value::as_i64) else {
+            return Ok(true);
+        };
+        let snapshot = message.get("type").and_then(Value::as_str) == Some("snapshot");
+        let last = self.update_ids().get(topic).copied();
+        if !snapshot && update_id != 1 {
+            let Some(last) = last else {
+                return Ok(false);
+            };
+            if update_id != last + 1 {
+                self.update_ids().remove(topic);
+                return Err(ExchangeError::ChecksumMismatch {
+                    stream: topic.to_string(),
+                    expected: last + 1,
+                    computed: update_id,
+                });
+            }
+        }
+        self.update_ids().insert(topic.to_string(), update_id);
+        Ok(true)
+    }
+}
+
 /// Bybit WebSocket codec implementation
-pub struct BybitCodec;
+///
+/// Order book deltas are checked for gaps with [`BookUpdateIds`].
+#[derive(Debug, Default)]
+pub struct BybitCodec {
+    update_ids: BookUpdateIds,
+}
 
 impl WsCodec for BybitCodec {
     type Message = BybitWsEvent;
@@ -81,6 +151,37 @@ impl WsCodec for BybitCodec {
         Ok(Message::Text(json_str))
     }
 
+    fn encode_ping(&self) -> Option<Message> {
+        Some(Message::Text(r#"{"op":"ping"}"#.to_string()))
+    }
+
+    fn acknowledges_subscriptions(&self) -> bool {
+        true
+    }
+
+    /// Op replies carry `conn_id`; public streams answer a ping with `op: "ping"` and
+    /// `ret_msg: "pong"`, private streams with `op: "pong"`
+    fn control_frame(&self, message: &Message) -> Option<ControlFrame> {
+        let Message::Text(text) = message else {
+            return None;
+        };
+        if !text.contains("\"op\"") {
+            return None;
+        }
+        let value: Value = serde_json::from_str(text).ok()?;
+        let op = value.get("op").and_then(Value::as_str);
+        let ret_msg = value.get("ret_msg").and_then(Value::as_str);
+        Some(ControlFrame {
+            connection_id: value
+                .get("conn_id")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            pong: op == Some("pong") || (op == Some("ping") && ret_msg == Some("pong")),
+            subscribed: op == Some("subscribe")
+                && value.get("success").and_then(Value::as_bool) == Some(true),
+        })
+    }
+
     fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
         match message {
             Message::Text(text) => {
@@ -93,6 +194,19 @@ impl WsCodec for BybitCodec {
 
                 // Try to parse as JSON for topic-based routing
                 if let Ok(value) = serde_json::from_str::<Value>(&text) {
+                    if value.get("op").and_then(|op| op.as_str()) == Some("auth") {
+                        return Ok(Some(BybitWsEvent::Auth {
+                            success: value
+                                .get("success")
+                                .and_then(|success| success.as_bool())
+                                .unwrap_or(false),
+                            ret_msg: value
+                                .get("ret_msg")
+                                .and_then(|msg| msg.as_str())
+                                .unwrap_or_default()
+                                .to_string(),
+                        }));
+                    }
                     if let Some(topic) = value.get("topic").and_then(|t| t.as_str()) {
                         if let Some(data) = value.get("data") {
                             match topic {
@@ -104,6 +218,9 @@ impl WsCodec for BybitCodec {
                                     }
                                 }
                                 t if t.starts_with("orderbook.") => {
+                                    if !self.update_ids.verify(t, &value)? {
+                                        return Ok(None);
+                                    }
                                     if let Ok(orderbook) =
                                         serde_json::from_value::<BybitWebSocketOrderBook>(
                                             data.clone(),
@@ -128,6 +245,27 @@ impl WsCodec for BybitCodec {
                                         return Ok(Some(BybitWsEvent::Kline { data: kline }));
                                     }
                                 }
+                                // Private topics, all-in-one or per category (`order.spot`)
+                                t if t == "order" || t.starts_with("order.") => {
+                                    if let Ok(data) = serde_json::from_value(data.clone()) {
+                                        return Ok(Some(BybitWsEvent::Order { data }));
+                                    }
+                                }
+                                t if t == "execution" || t.starts_with("execution.") => {
+                                    if let Ok(data) = serde_json::from_value(data.clone()) {
+                                        return Ok(Some(BybitWsEvent::Execution { data }));
+                                    }
+                                }
+                                "wallet" => {
+                                    if let Ok(data) = serde_json::from_value(data.clone()) {
+                                        return Ok(Some(BybitWsEvent::Wallet { data }));
+                                    }
+                                }
+                                t if t == "position" || t.starts_with("position.") => {
+                                    if let Ok(data) = serde_json::from_value(data.clone()) {
+                                        return Ok(Some(BybitWsEvent::Position { data }));
+                                    }
+                                }
                                 _ => {}
                             }
                         }
@@ -148,3 +286,73 @@ impl WsCodec for BybitCodec {
         }
     }
 }
+
+/// Order book depth for a subscription, from the 1/50/200 levels Bybit publishes
+pub fn bybit_depth_level(depth: Option<u32>) -> u32 {
+    const LEVELS: [u32; 3] = [1, 50, 200];
+    depth.map_or(200, |depth| {
+        crate::core::types::SubscriptionType::depth_level(depth, &LEVELS)
+    })
+}
+
+/// How long a private stream login signature stays valid
+const AUTH_EXPIRY_MS: u64 = 10_000;
+
+/// Codec for the private stream, which logs in with the API key on every connect
+pub struct BybitPrivateCodec {
+    signer: BybitSigner,
+    public: BybitCodec,
+}
+
+impl BybitPrivateCodec {
+    pub fn new(signer: BybitSigner) -> Self {
+        Self {
+            signer,
+            public: BybitCodec::default(),
+        }
+    }
+}
+
+impl WsCodec for BybitPrivateCodec {
+    type Message = BybitWsEvent;
+
+    fn encode_subscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        self.public.encode_subscription(streams)
+    }
+
+    fn encode_unsubscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        self.public.encode_unsubscription(streams)
+    }
+
+    fn encode_auth(&self) -> Result<Option<Message>, ExchangeError> {
+        let expires = BybitSigner::get_timestamp() + AUTH_EXPIRY_MS;
+        let signature = self.signer.sign_websocket_auth(expires)?;
+        let auth = json!({
+            "op": "auth",
+            "args": [self.signer.api_key(), expires, signature],
+        });
+        Ok(Some(Message::Text(auth.to_string())))
+    }
+
+    fn encode_ping(&self) -> Option<Message> {
+        self.public.encode_ping()
+    }
+
+    fn acknowledges_subscriptions(&self) -> bool {
+        self.public.acknowledges_subscriptions()
+    }
+
+    fn control_frame(&self, message: &Message) -> Option<ControlFrame> {
+        self.public.control_frame(message)
+    }
+
+    fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
+        self.public.decode_message(message)
+    }
+}
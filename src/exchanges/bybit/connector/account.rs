@@ -1,11 +1,36 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
-use crate::core::traits::AccountInfo;
-use crate::core::types::{Balance, Position};
-use crate::exchanges::bybit::conversions::convert_bybit_balance;
+use crate::core::traits::{AccountInfo, AssetTransfer, BorrowRateSource, WithdrawalSource};
+use crate::core::types::{
+    AccountType, Balance, BorrowRate, InterestRecord, Position, WithdrawalRecord, WithdrawalRequest,
+};
+use crate::exchanges::bybit::conversions::{
+    account_type_to_bybit, convert_bybit_balance, convert_bybit_borrow_rate,
+    convert_bybit_interest_record, convert_bybit_withdraw_record,
+};
 use crate::exchanges::bybit::rest::BybitRestClient;
-use crate::exchanges::bybit::types::{BybitAccountResult, BybitApiResponse};
+use crate::exchanges::bybit::types::{
+    bybit_error_kind, BybitAccountResult, BybitApiResponse, BybitBorrowHistoryResult,
+    BybitCollateralInfoResult, BybitTransferResult, BybitWithdrawRecordResult, BybitWithdrawResult,
+};
 use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde_json::json;
+
+/// Random v4 UUID, which Bybit requires as the id of every transfer
+fn transfer_id() -> String {
+    let mut id = rand::random::<u128>();
+    id = (id & !(0xf << 76)) | (0x4 << 76);
+    id = (id & !(0x3 << 62)) | (0x2 << 62);
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        id >> 96,
+        (id >> 80) & 0xffff,
+        (id >> 64) & 0xffff,
+        (id >> 48) & 0xffff,
+        id & 0xffff_ffff_ffff
+    )
+}
 
 /// Account implementation for Bybit
 pub struct Account<R: RestClient> {
@@ -25,6 +50,10 @@ impl<R: RestClient> Account<R> {
 
 #[async_trait]
 impl<R: RestClient + Send + Sync> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         let response: BybitApiResponse<BybitAccountResult> = self
             .rest
@@ -52,3 +81,193 @@ impl<R: RestClient + Send + Sync> AccountInfo for Account<R> {
         Ok(Vec::new())
     }
 }
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> BorrowRateSource for Account<R> {
+    async fn get_borrow_rates(&self, asset: &str) -> Result<Vec<BorrowRate>, ExchangeError> {
+        let response: BybitApiResponse<BybitCollateralInfoResult> = self
+            .rest
+            .get_json("/v5/account/collateral-info", &[("currency", asset)], true)
+            .await?;
+
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_error_kind),
+            );
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        response
+            .result
+            .list
+            .iter()
+            .map(|info| convert_bybit_borrow_rate(info, now))
+            .collect()
+    }
+
+    async fn get_interest_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<InterestRecord>, ExchangeError> {
+        let start_time_str = start_time.map(|t| t.to_string());
+        let end_time_str = end_time.map(|t| t.to_string());
+        let limit_str = limit.map(|l| l.to_string());
+
+        let mut params = Vec::new();
+        if let Some(asset) = asset {
+            params.push(("currency", asset));
+        }
+        if let Some(ref start_time) = start_time_str {
+            params.push(("startTime", start_time.as_str()));
+        }
+        if let Some(ref end_time) = end_time_str {
+            params.push(("endTime", end_time.as_str()));
+        }
+        if let Some(ref limit) = limit_str {
+            params.push(("limit", limit.as_str()));
+        }
+
+        let response: BybitApiResponse<BybitBorrowHistoryResult> = self
+            .rest
+            .get_json("/v5/account/borrow-history", &params, true)
+            .await?;
+
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_error_kind),
+            );
+        }
+
+        response
+            .result
+            .list
+            .iter()
+            .map(convert_bybit_interest_record)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> WithdrawalSource for Account<R> {
+    async fn withdraw(&self, request: WithdrawalRequest) -> Result<String, ExchangeError> {
+        let mut body = json!({
+            "coin": request.asset,
+            "address": request.address,
+            "amount": request.amount.to_string(),
+            "timestamp": chrono::Utc::now().timestamp_millis(),
+            "accountType": "FUND",
+        });
+        if let Some(network) = request.network {
+            body["chain"] = json!(network);
+        }
+        if let Some(tag) = request.address_tag {
+            body["tag"] = json!(tag);
+        }
+
+        let response: BybitApiResponse<BybitWithdrawResult> = self
+            .rest
+            .post_json("/v5/asset/withdraw", &body, true)
+            .await?;
+
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_error_kind),
+            );
+        }
+
+        Ok(response.result.id)
+    }
+
+    async fn get_withdrawal_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<WithdrawalRecord>, ExchangeError> {
+        let start_time_str = start_time.map(|t| t.to_string());
+        let end_time_str = end_time.map(|t| t.to_string());
+        let limit_str = limit.map(|l| l.to_string());
+
+        // Type 2 covers both on-chain and internal transfers
+        let mut params = vec![("withdrawType", "2")];
+        if let Some(asset) = asset {
+            params.push(("coin", asset));
+        }
+        if let Some(ref start_time) = start_time_str {
+            params.push(("startTime", start_time.as_str()));
+        }
+        if let Some(ref end_time) = end_time_str {
+            params.push(("endTime", end_time.as_str()));
+        }
+        if let Some(ref limit) = limit_str {
+            params.push(("limit", limit.as_str()));
+        }
+
+        let response: BybitApiResponse<BybitWithdrawRecordResult> = self
+            .rest
+            .get_json("/v5/asset/withdraw/query-record", &params, true)
+            .await?;
+
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_error_kind),
+            );
+        }
+
+        response
+            .result
+            .rows
+            .iter()
+            .map(convert_bybit_withdraw_record)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> AssetTransfer for Account<R> {
+    async fn transfer(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        from: AccountType,
+        to: AccountType,
+    ) -> Result<String, ExchangeError> {
+        let wallet = |account: AccountType| {
+            account_type_to_bybit(account).ok_or_else(|| {
+                ExchangeError::NotSupported(format!(
+                    "Bybit has no separate {} wallet; margin trades from the unified account",
+                    account
+                ))
+            })
+        };
+        let body = json!({
+            "transferId": transfer_id(),
+            "coin": asset,
+            "amount": amount.to_string(),
+            "fromAccountType": wallet(from)?,
+            "toAccountType": wallet(to)?,
+        });
+
+        let response: BybitApiResponse<BybitTransferResult> = self
+            .rest
+            .post_json("/v5/asset/transfer/inter-transfer", &body, true)
+            .await?;
+
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_error_kind),
+            );
+        }
+
+        Ok(response.result.transfer_id)
+    }
+}
@@ -1,14 +1,24 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
-use crate::core::traits::MarketDataSource;
+use crate::core::traits::{
+    HistoricalTradeSource, MarketSnapshotSource, RestMarketData, StreamingMarketData,
+};
 use crate::core::types::{
-    Kline, KlineInterval, Market, MarketDataType, SubscriptionType, WebSocketConfig,
+    Kline, KlineInterval, Market, MarketDataType, OrderBook, SubscriptionType, Symbol, Ticker,
+    Trade, WebSocketConfig,
 };
+use crate::exchanges::bybit::conversions::SYMBOLS;
 use crate::exchanges::bybit::conversions::{
-    convert_bybit_kline, convert_bybit_market, kline_interval_to_bybit_string,
+    convert_bybit_kline, convert_bybit_market, convert_bybit_order_book, convert_bybit_ticker,
+    convert_bybit_trade, kline_interval_to_bybit_string,
+};
+use crate::exchanges::bybit::types::{
+    bybit_error_kind, BybitApiResponse, BybitKlineResult, BybitMarketsResult, BybitOrderBook,
+    BybitTickerList, BybitTradeList,
 };
-use crate::exchanges::bybit::types::{BybitApiResponse, BybitKlineResult, BybitMarketsResult};
+use crate::exchanges::factory::ExchangeId;
 use async_trait::async_trait;
+use serde::de::DeserializeOwned;
 use tokio::sync::mpsc;
 
 /// Market data operations for Bybit
@@ -36,8 +46,28 @@ impl<R: RestClient, W> MarketData<R, W> {
     }
 }
 
+impl<R: RestClient, W: Sync> MarketData<R, W> {
+    /// Get a public spot endpoint and unwrap its V5 envelope
+    async fn get_spot<T: DeserializeOwned + Send>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, &str)],
+    ) -> Result<BybitApiResponse<T>, ExchangeError> {
+        let mut query = vec![("category", "spot")];
+        query.extend_from_slice(params);
+        let response: BybitApiResponse<T> = self.rest.get_json(endpoint, &query, false).await?;
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_error_kind),
+            );
+        }
+        Ok(response)
+    }
+}
+
 #[async_trait]
-impl<R: RestClient + 'static, W: Send + Sync + 'static> MarketDataSource for MarketData<R, W> {
+impl<R: RestClient + 'static, W: Send + Sync + 'static> RestMarketData for MarketData<R, W> {
     /// Get all available markets/trading pairs
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         let response: BybitApiResponse<BybitMarketsResult> = self
@@ -50,10 +80,10 @@ impl<R: RestClient + 'static, W: Send + Sync + 'static> MarketDataSource for Mar
             .await?;
 
         if response.ret_code != 0 {
-            return Err(ExchangeError::ApiError {
-                code: response.ret_code,
-                message: response.ret_msg,
-            });
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_error_kind),
+            );
         }
 
         let bybit_markets = response.result.list;
@@ -68,37 +98,24 @@ impl<R: RestClient + 'static, W: Send + Sync + 'static> MarketDataSource for Mar
         Ok(markets)
     }
 
-    /// Subscribe to market data via WebSocket
-    async fn subscribe_market_data(
-        &self,
-        _symbols: Vec<String>,
-        _subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        // WebSocket implementation not yet ready
-        Err(ExchangeError::Other(
-            "WebSocket market data subscription not implemented yet".to_string(),
-        ))
-    }
-
-    /// Get WebSocket endpoint URL for market data
-    fn get_websocket_url(&self) -> String {
-        if self.testnet {
-            "wss://stream-testnet.bybit.com/v5/public/spot".to_string()
-        } else {
-            "wss://stream.bybit.com/v5/public/spot".to_string()
-        }
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let response = self.rest.get("/v5/market/time", &[], false).await?;
+        // The envelope's `time` is the server time in milliseconds
+        response["time"].as_i64().ok_or_else(|| {
+            ExchangeError::InvalidResponseFormat("Missing time in server time response".to_string())
+        })
     }
 
     /// Get historical k-lines/candlestick data
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let interval_str = kline_interval_to_bybit_string(interval);
         let limit_str = limit.unwrap_or(200).to_string();
 
@@ -128,10 +145,10 @@ impl<R: RestClient + 'static, W: Send + Sync + 'static> MarketDataSource for Mar
             .await?;
 
         if response.ret_code != 0 {
-            return Err(ExchangeError::ApiError {
-                code: response.ret_code,
-                message: response.ret_msg,
-            });
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_error_kind),
+            );
         }
 
         let bybit_klines = response.result.list;
@@ -139,9 +156,10 @@ impl<R: RestClient + 'static, W: Send + Sync + 'static> MarketDataSource for Mar
 
         for bybit_kline in bybit_klines {
             if bybit_kline.len() >= 6 {
+                let start_time = bybit_kline[0].parse::<i64>().unwrap_or_default();
                 let kline_data = crate::exchanges::bybit::types::BybitKlineData {
-                    start_time: bybit_kline[0].parse::<i64>().unwrap_or_default(),
-                    end_time: bybit_kline[0].parse::<i64>().unwrap_or_default() + 60000, // Approximate end time
+                    start_time,
+                    end_time: interval.close_time(start_time),
                     interval: interval_str.to_string(),
                     open_price: bybit_kline[1].clone(),
                     high_price: bybit_kline[2].clone(),
@@ -164,3 +182,113 @@ impl<R: RestClient + 'static, W: Send + Sync + 'static> MarketDataSource for Mar
         Ok(klines)
     }
 }
+
+/// Deepest spot book served by `/v5/market/orderbook`
+const MAX_BOOK_DEPTH: u32 = 200;
+
+#[async_trait]
+impl<R: RestClient + 'static, W: Send + Sync + 'static> MarketSnapshotSource for MarketData<R, W> {
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let response: BybitApiResponse<BybitTickerList> = self
+            .get_spot("/v5/market/tickers", &[("symbol", &symbol)])
+            .await?;
+        let ticker = response.result.list.first().ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Unknown symbol: {}", symbol))
+        })?;
+        convert_bybit_ticker(ticker, response.time)
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        // Bybit defaults to a single level, so ask for the full book instead
+        let limit = depth
+            .unwrap_or(MAX_BOOK_DEPTH)
+            .min(MAX_BOOK_DEPTH)
+            .to_string();
+        let response: BybitApiResponse<BybitOrderBook> = self
+            .get_spot(
+                "/v5/market/orderbook",
+                &[
+                    ("symbol", &SYMBOLS.try_to_exchange_symbol(&symbol)?),
+                    ("limit", &limit),
+                ],
+            )
+            .await?;
+        Ok(convert_bybit_order_book(&response.result)?.truncated(depth))
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let limit_str = limit.map(|l| l.to_string());
+        let mut params = vec![("symbol", symbol.as_str())];
+        if let Some(ref limit) = limit_str {
+            params.push(("limit", limit.as_str()));
+        }
+        let response: BybitApiResponse<BybitTradeList> =
+            self.get_spot("/v5/market/recent-trade", &params).await?;
+        // Listed newest first
+        response
+            .result
+            .list
+            .iter()
+            .rev()
+            .map(|trade| convert_bybit_trade(trade, &symbol))
+            .collect()
+    }
+}
+
+/// Most trades `/v5/market/recent-trade` returns for spot
+const MAX_RECENT_TRADES: u32 = 60;
+
+#[async_trait]
+impl<R: RestClient + 'static, W: Send + Sync + 'static> HistoricalTradeSource for MarketData<R, W> {
+    /// Bybit keeps no public trade history over REST, so only the latest 60 spot trades
+    /// can be returned
+    async fn get_historical_trades(
+        &self,
+        symbol: Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let trades = self
+            .get_recent_trades(symbol, Some(MAX_RECENT_TRADES))
+            .await?;
+        Ok(trades
+            .into_iter()
+            .filter(|trade| (start_time..=end_time).contains(&trade.time))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + 'static, W: Send + Sync + 'static> StreamingMarketData for MarketData<R, W> {
+    /// Spot streaming is not wired up yet, which the conformance suite records as skipped
+    async fn subscribe_market_data(
+        &self,
+        _symbols: Vec<Symbol>,
+        _subscription_types: Vec<SubscriptionType>,
+        _config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        Err(ExchangeError::NotSupported(
+            "Bybit spot does not stream market data yet".to_string(),
+        ))
+    }
+
+    /// Get WebSocket endpoint URL for market data
+    fn get_websocket_url(&self) -> String {
+        let endpoints = ExchangeId::Bybit.endpoints();
+        endpoints
+            .network(self.testnet)
+            .unwrap_or(endpoints.mainnet)
+            .ws
+            .to_string()
+    }
+}
@@ -1,36 +1,33 @@
 use crate::core::config::ExchangeConfig;
-use crate::core::kernel::RestClient;
-use crate::core::traits::{AccountInfo, MarketDataSource, OrderPlacer};
+use crate::core::kernel::{diagnostics, Diagnostics, RestClient};
+use crate::core::traits::{
+    AccountInfo, AssetTransfer, BorrowRateSource, DiagnosticsSource, HistoricalTradeSource,
+    MarketSnapshotSource, OrderHistorySource, OrderPlacer, OrderQuery, RestMarketData,
+    StreamingMarketData, UserDataStream, WithdrawalSource,
+};
 use async_trait::async_trait;
 
 pub mod account;
 pub mod market_data;
 pub mod trading;
+pub mod user_data;
 
 pub use account::Account;
 pub use market_data::MarketData;
 pub use trading::Trading;
+pub use user_data::UserData;
 
 /// Bybit connector that composes all sub-trait implementations
 pub struct BybitConnector<R: RestClient, W = ()> {
     pub market: MarketData<R, W>,
     pub trading: Trading<R>,
     pub account: Account<R>,
+    pub user_data: UserData,
 }
 
 impl<R: RestClient + Clone + Send + Sync> BybitConnector<R, ()> {
     pub fn new(config: ExchangeConfig) -> BybitConnector<crate::core::kernel::ReqwestRest> {
-        // Create a default REST client for factory usage
-        let rest_config = crate::core::kernel::RestClientConfig::new(
-            "https://api.bybit.com".to_string(),
-            "bybit".to_string(),
-        );
-
-        let rest_client = crate::core::kernel::RestClientBuilder::new(rest_config)
-            .build()
-            .expect("Failed to create REST client");
-
-        BybitConnector::new_with_rest(rest_client, config)
+        BybitConnector::for_factory(config)
     }
 
     pub fn new_with_rest(rest: R, config: ExchangeConfig) -> Self {
@@ -38,6 +35,7 @@ impl<R: RestClient + Clone + Send + Sync> BybitConnector<R, ()> {
             market: MarketData::with_testnet(rest.clone(), config.testnet),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
+            user_data: UserData::new(&config),
         }
     }
 
@@ -49,22 +47,13 @@ impl<R: RestClient + Clone + Send + Sync> BybitConnector<R, ()> {
 // Concrete factory method for exchange factory usage
 impl BybitConnector<crate::core::kernel::ReqwestRest> {
     pub fn for_factory(config: ExchangeConfig) -> Self {
-        let rest_config = crate::core::kernel::RestClientConfig::new(
-            "https://api.bybit.com".to_string(),
-            "bybit".to_string(),
-        );
-
-        let rest_client = crate::core::kernel::RestClientBuilder::new(rest_config)
-            .build()
-            .expect("Failed to create REST client");
-
-        Self::new_with_rest(rest_client, config)
+        crate::exchanges::bybit::build_connector(config).expect("Failed to create REST client")
     }
 }
 
 // Implement traits for the connector by delegating to sub-components
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> MarketDataSource
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> RestMarketData
     for BybitConnector<R, W>
 {
     async fn get_markets(
@@ -73,9 +62,31 @@ impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> Ma
         self.market.get_markets().await
     }
 
+    async fn get_server_time(&self) -> Result<i64, crate::core::errors::ExchangeError> {
+        self.market.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: crate::core::types::Symbol,
+        interval: crate::core::types::KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<crate::core::types::Kline>, crate::core::errors::ExchangeError> {
+        self.market
+            .get_klines(symbol, interval, limit, start_time, end_time)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> StreamingMarketData
+    for BybitConnector<R, W>
+{
     async fn subscribe_market_data(
         &self,
-        symbols: Vec<String>,
+        symbols: Vec<crate::core::types::Symbol>,
         subscription_types: Vec<crate::core::types::SubscriptionType>,
         config: Option<crate::core::types::WebSocketConfig>,
     ) -> Result<
@@ -90,17 +101,48 @@ impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> Ma
     fn get_websocket_url(&self) -> String {
         self.market.get_websocket_url()
     }
+}
 
-    async fn get_klines(
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> MarketSnapshotSource
+    for BybitConnector<R, W>
+{
+    async fn get_ticker(
         &self,
-        symbol: String,
-        interval: crate::core::types::KlineInterval,
+        symbol: crate::core::types::Symbol,
+    ) -> Result<crate::core::types::Ticker, crate::core::errors::ExchangeError> {
+        self.market.get_ticker(symbol).await
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: crate::core::types::Symbol,
+        depth: Option<u32>,
+    ) -> Result<crate::core::types::OrderBook, crate::core::errors::ExchangeError> {
+        self.market.get_order_book_snapshot(symbol, depth).await
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: crate::core::types::Symbol,
         limit: Option<u32>,
-        start_time: Option<i64>,
-        end_time: Option<i64>,
-    ) -> Result<Vec<crate::core::types::Kline>, crate::core::errors::ExchangeError> {
+    ) -> Result<Vec<crate::core::types::Trade>, crate::core::errors::ExchangeError> {
+        self.market.get_recent_trades(symbol, limit).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> HistoricalTradeSource
+    for BybitConnector<R, W>
+{
+    async fn get_historical_trades(
+        &self,
+        symbol: crate::core::types::Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<crate::core::types::Trade>, crate::core::errors::ExchangeError> {
         self.market
-            .get_klines(symbol, interval, limit, start_time, end_time)
+            .get_historical_trades(symbol, start_time, end_time)
             .await
     }
 }
@@ -109,6 +151,10 @@ impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> Ma
 impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> OrderPlacer
     for BybitConnector<R, W>
 {
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
     async fn place_order(
         &self,
         order: crate::core::types::OrderRequest,
@@ -118,17 +164,68 @@ impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> Or
 
     async fn cancel_order(
         &self,
-        symbol: String,
+        symbol: crate::core::types::Symbol,
         order_id: String,
     ) -> Result<(), crate::core::errors::ExchangeError> {
         self.trading.cancel_order(symbol, order_id).await
     }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: crate::core::types::Symbol,
+        client_order_id: String,
+    ) -> Result<(), crate::core::errors::ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> OrderQuery
+    for BybitConnector<R, W>
+{
+    async fn get_open_orders(
+        &self,
+        symbol: Option<crate::core::types::Symbol>,
+    ) -> Result<Vec<crate::core::types::Order>, crate::core::errors::ExchangeError> {
+        self.trading.get_open_orders(symbol).await
+    }
+
+    async fn get_order(
+        &self,
+        symbol: crate::core::types::Symbol,
+        order_id: String,
+    ) -> Result<crate::core::types::Order, crate::core::errors::ExchangeError> {
+        self.trading.get_order(symbol, order_id).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> OrderHistorySource
+    for BybitConnector<R, W>
+{
+    async fn get_order_history(
+        &self,
+        symbol: crate::core::types::Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<crate::core::types::OrderPage, crate::core::errors::ExchangeError> {
+        self.trading
+            .get_order_history(symbol, start_time, end_time, cursor)
+            .await
+    }
 }
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> AccountInfo
     for BybitConnector<R, W>
 {
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
     async fn get_account_balance(
         &self,
     ) -> Result<Vec<crate::core::types::Balance>, crate::core::errors::ExchangeError> {
@@ -141,3 +238,87 @@ impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> Ac
         self.account.get_positions().await
     }
 }
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> BorrowRateSource
+    for BybitConnector<R, W>
+{
+    async fn get_borrow_rates(
+        &self,
+        asset: &str,
+    ) -> Result<Vec<crate::core::types::BorrowRate>, crate::core::errors::ExchangeError> {
+        self.account.get_borrow_rates(asset).await
+    }
+
+    async fn get_interest_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<crate::core::types::InterestRecord>, crate::core::errors::ExchangeError> {
+        self.account
+            .get_interest_history(asset, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> WithdrawalSource
+    for BybitConnector<R, W>
+{
+    async fn withdraw(
+        &self,
+        request: crate::core::types::WithdrawalRequest,
+    ) -> Result<String, crate::core::errors::ExchangeError> {
+        self.account.withdraw(request).await
+    }
+
+    async fn get_withdrawal_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<crate::core::types::WithdrawalRecord>, crate::core::errors::ExchangeError> {
+        self.account
+            .get_withdrawal_history(asset, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> AssetTransfer
+    for BybitConnector<R, W>
+{
+    async fn transfer(
+        &self,
+        asset: &str,
+        amount: rust_decimal::Decimal,
+        from: crate::core::types::AccountType,
+        to: crate::core::types::AccountType,
+    ) -> Result<String, crate::core::errors::ExchangeError> {
+        self.account.transfer(asset, amount, from, to).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync + 'static, W: Send + Sync + 'static> UserDataStream
+    for BybitConnector<R, W>
+{
+    async fn subscribe_user_data(
+        &self,
+        channels: Vec<crate::core::types::UserDataChannel>,
+    ) -> Result<
+        tokio::sync::mpsc::Receiver<crate::core::types::AccountEvent>,
+        crate::core::errors::ExchangeError,
+    > {
+        self.user_data.subscribe_user_data(channels).await
+    }
+}
+
+impl<R: RestClient, W> DiagnosticsSource for BybitConnector<R, W> {
+    fn diagnostics(&self) -> Diagnostics {
+        diagnostics("bybit")
+    }
+}
@@ -1,12 +1,16 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
-use crate::core::traits::OrderPlacer;
-use crate::core::types::{OrderRequest, OrderResponse, Symbol};
+use crate::core::traits::{OrderHistorySource, OrderPlacer, OrderQuery};
+use crate::core::types::{Order, OrderPage, OrderRequest, OrderResponse, Symbol};
+use crate::exchanges::bybit::conversions::SYMBOLS;
 use crate::exchanges::bybit::conversions::{
-    convert_order_side, convert_order_type, convert_time_in_force,
+    convert_bybit_order, convert_bybit_order_response, convert_order_side, convert_order_type,
+    convert_time_in_force,
 };
 use crate::exchanges::bybit::rest::BybitRestClient;
-use crate::exchanges::bybit::types::{BybitOrderRequest, BybitOrderResponse};
+use crate::exchanges::bybit::types::{
+    bybit_error_kind, BybitApiResponse, BybitOrderList, BybitOrderRequest, BybitOrderResponse,
+};
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use std::str::FromStr;
@@ -29,17 +33,22 @@ impl<R: RestClient> Trading<R> {
 
 #[async_trait]
 impl<R: RestClient + Send + Sync> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
         // Convert unified order to Bybit format
         let bybit_order = BybitOrderRequest {
             category: "spot".to_string(),
-            symbol: order.symbol.to_string(),
+            symbol: SYMBOLS.try_to_exchange_symbol(&order.symbol)?,
             side: convert_order_side(&order.side),
             order_type: convert_order_type(&order.order_type),
             qty: order.quantity.to_string(),
             price: order.price.map(|p| p.to_string()),
             time_in_force: order.time_in_force.as_ref().map(convert_time_in_force),
             stop_price: order.stop_price.map(|p| p.to_string()),
+            order_link_id: order.client_order_id.clone(),
         };
 
         // Validate required fields
@@ -61,23 +70,103 @@ impl<R: RestClient + Send + Sync> OrderPlacer for Trading<R> {
 
         let bybit_response: BybitOrderResponse = self.rest.place_order(&bybit_order).await?;
 
-        // Convert Bybit response to unified response
-        Ok(OrderResponse {
-            order_id: bybit_response.order_id.clone(),
-            client_order_id: bybit_response.client_order_id.clone(),
-            symbol: Symbol::from_string(&bybit_response.symbol)
-                .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid symbol: {}", e)))?,
-            side: order.side,
-            order_type: order.order_type,
-            quantity: order.quantity,
-            price: order.price,
-            status: bybit_response.status,
-            timestamp: bybit_response.timestamp,
-        })
+        Ok(convert_bybit_order_response(bybit_response, order))
     }
 
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         self.rest.cancel_order(&symbol, &order_id).await?;
         Ok(())
     }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        self.rest
+            .cancel_order_by_link_id(&symbol, &client_order_id)
+            .await?;
+        Ok(())
+    }
+}
+
+fn order_list(response: BybitApiResponse<BybitOrderList>) -> Result<Vec<Order>, ExchangeError> {
+    if response.ret_code != 0 {
+        return Err(
+            ExchangeError::api_error(response.ret_code, response.ret_msg)
+                .classify(bybit_error_kind),
+        );
+    }
+    response
+        .result
+        .list
+        .iter()
+        .map(convert_bybit_order)
+        .collect()
+}
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> OrderQuery for Trading<R> {
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        let symbol = symbol
+            .map(|s| SYMBOLS.try_to_exchange_symbol(&s))
+            .transpose()?;
+        order_list(self.rest.get_open_orders(symbol.as_deref(), None).await?)
+    }
+
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let open = order_list(
+            self.rest
+                .get_open_orders(Some(&symbol), Some(&order_id))
+                .await?,
+        )?;
+        // Filled and canceled orders drop out of the realtime list
+        let mut orders = if open.is_empty() {
+            order_list(self.rest.get_closed_order(&symbol, &order_id).await?)?
+        } else {
+            open
+        };
+        orders.pop().ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Order {} not found", order_id))
+        })
+    }
+}
+
+/// Largest page `/v5/order/history` returns
+const ORDER_HISTORY_LIMIT: u32 = 50;
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> OrderHistorySource for Trading<R> {
+    /// Pages run newest first; the cursor is Bybit's `nextPageCursor`
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        let response = self
+            .rest
+            .get_order_history(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                start_time,
+                end_time,
+                cursor.as_deref(),
+                ORDER_HISTORY_LIMIT,
+            )
+            .await?;
+        let next_cursor = Some(response.result.next_page_cursor.clone())
+            .filter(|cursor| !cursor.is_empty() && !response.result.list.is_empty());
+        let orders = order_list(response)?
+            .into_iter()
+            .filter(|order| !order.status.is_open())
+            .collect();
+        Ok(OrderPage {
+            orders,
+            next_cursor,
+        })
+    }
 }
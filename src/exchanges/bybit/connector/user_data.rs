@@ -0,0 +1,186 @@
+use crate::core::{
+    config::ExchangeConfig,
+    errors::ExchangeError,
+    kernel::{ReconnectWs, TungsteniteWs, WsConfig, WsControl, WsSession},
+    traits::UserDataStream,
+    types::{AccountEvent, UserDataChannel},
+};
+use crate::exchanges::bybit::codec::{BybitPrivateCodec, BybitWsEvent};
+use crate::exchanges::bybit::conversions::{
+    convert_bybit_balance, convert_bybit_execution, convert_bybit_order_update,
+    convert_bybit_ws_position,
+};
+use crate::exchanges::bybit::signer::BybitSigner;
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Bybit drops private connections that stay silent, so ping well inside its window
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(20);
+const AUTH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// User data stream implementation for Bybit
+pub struct UserData {
+    signer: Option<BybitSigner>,
+    testnet: bool,
+    ws_config: WsConfig,
+    control: WsControl,
+}
+
+impl UserData {
+    pub fn new(config: &ExchangeConfig) -> Self {
+        let signer = config.has_credentials().then(|| {
+            BybitSigner::new(
+                config.api_key().to_string(),
+                config.secret_key().to_string(),
+            )
+        });
+        Self {
+            signer,
+            testnet: config.testnet,
+            ws_config: config.ws_config(),
+            control: WsControl::default(),
+        }
+    }
+
+    /// Control info of the most recently opened user data stream, including its
+    /// `conn_id` and heartbeat round trip
+    pub fn control(&self) -> WsControl {
+        self.control.clone()
+    }
+
+    fn ws_url(&self) -> String {
+        if self.testnet {
+            "wss://stream-testnet.bybit.com/v5/private".to_string()
+        } else {
+            "wss://stream.bybit.com/v5/private".to_string()
+        }
+    }
+}
+
+const fn channel_topic(channel: UserDataChannel) -> &'static str {
+    match channel {
+        UserDataChannel::Orders => "order",
+        UserDataChannel::Fills => "execution",
+        UserDataChannel::Balances => "wallet",
+        UserDataChannel::Positions => "position",
+    }
+}
+
+fn convert_event(event: BybitWsEvent) -> Result<Vec<AccountEvent>, ExchangeError> {
+    match event {
+        BybitWsEvent::Order { data } => data
+            .iter()
+            .map(|order| convert_bybit_order_update(order).map(AccountEvent::Order))
+            .collect(),
+        BybitWsEvent::Execution { data } => data
+            .iter()
+            .map(|execution| convert_bybit_execution(execution).map(AccountEvent::Fill))
+            .collect(),
+        BybitWsEvent::Wallet { data } => data
+            .iter()
+            .flat_map(|account| &account.coin)
+            .map(|coin| convert_bybit_balance(coin).map(AccountEvent::Balance))
+            .collect(),
+        BybitWsEvent::Position { data } => data
+            .iter()
+            .map(|position| convert_bybit_ws_position(position).map(AccountEvent::Position))
+            .collect(),
+        _ => Ok(Vec::new()),
+    }
+}
+
+#[async_trait]
+impl UserDataStream for UserData {
+    async fn subscribe_user_data(
+        &self,
+        channels: Vec<UserDataChannel>,
+    ) -> Result<mpsc::Receiver<AccountEvent>, ExchangeError> {
+        let signer = self
+            .signer
+            .clone()
+            .ok_or(ExchangeError::AuthenticationRequired)?;
+        let topics: Vec<&str> = channels.iter().map(|c| channel_topic(*c)).collect();
+
+        // The codec logs in on every connect, before the session resubscribes
+        let ws_session = TungsteniteWs::new(
+            self.ws_url(),
+            "bybit".to_string(),
+            BybitPrivateCodec::new(signer),
+        )
+        .with_config(self.ws_config.clone())
+        .with_control(self.control.clone());
+        let mut reconnect_ws = ReconnectWs::new(ws_session).with_max_reconnect_attempts(u32::MAX);
+        reconnect_ws.connect().await.map_err(|e| {
+            ExchangeError::Other(format!("Failed to connect user data stream: {}", e))
+        })?;
+
+        // Surface rejected credentials to the caller rather than a silent stream
+        let login = tokio::time::timeout(AUTH_TIMEOUT, async {
+            while let Some(result) = reconnect_ws.next_message().await {
+                if let Ok(BybitWsEvent::Auth { success, ret_msg }) = result {
+                    return success
+                        .then_some(())
+                        .ok_or(ExchangeError::AuthError(ret_msg));
+                }
+            }
+            Err(ExchangeError::NetworkError(
+                "User data stream closed during login".to_string(),
+            ))
+        });
+        login.await.map_err(|_| {
+            ExchangeError::ConnectionTimeout("No reply to user data stream login".to_string())
+        })??;
+
+        reconnect_ws.subscribe(&topics).await?;
+
+        let (tx, rx) = mpsc::channel(1000);
+        tokio::spawn(async move {
+            let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+            heartbeat.tick().await;
+
+            loop {
+                let result = tokio::select! {
+                    _ = heartbeat.tick() => {
+                        if let Err(e) = reconnect_ws.ping().await {
+                            tracing::warn!(error = %e, "Failed to ping Bybit user data stream");
+                        }
+                        continue;
+                    }
+                    result = reconnect_ws.next_message() => result,
+                };
+                let Some(result) = result else {
+                    break;
+                };
+
+                let event = match result {
+                    Ok(BybitWsEvent::Auth {
+                        success: false,
+                        ret_msg,
+                    }) => {
+                        tracing::error!(error = %ret_msg, "Bybit user data stream login rejected");
+                        break;
+                    }
+                    Ok(event) => event,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Bybit user data stream error");
+                        continue;
+                    }
+                };
+                match convert_event(event) {
+                    Ok(events) => {
+                        for event in events {
+                            if channels.contains(&event.channel()) && tx.send(event).await.is_err()
+                            {
+                                return; // Receiver dropped
+                            }
+                        }
+                    }
+                    Err(e) => tracing::warn!(error = %e, "Dropping malformed Bybit account event"),
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
@@ -1,41 +1,57 @@
+use crate::core::symbol::SymbolConvention;
 use crate::core::{
     errors::ExchangeError,
     types::{
-        Balance, Kline, KlineInterval, Market, MarketDataType, OrderSide, OrderType, Price,
-        Quantity, Symbol, Ticker, TimeInForce, Trade, Volume,
+        conversion, AccountFill, AccountType, Balance, BorrowRate, ContractStyle, InterestRecord,
+        Kline, KlineInterval, Market, MarketDataType, MarketStatus, Order, OrderBook, OrderRequest,
+        OrderResponse, OrderSide, OrderStatus, OrderType, OrderUpdate, Position, PositionSide,
+        Price, Quantity, Symbol, Ticker, TimeInForce, Trade, Volume, WithdrawalRecord,
+        WithdrawalStatus,
     },
 };
 use crate::exchanges::bybit::types::{
-    BybitCoinBalance, BybitKlineData, BybitMarket, BybitTicker, BybitTrade,
+    BybitBorrowRecord, BybitCoinBalance, BybitCollateralInfo, BybitKlineData, BybitMarket,
+    BybitOrder, BybitOrderBook, BybitOrderResponse, BybitTicker, BybitTrade,
+    BybitWebSocketExecution, BybitWebSocketOrder, BybitWebSocketPosition, BybitWithdrawRecord,
 };
+use crate::exchanges::factory::ExchangeId;
 use rust_decimal::Decimal;
 use serde_json::Value;
-use std::str::FromStr;
+
+/// Bybit symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::Bybit.symbol_convention();
 
 /// Convert Bybit market data to unified Market type
 pub fn convert_bybit_market(market: &BybitMarket) -> Result<Market, ExchangeError> {
     Ok(Market {
         symbol: Symbol::new(market.base_coin.clone(), market.quote_coin.clone())
             .unwrap_or_else(|_| Symbol::default()),
-        status: market.status.clone(),
+        status: MarketStatus::from_venue_str(&market.status),
         base_precision: market.base_precision.unwrap_or(8) as i32,
         quote_precision: market.quote_precision.unwrap_or(8) as i32,
         min_qty: market
             .min_qty
-            .clone()
-            .and_then(|s| Quantity::from_str(&s).ok()),
+            .as_deref()
+            .map(conversion::try_string_to_quantity)
+            .transpose()?,
         max_qty: market
             .max_qty
-            .clone()
-            .and_then(|s| Quantity::from_str(&s).ok()),
+            .as_deref()
+            .map(conversion::try_string_to_quantity)
+            .transpose()?,
         min_price: market
             .min_price
-            .clone()
-            .and_then(|s| Price::from_str(&s).ok()),
+            .as_deref()
+            .map(conversion::try_string_to_price)
+            .transpose()?,
         max_price: market
             .max_price
-            .clone()
-            .and_then(|s| Price::from_str(&s).ok()),
+            .as_deref()
+            .map(conversion::try_string_to_price)
+            .transpose()?,
+        contract_style: ContractStyle::Spot,
+        contract_size: None,
+        extensions: None,
     })
 }
 
@@ -48,36 +64,58 @@ pub fn convert_bybit_market_to_symbol(bybit_market: &BybitMarket) -> Symbol {
     .unwrap_or_else(|_| Symbol::default())
 }
 
-/// Convert Bybit ticker to unified Ticker type
-pub fn convert_bybit_ticker(ticker: &BybitTicker, symbol: &str) -> Result<Ticker, ExchangeError> {
-    let symbol_obj = Symbol::from_string(symbol)
-        .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid symbol: {}", e)))?;
+/// Convert Bybit ticker to unified Ticker type, stamped with the reply's server `time`
+pub fn convert_bybit_ticker(ticker: &BybitTicker, time: i64) -> Result<Ticker, ExchangeError> {
+    let optional_price = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(conversion::try_string_to_price)
+            .transpose()
+            .map(|price| price.unwrap_or(Price::ZERO))
+    };
+    let optional_volume = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(conversion::try_string_to_volume)
+            .transpose()
+            .map(|volume| volume.unwrap_or(Volume::ZERO))
+    };
+
+    let price = conversion::try_string_to_price(&ticker.last_price)?;
+    let prev_price = optional_price(&ticker.prev_price_24h)?;
+    let change_fraction = ticker
+        .price_24h_pcnt
+        .as_deref()
+        .map(conversion::try_string_to_decimal)
+        .transpose()?
+        .unwrap_or(Decimal::ZERO);
 
     Ok(Ticker {
-        symbol: symbol_obj,
-        price: Price::from_str(&ticker.last_price)
-            .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid price: {}", e)))?,
-        price_change: Price::from_str("0.0").unwrap(), // Default as we don't have this data
-        price_change_percent: Decimal::from_str("0.0").unwrap(), // Default
-        high_price: ticker
-            .high_price_24h
-            .as_ref()
-            .and_then(|s| Price::from_str(s).ok())
-            .unwrap_or_else(|| Price::from_str("0.0").unwrap()),
-        low_price: ticker
-            .low_price_24h
-            .as_ref()
-            .and_then(|s| Price::from_str(s).ok())
-            .unwrap_or_else(|| Price::from_str("0.0").unwrap()),
-        volume: ticker
-            .volume_24h
-            .as_ref()
-            .and_then(|s| Volume::from_str(s).ok())
-            .unwrap_or_else(|| Volume::from_str("0.0").unwrap()),
-        quote_volume: Volume::from_str("0.0").unwrap(), // Default
-        open_time: ticker.time.unwrap_or(0),
-        close_time: ticker.time.unwrap_or(0),
-        count: 0, // Default
+        symbol: SYMBOLS.from_exchange_symbol(&ticker.symbol),
+        price,
+        price_change: if prev_price.value().is_zero() {
+            Price::ZERO
+        } else {
+            Price::new(price.value() - prev_price.value())
+        },
+        price_change_percent: change_fraction * Decimal::ONE_HUNDRED,
+        high_price: optional_price(&ticker.high_price_24h)?,
+        low_price: optional_price(&ticker.low_price_24h)?,
+        volume: optional_volume(&ticker.volume_24h)?,
+        quote_volume: optional_volume(&ticker.turnover_24h)?,
+        open_time: time - 24 * 60 * 60 * 1000,
+        close_time: time,
+        count: 0, // Not reported by Bybit
+    })
+}
+
+/// Convert Bybit order book to unified `OrderBook` type
+pub fn convert_bybit_order_book(book: &BybitOrderBook) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: SYMBOLS.from_exchange_symbol(&book.symbol),
+        bids: conversion::try_book_levels(&book.bids)?,
+        asks: conversion::try_book_levels(&book.asks)?,
+        last_update_id: book.update_id,
     })
 }
 
@@ -85,38 +123,230 @@ pub fn convert_bybit_ticker(ticker: &BybitTicker, symbol: &str) -> Result<Ticker
 pub fn convert_bybit_balance(balance: &BybitCoinBalance) -> Result<Balance, ExchangeError> {
     Ok(Balance {
         asset: balance.coin.clone(),
-        free: Quantity::from_str(&balance.wallet_balance)
-            .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid balance: {}", e)))?,
-        locked: Quantity::from_str(&balance.locked).map_err(|e| {
-            ExchangeError::InvalidParameters(format!("Invalid locked balance: {}", e))
-        })?,
+        free: conversion::try_string_to_quantity(&balance.wallet_balance)?,
+        locked: conversion::try_string_to_quantity(&balance.locked)?,
+    })
+}
+
+/// Convert Bybit collateral info to unified `BorrowRate` type, observed at `timestamp`
+pub fn convert_bybit_borrow_rate(
+    info: &BybitCollateralInfo,
+    timestamp: i64,
+) -> Result<BorrowRate, ExchangeError> {
+    Ok(BorrowRate {
+        asset: info.currency.clone(),
+        rate: conversion::try_string_to_decimal(&info.hourly_borrow_rate)?,
+        interval: std::time::Duration::from_secs(3600),
+        timestamp,
+    })
+}
+
+/// Convert Bybit borrow history entry to unified `InterestRecord` type
+pub fn convert_bybit_interest_record(
+    record: &BybitBorrowRecord,
+) -> Result<InterestRecord, ExchangeError> {
+    Ok(InterestRecord {
+        asset: record.currency.clone(),
+        interest: conversion::try_string_to_decimal(&record.borrow_cost)?,
+        principal: conversion::try_string_to_decimal(&record.interest_bearing_borrow_size).ok(),
+        rate: conversion::try_string_to_decimal(&record.hourly_borrow_rate).ok(),
+        timestamp: record.created_time,
+    })
+}
+
+/// Bybit v5 account type of a wallet; margin trading lives inside the unified account
+pub const fn account_type_to_bybit(account: AccountType) -> Option<&'static str> {
+    match account {
+        AccountType::Spot => Some("SPOT"),
+        AccountType::Futures => Some("CONTRACT"),
+        AccountType::Funding => Some("FUND"),
+        AccountType::Unified => Some("UNIFIED"),
+        AccountType::Margin => None,
+    }
+}
+
+/// Map Bybit withdrawal status to unified `WithdrawalStatus` type
+pub fn convert_bybit_withdraw_status(status: &str) -> WithdrawalStatus {
+    match status {
+        "BlockchainConfirmed" => WithdrawalStatus::Processing,
+        "success" => WithdrawalStatus::Completed,
+        "CancelByUser" => WithdrawalStatus::Cancelled,
+        "Reject" | "Fail" => WithdrawalStatus::Failed,
+        // SecurityCheck, Pending, MoreInformationRequired and anything new
+        _ => WithdrawalStatus::Pending,
+    }
+}
+
+/// Convert Bybit withdrawal record to unified `WithdrawalRecord` type
+pub fn convert_bybit_withdraw_record(
+    record: &BybitWithdrawRecord,
+) -> Result<WithdrawalRecord, ExchangeError> {
+    let timestamp = record
+        .create_time
+        .parse::<i64>()
+        .map_err(|e| ExchangeError::ParseError(format!("Invalid withdrawal time: {}", e)))?;
+
+    Ok(WithdrawalRecord {
+        id: record.withdraw_id.clone(),
+        asset: record.coin.clone(),
+        amount: conversion::try_string_to_decimal(&record.amount)?,
+        fee: conversion::try_string_to_decimal(&record.withdraw_fee).ok(),
+        address: record.to_address.clone(),
+        network: Some(record.chain.clone()).filter(|chain| !chain.is_empty()),
+        tx_id: Some(record.tx_id.clone()).filter(|tx_id| !tx_id.is_empty()),
+        status: convert_bybit_withdraw_status(&record.status),
+        timestamp,
+    })
+}
+
+fn parse_bybit_side(side: &str) -> OrderSide {
+    if side == "Sell" {
+        OrderSide::Sell
+    } else {
+        OrderSide::Buy
+    }
+}
+
+fn parse_bybit_time(time: &str) -> Result<i64, ExchangeError> {
+    time.parse::<i64>()
+        .map_err(|e| ExchangeError::ParseError(format!("Invalid timestamp '{}': {}", time, e)))
+}
+
+/// Convert Bybit private `order` update to unified `OrderUpdate` type
+pub fn convert_bybit_order_update(
+    order: &BybitWebSocketOrder,
+) -> Result<OrderUpdate, ExchangeError> {
+    // Market orders report a zero or empty price
+    let price = conversion::try_string_to_price(&order.price)
+        .ok()
+        .filter(|price| !price.value().is_zero());
+
+    Ok(OrderUpdate {
+        symbol: SYMBOLS.from_exchange_symbol(&order.symbol),
+        order_id: order.order_id.clone(),
+        client_order_id: order.order_link_id.clone(),
+        side: parse_bybit_side(&order.side),
+        status: order.order_status.clone(),
+        price,
+        quantity: conversion::try_string_to_quantity(&order.qty)?,
+        filled_quantity: conversion::try_string_to_quantity(&order.cum_exec_qty)?,
+        timestamp: parse_bybit_time(&order.updated_time)?,
+    })
+}
+
+/// Convert Bybit queried order to unified `Order` type
+pub fn convert_bybit_order(order: &BybitOrder) -> Result<Order, ExchangeError> {
+    // Market orders report a zero or empty price, unfilled orders a zero or empty average
+    let non_zero = |value: &str| {
+        conversion::try_string_to_price(value)
+            .ok()
+            .filter(|price| !price.value().is_zero())
+    };
+
+    Ok(Order {
+        order_id: order.order_id.clone(),
+        client_order_id: order.order_link_id.clone(),
+        symbol: SYMBOLS.from_exchange_symbol(&order.symbol),
+        side: parse_bybit_side(&order.side),
+        order_type: if order.order_type == "Market" {
+            OrderType::Market
+        } else {
+            OrderType::Limit
+        },
+        status: OrderStatus::from_venue(&order.order_status),
+        price: non_zero(&order.price),
+        quantity: conversion::try_string_to_quantity(&order.qty)?,
+        filled_quantity: conversion::try_string_to_quantity(&order.cum_exec_qty)?,
+        average_price: non_zero(&order.avg_price),
+        created_time: parse_bybit_time(&order.created_time)?,
+        updated_time: parse_bybit_time(&order.updated_time)?,
+    })
+}
+
+/// Convert Bybit private `execution` update to unified `AccountFill` type
+pub fn convert_bybit_execution(
+    execution: &BybitWebSocketExecution,
+) -> Result<AccountFill, ExchangeError> {
+    Ok(AccountFill {
+        symbol: SYMBOLS.from_exchange_symbol(&execution.symbol),
+        order_id: execution.order_id.clone(),
+        trade_id: execution.exec_id.clone(),
+        side: parse_bybit_side(&execution.side),
+        price: conversion::try_string_to_price(&execution.exec_price)?,
+        quantity: conversion::try_string_to_quantity(&execution.exec_qty)?,
+        fee: conversion::try_string_to_decimal(&execution.exec_fee)?,
+        fee_asset: execution
+            .fee_currency
+            .clone()
+            .filter(|asset| !asset.is_empty()),
+        is_maker: execution.is_maker,
+        timestamp: parse_bybit_time(&execution.exec_time)?,
     })
 }
 
+/// Convert Bybit private `position` update to unified Position type
+pub fn convert_bybit_ws_position(
+    position: &BybitWebSocketPosition,
+) -> Result<Position, ExchangeError> {
+    Ok(Position {
+        symbol: SYMBOLS.from_exchange_symbol(&position.symbol),
+        position_side: match position.side.as_str() {
+            "Buy" => PositionSide::Long,
+            "Sell" => PositionSide::Short,
+            _ => PositionSide::Both,
+        },
+        // Empty once the position is closed
+        entry_price: conversion::try_string_to_price(&position.entry_price)
+            .unwrap_or_else(|_| Price::new(Decimal::ZERO)),
+        position_amount: conversion::try_string_to_quantity(&position.size)?,
+        unrealized_pnl: conversion::try_string_to_decimal(&position.unrealised_pnl)
+            .unwrap_or_default(),
+        // Empty when the position has no liquidation price
+        liquidation_price: conversion::try_string_to_price(&position.liq_price).ok(),
+        leverage: conversion::try_string_to_decimal(&position.leverage).unwrap_or(Decimal::ONE),
+        margin_mode: None,
+        margin: None,
+        extensions: None,
+    })
+}
+
+/// Convert a Bybit order acknowledgement, filling what it omits from `order`
+///
+/// The order is live once Bybit acknowledges it, so nothing here may fail.
+pub fn convert_bybit_order_response(
+    response: BybitOrderResponse,
+    order: OrderRequest,
+) -> OrderResponse {
+    OrderResponse {
+        order_id: response.order_id,
+        client_order_id: response.client_order_id,
+        symbol: SYMBOLS.from_exchange_symbol(&response.symbol),
+        side: order.side,
+        order_type: order.order_type,
+        quantity: order.quantity,
+        price: order.price,
+        status: response.status,
+        timestamp: response.timestamp,
+        extensions: None,
+    }
+}
+
 /// Convert Bybit kline data to unified Kline type
 pub fn convert_bybit_kline(
     kline: &BybitKlineData,
     symbol: &str,
     interval: &str,
 ) -> Result<Kline, ExchangeError> {
-    let symbol_obj = Symbol::from_string(symbol)
-        .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid symbol: {}", e)))?;
-
     Ok(Kline {
-        symbol: symbol_obj,
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
         open_time: kline.start_time,
         close_time: kline.end_time,
         interval: interval.to_string(),
-        open_price: Price::from_str(&kline.open_price)
-            .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid open price: {}", e)))?,
-        high_price: Price::from_str(&kline.high_price)
-            .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid high price: {}", e)))?,
-        low_price: Price::from_str(&kline.low_price)
-            .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid low price: {}", e)))?,
-        close_price: Price::from_str(&kline.close_price)
-            .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid close price: {}", e)))?,
-        volume: Volume::from_str(&kline.volume)
-            .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid volume: {}", e)))?,
+        open_price: conversion::try_string_to_price(&kline.open_price)?,
+        high_price: conversion::try_string_to_price(&kline.high_price)?,
+        low_price: conversion::try_string_to_price(&kline.low_price)?,
+        close_price: conversion::try_string_to_price(&kline.close_price)?,
+        volume: conversion::try_string_to_volume(&kline.volume)?,
         number_of_trades: 0, // Default as we don't have this in BybitKlineData
         final_bar: true,
     })
@@ -124,21 +354,16 @@ pub fn convert_bybit_kline(
 
 /// Convert Bybit trade to unified Trade type
 pub fn convert_bybit_trade(trade: &BybitTrade, symbol: &str) -> Result<Trade, ExchangeError> {
-    let symbol_obj = Symbol::from_string(symbol)
-        .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid symbol: {}", e)))?;
-
-    let trade_id = trade.id.parse::<i64>().unwrap_or(0);
-
     Ok(Trade {
-        symbol: symbol_obj,
-        id: trade_id,
-        price: Price::from_str(&trade.price)
-            .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid trade price: {}", e)))?,
-        quantity: Quantity::from_str(&trade.qty).map_err(|e| {
-            ExchangeError::InvalidParameters(format!("Invalid trade quantity: {}", e))
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
+        // Spot ids are numeric; derivatives use UUIDs, which have no numeric form
+        id: trade.exec_id.parse().unwrap_or(0),
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: conversion::try_string_to_quantity(&trade.size)?,
+        time: trade.time.parse().map_err(|_| {
+            ExchangeError::ParseError(format!("Invalid trade time: {}", trade.time))
         })?,
-        time: trade.time,
-        is_buyer_maker: trade.is_buyer_maker.unwrap_or(false),
+        is_buyer_maker: trade.side == "Sell",
     })
 }
 
@@ -202,45 +427,45 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
             if topic.starts_with("tickers.") {
                 if let Some(ticker_data) = data.as_object() {
                     let symbol = topic.strip_prefix("tickers.").unwrap_or("").to_string();
-                    let symbol_obj = Symbol::from_string(&symbol).ok()?;
+                    let symbol_obj = SYMBOLS.from_exchange_symbol(&symbol);
 
                     return Some(MarketDataType::Ticker(Ticker {
                         symbol: symbol_obj,
                         price: ticker_data
                             .get("lastPrice")
                             .and_then(|p| p.as_str())
-                            .and_then(|s| Price::from_str(s).ok())
-                            .unwrap_or_else(|| Price::from_str("0").unwrap()),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         price_change: ticker_data
                             .get("price24hChg")
                             .and_then(|c| c.as_str())
-                            .and_then(|s| Price::from_str(s).ok())
-                            .unwrap_or_else(|| Price::from_str("0").unwrap()),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         price_change_percent: ticker_data
                             .get("price24hPcnt")
                             .and_then(|c| c.as_str())
-                            .and_then(|s| Decimal::from_str(s).ok())
-                            .unwrap_or_else(|| Decimal::from_str("0").unwrap()),
+                            .map_or(Ok(Decimal::ZERO), conversion::try_string_to_decimal)
+                            .ok()?,
                         high_price: ticker_data
                             .get("highPrice24h")
                             .and_then(|h| h.as_str())
-                            .and_then(|s| Price::from_str(s).ok())
-                            .unwrap_or_else(|| Price::from_str("0").unwrap()),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         low_price: ticker_data
                             .get("lowPrice24h")
                             .and_then(|l| l.as_str())
-                            .and_then(|s| Price::from_str(s).ok())
-                            .unwrap_or_else(|| Price::from_str("0").unwrap()),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         volume: ticker_data
                             .get("volume24h")
                             .and_then(|v| v.as_str())
-                            .and_then(|s| Volume::from_str(s).ok())
-                            .unwrap_or_else(|| Volume::from_str("0").unwrap()),
+                            .map_or(Ok(Volume::ZERO), conversion::try_string_to_volume)
+                            .ok()?,
                         quote_volume: ticker_data
                             .get("turnover24h")
                             .and_then(|q| q.as_str())
-                            .and_then(|s| Volume::from_str(s).ok())
-                            .unwrap_or_else(|| Volume::from_str("0").unwrap()),
+                            .map_or(Ok(Volume::ZERO), conversion::try_string_to_volume)
+                            .ok()?,
                         open_time: 0,
                         close_time: 0,
                         count: 0,
@@ -252,7 +477,7 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
             if topic.starts_with("publicTrade.") {
                 if let Some(trades) = data.as_array() {
                     let symbol = topic.strip_prefix("publicTrade.").unwrap_or("").to_string();
-                    let symbol_obj = Symbol::from_string(&symbol).ok()?;
+                    let symbol_obj = SYMBOLS.from_exchange_symbol(&symbol);
 
                     for trade in trades {
                         if let Some(trade_obj) = trade.as_object() {
@@ -266,13 +491,13 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
                                 price: trade_obj
                                     .get("p")
                                     .and_then(|p| p.as_str())
-                                    .and_then(|s| Price::from_str(s).ok())
-                                    .unwrap_or_else(|| Price::from_str("0").unwrap()),
+                                    .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                                    .ok()?,
                                 quantity: trade_obj
                                     .get("v")
                                     .and_then(|q| q.as_str())
-                                    .and_then(|s| Quantity::from_str(s).ok())
-                                    .unwrap_or_else(|| Quantity::from_str("0").unwrap()),
+                                    .map_or(Ok(Quantity::ZERO), conversion::try_string_to_quantity)
+                                    .ok()?,
                                 time: trade_obj.get("T").and_then(|t| t.as_i64()).unwrap_or(0),
                                 is_buyer_maker: trade_obj.get("S").and_then(|s| s.as_str())
                                     == Some("Buy"),
@@ -289,7 +514,7 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
                     if topic_parts.len() >= 3 {
                         let symbol = topic_parts[2].to_string();
                         let interval = topic_parts[1].to_string();
-                        let symbol_obj = Symbol::from_string(&symbol).ok()?;
+                        let symbol_obj = SYMBOLS.from_exchange_symbol(&symbol);
 
                         for kline in klines {
                             if let Some(kline_obj) = kline.as_object() {
@@ -307,30 +532,33 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
                                     open_price: kline_obj
                                         .get("open")
                                         .and_then(|p| p.as_str())
-                                        .and_then(|s| Price::from_str(s).ok())
-                                        .unwrap_or_else(|| Price::from_str("0").unwrap()),
+                                        .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                                        .ok()?,
                                     high_price: kline_obj
                                         .get("high")
                                         .and_then(|p| p.as_str())
-                                        .and_then(|s| Price::from_str(s).ok())
-                                        .unwrap_or_else(|| Price::from_str("0").unwrap()),
+                                        .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                                        .ok()?,
                                     low_price: kline_obj
                                         .get("low")
                                         .and_then(|p| p.as_str())
-                                        .and_then(|s| Price::from_str(s).ok())
-                                        .unwrap_or_else(|| Price::from_str("0").unwrap()),
+                                        .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                                        .ok()?,
                                     close_price: kline_obj
                                         .get("close")
                                         .and_then(|p| p.as_str())
-                                        .and_then(|s| Price::from_str(s).ok())
-                                        .unwrap_or_else(|| Price::from_str("0").unwrap()),
+                                        .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                                        .ok()?,
                                     volume: kline_obj
                                         .get("volume")
                                         .and_then(|v| v.as_str())
-                                        .and_then(|s| Volume::from_str(s).ok())
-                                        .unwrap_or_else(|| Volume::from_str("0").unwrap()),
+                                        .map_or(Ok(Volume::ZERO), conversion::try_string_to_volume)
+                                        .ok()?,
                                     number_of_trades: 0,
-                                    final_bar: true,
+                                    final_bar: kline_obj
+                                        .get("confirm")
+                                        .and_then(|c| c.as_bool())
+                                        .unwrap_or(true),
                                 }));
                             }
                         }
@@ -342,3 +570,192 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_withdraw_record() {
+        let record: BybitWithdrawRecord = serde_json::from_value(serde_json::json!({
+            "coin": "USDT",
+            "chain": "ETH",
+            "amount": "18",
+            "txID": "",
+            "status": "SecurityCheck",
+            "toAddress": "0x99ced1edb7a0b5ffee0c5d3d2b8e6e8d17dcf6e2",
+            "tag": "",
+            "withdrawFee": "5",
+            "createTime": "1670922217000",
+            "updateTime": "1670922217000",
+            "withdrawId": "9976",
+            "withdrawType": 0
+        }))
+        .unwrap();
+
+        let converted = convert_bybit_withdraw_record(&record).unwrap();
+        assert_eq!(converted.id, "9976");
+        assert_eq!(converted.network.as_deref(), Some("ETH"));
+        assert_eq!(converted.tx_id, None);
+        assert_eq!(converted.status, WithdrawalStatus::Pending);
+        assert_eq!(converted.timestamp, 1_670_922_217_000);
+        assert_eq!(
+            convert_bybit_withdraw_status("CancelByUser"),
+            WithdrawalStatus::Cancelled
+        );
+    }
+
+    #[test]
+    fn test_book_update_id_gaps_are_reported() {
+        use crate::core::errors::ExchangeError;
+        use crate::core::kernel::WsCodec;
+        use crate::exchanges::bybit::codec::BybitCodec;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let codec = BybitCodec::default();
+        let book = |kind: &str, update_id: i64| {
+            Message::Text(
+                serde_json::json!({
+                    "topic": "orderbook.50.BTCUSDT",
+                    "type": kind,
+                    "ts": 1_672_304_484_978_u64,
+                    "data": {"s": "BTCUSDT", "b": [], "a": [], "u": update_id, "seq": 1},
+                })
+                .to_string(),
+            )
+        };
+
+        assert!(codec
+            .decode_message(book("snapshot", 10))
+            .unwrap()
+            .is_some());
+        assert!(codec.decode_message(book("delta", 11)).unwrap().is_some());
+        let err = codec.decode_message(book("delta", 13)).unwrap_err();
+        assert!(matches!(
+            err,
+            ExchangeError::ChecksumMismatch {
+                expected: 12,
+                computed: 13,
+                ..
+            }
+        ));
+
+        // Deltas are dropped until the resubscription's snapshot arrives
+        assert!(codec.decode_message(book("delta", 14)).unwrap().is_none());
+        assert!(codec
+            .decode_message(book("snapshot", 20))
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn test_decode_private_topics() {
+        use crate::core::kernel::WsCodec;
+        use crate::exchanges::bybit::codec::{BybitCodec, BybitWsEvent};
+        use tokio_tungstenite::tungstenite::Message;
+
+        let codec = BybitCodec::default();
+        let decode = |value: serde_json::Value| {
+            codec
+                .decode_message(Message::Text(value.to_string()))
+                .unwrap()
+                .unwrap()
+        };
+
+        let auth = decode(serde_json::json!({
+            "success": false,
+            "ret_msg": "Params Error",
+            "op": "auth",
+            "conn_id": "cejreaspqfh3sjdnldmg-p"
+        }));
+        assert!(matches!(auth, BybitWsEvent::Auth { success: false, .. }));
+
+        let execution = decode(serde_json::json!({
+            "topic": "execution",
+            "id": "386825804_BTCUSDT_140612148849382",
+            "creationTime": 1_746_270_400_355_i64,
+            "data": [{
+                "category": "linear",
+                "symbol": "BTCUSDT",
+                "execFee": "0.0049371",
+                "execId": "7e5f5ef7-0dcb-5e6e-bd2b-6f5b3e6a25f0",
+                "execPrice": "95342.4",
+                "execQty": "0.001",
+                "feeCurrency": "USDT",
+                "isMaker": false,
+                "orderId": "9e6e8f4a-0dc2-4f57-bc05-5b1a4d8a3d1c",
+                "orderLinkId": "",
+                "side": "Sell",
+                "execTime": "1746270400353"
+            }]
+        }));
+        let BybitWsEvent::Execution { data } = execution else {
+            panic!("expected execution");
+        };
+        let fill = convert_bybit_execution(&data[0]).unwrap();
+        assert!(matches!(fill.side, OrderSide::Sell));
+        assert_eq!(fill.price.value(), Decimal::new(953_424, 1));
+        assert_eq!(fill.fee_asset.as_deref(), Some("USDT"));
+        assert_eq!(fill.timestamp, 1_746_270_400_353);
+
+        let closed = decode(serde_json::json!({
+            "topic": "position",
+            "data": [{
+                "symbol": "BTCUSDT",
+                "side": "",
+                "size": "0",
+                "entryPrice": "0",
+                "leverage": "10",
+                "unrealisedPnl": "0",
+                "liqPrice": ""
+            }]
+        }));
+        let BybitWsEvent::Position { data } = closed else {
+            panic!("expected position");
+        };
+        let position = convert_bybit_ws_position(&data[0]).unwrap();
+        assert!(position.position_amount.value().is_zero());
+        assert!(position.liquidation_price.is_none());
+    }
+
+    #[test]
+    fn test_order_response_keeps_unusual_symbols() {
+        use crate::core::types::{Price, Quantity};
+
+        let response: BybitOrderResponse = serde_json::from_value(serde_json::json!({
+            "orderId": "1321003749386327552",
+            "orderLinkId": "spot-1",
+            "symbol": "1000PEPEUSDT",
+            "side": "Buy",
+            "orderType": "Limit",
+            "qty": "1000",
+            "price": "0.01",
+            "orderStatus": "New",
+            "createdTime": 1_672_211_918_471_i64
+        }))
+        .unwrap();
+        let order = OrderRequest {
+            symbol: SYMBOLS.from_exchange_symbol("1000PEPEUSDT"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::new(Decimal::from(1000)),
+            price: Some(Price::new(Decimal::new(1, 2))),
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: Some("spot-1".to_string()),
+        };
+
+        let converted = convert_bybit_order_response(response, order);
+        assert_eq!(converted.order_id, "1321003749386327552");
+        assert_eq!(
+            SYMBOLS.to_exchange_symbol(&converted.symbol),
+            "1000PEPEUSDT"
+        );
+
+        // A spelling the convention cannot split still renders back unchanged
+        let odd = SYMBOLS.from_exchange_symbol("BTC-0329");
+        assert_eq!(SYMBOLS.to_exchange_symbol(&odd), "BTC-0329");
+    }
+}
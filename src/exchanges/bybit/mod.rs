@@ -12,6 +12,7 @@ pub use builder::{
     build_connector,
     build_connector_with_reconnection,
     build_connector_with_websocket,
+    build_public,
     // Legacy compatibility exports
     create_bybit_connector,
     create_bybit_connector_with_reconnection,
@@ -36,11 +37,16 @@ pub fn create_bybit_stream_identifiers(
                 crate::core::types::SubscriptionType::Ticker => {
                     streams.push(format!("tickers.{}", symbol));
                 }
-                crate::core::types::SubscriptionType::Trades => {
+                crate::core::types::SubscriptionType::Trades
+                | crate::core::types::SubscriptionType::AggTrades => {
                     streams.push(format!("publicTrade.{}", symbol));
                 }
-                crate::core::types::SubscriptionType::OrderBook { depth: _ } => {
-                    streams.push(format!("orderbook.{}.200ms", symbol));
+                crate::core::types::SubscriptionType::OrderBook { depth, .. } => {
+                    streams.push(format!(
+                        "orderbook.{}.{}",
+                        crate::exchanges::bybit::codec::bybit_depth_level(*depth),
+                        symbol
+                    ));
                 }
                 crate::core::types::SubscriptionType::Klines { interval } => {
                     let interval_str =
@@ -49,6 +55,8 @@ pub fn create_bybit_stream_identifiers(
                         );
                     streams.push(format!("kline.{}.{}", interval_str, symbol));
                 }
+                // Spot has no mark price
+                crate::core::types::SubscriptionType::MarkPrice => {}
             }
         }
     }
@@ -1,10 +1,10 @@
 use crate::core::errors::ExchangeError;
-use crate::core::kernel::RestClient;
+use crate::core::kernel::{RateLimitStatus, RestClient};
 use crate::core::types::KlineInterval;
 use crate::exchanges::bybit::conversions::kline_interval_to_bybit_string;
 use crate::exchanges::bybit::types::{
-    BybitAccountInfo, BybitKlineResult, BybitMarketsResult, BybitOrderRequest, BybitOrderResponse,
-    BybitTicker,
+    BybitAccountInfo, BybitApiResponse, BybitKlineResult, BybitMarketsResult, BybitOrderList,
+    BybitOrderRequest, BybitOrderResponse, BybitTickerList,
 };
 use async_trait::async_trait;
 use reqwest::Method;
@@ -29,7 +29,10 @@ impl<R: RestClient> BybitRestClient<R> {
     }
 
     /// Get ticker for a symbol
-    pub async fn get_ticker(&self, symbol: &str) -> Result<BybitTicker, ExchangeError> {
+    pub async fn get_ticker(
+        &self,
+        symbol: &str,
+    ) -> Result<BybitApiResponse<BybitTickerList>, ExchangeError> {
         let params = [("category", "spot"), ("symbol", symbol)];
         self.client
             .get_json("/v5/market/tickers", &params, false)
@@ -108,6 +111,88 @@ impl<R: RestClient> BybitRestClient<R> {
         self.client.post_json("/v5/order/cancel", &body, true).await
     }
 
+    /// Cancel an order by its `orderLinkId` (requires authentication)
+    pub async fn cancel_order_by_link_id(
+        &self,
+        symbol: &str,
+        order_link_id: &str,
+    ) -> Result<serde_json::Value, ExchangeError> {
+        let body = serde_json::json!({
+            "category": "spot",
+            "symbol": symbol,
+            "orderLinkId": order_link_id
+        });
+
+        self.client.post_json("/v5/order/cancel", &body, true).await
+    }
+
+    /// Get open orders, or look one up by id among open and recent ones (requires authentication)
+    pub async fn get_open_orders(
+        &self,
+        symbol: Option<&str>,
+        order_id: Option<&str>,
+    ) -> Result<BybitApiResponse<BybitOrderList>, ExchangeError> {
+        self.get_order_list("/v5/order/realtime", symbol, order_id)
+            .await
+    }
+
+    /// Look up closed orders by id (requires authentication)
+    pub async fn get_closed_order(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<BybitApiResponse<BybitOrderList>, ExchangeError> {
+        self.get_order_list("/v5/order/history", Some(symbol), Some(order_id))
+            .await
+    }
+
+    async fn get_order_list(
+        &self,
+        endpoint: &str,
+        symbol: Option<&str>,
+        order_id: Option<&str>,
+    ) -> Result<BybitApiResponse<BybitOrderList>, ExchangeError> {
+        let mut params = vec![("category", "spot")];
+        if let Some(symbol) = symbol {
+            params.push(("symbol", symbol));
+        }
+        if let Some(order_id) = order_id {
+            params.push(("orderId", order_id));
+        }
+        self.client.get_json(endpoint, &params, true).await
+    }
+
+    /// Get closed orders on a symbol, newest first (requires authentication)
+    pub async fn get_order_history(
+        &self,
+        symbol: &str,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<&str>,
+        limit: u32,
+    ) -> Result<BybitApiResponse<BybitOrderList>, ExchangeError> {
+        let start_time = start_time.map(|t| t.to_string());
+        let end_time = end_time.map(|t| t.to_string());
+        let limit = limit.to_string();
+        let mut params = vec![
+            ("category", "spot"),
+            ("symbol", symbol),
+            ("limit", limit.as_str()),
+        ];
+        if let Some(start_time) = &start_time {
+            params.push(("startTime", start_time));
+        }
+        if let Some(end_time) = &end_time {
+            params.push(("endTime", end_time));
+        }
+        if let Some(cursor) = cursor {
+            params.push(("cursor", cursor));
+        }
+        self.client
+            .get_json("/v5/order/history", &params, true)
+            .await
+    }
+
     /// Get order history (requires authentication)
     pub async fn get_orders(&self, symbol: &str) -> Result<serde_json::Value, ExchangeError> {
         let params = [("category", "spot"), ("symbol", symbol)];
@@ -225,4 +310,12 @@ impl<R: RestClient> RestClient for BybitRestClient<R> {
             .signed_request_json(method, endpoint, query_params, body)
             .await
     }
+
+    fn can_sign(&self) -> bool {
+        self.client.can_sign()
+    }
+
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.client.rate_limit_status()
+    }
 }
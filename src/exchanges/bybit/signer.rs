@@ -31,6 +31,19 @@ impl BybitSigner {
             .as_millis() as u64
     }
 
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// Sign the private WebSocket login, valid until `expires` in milliseconds
+    pub fn sign_websocket_auth(&self, expires: u64) -> Result<String, ExchangeError> {
+        let mut mac = HmacSha256::new_from_slice(self.secret_key.as_bytes())
+            .map_err(|_| ExchangeError::AuthError("Invalid secret key".to_string()))?;
+
+        mac.update(format!("GET/realtime{}", expires).as_bytes());
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+
     /// Sign request for Bybit V5 API
     pub fn sign_v5_request(&self, body: &str, timestamp: u64) -> Result<String, ExchangeError> {
         let recv_window = "5000";
@@ -1,3 +1,4 @@
+use crate::core::errors::ErrorKind;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -108,6 +109,9 @@ pub struct BybitApiResponse<T> {
     #[serde(rename = "retMsg")]
     pub ret_msg: String,
     pub result: T,
+    /// Server time of the reply in milliseconds
+    #[serde(default)]
+    pub time: i64,
 }
 
 // Market data types
@@ -147,25 +151,54 @@ pub struct BybitTicker {
     pub symbol: String,
     #[serde(rename = "lastPrice")]
     pub last_price: String,
+    #[serde(rename = "prevPrice24h")]
+    pub prev_price_24h: Option<String>,
+    /// Change over 24h as a fraction, `0.01` being 1%
+    #[serde(rename = "price24hPcnt")]
+    pub price_24h_pcnt: Option<String>,
     #[serde(rename = "highPrice24h")]
     pub high_price_24h: Option<String>,
     #[serde(rename = "lowPrice24h")]
     pub low_price_24h: Option<String>,
     #[serde(rename = "volume24h")]
     pub volume_24h: Option<String>,
-    #[serde(rename = "priceChangePercent24h")]
-    pub price_change_percent_24h: Option<String>,
-    pub time: Option<i64>,
+    #[serde(rename = "turnover24h")]
+    pub turnover_24h: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitTickerList {
+    pub list: Vec<BybitTicker>,
+}
+
+/// Result of `/v5/market/orderbook`
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitOrderBook {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b")]
+    pub bids: Vec<[String; 2]>,
+    #[serde(rename = "a")]
+    pub asks: Vec<[String; 2]>,
+    #[serde(rename = "u")]
+    pub update_id: i64,
 }
 
-// Trade data type
+// Trade data type, as listed by `/v5/market/recent-trade`
 #[derive(Debug, Deserialize, Serialize)]
 pub struct BybitTrade {
-    pub id: String,
+    #[serde(rename = "execId")]
+    pub exec_id: String,
     pub price: String,
-    pub qty: String,
-    pub time: i64,
-    pub is_buyer_maker: Option<bool>,
+    pub size: String,
+    /// Taker side
+    pub side: String,
+    pub time: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitTradeList {
+    pub list: Vec<BybitTrade>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -174,7 +207,7 @@ pub struct BybitMarketsResult {
 }
 
 // Account balance types for UNIFIED account
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BybitCoinBalance {
     pub coin: String,
     #[serde(rename = "walletBalance")]
@@ -187,7 +220,7 @@ pub struct BybitCoinBalance {
     pub available_to_withdraw: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BybitAccountList {
     #[serde(rename = "accountType")]
     pub account_type: String,
@@ -199,6 +232,72 @@ pub struct BybitAccountResult {
     pub list: Vec<BybitAccountList>,
 }
 
+// Margin borrowing types for UNIFIED account
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitCollateralInfo {
+    pub currency: String,
+    #[serde(rename = "hourlyBorrowRate")]
+    pub hourly_borrow_rate: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitCollateralInfoResult {
+    pub list: Vec<BybitCollateralInfo>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitBorrowRecord {
+    pub currency: String,
+    #[serde(rename = "createdTime")]
+    pub created_time: i64,
+    #[serde(rename = "borrowCost")]
+    pub borrow_cost: String,
+    #[serde(rename = "hourlyBorrowRate")]
+    pub hourly_borrow_rate: String,
+    #[serde(rename = "InterestBearingBorrowSize")]
+    pub interest_bearing_borrow_size: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitBorrowHistoryResult {
+    pub list: Vec<BybitBorrowRecord>,
+}
+
+// Withdrawal types
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitWithdrawResult {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitTransferResult {
+    #[serde(rename = "transferId")]
+    pub transfer_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitWithdrawRecord {
+    #[serde(rename = "withdrawId")]
+    pub withdraw_id: String,
+    pub coin: String,
+    pub chain: String,
+    pub amount: String,
+    #[serde(rename = "withdrawFee")]
+    pub withdraw_fee: String,
+    #[serde(rename = "toAddress")]
+    pub to_address: String,
+    #[serde(rename = "txID")]
+    pub tx_id: String,
+    pub status: String,
+    #[serde(rename = "createTime")]
+    pub create_time: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitWithdrawRecordResult {
+    pub rows: Vec<BybitWithdrawRecord>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BybitExchangeInfo {
     pub category: String,
@@ -264,6 +363,35 @@ pub struct BybitOrderRequest {
     pub time_in_force: Option<String>,
     #[serde(rename = "stopPrice")]
     pub stop_price: Option<String>,
+    #[serde(rename = "orderLinkId", skip_serializing_if = "Option::is_none")]
+    pub order_link_id: Option<String>,
+}
+
+/// Order as returned by `/v5/order/realtime` and `/v5/order/history`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitOrder {
+    pub symbol: String,
+    pub order_id: String,
+    pub order_link_id: String,
+    pub side: String,
+    pub order_type: String,
+    pub price: String,
+    pub qty: String,
+    pub cum_exec_qty: String,
+    #[serde(default)]
+    pub avg_price: String,
+    pub order_status: String,
+    pub created_time: String,
+    pub updated_time: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BybitOrderList {
+    pub list: Vec<BybitOrder>,
+    /// Empty on the last page
+    #[serde(rename = "nextPageCursor", default)]
+    pub next_page_cursor: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -318,6 +446,50 @@ pub struct BybitWebSocketTrade {
     pub trade_id: String,
 }
 
+// Private stream types; `wallet` reuses `BybitAccountList`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWebSocketOrder {
+    pub symbol: String,
+    pub order_id: String,
+    pub order_link_id: String,
+    pub side: String,
+    pub price: String,
+    pub qty: String,
+    pub order_status: String,
+    pub cum_exec_qty: String,
+    pub updated_time: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWebSocketExecution {
+    pub symbol: String,
+    pub order_id: String,
+    pub exec_id: String,
+    pub side: String,
+    pub exec_price: String,
+    pub exec_qty: String,
+    pub exec_fee: String,
+    #[serde(default)]
+    pub fee_currency: Option<String>,
+    pub is_maker: bool,
+    pub exec_time: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWebSocketPosition {
+    pub symbol: String,
+    /// Empty once the position is closed
+    pub side: String,
+    pub size: String,
+    pub entry_price: String,
+    pub leverage: String,
+    pub unrealised_pnl: String,
+    pub liq_price: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BybitWebSocketKline {
     pub symbol: String,
@@ -366,3 +538,20 @@ pub struct BybitKlineResponse {
     pub ret_msg: String,
     pub result: BybitKlineResult,
 }
+
+/// Classify a Bybit spot `retCode` and `retMsg`
+pub fn bybit_error_kind(code: i32, _message: &str) -> Option<ErrorKind> {
+    match code {
+        10006 | 10018 => Some(ErrorKind::RateLimited),
+        10003..=10005 => Some(ErrorKind::Unauthorized),
+        170_121 => Some(ErrorKind::InvalidSymbol),
+        170_131 => Some(ErrorKind::InsufficientBalance),
+        170_213 => Some(ErrorKind::OrderNotFound),
+        170_136 | 170_140 => Some(ErrorKind::MinNotional),
+        170_134 => Some(ErrorKind::InvalidPrice),
+        170_137 => Some(ErrorKind::InvalidQuantity),
+        170_141 => Some(ErrorKind::DuplicateOrder),
+        170_218 => Some(ErrorKind::PostOnlyReject),
+        _ => None,
+    }
+}
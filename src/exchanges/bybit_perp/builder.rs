@@ -3,25 +3,21 @@ use crate::core::errors::ExchangeError;
 use crate::core::kernel::{RestClientBuilder, RestClientConfig, TungsteniteWs};
 use crate::exchanges::bybit_perp::{
     codec::BybitPerpCodec, connector::BybitPerpConnector, signer::BybitPerpSigner,
+    types::BybitPerpCategory,
 };
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
 use std::sync::Arc;
 
 /// Create a Bybit Perpetual connector with REST-only support
 pub fn build_connector(
     config: ExchangeConfig,
 ) -> Result<BybitPerpConnector<crate::core::kernel::ReqwestRest, ()>, ExchangeError> {
-    let base_url = if config.testnet {
-        "https://api-testnet.bybit.com".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.bybit.com".to_string())
-    };
-
-    let rest_config = RestClientConfig::new(base_url, "bybit_perp".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let endpoints = resolve_endpoints(ExchangeId::BybitPerp, &config)?;
+
+    let rest_config = RestClientConfig::new(endpoints.rest, "bybit_perp".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -44,18 +40,48 @@ pub fn build_connector_with_websocket(
     BybitPerpConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<BybitPerpCodec>>,
     ExchangeError,
 > {
-    let base_url = if config.testnet {
-        "https://api-testnet.bybit.com".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.bybit.com".to_string())
-    };
-
-    let rest_config = RestClientConfig::new(base_url, "bybit_perp".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    build_category_connector_with_websocket(config, BybitPerpCategory::Linear)
+}
+
+/// Create a Bybit perpetual connector for public market data, ignoring any credentials in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<
+    BybitPerpConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<BybitPerpCodec>>,
+    ExchangeError,
+> {
+    build_connector_with_websocket(config.without_credentials())
+}
+
+/// Create a Bybit inverse (coin-margined) connector with REST-only support
+pub fn build_inverse_connector(
+    config: ExchangeConfig,
+) -> Result<BybitPerpConnector<crate::core::kernel::ReqwestRest, ()>, ExchangeError> {
+    Ok(build_connector(config)?.with_category(BybitPerpCategory::Inverse))
+}
+
+/// Create a Bybit inverse (coin-margined) connector with WebSocket support
+pub fn build_inverse_connector_with_websocket(
+    config: ExchangeConfig,
+) -> Result<
+    BybitPerpConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<BybitPerpCodec>>,
+    ExchangeError,
+> {
+    build_category_connector_with_websocket(config, BybitPerpCategory::Inverse)
+}
+
+fn build_category_connector_with_websocket(
+    config: ExchangeConfig,
+    category: BybitPerpCategory,
+) -> Result<
+    BybitPerpConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<BybitPerpCodec>>,
+    ExchangeError,
+> {
+    let endpoints = resolve_endpoints(ExchangeId::BybitPerp, &config)?;
+
+    let rest_config = RestClientConfig::new(endpoints.rest, "bybit_perp".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -69,14 +95,11 @@ pub fn build_connector_with_websocket(
 
     let rest = rest_builder.build()?;
 
-    let ws_url = if config.testnet {
-        "wss://stream-testnet.bybit.com/v5/public/linear".to_string()
-    } else {
-        "wss://stream.bybit.com/v5/public/linear".to_string()
-    };
+    let ws_url = category.public_ws_url(config.testnet);
 
-    let ws = TungsteniteWs::new(ws_url, "bybit_perp".to_string(), BybitPerpCodec::new());
-    Ok(BybitPerpConnector::new(rest, ws, config))
+    let ws = TungsteniteWs::new(ws_url, "bybit_perp".to_string(), BybitPerpCodec::new())
+        .with_config(config.ws_config());
+    Ok(BybitPerpConnector::new(rest, ws, config).with_category(category))
 }
 
 /// Legacy function for backward compatibility
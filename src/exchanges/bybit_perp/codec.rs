@@ -1,7 +1,10 @@
 use crate::core::errors::ExchangeError;
-use crate::core::kernel::WsCodec;
-use crate::core::types::MarketDataType;
-use crate::exchanges::bybit_perp::conversions::parse_websocket_message;
+use crate::core::kernel::{ControlFrame, WsCodec};
+use crate::core::types::{MarkPrice, MarketDataType};
+use crate::exchanges::bybit::codec::BookUpdateIds;
+use crate::exchanges::bybit_perp::conversions::{
+    parse_mark_price_message, parse_websocket_message,
+};
 use serde_json::{json, Value};
 use tokio_tungstenite::tungstenite::Message;
 
@@ -9,6 +12,13 @@ use tokio_tungstenite::tungstenite::Message;
 #[derive(Debug, Clone)]
 pub enum BybitPerpWsEvent {
     MarketData(MarketDataType),
+    /// A `tickers` push, which serves both ticker and mark price subscriptions
+    ///
+    /// `ticker` is only set on snapshots; deltas omit the 24h fields.
+    Tickers {
+        ticker: Option<MarketDataType>,
+        mark_price: Option<MarkPrice>,
+    },
     Ping,
     Pong,
     Error(String),
@@ -16,7 +26,12 @@ pub enum BybitPerpWsEvent {
 }
 
 /// Bybit Perpetual WebSocket codec
-pub struct BybitPerpCodec;
+///
+/// Order book deltas are checked for gaps in their update ids like the spot codec's.
+#[derive(Debug)]
+pub struct BybitPerpCodec {
+    update_ids: BookUpdateIds,
+}
 
 impl Default for BybitPerpCodec {
     fn default() -> Self {
@@ -26,7 +41,9 @@ impl Default for BybitPerpCodec {
 
 impl BybitPerpCodec {
     pub fn new() -> Self {
-        Self
+        Self {
+            update_ids: BookUpdateIds::default(),
+        }
     }
 }
 
@@ -61,6 +78,47 @@ impl WsCodec for BybitPerpCodec {
         Ok(Message::Text(message_str))
     }
 
+    fn encode_ping(&self) -> Option<Message> {
+        Some(Message::Text(r#"{"op":"ping"}"#.to_string()))
+    }
+
+    fn acknowledges_subscriptions(&self) -> bool {
+        true
+    }
+
+    /// Op replies carry `conn_id`; a ping is answered with `op: "pong"`, or `op: "ping"`
+    /// and `ret_msg: "pong"`
+    fn control_frame(&self, msg: &Message) -> Option<ControlFrame> {
+        let Message::Text(text) = msg else {
+            return None;
+        };
+        if !text.contains("\"op\"") {
+            return None;
+        }
+        let value: Value = serde_json::from_str(text).ok()?;
+        let op = value.get("op").and_then(Value::as_str);
+        let ret_msg = value.get("ret_msg").and_then(Value::as_str);
+        Some(ControlFrame {
+            connection_id: value
+                .get("conn_id")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            pong: op == Some("pong") || (op == Some("ping") && ret_msg == Some("pong")),
+            subscribed: op == Some("subscribe")
+                && value.get("success").and_then(Value::as_bool) == Some(true),
+        })
+    }
+
+    fn exchange_time(&self, message: &Self::Message) -> Option<i64> {
+        match message {
+            BybitPerpWsEvent::MarketData(data) => data.exchange_time(),
+            BybitPerpWsEvent::Tickers { mark_price, .. } => {
+                mark_price.as_ref().map(|mark| mark.timestamp)
+            }
+            _ => None,
+        }
+    }
+
     fn decode_message(&self, msg: Message) -> Result<Option<Self::Message>, ExchangeError> {
         match msg {
             Message::Text(text) => {
@@ -85,7 +143,19 @@ impl WsCodec for BybitPerpCodec {
                         }
                         _ => Ok(Some(BybitPerpWsEvent::Other(value))),
                     }
-                } else if value.get("topic").is_some() {
+                } else if value
+                    .get("topic")
+                    .and_then(Value::as_str)
+                    .is_some_and(|topic| topic.starts_with("tickers."))
+                {
+                    Ok(Some(BybitPerpWsEvent::Tickers {
+                        mark_price: parse_mark_price_message(&value),
+                        ticker: parse_websocket_message(value),
+                    }))
+                } else if let Some(topic) = value.get("topic").and_then(Value::as_str) {
+                    if topic.starts_with("orderbook.") && !self.update_ids.verify(topic, &value)? {
+                        return Ok(None);
+                    }
                     // This is market data
                     parse_websocket_message(value.clone()).map_or_else(
                         || Ok(Some(BybitPerpWsEvent::Other(value))),
@@ -125,6 +195,14 @@ impl WsCodec for BybitPerpCodec {
     }
 }
 
+/// Order book depth for a subscription, from the 1/50/200 levels Bybit publishes
+pub fn bybit_depth_level(depth: Option<u32>) -> u32 {
+    const LEVELS: [u32; 3] = [1, 50, 200];
+    depth.map_or(200, |depth| {
+        crate::core::types::SubscriptionType::depth_level(depth, &LEVELS)
+    })
+}
+
 /// Helper functions for creating stream identifiers
 pub fn create_bybit_perp_stream_identifiers(
     symbols: &[String],
@@ -135,36 +213,26 @@ pub fn create_bybit_perp_stream_identifiers(
     for symbol in symbols {
         for sub_type in subscription_types {
             match sub_type {
-                crate::core::types::SubscriptionType::Ticker => {
-                    streams.push(format!("tickers.{}", symbol));
-                }
-                crate::core::types::SubscriptionType::OrderBook { depth } => {
-                    if let Some(d) = depth {
-                        streams.push(format!("orderbook.{}.{}", d, symbol));
-                    } else {
-                        streams.push(format!("orderbook.1.{}", symbol));
+                crate::core::types::SubscriptionType::Ticker
+                | crate::core::types::SubscriptionType::MarkPrice => {
+                    let topic = format!("tickers.{}", symbol);
+                    if !streams.contains(&topic) {
+                        streams.push(topic);
                     }
                 }
-                crate::core::types::SubscriptionType::Trades => {
+                crate::core::types::SubscriptionType::OrderBook { depth, .. } => {
+                    streams.push(format!(
+                        "orderbook.{}.{}",
+                        crate::exchanges::bybit_perp::codec::bybit_depth_level(*depth),
+                        symbol
+                    ));
+                }
+                crate::core::types::SubscriptionType::Trades
+                | crate::core::types::SubscriptionType::AggTrades => {
                     streams.push(format!("publicTrade.{}", symbol));
                 }
                 crate::core::types::SubscriptionType::Klines { interval } => {
-                    let interval_str = match interval {
-                        crate::core::types::KlineInterval::Minutes3 => "3",
-                        crate::core::types::KlineInterval::Minutes5 => "5",
-                        crate::core::types::KlineInterval::Minutes15 => "15",
-                        crate::core::types::KlineInterval::Minutes30 => "30",
-                        crate::core::types::KlineInterval::Hours1 => "60",
-                        crate::core::types::KlineInterval::Hours2 => "120",
-                        crate::core::types::KlineInterval::Hours4 => "240",
-                        crate::core::types::KlineInterval::Hours6 => "360",
-                        crate::core::types::KlineInterval::Hours12 => "720",
-                        crate::core::types::KlineInterval::Days1 => "D",
-                        crate::core::types::KlineInterval::Weeks1 => "W",
-                        crate::core::types::KlineInterval::Months1 => "M",
-                        _ => "1", // Default to 1 minute (including Minutes1)
-                    };
-                    streams.push(format!("kline.{}.{}", interval_str, symbol));
+                    streams.push(format!("kline.{}.{}", interval.to_bybit_format(), symbol));
                 }
             }
         }
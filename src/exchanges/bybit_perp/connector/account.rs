@@ -1,8 +1,13 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
-use crate::core::traits::AccountInfo;
-use crate::core::types::{conversion, Balance, Position, PositionSide};
+use crate::core::traits::{AccountInfo, AccountSummarySource, TradeHistorySource};
+use crate::core::types::{conversion, AccountFill, AccountSummary, Balance, Position, Symbol};
+use crate::exchanges::bybit_perp::conversions::SYMBOLS;
+use crate::exchanges::bybit_perp::conversions::{
+    convert_bybit_perp_account_summary, convert_bybit_perp_execution, convert_bybit_perp_position,
+};
 use crate::exchanges::bybit_perp::rest::BybitPerpRestClient;
+use crate::exchanges::bybit_perp::types::BybitPerpCategory;
 use async_trait::async_trait;
 
 /// Account implementation for Bybit Perpetual
@@ -19,10 +24,21 @@ impl<R: RestClient> Account<R> {
             rest: BybitPerpRestClient::new(rest.clone()),
         }
     }
+
+    /// Serve a different contract category (linear by default)
+    #[must_use]
+    pub fn with_category(mut self, category: BybitPerpCategory) -> Self {
+        self.rest = self.rest.with_category(category);
+        self
+    }
 }
 
 #[async_trait]
 impl<R: RestClient> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         let api_response = self.rest.get_account_balance().await?;
 
@@ -43,18 +59,20 @@ impl<R: RestClient> AccountInfo for Account<R> {
                 let equity: f64 = balance.equity.parse().unwrap_or(0.0);
                 wallet_balance > 0.0 || equity > 0.0
             })
-            .map(|balance| Balance {
-                asset: balance.coin,
-                free: conversion::string_to_quantity(&balance.equity), // Use equity as available balance (after margin)
-                locked: conversion::string_to_quantity(&balance.locked),
+            .map(|balance| {
+                Ok(Balance {
+                    asset: balance.coin,
+                    free: conversion::try_string_to_quantity(&balance.equity)?, // Use equity as available balance (after margin)
+                    locked: conversion::try_string_to_quantity(&balance.locked)?,
+                })
             })
-            .collect();
+            .collect::<Result<_, ExchangeError>>()?;
 
         Ok(balances)
     }
 
     async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
-        let api_response = self.rest.get_positions(Some("USDT")).await?;
+        let api_response = self.rest.get_positions(None).await?;
 
         if api_response.ret_code != 0 {
             return Err(ExchangeError::NetworkError(format!(
@@ -66,31 +84,70 @@ impl<R: RestClient> AccountInfo for Account<R> {
         let positions = api_response
             .result
             .list
-            .into_iter()
+            .iter()
             .filter(|position| {
                 let size: f64 = position.size.parse().unwrap_or(0.0);
                 size != 0.0
             })
-            .map(|position| {
-                let position_side = match position.side.as_str() {
-                    "Sell" => PositionSide::Short,
-                    _ => PositionSide::Long,
-                };
-
-                Position {
-                    symbol: conversion::string_to_symbol(&position.symbol),
-                    position_side,
-                    entry_price: conversion::string_to_price(&position.entry_price),
-                    position_amount: conversion::string_to_quantity(&position.size),
-                    unrealized_pnl: conversion::string_to_decimal(&position.unrealised_pnl),
-                    liquidation_price: Some(conversion::string_to_price(
-                        &position.liquidation_price,
-                    )),
-                    leverage: conversion::string_to_decimal(&position.leverage),
-                }
-            })
-            .collect();
+            .map(convert_bybit_perp_position)
+            .collect::<Result<_, ExchangeError>>()?;
 
         Ok(positions)
     }
 }
+
+#[async_trait]
+impl<R: RestClient> AccountSummarySource for Account<R> {
+    async fn get_account_summary(&self) -> Result<AccountSummary, ExchangeError> {
+        let api_response = self.rest.get_account_balance().await?;
+
+        if api_response.ret_code != 0 {
+            return Err(ExchangeError::NetworkError(format!(
+                "Bybit API error ({}): {}",
+                api_response.ret_code, api_response.ret_msg
+            )));
+        }
+
+        let account = api_response.result.list.first().ok_or_else(|| {
+            ExchangeError::Other("No unified account in wallet balance".to_string())
+        })?;
+        convert_bybit_perp_account_summary(account, chrono::Utc::now().timestamp_millis())
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> TradeHistorySource for Account<R> {
+    async fn get_my_trades(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AccountFill>, ExchangeError> {
+        let api_response = self
+            .rest
+            .get_executions(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                start_time,
+                end_time,
+                limit,
+            )
+            .await?;
+
+        if api_response.ret_code != 0 {
+            return Err(ExchangeError::NetworkError(format!(
+                "Bybit API error ({}): {}",
+                api_response.ret_code, api_response.ret_msg
+            )));
+        }
+
+        // Executions come newest first
+        api_response
+            .result
+            .list
+            .iter()
+            .rev()
+            .map(convert_bybit_perp_execution)
+            .collect()
+    }
+}
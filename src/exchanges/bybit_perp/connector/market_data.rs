@@ -4,25 +4,83 @@
 #![allow(clippy::use_self)]
 
 use crate::core::errors::ExchangeError;
-use crate::core::kernel::{ws::WsSession, RestClient};
-use crate::core::traits::{FundingRateSource, MarketDataSource};
+use crate::core::kernel::{RestClient, TungsteniteWs, WsConfig};
+use crate::core::streaming::{SharedHub, StreamRouter};
+use crate::core::traits::{
+    FundingRateSource, HistoricalTradeSource, MarketSnapshotSource, RestMarketData,
+    StreamingMarketData,
+};
 use crate::core::types::{
-    conversion, FundingRate, Kline, KlineInterval, Market, MarketDataType, SubscriptionType,
-    WebSocketConfig,
+    conversion, FundingRate, Kline, KlineInterval, Market, MarketDataType, OrderBook,
+    SubscriptionType, Symbol, Ticker, Trade, WebSocketConfig,
+};
+use crate::exchanges::bybit_perp::codec::{bybit_depth_level, BybitPerpCodec, BybitPerpWsEvent};
+use crate::exchanges::bybit_perp::conversions::SYMBOLS;
+use crate::exchanges::bybit_perp::conversions::{
+    convert_bybit_perp_market, convert_bybit_perp_order_book, convert_bybit_perp_ticker,
+    convert_bybit_perp_trade,
 };
-use crate::exchanges::bybit_perp::conversions::convert_bybit_perp_market;
 use crate::exchanges::bybit_perp::rest::BybitPerpRestClient;
-use crate::exchanges::bybit_perp::types::{self as bybit_perp_types};
+use crate::exchanges::bybit_perp::types::{self as bybit_perp_types, BybitPerpCategory};
 use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tracing::{instrument, warn};
 
+/// Routes Bybit V5 public topic updates
+struct BybitPerpRouter;
+
+impl StreamRouter for BybitPerpRouter {
+    type Codec = BybitPerpCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(symbol)?;
+        Ok(Some(match subscription {
+            // One `tickers` topic serves both
+            SubscriptionType::Ticker | SubscriptionType::MarkPrice => {
+                format!("tickers.{}", symbol)
+            }
+            SubscriptionType::OrderBook { depth, .. } => {
+                format!("orderbook.{}.{}", bybit_depth_level(*depth), symbol)
+            }
+            SubscriptionType::Trades | SubscriptionType::AggTrades => {
+                format!("publicTrade.{}", symbol)
+            }
+            SubscriptionType::Klines { interval } => {
+                format!("kline.{}.{}", interval.to_bybit_format(), symbol)
+            }
+        }))
+    }
+
+    fn convert(&self, event: BybitPerpWsEvent) -> Vec<MarketDataType> {
+        convert_bybit_event_to_market_data(event)
+    }
+}
+
 /// Market data implementation for Bybit Perpetual
 pub struct MarketData<R: RestClient, W = ()> {
     rest: BybitPerpRestClient<R>,
-    #[allow(dead_code)]
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
     ws: Option<W>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
     testnet: bool,
+    ws_url: String,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
 }
 
 impl<R: RestClient + Clone, W> MarketData<R, W> {
@@ -30,7 +88,10 @@ impl<R: RestClient + Clone, W> MarketData<R, W> {
         Self {
             rest: BybitPerpRestClient::new(rest.clone()),
             ws,
+            hub: SharedHub::new(),
             testnet: false, // Default to mainnet
+            ws_url: BybitPerpCategory::Linear.public_ws_url(false),
+            ws_config: WsConfig::default(),
         }
     }
 
@@ -38,9 +99,28 @@ impl<R: RestClient + Clone, W> MarketData<R, W> {
         Self {
             rest: BybitPerpRestClient::new(rest.clone()),
             ws,
+            hub: SharedHub::new(),
             testnet,
+            ws_url: BybitPerpCategory::Linear.public_ws_url(testnet),
+            ws_config: WsConfig::default(),
         }
     }
+
+    /// Serve a different contract category (linear by default)
+    #[must_use]
+    pub fn with_category(mut self, category: BybitPerpCategory) -> Self {
+        self.ws_url = category.public_ws_url(self.testnet);
+        self.rest = self.rest.with_category(category);
+        self
+    }
+
+    /// Stream from `ws_url` rather than the category's public feed; set it after
+    /// `with_category`, which resets it
+    #[must_use]
+    pub fn with_ws_url(mut self, ws_url: String) -> Self {
+        self.ws_url = ws_url;
+        self
+    }
 }
 
 // Safety: MarketData is Sync if its fields are Sync
@@ -54,7 +134,7 @@ fn handle_api_response_error(ret_code: i32, ret_msg: String) -> bybit_perp_types
 }
 
 #[async_trait]
-impl<R: RestClient + Clone, W: Send + Sync> MarketDataSource for MarketData<R, W> {
+impl<R: RestClient + Clone, W: Send + Sync> RestMarketData for MarketData<R, W> {
     #[instrument(skip(self), fields(exchange = "bybit_perp"))]
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         let api_response = self.rest.get_markets().await?;
@@ -70,117 +150,25 @@ impl<R: RestClient + Clone, W: Send + Sync> MarketDataSource for MarketData<R, W
             .list
             .into_iter()
             .map(convert_bybit_perp_market)
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         Ok(markets)
     }
 
-    #[instrument(skip(self, _config), fields(exchange = "bybit_perp", symbols_count = symbols.len()))]
-    async fn subscribe_market_data(
-        &self,
-        symbols: Vec<String>,
-        subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        // Build streams for Bybit V5 WebSocket format
-        let mut streams = Vec::new();
-
-        for symbol in &symbols {
-            for sub_type in &subscription_types {
-                match sub_type {
-                    SubscriptionType::Ticker => {
-                        streams.push(format!("tickers.{}", symbol));
-                    }
-                    SubscriptionType::OrderBook { depth } => {
-                        if let Some(d) = depth {
-                            streams.push(format!("orderbook.{}.{}", d, symbol));
-                        } else {
-                            streams.push(format!("orderbook.1.{}", symbol));
-                        }
-                    }
-                    SubscriptionType::Trades => {
-                        streams.push(format!("publicTrade.{}", symbol));
-                    }
-                    SubscriptionType::Klines { interval } => {
-                        streams.push(format!("kline.{}.{}", interval.to_bybit_format(), symbol));
-                    }
-                }
-            }
-        }
-
-        let ws_url = self.get_websocket_url();
-
-        // Use kernel WebSocket implementation with BybitPerpCodec
-        let codec = crate::exchanges::bybit_perp::codec::BybitPerpCodec::new();
-        let ws_session =
-            crate::core::kernel::ws::TungsteniteWs::new(ws_url, "bybit_perp".to_string(), codec);
-
-        // Add reconnection wrapper for production reliability
-        let mut reconnect_ws = crate::core::kernel::ws::ReconnectWs::new(ws_session)
-            .with_auto_resubscribe(true)
-            .with_max_reconnect_attempts(u32::MAX);
-
-        // Connect and subscribe
-        reconnect_ws.connect().await.map_err(|e| {
-            ExchangeError::Other(format!(
-                "Failed to connect to WebSocket for symbols: {:?}, error: {}",
-                symbols, e
-            ))
-        })?;
-
-        if !streams.is_empty() {
-            let stream_refs: Vec<&str> = streams.iter().map(|s| s.as_str()).collect();
-            reconnect_ws.subscribe(&stream_refs).await.map_err(|e| {
-                ExchangeError::Other(format!(
-                    "Failed to subscribe to streams: {:?}, error: {}",
-                    streams, e
-                ))
-            })?;
-        }
-
-        // Create channel for messages
-        let (tx, rx) = mpsc::channel(1000);
-
-        // Spawn task to handle messages
-        tokio::spawn(async move {
-            while let Some(result) = reconnect_ws.next_message().await {
-                match result {
-                    Ok(bybit_event) => {
-                        // Convert BybitPerpWsEvent to MarketDataType
-                        if let Some(market_data) = convert_bybit_event_to_market_data(bybit_event) {
-                            if tx.send(market_data).await.is_err() {
-                                break; // Receiver dropped
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("WebSocket error: {:?}", e);
-                        // Continue processing to handle reconnection
-                    }
-                }
-            }
-        });
-
-        Ok(rx)
-    }
-
-    fn get_websocket_url(&self) -> String {
-        if self.testnet {
-            "wss://stream-testnet.bybit.com/v5/public/linear".to_string()
-        } else {
-            "wss://stream.bybit.com/v5/public/linear".to_string()
-        }
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
     }
 
     #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %symbol, interval = %interval))]
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let interval_str = interval.to_bybit_format();
         let klines_response = self
             .rest
@@ -209,65 +197,166 @@ impl<R: RestClient + Clone, W: Send + Sync> MarketDataSource for MarketData<R, W
                         0
                     });
 
-                // Calculate close time based on interval
-                let interval_ms = match interval {
-                    // Seconds1 removed - not commonly supported
-                    KlineInterval::Minutes1 => 60_000,
-                    KlineInterval::Minutes3 => 180_000,
-                    KlineInterval::Minutes5 => 300_000,
-                    KlineInterval::Minutes15 => 900_000,
-                    KlineInterval::Minutes30 => 1_800_000,
-                    KlineInterval::Hours1 => 3_600_000,
-                    KlineInterval::Hours2 => 7_200_000,
-                    KlineInterval::Hours4 => 14_400_000,
-                    KlineInterval::Hours6 => 21_600_000,
-                    KlineInterval::Hours8 => 28_800_000,
-                    KlineInterval::Hours12 => 43_200_000,
-                    KlineInterval::Days1 => 86_400_000,
-                    KlineInterval::Days3 => 259_200_000,
-                    KlineInterval::Weeks1 => 604_800_000,
-                    KlineInterval::Months1 => 2_592_000_000, // Approximate
-                };
-
-                let close_time = start_time + interval_ms;
-
-                Kline {
-                    symbol: conversion::string_to_symbol(&symbol),
+                Ok(Kline {
+                    symbol: SYMBOLS.from_exchange_symbol(&symbol),
                     open_time: start_time,
-                    close_time,
+                    close_time: interval.close_time(start_time),
                     interval: interval_str.clone(),
-                    open_price: conversion::string_to_price(
+                    open_price: conversion::try_string_to_price(
                         kline_vec.get(1).unwrap_or(&"0".to_string()),
-                    ),
-                    high_price: conversion::string_to_price(
+                    )?,
+                    high_price: conversion::try_string_to_price(
                         kline_vec.get(2).unwrap_or(&"0".to_string()),
-                    ),
-                    low_price: conversion::string_to_price(
+                    )?,
+                    low_price: conversion::try_string_to_price(
                         kline_vec.get(3).unwrap_or(&"0".to_string()),
-                    ),
-                    close_price: conversion::string_to_price(
+                    )?,
+                    close_price: conversion::try_string_to_price(
                         kline_vec.get(4).unwrap_or(&"0".to_string()),
-                    ),
-                    volume: conversion::string_to_volume(
+                    )?,
+                    volume: conversion::try_string_to_volume(
                         kline_vec.get(5).unwrap_or(&"0".to_string()),
-                    ),
+                    )?,
                     number_of_trades: 0, // Bybit doesn't provide this in REST API
                     final_bar: true,
-                }
+                })
             })
-            .collect();
+            .collect::<Result<_, ExchangeError>>()?;
 
         Ok(klines)
     }
 }
 
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> StreamingMarketData for MarketData<R, W> {
+    #[instrument(skip(self, config), fields(exchange = "bybit_perp", symbols_count = symbols.len()))]
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(
+                        self.ws_url.clone(),
+                        "bybit_perp".to_string(),
+                        BybitPerpCodec::new(),
+                    )
+                    .with_config(self.ws_config.clone())
+                },
+                BybitPerpRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.ws_url.clone()
+    }
+}
+
+/// Deepest derivatives book served by `/v5/market/orderbook`
+const MAX_BOOK_DEPTH: u32 = 500;
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> MarketSnapshotSource for MarketData<R, W> {
+    #[instrument(skip(self), fields(exchange = "bybit_perp"))]
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let response = self.rest.get_tickers(Some(&symbol)).await?;
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_perp_types::bybit_perp_error_kind),
+            );
+        }
+        let ticker = response.result.list.first().ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Unknown symbol: {}", symbol))
+        })?;
+        convert_bybit_perp_ticker(ticker, response.time)
+    }
+
+    #[instrument(skip(self), fields(exchange = "bybit_perp"))]
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let limit = depth.map(|depth| depth.min(MAX_BOOK_DEPTH));
+        let response = self
+            .rest
+            .get_order_book(&SYMBOLS.try_to_exchange_symbol(&symbol)?, limit)
+            .await?;
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_perp_types::bybit_perp_error_kind),
+            );
+        }
+        Ok(convert_bybit_perp_order_book(&response.result)?.truncated(depth))
+    }
+
+    #[instrument(skip(self), fields(exchange = "bybit_perp"))]
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let response = self.rest.get_recent_trades(&symbol, limit).await?;
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_perp_types::bybit_perp_error_kind),
+            );
+        }
+        // Listed newest first
+        response
+            .result
+            .list
+            .iter()
+            .rev()
+            .map(|trade| convert_bybit_perp_trade(trade, &symbol))
+            .collect()
+    }
+}
+
+/// Most trades `/v5/market/recent-trade` returns for derivatives
+const MAX_RECENT_TRADES: u32 = 1000;
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> HistoricalTradeSource for MarketData<R, W> {
+    /// Bybit keeps no public trade history over REST, so only the latest 1000 trades
+    /// can be returned
+    #[instrument(skip(self), fields(exchange = "bybit_perp"))]
+    async fn get_historical_trades(
+        &self,
+        symbol: Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let trades = self
+            .get_recent_trades(symbol, Some(MAX_RECENT_TRADES))
+            .await?;
+        Ok(trades
+            .into_iter()
+            .filter(|trade| (start_time..=end_time).contains(&trade.time))
+            .collect())
+    }
+}
+
 #[async_trait]
 impl<R: RestClient + Clone, W: Send + Sync> FundingRateSource for MarketData<R, W> {
     #[instrument(skip(self), fields(exchange = "bybit_perp"))]
     async fn get_funding_rates(
         &self,
-        symbols: Option<Vec<String>>,
+        symbols: Option<Vec<Symbol>>,
     ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let symbols: Option<Vec<String>> =
+            symbols.map(|symbols| symbols.iter().map(ToString::to_string).collect());
         match symbols {
             Some(symbol_list) => {
                 let mut funding_rates = Vec::new();
@@ -293,11 +382,12 @@ impl<R: RestClient + Clone, W: Send + Sync> FundingRateSource for MarketData<R,
     #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %symbol))]
     async fn get_funding_rate_history(
         &self,
-        symbol: String,
+        symbol: Symbol,
         start_time: Option<i64>,
         end_time: Option<i64>,
         limit: Option<u32>,
     ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let _ = (start_time, end_time, limit); // Suppress unused warnings for now
                                                // For now, return single funding rate - extend later for history
         let rate = self.get_single_funding_rate(&symbol).await?;
@@ -306,9 +396,36 @@ impl<R: RestClient + Clone, W: Send + Sync> FundingRateSource for MarketData<R,
 }
 
 impl<R: RestClient + Clone, W> MarketData<R, W> {
+    /// Funding interval per symbol from instrument info
+    async fn funding_intervals(&self, symbol: Option<&str>) -> HashMap<String, Duration> {
+        match self.rest.get_instruments(symbol).await {
+            Ok(response) if response.ret_code == 0 => response
+                .result
+                .list
+                .into_iter()
+                .filter_map(|market| {
+                    let minutes = market.funding_interval?;
+                    Some((market.symbol, Duration::from_secs(minutes * 60)))
+                })
+                .collect(),
+            Ok(response) => {
+                warn!(code = response.ret_code, message = %response.ret_msg, "Failed to fetch funding intervals");
+                HashMap::new()
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to fetch funding intervals");
+                HashMap::new()
+            }
+        }
+    }
+
     async fn get_single_funding_rate(&self, symbol: &str) -> Result<FundingRate, ExchangeError> {
         // Get ticker data which includes current funding rate and mark/index prices
-        let ticker_response = self.rest.get_tickers(Some(symbol)).await?;
+        let (ticker_response, intervals) = tokio::join!(
+            self.rest.get_tickers(Some(symbol)),
+            self.funding_intervals(Some(symbol))
+        );
+        let ticker_response = ticker_response?;
 
         if ticker_response.ret_code != 0 {
             return Err(ExchangeError::Other(format!(
@@ -325,21 +442,24 @@ impl<R: RestClient + Clone, W> MarketData<R, W> {
         let next_funding_time = ticker_info.next_funding_time.parse::<i64>().ok();
 
         Ok(FundingRate {
-            symbol: conversion::string_to_symbol(&ticker_info.symbol),
-            funding_rate: Some(conversion::string_to_decimal(&ticker_info.funding_rate)),
+            symbol: SYMBOLS.from_exchange_symbol(&ticker_info.symbol),
+            funding_rate: conversion::try_string_to_decimal(&ticker_info.funding_rate).ok(),
             previous_funding_rate: None,
             next_funding_rate: None,
             funding_time: None, // Current funding rate doesn't have historical timestamp
             next_funding_time,
-            mark_price: Some(conversion::string_to_price(&ticker_info.mark_price)),
-            index_price: Some(conversion::string_to_price(&ticker_info.index_price)),
+            mark_price: conversion::try_string_to_price(&ticker_info.mark_price).ok(),
+            index_price: conversion::try_string_to_price(&ticker_info.index_price).ok(),
             timestamp: chrono::Utc::now().timestamp_millis(),
+            funding_interval: intervals.get(&ticker_info.symbol).copied(),
         })
     }
 
     async fn get_all_funding_rates_internal(&self) -> Result<Vec<FundingRate>, ExchangeError> {
         // Get all tickers which include funding rates and mark/index prices
-        let ticker_response = self.rest.get_tickers(None).await?;
+        let (ticker_response, intervals) =
+            tokio::join!(self.rest.get_tickers(None), self.funding_intervals(None));
+        let ticker_response = ticker_response?;
 
         if ticker_response.ret_code != 0 {
             return Err(ExchangeError::Other(format!(
@@ -357,15 +477,16 @@ impl<R: RestClient + Clone, W> MarketData<R, W> {
                 let next_funding_time = ticker_info.next_funding_time.parse::<i64>().ok();
 
                 FundingRate {
-                    symbol: conversion::string_to_symbol(&ticker_info.symbol),
-                    funding_rate: Some(conversion::string_to_decimal(&ticker_info.funding_rate)),
+                    symbol: SYMBOLS.from_exchange_symbol(&ticker_info.symbol),
+                    funding_rate: conversion::try_string_to_decimal(&ticker_info.funding_rate).ok(),
                     previous_funding_rate: None,
                     next_funding_rate: None,
                     funding_time: None, // Current funding rate doesn't have historical timestamp
                     next_funding_time,
-                    mark_price: Some(conversion::string_to_price(&ticker_info.mark_price)),
-                    index_price: Some(conversion::string_to_price(&ticker_info.index_price)),
+                    mark_price: conversion::try_string_to_price(&ticker_info.mark_price).ok(),
+                    index_price: conversion::try_string_to_price(&ticker_info.index_price).ok(),
                     timestamp: chrono::Utc::now().timestamp_millis(),
+                    funding_interval: intervals.get(&ticker_info.symbol).copied(),
                 }
             })
             .collect();
@@ -405,13 +526,122 @@ impl BybitFormat for KlineInterval {
 }
 
 /// Convert `BybitPerpWsEvent` to `MarketDataType`
-fn convert_bybit_event_to_market_data(
-    event: crate::exchanges::bybit_perp::codec::BybitPerpWsEvent,
-) -> Option<MarketDataType> {
+///
+/// A `tickers` push yields both the ticker and the mark price; the hub hands each only
+/// to subscribers of its kind.
+fn convert_bybit_event_to_market_data(event: BybitPerpWsEvent) -> Vec<MarketDataType> {
     match event {
-        crate::exchanges::bybit_perp::codec::BybitPerpWsEvent::MarketData(market_data) => {
-            Some(market_data)
+        BybitPerpWsEvent::MarketData(market_data) => vec![market_data],
+        BybitPerpWsEvent::Tickers { ticker, mark_price } => ticker
+            .into_iter()
+            .chain(mark_price.map(MarketDataType::MarkPrice))
+            .collect(),
+        _ => Vec::new(), // Ignore ping, pong, error, and other events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::kernel::rest::ReqwestRest;
+    use futures_util::{SinkExt, StreamExt};
+    use serde_json::{json, Value};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    fn book(kind: &str, update_id: i64, bid: &str) -> Message {
+        Message::Text(
+            json!({
+                "topic": "orderbook.50.BTCUSDT",
+                "type": kind,
+                "ts": 1_672_304_484_978_i64,
+                "data": {
+                    "s": "BTCUSDT",
+                    "b": [[bid, "1"]],
+                    "a": [["101", "1"]],
+                    "u": update_id,
+                    "seq": update_id,
+                },
+            })
+            .to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_update_id_gap_resubscribes_for_a_fresh_snapshot() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (sent_tx, mut sent_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let mut subscriptions = 0;
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: Value = serde_json::from_str(&text).unwrap();
+                let op = request["op"].as_str().unwrap_or_default().to_string();
+                let _ = sent_tx.send(format!("{} {}", op, request["args"]));
+                if op != "subscribe" {
+                    continue;
+                }
+                let ack = json!({"op": "subscribe", "success": true, "conn_id": "c-1"});
+                ws.send(Message::Text(ack.to_string())).await.unwrap();
+                subscriptions += 1;
+                let pushes = if subscriptions == 1 {
+                    // Update 3 never arrives
+                    vec![
+                        book("snapshot", 1, "100"),
+                        book("delta", 2, "100.5"),
+                        book("delta", 4, "99"),
+                    ]
+                } else {
+                    vec![book("snapshot", 10, "100.2")]
+                };
+                for push in pushes {
+                    ws.send(push).await.unwrap();
+                }
+            }
+        });
+
+        let rest = ReqwestRest::new(url.clone(), "bybit_perp".to_string(), None).unwrap();
+        let market_data = MarketData::<_, ()>::new(&rest, None).with_ws_url(url);
+        let mut rx = market_data
+            .subscribe_market_data(
+                vec![Symbol::new("BTC", "USDT").unwrap()],
+                vec![SubscriptionType::OrderBook {
+                    depth: Some(50),
+                    mode: crate::core::types::OrderBookMode::Snapshot,
+                }],
+                None,
+            )
+            .await
+            .unwrap();
+
+        let mut bids = Vec::new();
+        for _ in 0..3 {
+            let Some(MarketDataType::OrderBook(book)) = rx.recv().await else {
+                panic!("expected an order book update");
+            };
+            bids.push((book.last_update_id, book.bids[0].price.to_string()));
         }
-        _ => None, // Ignore ping, pong, error, and other events
+        assert_eq!(
+            bids,
+            [
+                (1, "100".to_string()),
+                (2, "100.5".to_string()),
+                (10, "100.2".to_string()),
+            ]
+        );
+        assert_eq!(
+            sent_rx.recv().await.unwrap(),
+            r#"subscribe ["orderbook.50.BTCUSDT"]"#
+        );
+        assert_eq!(
+            sent_rx.recv().await.unwrap(),
+            r#"unsubscribe ["orderbook.50.BTCUSDT"]"#
+        );
+        assert_eq!(
+            sent_rx.recv().await.unwrap(),
+            r#"subscribe ["orderbook.50.BTCUSDT"]"#
+        );
     }
 }
@@ -1,7 +1,12 @@
 use crate::core::config::ExchangeConfig;
 use crate::core::errors::ExchangeError;
-use crate::core::kernel::RestClient;
-use crate::core::traits::{AccountInfo, FundingRateSource, MarketDataSource, OrderPlacer};
+use crate::core::kernel::{diagnostics, Diagnostics, RestClient};
+use crate::core::traits::{
+    AccountInfo, AccountSummarySource, DiagnosticsSource, FundingRateSource, HistoricalTradeSource,
+    KillSwitch, MarketSnapshotSource, OrderHistorySource, OrderPlacer, OrderQuery, PositionManager,
+    RestMarketData, StreamingMarketData, TradeHistorySource,
+};
+use crate::exchanges::bybit_perp::types::BybitPerpCategory;
 use async_trait::async_trait;
 
 pub mod account;
@@ -22,7 +27,8 @@ pub struct BybitPerpConnector<R: RestClient, W = ()> {
 impl<R: RestClient + Clone + Send + Sync> BybitPerpConnector<R, ()> {
     pub fn new_without_ws(rest: R, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::with_testnet(&rest, None, config.testnet),
+            market: MarketData::with_testnet(&rest, None, config.testnet)
+                .with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
         }
@@ -32,25 +38,40 @@ impl<R: RestClient + Clone + Send + Sync> BybitPerpConnector<R, ()> {
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> BybitPerpConnector<R, W> {
     pub fn new(rest: R, ws: W, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::with_testnet(&rest, Some(ws), config.testnet),
+            market: MarketData::with_testnet(&rest, Some(ws), config.testnet)
+                .with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
         }
     }
+
+    /// Serve a different contract category, e.g. `BybitPerpCategory::Inverse`
+    #[must_use]
+    pub fn with_category(self, category: BybitPerpCategory) -> Self {
+        Self {
+            market: self.market.with_category(category),
+            trading: self.trading.with_category(category),
+            account: self.account.with_category(category),
+        }
+    }
 }
 
 // Implement traits for the connector by delegating to sub-components
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketDataSource
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> RestMarketData
     for BybitPerpConnector<R, W>
 {
     async fn get_markets(&self) -> Result<Vec<crate::core::types::Market>, ExchangeError> {
         self.market.get_markets().await
     }
 
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
+    }
+
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: crate::core::types::Symbol,
         interval: crate::core::types::KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
@@ -60,10 +81,59 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketDataSource
             .get_klines(symbol, interval, limit, start_time, end_time)
             .await
     }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for BybitPerpConnector<R, W>
+{
+    async fn get_ticker(
+        &self,
+        symbol: crate::core::types::Symbol,
+    ) -> Result<crate::core::types::Ticker, ExchangeError> {
+        self.market.get_ticker(symbol).await
+    }
 
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: crate::core::types::Symbol,
+        depth: Option<u32>,
+    ) -> Result<crate::core::types::OrderBook, ExchangeError> {
+        self.market.get_order_book_snapshot(symbol, depth).await
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: crate::core::types::Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<crate::core::types::Trade>, ExchangeError> {
+        self.market.get_recent_trades(symbol, limit).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> HistoricalTradeSource
+    for BybitPerpConnector<R, W>
+{
+    async fn get_historical_trades(
+        &self,
+        symbol: crate::core::types::Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<crate::core::types::Trade>, ExchangeError> {
+        self.market
+            .get_historical_trades(symbol, start_time, end_time)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> StreamingMarketData
+    for BybitPerpConnector<R, W>
+{
     async fn subscribe_market_data(
         &self,
-        symbols: Vec<String>,
+        symbols: Vec<crate::core::types::Symbol>,
         subscription_types: Vec<crate::core::types::SubscriptionType>,
         config: Option<crate::core::types::WebSocketConfig>,
     ) -> Result<tokio::sync::mpsc::Receiver<crate::core::types::MarketDataType>, ExchangeError>
@@ -84,7 +154,7 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
 {
     async fn get_funding_rates(
         &self,
-        symbols: Option<Vec<String>>,
+        symbols: Option<Vec<crate::core::types::Symbol>>,
     ) -> Result<Vec<crate::core::types::FundingRate>, ExchangeError> {
         self.market.get_funding_rates(symbols).await
     }
@@ -97,7 +167,7 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
 
     async fn get_funding_rate_history(
         &self,
-        symbol: String,
+        symbol: crate::core::types::Symbol,
         start_time: Option<i64>,
         end_time: Option<i64>,
         limit: Option<u32>,
@@ -110,6 +180,10 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderPlacer for BybitPerpConnector<R, W> {
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
     async fn place_order(
         &self,
         order: crate::core::types::OrderRequest,
@@ -117,13 +191,66 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderPlacer for BybitP
         self.trading.place_order(order).await
     }
 
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(
+        &self,
+        symbol: crate::core::types::Symbol,
+        order_id: String,
+    ) -> Result<(), ExchangeError> {
         self.trading.cancel_order(symbol, order_id).await
     }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: crate::core::types::Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderQuery for BybitPerpConnector<R, W> {
+    async fn get_open_orders(
+        &self,
+        symbol: Option<crate::core::types::Symbol>,
+    ) -> Result<Vec<crate::core::types::Order>, ExchangeError> {
+        self.trading.get_open_orders(symbol).await
+    }
+
+    async fn get_order(
+        &self,
+        symbol: crate::core::types::Symbol,
+        order_id: String,
+    ) -> Result<crate::core::types::Order, ExchangeError> {
+        self.trading.get_order(symbol, order_id).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderHistorySource
+    for BybitPerpConnector<R, W>
+{
+    async fn get_order_history(
+        &self,
+        symbol: crate::core::types::Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<crate::core::types::OrderPage, ExchangeError> {
+        self.trading
+            .get_order_history(symbol, start_time, end_time, cursor)
+            .await
+    }
 }
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo for BybitPerpConnector<R, W> {
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
     async fn get_account_balance(&self) -> Result<Vec<crate::core::types::Balance>, ExchangeError> {
         self.account.get_account_balance().await
     }
@@ -132,3 +259,89 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo for BybitP
         self.account.get_positions().await
     }
 }
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountSummarySource
+    for BybitPerpConnector<R, W>
+{
+    async fn get_account_summary(
+        &self,
+    ) -> Result<crate::core::types::AccountSummary, ExchangeError> {
+        self.account.get_account_summary().await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> TradeHistorySource
+    for BybitPerpConnector<R, W>
+{
+    async fn get_my_trades(
+        &self,
+        symbol: crate::core::types::Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<crate::core::types::AccountFill>, ExchangeError> {
+        self.account
+            .get_my_trades(symbol, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> PositionManager
+    for BybitPerpConnector<R, W>
+{
+    async fn set_leverage(
+        &self,
+        symbol: crate::core::types::Symbol,
+        leverage: u32,
+    ) -> Result<(), ExchangeError> {
+        self.trading.set_leverage(symbol, leverage).await
+    }
+
+    async fn set_margin_mode(
+        &self,
+        symbol: crate::core::types::Symbol,
+        mode: crate::core::types::MarginMode,
+    ) -> Result<(), ExchangeError> {
+        self.trading.set_margin_mode(symbol, mode).await
+    }
+
+    async fn get_leverage_brackets(
+        &self,
+        symbol: crate::core::types::Symbol,
+    ) -> Result<Vec<crate::core::types::LeverageBracket>, ExchangeError> {
+        self.trading.get_leverage_brackets(symbol).await
+    }
+
+    async fn close_position(
+        &self,
+        symbol: crate::core::types::Symbol,
+    ) -> Result<Option<crate::core::types::OrderResponse>, ExchangeError> {
+        self.trading.close_position(symbol).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> KillSwitch for BybitPerpConnector<R, W> {
+    async fn cancel_all_orders(
+        &self,
+        symbol: Option<crate::core::types::Symbol>,
+    ) -> Result<(), ExchangeError> {
+        self.trading.cancel_all_orders(symbol).await
+    }
+
+    async fn set_cancel_on_disconnect(
+        &self,
+        timeout: std::time::Duration,
+    ) -> Result<(), ExchangeError> {
+        self.trading.set_cancel_on_disconnect(timeout).await
+    }
+}
+
+impl<R: RestClient, W> DiagnosticsSource for BybitPerpConnector<R, W> {
+    fn diagnostics(&self) -> Diagnostics {
+        diagnostics("bybit_perp")
+    }
+}
@@ -1,13 +1,24 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
-use crate::core::traits::OrderPlacer;
-use crate::core::types::{conversion, OrderRequest, OrderResponse, OrderType};
+use crate::core::traits::{
+    KillSwitch, OrderHistorySource, OrderPlacer, OrderQuery, PositionManager,
+};
+use crate::core::types::{
+    conversion, LeverageBracket, MarginMode, Order, OrderPage, OrderRequest, OrderResponse,
+    OrderType, Symbol,
+};
+use crate::exchanges::bybit_perp::conversions::SYMBOLS;
 use crate::exchanges::bybit_perp::conversions::{
+    convert_bybit_perp_order, convert_bybit_perp_position, convert_bybit_perp_risk_limits,
     convert_order_side, convert_order_type, convert_time_in_force,
 };
 use crate::exchanges::bybit_perp::rest::BybitPerpRestClient;
-use crate::exchanges::bybit_perp::types::{BybitPerpError, BybitPerpOrderRequest};
+use crate::exchanges::bybit_perp::types::{
+    bybit_perp_error_kind, BybitPerpApiResponse, BybitPerpCategory, BybitPerpError,
+    BybitPerpOrderList, BybitPerpOrderRequest,
+};
 use async_trait::async_trait;
+use std::time::Duration;
 use tracing::{error, instrument};
 
 /// Trading implementation for Bybit Perpetual
@@ -24,14 +35,21 @@ impl<R: RestClient> Trading<R> {
             rest: BybitPerpRestClient::new(rest.clone()),
         }
     }
+
+    /// Serve a different contract category (linear by default)
+    #[must_use]
+    pub fn with_category(mut self, category: BybitPerpCategory) -> Self {
+        self.rest = self.rest.with_category(category);
+        self
+    }
 }
 
 /// Helper to handle API response errors for orders
 #[cold]
 #[inline(never)]
-fn handle_order_api_error(ret_code: i32, ret_msg: String, contract: &str) -> BybitPerpError {
+fn handle_order_api_error(ret_code: i32, ret_msg: String, contract: &str) -> ExchangeError {
     error!(contract = %contract, code = ret_code, message = %ret_msg, "Order API error");
-    BybitPerpError::api_error(ret_code, ret_msg)
+    ExchangeError::api_error(ret_code, ret_msg).classify(bybit_perp_error_kind)
 }
 
 /// Helper to handle order parsing errors
@@ -49,18 +67,26 @@ fn handle_order_parse_error(
 
 #[async_trait]
 impl<R: RestClient> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %order.symbol, side = ?order.side, order_type = ?order.order_type))]
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
         // Build the request body for V5 API
         let mut request_body = BybitPerpOrderRequest {
-            category: "linear".to_string(), // Use linear for perpetual futures
-            symbol: order.symbol.to_string(),
+            category: self.rest.category().as_str().to_string(),
+            symbol: SYMBOLS.try_to_exchange_symbol(&order.symbol)?,
             side: convert_order_side(&order.side),
             order_type: convert_order_type(&order.order_type),
             qty: order.quantity.to_string(),
             price: None,
             time_in_force: None,
             stop_price: None,
+            reduce_only: (order.reduce_only == Some(true) || order.close_position == Some(true))
+                .then_some(true),
+            close_on_trigger: (order.close_position == Some(true)).then_some(true),
+            order_link_id: order.client_order_id.clone(),
         };
 
         // Add price for limit orders
@@ -82,13 +108,10 @@ impl<R: RestClient> OrderPlacer for Trading<R> {
         let api_response = self.rest.place_order(&request_body).await?;
 
         if api_response.ret_code != 0 {
-            return Err(ExchangeError::Other(
-                handle_order_api_error(
-                    api_response.ret_code,
-                    api_response.ret_msg,
-                    &order.symbol.to_string(),
-                )
-                .to_string(),
+            return Err(handle_order_api_error(
+                api_response.ret_code,
+                api_response.ret_msg,
+                &SYMBOLS.try_to_exchange_symbol(&order.symbol)?,
             ));
         }
 
@@ -97,27 +120,264 @@ impl<R: RestClient> OrderPlacer for Trading<R> {
         Ok(OrderResponse {
             order_id,
             client_order_id: bybit_response.client_order_id,
-            symbol: conversion::string_to_symbol(&bybit_response.symbol),
+            symbol: SYMBOLS.from_exchange_symbol(&bybit_response.symbol),
             side: order.side,
             order_type: order.order_type,
-            quantity: conversion::string_to_quantity(&bybit_response.qty),
-            price: Some(conversion::string_to_price(&bybit_response.price)),
+            quantity: conversion::try_string_to_quantity(&bybit_response.qty)?,
+            price: Some(conversion::try_string_to_price(&bybit_response.price)?),
             status: bybit_response.status,
             timestamp: bybit_response.timestamp,
+            extensions: None,
         })
     }
 
     #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %symbol, order_id = %order_id))]
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let api_response = self.rest.cancel_order(&symbol, &order_id).await?;
 
         if api_response.ret_code != 0 {
-            return Err(ExchangeError::Other(
-                handle_order_api_error(api_response.ret_code, api_response.ret_msg, &symbol)
-                    .to_string(),
+            return Err(handle_order_api_error(
+                api_response.ret_code,
+                api_response.ret_msg,
+                &symbol,
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %symbol, client_order_id = %client_order_id))]
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let api_response = self
+            .rest
+            .cancel_order_by_link_id(&symbol, &client_order_id)
+            .await?;
+
+        if api_response.ret_code != 0 {
+            return Err(handle_order_api_error(
+                api_response.ret_code,
+                api_response.ret_msg,
+                &symbol,
             ));
         }
 
         Ok(())
     }
 }
+
+fn order_list(
+    response: BybitPerpApiResponse<BybitPerpOrderList>,
+) -> Result<Vec<Order>, ExchangeError> {
+    if response.ret_code != 0 {
+        return Err(
+            ExchangeError::api_error(response.ret_code, response.ret_msg)
+                .classify(bybit_perp_error_kind),
+        );
+    }
+    response
+        .result
+        .list
+        .iter()
+        .map(convert_bybit_perp_order)
+        .collect()
+}
+
+/// Fail on a non-zero `retCode` other than `unchanged`, which means already set
+fn ret_code_result<T>(
+    response: BybitPerpApiResponse<T>,
+    unchanged: i32,
+) -> Result<(), ExchangeError> {
+    match response.ret_code {
+        0 => Ok(()),
+        code if code == unchanged => Ok(()),
+        code => {
+            Err(ExchangeError::api_error(code, response.ret_msg).classify(bybit_perp_error_kind))
+        }
+    }
+}
+
+/// Largest page `/v5/order/history` returns
+const ORDER_HISTORY_LIMIT: u32 = 50;
+
+#[async_trait]
+impl<R: RestClient> OrderHistorySource for Trading<R> {
+    /// Pages run newest first; the cursor is Bybit's `nextPageCursor`
+    #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %symbol))]
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        let response = self
+            .rest
+            .get_order_history(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                start_time,
+                end_time,
+                cursor.as_deref(),
+                Some(ORDER_HISTORY_LIMIT),
+            )
+            .await?;
+        let next_cursor = Some(response.result.next_page_cursor.clone())
+            .filter(|cursor| !cursor.is_empty() && !response.result.list.is_empty());
+        let orders = order_list(response)?
+            .into_iter()
+            .filter(|order| !order.status.is_open())
+            .collect();
+        Ok(OrderPage {
+            orders,
+            next_cursor,
+        })
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> PositionManager for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %symbol))]
+    async fn set_leverage(&self, symbol: Symbol, leverage: u32) -> Result<(), ExchangeError> {
+        let response = self
+            .rest
+            .set_leverage(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                &leverage.to_string(),
+            )
+            .await?;
+        // 110043: leverage not modified
+        ret_code_result(response, 110_043)
+    }
+
+    #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %symbol))]
+    async fn set_margin_mode(&self, symbol: Symbol, mode: MarginMode) -> Result<(), ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        // Switching requires the leverage, so keep the current one
+        let positions = self.rest.get_position(&symbol).await?;
+        if positions.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(positions.ret_code, positions.ret_msg)
+                    .classify(bybit_perp_error_kind),
+            );
+        }
+        let leverage = positions
+            .result
+            .list
+            .into_iter()
+            .next()
+            .map_or_else(|| "1".to_string(), |position| position.leverage);
+
+        let trade_mode = match mode {
+            MarginMode::Cross => 0,
+            MarginMode::Isolated => 1,
+        };
+        let response = self
+            .rest
+            .switch_isolated(&symbol, trade_mode, &leverage)
+            .await?;
+        // 110026: cross/isolated margin mode is not modified
+        ret_code_result(response, 110_026)
+    }
+
+    #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %symbol))]
+    async fn get_leverage_brackets(
+        &self,
+        symbol: Symbol,
+    ) -> Result<Vec<LeverageBracket>, ExchangeError> {
+        let response = self
+            .rest
+            .get_risk_limits(&SYMBOLS.try_to_exchange_symbol(&symbol)?)
+            .await?;
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_perp_error_kind),
+            );
+        }
+        convert_bybit_perp_risk_limits(&response.result.list)
+    }
+
+    #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %symbol))]
+    async fn close_position(&self, symbol: Symbol) -> Result<Option<OrderResponse>, ExchangeError> {
+        let response = self
+            .rest
+            .get_position(&SYMBOLS.try_to_exchange_symbol(&symbol)?)
+            .await?;
+        if response.ret_code != 0 {
+            return Err(
+                ExchangeError::api_error(response.ret_code, response.ret_msg)
+                    .classify(bybit_perp_error_kind),
+            );
+        }
+        for position in &response.result.list {
+            if let Some(order) = convert_bybit_perp_position(position)?.close_order() {
+                return self.place_order(order).await.map(Some);
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> KillSwitch for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "bybit_perp"))]
+    async fn cancel_all_orders(&self, symbol: Option<Symbol>) -> Result<(), ExchangeError> {
+        let symbol = symbol
+            .map(|s| SYMBOLS.try_to_exchange_symbol(&s))
+            .transpose()?;
+        let response = self.rest.cancel_all_orders(symbol.as_deref()).await?;
+        ret_code_result(response, 0)
+    }
+
+    /// Bybit's switch fires when the private WebSocket drops, not on REST silence
+    ///
+    /// The window is whole seconds, 3 to 300; Bybit offers no way to disarm it.
+    #[instrument(skip(self), fields(exchange = "bybit_perp"))]
+    async fn set_cancel_on_disconnect(&self, timeout: Duration) -> Result<(), ExchangeError> {
+        if timeout.is_zero() {
+            return Err(ExchangeError::InvalidParameters(
+                "Bybit disconnect-cancel-all cannot be disarmed".to_string(),
+            ));
+        }
+        let response = self
+            .rest
+            .set_disconnected_cancel_all(timeout.as_secs().max(3))
+            .await?;
+        ret_code_result(response, 0)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> OrderQuery for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "bybit_perp"))]
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        let symbol = symbol
+            .map(|s| SYMBOLS.try_to_exchange_symbol(&s))
+            .transpose()?;
+        order_list(self.rest.get_open_orders(symbol.as_deref(), None).await?)
+    }
+
+    #[instrument(skip(self), fields(exchange = "bybit_perp", contract = %symbol, order_id = %order_id))]
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let open = order_list(
+            self.rest
+                .get_open_orders(Some(&symbol), Some(&order_id))
+                .await?,
+        )?;
+        // Orders closed long enough ago drop out of the realtime list
+        let mut orders = if open.is_empty() {
+            order_list(self.rest.get_closed_order(&symbol, &order_id).await?)?
+        } else {
+            open
+        };
+        orders.pop().ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Order {} not found", order_id))
+        })
+    }
+}
@@ -1,13 +1,42 @@
 use super::types as bybit_perp_types;
-use super::types::{BybitPerpKlineData, BybitPerpMarket};
+use super::types::{
+    BybitPerpAccountList, BybitPerpExecution, BybitPerpKlineData, BybitPerpMarket, BybitPerpOrder,
+    BybitPerpOrderBook, BybitPerpPosition, BybitPerpRiskLimit, BybitPerpTickerInfo, BybitPerpTrade,
+};
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
 use crate::core::types::{
-    Kline, Market, MarketDataType, OrderBook, OrderBookEntry, OrderSide, OrderType, Symbol, Ticker,
-    TimeInForce, Trade,
+    conversion, AccountFill, AccountSummary, ContractStyle, Kline, LeverageBracket, MarkPrice,
+    Market, MarketDataType, MarketStatus, Order, OrderBook, OrderBookEntry, OrderSide, OrderStatus,
+    OrderType, Position, PositionSide, Price, Symbol, Ticker, TimeInForce, Trade,
 };
+use crate::exchanges::factory::ExchangeId;
+use rust_decimal::Decimal;
 use serde_json::Value;
 
+/// Bybit linear perpetual symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::BybitPerp.symbol_convention();
+
+/// Convert unified account totals to core account summary
+pub fn convert_bybit_perp_account_summary(
+    account: &BybitPerpAccountList,
+    timestamp: i64,
+) -> Result<AccountSummary, ExchangeError> {
+    Ok(AccountSummary {
+        currency: "USD".to_string(),
+        total_equity: conversion::try_string_to_decimal(&account.total_equity)?,
+        available_margin: conversion::try_string_to_decimal(&account.total_available_balance)?,
+        initial_margin: conversion::try_string_to_decimal(&account.total_initial_margin)?,
+        maintenance_margin: conversion::try_string_to_decimal(&account.total_maintenance_margin)?,
+        unrealized_pnl: conversion::try_string_to_decimal(&account.total_perp_upl)?,
+        timestamp,
+    })
+}
+
 /// Convert bybit perp market to core market type
-pub fn convert_bybit_perp_market(bybit_perp_market: bybit_perp_types::BybitPerpMarket) -> Market {
+pub fn convert_bybit_perp_market(
+    bybit_perp_market: bybit_perp_types::BybitPerpMarket,
+) -> Result<Market, ExchangeError> {
     // Parse precision from price scale string
     let price_precision = bybit_perp_market.price_scale.parse::<i32>().unwrap_or(2);
 
@@ -16,30 +45,38 @@ pub fn convert_bybit_perp_market(bybit_perp_market: bybit_perp_types::BybitPerpM
         .lot_size_filter
         .qty_step
         .parse::<f64>()
-        .map(|p| (-p.log10()).ceil() as i32)
-        .unwrap_or(3);
+        .map_or(3, |p| (-p.log10()).ceil() as i32);
+
+    // Inverse contracts settle in the base coin and are sized in 1 USD contracts
+    let (contract_style, contract_size) =
+        if bybit_perp_market.settle_coin == bybit_perp_market.base_coin {
+            (ContractStyle::Inverse, Some(Decimal::ONE))
+        } else {
+            (ContractStyle::Linear, None)
+        };
 
-    Market {
+    Ok(Market {
         symbol: Symbol::new(bybit_perp_market.base_coin, bybit_perp_market.quote_coin)
-            .unwrap_or_else(|_| {
-                crate::core::types::conversion::string_to_symbol(&bybit_perp_market.symbol)
-            }),
-        status: bybit_perp_market.status,
+            .unwrap_or_else(|_| SYMBOLS.from_exchange_symbol(&bybit_perp_market.symbol)),
+        status: MarketStatus::from_venue_str(&bybit_perp_market.status),
         base_precision,
         quote_precision: price_precision,
-        min_qty: Some(crate::core::types::conversion::string_to_quantity(
+        min_qty: Some(conversion::try_string_to_quantity(
             &bybit_perp_market.lot_size_filter.min_order_qty,
-        )),
-        max_qty: Some(crate::core::types::conversion::string_to_quantity(
+        )?),
+        max_qty: Some(conversion::try_string_to_quantity(
             &bybit_perp_market.lot_size_filter.max_order_qty,
-        )),
-        min_price: Some(crate::core::types::conversion::string_to_price(
+        )?),
+        min_price: Some(conversion::try_string_to_price(
             &bybit_perp_market.price_filter.min_price,
-        )),
-        max_price: Some(crate::core::types::conversion::string_to_price(
+        )?),
+        max_price: Some(conversion::try_string_to_price(
             &bybit_perp_market.price_filter.max_price,
-        )),
-    }
+        )?),
+        contract_style,
+        contract_size,
+        extensions: None,
+    })
 }
 
 /// Convert order side to bybit perp format
@@ -71,27 +108,204 @@ pub fn convert_time_in_force(tif: &TimeInForce) -> String {
     }
 }
 
+/// Convert bybit perp queried order to core order type
+pub fn convert_bybit_perp_order(order: &BybitPerpOrder) -> Result<Order, ExchangeError> {
+    // Market orders report a zero or empty price, unfilled orders a zero or empty average
+    let non_zero = |value: &str| {
+        conversion::try_string_to_price(value)
+            .ok()
+            .filter(|price| !price.value().is_zero())
+    };
+    let parse_time = |time: &str| {
+        time.parse::<i64>()
+            .map_err(|e| ExchangeError::ParseError(format!("Invalid timestamp '{}': {}", time, e)))
+    };
+
+    Ok(Order {
+        order_id: order.order_id.clone(),
+        client_order_id: order.order_link_id.clone(),
+        symbol: SYMBOLS.from_exchange_symbol(&order.symbol),
+        side: if order.side == "Sell" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        order_type: if order.order_type == "Market" {
+            OrderType::Market
+        } else {
+            OrderType::Limit
+        },
+        status: OrderStatus::from_venue(&order.order_status),
+        price: non_zero(&order.price),
+        quantity: conversion::try_string_to_quantity(&order.qty)?,
+        filled_quantity: conversion::try_string_to_quantity(&order.cum_exec_qty)?,
+        average_price: non_zero(&order.avg_price),
+        created_time: parse_time(&order.created_time)?,
+        updated_time: parse_time(&order.updated_time)?,
+    })
+}
+
+/// Convert bybit perp risk limits to core brackets
+///
+/// Bybit lists only where each tier ends; a tier starts where the previous one ended.
+pub fn convert_bybit_perp_risk_limits(
+    risk_limits: &[BybitPerpRiskLimit],
+) -> Result<Vec<LeverageBracket>, ExchangeError> {
+    let mut tiers = risk_limits
+        .iter()
+        .map(|tier| {
+            Ok((
+                conversion::try_string_to_decimal(&tier.risk_limit_value)?,
+                conversion::try_string_to_decimal(&tier.max_leverage)?,
+                conversion::try_string_to_decimal(&tier.maintenance_margin)? / Decimal::ONE_HUNDRED,
+            ))
+        })
+        .collect::<Result<Vec<_>, ExchangeError>>()?;
+    tiers.sort_by_key(|tier| tier.0);
+
+    let mut floor = Decimal::ZERO;
+    Ok(tiers
+        .into_iter()
+        .map(|(cap, max_leverage, maintenance_margin_rate)| {
+            let bracket = LeverageBracket {
+                max_leverage,
+                floor,
+                cap: Some(cap),
+                maintenance_margin_rate,
+            };
+            floor = cap;
+            bracket
+        })
+        .collect())
+}
+
+/// Convert bybit perp execution to core fill
+pub fn convert_bybit_perp_execution(
+    execution: &BybitPerpExecution,
+) -> Result<AccountFill, ExchangeError> {
+    Ok(AccountFill {
+        symbol: SYMBOLS.from_exchange_symbol(&execution.symbol),
+        order_id: execution.order_id.clone(),
+        trade_id: execution.exec_id.clone(),
+        side: if execution.side == "Sell" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        price: conversion::try_string_to_price(&execution.exec_price)?,
+        quantity: conversion::try_string_to_quantity(&execution.exec_qty)?,
+        fee: conversion::try_string_to_decimal(&execution.exec_fee)?,
+        fee_asset: execution
+            .fee_currency
+            .clone()
+            .filter(|asset| !asset.is_empty()),
+        is_maker: execution.is_maker,
+        timestamp: execution.exec_time.parse::<i64>().map_err(|e| {
+            ExchangeError::ParseError(format!(
+                "Invalid timestamp '{}': {}",
+                execution.exec_time, e
+            ))
+        })?,
+    })
+}
+
+/// Convert bybit perp position to core position type
+pub fn convert_bybit_perp_position(
+    position: &BybitPerpPosition,
+) -> Result<Position, ExchangeError> {
+    let position_side = match position.side.as_str() {
+        "Sell" => PositionSide::Short,
+        _ => PositionSide::Long,
+    };
+
+    Ok(Position {
+        symbol: SYMBOLS.from_exchange_symbol(&position.symbol),
+        position_side,
+        entry_price: conversion::try_string_to_price(&position.entry_price)?,
+        position_amount: conversion::try_string_to_quantity(&position.size)?,
+        unrealized_pnl: conversion::try_string_to_decimal(&position.unrealised_pnl)?,
+        // Empty when the position has no liquidation price
+        liquidation_price: conversion::try_string_to_price(&position.liquidation_price).ok(),
+        leverage: conversion::try_string_to_decimal(&position.leverage)?,
+        margin_mode: None,
+        margin: None,
+        extensions: None,
+    })
+}
+
 /// Convert bybit perp kline to core kline type
 pub fn convert_bybit_perp_kline(
     symbol: String,
     interval: String,
     bybit_perp_kline: bybit_perp_types::BybitPerpRestKline,
-) -> Kline {
-    use crate::core::types::conversion;
-
-    Kline {
-        symbol: conversion::string_to_symbol(&symbol),
+) -> Result<Kline, ExchangeError> {
+    Ok(Kline {
+        symbol: SYMBOLS.from_exchange_symbol(&symbol),
         open_time: bybit_perp_kline.start_time,
         close_time: bybit_perp_kline.end_time,
         interval,
-        open_price: conversion::string_to_price(&bybit_perp_kline.open_price),
-        high_price: conversion::string_to_price(&bybit_perp_kline.high_price),
-        low_price: conversion::string_to_price(&bybit_perp_kline.low_price),
-        close_price: conversion::string_to_price(&bybit_perp_kline.close_price),
-        volume: conversion::string_to_volume(&bybit_perp_kline.volume),
+        open_price: conversion::try_string_to_price(&bybit_perp_kline.open_price)?,
+        high_price: conversion::try_string_to_price(&bybit_perp_kline.high_price)?,
+        low_price: conversion::try_string_to_price(&bybit_perp_kline.low_price)?,
+        close_price: conversion::try_string_to_price(&bybit_perp_kline.close_price)?,
+        volume: conversion::try_string_to_volume(&bybit_perp_kline.volume)?,
         number_of_trades: 0, // Bybit doesn't provide this in REST API
         final_bar: true,
-    }
+    })
+}
+
+/// Convert bybit perp ticker to core ticker type, stamped with the reply's server `time`
+pub fn convert_bybit_perp_ticker(
+    ticker: &BybitPerpTickerInfo,
+    time: i64,
+) -> Result<Ticker, ExchangeError> {
+    let price = conversion::try_string_to_price(&ticker.last_price)?;
+    let prev_price = conversion::try_string_to_price(&ticker.prev_price_24h)?;
+    Ok(Ticker {
+        symbol: SYMBOLS.from_exchange_symbol(&ticker.symbol),
+        price,
+        price_change: Price::new(price.value() - prev_price.value()),
+        // Reported as a fraction, `0.01` being 1%
+        price_change_percent: conversion::try_string_to_decimal(&ticker.price_24h_pcnt)?
+            * Decimal::ONE_HUNDRED,
+        high_price: conversion::try_string_to_price(&ticker.high_price_24h)?,
+        low_price: conversion::try_string_to_price(&ticker.low_price_24h)?,
+        volume: conversion::try_string_to_volume(&ticker.volume_24h)?,
+        quote_volume: conversion::try_string_to_volume(&ticker.turnover_24h)?,
+        open_time: time - 24 * 60 * 60 * 1000,
+        close_time: time,
+        count: 0, // Not reported by Bybit
+    })
+}
+
+/// Convert bybit perp order book to core order book type
+pub fn convert_bybit_perp_order_book(
+    book: &BybitPerpOrderBook,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: SYMBOLS.from_exchange_symbol(&book.symbol),
+        bids: conversion::try_book_levels(&book.bids)?,
+        asks: conversion::try_book_levels(&book.asks)?,
+        last_update_id: book.update_id,
+    })
+}
+
+/// Convert bybit perp public trade to core trade type
+pub fn convert_bybit_perp_trade(
+    trade: &BybitPerpTrade,
+    symbol: &str,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
+        // Derivative trade ids are UUIDs, which have no numeric form
+        id: trade.exec_id.parse().unwrap_or(0),
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: conversion::try_string_to_quantity(&trade.size)?,
+        time: trade.time.parse().map_err(|_| {
+            ExchangeError::ParseError(format!("Invalid trade time: {}", trade.time))
+        })?,
+        is_buyer_maker: trade.side == "Sell",
+    })
 }
 
 /// Parse WebSocket message and convert to `MarketDataType`
@@ -104,17 +318,16 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
         if let Ok(ticker) =
             serde_json::from_value::<bybit_perp_types::BybitPerpTickerData>(data.clone())
         {
-            use crate::core::types::conversion;
-
             return Some(MarketDataType::Ticker(Ticker {
-                symbol: conversion::string_to_symbol(&ticker.symbol),
-                price: conversion::string_to_price(&ticker.last_price),
-                price_change: conversion::string_to_price("0"), // Not provided in Bybit ticker
-                price_change_percent: conversion::string_to_decimal(&ticker.price_24h_pcnt),
-                high_price: conversion::string_to_price(&ticker.high_price_24h),
-                low_price: conversion::string_to_price(&ticker.low_price_24h),
-                volume: conversion::string_to_volume(&ticker.volume_24h),
-                quote_volume: conversion::string_to_volume(&ticker.turnover_24h),
+                symbol: SYMBOLS.from_exchange_symbol(&ticker.symbol),
+                price: conversion::try_string_to_price(&ticker.last_price).ok()?,
+                price_change: Price::ZERO, // Not provided in Bybit ticker
+                price_change_percent: conversion::try_string_to_decimal(&ticker.price_24h_pcnt)
+                    .ok()?,
+                high_price: conversion::try_string_to_price(&ticker.high_price_24h).ok()?,
+                low_price: conversion::try_string_to_price(&ticker.low_price_24h).ok()?,
+                volume: conversion::try_string_to_volume(&ticker.volume_24h).ok()?,
+                quote_volume: conversion::try_string_to_volume(&ticker.turnover_24h).ok()?,
                 open_time: 0,  // Not provided in Bybit ticker
                 close_time: 0, // Not provided in Bybit ticker
                 count: 0,      // Not provided in Bybit ticker
@@ -124,28 +337,30 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
         if let Ok(orderbook) =
             serde_json::from_value::<bybit_perp_types::BybitPerpOrderBookData>(data.clone())
         {
-            use crate::core::types::conversion;
-
             let bids = orderbook
                 .bids
                 .into_iter()
-                .map(|[price, qty]| OrderBookEntry {
-                    price: conversion::string_to_price(&price),
-                    quantity: conversion::string_to_quantity(&qty),
+                .map(|[price, qty]| {
+                    Some(OrderBookEntry {
+                        price: conversion::try_string_to_price(&price).ok()?,
+                        quantity: conversion::try_string_to_quantity(&qty).ok()?,
+                    })
                 })
-                .collect();
+                .collect::<Option<Vec<_>>>()?;
 
             let asks = orderbook
                 .asks
                 .into_iter()
-                .map(|[price, qty]| OrderBookEntry {
-                    price: conversion::string_to_price(&price),
-                    quantity: conversion::string_to_quantity(&qty),
+                .map(|[price, qty]| {
+                    Some(OrderBookEntry {
+                        price: conversion::try_string_to_price(&price).ok()?,
+                        quantity: conversion::try_string_to_quantity(&qty).ok()?,
+                    })
                 })
-                .collect();
+                .collect::<Option<Vec<_>>>()?;
 
             return Some(MarketDataType::OrderBook(OrderBook {
-                symbol: conversion::string_to_symbol(&orderbook.symbol),
+                symbol: SYMBOLS.from_exchange_symbol(&orderbook.symbol),
                 bids,
                 asks,
                 last_update_id: orderbook.u,
@@ -155,35 +370,34 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
         if let Ok(trade) =
             serde_json::from_value::<bybit_perp_types::BybitPerpTradeData>(data.clone())
         {
-            use crate::core::types::conversion;
-
             return Some(MarketDataType::Trade(Trade {
-                symbol: conversion::string_to_symbol(&trade.symbol),
+                symbol: SYMBOLS.from_exchange_symbol(&trade.symbol),
                 id: trade.trade_id.parse().unwrap_or(0),
-                price: conversion::string_to_price(&trade.price),
-                quantity: conversion::string_to_quantity(&trade.size),
+                price: conversion::try_string_to_price(&trade.price).ok()?,
+                quantity: conversion::try_string_to_quantity(&trade.size).ok()?,
                 time: trade.trade_time_ms,
                 is_buyer_maker: trade.side == "Sell",
             }));
         }
     } else if topic.contains("kline") {
-        if let Ok(kline) =
-            serde_json::from_value::<bybit_perp_types::BybitPerpKlineData>(data.clone())
+        // Topic is `kline.{interval}.{symbol}`; data carries one or more bars, latest last
+        if let Ok(mut klines) =
+            serde_json::from_value::<Vec<bybit_perp_types::BybitPerpWsKline>>(data.clone())
         {
-            use crate::core::types::conversion;
-
+            let kline = klines.pop()?;
+            let symbol = topic.rsplit('.').next().unwrap_or_default();
             return Some(MarketDataType::Kline(Kline {
-                symbol: conversion::string_to_symbol(""), // Extract from topic
-                open_time: kline.start_time,
-                close_time: kline.end_time,
+                symbol: SYMBOLS.from_exchange_symbol(symbol),
+                open_time: kline.start,
+                close_time: kline.end,
                 interval: kline.interval,
-                open_price: conversion::string_to_price(&kline.open_price),
-                high_price: conversion::string_to_price(&kline.high_price),
-                low_price: conversion::string_to_price(&kline.low_price),
-                close_price: conversion::string_to_price(&kline.close_price),
-                volume: conversion::string_to_volume(&kline.volume),
+                open_price: conversion::try_string_to_price(&kline.open).ok()?,
+                high_price: conversion::try_string_to_price(&kline.high).ok()?,
+                low_price: conversion::try_string_to_price(&kline.low).ok()?,
+                close_price: conversion::try_string_to_price(&kline.close).ok()?,
+                volume: conversion::try_string_to_volume(&kline.volume).ok()?,
                 number_of_trades: 0, // Not provided in Bybit kline
-                final_bar: true,
+                final_bar: kline.confirm,
             }));
         }
     }
@@ -191,32 +405,49 @@ pub fn parse_websocket_message(value: Value) -> Option<MarketDataType> {
     None
 }
 
+/// Mark price carried by a `tickers` push, if this frame updates it
+pub fn parse_mark_price_message(value: &Value) -> Option<MarkPrice> {
+    let data =
+        serde_json::from_value::<bybit_perp_types::BybitPerpMarkPriceData>(value["data"].clone())
+            .ok()?;
+    Some(MarkPrice {
+        symbol: SYMBOLS.from_exchange_symbol(&data.symbol),
+        mark_price: conversion::try_string_to_price(&data.mark_price).ok()?,
+        index_price: data
+            .index_price
+            .and_then(|price| conversion::try_string_to_price(&price).ok()),
+        funding_rate: data
+            .funding_rate
+            .and_then(|rate| conversion::try_string_to_decimal(&rate).ok()),
+        next_funding_time: data.next_funding_time.and_then(|time| time.parse().ok()),
+        timestamp: value["ts"].as_i64().unwrap_or(0),
+    })
+}
+
 pub fn convert_bybit_perp_market_to_symbol(bybit_perp_market: &BybitPerpMarket) -> Symbol {
     Symbol::new(
         bybit_perp_market.base_coin.clone(),
         bybit_perp_market.quote_coin.clone(),
     )
-    .unwrap_or_else(|_| crate::core::types::conversion::string_to_symbol(&bybit_perp_market.symbol))
+    .unwrap_or_else(|_| SYMBOLS.from_exchange_symbol(&bybit_perp_market.symbol))
 }
 
 pub fn convert_bybit_perp_kline_to_kline(
     symbol: String,
     interval: String,
     bybit_kline: &BybitPerpKlineData,
-) -> Kline {
-    use crate::core::types::conversion;
-
-    Kline {
-        symbol: conversion::string_to_symbol(&symbol),
+) -> Result<Kline, ExchangeError> {
+    Ok(Kline {
+        symbol: SYMBOLS.from_exchange_symbol(&symbol),
         open_time: bybit_kline.start_time,
         close_time: bybit_kline.end_time,
         interval,
-        open_price: conversion::string_to_price(&bybit_kline.open_price),
-        high_price: conversion::string_to_price(&bybit_kline.high_price),
-        low_price: conversion::string_to_price(&bybit_kline.low_price),
-        close_price: conversion::string_to_price(&bybit_kline.close_price),
-        volume: conversion::string_to_volume(&bybit_kline.volume),
+        open_price: conversion::try_string_to_price(&bybit_kline.open_price)?,
+        high_price: conversion::try_string_to_price(&bybit_kline.high_price)?,
+        low_price: conversion::try_string_to_price(&bybit_kline.low_price)?,
+        close_price: conversion::try_string_to_price(&bybit_kline.close_price)?,
+        volume: conversion::try_string_to_volume(&bybit_kline.volume)?,
         number_of_trades: 0, // Bybit doesn't provide this
         final_bar: true,
-    }
+    })
 }
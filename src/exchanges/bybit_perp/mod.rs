@@ -11,6 +11,9 @@ pub mod rest;
 pub use builder::{
     build_connector,
     build_connector_with_websocket,
+    build_inverse_connector,
+    build_inverse_connector_with_websocket,
+    build_public,
     // Legacy compatibility exports
     create_bybit_perp_connector,
 };
@@ -19,7 +22,7 @@ pub use connector::{Account, BybitPerpConnector, MarketData, Trading};
 
 // Helper functions for backward compatibility
 pub use types::{
-    BybitPerpCoinBalance, BybitPerpError, BybitPerpExchangeInfo, BybitPerpKlineData,
-    BybitPerpLotSizeFilter, BybitPerpMarket, BybitPerpOrderRequest, BybitPerpOrderResponse,
-    BybitPerpPriceFilter, BybitPerpRestKline, BybitPerpResultExt,
+    BybitPerpCategory, BybitPerpCoinBalance, BybitPerpError, BybitPerpExchangeInfo,
+    BybitPerpKlineData, BybitPerpLotSizeFilter, BybitPerpMarket, BybitPerpOrderRequest,
+    BybitPerpOrderResponse, BybitPerpPriceFilter, BybitPerpRestKline, BybitPerpResultExt,
 };
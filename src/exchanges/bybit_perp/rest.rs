@@ -1,32 +1,77 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
 use crate::exchanges::bybit_perp::types::{
-    BybitPerpAccountResult, BybitPerpApiResponse, BybitPerpExchangeInfo,
-    BybitPerpFundingRateResponse, BybitPerpKlineResponse, BybitPerpOrderRequest,
-    BybitPerpOrderResponse, BybitPerpPositionResult, BybitPerpTickerResponse,
+    BybitPerpAccountResult, BybitPerpApiResponse, BybitPerpCategory, BybitPerpExchangeInfo,
+    BybitPerpExecutionList, BybitPerpFundingRateResponse, BybitPerpKlineResponse,
+    BybitPerpOrderBook, BybitPerpOrderList, BybitPerpOrderRequest, BybitPerpOrderResponse,
+    BybitPerpPositionResult, BybitPerpRiskLimitList, BybitPerpTickerResponse, BybitPerpTradeList,
 };
 use serde_json::Value;
 
 /// Thin typed wrapper around `RestClient` for Bybit Perpetual API
 pub struct BybitPerpRestClient<R: RestClient> {
     client: R,
+    category: BybitPerpCategory,
 }
 
 impl<R: RestClient> BybitPerpRestClient<R> {
     pub fn new(client: R) -> Self {
-        Self { client }
+        Self {
+            client,
+            category: BybitPerpCategory::default(),
+        }
+    }
+
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.client.can_sign()
+    }
+
+    /// Target a different contract category (linear by default)
+    pub const fn with_category(mut self, category: BybitPerpCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    pub const fn category(&self) -> BybitPerpCategory {
+        self.category
     }
 
     /// Get all perpetual markets
     pub async fn get_markets(
         &self,
     ) -> Result<BybitPerpApiResponse<BybitPerpExchangeInfo>, ExchangeError> {
-        let params = [("category", "linear")];
+        let params = [("category", self.category.as_str())];
+        self.client
+            .get_json("/v5/market/instruments-info", &params, false)
+            .await
+    }
+
+    /// Get instrument info, optionally for a single symbol
+    pub async fn get_instruments(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<BybitPerpApiResponse<BybitPerpExchangeInfo>, ExchangeError> {
+        let mut params = vec![("category", self.category.as_str())];
+
+        if let Some(sym) = symbol {
+            params.push(("symbol", sym));
+        }
+
         self.client
             .get_json("/v5/market/instruments-info", &params, false)
             .await
     }
 
+    /// Get server time in milliseconds
+    pub async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let response = self.client.get("/v5/market/time", &[], false).await?;
+        // The envelope's `time` is the server time in milliseconds
+        response["time"].as_i64().ok_or_else(|| {
+            ExchangeError::InvalidResponseFormat("Missing time in server time response".to_string())
+        })
+    }
+
     /// Get klines for a symbol
     pub async fn get_klines(
         &self,
@@ -37,7 +82,7 @@ impl<R: RestClient> BybitPerpRestClient<R> {
         end_time: Option<i64>,
     ) -> Result<BybitPerpKlineResponse, ExchangeError> {
         let mut params = vec![
-            ("category", "linear"),
+            ("category", self.category.as_str()),
             ("symbol", symbol),
             ("interval", interval),
         ];
@@ -70,7 +115,7 @@ impl<R: RestClient> BybitPerpRestClient<R> {
         &self,
         symbol: Option<&str>,
     ) -> Result<BybitPerpTickerResponse, ExchangeError> {
-        let mut params = vec![("category", "linear")];
+        let mut params = vec![("category", self.category.as_str())];
 
         if let Some(sym) = symbol {
             params.push(("symbol", sym));
@@ -86,7 +131,7 @@ impl<R: RestClient> BybitPerpRestClient<R> {
         &self,
         symbol: &str,
     ) -> Result<BybitPerpFundingRateResponse, ExchangeError> {
-        let params = [("category", "linear"), ("symbol", symbol)];
+        let params = [("category", self.category.as_str()), ("symbol", symbol)];
         self.client
             .get_json("/v5/market/funding/history", &params, false)
             .await
@@ -96,7 +141,7 @@ impl<R: RestClient> BybitPerpRestClient<R> {
     pub async fn get_all_funding_rates(
         &self,
     ) -> Result<BybitPerpFundingRateResponse, ExchangeError> {
-        let params = [("category", "linear")];
+        let params = [("category", self.category.as_str())];
         self.client
             .get_json("/v5/market/funding/history", &params, false)
             .await
@@ -117,12 +162,13 @@ impl<R: RestClient> BybitPerpRestClient<R> {
         &self,
         settle_coin: Option<&str>,
     ) -> Result<BybitPerpApiResponse<BybitPerpPositionResult>, ExchangeError> {
-        let mut params = vec![("category", "linear")];
+        let mut params = vec![("category", self.category.as_str())];
 
-        if let Some(coin) = settle_coin {
-            params.push(("settleCoin", coin));
-        } else {
-            params.push(("settleCoin", "USDT"));
+        // Linear positions must be filtered by settle coin; inverse ones need not be
+        match (settle_coin, self.category) {
+            (Some(coin), _) => params.push(("settleCoin", coin)),
+            (None, BybitPerpCategory::Linear) => params.push(("settleCoin", "USDT")),
+            (None, BybitPerpCategory::Inverse) => {}
         }
 
         self.client
@@ -130,6 +176,68 @@ impl<R: RestClient> BybitPerpRestClient<R> {
             .await
     }
 
+    /// Get the position of one symbol
+    pub async fn get_position(
+        &self,
+        symbol: &str,
+    ) -> Result<BybitPerpApiResponse<BybitPerpPositionResult>, ExchangeError> {
+        let params = [("category", self.category.as_str()), ("symbol", symbol)];
+        self.client
+            .get_json("/v5/position/list", &params, true)
+            .await
+    }
+
+    /// Set the buy and sell leverage of a symbol
+    pub async fn set_leverage(
+        &self,
+        symbol: &str,
+        leverage: &str,
+    ) -> Result<BybitPerpApiResponse<Value>, ExchangeError> {
+        let request_body = serde_json::json!({
+            "category": self.category.as_str(),
+            "symbol": symbol,
+            "buyLeverage": leverage,
+            "sellLeverage": leverage
+        });
+
+        self.client
+            .post_json("/v5/position/set-leverage", &request_body, true)
+            .await
+    }
+
+    /// Switch a symbol between cross (`0`) and isolated (`1`) margin
+    ///
+    /// Classic accounts only; unified accounts set the margin mode account-wide.
+    pub async fn switch_isolated(
+        &self,
+        symbol: &str,
+        trade_mode: u8,
+        leverage: &str,
+    ) -> Result<BybitPerpApiResponse<Value>, ExchangeError> {
+        let request_body = serde_json::json!({
+            "category": self.category.as_str(),
+            "symbol": symbol,
+            "tradeMode": trade_mode,
+            "buyLeverage": leverage,
+            "sellLeverage": leverage
+        });
+
+        self.client
+            .post_json("/v5/position/switch-isolated", &request_body, true)
+            .await
+    }
+
+    /// Get the risk limit tiers of a symbol
+    pub async fn get_risk_limits(
+        &self,
+        symbol: &str,
+    ) -> Result<BybitPerpApiResponse<BybitPerpRiskLimitList>, ExchangeError> {
+        let params = [("category", self.category.as_str()), ("symbol", symbol)];
+        self.client
+            .get_json("/v5/market/risk-limit", &params, false)
+            .await
+    }
+
     /// Place an order
     pub async fn place_order(
         &self,
@@ -146,7 +254,7 @@ impl<R: RestClient> BybitPerpRestClient<R> {
         order_id: &str,
     ) -> Result<BybitPerpApiResponse<Value>, ExchangeError> {
         let request_body = serde_json::json!({
-            "category": "linear",
+            "category": self.category.as_str(),
             "symbol": symbol,
             "orderId": order_id
         });
@@ -156,21 +264,126 @@ impl<R: RestClient> BybitPerpRestClient<R> {
             .await
     }
 
-    /// Get order history
-    pub async fn get_order_history(
+    /// Cancel an order by its `orderLinkId`
+    pub async fn cancel_order_by_link_id(
+        &self,
+        symbol: &str,
+        order_link_id: &str,
+    ) -> Result<BybitPerpApiResponse<Value>, ExchangeError> {
+        let request_body = serde_json::json!({
+            "category": self.category.as_str(),
+            "symbol": symbol,
+            "orderLinkId": order_link_id
+        });
+
+        self.client
+            .post_json("/v5/order/cancel", &request_body, true)
+            .await
+    }
+
+    /// Cancel all open orders on a symbol, or on every symbol
+    ///
+    /// Linear cancels without a symbol cover USDT-settled contracts.
+    pub async fn cancel_all_orders(
         &self,
         symbol: Option<&str>,
-        limit: Option<u32>,
     ) -> Result<BybitPerpApiResponse<Value>, ExchangeError> {
-        let mut params = vec![("category", "linear")];
+        let mut request_body = serde_json::json!({ "category": self.category.as_str() });
+        match (symbol, self.category) {
+            (Some(symbol), _) => request_body["symbol"] = Value::from(symbol),
+            (None, BybitPerpCategory::Linear) => request_body["settleCoin"] = Value::from("USDT"),
+            (None, BybitPerpCategory::Inverse) => {}
+        }
 
-        if let Some(sym) = symbol {
-            params.push(("symbol", sym));
+        self.client
+            .post_json("/v5/order/cancel-all", &request_body, true)
+            .await
+    }
+
+    /// Cancel all derivatives orders if the private WebSocket stays down for `time_window` seconds
+    pub async fn set_disconnected_cancel_all(
+        &self,
+        time_window: u64,
+    ) -> Result<BybitPerpApiResponse<Value>, ExchangeError> {
+        let request_body = serde_json::json!({
+            "product": "DERIVATIVES",
+            "timeWindow": time_window
+        });
+
+        self.client
+            .post_json("/v5/order/disconnected-cancel-all", &request_body, true)
+            .await
+    }
+
+    /// Get open orders, or look one up by id among open and recent ones
+    ///
+    /// Linear queries without a symbol cover USDT-settled contracts.
+    pub async fn get_open_orders(
+        &self,
+        symbol: Option<&str>,
+        order_id: Option<&str>,
+    ) -> Result<BybitPerpApiResponse<BybitPerpOrderList>, ExchangeError> {
+        let mut params = vec![("category", self.category.as_str())];
+        match symbol {
+            Some(symbol) => params.push(("symbol", symbol)),
+            // Linear requires a symbol or a settle coin
+            None if self.category == BybitPerpCategory::Linear => {
+                params.push(("settleCoin", "USDT"));
+            }
+            None => {}
         }
+        if let Some(order_id) = order_id {
+            params.push(("orderId", order_id));
+        }
+        self.client
+            .get_json("/v5/order/realtime", &params, true)
+            .await
+    }
+
+    /// Look up a closed order by id
+    pub async fn get_closed_order(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<BybitPerpApiResponse<BybitPerpOrderList>, ExchangeError> {
+        let params = [
+            ("category", self.category.as_str()),
+            ("symbol", symbol),
+            ("orderId", order_id),
+        ];
+        self.client
+            .get_json("/v5/order/history", &params, true)
+            .await
+    }
+
+    /// Get closed orders on a symbol, newest first
+    pub async fn get_order_history(
+        &self,
+        symbol: &str,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<BybitPerpApiResponse<BybitPerpOrderList>, ExchangeError> {
+        let mut params = vec![("category", self.category.as_str()), ("symbol", symbol)];
 
+        let start_time_str;
+        let end_time_str;
         let limit_str;
-        if let Some(limit_val) = limit {
-            limit_str = limit_val.to_string();
+
+        if let Some(start_time) = start_time {
+            start_time_str = start_time.to_string();
+            params.push(("startTime", &start_time_str));
+        }
+        if let Some(end_time) = end_time {
+            end_time_str = end_time.to_string();
+            params.push(("endTime", &end_time_str));
+        }
+        if let Some(cursor) = cursor {
+            params.push(("cursor", cursor));
+        }
+        if let Some(limit) = limit {
+            limit_str = limit.to_string();
             params.push(("limit", &limit_str));
         }
 
@@ -179,13 +392,45 @@ impl<R: RestClient> BybitPerpRestClient<R> {
             .await
     }
 
+    /// Get the account's executions on a symbol, newest first
+    pub async fn get_executions(
+        &self,
+        symbol: &str,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<BybitPerpApiResponse<BybitPerpExecutionList>, ExchangeError> {
+        let mut params = vec![("category", self.category.as_str()), ("symbol", symbol)];
+
+        let start_time_str;
+        let end_time_str;
+        let limit_str;
+
+        if let Some(start_time) = start_time {
+            start_time_str = start_time.to_string();
+            params.push(("startTime", &start_time_str));
+        }
+        if let Some(end_time) = end_time {
+            end_time_str = end_time.to_string();
+            params.push(("endTime", &end_time_str));
+        }
+        if let Some(limit) = limit {
+            limit_str = limit.to_string();
+            params.push(("limit", &limit_str));
+        }
+
+        self.client
+            .get_json("/v5/execution/list", &params, true)
+            .await
+    }
+
     /// Get order book
     pub async fn get_order_book(
         &self,
         symbol: &str,
         limit: Option<u32>,
-    ) -> Result<BybitPerpApiResponse<Value>, ExchangeError> {
-        let mut params = vec![("category", "linear"), ("symbol", symbol)];
+    ) -> Result<BybitPerpApiResponse<BybitPerpOrderBook>, ExchangeError> {
+        let mut params = vec![("category", self.category.as_str()), ("symbol", symbol)];
 
         let limit_str;
         if let Some(limit_val) = limit {
@@ -198,13 +443,13 @@ impl<R: RestClient> BybitPerpRestClient<R> {
             .await
     }
 
-    /// Get recent trades
+    /// Get recent trades, newest first
     pub async fn get_recent_trades(
         &self,
         symbol: &str,
         limit: Option<u32>,
-    ) -> Result<BybitPerpApiResponse<Value>, ExchangeError> {
-        let mut params = vec![("category", "linear"), ("symbol", symbol)];
+    ) -> Result<BybitPerpApiResponse<BybitPerpTradeList>, ExchangeError> {
+        let mut params = vec![("category", self.category.as_str()), ("symbol", symbol)];
 
         let limit_str;
         if let Some(limit_val) = limit {
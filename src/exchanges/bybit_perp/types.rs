@@ -1,3 +1,4 @@
+use crate::core::errors::ErrorKind;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -10,6 +11,36 @@ pub struct BybitPerpApiResponse<T> {
     pub result: T,
 }
 
+/// Bybit V5 derivatives category served by the perpetual connector
+///
+/// `Linear` contracts are USDT/USDC-margined; `Inverse` contracts are quoted in USD and
+/// settled in the base coin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BybitPerpCategory {
+    #[default]
+    Linear,
+    Inverse,
+}
+
+impl BybitPerpCategory {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Linear => "linear",
+            Self::Inverse => "inverse",
+        }
+    }
+
+    /// Public WebSocket endpoint for this category
+    pub fn public_ws_url(self, testnet: bool) -> String {
+        let host = if testnet {
+            "stream-testnet.bybit.com"
+        } else {
+            "stream.bybit.com"
+        };
+        format!("wss://{}/v5/public/{}", host, self.as_str())
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BybitPerpExchangeInfo {
     pub category: String,
@@ -32,6 +63,9 @@ pub struct BybitPerpMarket {
     pub lot_size_filter: BybitPerpLotSizeFilter,
     #[serde(rename = "priceFilter")]
     pub price_filter: BybitPerpPriceFilter,
+    /// Minutes between funding settlements
+    #[serde(rename = "fundingInterval", default)]
+    pub funding_interval: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -85,6 +119,17 @@ pub struct BybitPerpAccountList {
     #[serde(rename = "accountType")]
     pub account_type: String,
     pub coin: Vec<BybitPerpCoinBalance>,
+    // Unified account totals, valued in USD
+    #[serde(rename = "totalEquity", default)]
+    pub total_equity: String,
+    #[serde(rename = "totalAvailableBalance", default)]
+    pub total_available_balance: String,
+    #[serde(rename = "totalInitialMargin", default)]
+    pub total_initial_margin: String,
+    #[serde(rename = "totalMaintenanceMargin", default)]
+    pub total_maintenance_margin: String,
+    #[serde(rename = "totalPerpUPL", default)]
+    pub total_perp_upl: String,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -128,6 +173,13 @@ pub struct BybitPerpOrderRequest {
     pub time_in_force: Option<String>,
     #[serde(rename = "stopPrice")]
     pub stop_price: Option<String>,
+    #[serde(rename = "reduceOnly", skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    /// Cancel other orders if needed so the close can trigger; implies reduce-only
+    #[serde(rename = "closeOnTrigger", skip_serializing_if = "Option::is_none")]
+    pub close_on_trigger: Option<bool>,
+    #[serde(rename = "orderLinkId", skip_serializing_if = "Option::is_none")]
+    pub order_link_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -148,6 +200,71 @@ pub struct BybitPerpOrderResponse {
     pub timestamp: i64,
 }
 
+/// Order as returned by `/v5/order/realtime` and `/v5/order/history`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPerpOrder {
+    pub symbol: String,
+    pub order_id: String,
+    pub order_link_id: String,
+    pub side: String,
+    pub order_type: String,
+    pub price: String,
+    pub qty: String,
+    pub cum_exec_qty: String,
+    #[serde(default)]
+    pub avg_price: String,
+    pub order_status: String,
+    pub created_time: String,
+    pub updated_time: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BybitPerpOrderList {
+    pub list: Vec<BybitPerpOrder>,
+    /// Empty on the last page
+    #[serde(rename = "nextPageCursor", default)]
+    pub next_page_cursor: String,
+}
+
+/// Entry of `/v5/execution/list`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPerpExecution {
+    pub symbol: String,
+    pub order_id: String,
+    pub exec_id: String,
+    pub side: String,
+    pub exec_price: String,
+    pub exec_qty: String,
+    pub exec_fee: String,
+    #[serde(default)]
+    pub fee_currency: Option<String>,
+    pub is_maker: bool,
+    pub exec_time: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BybitPerpExecutionList {
+    pub list: Vec<BybitPerpExecution>,
+}
+
+/// Tier of `/v5/market/risk-limit`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPerpRiskLimit {
+    /// Position value the tier ends at
+    pub risk_limit_value: String,
+    /// Maintenance margin rate, in percent
+    pub maintenance_margin: String,
+    pub max_leverage: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BybitPerpRiskLimitList {
+    pub list: Vec<BybitPerpRiskLimit>,
+}
+
 // WebSocket Types for Perpetual Futures
 #[derive(Debug, Deserialize)]
 pub struct BybitPerpWebSocketMessage {
@@ -177,12 +294,29 @@ pub struct BybitPerpTickerData {
     pub turnover_24h: String,
 }
 
+/// Mark price fields of a `tickers` push; deltas carry only the fields that changed
+#[derive(Debug, Deserialize)]
+pub struct BybitPerpMarkPriceData {
+    pub symbol: String,
+    #[serde(rename = "markPrice")]
+    pub mark_price: String,
+    #[serde(rename = "indexPrice")]
+    pub index_price: Option<String>,
+    #[serde(rename = "fundingRate")]
+    pub funding_rate: Option<String>,
+    #[serde(rename = "nextFundingTime")]
+    pub next_funding_time: Option<String>,
+}
+
+/// `data` of an `orderbook.{depth}.{symbol}` push; the timestamp is on the envelope
 #[derive(Debug, Deserialize)]
 pub struct BybitPerpOrderBookData {
+    #[serde(rename = "s")]
     pub symbol: String,
+    #[serde(rename = "b")]
     pub bids: Vec<[String; 2]>,
+    #[serde(rename = "a")]
     pub asks: Vec<[String; 2]>,
-    pub ts: i64,
     pub u: i64,
 }
 
@@ -211,6 +345,22 @@ pub struct BybitPerpKlineData {
     pub turnover: String,
 }
 
+/// Bar pushed on the `kline.{interval}.{symbol}` WebSocket topic
+#[derive(Debug, Deserialize)]
+pub struct BybitPerpWsKline {
+    pub start: i64,
+    pub end: i64,
+    pub interval: String,
+    pub open: String,
+    pub close: String,
+    pub high: String,
+    pub low: String,
+    pub volume: String,
+    pub turnover: String,
+    /// Whether the bar has closed
+    pub confirm: bool,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BybitPerpMarketsResult {
     pub list: Vec<BybitPerpMarket>,
@@ -324,6 +474,39 @@ pub struct BybitPerpTickerResponse {
     #[serde(rename = "retMsg")]
     pub ret_msg: String,
     pub result: BybitPerpTickerResult,
+    /// Server time of the reply in milliseconds
+    #[serde(default)]
+    pub time: i64,
+}
+
+/// Result of `/v5/market/orderbook`
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitPerpOrderBook {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b")]
+    pub bids: Vec<[String; 2]>,
+    #[serde(rename = "a")]
+    pub asks: Vec<[String; 2]>,
+    #[serde(rename = "u")]
+    pub update_id: i64,
+}
+
+/// Entry of `/v5/market/recent-trade`
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitPerpTrade {
+    #[serde(rename = "execId")]
+    pub exec_id: String,
+    pub price: String,
+    pub size: String,
+    /// Taker side
+    pub side: String,
+    pub time: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BybitPerpTradeList {
+    pub list: Vec<BybitPerpTrade>,
 }
 
 // Bybit Perpetual-specific error types following HFT error handling guidelines
@@ -481,3 +664,18 @@ where
         })
     }
 }
+
+/// Classify a Bybit derivatives `retCode` and `retMsg`
+pub fn bybit_perp_error_kind(code: i32, message: &str) -> Option<ErrorKind> {
+    match code {
+        10006 | 10018 => Some(ErrorKind::RateLimited),
+        10003..=10005 => Some(ErrorKind::Unauthorized),
+        10001 if message.contains("symbol") => Some(ErrorKind::InvalidSymbol),
+        110_004 | 110_007 | 110_012 | 110_044 => Some(ErrorKind::InsufficientBalance),
+        110_001 => Some(ErrorKind::OrderNotFound),
+        110_094 => Some(ErrorKind::MinNotional),
+        110_003 => Some(ErrorKind::InvalidPrice),
+        110_072 => Some(ErrorKind::DuplicateOrder),
+        _ => None,
+    }
+}
@@ -0,0 +1,56 @@
+use crate::core::config::ExchangeConfig;
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{ReqwestRest, RestClientBuilder, RestClientConfig, TungsteniteWs};
+use crate::exchanges::coinbase::{
+    codec::CoinbaseCodec, connector::CoinbaseConnector, signer::CoinbaseSigner,
+};
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
+use std::sync::Arc;
+
+fn build_rest(config: &ExchangeConfig) -> Result<ReqwestRest, ExchangeError> {
+    // The sandbox serves static responses for the brokerage endpoints
+    let endpoints = resolve_endpoints(ExchangeId::Coinbase, config)?;
+
+    let rest_config = RestClientConfig::new(endpoints.rest, "coinbase".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
+
+    let mut rest_builder = RestClientBuilder::new(rest_config);
+
+    // Add authentication if credentials are provided
+    if config.has_credentials() {
+        let signer = Arc::new(CoinbaseSigner::new(
+            config.api_key().to_string(),
+            config.secret_key().to_string(),
+        ));
+        rest_builder = rest_builder.with_signer(signer);
+    }
+
+    rest_builder.build()
+}
+
+/// Create a Coinbase connector with REST-only support
+pub fn build_connector(
+    config: ExchangeConfig,
+) -> Result<CoinbaseConnector<ReqwestRest, ()>, ExchangeError> {
+    Ok(CoinbaseConnector::new_without_ws(build_rest(&config)?).with_ws_config(config.ws_config()))
+}
+
+/// Create a Coinbase connector with WebSocket support
+pub fn build_connector_with_websocket(
+    config: ExchangeConfig,
+) -> Result<CoinbaseConnector<ReqwestRest, TungsteniteWs<CoinbaseCodec>>, ExchangeError> {
+    let rest = build_rest(&config)?;
+    let ws_url = resolve_endpoints(ExchangeId::Coinbase, &config)?.ws;
+    let ws = TungsteniteWs::new(ws_url, "coinbase".to_string(), CoinbaseCodec)
+        .with_config(config.ws_config());
+    Ok(CoinbaseConnector::new(rest, ws).with_ws_config(config.ws_config()))
+}
+
+/// Create a Coinbase connector for public market data, ignoring any credentials in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<CoinbaseConnector<ReqwestRest, TungsteniteWs<CoinbaseCodec>>, ExchangeError> {
+    build_connector_with_websocket(config.without_credentials())
+}
@@ -0,0 +1,348 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::WsCodec;
+use crate::core::types::{KlineInterval, SubscriptionType};
+use crate::exchanges::coinbase::types::{
+    CoinbaseCandle, CoinbaseFullMessage, CoinbaseWsEnvelope, CoinbaseWsLevel2, CoinbaseWsTicker,
+    CoinbaseWsTrade,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio_tungstenite::tungstenite::Message;
+
+#[derive(Debug, Clone)]
+pub enum CoinbaseMessage {
+    Tickers {
+        timestamp: i64,
+        tickers: Vec<CoinbaseWsTicker>,
+    },
+    Trades(Vec<CoinbaseWsTrade>),
+    Level2 {
+        sequence_num: i64,
+        books: Vec<CoinbaseWsLevel2>,
+    },
+    Candles(Vec<CoinbaseCandle>),
+    Unknown,
+}
+
+pub struct CoinbaseCodec;
+
+impl CoinbaseCodec {
+    /// Coinbase takes one channel per request, so all streams must share it
+    fn encode_request(
+        request_type: &str,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        let mut channel = None;
+        let mut product_ids = Vec::new();
+        for stream in streams {
+            let (stream_channel, product_id) =
+                stream.as_ref().split_once(':').ok_or_else(|| {
+                    ExchangeError::InvalidParameters(format!(
+                        "Invalid Coinbase stream: {}",
+                        stream.as_ref()
+                    ))
+                })?;
+            if channel.is_some_and(|c| c != stream_channel) {
+                return Err(ExchangeError::InvalidParameters(
+                    "Coinbase streams of one request must share a channel".to_string(),
+                ));
+            }
+            channel = Some(stream_channel);
+            product_ids.push(product_id);
+        }
+
+        let request = json!({
+            "type": request_type,
+            "product_ids": product_ids,
+            "channel": channel.unwrap_or_default(),
+        });
+        Ok(Message::Text(request.to_string()))
+    }
+
+    fn events<T: DeserializeOwned>(events: &[Value], field: &str) -> Result<Vec<T>, ExchangeError> {
+        let mut items = Vec::new();
+        for event in events {
+            if let Some(values) = event.get(field) {
+                let parsed: Vec<T> = serde_json::from_value(values.clone()).map_err(|e| {
+                    ExchangeError::DeserializationError(format!("Failed to parse {}: {}", field, e))
+                })?;
+                items.extend(parsed);
+            }
+        }
+        Ok(items)
+    }
+}
+
+impl WsCodec for CoinbaseCodec {
+    type Message = CoinbaseMessage;
+
+    fn encode_subscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Self::encode_request("subscribe", streams)
+    }
+
+    fn encode_unsubscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Self::encode_request("unsubscribe", streams)
+    }
+
+    fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
+        let Message::Text(text) = message else {
+            return Ok(None);
+        };
+        let value: Value = serde_json::from_str(&text).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse JSON: {}", e))
+        })?;
+        if value.get("type").and_then(Value::as_str) == Some("error") {
+            return Err(ExchangeError::WebSocketError(format!(
+                "Coinbase error: {}",
+                value.get("message").and_then(Value::as_str).unwrap_or("")
+            )));
+        }
+
+        let envelope: CoinbaseWsEnvelope = serde_json::from_value(value).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse message: {}", e))
+        })?;
+        let message = match envelope.channel.as_str() {
+            "ticker" | "ticker_batch" => CoinbaseMessage::Tickers {
+                timestamp: chrono::DateTime::parse_from_rfc3339(&envelope.timestamp).map_or_else(
+                    |_| chrono::Utc::now().timestamp_millis(),
+                    |t| t.timestamp_millis(),
+                ),
+                tickers: Self::events(&envelope.events, "tickers")?,
+            },
+            "market_trades" => CoinbaseMessage::Trades(Self::events(&envelope.events, "trades")?),
+            "l2_data" => CoinbaseMessage::Level2 {
+                sequence_num: envelope.sequence_num,
+                books: serde_json::from_value(Value::Array(envelope.events)).map_err(|e| {
+                    ExchangeError::DeserializationError(format!("Failed to parse level2: {}", e))
+                })?,
+            },
+            "candles" => CoinbaseMessage::Candles(Self::events(&envelope.events, "candles")?),
+            // Subscription acks and heartbeats
+            _ => CoinbaseMessage::Unknown,
+        };
+        Ok(Some(message))
+    }
+}
+
+/// Codec of the Exchange feed's order-level `full` channel; streams are product ids
+pub struct CoinbaseFullCodec;
+
+impl CoinbaseFullCodec {
+    fn encode_request(request_type: &str, streams: &[impl AsRef<str> + Send + Sync]) -> Message {
+        let product_ids: Vec<&str> = streams.iter().map(AsRef::as_ref).collect();
+        let request = json!({
+            "type": request_type,
+            "product_ids": product_ids,
+            "channels": ["full"],
+        });
+        Message::Text(request.to_string())
+    }
+}
+
+impl WsCodec for CoinbaseFullCodec {
+    type Message = CoinbaseFullMessage;
+
+    fn encode_subscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Ok(Self::encode_request("subscribe", streams))
+    }
+
+    fn encode_unsubscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Ok(Self::encode_request("unsubscribe", streams))
+    }
+
+    fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
+        let Message::Text(text) = message else {
+            return Ok(None);
+        };
+        let value: Value = serde_json::from_str(&text).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse JSON: {}", e))
+        })?;
+        if value.get("type").and_then(Value::as_str) == Some("error") {
+            return Err(ExchangeError::WebSocketError(format!(
+                "Coinbase error: {}",
+                value.get("reason").and_then(Value::as_str).unwrap_or("")
+            )));
+        }
+
+        match serde_json::from_value(value) {
+            Ok(CoinbaseFullMessage::Other) => Ok(None),
+            Ok(message) => Ok(Some(message)),
+            Err(e) => Err(ExchangeError::DeserializationError(format!(
+                "Failed to parse full channel message: {}",
+                e
+            ))),
+        }
+    }
+}
+
+/// Create Coinbase stream identifiers, as `channel:product_id`
+///
+/// Coinbase only streams 5 minute candles.
+pub fn create_coinbase_stream_identifiers(
+    product_ids: &[String],
+    subscription_types: &[SubscriptionType],
+) -> Result<Vec<String>, ExchangeError> {
+    let mut streams = Vec::new();
+    for subscription in subscription_types {
+        let channel = match subscription {
+            SubscriptionType::Ticker => "ticker",
+            SubscriptionType::OrderBook { .. } => "level2",
+            SubscriptionType::Trades | SubscriptionType::AggTrades => "market_trades",
+            SubscriptionType::Klines {
+                interval: KlineInterval::Minutes5,
+            } => "candles",
+            SubscriptionType::Klines { interval } => {
+                return Err(ExchangeError::NotSupported(format!(
+                    "Coinbase streams 5m candles only, not {}",
+                    interval
+                )))
+            }
+            // Spot has no mark price
+            SubscriptionType::MarkPrice => continue,
+        };
+        for product_id in product_ids {
+            let stream = format!("{}:{}", channel, product_id);
+            if !streams.contains(&stream) {
+                streams.push(stream);
+            }
+        }
+    }
+    Ok(streams)
+}
+
+/// Split streams into the requests Coinbase takes, one per channel
+pub fn coinbase_request_groups(streams: Vec<String>) -> Vec<Vec<String>> {
+    let mut channels: Vec<(String, Vec<String>)> = Vec::new();
+    for stream in streams {
+        let channel = stream.split(':').next().unwrap_or_default().to_string();
+        match channels.iter_mut().find(|(c, _)| *c == channel) {
+            Some((_, group)) => group.push(stream),
+            None => channels.push((channel, vec![stream])),
+        }
+    }
+    channels.into_iter().map(|(_, group)| group).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encodes_one_channel_per_request() {
+        let message = CoinbaseCodec
+            .encode_subscription(&["level2:BTC-USD", "level2:ETH-USD"])
+            .unwrap();
+        let Message::Text(text) = message else {
+            panic!("expected text");
+        };
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value["channel"], "level2");
+        assert_eq!(value["product_ids"], json!(["BTC-USD", "ETH-USD"]));
+
+        assert!(CoinbaseCodec
+            .encode_subscription(&["level2:BTC-USD", "ticker:BTC-USD"])
+            .is_err());
+        assert_eq!(
+            coinbase_request_groups(vec![
+                "level2:BTC-USD".to_string(),
+                "ticker:BTC-USD".to_string(),
+                "level2:ETH-USD".to_string(),
+            ]),
+            vec![
+                vec!["level2:BTC-USD", "level2:ETH-USD"],
+                vec!["ticker:BTC-USD"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decodes_level2_update() {
+        let text = r#"{"channel":"l2_data","client_id":"","timestamp":"2023-02-09T20:32:50.714964855Z","sequence_num":7,"events":[{"type":"update","product_id":"BTC-USD","updates":[{"side":"bid","event_time":"1970-01-01T00:00:00Z","price_level":"21921.73","new_quantity":"0.06317902"},{"side":"offer","event_time":"1970-01-01T00:00:00Z","price_level":"21921.78","new_quantity":"0"}]}]}"#;
+        let Some(CoinbaseMessage::Level2 {
+            sequence_num,
+            books,
+        }) = CoinbaseCodec
+            .decode_message(Message::Text(text.to_string()))
+            .unwrap()
+        else {
+            panic!("expected level2");
+        };
+        assert_eq!(sequence_num, 7);
+        assert_eq!(books[0].product_id, "BTC-USD");
+        assert_eq!(books[0].updates.len(), 2);
+        assert_eq!(books[0].updates[1].side, "offer");
+    }
+
+    #[test]
+    fn test_full_channel_decodes_into_l3_events() {
+        use crate::core::types::{L3Update, OrderBookL3, OrderSide};
+        use crate::exchanges::coinbase::conversions::convert_coinbase_full_message;
+        use crate::utils::l3_book::L3OrderBook;
+        use rust_decimal::Decimal;
+
+        let Message::Text(text) = CoinbaseFullCodec.encode_subscription(&["BTC-USD"]).unwrap()
+        else {
+            panic!("expected text");
+        };
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value["channels"], json!(["full"]));
+        assert_eq!(value["product_ids"], json!(["BTC-USD"]));
+
+        let messages = [
+            r#"{"type":"subscriptions","channels":[{"name":"full","product_ids":["BTC-USD"]}]}"#,
+            r#"{"type":"received","time":"2024-06-10T01:00:00.000001Z","product_id":"BTC-USD","sequence":11,"order_id":"d50ec984","size":"1.34","price":"502.1","side":"buy","order_type":"limit"}"#,
+            r#"{"type":"open","time":"2024-06-10T01:00:00.000002Z","product_id":"BTC-USD","sequence":12,"order_id":"d50ec984","price":"502.1","remaining_size":"1.34","side":"buy"}"#,
+            r#"{"type":"open","time":"2024-06-10T01:00:00.000003Z","product_id":"BTC-USD","sequence":13,"order_id":"a71b3c02","price":"502.1","remaining_size":"2","side":"buy"}"#,
+            r#"{"type":"match","trade_id":10,"sequence":14,"maker_order_id":"d50ec984","taker_order_id":"132fb6ae","time":"2024-06-10T01:00:01Z","product_id":"BTC-USD","size":"0.34","price":"502.1","side":"buy"}"#,
+            r#"{"type":"change","reason":"modify_order","time":"2024-06-10T01:00:02Z","sequence":15,"order_id":"a71b3c02","side":"buy","product_id":"BTC-USD","old_size":"2","new_size":"1.5","old_price":"502.1","new_price":"502.1"}"#,
+            r#"{"type":"done","time":"2024-06-10T01:00:03Z","product_id":"BTC-USD","sequence":16,"price":"502.1","order_id":"d50ec984","reason":"canceled","side":"buy","remaining_size":"1"}"#,
+        ];
+        let events: Vec<_> = messages
+            .iter()
+            .filter_map(|text| {
+                CoinbaseFullCodec
+                    .decode_message(Message::Text((*text).to_string()))
+                    .unwrap()
+            })
+            .map(|message| convert_coinbase_full_message(&message).unwrap().unwrap())
+            .collect();
+        assert_eq!(events.len(), 6);
+        assert_eq!(events[0].symbol.joined("-"), "BTC-USD");
+        assert!(matches!(events[0].update, L3Update::Unchanged { .. }));
+        let L3Update::Open(order) = &events[1].update else {
+            panic!("expected open");
+        };
+        assert!(matches!(order.side, OrderSide::Buy));
+        assert_eq!(order.quantity.value(), Decimal::new(134, 2));
+        assert_eq!(order.timestamp, 1_717_981_200_000);
+
+        // Coinbase sequences every message, so the book applies them without gaps
+        let mut book = L3OrderBook::from_snapshot(OrderBookL3 {
+            symbol: events[0].symbol.clone(),
+            bids: Vec::new(),
+            asks: Vec::new(),
+            sequence: 10,
+        });
+        for event in &events {
+            book.apply(event).unwrap();
+        }
+        assert_eq!(book.sequence(), 16);
+        assert!(book.order("d50ec984").is_none());
+        assert_eq!(
+            book.order("a71b3c02").unwrap().quantity.value(),
+            Decimal::new(15, 1)
+        );
+    }
+}
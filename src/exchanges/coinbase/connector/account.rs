@@ -0,0 +1,58 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::RestClient,
+    traits::AccountInfo,
+    types::{Balance, Position},
+};
+use crate::exchanges::coinbase::{conversions::convert_coinbase_balance, rest::CoinbaseRestClient};
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Account implementation for Coinbase
+pub struct Account<R: RestClient> {
+    rest: CoinbaseRestClient<R>,
+}
+
+impl<R: RestClient> Account<R> {
+    /// Create a new account manager
+    pub fn new(rest: &R) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            rest: CoinbaseRestClient::new(rest.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    #[instrument(skip(self), fields(exchange = "coinbase"))]
+    async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let mut balances = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = self.rest.get_accounts(cursor.as_deref()).await?;
+            for account in &page.accounts {
+                let balance = convert_coinbase_balance(account)?;
+                if !balance.free.value().is_zero() || !balance.locked.value().is_zero() {
+                    balances.push(balance);
+                }
+            }
+            if !page.has_next || page.cursor.is_empty() {
+                return Ok(balances);
+            }
+            cursor = Some(page.cursor);
+        }
+    }
+
+    #[instrument(skip(self), fields(exchange = "coinbase"))]
+    async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
+        // Spot only, so there are no positions
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,337 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::{ReconnectWs, RestClient, TungsteniteWs, WsConfig, WsSession},
+    streaming::{SharedHub, StreamRouter},
+    traits::{MarketSnapshotSource, RestMarketData, StreamingMarketData},
+    types::{
+        Kline, KlineInterval, Market, MarketDataType, OrderBook, OrderBookL3Event,
+        SubscriptionType, Symbol, Ticker, Trade, WebSocketConfig,
+    },
+};
+use crate::exchanges::coinbase::{
+    codec::{
+        coinbase_request_groups, create_coinbase_stream_identifiers, CoinbaseCodec,
+        CoinbaseFullCodec, CoinbaseMessage,
+    },
+    conversions::{
+        convert_coinbase_candle, convert_coinbase_full_message, convert_coinbase_market,
+        convert_coinbase_product_book, convert_coinbase_product_stats, convert_coinbase_ws_level2,
+        convert_coinbase_ws_ticker, convert_coinbase_ws_trade, convert_product_id_to_symbol,
+        convert_symbol_to_product_id, kline_interval_to_coinbase_granularity,
+    },
+    rest::CoinbaseRestClient,
+};
+use crate::exchanges::factory::ExchangeId;
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+pub const COINBASE_WS_URL: &str = ExchangeId::Coinbase.endpoints().mainnet.ws;
+
+/// Coinbase Exchange feed; Advanced Trade has no order-level channel
+pub const COINBASE_FEED_URL: &str = "wss://ws-feed.exchange.coinbase.com";
+
+/// Most candles Coinbase returns per request
+const MAX_CANDLES: u32 = 350;
+
+/// Routes Coinbase updates, subscribing one channel per request
+struct CoinbaseRouter;
+
+impl StreamRouter for CoinbaseRouter {
+    type Codec = CoinbaseCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        Ok(create_coinbase_stream_identifiers(
+            &[convert_symbol_to_product_id(symbol)?],
+            std::slice::from_ref(subscription),
+        )?
+        .pop())
+    }
+
+    fn request_groups(&self, streams: Vec<String>) -> Vec<Vec<String>> {
+        coinbase_request_groups(streams)
+    }
+
+    fn convert(&self, message: CoinbaseMessage) -> Vec<MarketDataType> {
+        convert_coinbase_message_to_market_data(message).unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Dropping malformed Coinbase message");
+            Vec::new()
+        })
+    }
+}
+
+/// Market data implementation for Coinbase
+pub struct MarketData<R: RestClient, W = ()> {
+    rest: CoinbaseRestClient<R>,
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
+    ws: Option<W>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
+}
+
+impl<R: RestClient + Clone, W> MarketData<R, W> {
+    /// Create a new market data source
+    pub fn new(rest: &R, ws: Option<W>) -> Self {
+        Self {
+            rest: CoinbaseRestClient::new(rest.clone()),
+            ws,
+            hub: SharedHub::new(),
+            ws_config: WsConfig::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> RestMarketData for MarketData<R, W> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.rest
+            .get_products()
+            .await?
+            .products
+            .into_iter()
+            .map(convert_coinbase_market)
+            .collect()
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        let granularity = kline_interval_to_coinbase_granularity(interval).ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!(
+                "Coinbase does not offer {} candles",
+                interval
+            ))
+        })?;
+
+        // Coinbase takes a time range in seconds rather than a count
+        let limit = limit.unwrap_or(MAX_CANDLES).min(MAX_CANDLES);
+        let span = interval.duration_millis() / 1000 * i64::from(limit);
+        let (start, end) = match (start_time, end_time) {
+            (Some(start), Some(end)) => (start / 1000, (end / 1000).min(start / 1000 + span)),
+            (Some(start), None) => (start / 1000, start / 1000 + span),
+            (None, Some(end)) => (end / 1000 - span, end / 1000),
+            (None, None) => {
+                let now = chrono::Utc::now().timestamp();
+                (now - span, now)
+            }
+        };
+
+        let product_id = convert_symbol_to_product_id(&symbol)?;
+        let mut candles = self
+            .rest
+            .get_candles(&product_id, granularity, start, end)
+            .await?
+            .candles;
+        // Newest first on the wire
+        candles.reverse();
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut klines = candles
+            .iter()
+            .map(|candle| {
+                convert_coinbase_candle(candle, &symbol, interval, false).map(|mut kline| {
+                    kline.final_bar = kline.close_time < now;
+                    kline
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let excess = klines.len().saturating_sub(limit as usize);
+        klines.drain(..excess);
+        Ok(klines)
+    }
+}
+
+/// Trades fetched when the caller sets no limit
+const DEFAULT_TRADES_LIMIT: u32 = 100;
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> MarketSnapshotSource for MarketData<R, W> {
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let product = self
+            .rest
+            .get_product(&convert_symbol_to_product_id(&symbol)?)
+            .await?;
+        convert_coinbase_product_stats(&product, chrono::Utc::now().timestamp_millis())
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let book = self
+            .rest
+            .get_product_book(&convert_symbol_to_product_id(&symbol)?, depth)
+            .await?;
+        Ok(convert_coinbase_product_book(&book.pricebook)?.truncated(depth))
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let response = self
+            .rest
+            .get_market_trades(
+                &convert_symbol_to_product_id(&symbol)?,
+                limit.unwrap_or(DEFAULT_TRADES_LIMIT),
+            )
+            .await?;
+        // Listed newest first
+        response
+            .trades
+            .iter()
+            .rev()
+            .map(convert_coinbase_ws_trade)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: WsSession<CoinbaseCodec> + Send + Sync> StreamingMarketData
+    for MarketData<R, W>
+{
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(
+                        COINBASE_WS_URL.to_string(),
+                        "coinbase".to_string(),
+                        CoinbaseCodec,
+                    )
+                    .with_config(self.ws_config.clone())
+                },
+                CoinbaseRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        COINBASE_WS_URL.to_string()
+    }
+
+    fn supports_order_book_l3(&self) -> bool {
+        true
+    }
+
+    /// Stream the Exchange feed's `full` channel
+    ///
+    /// Events carry Coinbase's per-product sequence, so an `L3OrderBook` seeded from the
+    /// Exchange `/products/{id}/book?level=3` snapshot applies them as is. A reconnect
+    /// skips sequences, which the book reports as a `SequenceGap`.
+    async fn subscribe_order_book_l3(
+        &self,
+        symbols: Vec<Symbol>,
+    ) -> Result<mpsc::Receiver<OrderBookL3Event>, ExchangeError> {
+        let product_ids = symbols
+            .iter()
+            .map(convert_symbol_to_product_id)
+            .collect::<Result<Vec<_>, _>>()?;
+        let ws_session = TungsteniteWs::new(
+            COINBASE_FEED_URL.to_string(),
+            "coinbase".to_string(),
+            CoinbaseFullCodec,
+        )
+        .with_config(self.ws_config.clone());
+        let mut reconnect_ws = ReconnectWs::new(ws_session)
+            .with_auto_resubscribe(true)
+            .with_max_reconnect_attempts(u32::MAX);
+
+        reconnect_ws.connect().await.map_err(|e| {
+            ExchangeError::Other(format!(
+                "Failed to connect to WebSocket for symbols: {:?}, error: {}",
+                product_ids, e
+            ))
+        })?;
+        reconnect_ws.subscribe(&product_ids).await.map_err(|e| {
+            ExchangeError::Other(format!(
+                "Failed to subscribe to streams: {:?}, error: {}",
+                product_ids, e
+            ))
+        })?;
+
+        let (tx, rx) = mpsc::channel(1000);
+        tokio::spawn(async move {
+            while let Some(result) = reconnect_ws.next_message().await {
+                match result.and_then(|message| convert_coinbase_full_message(&message)) {
+                    Ok(Some(event)) => {
+                        if tx.send(event).await.is_err() {
+                            return; // Receiver dropped
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => tracing::warn!(error = %e, "Coinbase full channel error"),
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
+
+/// Convert `CoinbaseMessage` to `MarketDataType`s
+fn convert_coinbase_message_to_market_data(
+    message: CoinbaseMessage,
+) -> Result<Vec<MarketDataType>, ExchangeError> {
+    match message {
+        CoinbaseMessage::Tickers { timestamp, tickers } => tickers
+            .iter()
+            .map(|ticker| convert_coinbase_ws_ticker(ticker, timestamp).map(MarketDataType::Ticker))
+            .collect(),
+        CoinbaseMessage::Trades(trades) => trades
+            .iter()
+            .map(|trade| convert_coinbase_ws_trade(trade).map(MarketDataType::Trade))
+            .collect(),
+        CoinbaseMessage::Level2 {
+            sequence_num,
+            books,
+        } => books
+            .iter()
+            .map(|book| {
+                convert_coinbase_ws_level2(book, sequence_num).map(MarketDataType::OrderBook)
+            })
+            .collect(),
+        // The candles channel updates the current 5 minute bar
+        CoinbaseMessage::Candles(candles) => candles
+            .iter()
+            .map(|candle| {
+                let symbol =
+                    convert_product_id_to_symbol(candle.product_id.as_deref().unwrap_or_default());
+                convert_coinbase_candle(candle, &symbol, KlineInterval::Minutes5, false)
+                    .map(MarketDataType::Kline)
+            })
+            .collect(),
+        CoinbaseMessage::Unknown => Ok(Vec::new()),
+    }
+}
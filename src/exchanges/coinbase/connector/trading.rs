@@ -0,0 +1,154 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::RestClient,
+    traits::{OrderHistorySource, OrderPlacer},
+    types::{OrderPage, OrderRequest, OrderResponse, Symbol},
+};
+use crate::exchanges::coinbase::{
+    conversions::{
+        convert_coinbase_historical_order, convert_order_configuration, convert_order_side,
+        convert_product_id_to_symbol, convert_symbol_to_product_id,
+    },
+    rest::CoinbaseRestClient,
+    types::CoinbaseOrderRequest,
+};
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Trading implementation for Coinbase
+pub struct Trading<R: RestClient> {
+    rest: CoinbaseRestClient<R>,
+}
+
+impl<R: RestClient> Trading<R> {
+    /// Create a new trading engine
+    pub fn new(rest: &R) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            rest: CoinbaseRestClient::new(rest.clone()),
+        }
+    }
+}
+
+/// Unique client order id; Coinbase requires one on every order
+///
+/// The caller's id is sent as is, otherwise one is made up. Coinbase cancels by order id
+/// only, so there is no `cancel_order_by_client_id`.
+fn client_order_id(order: &OrderRequest) -> String {
+    order.client_order_id.clone().unwrap_or_else(|| {
+        format!(
+            "lotusx-{}-{:08x}",
+            chrono::Utc::now().timestamp_millis(),
+            rand::random::<u32>()
+        )
+    })
+}
+
+#[async_trait]
+impl<R: RestClient> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    #[instrument(skip(self), fields(exchange = "coinbase"))]
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let request = CoinbaseOrderRequest {
+            client_order_id: client_order_id(&order),
+            product_id: convert_symbol_to_product_id(&order.symbol)?,
+            side: convert_order_side(&order.side),
+            order_configuration: convert_order_configuration(&order)?,
+        };
+
+        let response = self.rest.place_order(&request).await?;
+        let Some(accepted) = response.success_response.filter(|_| response.success) else {
+            let error = response.error_response;
+            return Err(ExchangeError::InvalidParameters(format!(
+                "Coinbase rejected order: {}",
+                error.map_or_else(
+                    || "no reason given".to_string(),
+                    |e| format!("{} {} {}", e.error, e.message, e.error_details)
+                        .trim()
+                        .to_string()
+                )
+            )));
+        };
+
+        Ok(OrderResponse {
+            order_id: accepted.order_id,
+            client_order_id: accepted.client_order_id,
+            symbol: convert_product_id_to_symbol(&accepted.product_id),
+            side: order.side,
+            order_type: order.order_type,
+            quantity: order.quantity,
+            price: order.price,
+            status: "PENDING".to_string(),
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            extensions: None,
+        })
+    }
+
+    #[instrument(skip(self), fields(exchange = "coinbase", order_id = %order_id))]
+    async fn cancel_order(&self, _symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        // Order ids are unique across products
+        let response = self.rest.cancel_orders(&[order_id.as_str()]).await?;
+        match response
+            .results
+            .into_iter()
+            .find(|r| r.order_id == order_id)
+        {
+            Some(result) if result.success => Ok(()),
+            Some(result) => Err(ExchangeError::InvalidParameters(format!(
+                "Coinbase failed to cancel order {}: {}",
+                order_id, result.failure_reason
+            ))),
+            None => Err(ExchangeError::InvalidResponseFormat(format!(
+                "Coinbase cancel response missing order {}",
+                order_id
+            ))),
+        }
+    }
+}
+
+/// RFC 3339 form of a millisecond timestamp, as Coinbase takes time filters
+fn rfc3339(millis: i64) -> Result<String, ExchangeError> {
+    chrono::DateTime::from_timestamp_millis(millis)
+        .map(|time| time.to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+        .ok_or_else(|| ExchangeError::InvalidParameters(format!("Invalid timestamp {}", millis)))
+}
+
+#[async_trait]
+impl<R: RestClient> OrderHistorySource for Trading<R> {
+    /// Pages run newest first; the cursor is the one Coinbase returns while `has_next`
+    #[instrument(skip(self), fields(exchange = "coinbase"))]
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        let start_date = start_time.map(rfc3339).transpose()?;
+        let end_date = end_time.map(rfc3339).transpose()?;
+        let page = self
+            .rest
+            .get_historical_orders(
+                &convert_symbol_to_product_id(&symbol)?,
+                start_date.as_deref(),
+                end_date.as_deref(),
+                cursor.as_deref(),
+            )
+            .await?;
+
+        let orders = page
+            .orders
+            .iter()
+            .map(convert_coinbase_historical_order)
+            .collect::<Result<_, _>>()?;
+        Ok(OrderPage {
+            orders,
+            next_cursor: Some(page.cursor).filter(|cursor| page.has_next && !cursor.is_empty()),
+        })
+    }
+}
@@ -0,0 +1,581 @@
+use super::types as coinbase_types;
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
+use crate::core::types::{
+    conversion, Balance, ContractStyle, Kline, KlineInterval, L3Order, L3Update, Market,
+    MarketStatus, Order, OrderBook, OrderBookEntry, OrderBookL3Event, OrderRequest, OrderSide,
+    OrderStatus, OrderType, Price, Quantity, Symbol, Ticker, TimeInForce, Trade, Volume,
+};
+use crate::exchanges::factory::ExchangeId;
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+
+/// Coinbase symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::Coinbase.symbol_convention();
+
+/// Convert symbol to Coinbase product id, e.g. `BTC-USD`
+pub fn convert_symbol_to_product_id(symbol: &Symbol) -> Result<String, ExchangeError> {
+    SYMBOLS.try_to_exchange_symbol(symbol)
+}
+
+/// Convert Coinbase product id to symbol
+pub fn convert_product_id_to_symbol(product_id: &str) -> Symbol {
+    SYMBOLS.from_exchange_symbol(product_id)
+}
+
+/// Decimal places of an increment such as `0.00000001`
+fn increment_precision(increment: &str) -> Result<i32, ExchangeError> {
+    let increment = conversion::try_string_to_decimal(increment)?;
+    Ok(i32::try_from(increment.normalize().scale()).unwrap_or(i32::MAX))
+}
+
+/// Convert coinbase product to core market type
+pub fn convert_coinbase_market(
+    product: coinbase_types::CoinbaseProduct,
+) -> Result<Market, ExchangeError> {
+    let status = if product.trading_disabled {
+        MarketStatus::Halted
+    } else {
+        MarketStatus::from_venue_str(&product.status)
+    };
+
+    Ok(Market {
+        symbol: Symbol::new(product.base_currency_id, product.quote_currency_id)
+            .unwrap_or_else(|_| convert_product_id_to_symbol(&product.product_id)),
+        status,
+        base_precision: increment_precision(&product.base_increment)?,
+        quote_precision: increment_precision(&product.quote_increment)?,
+        min_qty: Some(conversion::try_string_to_quantity(&product.base_min_size)?),
+        max_qty: Some(conversion::try_string_to_quantity(&product.base_max_size)?),
+        min_price: Some(conversion::try_string_to_price(&product.quote_increment)?),
+        max_price: None,
+        contract_style: ContractStyle::Spot,
+        contract_size: None,
+        extensions: None,
+    })
+}
+
+/// Coinbase candle granularity, `None` for intervals Coinbase does not offer
+pub const fn kline_interval_to_coinbase_granularity(
+    interval: KlineInterval,
+) -> Option<&'static str> {
+    match interval {
+        KlineInterval::Minutes1 => Some("ONE_MINUTE"),
+        KlineInterval::Minutes5 => Some("FIVE_MINUTE"),
+        KlineInterval::Minutes15 => Some("FIFTEEN_MINUTE"),
+        KlineInterval::Minutes30 => Some("THIRTY_MINUTE"),
+        KlineInterval::Hours1 => Some("ONE_HOUR"),
+        KlineInterval::Hours2 => Some("TWO_HOUR"),
+        KlineInterval::Hours4 => Some("FOUR_HOUR"),
+        KlineInterval::Hours6 => Some("SIX_HOUR"),
+        KlineInterval::Days1 => Some("ONE_DAY"),
+        _ => None,
+    }
+}
+
+/// Convert coinbase candle to core kline type
+pub fn convert_coinbase_candle(
+    candle: &coinbase_types::CoinbaseCandle,
+    symbol: &Symbol,
+    interval: KlineInterval,
+    final_bar: bool,
+) -> Result<Kline, ExchangeError> {
+    let open_time = candle.start.parse::<i64>().map_err(|e| {
+        ExchangeError::DeserializationError(format!("Invalid candle start {}: {}", candle.start, e))
+    })? * 1000;
+
+    Ok(Kline {
+        symbol: symbol.clone(),
+        open_time,
+        close_time: open_time + interval.duration_millis() - 1,
+        interval: interval.to_string(),
+        open_price: conversion::try_string_to_price(&candle.open)?,
+        high_price: conversion::try_string_to_price(&candle.high)?,
+        low_price: conversion::try_string_to_price(&candle.low)?,
+        close_price: conversion::try_string_to_price(&candle.close)?,
+        volume: conversion::try_string_to_volume(&candle.volume)?,
+        number_of_trades: 0, // Not reported by Coinbase
+        final_bar,
+    })
+}
+
+/// Convert coinbase account to core balance type
+pub fn convert_coinbase_balance(
+    account: &coinbase_types::CoinbaseAccount,
+) -> Result<Balance, ExchangeError> {
+    Ok(Balance {
+        asset: account.currency.clone(),
+        free: conversion::try_string_to_quantity(&account.available_balance.value)?,
+        locked: conversion::try_string_to_quantity(&account.hold.value)?,
+    })
+}
+
+/// Convert order side to coinbase format
+pub fn convert_order_side(side: &OrderSide) -> String {
+    match side {
+        OrderSide::Buy => "BUY".to_string(),
+        OrderSide::Sell => "SELL".to_string(),
+    }
+}
+
+/// Build the `order_configuration` object for a core order request
+pub fn convert_order_configuration(order: &OrderRequest) -> Result<Value, ExchangeError> {
+    let base_size = order.quantity.to_string();
+    let limit_price = || {
+        order.price.map(|p| p.to_string()).ok_or_else(|| {
+            ExchangeError::InvalidParameters("Limit orders require a price".to_string())
+        })
+    };
+
+    let configuration = match order.order_type {
+        OrderType::Market => json!({ "market_market_ioc": { "base_size": base_size } }),
+        OrderType::Limit => match order.time_in_force {
+            None | Some(TimeInForce::GTC) => json!({
+                "limit_limit_gtc": {
+                    "base_size": base_size,
+                    "limit_price": limit_price()?,
+                    "post_only": false,
+                }
+            }),
+            Some(TimeInForce::IOC) => json!({
+                "sor_limit_ioc": { "base_size": base_size, "limit_price": limit_price()? }
+            }),
+            Some(TimeInForce::FOK) => json!({
+                "limit_limit_fok": { "base_size": base_size, "limit_price": limit_price()? }
+            }),
+        },
+        OrderType::StopLossLimit | OrderType::TakeProfitLimit => {
+            let stop_price = order.stop_price.ok_or_else(|| {
+                ExchangeError::InvalidParameters("Stop orders require a stop price".to_string())
+            })?;
+            // A stop loss triggers against the position, a take profit with it
+            let falling = matches!(order.order_type, OrderType::StopLossLimit)
+                == matches!(order.side, OrderSide::Sell);
+            json!({
+                "stop_limit_stop_limit_gtc": {
+                    "base_size": base_size,
+                    "limit_price": limit_price()?,
+                    "stop_price": stop_price.to_string(),
+                    "stop_direction": if falling {
+                        "STOP_DIRECTION_STOP_DOWN"
+                    } else {
+                        "STOP_DIRECTION_STOP_UP"
+                    },
+                }
+            })
+        }
+        OrderType::StopLoss | OrderType::TakeProfit => {
+            return Err(ExchangeError::NotSupported(
+                "Coinbase only supports stop-limit orders".to_string(),
+            ))
+        }
+    };
+    Ok(configuration)
+}
+
+/// Convert a finished order of `/orders/historical/batch` to core Order type
+///
+/// Size and limit price live in the order configuration; orders sized in the quote
+/// currency report their filled size as the quantity.
+pub fn convert_coinbase_historical_order(
+    order: &coinbase_types::CoinbaseHistoricalOrder,
+) -> Result<Order, ExchangeError> {
+    let configuration = order
+        .order_configuration
+        .as_object()
+        .and_then(|kinds| kinds.values().next());
+    let config_decimal = |field: &str| {
+        configuration
+            .and_then(|config| config.get(field))
+            .and_then(Value::as_str)
+            .map(conversion::try_string_to_decimal)
+            .transpose()
+    };
+    let filled = conversion::try_string_to_decimal(&order.filled_size)?;
+    let average_price = conversion::try_string_to_decimal(&order.average_filled_price)?;
+    let created_time = parse_rfc3339_millis(&order.created_time)?;
+
+    Ok(Order {
+        order_id: order.order_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        symbol: convert_product_id_to_symbol(&order.product_id),
+        side: if order.side == "SELL" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        order_type: match order.order_type.as_str() {
+            "LIMIT" => OrderType::Limit,
+            "STOP_LIMIT" => OrderType::StopLossLimit,
+            "STOP" => OrderType::StopLoss,
+            _ => OrderType::Market,
+        },
+        status: match order.status.as_str() {
+            "FAILED" => OrderStatus::Rejected,
+            "PENDING" | "QUEUED" | "CANCEL_QUEUED" => OrderStatus::New,
+            status => OrderStatus::from_venue(status),
+        },
+        price: config_decimal("limit_price")?.map(Price::new),
+        quantity: Quantity::new(config_decimal("base_size")?.unwrap_or(filled)),
+        filled_quantity: Quantity::new(filled),
+        average_price: (!average_price.is_zero()).then(|| Price::new(average_price)),
+        created_time,
+        updated_time: order
+            .last_fill_time
+            .as_deref()
+            .map(parse_rfc3339_millis)
+            .transpose()?
+            .unwrap_or(created_time),
+    })
+}
+
+fn parse_rfc3339_millis(time: &str) -> Result<i64, ExchangeError> {
+    chrono::DateTime::parse_from_rfc3339(time)
+        .map(|t| t.timestamp_millis())
+        .map_err(|e| ExchangeError::DeserializationError(format!("Invalid time {}: {}", time, e)))
+}
+
+/// Convert coinbase WebSocket ticker to core ticker type
+pub fn convert_coinbase_ws_ticker(
+    ticker: &coinbase_types::CoinbaseWsTicker,
+    timestamp: i64,
+) -> Result<Ticker, ExchangeError> {
+    let price = conversion::try_string_to_price(&ticker.price)?;
+    let price_change_percent = conversion::try_string_to_decimal(&ticker.price_percent_chg_24_h)?;
+    let price_change = price_change_from_percent(price.value(), price_change_percent);
+
+    Ok(Ticker {
+        symbol: convert_product_id_to_symbol(&ticker.product_id),
+        price,
+        price_change: Price::new(price_change),
+        price_change_percent,
+        high_price: conversion::try_string_to_price(&ticker.high_24_h)?,
+        low_price: conversion::try_string_to_price(&ticker.low_24_h)?,
+        volume: conversion::try_string_to_volume(&ticker.volume_24_h)?,
+        quote_volume: Volume::new(Decimal::ZERO), // Not reported by Coinbase
+        open_time: timestamp - 24 * 60 * 60 * 1000,
+        close_time: timestamp,
+        count: 0,
+    })
+}
+
+/// change = price - open, where price = open * (1 + percent / 100)
+fn price_change_from_percent(price: Decimal, percent: Decimal) -> Decimal {
+    let denominator = Decimal::ONE_HUNDRED + percent;
+    if denominator.is_zero() {
+        Decimal::ZERO
+    } else {
+        price * percent / denominator
+    }
+}
+
+/// Convert coinbase product statistics to core ticker type
+///
+/// Coinbase reports no 24h high, low or trade count over REST, so those are zero.
+pub fn convert_coinbase_product_stats(
+    product: &coinbase_types::CoinbaseProductStats,
+    timestamp: i64,
+) -> Result<Ticker, ExchangeError> {
+    let price = conversion::try_string_to_price(&product.price)?;
+    let price_change_percent =
+        conversion::try_string_to_decimal(&product.price_percentage_change_24h)?;
+
+    Ok(Ticker {
+        symbol: convert_product_id_to_symbol(&product.product_id),
+        price,
+        price_change: Price::new(price_change_from_percent(
+            price.value(),
+            price_change_percent,
+        )),
+        price_change_percent,
+        high_price: Price::ZERO,
+        low_price: Price::ZERO,
+        volume: conversion::try_string_to_volume(&product.volume_24h)?,
+        quote_volume: product
+            .approximate_quote_24h_volume
+            .as_deref()
+            .map(conversion::try_string_to_volume)
+            .transpose()?
+            .unwrap_or(Volume::ZERO),
+        open_time: timestamp - 24 * 60 * 60 * 1000,
+        close_time: timestamp,
+        count: 0,
+    })
+}
+
+/// Convert coinbase product book to core order book type
+pub fn convert_coinbase_product_book(
+    book: &coinbase_types::CoinbasePriceBook,
+) -> Result<OrderBook, ExchangeError> {
+    let convert_side = |levels: &[coinbase_types::CoinbaseBookLevel]| {
+        levels
+            .iter()
+            .map(|level| {
+                Ok(OrderBookEntry {
+                    price: conversion::try_string_to_price(&level.price)?,
+                    quantity: conversion::try_string_to_quantity(&level.size)?,
+                })
+            })
+            .collect::<Result<Vec<_>, ExchangeError>>()
+    };
+
+    Ok(OrderBook {
+        symbol: convert_product_id_to_symbol(&book.product_id),
+        bids: convert_side(&book.bids)?,
+        asks: convert_side(&book.asks)?,
+        last_update_id: 0, // Not sequenced over REST
+    })
+}
+
+/// Convert coinbase WebSocket trade to core trade type
+pub fn convert_coinbase_ws_trade(
+    trade: &coinbase_types::CoinbaseWsTrade,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: convert_product_id_to_symbol(&trade.product_id),
+        id: trade.trade_id.parse().map_err(|e| {
+            ExchangeError::DeserializationError(format!(
+                "Invalid trade id {}: {}",
+                trade.trade_id, e
+            ))
+        })?,
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: conversion::try_string_to_quantity(&trade.size)?,
+        time: parse_rfc3339_millis(&trade.time)?,
+        is_buyer_maker: trade.side == "SELL",
+    })
+}
+
+/// Convert coinbase level2 event to core order book type
+///
+/// Snapshots carry the whole book; updates carry changed levels, with zero quantity
+/// for removed ones.
+pub fn convert_coinbase_ws_level2(
+    level2: &coinbase_types::CoinbaseWsLevel2,
+    sequence_num: i64,
+) -> Result<OrderBook, ExchangeError> {
+    let mut bids = Vec::new();
+    let mut asks = Vec::new();
+    for level in &level2.updates {
+        let entry = OrderBookEntry {
+            price: conversion::try_string_to_price(&level.price_level)?,
+            quantity: conversion::try_string_to_quantity(&level.new_quantity)?,
+        };
+        if level.side == "bid" {
+            bids.push(entry);
+        } else {
+            asks.push(entry);
+        }
+    }
+
+    Ok(OrderBook {
+        symbol: convert_product_id_to_symbol(&level2.product_id),
+        bids,
+        asks,
+        last_update_id: sequence_num,
+    })
+}
+
+/// Convert a `full` channel message to an L3 event, `None` for unsequenced messages
+pub fn convert_coinbase_full_message(
+    message: &coinbase_types::CoinbaseFullMessage,
+) -> Result<Option<OrderBookL3Event>, ExchangeError> {
+    use coinbase_types::CoinbaseFullMessage;
+
+    let (product_id, sequence, update) = match message {
+        CoinbaseFullMessage::Received {
+            product_id,
+            sequence,
+            order_id,
+        } => (
+            product_id,
+            sequence,
+            L3Update::Unchanged {
+                order_id: order_id.clone(),
+            },
+        ),
+        CoinbaseFullMessage::Open {
+            product_id,
+            sequence,
+            order_id,
+            side,
+            price,
+            remaining_size,
+            time,
+        } => (
+            product_id,
+            sequence,
+            L3Update::Open(L3Order {
+                order_id: order_id.clone(),
+                side: if side == "sell" {
+                    OrderSide::Sell
+                } else {
+                    OrderSide::Buy
+                },
+                price: conversion::try_string_to_price(price)?,
+                quantity: conversion::try_string_to_quantity(remaining_size)?,
+                timestamp: parse_rfc3339_millis(time)?,
+            }),
+        ),
+        CoinbaseFullMessage::Change {
+            product_id,
+            sequence,
+            order_id,
+            new_size,
+        } => {
+            let order_id = order_id.clone();
+            let update = match new_size {
+                Some(size) => L3Update::Change {
+                    order_id,
+                    quantity: conversion::try_string_to_quantity(size)?,
+                },
+                // Funds of a market order, which never rests
+                None => L3Update::Unchanged { order_id },
+            };
+            (product_id, sequence, update)
+        }
+        CoinbaseFullMessage::Match {
+            product_id,
+            sequence,
+            maker_order_id,
+            size,
+        } => (
+            product_id,
+            sequence,
+            L3Update::Match {
+                order_id: maker_order_id.clone(),
+                quantity: conversion::try_string_to_quantity(size)?,
+            },
+        ),
+        CoinbaseFullMessage::Done {
+            product_id,
+            sequence,
+            order_id,
+        } => (
+            product_id,
+            sequence,
+            L3Update::Done {
+                order_id: order_id.clone(),
+            },
+        ),
+        CoinbaseFullMessage::Other => return Ok(None),
+    };
+
+    Ok(Some(OrderBookL3Event {
+        symbol: convert_product_id_to_symbol(product_id),
+        sequence: *sequence,
+        update,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Quantity;
+
+    #[test]
+    fn test_convert_product() {
+        let product = coinbase_types::CoinbaseProduct {
+            product_id: "BTC-USD".to_string(),
+            base_currency_id: "BTC".to_string(),
+            quote_currency_id: "USD".to_string(),
+            status: "online".to_string(),
+            trading_disabled: false,
+            base_increment: "0.00000001".to_string(),
+            quote_increment: "0.01".to_string(),
+            base_min_size: "0.00000001".to_string(),
+            base_max_size: "3400".to_string(),
+            product_type: "SPOT".to_string(),
+        };
+
+        let market = convert_coinbase_market(product).unwrap();
+        assert_eq!(market.symbol.joined("-"), "BTC-USD");
+        assert!(market.status.is_trading());
+        assert_eq!(market.base_precision, 8);
+        assert_eq!(market.quote_precision, 2);
+    }
+
+    #[test]
+    fn test_order_configuration() {
+        let mut order = OrderRequest {
+            symbol: Symbol::new("BTC", "USD").unwrap(),
+            side: OrderSide::Sell,
+            order_type: OrderType::StopLossLimit,
+            quantity: Quantity::new(Decimal::new(1, 2)),
+            price: Some(Price::new(Decimal::from(59_000))),
+            time_in_force: None,
+            stop_price: Some(Price::new(Decimal::from(60_000))),
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        };
+        let config = convert_order_configuration(&order).unwrap();
+        assert_eq!(
+            config["stop_limit_stop_limit_gtc"]["stop_direction"],
+            "STOP_DIRECTION_STOP_DOWN"
+        );
+        assert_eq!(config["stop_limit_stop_limit_gtc"]["base_size"], "0.01");
+
+        order.order_type = OrderType::Limit;
+        order.time_in_force = Some(TimeInForce::IOC);
+        let config = convert_order_configuration(&order).unwrap();
+        assert_eq!(config["sor_limit_ioc"]["limit_price"], "59000");
+
+        order.order_type = OrderType::StopLoss;
+        assert!(convert_order_configuration(&order).is_err());
+    }
+
+    #[test]
+    fn test_convert_historical_orders() {
+        let page: coinbase_types::CoinbaseHistoricalOrders = serde_json::from_str(
+            r#"{
+                "orders": [
+                    {"order_id": "0000-000001", "product_id": "BTC-USD", "user_id": "u1",
+                     "order_configuration": {"limit_limit_gtc": {"base_size": "0.5",
+                         "limit_price": "60000", "post_only": false}},
+                     "side": "SELL", "client_order_id": "grid-7", "status": "CANCELLED",
+                     "time_in_force": "GOOD_UNTIL_CANCELLED",
+                     "created_time": "2024-06-10T01:00:00.000Z", "filled_size": "0.2",
+                     "average_filled_price": "60000", "order_type": "LIMIT",
+                     "last_fill_time": "2024-06-10T01:05:00.500Z"},
+                    {"order_id": "0000-000002", "product_id": "BTC-USD", "user_id": "u1",
+                     "order_configuration": {"market_market_ioc": {"quote_size": "100"}},
+                     "side": "BUY", "client_order_id": "", "status": "FILLED",
+                     "created_time": "2024-06-10T00:00:00Z", "filled_size": "0.00166",
+                     "average_filled_price": "60240.96", "order_type": "MARKET",
+                     "last_fill_time": null}
+                ],
+                "sequence": "0",
+                "has_next": true,
+                "cursor": "789100"
+            }"#,
+        )
+        .unwrap();
+        assert!(page.has_next);
+        assert_eq!(page.cursor, "789100");
+
+        let cancelled = convert_coinbase_historical_order(&page.orders[0]).unwrap();
+        assert_eq!(cancelled.symbol.joined("-"), "BTC-USD");
+        assert_eq!(cancelled.client_order_id, "grid-7");
+        assert!(matches!(cancelled.side, OrderSide::Sell));
+        assert!(matches!(cancelled.order_type, OrderType::Limit));
+        assert_eq!(cancelled.status, OrderStatus::Canceled);
+        assert_eq!(cancelled.price.unwrap().value(), Decimal::from(60_000));
+        assert_eq!(cancelled.quantity.value(), Decimal::new(5, 1));
+        assert_eq!(cancelled.filled_quantity.value(), Decimal::new(2, 1));
+        assert_eq!(cancelled.created_time, 1_717_981_200_000);
+        assert_eq!(cancelled.updated_time, 1_717_981_500_500);
+
+        let filled = convert_coinbase_historical_order(&page.orders[1]).unwrap();
+        assert_eq!(filled.status, OrderStatus::Filled);
+        assert!(matches!(filled.order_type, OrderType::Market));
+        assert!(filled.price.is_none());
+        // Quote-sized orders report the filled base size as their quantity
+        assert_eq!(filled.quantity.value(), Decimal::new(166, 5));
+        assert_eq!(
+            filled.average_price.unwrap().value(),
+            Decimal::new(6_024_096, 2)
+        );
+        assert_eq!(filled.updated_time, filled.created_time);
+    }
+}
@@ -0,0 +1,26 @@
+pub mod codec;
+pub mod conversions;
+#[cfg(feature = "fix")]
+pub mod fix;
+pub mod signer;
+pub mod types;
+
+pub mod builder;
+pub mod connector;
+pub mod rest;
+
+// Re-export main components
+pub use builder::{build_connector, build_connector_with_websocket, build_public};
+pub use codec::{CoinbaseCodec, CoinbaseFullCodec, CoinbaseMessage};
+pub use connector::{Account, CoinbaseConnector, MarketData, Trading};
+pub use types::{
+    CoinbaseAccount, CoinbaseCandle, CoinbaseOrderRequest, CoinbaseOrderResponse, CoinbaseProduct,
+};
+
+/// Helper function to create WebSocket stream identifiers for Coinbase
+pub fn create_coinbase_stream_identifiers(
+    product_ids: &[String],
+    subscription_types: &[crate::core::types::SubscriptionType],
+) -> Result<Vec<String>, crate::core::errors::ExchangeError> {
+    codec::create_coinbase_stream_identifiers(product_ids, subscription_types)
+}
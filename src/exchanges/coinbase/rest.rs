@@ -0,0 +1,186 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::RestClient;
+use crate::exchanges::coinbase::types::{
+    CoinbaseAccounts, CoinbaseCancelResponse, CoinbaseCandles, CoinbaseHistoricalOrders,
+    CoinbaseMarketTrades, CoinbaseOrderRequest, CoinbaseOrderResponse, CoinbaseProductBook,
+    CoinbaseProductStats, CoinbaseProducts, CoinbaseServerTime,
+};
+use serde_json::json;
+
+/// Largest page of accounts Coinbase returns
+const ACCOUNTS_PAGE_SIZE: &str = "250";
+
+/// Largest page of historical orders Coinbase returns
+const ORDERS_PAGE_SIZE: &str = "1000";
+
+/// Order statuses that can no longer change
+const FINISHED_ORDER_STATUSES: [&str; 4] = ["FILLED", "CANCELLED", "EXPIRED", "FAILED"];
+
+/// Thin typed wrapper around `RestClient` for the Coinbase Advanced Trade API
+pub struct CoinbaseRestClient<R: RestClient> {
+    client: R,
+}
+
+impl<R: RestClient> CoinbaseRestClient<R> {
+    pub fn new(client: R) -> Self {
+        Self { client }
+    }
+
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.client.can_sign()
+    }
+
+    /// Get all spot products
+    pub async fn get_products(&self) -> Result<CoinbaseProducts, ExchangeError> {
+        self.client
+            .get_json(
+                "/api/v3/brokerage/market/products",
+                &[("product_type", "SPOT")],
+                false,
+            )
+            .await
+    }
+
+    /// Get server time in milliseconds
+    pub async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let time: CoinbaseServerTime = self
+            .client
+            .get_json("/api/v3/brokerage/time", &[], false)
+            .await?;
+        time.epoch_millis.parse().map_err(|e| {
+            ExchangeError::DeserializationError(format!(
+                "Invalid server time {}: {}",
+                time.epoch_millis, e
+            ))
+        })
+    }
+
+    /// Get candles between `start` and `end`, in unix seconds
+    pub async fn get_candles(
+        &self,
+        product_id: &str,
+        granularity: &str,
+        start: i64,
+        end: i64,
+    ) -> Result<CoinbaseCandles, ExchangeError> {
+        let endpoint = format!("/api/v3/brokerage/market/products/{}/candles", product_id);
+        let start = start.to_string();
+        let end = end.to_string();
+        let params = [
+            ("start", start.as_str()),
+            ("end", end.as_str()),
+            ("granularity", granularity),
+        ];
+        self.client.get_json(&endpoint, &params, false).await
+    }
+
+    /// Get a product with its 24h statistics
+    pub async fn get_product(
+        &self,
+        product_id: &str,
+    ) -> Result<CoinbaseProductStats, ExchangeError> {
+        let endpoint = format!("/api/v3/brokerage/market/products/{}", product_id);
+        self.client.get_json(&endpoint, &[], false).await
+    }
+
+    /// Get the order book of a product, `limit` levels per side
+    pub async fn get_product_book(
+        &self,
+        product_id: &str,
+        limit: Option<u32>,
+    ) -> Result<CoinbaseProductBook, ExchangeError> {
+        let limit_str = limit.map(|l| l.to_string());
+        let mut params = vec![("product_id", product_id)];
+        if let Some(ref limit) = limit_str {
+            params.push(("limit", limit.as_str()));
+        }
+        self.client
+            .get_json("/api/v3/brokerage/market/product_book", &params, false)
+            .await
+    }
+
+    /// Get the latest `limit` trades of a product, newest first
+    pub async fn get_market_trades(
+        &self,
+        product_id: &str,
+        limit: u32,
+    ) -> Result<CoinbaseMarketTrades, ExchangeError> {
+        let endpoint = format!("/api/v3/brokerage/market/products/{}/ticker", product_id);
+        let limit = limit.to_string();
+        self.client
+            .get_json(&endpoint, &[("limit", limit.as_str())], false)
+            .await
+    }
+
+    /// Get one page of accounts, starting after `cursor`
+    pub async fn get_accounts(
+        &self,
+        cursor: Option<&str>,
+    ) -> Result<CoinbaseAccounts, ExchangeError> {
+        let mut params = vec![("limit", ACCOUNTS_PAGE_SIZE)];
+        if let Some(cursor) = cursor {
+            params.push(("cursor", cursor));
+        }
+        self.client
+            .get_json("/api/v3/brokerage/accounts", &params, true)
+            .await
+    }
+
+    /// Get one page of finished orders on a product, newest first, starting at `cursor`
+    ///
+    /// `start_date` and `end_date` are RFC 3339 times.
+    pub async fn get_historical_orders(
+        &self,
+        product_id: &str,
+        start_date: Option<&str>,
+        end_date: Option<&str>,
+        cursor: Option<&str>,
+    ) -> Result<CoinbaseHistoricalOrders, ExchangeError> {
+        let mut params = vec![("product_ids", product_id), ("limit", ORDERS_PAGE_SIZE)];
+        params.extend(
+            FINISHED_ORDER_STATUSES
+                .iter()
+                .map(|status| ("order_status", *status)),
+        );
+        if let Some(start_date) = start_date {
+            params.push(("start_date", start_date));
+        }
+        if let Some(end_date) = end_date {
+            params.push(("end_date", end_date));
+        }
+        if let Some(cursor) = cursor {
+            params.push(("cursor", cursor));
+        }
+        self.client
+            .get_json("/api/v3/brokerage/orders/historical/batch", &params, true)
+            .await
+    }
+
+    /// Place an order
+    pub async fn place_order(
+        &self,
+        order: &CoinbaseOrderRequest,
+    ) -> Result<CoinbaseOrderResponse, ExchangeError> {
+        let body = serde_json::to_value(order).map_err(|e| {
+            ExchangeError::SerializationError(format!("Failed to serialize order: {}", e))
+        })?;
+        self.client
+            .post_json("/api/v3/brokerage/orders", &body, true)
+            .await
+    }
+
+    /// Cancel orders by id
+    pub async fn cancel_orders(
+        &self,
+        order_ids: &[&str],
+    ) -> Result<CoinbaseCancelResponse, ExchangeError> {
+        self.client
+            .post_json(
+                "/api/v3/brokerage/orders/batch_cancel",
+                &json!({ "order_ids": order_ids }),
+                true,
+            )
+            .await
+    }
+}
@@ -0,0 +1,281 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinbaseServerTime {
+    pub epoch_millis: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseProducts {
+    pub products: Vec<CoinbaseProduct>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseProduct {
+    pub product_id: String,
+    pub base_currency_id: String,
+    pub quote_currency_id: String,
+    pub status: String,
+    #[serde(default)]
+    pub trading_disabled: bool,
+    pub base_increment: String,
+    pub quote_increment: String,
+    pub base_min_size: String,
+    pub base_max_size: String,
+    #[serde(default)]
+    pub product_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseCandles {
+    pub candles: Vec<CoinbaseCandle>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CoinbaseCandle {
+    /// Bucket start, unix seconds
+    pub start: String,
+    pub low: String,
+    pub high: String,
+    pub open: String,
+    pub close: String,
+    pub volume: String,
+    /// Only set on the WebSocket `candles` channel
+    #[serde(default)]
+    pub product_id: Option<String>,
+}
+
+/// Reply of `/market/products/{product_id}`, reduced to its 24h statistics
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseProductStats {
+    pub product_id: String,
+    pub price: String,
+    /// Change over 24h in percent
+    pub price_percentage_change_24h: String,
+    pub volume_24h: String,
+    #[serde(default)]
+    pub approximate_quote_24h_volume: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseProductBook {
+    pub pricebook: CoinbasePriceBook,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbasePriceBook {
+    pub product_id: String,
+    pub bids: Vec<CoinbaseBookLevel>,
+    pub asks: Vec<CoinbaseBookLevel>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseBookLevel {
+    pub price: String,
+    pub size: String,
+}
+
+/// Reply of `/market/products/{product_id}/ticker`; trades share the WebSocket shape
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseMarketTrades {
+    pub trades: Vec<CoinbaseWsTrade>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseAccounts {
+    pub accounts: Vec<CoinbaseAccount>,
+    #[serde(default)]
+    pub has_next: bool,
+    #[serde(default)]
+    pub cursor: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseAccount {
+    pub currency: String,
+    pub available_balance: CoinbaseAmount,
+    pub hold: CoinbaseAmount,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseAmount {
+    pub value: String,
+    pub currency: String,
+}
+
+/// Page of `/orders/historical/batch`
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseHistoricalOrders {
+    pub orders: Vec<CoinbaseHistoricalOrder>,
+    #[serde(default)]
+    pub has_next: bool,
+    #[serde(default)]
+    pub cursor: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseHistoricalOrder {
+    pub order_id: String,
+    #[serde(default)]
+    pub client_order_id: String,
+    pub product_id: String,
+    pub side: String,
+    /// `MARKET`, `LIMIT`, `STOP_LIMIT`, ...
+    pub order_type: String,
+    /// Keyed by order kind, as in `CoinbaseOrderRequest`
+    pub order_configuration: Value,
+    pub status: String,
+    pub filled_size: String,
+    pub average_filled_price: String,
+    pub created_time: String,
+    #[serde(default)]
+    pub last_fill_time: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CoinbaseOrderRequest {
+    pub client_order_id: String,
+    pub product_id: String,
+    pub side: String,
+    /// Keyed by order kind, e.g. `{"limit_limit_gtc": {...}}`
+    pub order_configuration: Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseOrderResponse {
+    pub success: bool,
+    #[serde(default)]
+    pub success_response: Option<CoinbaseOrderSuccess>,
+    #[serde(default)]
+    pub error_response: Option<CoinbaseOrderError>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseOrderSuccess {
+    pub order_id: String,
+    pub product_id: String,
+    pub side: String,
+    pub client_order_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseOrderError {
+    #[serde(default)]
+    pub error: String,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub error_details: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseCancelResponse {
+    pub results: Vec<CoinbaseCancelResult>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseCancelResult {
+    pub success: bool,
+    #[serde(default)]
+    pub failure_reason: String,
+    pub order_id: String,
+}
+
+/// Message of the Exchange feed's `full` channel
+///
+/// `sequence` numbers every message of a product, so a missing one means the
+/// order-level book drifted.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseFullMessage {
+    /// Order accepted by the matching engine, not yet resting
+    Received {
+        product_id: String,
+        sequence: i64,
+        order_id: String,
+    },
+    /// Order now resting on the book
+    Open {
+        product_id: String,
+        sequence: i64,
+        order_id: String,
+        side: String,
+        price: String,
+        remaining_size: String,
+        time: String,
+    },
+    /// Order size changed; market orders change `new_funds` instead
+    Change {
+        product_id: String,
+        sequence: i64,
+        order_id: String,
+        #[serde(default)]
+        new_size: Option<String>,
+    },
+    /// Trade against the resting `maker_order_id`
+    Match {
+        product_id: String,
+        sequence: i64,
+        maker_order_id: String,
+        size: String,
+    },
+    /// Order no longer on the book, or never rested
+    Done {
+        product_id: String,
+        sequence: i64,
+        order_id: String,
+    },
+    /// Subscription acks, heartbeats and unsequenced stop activations
+    #[serde(other)]
+    Other,
+}
+
+/// Envelope of every Advanced Trade WebSocket message
+#[derive(Debug, Clone, Deserialize)]
+pub struct CoinbaseWsEnvelope {
+    pub channel: String,
+    #[serde(default)]
+    pub timestamp: String,
+    #[serde(default)]
+    pub sequence_num: i64,
+    #[serde(default)]
+    pub events: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CoinbaseWsTicker {
+    pub product_id: String,
+    pub price: String,
+    pub volume_24_h: String,
+    pub low_24_h: String,
+    pub high_24_h: String,
+    pub price_percent_chg_24_h: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CoinbaseWsTrade {
+    pub trade_id: String,
+    pub product_id: String,
+    pub price: String,
+    pub size: String,
+    /// Taker side
+    pub side: String,
+    pub time: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CoinbaseWsLevel2 {
+    pub product_id: String,
+    pub updates: Vec<CoinbaseWsLevel>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CoinbaseWsLevel {
+    /// `bid` or `offer`
+    pub side: String,
+    pub price_level: String,
+    /// Size now resting at the level; zero removes it
+    pub new_quantity: String,
+}
@@ -0,0 +1,76 @@
+use crate::core::config::ExchangeConfig;
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{ReqwestRest, RestClientBuilder, RestClientConfig, TungsteniteWs};
+use crate::exchanges::dydx::{
+    codec::DydxCodec, connector::DydxConnector, rest::DydxRestClient, signer::DydxSigner,
+};
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
+use std::sync::Arc;
+
+/// Public REST API of a mainnet validator node, where orders are broadcast
+///
+/// Community-run; build a `DydxRestClient` on your own node for production trading.
+pub const DYDX_NODE_URL: &str = "https://dydx-api.polkachu.com";
+/// Public REST API of a testnet validator node
+pub const DYDX_TESTNET_NODE_URL: &str = "https://dydx-testnet-api.polkachu.com";
+
+pub const DYDX_CHAIN_ID: &str = "dydx-mainnet-1";
+pub const DYDX_TESTNET_CHAIN_ID: &str = "dydx-testnet-4";
+
+fn build_rest(config: &ExchangeConfig) -> Result<DydxRestClient<ReqwestRest>, ExchangeError> {
+    let endpoints = resolve_endpoints(ExchangeId::Dydx, config)?;
+    let (node_url, chain_id) = if config.testnet {
+        (DYDX_TESTNET_NODE_URL, DYDX_TESTNET_CHAIN_ID)
+    } else {
+        (DYDX_NODE_URL, DYDX_CHAIN_ID)
+    };
+
+    let client = |url: String| {
+        let rest_config = RestClientConfig::new(url, "dydx".to_string())
+            .with_profile(config.profile)
+            .with_network(config.proxy.clone(), config.tls.clone());
+        RestClientBuilder::new(rest_config).build()
+    };
+
+    // Orders are signed transactions rather than signed requests, see `DydxSigner`
+    let signer = if config.has_credentials() {
+        Some(Arc::new(DydxSigner::new(config.secret_key())?))
+    } else {
+        None
+    };
+
+    Ok(DydxRestClient::new(
+        client(endpoints.rest)?,
+        client(node_url.to_string())?,
+        signer,
+        chain_id,
+    ))
+}
+
+/// Create a dYdX connector with REST-only support
+pub fn build_connector(
+    config: ExchangeConfig,
+) -> Result<DydxConnector<ReqwestRest, ()>, ExchangeError> {
+    let ws_url = resolve_endpoints(ExchangeId::Dydx, &config)?.ws;
+    Ok(DydxConnector::new_without_ws(build_rest(&config)?, ws_url)
+        .with_ws_config(config.ws_config()))
+}
+
+/// Create a dYdX connector with WebSocket support
+pub fn build_connector_with_websocket(
+    config: ExchangeConfig,
+) -> Result<DydxConnector<ReqwestRest, TungsteniteWs<DydxCodec>>, ExchangeError> {
+    let rest = build_rest(&config)?;
+    let ws_url = resolve_endpoints(ExchangeId::Dydx, &config)?.ws;
+    let ws = TungsteniteWs::new(ws_url.clone(), "dydx".to_string(), DydxCodec)
+        .with_config(config.ws_config());
+    Ok(DydxConnector::new(rest, ws, ws_url).with_ws_config(config.ws_config()))
+}
+
+/// Create a dYdX connector for public market data, ignoring any private key in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<DydxConnector<ReqwestRest, TungsteniteWs<DydxCodec>>, ExchangeError> {
+    build_connector_with_websocket(config.without_credentials())
+}
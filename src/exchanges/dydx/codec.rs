@@ -0,0 +1,224 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{ControlFrame, WsCodec};
+use crate::core::types::SubscriptionType;
+use crate::exchanges::dydx::conversions::kline_interval_to_dydx;
+use crate::exchanges::dydx::types::{
+    DydxCandle, DydxCandles, DydxOrderBook, DydxOrderBookUpdate, DydxTrades, DydxWsEnvelope,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio_tungstenite::tungstenite::Message;
+
+#[derive(Debug, Clone)]
+pub enum DydxMessage {
+    Trades {
+        ticker: String,
+        trades: DydxTrades,
+    },
+    /// Full book, sent on subscribing
+    Book {
+        ticker: String,
+        book: DydxOrderBook,
+    },
+    BookUpdate {
+        ticker: String,
+        update: DydxOrderBookUpdate,
+    },
+    Candles(Vec<DydxCandle>),
+    Unknown,
+}
+
+/// Codec for the dYdX v4 indexer WebSocket feed
+pub struct DydxCodec;
+
+impl DydxCodec {
+    /// Streams are `channel@id`: `v4_trades@BTC-USD`, or `v4_candles@BTC-USD/1MIN`
+    ///
+    /// A request covers one channel and one id, so every stream gets its own session.
+    fn encode_request(
+        kind: &str,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        let [stream] = streams else {
+            return Err(ExchangeError::InvalidParameters(
+                "dYdX takes one stream per request".to_string(),
+            ));
+        };
+        let (channel, id) = stream.as_ref().split_once('@').ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Invalid dYdX stream: {}", stream.as_ref()))
+        })?;
+        let request = json!({ "type": kind, "channel": channel, "id": id });
+        Ok(Message::Text(request.to_string()))
+    }
+
+    fn contents<T: DeserializeOwned>(channel: &str, contents: Value) -> Result<T, ExchangeError> {
+        serde_json::from_value(contents).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse {}: {}", channel, e))
+        })
+    }
+}
+
+impl WsCodec for DydxCodec {
+    type Message = DydxMessage;
+
+    fn encode_subscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Self::encode_request("subscribe", streams)
+    }
+
+    fn encode_unsubscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Self::encode_request("unsubscribe", streams)
+    }
+
+    fn acknowledges_subscriptions(&self) -> bool {
+        true
+    }
+
+    fn control_frame(&self, message: &Message) -> Option<ControlFrame> {
+        let Message::Text(text) = message else {
+            return None;
+        };
+        // The ack carries the channel's snapshot, so it is decoded as well
+        text.contains("\"subscribed\"").then(|| ControlFrame {
+            subscribed: true,
+            ..ControlFrame::default()
+        })
+    }
+
+    fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
+        let Message::Text(text) = message else {
+            return Ok(None);
+        };
+        let envelope: DydxWsEnvelope = serde_json::from_str(&text).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse message: {}", e))
+        })?;
+        let snapshot = match envelope.kind.as_str() {
+            "subscribed" => true,
+            "channel_data" => false,
+            "error" => {
+                return Err(ExchangeError::WebSocketError(format!(
+                    "dYdX error: {}",
+                    envelope.message.unwrap_or_default()
+                )))
+            }
+            // `connected`, unsubscribe acks, and batches, which are only sent on request
+            _ => return Ok(Some(DydxMessage::Unknown)),
+        };
+        let ticker = envelope.id.unwrap_or_default();
+
+        let message = match (envelope.channel.as_str(), snapshot) {
+            ("v4_trades", _) => DydxMessage::Trades {
+                ticker,
+                trades: Self::contents(&envelope.channel, envelope.contents)?,
+            },
+            ("v4_orderbook", true) => DydxMessage::Book {
+                ticker,
+                book: Self::contents(&envelope.channel, envelope.contents)?,
+            },
+            ("v4_orderbook", false) => DydxMessage::BookUpdate {
+                ticker,
+                update: Self::contents(&envelope.channel, envelope.contents)?,
+            },
+            ("v4_candles", true) => {
+                let candles: DydxCandles = Self::contents(&envelope.channel, envelope.contents)?;
+                // Newest first, like REST; only the current candle is of interest
+                DydxMessage::Candles(candles.candles.into_iter().take(1).collect())
+            }
+            ("v4_candles", false) => {
+                DydxMessage::Candles(vec![Self::contents(&envelope.channel, envelope.contents)?])
+            }
+            _ => DydxMessage::Unknown,
+        };
+        Ok(Some(message))
+    }
+}
+
+/// Create dYdX stream identifiers, as `channel@id`
+///
+/// Books are full depth, a snapshot followed by deltas, whatever the requested mode.
+/// Tickers and mark prices are not streamed; poll `MarketSnapshotSource` and
+/// `FundingRateSource` instead.
+pub fn create_dydx_stream_identifiers(
+    tickers: &[String],
+    subscription_types: &[SubscriptionType],
+) -> Result<Vec<String>, ExchangeError> {
+    let mut streams = Vec::new();
+    for subscription in subscription_types {
+        let (channel, suffix) = match subscription {
+            SubscriptionType::OrderBook { .. } => ("v4_orderbook", String::new()),
+            SubscriptionType::Trades | SubscriptionType::AggTrades => ("v4_trades", String::new()),
+            SubscriptionType::Klines { interval } => {
+                let resolution = kline_interval_to_dydx(*interval).ok_or_else(|| {
+                    ExchangeError::NotSupported(format!("dYdX does not offer {} candles", interval))
+                })?;
+                ("v4_candles", format!("/{}", resolution))
+            }
+            SubscriptionType::Ticker | SubscriptionType::MarkPrice => continue,
+        };
+        for ticker in tickers {
+            let stream = format!("{}@{}{}", channel, ticker, suffix);
+            if !streams.contains(&stream) {
+                streams.push(stream);
+            }
+        }
+    }
+    Ok(streams)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{KlineInterval, OrderBookMode};
+
+    #[test]
+    fn test_decodes_book_snapshots_and_updates() {
+        let streams = create_dydx_stream_identifiers(
+            &["BTC-USD".to_string()],
+            &[
+                SubscriptionType::OrderBook {
+                    depth: Some(5),
+                    mode: OrderBookMode::Delta,
+                },
+                SubscriptionType::Klines {
+                    interval: KlineInterval::Minutes1,
+                },
+            ],
+        )
+        .unwrap();
+        assert_eq!(streams, ["v4_orderbook@BTC-USD", "v4_candles@BTC-USD/1MIN"]);
+        let Message::Text(text) = DydxCodec.encode_subscription(&streams[..1]).unwrap() else {
+            panic!("expected text");
+        };
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            value,
+            json!({"type": "subscribe", "channel": "v4_orderbook", "id": "BTC-USD"})
+        );
+        assert!(DydxCodec.encode_subscription(&streams).is_err());
+
+        let text = r#"{"type":"subscribed","connection_id":"c","message_id":1,"channel":"v4_orderbook","id":"BTC-USD","contents":{"bids":[{"price":"65000","size":"1.5"}],"asks":[{"price":"65001","size":"0.2"}]}}"#;
+        let message = Message::Text(text.to_string());
+        assert!(DydxCodec.control_frame(&message).unwrap().subscribed);
+        let Some(DydxMessage::Book { ticker, book }) = DydxCodec.decode_message(message).unwrap()
+        else {
+            panic!("expected book");
+        };
+        assert_eq!(ticker, "BTC-USD");
+        assert_eq!(book.bids[0].size, "1.5");
+
+        let text = r#"{"type":"channel_data","connection_id":"c","message_id":2,"id":"BTC-USD","channel":"v4_orderbook","version":"1.0.0","contents":{"asks":[["65001","0"]]}}"#;
+        let Some(DydxMessage::BookUpdate { update, .. }) = DydxCodec
+            .decode_message(Message::Text(text.to_string()))
+            .unwrap()
+        else {
+            panic!("expected book update");
+        };
+        assert!(update.bids.is_empty());
+        assert_eq!(update.asks[0], ("65001".to_string(), "0".to_string()));
+    }
+}
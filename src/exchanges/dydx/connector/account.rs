@@ -0,0 +1,49 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::RestClient,
+    traits::AccountInfo,
+    types::{Balance, Position},
+};
+use crate::exchanges::dydx::{
+    conversions::{convert_dydx_position, convert_dydx_subaccount},
+    rest::DydxRestClient,
+};
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Account implementation for a dYdX v4 subaccount
+pub struct Account<R: RestClient> {
+    rest: DydxRestClient<R>,
+}
+
+impl<R: RestClient + Clone> Account<R> {
+    /// Create a new account manager
+    pub fn new(rest: &DydxRestClient<R>) -> Self {
+        Self { rest: rest.clone() }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> AccountInfo for Account<R> {
+    /// The indexer is public; the address comes from the signing key
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    /// Collateral is USDC; the part backing positions and orders shows as locked
+    #[instrument(skip(self), fields(exchange = "dydx"))]
+    async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let subaccount = self.rest.get_subaccount().await?;
+        Ok(vec![convert_dydx_subaccount(&subaccount)?])
+    }
+
+    #[instrument(skip(self), fields(exchange = "dydx"))]
+    async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
+        let subaccount = self.rest.get_subaccount().await?;
+        subaccount
+            .open_perpetual_positions
+            .values()
+            .map(convert_dydx_position)
+            .collect()
+    }
+}
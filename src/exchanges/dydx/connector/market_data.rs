@@ -0,0 +1,327 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::{RestClient, TungsteniteWs, WsConfig, WsSession},
+    streaming::{SharedHub, StreamRouter},
+    traits::{FundingRateSource, MarketSnapshotSource, RestMarketData, StreamingMarketData},
+    types::{
+        FundingRate, Kline, KlineInterval, Market, MarketDataType, OrderBook, SubscriptionType,
+        Symbol, Ticker, Trade, WebSocketConfig,
+    },
+};
+use crate::exchanges::dydx::{
+    codec::{create_dydx_stream_identifiers, DydxCodec, DydxMessage},
+    conversions::{
+        convert_dydx_candle, convert_dydx_historical_funding,
+        convert_dydx_hourly_candles_to_ticker, convert_dydx_market, convert_dydx_market_funding,
+        convert_dydx_order_book, convert_dydx_order_book_update, convert_dydx_trade,
+        convert_symbol_to_ticker, convert_ticker_to_symbol, kline_interval_to_dydx,
+    },
+    rest::DydxRestClient,
+};
+use crate::exchanges::factory::ExchangeId;
+use async_trait::async_trait;
+use chrono::{SecondsFormat, TimeZone, Utc};
+use tokio::sync::mpsc;
+use tracing::{instrument, warn};
+
+pub const DYDX_WS_URL: &str = ExchangeId::Dydx.endpoints().mainnet.ws;
+
+/// Most candles the indexer returns per request
+const MAX_CANDLES: u32 = 100;
+
+fn iso_time(millis: i64) -> Option<String> {
+    Utc.timestamp_millis_opt(millis)
+        .single()
+        .map(|time| time.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// Routes dYdX indexer updates, subscribing one channel and market per request
+struct DydxRouter;
+
+impl StreamRouter for DydxRouter {
+    type Codec = DydxCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        Ok(create_dydx_stream_identifiers(
+            &[convert_symbol_to_ticker(symbol)?],
+            std::slice::from_ref(subscription),
+        )?
+        .pop())
+    }
+
+    fn request_groups(&self, streams: Vec<String>) -> Vec<Vec<String>> {
+        streams.into_iter().map(|stream| vec![stream]).collect()
+    }
+
+    fn convert(&self, message: DydxMessage) -> Vec<MarketDataType> {
+        convert_dydx_message_to_market_data(message).unwrap_or_else(|e| {
+            warn!(error = %e, "Dropping malformed dYdX message");
+            Vec::new()
+        })
+    }
+}
+
+/// Market data implementation for dYdX v4 perpetuals
+pub struct MarketData<R: RestClient, W = ()> {
+    rest: DydxRestClient<R>,
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
+    ws: Option<W>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
+    ws_url: String,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
+}
+
+impl<R: RestClient + Clone, W> MarketData<R, W> {
+    /// Create a new market data source streaming from `ws_url`
+    pub fn new(rest: &DydxRestClient<R>, ws: Option<W>, ws_url: String) -> Self {
+        Self {
+            rest: rest.clone(),
+            ws,
+            hub: SharedHub::new(),
+            ws_url,
+            ws_config: WsConfig::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> RestMarketData for MarketData<R, W> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.rest
+            .get_markets()
+            .await?
+            .iter()
+            .map(convert_dydx_market)
+            .collect()
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        let resolution = kline_interval_to_dydx(interval).ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("dYdX does not offer {} candles", interval))
+        })?;
+        let limit = limit.unwrap_or(MAX_CANDLES).min(MAX_CANDLES);
+        let candles = self
+            .rest
+            .get_candles(
+                &convert_symbol_to_ticker(&symbol)?,
+                resolution,
+                Some(limit),
+                start_time.and_then(iso_time).as_deref(),
+                end_time.and_then(iso_time).as_deref(),
+            )
+            .await?;
+
+        let now = Utc::now().timestamp_millis();
+        // Newest first over the wire
+        candles
+            .candles
+            .iter()
+            .rev()
+            .map(|candle| convert_dydx_candle(candle, now))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> MarketSnapshotSource for MarketData<R, W> {
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let candles = self
+            .rest
+            .get_candles(
+                &convert_symbol_to_ticker(&symbol)?,
+                "1HOUR",
+                Some(24),
+                None,
+                None,
+            )
+            .await?;
+        convert_dydx_hourly_candles_to_ticker(
+            &candles.candles,
+            &symbol,
+            Utc::now().timestamp_millis(),
+        )
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let book = self
+            .rest
+            .get_order_book(&convert_symbol_to_ticker(&symbol)?)
+            .await?;
+        Ok(convert_dydx_order_book(&book, &symbol)?.truncated(depth))
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let trades = self
+            .rest
+            .get_trades(&convert_symbol_to_ticker(&symbol)?, limit)
+            .await?;
+        // Newest first over the wire
+        trades
+            .trades
+            .iter()
+            .rev()
+            .map(|trade| convert_dydx_trade(trade, &symbol))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> FundingRateSource for MarketData<R, W> {
+    #[instrument(skip(self), fields(exchange = "dydx"))]
+    async fn get_funding_rates(
+        &self,
+        symbols: Option<Vec<Symbol>>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let Some(symbols) = symbols else {
+            return self.get_all_funding_rates().await;
+        };
+        let now = Utc::now().timestamp_millis();
+        let mut rates = Vec::with_capacity(symbols.len());
+        for symbol in symbols {
+            match self
+                .rest
+                .get_market(&convert_symbol_to_ticker(&symbol)?)
+                .await
+            {
+                Ok(market) => rates.push(convert_dydx_market_funding(&market, now)),
+                Err(e) => warn!(market = %symbol, error = %e, "Failed to get funding rate"),
+            }
+        }
+        Ok(rates)
+    }
+
+    #[instrument(skip(self), fields(exchange = "dydx"))]
+    async fn get_all_funding_rates(&self) -> Result<Vec<FundingRate>, ExchangeError> {
+        let now = Utc::now().timestamp_millis();
+        Ok(self
+            .rest
+            .get_markets()
+            .await?
+            .iter()
+            .map(|market| convert_dydx_market_funding(market, now))
+            .collect())
+    }
+
+    /// Pages back from `end_time`; rates before `start_time` are dropped
+    #[instrument(skip(self), fields(exchange = "dydx", market = %symbol))]
+    async fn get_funding_rate_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let history = self
+            .rest
+            .get_historical_funding(
+                &convert_symbol_to_ticker(&symbol)?,
+                limit,
+                end_time.and_then(iso_time).as_deref(),
+            )
+            .await?;
+        // Newest first over the wire
+        let mut rates = history
+            .historical_funding
+            .iter()
+            .rev()
+            .map(convert_dydx_historical_funding)
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(start) = start_time {
+            rates.retain(|rate| rate.timestamp >= start);
+        }
+        Ok(rates)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: WsSession<DydxCodec> + Send + Sync> StreamingMarketData
+    for MarketData<R, W>
+{
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(self.ws_url.clone(), "dydx".to_string(), DydxCodec)
+                        .with_config(self.ws_config.clone())
+                },
+                DydxRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.ws_url.clone()
+    }
+}
+
+/// Convert `DydxMessage` to `MarketDataType`s
+fn convert_dydx_message_to_market_data(
+    message: DydxMessage,
+) -> Result<Vec<MarketDataType>, ExchangeError> {
+    match message {
+        DydxMessage::Trades { ticker, trades } => {
+            let symbol = convert_ticker_to_symbol(&ticker);
+            // Newest first over the wire
+            trades
+                .trades
+                .iter()
+                .rev()
+                .map(|trade| convert_dydx_trade(trade, &symbol).map(MarketDataType::Trade))
+                .collect()
+        }
+        DydxMessage::Book { ticker, book } => Ok(vec![MarketDataType::OrderBook(
+            convert_dydx_order_book(&book, &convert_ticker_to_symbol(&ticker))?,
+        )]),
+        DydxMessage::BookUpdate { ticker, update } => Ok(vec![MarketDataType::OrderBook(
+            convert_dydx_order_book_update(&update, &convert_ticker_to_symbol(&ticker))?,
+        )]),
+        DydxMessage::Candles(candles) => {
+            let now = Utc::now().timestamp_millis();
+            candles
+                .iter()
+                .map(|candle| convert_dydx_candle(candle, now).map(MarketDataType::Kline))
+                .collect()
+        }
+        DydxMessage::Unknown => Ok(Vec::new()),
+    }
+}
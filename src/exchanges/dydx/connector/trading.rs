@@ -0,0 +1,133 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::RestClient,
+    traits::OrderPlacer,
+    types::{OrderRequest, OrderResponse, Symbol},
+};
+use crate::exchanges::dydx::{
+    conversions::{convert_order_request, convert_symbol_to_ticker},
+    proto,
+    rest::DydxRestClient,
+};
+use async_trait::async_trait;
+use serde_json::json;
+use tracing::instrument;
+
+/// How long a cancellation stays valid for the chain to apply it
+const CANCEL_WINDOW_SECS: i64 = 60;
+
+/// Trading implementation for dYdX v4 perpetuals
+pub struct Trading<R: RestClient> {
+    rest: DydxRestClient<R>,
+}
+
+impl<R: RestClient + Clone> Trading<R> {
+    /// Create a new trading engine
+    pub fn new(rest: &DydxRestClient<R>) -> Self {
+        Self { rest: rest.clone() }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    /// The order id is the client id the order was signed with, the caller's
+    /// `client_order_id` when set, which must then be a `u32`
+    ///
+    /// The response only confirms the node accepted the transaction; fills and
+    /// rejections by the matching engine show on the indexer afterwards.
+    #[instrument(skip(self), fields(exchange = "dydx"))]
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let signer = self.rest.signer()?;
+        let ticker = convert_symbol_to_ticker(&order.symbol)?;
+        let (market, height) = tokio::join!(self.rest.get_market(&ticker), self.rest.get_height());
+
+        let client_id = match &order.client_order_id {
+            Some(id) => id.parse().map_err(|_| {
+                ExchangeError::InvalidParameters(format!(
+                    "dYdX client order ids are 32-bit unsigned integers: {}",
+                    id
+                ))
+            })?,
+            None => rand::random(),
+        };
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let order_id = proto::OrderId {
+            owner: signer.address().to_string(),
+            subaccount_number: self.rest.subaccount_number(),
+            client_id,
+            order_flags: proto::ORDER_FLAGS_SHORT_TERM,
+            clob_pair_id: 0,
+        };
+        let chain_order = convert_order_request(&order, &market?, order_id, height?, now)?;
+        let tx = self
+            .rest
+            .broadcast(&[proto::place_order_message(&chain_order)])
+            .await?;
+
+        let client_id = chain_order.order_id.client_id.to_string();
+        Ok(OrderResponse {
+            order_id: client_id.clone(),
+            client_order_id: client_id,
+            symbol: order.symbol,
+            side: order.side,
+            order_type: order.order_type,
+            quantity: order.quantity,
+            price: order.price,
+            status: "NEW".to_string(),
+            timestamp: now,
+            extensions: Some(json!({
+                "txhash": tx.txhash,
+                "orderFlags": chain_order.order_id.order_flags,
+                "clobPairId": chain_order.order_id.clob_pair_id,
+            })),
+        })
+    }
+
+    /// Cancels a resting long-term order by the client id `place_order` returned;
+    /// short-term orders never rest, so there is nothing to cancel
+    #[instrument(skip(self), fields(exchange = "dydx", order_id = %order_id))]
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let signer = self.rest.signer()?;
+        let client_id = order_id.parse().map_err(|e| {
+            ExchangeError::InvalidParameters(format!("Invalid dYdX client id {}: {}", order_id, e))
+        })?;
+        let market = self
+            .rest
+            .get_market(&convert_symbol_to_ticker(&symbol)?)
+            .await?;
+        let clob_pair_id = market.clob_pair_id.parse().map_err(|e| {
+            ExchangeError::DeserializationError(format!(
+                "Invalid clob pair id {}: {}",
+                market.clob_pair_id, e
+            ))
+        })?;
+
+        let order_id = proto::OrderId {
+            owner: signer.address().to_string(),
+            subaccount_number: self.rest.subaccount_number(),
+            client_id,
+            order_flags: proto::ORDER_FLAGS_LONG_TERM,
+            clob_pair_id,
+        };
+        let expiry = chrono::Utc::now().timestamp() + CANCEL_WINDOW_SECS;
+        let good_til = proto::GoodTil::BlockTime(u32::try_from(expiry).unwrap_or(u32::MAX));
+        self.rest
+            .broadcast(&[proto::cancel_order_message(&order_id, good_til)])
+            .await?;
+        Ok(())
+    }
+
+    /// Orders are identified by their client id, so this is `cancel_order`
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.cancel_order(symbol, client_order_id).await
+    }
+}
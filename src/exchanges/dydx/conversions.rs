@@ -0,0 +1,615 @@
+use super::proto;
+use super::types as dydx_types;
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
+use crate::core::types::{
+    conversion, Balance, ContractStyle, FundingRate, Kline, KlineInterval, MarginMode, Market,
+    MarketStatus, OrderBook, OrderBookEntry, OrderRequest, OrderSide, OrderType, Position,
+    PositionSide, Price, Quantity, Symbol, Ticker, TimeInForce, Trade, Volume,
+};
+use crate::exchanges::factory::ExchangeId;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::time::Duration;
+
+/// dYdX market ticker spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::Dydx.symbol_convention();
+
+/// Funding settles every hour
+pub const FUNDING_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Quote amounts are USDC, in units of `10^-6`
+const QUOTE_ATOMIC_RESOLUTION: i32 = -6;
+
+/// Blocks a short-term order stays valid for; the chain accepts up to 20
+pub const SHORT_TERM_ORDER_BLOCKS: u32 = 10;
+
+/// Lifetime of resting orders; the chain accepts up to 95 days
+pub const LONG_TERM_ORDER_LIFETIME: Duration = Duration::from_secs(28 * 24 * 60 * 60);
+
+/// Share of the oracle price a market order without a price may trade away from it
+const MARKET_ORDER_SLIPPAGE: Decimal = Decimal::from_parts(5, 0, 0, false, 2);
+
+/// Convert symbol to a `BASE-USD` market ticker
+pub fn convert_symbol_to_ticker(symbol: &Symbol) -> Result<String, ExchangeError> {
+    SYMBOLS.try_to_exchange_symbol(symbol)
+}
+
+/// Convert a `BASE-USD` market ticker to symbol
+pub fn convert_ticker_to_symbol(ticker: &str) -> Symbol {
+    SYMBOLS.from_exchange_symbol(ticker)
+}
+
+fn parse_optional(value: &str) -> Option<Decimal> {
+    conversion::try_string_to_decimal(value).ok()
+}
+
+fn parse_rfc3339_millis(time: &str) -> Result<i64, ExchangeError> {
+    chrono::DateTime::parse_from_rfc3339(time)
+        .map(|time| time.timestamp_millis())
+        .map_err(|e| ExchangeError::DeserializationError(format!("Invalid time {}: {}", time, e)))
+}
+
+/// Convert kline interval to a dYdX candle resolution
+pub const fn kline_interval_to_dydx(interval: KlineInterval) -> Option<&'static str> {
+    match interval {
+        KlineInterval::Minutes1 => Some("1MIN"),
+        KlineInterval::Minutes5 => Some("5MINS"),
+        KlineInterval::Minutes15 => Some("15MINS"),
+        KlineInterval::Minutes30 => Some("30MINS"),
+        KlineInterval::Hours1 => Some("1HOUR"),
+        KlineInterval::Hours4 => Some("4HOURS"),
+        KlineInterval::Days1 => Some("1DAY"),
+        _ => None,
+    }
+}
+
+fn dydx_to_kline_interval(resolution: &str) -> Option<KlineInterval> {
+    Some(match resolution {
+        "1MIN" => KlineInterval::Minutes1,
+        "5MINS" => KlineInterval::Minutes5,
+        "15MINS" => KlineInterval::Minutes15,
+        "30MINS" => KlineInterval::Minutes30,
+        "1HOUR" => KlineInterval::Hours1,
+        "4HOURS" => KlineInterval::Hours4,
+        "1DAY" => KlineInterval::Days1,
+        _ => return None,
+    })
+}
+
+/// `10^exponent`, exact for the exponents markets use
+fn pow10(exponent: i32) -> Decimal {
+    let scale = Decimal::from(10_u64.pow(exponent.unsigned_abs().min(18)));
+    if exponent < 0 {
+        Decimal::ONE / scale
+    } else {
+        scale
+    }
+}
+
+/// Convert dydx market to core market type
+pub fn convert_dydx_market(market: &dydx_types::DydxMarket) -> Result<Market, ExchangeError> {
+    let tick = conversion::try_string_to_decimal(&market.tick_size)?;
+    let step = conversion::try_string_to_decimal(&market.step_size)?;
+    Ok(Market {
+        symbol: convert_ticker_to_symbol(&market.ticker),
+        status: match market.status.as_str() {
+            "INITIALIZING" => MarketStatus::PreTrading,
+            "FINAL_SETTLEMENT" => MarketStatus::Closed,
+            status => MarketStatus::from_venue_str(status),
+        },
+        base_precision: i32::try_from(step.normalize().scale()).unwrap_or(i32::MAX),
+        quote_precision: i32::try_from(tick.normalize().scale()).unwrap_or(i32::MAX),
+        min_qty: Some(Quantity::new(step)),
+        max_qty: None,
+        min_price: Some(Price::new(tick)),
+        max_price: None,
+        contract_style: ContractStyle::Linear,
+        contract_size: None,
+        extensions: Some(serde_json::json!({ "clobPairId": market.clob_pair_id })),
+    })
+}
+
+/// Convert dydx market to the current core funding rate
+///
+/// `nextFundingRate` is the hourly rate accruing towards the next settlement at the top
+/// of the hour; dYdX marks positions to the oracle price.
+pub fn convert_dydx_market_funding(market: &dydx_types::DydxMarket, timestamp: i64) -> FundingRate {
+    let interval = FUNDING_INTERVAL.as_millis() as i64;
+    FundingRate {
+        symbol: convert_ticker_to_symbol(&market.ticker),
+        funding_rate: parse_optional(&market.next_funding_rate),
+        previous_funding_rate: None,
+        next_funding_rate: None,
+        funding_time: None,
+        next_funding_time: Some((timestamp / interval + 1) * interval),
+        mark_price: market
+            .oracle_price
+            .as_deref()
+            .and_then(parse_optional)
+            .map(Price::new),
+        index_price: None,
+        timestamp,
+        funding_interval: Some(FUNDING_INTERVAL),
+    }
+}
+
+/// Convert dydx settled funding to core funding rate
+pub fn convert_dydx_historical_funding(
+    funding: &dydx_types::DydxHistoricalFunding,
+) -> Result<FundingRate, ExchangeError> {
+    let funding_time = parse_rfc3339_millis(&funding.effective_at)?;
+    Ok(FundingRate {
+        symbol: convert_ticker_to_symbol(&funding.ticker),
+        funding_rate: Some(conversion::try_string_to_decimal(&funding.rate)?),
+        previous_funding_rate: None,
+        next_funding_rate: None,
+        funding_time: Some(funding_time),
+        next_funding_time: None,
+        mark_price: parse_optional(&funding.price).map(Price::new),
+        index_price: None,
+        timestamp: funding_time,
+        funding_interval: Some(FUNDING_INTERVAL),
+    })
+}
+
+/// Convert dydx candle to core kline type; volume is in the base asset
+pub fn convert_dydx_candle(
+    candle: &dydx_types::DydxCandle,
+    now: i64,
+) -> Result<Kline, ExchangeError> {
+    let interval = dydx_to_kline_interval(&candle.resolution).ok_or_else(|| {
+        ExchangeError::DeserializationError(format!(
+            "Unknown dYdX resolution {}",
+            candle.resolution
+        ))
+    })?;
+    let open_time = parse_rfc3339_millis(&candle.started_at)?;
+    let close_time = open_time + interval.duration_millis() - 1;
+    Ok(Kline {
+        symbol: convert_ticker_to_symbol(&candle.ticker),
+        open_time,
+        close_time,
+        interval: interval.to_string(),
+        open_price: conversion::try_string_to_price(&candle.open)?,
+        high_price: conversion::try_string_to_price(&candle.high)?,
+        low_price: conversion::try_string_to_price(&candle.low)?,
+        close_price: conversion::try_string_to_price(&candle.close)?,
+        volume: Volume::new(conversion::try_string_to_decimal(
+            &candle.base_token_volume,
+        )?),
+        number_of_trades: candle.trades,
+        final_bar: close_time < now,
+    })
+}
+
+/// Roll hourly candles, newest first, up into a core ticker
+///
+/// The indexer serves no 24 hour ticker, so it is rebuilt from the last 24 hourly
+/// candles.
+pub fn convert_dydx_hourly_candles_to_ticker(
+    candles: &[dydx_types::DydxCandle],
+    symbol: &Symbol,
+    now: i64,
+) -> Result<Ticker, ExchangeError> {
+    let (Some(newest), Some(oldest)) = (candles.first(), candles.last()) else {
+        return Err(ExchangeError::InvalidResponseFormat(format!(
+            "No candles for {}",
+            symbol
+        )));
+    };
+    let price = conversion::try_string_to_price(&newest.close)?;
+    let open = conversion::try_string_to_decimal(&oldest.open)?;
+    let mut high = Decimal::MIN;
+    let mut low = Decimal::MAX;
+    let mut volume = Decimal::ZERO;
+    let mut quote_volume = Decimal::ZERO;
+    let mut count = 0;
+    for candle in candles {
+        high = high.max(conversion::try_string_to_decimal(&candle.high)?);
+        low = low.min(conversion::try_string_to_decimal(&candle.low)?);
+        volume += conversion::try_string_to_decimal(&candle.base_token_volume)?;
+        quote_volume += parse_optional(&candle.usd_volume).unwrap_or_default();
+        count += candle.trades;
+    }
+    let change = price.value() - open;
+
+    Ok(Ticker {
+        symbol: symbol.clone(),
+        price,
+        price_change: Price::new(change),
+        price_change_percent: if open.is_zero() {
+            Decimal::ZERO
+        } else {
+            (change / open * Decimal::ONE_HUNDRED).round_dp(4)
+        },
+        high_price: Price::new(high),
+        low_price: Price::new(low),
+        volume: Volume::new(volume),
+        quote_volume: Volume::new(quote_volume),
+        open_time: parse_rfc3339_millis(&oldest.started_at)?,
+        close_time: now,
+        count,
+    })
+}
+
+fn convert_levels(levels: &[dydx_types::DydxLevel]) -> Result<Vec<OrderBookEntry>, ExchangeError> {
+    levels
+        .iter()
+        .map(|level| {
+            Ok(OrderBookEntry {
+                price: conversion::try_string_to_price(&level.price)?,
+                quantity: conversion::try_string_to_quantity(&level.size)?,
+            })
+        })
+        .collect()
+}
+
+fn convert_level_updates(
+    levels: &[(String, String)],
+) -> Result<Vec<OrderBookEntry>, ExchangeError> {
+    levels
+        .iter()
+        .map(|(price, size)| {
+            Ok(OrderBookEntry {
+                price: conversion::try_string_to_price(price)?,
+                quantity: conversion::try_string_to_quantity(size)?,
+            })
+        })
+        .collect()
+}
+
+/// Convert dydx REST book or `v4_orderbook` snapshot to core order book type
+pub fn convert_dydx_order_book(
+    book: &dydx_types::DydxOrderBook,
+    symbol: &Symbol,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: symbol.clone(),
+        bids: convert_levels(&book.bids)?,
+        asks: convert_levels(&book.asks)?,
+        last_update_id: 0, // Not reported
+    })
+}
+
+/// Convert dydx `v4_orderbook` update to core order book type holding the changed levels
+pub fn convert_dydx_order_book_update(
+    update: &dydx_types::DydxOrderBookUpdate,
+    symbol: &Symbol,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: symbol.clone(),
+        bids: convert_level_updates(&update.bids)?,
+        asks: convert_level_updates(&update.asks)?,
+        last_update_id: 0,
+    })
+}
+
+/// Numeric trade id packed from the hex event id: block height, then the transaction
+/// and event index within the block; 0 for ids of another shape
+fn trade_id(id: &str) -> i64 {
+    let field = |range: std::ops::Range<usize>| {
+        id.get(range)
+            .and_then(|hex| i64::from_str_radix(hex, 16).ok())
+    };
+    match (id.len(), field(0..8), field(8..16), field(16..24)) {
+        (24, Some(height), Some(transaction), Some(event)) => {
+            height << 32 | (transaction & 0xffff) << 16 | (event & 0xffff)
+        }
+        _ => 0,
+    }
+}
+
+/// Convert dydx trade to core trade type
+pub fn convert_dydx_trade(
+    trade: &dydx_types::DydxTrade,
+    symbol: &Symbol,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: symbol.clone(),
+        id: trade_id(&trade.id),
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: conversion::try_string_to_quantity(&trade.size)?,
+        time: parse_rfc3339_millis(&trade.created_at)?,
+        is_buyer_maker: trade.side == "SELL",
+    })
+}
+
+/// Convert dydx subaccount to core balance type, in USDC
+pub fn convert_dydx_subaccount(
+    subaccount: &dydx_types::DydxSubaccount,
+) -> Result<Balance, ExchangeError> {
+    let equity = conversion::try_string_to_decimal(&subaccount.equity)?;
+    let free = conversion::try_string_to_decimal(&subaccount.free_collateral)?;
+    Ok(Balance {
+        asset: "USDC".to_string(),
+        free: Quantity::new(free),
+        locked: Quantity::new(equity - free),
+    })
+}
+
+/// Convert dydx open perpetual position to core position
+///
+/// Subaccounts are cross-margined; the indexer reports no leverage or liquidation price.
+pub fn convert_dydx_position(
+    position: &dydx_types::DydxPerpetualPosition,
+) -> Result<Position, ExchangeError> {
+    Ok(Position {
+        symbol: convert_ticker_to_symbol(&position.market),
+        position_side: if position.side == "SHORT" {
+            PositionSide::Short
+        } else {
+            PositionSide::Long
+        },
+        entry_price: conversion::try_string_to_price(&position.entry_price)?,
+        position_amount: Quantity::new(conversion::try_string_to_decimal(&position.size)?.abs()),
+        unrealized_pnl: parse_optional(&position.unrealized_pnl).unwrap_or_default(),
+        liquidation_price: None,
+        leverage: Decimal::ONE,
+        margin_mode: Some(MarginMode::Cross),
+        margin: None,
+        extensions: None,
+    })
+}
+
+/// Order size and price in the chain's integer units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuantizedOrder {
+    pub quantums: u64,
+    pub subticks: u64,
+}
+
+/// Express `quantity` in quantums and `price` in subticks of `market`
+///
+/// The quantity rounds down to a multiple of the step, never ordering more than asked;
+/// the price rounds to the nearest tick. Orders below one step are rejected.
+pub fn quantize_order(
+    market: &dydx_types::DydxMarket,
+    side: &OrderSide,
+    quantity: Decimal,
+    price: Decimal,
+) -> Result<QuantizedOrder, ExchangeError> {
+    let step = market.step_base_quantums.max(1);
+    let quantums = (quantity * pow10(-market.atomic_resolution))
+        .floor()
+        .to_u64()
+        .map(|quantums| quantums / step * step)
+        .filter(|quantums| *quantums > 0)
+        .ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!(
+                "Quantity {} is below the {} step of {}",
+                quantity, market.step_size, market.ticker
+            ))
+        })?;
+
+    let exponent =
+        market.atomic_resolution - market.quantum_conversion_exponent - QUOTE_ATOMIC_RESOLUTION;
+    let per_tick = Decimal::from(market.subticks_per_tick.max(1));
+    let ticks = (price * pow10(exponent) / per_tick).round();
+    let subticks = (ticks * per_tick)
+        .to_u64()
+        .filter(|subticks| *subticks > 0)
+        .ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!(
+                "Price {} is outside the range of {} on {:?}",
+                price, market.ticker, side
+            ))
+        })?;
+
+    Ok(QuantizedOrder { quantums, subticks })
+}
+
+/// Worst price a market order accepts: its own price, else the oracle price moved by
+/// `MARKET_ORDER_SLIPPAGE` against the order
+fn market_order_price(
+    order: &OrderRequest,
+    market: &dydx_types::DydxMarket,
+) -> Result<Decimal, ExchangeError> {
+    if let Some(price) = order.price {
+        return Ok(price.value());
+    }
+    let oracle = market
+        .oracle_price
+        .as_deref()
+        .and_then(parse_optional)
+        .ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!(
+                "No oracle price to bound a market order on {}",
+                market.ticker
+            ))
+        })?;
+    Ok(match order.side {
+        OrderSide::Buy => oracle * (Decimal::ONE + MARKET_ORDER_SLIPPAGE),
+        OrderSide::Sell => oracle * (Decimal::ONE - MARKET_ORDER_SLIPPAGE),
+    })
+}
+
+/// Build the chain order for a core order request on `market`
+///
+/// `order_id` names the owner, subaccount and client id; its flags and market are
+/// filled in here. Good-til-canceled limit orders rest as long-term orders. Market,
+/// IOC and FOK orders go out short-term, valid for a few blocks past `height`; market
+/// orders are immediate-or-cancel at `price`, or within `MARKET_ORDER_SLIPPAGE` of the
+/// oracle price when it is `None`. Trigger orders are not supported.
+pub fn convert_order_request(
+    order: &OrderRequest,
+    market: &dydx_types::DydxMarket,
+    mut order_id: proto::OrderId,
+    height: u32,
+    now: i64,
+) -> Result<proto::Order, ExchangeError> {
+    let (price, time_in_force) = match order.order_type {
+        OrderType::Market => (
+            market_order_price(order, market)?,
+            Some(proto::TimeInForce::Ioc),
+        ),
+        OrderType::Limit => {
+            let price = order.price.ok_or_else(|| {
+                ExchangeError::InvalidParameters("Limit orders require a price".to_string())
+            })?;
+            let time_in_force = match order.time_in_force {
+                None | Some(TimeInForce::GTC) => None,
+                Some(TimeInForce::IOC) => Some(proto::TimeInForce::Ioc),
+                Some(TimeInForce::FOK) => Some(proto::TimeInForce::FillOrKill),
+            };
+            (price.value(), time_in_force)
+        }
+        _ => {
+            return Err(ExchangeError::NotSupported(format!(
+                "dYdX does not support {:?} orders",
+                order.order_type
+            )))
+        }
+    };
+    let quantized = quantize_order(market, &order.side, order.quantity.value(), price)?;
+
+    order_id.clob_pair_id = market.clob_pair_id.parse().map_err(|e| {
+        ExchangeError::DeserializationError(format!(
+            "Invalid clob pair id {}: {}",
+            market.clob_pair_id, e
+        ))
+    })?;
+    let (good_til, time_in_force) = if let Some(time_in_force) = time_in_force {
+        order_id.order_flags = proto::ORDER_FLAGS_SHORT_TERM;
+        (
+            proto::GoodTil::Block(height + SHORT_TERM_ORDER_BLOCKS),
+            time_in_force,
+        )
+    } else {
+        order_id.order_flags = proto::ORDER_FLAGS_LONG_TERM;
+        let expiry =
+            u64::try_from(now / 1000).unwrap_or_default() + LONG_TERM_ORDER_LIFETIME.as_secs();
+        (
+            proto::GoodTil::BlockTime(u32::try_from(expiry).unwrap_or(u32::MAX)),
+            proto::TimeInForce::Unspecified,
+        )
+    };
+
+    Ok(proto::Order {
+        order_id,
+        side: match order.side {
+            OrderSide::Buy => proto::Side::Buy,
+            OrderSide::Sell => proto::Side::Sell,
+        },
+        quantums: quantized.quantums,
+        subticks: quantized.subticks,
+        good_til,
+        time_in_force,
+        reduce_only: order.reduce_only == Some(true) || order.close_position == Some(true),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn btc_market() -> dydx_types::DydxMarket {
+        serde_json::from_str(
+            r#"{"clobPairId":"0","ticker":"BTC-USD","status":"ACTIVE","oraclePrice":"65000.5","priceChange24H":"-150","volume24H":"1000000","trades24H":1200,"nextFundingRate":"0.0000125","initialMarginFraction":"0.05","maintenanceMarginFraction":"0.03","openInterest":"800","atomicResolution":-10,"quantumConversionExponent":-9,"tickSize":"1","stepSize":"0.0001","stepBaseQuantums":1000000,"subticksPerTick":100000,"marketType":"CROSS"}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_market_funding_and_quantization() {
+        let market = btc_market();
+        let converted = convert_dydx_market(&market).unwrap();
+        assert_eq!(converted.symbol.joined("-"), "BTC-USD");
+        assert_eq!(converted.base_precision, 4);
+        assert_eq!(converted.quote_precision, 0);
+
+        let rate = convert_dydx_market_funding(&market, 1_700_000_100_000);
+        assert_eq!(rate.funding_rate, Some(Decimal::new(125, 7)));
+        assert_eq!(rate.next_funding_time, Some(1_700_002_800_000));
+
+        // 0.01234 BTC rounds down to 0.0123, 65000.4 USD to the 1 USD tick
+        let order = quantize_order(
+            &market,
+            &OrderSide::Buy,
+            Decimal::new(1234, 5),
+            Decimal::new(650_004, 1),
+        )
+        .unwrap();
+        assert_eq!(order.quantums, 123_000_000);
+        assert_eq!(order.subticks, 6_500_000_000);
+
+        assert!(
+            quantize_order(&market, &OrderSide::Sell, Decimal::new(1, 5), Decimal::ONE).is_err()
+        );
+    }
+
+    #[test]
+    fn test_order_lifetimes() {
+        let market = btc_market();
+        let order_id = proto::OrderId {
+            owner: "dydx1".to_string(),
+            subaccount_number: 0,
+            client_id: 7,
+            order_flags: proto::ORDER_FLAGS_SHORT_TERM,
+            clob_pair_id: 0,
+        };
+        let order = OrderRequest {
+            symbol: convert_ticker_to_symbol("BTC-USD"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: Quantity::new(Decimal::new(1, 2)),
+            price: None,
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        };
+        let market_order =
+            convert_order_request(&order, &market, order_id.clone(), 100, 1_700_000_000_000)
+                .unwrap();
+        assert_eq!(
+            market_order.order_id.order_flags,
+            proto::ORDER_FLAGS_SHORT_TERM
+        );
+        assert_eq!(market_order.good_til, proto::GoodTil::Block(110));
+        assert_eq!(market_order.time_in_force, proto::TimeInForce::Ioc);
+        // 5% over the 65000.5 oracle price, to the 1 USD tick
+        assert_eq!(market_order.subticks, 6_825_100_000);
+
+        let limit = OrderRequest {
+            order_type: OrderType::Limit,
+            price: Some(Price::new(Decimal::from(64_000))),
+            ..order
+        };
+        let resting =
+            convert_order_request(&limit, &market, order_id, 100, 1_700_000_000_000).unwrap();
+        assert_eq!(resting.order_id.order_flags, proto::ORDER_FLAGS_LONG_TERM);
+        assert_eq!(
+            resting.good_til,
+            proto::GoodTil::BlockTime(1_700_000_000 + 28 * 24 * 60 * 60)
+        );
+        assert_eq!(resting.time_in_force, proto::TimeInForce::Unspecified);
+    }
+
+    #[test]
+    fn test_trades_and_candles() {
+        let trades: dydx_types::DydxTrades = serde_json::from_str(
+            r#"{"trades":[{"id":"014c6a4d0000000200000003","side":"SELL","size":"0.002","price":"64990","type":"LIMIT","createdAt":"2024-05-01T12:00:00.250Z","createdAtHeight":"21785165"}]}"#,
+        )
+        .unwrap();
+        let symbol = convert_ticker_to_symbol("BTC-USD");
+        let trade = convert_dydx_trade(&trades.trades[0], &symbol).unwrap();
+        assert_eq!(trade.id, 0x014c_6a4d << 32 | 2 << 16 | 3);
+        assert_eq!(trade.time, 1_714_564_800_250);
+        assert!(trade.is_buyer_maker);
+
+        let candle: dydx_types::DydxCandle = serde_json::from_str(
+            r#"{"startedAt":"2024-05-01T12:00:00.000Z","ticker":"BTC-USD","resolution":"1HOUR","low":"64800","high":"65100","open":"64900","close":"65000","baseTokenVolume":"12.5","usdVolume":"812000","trades":340,"startingOpenInterest":"800"}"#,
+        )
+        .unwrap();
+        let kline = convert_dydx_candle(&candle, 1_714_568_400_000).unwrap();
+        assert_eq!(kline.close_time, 1_714_568_399_999);
+        assert!(kline.final_bar);
+        assert_eq!(kline.number_of_trades, 340);
+
+        let ticker = convert_dydx_hourly_candles_to_ticker(&[candle], &symbol, 0).unwrap();
+        assert_eq!(ticker.price_change.value(), Decimal::from(100));
+        assert_eq!(ticker.quote_volume.value(), Decimal::from(812_000));
+    }
+}
@@ -0,0 +1,24 @@
+pub mod codec;
+pub mod conversions;
+pub mod proto;
+pub mod signer;
+pub mod types;
+
+pub mod builder;
+pub mod connector;
+pub mod rest;
+
+// Re-export main components
+pub use builder::{build_connector, build_connector_with_websocket, build_public};
+pub use codec::{DydxCodec, DydxMessage};
+pub use connector::{Account, DydxConnector, MarketData, Trading};
+pub use rest::DydxRestClient;
+pub use signer::DydxSigner;
+
+/// Helper function to create WebSocket stream identifiers for dYdX
+pub fn create_dydx_stream_identifiers(
+    tickers: &[String],
+    subscription_types: &[crate::core::types::SubscriptionType],
+) -> Result<Vec<String>, crate::core::errors::ExchangeError> {
+    codec::create_dydx_stream_identifiers(tickers, subscription_types)
+}
@@ -0,0 +1,213 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::RestClient;
+use crate::exchanges::dydx::signer::DydxSigner;
+use crate::exchanges::dydx::types::{
+    DydxAccountResponse, DydxBroadcastResponse, DydxCandles, DydxHeight, DydxHistoricalFundings,
+    DydxMarket, DydxMarkets, DydxOrderBook, DydxSubaccount, DydxSubaccountResponse, DydxTime,
+    DydxTrades, DydxTxResponse,
+};
+use base64::Engine;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Typed wrapper around the dYdX v4 indexer and a validator node's REST API
+///
+/// The indexer serves market and account data read-only; orders are signed
+/// transactions broadcast through the node.
+#[derive(Clone)]
+pub struct DydxRestClient<R: RestClient> {
+    indexer: R,
+    node: R,
+    signer: Option<Arc<DydxSigner>>,
+    chain_id: &'static str,
+    subaccount_number: u32,
+}
+
+impl<R: RestClient> DydxRestClient<R> {
+    pub fn new(
+        indexer: R,
+        node: R,
+        signer: Option<Arc<DydxSigner>>,
+        chain_id: &'static str,
+    ) -> Self {
+        Self {
+            indexer,
+            node,
+            signer,
+            chain_id,
+            subaccount_number: 0,
+        }
+    }
+
+    /// Trade and report on subaccount `number` of the signer's address, 0 by default
+    #[must_use]
+    pub fn with_subaccount_number(mut self, number: u32) -> Self {
+        self.subaccount_number = number;
+        self
+    }
+
+    /// Whether transactions can be signed
+    pub fn can_sign(&self) -> bool {
+        self.signer.is_some()
+    }
+
+    pub const fn subaccount_number(&self) -> u32 {
+        self.subaccount_number
+    }
+
+    pub(crate) fn signer(&self) -> Result<&DydxSigner, ExchangeError> {
+        self.signer.as_deref().ok_or_else(|| {
+            ExchangeError::AuthError("dYdX requires a private key to sign".to_string())
+        })
+    }
+
+    /// Get every perpetual market
+    pub async fn get_markets(&self) -> Result<Vec<DydxMarket>, ExchangeError> {
+        let markets: DydxMarkets = self
+            .indexer
+            .get_json("/perpetualMarkets", &[], false)
+            .await?;
+        Ok(markets.markets.into_values().collect())
+    }
+
+    /// Get one perpetual market
+    pub async fn get_market(&self, ticker: &str) -> Result<DydxMarket, ExchangeError> {
+        let params = [("ticker", ticker)];
+        let mut markets: DydxMarkets = self
+            .indexer
+            .get_json("/perpetualMarkets", &params, false)
+            .await?;
+        markets.markets.remove(ticker).ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Unknown dYdX market {}", ticker))
+        })
+    }
+
+    /// Get indexer time in milliseconds
+    pub async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let time: DydxTime = self.indexer.get_json("/time", &[], false).await?;
+        Ok((time.epoch * 1000.0).round() as i64)
+    }
+
+    /// Get the latest block height the indexer has processed
+    pub async fn get_height(&self) -> Result<u32, ExchangeError> {
+        let height: DydxHeight = self.indexer.get_json("/height", &[], false).await?;
+        height.height.parse().map_err(|e| {
+            ExchangeError::DeserializationError(format!("Invalid height {}: {}", height.height, e))
+        })
+    }
+
+    /// Get up to 100 candles, newest first; `from` and `to` are RFC 3339 times
+    pub async fn get_candles(
+        &self,
+        ticker: &str,
+        resolution: &str,
+        limit: Option<u32>,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<DydxCandles, ExchangeError> {
+        let endpoint = format!("/candles/perpetualMarkets/{}", ticker);
+        let limit = limit.map(|l| l.to_string());
+        let mut params = vec![("resolution", resolution)];
+        if let Some(limit) = &limit {
+            params.push(("limit", limit.as_str()));
+        }
+        if let Some(from) = from {
+            params.push(("fromISO", from));
+        }
+        if let Some(to) = to {
+            params.push(("toISO", to));
+        }
+        self.indexer.get_json(&endpoint, &params, false).await
+    }
+
+    /// Get the full order book
+    pub async fn get_order_book(&self, ticker: &str) -> Result<DydxOrderBook, ExchangeError> {
+        let endpoint = format!("/orderbooks/perpetualMarket/{}", ticker);
+        self.indexer.get_json(&endpoint, &[], false).await
+    }
+
+    /// Get recent trades, newest first
+    pub async fn get_trades(
+        &self,
+        ticker: &str,
+        limit: Option<u32>,
+    ) -> Result<DydxTrades, ExchangeError> {
+        let endpoint = format!("/trades/perpetualMarket/{}", ticker);
+        let limit = limit.map(|l| l.to_string());
+        let params: Vec<(&str, &str)> = limit.iter().map(|l| ("limit", l.as_str())).collect();
+        self.indexer.get_json(&endpoint, &params, false).await
+    }
+
+    /// Get settled funding, newest first, up to `effective_before_or_at` (RFC 3339)
+    pub async fn get_historical_funding(
+        &self,
+        ticker: &str,
+        limit: Option<u32>,
+        effective_before_or_at: Option<&str>,
+    ) -> Result<DydxHistoricalFundings, ExchangeError> {
+        let endpoint = format!("/historicalFunding/{}", ticker);
+        let limit = limit.map(|l| l.to_string());
+        let mut params = Vec::new();
+        if let Some(limit) = &limit {
+            params.push(("limit", limit.as_str()));
+        }
+        if let Some(before) = effective_before_or_at {
+            params.push(("effectiveBeforeOrAt", before));
+        }
+        self.indexer.get_json(&endpoint, &params, false).await
+    }
+
+    /// Get the signer's subaccount with its open positions
+    pub async fn get_subaccount(&self) -> Result<DydxSubaccount, ExchangeError> {
+        let endpoint = format!(
+            "/addresses/{}/subaccountNumber/{}",
+            self.signer()?.address(),
+            self.subaccount_number
+        );
+        let response: DydxSubaccountResponse = self.indexer.get_json(&endpoint, &[], false).await?;
+        Ok(response.subaccount)
+    }
+
+    /// Get the signer's account number and next sequence from the node
+    async fn get_account(&self, address: &str) -> Result<(u64, u64), ExchangeError> {
+        let endpoint = format!("/cosmos/auth/v1beta1/accounts/{}", address);
+        let response: DydxAccountResponse = self.node.get_json(&endpoint, &[], false).await?;
+        let parse = |value: &str| {
+            value.parse::<u64>().map_err(|e| {
+                ExchangeError::DeserializationError(format!(
+                    "Invalid account field {}: {}",
+                    value, e
+                ))
+            })
+        };
+        Ok((
+            parse(&response.account.account_number)?,
+            parse(&response.account.sequence)?,
+        ))
+    }
+
+    /// Sign `messages` into one transaction and broadcast it, returning once the node
+    /// has checked it into the mempool
+    pub async fn broadcast(&self, messages: &[Vec<u8>]) -> Result<DydxTxResponse, ExchangeError> {
+        let signer = self.signer()?;
+        let (account_number, sequence) = self.get_account(signer.address()).await?;
+        let tx = signer.sign_tx(messages, self.chain_id, account_number, sequence)?;
+
+        let body = json!({
+            "tx_bytes": base64::engine::general_purpose::STANDARD.encode(tx),
+            "mode": "BROADCAST_MODE_SYNC",
+        });
+        let response: DydxBroadcastResponse = self
+            .node
+            .post_json("/cosmos/tx/v1beta1/txs", &body, false)
+            .await?;
+        let tx = response.tx_response;
+        if tx.code != 0 {
+            return Err(ExchangeError::ApiError {
+                code: i32::try_from(tx.code).unwrap_or(i32::MAX),
+                message: tx.raw_log,
+            });
+        }
+        Ok(tx)
+    }
+}
@@ -0,0 +1,113 @@
+use crate::core::errors::ExchangeError;
+use crate::exchanges::dydx::proto;
+
+use bech32::{Bech32, Hrp};
+use ripemd::Ripemd160;
+use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+use sha2::{Digest, Sha256};
+
+/// Signs dYdX chain transactions with a Cosmos secp256k1 key
+///
+/// The indexer needs no authentication; orders are transactions the validator node
+/// verifies against the public key in `AuthInfo`, in `SIGN_MODE_DIRECT`.
+pub struct DydxSigner {
+    secret_key: SecretKey,
+    public_key: [u8; 33],
+    address: String,
+    secp: Secp256k1<secp256k1::All>,
+}
+
+impl DydxSigner {
+    /// Create a signer from a hex private key, with or without `0x`
+    pub fn new(private_key: &str) -> Result<Self, ExchangeError> {
+        let secret_key = SecretKey::from_slice(
+            &hex::decode(private_key.trim_start_matches("0x"))
+                .map_err(|e| ExchangeError::AuthError(format!("Invalid private key hex: {}", e)))?,
+        )
+        .map_err(|e| ExchangeError::AuthError(format!("Invalid private key: {}", e)))?;
+
+        let secp = Secp256k1::new();
+        let public_key = PublicKey::from_secret_key(&secp, &secret_key).serialize();
+        let address = public_key_to_address(&public_key)?;
+
+        Ok(Self {
+            secret_key,
+            public_key,
+            address,
+            secp,
+        })
+    }
+
+    /// Bech32 `dydx1...` address owning the subaccounts
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Sign a transaction of `messages` and return the `TxRaw` bytes to broadcast
+    pub fn sign_tx(
+        &self,
+        messages: &[Vec<u8>],
+        chain_id: &str,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Vec<u8>, ExchangeError> {
+        let body = proto::tx_body(messages);
+        let auth_info = proto::auth_info(&self.public_key, sequence);
+        let sign_doc = proto::sign_doc(&body, &auth_info, chain_id, account_number);
+
+        let digest = Sha256::digest(&sign_doc);
+        let message = Message::from_digest_slice(&digest)
+            .map_err(|e| ExchangeError::AuthError(format!("Invalid digest: {}", e)))?;
+        // Cosmos takes the 64-byte `r || s` form with a low `s`, as signed here
+        let signature = self
+            .secp
+            .sign_ecdsa(&message, &self.secret_key)
+            .serialize_compact();
+
+        Ok(proto::tx_raw(&body, &auth_info, &signature))
+    }
+}
+
+/// Bech32 address of a compressed public key: `ripemd160(sha256(key))` under `dydx`
+fn public_key_to_address(public_key: &[u8]) -> Result<String, ExchangeError> {
+    let hash = Ripemd160::digest(Sha256::digest(public_key));
+    let hrp = Hrp::parse("dydx")
+        .map_err(|e| ExchangeError::AuthError(format!("Invalid address prefix: {}", e)))?;
+    bech32::encode::<Bech32>(hrp, &hash)
+        .map_err(|e| ExchangeError::AuthError(format!("Failed to encode address: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secp256k1::ecdsa::Signature;
+
+    #[test]
+    fn test_derives_address_and_signs_verifiable_transactions() {
+        let signer =
+            DydxSigner::new("0x0000000000000000000000000000000000000000000000000000000000000001")
+                .unwrap();
+        // Generator point of secp256k1, as a Cosmos account
+        assert_eq!(
+            signer.address(),
+            "dydx1w508d6qejxtdg4y5r3zarvary0c5xw7knye700"
+        );
+
+        let tx = signer
+            .sign_tx(&[vec![1, 2, 3]], "dydx-testnet-4", 7, 2)
+            .unwrap();
+        let signature = Signature::from_compact(&tx[tx.len() - 64..]).unwrap();
+        let body = proto::tx_body(&[vec![1, 2, 3]]);
+        let auth_info = proto::auth_info(&signer.public_key, 2);
+        let digest = Sha256::digest(proto::sign_doc(&body, &auth_info, "dydx-testnet-4", 7));
+        let public_key = PublicKey::from_slice(&signer.public_key).unwrap();
+        assert!(signer
+            .secp
+            .verify_ecdsa(
+                &Message::from_digest_slice(&digest).unwrap(),
+                &signature,
+                &public_key
+            )
+            .is_ok());
+    }
+}
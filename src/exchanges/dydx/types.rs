@@ -0,0 +1,221 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// `/perpetualMarkets` reply, keyed by ticker
+#[derive(Debug, Deserialize)]
+pub struct DydxMarkets {
+    pub markets: HashMap<String, DydxMarket>,
+}
+
+/// Perpetual market of the indexer
+///
+/// Sizes are integer quantums of `10^atomicResolution` base units and prices integer
+/// subticks; see `conversions::quantize_order`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxMarket {
+    /// e.g. `BTC-USD`
+    pub ticker: String,
+    pub clob_pair_id: String,
+    /// `ACTIVE`, `PAUSED`, `CANCEL_ONLY`, `POST_ONLY`, `INITIALIZING` or `FINAL_SETTLEMENT`
+    pub status: String,
+    #[serde(default)]
+    pub oracle_price: Option<String>,
+    /// Hourly rate accruing towards the next settlement
+    #[serde(default)]
+    pub next_funding_rate: String,
+    pub atomic_resolution: i32,
+    pub quantum_conversion_exponent: i32,
+    pub tick_size: String,
+    pub step_size: String,
+    pub step_base_quantums: u64,
+    pub subticks_per_tick: u64,
+    #[serde(default)]
+    pub initial_margin_fraction: String,
+    #[serde(default)]
+    pub maintenance_margin_fraction: String,
+}
+
+/// Candle; `baseTokenVolume` is in the base asset, `usdVolume` in USD
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxCandle {
+    /// RFC 3339 open time
+    pub started_at: String,
+    pub ticker: String,
+    /// e.g. `1MIN` or `4HOURS`
+    pub resolution: String,
+    pub low: String,
+    pub high: String,
+    pub open: String,
+    pub close: String,
+    pub base_token_volume: String,
+    #[serde(default)]
+    pub usd_volume: String,
+    #[serde(default)]
+    pub trades: i64,
+}
+
+/// `/candles/perpetualMarkets/{ticker}` reply, newest first
+#[derive(Debug, Deserialize)]
+pub struct DydxCandles {
+    pub candles: Vec<DydxCandle>,
+}
+
+/// Book level of the REST book and the `v4_orderbook` snapshot
+#[derive(Debug, Clone, Deserialize)]
+pub struct DydxLevel {
+    pub price: String,
+    pub size: String,
+}
+
+/// `/orderbooks/perpetualMarket/{ticker}` reply and `v4_orderbook` snapshot
+#[derive(Debug, Clone, Deserialize)]
+pub struct DydxOrderBook {
+    #[serde(default)]
+    pub bids: Vec<DydxLevel>,
+    #[serde(default)]
+    pub asks: Vec<DydxLevel>,
+}
+
+/// `v4_orderbook` update; levels are `[price, size]` and size 0 removes a level
+#[derive(Debug, Clone, Deserialize)]
+pub struct DydxOrderBookUpdate {
+    #[serde(default)]
+    pub bids: Vec<(String, String)>,
+    #[serde(default)]
+    pub asks: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxTrade {
+    /// Hex event id: block height, transaction index and event index
+    pub id: String,
+    /// Taker side, `BUY` or `SELL`
+    pub side: String,
+    pub size: String,
+    pub price: String,
+    /// RFC 3339
+    pub created_at: String,
+}
+
+/// `/trades/perpetualMarket/{ticker}` reply and `v4_trades` payload, newest first
+#[derive(Debug, Clone, Deserialize)]
+pub struct DydxTrades {
+    pub trades: Vec<DydxTrade>,
+}
+
+/// Settled hourly funding
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxHistoricalFunding {
+    pub ticker: String,
+    pub rate: String,
+    /// Oracle price at settlement
+    pub price: String,
+    /// RFC 3339
+    pub effective_at: String,
+}
+
+/// `/historicalFunding/{ticker}` reply, newest first
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxHistoricalFundings {
+    pub historical_funding: Vec<DydxHistoricalFunding>,
+}
+
+/// `/time` reply
+#[derive(Debug, Deserialize)]
+pub struct DydxTime {
+    pub iso: String,
+    /// Unix seconds with a fraction
+    pub epoch: f64,
+}
+
+/// `/height` reply, the latest block the indexer has processed
+#[derive(Debug, Deserialize)]
+pub struct DydxHeight {
+    pub height: String,
+    pub time: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxPerpetualPosition {
+    pub market: String,
+    /// `LONG` or `SHORT`
+    pub side: String,
+    /// Negative for shorts
+    pub size: String,
+    pub entry_price: String,
+    #[serde(default)]
+    pub unrealized_pnl: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxSubaccount {
+    pub address: String,
+    pub subaccount_number: u32,
+    /// Collateral plus unrealized profit and loss, in USDC
+    pub equity: String,
+    pub free_collateral: String,
+    #[serde(default)]
+    pub open_perpetual_positions: HashMap<String, DydxPerpetualPosition>,
+}
+
+/// `/addresses/{address}/subaccountNumber/{number}` reply
+#[derive(Debug, Deserialize)]
+pub struct DydxSubaccountResponse {
+    pub subaccount: DydxSubaccount,
+}
+
+/// Cosmos `BaseAccount` of the node's auth module; numbers are decimal strings
+#[derive(Debug, Deserialize)]
+pub struct DydxBaseAccount {
+    pub account_number: String,
+    pub sequence: String,
+}
+
+/// `/cosmos/auth/v1beta1/accounts/{address}` reply
+#[derive(Debug, Deserialize)]
+pub struct DydxAccountResponse {
+    pub account: DydxBaseAccount,
+}
+
+/// Result of checking a broadcast transaction; `code` 0 means it entered the mempool
+#[derive(Debug, Deserialize)]
+pub struct DydxTxResponse {
+    pub txhash: String,
+    #[serde(default)]
+    pub code: u32,
+    #[serde(default)]
+    pub raw_log: String,
+}
+
+/// `/cosmos/tx/v1beta1/txs` reply
+#[derive(Debug, Deserialize)]
+pub struct DydxBroadcastResponse {
+    pub tx_response: DydxTxResponse,
+}
+
+/// Frame of the indexer WebSocket feed
+///
+/// `subscribed` carries the channel's snapshot in `contents`, `channel_data` one update
+/// and `channel_batch_data` an array of them.
+#[derive(Debug, Deserialize)]
+pub struct DydxWsEnvelope {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub channel: String,
+    /// Subscription id, e.g. `BTC-USD` or `BTC-USD/1MIN` for candles
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub contents: Value,
+    #[serde(default)]
+    pub message: Option<String>,
+}
@@ -0,0 +1,250 @@
+use crate::core::config::ExchangeConfig;
+use crate::core::errors::ExchangeError;
+use crate::exchanges::factory::ExchangeId;
+
+/// REST base URL and public WebSocket URL of one network
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Endpoints {
+    pub rest: &'static str,
+    pub ws: &'static str,
+}
+
+/// Production endpoints of a venue, plus its testnet or sandbox where it runs one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VenueEndpoints {
+    pub mainnet: Endpoints,
+    pub testnet: Option<Endpoints>,
+}
+
+impl VenueEndpoints {
+    /// Endpoints of the selected network, or `None` for a testnet the venue lacks
+    pub const fn network(self, testnet: bool) -> Option<Endpoints> {
+        if testnet {
+            self.testnet
+        } else {
+            Some(self.mainnet)
+        }
+    }
+}
+
+/// URLs a builder connects to for one `ExchangeConfig`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedEndpoints {
+    pub rest: String,
+    pub ws: String,
+}
+
+impl ExchangeId {
+    /// Every URL a builder defaults to for this venue
+    ///
+    /// Derivative families served by the same connector (Binance COIN-M, Bybit inverse)
+    /// pick their own hosts; the entries here are the default family.
+    #[allow(clippy::too_many_lines)]
+    pub const fn endpoints(self) -> VenueEndpoints {
+        match self {
+            Self::Binance => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.binance.com",
+                    ws: "wss://stream.binance.com:443/ws",
+                },
+                testnet: Some(Endpoints {
+                    rest: "https://testnet.binance.vision",
+                    ws: "wss://testnet.binance.vision/ws",
+                }),
+            },
+            // The spot testnet serves no `/sapi` margin endpoints
+            Self::BinanceMargin => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.binance.com",
+                    ws: "wss://stream.binance.com:443/ws",
+                },
+                testnet: None,
+            },
+            Self::BinancePerp => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://fapi.binance.com",
+                    ws: "wss://fstream.binance.com/ws",
+                },
+                testnet: Some(Endpoints {
+                    rest: "https://testnet.binancefuture.com",
+                    ws: "wss://stream.binancefuture.com/ws",
+                }),
+            },
+            Self::Bybit => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.bybit.com",
+                    ws: "wss://stream.bybit.com/v5/public/spot",
+                },
+                testnet: Some(Endpoints {
+                    rest: "https://api-testnet.bybit.com",
+                    ws: "wss://stream-testnet.bybit.com/v5/public/spot",
+                }),
+            },
+            Self::BybitPerp => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.bybit.com",
+                    ws: "wss://stream.bybit.com/v5/public/linear",
+                },
+                testnet: Some(Endpoints {
+                    rest: "https://api-testnet.bybit.com",
+                    ws: "wss://stream-testnet.bybit.com/v5/public/linear",
+                }),
+            },
+            Self::Backpack => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.backpack.exchange",
+                    ws: "wss://ws.backpack.exchange",
+                },
+                testnet: None,
+            },
+            // The sandbox mocks the brokerage endpoints only; public data stays on production
+            Self::Coinbase => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.coinbase.com",
+                    ws: "wss://advanced-trade-ws.coinbase.com",
+                },
+                testnet: Some(Endpoints {
+                    rest: "https://api-sandbox.coinbase.com",
+                    ws: "wss://advanced-trade-ws.coinbase.com",
+                }),
+            },
+            // Indexer only; orders go to a validator node, see `dydx::builder`
+            Self::Dydx => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://indexer.dydx.trade/v4",
+                    ws: "wss://indexer.dydx.trade/v4/ws",
+                },
+                testnet: Some(Endpoints {
+                    rest: "https://indexer.v4testnet.dydx.exchange/v4",
+                    ws: "wss://indexer.v4testnet.dydx.exchange/v4/ws",
+                }),
+            },
+            // Gate.io's testnet covers futures only
+            Self::Gateio => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.gateio.ws",
+                    ws: "wss://api.gateio.ws/ws/v4/",
+                },
+                testnet: None,
+            },
+            Self::GateioPerp => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.gateio.ws",
+                    ws: "wss://fx-ws.gateio.ws/v4/ws/usdt",
+                },
+                testnet: Some(Endpoints {
+                    rest: "https://fx-api-testnet.gateio.ws",
+                    ws: "wss://fx-ws-testnet.gateio.ws/v4/ws/usdt",
+                }),
+            },
+            Self::Hyperliquid => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.hyperliquid.xyz",
+                    ws: "wss://api.hyperliquid.xyz/ws",
+                },
+                testnet: Some(Endpoints {
+                    rest: "https://api.hyperliquid-testnet.xyz",
+                    ws: "wss://api.hyperliquid-testnet.xyz/ws",
+                }),
+            },
+            // Kraken has no spot testnet
+            Self::Kraken => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.kraken.com",
+                    ws: "wss://ws.kraken.com/v2",
+                },
+                testnet: None,
+            },
+            // Demo trading shares the REST host and is selected per request by a header
+            Self::Okx | Self::OkxSwap => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://www.okx.com",
+                    ws: "wss://ws.okx.com:8443/ws/v5/public",
+                },
+                testnet: Some(Endpoints {
+                    rest: "https://www.okx.com",
+                    ws: "wss://wspap.okx.com:8443/ws/v5/public",
+                }),
+            },
+            Self::Paradex => VenueEndpoints {
+                mainnet: Endpoints {
+                    rest: "https://api.paradex.trade",
+                    ws: "wss://ws.paradex.trade/v1",
+                },
+                testnet: Some(Endpoints {
+                    rest: "https://api.testnet.paradex.trade",
+                    ws: "wss://ws.testnet.paradex.trade/v1",
+                }),
+            },
+        }
+    }
+}
+
+/// Resolve the URLs `config` selects on `exchange`
+///
+/// `config.base_url` replaces the REST URL on either network, e.g. to point at a local
+/// mock. Asking for the testnet of a venue without one is a configuration error rather
+/// than a silent fallback to production.
+pub fn resolve_endpoints(
+    exchange: ExchangeId,
+    config: &ExchangeConfig,
+) -> Result<ResolvedEndpoints, ExchangeError> {
+    let endpoints = exchange
+        .endpoints()
+        .network(config.testnet)
+        .ok_or_else(|| {
+            ExchangeError::ConfigurationError(format!("{} has no testnet or sandbox", exchange))
+        })?;
+    Ok(ResolvedEndpoints {
+        rest: config
+            .base_url
+            .clone()
+            .unwrap_or_else(|| endpoints.rest.to_string()),
+        ws: endpoints.ws.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolves_the_selected_network() {
+        let mainnet =
+            resolve_endpoints(ExchangeId::Hyperliquid, &ExchangeConfig::read_only()).unwrap();
+        assert_eq!(mainnet.rest, "https://api.hyperliquid.xyz");
+
+        let testnet = resolve_endpoints(
+            ExchangeId::Hyperliquid,
+            &ExchangeConfig::read_only().testnet(true),
+        )
+        .unwrap();
+        assert_eq!(testnet.rest, "https://api.hyperliquid-testnet.xyz");
+        assert_eq!(testnet.ws, "wss://api.hyperliquid-testnet.xyz/ws");
+
+        let mock = resolve_endpoints(
+            ExchangeId::Binance,
+            &ExchangeConfig::read_only()
+                .testnet(true)
+                .base_url("http://127.0.0.1:8080".to_string()),
+        )
+        .unwrap();
+        assert_eq!(mock.rest, "http://127.0.0.1:8080");
+        assert_eq!(mock.ws, "wss://testnet.binance.vision/ws");
+    }
+
+    #[test]
+    fn test_venues_without_a_testnet_refuse_it() {
+        for id in ExchangeId::ALL {
+            let result = resolve_endpoints(id, &ExchangeConfig::read_only().testnet(true));
+            match id.endpoints().testnet {
+                Some(_) => assert!(result.is_ok(), "{} has a testnet", id),
+                None => assert!(
+                    matches!(result, Err(ExchangeError::ConfigurationError(_))),
+                    "{} has no testnet",
+                    id
+                ),
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::core::config::ExchangeConfig;
+use crate::core::errors::ExchangeError;
+use crate::core::traits::ExchangeConnector;
+use crate::exchanges::{
+    backpack, binance, binance_margin, binance_perp, bybit, bybit_perp, coinbase, dydx, gateio,
+    gateio_perp, hyperliquid, kraken, okx, paradex,
+};
+use std::fmt;
+use std::str::FromStr;
+
+/// Venue selectable at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExchangeId {
+    Binance,
+    BinanceMargin,
+    BinancePerp,
+    Bybit,
+    BybitPerp,
+    Backpack,
+    Coinbase,
+    Dydx,
+    Gateio,
+    GateioPerp,
+    Hyperliquid,
+    Kraken,
+    Okx,
+    OkxSwap,
+    Paradex,
+}
+
+impl ExchangeId {
+    pub const ALL: [Self; 15] = [
+        Self::Binance,
+        Self::BinanceMargin,
+        Self::BinancePerp,
+        Self::Bybit,
+        Self::BybitPerp,
+        Self::Backpack,
+        Self::Coinbase,
+        Self::Dydx,
+        Self::Gateio,
+        Self::GateioPerp,
+        Self::Hyperliquid,
+        Self::Kraken,
+        Self::Okx,
+        Self::OkxSwap,
+        Self::Paradex,
+    ];
+
+    /// Registry key, as used in config files
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Binance => "binance",
+            Self::BinanceMargin => "binance_margin",
+            Self::BinancePerp => "binance_perp",
+            Self::Bybit => "bybit",
+            Self::BybitPerp => "bybit_perp",
+            Self::Backpack => "backpack",
+            Self::Coinbase => "coinbase",
+            Self::Dydx => "dydx",
+            Self::Gateio => "gateio",
+            Self::GateioPerp => "gateio_perp",
+            Self::Hyperliquid => "hyperliquid",
+            Self::Kraken => "kraken",
+            Self::Okx => "okx",
+            Self::OkxSwap => "okx_swap",
+            Self::Paradex => "paradex",
+        }
+    }
+}
+
+impl fmt::Display for ExchangeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ExchangeId {
+    type Err = ExchangeError;
+
+    /// Case-insensitive; `-` is accepted in place of `_`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key = s.to_lowercase().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|id| id.as_str() == key)
+            .ok_or_else(|| ExchangeError::InvalidParameters(format!("Unknown exchange: {}", s)))
+    }
+}
+
+/// Build a connector for `exchange` behind the common trait object
+///
+/// REST, signer, codec and WebSocket are wired the same way as the exchange's own
+/// WebSocket-enabled builder. Bybit spot streams without a session, so it is built
+/// REST-only. Without credentials in `config` the connector serves market data only.
+pub fn build_connector(
+    exchange: ExchangeId,
+    config: ExchangeConfig,
+) -> Result<Box<dyn ExchangeConnector>, ExchangeError> {
+    let connector: Box<dyn ExchangeConnector> = match exchange {
+        ExchangeId::Binance => Box::new(binance::build_connector_with_websocket(config)?),
+        ExchangeId::BinanceMargin => {
+            Box::new(binance_margin::build_connector_with_websocket(config)?)
+        }
+        ExchangeId::BinancePerp => Box::new(binance_perp::build_connector_with_websocket(config)?),
+        ExchangeId::Bybit => Box::new(bybit::build_connector(config)?),
+        ExchangeId::BybitPerp => Box::new(bybit_perp::build_connector_with_websocket(config)?),
+        ExchangeId::Backpack => Box::new(backpack::build_connector_with_websocket(config)?),
+        ExchangeId::Coinbase => Box::new(coinbase::build_connector_with_websocket(config)?),
+        ExchangeId::Dydx => Box::new(dydx::build_connector_with_websocket(config)?),
+        ExchangeId::Gateio => Box::new(gateio::build_connector_with_websocket(config)?),
+        ExchangeId::GateioPerp => Box::new(gateio_perp::build_connector_with_websocket(config)?),
+        ExchangeId::Hyperliquid => Box::new(
+            hyperliquid::build_hyperliquid_connector_with_websocket(config)?,
+        ),
+        ExchangeId::Kraken => Box::new(kraken::build_connector_with_websocket(config)?),
+        ExchangeId::Okx => Box::new(okx::build_connector_with_websocket(config)?),
+        ExchangeId::OkxSwap => Box::new(okx::build_swap_connector_with_websocket(config)?),
+        ExchangeId::Paradex => Box::new(paradex::build_connector_with_websocket(config)?),
+    };
+    Ok(connector)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{KlineInterval, Symbol};
+
+    #[test]
+    fn test_exchange_id_round_trips_through_its_key() {
+        for id in ExchangeId::ALL {
+            assert_eq!(id.to_string().parse::<ExchangeId>().unwrap(), id);
+        }
+        assert_eq!(
+            "Binance-Perp".parse::<ExchangeId>().unwrap(),
+            ExchangeId::BinancePerp
+        );
+        assert!("ftx".parse::<ExchangeId>().is_err());
+    }
+
+    #[test]
+    fn test_builds_every_exchange_without_credentials() {
+        for id in ExchangeId::ALL {
+            assert!(
+                build_connector(id, ExchangeConfig::read_only()).is_ok(),
+                "{} failed to build",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_testnet_builds_only_where_the_venue_has_one() {
+        for id in ExchangeId::ALL {
+            let result = build_connector(id, ExchangeConfig::read_only().testnet(true));
+            assert_eq!(
+                result.is_ok(),
+                id.endpoints().testnet.is_some(),
+                "{} testnet build",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_paradex_testnet_streams_from_the_testnet() {
+        let connector = build_connector(
+            ExchangeId::Paradex,
+            ExchangeConfig::read_only().testnet(true),
+        )
+        .unwrap();
+        assert_eq!(
+            connector.get_websocket_url(),
+            "wss://ws.testnet.paradex.trade/v1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mistyped_symbols_are_rejected_before_sending() {
+        for id in ExchangeId::ALL {
+            let connector = build_connector(id, ExchangeConfig::read_only()).unwrap();
+            // `USTD` is no quote asset, so this parses as a bare base
+            let symbol = if id.symbol_convention().implied_quote.is_some() {
+                Symbol::from("BTC$")
+            } else {
+                Symbol::from("BTCUSTD")
+            };
+            let result = connector
+                .get_klines(symbol, KlineInterval::Hours1, Some(1), None, None)
+                .await;
+            assert!(
+                matches!(result, Err(ExchangeError::InvalidParameters(_))),
+                "{} sent a mistyped symbol: {:?}",
+                id,
+                result
+            );
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{ControlFrame, WsCodec};
+use crate::core::types::{OrderBookMode, SubscriptionType};
+use crate::exchanges::gateio::conversions::kline_interval_to_gateio;
+use crate::exchanges::gateio::types::{
+    GateioTicker, GateioTrade, GateioWsBook, GateioWsBookUpdate, GateioWsCandle, GateioWsEnvelope,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio_tungstenite::tungstenite::Message;
+
+/// Book depths `spot.order_book` offers
+const BOOK_DEPTHS: [u32; 5] = [5, 10, 20, 50, 100];
+
+#[derive(Debug, Clone)]
+pub enum GateioMessage {
+    Ticker {
+        timestamp: i64,
+        ticker: GateioTicker,
+    },
+    Trade(GateioTrade),
+    Book(GateioWsBook),
+    BookUpdate(GateioWsBookUpdate),
+    Candle(GateioWsCandle),
+    Unknown,
+}
+
+/// Codec for the Gate.io spot WebSocket v4 feed
+pub struct GateioCodec;
+
+impl GateioCodec {
+    /// Streams are `channel@PAIR`, where the channel may carry a parameter after a
+    /// colon: `spot.order_book:20@BTC_USDT` or `spot.candlesticks:1m@BTC_USDT`. Book
+    /// snapshots may add their push interval, as in `spot.order_book:20:1000ms`.
+    ///
+    /// A request covers one channel; books and candles also cover one pair, see
+    /// `gateio_request_groups`.
+    fn encode_request(
+        event: &str,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        let mut channel = None;
+        let mut pairs = Vec::new();
+        for stream in streams {
+            let (stream_channel, pair) = stream.as_ref().split_once('@').ok_or_else(|| {
+                ExchangeError::InvalidParameters(format!(
+                    "Invalid Gate.io stream: {}",
+                    stream.as_ref()
+                ))
+            })?;
+            if channel.is_some_and(|c| c != stream_channel) {
+                return Err(ExchangeError::InvalidParameters(
+                    "Gate.io streams of one request must share a channel".to_string(),
+                ));
+            }
+            channel = Some(stream_channel);
+            pairs.push(pair);
+        }
+
+        let channel = channel.unwrap_or_default();
+        let (name, parameter) = channel.split_once(':').unwrap_or((channel, ""));
+        let payload = match (name, pairs.as_slice()) {
+            ("spot.order_book", [pair]) => {
+                let (level, interval) = parameter.split_once(':').unwrap_or((parameter, "100ms"));
+                json!([pair, level, interval])
+            }
+            ("spot.order_book_update", [pair]) => json!([pair, parameter]),
+            ("spot.candlesticks", [pair]) => json!([parameter, pair]),
+            ("spot.order_book" | "spot.order_book_update" | "spot.candlesticks", _) => {
+                return Err(ExchangeError::InvalidParameters(format!(
+                    "Gate.io {} takes one pair per request",
+                    name
+                )))
+            }
+            _ => json!(pairs),
+        };
+
+        let request = json!({
+            "time": chrono::Utc::now().timestamp(),
+            "channel": name,
+            "event": event,
+            "payload": payload,
+        });
+        Ok(Message::Text(request.to_string()))
+    }
+
+    fn result<T: DeserializeOwned>(envelope: GateioWsEnvelope) -> Result<T, ExchangeError> {
+        serde_json::from_value(envelope.result).map_err(|e| {
+            ExchangeError::DeserializationError(format!(
+                "Failed to parse {}: {}",
+                envelope.channel, e
+            ))
+        })
+    }
+}
+
+impl WsCodec for GateioCodec {
+    type Message = GateioMessage;
+
+    fn encode_subscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Self::encode_request("subscribe", streams)
+    }
+
+    fn encode_unsubscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Self::encode_request("unsubscribe", streams)
+    }
+
+    fn encode_ping(&self) -> Option<Message> {
+        let ping = json!({ "time": chrono::Utc::now().timestamp(), "channel": "spot.ping" });
+        Some(Message::Text(ping.to_string()))
+    }
+
+    fn acknowledges_subscriptions(&self) -> bool {
+        true
+    }
+
+    fn control_frame(&self, message: &Message) -> Option<ControlFrame> {
+        let Message::Text(text) = message else {
+            return None;
+        };
+        if !text.contains("spot.pong") && !text.contains("\"subscribe\"") {
+            return None;
+        }
+        let envelope: GateioWsEnvelope = serde_json::from_str(text).ok()?;
+        let frame = if envelope.channel == "spot.pong" {
+            ControlFrame {
+                pong: true,
+                ..ControlFrame::default()
+            }
+        } else if envelope.event == "subscribe"
+            && envelope.error.is_none()
+            && envelope.result.get("status").and_then(Value::as_str) == Some("success")
+        {
+            ControlFrame {
+                subscribed: true,
+                ..ControlFrame::default()
+            }
+        } else {
+            return None;
+        };
+        Some(frame)
+    }
+
+    fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
+        let Message::Text(text) = message else {
+            return Ok(None);
+        };
+        let envelope: GateioWsEnvelope = serde_json::from_str(&text).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse message: {}", e))
+        })?;
+        if let Some(error) = &envelope.error {
+            return Err(ExchangeError::WebSocketError(format!(
+                "Gate.io error {}: {}",
+                error.code, error.message
+            )));
+        }
+        // Subscription acks and pongs
+        if envelope.event != "update" {
+            return Ok(Some(GateioMessage::Unknown));
+        }
+
+        let message = match envelope.channel.as_str() {
+            "spot.tickers" => GateioMessage::Ticker {
+                timestamp: envelope
+                    .time_ms
+                    .unwrap_or_else(|| chrono::Utc::now().timestamp_millis()),
+                ticker: Self::result(envelope)?,
+            },
+            "spot.trades" => GateioMessage::Trade(Self::result(envelope)?),
+            "spot.order_book" => GateioMessage::Book(Self::result(envelope)?),
+            "spot.order_book_update" => GateioMessage::BookUpdate(Self::result(envelope)?),
+            "spot.candlesticks" => GateioMessage::Candle(Self::result(envelope)?),
+            _ => GateioMessage::Unknown,
+        };
+        Ok(Some(message))
+    }
+}
+
+/// Create Gate.io spot stream identifiers, as `channel@PAIR`
+///
+/// Book snapshots round the requested depth up to one Gate.io offers, 100 for the full
+/// book; deltas always cover the full book.
+pub fn create_gateio_stream_identifiers(
+    pairs: &[String],
+    subscription_types: &[SubscriptionType],
+) -> Result<Vec<String>, ExchangeError> {
+    let mut streams = Vec::new();
+    for subscription in subscription_types {
+        let channel = match subscription {
+            SubscriptionType::Ticker => "spot.tickers".to_string(),
+            SubscriptionType::OrderBook { depth, mode } => {
+                let depth = depth
+                    .and_then(|depth| BOOK_DEPTHS.into_iter().find(|d| *d >= depth))
+                    .unwrap_or(100);
+                match mode {
+                    OrderBookMode::Snapshot => format!("spot.order_book:{}", depth),
+                    OrderBookMode::Delta => "spot.order_book_update:100ms".to_string(),
+                    OrderBookMode::Aggregated => format!("spot.order_book:{}:1000ms", depth),
+                }
+            }
+            SubscriptionType::Trades | SubscriptionType::AggTrades => "spot.trades".to_string(),
+            SubscriptionType::Klines { interval } => {
+                let interval = kline_interval_to_gateio(*interval).ok_or_else(|| {
+                    ExchangeError::NotSupported(format!(
+                        "Gate.io does not offer {} candles",
+                        interval
+                    ))
+                })?;
+                format!("spot.candlesticks:{}", interval)
+            }
+            // Spot has no mark price
+            SubscriptionType::MarkPrice => continue,
+        };
+        for pair in pairs {
+            let stream = format!("{}@{}", channel, pair);
+            if !streams.contains(&stream) {
+                streams.push(stream);
+            }
+        }
+    }
+    Ok(streams)
+}
+
+/// Split streams into the requests Gate.io takes: one per channel, and one per pair
+/// for channels with parameters
+pub fn gateio_request_groups(streams: Vec<String>) -> Vec<Vec<String>> {
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    for stream in streams {
+        let channel = stream.split('@').next().unwrap_or_default();
+        let key = if channel.contains(':') {
+            stream.clone()
+        } else {
+            channel.to_string()
+        };
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, group)) => group.push(stream),
+            None => groups.push((key, vec![stream])),
+        }
+    }
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encodes_one_request_per_channel_and_parameterized_pair() {
+        let streams = create_gateio_stream_identifiers(
+            &["BTC_USDT".to_string(), "ETH_USDT".to_string()],
+            &[
+                SubscriptionType::Ticker,
+                SubscriptionType::OrderBook {
+                    depth: Some(15),
+                    mode: OrderBookMode::Snapshot,
+                },
+            ],
+        )
+        .unwrap();
+        let groups = gateio_request_groups(streams);
+        assert_eq!(
+            groups,
+            vec![
+                vec!["spot.tickers@BTC_USDT", "spot.tickers@ETH_USDT"],
+                vec!["spot.order_book:20@BTC_USDT"],
+                vec!["spot.order_book:20@ETH_USDT"],
+            ]
+        );
+
+        let Message::Text(text) = GateioCodec.encode_subscription(&groups[1]).unwrap() else {
+            panic!("expected text");
+        };
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value["channel"], "spot.order_book");
+        assert_eq!(value["event"], "subscribe");
+        assert_eq!(value["payload"], json!(["BTC_USDT", "20", "100ms"]));
+        assert!(GateioCodec
+            .encode_subscription(&["spot.order_book:20@BTC_USDT", "spot.order_book:20@ETH_USDT"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_book_modes_pick_channel_and_interval() {
+        let pairs = ["BTC_USDT".to_string()];
+        let payloads: Vec<Value> = [OrderBookMode::Delta, OrderBookMode::Aggregated]
+            .into_iter()
+            .map(|mode| {
+                let streams = create_gateio_stream_identifiers(
+                    &pairs,
+                    &[SubscriptionType::OrderBook {
+                        depth: Some(10),
+                        mode,
+                    }],
+                )
+                .unwrap();
+                let Message::Text(text) = GateioCodec.encode_subscription(&streams).unwrap() else {
+                    panic!("expected text");
+                };
+                serde_json::from_str(&text).unwrap()
+            })
+            .collect();
+        assert_eq!(payloads[0]["channel"], "spot.order_book_update");
+        assert_eq!(payloads[0]["payload"], json!(["BTC_USDT", "100ms"]));
+        assert_eq!(payloads[1]["channel"], "spot.order_book");
+        assert_eq!(payloads[1]["payload"], json!(["BTC_USDT", "10", "1000ms"]));
+
+        let text = r#"{"time":1606294781,"time_ms":1606294781236,"channel":"spot.order_book_update","event":"update","result":{"t":1606294781123,"e":"depthUpdate","E":1606294781,"s":"BTC_USDT","U":48776301,"u":48776306,"b":[["19137.74","0.0001"]],"a":[["19137.75","0"]]}}"#;
+        let Some(GateioMessage::BookUpdate(update)) = GateioCodec
+            .decode_message(Message::Text(text.to_string()))
+            .unwrap()
+        else {
+            panic!("expected book update");
+        };
+        assert_eq!(update.last_update_id, 48_776_306);
+        assert_eq!(update.asks[0][1], "0");
+    }
+
+    #[test]
+    fn test_decodes_updates_and_errors() {
+        let text = r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"update","result":{"id":309143071,"create_time":1606292218,"create_time_ms":"1606292218213.4578","side":"sell","currency_pair":"GT_USDT","amount":"16.47","price":"0.4705"}}"#;
+        let Some(GateioMessage::Trade(trade)) = GateioCodec
+            .decode_message(Message::Text(text.to_string()))
+            .unwrap()
+        else {
+            panic!("expected trade");
+        };
+        assert_eq!(trade.id, 309_143_071);
+        assert_eq!(trade.price, "0.4705");
+
+        let ack = r#"{"time":1606292218,"channel":"spot.tickers","event":"subscribe","result":{"status":"success"}}"#;
+        let frame = GateioCodec.control_frame(&Message::Text(ack.to_string()));
+        assert!(frame.is_some_and(|frame| frame.subscribed));
+
+        let error = r#"{"time":1606292218,"channel":"spot.tickers","event":"subscribe","error":{"code":2,"message":"unknown currency pair"},"result":null}"#;
+        assert!(GateioCodec
+            .decode_message(Message::Text(error.to_string()))
+            .is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::{RestClient, TungsteniteWs, WsConfig, WsSession},
+    streaming::{SharedHub, StreamRouter},
+    traits::{MarketSnapshotSource, RestMarketData, StreamingMarketData},
+    types::{
+        Kline, KlineInterval, Market, MarketDataType, OrderBook, SubscriptionType, Symbol, Ticker,
+        Trade, WebSocketConfig,
+    },
+};
+use crate::exchanges::factory::ExchangeId;
+use crate::exchanges::gateio::{
+    codec::{create_gateio_stream_identifiers, gateio_request_groups, GateioCodec, GateioMessage},
+    conversions::{
+        convert_gateio_candle, convert_gateio_market, convert_gateio_order_book,
+        convert_gateio_ticker, convert_gateio_trade, convert_gateio_ws_book,
+        convert_gateio_ws_book_update, convert_gateio_ws_candle, convert_symbol_to_pair,
+        kline_interval_to_gateio,
+    },
+    rest::GateioRestClient,
+};
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+pub const GATEIO_WS_URL: &str = ExchangeId::Gateio.endpoints().mainnet.ws;
+
+/// Most candles Gate.io returns per request
+const MAX_CANDLES: u32 = 1000;
+
+/// Deepest book served by `/spot/order_book`
+const MAX_BOOK_DEPTH: u32 = 100;
+
+/// Routes Gate.io spot updates, subscribing one channel per request
+struct GateioRouter;
+
+impl StreamRouter for GateioRouter {
+    type Codec = GateioCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        Ok(create_gateio_stream_identifiers(
+            &[convert_symbol_to_pair(symbol)?],
+            std::slice::from_ref(subscription),
+        )?
+        .pop())
+    }
+
+    fn request_groups(&self, streams: Vec<String>) -> Vec<Vec<String>> {
+        gateio_request_groups(streams)
+    }
+
+    fn convert(&self, message: GateioMessage) -> Vec<MarketDataType> {
+        match convert_gateio_message_to_market_data(message) {
+            Ok(update) => update.into_iter().collect(),
+            Err(e) => {
+                tracing::warn!(error = %e, "Dropping malformed Gate.io message");
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Market data implementation for Gate.io spot
+pub struct MarketData<R: RestClient, W = ()> {
+    rest: GateioRestClient<R>,
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
+    ws: Option<W>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
+}
+
+impl<R: RestClient + Clone, W> MarketData<R, W> {
+    /// Create a new market data source
+    pub fn new(rest: &R, ws: Option<W>) -> Self {
+        Self {
+            rest: GateioRestClient::new(rest.clone()),
+            ws,
+            hub: SharedHub::new(),
+            ws_config: WsConfig::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> RestMarketData for MarketData<R, W> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.rest
+            .get_currency_pairs()
+            .await?
+            .iter()
+            .map(convert_gateio_market)
+            .collect()
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        let venue_interval = kline_interval_to_gateio(interval).ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Gate.io does not offer {} candles", interval))
+        })?;
+        let limit = limit.unwrap_or(MAX_CANDLES).min(MAX_CANDLES);
+
+        // A range may span at most `MAX_CANDLES` candles, so an open end is capped
+        let span = interval.duration_millis() * i64::from(limit - 1);
+        let (from, to) = match (start_time, end_time) {
+            (Some(start), None) => (Some(start), Some(start + span)),
+            (None, Some(end)) => (Some(end - span), Some(end)),
+            range => range,
+        };
+        let candles = self
+            .rest
+            .get_candlesticks(
+                &convert_symbol_to_pair(&symbol)?,
+                venue_interval,
+                Some(limit),
+                from.map(|from| from / 1000),
+                to.map(|to| to / 1000),
+            )
+            .await?;
+
+        let mut klines = candles
+            .iter()
+            .map(|candle| convert_gateio_candle(candle, &symbol, interval))
+            .collect::<Result<Vec<_>, _>>()?;
+        if start_time.is_some() {
+            klines.truncate(limit as usize);
+        } else {
+            let excess = klines.len().saturating_sub(limit as usize);
+            klines.drain(..excess);
+        }
+        Ok(klines)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> MarketSnapshotSource for MarketData<R, W> {
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let ticker = self
+            .rest
+            .get_tickers(&convert_symbol_to_pair(&symbol)?)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                ExchangeError::InvalidResponseFormat(format!("No ticker for {}", symbol))
+            })?;
+        let ticker = convert_gateio_ticker(&ticker, chrono::Utc::now().timestamp_millis())?;
+        Ok(Ticker { symbol, ..ticker })
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let limit = depth.map(|depth| depth.min(MAX_BOOK_DEPTH));
+        let book = self
+            .rest
+            .get_order_book(&convert_symbol_to_pair(&symbol)?, limit)
+            .await?;
+        Ok(convert_gateio_order_book(&book, &symbol)?.truncated(depth))
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let trades = self
+            .rest
+            .get_trades(&convert_symbol_to_pair(&symbol)?, limit)
+            .await?;
+        // Newest first over the wire
+        trades
+            .iter()
+            .rev()
+            .map(|trade| {
+                Ok(Trade {
+                    symbol: symbol.clone(),
+                    ..convert_gateio_trade(trade)?
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: WsSession<GateioCodec> + Send + Sync> StreamingMarketData
+    for MarketData<R, W>
+{
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(GATEIO_WS_URL.to_string(), "gateio".to_string(), GateioCodec)
+                        .with_config(self.ws_config.clone())
+                },
+                GateioRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        GATEIO_WS_URL.to_string()
+    }
+}
+
+/// Convert `GateioMessage` to `MarketDataType`
+fn convert_gateio_message_to_market_data(
+    message: GateioMessage,
+) -> Result<Option<MarketDataType>, ExchangeError> {
+    Ok(Some(match message {
+        GateioMessage::Ticker { timestamp, ticker } => {
+            MarketDataType::Ticker(convert_gateio_ticker(&ticker, timestamp)?)
+        }
+        GateioMessage::Trade(trade) => MarketDataType::Trade(convert_gateio_trade(&trade)?),
+        GateioMessage::Book(book) => MarketDataType::OrderBook(convert_gateio_ws_book(&book)?),
+        GateioMessage::BookUpdate(update) => {
+            MarketDataType::OrderBook(convert_gateio_ws_book_update(&update)?)
+        }
+        GateioMessage::Candle(candle) => MarketDataType::Kline(convert_gateio_ws_candle(&candle)?),
+        GateioMessage::Unknown => return Ok(None),
+    }))
+}
@@ -0,0 +1,354 @@
+use super::types as gateio_types;
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
+use crate::core::types::{
+    conversion, Balance, ContractStyle, Kline, KlineInterval, Market, MarketStatus, OrderBook,
+    OrderRequest, OrderSide, OrderType, Price, Symbol, Ticker, TimeInForce, Trade,
+};
+use crate::exchanges::factory::ExchangeId;
+use rust_decimal::Decimal;
+
+/// Gate.io spot symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::Gateio.symbol_convention();
+
+/// Convert symbol to Gate.io currency pair, e.g. `BTC_USDT`
+pub fn convert_symbol_to_pair(symbol: &Symbol) -> Result<String, ExchangeError> {
+    SYMBOLS.try_to_exchange_symbol(symbol)
+}
+
+/// Convert a `BASE_QUOTE` Gate.io currency pair to symbol
+pub fn convert_pair_to_symbol(pair: &str) -> Symbol {
+    SYMBOLS.from_exchange_symbol(pair)
+}
+
+/// Gate.io candlestick interval, `None` for intervals Gate.io does not offer
+pub const fn kline_interval_to_gateio(interval: KlineInterval) -> Option<&'static str> {
+    match interval {
+        KlineInterval::Minutes1 => Some("1m"),
+        KlineInterval::Minutes5 => Some("5m"),
+        KlineInterval::Minutes15 => Some("15m"),
+        KlineInterval::Minutes30 => Some("30m"),
+        KlineInterval::Hours1 => Some("1h"),
+        KlineInterval::Hours4 => Some("4h"),
+        KlineInterval::Hours8 => Some("8h"),
+        KlineInterval::Days1 => Some("1d"),
+        KlineInterval::Weeks1 => Some("7d"),
+        KlineInterval::Months1 => Some("30d"),
+        _ => None,
+    }
+}
+
+/// Inverse of `kline_interval_to_gateio`
+pub fn gateio_to_kline_interval(interval: &str) -> Option<KlineInterval> {
+    match interval {
+        "7d" => Some(KlineInterval::Weeks1),
+        "30d" => Some(KlineInterval::Months1),
+        _ => KlineInterval::from_venue_str(interval),
+    }
+}
+
+/// Parse `"1606292218213.4578"` style millisecond times
+pub fn parse_fractional_millis(time: &str) -> Result<i64, ExchangeError> {
+    let whole = time.split_once('.').map_or(time, |(whole, _)| whole);
+    whole
+        .parse()
+        .map_err(|e| ExchangeError::DeserializationError(format!("Invalid time {:?}: {}", time, e)))
+}
+
+/// Convert gateio currency pair to core market type
+pub fn convert_gateio_market(
+    pair: &gateio_types::GateioCurrencyPair,
+) -> Result<Market, ExchangeError> {
+    Ok(Market {
+        symbol: Symbol::new(&pair.base, &pair.quote)
+            .unwrap_or_else(|_| convert_pair_to_symbol(&pair.id)),
+        status: MarketStatus::from_venue_str(&pair.trade_status),
+        base_precision: pair.amount_precision,
+        quote_precision: pair.precision,
+        min_qty: pair
+            .min_base_amount
+            .as_deref()
+            .map(conversion::try_string_to_quantity)
+            .transpose()?,
+        max_qty: pair
+            .max_base_amount
+            .as_deref()
+            .map(conversion::try_string_to_quantity)
+            .transpose()?,
+        min_price: None,
+        max_price: None,
+        contract_style: ContractStyle::Spot,
+        contract_size: None,
+        extensions: None,
+    })
+}
+
+/// Convert gateio REST candle to core kline type
+pub fn convert_gateio_candle(
+    candle: &gateio_types::GateioCandle,
+    symbol: &Symbol,
+    interval: KlineInterval,
+) -> Result<Kline, ExchangeError> {
+    let open_time = candle.0.parse::<i64>().map_err(|e| {
+        ExchangeError::DeserializationError(format!("Invalid candle time {}: {}", candle.0, e))
+    })? * 1000;
+    Ok(Kline {
+        symbol: symbol.clone(),
+        open_time,
+        close_time: open_time + interval.duration_millis() - 1,
+        interval: interval.to_string(),
+        open_price: conversion::try_string_to_price(&candle.5)?,
+        high_price: conversion::try_string_to_price(&candle.3)?,
+        low_price: conversion::try_string_to_price(&candle.4)?,
+        close_price: conversion::try_string_to_price(&candle.2)?,
+        volume: conversion::try_string_to_volume(&candle.6)?,
+        number_of_trades: 0, // Not reported
+        final_bar: candle.7 == "true",
+    })
+}
+
+/// Convert gateio ticker to core ticker type, observed at `timestamp`
+pub fn convert_gateio_ticker(
+    ticker: &gateio_types::GateioTicker,
+    timestamp: i64,
+) -> Result<Ticker, ExchangeError> {
+    let price = conversion::try_string_to_price(&ticker.last)?;
+    let change_percent = conversion::try_string_to_decimal(&ticker.change_percentage)?;
+    // Back out the open from the last price and the percent change
+    let open = price.value() / (Decimal::ONE + change_percent / Decimal::ONE_HUNDRED);
+
+    Ok(Ticker {
+        symbol: convert_pair_to_symbol(&ticker.currency_pair),
+        price,
+        price_change: Price::new((price.value() - open).round_dp(price.value().scale())),
+        price_change_percent: change_percent,
+        high_price: conversion::try_string_to_price(&ticker.high_24h)?,
+        low_price: conversion::try_string_to_price(&ticker.low_24h)?,
+        volume: conversion::try_string_to_volume(&ticker.base_volume)?,
+        quote_volume: conversion::try_string_to_volume(&ticker.quote_volume)?,
+        open_time: timestamp - 24 * 60 * 60 * 1000,
+        close_time: timestamp,
+        count: 0,
+    })
+}
+
+/// Convert gateio REST order book to core order book type
+pub fn convert_gateio_order_book(
+    book: &gateio_types::GateioOrderBook,
+    symbol: &Symbol,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: symbol.clone(),
+        bids: conversion::try_book_levels(&book.bids)?,
+        asks: conversion::try_book_levels(&book.asks)?,
+        last_update_id: book.id.unwrap_or_default(),
+    })
+}
+
+/// Convert gateio trade to core trade type
+pub fn convert_gateio_trade(trade: &gateio_types::GateioTrade) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: convert_pair_to_symbol(&trade.currency_pair),
+        id: trade.id,
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: conversion::try_string_to_quantity(&trade.amount)?,
+        time: parse_fractional_millis(&trade.create_time_ms)?,
+        is_buyer_maker: trade.side == "sell",
+    })
+}
+
+/// Convert gateio spot account to core balance type
+pub fn convert_gateio_balance(
+    balance: &gateio_types::GateioBalance,
+) -> Result<Balance, ExchangeError> {
+    Ok(Balance {
+        asset: balance.currency.clone(),
+        free: conversion::try_string_to_quantity(&balance.available)?,
+        locked: conversion::try_string_to_quantity(&balance.locked)?,
+    })
+}
+
+/// Convert gateio `spot.order_book` snapshot to core order book type
+pub fn convert_gateio_ws_book(
+    book: &gateio_types::GateioWsBook,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: convert_pair_to_symbol(&book.s),
+        bids: conversion::try_book_levels(&book.bids)?,
+        asks: conversion::try_book_levels(&book.asks)?,
+        last_update_id: book.last_update_id,
+    })
+}
+
+/// Convert gateio `spot.order_book_update` to core order book type holding the changed levels
+pub fn convert_gateio_ws_book_update(
+    update: &gateio_types::GateioWsBookUpdate,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: convert_pair_to_symbol(&update.s),
+        bids: conversion::try_book_levels(&update.bids)?,
+        asks: conversion::try_book_levels(&update.asks)?,
+        last_update_id: update.last_update_id,
+    })
+}
+
+/// Convert gateio `spot.candlesticks` update to core kline type
+pub fn convert_gateio_ws_candle(
+    candle: &gateio_types::GateioWsCandle,
+) -> Result<Kline, ExchangeError> {
+    let (interval, pair) = candle.n.split_once('_').ok_or_else(|| {
+        ExchangeError::DeserializationError(format!("Invalid candle name {}", candle.n))
+    })?;
+    let interval = gateio_to_kline_interval(interval).ok_or_else(|| {
+        ExchangeError::DeserializationError(format!("Unknown Gate.io interval {}", interval))
+    })?;
+    let open_time = candle.t.parse::<i64>().map_err(|e| {
+        ExchangeError::DeserializationError(format!("Invalid candle time {}: {}", candle.t, e))
+    })? * 1000;
+
+    Ok(Kline {
+        symbol: convert_pair_to_symbol(pair),
+        open_time,
+        close_time: open_time + interval.duration_millis() - 1,
+        interval: interval.to_string(),
+        open_price: conversion::try_string_to_price(&candle.o)?,
+        high_price: conversion::try_string_to_price(&candle.h)?,
+        low_price: conversion::try_string_to_price(&candle.l)?,
+        close_price: conversion::try_string_to_price(&candle.c)?,
+        volume: conversion::try_string_to_volume(&candle.a)?,
+        number_of_trades: 0,
+        final_bar: candle.w,
+    })
+}
+
+/// Convert order side to gateio format
+pub fn convert_order_side(side: &OrderSide) -> String {
+    match side {
+        OrderSide::Buy => "buy".to_string(),
+        OrderSide::Sell => "sell".to_string(),
+    }
+}
+
+/// Build a spot order request for a core order request
+///
+/// Gate.io sizes market buys in the quote currency, so they need `price` as the
+/// reference price that turns the base quantity into a quote amount. Trigger orders
+/// live on a separate endpoint and are not supported.
+pub fn convert_order_request(
+    order: &OrderRequest,
+    text: String,
+) -> Result<gateio_types::GateioOrderRequest, ExchangeError> {
+    let (order_type, amount, price) = match order.order_type {
+        OrderType::Market => {
+            let amount = match order.side {
+                OrderSide::Buy => {
+                    let reference = order.price.ok_or_else(|| {
+                        ExchangeError::InvalidParameters(
+                            "Gate.io market buys require a reference price to size the quote amount"
+                                .to_string(),
+                        )
+                    })?;
+                    (order.quantity.value() * reference.value())
+                        .normalize()
+                        .to_string()
+                }
+                OrderSide::Sell => order.quantity.to_string(),
+            };
+            ("market", amount, None)
+        }
+        OrderType::Limit => {
+            let price = order.price.ok_or_else(|| {
+                ExchangeError::InvalidParameters("Limit orders require a price".to_string())
+            })?;
+            ("limit", order.quantity.to_string(), Some(price.to_string()))
+        }
+        _ => {
+            return Err(ExchangeError::NotSupported(format!(
+                "Gate.io spot does not support {:?} orders",
+                order.order_type
+            )))
+        }
+    };
+    let time_in_force = match (&order.order_type, &order.time_in_force) {
+        // Market orders only fill immediately
+        (OrderType::Market, _) | (_, Some(TimeInForce::IOC)) => "ioc",
+        (_, None | Some(TimeInForce::GTC)) => "gtc",
+        (_, Some(TimeInForce::FOK)) => "fok",
+    };
+
+    Ok(gateio_types::GateioOrderRequest {
+        text,
+        currency_pair: convert_symbol_to_pair(&order.symbol)?,
+        order_type: order_type.to_string(),
+        account: "spot".to_string(),
+        side: convert_order_side(&order.side),
+        amount,
+        price,
+        time_in_force: time_in_force.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Quantity;
+
+    #[test]
+    fn test_ticker_trade_and_candle() {
+        let ticker: gateio_types::GateioTicker = serde_json::from_str(
+            r#"{"currency_pair":"BTC_USDT","last":"110","lowest_ask":"110.1","highest_bid":"109.9","change_percentage":"10","base_volume":"2","quote_volume":"210","high_24h":"111","low_24h":"99"}"#,
+        )
+        .unwrap();
+        let ticker = convert_gateio_ticker(&ticker, 1_000).unwrap();
+        assert_eq!(ticker.symbol.joined("/"), "BTC/USDT");
+        assert_eq!(ticker.price_change.to_string(), "10");
+        assert_eq!(ticker.price_change_percent, Decimal::TEN);
+
+        let trade: gateio_types::GateioTrade = serde_json::from_str(
+            r#"{"id":"1232893232","create_time":"1606292218","create_time_ms":"1606292218213.4578","currency_pair":"BTC_USDT","side":"sell","amount":"0.5","price":"19000.1"}"#,
+        )
+        .unwrap();
+        let trade = convert_gateio_trade(&trade).unwrap();
+        assert_eq!(trade.id, 1_232_893_232);
+        assert_eq!(trade.time, 1_606_292_218_213);
+        assert!(trade.is_buyer_maker);
+
+        let candle: gateio_types::GateioCandle = serde_json::from_str(
+            r#"["1606292580","2362.3","19128.1","19130","19100","19110","0.1235","true"]"#,
+        )
+        .unwrap();
+        let symbol = convert_pair_to_symbol("BTC_USDT");
+        let kline = convert_gateio_candle(&candle, &symbol, KlineInterval::Minutes1).unwrap();
+        assert_eq!(kline.open_time, 1_606_292_580_000);
+        assert_eq!(kline.open_price.to_string(), "19110");
+        assert_eq!(kline.close_price.to_string(), "19128.1");
+        assert_eq!(kline.volume.to_string(), "0.1235");
+        assert!(kline.final_bar);
+    }
+
+    #[test]
+    fn test_market_buy_is_sized_in_quote() {
+        let order = OrderRequest {
+            symbol: convert_pair_to_symbol("BTC_USDT"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: Quantity::new(Decimal::new(5, 1)),
+            price: Some(Price::new(Decimal::from(20_000))),
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        };
+        let request = convert_order_request(&order, "t-1".to_string()).unwrap();
+        assert_eq!(request.amount, "10000");
+        assert_eq!(request.time_in_force, "ioc");
+        assert!(request.price.is_none());
+
+        let order = OrderRequest {
+            price: None,
+            ..order
+        };
+        assert!(convert_order_request(&order, "t-1".to_string()).is_err());
+    }
+}
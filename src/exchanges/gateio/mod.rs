@@ -0,0 +1,23 @@
+pub mod codec;
+pub mod conversions;
+pub mod signer;
+pub mod types;
+
+pub mod builder;
+pub mod connector;
+pub mod rest;
+
+// Re-export main components
+pub use builder::{build_connector, build_connector_with_websocket, build_public};
+pub use codec::{GateioCodec, GateioMessage};
+pub use connector::{Account, GateioConnector, MarketData, Trading};
+pub use signer::GateioSigner;
+pub use types::{GateioCurrencyPair, GateioOrderRequest, GateioTicker};
+
+/// Helper function to create WebSocket stream identifiers for Gate.io spot
+pub fn create_gateio_stream_identifiers(
+    pairs: &[String],
+    subscription_types: &[crate::core::types::SubscriptionType],
+) -> Result<Vec<String>, crate::core::errors::ExchangeError> {
+    codec::create_gateio_stream_identifiers(pairs, subscription_types)
+}
@@ -0,0 +1,141 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::RestClient;
+use crate::exchanges::gateio::types::{
+    GateioBalance, GateioCandle, GateioCurrencyPair, GateioOrder, GateioOrderBook,
+    GateioOrderRequest, GateioServerTime, GateioTicker, GateioTrade,
+};
+
+/// Thin typed wrapper around `RestClient` for the Gate.io v4 spot API
+///
+/// Rejections arrive as HTTP errors carrying a `label`, which the client classifies
+/// with `gateio_error_kind`.
+pub struct GateioRestClient<R: RestClient> {
+    client: R,
+}
+
+impl<R: RestClient> GateioRestClient<R> {
+    pub fn new(client: R) -> Self {
+        Self { client }
+    }
+
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.client.can_sign()
+    }
+
+    /// Get all currency pairs
+    pub async fn get_currency_pairs(&self) -> Result<Vec<GateioCurrencyPair>, ExchangeError> {
+        self.client
+            .get_json("/api/v4/spot/currency_pairs", &[], false)
+            .await
+    }
+
+    /// Get server time in milliseconds
+    pub async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let time: GateioServerTime = self
+            .client
+            .get_json("/api/v4/spot/time", &[], false)
+            .await?;
+        Ok(time.server_time)
+    }
+
+    /// Get up to 1000 candles, oldest first; `from` and `to` are unix seconds
+    pub async fn get_candlesticks(
+        &self,
+        pair: &str,
+        interval: &str,
+        limit: Option<u32>,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<Vec<GateioCandle>, ExchangeError> {
+        let limit = limit.map(|l| l.to_string());
+        let from = from.map(|f| f.to_string());
+        let to = to.map(|t| t.to_string());
+        let mut params = vec![("currency_pair", pair), ("interval", interval)];
+        // Gate.io rejects `limit` alongside a `from`/`to` range
+        if let (None, None, Some(limit)) = (&from, &to, &limit) {
+            params.push(("limit", limit.as_str()));
+        } else {
+            if let Some(from) = &from {
+                params.push(("from", from.as_str()));
+            }
+            if let Some(to) = &to {
+                params.push(("to", to.as_str()));
+            }
+        }
+        self.client
+            .get_json("/api/v4/spot/candlesticks", &params, false)
+            .await
+    }
+
+    /// Get the 24 hour ticker of a pair
+    pub async fn get_tickers(&self, pair: &str) -> Result<Vec<GateioTicker>, ExchangeError> {
+        let params = [("currency_pair", pair)];
+        self.client
+            .get_json("/api/v4/spot/tickers", &params, false)
+            .await
+    }
+
+    /// Get the order book; Gate.io defaults to 10 levels and serves up to 100
+    pub async fn get_order_book(
+        &self,
+        pair: &str,
+        limit: Option<u32>,
+    ) -> Result<GateioOrderBook, ExchangeError> {
+        let limit = limit.map(|l| l.to_string());
+        let mut params = vec![("currency_pair", pair), ("with_id", "true")];
+        if let Some(limit) = &limit {
+            params.push(("limit", limit.as_str()));
+        }
+        self.client
+            .get_json("/api/v4/spot/order_book", &params, false)
+            .await
+    }
+
+    /// Get recent trades, newest first; Gate.io defaults to 100 and serves up to 1000
+    pub async fn get_trades(
+        &self,
+        pair: &str,
+        limit: Option<u32>,
+    ) -> Result<Vec<GateioTrade>, ExchangeError> {
+        let limit = limit.map(|l| l.to_string());
+        let mut params = vec![("currency_pair", pair)];
+        if let Some(limit) = &limit {
+            params.push(("limit", limit.as_str()));
+        }
+        self.client
+            .get_json("/api/v4/spot/trades", &params, false)
+            .await
+    }
+
+    /// Get spot balances
+    pub async fn get_accounts(&self) -> Result<Vec<GateioBalance>, ExchangeError> {
+        self.client
+            .get_json("/api/v4/spot/accounts", &[], true)
+            .await
+    }
+
+    /// Place an order
+    pub async fn place_order(
+        &self,
+        order: &GateioOrderRequest,
+    ) -> Result<GateioOrder, ExchangeError> {
+        let body = serde_json::to_value(order).map_err(|e| {
+            ExchangeError::SerializationError(format!("Failed to serialize order: {}", e))
+        })?;
+        self.client
+            .post_json("/api/v4/spot/orders", &body, true)
+            .await
+    }
+
+    /// Cancel an order by id
+    pub async fn cancel_order(
+        &self,
+        pair: &str,
+        order_id: &str,
+    ) -> Result<GateioOrder, ExchangeError> {
+        let endpoint = format!("/api/v4/spot/orders/{}", order_id);
+        let params = [("currency_pair", pair)];
+        self.client.delete_json(&endpoint, &params, true).await
+    }
+}
@@ -0,0 +1,110 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::Signer;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha512};
+use std::collections::HashMap;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// HMAC-SHA512 signer for Gate.io v4 private endpoints, spot and futures alike
+#[derive(Debug, Clone)]
+pub struct GateioSigner {
+    api_key: String,
+    secret_key: String,
+}
+
+impl GateioSigner {
+    pub fn new(api_key: String, secret_key: String) -> Self {
+        Self {
+            api_key,
+            secret_key,
+        }
+    }
+
+    /// Hex HMAC-SHA512 of `METHOD\npath\nquery\nhex(SHA512(body))\ntimestamp`
+    ///
+    /// `path` includes the `/api/v4` prefix and `timestamp` is in unix seconds.
+    pub fn generate_signature(
+        &self,
+        method: &str,
+        path: &str,
+        query_string: &str,
+        body: &[u8],
+        timestamp: u64,
+    ) -> Result<String, ExchangeError> {
+        let body_hash = hex::encode(Sha512::digest(body));
+        let payload = format!(
+            "{}\n{}\n{}\n{}\n{}",
+            method, path, query_string, body_hash, timestamp
+        );
+
+        let mut mac = HmacSha512::new_from_slice(self.secret_key.as_bytes())
+            .map_err(|e| ExchangeError::AuthError(format!("Failed to create HMAC: {}", e)))?;
+        mac.update(payload.as_bytes());
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+}
+
+impl Signer for GateioSigner {
+    fn sign_request(
+        &self,
+        method: &str,
+        endpoint: &str,
+        query_string: &str,
+        body: &[u8],
+        timestamp: u64,
+    ) -> Result<(HashMap<String, String>, Vec<(String, String)>), ExchangeError> {
+        // Gate.io rejects timestamps more than 60 seconds off, in seconds
+        let timestamp = timestamp / 1000;
+        let signature = self.generate_signature(method, endpoint, query_string, body, timestamp)?;
+
+        let mut headers = HashMap::new();
+        headers.insert("KEY".to_string(), self.api_key.clone());
+        headers.insert("Timestamp".to_string(), timestamp.to_string());
+        headers.insert("SIGN".to_string(), signature);
+
+        // The query goes out exactly as it was signed
+        let params = query_string
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        Ok((headers, params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signs_method_path_query_body_hash_and_seconds() {
+        let signer = GateioSigner::new("key".to_string(), "secret".to_string());
+        let (headers, params) = signer
+            .sign_request(
+                "GET",
+                "/api/v4/spot/orders",
+                "currency_pair=BTC_USDT&status=open",
+                b"",
+                1_700_000_000_123,
+            )
+            .unwrap();
+
+        // SHA512 of the empty body
+        let payload = "GET\n/api/v4/spot/orders\ncurrency_pair=BTC_USDT&status=open\n\
+            cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce\
+            47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e\n1700000000";
+        let mut mac = HmacSha512::new_from_slice(b"secret").unwrap();
+        mac.update(payload.as_bytes());
+        assert_eq!(headers["SIGN"], hex::encode(mac.finalize().into_bytes()));
+        assert_eq!(headers["Timestamp"], "1700000000");
+        assert_eq!(headers["KEY"], "key");
+        assert_eq!(
+            params,
+            vec![
+                ("currency_pair".to_string(), "BTC_USDT".to_string()),
+                ("status".to_string(), "open".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::core::errors::ErrorKind;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Trade ids are strings over REST and numbers over WebSocket
+fn trade_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Id {
+        Number(i64),
+        Text(String),
+    }
+    match Id::deserialize(deserializer)? {
+        Id::Number(id) => Ok(id),
+        Id::Text(id) => id.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+/// Error body of a rejected v4 request, e.g. `{"label":"INVALID_SIGNATURE","message":"..."}`
+#[derive(Debug, Deserialize)]
+pub struct GateioError {
+    pub label: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateioCurrencyPair {
+    pub id: String,
+    pub base: String,
+    pub quote: String,
+    #[serde(default)]
+    pub min_base_amount: Option<String>,
+    #[serde(default)]
+    pub max_base_amount: Option<String>,
+    pub amount_precision: i32,
+    pub precision: i32,
+    pub trade_status: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateioServerTime {
+    /// Milliseconds
+    pub server_time: i64,
+}
+
+/// `[time, quote volume, close, high, low, open, base volume, closed]`, time in unix seconds
+#[derive(Debug, Deserialize)]
+pub struct GateioCandle(
+    pub String,
+    pub String,
+    pub String,
+    pub String,
+    pub String,
+    pub String,
+    pub String,
+    pub String,
+);
+
+/// 24 hour ticker, over REST and on the `spot.tickers` channel
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioTicker {
+    pub currency_pair: String,
+    pub last: String,
+    /// Percent change over the last 24 hours
+    pub change_percentage: String,
+    pub base_volume: String,
+    pub quote_volume: String,
+    pub high_24h: String,
+    pub low_24h: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateioOrderBook {
+    #[serde(default)]
+    pub id: Option<i64>,
+    /// Milliseconds
+    pub current: i64,
+    pub asks: Vec<[String; 2]>,
+    pub bids: Vec<[String; 2]>,
+}
+
+/// Public trade, over REST and on the `spot.trades` channel
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioTrade {
+    #[serde(deserialize_with = "trade_id")]
+    pub id: i64,
+    /// Milliseconds with a fractional part, e.g. `"1606292218213.4578"`
+    pub create_time_ms: String,
+    pub currency_pair: String,
+    /// Taker side
+    pub side: String,
+    pub amount: String,
+    pub price: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateioBalance {
+    pub currency: String,
+    pub available: String,
+    pub locked: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GateioOrderRequest {
+    /// Client order id, `t-` followed by up to 28 characters
+    pub text: String,
+    pub currency_pair: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub account: String,
+    pub side: String,
+    /// Base amount, or quote amount for market buys
+    pub amount: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    pub time_in_force: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateioOrder {
+    pub id: String,
+    #[serde(default)]
+    pub text: String,
+    pub status: String,
+}
+
+/// Channel message of the WebSocket v4 feed
+#[derive(Debug, Deserialize)]
+pub struct GateioWsEnvelope {
+    /// Milliseconds
+    #[serde(default)]
+    pub time_ms: Option<i64>,
+    #[serde(default)]
+    pub channel: String,
+    #[serde(default)]
+    pub event: String,
+    #[serde(default)]
+    pub error: Option<GateioWsError>,
+    #[serde(default)]
+    pub result: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateioWsError {
+    pub code: i64,
+    pub message: String,
+}
+
+/// Limited-level book snapshot pushed by `spot.order_book`
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioWsBook {
+    /// Milliseconds
+    pub t: i64,
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: i64,
+    pub s: String,
+    pub bids: Vec<[String; 2]>,
+    pub asks: Vec<[String; 2]>,
+}
+
+/// Changed levels pushed by `spot.order_book_update`; a zero amount removes the level
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioWsBookUpdate {
+    /// Milliseconds
+    pub t: i64,
+    pub s: String,
+    #[serde(rename = "U")]
+    pub first_update_id: i64,
+    #[serde(rename = "u")]
+    pub last_update_id: i64,
+    #[serde(rename = "b", default)]
+    pub bids: Vec<[String; 2]>,
+    #[serde(rename = "a", default)]
+    pub asks: Vec<[String; 2]>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioWsCandle {
+    /// Unix seconds
+    pub t: String,
+    pub o: String,
+    pub h: String,
+    pub l: String,
+    pub c: String,
+    /// Base volume
+    pub a: String,
+    /// `interval_PAIR`, e.g. `1m_BTC_USDT`
+    pub n: String,
+    /// Whether the window has closed
+    #[serde(default)]
+    pub w: bool,
+}
+
+/// Classify a Gate.io error by the `label` in the response body
+pub fn gateio_error_kind(_code: i32, message: &str) -> Option<ErrorKind> {
+    let label = serde_json::from_str::<GateioError>(message)
+        .map_or_else(|_| message.to_string(), |error| error.label);
+    match label.as_str() {
+        "BALANCE_NOT_ENOUGH" | "MARGIN_BALANCE_NOT_ENOUGH" | "INSUFFICIENT_AVAILABLE" => {
+            Some(ErrorKind::InsufficientBalance)
+        }
+        "INVALID_CURRENCY_PAIR" | "INVALID_CURRENCY" | "CONTRACT_NOT_FOUND" => {
+            Some(ErrorKind::InvalidSymbol)
+        }
+        "TOO_MANY_REQUESTS" => Some(ErrorKind::RateLimited),
+        "ORDER_NOT_FOUND" => Some(ErrorKind::OrderNotFound),
+        "TOO_SMALL_AMOUNT" | "TOO_SMALL_ORDER_SIZE" | "ORDER_SIZE_TOO_SMALL" => {
+            Some(ErrorKind::MinNotional)
+        }
+        "ORDER_POC_IMMEDIATE" => Some(ErrorKind::PostOnlyReject),
+        "INVALID_PRECISION" | "INVALID_PRICE" | "PRICE_TOO_DEVIATED" => {
+            Some(ErrorKind::InvalidPrice)
+        }
+        "INVALID_AMOUNT" | "TOO_LARGE_AMOUNT" | "INVALID_SIZE" => Some(ErrorKind::InvalidQuantity),
+        "DUPLICATE_REQUEST" => Some(ErrorKind::DuplicateOrder),
+        "INVALID_KEY" | "INVALID_SIGNATURE" | "REQUEST_EXPIRED" | "FORBIDDEN" => {
+            Some(ErrorKind::Unauthorized)
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,59 @@
+use crate::core::config::ExchangeConfig;
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{ReqwestRest, RestClientBuilder, RestClientConfig, TungsteniteWs};
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
+use crate::exchanges::gateio::{signer::GateioSigner, types::gateio_error_kind};
+use crate::exchanges::gateio_perp::{codec::GateioPerpCodec, connector::GateioPerpConnector};
+use std::sync::Arc;
+
+fn build_rest(config: &ExchangeConfig) -> Result<ReqwestRest, ExchangeError> {
+    let endpoints = resolve_endpoints(ExchangeId::GateioPerp, config)?;
+
+    let rest_config = RestClientConfig::new(endpoints.rest, "gateio_perp".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone())
+        .with_error_kinds(gateio_error_kind);
+
+    let mut rest_builder = RestClientBuilder::new(rest_config);
+
+    // Futures sign like spot, with the same API keys
+    if config.has_credentials() {
+        let signer = Arc::new(GateioSigner::new(
+            config.api_key().to_string(),
+            config.secret_key().to_string(),
+        ));
+        rest_builder = rest_builder.with_signer(signer);
+    }
+
+    rest_builder.build()
+}
+
+/// Create a Gate.io USDT perpetuals connector with REST-only support
+pub fn build_connector(
+    config: ExchangeConfig,
+) -> Result<GateioPerpConnector<ReqwestRest, ()>, ExchangeError> {
+    let ws_url = resolve_endpoints(ExchangeId::GateioPerp, &config)?.ws;
+    Ok(GateioPerpConnector::new_without_ws(build_rest(&config)?)
+        .with_ws_url(ws_url)
+        .with_ws_config(config.ws_config()))
+}
+
+/// Create a Gate.io USDT perpetuals connector with WebSocket support
+pub fn build_connector_with_websocket(
+    config: ExchangeConfig,
+) -> Result<GateioPerpConnector<ReqwestRest, TungsteniteWs<GateioPerpCodec>>, ExchangeError> {
+    let rest = build_rest(&config)?;
+    let ws_url = resolve_endpoints(ExchangeId::GateioPerp, &config)?.ws;
+    let ws = TungsteniteWs::new(ws_url.clone(), "gateio_perp".to_string(), GateioPerpCodec)
+        .with_config(config.ws_config());
+    Ok(GateioPerpConnector::new(rest, ws, ws_url).with_ws_config(config.ws_config()))
+}
+
+/// Create a Gate.io USDT perpetuals connector for public market data, ignoring any
+/// credentials in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<GateioPerpConnector<ReqwestRest, TungsteniteWs<GateioPerpCodec>>, ExchangeError> {
+    build_connector_with_websocket(config.without_credentials())
+}
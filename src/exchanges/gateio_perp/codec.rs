@@ -0,0 +1,285 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{ControlFrame, WsCodec};
+use crate::core::types::{OrderBookMode, SubscriptionType};
+use crate::exchanges::gateio::types::GateioWsEnvelope;
+use crate::exchanges::gateio_perp::conversions::kline_interval_to_gateio;
+use crate::exchanges::gateio_perp::types::{
+    GateioFuturesTicker, GateioFuturesTrade, GateioFuturesWsBook, GateioFuturesWsBookUpdate,
+    GateioFuturesWsCandle,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio_tungstenite::tungstenite::Message;
+
+/// Book depths `futures.order_book` offers
+const BOOK_DEPTHS: [u32; 6] = [1, 5, 10, 20, 50, 100];
+/// Book depths `futures.order_book_update` offers
+const BOOK_UPDATE_DEPTHS: [u32; 3] = [20, 50, 100];
+
+#[derive(Debug, Clone)]
+pub enum GateioPerpMessage {
+    Tickers {
+        timestamp: i64,
+        tickers: Vec<GateioFuturesTicker>,
+    },
+    Trades(Vec<GateioFuturesTrade>),
+    Book(GateioFuturesWsBook),
+    BookUpdate(GateioFuturesWsBookUpdate),
+    Candles(Vec<GateioFuturesWsCandle>),
+    Unknown,
+}
+
+/// Codec for the Gate.io USDT perpetuals WebSocket v4 feed
+pub struct GateioPerpCodec;
+
+impl GateioPerpCodec {
+    /// Streams are `channel@CONTRACT`, where the channel may carry a parameter after a
+    /// colon: `futures.order_book:20@BTC_USDT` or `futures.candlesticks:1m@BTC_USDT`.
+    ///
+    /// A request covers one channel; books and candles also cover one contract, see
+    /// `gateio_request_groups`.
+    fn encode_request(
+        event: &str,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        let mut channel = None;
+        let mut contracts = Vec::new();
+        for stream in streams {
+            let (stream_channel, contract) = stream.as_ref().split_once('@').ok_or_else(|| {
+                ExchangeError::InvalidParameters(format!(
+                    "Invalid Gate.io stream: {}",
+                    stream.as_ref()
+                ))
+            })?;
+            if channel.is_some_and(|c| c != stream_channel) {
+                return Err(ExchangeError::InvalidParameters(
+                    "Gate.io streams of one request must share a channel".to_string(),
+                ));
+            }
+            channel = Some(stream_channel);
+            contracts.push(contract);
+        }
+
+        let channel = channel.unwrap_or_default();
+        let (name, parameter) = channel.split_once(':').unwrap_or((channel, ""));
+        let payload = match (name, contracts.as_slice()) {
+            // Interval 0 pushes a full snapshot on every change
+            ("futures.order_book", [contract]) => json!([contract, parameter, "0"]),
+            ("futures.order_book_update", [contract]) => json!([contract, "100ms", parameter]),
+            ("futures.candlesticks", [contract]) => json!([parameter, contract]),
+            ("futures.order_book" | "futures.order_book_update" | "futures.candlesticks", _) => {
+                return Err(ExchangeError::InvalidParameters(format!(
+                    "Gate.io {} takes one contract per request",
+                    name
+                )))
+            }
+            _ => json!(contracts),
+        };
+
+        let request = json!({
+            "time": chrono::Utc::now().timestamp(),
+            "channel": name,
+            "event": event,
+            "payload": payload,
+        });
+        Ok(Message::Text(request.to_string()))
+    }
+
+    fn result<T: DeserializeOwned>(envelope: GateioWsEnvelope) -> Result<T, ExchangeError> {
+        serde_json::from_value(envelope.result).map_err(|e| {
+            ExchangeError::DeserializationError(format!(
+                "Failed to parse {}: {}",
+                envelope.channel, e
+            ))
+        })
+    }
+}
+
+impl WsCodec for GateioPerpCodec {
+    type Message = GateioPerpMessage;
+
+    fn encode_subscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Self::encode_request("subscribe", streams)
+    }
+
+    fn encode_unsubscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Self::encode_request("unsubscribe", streams)
+    }
+
+    fn encode_ping(&self) -> Option<Message> {
+        let ping = json!({ "time": chrono::Utc::now().timestamp(), "channel": "futures.ping" });
+        Some(Message::Text(ping.to_string()))
+    }
+
+    fn acknowledges_subscriptions(&self) -> bool {
+        true
+    }
+
+    fn control_frame(&self, message: &Message) -> Option<ControlFrame> {
+        let Message::Text(text) = message else {
+            return None;
+        };
+        if !text.contains("futures.pong") && !text.contains("\"subscribe\"") {
+            return None;
+        }
+        let envelope: GateioWsEnvelope = serde_json::from_str(text).ok()?;
+        let frame = if envelope.channel == "futures.pong" {
+            ControlFrame {
+                pong: true,
+                ..ControlFrame::default()
+            }
+        } else if envelope.event == "subscribe"
+            && envelope.error.is_none()
+            && envelope.result.get("status").and_then(Value::as_str) == Some("success")
+        {
+            ControlFrame {
+                subscribed: true,
+                ..ControlFrame::default()
+            }
+        } else {
+            return None;
+        };
+        Some(frame)
+    }
+
+    fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
+        let Message::Text(text) = message else {
+            return Ok(None);
+        };
+        let envelope: GateioWsEnvelope = serde_json::from_str(&text).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse message: {}", e))
+        })?;
+        if let Some(error) = &envelope.error {
+            return Err(ExchangeError::WebSocketError(format!(
+                "Gate.io error {}: {}",
+                error.code, error.message
+            )));
+        }
+        // Subscription acks and pongs; book snapshots come as `all`
+        if envelope.event != "update" && envelope.event != "all" {
+            return Ok(Some(GateioPerpMessage::Unknown));
+        }
+
+        let message = match envelope.channel.as_str() {
+            "futures.tickers" => GateioPerpMessage::Tickers {
+                timestamp: envelope
+                    .time_ms
+                    .unwrap_or_else(|| chrono::Utc::now().timestamp_millis()),
+                tickers: Self::result(envelope)?,
+            },
+            "futures.trades" => GateioPerpMessage::Trades(Self::result(envelope)?),
+            "futures.order_book" => GateioPerpMessage::Book(Self::result(envelope)?),
+            "futures.order_book_update" => GateioPerpMessage::BookUpdate(Self::result(envelope)?),
+            "futures.candlesticks" => GateioPerpMessage::Candles(Self::result(envelope)?),
+            _ => GateioPerpMessage::Unknown,
+        };
+        Ok(Some(message))
+    }
+}
+
+/// Create Gate.io perpetual stream identifiers, as `channel@CONTRACT`
+///
+/// Order books round the requested depth up to one Gate.io offers for the mode, 100 for
+/// the full book. There is no slower snapshot feed, so `Aggregated` gets snapshots.
+pub fn create_gateio_perp_stream_identifiers(
+    contracts: &[String],
+    subscription_types: &[SubscriptionType],
+) -> Result<Vec<String>, ExchangeError> {
+    let mut streams = Vec::new();
+    for subscription in subscription_types {
+        let channel = match subscription {
+            SubscriptionType::Ticker => "futures.tickers".to_string(),
+            SubscriptionType::OrderBook { depth, mode } => {
+                let (channel, depths) = match mode {
+                    OrderBookMode::Snapshot | OrderBookMode::Aggregated => {
+                        ("futures.order_book", BOOK_DEPTHS.as_slice())
+                    }
+                    OrderBookMode::Delta => {
+                        ("futures.order_book_update", BOOK_UPDATE_DEPTHS.as_slice())
+                    }
+                };
+                let depth = depth
+                    .and_then(|depth| depths.iter().copied().find(|d| *d >= depth))
+                    .unwrap_or(100);
+                format!("{}:{}", channel, depth)
+            }
+            SubscriptionType::Trades | SubscriptionType::AggTrades => "futures.trades".to_string(),
+            SubscriptionType::Klines { interval } => {
+                let interval = kline_interval_to_gateio(*interval).ok_or_else(|| {
+                    ExchangeError::NotSupported(format!(
+                        "Gate.io does not offer {} candles",
+                        interval
+                    ))
+                })?;
+                format!("futures.candlesticks:{}", interval)
+            }
+            // Not streamed yet; poll `FundingRateSource` instead
+            SubscriptionType::MarkPrice => continue,
+        };
+        for contract in contracts {
+            let stream = format!("{}@{}", channel, contract);
+            if !streams.contains(&stream) {
+                streams.push(stream);
+            }
+        }
+    }
+    Ok(streams)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decodes_batched_trades_and_book_snapshots() {
+        let text = r#"{"channel":"futures.trades","event":"update","time":1541503698,"time_ms":1541503698123,"result":[{"size":-108,"id":27753479,"create_time":1545136464,"create_time_ms":1545136464123,"price":"96.4","contract":"BTC_USDT"}]}"#;
+        let Some(GateioPerpMessage::Trades(trades)) = GateioPerpCodec
+            .decode_message(Message::Text(text.to_string()))
+            .unwrap()
+        else {
+            panic!("expected trades");
+        };
+        assert_eq!(trades[0].size, -108);
+        assert_eq!(trades[0].create_time_ms, Some(1_545_136_464_123));
+
+        let text = r#"{"channel":"futures.order_book","event":"all","time":1541500161,"result":{"t":1541500161123,"contract":"BTC_USDT","id":93973511,"asks":[{"p":"97.1","s":2245}],"bids":[{"p":"97.0","s":100}]}}"#;
+        let Some(GateioPerpMessage::Book(book)) = GateioPerpCodec
+            .decode_message(Message::Text(text.to_string()))
+            .unwrap()
+        else {
+            panic!("expected book");
+        };
+        assert_eq!(book.id, 93_973_511);
+        assert_eq!(book.asks[0].s, 2245);
+
+        let streams = create_gateio_perp_stream_identifiers(
+            &["BTC_USDT".to_string()],
+            &[SubscriptionType::OrderBook {
+                depth: Some(5),
+                mode: OrderBookMode::Delta,
+            }],
+        )
+        .unwrap();
+        assert_eq!(streams, vec!["futures.order_book_update:20@BTC_USDT"]);
+        let Message::Text(text) = GateioPerpCodec.encode_subscription(&streams).unwrap() else {
+            panic!("expected text");
+        };
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value["payload"], json!(["BTC_USDT", "100ms", "20"]));
+
+        let Message::Text(text) = GateioPerpCodec
+            .encode_subscription(&["futures.candlesticks:1m@BTC_USDT"])
+            .unwrap()
+        else {
+            panic!("expected text");
+        };
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value["payload"], json!(["1m", "BTC_USDT"]));
+    }
+}
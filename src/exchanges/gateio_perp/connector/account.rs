@@ -0,0 +1,71 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::RestClient,
+    traits::AccountInfo,
+    types::{conversion, Balance, Position},
+};
+use crate::exchanges::gateio_perp::{
+    conversions::{convert_gateio_futures_account, convert_gateio_position},
+    rest::GateioPerpRestClient,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tracing::{instrument, warn};
+
+/// Account implementation for Gate.io USDT perpetuals
+pub struct Account<R: RestClient> {
+    rest: GateioPerpRestClient<R>,
+}
+
+impl<R: RestClient> Account<R> {
+    /// Create a new account manager
+    pub fn new(rest: &R) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            rest: GateioPerpRestClient::new(rest.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    #[instrument(skip(self), fields(exchange = "gateio_perp"))]
+    async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let account = self.rest.get_account().await?;
+        Ok(vec![convert_gateio_futures_account(&account)?])
+    }
+
+    /// Amounts are in the base asset
+    #[instrument(skip(self), fields(exchange = "gateio_perp"))]
+    async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
+        let (positions, contracts) =
+            tokio::join!(self.rest.get_positions(), self.rest.get_contracts());
+        // Without contract sizes positions are reported in contracts
+        let sizes: HashMap<String, _> = match contracts {
+            Ok(contracts) => contracts
+                .into_iter()
+                .filter_map(|contract| {
+                    let size = conversion::try_string_to_decimal(&contract.quanto_multiplier);
+                    Some((contract.name, size.ok()?))
+                })
+                .collect(),
+            Err(e) => {
+                warn!(error = %e, "Failed to fetch contract sizes");
+                HashMap::new()
+            }
+        };
+        positions?
+            .iter()
+            .filter(|position| position.size != 0)
+            .map(|position| {
+                convert_gateio_position(position, sizes.get(&position.contract).copied())
+            })
+            .collect()
+    }
+}
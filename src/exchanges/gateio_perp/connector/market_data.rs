@@ -0,0 +1,343 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::{RestClient, TungsteniteWs, WsConfig, WsSession},
+    streaming::{SharedHub, StreamRouter},
+    traits::{FundingRateSource, MarketSnapshotSource, RestMarketData, StreamingMarketData},
+    types::{
+        FundingRate, Kline, KlineInterval, Market, MarketDataType, OrderBook, SubscriptionType,
+        Symbol, Ticker, Trade, WebSocketConfig,
+    },
+};
+use crate::exchanges::factory::ExchangeId;
+use crate::exchanges::gateio::codec::gateio_request_groups;
+use crate::exchanges::gateio_perp::{
+    codec::{create_gateio_perp_stream_identifiers, GateioPerpCodec, GateioPerpMessage},
+    conversions::{
+        convert_gateio_contract, convert_gateio_contract_funding, convert_gateio_funding_history,
+        convert_gateio_futures_candle, convert_gateio_futures_order_book,
+        convert_gateio_futures_ticker, convert_gateio_futures_trade,
+        convert_gateio_futures_ws_book, convert_gateio_futures_ws_book_update,
+        convert_gateio_futures_ws_candle, convert_symbol_to_contract, kline_interval_to_gateio,
+    },
+    rest::GateioPerpRestClient,
+};
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+use tracing::{instrument, warn};
+
+pub const GATEIO_PERP_WS_URL: &str = ExchangeId::GateioPerp.endpoints().mainnet.ws;
+
+/// Most candles Gate.io returns per request
+const MAX_CANDLES: u32 = 2000;
+
+/// Deepest book served by `/futures/usdt/order_book`
+const MAX_BOOK_DEPTH: u32 = 100;
+
+/// Routes Gate.io futures updates, subscribing one channel per request
+struct GateioPerpRouter;
+
+impl StreamRouter for GateioPerpRouter {
+    type Codec = GateioPerpCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        Ok(create_gateio_perp_stream_identifiers(
+            &[convert_symbol_to_contract(symbol)?],
+            std::slice::from_ref(subscription),
+        )?
+        .pop())
+    }
+
+    fn request_groups(&self, streams: Vec<String>) -> Vec<Vec<String>> {
+        gateio_request_groups(streams)
+    }
+
+    fn convert(&self, message: GateioPerpMessage) -> Vec<MarketDataType> {
+        convert_gateio_perp_message_to_market_data(message).unwrap_or_else(|e| {
+            warn!(error = %e, "Dropping malformed Gate.io message");
+            Vec::new()
+        })
+    }
+}
+
+/// Market data implementation for Gate.io USDT perpetuals
+pub struct MarketData<R: RestClient, W = ()> {
+    rest: GateioPerpRestClient<R>,
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
+    ws: Option<W>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
+    ws_url: String,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
+
+    /// Stream from `ws_url`, e.g. the testnet feed
+    #[must_use]
+    pub fn with_ws_url(mut self, ws_url: String) -> Self {
+        self.ws_url = ws_url;
+        self
+    }
+}
+
+impl<R: RestClient + Clone, W> MarketData<R, W> {
+    /// Create a new market data source streaming from `ws_url`
+    pub fn new(rest: &R, ws: Option<W>, ws_url: String) -> Self {
+        Self {
+            rest: GateioPerpRestClient::new(rest.clone()),
+            ws,
+            hub: SharedHub::new(),
+            ws_url,
+            ws_config: WsConfig::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> RestMarketData for MarketData<R, W> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.rest
+            .get_contracts()
+            .await?
+            .iter()
+            .map(convert_gateio_contract)
+            .collect()
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        let venue_interval = kline_interval_to_gateio(interval).ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Gate.io does not offer {} candles", interval))
+        })?;
+        let limit = limit.unwrap_or(MAX_CANDLES).min(MAX_CANDLES);
+
+        // A range may span at most `MAX_CANDLES` candles, so an open end is capped
+        let span = interval.duration_millis() * i64::from(limit - 1);
+        let (from, to) = match (start_time, end_time) {
+            (Some(start), None) => (Some(start), Some(start + span)),
+            (None, Some(end)) => (Some(end - span), Some(end)),
+            range => range,
+        };
+        let candles = self
+            .rest
+            .get_candlesticks(
+                &convert_symbol_to_contract(&symbol)?,
+                venue_interval,
+                Some(limit),
+                from.map(|from| from / 1000),
+                to.map(|to| to / 1000),
+            )
+            .await?;
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut klines = candles
+            .iter()
+            .map(|candle| convert_gateio_futures_candle(candle, &symbol, interval, now))
+            .collect::<Result<Vec<_>, _>>()?;
+        if start_time.is_some() {
+            klines.truncate(limit as usize);
+        } else {
+            let excess = klines.len().saturating_sub(limit as usize);
+            klines.drain(..excess);
+        }
+        Ok(klines)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> MarketSnapshotSource for MarketData<R, W> {
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let ticker = self
+            .rest
+            .get_tickers(&convert_symbol_to_contract(&symbol)?)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                ExchangeError::InvalidResponseFormat(format!("No ticker for {}", symbol))
+            })?;
+        let ticker = convert_gateio_futures_ticker(&ticker, chrono::Utc::now().timestamp_millis())?;
+        Ok(Ticker { symbol, ..ticker })
+    }
+
+    /// Levels are sized in contracts
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let limit = depth.map(|depth| depth.min(MAX_BOOK_DEPTH));
+        let book = self
+            .rest
+            .get_order_book(&convert_symbol_to_contract(&symbol)?, limit)
+            .await?;
+        Ok(convert_gateio_futures_order_book(&book, &symbol)?.truncated(depth))
+    }
+
+    /// Trades are sized in contracts
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let trades = self
+            .rest
+            .get_trades(&convert_symbol_to_contract(&symbol)?, limit)
+            .await?;
+        // Newest first over the wire
+        trades
+            .iter()
+            .rev()
+            .map(|trade| {
+                Ok(Trade {
+                    symbol: symbol.clone(),
+                    ..convert_gateio_futures_trade(trade)?
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> FundingRateSource for MarketData<R, W> {
+    #[instrument(skip(self), fields(exchange = "gateio_perp"))]
+    async fn get_funding_rates(
+        &self,
+        symbols: Option<Vec<Symbol>>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let Some(symbols) = symbols else {
+            return self.get_all_funding_rates().await;
+        };
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut rates = Vec::with_capacity(symbols.len());
+        for symbol in symbols {
+            match self
+                .rest
+                .get_contract(&convert_symbol_to_contract(&symbol)?)
+                .await
+            {
+                Ok(contract) => rates.push(convert_gateio_contract_funding(&contract, now)),
+                Err(e) => warn!(contract = %symbol, error = %e, "Failed to get funding rate"),
+            }
+        }
+        Ok(rates)
+    }
+
+    #[instrument(skip(self), fields(exchange = "gateio_perp"))]
+    async fn get_all_funding_rates(&self) -> Result<Vec<FundingRate>, ExchangeError> {
+        let now = chrono::Utc::now().timestamp_millis();
+        Ok(self
+            .rest
+            .get_contracts()
+            .await?
+            .iter()
+            .map(|contract| convert_gateio_contract_funding(contract, now))
+            .collect())
+    }
+
+    #[instrument(skip(self), fields(exchange = "gateio_perp", contract = %symbol))]
+    async fn get_funding_rate_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let history = self
+            .rest
+            .get_funding_rate_history(
+                &convert_symbol_to_contract(&symbol)?,
+                limit,
+                start_time.map(|start| start / 1000),
+                end_time.map(|end| end / 1000),
+            )
+            .await?;
+        // Newest first over the wire
+        history
+            .iter()
+            .rev()
+            .map(|rate| convert_gateio_funding_history(rate, &symbol))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: WsSession<GateioPerpCodec> + Send + Sync> StreamingMarketData
+    for MarketData<R, W>
+{
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(
+                        self.ws_url.clone(),
+                        "gateio_perp".to_string(),
+                        GateioPerpCodec,
+                    )
+                    .with_config(self.ws_config.clone())
+                },
+                GateioPerpRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.ws_url.clone()
+    }
+}
+
+/// Convert `GateioPerpMessage` to `MarketDataType`s
+fn convert_gateio_perp_message_to_market_data(
+    message: GateioPerpMessage,
+) -> Result<Vec<MarketDataType>, ExchangeError> {
+    match message {
+        GateioPerpMessage::Tickers { timestamp, tickers } => tickers
+            .iter()
+            .map(|ticker| {
+                convert_gateio_futures_ticker(ticker, timestamp).map(MarketDataType::Ticker)
+            })
+            .collect(),
+        GateioPerpMessage::Trades(trades) => trades
+            .iter()
+            .map(|trade| convert_gateio_futures_trade(trade).map(MarketDataType::Trade))
+            .collect(),
+        GateioPerpMessage::Book(book) => Ok(vec![MarketDataType::OrderBook(
+            convert_gateio_futures_ws_book(&book)?,
+        )]),
+        GateioPerpMessage::BookUpdate(update) => Ok(vec![MarketDataType::OrderBook(
+            convert_gateio_futures_ws_book_update(&update)?,
+        )]),
+        GateioPerpMessage::Candles(candles) => candles
+            .iter()
+            .map(|candle| convert_gateio_futures_ws_candle(candle).map(MarketDataType::Kline))
+            .collect(),
+        GateioPerpMessage::Unknown => Ok(Vec::new()),
+    }
+}
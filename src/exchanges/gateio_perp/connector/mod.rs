@@ -0,0 +1,229 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::{
+    AccountInfo, DiagnosticsSource, FundingRateSource, MarketSnapshotSource, OrderPlacer,
+    RestMarketData, StreamingMarketData,
+};
+use crate::core::types::{
+    Balance, FundingRate, Kline, KlineInterval, Market, MarketDataType, OrderBook, OrderRequest,
+    OrderResponse, Position, SubscriptionType, Symbol, Ticker, Trade, WebSocketConfig,
+};
+use crate::core::{
+    kernel::diagnostics, kernel::Diagnostics, kernel::RestClient, kernel::WsConfig,
+    kernel::WsSession,
+};
+use crate::exchanges::gateio_perp::codec::GateioPerpCodec;
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+pub mod account;
+pub mod market_data;
+pub mod trading;
+
+pub use account::Account;
+pub use market_data::MarketData;
+pub use trading::Trading;
+
+/// Gate.io USDT perpetuals connector that composes all sub-trait implementations
+pub struct GateioPerpConnector<R: RestClient, W = ()> {
+    pub market: MarketData<R, W>,
+    pub trading: Trading<R>,
+    pub account: Account<R>,
+}
+
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<GateioPerpCodec> + Send + Sync>
+    GateioPerpConnector<R, W>
+{
+    /// Create a new Gate.io perpetuals connector with WebSocket support, streaming from
+    /// `ws_url`
+    pub fn new(rest: R, ws: W, ws_url: String) -> Self {
+        Self {
+            market: MarketData::new(&rest, Some(ws), ws_url),
+            trading: Trading::new(&rest),
+            account: Account::new(&rest),
+        }
+    }
+}
+
+impl<R: RestClient + Clone + Send + Sync> GateioPerpConnector<R, ()> {
+    /// Create a new Gate.io perpetuals connector without WebSocket support
+    pub fn new_without_ws(rest: R) -> Self {
+        Self {
+            market: MarketData::new(&rest, None, market_data::GATEIO_PERP_WS_URL.to_string()),
+            trading: Trading::new(&rest),
+            account: Account::new(&rest),
+        }
+    }
+}
+
+impl<R: RestClient, W> GateioPerpConnector<R, W> {
+    /// Open market data subscriptions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(self, config: WsConfig) -> Self {
+        Self {
+            market: self.market.with_ws_config(config),
+            trading: self.trading,
+            account: self.account,
+        }
+    }
+
+    /// Stream market data from `ws_url`, e.g. the testnet feed
+    #[must_use]
+    pub fn with_ws_url(self, ws_url: String) -> Self {
+        Self {
+            market: self.market.with_ws_url(ws_url),
+            trading: self.trading,
+            account: self.account,
+        }
+    }
+}
+
+// Implement traits for the connector by delegating to sub-components
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> RestMarketData
+    for GateioPerpConnector<R, W>
+{
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.market.get_markets().await
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        self.market
+            .get_klines(symbol, interval, limit, start_time, end_time)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for GateioPerpConnector<R, W>
+{
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        self.market.get_ticker(symbol).await
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        self.market.get_order_book_snapshot(symbol, depth).await
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        self.market.get_recent_trades(symbol, limit).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
+    for GateioPerpConnector<R, W>
+{
+    async fn get_funding_rates(
+        &self,
+        symbols: Option<Vec<Symbol>>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        self.market.get_funding_rates(symbols).await
+    }
+
+    async fn get_all_funding_rates(&self) -> Result<Vec<FundingRate>, ExchangeError> {
+        self.market.get_all_funding_rates().await
+    }
+
+    async fn get_funding_rate_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        self.market
+            .get_funding_rate_history(symbol, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<GateioPerpCodec> + Send + Sync>
+    StreamingMarketData for GateioPerpConnector<R, W>
+{
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.market
+            .subscribe_market_data(symbols, subscription_types, config)
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.market.get_websocket_url()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderPlacer
+    for GateioPerpConnector<R, W>
+{
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        self.trading.place_order(order).await
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        self.trading.cancel_order(symbol, order_id).await
+    }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo
+    for GateioPerpConnector<R, W>
+{
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
+    async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
+        self.account.get_account_balance().await
+    }
+
+    async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
+        self.account.get_positions().await
+    }
+}
+
+impl<R: RestClient, W> DiagnosticsSource for GateioPerpConnector<R, W> {
+    fn diagnostics(&self) -> Diagnostics {
+        diagnostics("gateio_perp")
+    }
+}
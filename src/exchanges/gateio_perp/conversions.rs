@@ -0,0 +1,429 @@
+use super::types as gateio_perp_types;
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
+use crate::core::types::{
+    conversion, Balance, ContractStyle, FundingRate, Kline, KlineInterval, MarginMode, Market,
+    MarketStatus, OrderBook, OrderBookEntry, OrderRequest, OrderSide, OrderType, Position,
+    PositionSide, Price, Quantity, Symbol, Ticker, TimeInForce, Trade, Volume,
+};
+use crate::exchanges::factory::ExchangeId;
+use crate::exchanges::gateio::conversions::gateio_to_kline_interval;
+use rust_decimal::Decimal;
+use std::time::Duration;
+
+pub use crate::exchanges::gateio::conversions::kline_interval_to_gateio;
+
+/// Gate.io USDT futures symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::GateioPerp.symbol_convention();
+
+/// Convert symbol to a `BASE_USDT` contract name
+pub fn convert_symbol_to_contract(symbol: &Symbol) -> Result<String, ExchangeError> {
+    SYMBOLS.try_to_exchange_symbol(symbol)
+}
+
+/// Convert a `BASE_USDT` contract name to symbol
+pub fn convert_contract_to_symbol(contract: &str) -> Symbol {
+    SYMBOLS.from_exchange_symbol(contract)
+}
+
+fn parse_optional(value: &str) -> Option<Decimal> {
+    conversion::try_string_to_decimal(value).ok()
+}
+
+/// Convert gateio contract to core market type
+///
+/// Orders, books and trades count whole contracts of `contract_size` base units.
+pub fn convert_gateio_contract(
+    contract: &gateio_perp_types::GateioContract,
+) -> Result<Market, ExchangeError> {
+    let tick = conversion::try_string_to_decimal(&contract.order_price_round)?;
+    Ok(Market {
+        symbol: convert_contract_to_symbol(&contract.name),
+        status: if contract.in_delisting {
+            MarketStatus::Restricted
+        } else {
+            MarketStatus::Trading
+        },
+        base_precision: 0,
+        quote_precision: i32::try_from(tick.normalize().scale()).unwrap_or(i32::MAX),
+        min_qty: Some(Quantity::new(Decimal::from(contract.order_size_min))),
+        max_qty: Some(Quantity::new(Decimal::from(contract.order_size_max))),
+        min_price: Some(Price::new(tick)),
+        max_price: None,
+        contract_style: ContractStyle::Linear,
+        contract_size: Some(conversion::try_string_to_decimal(
+            &contract.quanto_multiplier,
+        )?),
+        extensions: None,
+    })
+}
+
+/// Convert gateio contract to the current core funding rate
+pub fn convert_gateio_contract_funding(
+    contract: &gateio_perp_types::GateioContract,
+    timestamp: i64,
+) -> FundingRate {
+    FundingRate {
+        symbol: convert_contract_to_symbol(&contract.name),
+        funding_rate: parse_optional(&contract.funding_rate),
+        previous_funding_rate: None,
+        next_funding_rate: None,
+        funding_time: None,
+        next_funding_time: Some(contract.funding_next_apply * 1000),
+        mark_price: parse_optional(&contract.mark_price).map(Price::new),
+        index_price: parse_optional(&contract.index_price).map(Price::new),
+        timestamp,
+        funding_interval: Some(Duration::from_secs(contract.funding_interval)),
+    }
+}
+
+/// Convert gateio settled funding rate to core funding rate
+pub fn convert_gateio_funding_history(
+    rate: &gateio_perp_types::GateioFundingRate,
+    symbol: &Symbol,
+) -> Result<FundingRate, ExchangeError> {
+    let funding_time = rate.t * 1000;
+    Ok(FundingRate {
+        symbol: symbol.clone(),
+        funding_rate: Some(conversion::try_string_to_decimal(&rate.r)?),
+        previous_funding_rate: None,
+        next_funding_rate: None,
+        funding_time: Some(funding_time),
+        next_funding_time: None,
+        mark_price: None,
+        index_price: None,
+        timestamp: funding_time,
+        funding_interval: None,
+    })
+}
+
+/// Convert gateio futures candle to core kline type; volume counts contracts
+pub fn convert_gateio_futures_candle(
+    candle: &gateio_perp_types::GateioFuturesCandle,
+    symbol: &Symbol,
+    interval: KlineInterval,
+    now: i64,
+) -> Result<Kline, ExchangeError> {
+    let open_time = candle.t * 1000;
+    let close_time = open_time + interval.duration_millis() - 1;
+    Ok(Kline {
+        symbol: symbol.clone(),
+        open_time,
+        close_time,
+        interval: interval.to_string(),
+        open_price: conversion::try_string_to_price(&candle.o)?,
+        high_price: conversion::try_string_to_price(&candle.h)?,
+        low_price: conversion::try_string_to_price(&candle.l)?,
+        close_price: conversion::try_string_to_price(&candle.c)?,
+        volume: Volume::new(Decimal::from(candle.v)),
+        number_of_trades: 0, // Not reported
+        final_bar: close_time < now,
+    })
+}
+
+/// Convert gateio futures ticker to core ticker type, observed at `timestamp`
+pub fn convert_gateio_futures_ticker(
+    ticker: &gateio_perp_types::GateioFuturesTicker,
+    timestamp: i64,
+) -> Result<Ticker, ExchangeError> {
+    let price = conversion::try_string_to_price(&ticker.last)?;
+    let change_percent = conversion::try_string_to_decimal(&ticker.change_percentage)?;
+    // Back out the open from the last price and the percent change
+    let open = price.value() / (Decimal::ONE + change_percent / Decimal::ONE_HUNDRED);
+
+    Ok(Ticker {
+        symbol: convert_contract_to_symbol(&ticker.contract),
+        price,
+        price_change: Price::new((price.value() - open).round_dp(price.value().scale())),
+        price_change_percent: change_percent,
+        high_price: conversion::try_string_to_price(&ticker.high_24h)?,
+        low_price: conversion::try_string_to_price(&ticker.low_24h)?,
+        volume: Volume::new(parse_optional(&ticker.volume_24h_base).unwrap_or_default()),
+        quote_volume: Volume::new(parse_optional(&ticker.volume_24h_quote).unwrap_or_default()),
+        open_time: timestamp - 24 * 60 * 60 * 1000,
+        close_time: timestamp,
+        count: 0,
+    })
+}
+
+fn convert_levels(
+    levels: &[gateio_perp_types::GateioFuturesLevel],
+) -> Result<Vec<OrderBookEntry>, ExchangeError> {
+    levels
+        .iter()
+        .map(|level| {
+            Ok(OrderBookEntry {
+                price: conversion::try_string_to_price(&level.p)?,
+                quantity: Quantity::new(Decimal::from(level.s)),
+            })
+        })
+        .collect()
+}
+
+/// Convert gateio futures REST order book to core order book type, sized in contracts
+pub fn convert_gateio_futures_order_book(
+    book: &gateio_perp_types::GateioFuturesOrderBook,
+    symbol: &Symbol,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: symbol.clone(),
+        bids: convert_levels(&book.bids)?,
+        asks: convert_levels(&book.asks)?,
+        last_update_id: book.id.unwrap_or_default(),
+    })
+}
+
+/// Convert gateio `futures.order_book` snapshot to core order book type
+pub fn convert_gateio_futures_ws_book(
+    book: &gateio_perp_types::GateioFuturesWsBook,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: convert_contract_to_symbol(&book.contract),
+        bids: convert_levels(&book.bids)?,
+        asks: convert_levels(&book.asks)?,
+        last_update_id: book.id,
+    })
+}
+
+/// Convert gateio `futures.order_book_update` to core order book type holding the changed levels
+pub fn convert_gateio_futures_ws_book_update(
+    update: &gateio_perp_types::GateioFuturesWsBookUpdate,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol: convert_contract_to_symbol(&update.s),
+        bids: convert_levels(&update.bids)?,
+        asks: convert_levels(&update.asks)?,
+        last_update_id: update.last_update_id,
+    })
+}
+
+/// Convert gateio futures trade to core trade type, sized in contracts
+pub fn convert_gateio_futures_trade(
+    trade: &gateio_perp_types::GateioFuturesTrade,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: convert_contract_to_symbol(&trade.contract),
+        id: trade.id,
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: Quantity::new(Decimal::from(trade.size.abs())),
+        time: trade
+            .create_time_ms
+            .unwrap_or_else(|| (trade.create_time * 1000.0).round() as i64),
+        is_buyer_maker: trade.size < 0,
+    })
+}
+
+/// Convert gateio `futures.candlesticks` update to core kline type
+pub fn convert_gateio_futures_ws_candle(
+    candle: &gateio_perp_types::GateioFuturesWsCandle,
+) -> Result<Kline, ExchangeError> {
+    let (interval, contract) = candle.n.split_once('_').ok_or_else(|| {
+        ExchangeError::DeserializationError(format!("Invalid candle name {}", candle.n))
+    })?;
+    let interval = gateio_to_kline_interval(interval).ok_or_else(|| {
+        ExchangeError::DeserializationError(format!("Unknown Gate.io interval {}", interval))
+    })?;
+    let open_time = candle.t * 1000;
+
+    Ok(Kline {
+        symbol: convert_contract_to_symbol(contract),
+        open_time,
+        close_time: open_time + interval.duration_millis() - 1,
+        interval: interval.to_string(),
+        open_price: conversion::try_string_to_price(&candle.o)?,
+        high_price: conversion::try_string_to_price(&candle.h)?,
+        low_price: conversion::try_string_to_price(&candle.l)?,
+        close_price: conversion::try_string_to_price(&candle.c)?,
+        volume: Volume::new(Decimal::from(candle.v)),
+        number_of_trades: 0,
+        final_bar: candle.w,
+    })
+}
+
+/// Convert gateio futures account to core balance type
+pub fn convert_gateio_futures_account(
+    account: &gateio_perp_types::GateioFuturesAccount,
+) -> Result<Balance, ExchangeError> {
+    let total = conversion::try_string_to_decimal(&account.total)?;
+    let available = conversion::try_string_to_decimal(&account.available)?;
+    Ok(Balance {
+        asset: account.currency.clone(),
+        free: Quantity::new(available),
+        locked: Quantity::new(total - available),
+    })
+}
+
+/// Convert gateio position to core position
+///
+/// Gate.io sizes positions in contracts; `contract_size` is the base units per contract
+/// and expresses the amount in the base asset. Without it the contract count is reported
+/// as is.
+pub fn convert_gateio_position(
+    position: &gateio_perp_types::GateioPosition,
+    contract_size: Option<Decimal>,
+) -> Result<Position, ExchangeError> {
+    let leverage = conversion::try_string_to_decimal(&position.leverage)?;
+    // Leverage 0 means cross margin, up to the cross leverage limit
+    let (margin_mode, leverage) = if leverage.is_zero() {
+        (
+            MarginMode::Cross,
+            parse_optional(&position.cross_leverage_limit)
+                .filter(|limit| !limit.is_zero())
+                .unwrap_or(Decimal::ONE),
+        )
+    } else {
+        (MarginMode::Isolated, leverage)
+    };
+    let contracts = Decimal::from(position.size.abs());
+
+    Ok(Position {
+        symbol: convert_contract_to_symbol(&position.contract),
+        position_side: if position.size < 0 {
+            PositionSide::Short
+        } else {
+            PositionSide::Long
+        },
+        entry_price: conversion::try_string_to_price(&position.entry_price)?,
+        position_amount: Quantity::new(contract_size.map_or(contracts, |size| contracts * size)),
+        unrealized_pnl: parse_optional(&position.unrealised_pnl).unwrap_or_default(),
+        liquidation_price: parse_optional(&position.liq_price)
+            .filter(|price| !price.is_zero())
+            .map(Price::new),
+        leverage,
+        margin_mode: Some(margin_mode),
+        margin: parse_optional(&position.margin),
+        extensions: Some(serde_json::json!({ "size": position.size })),
+    })
+}
+
+/// Build a futures order request for a core order request
+///
+/// `quantity` counts whole contracts. Market orders go out as immediate-or-cancel at
+/// price 0; trigger orders live on a separate endpoint and are not supported.
+pub fn convert_order_request(
+    order: &OrderRequest,
+    text: String,
+) -> Result<gateio_perp_types::GateioFuturesOrderRequest, ExchangeError> {
+    let contracts = order.quantity.value();
+    if !contracts.fract().is_zero() {
+        return Err(ExchangeError::InvalidParameters(format!(
+            "Gate.io futures orders count whole contracts, got {}",
+            contracts
+        )));
+    }
+    let contracts = i64::try_from(contracts).map_err(|e| {
+        ExchangeError::InvalidParameters(format!("Invalid contract count {}: {}", contracts, e))
+    })?;
+    let close = order.close_position == Some(true);
+    let size = match order.side {
+        _ if close => 0,
+        OrderSide::Buy => contracts,
+        OrderSide::Sell => -contracts,
+    };
+
+    let (price, tif) = match order.order_type {
+        OrderType::Market => ("0".to_string(), "ioc"),
+        OrderType::Limit => {
+            let price = order.price.ok_or_else(|| {
+                ExchangeError::InvalidParameters("Limit orders require a price".to_string())
+            })?;
+            let tif = match order.time_in_force {
+                None | Some(TimeInForce::GTC) => "gtc",
+                Some(TimeInForce::IOC) => "ioc",
+                Some(TimeInForce::FOK) => "fok",
+            };
+            (price.to_string(), tif)
+        }
+        _ => {
+            return Err(ExchangeError::NotSupported(format!(
+                "Gate.io futures does not support {:?} orders",
+                order.order_type
+            )))
+        }
+    };
+
+    Ok(gateio_perp_types::GateioFuturesOrderRequest {
+        contract: convert_symbol_to_contract(&order.symbol)?,
+        size,
+        price,
+        tif: tif.to_string(),
+        text,
+        reduce_only: (order.reduce_only == Some(true) && !close).then_some(true),
+        close: close.then_some(true),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contract_market_and_funding() {
+        let contract: gateio_perp_types::GateioContract = serde_json::from_str(
+            r#"{"name":"BTC_USDT","type":"direct","quanto_multiplier":"0.0001","order_price_round":"0.1","order_size_min":1,"order_size_max":1000000,"in_delisting":false,"funding_rate":"0.0001","funding_interval":28800,"funding_next_apply":1610035200,"mark_price":"40000.1","index_price":"40000"}"#,
+        )
+        .unwrap();
+        let market = convert_gateio_contract(&contract).unwrap();
+        assert_eq!(market.symbol.joined("/"), "BTC/USDT");
+        assert_eq!(market.quote_precision, 1);
+        assert_eq!(market.contract_size, Some(Decimal::new(1, 4)));
+
+        let rate = convert_gateio_contract_funding(&contract, 0);
+        assert_eq!(rate.next_funding_time, Some(1_610_035_200_000));
+        assert_eq!(
+            rate.funding_interval,
+            Some(Duration::from_secs(8 * 60 * 60))
+        );
+        assert_eq!(rate.funding_rate, Some(Decimal::new(1, 4)));
+    }
+
+    #[test]
+    fn test_signed_sizes() {
+        let trade: gateio_perp_types::GateioFuturesTrade = serde_json::from_str(
+            r#"{"id":121234231,"create_time":1514764800.123,"contract":"BTC_USDT","size":-100,"price":"100.123"}"#,
+        )
+        .unwrap();
+        let trade = convert_gateio_futures_trade(&trade).unwrap();
+        assert_eq!(trade.time, 1_514_764_800_123);
+        assert_eq!(trade.quantity.to_string(), "100");
+        assert!(trade.is_buyer_maker);
+
+        let position: gateio_perp_types::GateioPosition = serde_json::from_str(
+            r#"{"contract":"BTC_USDT","size":-20,"leverage":"0","cross_leverage_limit":"10","entry_price":"40000","liq_price":"0","unrealised_pnl":"-1.5","margin":"8"}"#,
+        )
+        .unwrap();
+        let position = convert_gateio_position(&position, Some(Decimal::new(1, 4))).unwrap();
+        assert!(matches!(position.position_side, PositionSide::Short));
+        assert_eq!(position.position_amount.to_string(), "0.0020");
+        assert_eq!(position.margin_mode, Some(MarginMode::Cross));
+        assert_eq!(position.leverage, Decimal::TEN);
+        assert!(position.liquidation_price.is_none());
+
+        let order = OrderRequest {
+            symbol: convert_contract_to_symbol("BTC_USDT"),
+            side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            quantity: Quantity::new(Decimal::from(3)),
+            price: None,
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: Some(true),
+            close_position: None,
+            client_order_id: None,
+        };
+        let request = convert_order_request(&order, "t-1".to_string()).unwrap();
+        assert_eq!(request.size, -3);
+        assert_eq!(request.price, "0");
+        assert_eq!(request.tif, "ioc");
+        assert_eq!(request.reduce_only, Some(true));
+
+        let close = OrderRequest {
+            close_position: Some(true),
+            ..order
+        };
+        let request = convert_order_request(&close, "t-2".to_string()).unwrap();
+        assert_eq!(request.size, 0);
+        assert_eq!(request.close, Some(true));
+        assert!(request.reduce_only.is_none());
+    }
+}
@@ -0,0 +1,21 @@
+pub mod codec;
+pub mod conversions;
+pub mod types;
+
+pub mod builder;
+pub mod connector;
+pub mod rest;
+
+// Re-export main components
+pub use builder::{build_connector, build_connector_with_websocket, build_public};
+pub use codec::{GateioPerpCodec, GateioPerpMessage};
+pub use connector::{Account, GateioPerpConnector, MarketData, Trading};
+pub use types::{GateioContract, GateioFuturesOrderRequest, GateioPosition};
+
+/// Helper function to create WebSocket stream identifiers for Gate.io perpetuals
+pub fn create_gateio_perp_stream_identifiers(
+    contracts: &[String],
+    subscription_types: &[crate::core::types::SubscriptionType],
+) -> Result<Vec<String>, crate::core::errors::ExchangeError> {
+    codec::create_gateio_perp_stream_identifiers(contracts, subscription_types)
+}
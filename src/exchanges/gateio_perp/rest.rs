@@ -0,0 +1,174 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::RestClient;
+use crate::exchanges::gateio::types::GateioServerTime;
+use crate::exchanges::gateio_perp::types::{
+    GateioContract, GateioFundingRate, GateioFuturesAccount, GateioFuturesCandle,
+    GateioFuturesOrder, GateioFuturesOrderBook, GateioFuturesOrderRequest, GateioFuturesTicker,
+    GateioFuturesTrade, GateioPosition,
+};
+
+/// Thin typed wrapper around `RestClient` for Gate.io v4 USDT-settled perpetuals
+pub struct GateioPerpRestClient<R: RestClient> {
+    client: R,
+}
+
+impl<R: RestClient> GateioPerpRestClient<R> {
+    pub fn new(client: R) -> Self {
+        Self { client }
+    }
+
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.client.can_sign()
+    }
+
+    /// Get all contracts, with their current funding rate
+    pub async fn get_contracts(&self) -> Result<Vec<GateioContract>, ExchangeError> {
+        self.client
+            .get_json("/api/v4/futures/usdt/contracts", &[], false)
+            .await
+    }
+
+    /// Get one contract
+    pub async fn get_contract(&self, contract: &str) -> Result<GateioContract, ExchangeError> {
+        let endpoint = format!("/api/v4/futures/usdt/contracts/{}", contract);
+        self.client.get_json(&endpoint, &[], false).await
+    }
+
+    /// Get server time in milliseconds; the futures API shares the spot clock
+    pub async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let time: GateioServerTime = self
+            .client
+            .get_json("/api/v4/spot/time", &[], false)
+            .await?;
+        Ok(time.server_time)
+    }
+
+    /// Get up to 2000 candles, oldest first; `from` and `to` are unix seconds
+    pub async fn get_candlesticks(
+        &self,
+        contract: &str,
+        interval: &str,
+        limit: Option<u32>,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<Vec<GateioFuturesCandle>, ExchangeError> {
+        let limit = limit.map(|l| l.to_string());
+        let from = from.map(|f| f.to_string());
+        let to = to.map(|t| t.to_string());
+        let mut params = vec![("contract", contract), ("interval", interval)];
+        // Gate.io rejects `limit` alongside a `from`/`to` range
+        if let (None, None, Some(limit)) = (&from, &to, &limit) {
+            params.push(("limit", limit.as_str()));
+        } else {
+            if let Some(from) = &from {
+                params.push(("from", from.as_str()));
+            }
+            if let Some(to) = &to {
+                params.push(("to", to.as_str()));
+            }
+        }
+        self.client
+            .get_json("/api/v4/futures/usdt/candlesticks", &params, false)
+            .await
+    }
+
+    /// Get the 24 hour ticker of a contract
+    pub async fn get_tickers(
+        &self,
+        contract: &str,
+    ) -> Result<Vec<GateioFuturesTicker>, ExchangeError> {
+        let params = [("contract", contract)];
+        self.client
+            .get_json("/api/v4/futures/usdt/tickers", &params, false)
+            .await
+    }
+
+    /// Get the order book; Gate.io defaults to 10 levels and serves up to 100
+    pub async fn get_order_book(
+        &self,
+        contract: &str,
+        limit: Option<u32>,
+    ) -> Result<GateioFuturesOrderBook, ExchangeError> {
+        let limit = limit.map(|l| l.to_string());
+        let mut params = vec![("contract", contract), ("with_id", "true")];
+        if let Some(limit) = &limit {
+            params.push(("limit", limit.as_str()));
+        }
+        self.client
+            .get_json("/api/v4/futures/usdt/order_book", &params, false)
+            .await
+    }
+
+    /// Get recent trades, newest first; Gate.io defaults to 100 and serves up to 1000
+    pub async fn get_trades(
+        &self,
+        contract: &str,
+        limit: Option<u32>,
+    ) -> Result<Vec<GateioFuturesTrade>, ExchangeError> {
+        let limit = limit.map(|l| l.to_string());
+        let mut params = vec![("contract", contract)];
+        if let Some(limit) = &limit {
+            params.push(("limit", limit.as_str()));
+        }
+        self.client
+            .get_json("/api/v4/futures/usdt/trades", &params, false)
+            .await
+    }
+
+    /// Get settled funding rates, newest first; `from` and `to` are unix seconds
+    pub async fn get_funding_rate_history(
+        &self,
+        contract: &str,
+        limit: Option<u32>,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<Vec<GateioFundingRate>, ExchangeError> {
+        let limit = limit.map(|l| l.to_string());
+        let from = from.map(|f| f.to_string());
+        let to = to.map(|t| t.to_string());
+        let mut params = vec![("contract", contract)];
+        for (key, value) in [("limit", &limit), ("from", &from), ("to", &to)] {
+            if let Some(value) = value {
+                params.push((key, value.as_str()));
+            }
+        }
+        self.client
+            .get_json("/api/v4/futures/usdt/funding_rate", &params, false)
+            .await
+    }
+
+    /// Get the USDT futures account
+    pub async fn get_account(&self) -> Result<GateioFuturesAccount, ExchangeError> {
+        self.client
+            .get_json("/api/v4/futures/usdt/accounts", &[], true)
+            .await
+    }
+
+    /// Get open positions
+    pub async fn get_positions(&self) -> Result<Vec<GateioPosition>, ExchangeError> {
+        let params = [("holding", "true")];
+        self.client
+            .get_json("/api/v4/futures/usdt/positions", &params, true)
+            .await
+    }
+
+    /// Place an order
+    pub async fn place_order(
+        &self,
+        order: &GateioFuturesOrderRequest,
+    ) -> Result<GateioFuturesOrder, ExchangeError> {
+        let body = serde_json::to_value(order).map_err(|e| {
+            ExchangeError::SerializationError(format!("Failed to serialize order: {}", e))
+        })?;
+        self.client
+            .post_json("/api/v4/futures/usdt/orders", &body, true)
+            .await
+    }
+
+    /// Cancel an order by id
+    pub async fn cancel_order(&self, order_id: &str) -> Result<GateioFuturesOrder, ExchangeError> {
+        let endpoint = format!("/api/v4/futures/usdt/orders/{}", order_id);
+        self.client.delete_json(&endpoint, &[], true).await
+    }
+}
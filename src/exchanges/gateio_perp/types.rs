@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+
+/// USDT-settled perpetual contract
+#[derive(Debug, Deserialize)]
+pub struct GateioContract {
+    /// e.g. `BTC_USDT`
+    pub name: String,
+    /// Base units per contract
+    pub quanto_multiplier: String,
+    /// Price tick
+    pub order_price_round: String,
+    pub order_size_min: i64,
+    pub order_size_max: i64,
+    #[serde(default)]
+    pub in_delisting: bool,
+    pub funding_rate: String,
+    /// Seconds between funding settlements
+    pub funding_interval: u64,
+    /// Unix seconds
+    pub funding_next_apply: i64,
+    pub mark_price: String,
+    pub index_price: String,
+}
+
+/// Candle of a contract; volume `v` counts contracts
+#[derive(Debug, Deserialize)]
+pub struct GateioFuturesCandle {
+    /// Unix seconds
+    pub t: i64,
+    #[serde(default)]
+    pub v: i64,
+    pub c: String,
+    pub h: String,
+    pub l: String,
+    pub o: String,
+}
+
+/// 24 hour ticker, over REST and on the `futures.tickers` channel
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioFuturesTicker {
+    pub contract: String,
+    pub last: String,
+    /// Percent change over the last 24 hours
+    pub change_percentage: String,
+    pub high_24h: String,
+    pub low_24h: String,
+    #[serde(default)]
+    pub volume_24h_base: String,
+    #[serde(default)]
+    pub volume_24h_quote: String,
+    #[serde(default)]
+    pub mark_price: String,
+    #[serde(default)]
+    pub index_price: String,
+    #[serde(default)]
+    pub funding_rate: String,
+}
+
+/// Book level; `s` counts contracts
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioFuturesLevel {
+    pub p: String,
+    pub s: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateioFuturesOrderBook {
+    #[serde(default)]
+    pub id: Option<i64>,
+    pub asks: Vec<GateioFuturesLevel>,
+    pub bids: Vec<GateioFuturesLevel>,
+}
+
+/// Public trade; a negative `size` is a sell
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioFuturesTrade {
+    pub id: i64,
+    /// Unix seconds with a fractional part over REST
+    #[serde(default)]
+    pub create_time: f64,
+    /// Milliseconds, WebSocket only
+    #[serde(default)]
+    pub create_time_ms: Option<i64>,
+    pub contract: String,
+    pub size: i64,
+    pub price: String,
+}
+
+/// Settled funding rate
+#[derive(Debug, Deserialize)]
+pub struct GateioFundingRate {
+    /// Unix seconds
+    pub t: i64,
+    pub r: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateioFuturesAccount {
+    pub currency: String,
+    pub total: String,
+    pub available: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateioPosition {
+    pub contract: String,
+    /// Contracts, negative when short
+    pub size: i64,
+    /// `0` for cross margin
+    pub leverage: String,
+    #[serde(default)]
+    pub cross_leverage_limit: String,
+    pub entry_price: String,
+    pub liq_price: String,
+    pub unrealised_pnl: String,
+    pub margin: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GateioFuturesOrderRequest {
+    pub contract: String,
+    /// Contracts, negative to sell
+    pub size: i64,
+    /// `0` for market orders
+    pub price: String,
+    pub tif: String,
+    /// Client order id, `t-` followed by up to 28 characters
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    /// Close the whole position; requires `size` 0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateioFuturesOrder {
+    pub id: i64,
+    pub status: String,
+}
+
+/// Limited-level book snapshot pushed by `futures.order_book`
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioFuturesWsBook {
+    pub contract: String,
+    #[serde(default)]
+    pub id: i64,
+    pub asks: Vec<GateioFuturesLevel>,
+    pub bids: Vec<GateioFuturesLevel>,
+}
+
+/// Changed levels pushed by `futures.order_book_update`; a zero size removes the level
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioFuturesWsBookUpdate {
+    /// Milliseconds
+    pub t: i64,
+    pub s: String,
+    #[serde(rename = "U")]
+    pub first_update_id: i64,
+    #[serde(rename = "u")]
+    pub last_update_id: i64,
+    #[serde(rename = "b", default)]
+    pub bids: Vec<GateioFuturesLevel>,
+    #[serde(rename = "a", default)]
+    pub asks: Vec<GateioFuturesLevel>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GateioFuturesWsCandle {
+    /// Unix seconds
+    pub t: i64,
+    #[serde(default)]
+    pub v: i64,
+    pub o: String,
+    pub h: String,
+    pub l: String,
+    pub c: String,
+    /// `interval_CONTRACT`, e.g. `1m_BTC_USDT`
+    pub n: String,
+    /// Whether the window has closed
+    #[serde(default)]
+    pub w: bool,
+}
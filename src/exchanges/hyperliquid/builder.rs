@@ -1,17 +1,16 @@
 use crate::core::config::ExchangeConfig;
 use crate::core::errors::ExchangeError;
-use crate::core::kernel::{ReqwestRest, RestClientBuilder, RestClientConfig, TungsteniteWs};
+use crate::core::kernel::{
+    Profile, ReqwestRest, RestClientBuilder, RestClientConfig, TungsteniteWs,
+};
+use crate::exchanges::endpoints::{resolve_endpoints, ResolvedEndpoints};
+use crate::exchanges::factory::ExchangeId;
 use crate::exchanges::hyperliquid::codec::HyperliquidCodec;
 use crate::exchanges::hyperliquid::connector::HyperliquidConnector;
 use crate::exchanges::hyperliquid::rest::HyperliquidRest;
 use crate::exchanges::hyperliquid::signer::HyperliquidSigner;
 use std::sync::Arc;
 
-const MAINNET_API_URL: &str = "https://api.hyperliquid.xyz";
-const TESTNET_API_URL: &str = "https://api.hyperliquid-testnet.xyz";
-const MAINNET_WS_URL: &str = "wss://api.hyperliquid.xyz/ws";
-const TESTNET_WS_URL: &str = "wss://api.hyperliquid-testnet.xyz/ws";
-
 /// Builder for creating Hyperliquid connectors
 pub struct HyperliquidBuilder {
     config: ExchangeConfig,
@@ -50,11 +49,17 @@ impl HyperliquidBuilder {
         self
     }
 
+    /// Select the transport defaults
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        self.config.profile = profile;
+        self
+    }
+
     /// Build a REST-only connector
     pub fn build_rest_only(self) -> Result<HyperliquidConnector<ReqwestRest, ()>, ExchangeError> {
         let rest_client = self.build_rest_client()?;
         let hyperliquid_rest = self.build_hyperliquid_rest(rest_client)?;
-        Ok(HyperliquidConnector::new(hyperliquid_rest))
+        Ok(HyperliquidConnector::new(hyperliquid_rest).with_ws_config(self.config.ws_config()))
     }
 
     /// Build a connector with WebSocket support
@@ -64,11 +69,11 @@ impl HyperliquidBuilder {
     {
         let rest_client = self.build_rest_client()?;
         let hyperliquid_rest = self.build_hyperliquid_rest(rest_client)?;
-        let ws_client = self.build_websocket_client();
-        Ok(HyperliquidConnector::new_with_ws(
-            hyperliquid_rest,
-            ws_client,
-        ))
+        let ws_client = self.build_websocket_client()?;
+        Ok(
+            HyperliquidConnector::new_with_ws(hyperliquid_rest, ws_client)
+                .with_ws_config(self.config.ws_config()),
+        )
     }
 
     /// Build a connector (auto-detects WebSocket requirement)
@@ -77,14 +82,18 @@ impl HyperliquidBuilder {
         self.build_rest_only()
     }
 
+    /// `with_mainnet` takes precedence over the config's testnet flag
+    fn endpoints(&self) -> Result<ResolvedEndpoints, ExchangeError> {
+        let config = self.config.clone().testnet(!self.is_mainnet);
+        resolve_endpoints(ExchangeId::Hyperliquid, &config)
+    }
+
     fn build_rest_client(&self) -> Result<ReqwestRest, ExchangeError> {
-        let base_url = if self.is_mainnet {
-            MAINNET_API_URL
-        } else {
-            TESTNET_API_URL
-        };
+        let endpoints = self.endpoints()?;
 
-        let rest_config = RestClientConfig::new(base_url.to_string(), "hyperliquid".to_string());
+        let rest_config = RestClientConfig::new(endpoints.rest, "hyperliquid".to_string())
+            .with_profile(self.config.profile)
+            .with_network(self.config.proxy.clone(), self.config.tls.clone());
         let mut rest_builder = RestClientBuilder::new(rest_config);
 
         // Add signer if credentials are available
@@ -125,15 +134,14 @@ impl HyperliquidBuilder {
         Ok(hyperliquid_rest)
     }
 
-    fn build_websocket_client(&self) -> TungsteniteWs<HyperliquidCodec> {
-        let ws_url = if self.is_mainnet {
-            MAINNET_WS_URL
-        } else {
-            TESTNET_WS_URL
-        };
+    fn build_websocket_client(&self) -> Result<TungsteniteWs<HyperliquidCodec>, ExchangeError> {
+        let endpoints = self.endpoints()?;
 
         let codec = HyperliquidCodec::new();
-        TungsteniteWs::new(ws_url.to_string(), "hyperliquid".to_string(), codec)
+        Ok(
+            TungsteniteWs::new(endpoints.ws, "hyperliquid".to_string(), codec)
+                .with_config(self.config.ws_config()),
+        )
     }
 }
 
@@ -153,6 +161,13 @@ pub fn build_hyperliquid_connector_with_websocket(
         .build_with_websocket()
 }
 
+/// Create a Hyperliquid connector for public market data, ignoring any private key in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<HyperliquidConnector<ReqwestRest, TungsteniteWs<HyperliquidCodec>>, ExchangeError> {
+    build_hyperliquid_connector_with_websocket(config.without_credentials())
+}
+
 /// Legacy compatibility function - create a connector from `ExchangeConfig`
 pub fn create_hyperliquid_client(
     config: ExchangeConfig,
@@ -3,6 +3,7 @@ use crate::core::kernel::codec::WsCodec;
 use crate::core::types::{
     conversion, Kline, KlineInterval, MarketDataType, OrderBook, OrderBookEntry, Ticker, Trade,
 };
+use crate::exchanges::hyperliquid::conversions::SYMBOLS;
 use serde_json::{json, Value};
 use tokio_tungstenite::tungstenite::Message;
 use tracing::warn;
@@ -28,7 +29,7 @@ impl From<HyperliquidWsMessage> for MarketDataType {
             // For heartbeat and unknown messages, we'll create a dummy ticker
             HyperliquidWsMessage::Heartbeat | HyperliquidWsMessage::Unknown(_) => {
                 Self::Ticker(Ticker {
-                    symbol: conversion::string_to_symbol("HEARTBEAT"),
+                    symbol: SYMBOLS.from_exchange_symbol("HEARTBEAT"),
                     price: conversion::string_to_price("0"),
                     price_change: conversion::string_to_price("0"),
                     price_change_percent: conversion::string_to_decimal("0"),
@@ -103,13 +104,14 @@ impl WsCodec for HyperliquidCodec {
                                     }
                                 })
                             }
-                            "kline" => {
+                            kline if kline.starts_with("kline") => {
+                                // "kline_<interval>"; bare "kline" means 1m
                                 json!({
                                     "method": "subscribe",
                                     "subscription": {
                                         "type": "candle",
                                         "coin": symbol,
-                                        "interval": "1m"
+                                        "interval": kline.strip_prefix("kline_").unwrap_or("1m")
                                     }
                                 })
                             }
@@ -195,13 +197,14 @@ impl WsCodec for HyperliquidCodec {
                                     }
                                 })
                             }
-                            "kline" => {
+                            kline if kline.starts_with("kline") => {
+                                // "kline_<interval>"; bare "kline" means 1m
                                 json!({
                                     "method": "unsubscribe",
                                     "subscription": {
                                         "type": "candle",
                                         "coin": symbol,
-                                        "interval": "1m"
+                                        "interval": kline.strip_prefix("kline_").unwrap_or("1m")
                                     }
                                 })
                             }
@@ -323,14 +326,14 @@ impl HyperliquidCodec {
         if let Some(mids) = data.as_object() {
             for (sym, price) in mids {
                 if let Some(price_str) = price.as_str() {
-                    if let Ok(_price_f64) = price_str.parse::<f64>() {
+                    if let Ok(mid) = conversion::try_string_to_price(price_str) {
                         return Some(Ticker {
-                            symbol: conversion::string_to_symbol(sym),
-                            price: conversion::string_to_price(price_str),
+                            symbol: SYMBOLS.from_exchange_symbol(sym),
+                            price: mid,
                             price_change: conversion::string_to_price("0"),
                             price_change_percent: conversion::string_to_decimal("0"),
-                            high_price: conversion::string_to_price(price_str),
-                            low_price: conversion::string_to_price(price_str),
+                            high_price: mid,
+                            low_price: mid,
                             volume: conversion::string_to_volume("0"),
                             quote_volume: conversion::string_to_volume("0"),
                             open_time: chrono::Utc::now().timestamp_millis(),
@@ -356,18 +359,15 @@ impl HyperliquidCodec {
                         let price = level_data[0]
                             .get("px")
                             .and_then(|p| p.as_str())
-                            .and_then(|p| p.parse::<f64>().ok());
+                            .and_then(|p| conversion::try_string_to_price(p).ok());
                         let quantity = level_data[0]
                             .get("sz")
                             .and_then(|s| s.as_str())
-                            .and_then(|s| s.parse::<f64>().ok());
+                            .and_then(|s| conversion::try_string_to_quantity(s).ok());
                         let side = level_data[0].get("side").and_then(|s| s.as_str());
 
                         if let (Some(price), Some(quantity), Some(side)) = (price, quantity, side) {
-                            let entry = OrderBookEntry {
-                                price: conversion::string_to_price(&price.to_string()),
-                                quantity: conversion::string_to_quantity(&quantity.to_string()),
-                            };
+                            let entry = OrderBookEntry { price, quantity };
 
                             if side == "B" {
                                 bids.push(entry);
@@ -380,7 +380,7 @@ impl HyperliquidCodec {
             }
 
             return Some(OrderBook {
-                symbol: conversion::string_to_symbol(symbol),
+                symbol: SYMBOLS.from_exchange_symbol(symbol),
                 bids,
                 asks,
                 last_update_id: chrono::Utc::now().timestamp_millis(),
@@ -397,11 +397,11 @@ impl HyperliquidCodec {
                     trade
                         .get("px")
                         .and_then(|p| p.as_str())
-                        .and_then(|p| p.parse::<f64>().ok()),
+                        .and_then(|p| conversion::try_string_to_price(p).ok()),
                     trade
                         .get("sz")
                         .and_then(|s| s.as_str())
-                        .and_then(|s| s.parse::<f64>().ok()),
+                        .and_then(|s| conversion::try_string_to_quantity(s).ok()),
                     trade.get("time").and_then(|t| t.as_i64()),
                 ) {
                     let side = trade
@@ -410,10 +410,10 @@ impl HyperliquidCodec {
                         .unwrap_or("unknown");
 
                     return Some(Trade {
-                        symbol: conversion::string_to_symbol(symbol),
+                        symbol: SYMBOLS.from_exchange_symbol(symbol),
                         id: trade.get("tid").and_then(|t| t.as_i64()).unwrap_or(0),
-                        price: conversion::string_to_price(&price.to_string()),
-                        quantity: conversion::string_to_quantity(&quantity.to_string()),
+                        price,
+                        quantity,
                         time: timestamp,
                         is_buyer_maker: side == "B",
                     });
@@ -428,31 +428,37 @@ impl HyperliquidCodec {
         if let (Some(open), Some(high), Some(low), Some(close), Some(volume), Some(timestamp)) = (
             data.get("o")
                 .and_then(|o| o.as_str())
-                .and_then(|o| o.parse::<f64>().ok()),
+                .and_then(|o| conversion::try_string_to_price(o).ok()),
             data.get("h")
                 .and_then(|h| h.as_str())
-                .and_then(|h| h.parse::<f64>().ok()),
+                .and_then(|h| conversion::try_string_to_price(h).ok()),
             data.get("l")
                 .and_then(|l| l.as_str())
-                .and_then(|l| l.parse::<f64>().ok()),
+                .and_then(|l| conversion::try_string_to_price(l).ok()),
             data.get("c")
                 .and_then(|c| c.as_str())
-                .and_then(|c| c.parse::<f64>().ok()),
+                .and_then(|c| conversion::try_string_to_price(c).ok()),
             data.get("v")
                 .and_then(|v| v.as_str())
-                .and_then(|v| v.parse::<f64>().ok()),
+                .and_then(|v| conversion::try_string_to_volume(v).ok()),
             data.get("t").and_then(|t| t.as_i64()),
         ) {
+            // Candle updates carry their interval as `i`, e.g. "15m"
+            let interval = data
+                .get("i")
+                .and_then(|i| i.as_str())
+                .and_then(KlineInterval::from_venue_str)
+                .unwrap_or(KlineInterval::Minutes1);
             return Some(Kline {
-                symbol: conversion::string_to_symbol(symbol),
+                symbol: SYMBOLS.from_exchange_symbol(symbol),
                 open_time: timestamp,
-                close_time: timestamp,
-                interval: KlineInterval::Minutes1.to_binance_format(),
-                open_price: conversion::string_to_price(&open.to_string()),
-                high_price: conversion::string_to_price(&high.to_string()),
-                low_price: conversion::string_to_price(&low.to_string()),
-                close_price: conversion::string_to_price(&close.to_string()),
-                volume: conversion::string_to_volume(&volume.to_string()),
+                close_time: interval.close_time(timestamp),
+                interval: interval.to_binance_format(),
+                open_price: open,
+                high_price: high,
+                low_price: low,
+                close_price: close,
+                volume,
                 number_of_trades: 1,
                 final_bar: true,
             });
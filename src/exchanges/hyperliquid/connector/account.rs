@@ -1,7 +1,7 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
-use crate::core::traits::AccountInfo;
-use crate::core::types::{Balance, Position};
+use crate::core::traits::{AccountInfo, AccountSummarySource, TradeHistorySource};
+use crate::core::types::{AccountFill, AccountSummary, Balance, Position, Symbol};
 use crate::exchanges::hyperliquid::conversions;
 use crate::exchanges::hyperliquid::rest::HyperliquidRest;
 use async_trait::async_trait;
@@ -28,6 +28,10 @@ impl<R: RestClient> Account<R> {
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     /// Get account balance
     #[instrument(skip(self), fields(exchange = "hyperliquid"))]
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
@@ -42,7 +46,7 @@ impl<R: RestClient + Clone + Send + Sync> AccountInfo for Account<R> {
             .ok_or_else(|| ExchangeError::AuthError("No wallet address available".to_string()))?;
 
         let user_state = self.rest.get_user_state(wallet_address).await?;
-        Ok(conversions::convert_user_state_to_balances(&user_state))
+        conversions::convert_user_state_to_balances(&user_state)
     }
 
     /// Get account positions
@@ -59,7 +63,68 @@ impl<R: RestClient + Clone + Send + Sync> AccountInfo for Account<R> {
             .ok_or_else(|| ExchangeError::AuthError("No wallet address available".to_string()))?;
 
         let user_state = self.rest.get_user_state(wallet_address).await?;
-        Ok(conversions::convert_user_state_to_positions(&user_state))
+        conversions::convert_user_state_to_positions(&user_state)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> AccountSummarySource for Account<R> {
+    #[instrument(skip(self), fields(exchange = "hyperliquid"))]
+    async fn get_account_summary(&self) -> Result<AccountSummary, ExchangeError> {
+        let wallet_address = self
+            .wallet_address()
+            .ok_or_else(|| ExchangeError::AuthError("No wallet address available".to_string()))?;
+
+        let user_state = self.rest.get_user_state(wallet_address).await?;
+        conversions::convert_user_state_to_account_summary(&user_state)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> TradeHistorySource for Account<R> {
+    /// `userFills` only covers recent fills, so a start time switches to `userFillsByTime`
+    #[instrument(skip(self), fields(exchange = "hyperliquid", symbol = %symbol))]
+    async fn get_my_trades(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AccountFill>, ExchangeError> {
+        let wallet_address = self
+            .wallet_address()
+            .ok_or_else(|| ExchangeError::AuthError("No wallet address available".to_string()))?;
+
+        let to_millis = |time: i64| u64::try_from(time).unwrap_or(0);
+        let fills = match start_time {
+            Some(start_time) => {
+                self.rest
+                    .get_user_fills_by_time(
+                        wallet_address,
+                        to_millis(start_time),
+                        end_time.map(to_millis),
+                    )
+                    .await?
+            }
+            None => self.rest.get_user_fills(wallet_address).await?,
+        };
+
+        let mut fills = fills
+            .iter()
+            .filter(|fill| fill.coin == symbol.base)
+            .map(conversions::convert_user_fill)
+            .filter(|fill| {
+                fill.as_ref().map_or(true, |fill| {
+                    end_time.map_or(true, |end| fill.timestamp <= end)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        fills.sort_by_key(|fill| fill.timestamp);
+        if let Some(limit) = limit {
+            let excess = fills.len().saturating_sub(limit as usize);
+            fills.drain(..excess);
+        }
+        Ok(fills)
     }
 }
 
@@ -1,192 +1,79 @@
 use crate::core::{
     errors::ExchangeError,
-    kernel::{rest::RestClient, ws::WsSession, ReconnectWs, TungsteniteWs},
-    traits::MarketDataSource,
-    types::{Kline, KlineInterval, Market, MarketDataType, SubscriptionType, WebSocketConfig},
+    kernel::{rest::RestClient, ws::WsSession, TungsteniteWs, WsConfig},
+    streaming::{SharedHub, StreamRouter},
+    traits::{HistoricalTradeSource, MarketSnapshotSource, RestMarketData, StreamingMarketData},
+    types::{
+        Kline, KlineInterval, Market, MarketDataType, OrderBook, SubscriptionType, Symbol, Ticker,
+        Trade, WebSocketConfig,
+    },
+};
+use crate::exchanges::hyperliquid::{
+    codec::{HyperliquidCodec, HyperliquidWsMessage},
+    conversions,
+    rest::HyperliquidRest,
 };
-use crate::exchanges::hyperliquid::{codec::HyperliquidCodec, conversions, rest::HyperliquidRest};
 use async_trait::async_trait;
-use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::{mpsc, oneshot, Mutex, RwLock};
-use tracing::{error, info, instrument, warn};
-
-/// Message for WebSocket subscription management
-#[derive(Debug)]
-#[allow(dead_code)] // Unsubscribe may be used in future implementations
-enum SubscriptionCommand {
-    Subscribe {
-        streams: Vec<String>,
-        response: oneshot::Sender<Result<(), ExchangeError>>,
-    },
-    Unsubscribe {
-        streams: Vec<String>,
-        response: oneshot::Sender<Result<(), ExchangeError>>,
-    },
-}
+use tokio::sync::mpsc;
+use tracing::instrument;
 
-/// WebSocket subscription manager that handles the actual WebSocket connection
-struct WebSocketManager {
-    ws_session: ReconnectWs<HyperliquidCodec, TungsteniteWs<HyperliquidCodec>>,
-    subscribers: HashMap<String, Vec<mpsc::Sender<MarketDataType>>>,
-    command_rx: mpsc::Receiver<SubscriptionCommand>,
-    active_subscriptions: Vec<String>,
-}
+/// Routes Hyperliquid updates, which are keyed by coin alone
+struct HyperliquidRouter;
 
-impl WebSocketManager {
-    async fn run(mut self) {
-        // Connect to WebSocket
-        if let Err(e) = self.ws_session.connect().await {
-            error!("Failed to connect to WebSocket: {}", e);
-            return;
-        }
+impl StreamRouter for HyperliquidRouter {
+    type Codec = HyperliquidCodec;
 
-        info!("WebSocket manager started and connected");
-
-        loop {
-            tokio::select! {
-                // Handle subscription commands
-                cmd = self.command_rx.recv() => {
-                    match cmd {
-                        Some(SubscriptionCommand::Subscribe { streams, response }) => {
-                            let result = self.handle_subscribe(streams).await;
-                            let _ = response.send(result);
-                        }
-                        Some(SubscriptionCommand::Unsubscribe { streams, response }) => {
-                            let result = self.handle_unsubscribe(streams).await;
-                            let _ = response.send(result);
-                        }
-                        None => {
-                            warn!("Subscription command channel closed");
-                            break;
-                        }
-                    }
-                }
-
-                // Handle incoming WebSocket messages
-                msg = self.ws_session.next_message() => {
-                    match msg {
-                        Some(Ok(message)) => {
-                            self.handle_message(message).await;
-                        }
-                        Some(Err(e)) => {
-                            error!("WebSocket error: {}", e);
-                            // The ReconnectWs will handle reconnection automatically
-                        }
-                        None => {
-                            warn!("WebSocket stream ended");
-                            break;
-                        }
-                    }
-                }
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        let coin = conversions::SYMBOLS.try_to_exchange_symbol(symbol)?;
+        Ok(Some(match subscription {
+            SubscriptionType::Ticker => format!("{}@ticker", coin),
+            SubscriptionType::OrderBook { .. } => format!("{}@orderbook", coin),
+            SubscriptionType::Trades | SubscriptionType::AggTrades => format!("{}@trade", coin),
+            SubscriptionType::Klines { interval } => format!(
+                "{}@kline_{}",
+                coin,
+                conversions::convert_kline_interval_to_hyperliquid(*interval)
+            ),
+            SubscriptionType::MarkPrice => {
+                return Err(ExchangeError::NotSupported(
+                    "Hyperliquid mark price stream not supported yet".to_string(),
+                ))
             }
-        }
+        }))
     }
 
-    async fn handle_subscribe(&mut self, streams: Vec<String>) -> Result<(), ExchangeError> {
-        // Filter out streams that are already subscribed
-        let new_streams: Vec<String> = streams
-            .into_iter()
-            .filter(|stream| !self.active_subscriptions.contains(stream))
-            .collect();
-
-        if new_streams.is_empty() {
-            return Ok(());
-        }
-
-        // Subscribe to new streams
-        if let Err(e) = self.ws_session.subscribe(&new_streams).await {
-            error!("Failed to subscribe to streams: {}", e);
-            return Err(e);
+    fn convert(&self, message: HyperliquidWsMessage) -> Vec<MarketDataType> {
+        match message {
+            HyperliquidWsMessage::Heartbeat | HyperliquidWsMessage::Unknown(_) => Vec::new(),
+            message => vec![MarketDataType::from(message)],
         }
-
-        // Add to active subscriptions
-        self.active_subscriptions.extend(new_streams.clone());
-        info!("Subscribed to {} streams", new_streams.len());
-
-        Ok(())
     }
 
-    async fn handle_unsubscribe(&mut self, streams: Vec<String>) -> Result<(), ExchangeError> {
-        // Filter streams that are actually subscribed
-        let subscribed_streams: Vec<String> = streams
-            .into_iter()
-            .filter(|stream| self.active_subscriptions.contains(stream))
-            .collect();
-
-        if subscribed_streams.is_empty() {
-            return Ok(());
-        }
-
-        // Unsubscribe from streams
-        if let Err(e) = self.ws_session.unsubscribe(&subscribed_streams).await {
-            error!("Failed to unsubscribe from streams: {}", e);
-            return Err(e);
-        }
-
-        // Remove from active subscriptions
-        self.active_subscriptions
-            .retain(|stream| !subscribed_streams.contains(stream));
-        info!("Unsubscribed from {} streams", subscribed_streams.len());
-
-        Ok(())
+    fn route_symbol(&self, symbol: &Symbol) -> String {
+        conversions::SYMBOLS.to_exchange_symbol(symbol)
     }
-
-    async fn handle_message(
-        &self,
-        message: crate::exchanges::hyperliquid::codec::HyperliquidWsMessage,
-    ) {
-        // Convert message to MarketDataType
-        let market_data = MarketDataType::from(message);
-
-        // Get the symbol from the message to determine routing
-        let symbol = match &market_data {
-            MarketDataType::Ticker(ticker) => ticker.symbol.as_str(),
-            MarketDataType::OrderBook(book) => book.symbol.as_str(),
-            MarketDataType::Trade(trade) => trade.symbol.as_str(),
-            MarketDataType::Kline(kline) => kline.symbol.as_str(),
-        };
-
-        // Send to symbol-specific subscribers
-        if let Some(senders) = self.subscribers.get(symbol.as_str()) {
-            for sender in senders {
-                if let Err(e) = sender.send(market_data.clone()).await {
-                    warn!("Failed to send message to subscriber for {}: {}", symbol, e);
-                }
-            }
-        }
-
-        // Send to wildcard subscribers (symbol "*")
-        if let Some(senders) = self.subscribers.get("*") {
-            for sender in senders {
-                if let Err(e) = sender.send(market_data.clone()).await {
-                    warn!("Failed to send message to wildcard subscriber: {}", e);
-                }
-            }
-        }
-    }
-
-    #[allow(dead_code)] // May be used in future implementations
-    fn add_subscriber(&mut self, symbol: String, sender: mpsc::Sender<MarketDataType>) {
-        self.subscribers.entry(symbol).or_default().push(sender);
-    }
-}
-
-/// Shared WebSocket subscription manager
-type SharedSubscriptionManager = Arc<RwLock<HashMap<String, Vec<mpsc::Sender<MarketDataType>>>>>;
-
-/// Internal state for WebSocket management
-struct WebSocketState {
-    command_tx: Option<mpsc::Sender<SubscriptionCommand>>,
-    handler_started: bool,
 }
 
 pub struct MarketData<R: RestClient, W = ()> {
     rest: HyperliquidRest<R>,
-    #[allow(dead_code)] // May be used in future implementations
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
     ws: Option<W>,
-    subscription_manager: Option<SharedSubscriptionManager>,
-    ws_state: Arc<Mutex<WebSocketState>>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
 }
 
 impl<R: RestClient + Clone> MarketData<R, ()> {
@@ -194,11 +81,8 @@ impl<R: RestClient + Clone> MarketData<R, ()> {
         Self {
             rest,
             ws: None,
-            subscription_manager: None,
-            ws_state: Arc::new(Mutex::new(WebSocketState {
-                command_tx: None,
-                handler_started: false,
-            })),
+            hub: SharedHub::new(),
+            ws_config: WsConfig::default(),
         }
     }
 }
@@ -208,119 +92,14 @@ impl<R: RestClient + Clone, W: WsSession<HyperliquidCodec> + Send + Sync> Market
         Self {
             rest,
             ws: Some(ws),
-            subscription_manager: Some(Arc::new(RwLock::new(HashMap::new()))),
-            ws_state: Arc::new(Mutex::new(WebSocketState {
-                command_tx: None,
-                handler_started: false,
-            })),
-        }
-    }
-
-    /// Start WebSocket manager in a background task (only once)
-    #[allow(clippy::significant_drop_tightening)]
-    async fn ensure_websocket_handler_started(&self) -> Result<(), ExchangeError> {
-        let mut state = self.ws_state.lock().await;
-        if state.handler_started {
-            return Ok(());
-        }
-
-        // Create WebSocket manager
-        let ws_url = self.rest.get_websocket_url();
-        let codec = HyperliquidCodec::new();
-        let base_ws = TungsteniteWs::new(ws_url, "hyperliquid".to_string(), codec);
-        let reconnect_ws = ReconnectWs::new(base_ws)
-            .with_max_reconnect_attempts(5)
-            .with_reconnect_delay(std::time::Duration::from_secs(2))
-            .with_auto_resubscribe(true);
-
-        // Create command channel
-        let (command_tx, command_rx) = mpsc::channel(100);
-        state.command_tx = Some(command_tx);
-
-        // Create WebSocket manager
-        let manager = WebSocketManager {
-            ws_session: reconnect_ws,
-            subscribers: HashMap::new(),
-            command_rx,
-            active_subscriptions: Vec::new(),
-        };
-
-        // Start the manager in a background task
-        tokio::spawn(async move {
-            manager.run().await;
-        });
-
-        state.handler_started = true;
-        Ok(())
-    }
-
-    /// Subscribe to market data streams
-    async fn subscribe_to_streams(
-        &self,
-        symbols: Vec<String>,
-        subscription_types: Vec<SubscriptionType>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        // Ensure WebSocket handler is started
-        self.ensure_websocket_handler_started().await?;
-
-        // Create a channel for this subscription
-        let (tx, rx) = mpsc::channel(1000);
-
-        // Build subscription streams in Hyperliquid format
-        let mut streams = Vec::new();
-        for symbol in &symbols {
-            for sub_type in &subscription_types {
-                let stream = match sub_type {
-                    SubscriptionType::Ticker => format!("{}@ticker", symbol),
-                    SubscriptionType::OrderBook { .. } => format!("{}@orderbook", symbol),
-                    SubscriptionType::Trades => format!("{}@trade", symbol),
-                    SubscriptionType::Klines { .. } => format!("{}@kline", symbol),
-                };
-                streams.push(stream);
-            }
+            hub: SharedHub::new(),
+            ws_config: WsConfig::default(),
         }
-
-        // Send subscription command
-        {
-            let state = self.ws_state.lock().await;
-            if let Some(command_tx) = &state.command_tx {
-                let (response_tx, response_rx) = oneshot::channel();
-                let subscribe_cmd = SubscriptionCommand::Subscribe {
-                    streams: streams.clone(),
-                    response: response_tx,
-                };
-
-                command_tx.send(subscribe_cmd).await.map_err(|_| {
-                    ExchangeError::Other("Failed to send subscription command".to_string())
-                })?;
-
-                response_rx.await.map_err(|_| {
-                    ExchangeError::Other("Failed to receive subscription response".to_string())
-                })??;
-            }
-        }
-
-        // Register subscribers for each symbol
-        if let Some(subscription_manager) = &self.subscription_manager {
-            let mut subscribers = subscription_manager.write().await;
-            for symbol in &symbols {
-                subscribers
-                    .entry(symbol.clone())
-                    .or_default()
-                    .push(tx.clone());
-            }
-        }
-
-        info!(
-            "WebSocket subscriptions registered for {} symbols",
-            symbols.len()
-        );
-        Ok(rx)
     }
 }
 
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync> MarketDataSource for MarketData<R, ()> {
+impl<R: RestClient + Clone + Send + Sync> RestMarketData for MarketData<R, ()> {
     /// Get all available markets/trading pairs
     #[instrument(skip(self), fields(exchange = "hyperliquid"))]
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
@@ -331,35 +110,17 @@ impl<R: RestClient + Clone + Send + Sync> MarketDataSource for MarketData<R, ()>
             .collect())
     }
 
-    /// Subscribe to market data via WebSocket
-    #[instrument(skip(self), fields(exchange = "hyperliquid"))]
-    async fn subscribe_market_data(
-        &self,
-        _symbols: Vec<String>,
-        _subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        // For now, return an error as we don't have WebSocket support in this implementation
-        Err(ExchangeError::Other(
-            "WebSocket subscriptions require WebSocket session".to_string(),
-        ))
-    }
-
-    /// Get WebSocket endpoint URL for market data
-    fn get_websocket_url(&self) -> String {
-        self.rest.get_websocket_url()
-    }
-
     /// Get historical k-lines/candlestick data
     #[instrument(skip(self), fields(exchange = "hyperliquid", symbol = %symbol, interval = ?interval))]
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = conversions::SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let interval_str = conversions::convert_kline_interval_to_hyperliquid(interval);
         let candles = self
             .rest
@@ -370,7 +131,7 @@ impl<R: RestClient + Clone + Send + Sync> MarketDataSource for MarketData<R, ()>
         let mut klines: Vec<Kline> = candles
             .into_iter()
             .map(|c| conversions::convert_candle_to_kline(&c, &symbol, interval))
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         if let Some(limit) = limit {
             klines.truncate(limit as usize);
@@ -382,7 +143,7 @@ impl<R: RestClient + Clone + Send + Sync> MarketDataSource for MarketData<R, ()>
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: WsSession<HyperliquidCodec> + Send + Sync>
-    MarketDataSource for MarketData<R, W>
+    RestMarketData for MarketData<R, W>
 {
     /// Get all available markets/trading pairs
     #[instrument(skip(self), fields(exchange = "hyperliquid"))]
@@ -394,33 +155,17 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<HyperliquidCodec> + Send
             .collect())
     }
 
-    /// Subscribe to market data via WebSocket
-    #[instrument(skip(self), fields(exchange = "hyperliquid"))]
-    async fn subscribe_market_data(
-        &self,
-        symbols: Vec<String>,
-        subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        // Use the safe implementation with interior mutability
-        self.subscribe_to_streams(symbols, subscription_types).await
-    }
-
-    /// Get WebSocket endpoint URL for market data
-    fn get_websocket_url(&self) -> String {
-        self.rest.get_websocket_url()
-    }
-
     /// Get historical k-lines/candlestick data
     #[instrument(skip(self), fields(exchange = "hyperliquid", symbol = %symbol, interval = ?interval))]
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = conversions::SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let interval_str = conversions::convert_kline_interval_to_hyperliquid(interval);
         let candles = self
             .rest
@@ -431,7 +176,7 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<HyperliquidCodec> + Send
         let mut klines: Vec<Kline> = candles
             .into_iter()
             .map(|c| conversions::convert_candle_to_kline(&c, &symbol, interval))
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         if let Some(limit) = limit {
             klines.truncate(limit as usize);
@@ -441,6 +186,108 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<HyperliquidCodec> + Send
     }
 }
 
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for MarketData<R, W>
+{
+    #[instrument(skip(self), fields(exchange = "hyperliquid", symbol = %symbol))]
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let response = self.rest.get_meta_and_asset_ctxs().await?;
+        let ctx = response
+            .0
+            .universe
+            .iter()
+            .position(|asset| asset.name == symbol.base)
+            .and_then(|index| response.1.get(index))
+            .ok_or_else(|| {
+                ExchangeError::InvalidParameters(format!("Unknown symbol: {}", symbol))
+            })?;
+        let ticker = conversions::convert_asset_context_to_ticker(
+            &symbol.base,
+            ctx,
+            chrono::Utc::now().timestamp_millis(),
+        )?;
+        Ok(Ticker { symbol, ..ticker })
+    }
+
+    /// Hyperliquid serves at most 20 levels per side
+    #[instrument(skip(self), fields(exchange = "hyperliquid", symbol = %symbol))]
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let book = self
+            .rest
+            .get_l2_book(&conversions::SYMBOLS.try_to_exchange_symbol(&symbol)?)
+            .await?;
+        let book = conversions::convert_l2_book(&book)?;
+        Ok(OrderBook { symbol, ..book }.truncated(depth))
+    }
+
+    async fn get_recent_trades(
+        &self,
+        _symbol: Symbol,
+        _limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        Err(ExchangeError::NotSupported(
+            "Hyperliquid publishes public trades only over WebSocket".to_string(),
+        ))
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> HistoricalTradeSource
+    for MarketData<R, W>
+{
+    /// The info API has no public trade history; record the `trades` stream instead
+    async fn get_historical_trades(
+        &self,
+        _symbol: Symbol,
+        _start_time: i64,
+        _end_time: i64,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        Err(ExchangeError::NotSupported(
+            "Hyperliquid publishes public trades only over WebSocket".to_string(),
+        ))
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<HyperliquidCodec> + Send + Sync>
+    StreamingMarketData for MarketData<R, W>
+{
+    /// Subscribe to market data via WebSocket
+    #[instrument(skip(self), fields(exchange = "hyperliquid"))]
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(
+                        self.rest.get_websocket_url(),
+                        "hyperliquid".to_string(),
+                        HyperliquidCodec::new(),
+                    )
+                    .with_config(self.ws_config.clone())
+                },
+                HyperliquidRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    /// Get WebSocket endpoint URL for market data
+    fn get_websocket_url(&self) -> String {
+        self.rest.get_websocket_url()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -458,8 +305,9 @@ mod tests {
         let hyperliquid_rest = HyperliquidRest::new(rest_client, None, false);
         let market_data = MarketData::new(hyperliquid_rest);
 
-        // Test basic functionality
-        assert!(market_data.get_websocket_url().contains("hyperliquid"));
+        // REST-only market data carries no WebSocket state
+        assert!(market_data.ws.is_none());
+        assert!(market_data.hub.get().is_none());
     }
 
     #[test]
@@ -479,8 +327,8 @@ mod tests {
 
         let market_data = MarketData::new_with_ws(hyperliquid_rest, ws_session);
 
-        // Test that WebSocket functionality is available
-        assert!(market_data.subscription_manager.is_some());
+        // The stream hub connects on the first subscription
         assert!(market_data.ws.is_some());
+        assert!(market_data.hub.get().is_none());
     }
 }
@@ -1,5 +1,9 @@
-use crate::core::kernel::RestClient;
-use crate::core::traits::{AccountInfo, ExchangeConnector, MarketDataSource, OrderPlacer};
+use crate::core::kernel::{diagnostics, Diagnostics, RestClient, WsConfig};
+use crate::core::traits::{
+    AccountInfo, AccountSummarySource, DiagnosticsSource, HistoricalTradeSource, KillSwitch,
+    MarketSnapshotSource, OrderHistorySource, OrderPlacer, OrderQuery, PositionManager,
+    RestMarketData, StreamingMarketData, TradeHistorySource,
+};
 use crate::exchanges::hyperliquid::rest::HyperliquidRest;
 use async_trait::async_trait;
 
@@ -43,48 +47,57 @@ impl<R: RestClient + Clone, W> HyperliquidConnector<R, W> {
     }
 }
 
-// Implement the composite trait for convenience
-#[async_trait]
-impl<R: RestClient + Clone + Send + Sync> ExchangeConnector for HyperliquidConnector<R, ()> {}
-
-#[async_trait]
-impl<R: RestClient + Clone + Send + Sync, W> ExchangeConnector for HyperliquidConnector<R, W> where
-    W: crate::core::kernel::WsSession<crate::exchanges::hyperliquid::codec::HyperliquidCodec>
-        + Send
-        + Sync
-{
+// Delegate market data methods to the market component
+impl<R: RestClient, W> HyperliquidConnector<R, W> {
+    /// Open market data subscriptions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(self, config: WsConfig) -> Self {
+        Self {
+            market: self.market.with_ws_config(config),
+            trading: self.trading,
+            account: self.account,
+        }
+    }
 }
 
-// Delegate MarketDataSource methods to the market component
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync> MarketDataSource for HyperliquidConnector<R, ()> {
+impl<R: RestClient + Clone + Send + Sync> RestMarketData for HyperliquidConnector<R, ()> {
     async fn get_markets(
         &self,
     ) -> Result<Vec<crate::core::types::Market>, crate::core::errors::ExchangeError> {
         self.market.get_markets().await
     }
 
-    async fn subscribe_market_data(
+    async fn get_klines(
         &self,
-        symbols: Vec<String>,
-        subscription_types: Vec<crate::core::types::SubscriptionType>,
-        config: Option<crate::core::types::WebSocketConfig>,
-    ) -> Result<
-        tokio::sync::mpsc::Receiver<crate::core::types::MarketDataType>,
-        crate::core::errors::ExchangeError,
-    > {
+        symbol: crate::core::types::Symbol,
+        interval: crate::core::types::KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<crate::core::types::Kline>, crate::core::errors::ExchangeError> {
         self.market
-            .subscribe_market_data(symbols, subscription_types, config)
+            .get_klines(symbol, interval, limit, start_time, end_time)
             .await
     }
+}
 
-    fn get_websocket_url(&self) -> String {
-        self.market.get_websocket_url()
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W> RestMarketData for HyperliquidConnector<R, W>
+where
+    W: crate::core::kernel::WsSession<crate::exchanges::hyperliquid::codec::HyperliquidCodec>
+        + Send
+        + Sync,
+{
+    async fn get_markets(
+        &self,
+    ) -> Result<Vec<crate::core::types::Market>, crate::core::errors::ExchangeError> {
+        self.market.get_markets().await
     }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: crate::core::types::Symbol,
         interval: crate::core::types::KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
@@ -97,21 +110,59 @@ impl<R: RestClient + Clone + Send + Sync> MarketDataSource for HyperliquidConnec
 }
 
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync, W> MarketDataSource for HyperliquidConnector<R, W>
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for HyperliquidConnector<R, W>
+{
+    async fn get_ticker(
+        &self,
+        symbol: crate::core::types::Symbol,
+    ) -> Result<crate::core::types::Ticker, crate::core::errors::ExchangeError> {
+        self.market.get_ticker(symbol).await
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: crate::core::types::Symbol,
+        depth: Option<u32>,
+    ) -> Result<crate::core::types::OrderBook, crate::core::errors::ExchangeError> {
+        self.market.get_order_book_snapshot(symbol, depth).await
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: crate::core::types::Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<crate::core::types::Trade>, crate::core::errors::ExchangeError> {
+        self.market.get_recent_trades(symbol, limit).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> HistoricalTradeSource
+    for HyperliquidConnector<R, W>
+{
+    async fn get_historical_trades(
+        &self,
+        symbol: crate::core::types::Symbol,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<crate::core::types::Trade>, crate::core::errors::ExchangeError> {
+        self.market
+            .get_historical_trades(symbol, start_time, end_time)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W> StreamingMarketData for HyperliquidConnector<R, W>
 where
     W: crate::core::kernel::WsSession<crate::exchanges::hyperliquid::codec::HyperliquidCodec>
         + Send
         + Sync,
 {
-    async fn get_markets(
-        &self,
-    ) -> Result<Vec<crate::core::types::Market>, crate::core::errors::ExchangeError> {
-        self.market.get_markets().await
-    }
-
     async fn subscribe_market_data(
         &self,
-        symbols: Vec<String>,
+        symbols: Vec<crate::core::types::Symbol>,
         subscription_types: Vec<crate::core::types::SubscriptionType>,
         config: Option<crate::core::types::WebSocketConfig>,
     ) -> Result<
@@ -126,24 +177,15 @@ where
     fn get_websocket_url(&self) -> String {
         self.market.get_websocket_url()
     }
-
-    async fn get_klines(
-        &self,
-        symbol: String,
-        interval: crate::core::types::KlineInterval,
-        limit: Option<u32>,
-        start_time: Option<i64>,
-        end_time: Option<i64>,
-    ) -> Result<Vec<crate::core::types::Kline>, crate::core::errors::ExchangeError> {
-        self.market
-            .get_klines(symbol, interval, limit, start_time, end_time)
-            .await
-    }
 }
 
 // Delegate OrderPlacer methods to the trading component
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync> OrderPlacer for HyperliquidConnector<R, ()> {
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
     async fn place_order(
         &self,
         order: crate::core::types::OrderRequest,
@@ -151,14 +193,31 @@ impl<R: RestClient + Clone + Send + Sync> OrderPlacer for HyperliquidConnector<R
         self.trading.place_order(order).await
     }
 
+    async fn place_orders(
+        &self,
+        orders: Vec<crate::core::types::OrderRequest>,
+    ) -> Vec<Result<crate::core::types::OrderResponse, crate::core::errors::ExchangeError>> {
+        self.trading.place_orders(orders).await
+    }
+
     async fn cancel_order(
         &self,
-        symbol: String,
+        symbol: crate::core::types::Symbol,
         order_id: String,
     ) -> Result<(), crate::core::errors::ExchangeError> {
         self.trading.cancel_order(symbol, order_id).await
     }
 
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: crate::core::types::Symbol,
+        client_order_id: String,
+    ) -> Result<(), crate::core::errors::ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+
     async fn modify_order(
         &self,
         order_id: String,
@@ -201,6 +260,10 @@ where
         + Send
         + Sync,
 {
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
     async fn place_order(
         &self,
         order: crate::core::types::OrderRequest,
@@ -208,14 +271,31 @@ where
         self.trading.place_order(order).await
     }
 
+    async fn place_orders(
+        &self,
+        orders: Vec<crate::core::types::OrderRequest>,
+    ) -> Vec<Result<crate::core::types::OrderResponse, crate::core::errors::ExchangeError>> {
+        self.trading.place_orders(orders).await
+    }
+
     async fn cancel_order(
         &self,
-        symbol: String,
+        symbol: crate::core::types::Symbol,
         order_id: String,
     ) -> Result<(), crate::core::errors::ExchangeError> {
         self.trading.cancel_order(symbol, order_id).await
     }
 
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: crate::core::types::Symbol,
+        client_order_id: String,
+    ) -> Result<(), crate::core::errors::ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+
     async fn modify_order(
         &self,
         order_id: String,
@@ -251,9 +331,51 @@ where
     }
 }
 
+// `Trading` has an inherent `get_open_orders` returning raw orders, so name the trait here
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderQuery
+    for HyperliquidConnector<R, W>
+{
+    async fn get_open_orders(
+        &self,
+        symbol: Option<crate::core::types::Symbol>,
+    ) -> Result<Vec<crate::core::types::Order>, crate::core::errors::ExchangeError> {
+        OrderQuery::get_open_orders(&self.trading, symbol).await
+    }
+
+    async fn get_order(
+        &self,
+        symbol: crate::core::types::Symbol,
+        order_id: String,
+    ) -> Result<crate::core::types::Order, crate::core::errors::ExchangeError> {
+        self.trading.get_order(symbol, order_id).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderHistorySource
+    for HyperliquidConnector<R, W>
+{
+    async fn get_order_history(
+        &self,
+        symbol: crate::core::types::Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<crate::core::types::OrderPage, crate::core::errors::ExchangeError> {
+        self.trading
+            .get_order_history(symbol, start_time, end_time, cursor)
+            .await
+    }
+}
+
 // Delegate AccountInfo methods to the account component
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync> AccountInfo for HyperliquidConnector<R, ()> {
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
     async fn get_account_balance(
         &self,
     ) -> Result<Vec<crate::core::types::Balance>, crate::core::errors::ExchangeError> {
@@ -274,6 +396,10 @@ where
         + Send
         + Sync,
 {
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
     async fn get_account_balance(
         &self,
     ) -> Result<Vec<crate::core::types::Balance>, crate::core::errors::ExchangeError> {
@@ -287,6 +413,94 @@ where
     }
 }
 
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountSummarySource
+    for HyperliquidConnector<R, W>
+{
+    async fn get_account_summary(
+        &self,
+    ) -> Result<crate::core::types::AccountSummary, crate::core::errors::ExchangeError> {
+        self.account.get_account_summary().await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> TradeHistorySource
+    for HyperliquidConnector<R, W>
+{
+    async fn get_my_trades(
+        &self,
+        symbol: crate::core::types::Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<crate::core::types::AccountFill>, crate::core::errors::ExchangeError> {
+        self.account
+            .get_my_trades(symbol, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> PositionManager
+    for HyperliquidConnector<R, W>
+{
+    async fn set_leverage(
+        &self,
+        symbol: crate::core::types::Symbol,
+        leverage: u32,
+    ) -> Result<(), crate::core::errors::ExchangeError> {
+        self.trading.set_leverage(symbol, leverage).await
+    }
+
+    async fn set_margin_mode(
+        &self,
+        symbol: crate::core::types::Symbol,
+        mode: crate::core::types::MarginMode,
+    ) -> Result<(), crate::core::errors::ExchangeError> {
+        self.trading.set_margin_mode(symbol, mode).await
+    }
+
+    async fn get_leverage_brackets(
+        &self,
+        symbol: crate::core::types::Symbol,
+    ) -> Result<Vec<crate::core::types::LeverageBracket>, crate::core::errors::ExchangeError> {
+        self.trading.get_leverage_brackets(symbol).await
+    }
+
+    async fn close_position(
+        &self,
+        symbol: crate::core::types::Symbol,
+    ) -> Result<Option<crate::core::types::OrderResponse>, crate::core::errors::ExchangeError> {
+        self.trading.close_position(symbol).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> KillSwitch
+    for HyperliquidConnector<R, W>
+{
+    async fn cancel_all_orders(
+        &self,
+        symbol: Option<crate::core::types::Symbol>,
+    ) -> Result<(), crate::core::errors::ExchangeError> {
+        self.trading.cancel_all_orders(symbol).await
+    }
+
+    async fn set_cancel_on_disconnect(
+        &self,
+        timeout: std::time::Duration,
+    ) -> Result<(), crate::core::errors::ExchangeError> {
+        self.trading.set_cancel_on_disconnect(timeout).await
+    }
+}
+
+impl<R: RestClient, W> DiagnosticsSource for HyperliquidConnector<R, W> {
+    fn diagnostics(&self) -> Diagnostics {
+        diagnostics("hyperliquid")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -304,7 +518,6 @@ mod tests {
         let connector = HyperliquidConnector::new(hyperliquid_rest);
 
         // Test that we can access components
-        assert!(connector.market.get_websocket_url().contains("hyperliquid"));
         assert!(!connector.trading.can_sign());
         assert!(!connector.account.can_sign());
     }
@@ -321,7 +534,6 @@ mod tests {
         let connector = HyperliquidConnector::new(hyperliquid_rest);
 
         // Test that we can access components
-        assert!(connector.market.get_websocket_url().contains("hyperliquid"));
         assert!(!connector.trading.can_sign());
         assert!(!connector.account.can_sign());
     }
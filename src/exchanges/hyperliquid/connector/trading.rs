@@ -1,10 +1,17 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
-use crate::core::traits::OrderPlacer;
-use crate::core::types::{OrderRequest, OrderResponse};
+use crate::core::traits::{
+    KillSwitch, OrderHistorySource, OrderPlacer, OrderQuery, PositionManager,
+};
+use crate::core::types::{
+    LeverageBracket, MarginMode, Order, OrderPage, OrderRequest, OrderResponse, Symbol,
+};
 use crate::exchanges::hyperliquid::conversions;
+use crate::exchanges::hyperliquid::conversions::SYMBOLS;
 use crate::exchanges::hyperliquid::rest::HyperliquidRest;
+use crate::exchanges::hyperliquid::types::{AssetInfo, Leverage};
 use async_trait::async_trait;
+use std::time::Duration;
 use tracing::instrument;
 
 /// Trading implementation for Hyperliquid
@@ -28,6 +35,10 @@ impl<R: RestClient> Trading<R> {
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     /// Place a new order
     #[instrument(skip(self, order), fields(exchange = "hyperliquid"))]
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
@@ -47,9 +58,63 @@ impl<R: RestClient + Clone + Send + Sync> OrderPlacer for Trading<R> {
         conversions::convert_hyperliquid_order_response_to_generic(&response, &order)
     }
 
+    /// Place several orders in a single bulk order action
+    #[instrument(skip(self, orders), fields(exchange = "hyperliquid", count = orders.len()))]
+    async fn place_orders(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Vec<Result<OrderResponse, ExchangeError>> {
+        if !self.can_sign() {
+            return orders
+                .iter()
+                .map(|_| {
+                    Err(ExchangeError::AuthError(
+                        "Trading requires authentication".to_string(),
+                    ))
+                })
+                .collect();
+        }
+
+        // Orders that fail conversion are reported in place and left out of the action
+        let mut results = Vec::with_capacity(orders.len());
+        let mut slots = Vec::new();
+        let mut batch = Vec::new();
+        let mut accepted = Vec::new();
+        for order in orders {
+            match conversions::convert_order_request_to_hyperliquid(&order) {
+                Ok(converted) => {
+                    slots.push(results.len());
+                    results.push(None);
+                    batch.push(converted);
+                    accepted.push(order);
+                }
+                Err(e) => results.push(Some(Err(e))),
+            }
+        }
+
+        if !batch.is_empty() {
+            let placed = match self.rest.place_orders(&batch).await {
+                Ok(response) => conversions::convert_hyperliquid_bulk_response(&response, accepted),
+                Err(e) => {
+                    let message = e.to_string();
+                    accepted
+                        .iter()
+                        .map(|_| Err(ExchangeError::Other(message.clone())))
+                        .collect()
+                }
+            };
+            for (slot, result) in slots.into_iter().zip(placed) {
+                results[slot] = Some(result);
+            }
+        }
+
+        results.into_iter().flatten().collect()
+    }
+
     /// Cancel an existing order
     #[instrument(skip(self, symbol, order_id), fields(exchange = "hyperliquid"))]
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         if !self.can_sign() {
             return Err(ExchangeError::AuthError(
                 "Trading requires authentication".to_string(),
@@ -66,6 +131,29 @@ impl<R: RestClient + Clone + Send + Sync> OrderPlacer for Trading<R> {
         Ok(())
     }
 
+    /// Cancel an order by the cloid it was placed with
+    #[instrument(skip(self, symbol), fields(exchange = "hyperliquid", cloid = %client_order_id))]
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        if !self.can_sign() {
+            return Err(ExchangeError::AuthError(
+                "Trading requires authentication".to_string(),
+            ));
+        }
+        conversions::validate_cloid(&client_order_id)?;
+
+        let (asset, _) = self
+            .asset(&SYMBOLS.try_to_exchange_symbol(&symbol)?)
+            .await?;
+        self.rest
+            .cancel_order_by_cloid(asset, &client_order_id)
+            .await?;
+        Ok(())
+    }
+
     /// Modify an existing order
     #[instrument(skip(self, order_id, order), fields(exchange = "hyperliquid"))]
     async fn modify_order(
@@ -101,18 +189,113 @@ impl<R: RestClient + Clone + Send + Sync> OrderPlacer for Trading<R> {
     }
 }
 
-impl<R: RestClient> Trading<R> {
-    /// Cancel all open orders (Hyperliquid-specific)
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> OrderQuery for Trading<R> {
+    /// Get open orders, optionally for one coin
     #[instrument(skip(self), fields(exchange = "hyperliquid"))]
-    pub async fn cancel_all_orders(&self) -> Result<(), ExchangeError> {
-        if !self.can_sign() {
-            return Err(ExchangeError::AuthError(
-                "Trading requires authentication".to_string(),
-            ));
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        let wallet_address = self
+            .wallet_address()
+            .ok_or_else(|| ExchangeError::AuthError("No wallet address available".to_string()))?;
+
+        self.rest
+            .get_open_orders(wallet_address)
+            .await?
+            .iter()
+            .filter(|order| symbol.as_ref().map_or(true, |s| order.coin == s.base))
+            .map(|order| conversions::convert_hyperliquid_order(order, "open", order.timestamp))
+            .collect()
+    }
+
+    /// Get a single order; order ids are unique per account, so the symbol is not needed
+    #[instrument(skip(self, _symbol), fields(exchange = "hyperliquid", order_id = %order_id))]
+    async fn get_order(&self, _symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        let wallet_address = self
+            .wallet_address()
+            .ok_or_else(|| ExchangeError::AuthError("No wallet address available".to_string()))?;
+        let oid: u64 = order_id.parse().map_err(|_| {
+            ExchangeError::InvalidParameters(format!("Invalid order ID format: {}", order_id))
+        })?;
+
+        let response = self.rest.get_order_status(wallet_address, oid).await?;
+        let entry = response.order.ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Order {} not found", order_id))
+        })?;
+        conversions::convert_hyperliquid_order(&entry.order, &entry.status, entry.status_timestamp)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> OrderHistorySource for Trading<R> {
+    /// Hyperliquid serves the latest 2000 orders in one page, so there is never a next cursor
+    #[instrument(skip(self), fields(exchange = "hyperliquid", symbol = %symbol))]
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        if let Some(cursor) = cursor {
+            return Err(ExchangeError::InvalidParameters(format!(
+                "Invalid cursor: {}",
+                cursor
+            )));
         }
+        let wallet_address = self
+            .wallet_address()
+            .ok_or_else(|| ExchangeError::AuthError("No wallet address available".to_string()))?;
 
-        let _response = self.rest.cancel_all_orders().await?;
-        Ok(())
+        let orders = self
+            .rest
+            .get_historical_orders(wallet_address)
+            .await?
+            .iter()
+            .filter(|entry| entry.order.coin == symbol.base)
+            .map(|entry| {
+                conversions::convert_hyperliquid_order(
+                    &entry.order,
+                    &entry.status,
+                    entry.status_timestamp,
+                )
+            })
+            .filter(|order| {
+                order.as_ref().map_or(true, |order| {
+                    !order.status.is_open()
+                        && start_time.map_or(true, |start| order.updated_time >= start)
+                        && end_time.map_or(true, |end| order.updated_time <= end)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(OrderPage {
+            orders,
+            next_cursor: None,
+        })
+    }
+}
+
+impl<R: RestClient> Trading<R> {
+    /// Index of `coin` in the perpetuals universe, which actions address assets by
+    async fn asset(&self, coin: &str) -> Result<(usize, AssetInfo), ExchangeError> {
+        self.rest
+            .get_markets()
+            .await?
+            .into_iter()
+            .enumerate()
+            .find(|(_, asset)| asset.name == coin)
+            .ok_or_else(|| ExchangeError::InvalidParameters(format!("Unknown coin: {}", coin)))
+    }
+
+    /// Current leverage setting of the user on `coin`
+    async fn leverage(&self, coin: &str) -> Result<Leverage, ExchangeError> {
+        let wallet_address = self
+            .wallet_address()
+            .ok_or_else(|| ExchangeError::AuthError("No wallet address available".to_string()))?;
+        Ok(self
+            .rest
+            .get_active_asset_data(wallet_address, coin)
+            .await?
+            .leverage)
     }
 
     /// Modify an existing order (Hyperliquid-specific)
@@ -149,6 +332,85 @@ impl<R: RestClient> Trading<R> {
     }
 }
 
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> PositionManager for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "hyperliquid", coin = %symbol.base))]
+    async fn set_leverage(&self, symbol: Symbol, leverage: u32) -> Result<(), ExchangeError> {
+        let (asset, _) = self.asset(&symbol.base).await?;
+        // The action sets both, so keep the current margin mode
+        let is_cross = self.leverage(&symbol.base).await?.leverage_type == "cross";
+        self.rest.update_leverage(asset, is_cross, leverage).await
+    }
+
+    #[instrument(skip(self), fields(exchange = "hyperliquid", coin = %symbol.base))]
+    async fn set_margin_mode(&self, symbol: Symbol, mode: MarginMode) -> Result<(), ExchangeError> {
+        let (asset, _) = self.asset(&symbol.base).await?;
+        let leverage = self.leverage(&symbol.base).await?.value;
+        self.rest
+            .update_leverage(asset, mode == MarginMode::Cross, leverage)
+            .await
+    }
+
+    #[instrument(skip(self), fields(exchange = "hyperliquid", coin = %symbol.base))]
+    async fn get_leverage_brackets(
+        &self,
+        symbol: Symbol,
+    ) -> Result<Vec<LeverageBracket>, ExchangeError> {
+        let (_, asset) = self.asset(&symbol.base).await?;
+        Ok(vec![conversions::convert_asset_to_leverage_bracket(&asset)])
+    }
+
+    #[instrument(skip(self), fields(exchange = "hyperliquid", coin = %symbol.base))]
+    async fn close_position(&self, symbol: Symbol) -> Result<Option<OrderResponse>, ExchangeError> {
+        let wallet_address = self
+            .wallet_address()
+            .ok_or_else(|| ExchangeError::AuthError("No wallet address available".to_string()))?;
+        let user_state = self.rest.get_user_state(wallet_address).await?;
+        let positions = conversions::convert_user_state_to_positions(&user_state)?;
+        for (asset_position, position) in user_state.asset_positions.iter().zip(positions) {
+            if asset_position.position.coin != symbol.base {
+                continue;
+            }
+            if let Some(order) = position.close_order() {
+                let order = OrderRequest {
+                    symbol: symbol.clone(),
+                    ..order
+                };
+                return self.place_order(order).await.map(Some);
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> KillSwitch for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "hyperliquid"))]
+    async fn cancel_all_orders(&self, symbol: Option<Symbol>) -> Result<(), ExchangeError> {
+        let cancels: Vec<(String, u64)> = self
+            .get_open_orders()
+            .await?
+            .into_iter()
+            .filter(|order| symbol.as_ref().map_or(true, |s| order.coin == s.base))
+            .map(|order| (order.coin, order.oid))
+            .collect();
+        if !cancels.is_empty() {
+            self.rest.cancel_orders(&cancels).await?;
+        }
+        Ok(())
+    }
+
+    /// Schedules a cancel-all `timeout` from now, which must be at least five seconds
+    #[instrument(skip(self), fields(exchange = "hyperliquid"))]
+    async fn set_cancel_on_disconnect(&self, timeout: Duration) -> Result<(), ExchangeError> {
+        let time = (!timeout.is_zero()).then(|| {
+            let now = u64::try_from(chrono::Utc::now().timestamp_millis()).unwrap_or_default();
+            now.saturating_add(u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX))
+        });
+        self.rest.schedule_cancel(time).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +431,110 @@ mod tests {
         assert!(!trading.can_sign());
         assert!(trading.wallet_address().is_none());
     }
+
+    #[tokio::test]
+    async fn test_order_history_has_no_further_pages() {
+        let rest_client = ReqwestRest::new(
+            "https://api.hyperliquid.xyz".to_string(),
+            "hyperliquid".to_string(),
+            None,
+        )
+        .unwrap();
+        let trading = Trading::new(HyperliquidRest::new(rest_client, None, false));
+        let symbol = Symbol::new("BTC", "USD").unwrap();
+
+        let result = trading
+            .get_order_history(symbol, None, None, Some("1".to_string()))
+            .await;
+        assert!(matches!(result, Err(ExchangeError::InvalidParameters(_))));
+    }
+
+    #[test]
+    fn test_leverage_bracket_from_asset() {
+        let asset: AssetInfo = serde_json::from_str(
+            r#"{"name":"BTC","szDecimals":5,"maxLeverage":40,"marginTableId":56}"#,
+        )
+        .unwrap();
+        let bracket = conversions::convert_asset_to_leverage_bracket(&asset);
+
+        assert_eq!(bracket.max_leverage, rust_decimal::Decimal::from(40));
+        assert_eq!(bracket.cap, None);
+        assert_eq!(
+            bracket.maintenance_margin_rate,
+            rust_decimal::Decimal::new(125, 4)
+        );
+    }
+
+    #[test]
+    fn test_bulk_response_maps_statuses_in_order() {
+        use crate::core::types::{conversion, OrderSide, OrderType};
+
+        let response: crate::exchanges::hyperliquid::types::OrderResponse = serde_json::from_str(
+            r#"{"status":"ok","response":{"type":"order","data":{"statuses":[
+                    {"resting":{"oid":77}},
+                    {"error":"Order has invalid price."},
+                    {"filled":{"avgPx":"30000.5","oid":78,"totalSz":"0.1"}}
+                ]}}}"#,
+        )
+        .unwrap();
+        let order = OrderRequest {
+            symbol: SYMBOLS.from_exchange_symbol("BTC"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: conversion::string_to_quantity("0.1"),
+            price: Some(conversion::string_to_price("30000")),
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: Some("0x0123456789abcdef0123456789abcdef".to_string()),
+        };
+        let request = conversions::convert_order_request_to_hyperliquid(&order).unwrap();
+        assert_eq!(request.cloid, order.client_order_id);
+        assert!(conversions::validate_cloid("grid-1").is_err());
+
+        let results = conversions::convert_hyperliquid_bulk_response(&response, vec![order; 4]);
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap().order_id, "77");
+        assert_eq!(
+            results[0].as_ref().unwrap().client_order_id,
+            "0x0123456789abcdef0123456789abcdef"
+        );
+        assert_eq!(
+            results[1].as_ref().unwrap_err().kind(),
+            crate::core::errors::ErrorKind::InvalidPrice
+        );
+        assert_eq!(results[2].as_ref().unwrap().status, "FILLED");
+        assert_eq!(
+            results[2].as_ref().unwrap().extensions,
+            Some(serde_json::json!({"oid": 78, "avgPx": "30000.5", "totalSz": "0.1"}))
+        );
+        // Missing statuses surface as errors rather than shortening the output
+        assert!(results[3].is_err());
+    }
+
+    #[test]
+    fn test_order_status_marks_partial_fills() {
+        use crate::core::types::{OrderSide, OrderStatus};
+
+        let response: crate::exchanges::hyperliquid::types::OrderStatusResponse =
+            serde_json::from_str(
+                r#"{"status":"order","order":{"order":{"coin":"ETH","side":"A",
+                    "limitPx":"2500.0","sz":"0.4","oid":91,"timestamp":1700000000000,
+                    "origSz":"1.0"},"status":"open","statusTimestamp":1700000000500}}"#,
+            )
+            .unwrap();
+        let entry = response.order.unwrap();
+
+        let order = conversions::convert_hyperliquid_order(
+            &entry.order,
+            &entry.status,
+            entry.status_timestamp,
+        )
+        .unwrap();
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert!(matches!(order.side, OrderSide::Sell));
+        assert_eq!(order.filled_quantity.to_string(), "0.6");
+        assert_eq!(order.updated_time, 1_700_000_000_500);
+    }
 }
@@ -1,11 +1,54 @@
 use super::types::OrderRequest as HyperliquidOrderRequest;
 use super::types::{
-    AssetInfo, Candle, LimitOrder, OrderType, TimeInForce as HLTimeInForce, UserState,
+    AssetContext, AssetInfo, Candle, L2Book, L2Level, LimitOrder, OpenOrder,
+    OrderStatus as HLOrderStatus, OrderType, TimeInForce as HLTimeInForce, UserFill, UserState,
 };
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
 use crate::core::types::{
-    conversion, Balance, Kline, KlineInterval, Market, OrderRequest, OrderResponse, OrderSide,
-    Position, TimeInForce,
+    conversion, AccountFill, AccountSummary, Balance, ContractStyle, Kline, KlineInterval,
+    LeverageBracket, Market, MarketStatus, Order, OrderBook, OrderBookEntry, OrderRequest,
+    OrderResponse, OrderSide, OrderStatus, Position, Price, Quantity, Ticker, TimeInForce, Volume,
 };
+use crate::exchanges::factory::ExchangeId;
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+
+/// Hyperliquid symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::Hyperliquid.symbol_convention();
+
+/// Venue order id and fill details of an order status, for `OrderResponse::extensions`
+fn order_status_extensions(status: &HLOrderStatus) -> Option<Value> {
+    match (&status.resting, &status.filled) {
+        (Some(resting), _) => Some(json!({ "oid": resting.oid })),
+        (None, Some(filled)) => Some(json!({
+            "oid": filled.oid,
+            "avgPx": filled.avg_px,
+            "totalSz": filled.total_sz,
+        })),
+        (None, None) => None,
+    }
+}
+
+fn first_status_extensions(response: &super::types::OrderResponse) -> Option<Value> {
+    response
+        .response
+        .data
+        .as_ref()
+        .and_then(|data| data.statuses.first())
+        .and_then(order_status_extensions)
+}
+
+/// Check that `cloid` is the 16 bytes of hex Hyperliquid takes as a client order id
+pub fn validate_cloid(cloid: &str) -> Result<(), ExchangeError> {
+    match cloid.strip_prefix("0x") {
+        Some(hex) if hex.len() == 32 && hex.bytes().all(|b| b.is_ascii_hexdigit()) => Ok(()),
+        _ => Err(ExchangeError::InvalidParameters(format!(
+            "Hyperliquid client order ids are 128-bit hex strings like 0x1234...: {}",
+            cloid
+        ))),
+    }
+}
 
 /// Convert core `OrderRequest` to Hyperliquid `OrderRequest`
 /// This is a hot path function for trading, so it's marked inline
@@ -51,13 +94,19 @@ pub fn convert_order_request_to_hyperliquid(
             .unwrap_or_else(|| conversion::string_to_price("0")),
     };
 
+    if let Some(cloid) = &order.client_order_id {
+        validate_cloid(cloid)?;
+    }
+
     Ok(HyperliquidOrderRequest {
-        coin: order.symbol.to_string(),
+        coin: SYMBOLS.try_to_exchange_symbol(&order.symbol)?,
         is_buy,
         sz: order.quantity.to_string(),
         limit_px: price.to_string(),
         order_type,
-        reduce_only: false,
+        // Hyperliquid has no whole-position flag, so a close is sent reduce-only
+        reduce_only: order.reduce_only == Some(true) || order.close_position == Some(true),
+        cloid: order.client_order_id.clone(),
     })
 }
 
@@ -104,12 +153,14 @@ pub fn convert_to_hyperliquid_order(order: &OrderRequest) -> super::types::Order
     };
 
     HyperliquidOrderRequest {
-        coin: order.symbol.to_string(),
+        coin: SYMBOLS.to_exchange_symbol(&order.symbol),
         is_buy,
         sz: order.quantity.to_string(),
         limit_px: price.to_string(),
         order_type,
-        reduce_only: false,
+        // Hyperliquid has no whole-position flag, so a close is sent reduce-only
+        reduce_only: order.reduce_only == Some(true) || order.close_position == Some(true),
+        cloid: order.client_order_id.clone(),
     }
 }
 
@@ -122,7 +173,7 @@ pub fn convert_hyperliquid_order_response_to_generic(
 ) -> Result<OrderResponse, crate::core::errors::ExchangeError> {
     Ok(OrderResponse {
         order_id: "0".to_string(), // Hyperliquid uses different ID system
-        client_order_id: String::new(),
+        client_order_id: original_order.client_order_id.clone().unwrap_or_default(),
         symbol: original_order.symbol.clone(),
         side: original_order.side.clone(),
         order_type: original_order.order_type.clone(),
@@ -134,9 +185,59 @@ pub fn convert_hyperliquid_order_response_to_generic(
             "REJECTED".to_string()
         },
         timestamp: chrono::Utc::now().timestamp_millis(),
+        extensions: first_status_extensions(response),
     })
 }
 
+/// Split a bulk order reply into one result per order, matched by position
+pub fn convert_hyperliquid_bulk_response(
+    response: &super::types::OrderResponse,
+    orders: Vec<OrderRequest>,
+) -> Vec<Result<OrderResponse, ExchangeError>> {
+    let statuses = response
+        .response
+        .data
+        .as_ref()
+        .map_or(&[][..], |data| data.statuses.as_slice());
+
+    orders
+        .into_iter()
+        .enumerate()
+        .map(|(index, order)| {
+            let status = statuses.get(index).ok_or_else(|| {
+                ExchangeError::InvalidResponseFormat(format!(
+                    "No status for order {} in bulk reply",
+                    index
+                ))
+            })?;
+            if let Some(error) = &status.error {
+                return Err(super::types::hyperliquid_error(error.clone()));
+            }
+            let (order_id, order_status) = match (&status.resting, &status.filled) {
+                (Some(resting), _) => (resting.oid, "NEW"),
+                (None, Some(filled)) => (filled.oid, "FILLED"),
+                (None, None) => {
+                    return Err(ExchangeError::InvalidResponseFormat(
+                        "Bulk order status carries neither an order nor an error".to_string(),
+                    ))
+                }
+            };
+            Ok(OrderResponse {
+                order_id: order_id.to_string(),
+                client_order_id: order.client_order_id.clone().unwrap_or_default(),
+                symbol: order.symbol,
+                side: order.side,
+                order_type: order.order_type,
+                quantity: order.quantity,
+                price: order.price,
+                status: order_status.to_string(),
+                timestamp: chrono::Utc::now().timestamp_millis(),
+                extensions: order_status_extensions(status),
+            })
+        })
+        .collect()
+}
+
 /// Convert Hyperliquid `OrderResponse` to core `OrderResponse`
 /// This is also a hot path function, so it's marked inline
 #[inline]
@@ -146,7 +247,7 @@ pub fn convert_from_hyperliquid_response(
 ) -> OrderResponse {
     OrderResponse {
         order_id: "0".to_string(), // Hyperliquid uses different ID system
-        client_order_id: String::new(),
+        client_order_id: original_order.client_order_id.clone().unwrap_or_default(),
         symbol: original_order.symbol.clone(),
         side: original_order.side.clone(),
         order_type: original_order.order_type.clone(),
@@ -158,6 +259,7 @@ pub fn convert_from_hyperliquid_response(
             "REJECTED".to_string()
         },
         timestamp: chrono::Utc::now().timestamp_millis(),
+        extensions: first_status_extensions(response),
     }
 }
 
@@ -165,52 +267,237 @@ pub fn convert_from_hyperliquid_response(
 #[inline]
 pub fn convert_asset_to_market(asset: AssetInfo) -> Market {
     Market {
-        symbol: conversion::string_to_symbol(&asset.name),
-        status: "TRADING".to_string(),
+        symbol: SYMBOLS.from_exchange_symbol(&asset.name),
+        status: MarketStatus::Trading,
         base_precision: 6,
         quote_precision: 6,
         min_qty: Some(conversion::string_to_quantity("0.001")),
         max_qty: Some(conversion::string_to_quantity("1000000")),
         min_price: Some(conversion::string_to_price("0.000001")),
         max_price: Some(conversion::string_to_price("1000000")),
+        contract_style: ContractStyle::Linear,
+        contract_size: None,
+        extensions: None,
+    }
+}
+
+/// Convert an asset context to a ticker as of `timestamp`
+///
+/// Hyperliquid reports no 24h high, low or trade count, so those are left at zero.
+pub fn convert_asset_context_to_ticker(
+    coin: &str,
+    ctx: &AssetContext,
+    timestamp: i64,
+) -> Result<Ticker, ExchangeError> {
+    let price = conversion::try_string_to_price(ctx.mid_px.as_deref().unwrap_or(&ctx.mark_px))?;
+    let prev_day_price = conversion::try_string_to_price(&ctx.prev_day_px)?;
+    let price_change = price.value() - prev_day_price.value();
+    let price_change_percent = if prev_day_price.value().is_zero() {
+        Decimal::ZERO
+    } else {
+        price_change / prev_day_price.value() * Decimal::ONE_HUNDRED
+    };
+
+    Ok(Ticker {
+        symbol: SYMBOLS.from_exchange_symbol(coin),
+        price,
+        price_change: Price::new(price_change),
+        price_change_percent,
+        high_price: Price::ZERO,
+        low_price: Price::ZERO,
+        volume: ctx
+            .day_base_vlm
+            .as_deref()
+            .map(conversion::try_string_to_volume)
+            .transpose()?
+            .unwrap_or(Volume::ZERO),
+        quote_volume: conversion::try_string_to_volume(&ctx.day_ntl_vlm)?,
+        open_time: timestamp - 24 * 60 * 60 * 1000,
+        close_time: timestamp,
+        count: 0,
+    })
+}
+
+/// Convert an L2 book snapshot to an order book
+pub fn convert_l2_book(book: &L2Book) -> Result<OrderBook, ExchangeError> {
+    let convert_side = |levels: &[L2Level]| {
+        levels
+            .iter()
+            .map(|level| {
+                Ok(OrderBookEntry {
+                    price: conversion::try_string_to_price(&level.px)?,
+                    quantity: conversion::try_string_to_quantity(&level.sz)?,
+                })
+            })
+            .collect::<Result<Vec<_>, ExchangeError>>()
+    };
+
+    Ok(OrderBook {
+        symbol: SYMBOLS.from_exchange_symbol(&book.coin),
+        bids: convert_side(&book.levels[0])?,
+        asks: convert_side(&book.levels[1])?,
+        last_update_id: i64::try_from(book.time).unwrap_or(i64::MAX),
+    })
+}
+
+/// Convert `AssetInfo` to its leverage schedule
+///
+/// Hyperliquid has one tier per asset; maintenance margin is half the initial margin at
+/// maximum leverage.
+pub fn convert_asset_to_leverage_bracket(asset: &AssetInfo) -> LeverageBracket {
+    let max_leverage = Decimal::from(asset.max_leverage.max(1));
+    LeverageBracket {
+        max_leverage,
+        floor: rust_decimal::Decimal::ZERO,
+        cap: None,
+        maintenance_margin_rate: rust_decimal::Decimal::ONE
+            / (max_leverage * rust_decimal::Decimal::TWO),
     }
 }
 
 /// Convert `UserState` to Balance vector
 #[inline]
-pub fn convert_user_state_to_balances(user_state: &UserState) -> Vec<Balance> {
+pub fn convert_user_state_to_balances(
+    user_state: &UserState,
+) -> Result<Vec<Balance>, ExchangeError> {
     let balances = vec![Balance {
         asset: "USD".to_string(),
-        free: conversion::string_to_quantity(&user_state.margin_summary.account_value.to_string()),
-        locked: conversion::string_to_quantity("0"),
+        free: conversion::try_string_to_quantity(&user_state.margin_summary.account_value)?,
+        locked: Quantity::ZERO,
     }];
 
-    balances
+    Ok(balances)
+}
+
+/// Convert clearinghouse state to core account summary, in USD
+///
+/// Maintenance margin covers cross positions only; Hyperliquid does not total it for
+/// isolated ones.
+pub fn convert_user_state_to_account_summary(
+    user_state: &UserState,
+) -> Result<AccountSummary, ExchangeError> {
+    let unrealized_pnl = user_state
+        .asset_positions
+        .iter()
+        .map(|asset_position| {
+            conversion::try_string_to_decimal(&asset_position.position.unrealized_pnl)
+        })
+        .sum::<Result<Decimal, _>>()?;
+
+    Ok(AccountSummary {
+        currency: "USD".to_string(),
+        total_equity: conversion::try_string_to_decimal(&user_state.margin_summary.account_value)?,
+        available_margin: conversion::try_string_to_decimal(&user_state.withdrawable)?,
+        initial_margin: conversion::try_string_to_decimal(
+            &user_state.margin_summary.total_margin_used,
+        )?,
+        maintenance_margin: conversion::try_string_to_decimal(
+            &user_state.cross_maintenance_margin_used,
+        )?,
+        unrealized_pnl,
+        timestamp: user_state
+            .time
+            .unwrap_or_else(|| chrono::Utc::now().timestamp_millis()),
+    })
+}
+
+/// Convert an open or queried order to core `Order`
+///
+/// `status` is the venue status, `"open"` for entries of the open orders list.
+#[allow(clippy::cast_possible_wrap)]
+pub fn convert_hyperliquid_order(
+    order: &OpenOrder,
+    status: &str,
+    updated_time: u64,
+) -> Result<Order, ExchangeError> {
+    let remaining = conversion::try_string_to_quantity(&order.sz)?;
+    let quantity = order
+        .orig_sz
+        .as_deref()
+        .map_or(Ok(remaining), conversion::try_string_to_quantity)?;
+    let filled_quantity = Quantity::new(quantity.value() - remaining.value());
+    let status = match OrderStatus::from_venue(status) {
+        OrderStatus::New if !filled_quantity.value().is_zero() => OrderStatus::PartiallyFilled,
+        status => status,
+    };
+
+    Ok(Order {
+        order_id: order.oid.to_string(),
+        client_order_id: order.cloid.clone().unwrap_or_default(),
+        symbol: SYMBOLS.from_exchange_symbol(&order.coin),
+        // Hyperliquid sides are "B" (bid) and "A" (ask)
+        side: if order.side == "A" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        order_type: crate::core::types::OrderType::Limit,
+        status,
+        price: Some(conversion::try_string_to_price(&order.limit_px)?),
+        quantity,
+        filled_quantity,
+        average_price: None,
+        created_time: order.timestamp.min(i64::MAX as u64) as i64,
+        updated_time: updated_time.min(i64::MAX as u64) as i64,
+    })
+}
+
+/// Convert `UserFill` to `AccountFill`; fills that crossed the book are taker fills
+pub fn convert_user_fill(fill: &UserFill) -> Result<AccountFill, ExchangeError> {
+    Ok(AccountFill {
+        symbol: SYMBOLS.from_exchange_symbol(&fill.coin),
+        order_id: fill.oid.to_string(),
+        trade_id: fill.tid.to_string(),
+        side: if fill.side == "A" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        price: conversion::try_string_to_price(&fill.px)?,
+        quantity: conversion::try_string_to_quantity(&fill.sz)?,
+        fee: conversion::try_string_to_decimal(&fill.fee)?,
+        fee_asset: fill.fee_token.clone(),
+        is_maker: !fill.crossed,
+        timestamp: i64::try_from(fill.time).unwrap_or(i64::MAX),
+    })
 }
 
 /// Convert `UserState` to Position vector
 #[inline]
-pub fn convert_user_state_to_positions(user_state: &UserState) -> Vec<Position> {
+pub fn convert_user_state_to_positions(
+    user_state: &UserState,
+) -> Result<Vec<Position>, ExchangeError> {
     use crate::core::types::PositionSide;
 
     user_state
         .asset_positions
         .iter()
-        .map(|pos| Position {
-            symbol: conversion::string_to_symbol(&pos.position.coin),
-            position_side: if pos.position.szi.parse::<f64>().unwrap_or(0.0) > 0.0 {
-                PositionSide::Long
-            } else {
-                PositionSide::Short
-            },
-            entry_price: pos.position.entry_px.as_ref().map_or_else(
-                || conversion::string_to_price("0"),
-                |px| conversion::string_to_price(px),
-            ),
-            position_amount: conversion::string_to_quantity(&pos.position.szi),
-            unrealized_pnl: conversion::string_to_decimal(&pos.position.unrealized_pnl),
-            liquidation_price: None, // Not available in response
-            leverage: rust_decimal::Decimal::from(pos.position.leverage.value),
+        .map(|pos| {
+            Ok(Position {
+                symbol: SYMBOLS.from_exchange_symbol(&pos.position.coin),
+                position_side: if pos.position.szi.parse::<f64>().unwrap_or(0.0) > 0.0 {
+                    PositionSide::Long
+                } else {
+                    PositionSide::Short
+                },
+                entry_price: pos
+                    .position
+                    .entry_px
+                    .as_deref()
+                    .map_or(Ok(Price::ZERO), conversion::try_string_to_price)?,
+                position_amount: conversion::try_string_to_quantity(&pos.position.szi)?,
+                unrealized_pnl: conversion::try_string_to_decimal(&pos.position.unrealized_pnl)?,
+                liquidation_price: None, // Not available in response
+                leverage: rust_decimal::Decimal::from(pos.position.leverage.value),
+                margin_mode: None,
+                margin: None,
+                extensions: Some(json!({
+                    "type": pos.position_type,
+                    "positionValue": pos.position.position_value,
+                    "returnOnEquity": pos.position.return_on_equity,
+                    "maxLeverage": pos.position.max_leverage,
+                })),
+            })
         })
         .collect()
 }
@@ -218,20 +505,25 @@ pub fn convert_user_state_to_positions(user_state: &UserState) -> Vec<Position>
 /// Convert Candle to Kline
 #[inline]
 #[allow(clippy::cast_possible_wrap)]
-pub fn convert_candle_to_kline(candle: &Candle, symbol: &str, interval: KlineInterval) -> Kline {
-    Kline {
-        symbol: conversion::string_to_symbol(symbol),
-        open_time: candle.time.min(i64::MAX as u64) as i64,
-        close_time: (candle.time.min(i64::MAX as u64) as i64).saturating_add(60000), // Add 1 minute (default)
+pub fn convert_candle_to_kline(
+    candle: &Candle,
+    symbol: &str,
+    interval: KlineInterval,
+) -> Result<Kline, ExchangeError> {
+    let open_time = candle.open_time.min(i64::MAX as u64) as i64;
+    Ok(Kline {
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
+        open_time,
+        close_time: interval.close_time(open_time),
         interval: format!("{:?}", interval),
-        open_price: conversion::string_to_price(&candle.open),
-        high_price: conversion::string_to_price(&candle.high),
-        low_price: conversion::string_to_price(&candle.low),
-        close_price: conversion::string_to_price(&candle.close),
-        volume: conversion::string_to_volume(&candle.volume),
+        open_price: conversion::try_string_to_price(&candle.open)?,
+        high_price: conversion::try_string_to_price(&candle.high)?,
+        low_price: conversion::try_string_to_price(&candle.low)?,
+        close_price: conversion::try_string_to_price(&candle.close)?,
+        volume: conversion::try_string_to_volume(&candle.volume)?,
         number_of_trades: candle.num_trades as i64,
         final_bar: true,
-    }
+    })
 }
 
 /// Convert `KlineInterval` to Hyperliquid interval string
@@ -13,7 +13,7 @@ pub mod builder;
 
 // Re-export main types for easier importing
 pub use builder::{
-    build_hyperliquid_connector, build_hyperliquid_connector_with_websocket,
+    build_hyperliquid_connector, build_hyperliquid_connector_with_websocket, build_public,
     create_hyperliquid_client, HyperliquidBuilder,
 };
 pub use connector::HyperliquidConnector;
@@ -1,10 +1,12 @@
 use super::signer::HyperliquidSigner;
 use super::types::{
-    AssetInfo, Candle, InfoRequest, L2Book, ModifyRequest, OpenOrder, OrderRequest, OrderResponse,
-    UserFill, UserState,
+    hyperliquid_error, ActiveAssetData, AssetInfo, Candle, InfoRequest, L2Book,
+    MetaAndAssetCtxsResponse, ModifyRequest, OpenOrder, OrderRequest, OrderResponse,
+    OrderStatusEntry, OrderStatusResponse, UserFill, UserState,
 };
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
+use crate::exchanges::factory::ExchangeId;
 use serde_json::Value;
 use tracing::instrument;
 
@@ -89,6 +91,15 @@ impl<R: RestClient> HyperliquidRest<R> {
         self.client.post_json("/info", &request_value, false).await
     }
 
+    /// Get the universe along with each asset's mark price, funding and 24h volume
+    #[instrument(skip(self), fields(exchange = "hyperliquid"))]
+    pub async fn get_meta_and_asset_ctxs(&self) -> Result<MetaAndAssetCtxsResponse, ExchangeError> {
+        let request_value = serde_json::to_value(&InfoRequest::MetaAndAssetCtxs)
+            .map_err(ExchangeError::JsonError)?;
+
+        self.client.post_json("/info", &request_value, false).await
+    }
+
     /// Get recent trades for a specific coin
     #[instrument(skip(self), fields(exchange = "hyperliquid", coin = %coin))]
     pub async fn get_recent_trades(&self, coin: &str) -> Result<Vec<Value>, ExchangeError> {
@@ -130,6 +141,22 @@ impl<R: RestClient> HyperliquidRest<R> {
         self.client.post_json("/info", &request_value, false).await
     }
 
+    /// Get the user's leverage setting on a coin
+    #[instrument(skip(self), fields(exchange = "hyperliquid", user = %user, coin = %coin))]
+    pub async fn get_active_asset_data(
+        &self,
+        user: &str,
+        coin: &str,
+    ) -> Result<ActiveAssetData, ExchangeError> {
+        let request = InfoRequest::ActiveAssetData {
+            user: user.to_string(),
+            coin: coin.to_string(),
+        };
+        let request_value = serde_json::to_value(&request).map_err(ExchangeError::JsonError)?;
+
+        self.client.post_json("/info", &request_value, false).await
+    }
+
     /// Get user fills (requires authentication)
     #[instrument(skip(self), fields(exchange = "hyperliquid", user = %user))]
     pub async fn get_user_fills(&self, user: &str) -> Result<Vec<UserFill>, ExchangeError> {
@@ -141,6 +168,24 @@ impl<R: RestClient> HyperliquidRest<R> {
         self.client.post_json("/info", &request_value, false).await
     }
 
+    /// Get user fills within a time range (requires authentication)
+    #[instrument(skip(self), fields(exchange = "hyperliquid", user = %user))]
+    pub async fn get_user_fills_by_time(
+        &self,
+        user: &str,
+        start_time: u64,
+        end_time: Option<u64>,
+    ) -> Result<Vec<UserFill>, ExchangeError> {
+        let request = InfoRequest::UserFillsByTime {
+            user: user.to_string(),
+            start_time,
+            end_time,
+        };
+        let request_value = serde_json::to_value(&request).map_err(ExchangeError::JsonError)?;
+
+        self.client.post_json("/info", &request_value, false).await
+    }
+
     /// Get open orders (requires authentication)
     #[instrument(skip(self), fields(exchange = "hyperliquid", user = %user))]
     pub async fn get_open_orders(&self, user: &str) -> Result<Vec<OpenOrder>, ExchangeError> {
@@ -152,6 +197,38 @@ impl<R: RestClient> HyperliquidRest<R> {
         self.client.post_json("/info", &request_value, false).await
     }
 
+    /// Get the status of one order, open or closed (requires authentication)
+    #[instrument(skip(self), fields(exchange = "hyperliquid", user = %user, oid = %oid))]
+    pub async fn get_order_status(
+        &self,
+        user: &str,
+        oid: u64,
+    ) -> Result<OrderStatusResponse, ExchangeError> {
+        let request = InfoRequest::OrderStatus {
+            user: user.to_string(),
+            oid,
+        };
+        let request_value = serde_json::to_value(&request).map_err(ExchangeError::JsonError)?;
+
+        self.client.post_json("/info", &request_value, false).await
+    }
+
+    /// Get the account's most recent orders with their final status (requires authentication)
+    ///
+    /// Hyperliquid returns at most 2000 orders and offers no paging beyond them.
+    #[instrument(skip(self), fields(exchange = "hyperliquid", user = %user))]
+    pub async fn get_historical_orders(
+        &self,
+        user: &str,
+    ) -> Result<Vec<OrderStatusEntry>, ExchangeError> {
+        let request = InfoRequest::HistoricalOrders {
+            user: user.to_string(),
+        };
+        let request_value = serde_json::to_value(&request).map_err(ExchangeError::JsonError)?;
+
+        self.client.post_json("/info", &request_value, false).await
+    }
+
     /// Place an order (requires authentication)
     #[instrument(skip(self, order), fields(exchange = "hyperliquid"))]
     pub async fn place_order(&self, order: &OrderRequest) -> Result<OrderResponse, ExchangeError> {
@@ -173,6 +250,30 @@ impl<R: RestClient> HyperliquidRest<R> {
             .await
     }
 
+    /// Place several orders in one signed bulk action (requires authentication)
+    #[instrument(skip(self, orders), fields(exchange = "hyperliquid", count = orders.len()))]
+    pub async fn place_orders(
+        &self,
+        orders: &[OrderRequest],
+    ) -> Result<OrderResponse, ExchangeError> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            ExchangeError::AuthError("No signer available for placing orders".to_string())
+        })?;
+
+        let action = serde_json::json!({
+            "type": "order",
+            "orders": orders
+        });
+
+        let exchange_request = signer.sign_l1_action(action, self.vault_address.clone(), None)?;
+        let request_value =
+            serde_json::to_value(&exchange_request).map_err(ExchangeError::JsonError)?;
+
+        self.client
+            .post_json("/exchange", &request_value, false)
+            .await
+    }
+
     /// Cancel an order (requires authentication)
     #[instrument(skip(self), fields(exchange = "hyperliquid", coin = %coin, oid = %oid))]
     pub async fn cancel_order(&self, coin: &str, oid: u64) -> Result<OrderResponse, ExchangeError> {
@@ -197,16 +298,25 @@ impl<R: RestClient> HyperliquidRest<R> {
             .await
     }
 
-    /// Cancel all orders (requires authentication)
-    #[instrument(skip(self), fields(exchange = "hyperliquid"))]
-    pub async fn cancel_all_orders(&self) -> Result<OrderResponse, ExchangeError> {
+    /// Cancel an order by its client order id (requires authentication)
+    ///
+    /// `asset` is the coin's index in the perpetuals universe.
+    #[instrument(skip(self), fields(exchange = "hyperliquid", asset = asset, cloid = %cloid))]
+    pub async fn cancel_order_by_cloid(
+        &self,
+        asset: usize,
+        cloid: &str,
+    ) -> Result<OrderResponse, ExchangeError> {
         let signer = self.signer.as_ref().ok_or_else(|| {
             ExchangeError::AuthError("No signer available for canceling orders".to_string())
         })?;
 
         let action = serde_json::json!({
             "type": "cancelByCloid",
-            "cancels": []
+            "cancels": [{
+                "asset": asset,
+                "cloid": cloid
+            }]
         });
 
         let exchange_request = signer.sign_l1_action(action, self.vault_address.clone(), None)?;
@@ -218,6 +328,59 @@ impl<R: RestClient> HyperliquidRest<R> {
             .await
     }
 
+    /// Cancel several orders in one action (requires authentication)
+    #[instrument(skip(self, cancels), fields(exchange = "hyperliquid", count = cancels.len()))]
+    pub async fn cancel_orders(
+        &self,
+        cancels: &[(String, u64)],
+    ) -> Result<OrderResponse, ExchangeError> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            ExchangeError::AuthError("No signer available for canceling orders".to_string())
+        })?;
+
+        let cancels: Vec<Value> = cancels
+            .iter()
+            .map(|(coin, oid)| serde_json::json!({ "coin": coin, "oid": oid }))
+            .collect();
+        let action = serde_json::json!({
+            "type": "cancel",
+            "cancels": cancels
+        });
+
+        let exchange_request = signer.sign_l1_action(action, self.vault_address.clone(), None)?;
+        let request_value =
+            serde_json::to_value(&exchange_request).map_err(ExchangeError::JsonError)?;
+
+        self.client
+            .post_json("/exchange", &request_value, false)
+            .await
+    }
+
+    /// Cancel all orders at `time`, in milliseconds, or clear the schedule for `None`
+    ///
+    /// The time must be at least five seconds ahead (requires authentication).
+    #[instrument(skip(self), fields(exchange = "hyperliquid"))]
+    pub async fn schedule_cancel(&self, time: Option<u64>) -> Result<(), ExchangeError> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            ExchangeError::AuthError("No signer available for scheduling a cancel".to_string())
+        })?;
+
+        let mut action = serde_json::json!({ "type": "scheduleCancel" });
+        if let Some(time) = time {
+            action["time"] = Value::from(time);
+        }
+
+        let exchange_request = signer.sign_l1_action(action, self.vault_address.clone(), None)?;
+        let request_value =
+            serde_json::to_value(&exchange_request).map_err(ExchangeError::JsonError)?;
+
+        let response: Value = self
+            .client
+            .post_json("/exchange", &request_value, false)
+            .await?;
+        action_result(&response, "Scheduled cancel")
+    }
+
     /// Modify an order (requires authentication)
     #[instrument(skip(self, modify_request), fields(exchange = "hyperliquid"))]
     pub async fn modify_order(
@@ -242,13 +405,59 @@ impl<R: RestClient> HyperliquidRest<R> {
             .await
     }
 
+    /// Set the leverage and margin mode of an asset (requires authentication)
+    ///
+    /// `asset` is the coin's index in the perpetuals universe.
+    #[instrument(skip(self), fields(exchange = "hyperliquid", asset = asset))]
+    pub async fn update_leverage(
+        &self,
+        asset: usize,
+        is_cross: bool,
+        leverage: u32,
+    ) -> Result<(), ExchangeError> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            ExchangeError::AuthError("No signer available for updating leverage".to_string())
+        })?;
+
+        let action = serde_json::json!({
+            "type": "updateLeverage",
+            "asset": asset,
+            "isCross": is_cross,
+            "leverage": leverage
+        });
+
+        let exchange_request = signer.sign_l1_action(action, self.vault_address.clone(), None)?;
+        let request_value =
+            serde_json::to_value(&exchange_request).map_err(ExchangeError::JsonError)?;
+
+        let response: Value = self
+            .client
+            .post_json("/exchange", &request_value, false)
+            .await?;
+        action_result(&response, "Leverage update")
+    }
+
     /// Get WebSocket URL for this client
     pub fn get_websocket_url(&self) -> String {
-        if self.is_testnet {
-            "wss://api.hyperliquid-testnet.xyz/ws".to_string()
-        } else {
-            "wss://api.hyperliquid.xyz/ws".to_string()
-        }
+        let endpoints = ExchangeId::Hyperliquid.endpoints();
+        endpoints
+            .network(self.is_testnet)
+            .unwrap_or(endpoints.mainnet)
+            .ws
+            .to_string()
+    }
+}
+
+/// Rejected actions come back as `{"status": "err", "response": "<reason>"}`
+fn action_result(response: &Value, action: &str) -> Result<(), ExchangeError> {
+    if response.get("status").and_then(Value::as_str) == Some("ok") {
+        Ok(())
+    } else {
+        Err(hyperliquid_error(format!(
+            "{} rejected: {}",
+            action,
+            response.get("response").unwrap_or(response)
+        )))
     }
 }
 
@@ -270,4 +479,16 @@ mod tests {
         assert!(!hyperliquid_rest.can_sign());
         assert!(hyperliquid_rest.wallet_address().is_none());
     }
+
+    #[test]
+    fn test_action_result_surfaces_rejection() {
+        let ok = serde_json::json!({"status": "ok", "response": {"type": "default"}});
+        assert!(action_result(&ok, "Scheduled cancel").is_ok());
+
+        let err = serde_json::json!({"status": "err", "response": "Cannot set scheduled cancel time until enough volume traded"});
+        let message = action_result(&err, "Scheduled cancel")
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("Scheduled cancel rejected: \"Cannot set"));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::core::errors::{ErrorKind, ExchangeError};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -249,8 +250,20 @@ pub enum InfoRequest {
     UserState { user: String },
     #[serde(rename = "openOrders")]
     OpenOrders { user: String },
+    #[serde(rename = "orderStatus")]
+    OrderStatus { user: String, oid: u64 },
+    #[serde(rename = "historicalOrders")]
+    HistoricalOrders { user: String },
     #[serde(rename = "userFills")]
     UserFills { user: String },
+    #[serde(rename = "userFillsByTime")]
+    UserFillsByTime {
+        user: String,
+        #[serde(rename = "startTime")]
+        start_time: u64,
+        #[serde(rename = "endTime", skip_serializing_if = "Option::is_none")]
+        end_time: Option<u64>,
+    },
     #[serde(rename = "l2Book")]
     L2Book { coin: String },
     #[serde(rename = "candleSnapshot")]
@@ -272,6 +285,8 @@ pub enum InfoRequest {
     },
     #[serde(rename = "metaAndAssetCtxs")]
     MetaAndAssetCtxs,
+    #[serde(rename = "activeAssetData")]
+    ActiveAssetData { user: String, coin: String },
 }
 
 // Info endpoint response types
@@ -288,12 +303,10 @@ pub struct FundingHistoryEntry {
     pub time: u64,
 }
 
+/// Reply of `metaAndAssetCtxs`, a `[meta, contexts]` pair whose contexts line up with
+/// the universe by index
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MetaAndAssetCtxsResponse {
-    pub universe: Vec<AssetInfo>,
-    #[serde(rename = "assetContexts")]
-    pub asset_contexts: Vec<AssetContext>,
-}
+pub struct MetaAndAssetCtxsResponse(pub Universe, pub Vec<AssetContext>);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssetContext {
@@ -315,6 +328,8 @@ pub struct AssetContext {
     pub premium: Option<String>,
     #[serde(rename = "prevDayPx")]
     pub prev_day_px: String,
+    #[serde(rename = "dayBaseVlm", default)]
+    pub day_base_vlm: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -329,6 +344,8 @@ pub struct UserState {
     pub margin_summary: MarginSummary,
     #[serde(rename = "withdrawable")]
     pub withdrawable: String,
+    #[serde(default)]
+    pub time: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -367,6 +384,13 @@ pub struct Leverage {
     pub value: u32,
 }
 
+/// The user's leverage setting on one asset, from `activeAssetData`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveAssetData {
+    pub coin: String,
+    pub leverage: Leverage,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarginSummary {
     #[serde(rename = "accountValue")]
@@ -386,8 +410,29 @@ pub struct OpenOrder {
     pub limit_px: String,
     pub oid: u64,
     pub side: String,
+    /// Size still resting
     pub sz: String,
     pub timestamp: u64,
+    /// Size when placed
+    #[serde(rename = "origSz", default)]
+    pub orig_sz: Option<String>,
+    #[serde(default)]
+    pub cloid: Option<String>,
+}
+
+/// Reply to an `orderStatus` info request; `order` is absent for unknown ids
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderStatusResponse {
+    pub status: String,
+    pub order: Option<OrderStatusEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderStatusEntry {
+    pub order: OpenOrder,
+    pub status: String,
+    #[serde(rename = "statusTimestamp")]
+    pub status_timestamp: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -407,12 +452,14 @@ pub struct UserFill {
     pub crossed: bool,
     pub fee: String,
     pub tid: u64,
+    #[serde(rename = "feeToken", default)]
+    pub fee_token: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct L2Book {
     pub coin: String,
-    pub levels: Vec<[Vec<L2Level>; 2]>, // [bids, asks]
+    pub levels: [Vec<L2Level>; 2], // [bids, asks]
     pub time: u64,
 }
 
@@ -425,6 +472,10 @@ pub struct L2Level {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Candle {
+    /// Bar open time
+    #[serde(rename = "t")]
+    pub open_time: u64,
+    /// Bar close time
     #[serde(rename = "T")]
     pub time: u64,
     #[serde(rename = "c")]
@@ -454,6 +505,9 @@ pub struct OrderRequest {
     pub order_type: OrderType,
     #[serde(rename = "reduce_only")]
     pub reduce_only: bool,
+    /// Client order id, a 128-bit hex string `0x...`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloid: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -552,3 +606,35 @@ pub struct ExchangeRequest {
     #[serde(rename = "vaultAddress", skip_serializing_if = "Option::is_none")]
     pub vault_address: Option<String>,
 }
+
+/// `ExchangeError::Rejected` for a recognised rejection message, `Other` otherwise
+pub fn hyperliquid_error(message: String) -> ExchangeError {
+    match hyperliquid_error_kind(0, &message) {
+        Some(kind) => ExchangeError::Rejected {
+            kind,
+            code: 0,
+            message,
+        },
+        None => ExchangeError::Other(message),
+    }
+}
+
+/// Classify a Hyperliquid error; the venue reports messages without codes
+pub fn hyperliquid_error_kind(_code: i32, message: &str) -> Option<ErrorKind> {
+    let message = message.to_lowercase();
+    if message.contains("insufficient") {
+        Some(ErrorKind::InsufficientBalance)
+    } else if message.contains("minimum value") {
+        Some(ErrorKind::MinNotional)
+    } else if message.contains("post only order would have immediately matched") {
+        Some(ErrorKind::PostOnlyReject)
+    } else if message.contains("never placed, already canceled, or filled") {
+        Some(ErrorKind::OrderNotFound)
+    } else if message.contains("invalid price") {
+        Some(ErrorKind::InvalidPrice)
+    } else if message.contains("invalid size") {
+        Some(ErrorKind::InvalidQuantity)
+    } else {
+        None
+    }
+}
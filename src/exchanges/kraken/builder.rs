@@ -0,0 +1,55 @@
+use crate::core::config::ExchangeConfig;
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{ReqwestRest, RestClientBuilder, RestClientConfig, TungsteniteWs};
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
+use crate::exchanges::kraken::{
+    codec::KrakenCodec, connector::KrakenConnector, signer::KrakenSigner,
+};
+use std::sync::Arc;
+
+fn build_rest(config: &ExchangeConfig) -> Result<ReqwestRest, ExchangeError> {
+    let endpoints = resolve_endpoints(ExchangeId::Kraken, config)?;
+
+    let rest_config = RestClientConfig::new(endpoints.rest, "kraken".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
+
+    let mut rest_builder = RestClientBuilder::new(rest_config);
+
+    // Add authentication if credentials are provided
+    if config.has_credentials() {
+        let signer = Arc::new(KrakenSigner::new(
+            config.api_key().to_string(),
+            config.secret_key().to_string(),
+        ));
+        rest_builder = rest_builder.with_signer(signer);
+    }
+
+    rest_builder.build()
+}
+
+/// Create a Kraken connector with REST-only support
+pub fn build_connector(
+    config: ExchangeConfig,
+) -> Result<KrakenConnector<ReqwestRest, ()>, ExchangeError> {
+    Ok(KrakenConnector::new_without_ws(build_rest(&config)?).with_ws_config(config.ws_config()))
+}
+
+/// Create a Kraken connector with WebSocket support
+pub fn build_connector_with_websocket(
+    config: ExchangeConfig,
+) -> Result<KrakenConnector<ReqwestRest, TungsteniteWs<KrakenCodec>>, ExchangeError> {
+    let rest = build_rest(&config)?;
+    let ws_url = resolve_endpoints(ExchangeId::Kraken, &config)?.ws;
+    let ws = TungsteniteWs::new(ws_url, "kraken".to_string(), KrakenCodec)
+        .with_config(config.ws_config());
+    Ok(KrakenConnector::new(rest, ws).with_ws_config(config.ws_config()))
+}
+
+/// Create a Kraken connector for public market data, ignoring any credentials in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<KrakenConnector<ReqwestRest, TungsteniteWs<KrakenCodec>>, ExchangeError> {
+    build_connector_with_websocket(config.without_credentials())
+}
@@ -0,0 +1,286 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{ControlFrame, WsCodec};
+use crate::core::types::SubscriptionType;
+use crate::exchanges::kraken::conversions::kline_interval_to_kraken_minutes;
+use crate::exchanges::kraken::types::{
+    KrakenWsBook, KrakenWsEnvelope, KrakenWsOhlc, KrakenWsTicker, KrakenWsTrade,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+use tokio_tungstenite::tungstenite::Message;
+
+/// Book depths Kraken offers
+const BOOK_DEPTHS: [u32; 5] = [10, 25, 100, 500, 1000];
+
+#[derive(Debug, Clone)]
+pub enum KrakenMessage {
+    Tickers {
+        timestamp: i64,
+        tickers: Vec<KrakenWsTicker>,
+    },
+    Trades(Vec<KrakenWsTrade>),
+    Books(Vec<KrakenWsBook>),
+    Ohlc(Vec<KrakenWsOhlc>),
+    Unknown,
+}
+
+/// Codec for the Kraken WebSocket v2 feed
+pub struct KrakenCodec;
+
+impl KrakenCodec {
+    /// Kraken takes one channel per request, so all streams must share it
+    ///
+    /// Streams are `channel@SYMBOL`, where the channel may carry a parameter:
+    /// `book.10@BTC/USD` or `ohlc.5@BTC/USD`.
+    fn encode_request(
+        method: &str,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        let mut channel = None;
+        let mut symbols = Vec::new();
+        for stream in streams {
+            let (stream_channel, symbol) = stream.as_ref().split_once('@').ok_or_else(|| {
+                ExchangeError::InvalidParameters(format!(
+                    "Invalid Kraken stream: {}",
+                    stream.as_ref()
+                ))
+            })?;
+            if channel.is_some_and(|c| c != stream_channel) {
+                return Err(ExchangeError::InvalidParameters(
+                    "Kraken streams of one request must share a channel".to_string(),
+                ));
+            }
+            channel = Some(stream_channel);
+            symbols.push(symbol);
+        }
+
+        let channel = channel.unwrap_or_default();
+        let (name, parameter) = channel.split_once('.').unwrap_or((channel, ""));
+        let mut params = Map::new();
+        params.insert("channel".to_string(), json!(name));
+        params.insert("symbol".to_string(), json!(symbols));
+        match name {
+            "book" => {
+                params.insert("depth".to_string(), json!(parameter.parse::<u32>().ok()));
+            }
+            "ohlc" => {
+                params.insert("interval".to_string(), json!(parameter.parse::<u32>().ok()));
+            }
+            _ => {}
+        }
+
+        let request = json!({ "method": method, "params": params });
+        Ok(Message::Text(request.to_string()))
+    }
+
+    fn data<T: DeserializeOwned>(envelope: KrakenWsEnvelope) -> Result<Vec<T>, ExchangeError> {
+        serde_json::from_value(Value::Array(envelope.data)).map_err(|e| {
+            ExchangeError::DeserializationError(format!(
+                "Failed to parse {}: {}",
+                envelope.channel, e
+            ))
+        })
+    }
+}
+
+impl WsCodec for KrakenCodec {
+    type Message = KrakenMessage;
+
+    fn encode_subscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Self::encode_request("subscribe", streams)
+    }
+
+    fn encode_unsubscription(
+        &self,
+        streams: &[impl AsRef<str> + Send + Sync],
+    ) -> Result<Message, ExchangeError> {
+        Self::encode_request("unsubscribe", streams)
+    }
+
+    fn encode_ping(&self) -> Option<Message> {
+        Some(Message::Text(r#"{"method":"ping"}"#.to_string()))
+    }
+
+    fn acknowledges_subscriptions(&self) -> bool {
+        true
+    }
+
+    fn control_frame(&self, message: &Message) -> Option<ControlFrame> {
+        let Message::Text(text) = message else {
+            return None;
+        };
+        if !text.contains("\"method\"") {
+            return None;
+        }
+        let value: Value = serde_json::from_str(text).ok()?;
+        let frame = match value.get("method").and_then(Value::as_str) {
+            Some("pong") => ControlFrame {
+                pong: true,
+                ..ControlFrame::default()
+            },
+            Some("subscribe") if value.get("success").and_then(Value::as_bool) == Some(true) => {
+                ControlFrame {
+                    subscribed: true,
+                    ..ControlFrame::default()
+                }
+            }
+            _ => return None,
+        };
+        Some(frame)
+    }
+
+    fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
+        let Message::Text(text) = message else {
+            return Ok(None);
+        };
+        let value: Value = serde_json::from_str(&text).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse JSON: {}", e))
+        })?;
+        // Method replies: subscription acks and pongs
+        if value.get("method").is_some() {
+            if value.get("success").and_then(Value::as_bool) == Some(false) {
+                return Err(ExchangeError::WebSocketError(format!(
+                    "Kraken error: {}",
+                    value.get("error").and_then(Value::as_str).unwrap_or("")
+                )));
+            }
+            return Ok(Some(KrakenMessage::Unknown));
+        }
+
+        let envelope: KrakenWsEnvelope = serde_json::from_value(value).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse message: {}", e))
+        })?;
+        let message = match envelope.channel.as_str() {
+            "ticker" => KrakenMessage::Tickers {
+                timestamp: chrono::Utc::now().timestamp_millis(),
+                tickers: Self::data(envelope)?,
+            },
+            "trade" => KrakenMessage::Trades(Self::data(envelope)?),
+            "book" => KrakenMessage::Books(Self::data(envelope)?),
+            "ohlc" => KrakenMessage::Ohlc(Self::data(envelope)?),
+            // Heartbeats and status updates
+            _ => KrakenMessage::Unknown,
+        };
+        Ok(Some(message))
+    }
+}
+
+/// Create Kraken stream identifiers, as `channel@SYMBOL`
+///
+/// Order books round the requested depth up to one Kraken offers, 1000 for the full book.
+pub fn create_kraken_stream_identifiers(
+    ws_symbols: &[String],
+    subscription_types: &[SubscriptionType],
+) -> Result<Vec<String>, ExchangeError> {
+    let mut streams = Vec::new();
+    for subscription in subscription_types {
+        let channel = match subscription {
+            SubscriptionType::Ticker => "ticker".to_string(),
+            SubscriptionType::OrderBook { depth, .. } => {
+                let depth = depth
+                    .and_then(|depth| BOOK_DEPTHS.into_iter().find(|d| *d >= depth))
+                    .unwrap_or(1000);
+                format!("book.{}", depth)
+            }
+            SubscriptionType::Trades | SubscriptionType::AggTrades => "trade".to_string(),
+            SubscriptionType::Klines { interval } => {
+                let minutes = kline_interval_to_kraken_minutes(*interval).ok_or_else(|| {
+                    ExchangeError::NotSupported(format!(
+                        "Kraken does not offer {} candles",
+                        interval
+                    ))
+                })?;
+                format!("ohlc.{}", minutes)
+            }
+            // Spot has no mark price
+            SubscriptionType::MarkPrice => continue,
+        };
+        for symbol in ws_symbols {
+            let stream = format!("{}@{}", channel, symbol);
+            if !streams.contains(&stream) {
+                streams.push(stream);
+            }
+        }
+    }
+    Ok(streams)
+}
+
+/// Split streams into the requests Kraken takes, one per channel
+pub fn kraken_request_groups(streams: Vec<String>) -> Vec<Vec<String>> {
+    let mut channels: Vec<(String, Vec<String>)> = Vec::new();
+    for stream in streams {
+        let channel = stream.split('@').next().unwrap_or_default().to_string();
+        match channels.iter_mut().find(|(c, _)| *c == channel) {
+            Some((_, group)) => group.push(stream),
+            None => channels.push((channel, vec![stream])),
+        }
+    }
+    channels.into_iter().map(|(_, group)| group).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::OrderBookMode;
+
+    #[test]
+    fn test_encodes_channel_parameters() {
+        let message = KrakenCodec
+            .encode_subscription(&["book.25@BTC/USD", "book.25@ETH/USD"])
+            .unwrap();
+        let Message::Text(text) = message else {
+            panic!("expected text");
+        };
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value["method"], "subscribe");
+        assert_eq!(value["params"]["channel"], "book");
+        assert_eq!(value["params"]["depth"], 25);
+        assert_eq!(value["params"]["symbol"], json!(["BTC/USD", "ETH/USD"]));
+
+        let streams = create_kraken_stream_identifiers(
+            &["BTC/USD".to_string()],
+            &[SubscriptionType::OrderBook {
+                depth: Some(20),
+                mode: OrderBookMode::Snapshot,
+            }],
+        )
+        .unwrap();
+        assert_eq!(streams, vec!["book.25@BTC/USD"]);
+        assert!(KrakenCodec
+            .encode_subscription(&["book.25@BTC/USD", "trade@BTC/USD"])
+            .is_err());
+        assert_eq!(
+            kraken_request_groups(vec![
+                "book.25@BTC/USD".to_string(),
+                "trade@BTC/USD".to_string(),
+                "book.25@ETH/USD".to_string(),
+            ]),
+            vec![
+                vec!["book.25@BTC/USD", "book.25@ETH/USD"],
+                vec!["trade@BTC/USD"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decodes_trade_with_exact_decimals() {
+        let text = r#"{"channel":"trade","type":"update","data":[{"symbol":"BTC/USD","side":"sell","price":26781.1,"qty":0.00010000,"ord_type":"market","trade_id":1,"timestamp":"2023-09-25T07:49:37.708706Z"}]}"#;
+        let Some(KrakenMessage::Trades(trades)) = KrakenCodec
+            .decode_message(Message::Text(text.to_string()))
+            .unwrap()
+        else {
+            panic!("expected trades");
+        };
+        assert_eq!(trades[0].price.to_string(), "26781.1");
+        assert_eq!(trades[0].qty.to_string(), "0.0001");
+        assert_eq!(trades[0].side, "sell");
+
+        let ack = r#"{"method":"subscribe","success":false,"error":"Currency pair not supported"}"#;
+        assert!(KrakenCodec
+            .decode_message(Message::Text(ack.to_string()))
+            .is_err());
+    }
+}
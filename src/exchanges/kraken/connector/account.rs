@@ -0,0 +1,51 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::RestClient,
+    traits::AccountInfo,
+    types::{Balance, Position},
+};
+use crate::exchanges::kraken::{conversions::convert_kraken_balance, rest::KrakenRestClient};
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Account implementation for Kraken
+pub struct Account<R: RestClient> {
+    rest: KrakenRestClient<R>,
+}
+
+impl<R: RestClient> Account<R> {
+    /// Create a new account manager
+    pub fn new(rest: &R) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            rest: KrakenRestClient::new(rest.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    #[instrument(skip(self), fields(exchange = "kraken"))]
+    async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let mut balances = Vec::new();
+        for (asset, balance) in &self.rest.get_balances().await? {
+            let balance = convert_kraken_balance(asset, balance)?;
+            if !balance.free.value().is_zero() || !balance.locked.value().is_zero() {
+                balances.push(balance);
+            }
+        }
+        Ok(balances)
+    }
+
+    #[instrument(skip(self), fields(exchange = "kraken"))]
+    async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
+        // Spot only, so there are no positions
+        Ok(vec![])
+    }
+}
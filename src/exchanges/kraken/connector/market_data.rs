@@ -0,0 +1,275 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::{RestClient, TungsteniteWs, WsConfig, WsSession},
+    streaming::{SharedHub, StreamRouter},
+    traits::{MarketSnapshotSource, RestMarketData, StreamingMarketData},
+    types::{
+        Kline, KlineInterval, Market, MarketDataType, OrderBook, SubscriptionType, Symbol, Ticker,
+        Trade, WebSocketConfig,
+    },
+};
+use crate::exchanges::factory::ExchangeId;
+use crate::exchanges::kraken::{
+    codec::{create_kraken_stream_identifiers, kraken_request_groups, KrakenCodec, KrakenMessage},
+    conversions::{
+        convert_kraken_depth, convert_kraken_market, convert_kraken_ohlc,
+        convert_kraken_rest_trade, convert_kraken_ticker, convert_kraken_ws_book,
+        convert_kraken_ws_ohlc, convert_kraken_ws_ticker, convert_kraken_ws_trade,
+        convert_symbol_to_pair, convert_symbol_to_ws_symbol, kline_interval_to_kraken_minutes,
+    },
+    rest::KrakenRestClient,
+    types::{KrakenOhlc, KrakenRestTrade},
+};
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+pub const KRAKEN_WS_URL: &str = ExchangeId::Kraken.endpoints().mainnet.ws;
+
+/// Most bars Kraken returns per OHLC request
+const MAX_OHLC: u32 = 720;
+
+/// Deepest book served by `/0/public/Depth`
+const MAX_BOOK_DEPTH: u32 = 500;
+
+/// Routes Kraken updates, subscribing one channel per request
+struct KrakenRouter;
+
+impl StreamRouter for KrakenRouter {
+    type Codec = KrakenCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        Ok(create_kraken_stream_identifiers(
+            &[convert_symbol_to_ws_symbol(symbol)?],
+            std::slice::from_ref(subscription),
+        )?
+        .pop())
+    }
+
+    fn request_groups(&self, streams: Vec<String>) -> Vec<Vec<String>> {
+        kraken_request_groups(streams)
+    }
+
+    fn convert(&self, message: KrakenMessage) -> Vec<MarketDataType> {
+        convert_kraken_message_to_market_data(message).unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Dropping malformed Kraken message");
+            Vec::new()
+        })
+    }
+}
+
+/// Market data implementation for Kraken
+pub struct MarketData<R: RestClient, W = ()> {
+    rest: KrakenRestClient<R>,
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
+    ws: Option<W>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
+}
+
+impl<R: RestClient + Clone, W> MarketData<R, W> {
+    /// Create a new market data source
+    pub fn new(rest: &R, ws: Option<W>) -> Self {
+        Self {
+            rest: KrakenRestClient::new(rest.clone()),
+            ws,
+            hub: SharedHub::new(),
+            ws_config: WsConfig::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> RestMarketData for MarketData<R, W> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.rest
+            .get_asset_pairs()
+            .await?
+            .values()
+            .map(convert_kraken_market)
+            .collect()
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        let minutes = kline_interval_to_kraken_minutes(interval).ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Kraken does not offer {} candles", interval))
+        })?;
+        let limit = limit.unwrap_or(MAX_OHLC).min(MAX_OHLC) as usize;
+
+        let pair = convert_symbol_to_pair(&symbol)?;
+        let result = self
+            .rest
+            .get_ohlc(&pair, minutes, start_time.map(|start| start / 1000))
+            .await?;
+        // Bars are keyed by Kraken's own pair name, next to the `last` cursor
+        let bars: Vec<KrakenOhlc> = result
+            .into_iter()
+            .find(|(key, _)| key != "last")
+            .map(|(_, bars)| serde_json::from_value(bars))
+            .transpose()
+            .map_err(|e| {
+                ExchangeError::DeserializationError(format!("Failed to parse OHLC: {}", e))
+            })?
+            .unwrap_or_default();
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut klines = Vec::with_capacity(bars.len());
+        for bar in &bars {
+            let mut kline = convert_kraken_ohlc(bar, &symbol, interval, false)?;
+            if start_time.is_some_and(|start| kline.open_time < start)
+                || end_time.is_some_and(|end| kline.open_time > end)
+            {
+                continue;
+            }
+            // The newest bar is still forming
+            kline.final_bar = kline.close_time < now;
+            klines.push(kline);
+        }
+
+        if start_time.is_some() {
+            klines.truncate(limit);
+        } else {
+            let excess = klines.len().saturating_sub(limit);
+            klines.drain(..excess);
+        }
+        Ok(klines)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: Send + Sync> MarketSnapshotSource for MarketData<R, W> {
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        // Keyed by Kraken's own pair name, the only entry for a single pair
+        let ticker = self
+            .rest
+            .get_ticker(&convert_symbol_to_pair(&symbol)?)
+            .await?
+            .into_values()
+            .next()
+            .ok_or_else(|| {
+                ExchangeError::InvalidResponseFormat(format!("No ticker for {}", symbol))
+            })?;
+        convert_kraken_ticker(&ticker, &symbol, chrono::Utc::now().timestamp_millis())
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let count = depth.map(|depth| depth.min(MAX_BOOK_DEPTH));
+        let book = self
+            .rest
+            .get_depth(&convert_symbol_to_pair(&symbol)?, count)
+            .await?
+            .into_values()
+            .next()
+            .ok_or_else(|| {
+                ExchangeError::InvalidResponseFormat(format!("No order book for {}", symbol))
+            })?;
+        Ok(convert_kraken_depth(&book, &symbol)?.truncated(depth))
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let result = self
+            .rest
+            .get_trades(&convert_symbol_to_pair(&symbol)?, limit)
+            .await?;
+        // Trades are keyed by Kraken's own pair name, next to the `last` cursor
+        let trades: Vec<KrakenRestTrade> = result
+            .into_iter()
+            .find(|(key, _)| key != "last")
+            .map(|(_, trades)| serde_json::from_value(trades))
+            .transpose()
+            .map_err(|e| {
+                ExchangeError::DeserializationError(format!("Failed to parse trades: {}", e))
+            })?
+            .unwrap_or_default();
+        trades
+            .iter()
+            .map(|trade| convert_kraken_rest_trade(trade, &symbol))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone, W: WsSession<KrakenCodec> + Send + Sync> StreamingMarketData
+    for MarketData<R, W>
+{
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(KRAKEN_WS_URL.to_string(), "kraken".to_string(), KrakenCodec)
+                        .with_config(self.ws_config.clone())
+                },
+                KrakenRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        KRAKEN_WS_URL.to_string()
+    }
+}
+
+/// Convert `KrakenMessage` to `MarketDataType`s
+fn convert_kraken_message_to_market_data(
+    message: KrakenMessage,
+) -> Result<Vec<MarketDataType>, ExchangeError> {
+    match message {
+        KrakenMessage::Tickers { timestamp, tickers } => Ok(tickers
+            .iter()
+            .map(|ticker| MarketDataType::Ticker(convert_kraken_ws_ticker(ticker, timestamp)))
+            .collect()),
+        KrakenMessage::Trades(trades) => trades
+            .iter()
+            .map(|trade| convert_kraken_ws_trade(trade).map(MarketDataType::Trade))
+            .collect(),
+        KrakenMessage::Books(books) => Ok(books
+            .iter()
+            .map(|book| MarketDataType::OrderBook(convert_kraken_ws_book(book)))
+            .collect()),
+        // OHLC updates the current bar
+        KrakenMessage::Ohlc(bars) => bars
+            .iter()
+            .map(|bar| convert_kraken_ws_ohlc(bar).map(MarketDataType::Kline))
+            .collect(),
+        KrakenMessage::Unknown => Ok(Vec::new()),
+    }
+}
@@ -0,0 +1,148 @@
+use crate::core::{
+    errors::ExchangeError,
+    kernel::RestClient,
+    traits::{OrderHistorySource, OrderPlacer},
+    types::{OrderPage, OrderRequest, OrderResponse, Symbol},
+};
+use crate::exchanges::kraken::{
+    conversions::{convert_kraken_closed_order, convert_order_request, convert_symbol_to_pair},
+    rest::KrakenRestClient,
+};
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Trading implementation for Kraken
+pub struct Trading<R: RestClient> {
+    rest: KrakenRestClient<R>,
+}
+
+impl<R: RestClient> Trading<R> {
+    /// Create a new trading engine
+    pub fn new(rest: &R) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            rest: KrakenRestClient::new(rest.clone()),
+        }
+    }
+}
+
+/// Client order id; Kraken accepts free text of up to 18 characters
+///
+/// The caller's id is sent as is, otherwise one is made up.
+fn client_order_id(order: &OrderRequest) -> String {
+    order
+        .client_order_id
+        .clone()
+        .unwrap_or_else(|| format!("lx{:016x}", rand::random::<u64>()))
+}
+
+#[async_trait]
+impl<R: RestClient> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    #[instrument(skip(self), fields(exchange = "kraken"))]
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let client_order_id = client_order_id(&order);
+        let request = convert_order_request(&order, client_order_id.clone())?;
+        let result = self.rest.add_order(&request).await?;
+        let order_id = result.txid.into_iter().next().ok_or_else(|| {
+            ExchangeError::InvalidResponseFormat("Kraken order response without txid".to_string())
+        })?;
+
+        Ok(OrderResponse {
+            order_id,
+            client_order_id,
+            symbol: order.symbol,
+            side: order.side,
+            order_type: order.order_type,
+            quantity: order.quantity,
+            price: order.price,
+            status: "PENDING".to_string(),
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            extensions: None,
+        })
+    }
+
+    #[instrument(skip(self), fields(exchange = "kraken", order_id = %order_id))]
+    async fn cancel_order(&self, _symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        // Transaction ids are unique across pairs
+        let result = self.rest.cancel_order(&order_id).await?;
+        if result.count == 0 {
+            return Err(ExchangeError::InvalidParameters(format!(
+                "Kraken cancelled no order for {}",
+                order_id
+            )));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(exchange = "kraken", client_order_id = %client_order_id))]
+    async fn cancel_order_by_client_id(
+        &self,
+        _symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        let result = self
+            .rest
+            .cancel_order_by_client_id(&client_order_id)
+            .await?;
+        if result.count == 0 {
+            return Err(ExchangeError::InvalidParameters(format!(
+                "Kraken cancelled no order for client id {}",
+                client_order_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> OrderHistorySource for Trading<R> {
+    /// Pages run newest first; the cursor is the offset of the next page. Kraken pages
+    /// closed orders of every pair, so a page may hold fewer orders of `symbol`, or none.
+    #[instrument(skip(self), fields(exchange = "kraken"))]
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        let offset = cursor
+            .as_deref()
+            .map(str::parse::<u32>)
+            .transpose()
+            .map_err(|e| ExchangeError::InvalidParameters(format!("Invalid cursor: {}", e)))?
+            .unwrap_or(0);
+        let result = self
+            .rest
+            .get_closed_orders(
+                start_time.map(|start| start / 1000),
+                end_time.map(|end| end / 1000),
+                offset,
+            )
+            .await?;
+
+        let page_len = u32::try_from(result.closed.len()).unwrap_or(u32::MAX);
+        let next_offset = offset.saturating_add(page_len);
+        let next_cursor =
+            (page_len > 0 && next_offset < result.count).then(|| next_offset.to_string());
+        let pair = convert_symbol_to_pair(&symbol)?;
+        let mut orders = result
+            .closed
+            .iter()
+            .filter(|(_, order)| order.descr.pair == pair)
+            .map(|(txid, order)| convert_kraken_closed_order(txid, order, &symbol))
+            .collect::<Result<Vec<_>, _>>()?;
+        // The result is a map, so restore Kraken's newest-first order
+        orders.sort_by_key(|order| std::cmp::Reverse(order.updated_time));
+        Ok(OrderPage {
+            orders,
+            next_cursor,
+        })
+    }
+}
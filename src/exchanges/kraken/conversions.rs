@@ -0,0 +1,581 @@
+use super::types as kraken_types;
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
+use crate::core::types::{
+    conversion, Balance, ContractStyle, Kline, KlineInterval, Market, MarketStatus, Order,
+    OrderBook, OrderBookEntry, OrderRequest, OrderSide, OrderStatus, OrderType, Price, Quantity,
+    Symbol, Ticker, TimeInForce, Trade, Volume,
+};
+use crate::exchanges::factory::ExchangeId;
+use rust_decimal::Decimal;
+
+/// Kraken WebSocket symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::Kraken.symbol_convention();
+
+/// Common asset name for a Kraken one, e.g. `XXBT` -> `BTC`, `ZUSD` -> `USD`, `ETH.F` -> `ETH`
+pub fn normalize_kraken_asset(asset: &str) -> String {
+    let asset = asset.split('.').next().unwrap_or(asset);
+    // Legacy assets carry an X (crypto) or Z (fiat) prefix
+    let asset = match asset {
+        "XETC" | "XETH" | "XLTC" | "XMLN" | "XREP" | "XXBT" | "XXDG" | "XXLM" | "XXMR" | "XXRP"
+        | "XZEC" | "ZAUD" | "ZCAD" | "ZCHF" | "ZEUR" | "ZGBP" | "ZJPY" | "ZUSD" => &asset[1..],
+        _ => asset,
+    };
+    match asset {
+        "XBT" => "BTC".to_string(),
+        "XDG" => "DOGE".to_string(),
+        _ => asset.to_string(),
+    }
+}
+
+/// Kraken REST name of a common asset
+fn to_kraken_asset(asset: &str) -> &str {
+    match asset {
+        "BTC" => "XBT",
+        "DOGE" => "XDG",
+        _ => asset,
+    }
+}
+
+/// Convert symbol to Kraken REST pair, e.g. `XBTUSD`
+pub fn convert_symbol_to_pair(symbol: &Symbol) -> Result<String, ExchangeError> {
+    SYMBOLS.validate(symbol)?;
+    Ok(format!(
+        "{}{}",
+        to_kraken_asset(&symbol.base),
+        to_kraken_asset(&symbol.quote)
+    ))
+}
+
+/// Convert symbol to Kraken WebSocket v2 symbol, e.g. `BTC/USD`
+pub fn convert_symbol_to_ws_symbol(symbol: &Symbol) -> Result<String, ExchangeError> {
+    SYMBOLS.try_to_exchange_symbol(symbol)
+}
+
+/// Convert a `BASE/QUOTE` Kraken symbol to symbol
+pub fn convert_ws_symbol_to_symbol(ws_symbol: &str) -> Symbol {
+    let symbol = SYMBOLS.from_exchange_symbol(ws_symbol);
+    Symbol::new(
+        normalize_kraken_asset(&symbol.base),
+        normalize_kraken_asset(&symbol.quote),
+    )
+    .unwrap_or(symbol)
+}
+
+/// Convert kraken asset pair to core market type
+pub fn convert_kraken_market(
+    pair: &kraken_types::KrakenAssetPair,
+) -> Result<Market, ExchangeError> {
+    let symbol = pair.wsname.as_deref().map_or_else(
+        || {
+            Symbol::new(
+                normalize_kraken_asset(&pair.base),
+                normalize_kraken_asset(&pair.quote),
+            )
+            .unwrap_or_else(|_| SYMBOLS.from_exchange_symbol(&pair.altname))
+        },
+        convert_ws_symbol_to_symbol,
+    );
+
+    Ok(Market {
+        symbol,
+        status: pair
+            .status
+            .as_deref()
+            .map_or(MarketStatus::Trading, MarketStatus::from_venue_str),
+        base_precision: i32::try_from(pair.lot_decimals).unwrap_or(i32::MAX),
+        quote_precision: i32::try_from(pair.pair_decimals).unwrap_or(i32::MAX),
+        min_qty: pair
+            .ordermin
+            .as_deref()
+            .map(conversion::try_string_to_quantity)
+            .transpose()?,
+        max_qty: None,
+        min_price: pair
+            .tick_size
+            .as_deref()
+            .map(conversion::try_string_to_price)
+            .transpose()?,
+        max_price: None,
+        contract_style: ContractStyle::Spot,
+        contract_size: None,
+        extensions: None,
+    })
+}
+
+/// Kraken OHLC interval in minutes, `None` for intervals Kraken does not offer
+pub const fn kline_interval_to_kraken_minutes(interval: KlineInterval) -> Option<u32> {
+    match interval {
+        KlineInterval::Minutes1 => Some(1),
+        KlineInterval::Minutes5 => Some(5),
+        KlineInterval::Minutes15 => Some(15),
+        KlineInterval::Minutes30 => Some(30),
+        KlineInterval::Hours1 => Some(60),
+        KlineInterval::Hours4 => Some(240),
+        KlineInterval::Days1 => Some(1440),
+        KlineInterval::Weeks1 => Some(10080),
+        _ => None,
+    }
+}
+
+/// Convert kraken REST OHLC bar to core kline type
+pub fn convert_kraken_ohlc(
+    bar: &kraken_types::KrakenOhlc,
+    symbol: &Symbol,
+    interval: KlineInterval,
+    final_bar: bool,
+) -> Result<Kline, ExchangeError> {
+    let open_time = bar.0 * 1000;
+    Ok(Kline {
+        symbol: symbol.clone(),
+        open_time,
+        close_time: open_time + interval.duration_millis() - 1,
+        interval: interval.to_string(),
+        open_price: conversion::try_string_to_price(&bar.1)?,
+        high_price: conversion::try_string_to_price(&bar.2)?,
+        low_price: conversion::try_string_to_price(&bar.3)?,
+        close_price: conversion::try_string_to_price(&bar.4)?,
+        volume: conversion::try_string_to_volume(&bar.6)?,
+        number_of_trades: i64::try_from(bar.7).unwrap_or(i64::MAX),
+        final_bar,
+    })
+}
+
+/// Convert kraken REST ticker to core ticker type, observed at `timestamp`
+///
+/// Kraken measures the change from the opening price of the UTC day rather than
+/// over a rolling 24 hours.
+pub fn convert_kraken_ticker(
+    ticker: &kraken_types::KrakenTicker,
+    symbol: &Symbol,
+    timestamp: i64,
+) -> Result<Ticker, ExchangeError> {
+    let price = ticker
+        .c
+        .first()
+        .map(|last| conversion::try_string_to_price(last))
+        .transpose()?
+        .unwrap_or(Price::ZERO);
+    let open = conversion::try_string_to_decimal(&ticker.o)?;
+    let price_change = price.value() - open;
+    let volume = conversion::try_string_to_decimal(&ticker.v[1])?;
+    let vwap = conversion::try_string_to_decimal(&ticker.p[1])?;
+
+    Ok(Ticker {
+        symbol: symbol.clone(),
+        price,
+        price_change: Price::new(price_change),
+        price_change_percent: if open.is_zero() {
+            Decimal::ZERO
+        } else {
+            price_change / open * Decimal::ONE_HUNDRED
+        },
+        high_price: conversion::try_string_to_price(&ticker.h[1])?,
+        low_price: conversion::try_string_to_price(&ticker.l[1])?,
+        volume: Volume::new(volume),
+        quote_volume: Volume::new(volume * vwap),
+        open_time: timestamp - 24 * 60 * 60 * 1000,
+        close_time: timestamp,
+        count: ticker.t[1],
+    })
+}
+
+/// Convert kraken REST depth to core order book type
+pub fn convert_kraken_depth(
+    depth: &kraken_types::KrakenDepth,
+    symbol: &Symbol,
+) -> Result<OrderBook, ExchangeError> {
+    let entries = |levels: &[kraken_types::KrakenDepthLevel]| {
+        levels
+            .iter()
+            .map(|level| {
+                Ok(OrderBookEntry {
+                    price: conversion::try_string_to_price(&level.0)?,
+                    quantity: conversion::try_string_to_quantity(&level.1)?,
+                })
+            })
+            .collect::<Result<Vec<_>, ExchangeError>>()
+    };
+    Ok(OrderBook {
+        symbol: symbol.clone(),
+        bids: entries(&depth.bids)?,
+        asks: entries(&depth.asks)?,
+        last_update_id: 0, // Not sequenced over REST
+    })
+}
+
+/// Convert kraken REST trade to core trade type
+pub fn convert_kraken_rest_trade(
+    trade: &kraken_types::KrakenRestTrade,
+    symbol: &Symbol,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: symbol.clone(),
+        id: trade.6,
+        price: conversion::try_string_to_price(&trade.0)?,
+        quantity: conversion::try_string_to_quantity(&trade.1)?,
+        time: (trade.2 * 1000.0) as i64,
+        is_buyer_maker: trade.3 == "s",
+    })
+}
+
+/// Convert a `ClosedOrders` entry to core Order type for `symbol`
+pub fn convert_kraken_closed_order(
+    txid: &str,
+    order: &kraken_types::KrakenClosedOrder,
+    symbol: &Symbol,
+) -> Result<Order, ExchangeError> {
+    let descr = &order.descr;
+    let filled = conversion::try_string_to_decimal(&order.vol_exec)?;
+    let average_price = conversion::try_string_to_decimal(&order.price)?;
+    let (order_type, limit_price) = match descr.ordertype.as_str() {
+        "limit" => (OrderType::Limit, Some(&descr.price)),
+        "stop-loss" => (OrderType::StopLoss, None),
+        "stop-loss-limit" => (OrderType::StopLossLimit, Some(&descr.price2)),
+        "take-profit" => (OrderType::TakeProfit, None),
+        "take-profit-limit" => (OrderType::TakeProfitLimit, Some(&descr.price2)),
+        _ => (OrderType::Market, None),
+    };
+
+    Ok(Order {
+        order_id: txid.to_string(),
+        client_order_id: order.cl_ord_id.clone().unwrap_or_default(),
+        symbol: symbol.clone(),
+        side: if descr.side == "sell" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        order_type,
+        // A closed order stopped by filling in full
+        status: match order.status.as_str() {
+            "closed" => OrderStatus::Filled,
+            status => OrderStatus::from_venue(status),
+        },
+        price: limit_price
+            .map(|price| conversion::try_string_to_price(price))
+            .transpose()?,
+        quantity: conversion::try_string_to_quantity(&order.vol)?,
+        filled_quantity: Quantity::new(filled),
+        average_price: (!filled.is_zero()).then(|| Price::new(average_price)),
+        created_time: (order.opentm * 1000.0) as i64,
+        updated_time: (order.closetm * 1000.0) as i64,
+    })
+}
+
+/// Convert kraken extended balance to core balance type
+pub fn convert_kraken_balance(
+    asset: &str,
+    balance: &kraken_types::KrakenBalance,
+) -> Result<Balance, ExchangeError> {
+    let total = conversion::try_string_to_decimal(&balance.balance)?;
+    let held = balance
+        .hold_trade
+        .as_deref()
+        .map(conversion::try_string_to_decimal)
+        .transpose()?
+        .unwrap_or_default();
+    Ok(Balance {
+        asset: normalize_kraken_asset(asset),
+        free: Quantity::new(total - held),
+        locked: Quantity::new(held),
+    })
+}
+
+/// Convert order side to kraken format
+pub fn convert_order_side(side: &OrderSide) -> String {
+    match side {
+        OrderSide::Buy => "buy".to_string(),
+        OrderSide::Sell => "sell".to_string(),
+    }
+}
+
+/// Build an `AddOrder` request for a core order request
+///
+/// Kraken prices stop orders by their trigger; stop-limit orders carry the limit
+/// price in `price2`.
+pub fn convert_order_request(
+    order: &OrderRequest,
+    cl_ord_id: String,
+) -> Result<kraken_types::KrakenOrderRequest, ExchangeError> {
+    let limit_price = || {
+        order.price.map(|p| p.to_string()).ok_or_else(|| {
+            ExchangeError::InvalidParameters("Limit orders require a price".to_string())
+        })
+    };
+    let stop_price = || {
+        order.stop_price.map(|p| p.to_string()).ok_or_else(|| {
+            ExchangeError::InvalidParameters("Stop orders require a stop price".to_string())
+        })
+    };
+
+    let (ordertype, price, price2) = match order.order_type {
+        OrderType::Market => ("market", None, None),
+        OrderType::Limit => ("limit", Some(limit_price()?), None),
+        OrderType::StopLoss => ("stop-loss", Some(stop_price()?), None),
+        OrderType::StopLossLimit => ("stop-loss-limit", Some(stop_price()?), Some(limit_price()?)),
+        OrderType::TakeProfit => ("take-profit", Some(stop_price()?), None),
+        OrderType::TakeProfitLimit => (
+            "take-profit-limit",
+            Some(stop_price()?),
+            Some(limit_price()?),
+        ),
+    };
+    let timeinforce = match order.time_in_force {
+        None => None,
+        Some(TimeInForce::GTC) => Some("GTC".to_string()),
+        Some(TimeInForce::IOC) => Some("IOC".to_string()),
+        Some(TimeInForce::FOK) => {
+            return Err(ExchangeError::NotSupported(
+                "Kraken spot does not support fill-or-kill".to_string(),
+            ))
+        }
+    };
+
+    Ok(kraken_types::KrakenOrderRequest {
+        pair: convert_symbol_to_pair(&order.symbol)?,
+        side: convert_order_side(&order.side),
+        ordertype: ordertype.to_string(),
+        volume: order.quantity.to_string(),
+        price,
+        price2,
+        timeinforce,
+        cl_ord_id,
+    })
+}
+
+fn parse_rfc3339_millis(time: &str) -> Result<i64, ExchangeError> {
+    chrono::DateTime::parse_from_rfc3339(time)
+        .map(|t| t.timestamp_millis())
+        .map_err(|e| ExchangeError::DeserializationError(format!("Invalid time {}: {}", time, e)))
+}
+
+/// Convert kraken WebSocket ticker to core ticker type
+pub fn convert_kraken_ws_ticker(ticker: &kraken_types::KrakenWsTicker, timestamp: i64) -> Ticker {
+    Ticker {
+        symbol: convert_ws_symbol_to_symbol(&ticker.symbol),
+        price: Price::new(ticker.last),
+        price_change: Price::new(ticker.change),
+        price_change_percent: ticker.change_pct,
+        high_price: Price::new(ticker.high),
+        low_price: Price::new(ticker.low),
+        volume: Volume::new(ticker.volume),
+        quote_volume: Volume::new(ticker.volume * ticker.vwap),
+        open_time: timestamp - 24 * 60 * 60 * 1000,
+        close_time: timestamp,
+        count: 0,
+    }
+}
+
+/// Convert kraken WebSocket trade to core trade type
+pub fn convert_kraken_ws_trade(
+    trade: &kraken_types::KrakenWsTrade,
+) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol: convert_ws_symbol_to_symbol(&trade.symbol),
+        id: trade.trade_id,
+        price: Price::new(trade.price),
+        quantity: Quantity::new(trade.qty),
+        time: parse_rfc3339_millis(&trade.timestamp)?,
+        is_buyer_maker: trade.side == "sell",
+    })
+}
+
+/// Convert kraken book message to core order book type
+///
+/// Snapshots carry the whole book; updates carry changed levels, with zero quantity
+/// for removed ones. Kraken books are checksummed rather than sequenced.
+pub fn convert_kraken_ws_book(book: &kraken_types::KrakenWsBook) -> OrderBook {
+    let entries = |levels: &[kraken_types::KrakenWsLevel]| {
+        levels
+            .iter()
+            .map(|level| OrderBookEntry {
+                price: Price::new(level.price),
+                quantity: Quantity::new(level.qty),
+            })
+            .collect()
+    };
+    OrderBook {
+        symbol: convert_ws_symbol_to_symbol(&book.symbol),
+        bids: entries(&book.bids),
+        asks: entries(&book.asks),
+        last_update_id: 0,
+    }
+}
+
+/// Convert kraken WebSocket OHLC update to core kline type
+pub fn convert_kraken_ws_ohlc(ohlc: &kraken_types::KrakenWsOhlc) -> Result<Kline, ExchangeError> {
+    let interval = KlineInterval::from_venue_str(&ohlc.interval.to_string())
+        .or(match ohlc.interval {
+            1440 => Some(KlineInterval::Days1),
+            10080 => Some(KlineInterval::Weeks1),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            ExchangeError::DeserializationError(format!(
+                "Unknown Kraken interval {}",
+                ohlc.interval
+            ))
+        })?;
+    let open_time = parse_rfc3339_millis(&ohlc.interval_begin)?;
+
+    Ok(Kline {
+        symbol: convert_ws_symbol_to_symbol(&ohlc.symbol),
+        open_time,
+        close_time: open_time + interval.duration_millis() - 1,
+        interval: interval.to_string(),
+        open_price: Price::new(ohlc.open),
+        high_price: Price::new(ohlc.high),
+        low_price: Price::new(ohlc.low),
+        close_price: Price::new(ohlc.close),
+        volume: Volume::new(ohlc.volume),
+        number_of_trades: i64::try_from(ohlc.trades).unwrap_or(i64::MAX),
+        final_bar: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_and_symbol_names() {
+        assert_eq!(normalize_kraken_asset("XXBT"), "BTC");
+        assert_eq!(normalize_kraken_asset("ZUSD"), "USD");
+        assert_eq!(normalize_kraken_asset("ETH.F"), "ETH");
+        assert_eq!(normalize_kraken_asset("SOL"), "SOL");
+
+        let symbol = convert_ws_symbol_to_symbol("XBT/USD");
+        assert_eq!(symbol.joined("/"), "BTC/USD");
+        assert_eq!(convert_symbol_to_pair(&symbol).unwrap(), "XBTUSD");
+        assert_eq!(convert_symbol_to_ws_symbol(&symbol).unwrap(), "BTC/USD");
+    }
+
+    #[test]
+    fn test_rest_ticker_and_trade() {
+        let ticker: kraken_types::KrakenTicker = serde_json::from_value(serde_json::json!({
+            "a": ["30010.0", "1", "1.000"],
+            "b": ["30000.0", "2", "2.000"],
+            "c": ["30000.0", "0.01"],
+            "v": ["10.0", "100.0"],
+            "p": ["29900.0", "29500.0"],
+            "t": [120, 1500],
+            "l": ["29000.0", "28500.0"],
+            "h": ["30100.0", "30200.0"],
+            "o": "28800.0"
+        }))
+        .unwrap();
+        let symbol = Symbol::new("BTC", "USD").unwrap();
+        let ticker = convert_kraken_ticker(&ticker, &symbol, 86_400_000).unwrap();
+        assert_eq!(ticker.price_change.value(), Decimal::from(1200));
+        assert_eq!(ticker.high_price.value(), Decimal::from(30200));
+        assert_eq!(ticker.quote_volume.value(), Decimal::from(2_950_000));
+        assert_eq!(ticker.count, 1500);
+        assert_eq!(ticker.open_time, 0);
+
+        let trade: kraken_types::KrakenRestTrade = serde_json::from_value(serde_json::json!([
+            "30000.1",
+            "0.25",
+            1_688_667_796.221_7,
+            "s",
+            "l",
+            "",
+            42
+        ]))
+        .unwrap();
+        let trade = convert_kraken_rest_trade(&trade, &symbol).unwrap();
+        assert_eq!(trade.id, 42);
+        assert_eq!(trade.time, 1_688_667_796_221);
+        assert!(trade.is_buyer_maker);
+    }
+
+    #[test]
+    fn test_order_request() {
+        let mut order = OrderRequest {
+            symbol: Symbol::new("BTC", "USD").unwrap(),
+            side: OrderSide::Sell,
+            order_type: OrderType::StopLossLimit,
+            quantity: Quantity::new(Decimal::new(125, 2)),
+            price: Some(Price::new(Decimal::from(27_400))),
+            time_in_force: Some(TimeInForce::GTC),
+            stop_price: Some(Price::new(Decimal::from(27_500))),
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        };
+        let request = convert_order_request(&order, "c1".to_string()).unwrap();
+        assert_eq!(request.ordertype, "stop-loss-limit");
+        assert_eq!(request.price.as_deref(), Some("27500"));
+        assert_eq!(request.price2.as_deref(), Some("27400"));
+        assert_eq!(request.volume, "1.25");
+        assert_eq!(request.pair, "XBTUSD");
+
+        order.time_in_force = Some(TimeInForce::FOK);
+        assert!(convert_order_request(&order, "c2".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_convert_closed_orders() {
+        let result: kraken_types::KrakenClosedOrders = serde_json::from_str(
+            r#"{
+                "closed": {
+                    "O37652-RJWRT-IMO74O": {
+                        "refid": null, "userref": 0, "cl_ord_id": "grid-7",
+                        "status": "canceled", "reason": "User requested",
+                        "opentm": 1688148493.7708, "closetm": 1688148610.0482,
+                        "starttm": 0, "expiretm": 0,
+                        "descr": {"pair": "XBTUSD", "type": "sell", "ordertype": "limit",
+                                  "price": "30010.0", "price2": "0", "leverage": "none",
+                                  "order": "sell 0.50000000 XBTUSD @ limit 30010.0", "close": ""},
+                        "vol": "0.50000000", "vol_exec": "0.20000000", "cost": "6002.00000",
+                        "fee": "1.56052", "price": "30010.0", "stopprice": "0.00000",
+                        "limitprice": "0.00000", "misc": "", "oflags": "fciq"
+                    },
+                    "OB5VMB-B4U2U-DK2WRW": {
+                        "refid": null, "userref": 0, "status": "closed",
+                        "opentm": 1688148000.0, "closetm": 1688148000.5,
+                        "starttm": 0, "expiretm": 0,
+                        "descr": {"pair": "XBTUSD", "type": "buy", "ordertype": "market",
+                                  "price": "0", "price2": "0", "leverage": "none",
+                                  "order": "buy 0.10000000 XBTUSD @ market", "close": ""},
+                        "vol": "0.10000000", "vol_exec": "0.10000000", "cost": "3000.00000",
+                        "fee": "0.78000", "price": "30000.0", "stopprice": "0.00000",
+                        "limitprice": "0.00000", "misc": "", "oflags": "fciq"
+                    }
+                },
+                "count": 2
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(result.count, 2);
+        let btc_usd = Symbol::new("BTC", "USD").unwrap();
+
+        let cancelled = convert_kraken_closed_order(
+            "O37652-RJWRT-IMO74O",
+            &result.closed["O37652-RJWRT-IMO74O"],
+            &btc_usd,
+        )
+        .unwrap();
+        assert_eq!(convert_symbol_to_pair(&btc_usd).unwrap(), "XBTUSD");
+        assert_eq!(cancelled.client_order_id, "grid-7");
+        assert!(matches!(cancelled.side, OrderSide::Sell));
+        assert!(matches!(cancelled.order_type, OrderType::Limit));
+        assert_eq!(cancelled.status, OrderStatus::Canceled);
+        assert_eq!(cancelled.price.unwrap().value(), Decimal::from(30_010));
+        assert_eq!(cancelled.filled_quantity.value(), Decimal::new(2, 1));
+        assert_eq!(cancelled.created_time, 1_688_148_493_770);
+        assert_eq!(cancelled.updated_time, 1_688_148_610_048);
+
+        let filled = convert_kraken_closed_order(
+            "OB5VMB-B4U2U-DK2WRW",
+            &result.closed["OB5VMB-B4U2U-DK2WRW"],
+            &btc_usd,
+        )
+        .unwrap();
+        assert_eq!(filled.client_order_id, "");
+        assert_eq!(filled.status, OrderStatus::Filled);
+        assert!(matches!(filled.order_type, OrderType::Market));
+        assert!(filled.price.is_none());
+        assert_eq!(filled.average_price.unwrap().value(), Decimal::from(30_000));
+    }
+}
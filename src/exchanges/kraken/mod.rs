@@ -0,0 +1,22 @@
+pub mod codec;
+pub mod conversions;
+pub mod signer;
+pub mod types;
+
+pub mod builder;
+pub mod connector;
+pub mod rest;
+
+// Re-export main components
+pub use builder::{build_connector, build_connector_with_websocket, build_public};
+pub use codec::{KrakenCodec, KrakenMessage};
+pub use connector::{Account, KrakenConnector, MarketData, Trading};
+pub use types::{KrakenAssetPair, KrakenBalance, KrakenOhlc, KrakenOrderRequest};
+
+/// Helper function to create WebSocket stream identifiers for Kraken
+pub fn create_kraken_stream_identifiers(
+    ws_symbols: &[String],
+    subscription_types: &[crate::core::types::SubscriptionType],
+) -> Result<Vec<String>, crate::core::errors::ExchangeError> {
+    codec::create_kraken_stream_identifiers(ws_symbols, subscription_types)
+}
@@ -0,0 +1,209 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::RestClient;
+use crate::exchanges::kraken::types::{
+    kraken_error_kind, KrakenAssetPair, KrakenBalance, KrakenCancelResult, KrakenClosedOrders,
+    KrakenDepth, KrakenOhlcResult, KrakenOrderRequest, KrakenOrderResult, KrakenResponse,
+    KrakenServerTime, KrakenTicker, KrakenTradesResult,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Last nonce handed out; Kraken rejects nonces that do not increase per key
+static LAST_NONCE: AtomicU64 = AtomicU64::new(0);
+
+/// Strictly increasing nonce based on the clock in microseconds
+fn next_nonce() -> u64 {
+    let now = u64::try_from(chrono::Utc::now().timestamp_micros()).unwrap_or_default();
+    let mut last = LAST_NONCE.load(Ordering::Relaxed);
+    loop {
+        let nonce = now.max(last + 1);
+        match LAST_NONCE.compare_exchange_weak(last, nonce, Ordering::Relaxed, Ordering::Relaxed) {
+            Ok(_) => return nonce,
+            Err(current) => last = current,
+        }
+    }
+}
+
+/// Map Kraken `error` strings, e.g. `EOrder:Insufficient funds`, to `ExchangeError`
+fn map_kraken_error(errors: &[String]) -> ExchangeError {
+    let message = errors.join("; ");
+    let first = errors.first().map_or("", String::as_str);
+    if let Some(kind) = kraken_error_kind(-1, first) {
+        return ExchangeError::Rejected {
+            kind,
+            code: -1,
+            message,
+        };
+    }
+    match first
+        .split_once(':')
+        .map_or(first, |(category, _)| category)
+    {
+        _ if first.contains("Rate limit") => ExchangeError::RateLimitExceeded(message),
+        "EAPI" if first.contains("Invalid arguments") => ExchangeError::InvalidParameters(message),
+        "EAPI" => ExchangeError::AuthError(message),
+        "EOrder" | "EGeneral" | "EQuery" => ExchangeError::InvalidParameters(message),
+        "EService" => ExchangeError::ServerError(message),
+        _ => ExchangeError::ApiError { code: -1, message },
+    }
+}
+
+/// Unwrap the `{error, result}` envelope
+fn into_result<T: DeserializeOwned>(value: Value) -> Result<T, ExchangeError> {
+    let response: KrakenResponse<T> = serde_json::from_value(value).map_err(|e| {
+        ExchangeError::DeserializationError(format!("Failed to parse Kraken response: {}", e))
+    })?;
+    if !response.error.is_empty() {
+        return Err(map_kraken_error(&response.error));
+    }
+    response.result.ok_or_else(|| {
+        ExchangeError::InvalidResponseFormat("Kraken response without result".to_string())
+    })
+}
+
+/// Thin typed wrapper around `RestClient` for the Kraken spot API
+pub struct KrakenRestClient<R: RestClient> {
+    client: R,
+}
+
+impl<R: RestClient> KrakenRestClient<R> {
+    pub fn new(client: R) -> Self {
+        Self { client }
+    }
+
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.client.can_sign()
+    }
+
+    /// POST a private endpoint with `params` plus a fresh nonce
+    async fn private<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Value,
+    ) -> Result<T, ExchangeError> {
+        let mut body = json!({ "nonce": next_nonce().to_string() });
+        if let (Some(body), Value::Object(params)) = (body.as_object_mut(), params) {
+            body.extend(params);
+        }
+        into_result(self.client.post(endpoint, &body, true).await?)
+    }
+
+    /// Get all tradable asset pairs, keyed by pair name
+    pub async fn get_asset_pairs(&self) -> Result<HashMap<String, KrakenAssetPair>, ExchangeError> {
+        into_result(self.client.get("/0/public/AssetPairs", &[], false).await?)
+    }
+
+    /// Get server time in milliseconds
+    pub async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let time: KrakenServerTime =
+            into_result(self.client.get("/0/public/Time", &[], false).await?)?;
+        Ok(time.unixtime * 1000)
+    }
+
+    /// Get up to 720 OHLC bars of `interval` minutes, after `since` in unix seconds
+    pub async fn get_ohlc(
+        &self,
+        pair: &str,
+        interval: u32,
+        since: Option<i64>,
+    ) -> Result<KrakenOhlcResult, ExchangeError> {
+        let interval = interval.to_string();
+        let since = since.map(|s| s.to_string());
+        let mut params = vec![("pair", pair), ("interval", interval.as_str())];
+        if let Some(since) = &since {
+            params.push(("since", since.as_str()));
+        }
+        into_result(self.client.get("/0/public/OHLC", &params, false).await?)
+    }
+
+    /// Get the ticker of a pair, keyed by Kraken's own pair name
+    pub async fn get_ticker(
+        &self,
+        pair: &str,
+    ) -> Result<HashMap<String, KrakenTicker>, ExchangeError> {
+        let params = [("pair", pair)];
+        into_result(self.client.get("/0/public/Ticker", &params, false).await?)
+    }
+
+    /// Get the order book, keyed by Kraken's own pair name; Kraken defaults to 100
+    /// levels and serves up to 500
+    pub async fn get_depth(
+        &self,
+        pair: &str,
+        count: Option<u32>,
+    ) -> Result<HashMap<String, KrakenDepth>, ExchangeError> {
+        let count = count.map(|c| c.to_string());
+        let mut params = vec![("pair", pair)];
+        if let Some(count) = &count {
+            params.push(("count", count.as_str()));
+        }
+        into_result(self.client.get("/0/public/Depth", &params, false).await?)
+    }
+
+    /// Get recent trades, oldest first; Kraken defaults to 1000, the most it serves
+    pub async fn get_trades(
+        &self,
+        pair: &str,
+        count: Option<u32>,
+    ) -> Result<KrakenTradesResult, ExchangeError> {
+        let count = count.map(|c| c.to_string());
+        let mut params = vec![("pair", pair)];
+        if let Some(count) = &count {
+            params.push(("count", count.as_str()));
+        }
+        into_result(self.client.get("/0/public/Trades", &params, false).await?)
+    }
+
+    /// Get balances with the amount held by open orders, keyed by Kraken asset name
+    pub async fn get_balances(&self) -> Result<HashMap<String, KrakenBalance>, ExchangeError> {
+        self.private("/0/private/BalanceEx", json!({})).await
+    }
+
+    /// Get closed orders of every pair, newest first, skipping the first `ofs`
+    ///
+    /// `start` and `end` are unix seconds.
+    pub async fn get_closed_orders(
+        &self,
+        start: Option<i64>,
+        end: Option<i64>,
+        ofs: u32,
+    ) -> Result<KrakenClosedOrders, ExchangeError> {
+        let mut params = json!({ "ofs": ofs });
+        if let Some(start) = start {
+            params["start"] = json!(start);
+        }
+        if let Some(end) = end {
+            params["end"] = json!(end);
+        }
+        self.private("/0/private/ClosedOrders", params).await
+    }
+
+    /// Place an order
+    pub async fn add_order(
+        &self,
+        order: &KrakenOrderRequest,
+    ) -> Result<KrakenOrderResult, ExchangeError> {
+        let params = serde_json::to_value(order).map_err(|e| {
+            ExchangeError::SerializationError(format!("Failed to serialize order: {}", e))
+        })?;
+        self.private("/0/private/AddOrder", params).await
+    }
+
+    /// Cancel an order by transaction id
+    pub async fn cancel_order(&self, txid: &str) -> Result<KrakenCancelResult, ExchangeError> {
+        self.private("/0/private/CancelOrder", json!({ "txid": txid }))
+            .await
+    }
+
+    /// Cancel the order placed with client order id `cl_ord_id`
+    pub async fn cancel_order_by_client_id(
+        &self,
+        cl_ord_id: &str,
+    ) -> Result<KrakenCancelResult, ExchangeError> {
+        self.private("/0/private/CancelOrder", json!({ "cl_ord_id": cl_ord_id }))
+            .await
+    }
+}
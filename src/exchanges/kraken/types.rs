@@ -0,0 +1,277 @@
+use crate::core::errors::ErrorKind;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// WebSocket v2 sends prices and quantities as JSON numbers; keep their exact digits
+fn number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+    let text = serde_json::Number::deserialize(deserializer)?.to_string();
+    Decimal::from_str(&text)
+        .or_else(|_| Decimal::from_scientific(&text))
+        .map_err(serde::de::Error::custom)
+}
+
+/// Envelope of every Kraken REST response
+#[derive(Debug, Deserialize)]
+pub struct KrakenResponse<T> {
+    #[serde(default)]
+    pub error: Vec<String>,
+    pub result: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KrakenAssetPair {
+    pub altname: String,
+    #[serde(default)]
+    pub wsname: Option<String>,
+    pub base: String,
+    pub quote: String,
+    pub pair_decimals: u32,
+    pub lot_decimals: u32,
+    #[serde(default)]
+    pub ordermin: Option<String>,
+    #[serde(default)]
+    pub tick_size: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// `[time, open, high, low, close, vwap, volume, count]`, time in unix seconds
+#[derive(Debug, Clone, Deserialize)]
+pub struct KrakenOhlc(
+    pub i64,
+    pub String,
+    pub String,
+    pub String,
+    pub String,
+    pub String,
+    pub String,
+    pub u64,
+);
+
+/// OHLC result: bars keyed by the canonical pair name, plus `last`
+pub type KrakenOhlcResult = HashMap<String, Value>;
+
+/// Server time; Kraken reports whole seconds
+#[derive(Debug, Deserialize)]
+pub struct KrakenServerTime {
+    pub unixtime: i64,
+}
+
+/// Ticker of one pair; two-element fields are `[today, last 24 hours]`
+#[derive(Debug, Deserialize)]
+pub struct KrakenTicker {
+    /// Last trade `[price, lot volume]`
+    pub c: Vec<String>,
+    /// Volume
+    pub v: [String; 2],
+    /// Volume weighted average price
+    pub p: [String; 2],
+    /// Number of trades
+    pub t: [i64; 2],
+    /// Low
+    pub l: [String; 2],
+    /// High
+    pub h: [String; 2],
+    /// Opening price of the UTC day
+    pub o: String,
+}
+
+/// `[price, volume, timestamp]`, timestamp in unix seconds
+#[derive(Debug, Deserialize)]
+pub struct KrakenDepthLevel(pub String, pub String, pub i64);
+
+#[derive(Debug, Deserialize)]
+pub struct KrakenDepth {
+    pub bids: Vec<KrakenDepthLevel>,
+    pub asks: Vec<KrakenDepthLevel>,
+}
+
+/// `[price, volume, time, side, order type, misc, trade id]`, time in unix seconds
+#[derive(Debug, Deserialize)]
+pub struct KrakenRestTrade(
+    pub String,
+    pub String,
+    pub f64,
+    pub String,
+    pub String,
+    pub String,
+    pub i64,
+);
+
+/// Trades result: trades keyed by the canonical pair name, plus `last`
+pub type KrakenTradesResult = HashMap<String, Value>;
+
+#[derive(Debug, Deserialize)]
+pub struct KrakenBalance {
+    pub balance: String,
+    #[serde(default)]
+    pub hold_trade: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct KrakenOrderRequest {
+    pub pair: String,
+    #[serde(rename = "type")]
+    pub side: String,
+    pub ordertype: String,
+    pub volume: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeinforce: Option<String>,
+    pub cl_ord_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KrakenOrderResult {
+    pub txid: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KrakenCancelResult {
+    pub count: u32,
+}
+
+/// `ClosedOrders` result: up to 50 orders keyed by transaction id, newest first
+#[derive(Debug, Deserialize)]
+pub struct KrakenClosedOrders {
+    pub closed: HashMap<String, KrakenClosedOrder>,
+    /// Closed orders matching the query across all pages
+    pub count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KrakenClosedOrder {
+    #[serde(default)]
+    pub cl_ord_id: Option<String>,
+    /// `closed`, `canceled` or `expired`
+    pub status: String,
+    /// Unix seconds
+    pub opentm: f64,
+    pub closetm: f64,
+    pub descr: KrakenOrderDescription,
+    pub vol: String,
+    pub vol_exec: String,
+    /// Average fill price
+    pub price: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KrakenOrderDescription {
+    /// REST pair, e.g. `XBTUSD`
+    pub pair: String,
+    #[serde(rename = "type")]
+    pub side: String,
+    pub ordertype: String,
+    /// Limit or trigger price
+    pub price: String,
+    /// Limit price of stop-limit orders
+    #[serde(default)]
+    pub price2: String,
+}
+
+/// WebSocket v2 channel message
+#[derive(Debug, Deserialize)]
+pub struct KrakenWsEnvelope {
+    pub channel: String,
+    #[serde(rename = "type", default)]
+    pub kind: String,
+    #[serde(default)]
+    pub data: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KrakenWsTicker {
+    pub symbol: String,
+    #[serde(deserialize_with = "number")]
+    pub last: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub high: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub low: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub volume: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub vwap: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub change: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub change_pct: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KrakenWsTrade {
+    pub symbol: String,
+    pub side: String,
+    #[serde(deserialize_with = "number")]
+    pub price: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub qty: Decimal,
+    pub trade_id: i64,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KrakenWsBook {
+    pub symbol: String,
+    #[serde(default)]
+    pub bids: Vec<KrakenWsLevel>,
+    #[serde(default)]
+    pub asks: Vec<KrakenWsLevel>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KrakenWsLevel {
+    #[serde(deserialize_with = "number")]
+    pub price: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub qty: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KrakenWsOhlc {
+    pub symbol: String,
+    #[serde(deserialize_with = "number")]
+    pub open: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub high: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub low: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub close: Decimal,
+    #[serde(deserialize_with = "number")]
+    pub volume: Decimal,
+    pub trades: u64,
+    pub interval_begin: String,
+    /// Minutes
+    pub interval: u32,
+}
+
+/// Classify a Kraken `error` string such as `EOrder:Insufficient funds`
+pub fn kraken_error_kind(_code: i32, message: &str) -> Option<ErrorKind> {
+    if message.contains("Rate limit exceeded") {
+        Some(ErrorKind::RateLimited)
+    } else if message.starts_with("EAPI:Invalid key")
+        || message.starts_with("EAPI:Invalid signature")
+        || message.starts_with("EAPI:Invalid nonce")
+    {
+        Some(ErrorKind::Unauthorized)
+    } else if message.starts_with("EQuery:Unknown asset pair") {
+        Some(ErrorKind::InvalidSymbol)
+    } else if message.starts_with("EOrder:Insufficient funds") {
+        Some(ErrorKind::InsufficientBalance)
+    } else if message.starts_with("EOrder:Unknown order") {
+        Some(ErrorKind::OrderNotFound)
+    } else if message.starts_with("EOrder:Order minimum not met")
+        || message.starts_with("EOrder:Cost minimum not met")
+    {
+        Some(ErrorKind::MinNotional)
+    } else {
+        None
+    }
+}
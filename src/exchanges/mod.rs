@@ -1,8 +1,17 @@
 pub mod backpack;
 pub mod binance;
+pub mod binance_margin;
 pub mod binance_perp;
 pub mod bybit;
 pub mod bybit_perp;
+pub mod coinbase;
+pub mod dydx;
+pub mod endpoints;
+pub mod factory;
+pub mod gateio;
+pub mod gateio_perp;
 pub mod hyperliquid;
+pub mod kraken;
 pub mod okx;
 pub mod paradex;
+pub mod symbols;
@@ -1,7 +1,11 @@
 use crate::core::config::ExchangeConfig;
 use crate::core::errors::ExchangeError;
-use crate::core::kernel::{RestClientBuilder, RestClientConfig, TungsteniteWs};
-use crate::exchanges::okx::{codec::OkxCodec, connector::OkxConnector, signer::OkxSigner};
+use crate::core::kernel::{Profile, RestClientBuilder, RestClientConfig, TungsteniteWs};
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
+use crate::exchanges::okx::{
+    codec::OkxCodec, connector::OkxConnector, signer::OkxSigner, types::OkxInstType,
+};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -16,8 +20,9 @@ pub struct OkxBuilder {
     ws_reconnect_interval: Option<Duration>,
     ws_ping_interval: Option<Duration>,
     max_reconnect_attempts: Option<u32>,
-    rest_timeout: u64,
-    rest_max_retries: u32,
+    rest_timeout: Option<u64>,
+    rest_max_retries: Option<u32>,
+    inst_type: OkxInstType,
 }
 
 impl OkxBuilder {
@@ -29,8 +34,9 @@ impl OkxBuilder {
             ws_reconnect_interval: None,
             ws_ping_interval: None,
             max_reconnect_attempts: None,
-            rest_timeout: 30,
-            rest_max_retries: 3,
+            rest_timeout: None,
+            rest_max_retries: None,
+            inst_type: OkxInstType::Spot,
         }
     }
 
@@ -53,7 +59,10 @@ impl OkxBuilder {
         secret_key: String,
         passphrase: String,
     ) -> Self {
-        self.config = ExchangeConfig::new(api_key, secret_key).testnet(self.config.testnet);
+        self.config = ExchangeConfig::new(api_key, secret_key)
+            .testnet(self.config.testnet)
+            .rounding_policy(self.config.rounding_policy)
+            .profile(self.config.profile);
         if let Some(base_url) = self.config.base_url.clone() {
             self.config = self.config.base_url(base_url);
         }
@@ -91,38 +100,62 @@ impl OkxBuilder {
         self
     }
 
+    /// Select the transport defaults; explicit REST settings still override them
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        self.config.profile = profile;
+        self
+    }
+
     /// Set REST client timeout
     pub fn with_rest_timeout(mut self, timeout: u64) -> Self {
-        self.rest_timeout = timeout;
+        self.rest_timeout = Some(timeout);
         self
     }
 
     /// Set REST client maximum retries
     pub fn with_rest_max_retries(mut self, retries: u32) -> Self {
-        self.rest_max_retries = retries;
+        self.rest_max_retries = Some(retries);
         self
     }
 
+    /// Set the instrument family, spot by default
+    pub fn with_inst_type(mut self, inst_type: OkxInstType) -> Self {
+        self.inst_type = inst_type;
+        self
+    }
+
+    fn apply_rest_overrides(&self, mut rest_config: RestClientConfig) -> RestClientConfig {
+        if let Some(timeout) = self.rest_timeout {
+            rest_config = rest_config.with_timeout(timeout);
+        }
+        if let Some(retries) = self.rest_max_retries {
+            rest_config = rest_config.with_max_retries(retries);
+        }
+        rest_config
+    }
+
     /// Build a REST-only OKX connector
     pub fn build_rest_only(
         self,
     ) -> Result<OkxConnector<crate::core::kernel::ReqwestRest, ()>, ExchangeError> {
-        // Determine base URL
-        let base_url = if self.config.testnet {
-            "https://www.okx.com".to_string() // OKX doesn't have a separate testnet URL
-        } else {
-            self.config
-                .base_url
-                .clone()
-                .unwrap_or_else(|| "https://www.okx.com".to_string())
-        };
+        let endpoints = resolve_endpoints(ExchangeId::Okx, &self.config)?;
 
         // Build REST client
-        let rest_config = RestClientConfig::new(base_url, "okx".to_string())
-            .with_timeout(self.rest_timeout)
-            .with_max_retries(self.rest_max_retries);
+        let rest_config = RestClientConfig::new(endpoints.rest, "okx".to_string())
+            .with_profile(self.config.profile)
+            .with_network(self.config.proxy.clone(), self.config.tls.clone());
+        let rest_config = self.apply_rest_overrides(rest_config);
 
         let mut rest_builder = RestClientBuilder::new(rest_config);
+        // Demo trading shares the production host and is told apart by this header
+        if self.config.testnet {
+            rest_builder = rest_builder.with_request_hook(|request| {
+                request
+                    .headers
+                    .push(("x-simulated-trading".to_string(), "1".to_string()));
+                Ok(())
+            });
+        }
 
         // Add authentication if credentials are provided
         if self.config.has_credentials() {
@@ -142,7 +175,7 @@ impl OkxBuilder {
 
         let rest = rest_builder.build()?;
 
-        Ok(OkxConnector::new_without_ws(rest, self.config))
+        Ok(OkxConnector::new_without_ws(rest, self.config).with_inst_type(self.inst_type))
     }
 
     /// Build an OKX connector with WebSocket support
@@ -152,24 +185,24 @@ impl OkxBuilder {
         OkxConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<OkxCodec>>,
         ExchangeError,
     > {
-        // Determine URLs
-        let rest_base_url = if self.config.testnet {
-            "https://www.okx.com".to_string()
-        } else {
-            self.config
-                .base_url
-                .clone()
-                .unwrap_or_else(|| "https://www.okx.com".to_string())
-        };
-
-        let ws_url = "wss://ws.okx.com:8443/ws/v5/public".to_string();
+        let endpoints = resolve_endpoints(ExchangeId::Okx, &self.config)?;
 
         // Build REST client
-        let rest_config = RestClientConfig::new(rest_base_url, "okx".to_string())
-            .with_timeout(self.rest_timeout)
-            .with_max_retries(self.rest_max_retries);
+        let rest_config = RestClientConfig::new(endpoints.rest, "okx".to_string())
+            .with_profile(self.config.profile)
+            .with_network(self.config.proxy.clone(), self.config.tls.clone());
+        let rest_config = self.apply_rest_overrides(rest_config);
 
         let mut rest_builder = RestClientBuilder::new(rest_config);
+        // Demo trading shares the production host and is told apart by this header
+        if self.config.testnet {
+            rest_builder = rest_builder.with_request_hook(|request| {
+                request
+                    .headers
+                    .push(("x-simulated-trading".to_string(), "1".to_string()));
+                Ok(())
+            });
+        }
 
         // Add authentication if credentials are provided
         if self.config.has_credentials() {
@@ -191,9 +224,10 @@ impl OkxBuilder {
 
         // Build WebSocket client
         let codec = OkxCodec::new();
-        let ws = TungsteniteWs::new(ws_url, "okx".to_string(), codec);
+        let ws = TungsteniteWs::new(endpoints.ws, "okx".to_string(), codec)
+            .with_config(self.config.ws_config());
 
-        Ok(OkxConnector::new_with_ws(rest, ws, self.config))
+        Ok(OkxConnector::new_with_ws(rest, ws, self.config).with_inst_type(self.inst_type))
     }
 
     /// Build an OKX connector with WebSocket support and reconnection logic
@@ -231,6 +265,35 @@ pub fn build_connector_with_websocket(
     OkxBuilder::new().with_config(config).build_with_ws()
 }
 
+/// Create a OKX connector for public market data, ignoring any credentials in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<OkxConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<OkxCodec>>, ExchangeError>
+{
+    build_connector_with_websocket(config.without_credentials())
+}
+
+/// Create an OKX perpetual swap connector with REST-only support
+pub fn build_swap_connector(
+    config: ExchangeConfig,
+) -> Result<OkxConnector<crate::core::kernel::ReqwestRest, ()>, ExchangeError> {
+    OkxBuilder::new()
+        .with_config(config)
+        .with_inst_type(OkxInstType::Swap)
+        .build_rest_only()
+}
+
+/// Create an OKX perpetual swap connector with WebSocket support
+pub fn build_swap_connector_with_websocket(
+    config: ExchangeConfig,
+) -> Result<OkxConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<OkxCodec>>, ExchangeError>
+{
+    OkxBuilder::new()
+        .with_config(config)
+        .with_inst_type(OkxInstType::Swap)
+        .build_with_ws()
+}
+
 /// Create an OKX connector with WebSocket support and reconnection
 ///
 /// @deprecated Use `OkxBuilder` instead
@@ -338,4 +401,10 @@ mod tests {
         let result = builder.build_with_ws();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_build_swap_connector() {
+        let config = ExchangeConfig::new(String::new(), String::new());
+        assert!(build_swap_connector(config).is_ok());
+    }
 }
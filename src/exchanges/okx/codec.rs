@@ -1,9 +1,18 @@
 use crate::core::errors::ExchangeError;
-use crate::core::kernel::codec::WsCodec;
-use crate::core::types::SubscriptionType;
-use crate::exchanges::okx::types::{OkxWsChannel, OkxWsRequest};
+use crate::core::kernel::codec::{ControlFrame, WsCodec};
+use crate::core::types::{
+    MarketStatusUpdate, OrderBook, OrderBookEntry, OrderBookMode, Price, Quantity,
+    SubscriptionType, Symbol,
+};
+use crate::exchanges::okx::conversions::{
+    convert_okx_ws_instrument_status, kline_interval_to_okx_bar,
+};
+use crate::exchanges::okx::types::{OkxInstType, OkxWsChannel, OkxWsRequest};
+use rust_decimal::Decimal;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::sync::Mutex;
 use tokio_tungstenite::tungstenite::Message;
 
 /// OKX WebSocket message types
@@ -20,6 +29,9 @@ pub enum OkxMessage {
         inst_id: Option<String>,
         data: Value,
     },
+    /// Instrument state changes pushed on the `instruments` channel, e.g. a halt
+    /// (`suspend`) or a new listing; feed them to `MarketStatusTracker`
+    MarketStatus(Vec<MarketStatusUpdate>),
     /// Error message
     Error { code: String, message: String },
     /// Pong response
@@ -28,17 +40,117 @@ pub enum OkxMessage {
     Login { success: bool, message: String },
 }
 
+/// Channel pushing instrument state changes, subscribed per instrument family
+const INSTRUMENTS_CHANNEL: &str = "instruments";
+
+/// Stream identifier of the `instruments` channel for one instrument family
+pub fn okx_instruments_stream(inst_type: OkxInstType) -> String {
+    format!("{}:{}", INSTRUMENTS_CHANNEL, inst_type.as_str())
+}
+
+/// Levels OKX covers with a book checksum
+const CHECKSUM_LEVELS: usize = 25;
+
+/// Book rebuilt from a checksummed channel, to verify each update against
+#[derive(Debug, Default)]
+struct HeldBook {
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl HeldBook {
+    fn apply(&mut self, book: &Value) -> Result<(), ExchangeError> {
+        for (levels, side) in [(&mut self.bids, "bids"), (&mut self.asks, "asks")] {
+            for level in book
+                .get(side)
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                let field = |i: usize| {
+                    let text = level.get(i).and_then(Value::as_str).unwrap_or_default();
+                    Decimal::from_str(text).map_err(|e| {
+                        ExchangeError::ParseError(format!("Invalid book level {}: {}", level, e))
+                    })
+                };
+                let (price, size) = (field(0)?, field(1)?);
+                if size.is_zero() {
+                    levels.remove(&price);
+                } else {
+                    // The held key keeps the scale OKX printed it with
+                    levels.insert(price, size);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn checksum(&self) -> i32 {
+        let entry = |(price, size): (&Decimal, &Decimal)| OrderBookEntry {
+            price: Price::new(*price),
+            quantity: Quantity::new(*size),
+        };
+        let book = OrderBook {
+            symbol: Symbol::default(),
+            bids: self.bids.iter().rev().map(entry).collect(),
+            asks: self.asks.iter().map(entry).collect(),
+            last_update_id: 0,
+        };
+        // OKX sends the checksum as a signed 32-bit integer
+        i32::from_be_bytes(book.crc32(CHECKSUM_LEVELS).to_be_bytes())
+    }
+}
+
+/// Apply a checksummed book message to its held book, `Ok(false)` if it is an update
+/// awaiting a snapshot
+///
+/// A snapshot starts the book afresh; a mismatched book is dropped.
+fn verify_held_book(
+    books: &mut HashMap<String, HeldBook>,
+    stream: &str,
+    action: Option<&str>,
+    book: &Value,
+    expected: i64,
+) -> Result<bool, ExchangeError> {
+    if action == Some("snapshot") {
+        books.insert(stream.to_string(), HeldBook::default());
+    }
+    let Some(held) = books.get_mut(stream) else {
+        return Ok(false);
+    };
+    held.apply(book)?;
+
+    let computed = i64::from(held.checksum());
+    if computed != expected {
+        books.remove(stream);
+        return Err(ExchangeError::ChecksumMismatch {
+            stream: stream.to_string(),
+            expected,
+            computed,
+        });
+    }
+    Ok(true)
+}
+
 /// OKX WebSocket codec implementation
+///
+/// Books on checksummed channels (`books`, `books-l2-tbt`, ...) are rebuilt from their
+/// snapshot and updates, and every update is verified against the venue's CRC32. A
+/// mismatch is returned as `ExchangeError::ChecksumMismatch` naming the stream, which
+/// `ReconnectWs` resubscribes for a fresh snapshot; updates in between are dropped.
 pub struct OkxCodec {
     /// Channel subscriptions
     #[allow(dead_code)]
     subscriptions: HashMap<String, SubscriptionType>,
+    /// Held books by stream, `books:BTC-USDT`
+    books: Mutex<HashMap<String, HeldBook>>,
 }
 
 impl OkxCodec {
     pub fn new() -> Self {
         Self {
             subscriptions: HashMap::new(),
+            books: Mutex::new(HashMap::new()),
         }
     }
 
@@ -68,6 +180,27 @@ impl OkxCodec {
             },
         )
     }
+
+    /// Subscription argument of a stream identifier
+    ///
+    /// `instruments:SWAP` names an instrument family rather than an instrument.
+    fn stream_channel(stream: &str) -> OkxWsChannel {
+        let (channel, inst_id) = Self::parse_channel_info(stream);
+        if channel == INSTRUMENTS_CHANNEL {
+            return OkxWsChannel {
+                channel,
+                inst_type: Some(inst_id.unwrap_or_else(|| "SPOT".to_string())),
+                inst_family: None,
+                inst_id: None,
+            };
+        }
+        OkxWsChannel {
+            channel,
+            inst_type: Some("SPOT".to_string()),
+            inst_family: None,
+            inst_id,
+        }
+    }
 }
 
 impl Default for OkxCodec {
@@ -83,21 +216,10 @@ impl WsCodec for OkxCodec {
         &self,
         streams: &[impl AsRef<str> + Send + Sync],
     ) -> Result<Message, ExchangeError> {
-        let mut channels = Vec::new();
-
-        for stream in streams {
-            let stream_str = stream.as_ref();
-            let (channel_name, inst_id) = Self::parse_channel_info(stream_str);
-
-            let channel = OkxWsChannel {
-                channel: channel_name,
-                inst_type: Some("SPOT".to_string()),
-                inst_family: None,
-                inst_id,
-            };
-
-            channels.push(channel);
-        }
+        let channels = streams
+            .iter()
+            .map(|stream| Self::stream_channel(stream.as_ref()))
+            .collect();
 
         let message_str = Self::create_subscription_request(channels, "subscribe")?;
         Ok(Message::Text(message_str))
@@ -107,24 +229,55 @@ impl WsCodec for OkxCodec {
         &self,
         streams: &[impl AsRef<str> + Send + Sync],
     ) -> Result<Message, ExchangeError> {
-        let mut channels = Vec::new();
+        let channels = streams
+            .iter()
+            .map(|stream| Self::stream_channel(stream.as_ref()))
+            .collect();
+
+        let message_str = Self::create_subscription_request(channels, "unsubscribe")?;
+        Ok(Message::Text(message_str))
+    }
 
-        for stream in streams {
-            let stream_str = stream.as_ref();
-            let (channel_name, inst_id) = Self::parse_channel_info(stream_str);
+    fn encode_ping(&self) -> Option<Message> {
+        Some(Message::Text("ping".to_string()))
+    }
 
-            let channel = OkxWsChannel {
-                channel: channel_name,
-                inst_type: Some("SPOT".to_string()),
-                inst_family: None,
-                inst_id,
-            };
+    fn acknowledges_subscriptions(&self) -> bool {
+        true
+    }
 
-            channels.push(channel);
+    /// Event replies carry `connId`; pings are answered with a bare `pong`
+    fn control_frame(&self, message: &Message) -> Option<ControlFrame> {
+        let Message::Text(text) = message else {
+            return None;
+        };
+        if text == "pong" {
+            return Some(ControlFrame {
+                connection_id: None,
+                pong: true,
+                subscribed: false,
+            });
+        }
+        if !text.contains("\"connId\"") {
+            return None;
         }
+        let value: Value = serde_json::from_str(text).ok()?;
+        Some(ControlFrame {
+            connection_id: value
+                .get("connId")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            pong: false,
+            subscribed: value.get("event").and_then(Value::as_str) == Some("subscribe"),
+        })
+    }
 
-        let message_str = Self::create_subscription_request(channels, "unsubscribe")?;
-        Ok(Message::Text(message_str))
+    /// Pushes stamp each entry with `ts`, in milliseconds as a string
+    fn exchange_time(&self, message: &Self::Message) -> Option<i64> {
+        let OkxMessage::Data { data, .. } = message else {
+            return None;
+        };
+        data.get(0)?.get("ts")?.as_str()?.parse().ok()
     }
 
     fn decode_message(&self, message: Message) -> Result<Option<Self::Message>, ExchangeError> {
@@ -161,6 +314,8 @@ impl WsCodec for OkxCodec {
                         }));
                     }
                 }
+                // Sent when a book is resubscribed after a checksum mismatch
+                "unsubscribe" => return Ok(None),
                 "error" => {
                     let code = value
                         .get("code")
@@ -204,6 +359,33 @@ impl WsCodec for OkxCodec {
                 .ok_or_else(|| ExchangeError::ParseError("Missing data field".to_string()))?
                 .clone();
 
+            if channel_info.channel == INSTRUMENTS_CHANNEL {
+                return convert_okx_ws_instrument_status(&data)
+                    .map(|updates| Some(OkxMessage::MarketStatus(updates)))
+                    .map_err(ExchangeError::ParseError);
+            }
+
+            let book = data
+                .get(0)
+                .filter(|_| channel_info.channel.starts_with("books"));
+            if let Some((book, checksum)) = book.and_then(|book| {
+                let checksum = book.get("checksum").and_then(Value::as_i64)?;
+                Some((book, checksum))
+            }) {
+                let stream = format!(
+                    "{}:{}",
+                    channel_info.channel,
+                    channel_info.inst_id.as_deref().unwrap_or_default()
+                );
+                let action = value.get("action").and_then(Value::as_str);
+                let mut books = self.books.lock().unwrap_or_else(|e| e.into_inner());
+                let verified = verify_held_book(&mut books, &stream, action, book, checksum);
+                drop(books);
+                if !verified? {
+                    return Ok(None);
+                }
+            }
+
             return Ok(Some(OkxMessage::Data {
                 channel: channel_info.channel,
                 inst_id: channel_info.inst_id,
@@ -228,10 +410,18 @@ pub fn create_okx_stream_identifiers(
     for symbol in symbols {
         for sub_type in subscription_types {
             let channel = match sub_type {
-                SubscriptionType::Ticker => "tickers",
-                SubscriptionType::OrderBook { depth: _ } => "books",
-                SubscriptionType::Trades => "trades",
-                SubscriptionType::Klines { interval: _ } => "candle1m",
+                SubscriptionType::Ticker => "tickers".to_string(),
+                SubscriptionType::OrderBook {
+                    depth: Some(depth),
+                    mode: OrderBookMode::Snapshot | OrderBookMode::Aggregated,
+                } if *depth <= 5 => "books5".to_string(),
+                SubscriptionType::OrderBook { .. } => "books".to_string(),
+                SubscriptionType::Trades | SubscriptionType::AggTrades => "trades".to_string(),
+                SubscriptionType::Klines { interval } => {
+                    format!("candle{}", kline_interval_to_okx_bar(*interval))
+                }
+                // Not streamed yet; poll `FundingRateSource` instead
+                SubscriptionType::MarkPrice => continue,
             };
 
             identifiers.push(format!("{}:{}", channel, symbol));
@@ -295,15 +485,157 @@ mod tests {
         let symbols = vec!["BTC-USDT".to_string(), "ETH-USDT".to_string()];
         let subscription_types = vec![
             SubscriptionType::Ticker,
-            SubscriptionType::OrderBook { depth: None },
+            SubscriptionType::OrderBook {
+                depth: None,
+                mode: OrderBookMode::Delta,
+            },
+            SubscriptionType::Klines {
+                interval: crate::core::types::KlineInterval::Hours4,
+            },
         ];
 
         let identifiers = create_okx_stream_identifiers(&symbols, &subscription_types);
 
-        assert_eq!(identifiers.len(), 4);
+        assert_eq!(identifiers.len(), 6);
+        assert!(identifiers.contains(&"candle4H:ETH-USDT".to_string()));
         assert!(identifiers.contains(&"tickers:BTC-USDT".to_string()));
         assert!(identifiers.contains(&"books:BTC-USDT".to_string()));
         assert!(identifiers.contains(&"tickers:ETH-USDT".to_string()));
         assert!(identifiers.contains(&"books:ETH-USDT".to_string()));
     }
+
+    #[test]
+    fn test_shallow_snapshots_use_books5() {
+        let symbols = vec!["BTC-USDT".to_string()];
+        let identifiers = create_okx_stream_identifiers(
+            &symbols,
+            &[
+                SubscriptionType::OrderBook {
+                    depth: Some(5),
+                    mode: OrderBookMode::Snapshot,
+                },
+                SubscriptionType::OrderBook {
+                    depth: Some(50),
+                    mode: OrderBookMode::Snapshot,
+                },
+                SubscriptionType::OrderBook {
+                    depth: Some(5),
+                    mode: OrderBookMode::Delta,
+                },
+            ],
+        );
+
+        assert_eq!(
+            identifiers,
+            vec!["books5:BTC-USDT", "books:BTC-USDT", "books:BTC-USDT"]
+        );
+    }
+
+    #[test]
+    fn test_control_frame_reads_conn_id() {
+        let codec = OkxCodec::new();
+        let ack = Message::Text(
+            r#"{"event":"subscribe","arg":{"channel":"tickers","instId":"BTC-USDT"},"connId":"a4d3ae55"}"#
+                .to_string(),
+        );
+        assert_eq!(
+            codec.control_frame(&ack).unwrap().connection_id.as_deref(),
+            Some("a4d3ae55")
+        );
+        assert!(
+            codec
+                .control_frame(&Message::Text("pong".to_string()))
+                .unwrap()
+                .pong
+        );
+    }
+
+    #[test]
+    fn test_book_checksums_are_verified() {
+        let codec = OkxCodec::new();
+        let book = |action: &str, bids: &str, asks: &str, checksum: i64| {
+            Message::Text(format!(
+                r#"{{"arg":{{"channel":"books","instId":"ETH-USDT"}},"action":"{}","data":[{{"bids":{},"asks":{},"ts":"1","checksum":{}}}]}}"#,
+                action, bids, asks, checksum
+            ))
+        };
+
+        let snapshot = book(
+            "snapshot",
+            r#"[["3366.1","7","0","1"],["3366","6","0","1"]]"#,
+            r#"[["3366.8","9","0","1"],["3368","8","0","1"]]"#,
+            -1_881_014_294,
+        );
+        assert!(codec.decode_message(snapshot.clone()).unwrap().is_some());
+        let update = book(
+            "update",
+            r#"[["3365.5","1","0","1"]]"#,
+            "[]",
+            -1_587_199_491,
+        );
+        assert!(codec.decode_message(update).unwrap().is_some());
+
+        // A missed update leaves the held book behind the venue's
+        let update = book("update", r#"[["3366","0","0","0"]]"#, "[]", 42);
+        let err = codec.decode_message(update.clone()).unwrap_err();
+        assert!(matches!(
+            err,
+            ExchangeError::ChecksumMismatch { ref stream, expected: 42, .. } if stream == "books:ETH-USDT"
+        ));
+
+        // Updates are dropped until the resubscription's snapshot arrives
+        assert!(codec.decode_message(update).unwrap().is_none());
+        assert!(codec.decode_message(snapshot).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_instruments_channel_feeds_market_status() {
+        use crate::core::types::MarketStatus;
+        use crate::utils::market_watcher::{MarketEvent, MarketStatusTracker};
+
+        let codec = OkxCodec::new();
+        let Message::Text(text) = codec
+            .encode_subscription(&[okx_instruments_stream(OkxInstType::Swap)])
+            .unwrap()
+        else {
+            panic!("Expected text message");
+        };
+        let request: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(request["args"][0]["channel"], "instruments");
+        assert_eq!(request["args"][0]["instType"], "SWAP");
+        assert!(request["args"][0]["instId"].is_null());
+
+        let push = |state: &str| {
+            Message::Text(format!(
+                r#"{{"arg":{{"channel":"instruments","instType":"SPOT"}},"data":[{{"instType":"SPOT","instId":"BTC-USDT","baseCcy":"BTC","quoteCcy":"USDT","state":"{}","tickSz":"0.1","lotSz":"0.00000001","minSz":"0.00001","listTime":"1606468572000"}}]}}"#,
+                state
+            ))
+        };
+        let mut tracker = MarketStatusTracker::new();
+        let mut events = Vec::new();
+        for state in ["live", "suspend", "live"] {
+            let Some(OkxMessage::MarketStatus(updates)) =
+                codec.decode_message(push(state)).unwrap()
+            else {
+                panic!("Expected market status");
+            };
+            assert_eq!(updates.len(), 1);
+            events.extend(updates.iter().filter_map(|u| tracker.apply_update(u)));
+        }
+
+        let btc = Symbol::new("BTC", "USDT").unwrap();
+        assert_eq!(
+            events,
+            vec![
+                MarketEvent::MarketHalted {
+                    symbol: btc.clone(),
+                    status: MarketStatus::Halted,
+                },
+                MarketEvent::MarketResumed {
+                    symbol: btc,
+                    previous: MarketStatus::Halted,
+                },
+            ]
+        );
+    }
 }
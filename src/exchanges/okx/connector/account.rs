@@ -1,26 +1,45 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
-use crate::core::traits::AccountInfo;
-use crate::core::types::{Balance, Position, Quantity};
+use crate::core::traits::{AccountInfo, AccountSummarySource, AssetTransfer, BorrowRateSource};
+use crate::core::types::{
+    AccountSummary, AccountType, Balance, BorrowRate, InterestRecord, Position, Quantity,
+};
+use crate::exchanges::okx::conversions;
 use crate::exchanges::okx::rest::OkxRest;
+use crate::exchanges::okx::types::OkxInstType;
 use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
 
 /// OKX account implementation
 #[derive(Debug)]
 pub struct Account<R: RestClient> {
     rest: OkxRest<R>,
+    inst_type: OkxInstType,
 }
 
 impl<R: RestClient + Clone> Account<R> {
     pub fn new(rest: &R) -> Self {
         Self {
             rest: OkxRest::new(rest.clone()),
+            inst_type: OkxInstType::Spot,
         }
     }
+
+    /// Report positions of a different instrument family, e.g. `OkxInstType::Swap`
+    #[must_use]
+    pub const fn with_inst_type(mut self, inst_type: OkxInstType) -> Self {
+        self.inst_type = inst_type;
+        self
+    }
 }
 
 #[async_trait]
 impl<R: RestClient + Send + Sync> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         // Get account balance from OKX
         let okx_account = self.rest.get_balance(None).await?;
@@ -57,13 +76,104 @@ impl<R: RestClient + Send + Sync> AccountInfo for Account<R> {
     }
 
     async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
-        // OKX spot trading doesn't have positions in the traditional sense
-        // For spot trading, positions are essentially zero since we're trading actual assets
-        // However, we could return positions if there are any margin positions
+        // Spot holdings are balances, not positions
+        if self.inst_type == OkxInstType::Spot {
+            return Ok(Vec::new());
+        }
+
+        let inst_type = self.inst_type.as_str();
+        let (okx_positions, instruments) = tokio::try_join!(
+            self.rest.get_positions(inst_type),
+            self.rest.get_instruments(inst_type)
+        )?;
+        // Contract values turn contract counts into base-asset amounts
+        let markets: HashMap<String, _> = instruments
+            .into_iter()
+            .filter_map(|instrument| {
+                let inst_id = instrument.inst_id.clone();
+                conversions::convert_okx_market(instrument)
+                    .ok()
+                    .map(|market| (inst_id, market))
+            })
+            .collect();
+
+        okx_positions
+            .iter()
+            .filter(|position| !position.pos.is_empty() && position.pos != "0")
+            .map(|position| {
+                conversions::convert_okx_position(position, markets.get(&position.inst_id))
+                    .map_err(ExchangeError::ParseError)
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> AccountSummarySource for Account<R> {
+    async fn get_account_summary(&self) -> Result<AccountSummary, ExchangeError> {
+        let okx_account = self.rest.get_balance(None).await?;
+        conversions::convert_okx_account_summary(&okx_account).map_err(ExchangeError::ParseError)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> AssetTransfer for Account<R> {
+    async fn transfer(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        from: AccountType,
+        to: AccountType,
+    ) -> Result<String, ExchangeError> {
+        let (from_code, to_code) = (
+            conversions::account_type_to_okx(from),
+            conversions::account_type_to_okx(to),
+        );
+        if from_code == to_code {
+            return Err(ExchangeError::InvalidParameters(format!(
+                "OKX keeps {} and {} funds in the same account",
+                from, to
+            )));
+        }
 
-        // For now, return empty positions as this is spot trading
-        // In the future, this could be extended to support margin trading positions
-        Ok(Vec::new())
+        let transfer = self
+            .rest
+            .transfer(asset, &amount.to_string(), from_code, to_code)
+            .await?;
+        Ok(transfer.trans_id)
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> BorrowRateSource for Account<R> {
+    async fn get_borrow_rates(&self, asset: &str) -> Result<Vec<BorrowRate>, ExchangeError> {
+        let okx_rates = self.rest.get_interest_rate(Some(asset)).await?;
+        let now = chrono::Utc::now().timestamp_millis();
+        okx_rates
+            .iter()
+            .map(|rate| {
+                conversions::convert_okx_borrow_rate(rate, now).map_err(ExchangeError::ParseError)
+            })
+            .collect()
+    }
+
+    async fn get_interest_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<InterestRecord>, ExchangeError> {
+        let okx_interest = self
+            .rest
+            .get_interest_accrued(asset, start_time, end_time, limit)
+            .await?;
+        okx_interest
+            .iter()
+            .map(|record| {
+                conversions::convert_okx_interest_record(record).map_err(ExchangeError::ParseError)
+            })
+            .collect()
     }
 }
 
@@ -100,42 +210,4 @@ impl<R: RestClient + Send + Sync> Account<R> {
 
         Ok(None)
     }
-
-    /// Get account summary with USD value
-    pub async fn get_account_summary(&self) -> Result<AccountSummary, ExchangeError> {
-        let okx_account = self.rest.get_balance(None).await?;
-
-        let total_equity_usd = okx_account
-            .total_eq
-            .parse::<f64>()
-            .map_err(|e| ExchangeError::ParseError(format!("Invalid total equity: {}", e)))?;
-
-        let isolated_equity_usd = okx_account
-            .iso_eq
-            .parse::<f64>()
-            .map_err(|e| ExchangeError::ParseError(format!("Invalid isolated equity: {}", e)))?;
-
-        let available_equity_usd = okx_account
-            .adj_eq
-            .parse::<f64>()
-            .map_err(|e| ExchangeError::ParseError(format!("Invalid adjusted equity: {}", e)))?;
-
-        Ok(AccountSummary {
-            total_equity_usd,
-            isolated_equity_usd,
-            available_equity_usd,
-            account_level: okx_account.acct_lv,
-            position_mode: okx_account.pos_mode,
-        })
-    }
-}
-
-/// Account summary information
-#[derive(Debug, Clone)]
-pub struct AccountSummary {
-    pub total_equity_usd: f64,
-    pub isolated_equity_usd: f64,
-    pub available_equity_usd: f64,
-    pub account_level: String,
-    pub position_mode: String,
 }
@@ -1,48 +1,152 @@
 use crate::core::errors::ExchangeError;
-use crate::core::kernel::RestClient;
-use crate::core::traits::MarketDataSource;
+use crate::core::kernel::{ReconnectWs, RestClient, TungsteniteWs, WsConfig, WsSession};
+use crate::core::streaming::{SharedHub, StreamRouter};
+use crate::core::traits::{
+    FundingRateSource, MarketSnapshotSource, RestMarketData, StreamingMarketData,
+};
 use crate::core::types::{
-    Kline, KlineInterval, Market, MarketDataType, SubscriptionType, WebSocketConfig,
+    conversion, FundingRate, Kline, KlineInterval, Market, MarketDataType, MarketStatusUpdate,
+    OrderBook, OrderBookMode, Price, SubscriptionType, Symbol, Ticker, Trade, WebSocketConfig,
 };
 
-use crate::exchanges::okx::{conversions, rest::OkxRest};
+use crate::exchanges::factory::ExchangeId;
+use crate::exchanges::okx::codec::{okx_instruments_stream, OkxCodec, OkxMessage};
+use crate::exchanges::okx::{conversions, rest::OkxRest, types::OkxInstType};
 use async_trait::async_trait;
+use std::collections::HashMap;
 use tokio::sync::mpsc;
 
+/// Routes OKX public channel pushes, keyed `channel:instId`
+struct OkxRouter {
+    inst_type: OkxInstType,
+}
+
+impl StreamRouter for OkxRouter {
+    type Codec = OkxCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        let channel = match subscription {
+            SubscriptionType::Ticker => "tickers",
+            SubscriptionType::OrderBook {
+                depth: Some(depth),
+                mode: OrderBookMode::Snapshot | OrderBookMode::Aggregated,
+            } if *depth <= 5 => "books5",
+            SubscriptionType::OrderBook { .. } => "books",
+            SubscriptionType::Trades | SubscriptionType::AggTrades => "trades",
+            // Candles are served on the business endpoint, not the public one; mark
+            // prices are not streamed yet, poll `FundingRateSource` instead
+            SubscriptionType::Klines { .. } | SubscriptionType::MarkPrice => return Ok(None),
+        };
+        Ok(Some(format!(
+            "{}:{}",
+            channel,
+            self.inst_type.inst_id(symbol)?
+        )))
+    }
+
+    fn convert(&self, message: OkxMessage) -> Vec<MarketDataType> {
+        convert_okx_message_to_market_data(message).unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Dropping malformed OKX message");
+            Vec::new()
+        })
+    }
+}
+
+/// Updates carried by an OKX channel push
+fn convert_okx_message_to_market_data(message: OkxMessage) -> Result<Vec<MarketDataType>, String> {
+    let OkxMessage::Data {
+        channel,
+        inst_id: Some(inst_id),
+        data,
+    } = message
+    else {
+        return Ok(Vec::new());
+    };
+    Ok(match channel.as_str() {
+        "tickers" => vec![MarketDataType::Ticker(conversions::convert_okx_ws_ticker(
+            &data, &inst_id,
+        )?)],
+        "trades" => conversions::convert_okx_ws_trade(&data, &inst_id)?
+            .into_iter()
+            .map(MarketDataType::Trade)
+            .collect(),
+        books if books.starts_with("books") => vec![MarketDataType::OrderBook(
+            conversions::convert_okx_ws_order_book(&data, &inst_id)?,
+        )],
+        _ => Vec::new(),
+    })
+}
+
 /// OKX market data implementation
 #[derive(Debug)]
 pub struct MarketData<R: RestClient, W = ()> {
     rest: OkxRest<R>,
-    #[allow(dead_code)]
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
     ws: Option<W>,
-    #[allow(dead_code)]
-    testnet: bool,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
+    ws_url: String,
+    ws_config: WsConfig,
+    inst_type: OkxInstType,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
+
+    /// Stream from `ws_url` rather than the public feed
+    #[must_use]
+    pub fn with_ws_url(mut self, ws_url: String) -> Self {
+        self.ws_url = ws_url;
+        self
+    }
 }
 
 impl<R: RestClient + Clone, W> MarketData<R, W> {
     pub fn new(rest: &R, ws: Option<W>, testnet: bool) -> Self {
+        let endpoints = ExchangeId::Okx.endpoints();
         Self {
             rest: OkxRest::new(rest.clone()),
             ws,
-            testnet,
+            hub: SharedHub::new(),
+            ws_url: endpoints
+                .network(testnet)
+                .unwrap_or(endpoints.mainnet)
+                .ws
+                .to_string(),
+            ws_config: WsConfig::default(),
+            inst_type: OkxInstType::Spot,
         }
     }
+
+    /// Serve a different instrument family, e.g. `OkxInstType::Swap`
+    #[must_use]
+    pub const fn with_inst_type(mut self, inst_type: OkxInstType) -> Self {
+        self.inst_type = inst_type;
+        self
+    }
 }
 
 #[async_trait]
-impl<R: RestClient + Send + Sync, W: Send + Sync> MarketDataSource for MarketData<R, W> {
+impl<R: RestClient + Send + Sync, W: Send + Sync> RestMarketData for MarketData<R, W> {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
-        let okx_markets = self.rest.get_instruments("SPOT").await?;
+        let okx_markets = self.rest.get_instruments(self.inst_type.as_str()).await?;
 
+        // Suspended and pre-open instruments are kept so their status can be observed
         let mut markets = Vec::new();
         for okx_market in okx_markets {
-            // Only include live markets
-            if okx_market.state == "live" {
-                match conversions::convert_okx_market(okx_market) {
-                    Ok(market) => markets.push(market),
-                    Err(e) => {
-                        eprintln!("Failed to convert OKX market: {}", e);
-                    }
+            match conversions::convert_okx_market(okx_market) {
+                Ok(market) => markets.push(market),
+                Err(e) => {
+                    eprintln!("Failed to convert OKX market: {}", e);
                 }
             }
         }
@@ -50,32 +154,21 @@ impl<R: RestClient + Send + Sync, W: Send + Sync> MarketDataSource for MarketDat
         Ok(markets)
     }
 
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let time = self.rest.get_system_time().await?;
+        Ok(i64::try_from(time).unwrap_or(i64::MAX))
+    }
+
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         _start_time: Option<i64>,
         _end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
-        // Convert KlineInterval to OKX bar format
-        let bar = match interval {
-            KlineInterval::Minutes1 => "1m",
-            KlineInterval::Minutes3 => "3m",
-            KlineInterval::Minutes5 => "5m",
-            KlineInterval::Minutes15 => "15m",
-            KlineInterval::Minutes30 => "30m",
-            KlineInterval::Hours1 => "1H",
-            KlineInterval::Hours2 => "2H",
-            KlineInterval::Hours4 => "4H",
-            KlineInterval::Hours6 => "6H",
-            KlineInterval::Hours8 => "8H",
-            KlineInterval::Hours12 => "12H",
-            KlineInterval::Days1 => "1D",
-            KlineInterval::Days3 => "3D",
-            KlineInterval::Weeks1 => "1W",
-            KlineInterval::Months1 => "1M",
-        };
+        let symbol = self.inst_type.inst_id(&symbol)?;
+        let bar = conversions::kline_interval_to_okx_bar(interval);
 
         let okx_klines = self
             .rest
@@ -84,7 +177,7 @@ impl<R: RestClient + Send + Sync, W: Send + Sync> MarketDataSource for MarketDat
 
         let mut klines = Vec::new();
         for okx_kline in okx_klines {
-            match conversions::convert_okx_kline(okx_kline, &symbol) {
+            match conversions::convert_okx_kline(okx_kline, &symbol, interval) {
                 Ok(kline) => klines.push(kline),
                 Err(e) => {
                     eprintln!("Failed to convert OKX kline: {}", e);
@@ -94,21 +187,353 @@ impl<R: RestClient + Send + Sync, W: Send + Sync> MarketDataSource for MarketDat
 
         Ok(klines)
     }
+}
+
+impl<R: RestClient + Send + Sync, W: Send + Sync> MarketData<R, W> {
+    fn require_swap(&self) -> Result<(), ExchangeError> {
+        match self.inst_type {
+            OkxInstType::Swap => Ok(()),
+            OkxInstType::Spot => Err(ExchangeError::NotSupported(
+                "Funding rates are only available for OKX swaps".to_string(),
+            )),
+        }
+    }
+
+    /// Mark prices of every swap, keyed by instrument ID
+    async fn mark_prices(&self) -> Result<HashMap<String, Price>, ExchangeError> {
+        Ok(self
+            .rest
+            .get_mark_prices(self.inst_type.as_str())
+            .await?
+            .into_iter()
+            .filter_map(|mark| {
+                let price = conversion::try_string_to_price(&mark.mark_px).ok()?;
+                Some((mark.inst_id, price))
+            })
+            .collect())
+    }
+
+    async fn funding_rates_for(&self, inst_id: &str) -> Result<Vec<FundingRate>, ExchangeError> {
+        let (rates, mark_prices) =
+            tokio::try_join!(self.rest.get_funding_rate(inst_id), self.mark_prices())?;
+        rates
+            .iter()
+            .map(|rate| {
+                conversions::convert_okx_funding_rate(rate, mark_prices.get(&rate.inst_id).copied())
+                    .map_err(ExchangeError::ParseError)
+            })
+            .collect()
+    }
+}
+
+/// Deepest book served by `/api/v5/market/books`
+const MAX_BOOK_DEPTH: u32 = 400;
+
+#[async_trait]
+impl<R: RestClient + Send + Sync, W: Send + Sync> MarketSnapshotSource for MarketData<R, W> {
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let ticker = self
+            .rest
+            .get_ticker(&self.inst_type.inst_id(&symbol)?)
+            .await?;
+        let ticker = conversions::convert_okx_ticker(ticker).map_err(ExchangeError::ParseError)?;
+        Ok(Ticker { symbol, ..ticker })
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        // OKX defaults to a single level, so ask for the full book instead
+        let sz = depth.unwrap_or(MAX_BOOK_DEPTH).min(MAX_BOOK_DEPTH);
+        let inst_id = self.inst_type.inst_id(&symbol)?;
+        let book = self.rest.get_order_book(&inst_id, Some(sz)).await?;
+        Ok(conversions::convert_okx_order_book(book, &inst_id)
+            .map_err(ExchangeError::ParseError)?
+            .truncated(depth))
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let trades = self
+            .rest
+            .get_trades(&self.inst_type.inst_id(&symbol)?, limit)
+            .await?;
+        // Listed newest first
+        trades
+            .into_iter()
+            .rev()
+            .map(|trade| {
+                conversions::convert_okx_trade(trade)
+                    .map(|trade| Trade {
+                        symbol: symbol.clone(),
+                        ..trade
+                    })
+                    .map_err(ExchangeError::ParseError)
+            })
+            .collect()
+    }
+}
 
+#[async_trait]
+impl<R: RestClient + Send + Sync, W: Send + Sync> FundingRateSource for MarketData<R, W> {
+    async fn get_funding_rates(
+        &self,
+        symbols: Option<Vec<Symbol>>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let Some(symbols) = symbols else {
+            return self.get_all_funding_rates().await;
+        };
+        self.require_swap()?;
+
+        let mut rates = Vec::new();
+        for symbol in symbols {
+            rates.extend(
+                self.funding_rates_for(&self.inst_type.inst_id(&symbol)?)
+                    .await?,
+            );
+        }
+        Ok(rates)
+    }
+
+    async fn get_all_funding_rates(&self) -> Result<Vec<FundingRate>, ExchangeError> {
+        self.require_swap()?;
+        self.funding_rates_for("ANY").await
+    }
+
+    async fn get_funding_rate_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        self.require_swap()?;
+        let inst_id = self.inst_type.inst_id(&symbol)?;
+        let history = self
+            .rest
+            .get_funding_rate_history(&inst_id, start_time, end_time, limit)
+            .await?;
+
+        // OKX returns newest first
+        history
+            .iter()
+            .rev()
+            .map(|rate| {
+                conversions::convert_okx_funding_rate_history(rate)
+                    .map_err(ExchangeError::ParseError)
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Send + Sync, W: Send + Sync> StreamingMarketData for MarketData<R, W> {
     async fn subscribe_market_data(
         &self,
-        _symbols: Vec<String>,
-        _subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
     ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        // For now, return an error if WebSocket is not available
-        // TODO: Implement WebSocket subscription logic when WsSession is available
-        Err(ExchangeError::NotSupported(
-            "WebSocket subscriptions not yet implemented for OKX".to_string(),
-        ))
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(self.ws_url.clone(), "okx".to_string(), OkxCodec::new())
+                        .with_config(self.ws_config.clone())
+                },
+                OkxRouter {
+                    inst_type: self.inst_type,
+                },
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
     }
 
     fn get_websocket_url(&self) -> String {
-        "wss://ws.okx.com:8443/ws/v5/public".to_string()
+        self.ws_url.clone()
+    }
+
+    fn supports_market_status(&self) -> bool {
+        true
+    }
+
+    /// Stream the `instruments` channel of the connector's instrument family
+    ///
+    /// OKX pushes every instrument on subscribing, then each one whose `state` changes.
+    async fn subscribe_market_status(
+        &self,
+    ) -> Result<mpsc::Receiver<MarketStatusUpdate>, ExchangeError> {
+        let stream = okx_instruments_stream(self.inst_type);
+        let ws_session =
+            TungsteniteWs::new(self.ws_url.clone(), "okx".to_string(), OkxCodec::new())
+                .with_config(self.ws_config.clone());
+        let mut reconnect_ws = ReconnectWs::new(ws_session)
+            .with_auto_resubscribe(true)
+            .with_max_reconnect_attempts(u32::MAX);
+        reconnect_ws.connect().await?;
+        reconnect_ws.subscribe(&[stream]).await?;
+
+        let (tx, rx) = mpsc::channel(1000);
+        tokio::spawn(async move {
+            while let Some(result) = reconnect_ws.next_message().await {
+                match result {
+                    Ok(OkxMessage::MarketStatus(updates)) => {
+                        for update in updates {
+                            if tx.send(update).await.is_err() {
+                                return; // Receiver dropped
+                            }
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!(error = %e, "OKX instruments channel error"),
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::kernel::rest::ReqwestRest;
+    use crate::core::types::{OrderBookEntry, Quantity};
+    use futures_util::{SinkExt, StreamExt};
+    use rust_decimal::Decimal;
+    use serde_json::{json, Value};
+    use std::str::FromStr;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    /// A one-level `books` push, checksummed as OKX would unless `checksum` is given
+    fn book(action: &str, bid: &str, checksum: Option<i64>) -> Message {
+        let entry = |price: &str| OrderBookEntry {
+            price: Price::new(Decimal::from_str(price).unwrap()),
+            quantity: Quantity::new(Decimal::ONE),
+        };
+        let held = OrderBook {
+            symbol: Symbol::default(),
+            bids: vec![entry(bid)],
+            asks: vec![entry("101")],
+            last_update_id: 0,
+        };
+        let checksum =
+            checksum.unwrap_or_else(|| i64::from(i32::from_be_bytes(held.crc32(25).to_be_bytes())));
+        Message::Text(
+            json!({
+                "arg": {"channel": "books", "instId": "BTC-USDT"},
+                "action": action,
+                "data": [{
+                    "bids": [[bid, "1", "0", "1"]],
+                    "asks": [["101", "1", "0", "1"]],
+                    "ts": "1597026383085",
+                    "checksum": checksum,
+                }],
+            })
+            .to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_checksum_mismatch_resubscribes_for_a_fresh_snapshot() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (sent_tx, mut sent_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let mut subscriptions = 0;
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: Value = serde_json::from_str(&text).unwrap();
+                let op = request["op"].as_str().unwrap_or_default().to_string();
+                let _ = sent_tx.send(format!("{} {}", op, request["args"][0]["instId"]));
+                if op != "subscribe" {
+                    continue;
+                }
+                let ack = json!({"event": "subscribe", "arg": request["args"][0], "connId": "c-1"});
+                ws.send(Message::Text(ack.to_string())).await.unwrap();
+                subscriptions += 1;
+                let pushes = if subscriptions == 1 {
+                    vec![
+                        book("snapshot", "100", None),
+                        book("update", "100.5", Some(0)),
+                    ]
+                } else {
+                    vec![book("snapshot", "100.2", None)]
+                };
+                for push in pushes {
+                    ws.send(push).await.unwrap();
+                }
+            }
+        });
+
+        let rest = ReqwestRest::new(url.clone(), "okx".to_string(), None).unwrap();
+        let market_data = MarketData::<_, ()>::new(&rest, None, false).with_ws_url(url);
+        let mut rx = market_data
+            .subscribe_market_data(
+                vec![Symbol::new("BTC", "USDT").unwrap()],
+                vec![SubscriptionType::OrderBook {
+                    depth: None,
+                    mode: OrderBookMode::Snapshot,
+                }],
+                None,
+            )
+            .await
+            .unwrap();
+
+        let mut bids = Vec::new();
+        for _ in 0..2 {
+            let Some(MarketDataType::OrderBook(book)) = rx.recv().await else {
+                panic!("expected an order book update");
+            };
+            bids.push(book.bids[0].price.to_string());
+        }
+        assert_eq!(bids, ["100", "100.2"]);
+        assert_eq!(sent_rx.recv().await.unwrap(), r#"subscribe "BTC-USDT""#);
+        assert_eq!(sent_rx.recv().await.unwrap(), r#"unsubscribe "BTC-USDT""#);
+        assert_eq!(sent_rx.recv().await.unwrap(), r#"subscribe "BTC-USDT""#);
+    }
+
+    #[tokio::test]
+    async fn test_instrument_status_pushes_reach_the_market_watcher() {
+        use crate::utils::market_watcher::{watch_market_status, MarketEvent};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let Some(Ok(Message::Text(text))) = ws.next().await else {
+                return;
+            };
+            let request: Value = serde_json::from_str(&text).unwrap();
+            let arg = request["args"][0].clone();
+            let ack = json!({"event": "subscribe", "arg": arg, "connId": "c-1"});
+            ws.send(Message::Text(ack.to_string())).await.unwrap();
+            for state in ["live", "suspend"] {
+                let push = json!({"arg": arg, "data": [{"instId": "BTC-USDT", "state": state}]});
+                ws.send(Message::Text(push.to_string())).await.unwrap();
+            }
+            while ws.next().await.is_some() {}
+        });
+
+        let rest = ReqwestRest::new(url.clone(), "okx".to_string(), None).unwrap();
+        let market_data = MarketData::<_, ()>::new(&rest, None, false).with_ws_url(url);
+        assert!(market_data.supports_market_status());
+        let mut events = watch_market_status(&market_data).await.unwrap();
+
+        assert_eq!(
+            events.recv().await.unwrap(),
+            MarketEvent::MarketHalted {
+                symbol: Symbol::new("BTC", "USDT").unwrap(),
+                status: crate::core::types::MarketStatus::Halted,
+            }
+        );
     }
 }
@@ -1,12 +1,22 @@
 use crate::core::errors::ExchangeError;
-use crate::core::traits::{AccountInfo, MarketDataSource, OrderPlacer};
+use crate::core::traits::{
+    AccountInfo, AccountSummarySource, AssetTransfer, BorrowRateSource, DiagnosticsSource,
+    FundingRateSource, MarketSnapshotSource, OrderHistorySource, OrderPlacer, OrderQuery,
+    PositionManager, RestMarketData, StreamingMarketData,
+};
 use crate::core::types::{
-    Balance, Kline, KlineInterval, Market, MarketDataType, OrderRequest, OrderResponse, Position,
-    SubscriptionType, WebSocketConfig,
+    AccountSummary, AccountType, Balance, BorrowRate, FundingRate, InterestRecord, Kline,
+    KlineInterval, LeverageBracket, MarginMode, Market, MarketDataType, MarketStatusUpdate, Order,
+    OrderBook, OrderPage, OrderRequest, OrderResponse, Position, SubscriptionType, Symbol, Ticker,
+    Trade, WebSocketConfig,
+};
+use crate::core::{
+    config::ExchangeConfig, kernel::diagnostics, kernel::Diagnostics, kernel::RestClient,
+    kernel::WsSession,
 };
-use crate::core::{config::ExchangeConfig, kernel::RestClient, kernel::WsSession};
-use crate::exchanges::okx::codec::OkxCodec;
+use crate::exchanges::okx::{codec::OkxCodec, types::OkxInstType};
 use async_trait::async_trait;
+use rust_decimal::Decimal;
 use tokio::sync::mpsc;
 
 pub mod account;
@@ -29,7 +39,8 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<OkxCodec> + Send + Sync>
     /// Create a new OKX connector with WebSocket support
     pub fn new_with_ws(rest: R, ws: W, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::<R, W>::new(&rest, Some(ws), config.testnet),
+            market: MarketData::<R, W>::new(&rest, Some(ws), config.testnet)
+                .with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
         }
@@ -40,16 +51,33 @@ impl<R: RestClient + Clone + Send + Sync> OkxConnector<R, ()> {
     /// Create a new OKX connector without WebSocket support
     pub fn new_without_ws(rest: R, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::<R, ()>::new(&rest, None, config.testnet),
+            market: MarketData::<R, ()>::new(&rest, None, config.testnet)
+                .with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
         }
     }
 }
 
-/// Implement AccountInfo trait for the OKX connector
+impl<R: RestClient + Clone, W> OkxConnector<R, W> {
+    /// Target a different instrument family, e.g. `OkxInstType::Swap`
+    #[must_use]
+    pub fn with_inst_type(self, inst_type: OkxInstType) -> Self {
+        Self {
+            market: self.market.with_inst_type(inst_type),
+            trading: self.trading.with_inst_type(inst_type),
+            account: self.account.with_inst_type(inst_type),
+        }
+    }
+}
+
+/// Implement `AccountInfo` trait for the OKX connector
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo for OkxConnector<R, W> {
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         self.account.get_account_balance().await
     }
@@ -59,31 +87,87 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo for OkxCon
     }
 }
 
-/// Implement MarketDataSource trait for the OKX connector
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketDataSource for OkxConnector<R, W> {
-    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
-        self.market.get_markets().await
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountSummarySource
+    for OkxConnector<R, W>
+{
+    async fn get_account_summary(&self) -> Result<AccountSummary, ExchangeError> {
+        self.account.get_account_summary().await
     }
+}
 
-    async fn subscribe_market_data(
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AssetTransfer for OkxConnector<R, W> {
+    async fn transfer(
         &self,
-        symbols: Vec<String>,
-        subscription_types: Vec<SubscriptionType>,
-        config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        asset: &str,
+        amount: Decimal,
+        from: AccountType,
+        to: AccountType,
+    ) -> Result<String, ExchangeError> {
+        self.account.transfer(asset, amount, from, to).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> BorrowRateSource for OkxConnector<R, W> {
+    async fn get_borrow_rates(&self, asset: &str) -> Result<Vec<BorrowRate>, ExchangeError> {
+        self.account.get_borrow_rates(asset).await
+    }
+
+    async fn get_interest_history(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<InterestRecord>, ExchangeError> {
+        self.account
+            .get_interest_history(asset, start_time, end_time, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource for OkxConnector<R, W> {
+    async fn get_funding_rates(
+        &self,
+        symbols: Option<Vec<Symbol>>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
+        self.market.get_funding_rates(symbols).await
+    }
+
+    async fn get_all_funding_rates(&self) -> Result<Vec<FundingRate>, ExchangeError> {
+        self.market.get_all_funding_rates().await
+    }
+
+    async fn get_funding_rate_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<FundingRate>, ExchangeError> {
         self.market
-            .subscribe_market_data(symbols, subscription_types, config)
+            .get_funding_rate_history(symbol, start_time, end_time, limit)
             .await
     }
+}
 
-    fn get_websocket_url(&self) -> String {
-        self.market.get_websocket_url()
+/// Implement the market data traits for the OKX connector
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> RestMarketData for OkxConnector<R, W> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.market.get_markets().await
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
     }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
@@ -95,14 +179,149 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketDataSource for O
     }
 }
 
-/// Implement OrderPlacer trait for the OKX connector
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for OkxConnector<R, W>
+{
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        self.market.get_ticker(symbol).await
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        self.market.get_order_book_snapshot(symbol, depth).await
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        self.market.get_recent_trades(symbol, limit).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> StreamingMarketData
+    for OkxConnector<R, W>
+{
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.market
+            .subscribe_market_data(symbols, subscription_types, config)
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.market.get_websocket_url()
+    }
+
+    fn supports_market_status(&self) -> bool {
+        self.market.supports_market_status()
+    }
+
+    async fn subscribe_market_status(
+        &self,
+    ) -> Result<mpsc::Receiver<MarketStatusUpdate>, ExchangeError> {
+        self.market.subscribe_market_status().await
+    }
+}
+
+/// Implement `OrderPlacer` trait for the OKX connector
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderPlacer for OkxConnector<R, W> {
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
         self.trading.place_order(order).await
     }
 
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn place_orders(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Vec<Result<OrderResponse, ExchangeError>> {
+        self.trading.place_orders(orders).await
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
         self.trading.cancel_order(symbol, order_id).await
     }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+}
+
+/// Implement `OrderQuery` trait for the OKX connector
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderQuery for OkxConnector<R, W> {
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        self.trading.get_open_orders(symbol).await
+    }
+
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        self.trading.get_order(symbol, order_id).await
+    }
+}
+
+/// Implement `OrderHistorySource` trait for the OKX connector
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderHistorySource
+    for OkxConnector<R, W>
+{
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        self.trading
+            .get_order_history(symbol, start_time, end_time, cursor)
+            .await
+    }
+}
+
+/// Implement `PositionManager` trait for the OKX connector
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> PositionManager for OkxConnector<R, W> {
+    async fn set_leverage(&self, symbol: Symbol, leverage: u32) -> Result<(), ExchangeError> {
+        self.trading.set_leverage(symbol, leverage).await
+    }
+
+    async fn set_margin_mode(&self, symbol: Symbol, mode: MarginMode) -> Result<(), ExchangeError> {
+        self.trading.set_margin_mode(symbol, mode).await
+    }
+
+    async fn get_leverage_brackets(
+        &self,
+        symbol: Symbol,
+    ) -> Result<Vec<LeverageBracket>, ExchangeError> {
+        self.trading.get_leverage_brackets(symbol).await
+    }
+
+    async fn close_position(&self, symbol: Symbol) -> Result<Option<OrderResponse>, ExchangeError> {
+        self.trading.close_position(symbol).await
+    }
+}
+
+impl<R: RestClient, W> DiagnosticsSource for OkxConnector<R, W> {
+    fn diagnostics(&self) -> Diagnostics {
+        diagnostics("okx")
+    }
 }
@@ -1,86 +1,224 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
-use crate::core::traits::OrderPlacer;
-use crate::core::types::{OrderRequest, OrderResponse, OrderSide};
-use crate::exchanges::okx::{conversions, rest::OkxRest, types::OkxOrderRequest};
+use crate::core::traits::{OrderHistorySource, OrderPlacer, OrderQuery, PositionManager};
+use crate::core::types::{
+    LeverageBracket, MarginMode, Order, OrderPage, OrderRequest, OrderResponse, OrderSide, Symbol,
+};
+use crate::exchanges::okx::{
+    conversions,
+    rest::OkxRest,
+    types::{OkxInstType, OkxOrderRequest, OkxOrderResponse},
+};
 use async_trait::async_trait;
+use futures_util::future::join_all;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
 /// OKX trading implementation
 #[derive(Debug)]
 pub struct Trading<R: RestClient> {
     rest: OkxRest<R>,
+    inst_type: OkxInstType,
+    /// Margin mode chosen per swap instrument; OKX takes it on every order
+    margin_modes: Mutex<HashMap<String, MarginMode>>,
 }
 
 impl<R: RestClient + Clone> Trading<R> {
     pub fn new(rest: &R) -> Self {
         Self {
             rest: OkxRest::new(rest.clone()),
+            inst_type: OkxInstType::Spot,
+            margin_modes: Mutex::new(HashMap::new()),
         }
     }
+
+    /// Trade a different instrument family; swap order quantities are in contracts
+    #[must_use]
+    pub const fn with_inst_type(mut self, inst_type: OkxInstType) -> Self {
+        self.inst_type = inst_type;
+        self
+    }
 }
 
-#[async_trait]
-impl<R: RestClient + Send + Sync> OrderPlacer for Trading<R> {
-    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
-        // Convert core order request to OKX format
-        let inst_id = conversions::convert_symbol_to_okx_inst_id(&order.symbol);
-        let side = conversions::convert_order_side_to_okx(order.side.clone());
-        let ord_type = conversions::convert_order_type_to_okx(
-            order.order_type.clone(),
-            order.time_in_force.clone(),
-        );
+impl<R: RestClient> Trading<R> {
+    /// Order `tdMode` of `inst_id`: cash on spot, the chosen margin mode on swaps
+    fn td_mode(&self, inst_id: &str) -> &'static str {
+        if self.inst_type == OkxInstType::Spot {
+            return self.inst_type.td_mode();
+        }
+        let margin_modes = self.margin_modes.lock().unwrap_or_else(|e| e.into_inner());
+        match margin_modes.get(inst_id) {
+            Some(MarginMode::Isolated) => "isolated",
+            Some(MarginMode::Cross) => "cross",
+            None => self.inst_type.td_mode(),
+        }
+    }
+
+    fn require_swap(&self) -> Result<(), ExchangeError> {
+        match self.inst_type {
+            OkxInstType::Swap => Ok(()),
+            OkxInstType::Spot => Err(ExchangeError::NotSupported(
+                "Leverage and margin mode apply to OKX swaps only".to_string(),
+            )),
+        }
+    }
+}
+
+/// OKX caps `batch-orders` at twenty orders per request
+const MAX_BATCH_ORDERS: usize = 20;
+
+fn build_okx_order(
+    order: &OrderRequest,
+    inst_type: OkxInstType,
+    inst_id: String,
+    td_mode: &str,
+) -> OkxOrderRequest {
+    // Convert core order request to OKX format
+    let side = conversions::convert_order_side_to_okx(order.side.clone());
+    let ord_type = conversions::convert_order_type_to_okx(
+        order.order_type.clone(),
+        order.time_in_force.clone(),
+    );
 
-        // Build OKX order request
-        let mut okx_order = OkxOrderRequest {
-            inst_id,
-            td_mode: "cash".to_string(), // For spot trading
-            side,
-            ord_type: ord_type.clone(),
-            sz: order.quantity.to_string(),
-            px: None,
-            cl_ord_id: None,
-            tag: None,
-            tgt_ccy: None,
-            ban_amend: None,
+    // Build OKX order request
+    let mut okx_order = OkxOrderRequest {
+        inst_id,
+        td_mode: td_mode.to_string(),
+        side,
+        ord_type: ord_type.clone(),
+        sz: order.quantity.to_string(),
+        px: None,
+        cl_ord_id: order.client_order_id.clone(),
+        tag: None,
+        tgt_ccy: None,
+        ban_amend: None,
+        // OKX has no whole-position flag on orders, so a close is sent reduce-only
+        reduce_only: (order.reduce_only == Some(true) || order.close_position == Some(true))
+            .then_some(true),
+    };
+
+    // Set price for limit orders
+    if let Some(price) = order.price {
+        if ord_type != "market" {
+            okx_order.px = Some(price.to_string());
+        }
+    }
+
+    // Set target currency for spot market orders; swaps are always sized in contracts
+    if ord_type == "market" && inst_type == OkxInstType::Spot {
+        okx_order.tgt_ccy = match order.side {
+            OrderSide::Buy => Some("quote_ccy".to_string()),
+            OrderSide::Sell => Some("base_ccy".to_string()),
         };
+    }
 
-        // Set price for limit orders
-        if let Some(price) = order.price {
-            if ord_type != "market" {
-                okx_order.px = Some(price.to_string());
+    okx_order
+}
+
+fn convert_order_response(okx_response: OkxOrderResponse, order: OrderRequest) -> OrderResponse {
+    let extensions = serde_json::json!({
+        "ordId": okx_response.ord_id,
+        "tag": okx_response.tag,
+        "sCode": okx_response.s_code,
+        "sMsg": okx_response.s_msg,
+    });
+    OrderResponse {
+        order_id: okx_response.ord_id,
+        client_order_id: okx_response.cl_ord_id.unwrap_or_default(),
+        symbol: order.symbol,
+        side: order.side,
+        order_type: order.order_type,
+        quantity: order.quantity,
+        price: order.price,
+        status: if okx_response.s_code == "0" {
+            "NEW".to_string()
+        } else {
+            "REJECTED".to_string()
+        },
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        extensions: Some(extensions),
+    }
+}
+
+impl<R: RestClient + Send + Sync> Trading<R> {
+    async fn place_chunk(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Vec<Result<OrderResponse, ExchangeError>> {
+        // A symbol the venue cannot spell fails the chunk before anything is sent
+        let batch = orders
+            .iter()
+            .map(|order| {
+                let inst_id = self.inst_type.inst_id(&order.symbol)?;
+                let td_mode = self.td_mode(&inst_id);
+                Ok(build_okx_order(order, self.inst_type, inst_id, td_mode))
+            })
+            .collect::<Result<Vec<_>, ExchangeError>>();
+        let placed = match batch {
+            Ok(batch) => self.rest.place_batch_orders(&batch).await,
+            Err(e) => Err(e),
+        };
+        let message = match placed {
+            Ok(results) if results.len() == orders.len() => {
+                return results
+                    .into_iter()
+                    .zip(orders)
+                    .map(|(result, order)| {
+                        result.map(|response| convert_order_response(response, order))
+                    })
+                    .collect();
             }
-        }
+            Ok(results) => format!(
+                "Batch reply has {} entries for {} orders",
+                results.len(),
+                orders.len()
+            ),
+            // The whole request failed, so every order in it did
+            Err(e) => e.to_string(),
+        };
+        orders
+            .iter()
+            .map(|_| Err(ExchangeError::Other(message.clone())))
+            .collect()
+    }
+}
 
-        // Set target currency for market orders
-        if ord_type == "market" {
-            okx_order.tgt_ccy = match order.side {
-                OrderSide::Buy => Some("quote_ccy".to_string()),
-                OrderSide::Sell => Some("base_ccy".to_string()),
-            };
+#[async_trait]
+impl<R: RestClient + Send + Sync> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let inst_id = self.inst_type.inst_id(&order.symbol)?;
+        let td_mode = self.td_mode(&inst_id);
+        let okx_response = self
+            .rest
+            .place_order(&build_okx_order(&order, self.inst_type, inst_id, td_mode))
+            .await?;
+        Ok(convert_order_response(okx_response, order))
+    }
+
+    async fn place_orders(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Vec<Result<OrderResponse, ExchangeError>> {
+        let mut chunks = Vec::new();
+        let mut orders = orders.into_iter().peekable();
+        while orders.peek().is_some() {
+            chunks.push(orders.by_ref().take(MAX_BATCH_ORDERS).collect::<Vec<_>>());
         }
 
-        // Place the order
-        let okx_response = self.rest.place_order(&okx_order).await?;
-
-        // Convert response to core format
-        Ok(OrderResponse {
-            order_id: okx_response.ord_id,
-            client_order_id: okx_response.cl_ord_id.unwrap_or_default(),
-            symbol: order.symbol,
-            side: order.side,
-            order_type: order.order_type,
-            quantity: order.quantity,
-            price: order.price,
-            status: if okx_response.s_code == "0" {
-                "NEW".to_string()
-            } else {
-                "REJECTED".to_string()
-            },
-            timestamp: chrono::Utc::now().timestamp_millis(),
-        })
+        // Chunks go out concurrently; join_all keeps them in request order
+        join_all(chunks.into_iter().map(|chunk| self.place_chunk(chunk)))
+            .await
+            .into_iter()
+            .flatten()
+            .collect()
     }
 
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let symbol = self.inst_type.inst_id(&symbol)?;
         // Cancel the order
         let _okx_response = self
             .rest
@@ -90,4 +228,183 @@ impl<R: RestClient + Send + Sync> OrderPlacer for Trading<R> {
         // Return success if no error occurred
         Ok(())
     }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        let symbol = self.inst_type.inst_id(&symbol)?;
+        self.rest
+            .cancel_order(&symbol, None, Some(&client_order_id))
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> OrderQuery for Trading<R> {
+    async fn get_open_orders(&self, symbol: Option<Symbol>) -> Result<Vec<Order>, ExchangeError> {
+        let inst_id = symbol.map(|s| self.inst_type.inst_id(&s)).transpose()?;
+        self.rest
+            .get_pending_orders(Some(self.inst_type.as_str()), inst_id.as_deref())
+            .await?
+            .iter()
+            .map(|order| conversions::convert_okx_order(order).map_err(ExchangeError::ParseError))
+            .collect()
+    }
+
+    async fn get_order(&self, symbol: Symbol, order_id: String) -> Result<Order, ExchangeError> {
+        let inst_id = self.inst_type.inst_id(&symbol)?;
+        let order = self.rest.get_order(&inst_id, Some(&order_id), None).await?;
+        conversions::convert_okx_order(&order).map_err(ExchangeError::ParseError)
+    }
+}
+
+/// Largest page `orders-history-archive` returns
+const ORDER_HISTORY_LIMIT: u32 = 100;
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> OrderHistorySource for Trading<R> {
+    /// Pages run newest first over the last three months; the cursor is the last order id seen
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        let inst_id = self.inst_type.inst_id(&symbol)?;
+        let raw = self
+            .rest
+            .get_orders_history(
+                self.inst_type.as_str(),
+                &inst_id,
+                start_time,
+                end_time,
+                cursor.as_deref(),
+                ORDER_HISTORY_LIMIT,
+            )
+            .await?;
+
+        let next_cursor = (raw.len() == ORDER_HISTORY_LIMIT as usize)
+            .then(|| raw.last().map(|order| order.ord_id.clone()))
+            .flatten();
+        let orders = raw
+            .iter()
+            .map(|order| conversions::convert_okx_order(order).map_err(ExchangeError::ParseError))
+            .collect::<Result<_, _>>()?;
+        Ok(OrderPage {
+            orders,
+            next_cursor,
+        })
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Send + Sync> PositionManager for Trading<R> {
+    async fn set_leverage(&self, symbol: Symbol, leverage: u32) -> Result<(), ExchangeError> {
+        self.require_swap()?;
+        let inst_id = self.inst_type.inst_id(&symbol)?;
+        // OKX keeps a separate leverage per margin mode
+        let td_mode = self.td_mode(&inst_id);
+        self.rest.set_leverage(&inst_id, leverage, td_mode).await?;
+        Ok(())
+    }
+
+    async fn set_margin_mode(&self, symbol: Symbol, mode: MarginMode) -> Result<(), ExchangeError> {
+        self.require_swap()?;
+        // The margin mode is an order parameter on OKX, so it only needs remembering
+        let inst_id = self.inst_type.inst_id(&symbol)?;
+        self.margin_modes
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(inst_id, mode);
+        Ok(())
+    }
+
+    async fn get_leverage_brackets(
+        &self,
+        symbol: Symbol,
+    ) -> Result<Vec<LeverageBracket>, ExchangeError> {
+        self.require_swap()?;
+        let inst_id = self.inst_type.inst_id(&symbol)?;
+        self.rest
+            .get_position_tiers(
+                self.inst_type.as_str(),
+                self.td_mode(&inst_id),
+                &symbol.joined("-"),
+                &inst_id,
+            )
+            .await?
+            .iter()
+            .map(|tier| {
+                conversions::convert_okx_position_tier(tier).map_err(ExchangeError::ParseError)
+            })
+            .collect()
+    }
+
+    async fn close_position(&self, symbol: Symbol) -> Result<Option<OrderResponse>, ExchangeError> {
+        self.require_swap()?;
+        let inst_id = self.inst_type.inst_id(&symbol)?;
+        let positions = self.rest.get_positions(self.inst_type.as_str()).await?;
+        for position in positions
+            .iter()
+            .filter(|position| position.inst_id == inst_id)
+        {
+            // Without a market the amount stays in contracts, which is what orders take
+            let position = conversions::convert_okx_position(position, None)
+                .map_err(ExchangeError::ParseError)?;
+            if let Some(order) = position.close_order() {
+                let order = OrderRequest {
+                    symbol: symbol.clone(),
+                    ..order
+                };
+                return self.place_order(order).await.map(Some);
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::okx::builder::OkxBuilder;
+
+    #[tokio::test]
+    async fn test_swap_orders_use_chosen_margin_mode() {
+        let connector = OkxBuilder::new()
+            .with_inst_type(OkxInstType::Swap)
+            .build_rest_only()
+            .unwrap();
+        let trading = connector.trading;
+        let symbol = Symbol::new("BTC", "USDT").unwrap();
+        assert_eq!(trading.td_mode("BTC-USDT-SWAP"), "cross");
+
+        trading
+            .set_margin_mode(symbol.clone(), MarginMode::Isolated)
+            .await
+            .unwrap();
+        let order = OrderRequest {
+            symbol,
+            side: OrderSide::Buy,
+            order_type: crate::core::types::OrderType::Limit,
+            quantity: crate::core::types::conversion::string_to_quantity("1"),
+            price: Some(crate::core::types::conversion::string_to_price("50000")),
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        };
+        let okx_order = build_okx_order(
+            &order,
+            OkxInstType::Swap,
+            "BTC-USDT-SWAP".to_string(),
+            trading.td_mode("BTC-USDT-SWAP"),
+        );
+        assert_eq!(okx_order.inst_id, "BTC-USDT-SWAP");
+        assert_eq!(okx_order.td_mode, "isolated");
+    }
 }
@@ -1,37 +1,63 @@
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
 use crate::core::types::{
-    conversion, Kline, Market, OrderBook, OrderBookEntry, OrderSide, OrderType, Price, Symbol,
-    Ticker, TimeInForce, Trade,
+    conversion, AccountSummary, AccountType, BorrowRate, ContractStyle, FundingRate,
+    InterestRecord, Kline, KlineInterval, LeverageBracket, MarginMode, Market, MarketStatus,
+    MarketStatusUpdate, Order, OrderBook, OrderBookEntry, OrderSide, OrderStatus, OrderType,
+    Position, PositionSide, Price, Quantity, Symbol, Ticker, TimeInForce, Trade,
 };
+use crate::exchanges::factory::ExchangeId;
 use crate::exchanges::okx::types as okx_types;
 use rust_decimal::Decimal;
 use serde_json::Value;
 
+/// OKX symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::Okx.symbol_convention();
+
 /// Convert OKX market to core market type
 pub fn convert_okx_market(okx_market: okx_types::OkxMarket) -> Result<Market, String> {
-    // Parse symbol from inst_id (e.g., "BTC-USDT")
-    let symbol = conversion::string_to_symbol(&okx_market.inst_id);
+    // Parse symbol from inst_id (e.g., "BTC-USDT" or "BTC-USDT-SWAP")
+    let symbol = convert_okx_inst_id_to_symbol(&okx_market.inst_id);
+
+    let min_size =
+        conversion::try_string_to_quantity(&okx_market.min_sz).map_err(|e| e.to_string())?;
 
-    // Convert tick size and lot size to appropriate types
-    let _tick_size = conversion::string_to_price(&okx_market.tick_sz);
-    let _lot_size = conversion::string_to_quantity(&okx_market.lot_sz);
-    let min_size = conversion::string_to_quantity(&okx_market.min_sz);
+    // Derivatives carry a contract type (linear/inverse) and a contract value
+    let contract_style = match (okx_market.inst_type.as_str(), okx_market.ct_type.as_deref()) {
+        ("SPOT" | "MARGIN", _) => ContractStyle::Spot,
+        (_, Some("inverse")) => ContractStyle::Inverse,
+        _ => ContractStyle::Linear,
+    };
+    let contract_size = okx_market
+        .ct_val
+        .as_deref()
+        .and_then(|v| v.parse::<Decimal>().ok());
 
     Ok(Market {
         symbol,
-        status: okx_market.state,
+        status: MarketStatus::from_venue_str(&okx_market.state),
         base_precision: 8, // OKX doesn't provide precision directly, using default
         quote_precision: 8,
         min_qty: Some(min_size),
         max_qty: None,   // OKX doesn't specify max quantity directly
         min_price: None, // Not provided by OKX
         max_price: None, // OKX doesn't specify max price directly
+        contract_style,
+        contract_size,
+        // The instrument id keeps the `-SWAP` suffix the symbol drops
+        extensions: Some(serde_json::json!({
+            "instId": okx_market.inst_id,
+            "tickSz": okx_market.tick_sz,
+            "lotSz": okx_market.lot_sz,
+        })),
     })
 }
 
 /// Convert OKX ticker to core ticker type
 pub fn convert_okx_ticker(okx_ticker: okx_types::OkxTicker) -> Result<Ticker, String> {
-    let symbol = conversion::string_to_symbol(&okx_ticker.inst_id);
-    let last_price = conversion::string_to_price(&okx_ticker.last);
+    let symbol = convert_okx_inst_id_to_symbol(&okx_ticker.inst_id);
+    let last_price =
+        conversion::try_string_to_price(&okx_ticker.last).map_err(|e| e.to_string())?;
     // Note: bid/ask prices and quantities are not part of the core Ticker struct
 
     // Parse timestamp
@@ -41,7 +67,8 @@ pub fn convert_okx_ticker(okx_ticker: okx_types::OkxTicker) -> Result<Ticker, St
         .map_err(|e| format!("Failed to parse timestamp: {}", e))?;
 
     // Calculate 24h change
-    let open_24h = conversion::string_to_price(&okx_ticker.open_24h);
+    let open_24h =
+        conversion::try_string_to_price(&okx_ticker.open_24h).map_err(|e| e.to_string())?;
     let price_change_24h = Price::new(last_price.value() - open_24h.value());
     let price_change_percent_24h = if open_24h.value() > Decimal::ZERO {
         (price_change_24h.value() / open_24h.value()) * Decimal::from(100)
@@ -54,10 +81,13 @@ pub fn convert_okx_ticker(okx_ticker: okx_types::OkxTicker) -> Result<Ticker, St
         price: last_price,
         price_change: price_change_24h,
         price_change_percent: price_change_percent_24h,
-        high_price: conversion::string_to_price(&okx_ticker.high_24h),
-        low_price: conversion::string_to_price(&okx_ticker.low_24h),
-        volume: conversion::string_to_volume(&okx_ticker.vol_24h),
-        quote_volume: conversion::string_to_volume(&okx_ticker.vol_ccy_24h),
+        high_price: conversion::try_string_to_price(&okx_ticker.high_24h)
+            .map_err(|e| e.to_string())?,
+        low_price: conversion::try_string_to_price(&okx_ticker.low_24h)
+            .map_err(|e| e.to_string())?,
+        volume: conversion::try_string_to_volume(&okx_ticker.vol_24h).map_err(|e| e.to_string())?,
+        quote_volume: conversion::try_string_to_volume(&okx_ticker.vol_ccy_24h)
+            .map_err(|e| e.to_string())?,
         open_time: timestamp.try_into().unwrap_or(i64::MAX),
         close_time: timestamp.try_into().unwrap_or(i64::MAX),
         count: 0, // Default value
@@ -69,7 +99,7 @@ pub fn convert_okx_order_book(
     okx_order_book: okx_types::OkxOrderBook,
     symbol: &str,
 ) -> Result<OrderBook, String> {
-    let symbol = conversion::string_to_symbol(symbol);
+    let symbol = convert_okx_inst_id_to_symbol(symbol);
 
     // Parse timestamp
     let timestamp = okx_order_book
@@ -81,8 +111,10 @@ pub fn convert_okx_order_book(
     let mut bids = Vec::new();
     for bid_array in okx_order_book.bids {
         if bid_array.len() >= 2 {
-            let price = conversion::string_to_price(&bid_array[0]);
-            let quantity = conversion::string_to_quantity(&bid_array[1]);
+            let price =
+                conversion::try_string_to_price(&bid_array[0]).map_err(|e| e.to_string())?;
+            let quantity =
+                conversion::try_string_to_quantity(&bid_array[1]).map_err(|e| e.to_string())?;
             bids.push(OrderBookEntry { price, quantity });
         }
     }
@@ -90,8 +122,10 @@ pub fn convert_okx_order_book(
     let mut asks = Vec::new();
     for ask_array in okx_order_book.asks {
         if ask_array.len() >= 2 {
-            let price = conversion::string_to_price(&ask_array[0]);
-            let quantity = conversion::string_to_quantity(&ask_array[1]);
+            let price =
+                conversion::try_string_to_price(&ask_array[0]).map_err(|e| e.to_string())?;
+            let quantity =
+                conversion::try_string_to_quantity(&ask_array[1]).map_err(|e| e.to_string())?;
             asks.push(OrderBookEntry { price, quantity });
         }
     }
@@ -106,9 +140,9 @@ pub fn convert_okx_order_book(
 
 /// Convert OKX trade to core trade type
 pub fn convert_okx_trade(okx_trade: okx_types::OkxTrade) -> Result<Trade, String> {
-    let symbol = conversion::string_to_symbol(&okx_trade.inst_id);
-    let price = conversion::string_to_price(&okx_trade.px);
-    let quantity = conversion::string_to_quantity(&okx_trade.sz);
+    let symbol = convert_okx_inst_id_to_symbol(&okx_trade.inst_id);
+    let price = conversion::try_string_to_price(&okx_trade.px).map_err(|e| e.to_string())?;
+    let quantity = conversion::try_string_to_quantity(&okx_trade.sz).map_err(|e| e.to_string())?;
 
     // Parse timestamp
     let timestamp = okx_trade
@@ -134,30 +168,99 @@ pub fn convert_okx_trade(okx_trade: okx_types::OkxTrade) -> Result<Trade, String
 }
 
 /// Convert OKX kline to core kline type
-pub fn convert_okx_kline(okx_kline: okx_types::OkxKline, symbol: &str) -> Result<Kline, String> {
-    let symbol = conversion::string_to_symbol(symbol);
+pub fn convert_okx_kline(
+    okx_kline: okx_types::OkxKline,
+    symbol: &str,
+    interval: KlineInterval,
+) -> Result<Kline, String> {
+    let symbol = convert_okx_inst_id_to_symbol(symbol);
 
-    // Parse timestamp
-    let timestamp = okx_kline
+    // `ts` is the bar's open time
+    let open_time = okx_kline
         .ts
-        .parse::<u64>()
+        .parse::<i64>()
         .map_err(|e| format!("Failed to parse timestamp: {}", e))?;
 
     Ok(Kline {
         symbol,
-        open_time: timestamp.try_into().unwrap_or(i64::MAX),
-        close_time: timestamp.try_into().unwrap_or(i64::MAX),
-        interval: "1m".to_string(), // Default interval
-        open_price: conversion::string_to_price(&okx_kline.o),
-        high_price: conversion::string_to_price(&okx_kline.h),
-        low_price: conversion::string_to_price(&okx_kline.l),
-        close_price: conversion::string_to_price(&okx_kline.c),
-        volume: conversion::string_to_volume(&okx_kline.vol),
-        number_of_trades: 0, // Default value
-        final_bar: true,
+        open_time,
+        close_time: interval.close_time(open_time),
+        interval: kline_interval_to_okx_bar(interval).to_string(),
+        open_price: conversion::try_string_to_price(&okx_kline.o).map_err(|e| e.to_string())?,
+        high_price: conversion::try_string_to_price(&okx_kline.h).map_err(|e| e.to_string())?,
+        low_price: conversion::try_string_to_price(&okx_kline.l).map_err(|e| e.to_string())?,
+        close_price: conversion::try_string_to_price(&okx_kline.c).map_err(|e| e.to_string())?,
+        volume: conversion::try_string_to_volume(&okx_kline.vol).map_err(|e| e.to_string())?,
+        number_of_trades: 0,                 // Default value
+        final_bar: okx_kline.confirm != "0", // "0" marks the bar still forming
+    })
+}
+
+/// OKX account code of a wallet: spot, margin and derivatives all trade from the
+/// trading account (`18`), beside the funding account (`6`)
+pub const fn account_type_to_okx(account: AccountType) -> &'static str {
+    match account {
+        AccountType::Funding => "6",
+        AccountType::Spot | AccountType::Margin | AccountType::Futures | AccountType::Unified => {
+            "18"
+        }
+    }
+}
+
+/// Convert OKX interest rate to core borrow rate, observed at `timestamp`
+pub fn convert_okx_borrow_rate(
+    okx_rate: &okx_types::OkxInterestRate,
+    timestamp: i64,
+) -> Result<BorrowRate, String> {
+    Ok(BorrowRate {
+        asset: okx_rate.ccy.clone(),
+        rate: conversion::try_string_to_decimal(&okx_rate.interest_rate)
+            .map_err(|e| e.to_string())?,
+        interval: std::time::Duration::from_secs(3600),
+        timestamp,
+    })
+}
+
+/// Convert OKX accrued interest to core interest record
+pub fn convert_okx_interest_record(
+    okx_interest: &okx_types::OkxInterestAccrued,
+) -> Result<InterestRecord, String> {
+    let timestamp = okx_interest
+        .ts
+        .parse::<i64>()
+        .map_err(|e| format!("Failed to parse timestamp: {}", e))?;
+
+    Ok(InterestRecord {
+        asset: okx_interest.ccy.clone(),
+        interest: conversion::try_string_to_decimal(&okx_interest.interest)
+            .map_err(|e| e.to_string())?,
+        principal: conversion::try_string_to_decimal(&okx_interest.liab).ok(),
+        rate: conversion::try_string_to_decimal(&okx_interest.interest_rate).ok(),
+        timestamp,
     })
 }
 
+/// OKX `bar` parameter for a kline interval
+pub const fn kline_interval_to_okx_bar(interval: KlineInterval) -> &'static str {
+    match interval {
+        KlineInterval::Minutes1 => "1m",
+        KlineInterval::Minutes3 => "3m",
+        KlineInterval::Minutes5 => "5m",
+        KlineInterval::Minutes15 => "15m",
+        KlineInterval::Minutes30 => "30m",
+        KlineInterval::Hours1 => "1H",
+        KlineInterval::Hours2 => "2H",
+        KlineInterval::Hours4 => "4H",
+        KlineInterval::Hours6 => "6H",
+        KlineInterval::Hours8 => "8H",
+        KlineInterval::Hours12 => "12H",
+        KlineInterval::Days1 => "1D",
+        KlineInterval::Days3 => "3D",
+        KlineInterval::Weeks1 => "1W",
+        KlineInterval::Months1 => "1M",
+    }
+}
+
 /// Convert core order side to OKX order side
 pub fn convert_order_side_to_okx(side: OrderSide) -> String {
     match side {
@@ -199,9 +302,209 @@ pub fn convert_okx_order_state(state: &str) -> String {
     }
 }
 
+/// Convert OKX order details to core order
+pub fn convert_okx_order(okx_order: &okx_types::OkxOrder) -> Result<Order, String> {
+    // Market orders have no price and unfilled orders no average, reported as empty strings
+    let non_zero = |value: &str| {
+        conversion::try_string_to_price(value)
+            .ok()
+            .filter(|price| !price.value().is_zero())
+    };
+    let parse_time = |time: &str| {
+        time.parse::<i64>()
+            .map_err(|e| format!("Failed to parse timestamp: {}", e))
+    };
+
+    Ok(Order {
+        order_id: okx_order.ord_id.clone(),
+        client_order_id: okx_order.cl_ord_id.clone().unwrap_or_default(),
+        symbol: convert_okx_inst_id_to_symbol(&okx_order.inst_id),
+        side: if okx_order.side == "sell" {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        order_type: if okx_order.ord_type == "market" {
+            OrderType::Market
+        } else {
+            OrderType::Limit
+        },
+        status: OrderStatus::from_venue(&okx_order.state),
+        price: non_zero(&okx_order.px),
+        quantity: conversion::try_string_to_quantity(&okx_order.sz).map_err(|e| e.to_string())?,
+        filled_quantity: conversion::try_string_to_quantity(&okx_order.acc_fill_sz)
+            .map_err(|e| e.to_string())?,
+        average_price: non_zero(&okx_order.avg_px),
+        created_time: parse_time(&okx_order.c_time)?,
+        updated_time: parse_time(&okx_order.u_time)?,
+    })
+}
+
 /// Convert symbol to OKX instrument ID format
-pub fn convert_symbol_to_okx_inst_id(symbol: &Symbol) -> String {
-    format!("{}-{}", symbol.base, symbol.quote)
+pub fn convert_symbol_to_okx_inst_id(symbol: &Symbol) -> Result<String, ExchangeError> {
+    SYMBOLS.try_to_exchange_symbol(symbol)
+}
+
+/// Convert OKX instrument ID to symbol, marking `-SWAP` instruments perpetual
+pub fn convert_okx_inst_id_to_symbol(inst_id: &str) -> Symbol {
+    SYMBOLS.from_exchange_symbol(inst_id)
+}
+
+fn parse_okx_time(time: &str) -> Result<i64, String> {
+    time.parse::<i64>()
+        .map_err(|e| format!("Failed to parse timestamp {}: {}", time, e))
+}
+
+/// Convert OKX current funding rate to core funding rate
+///
+/// OKX `fundingTime` is the upcoming settlement the current rate applies to, so it
+/// maps to `next_funding_time`; the previous settlement is one interval earlier.
+pub fn convert_okx_funding_rate(
+    okx_rate: &okx_types::OkxFundingRate,
+    mark_price: Option<Price>,
+) -> Result<FundingRate, String> {
+    let next_funding_time = parse_okx_time(&okx_rate.funding_time)?;
+    let following_funding_time = parse_okx_time(&okx_rate.next_funding_time)?;
+    let interval = following_funding_time - next_funding_time;
+    let funding_interval = u64::try_from(interval)
+        .ok()
+        .filter(|ms| *ms > 0)
+        .map(std::time::Duration::from_millis);
+
+    Ok(FundingRate {
+        symbol: convert_okx_inst_id_to_symbol(&okx_rate.inst_id),
+        funding_rate: Some(
+            conversion::try_string_to_decimal(&okx_rate.funding_rate).map_err(|e| e.to_string())?,
+        ),
+        previous_funding_rate: None,
+        next_funding_rate: conversion::try_string_to_decimal(&okx_rate.next_funding_rate).ok(),
+        funding_time: funding_interval.map(|_| next_funding_time - interval),
+        next_funding_time: Some(next_funding_time),
+        mark_price,
+        index_price: None,
+        timestamp: parse_okx_time(&okx_rate.ts)?,
+        funding_interval,
+    })
+}
+
+/// Convert OKX settled funding rate to core funding rate
+pub fn convert_okx_funding_rate_history(
+    okx_rate: &okx_types::OkxFundingRateHistory,
+) -> Result<FundingRate, String> {
+    // `realizedRate` is what was charged; older records only carry `fundingRate`
+    let rate = conversion::try_string_to_decimal(&okx_rate.realized_rate)
+        .or_else(|_| conversion::try_string_to_decimal(&okx_rate.funding_rate))
+        .map_err(|e| e.to_string())?;
+    let funding_time = parse_okx_time(&okx_rate.funding_time)?;
+
+    Ok(FundingRate {
+        symbol: convert_okx_inst_id_to_symbol(&okx_rate.inst_id),
+        funding_rate: Some(rate),
+        previous_funding_rate: None,
+        next_funding_rate: None,
+        funding_time: Some(funding_time),
+        next_funding_time: None,
+        mark_price: None,
+        index_price: None,
+        timestamp: funding_time,
+        funding_interval: None,
+    })
+}
+
+/// Convert OKX account totals to core account summary
+///
+/// Totals are in USD. Margin fields are blank in spot mode and read as zero; available
+/// margin is the adjusted equity, or total equity where OKX leaves it blank, less the
+/// initial requirement.
+pub fn convert_okx_account_summary(
+    okx_account: &okx_types::OkxAccountInfo,
+) -> Result<AccountSummary, String> {
+    let optional = |value: &str| conversion::try_string_to_decimal(value).ok();
+    let total_equity =
+        conversion::try_string_to_decimal(&okx_account.total_eq).map_err(|e| e.to_string())?;
+    let initial_margin = optional(&okx_account.imr).unwrap_or_default();
+    let adjusted_equity = optional(&okx_account.adj_eq).unwrap_or(total_equity);
+
+    Ok(AccountSummary {
+        currency: "USD".to_string(),
+        total_equity,
+        available_margin: (adjusted_equity - initial_margin).max(Decimal::ZERO),
+        initial_margin,
+        maintenance_margin: optional(&okx_account.mmr).unwrap_or_default(),
+        unrealized_pnl: optional(&okx_account.upl).unwrap_or_default(),
+        timestamp: parse_okx_time(&okx_account.u_time)
+            .unwrap_or_else(|_| chrono::Utc::now().timestamp_millis()),
+    })
+}
+
+/// Convert OKX position to core position
+///
+/// OKX sizes positions in contracts; `market` supplies the contract value to express
+/// the amount in the base asset. Without it the contract count is reported as is.
+pub fn convert_okx_position(
+    okx_position: &okx_types::OkxPosition,
+    market: Option<&Market>,
+) -> Result<Position, String> {
+    let contracts =
+        conversion::try_string_to_decimal(&okx_position.pos).map_err(|e| e.to_string())?;
+    let optional = |value: &str| conversion::try_string_to_decimal(value).ok();
+
+    // Net mode reports one signed position per instrument
+    let position_side = match okx_position.pos_side.as_str() {
+        "long" => PositionSide::Long,
+        "short" => PositionSide::Short,
+        _ if contracts.is_sign_negative() => PositionSide::Short,
+        _ => PositionSide::Long,
+    };
+    let margin_mode = match okx_position.mgn_mode.as_str() {
+        "cross" => Some(MarginMode::Cross),
+        "isolated" => Some(MarginMode::Isolated),
+        _ => None,
+    };
+    // Isolated positions carry their own margin; cross ones report the initial requirement
+    let margin = match margin_mode {
+        Some(MarginMode::Isolated) => optional(&okx_position.margin),
+        _ => optional(&okx_position.imr),
+    };
+
+    let contracts = Quantity::new(contracts.abs());
+    let mark_price = optional(&okx_position.mark_px).map_or(Price::ZERO, Price::new);
+    let position_amount = market.map_or(contracts, |market| {
+        market.contracts_to_base(contracts, mark_price)
+    });
+
+    Ok(Position {
+        symbol: convert_okx_inst_id_to_symbol(&okx_position.inst_id),
+        position_side,
+        entry_price: conversion::try_string_to_price(&okx_position.avg_px)
+            .map_err(|e| e.to_string())?,
+        position_amount,
+        unrealized_pnl: optional(&okx_position.upl).unwrap_or_default(),
+        liquidation_price: optional(&okx_position.liq_px)
+            .filter(|price| !price.is_zero())
+            .map(Price::new),
+        leverage: optional(&okx_position.lever).unwrap_or(Decimal::ONE),
+        margin_mode,
+        margin,
+        extensions: Some(serde_json::json!({
+            "instId": okx_position.inst_id,
+            "pos": okx_position.pos,
+            "markPx": okx_position.mark_px,
+        })),
+    })
+}
+
+/// Convert OKX position tier to core leverage bracket, bounded in contracts
+pub fn convert_okx_position_tier(
+    tier: &okx_types::OkxPositionTier,
+) -> Result<LeverageBracket, String> {
+    let decimal = |value: &str| conversion::try_string_to_decimal(value).map_err(|e| e.to_string());
+    Ok(LeverageBracket {
+        max_leverage: decimal(&tier.max_lever)?,
+        floor: decimal(&tier.min_sz)?,
+        cap: Some(decimal(&tier.max_sz)?),
+        maintenance_margin_rate: decimal(&tier.mmr)?,
+    })
 }
 
 /// Helper function to convert OKX WebSocket ticker message
@@ -405,3 +708,114 @@ pub fn convert_okx_ws_trade(data: &Value, inst_id: &str) -> Result<Vec<Trade>, S
         Err("Invalid trade data format".to_string())
     }
 }
+
+/// Helper function to convert an OKX `instruments` channel push into status updates
+///
+/// OKX pushes the full instrument record whenever its `state` changes (e.g. `live` →
+/// `suspend`), so each entry becomes one `MarketStatusUpdate`.
+pub fn convert_okx_ws_instrument_status(data: &Value) -> Result<Vec<MarketStatusUpdate>, String> {
+    let instruments = data
+        .as_array()
+        .ok_or_else(|| "Invalid instrument data format".to_string())?;
+    let timestamp = chrono::Utc::now().timestamp_millis();
+
+    Ok(instruments
+        .iter()
+        .filter_map(|instrument| {
+            let inst_id = instrument.get("instId")?.as_str()?;
+            let state = instrument.get("state")?.as_str()?;
+            Some(MarketStatusUpdate {
+                symbol: convert_okx_inst_id_to_symbol(inst_id),
+                status: MarketStatus::from_venue_str(state),
+                timestamp,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_funding_rate() {
+        let okx_rate = okx_types::OkxFundingRate {
+            inst_id: "BTC-USDT-SWAP".to_string(),
+            funding_rate: "0.0001".to_string(),
+            next_funding_rate: String::new(),
+            funding_time: "1700006400000".to_string(),
+            next_funding_time: "1700035200000".to_string(),
+            ts: "1700000000000".to_string(),
+        };
+
+        let rate = convert_okx_funding_rate(&okx_rate, None).unwrap();
+        assert_eq!(rate.symbol.joined("-"), "BTC-USDT");
+        assert_eq!(rate.next_funding_time, Some(1_700_006_400_000));
+        assert_eq!(rate.funding_time, Some(1_699_977_600_000));
+        assert_eq!(
+            rate.funding_interval,
+            Some(std::time::Duration::from_secs(8 * 60 * 60))
+        );
+        assert_eq!(rate.next_funding_rate, None);
+    }
+
+    #[test]
+    fn test_convert_account_summary() {
+        let okx_account: okx_types::OkxAccountInfo = serde_json::from_value(serde_json::json!({
+            "uid": "", "acctLv": "2", "posMode": "net_mode", "autoLoan": false,
+            "greeksType": "", "level": "", "levelTmp": "", "mgnIso": "",
+            "totalEq": "41624.32", "isoEq": "0", "adjEq": "41624.32", "ordFroz": "0",
+            "imr": "4162.43", "mmr": "208.12", "notionalUsd": "41624.32",
+            "upl": "-12.5", "uTime": "1705449605015", "details": []
+        }))
+        .unwrap();
+
+        let summary = convert_okx_account_summary(&okx_account).unwrap();
+        assert_eq!(summary.currency, "USD");
+        assert_eq!(summary.available_margin.to_string(), "37461.89");
+        assert_eq!(summary.maintenance_margin.to_string(), "208.12");
+        assert_eq!(summary.unrealized_pnl.to_string(), "-12.5");
+        assert_eq!(summary.timestamp, 1_705_449_605_015);
+    }
+
+    #[test]
+    fn test_convert_net_position_in_base_units() {
+        let okx_position = okx_types::OkxPosition {
+            inst_id: "ETH-USDT-SWAP".to_string(),
+            pos_side: "net".to_string(),
+            pos: "-25".to_string(),
+            avg_px: "2000".to_string(),
+            upl: "-1.5".to_string(),
+            liq_px: String::new(),
+            lever: "10".to_string(),
+            mgn_mode: "isolated".to_string(),
+            margin: "50".to_string(),
+            imr: String::new(),
+            mark_px: "2001".to_string(),
+        };
+        let market = Market {
+            symbol: Symbol::new("ETH", "USDT").unwrap(),
+            status: MarketStatus::Trading,
+            base_precision: 8,
+            quote_precision: 8,
+            min_qty: None,
+            max_qty: None,
+            min_price: None,
+            max_price: None,
+            contract_style: ContractStyle::Linear,
+            contract_size: Some(Decimal::new(1, 1)),
+            extensions: None,
+        };
+
+        let position = convert_okx_position(&okx_position, Some(&market)).unwrap();
+        assert!(matches!(position.position_side, PositionSide::Short));
+        assert_eq!(position.position_amount.value(), Decimal::new(25, 1));
+        assert_eq!(position.margin_mode, Some(MarginMode::Isolated));
+        assert_eq!(position.margin, Some(Decimal::from(50)));
+        assert_eq!(position.leverage, Decimal::from(10));
+        assert!(position.liquidation_price.is_none());
+        let extensions = position.extensions.unwrap();
+        assert_eq!(extensions["instId"], "ETH-USDT-SWAP");
+        assert_eq!(extensions["pos"], "-25");
+    }
+}
@@ -12,17 +12,21 @@ pub use builder::{
     build_connector,
     build_connector_with_reconnection,
     build_connector_with_websocket,
+    build_public,
+    build_swap_connector,
+    build_swap_connector_with_websocket,
     // Legacy compatibility exports
     create_okx_connector,
     create_okx_connector_with_reconnection,
     create_okx_connector_with_websocket,
     create_okx_rest_connector,
 };
-pub use codec::{OkxCodec, OkxMessage};
+pub use codec::{okx_instruments_stream, OkxCodec, OkxMessage};
 pub use connector::{Account, MarketData, OkxConnector, Trading};
 pub use types::{
-    OkxAccountInfo, OkxBalance, OkxKline, OkxMarket, OkxOrder, OkxOrderBook, OkxOrderRequest,
-    OkxOrderResponse, OkxResponse, OkxTicker, OkxTrade, OkxWsChannel, OkxWsRequest, OkxWsResponse,
+    OkxAccountInfo, OkxBalance, OkxFundingRate, OkxInstType, OkxKline, OkxMarket, OkxOrder,
+    OkxOrderBook, OkxOrderRequest, OkxOrderResponse, OkxPosition, OkxResponse, OkxTicker, OkxTrade,
+    OkxWsChannel, OkxWsRequest, OkxWsResponse,
 };
 
 /// Helper function to create WebSocket stream identifiers for OKX
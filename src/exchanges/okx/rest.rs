@@ -1,8 +1,9 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
 use crate::exchanges::okx::types::{
-    OkxAccountInfo, OkxKline, OkxMarket, OkxOrder, OkxOrderBook, OkxOrderRequest, OkxOrderResponse,
-    OkxResponse, OkxTicker, OkxTrade,
+    okx_error_kind, OkxAccountInfo, OkxFundingRate, OkxFundingRateHistory, OkxInterestAccrued,
+    OkxInterestRate, OkxKline, OkxMarkPrice, OkxMarket, OkxOrder, OkxOrderBook, OkxOrderRequest,
+    OkxOrderResponse, OkxPosition, OkxPositionTier, OkxResponse, OkxTicker, OkxTrade, OkxTransfer,
 };
 use serde::de::DeserializeOwned;
 use serde_json::Value;
@@ -19,11 +20,27 @@ impl<R: RestClient> OkxRest<R> {
         Self { rest_client }
     }
 
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.rest_client.can_sign()
+    }
+
     /// Maps OKX error codes to appropriate `ExchangeError` variants
     ///
     /// This function provides a comprehensive mapping of OKX error codes to
     /// more specific `ExchangeError` variants, making error handling more precise.
     fn map_okx_error(&self, code: &str, message: &str) -> ExchangeError {
+        if let Some((code, kind)) = code
+            .parse()
+            .ok()
+            .and_then(|code| okx_error_kind(code, message).map(|kind| (code, kind)))
+        {
+            return ExchangeError::Rejected {
+                kind,
+                code,
+                message: message.to_string(),
+            };
+        }
         match code {
             // Authentication errors
             "50001" => ExchangeError::AuthError(format!("Invalid API key: {}", message)),
@@ -270,6 +287,59 @@ impl<R: RestClient> OkxRest<R> {
         Ok(klines)
     }
 
+    /// Get the current funding rate of a swap, or of every swap for `ANY`
+    pub async fn get_funding_rate(
+        &self,
+        inst_id: &str,
+    ) -> Result<Vec<OkxFundingRate>, ExchangeError> {
+        let endpoint = "/api/v5/public/funding-rate";
+        let query_params = &[("instId", inst_id)];
+
+        let response_value = self.rest_client.get(endpoint, query_params, false).await?;
+        self.handle_response(response_value)
+    }
+
+    /// Get settled funding rates, newest first, at most 100
+    pub async fn get_funding_rate_history(
+        &self,
+        inst_id: &str,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<OkxFundingRateHistory>, ExchangeError> {
+        let endpoint = "/api/v5/public/funding-rate-history";
+        let start_str = start_time.map(|t| t.to_string());
+        let end_str = end_time.map(|t| t.to_string());
+        let limit_str = limit.map(|l| l.to_string());
+
+        let mut query_params = vec![("instId", inst_id)];
+        // OKX pages by `before` (newer than) and `after` (older than)
+        if let Some(ref start) = start_str {
+            query_params.push(("before", start.as_str()));
+        }
+        if let Some(ref end) = end_str {
+            query_params.push(("after", end.as_str()));
+        }
+        if let Some(ref limit_val) = limit_str {
+            query_params.push(("limit", limit_val.as_str()));
+        }
+
+        let response_value = self.rest_client.get(endpoint, &query_params, false).await?;
+        self.handle_response(response_value)
+    }
+
+    /// Get mark prices of every instrument of `inst_type`
+    pub async fn get_mark_prices(
+        &self,
+        inst_type: &str,
+    ) -> Result<Vec<OkxMarkPrice>, ExchangeError> {
+        let endpoint = "/api/v5/public/mark-price";
+        let query_params = &[("instType", inst_type)];
+
+        let response_value = self.rest_client.get(endpoint, query_params, false).await?;
+        self.handle_response(response_value)
+    }
+
     // Trading API endpoints (require authentication)
 
     /// Place a new order
@@ -285,6 +355,43 @@ impl<R: RestClient> OkxRest<R> {
         self.handle_single_item_response(response_value, "No order response data found")
     }
 
+    /// Place up to twenty orders in one request
+    ///
+    /// The outer error covers the whole request; each order carries its own result, since OKX
+    /// accepts part of a batch and reports the rejected orders through `sCode`.
+    pub async fn place_batch_orders(
+        &self,
+        orders: &[OkxOrderRequest],
+    ) -> Result<Vec<Result<OkxOrderResponse, ExchangeError>>, ExchangeError> {
+        let endpoint = "/api/v5/trade/batch-orders";
+        let body = serde_json::to_value(orders)
+            .map_err(|e| ExchangeError::SerializationError(e.to_string()))?;
+
+        let response_value = self.rest_client.post(endpoint, &body, true).await?;
+        let response: OkxResponse<Value> = serde_json::from_value(response_value).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse OKX response: {}", e))
+        })?;
+
+        // "1" (all failed) and "2" (partially failed) still list every order
+        if !matches!(response.code.as_str(), "0" | "1" | "2") {
+            return Err(self.map_okx_error(&response.code, &response.msg));
+        }
+        let items: Vec<OkxOrderResponse> = serde_json::from_value(response.data).map_err(|e| {
+            ExchangeError::DeserializationError(format!("Failed to parse batch orders: {}", e))
+        })?;
+
+        Ok(items
+            .into_iter()
+            .map(|item| {
+                if item.s_code == "0" {
+                    Ok(item)
+                } else {
+                    Err(self.map_okx_error(&item.s_code, &item.s_msg))
+                }
+            })
+            .collect())
+    }
+
     /// Cancel an order
     pub async fn cancel_order(
         &self,
@@ -335,14 +442,56 @@ impl<R: RestClient> OkxRest<R> {
         self.handle_single_item_response(response_value, "No order data found")
     }
 
-    /// Get pending orders
+    /// Get pending orders, optionally for one instrument type or instrument
     pub async fn get_pending_orders(
         &self,
         inst_type: Option<&str>,
+        inst_id: Option<&str>,
     ) -> Result<Vec<OkxOrder>, ExchangeError> {
         let endpoint = "/api/v5/trade/orders-pending";
-        let query_params =
-            inst_type.map_or_else(Vec::new, |inst_type| vec![("instType", inst_type)]);
+        let mut query_params = Vec::new();
+        if let Some(inst_type) = inst_type {
+            query_params.push(("instType", inst_type));
+        }
+        if let Some(inst_id) = inst_id {
+            query_params.push(("instId", inst_id));
+        }
+
+        let response_value = self.rest_client.get(endpoint, &query_params, true).await?;
+        self.handle_response(response_value)
+    }
+
+    /// Get completed orders of the last three months, newest first
+    ///
+    /// `after` is an order id; only orders older than it are returned.
+    pub async fn get_orders_history(
+        &self,
+        inst_type: &str,
+        inst_id: &str,
+        begin: Option<i64>,
+        end: Option<i64>,
+        after: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<OkxOrder>, ExchangeError> {
+        let endpoint = "/api/v5/trade/orders-history-archive";
+        let begin_str = begin.map(|t| t.to_string());
+        let end_str = end.map(|t| t.to_string());
+        let limit_str = limit.to_string();
+
+        let mut query_params = vec![
+            ("instType", inst_type),
+            ("instId", inst_id),
+            ("limit", limit_str.as_str()),
+        ];
+        if let Some(ref begin) = begin_str {
+            query_params.push(("begin", begin.as_str()));
+        }
+        if let Some(ref end) = end_str {
+            query_params.push(("end", end.as_str()));
+        }
+        if let Some(after) = after {
+            query_params.push(("after", after));
+        }
 
         let response_value = self.rest_client.get(endpoint, &query_params, true).await?;
         self.handle_response(response_value)
@@ -358,4 +507,116 @@ impl<R: RestClient> OkxRest<R> {
         let response_value = self.rest_client.get(endpoint, &query_params, true).await?;
         self.handle_single_item_response(response_value, "No account data found")
     }
+
+    /// Get open positions of `inst_type` (requires authentication)
+    pub async fn get_positions(&self, inst_type: &str) -> Result<Vec<OkxPosition>, ExchangeError> {
+        let endpoint = "/api/v5/account/positions";
+        let query_params = &[("instType", inst_type)];
+
+        let response_value = self.rest_client.get(endpoint, query_params, true).await?;
+        self.handle_response(response_value)
+    }
+
+    /// Set the leverage of an instrument for `mgn_mode` (requires authentication)
+    pub async fn set_leverage(
+        &self,
+        inst_id: &str,
+        lever: u32,
+        mgn_mode: &str,
+    ) -> Result<Vec<Value>, ExchangeError> {
+        let endpoint = "/api/v5/account/set-leverage";
+        let body = serde_json::json!({
+            "instId": inst_id,
+            "lever": lever.to_string(),
+            "mgnMode": mgn_mode,
+        });
+
+        let response_value = self.rest_client.post(endpoint, &body, true).await?;
+        self.handle_response(response_value)
+    }
+
+    /// Get the position tiers of a derivative instrument
+    pub async fn get_position_tiers(
+        &self,
+        inst_type: &str,
+        td_mode: &str,
+        inst_family: &str,
+        inst_id: &str,
+    ) -> Result<Vec<OkxPositionTier>, ExchangeError> {
+        let endpoint = "/api/v5/public/position-tiers";
+        let query_params = &[
+            ("instType", inst_type),
+            ("tdMode", td_mode),
+            ("instFamily", inst_family),
+            ("instId", inst_id),
+        ];
+
+        let response_value = self.rest_client.get(endpoint, query_params, false).await?;
+        self.handle_response(response_value)
+    }
+
+    /// Move funds between the funding account (`6`) and the trading account (`18`)
+    /// (requires authentication)
+    pub async fn transfer(
+        &self,
+        ccy: &str,
+        amt: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<OkxTransfer, ExchangeError> {
+        let endpoint = "/api/v5/asset/transfer";
+        let body = serde_json::json!({
+            "ccy": ccy,
+            "amt": amt,
+            "from": from,
+            "to": to,
+        });
+
+        let response_value = self.rest_client.post(endpoint, &body, true).await?;
+        self.handle_single_item_response(response_value, "No transfer data found")
+    }
+
+    /// Get hourly borrow rates (requires authentication)
+    pub async fn get_interest_rate(
+        &self,
+        ccy: Option<&str>,
+    ) -> Result<Vec<OkxInterestRate>, ExchangeError> {
+        let endpoint = "/api/v5/account/interest-rate";
+        let query_params = ccy.map_or_else(Vec::new, |currency| vec![("ccy", currency)]);
+
+        let response_value = self.rest_client.get(endpoint, &query_params, true).await?;
+        self.handle_response(response_value)
+    }
+
+    /// Get accrued interest, newest first (requires authentication)
+    pub async fn get_interest_accrued(
+        &self,
+        ccy: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<OkxInterestAccrued>, ExchangeError> {
+        let endpoint = "/api/v5/account/interest-accrued";
+        let start_str = start_time.map(|t| t.to_string());
+        let end_str = end_time.map(|t| t.to_string());
+        let limit_str = limit.map(|l| l.to_string());
+
+        let mut query_params = Vec::new();
+        if let Some(currency) = ccy {
+            query_params.push(("ccy", currency));
+        }
+        // OKX pages by `before` (newer than) and `after` (older than)
+        if let Some(ref start) = start_str {
+            query_params.push(("before", start.as_str()));
+        }
+        if let Some(ref end) = end_str {
+            query_params.push(("after", end.as_str()));
+        }
+        if let Some(ref limit_val) = limit_str {
+            query_params.push(("limit", limit_val.as_str()));
+        }
+
+        let response_value = self.rest_client.get(endpoint, &query_params, true).await?;
+        self.handle_response(response_value)
+    }
 }
@@ -4,7 +4,6 @@ use base64::{engine::general_purpose, Engine as _};
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -25,7 +24,7 @@ impl OkxSigner {
 
     /// Generate the signature for OKX API requests
     /// The prehash string format is: timestamp + method + requestPath + body
-    fn generate_signature(
+    pub fn generate_signature(
         &self,
         timestamp: &str,
         method: &str,
@@ -44,15 +43,11 @@ impl OkxSigner {
         Ok(general_purpose::STANDARD.encode(signature_bytes))
     }
 
-    /// Get current timestamp in ISO format as required by OKX
-    fn get_timestamp() -> Result<String, ExchangeError> {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|e| ExchangeError::AuthError(format!("Failed to get timestamp: {}", e)))?
-            .as_millis();
-
-        // OKX requires timestamp in ISO format
-        let datetime = chrono::DateTime::from_timestamp_millis(timestamp as i64)
+    /// Format a millisecond timestamp in the ISO form OKX requires
+    fn format_timestamp(timestamp: u64) -> Result<String, ExchangeError> {
+        let datetime = i64::try_from(timestamp)
+            .ok()
+            .and_then(chrono::DateTime::from_timestamp_millis)
             .ok_or_else(|| ExchangeError::AuthError("Invalid timestamp".to_string()))?;
 
         Ok(datetime.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string())
@@ -66,10 +61,9 @@ impl Signer for OkxSigner {
         endpoint: &str,
         query_string: &str,
         body: &[u8],
-        _timestamp: u64, // We generate our own timestamp for OKX
+        timestamp: u64,
     ) -> Result<(HashMap<String, String>, Vec<(String, String)>), ExchangeError> {
-        // Generate ISO timestamp for OKX
-        let timestamp = Self::get_timestamp()?;
+        let timestamp = Self::format_timestamp(timestamp)?;
 
         // Build request path with query string if present
         let request_path = if query_string.is_empty() {
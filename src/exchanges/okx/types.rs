@@ -1,3 +1,6 @@
+use crate::core::errors::{ErrorKind, ExchangeError};
+use crate::core::types::Symbol;
+use crate::exchanges::factory::ExchangeId;
 use serde::{Deserialize, Serialize};
 
 /// OKX API standard response wrapper
@@ -8,6 +11,46 @@ pub struct OkxResponse<T> {
     pub data: T,
 }
 
+/// OKX instrument family a connector trades
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OkxInstType {
+    /// Spot pairs, e.g. `BTC-USDT`
+    #[default]
+    Spot,
+    /// Perpetual swaps, e.g. `BTC-USDT-SWAP`, sized in contracts
+    Swap,
+}
+
+impl OkxInstType {
+    /// `instType` query value
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Spot => "SPOT",
+            Self::Swap => "SWAP",
+        }
+    }
+
+    /// Instrument ID of `symbol`, e.g. `BTC-USDT` or `BTC-USDT-SWAP`
+    pub fn inst_id(self, symbol: &Symbol) -> Result<String, ExchangeError> {
+        match self {
+            Self::Spot => ExchangeId::Okx
+                .symbol_convention()
+                .try_to_exchange_symbol(symbol),
+            Self::Swap => ExchangeId::OkxSwap
+                .symbol_convention()
+                .try_to_exchange_symbol(symbol),
+        }
+    }
+
+    /// Order `tdMode`: spot trades cash, swaps cross margin
+    pub const fn td_mode(self) -> &'static str {
+        match self {
+            Self::Spot => "cash",
+            Self::Swap => "cross",
+        }
+    }
+}
+
 /// OKX Market (Instrument) information
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -53,6 +96,8 @@ pub struct OkxOrderRequest {
     pub tgt_ccy: Option<String>, // Target currency: base_ccy, quote_ccy
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ban_amend: Option<bool>, // Disallow amend
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>, // Only reduce the position (margin and swap)
 }
 
 /// OKX Order response
@@ -147,6 +192,38 @@ pub struct OkxAccountInfo {
     pub notional_usd: String,     // Notional in USD
     pub upl: String,              // Unrealized P&L
     pub details: Vec<OkxBalance>, // Balance details
+    #[serde(default)]
+    pub u_time: String, // Update time
+}
+
+/// OKX funds transfer acknowledgement
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxTransfer {
+    pub trans_id: String, // Transfer ID
+    pub ccy: String,      // Currency
+    pub amt: String,      // Amount
+}
+
+/// OKX Borrow interest rate
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxInterestRate {
+    pub ccy: String,           // Currency
+    pub interest_rate: String, // Hourly borrow rate
+}
+
+/// OKX Accrued interest
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxInterestAccrued {
+    pub ccy: String,           // Currency
+    pub inst_id: String,       // Instrument ID, for isolated margin
+    pub mgn_mode: String,      // Margin mode
+    pub interest: String,      // Interest accrued
+    pub interest_rate: String, // Hourly rate applied
+    pub liab: String,          // Liability the interest accrued on
+    pub ts: String,            // Timestamp
 }
 
 /// OKX Ticker data
@@ -206,6 +283,72 @@ pub struct OkxKline {
     pub confirm: String,       // Confirmation status
 }
 
+/// OKX current funding rate of a swap
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxFundingRate {
+    pub inst_id: String,      // Instrument ID
+    pub funding_rate: String, // Rate charged at `funding_time`
+    #[serde(default)]
+    pub next_funding_rate: String, // Forecast rate, empty when not published
+    pub funding_time: String, // Upcoming settlement time
+    pub next_funding_time: String, // Settlement after that
+    pub ts: String,           // Timestamp
+}
+
+/// OKX settled funding rate
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxFundingRateHistory {
+    pub inst_id: String,      // Instrument ID
+    pub funding_rate: String, // Predicted rate
+    #[serde(default)]
+    pub realized_rate: String, // Rate actually charged
+    pub funding_time: String, // Settlement time
+}
+
+/// OKX mark price
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxMarkPrice {
+    pub inst_id: String, // Instrument ID
+    pub mark_px: String, // Mark price
+    pub ts: String,      // Timestamp
+}
+
+/// OKX derivatives position
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxPosition {
+    pub inst_id: String,  // Instrument ID
+    pub pos_side: String, // long, short, or net
+    pub pos: String,      // Contracts; signed in net mode
+    pub avg_px: String,   // Average open price
+    #[serde(default)]
+    pub upl: String, // Unrealized PnL
+    #[serde(default)]
+    pub liq_px: String, // Liquidation price, empty if none
+    #[serde(default)]
+    pub lever: String, // Leverage
+    pub mgn_mode: String, // cross or isolated
+    #[serde(default)]
+    pub margin: String, // Isolated margin
+    #[serde(default)]
+    pub imr: String, // Initial margin requirement, cross only
+    #[serde(default)]
+    pub mark_px: String, // Mark price
+}
+
+/// Tier of `/api/v5/public/position-tiers`, sized in contracts
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxPositionTier {
+    pub min_sz: String,    // Lower bound of the tier
+    pub max_sz: String,    // Upper bound of the tier
+    pub mmr: String,       // Maintenance margin requirement rate
+    pub max_lever: String, // Maximum leverage
+}
+
 /// OKX WebSocket subscription request
 #[derive(Debug, Serialize, Clone)]
 pub struct OkxWsRequest {
@@ -241,3 +384,19 @@ pub struct OkxError {
     pub s_code: String, // Error code
     pub s_msg: String,  // Error message
 }
+
+/// Classify an OKX error code and message
+pub fn okx_error_kind(code: i32, _message: &str) -> Option<ErrorKind> {
+    match code {
+        50011 | 50061 => Some(ErrorKind::RateLimited),
+        50111 | 50113 => Some(ErrorKind::Unauthorized),
+        51001 => Some(ErrorKind::InvalidSymbol),
+        51008 => Some(ErrorKind::InsufficientBalance),
+        51400 | 51603 => Some(ErrorKind::OrderNotFound),
+        51020 => Some(ErrorKind::MinNotional),
+        51006 => Some(ErrorKind::InvalidPrice),
+        51121 => Some(ErrorKind::InvalidQuantity),
+        51016 => Some(ErrorKind::DuplicateOrder),
+        _ => None,
+    }
+}
@@ -1,6 +1,8 @@
 use crate::core::config::ExchangeConfig;
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::{RestClientBuilder, RestClientConfig, TungsteniteWs};
+use crate::exchanges::endpoints::resolve_endpoints;
+use crate::exchanges::factory::ExchangeId;
 use crate::exchanges::paradex::{
     codec::ParadexCodec, connector::ParadexConnector, signer::ParadexSigner,
 };
@@ -10,20 +12,12 @@ use std::sync::Arc;
 pub fn build_connector(
     config: ExchangeConfig,
 ) -> Result<ParadexConnector<crate::core::kernel::ReqwestRest, ()>, ExchangeError> {
-    // Determine base URL
-    let base_url = if config.testnet {
-        "https://api.testnet.paradex.trade".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.paradex.trade".to_string())
-    };
+    let endpoints = resolve_endpoints(ExchangeId::Paradex, &config)?;
 
     // Build REST client
-    let rest_config = RestClientConfig::new(base_url, "paradex".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let rest_config = RestClientConfig::new(endpoints.rest, "paradex".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -35,7 +29,7 @@ pub fn build_connector(
 
     let rest = rest_builder.build()?;
 
-    Ok(ParadexConnector::new_without_ws(rest, config))
+    Ok(ParadexConnector::new_without_ws(rest, endpoints.ws, config))
 }
 
 /// Create a Paradex connector with WebSocket support
@@ -45,20 +39,12 @@ pub fn build_connector_with_websocket(
     ParadexConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<ParadexCodec>>,
     ExchangeError,
 > {
-    // Determine base URL
-    let base_url = if config.testnet {
-        "https://api.testnet.paradex.trade".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.paradex.trade".to_string())
-    };
+    let endpoints = resolve_endpoints(ExchangeId::Paradex, &config)?;
 
     // Build REST client
-    let rest_config = RestClientConfig::new(base_url, "paradex".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let rest_config = RestClientConfig::new(endpoints.rest, "paradex".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -71,15 +57,20 @@ pub fn build_connector_with_websocket(
     let rest = rest_builder.build()?;
 
     // Create WebSocket client
-    let ws_url = if config.testnet {
-        "wss://ws.testnet.paradex.trade/v1".to_string()
-    } else {
-        "wss://ws.paradex.trade/v1".to_string()
-    };
+    let ws = TungsteniteWs::new(endpoints.ws.clone(), "paradex".to_string(), ParadexCodec)
+        .with_config(config.ws_config());
 
-    let ws = TungsteniteWs::new(ws_url, "paradex".to_string(), ParadexCodec);
+    Ok(ParadexConnector::new(rest, ws, endpoints.ws, config))
+}
 
-    Ok(ParadexConnector::new(rest, ws, config))
+/// Create a Paradex connector for public market data, ignoring any credentials in `config`
+pub fn build_public(
+    config: ExchangeConfig,
+) -> Result<
+    ParadexConnector<crate::core::kernel::ReqwestRest, TungsteniteWs<ParadexCodec>>,
+    ExchangeError,
+> {
+    build_connector_with_websocket(config.without_credentials())
 }
 
 /// Create a Paradex connector with WebSocket and auto-reconnection support
@@ -92,20 +83,12 @@ pub fn build_connector_with_reconnection(
     >,
     ExchangeError,
 > {
-    // Determine base URL
-    let base_url = if config.testnet {
-        "https://api.testnet.paradex.trade".to_string()
-    } else {
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://api.paradex.trade".to_string())
-    };
+    let endpoints = resolve_endpoints(ExchangeId::Paradex, &config)?;
 
     // Build REST client
-    let rest_config = RestClientConfig::new(base_url, "paradex".to_string())
-        .with_timeout(30)
-        .with_max_retries(3);
+    let rest_config = RestClientConfig::new(endpoints.rest, "paradex".to_string())
+        .with_profile(config.profile)
+        .with_network(config.proxy.clone(), config.tls.clone());
 
     let mut rest_builder = RestClientBuilder::new(rest_config);
 
@@ -118,19 +101,18 @@ pub fn build_connector_with_reconnection(
     let rest = rest_builder.build()?;
 
     // Create WebSocket client with auto-reconnection
-    let ws_url = if config.testnet {
-        "wss://ws.testnet.paradex.trade/v1".to_string()
-    } else {
-        "wss://ws.paradex.trade/v1".to_string()
-    };
-
-    let base_ws = TungsteniteWs::new(ws_url, "paradex".to_string(), ParadexCodec);
+    let base_ws = TungsteniteWs::new(endpoints.ws.clone(), "paradex".to_string(), ParadexCodec)
+        .with_config(config.ws_config());
     let reconnect_ws = crate::core::kernel::ReconnectWs::new(base_ws)
-        .with_max_reconnect_attempts(10)
-        .with_reconnect_delay(std::time::Duration::from_secs(2))
+        .with_profile(config.profile)
         .with_auto_resubscribe(true);
 
-    Ok(ParadexConnector::new(rest, reconnect_ws, config))
+    Ok(ParadexConnector::new(
+        rest,
+        reconnect_ws,
+        endpoints.ws,
+        config,
+    ))
 }
 
 /// Legacy function for backward compatibility
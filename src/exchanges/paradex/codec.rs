@@ -2,8 +2,11 @@ use crate::core::errors::ExchangeError;
 use crate::core::kernel::codec::WsCodec;
 use crate::core::types::conversion;
 use crate::core::types::{
-    Kline, MarketDataType, OrderBook, OrderBookEntry, SubscriptionType, Ticker, Trade,
+    Kline, KlineInterval, MarkPrice, MarketDataType, OrderBook, OrderBookEntry, OrderBookMode,
+    Price, Quantity, SubscriptionType, Ticker, Trade, Volume,
 };
+use crate::exchanges::paradex::conversions::SYMBOLS;
+use rust_decimal::Decimal;
 use serde_json::{json, Value};
 use tokio_tungstenite::tungstenite::Message;
 
@@ -14,6 +17,7 @@ pub enum ParadexWsEvent {
     OrderBook(OrderBook),
     Trade(Trade),
     Kline(Kline),
+    MarkPrice(MarkPrice),
     SubscriptionConfirmation(Value),
     Heartbeat,
     Error(String),
@@ -79,43 +83,43 @@ impl ParadexCodec {
                         symbol: data
                             .get("symbol")
                             .and_then(|s| s.as_str())
-                            .map(conversion::string_to_symbol)
+                            .map(|symbol| SYMBOLS.from_exchange_symbol(symbol))
                             .unwrap_or_default(),
                         price: data
                             .get("price")
                             .and_then(|p| p.as_str())
-                            .map(conversion::string_to_price)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         volume: data
                             .get("volume")
                             .and_then(|v| v.as_str())
-                            .map(conversion::string_to_volume)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Volume::ZERO), conversion::try_string_to_volume)
+                            .ok()?,
                         price_change: data
                             .get("price_change")
                             .and_then(|pc| pc.as_str())
-                            .map(conversion::string_to_price)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         price_change_percent: data
                             .get("price_change_percent")
                             .and_then(|pcp| pcp.as_str())
-                            .map(conversion::string_to_decimal)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Decimal::ZERO), conversion::try_string_to_decimal)
+                            .ok()?,
                         high_price: data
                             .get("high_price")
                             .and_then(|hp| hp.as_str())
-                            .map(conversion::string_to_price)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         low_price: data
                             .get("low_price")
                             .and_then(|lp| lp.as_str())
-                            .map(conversion::string_to_price)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         quote_volume: data
                             .get("quote_volume")
                             .and_then(|qv| qv.as_str())
-                            .map(conversion::string_to_volume)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Volume::ZERO), conversion::try_string_to_volume)
+                            .ok()?,
                         open_time: data
                             .get("open_time")
                             .and_then(|ot| ot.as_i64())
@@ -136,25 +140,30 @@ impl ParadexCodec {
                         symbol: data
                             .get("symbol")
                             .and_then(|s| s.as_str())
-                            .map(conversion::string_to_symbol)
+                            .map(|symbol| SYMBOLS.from_exchange_symbol(symbol))
                             .unwrap_or_default(),
-                        bids: data
-                            .get("bids")
-                            .and_then(|b| b.as_array())
-                            .map(|bids| {
+                        bids: data.get("bids").and_then(|b| b.as_array()).map_or(
+                            Some(Vec::new()),
+                            |bids| {
                                 bids.iter()
-                                    .filter_map(|bid| {
+                                    .map(|bid| {
                                         bid.as_array().and_then(|bid_array| {
                                             if bid_array.len() >= 2 {
                                                 Some(OrderBookEntry {
                                                     price: bid_array[0]
                                                         .as_str()
-                                                        .map(conversion::string_to_price)
-                                                        .unwrap_or_default(),
+                                                        .map_or(
+                                                            Ok(Price::ZERO),
+                                                            conversion::try_string_to_price,
+                                                        )
+                                                        .ok()?,
                                                     quantity: bid_array[1]
                                                         .as_str()
-                                                        .map(conversion::string_to_quantity)
-                                                        .unwrap_or_default(),
+                                                        .map_or(
+                                                            Ok(Quantity::ZERO),
+                                                            conversion::try_string_to_quantity,
+                                                        )
+                                                        .ok()?,
                                                 })
                                             } else {
                                                 None
@@ -162,25 +171,30 @@ impl ParadexCodec {
                                         })
                                     })
                                     .collect()
-                            })
-                            .unwrap_or_default(),
-                        asks: data
-                            .get("asks")
-                            .and_then(|a| a.as_array())
-                            .map(|asks| {
+                            },
+                        )?,
+                        asks: data.get("asks").and_then(|a| a.as_array()).map_or(
+                            Some(Vec::new()),
+                            |asks| {
                                 asks.iter()
-                                    .filter_map(|ask| {
+                                    .map(|ask| {
                                         ask.as_array().and_then(|ask_array| {
                                             if ask_array.len() >= 2 {
                                                 Some(OrderBookEntry {
                                                     price: ask_array[0]
                                                         .as_str()
-                                                        .map(conversion::string_to_price)
-                                                        .unwrap_or_default(),
+                                                        .map_or(
+                                                            Ok(Price::ZERO),
+                                                            conversion::try_string_to_price,
+                                                        )
+                                                        .ok()?,
                                                     quantity: ask_array[1]
                                                         .as_str()
-                                                        .map(conversion::string_to_quantity)
-                                                        .unwrap_or_default(),
+                                                        .map_or(
+                                                            Ok(Quantity::ZERO),
+                                                            conversion::try_string_to_quantity,
+                                                        )
+                                                        .ok()?,
                                                 })
                                             } else {
                                                 None
@@ -188,8 +202,8 @@ impl ParadexCodec {
                                         })
                                     })
                                     .collect()
-                            })
-                            .unwrap_or_default(),
+                            },
+                        )?,
                         last_update_id: data
                             .get("last_update_id")
                             .and_then(|id| id.as_i64())
@@ -202,19 +216,19 @@ impl ParadexCodec {
                         symbol: data
                             .get("symbol")
                             .and_then(|s| s.as_str())
-                            .map(conversion::string_to_symbol)
+                            .map(|symbol| SYMBOLS.from_exchange_symbol(symbol))
                             .unwrap_or_default(),
                         id: data.get("id").and_then(|i| i.as_i64()).unwrap_or_default(),
                         price: data
                             .get("price")
                             .and_then(|p| p.as_str())
-                            .map(conversion::string_to_price)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         quantity: data
                             .get("quantity")
                             .and_then(|q| q.as_str())
-                            .map(conversion::string_to_quantity)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Quantity::ZERO), conversion::try_string_to_quantity)
+                            .ok()?,
                         time: data
                             .get("time")
                             .and_then(|t| t.as_i64())
@@ -227,11 +241,11 @@ impl ParadexCodec {
                     Some(ParadexWsEvent::Trade(trade))
                 }
                 "kline" => {
-                    let kline = Kline {
+                    let mut kline = Kline {
                         symbol: data
                             .get("symbol")
                             .and_then(|s| s.as_str())
-                            .map(conversion::string_to_symbol)
+                            .map(|symbol| SYMBOLS.from_exchange_symbol(symbol))
                             .unwrap_or_default(),
                         open_time: data
                             .get("open_time")
@@ -249,28 +263,28 @@ impl ParadexCodec {
                         open_price: data
                             .get("open_price")
                             .and_then(|o| o.as_str())
-                            .map(conversion::string_to_price)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         high_price: data
                             .get("high_price")
                             .and_then(|h| h.as_str())
-                            .map(conversion::string_to_price)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         low_price: data
                             .get("low_price")
                             .and_then(|l| l.as_str())
-                            .map(conversion::string_to_price)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         close_price: data
                             .get("close_price")
                             .and_then(|c| c.as_str())
-                            .map(conversion::string_to_price)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+                            .ok()?,
                         volume: data
                             .get("volume")
                             .and_then(|v| v.as_str())
-                            .map(conversion::string_to_volume)
-                            .unwrap_or_default(),
+                            .map_or(Ok(Volume::ZERO), conversion::try_string_to_volume)
+                            .ok()?,
                         number_of_trades: data
                             .get("number_of_trades")
                             .and_then(|n| n.as_i64())
@@ -280,8 +294,40 @@ impl ParadexCodec {
                             .and_then(|f| f.as_bool())
                             .unwrap_or(true),
                     };
+                    if let Some(interval) = KlineInterval::from_venue_str(&kline.interval) {
+                        kline.close_time = interval.close_time(kline.open_time);
+                    }
                     Some(ParadexWsEvent::Kline(kline))
                 }
+                // Funding accrues continuously, so there is no next funding time
+                channel if channel.starts_with("markets_summary") => {
+                    let mark_price = MarkPrice {
+                        symbol: data
+                            .get("symbol")
+                            .and_then(|s| s.as_str())
+                            .map(|symbol| SYMBOLS.from_exchange_symbol(symbol))
+                            .unwrap_or_default(),
+                        mark_price: data
+                            .get("mark_price")
+                            .and_then(|p| p.as_str())
+                            .map(conversion::try_string_to_price)?
+                            .ok()?,
+                        index_price: data
+                            .get("underlying_price")
+                            .and_then(|p| p.as_str())
+                            .and_then(|p| conversion::try_string_to_price(p).ok()),
+                        funding_rate: data
+                            .get("funding_rate")
+                            .and_then(|r| r.as_str())
+                            .and_then(|r| conversion::try_string_to_decimal(r).ok()),
+                        next_funding_time: None,
+                        timestamp: data
+                            .get("created_at")
+                            .and_then(|t| t.as_i64())
+                            .unwrap_or_default(),
+                    };
+                    Some(ParadexWsEvent::MarkPrice(mark_price))
+                }
                 _ => None, // Unknown channel
             }
         } else {
@@ -299,14 +345,16 @@ impl ParadexCodec {
 pub fn create_subscription_channel(symbol: &str, subscription_type: &SubscriptionType) -> String {
     match subscription_type {
         SubscriptionType::Ticker => format!("ticker@{}", symbol),
-        SubscriptionType::OrderBook { depth } => depth.as_ref().map_or_else(
-            || format!("depth@{}", symbol),
-            |depth| format!("depth{}@{}", depth, symbol),
-        ),
-        SubscriptionType::Trades => format!("trade@{}", symbol),
+        SubscriptionType::OrderBook {
+            depth: Some(depth),
+            mode: OrderBookMode::Snapshot | OrderBookMode::Aggregated,
+        } => format!("depth{}@{}", depth, symbol),
+        SubscriptionType::OrderBook { .. } => format!("depth@{}", symbol),
+        SubscriptionType::Trades | SubscriptionType::AggTrades => format!("trade@{}", symbol),
         SubscriptionType::Klines { interval } => {
             format!("kline_{}@{}", interval.to_binance_format(), symbol)
         }
+        SubscriptionType::MarkPrice => format!("markets_summary.{}", symbol),
     }
 }
 
@@ -317,6 +365,7 @@ impl From<ParadexWsEvent> for Option<MarketDataType> {
             ParadexWsEvent::OrderBook(orderbook) => Some(MarketDataType::OrderBook(orderbook)),
             ParadexWsEvent::Trade(trade) => Some(MarketDataType::Trade(trade)),
             ParadexWsEvent::Kline(kline) => Some(MarketDataType::Kline(kline)),
+            ParadexWsEvent::MarkPrice(mark_price) => Some(MarketDataType::MarkPrice(mark_price)),
             ParadexWsEvent::SubscriptionConfirmation(_)
             | ParadexWsEvent::Error(_)
             | ParadexWsEvent::Heartbeat => None,
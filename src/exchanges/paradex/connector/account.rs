@@ -1,7 +1,7 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
-use crate::core::traits::AccountInfo;
-use crate::core::types::{Balance, Position};
+use crate::core::traits::{AccountInfo, AccountSummarySource};
+use crate::core::types::{AccountSummary, Balance, Position};
 use crate::exchanges::paradex::rest::ParadexRestClient;
 use async_trait::async_trait;
 use tracing::instrument;
@@ -24,15 +24,33 @@ impl<R: RestClient> Account<R> {
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync> AccountInfo for Account<R> {
+    fn supports_account(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     #[instrument(skip(self), fields(exchange = "paradex"))]
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         let paradex_balances = self.rest.get_account_balances().await?;
-        Ok(paradex_balances.into_iter().map(Into::into).collect())
+        paradex_balances
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect()
     }
 
     #[instrument(skip(self), fields(exchange = "paradex"))]
     async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
         let paradex_positions = self.rest.get_positions().await?;
-        Ok(paradex_positions.into_iter().map(Into::into).collect())
+        paradex_positions
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> AccountSummarySource for Account<R> {
+    #[instrument(skip(self), fields(exchange = "paradex"))]
+    async fn get_account_summary(&self) -> Result<AccountSummary, ExchangeError> {
+        self.rest.get_account().await?.try_into()
     }
 }
@@ -1,94 +1,122 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::rest::RestClient;
-use crate::core::traits::{FundingRateSource, MarketDataSource};
+use crate::core::kernel::{TungsteniteWs, WsConfig};
+use crate::core::streaming::{SharedHub, StreamRouter};
+use crate::core::traits::{
+    FundingRateSource, MarketSnapshotSource, RestMarketData, StreamingMarketData,
+};
 use crate::core::types::{
-    FundingRate, Kline, KlineInterval, Market, MarketDataType, SubscriptionType, WebSocketConfig,
+    FundingRate, Kline, KlineInterval, Market, MarketDataType, OrderBook, SubscriptionType, Symbol,
+    Ticker, Trade, WebSocketConfig,
 };
-use crate::exchanges::paradex::codec::ParadexWsEvent;
+use crate::exchanges::paradex::codec::{create_subscription_channel, ParadexCodec, ParadexWsEvent};
+use crate::exchanges::paradex::conversions::SYMBOLS;
 use crate::exchanges::paradex::conversions::{
     convert_paradex_funding_rate, convert_paradex_kline, convert_paradex_market,
+    convert_paradex_market_summary, convert_paradex_order_book, convert_paradex_trade,
+    PARADEX_FUNDING_PERIOD,
 };
 use crate::exchanges::paradex::rest::ParadexRestClient;
 use async_trait::async_trait;
 use tokio::sync::mpsc;
 use tracing::{error, instrument};
 
+/// Routes Paradex channel updates
+struct ParadexRouter;
+
+impl StreamRouter for ParadexRouter {
+    type Codec = ParadexCodec;
+
+    fn stream_id(
+        &self,
+        symbol: &Symbol,
+        subscription: &SubscriptionType,
+    ) -> Result<Option<String>, ExchangeError> {
+        Ok(Some(create_subscription_channel(
+            &SYMBOLS.try_to_exchange_symbol(symbol)?,
+            subscription,
+        )))
+    }
+
+    fn convert(&self, event: ParadexWsEvent) -> Vec<MarketDataType> {
+        if let ParadexWsEvent::Error(message) = &event {
+            error!(error = %message, "Paradex WebSocket error");
+        }
+        Option::<MarketDataType>::from(event).into_iter().collect()
+    }
+}
+
 /// Market data connector for Paradex
 pub struct MarketData<R: RestClient, W = ()> {
     rest: ParadexRestClient<R>,
-    _ws: Option<W>,
+    #[allow(dead_code)] // Subscriptions go through `hub`, which opens its own session
+    ws: Option<W>,
+    /// Shared by every subscription, started on the first one
+    hub: SharedHub,
+    ws_url: String,
+    ws_config: WsConfig,
+}
+
+impl<R: RestClient, W> MarketData<R, W> {
+    /// Open subscription sessions with `config`, e.g. to route them through a proxy
+    #[must_use]
+    pub fn with_ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = config;
+        self
+    }
 }
 
 impl<R: RestClient + Clone> MarketData<R, ()> {
-    pub fn new(rest: &R, _ws: Option<()>) -> Self {
+    /// Create a new market data source streaming from `ws_url`
+    pub fn new(rest: &R, _ws: Option<()>, ws_url: String) -> Self {
         Self {
             rest: ParadexRestClient::new(rest.clone()),
-            _ws: None,
+            ws: None,
+            hub: SharedHub::new(),
+            ws_url,
+            ws_config: WsConfig::default(),
         }
     }
 }
 
 impl<R: RestClient + Clone, W> MarketData<R, W> {
-    pub fn new_with_ws(rest: &R, ws: W) -> Self {
+    /// Create a new market data source streaming from `ws_url`
+    pub fn new_with_ws(rest: &R, ws: W, ws_url: String) -> Self {
         Self {
             rest: ParadexRestClient::new(rest.clone()),
-            _ws: Some(ws),
+            ws: Some(ws),
+            hub: SharedHub::new(),
+            ws_url,
+            ws_config: WsConfig::default(),
         }
     }
 }
 
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketDataSource for MarketData<R, W> {
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> RestMarketData for MarketData<R, W> {
     #[instrument(skip(self), fields(exchange = "paradex"))]
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         let paradex_markets = self.rest.get_markets().await?;
-        Ok(paradex_markets
+        paradex_markets
             .into_iter()
             .map(convert_paradex_market)
-            .collect())
-    }
-
-    #[instrument(
-        skip(self, _config),
-        fields(
-            exchange = "paradex",
-            symbols_count = _symbols.len(),
-            subscription_types = ?_subscription_types
-        )
-    )]
-    async fn subscribe_market_data(
-        &self,
-        _symbols: Vec<String>,
-        _subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        // Check if WebSocket is available
-        if self._ws.is_none() {
-            return Err(ExchangeError::WebSocketError(
-                "WebSocket not available in REST-only mode".to_string(),
-            ));
-        }
-
-        // For now, return an error since WebSocket implementation needs the kernel WsSession
-        // This will be implemented when the WebSocket session is properly integrated
-        Err(ExchangeError::WebSocketError(
-            "WebSocket integration in progress".to_string(),
-        ))
+            .collect()
     }
 
-    fn get_websocket_url(&self) -> String {
-        "wss://ws.paradex.trade/v1".to_string()
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.rest.get_server_time().await
     }
 
     #[instrument(skip(self), fields(exchange = "paradex", symbol = %symbol))]
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> Result<Vec<Kline>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let response = self
             .rest
             .get_klines(&symbol, interval, limit, start_time, end_time)
@@ -110,7 +138,7 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketDataSource for M
             |data| {
                 let klines = data
                     .iter()
-                    .filter_map(|item| convert_paradex_kline(item, &symbol))
+                    .filter_map(|item| convert_paradex_kline(item, &symbol, interval))
                     .collect();
                 Ok(klines)
             },
@@ -118,13 +146,104 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketDataSource for M
     }
 }
 
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for MarketData<R, W>
+{
+    #[instrument(skip(self), fields(exchange = "paradex", symbol = %symbol))]
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        let response = self
+            .rest
+            .get_market_summary(&SYMBOLS.try_to_exchange_symbol(&symbol)?)
+            .await?;
+        let summary = response.results.first().ok_or_else(|| {
+            ExchangeError::InvalidParameters(format!("Unknown symbol: {}", symbol))
+        })?;
+        convert_paradex_market_summary(summary, symbol)
+    }
+
+    #[instrument(skip(self), fields(exchange = "paradex", symbol = %symbol))]
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        let book = self
+            .rest
+            .get_order_book(&SYMBOLS.try_to_exchange_symbol(&symbol)?, depth)
+            .await?;
+        Ok(convert_paradex_order_book(&book, symbol)?.truncated(depth))
+    }
+
+    #[instrument(skip(self), fields(exchange = "paradex", symbol = %symbol))]
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        let response = self
+            .rest
+            .get_trades(&SYMBOLS.try_to_exchange_symbol(&symbol)?, limit)
+            .await?;
+        // Listed newest first
+        response
+            .results
+            .iter()
+            .rev()
+            .map(|trade| convert_paradex_trade(trade, symbol.clone()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> StreamingMarketData for MarketData<R, W> {
+    #[instrument(
+        skip(self, config),
+        fields(
+            exchange = "paradex",
+            symbols_count = symbols.len(),
+            subscription_types = ?subscription_types
+        )
+    )]
+    async fn subscribe_market_data(
+        &self,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
+    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+        self.hub
+            .get_or_start(
+                || {
+                    TungsteniteWs::new(self.ws_url.clone(), "paradex".to_string(), ParadexCodec)
+                        .with_config(self.ws_config.clone())
+                },
+                ParadexRouter,
+            )
+            .await?
+            .subscribe_market_data(symbols, subscription_types, config.as_ref())
+            .await
+    }
+
+    fn get_websocket_url(&self) -> String {
+        self.ws_url.clone()
+    }
+}
+
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource for MarketData<R, W> {
     #[instrument(skip(self), fields(exchange = "paradex"))]
     async fn get_funding_rates(
         &self,
-        symbols: Option<Vec<String>>,
+        symbols: Option<Vec<Symbol>>,
     ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let symbols = symbols
+            .map(|symbols| {
+                symbols
+                    .iter()
+                    .map(|symbol| SYMBOLS.try_to_exchange_symbol(symbol))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
         let paradex_rates = self.rest.get_funding_rates(symbols).await?;
         Ok(paradex_rates
             .into_iter()
@@ -140,11 +259,12 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource for
     #[instrument(skip(self), fields(exchange = "paradex", symbol = %symbol))]
     async fn get_funding_rate_history(
         &self,
-        symbol: String,
+        symbol: Symbol,
         start_time: Option<i64>,
         end_time: Option<i64>,
         limit: Option<u32>,
     ) -> Result<Vec<FundingRate>, ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         let history = self
             .rest
             .get_funding_rate_history(&symbol, start_time, end_time, limit)
@@ -153,10 +273,11 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource for
         Ok(history
             .into_iter()
             .map(|h| FundingRate {
-                symbol: crate::core::types::conversion::string_to_symbol(&h.symbol),
-                funding_rate: Some(crate::core::types::conversion::string_to_decimal(
+                symbol: SYMBOLS.from_exchange_symbol(&h.symbol),
+                funding_rate: crate::core::types::conversion::try_string_to_decimal(
                     &h.funding_rate,
-                )),
+                )
+                .ok(),
                 previous_funding_rate: None,
                 next_funding_rate: None,
                 funding_time: Some(h.funding_time),
@@ -164,39 +285,8 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource for
                 mark_price: None,
                 index_price: None,
                 timestamp: chrono::Utc::now().timestamp_millis(),
+                funding_interval: Some(PARADEX_FUNDING_PERIOD),
             })
             .collect())
     }
 }
-
-impl<R: RestClient + Clone, W> MarketData<R, W> {
-    /// Helper function to convert WebSocket events to `MarketDataType`
-    #[allow(dead_code)]
-    fn convert_ws_event(event: ParadexWsEvent) -> Option<MarketDataType> {
-        match event {
-            ParadexWsEvent::Ticker(ticker) => Some(MarketDataType::Ticker(ticker)),
-            ParadexWsEvent::OrderBook(orderbook) => Some(MarketDataType::OrderBook(orderbook)),
-            ParadexWsEvent::Trade(trade) => Some(MarketDataType::Trade(trade)),
-            ParadexWsEvent::Kline(kline) => Some(MarketDataType::Kline(kline)),
-            ParadexWsEvent::SubscriptionConfirmation(_)
-            | ParadexWsEvent::Error(_)
-            | ParadexWsEvent::Heartbeat => None,
-        }
-    }
-}
-
-/// Helper function to create subscription channels for Paradex WebSocket
-#[allow(dead_code)]
-fn create_subscription_channel(symbol: &str, subscription_type: &SubscriptionType) -> String {
-    match subscription_type {
-        SubscriptionType::Ticker => format!("ticker@{}", symbol),
-        SubscriptionType::OrderBook { depth } => depth.as_ref().map_or_else(
-            || format!("depth@{}", symbol),
-            |depth| format!("depth{}@{}", depth, symbol),
-        ),
-        SubscriptionType::Trades => format!("trade@{}", symbol),
-        SubscriptionType::Klines { interval } => {
-            format!("kline_{}@{}", interval.to_binance_format(), symbol)
-        }
-    }
-}
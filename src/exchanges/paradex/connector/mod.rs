@@ -1,10 +1,17 @@
 use crate::core::errors::ExchangeError;
-use crate::core::traits::{AccountInfo, FundingRateSource, MarketDataSource, OrderPlacer};
+use crate::core::traits::{
+    AccountInfo, AccountSummarySource, DiagnosticsSource, FundingRateSource, MarketSnapshotSource,
+    OrderHistorySource, OrderPlacer, PositionManager, RestMarketData, StreamingMarketData,
+};
 use crate::core::types::{
-    Balance, FundingRate, Kline, KlineInterval, Market, MarketDataType, OrderRequest,
-    OrderResponse, Position, SubscriptionType, WebSocketConfig,
+    AccountSummary, Balance, FundingRate, Kline, KlineInterval, LeverageBracket, MarginMode,
+    Market, MarketDataType, OrderBook, OrderPage, OrderRequest, OrderResponse, Position,
+    SubscriptionType, Symbol, Ticker, Trade, WebSocketConfig,
+};
+use crate::core::{
+    config::ExchangeConfig, kernel::diagnostics, kernel::Diagnostics, kernel::RestClient,
+    kernel::WsSession,
 };
-use crate::core::{config::ExchangeConfig, kernel::RestClient, kernel::WsSession};
 use crate::exchanges::paradex::codec::ParadexCodec;
 use async_trait::async_trait;
 use tokio::sync::mpsc;
@@ -27,10 +34,11 @@ pub struct ParadexConnector<R: RestClient, W = ()> {
 impl<R: RestClient + Clone + Send + Sync, W: WsSession<ParadexCodec> + Send + Sync>
     ParadexConnector<R, W>
 {
-    /// Create a new Paradex connector with WebSocket support
-    pub fn new(rest: R, ws: W, _config: ExchangeConfig) -> Self {
+    /// Create a new Paradex connector with WebSocket support, streaming from `ws_url`
+    pub fn new(rest: R, ws: W, ws_url: String, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::<R, W>::new_with_ws(&rest, ws),
+            market: MarketData::<R, W>::new_with_ws(&rest, ws, ws_url)
+                .with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
         }
@@ -38,10 +46,11 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<ParadexCodec> + Send + Sy
 }
 
 impl<R: RestClient + Clone + Send + Sync> ParadexConnector<R, ()> {
-    /// Create a new Paradex connector without WebSocket support
-    pub fn new_without_ws(rest: R, _config: ExchangeConfig) -> Self {
+    /// Create a new Paradex connector without WebSocket support, streaming from `ws_url`
+    pub fn new_without_ws(rest: R, ws_url: String, config: ExchangeConfig) -> Self {
         Self {
-            market: MarketData::<R, ()>::new(&rest, None),
+            market: MarketData::<R, ()>::new(&rest, None, ws_url)
+                .with_ws_config(config.ws_config()),
             trading: Trading::new(&rest),
             account: Account::new(&rest),
         }
@@ -51,31 +60,20 @@ impl<R: RestClient + Clone + Send + Sync> ParadexConnector<R, ()> {
 // Implement traits for the connector by delegating to sub-components
 
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync, W: WsSession<ParadexCodec> + Send + Sync> MarketDataSource
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<ParadexCodec> + Send + Sync> RestMarketData
     for ParadexConnector<R, W>
 {
     async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
         self.market.get_markets().await
     }
 
-    async fn subscribe_market_data(
-        &self,
-        symbols: Vec<String>,
-        subscription_types: Vec<SubscriptionType>,
-        config: Option<WebSocketConfig>,
-    ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        self.market
-            .subscribe_market_data(symbols, subscription_types, config)
-            .await
-    }
-
-    fn get_websocket_url(&self) -> String {
-        self.market.get_websocket_url()
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
     }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
@@ -88,29 +86,38 @@ impl<R: RestClient + Clone + Send + Sync, W: WsSession<ParadexCodec> + Send + Sy
 }
 
 #[async_trait]
-impl<R: RestClient + Clone + Send + Sync> MarketDataSource for ParadexConnector<R, ()> {
-    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
-        self.market.get_markets().await
-    }
-
+impl<R: RestClient + Clone + Send + Sync, W: WsSession<ParadexCodec> + Send + Sync>
+    StreamingMarketData for ParadexConnector<R, W>
+{
     async fn subscribe_market_data(
         &self,
-        _symbols: Vec<String>,
-        _subscription_types: Vec<SubscriptionType>,
-        _config: Option<WebSocketConfig>,
+        symbols: Vec<Symbol>,
+        subscription_types: Vec<SubscriptionType>,
+        config: Option<WebSocketConfig>,
     ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
-        Err(ExchangeError::WebSocketError(
-            "WebSocket not available in REST-only mode".to_string(),
-        ))
+        self.market
+            .subscribe_market_data(symbols, subscription_types, config)
+            .await
     }
 
     fn get_websocket_url(&self) -> String {
         self.market.get_websocket_url()
     }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> RestMarketData for ParadexConnector<R, ()> {
+    async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+        self.market.get_markets().await
+    }
+
+    async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        self.market.get_server_time().await
+    }
 
     async fn get_klines(
         &self,
-        symbol: String,
+        symbol: Symbol,
         interval: KlineInterval,
         limit: Option<u32>,
         start_time: Option<i64>,
@@ -122,19 +129,103 @@ impl<R: RestClient + Clone + Send + Sync> MarketDataSource for ParadexConnector<
     }
 }
 
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> MarketSnapshotSource
+    for ParadexConnector<R, W>
+{
+    async fn get_ticker(&self, symbol: Symbol) -> Result<Ticker, ExchangeError> {
+        self.market.get_ticker(symbol).await
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        symbol: Symbol,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, ExchangeError> {
+        self.market.get_order_book_snapshot(symbol, depth).await
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: Symbol,
+        limit: Option<u32>,
+    ) -> Result<Vec<Trade>, ExchangeError> {
+        self.market.get_recent_trades(symbol, limit).await
+    }
+}
+
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderPlacer for ParadexConnector<R, W> {
+    fn supports_trading(&self) -> bool {
+        self.trading.supports_trading()
+    }
+
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
         self.trading.place_order(order).await
     }
 
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
         self.trading.cancel_order(symbol, order_id).await
     }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.trading
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> PositionManager
+    for ParadexConnector<R, W>
+{
+    async fn set_leverage(&self, symbol: Symbol, leverage: u32) -> Result<(), ExchangeError> {
+        self.trading.set_leverage(symbol, leverage).await
+    }
+
+    async fn set_margin_mode(&self, symbol: Symbol, mode: MarginMode) -> Result<(), ExchangeError> {
+        self.trading.set_margin_mode(symbol, mode).await
+    }
+
+    async fn get_leverage_brackets(
+        &self,
+        symbol: Symbol,
+    ) -> Result<Vec<LeverageBracket>, ExchangeError> {
+        self.trading.get_leverage_brackets(symbol).await
+    }
+
+    async fn close_position(&self, symbol: Symbol) -> Result<Option<OrderResponse>, ExchangeError> {
+        self.trading.close_position(symbol).await
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> OrderHistorySource
+    for ParadexConnector<R, W>
+{
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        self.trading
+            .get_order_history(symbol, start_time, end_time, cursor)
+            .await
+    }
 }
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo for ParadexConnector<R, W> {
+    fn supports_account(&self) -> bool {
+        self.account.supports_account()
+    }
+
     async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
         self.account.get_account_balance().await
     }
@@ -144,13 +235,22 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountInfo for Parade
     }
 }
 
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> AccountSummarySource
+    for ParadexConnector<R, W>
+{
+    async fn get_account_summary(&self) -> Result<AccountSummary, ExchangeError> {
+        self.account.get_account_summary().await
+    }
+}
+
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
     for ParadexConnector<R, W>
 {
     async fn get_funding_rates(
         &self,
-        symbols: Option<Vec<String>>,
+        symbols: Option<Vec<Symbol>>,
     ) -> Result<Vec<FundingRate>, ExchangeError> {
         self.market.get_funding_rates(symbols).await
     }
@@ -161,7 +261,7 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
 
     async fn get_funding_rate_history(
         &self,
-        symbol: String,
+        symbol: Symbol,
         start_time: Option<i64>,
         end_time: Option<i64>,
         limit: Option<u32>,
@@ -171,3 +271,9 @@ impl<R: RestClient + Clone + Send + Sync, W: Send + Sync> FundingRateSource
             .await
     }
 }
+
+impl<R: RestClient, W> DiagnosticsSource for ParadexConnector<R, W> {
+    fn diagnostics(&self) -> Diagnostics {
+        diagnostics("paradex")
+    }
+}
@@ -1,7 +1,11 @@
 use crate::core::errors::ExchangeError;
 use crate::core::kernel::rest::RestClient;
-use crate::core::traits::OrderPlacer;
-use crate::core::types::{OrderRequest, OrderResponse, OrderSide, OrderType};
+use crate::core::traits::{OrderHistorySource, OrderPlacer, PositionManager};
+use crate::core::types::{
+    LeverageBracket, MarginMode, OrderPage, OrderRequest, OrderResponse, OrderSide, OrderType,
+    Position, Symbol,
+};
+use crate::exchanges::paradex::conversions::{convert_historical_order, SYMBOLS};
 use crate::exchanges::paradex::rest::ParadexRestClient;
 use async_trait::async_trait;
 use serde_json::{json, Value};
@@ -25,6 +29,10 @@ impl<R: RestClient> Trading<R> {
 
 #[async_trait]
 impl<R: RestClient + Clone + Send + Sync> OrderPlacer for Trading<R> {
+    fn supports_trading(&self) -> bool {
+        self.rest.can_sign()
+    }
+
     #[instrument(
         skip(self),
         fields(
@@ -37,7 +45,7 @@ impl<R: RestClient + Clone + Send + Sync> OrderPlacer for Trading<R> {
     )]
     async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
         // Convert order to Paradex format
-        let paradex_order = convert_order_request(&order);
+        let paradex_order = convert_order_request(&order)?;
 
         // Place the order using the REST client
         let response = self.rest.place_order(&paradex_order).await?;
@@ -55,6 +63,7 @@ impl<R: RestClient + Clone + Send + Sync> OrderPlacer for Trading<R> {
             timestamp: chrono::DateTime::parse_from_rfc3339(&response.created_at)
                 .unwrap_or_else(|_| chrono::Utc::now().into())
                 .timestamp_millis(),
+            extensions: None,
         })
     }
 
@@ -66,7 +75,8 @@ impl<R: RestClient + Clone + Send + Sync> OrderPlacer for Trading<R> {
             order_id = %order_id
         )
     )]
-    async fn cancel_order(&self, symbol: String, order_id: String) -> Result<(), ExchangeError> {
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        let symbol = SYMBOLS.try_to_exchange_symbol(&symbol)?;
         // Cancel the order using the REST client
         let _response = self.rest.cancel_order(&order_id).await?;
 
@@ -79,10 +89,119 @@ impl<R: RestClient + Clone + Send + Sync> OrderPlacer for Trading<R> {
 
         Ok(())
     }
+
+    #[instrument(
+        skip(self),
+        fields(
+            exchange = "paradex",
+            symbol = %symbol,
+            client_order_id = %client_order_id
+        )
+    )]
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        let market = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        self.rest
+            .cancel_order_by_client_id(&market, &client_order_id)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> PositionManager for Trading<R> {
+    #[instrument(skip(self), fields(exchange = "paradex", symbol = %symbol))]
+    async fn set_leverage(&self, symbol: Symbol, leverage: u32) -> Result<(), ExchangeError> {
+        let market = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        // Paradex sets leverage and margin type together, so keep the current type
+        let config = self.rest.get_margin_config(&market).await?;
+        self.rest
+            .set_margin_config(&market, leverage, &config.margin_type)
+            .await?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(exchange = "paradex", symbol = %symbol))]
+    async fn set_margin_mode(&self, symbol: Symbol, mode: MarginMode) -> Result<(), ExchangeError> {
+        let market = SYMBOLS.try_to_exchange_symbol(&symbol)?;
+        let margin_type = match mode {
+            MarginMode::Cross => "CROSS",
+            MarginMode::Isolated => "ISOLATED",
+        };
+        let config = self.rest.get_margin_config(&market).await?;
+        self.rest
+            .set_margin_config(&market, config.leverage, margin_type)
+            .await?;
+        Ok(())
+    }
+
+    async fn get_leverage_brackets(
+        &self,
+        _symbol: Symbol,
+    ) -> Result<Vec<LeverageBracket>, ExchangeError> {
+        // Margin requirements scale with position size by formula rather than by tier
+        Err(ExchangeError::NotSupported(
+            "Paradex has no leverage brackets".to_string(),
+        ))
+    }
+
+    #[instrument(skip(self), fields(exchange = "paradex", symbol = %symbol))]
+    async fn close_position(&self, symbol: Symbol) -> Result<Option<OrderResponse>, ExchangeError> {
+        for position in self.rest.get_positions().await? {
+            let position: Position = position.try_into()?;
+            if position.symbol != symbol {
+                continue;
+            }
+            if let Some(order) = position.close_order() {
+                return self.place_order(order).await.map(Some);
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Largest page `orders-history` returns
+const ORDER_HISTORY_LIMIT: u32 = 100;
+
+#[async_trait]
+impl<R: RestClient + Clone + Send + Sync> OrderHistorySource for Trading<R> {
+    /// Pages run newest first; the cursor is the `next` token Paradex returns
+    #[instrument(skip(self), fields(exchange = "paradex"))]
+    async fn get_order_history(
+        &self,
+        symbol: Symbol,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<OrderPage, ExchangeError> {
+        let page = self
+            .rest
+            .get_orders_history(
+                &SYMBOLS.try_to_exchange_symbol(&symbol)?,
+                start_time,
+                end_time,
+                cursor.as_deref(),
+                ORDER_HISTORY_LIMIT,
+            )
+            .await?;
+
+        let orders = page
+            .results
+            .iter()
+            .map(convert_historical_order)
+            .collect::<Result<_, _>>()?;
+        Ok(OrderPage {
+            orders,
+            next_cursor: page.next.filter(|next| !next.is_empty()),
+        })
+    }
 }
 
 /// Convert `OrderRequest` to Paradex JSON format
-fn convert_order_request(order: &OrderRequest) -> Value {
+fn convert_order_request(order: &OrderRequest) -> Result<Value, ExchangeError> {
     let side = match order.side {
         OrderSide::Buy => "BUY",
         OrderSide::Sell => "SELL",
@@ -98,7 +217,7 @@ fn convert_order_request(order: &OrderRequest) -> Value {
     };
 
     let mut paradex_order = json!({
-        "market": order.symbol.to_string(),
+        "market": SYMBOLS.try_to_exchange_symbol(&order.symbol)?,
         "side": side,
         "type": order_type,
         "size": order.quantity.to_string(),
@@ -119,5 +238,14 @@ fn convert_order_request(order: &OrderRequest) -> Value {
         paradex_order["time_in_force"] = json!(time_in_force.to_string());
     }
 
-    paradex_order
+    if let Some(client_order_id) = &order.client_order_id {
+        paradex_order["client_id"] = json!(client_order_id);
+    }
+
+    // Paradex has no whole-position flag, so a close is sent reduce-only
+    if order.reduce_only == Some(true) || order.close_position == Some(true) {
+        paradex_order["flags"] = json!(["REDUCE_ONLY"]);
+    }
+
+    Ok(paradex_order)
 }
@@ -1,44 +1,116 @@
+use crate::core::errors::ExchangeError;
+use crate::core::symbol::SymbolConvention;
 use crate::core::types::{
-    conversion, Balance, FundingRate, Kline, Market, OrderResponse, OrderSide, OrderType, Position,
-    PositionSide, Symbol,
+    conversion, AccountSummary, Balance, ContractStyle, FundingRate, Kline, KlineInterval, Market,
+    MarketStatus, Order, OrderBook, OrderResponse, OrderSide, OrderStatus, OrderType, Position,
+    PositionSide, Price, Quantity, Symbol, Ticker, Trade, Volume,
 };
+use crate::exchanges::factory::ExchangeId;
+use crate::exchanges::paradex::rest::ParadexKlineInterval;
 use crate::exchanges::paradex::types::{
-    ParadexBalance, ParadexFundingRate, ParadexMarket, ParadexOrder, ParadexPosition,
+    ParadexAccount, ParadexBalance, ParadexFundingRate, ParadexHistoricalOrder, ParadexMarket,
+    ParadexMarketSummary, ParadexOrder, ParadexOrderBook, ParadexPosition, ParadexTrade,
 };
+use rust_decimal::Decimal;
 use serde_json::Value;
+use std::time::Duration;
+
+/// Paradex symbol spelling, applied to every symbol sent or received
+pub const SYMBOLS: SymbolConvention = ExchangeId::Paradex.symbol_convention();
 
 /// Convert `ParadexMarket` to Market
-pub fn convert_paradex_market(market: ParadexMarket) -> Market {
-    Market {
+pub fn convert_paradex_market(market: ParadexMarket) -> Result<Market, ExchangeError> {
+    Ok(Market {
         symbol: Symbol::new(market.base_asset.symbol, market.quote_asset.symbol)
-            .unwrap_or_else(|_| conversion::string_to_symbol(&market.symbol)),
-        status: market.status,
+            .unwrap_or_else(|_| SYMBOLS.from_exchange_symbol(&market.symbol)),
+        status: MarketStatus::from_venue_str(&market.status),
         base_precision: market.base_asset.decimals,
         quote_precision: market.quote_asset.decimals,
-        min_qty: Some(conversion::string_to_quantity(&market.min_order_size)),
-        max_qty: Some(conversion::string_to_quantity(&market.max_order_size)),
-        min_price: Some(conversion::string_to_price(&market.min_price)),
-        max_price: Some(conversion::string_to_price(&market.max_price)),
-    }
+        min_qty: Some(conversion::try_string_to_quantity(&market.min_order_size)?),
+        max_qty: Some(conversion::try_string_to_quantity(&market.max_order_size)?),
+        min_price: Some(conversion::try_string_to_price(&market.min_price)?),
+        max_price: Some(conversion::try_string_to_price(&market.max_price)?),
+        contract_style: ContractStyle::Linear,
+        contract_size: None,
+        extensions: None,
+    })
+}
+
+/// Convert `ParadexMarketSummary` to `Ticker`
+///
+/// Paradex reports neither 24h high/low, base volume nor trade count, so those are zero.
+pub fn convert_paradex_market_summary(
+    summary: &ParadexMarketSummary,
+    symbol: Symbol,
+) -> Result<Ticker, ExchangeError> {
+    let price = conversion::try_string_to_price(&summary.last_traded_price)?;
+    let change_rate = conversion::try_string_to_decimal(&summary.price_change_rate_24h)?;
+    // last = open * (1 + rate)
+    let open = price
+        .value()
+        .checked_div(Decimal::ONE + change_rate)
+        .unwrap_or(Decimal::ZERO);
+    Ok(Ticker {
+        symbol,
+        price,
+        price_change: Price::new(price.value() - open),
+        price_change_percent: change_rate * Decimal::ONE_HUNDRED,
+        high_price: Price::ZERO,
+        low_price: Price::ZERO,
+        volume: Volume::ZERO,
+        quote_volume: conversion::try_string_to_volume(&summary.volume_24h)?,
+        open_time: summary.created_at - 24 * 60 * 60 * 1000,
+        close_time: summary.created_at,
+        count: 0,
+    })
+}
+
+/// Convert `ParadexOrderBook` to `OrderBook`
+pub fn convert_paradex_order_book(
+    book: &ParadexOrderBook,
+    symbol: Symbol,
+) -> Result<OrderBook, ExchangeError> {
+    Ok(OrderBook {
+        symbol,
+        bids: conversion::try_book_levels(&book.bids)?,
+        asks: conversion::try_book_levels(&book.asks)?,
+        last_update_id: book.seq_no,
+    })
 }
 
+/// Convert `ParadexTrade` to `Trade`
+pub fn convert_paradex_trade(trade: &ParadexTrade, symbol: Symbol) -> Result<Trade, ExchangeError> {
+    Ok(Trade {
+        symbol,
+        id: trade.id.parse().unwrap_or(0),
+        price: conversion::try_string_to_price(&trade.price)?,
+        quantity: conversion::try_string_to_quantity(&trade.size)?,
+        time: trade.created_at,
+        is_buyer_maker: trade.side == "SELL",
+    })
+}
+
+/// Paradex accrues funding continuously over an 8h funding period
+pub const PARADEX_FUNDING_PERIOD: Duration = Duration::from_secs(8 * 60 * 60);
+
 /// Convert `ParadexFundingRate` to `FundingRate`
 pub fn convert_paradex_funding_rate(rate: ParadexFundingRate) -> FundingRate {
     FundingRate {
-        symbol: conversion::string_to_symbol(&rate.symbol),
-        funding_rate: Some(conversion::string_to_decimal(&rate.funding_rate)),
+        symbol: SYMBOLS.from_exchange_symbol(&rate.symbol),
+        funding_rate: conversion::try_string_to_decimal(&rate.funding_rate).ok(),
         previous_funding_rate: None,
         next_funding_rate: None,
         funding_time: None,
         next_funding_time: Some(rate.next_funding_time),
-        mark_price: Some(conversion::string_to_price(&rate.mark_price)),
-        index_price: Some(conversion::string_to_price(&rate.index_price)),
+        mark_price: conversion::try_string_to_price(&rate.mark_price).ok(),
+        index_price: conversion::try_string_to_price(&rate.index_price).ok(),
         timestamp: rate.timestamp,
+        funding_interval: Some(PARADEX_FUNDING_PERIOD),
     }
 }
 
 /// Convert JSON kline data to Kline
-pub fn convert_paradex_kline(data: &Value, symbol: &str) -> Option<Kline> {
+pub fn convert_paradex_kline(data: &Value, symbol: &str, interval: KlineInterval) -> Option<Kline> {
     // Paradex kline format: [timestamp, open, high, low, close, volume]
     let array = data.as_array()?;
     if array.len() < 6 {
@@ -50,96 +122,224 @@ pub fn convert_paradex_kline(data: &Value, symbol: &str) -> Option<Kline> {
         .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
 
     Some(Kline {
-        symbol: conversion::string_to_symbol(symbol),
+        symbol: SYMBOLS.from_exchange_symbol(symbol),
         open_time: timestamp,
-        close_time: timestamp + 60000, // Add 1 minute as default interval
-        interval: "1m".to_string(),    // Default to 1m
+        close_time: interval.close_time(timestamp),
+        interval: interval.to_paradex_format(),
         open_price: array[1]
             .as_str()
-            .map(conversion::string_to_price)
-            .unwrap_or_default(),
+            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+            .ok()?,
         high_price: array[2]
             .as_str()
-            .map(conversion::string_to_price)
-            .unwrap_or_default(),
+            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+            .ok()?,
         low_price: array[3]
             .as_str()
-            .map(conversion::string_to_price)
-            .unwrap_or_default(),
+            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+            .ok()?,
         close_price: array[4]
             .as_str()
-            .map(conversion::string_to_price)
-            .unwrap_or_default(),
+            .map_or(Ok(Price::ZERO), conversion::try_string_to_price)
+            .ok()?,
         volume: array[5]
             .as_str()
-            .map(conversion::string_to_volume)
-            .unwrap_or_default(),
+            .map_or(Ok(Volume::ZERO), conversion::try_string_to_volume)
+            .ok()?,
         number_of_trades: 0, // Not available from this data format
         final_bar: true,     // Assume final
     })
 }
 
-impl From<ParadexMarket> for Market {
-    fn from(market: ParadexMarket) -> Self {
+impl TryFrom<ParadexMarket> for Market {
+    type Error = ExchangeError;
+
+    fn try_from(market: ParadexMarket) -> Result<Self, Self::Error> {
         convert_paradex_market(market)
     }
 }
 
-impl From<ParadexOrder> for OrderResponse {
-    fn from(order: ParadexOrder) -> Self {
-        Self {
+/// Map a Paradex order type, falling back to market for unknown types
+fn convert_order_type(order_type: &str) -> OrderType {
+    match order_type {
+        "LIMIT" => OrderType::Limit,
+        "STOP_MARKET" => OrderType::StopLoss,
+        "STOP_LIMIT" => OrderType::StopLossLimit,
+        "TAKE_PROFIT_MARKET" => OrderType::TakeProfit,
+        "TAKE_PROFIT_LIMIT" => OrderType::TakeProfitLimit,
+        _ => OrderType::Market,
+    }
+}
+
+/// Convert a `/v1/orders-history` entry to core Order type
+///
+/// Paradex closes every finished order; a zero remaining size means it filled,
+/// anything else was cancelled for `cancel_reason`.
+pub fn convert_historical_order(order: &ParadexHistoricalOrder) -> Result<Order, ExchangeError> {
+    let quantity = conversion::try_string_to_decimal(&order.size)?;
+    let remaining = conversion::try_string_to_decimal(&order.remaining_size)?;
+    let positive_price = |value: &Option<String>| -> Result<Option<Price>, ExchangeError> {
+        let price = value
+            .as_deref()
+            .map(conversion::try_string_to_decimal)
+            .transpose()?;
+        Ok(price.filter(|price| !price.is_zero()).map(Price::new))
+    };
+
+    Ok(Order {
+        order_id: order.id.clone(),
+        client_order_id: order.client_id.clone(),
+        symbol: SYMBOLS.from_exchange_symbol(&order.market),
+        side: if order.side == "BUY" {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        },
+        order_type: convert_order_type(&order.order_type),
+        status: match order.status.as_str() {
+            "CLOSED" if remaining.is_zero() => OrderStatus::Filled,
+            "CLOSED" => OrderStatus::Canceled,
+            "OPEN" if remaining < quantity => OrderStatus::PartiallyFilled,
+            status => OrderStatus::from_venue(status),
+        },
+        price: positive_price(&order.price)?,
+        quantity: Quantity::new(quantity),
+        filled_quantity: Quantity::new(quantity - remaining),
+        average_price: positive_price(&order.avg_fill_price)?,
+        created_time: order.created_at,
+        updated_time: order.last_updated_at,
+    })
+}
+
+impl TryFrom<ParadexOrder> for OrderResponse {
+    type Error = ExchangeError;
+
+    fn try_from(order: ParadexOrder) -> Result<Self, Self::Error> {
+        Ok(Self {
             order_id: order.id,
             client_order_id: order.client_id,
-            symbol: conversion::string_to_symbol(&order.market),
+            symbol: SYMBOLS.from_exchange_symbol(&order.market),
             side: if order.side == "BUY" {
                 OrderSide::Buy
             } else {
                 OrderSide::Sell
             },
-            order_type: match order.order_type.as_str() {
-                "LIMIT" => OrderType::Limit,
-                "STOP_MARKET" => OrderType::StopLoss,
-                "STOP_LIMIT" => OrderType::StopLossLimit,
-                "TAKE_PROFIT_MARKET" => OrderType::TakeProfit,
-                "TAKE_PROFIT_LIMIT" => OrderType::TakeProfitLimit,
-                _ => OrderType::Market, // Default fallback for MARKET and unknown types
-            },
-            quantity: conversion::string_to_quantity(&order.size),
-            price: Some(conversion::string_to_price(&order.price)),
+            order_type: convert_order_type(&order.order_type),
+            quantity: conversion::try_string_to_quantity(&order.size)?,
+            price: Some(conversion::try_string_to_price(&order.price)?),
             status: order.status,
             timestamp: chrono::DateTime::parse_from_rfc3339(&order.created_at)
                 .unwrap_or_else(|_| chrono::Utc::now().into())
                 .timestamp_millis(),
-        }
+            extensions: None,
+        })
     }
 }
 
-impl From<ParadexPosition> for Position {
-    fn from(position: ParadexPosition) -> Self {
-        Self {
-            symbol: conversion::string_to_symbol(&position.market),
+impl TryFrom<ParadexPosition> for Position {
+    type Error = ExchangeError;
+
+    fn try_from(position: ParadexPosition) -> Result<Self, Self::Error> {
+        Ok(Self {
+            symbol: SYMBOLS.from_exchange_symbol(&position.market),
             position_side: if position.side == "LONG" {
                 PositionSide::Long
             } else {
                 PositionSide::Short
             },
-            entry_price: conversion::string_to_price(&position.average_entry_price),
-            position_amount: conversion::string_to_quantity(&position.size),
-            unrealized_pnl: conversion::string_to_decimal(&position.unrealized_pnl),
+            entry_price: conversion::try_string_to_price(&position.average_entry_price)?,
+            position_amount: conversion::try_string_to_quantity(&position.size)?,
+            unrealized_pnl: conversion::try_string_to_decimal(&position.unrealized_pnl)?,
             liquidation_price: position
                 .liquidation_price
-                .map(|p| conversion::string_to_price(&p)),
-            leverage: conversion::string_to_decimal(&position.leverage),
-        }
+                .as_deref()
+                .map(conversion::try_string_to_price)
+                .transpose()?,
+            leverage: conversion::try_string_to_decimal(&position.leverage)?,
+            margin_mode: None,
+            margin: None,
+            extensions: None,
+        })
     }
 }
 
-impl From<ParadexBalance> for Balance {
-    fn from(balance: ParadexBalance) -> Self {
-        Self {
+impl TryFrom<ParadexAccount> for AccountSummary {
+    type Error = ExchangeError;
+
+    fn try_from(account: ParadexAccount) -> Result<Self, Self::Error> {
+        let total_equity = conversion::try_string_to_decimal(&account.account_value)?;
+        let collateral = conversion::try_string_to_decimal(&account.total_collateral)?;
+        Ok(Self {
+            currency: account.settlement_asset,
+            total_equity,
+            available_margin: conversion::try_string_to_decimal(&account.free_collateral)?,
+            initial_margin: conversion::try_string_to_decimal(&account.initial_margin_requirement)?,
+            maintenance_margin: conversion::try_string_to_decimal(
+                &account.maintenance_margin_requirement,
+            )?,
+            unrealized_pnl: total_equity - collateral,
+            timestamp: account.updated_at,
+        })
+    }
+}
+
+impl TryFrom<ParadexBalance> for Balance {
+    type Error = ExchangeError;
+
+    fn try_from(balance: ParadexBalance) -> Result<Self, Self::Error> {
+        Ok(Self {
             asset: balance.asset,
-            free: conversion::string_to_quantity(&balance.available),
-            locked: conversion::string_to_quantity(&balance.locked),
-        }
+            free: conversion::try_string_to_quantity(&balance.available)?,
+            locked: conversion::try_string_to_quantity(&balance.locked)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::paradex::types::ParadexOrderHistory;
+
+    #[test]
+    fn test_convert_orders_history_page() {
+        let page: ParadexOrderHistory = serde_json::from_str(
+            r#"{
+                "next": "eyJmaWx0ZXIiOiJNQVg=",
+                "prev": "",
+                "results": [
+                    {"id": "1681462103821101699438490000", "account": "0x4638",
+                     "market": "ETH-USD-PERP", "side": "SELL", "type": "LIMIT",
+                     "size": "0.5", "remaining_size": "0.2", "price": "3520.5",
+                     "avg_fill_price": "3521", "status": "CLOSED",
+                     "cancel_reason": "USER_CANCELED", "client_id": "grid-7",
+                     "created_at": 1681493746016, "last_updated_at": 1681493747000},
+                    {"id": "1681462103821101699438490001", "account": "0x4638",
+                     "market": "ETH-USD-PERP", "side": "BUY", "type": "MARKET",
+                     "size": "0.1", "remaining_size": "0", "price": "0",
+                     "avg_fill_price": "3500", "status": "CLOSED", "cancel_reason": "",
+                     "client_id": "", "created_at": 1681493700000,
+                     "last_updated_at": 1681493700010}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(page.next.as_deref(), Some("eyJmaWx0ZXIiOiJNQVg="));
+
+        let cancelled = convert_historical_order(&page.results[0]).unwrap();
+        assert_eq!(cancelled.symbol.base, "ETH");
+        assert_eq!(cancelled.client_order_id, "grid-7");
+        assert!(matches!(cancelled.side, OrderSide::Sell));
+        assert!(matches!(cancelled.order_type, OrderType::Limit));
+        assert_eq!(cancelled.status, OrderStatus::Canceled);
+        assert_eq!(cancelled.filled_quantity.value(), Decimal::new(3, 1));
+        assert_eq!(cancelled.price.unwrap().value(), Decimal::new(35_205, 1));
+        assert_eq!(cancelled.updated_time, 1_681_493_747_000);
+
+        let filled = convert_historical_order(&page.results[1]).unwrap();
+        assert_eq!(filled.status, OrderStatus::Filled);
+        assert!(matches!(filled.order_type, OrderType::Market));
+        assert!(filled.price.is_none());
+        assert_eq!(filled.average_price.unwrap().value(), Decimal::from(3500));
+        assert_eq!(SYMBOLS.to_exchange_symbol(&filled.symbol), "ETH-USD-PERP");
     }
 }
@@ -12,6 +12,7 @@ pub use builder::{
     build_connector,
     build_connector_with_reconnection,
     build_connector_with_websocket,
+    build_public,
     // Legacy compatibility exports
     create_paradex_connector,
     create_paradex_connector_with_reconnection,
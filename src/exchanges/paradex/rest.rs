@@ -2,8 +2,9 @@ use crate::core::errors::ExchangeError;
 use crate::core::kernel::RestClient;
 use crate::core::types::KlineInterval;
 use crate::exchanges::paradex::types::{
-    ParadexBalance, ParadexFundingRate, ParadexFundingRateHistory, ParadexMarket, ParadexOrder,
-    ParadexPosition,
+    ParadexAccount, ParadexBalance, ParadexFundingRate, ParadexFundingRateHistory,
+    ParadexMarginConfig, ParadexMarginConfigs, ParadexMarket, ParadexMarketSummary, ParadexOrder,
+    ParadexOrderBook, ParadexOrderHistory, ParadexPosition, ParadexResults, ParadexTrade,
 };
 use serde_json::Value;
 
@@ -17,6 +18,11 @@ impl<R: RestClient> ParadexRestClient<R> {
         Self { client }
     }
 
+    /// Whether authenticated endpoints can be called
+    pub fn can_sign(&self) -> bool {
+        self.client.can_sign()
+    }
+
     /// Get all available markets
     #[allow(clippy::option_if_let_else)]
     pub async fn get_markets(&self) -> Result<Vec<ParadexMarket>, ExchangeError> {
@@ -35,6 +41,62 @@ impl<R: RestClient> ParadexRestClient<R> {
         }
     }
 
+    /// Get server time in milliseconds
+    pub async fn get_server_time(&self) -> Result<i64, ExchangeError> {
+        let response = self.client.get("/v1/system/time", &[], false).await?;
+        let server_time = &response["server_time"];
+        server_time
+            .as_i64()
+            .or_else(|| server_time.as_str().and_then(|time| time.parse().ok()))
+            .ok_or_else(|| {
+                ExchangeError::InvalidResponseFormat(format!(
+                    "Invalid server time response: {}",
+                    response
+                ))
+            })
+    }
+
+    /// Get the 24h summary of a market
+    pub async fn get_market_summary(
+        &self,
+        market: &str,
+    ) -> Result<ParadexResults<ParadexMarketSummary>, ExchangeError> {
+        let params = [("market", market)];
+        self.client
+            .get_json("/v1/markets/summary", &params, false)
+            .await
+    }
+
+    /// Get the order book of a market
+    pub async fn get_order_book(
+        &self,
+        market: &str,
+        depth: Option<u32>,
+    ) -> Result<ParadexOrderBook, ExchangeError> {
+        let depth_str = depth.map(|d| d.to_string());
+        let mut params = Vec::new();
+        if let Some(ref depth) = depth_str {
+            params.push(("depth", depth.as_str()));
+        }
+        self.client
+            .get_json(&format!("/v1/orderbook/{}", market), &params, false)
+            .await
+    }
+
+    /// Get recent trades of a market, newest first
+    pub async fn get_trades(
+        &self,
+        market: &str,
+        limit: Option<u32>,
+    ) -> Result<ParadexResults<ParadexTrade>, ExchangeError> {
+        let limit_str = limit.map(|l| l.to_string());
+        let mut params = vec![("market", market)];
+        if let Some(ref limit) = limit_str {
+            params.push(("page_size", limit.as_str()));
+        }
+        self.client.get_json("/v1/trades", &params, false).await
+    }
+
     /// Get klines/candlestick data
     pub async fn get_klines(
         &self,
@@ -147,12 +209,89 @@ impl<R: RestClient> ParadexRestClient<R> {
         self.client.post_json("/v1/orders", order, true).await
     }
 
+    /// Get the account's finished orders on `market`, newest first
+    pub async fn get_orders_history(
+        &self,
+        market: &str,
+        start_at: Option<i64>,
+        end_at: Option<i64>,
+        cursor: Option<&str>,
+        page_size: u32,
+    ) -> Result<ParadexOrderHistory, ExchangeError> {
+        let start_str = start_at.map(|start| start.to_string());
+        let end_str = end_at.map(|end| end.to_string());
+        let page_size_str = page_size.to_string();
+        let mut params = vec![("market", market), ("page_size", page_size_str.as_str())];
+
+        if let Some(ref start) = start_str {
+            params.push(("start_at", start.as_str()));
+        }
+        if let Some(ref end) = end_str {
+            params.push(("end_at", end.as_str()));
+        }
+        if let Some(cursor) = cursor {
+            params.push(("cursor", cursor));
+        }
+
+        self.client
+            .get_json("/v1/orders-history", &params, true)
+            .await
+    }
+
     /// Cancel an order
     pub async fn cancel_order(&self, order_id: &str) -> Result<Value, ExchangeError> {
         let endpoint = format!("/v1/orders/{}", order_id);
         self.client.delete_json(&endpoint, &[], true).await
     }
 
+    /// Cancel the open order on `market` placed with `client_id`
+    pub async fn cancel_order_by_client_id(
+        &self,
+        market: &str,
+        client_id: &str,
+    ) -> Result<Value, ExchangeError> {
+        let endpoint = format!("/v1/orders/by_client_id/{}", client_id);
+        self.client
+            .delete_json(&endpoint, &[("market", market)], true)
+            .await
+    }
+
+    /// Get the leverage and margin type of a market
+    pub async fn get_margin_config(
+        &self,
+        market: &str,
+    ) -> Result<ParadexMarginConfig, ExchangeError> {
+        let response: ParadexMarginConfigs = self
+            .client
+            .get_json("/v1/account/margin", &[("market", market)], true)
+            .await?;
+        response
+            .configs
+            .into_iter()
+            .find(|config| config.market == market)
+            .ok_or_else(|| ExchangeError::Other(format!("No margin config for {}", market)))
+    }
+
+    /// Set the leverage and margin type (`CROSS` or `ISOLATED`) of a market
+    pub async fn set_margin_config(
+        &self,
+        market: &str,
+        leverage: u32,
+        margin_type: &str,
+    ) -> Result<Value, ExchangeError> {
+        let endpoint = format!("/v1/account/margin/{}", market);
+        let body = serde_json::json!({
+            "leverage": leverage,
+            "margin_type": margin_type
+        });
+        self.client.post_json(&endpoint, &body, true).await
+    }
+
+    /// Get the account's margin totals
+    pub async fn get_account(&self) -> Result<ParadexAccount, ExchangeError> {
+        self.client.get_json("/v1/account", &[], true).await
+    }
+
     /// Get account balances
     #[allow(clippy::option_if_let_else)]
     pub async fn get_account_balances(&self) -> Result<Vec<ParadexBalance>, ExchangeError> {
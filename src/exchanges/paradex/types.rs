@@ -172,6 +172,41 @@ pub struct ParadexOrder {
     pub created_at: String,
 }
 
+/// Page of `/v1/orders-history`, newest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParadexOrderHistory {
+    /// Cursor of the next page, absent on the last one
+    #[serde(default)]
+    pub next: Option<String>,
+    pub results: Vec<ParadexHistoricalOrder>,
+}
+
+/// Finished order of `/v1/orders-history`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParadexHistoricalOrder {
+    pub id: String,
+    #[serde(default)]
+    pub client_id: String,
+    pub market: String,
+    pub side: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub size: String,
+    pub remaining_size: String,
+    /// `0` for market orders
+    #[serde(default)]
+    pub price: Option<String>,
+    #[serde(default)]
+    pub avg_fill_price: Option<String>,
+    /// `NEW`, `UNTRIGGERED`, `OPEN` or `CLOSED`
+    pub status: String,
+    /// Why a `CLOSED` order stopped short of a full fill
+    #[serde(default)]
+    pub cancel_reason: String,
+    pub created_at: i64,
+    pub last_updated_at: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParadexFill {
     pub id: i64,
@@ -194,6 +229,19 @@ pub struct ParadexPosition {
     pub leverage: String,
 }
 
+/// Leverage and margin type of one market, from `/v1/account/margin`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParadexMarginConfig {
+    pub market: String,
+    pub leverage: u32,
+    pub margin_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParadexMarginConfigs {
+    pub configs: Vec<ParadexMarginConfig>,
+}
+
 // API Response types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParadexApiResponse<T> {
@@ -226,6 +274,46 @@ pub struct ParadexFundingRateHistory {
     pub funding_time: i64,
 }
 
+// Public market snapshot types
+/// `results` page wrapper of list endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParadexResults<T> {
+    pub results: Vec<T>,
+}
+
+/// Entry of `/v1/markets/summary`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParadexMarketSummary {
+    pub symbol: String,
+    pub last_traded_price: String,
+    /// Change over 24h as a fraction, `0.01` being 1%
+    pub price_change_rate_24h: String,
+    /// Traded notional over 24h in USD
+    pub volume_24h: String,
+    pub created_at: i64,
+}
+
+/// Reply of `/v1/orderbook/{market}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParadexOrderBook {
+    pub market: String,
+    pub seq_no: i64,
+    pub bids: Vec<[String; 2]>,
+    pub asks: Vec<[String; 2]>,
+}
+
+/// Entry of `/v1/trades`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParadexTrade {
+    pub id: String,
+    pub market: String,
+    /// Taker side, `BUY` or `SELL`
+    pub side: String,
+    pub size: String,
+    pub price: String,
+    pub created_at: i64,
+}
+
 // Balance and account types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParadexBalance {
@@ -235,6 +323,19 @@ pub struct ParadexBalance {
     pub total: String,
 }
 
+/// Reply of `/v1/account`, amounts in the settlement asset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParadexAccount {
+    /// Collateral plus unrealized profit and loss
+    pub account_value: String,
+    pub free_collateral: String,
+    pub initial_margin_requirement: String,
+    pub maintenance_margin_requirement: String,
+    pub settlement_asset: String,
+    pub total_collateral: String,
+    pub updated_at: i64,
+}
+
 // WebSocket types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParadexWebSocketMessage {
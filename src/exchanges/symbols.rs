@@ -0,0 +1,90 @@
+use crate::core::symbol::{InstrumentType, SymbolConvention};
+use crate::exchanges::factory::ExchangeId;
+
+/// Concatenated pairs such as `BTCUSDT`
+const fn concatenated(instrument_type: InstrumentType) -> SymbolConvention {
+    SymbolConvention {
+        separator: "",
+        perpetual_suffix: "",
+        implied_quote: None,
+        instrument_type,
+    }
+}
+
+const fn separated(
+    separator: &'static str,
+    perpetual_suffix: &'static str,
+    instrument_type: InstrumentType,
+) -> SymbolConvention {
+    SymbolConvention {
+        separator,
+        perpetual_suffix,
+        implied_quote: None,
+        instrument_type,
+    }
+}
+
+impl ExchangeId {
+    /// How this venue spells symbols, used by its conversions in both directions
+    pub const fn symbol_convention(self) -> SymbolConvention {
+        match self {
+            Self::Binance | Self::BinanceMargin | Self::Bybit => concatenated(InstrumentType::Spot),
+            Self::BinancePerp | Self::BybitPerp => concatenated(InstrumentType::Perpetual),
+            // Spot `SOL_USDC` and perpetual `SOL_USDC_PERP` share one connector
+            Self::Backpack => separated("_", "_PERP", InstrumentType::Spot),
+            Self::Coinbase => separated("-", "", InstrumentType::Spot),
+            // Perpetuals only, all quoted in USD and settled in USDC
+            Self::Dydx => separated("-", "", InstrumentType::Perpetual),
+            Self::Gateio => separated("_", "", InstrumentType::Spot),
+            Self::GateioPerp => separated("_", "", InstrumentType::Perpetual),
+            // Perpetuals are named by coin and all quoted in USD
+            Self::Hyperliquid => SymbolConvention {
+                separator: "",
+                perpetual_suffix: "",
+                implied_quote: Some("USD"),
+                instrument_type: InstrumentType::Perpetual,
+            },
+            // WebSocket v2 spelling; REST asset codes are mapped by the Kraken conversions
+            Self::Kraken => separated("/", "", InstrumentType::Spot),
+            Self::Okx => separated("-", "-SWAP", InstrumentType::Spot),
+            Self::OkxSwap => separated("-", "-SWAP", InstrumentType::Perpetual),
+            Self::Paradex => separated("-", "-PERP", InstrumentType::Perpetual),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Symbol;
+
+    #[test]
+    fn test_one_symbol_renders_per_venue() {
+        let symbol = Symbol::from("BTC-USDT");
+        let rendered: Vec<String> = [
+            ExchangeId::Binance,
+            ExchangeId::Backpack,
+            ExchangeId::Hyperliquid,
+            ExchangeId::Kraken,
+            ExchangeId::OkxSwap,
+        ]
+        .iter()
+        .map(|id| id.symbol_convention().to_exchange_symbol(&symbol))
+        .collect();
+        assert_eq!(
+            rendered,
+            ["BTCUSDT", "BTC_USDT", "BTC", "BTC/USDT", "BTC-USDT-SWAP"]
+        );
+
+        for id in ExchangeId::ALL {
+            let convention = id.symbol_convention();
+            let exchange_symbol = convention.to_exchange_symbol(&symbol);
+            assert_eq!(
+                convention.to_exchange_symbol(&convention.from_exchange_symbol(&exchange_symbol)),
+                exchange_symbol,
+                "{} round trip",
+                id
+            );
+        }
+    }
+}
@@ -0,0 +1,709 @@
+//! C ABI for embedding connectors in non-Rust engines, built with the `ffi` feature
+//!
+//! The build script regenerates `include/lotusx.h` whenever the feature is enabled.
+//! Link against the library produced by
+//! `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`).
+//!
+//! Every function returns a `LotusxStatus`; on failure `lotusx_last_error` copies the
+//! message recorded for the calling thread. Each connector owns a small tokio runtime
+//! that keeps its WebSocket running between polls. A handle must not be used from two
+//! threads at the same time.
+
+use crate::core::config::ExchangeConfig;
+use crate::core::errors::ExchangeError;
+use crate::core::traits::ExchangeConnector;
+use crate::core::types::{
+    conversion, MarketDataType, OrderBookMode, OrderRequest, OrderSide, OrderType, Price, Quantity,
+    SubscriptionType, Symbol, TimeInForce,
+};
+use crate::exchanges::factory::{build_connector, ExchangeId};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::Duration;
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
+
+/// Subscribe to tickers
+pub const LOTUSX_CHANNEL_TICKER: u32 = 1;
+/// Subscribe to public trades
+pub const LOTUSX_CHANNEL_TRADES: u32 = 1 << 1;
+/// Subscribe to order book updates
+pub const LOTUSX_CHANNEL_ORDER_BOOK: u32 = 1 << 2;
+/// Subscribe to aggregated trades, a lighter alternative to `LOTUSX_CHANNEL_TRADES`
+pub const LOTUSX_CHANNEL_AGG_TRADES: u32 = 1 << 3;
+/// Subscribe to order book deltas, only the levels that changed, instead of snapshots
+pub const LOTUSX_CHANNEL_ORDER_BOOK_DELTA: u32 = 1 << 4;
+/// Subscribe to mark price and funding rate updates on perpetual venues
+pub const LOTUSX_CHANNEL_MARK_PRICE: u32 = 1 << 5;
+
+/// Result of every call
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LotusxStatus {
+    Ok = 0,
+    InvalidArgument = 1,
+    Auth = 2,
+    Network = 3,
+    RateLimited = 4,
+    Exchange = 5,
+    NotSupported = 6,
+    StreamClosed = 7,
+    BufferTooSmall = 8,
+    Internal = 9,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LotusxDataKind {
+    Ticker = 0,
+    Trade = 1,
+    OrderBook = 2,
+    Kline = 3,
+    MarkPrice = 4,
+}
+
+/// One market data update, flattened to a fixed layout
+///
+/// Tickers fill `price` (last), `quantity` (24h volume) and `timestamp` (close time).
+/// Trades fill `price`, `quantity`, `is_buyer_maker` and `timestamp`. Order books fill
+/// the best bid and ask. Klines fill `price` (close), `quantity` (volume), `timestamp`
+/// (open time) and `final_bar`. Mark prices fill `price` (mark), `quantity` (funding
+/// rate) and `timestamp`. Unused fields are zero.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct LotusxMarketData {
+    pub kind: LotusxDataKind,
+    /// NUL-terminated, truncated to fit
+    pub symbol: [c_char; 32],
+    /// Milliseconds since the Unix epoch
+    pub timestamp: i64,
+    pub price: f64,
+    pub quantity: f64,
+    pub bid_price: f64,
+    pub bid_quantity: f64,
+    pub ask_price: f64,
+    pub ask_quantity: f64,
+    pub is_buyer_maker: bool,
+    pub final_bar: bool,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LotusxSide {
+    Buy = 0,
+    Sell = 1,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LotusxOrderType {
+    Market = 0,
+    Limit = 1,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LotusxTimeInForce {
+    Gtc = 0,
+    Ioc = 1,
+    Fok = 2,
+}
+
+/// Order to place; amounts are decimal strings so no precision is lost
+#[repr(C)]
+pub struct LotusxOrder {
+    pub symbol: *const c_char,
+    pub side: LotusxSide,
+    pub order_type: LotusxOrderType,
+    pub quantity: *const c_char,
+    /// Limit price, ignored (and may be null) for market orders
+    pub price: *const c_char,
+    pub time_in_force: LotusxTimeInForce,
+}
+
+/// Opaque connector handle
+pub struct LotusxConnector {
+    runtime: Runtime,
+    exchange: Box<dyn ExchangeConnector>,
+    stream: Option<mpsc::Receiver<MarketDataType>>,
+}
+
+impl LotusxConnector {
+    fn trading(&self) -> Result<&dyn ExchangeConnector, FfiError> {
+        if self.exchange.supports_trading() {
+            Ok(self.exchange.as_ref())
+        } else {
+            Err(FfiError::new(LotusxStatus::Auth, "Trading needs API keys"))
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+struct FfiError {
+    status: LotusxStatus,
+    message: String,
+}
+
+impl FfiError {
+    fn new(status: LotusxStatus, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    fn invalid(message: impl Into<String>) -> Self {
+        Self::new(LotusxStatus::InvalidArgument, message)
+    }
+}
+
+impl From<ExchangeError> for FfiError {
+    fn from(error: ExchangeError) -> Self {
+        let status = match &error {
+            ExchangeError::AuthError(_) | ExchangeError::AuthenticationRequired => {
+                LotusxStatus::Auth
+            }
+            ExchangeError::HttpError(_)
+            | ExchangeError::NetworkError(_)
+            | ExchangeError::WebSocketError(_)
+            | ExchangeError::WebSocketClosed(_)
+            | ExchangeError::ConnectionTimeout(_) => LotusxStatus::Network,
+            ExchangeError::RateLimitExceeded(_) => LotusxStatus::RateLimited,
+            ExchangeError::InvalidParameters(_)
+            | ExchangeError::ConfigError(_)
+            | ExchangeError::ConfigurationError(_) => LotusxStatus::InvalidArgument,
+            ExchangeError::NotSupported(_) => LotusxStatus::NotSupported,
+            _ => LotusxStatus::Exchange,
+        };
+        Self::new(status, error.to_string())
+    }
+}
+
+/// Run `f`, record its error for `lotusx_last_error` and map it to a status
+fn run(f: impl FnOnce() -> Result<(), FfiError>) -> LotusxStatus {
+    let (status, message) = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => (LotusxStatus::Ok, None),
+        Ok(Err(error)) => (error.status, Some(error.message)),
+        Err(_) => (
+            LotusxStatus::Internal,
+            Some("panic inside lotusx".to_string()),
+        ),
+    };
+    let message =
+        message.map(|message| CString::new(message.replace('\0', " ")).unwrap_or_default());
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+    status
+}
+
+/// Borrow a required C string argument
+unsafe fn required_str<'a>(ptr: *const c_char, name: &str) -> Result<&'a str, FfiError> {
+    if ptr.is_null() {
+        return Err(FfiError::invalid(format!("{} must not be null", name)));
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|_| FfiError::invalid(format!("{} is not valid UTF-8", name)))
+}
+
+/// Borrow an optional C string argument, treating null as absent
+unsafe fn optional_str<'a>(ptr: *const c_char, name: &str) -> Result<Option<&'a str>, FfiError> {
+    if ptr.is_null() {
+        Ok(None)
+    } else {
+        required_str(ptr, name).map(Some)
+    }
+}
+
+unsafe fn connector<'a>(handle: *mut LotusxConnector) -> Result<&'a mut LotusxConnector, FfiError> {
+    handle
+        .as_mut()
+        .ok_or_else(|| FfiError::invalid("connector must not be null"))
+}
+
+/// Copy `value` into a caller buffer as a NUL-terminated string
+unsafe fn write_str(value: &str, buf: *mut c_char, len: usize) -> Result<(), FfiError> {
+    if buf.is_null() || len <= value.len() {
+        return Err(FfiError::new(
+            LotusxStatus::BufferTooSmall,
+            format!("Buffer needs {} bytes", value.len() + 1),
+        ));
+    }
+    std::ptr::copy_nonoverlapping(value.as_ptr().cast::<c_char>(), buf, value.len());
+    *buf.add(value.len()) = 0;
+    Ok(())
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+fn flatten(data: &MarketDataType) -> LotusxMarketData {
+    let mut out = LotusxMarketData {
+        kind: LotusxDataKind::Ticker,
+        symbol: [0; 32],
+        timestamp: 0,
+        price: 0.0,
+        quantity: 0.0,
+        bid_price: 0.0,
+        bid_quantity: 0.0,
+        ask_price: 0.0,
+        ask_quantity: 0.0,
+        is_buyer_maker: false,
+        final_bar: false,
+    };
+    let symbol = match data {
+        MarketDataType::Ticker(ticker) => {
+            out.price = to_f64(ticker.price.value());
+            out.quantity = to_f64(ticker.volume.value());
+            out.timestamp = ticker.close_time;
+            &ticker.symbol
+        }
+        MarketDataType::Trade(trade) => {
+            out.kind = LotusxDataKind::Trade;
+            out.price = to_f64(trade.price.value());
+            out.quantity = to_f64(trade.quantity.value());
+            out.timestamp = trade.time;
+            out.is_buyer_maker = trade.is_buyer_maker;
+            &trade.symbol
+        }
+        MarketDataType::OrderBook(book) => {
+            out.kind = LotusxDataKind::OrderBook;
+            if let Some(bid) = book.bids.first() {
+                out.bid_price = to_f64(bid.price.value());
+                out.bid_quantity = to_f64(bid.quantity.value());
+            }
+            if let Some(ask) = book.asks.first() {
+                out.ask_price = to_f64(ask.price.value());
+                out.ask_quantity = to_f64(ask.quantity.value());
+            }
+            &book.symbol
+        }
+        MarketDataType::Kline(kline) => {
+            out.kind = LotusxDataKind::Kline;
+            out.price = to_f64(kline.close_price.value());
+            out.quantity = to_f64(kline.volume.value());
+            out.timestamp = kline.open_time;
+            out.final_bar = kline.final_bar;
+            &kline.symbol
+        }
+        MarketDataType::MarkPrice(mark) => {
+            out.kind = LotusxDataKind::MarkPrice;
+            out.price = to_f64(mark.mark_price.value());
+            out.quantity = mark.funding_rate.map(to_f64).unwrap_or_default();
+            out.timestamp = mark.timestamp;
+            &mark.symbol
+        }
+    };
+
+    let symbol = symbol.to_string();
+    let len = symbol.len().min(out.symbol.len() - 1);
+    for (slot, byte) in out.symbol.iter_mut().zip(&symbol.as_bytes()[..len]) {
+        *slot = c_char::from_ne_bytes([*byte]);
+    }
+    out
+}
+
+/// Create a connector for `exchange` (e.g. `"binance_perp"`)
+///
+/// Pass null keys for a market-data-only connector; both keys enable trading.
+///
+/// # Safety
+///
+/// String arguments must be null or valid NUL-terminated strings, and `out` must be a
+/// valid pointer. Free the handle with `lotusx_connector_free`.
+#[no_mangle]
+pub unsafe extern "C" fn lotusx_connector_new(
+    exchange: *const c_char,
+    api_key: *const c_char,
+    secret_key: *const c_char,
+    testnet: bool,
+    out: *mut *mut LotusxConnector,
+) -> LotusxStatus {
+    run(|| {
+        if out.is_null() {
+            return Err(FfiError::invalid("out must not be null"));
+        }
+        let exchange: ExchangeId = required_str(exchange, "exchange")?.parse()?;
+        let config = match (
+            optional_str(api_key, "api_key")?,
+            optional_str(secret_key, "secret_key")?,
+        ) {
+            (Some(api_key), Some(secret_key)) => {
+                ExchangeConfig::new(api_key.to_string(), secret_key.to_string())
+            }
+            (None, None) => ExchangeConfig::read_only(),
+            _ => {
+                return Err(FfiError::invalid(
+                    "api_key and secret_key must be given together",
+                ))
+            }
+        };
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .map_err(|e| FfiError::new(LotusxStatus::Internal, e.to_string()))?;
+        let guard = runtime.enter();
+        let exchange = build_connector(exchange, config.testnet(testnet))
+            .map_err(|e| FfiError::invalid(e.to_string()))?;
+        drop(guard);
+
+        *out = Box::into_raw(Box::new(LotusxConnector {
+            runtime,
+            exchange,
+            stream: None,
+        }));
+        Ok(())
+    })
+}
+
+/// Close the connector, its streams and background tasks
+///
+/// # Safety
+///
+/// `handle` must be null or a pointer returned by `lotusx_connector_new` that has not
+/// been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn lotusx_connector_free(handle: *mut LotusxConnector) {
+    if !handle.is_null() {
+        let connector = Box::from_raw(handle);
+        connector.runtime.shutdown_background();
+    }
+}
+
+/// Subscribe to the `LOTUSX_CHANNEL_*` bitmask for `symbols`
+///
+/// Replaces any previous subscription on this handle. `depth` applies to order books;
+/// pass 0 for the venue default.
+///
+/// # Safety
+///
+/// `handle` must be a live connector and `symbols` must point to `symbol_count` valid
+/// NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn lotusx_subscribe(
+    handle: *mut LotusxConnector,
+    symbols: *const *const c_char,
+    symbol_count: usize,
+    channels: u32,
+    depth: u32,
+) -> LotusxStatus {
+    run(|| {
+        let connector = connector(handle)?;
+        if symbols.is_null() || symbol_count == 0 {
+            return Err(FfiError::invalid("at least one symbol is required"));
+        }
+        let symbols = std::slice::from_raw_parts(symbols, symbol_count)
+            .iter()
+            .map(|symbol| required_str(*symbol, "symbol").map(Symbol::from))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut subscriptions = Vec::new();
+        if channels & LOTUSX_CHANNEL_TICKER != 0 {
+            subscriptions.push(SubscriptionType::Ticker);
+        }
+        if channels & LOTUSX_CHANNEL_TRADES != 0 {
+            subscriptions.push(SubscriptionType::Trades);
+        }
+        if channels & LOTUSX_CHANNEL_AGG_TRADES != 0 {
+            subscriptions.push(SubscriptionType::AggTrades);
+        }
+        if channels & LOTUSX_CHANNEL_ORDER_BOOK != 0 {
+            subscriptions.push(SubscriptionType::OrderBook {
+                depth: (depth > 0).then_some(depth),
+                mode: OrderBookMode::Snapshot,
+            });
+        }
+        if channels & LOTUSX_CHANNEL_ORDER_BOOK_DELTA != 0 {
+            subscriptions.push(SubscriptionType::OrderBook {
+                depth: (depth > 0).then_some(depth),
+                mode: OrderBookMode::Delta,
+            });
+        }
+        if channels & LOTUSX_CHANNEL_MARK_PRICE != 0 {
+            subscriptions.push(SubscriptionType::MarkPrice);
+        }
+        if subscriptions.is_empty() {
+            return Err(FfiError::invalid("no channels selected"));
+        }
+
+        let stream = connector
+            .runtime
+            .block_on(
+                connector
+                    .exchange
+                    .subscribe_market_data(symbols, subscriptions, None),
+            )?;
+        connector.stream = Some(stream);
+        Ok(())
+    })
+}
+
+/// Drain up to `capacity` updates into `out`, waiting at most `timeout_ms` for the first
+///
+/// `written` receives the number of updates copied; zero with `Ok` means the wait timed
+/// out. Returns `StreamClosed` once the stream has ended and is empty.
+///
+/// # Safety
+///
+/// `handle` must be a live connector, `out` must have room for `capacity` entries and
+/// `written` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn lotusx_poll_market_data(
+    handle: *mut LotusxConnector,
+    out: *mut LotusxMarketData,
+    capacity: usize,
+    timeout_ms: u64,
+    written: *mut usize,
+) -> LotusxStatus {
+    run(|| {
+        let connector = connector(handle)?;
+        if out.is_null() || written.is_null() || capacity == 0 {
+            return Err(FfiError::invalid("out, capacity and written are required"));
+        }
+        *written = 0;
+        let Some(stream) = connector.stream.as_mut() else {
+            return Err(FfiError::invalid("call lotusx_subscribe first"));
+        };
+
+        let first = connector.runtime.block_on(async {
+            tokio::time::timeout(Duration::from_millis(timeout_ms), stream.recv()).await
+        });
+        let first = match first {
+            Ok(Some(data)) => data,
+            Ok(None) => {
+                return Err(FfiError::new(
+                    LotusxStatus::StreamClosed,
+                    "market data stream closed",
+                ))
+            }
+            Err(_) => return Ok(()),
+        };
+
+        let out = std::slice::from_raw_parts_mut(out, capacity);
+        out[0] = flatten(&first);
+        let mut count = 1;
+        while count < capacity {
+            match stream.try_recv() {
+                Ok(data) => {
+                    out[count] = flatten(&data);
+                    count += 1;
+                }
+                Err(_) => break,
+            }
+        }
+        *written = count;
+        Ok(())
+    })
+}
+
+/// Place an order and copy the venue's order id into `order_id`
+///
+/// # Safety
+///
+/// `handle` must be a live connector, `order` must point to a valid `LotusxOrder` and
+/// `order_id` must have room for `order_id_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn lotusx_place_order(
+    handle: *mut LotusxConnector,
+    order: *const LotusxOrder,
+    order_id: *mut c_char,
+    order_id_len: usize,
+) -> LotusxStatus {
+    run(|| {
+        let connector = connector(handle)?;
+        let order = order
+            .as_ref()
+            .ok_or_else(|| FfiError::invalid("order must not be null"))?;
+        let trading = connector.trading()?;
+
+        let quantity: Quantity = required_str(order.quantity, "quantity")?
+            .parse()
+            .map_err(|e| FfiError::invalid(format!("{}", e)))?;
+        let (order_type, price, time_in_force) = match order.order_type {
+            LotusxOrderType::Market => (OrderType::Market, None, None),
+            LotusxOrderType::Limit => {
+                let price: Price = required_str(order.price, "price")?
+                    .parse()
+                    .map_err(|e| FfiError::invalid(format!("{}", e)))?;
+                let time_in_force = match order.time_in_force {
+                    LotusxTimeInForce::Gtc => TimeInForce::GTC,
+                    LotusxTimeInForce::Ioc => TimeInForce::IOC,
+                    LotusxTimeInForce::Fok => TimeInForce::FOK,
+                };
+                (OrderType::Limit, Some(price), Some(time_in_force))
+            }
+        };
+        let request = OrderRequest {
+            symbol: conversion::string_to_symbol(required_str(order.symbol, "symbol")?),
+            side: match order.side {
+                LotusxSide::Buy => OrderSide::Buy,
+                LotusxSide::Sell => OrderSide::Sell,
+            },
+            order_type,
+            quantity,
+            price,
+            time_in_force,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        };
+
+        let response = connector.runtime.block_on(trading.place_order(request))?;
+        write_str(&response.order_id, order_id, order_id_len)
+    })
+}
+
+/// Cancel an order by venue order id
+///
+/// # Safety
+///
+/// `handle` must be a live connector and both strings valid NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn lotusx_cancel_order(
+    handle: *mut LotusxConnector,
+    symbol: *const c_char,
+    order_id: *const c_char,
+) -> LotusxStatus {
+    run(|| {
+        let connector = connector(handle)?;
+        let trading = connector.trading()?;
+        let symbol = Symbol::from(required_str(symbol, "symbol")?);
+        let order_id = required_str(order_id, "order_id")?.to_string();
+        connector
+            .runtime
+            .block_on(trading.cancel_order(symbol, order_id))?;
+        Ok(())
+    })
+}
+
+/// Copy the calling thread's last error message into `buf`
+///
+/// Returns the full message length excluding the NUL terminator, or 0 if the last call
+/// succeeded. The copy is truncated when `len` is too small.
+///
+/// # Safety
+///
+/// `buf` must be null or have room for `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn lotusx_last_error(buf: *mut c_char, len: usize) -> usize {
+    LAST_ERROR.with(|last| {
+        let last = last.borrow();
+        let Some(message) = last.as_ref() else {
+            return 0;
+        };
+        let bytes = message.as_bytes();
+        if !buf.is_null() && len > 0 {
+            let copied = bytes.len().min(len - 1);
+            std::ptr::copy_nonoverlapping(bytes.as_ptr().cast::<c_char>(), buf, copied);
+            *buf.add(copied) = 0;
+        }
+        bytes.len()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(value: &str) -> CString {
+        CString::new(value).unwrap()
+    }
+
+    fn last_error() -> String {
+        let mut buf = [0 as c_char; 256];
+        unsafe { lotusx_last_error(buf.as_mut_ptr(), buf.len()) };
+        unsafe { CStr::from_ptr(buf.as_ptr()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_errors_are_reported_through_status_and_last_error() {
+        let (nope, binance, symbol, quantity) = (c("nope"), c("binance"), c("BTCUSDT"), c("0.001"));
+        let mut handle = std::ptr::null_mut();
+        let status = unsafe {
+            lotusx_connector_new(
+                nope.as_ptr(),
+                std::ptr::null(),
+                std::ptr::null(),
+                false,
+                &mut handle,
+            )
+        };
+        assert_eq!(status, LotusxStatus::InvalidArgument);
+        assert!(last_error().contains("Unknown exchange"));
+
+        let status = unsafe {
+            lotusx_connector_new(
+                binance.as_ptr(),
+                std::ptr::null(),
+                std::ptr::null(),
+                false,
+                &mut handle,
+            )
+        };
+        assert_eq!(status, LotusxStatus::Ok);
+        assert_eq!(unsafe { lotusx_last_error(std::ptr::null_mut(), 0) }, 0);
+
+        let order = LotusxOrder {
+            symbol: symbol.as_ptr(),
+            side: LotusxSide::Buy,
+            order_type: LotusxOrderType::Market,
+            quantity: quantity.as_ptr(),
+            price: std::ptr::null(),
+            time_in_force: LotusxTimeInForce::Gtc,
+        };
+        let mut order_id = [0 as c_char; 64];
+        let status =
+            unsafe { lotusx_place_order(handle, &order, order_id.as_mut_ptr(), order_id.len()) };
+        assert_eq!(status, LotusxStatus::Auth);
+
+        let mut data = [flatten(&MarketDataType::Trade(crate::core::types::Trade {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            id: 1,
+            price: conversion::string_to_price("1"),
+            quantity: conversion::string_to_quantity("1"),
+            time: 0,
+            is_buyer_maker: false,
+        }))];
+        let mut written = 0;
+        let status = unsafe {
+            lotusx_poll_market_data(handle, data.as_mut_ptr(), data.len(), 0, &mut written)
+        };
+        assert_eq!(status, LotusxStatus::InvalidArgument);
+        assert!(last_error().contains("lotusx_subscribe"));
+
+        unsafe { lotusx_connector_free(handle) };
+    }
+
+    #[test]
+    fn test_flatten_trade() {
+        let trade = crate::core::types::Trade {
+            symbol: conversion::string_to_symbol("ETHUSDT"),
+            id: 7,
+            price: conversion::string_to_price("2000.5"),
+            quantity: conversion::string_to_quantity("0.25"),
+            time: 1_700_000_000_000,
+            is_buyer_maker: true,
+        };
+        let data = flatten(&MarketDataType::Trade(trade));
+
+        assert_eq!(data.kind, LotusxDataKind::Trade);
+        assert_eq!(
+            unsafe { CStr::from_ptr(data.symbol.as_ptr()) }.to_str(),
+            Ok("ETHUSDT")
+        );
+        assert!((data.price - 2000.5).abs() < f64::EPSILON);
+        assert!(data.is_buyer_maker);
+        assert_eq!(data.timestamp, 1_700_000_000_000);
+    }
+}
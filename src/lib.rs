@@ -1,8 +1,16 @@
 pub mod core;
 pub mod exchanges;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "python")]
+pub mod python;
 pub mod utils;
 
+#[cfg(test)]
+pub(crate) mod test_support;
+
 pub use core::{errors::ExchangeError, traits::ExchangeConnector, types::*};
 pub use exchanges::binance::BinanceConnector;
 pub use exchanges::bybit::BybitConnector;
 pub use exchanges::bybit_perp::BybitPerpConnector;
+pub use exchanges::factory::{build_connector, ExchangeId};
@@ -1,5 +1,5 @@
 use lotusx::core::config::ExchangeConfig;
-use lotusx::core::traits::MarketDataSource;
+use lotusx::core::traits::RestMarketData;
 use lotusx::exchanges::binance_perp;
 
 #[tokio::main]
@@ -0,0 +1,368 @@
+//! Python bindings, built with the `python` feature
+//!
+//! Connectors are created through `build_connector` and every network call returns an
+//! asyncio awaitable driven by a shared tokio runtime. Results cross the boundary as
+//! plain dicts and lists (prices and quantities stay decimal strings), so notebooks
+//! get the same data the Rust API does without extra classes to learn.
+//!
+//! ```python
+//! import asyncio, lotusx
+//!
+//! async def main():
+//!     conn = lotusx.Connector("binance_perp")
+//!     klines = await conn.get_klines("BTCUSDT", "1h", limit=24)
+//!     async for update in await conn.subscribe(["BTCUSDT"], ["trades"]):
+//!         print(update)
+//!
+//! asyncio.run(main())
+//! ```
+
+use crate::core::config::ExchangeConfig;
+use crate::core::traits::ExchangeConnector;
+use crate::core::types::{
+    conversion, KlineInterval, MarketDataType, OrderBookMode, OrderRequest, OrderSide, OrderType,
+    Price, Quantity, SubscriptionType, Symbol, TimeInForce,
+};
+use crate::exchanges::factory::{build_connector, ExchangeId};
+use pyo3::exceptions::{PyException, PyStopAsyncIteration, PyValueError};
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::{future_into_py, get_runtime};
+use serde::Serialize;
+use std::fmt::Display;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+
+pyo3::create_exception!(
+    lotusx,
+    LotusxError,
+    PyException,
+    "Error raised by a connector"
+);
+
+fn lotusx_err(error: impl Display) -> PyErr {
+    LotusxError::new_err(error.to_string())
+}
+
+fn value_err(error: impl Display) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Convert through JSON so Python sees the same shape as the serde output
+fn to_py<T: Serialize>(value: &T) -> PyResult<PyObject> {
+    let json = serde_json::to_string(value).map_err(lotusx_err)?;
+    Python::with_gil(|py| Ok(py.import("json")?.call_method1("loads", (json,))?.unbind()))
+}
+
+/// Accept ints, floats, strings or `Decimal`s and parse their string form
+fn decimal_str(value: &Bound<'_, PyAny>) -> PyResult<String> {
+    Ok(value.str()?.to_cow()?.into_owned())
+}
+
+fn parse_interval(interval: &str) -> PyResult<KlineInterval> {
+    Ok(match interval {
+        "1m" => KlineInterval::Minutes1,
+        "3m" => KlineInterval::Minutes3,
+        "5m" => KlineInterval::Minutes5,
+        "15m" => KlineInterval::Minutes15,
+        "30m" => KlineInterval::Minutes30,
+        "1h" => KlineInterval::Hours1,
+        "2h" => KlineInterval::Hours2,
+        "4h" => KlineInterval::Hours4,
+        "6h" => KlineInterval::Hours6,
+        "8h" => KlineInterval::Hours8,
+        "12h" => KlineInterval::Hours12,
+        "1d" => KlineInterval::Days1,
+        "3d" => KlineInterval::Days3,
+        "1w" => KlineInterval::Weeks1,
+        "1M" => KlineInterval::Months1,
+        other => return Err(value_err(format!("Unknown interval: {}", other))),
+    })
+}
+
+fn parse_side(side: &str) -> PyResult<OrderSide> {
+    match side.to_lowercase().as_str() {
+        "buy" => Ok(OrderSide::Buy),
+        "sell" => Ok(OrderSide::Sell),
+        other => Err(value_err(format!("Unknown side: {}", other))),
+    }
+}
+
+fn parse_order_type(order_type: &str) -> PyResult<OrderType> {
+    match order_type.to_lowercase().as_str() {
+        "market" => Ok(OrderType::Market),
+        "limit" => Ok(OrderType::Limit),
+        "stop_loss" => Ok(OrderType::StopLoss),
+        "stop_loss_limit" => Ok(OrderType::StopLossLimit),
+        "take_profit" => Ok(OrderType::TakeProfit),
+        "take_profit_limit" => Ok(OrderType::TakeProfitLimit),
+        other => Err(value_err(format!("Unknown order type: {}", other))),
+    }
+}
+
+fn parse_time_in_force(time_in_force: &str) -> PyResult<TimeInForce> {
+    match time_in_force.to_uppercase().as_str() {
+        "GTC" => Ok(TimeInForce::GTC),
+        "IOC" => Ok(TimeInForce::IOC),
+        "FOK" => Ok(TimeInForce::FOK),
+        other => Err(value_err(format!("Unknown time in force: {}", other))),
+    }
+}
+
+fn parse_subscription(
+    channel: &str,
+    depth: Option<u32>,
+    interval: &str,
+) -> PyResult<SubscriptionType> {
+    match channel {
+        "ticker" => Ok(SubscriptionType::Ticker),
+        "trades" => Ok(SubscriptionType::Trades),
+        "agg_trades" => Ok(SubscriptionType::AggTrades),
+        "orderbook" => Ok(SubscriptionType::OrderBook {
+            depth,
+            mode: OrderBookMode::Snapshot,
+        }),
+        "orderbook_delta" => Ok(SubscriptionType::OrderBook {
+            depth,
+            mode: OrderBookMode::Delta,
+        }),
+        "orderbook_aggregated" => Ok(SubscriptionType::OrderBook {
+            depth,
+            mode: OrderBookMode::Aggregated,
+        }),
+        "klines" => Ok(SubscriptionType::Klines {
+            interval: parse_interval(interval)?,
+        }),
+        other => Err(value_err(format!("Unknown channel: {}", other))),
+    }
+}
+
+/// Market data and, with credentials, trading on one venue
+#[pyclass(name = "Connector", module = "lotusx", frozen)]
+pub struct PyConnector {
+    exchange: ExchangeId,
+    connector: Arc<dyn ExchangeConnector>,
+}
+
+#[pymethods]
+impl PyConnector {
+    /// `exchange` takes the `ExchangeId` keys, e.g. `"binance_perp"` or `"hyperliquid"`
+    #[new]
+    #[pyo3(signature = (exchange, api_key=None, secret_key=None, testnet=false))]
+    fn new(
+        exchange: &str,
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        testnet: bool,
+    ) -> PyResult<Self> {
+        let exchange: ExchangeId = exchange.parse().map_err(value_err)?;
+        let config = match (api_key, secret_key) {
+            (Some(api_key), Some(secret_key)) => ExchangeConfig::new(api_key, secret_key),
+            (None, None) => ExchangeConfig::read_only(),
+            _ => return Err(value_err("api_key and secret_key must be given together")),
+        };
+
+        // Some builders spawn background tasks
+        let _runtime = get_runtime().enter();
+        let connector = build_connector(exchange, config.testnet(testnet)).map_err(lotusx_err)?;
+
+        Ok(Self {
+            exchange,
+            connector: Arc::from(connector),
+        })
+    }
+
+    #[getter]
+    fn exchange(&self) -> String {
+        self.exchange.to_string()
+    }
+
+    #[getter]
+    fn websocket_url(&self) -> String {
+        self.connector.get_websocket_url()
+    }
+
+    fn get_markets<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let market = self.connector.clone();
+        future_into_py(py, async move {
+            let markets = market.get_markets().await.map_err(lotusx_err)?;
+            to_py(&markets)
+        })
+    }
+
+    #[pyo3(signature = (symbol, interval="1m", limit=None, start_time=None, end_time=None))]
+    fn get_klines<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: String,
+        interval: &str,
+        limit: Option<u32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let interval = parse_interval(interval)?;
+        let market = self.connector.clone();
+        future_into_py(py, async move {
+            let klines = market
+                .get_klines(symbol.into(), interval, limit, start_time, end_time)
+                .await
+                .map_err(lotusx_err)?;
+            to_py(&klines)
+        })
+    }
+
+    /// Place an order; without `order_type` it is a limit order if `price` is given
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (symbol, side, quantity, price=None, order_type=None, time_in_force=None, client_order_id=None))]
+    fn place_order<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: &str,
+        side: &str,
+        quantity: &Bound<'py, PyAny>,
+        price: Option<&Bound<'py, PyAny>>,
+        order_type: Option<&str>,
+        time_in_force: Option<&str>,
+        client_order_id: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let price = price
+            .map(|price| decimal_str(price)?.parse::<Price>().map_err(value_err))
+            .transpose()?;
+        let order_type = match order_type {
+            Some(order_type) => parse_order_type(order_type)?,
+            None if price.is_some() => OrderType::Limit,
+            None => OrderType::Market,
+        };
+        let order = OrderRequest {
+            symbol: conversion::string_to_symbol(symbol),
+            side: parse_side(side)?,
+            order_type,
+            quantity: decimal_str(quantity)?
+                .parse::<Quantity>()
+                .map_err(value_err)?,
+            price,
+            time_in_force: time_in_force.map(parse_time_in_force).transpose()?,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id,
+        };
+
+        let trading = self.trading()?;
+        future_into_py(py, async move {
+            let response = trading.place_order(order).await.map_err(lotusx_err)?;
+            to_py(&response)
+        })
+    }
+
+    fn cancel_order<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: String,
+        order_id: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let trading = self.trading()?;
+        future_into_py(py, async move {
+            trading
+                .cancel_order(symbol.into(), order_id)
+                .await
+                .map_err(lotusx_err)
+        })
+    }
+
+    fn cancel_order_by_client_id<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: String,
+        client_order_id: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let trading = self.trading()?;
+        future_into_py(py, async move {
+            trading
+                .cancel_order_by_client_id(symbol.into(), client_order_id)
+                .await
+                .map_err(lotusx_err)
+        })
+    }
+
+    /// Subscribe to `"ticker"`, `"trades"`, `"agg_trades"`, `"orderbook"` and/or `"klines"`
+    ///
+    /// `"orderbook"` streams snapshots; `"orderbook_delta"` and `"orderbook_aggregated"`
+    /// pick the other book modes.
+    ///
+    /// Resolves to a `MarketDataStream` to consume with `async for`.
+    #[pyo3(signature = (symbols, channels=vec!["ticker".to_string(), "trades".to_string()], depth=None, interval="1m"))]
+    fn subscribe<'py>(
+        &self,
+        py: Python<'py>,
+        symbols: Vec<String>,
+        channels: Vec<String>,
+        depth: Option<u32>,
+        interval: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let subscriptions = channels
+            .iter()
+            .map(|channel| parse_subscription(channel, depth, interval))
+            .collect::<PyResult<Vec<_>>>()?;
+        let symbols = symbols.iter().map(Symbol::from).collect();
+        let market = self.connector.clone();
+        future_into_py(py, async move {
+            let receiver = market
+                .subscribe_market_data(symbols, subscriptions, None)
+                .await
+                .map_err(lotusx_err)?;
+            Ok(PyMarketDataStream {
+                receiver: Arc::new(Mutex::new(receiver)),
+            })
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Connector(exchange='{}', trading={})",
+            self.exchange,
+            self.connector.supports_trading()
+        )
+    }
+}
+
+impl PyConnector {
+    fn trading(&self) -> PyResult<Arc<dyn ExchangeConnector>> {
+        if self.connector.supports_trading() {
+            Ok(self.connector.clone())
+        } else {
+            Err(lotusx_err("Trading needs api_key and secret_key"))
+        }
+    }
+}
+
+/// Async iterator over market data updates
+#[pyclass(name = "MarketDataStream", module = "lotusx", frozen)]
+pub struct PyMarketDataStream {
+    receiver: Arc<Mutex<mpsc::Receiver<MarketDataType>>>,
+}
+
+#[pymethods]
+impl PyMarketDataStream {
+    const fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let receiver = self.receiver.clone();
+        future_into_py(py, async move {
+            let data = receiver.lock().await.recv().await;
+            data.map_or_else(
+                || Err(PyStopAsyncIteration::new_err("stream closed")),
+                |data| to_py(&data),
+            )
+        })
+    }
+}
+
+#[pymodule]
+fn lotusx(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyConnector>()?;
+    m.add_class::<PyMarketDataStream>()?;
+    m.add("LotusxError", m.py().get_type::<LotusxError>())?;
+    Ok(())
+}
@@ -0,0 +1,219 @@
+//! Fixtures shared by unit tests: a scriptable venue and market data builders
+
+use crate::core::errors::ExchangeError;
+use crate::core::traits::OrderPlacer;
+use crate::core::types::{
+    conversion, OrderBook, OrderBookEntry, OrderRequest, OrderResponse, OrderSide, OrderType,
+    Symbol, Ticker, Trade,
+};
+use crate::utils::paper::{Fill, Liquidity};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::sync::Mutex;
+use std::time::Duration;
+
+pub fn btc_usdt() -> Symbol {
+    Symbol::new("BTC", "USDT").unwrap()
+}
+
+/// Book with `(price, quantity)` levels, best first
+pub fn book(symbol: &Symbol, bids: &[(&str, &str)], asks: &[(&str, &str)]) -> OrderBook {
+    let levels = |levels: &[(&str, &str)]| {
+        levels
+            .iter()
+            .map(|(price, quantity)| OrderBookEntry {
+                price: conversion::string_to_price(price),
+                quantity: conversion::string_to_quantity(quantity),
+            })
+            .collect()
+    };
+    OrderBook {
+        symbol: symbol.clone(),
+        bids: levels(bids),
+        asks: levels(asks),
+        last_update_id: 0,
+    }
+}
+
+/// Buyer-aggressor trade at time zero
+pub fn trade(symbol: &Symbol, price: &str, quantity: &str) -> Trade {
+    Trade {
+        symbol: symbol.clone(),
+        id: 0,
+        price: conversion::string_to_price(price),
+        quantity: conversion::string_to_quantity(quantity),
+        time: 0,
+        is_buyer_maker: false,
+    }
+}
+
+/// Flat ticker at `price`
+pub fn ticker(symbol: &Symbol, price: &str) -> Ticker {
+    Ticker {
+        symbol: symbol.clone(),
+        price: conversion::string_to_price(price),
+        price_change: conversion::string_to_price("0"),
+        price_change_percent: Decimal::ZERO,
+        high_price: conversion::string_to_price(price),
+        low_price: conversion::string_to_price(price),
+        volume: conversion::string_to_volume("0"),
+        quote_volume: conversion::string_to_volume("0"),
+        open_time: 0,
+        close_time: 0,
+        count: 0,
+    }
+}
+
+/// Fee-free fill on `btc_usdt` at time zero
+pub fn fill(
+    order_id: &str,
+    side: OrderSide,
+    price: &str,
+    quantity: &str,
+    liquidity: Liquidity,
+) -> Fill {
+    Fill {
+        order_id: order_id.to_string(),
+        symbol: btc_usdt(),
+        side,
+        price: conversion::string_to_price(price),
+        quantity: conversion::string_to_quantity(quantity),
+        fee: Decimal::ZERO,
+        liquidity,
+        time: 0,
+    }
+}
+
+pub fn limit_order(
+    symbol: &Symbol,
+    side: OrderSide,
+    price: &str,
+    quantity: &str,
+) -> OrderRequest {
+    OrderRequest {
+        symbol: symbol.clone(),
+        side,
+        order_type: OrderType::Limit,
+        quantity: conversion::string_to_quantity(quantity),
+        price: Some(conversion::string_to_price(price)),
+        time_in_force: None,
+        stop_price: None,
+        reduce_only: None,
+        close_position: None,
+        client_order_id: None,
+    }
+}
+
+/// Venue that acknowledges every order and logs each call
+///
+/// Orders get ids `1`, `2`, ... and market orders come back filled. An amend
+/// cancel-replaces the order under `{id}-{n}`, as venues without in-place
+/// amends do.
+#[derive(Debug, Default)]
+pub struct FakeVenue {
+    max_quantity: Option<Decimal>,
+    latency: Duration,
+    next_id: Mutex<u32>,
+    calls: Mutex<Vec<String>>,
+}
+
+impl FakeVenue {
+    /// Reject orders larger than `quantity`
+    pub fn with_max_quantity(mut self, quantity: Decimal) -> Self {
+        self.max_quantity = Some(quantity);
+        self
+    }
+
+    /// Wait this long before answering an amend
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// `place {side:?} {symbol}`, `cancel {id} {symbol}` and `modify {id} {price}`, in order
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// Ids cancelled so far, sorted
+    pub fn cancelled(&self) -> Vec<String> {
+        let mut cancelled: Vec<String> = self
+            .calls()
+            .iter()
+            .filter_map(|call| call.strip_prefix("cancel "))
+            .filter_map(|call| call.split(' ').next())
+            .map(str::to_string)
+            .collect();
+        cancelled.sort();
+        cancelled
+    }
+
+    fn log(&self, call: String) {
+        self.calls.lock().unwrap().push(call);
+    }
+
+    fn response(order_id: String, order: OrderRequest) -> OrderResponse {
+        let status = if matches!(order.order_type, OrderType::Market) {
+            "FILLED"
+        } else {
+            "NEW"
+        };
+        OrderResponse {
+            order_id,
+            client_order_id: order.client_order_id.unwrap_or_default(),
+            symbol: order.symbol,
+            side: order.side,
+            order_type: order.order_type,
+            quantity: order.quantity,
+            price: order.price,
+            status: status.to_string(),
+            timestamp: 0,
+            extensions: None,
+        }
+    }
+}
+
+#[async_trait]
+impl OrderPlacer for FakeVenue {
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        if self
+            .max_quantity
+            .is_some_and(|max| order.quantity.value() > max)
+        {
+            return Err(ExchangeError::InvalidParameters("too large".to_string()));
+        }
+        self.log(format!("place {:?} {}", order.side, order.symbol));
+        let order_id = {
+            let mut next_id = self.next_id.lock().unwrap();
+            *next_id += 1;
+            next_id.to_string()
+        };
+        Ok(Self::response(order_id, order))
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        self.log(format!("cancel {} {}", order_id, symbol));
+        Ok(())
+    }
+
+    async fn modify_order(
+        &self,
+        order_id: String,
+        order: OrderRequest,
+    ) -> Result<OrderResponse, ExchangeError> {
+        tokio::time::sleep(self.latency).await;
+        let price = order
+            .price
+            .map(|price| price.to_string())
+            .unwrap_or_default();
+        let amends = {
+            let mut calls = self.calls.lock().unwrap();
+            calls.push(format!("modify {} {}", order_id, price));
+            calls
+                .iter()
+                .filter(|call| call.starts_with("modify "))
+                .count()
+        };
+        Ok(Self::response(format!("{}-{}", order_id, amends), order))
+    }
+}
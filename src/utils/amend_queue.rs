@@ -0,0 +1,301 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::OrderPlacer;
+use crate::core::types::{OrderRequest, OrderResponse, Symbol};
+use crate::utils::order_pacer::{OrderEndpoint, OrderPacer};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::mpsc;
+
+/// Result of one amend sent to the venue
+#[derive(Debug)]
+pub struct AmendOutcome {
+    /// Order the amend targeted; the response carries the new id after a cancel-replace
+    pub order_id: String,
+    pub symbol: Symbol,
+    /// Earlier updates for the order that this amend replaced unsent
+    pub conflated: u64,
+    pub result: Result<OrderResponse, ExchangeError>,
+}
+
+/// Counters kept by an `AmendQueue`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AmendStats {
+    /// Updates passed to `amend`
+    pub submitted: u64,
+    /// Amends sent to the venue
+    pub sent: u64,
+    /// Updates replaced by a later one before they were sent
+    pub conflated: u64,
+    /// Amends the pacer or the venue refused
+    pub failed: u64,
+}
+
+#[derive(Default)]
+struct SymbolQueue {
+    /// Order ids with a pending update, oldest first
+    order_ids: VecDeque<String>,
+    latest: HashMap<String, (OrderRequest, u64)>,
+    running: bool,
+}
+
+struct Inner<P> {
+    placer: Arc<P>,
+    pacer: Arc<OrderPacer>,
+    queues: Mutex<HashMap<Symbol, SymbolQueue>>,
+    outcomes: mpsc::Sender<AmendOutcome>,
+    submitted: AtomicU64,
+    sent: AtomicU64,
+    conflated: AtomicU64,
+    failed: AtomicU64,
+}
+
+impl<P> Inner<P> {
+    fn with_queues<T>(&self, f: impl FnOnce(&mut HashMap<Symbol, SymbolQueue>) -> T) -> T {
+        let mut queues = self.queues.lock().unwrap_or_else(PoisonError::into_inner);
+        f(&mut queues)
+    }
+}
+
+/// Per-symbol amend queue that conflates rapid price updates
+///
+/// Each symbol has at most one amend in flight. Updates for an order that arrive while
+/// its symbol is busy or waiting on the pacer replace each other, so only the latest is
+/// sent. Amends draw `Modify` capacity from the shared `OrderPacer`, and when a venue
+/// answers an amend with a new order id, queued updates follow the order to it.
+pub struct AmendQueue<P: OrderPlacer + Send + Sync + 'static> {
+    inner: Arc<Inner<P>>,
+}
+
+impl<P: OrderPlacer + Send + Sync + 'static> AmendQueue<P> {
+    /// Create the queue and the receiver its outcomes are reported on
+    ///
+    /// Outcomes are dropped once the receiver is, without affecting the amends.
+    pub fn new(venue: Arc<P>, pacer: Arc<OrderPacer>) -> (Self, mpsc::Receiver<AmendOutcome>) {
+        let (tx, rx) = mpsc::channel(256);
+        let inner = Inner {
+            placer: venue,
+            pacer,
+            queues: Mutex::new(HashMap::new()),
+            outcomes: tx,
+            submitted: AtomicU64::new(0),
+            sent: AtomicU64::new(0),
+            conflated: AtomicU64::new(0),
+            failed: AtomicU64::new(0),
+        };
+        (
+            Self {
+                inner: Arc::new(inner),
+            },
+            rx,
+        )
+    }
+
+    /// Queue `order` as the new state of `order_id`, replacing any unsent update
+    ///
+    /// Must be called within a Tokio runtime, which runs the symbol's worker.
+    pub fn amend(&self, order_id: impl Into<String>, order: OrderRequest) {
+        let order_id = order_id.into();
+        let symbol = order.symbol.clone();
+        self.inner.submitted.fetch_add(1, Ordering::Relaxed);
+
+        let start = self.inner.with_queues(|queues| {
+            let queue = queues.entry(symbol.clone()).or_default();
+            if let Some((pending, conflated)) = queue.latest.get_mut(&order_id) {
+                *pending = order;
+                *conflated += 1;
+                self.inner.conflated.fetch_add(1, Ordering::Relaxed);
+            } else {
+                queue.order_ids.push_back(order_id.clone());
+                queue.latest.insert(order_id, (order, 0));
+            }
+            !std::mem::replace(&mut queue.running, true)
+        });
+        if start {
+            tokio::spawn(run_symbol(self.inner.clone(), symbol));
+        }
+    }
+
+    /// Drop the unsent update for an order that filled or was cancelled
+    pub fn discard(&self, symbol: &Symbol, order_id: &str) -> bool {
+        self.inner.with_queues(|queues| {
+            queues.get_mut(symbol).is_some_and(|queue| {
+                queue.order_ids.retain(|pending| pending != order_id);
+                queue.latest.remove(order_id).is_some()
+            })
+        })
+    }
+
+    /// Updates waiting to be sent, across all symbols
+    pub fn pending(&self) -> usize {
+        self.inner
+            .with_queues(|queues| queues.values().map(|queue| queue.latest.len()).sum())
+    }
+
+    pub fn stats(&self) -> AmendStats {
+        AmendStats {
+            submitted: self.inner.submitted.load(Ordering::Relaxed),
+            sent: self.inner.sent.load(Ordering::Relaxed),
+            conflated: self.inner.conflated.load(Ordering::Relaxed),
+            failed: self.inner.failed.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Send a symbol's amends one at a time until its queue is empty
+async fn run_symbol<P: OrderPlacer + Send + Sync + 'static>(inner: Arc<Inner<P>>, symbol: Symbol) {
+    loop {
+        let idle = inner.with_queues(|queues| {
+            let queue = queues.entry(symbol.clone()).or_default();
+            if queue.latest.is_empty() {
+                queue.running = false;
+            }
+            !queue.running
+        });
+        if idle {
+            break;
+        }
+
+        // Wait for capacity before taking the update, so anything arriving meanwhile
+        // still conflates into it
+        let paced = inner.pacer.acquire(OrderEndpoint::Modify, &symbol).await;
+
+        let next = inner.with_queues(|queues| {
+            let queue = queues.get_mut(&symbol)?;
+            let order_id = queue.order_ids.pop_front()?;
+            let (order, conflated) = queue.latest.remove(&order_id)?;
+            Some((order_id, order, conflated))
+        });
+        // Discarded while waiting
+        let Some((order_id, order, conflated)) = next else {
+            continue;
+        };
+
+        let result = match paced {
+            Ok(()) => {
+                inner.sent.fetch_add(1, Ordering::Relaxed);
+                inner.placer.modify_order(order_id.clone(), order).await
+            }
+            Err(e) => Err(e),
+        };
+        match &result {
+            Ok(response) if response.order_id != order_id => {
+                inner.with_queues(|queues| {
+                    if let Some(queue) = queues.get_mut(&symbol) {
+                        follow_replacement(queue, &order_id, &response.order_id);
+                    }
+                });
+            }
+            Ok(_) => {}
+            Err(_) => {
+                inner.failed.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let outcome = AmendOutcome {
+            order_id,
+            symbol: symbol.clone(),
+            conflated,
+            result,
+        };
+        // A dropped receiver only means nobody is watching outcomes
+        let _ = inner.outcomes.send(outcome).await;
+    }
+}
+
+/// Retarget a queued update at the id a cancel-replace amend gave its order
+fn follow_replacement(queue: &mut SymbolQueue, old_id: &str, new_id: &str) {
+    if let Some(pending) = queue.latest.remove(old_id) {
+        queue.latest.insert(new_id.to_string(), pending);
+        for order_id in &mut queue.order_ids {
+            if order_id == old_id {
+                *order_id = new_id.to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{conversion, OrderSide};
+    use crate::test_support::{limit_order, FakeVenue};
+    use crate::utils::order_pacer::{PaceRule, PacerConfig};
+    use std::time::Duration;
+
+    fn quote(symbol: &str, price: i64) -> OrderRequest {
+        let symbol = conversion::string_to_symbol(symbol);
+        limit_order(&symbol, OrderSide::Buy, &price.to_string(), "1")
+    }
+
+    /// Cancel-replaces every amend under a fresh id, slowly
+    fn slow_venue() -> Arc<FakeVenue> {
+        Arc::new(FakeVenue::default().with_latency(Duration::from_millis(20)))
+    }
+
+    fn unpaced() -> Arc<OrderPacer> {
+        Arc::new(OrderPacer::new(PacerConfig::default()))
+    }
+
+    #[tokio::test]
+    async fn test_conflates_updates_while_amend_in_flight() {
+        let venue = slow_venue();
+        let (queue, mut outcomes) = AmendQueue::new(venue.clone(), unpaced());
+
+        queue.amend("1", quote("BTCUSDT", 100));
+        tokio::task::yield_now().await;
+        for price in 101..=105 {
+            queue.amend("1", quote("BTCUSDT", price));
+        }
+        assert_eq!(queue.pending(), 1);
+
+        let first = outcomes.recv().await.unwrap();
+        assert_eq!(first.result.unwrap().order_id, "1-1");
+        let second = outcomes.recv().await.unwrap();
+        assert_eq!(second.conflated, 4);
+
+        // The latest price went to the replacement order
+        assert_eq!(venue.calls(), ["modify 1 100", "modify 1-1 105"]);
+        assert_eq!(
+            queue.stats(),
+            AmendStats {
+                submitted: 6,
+                sent: 2,
+                conflated: 4,
+                failed: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_symbols_are_independent_and_paced() {
+        let venue = slow_venue();
+        let pacer = Arc::new(OrderPacer::new(PacerConfig::new(vec![PaceRule::new(
+            &[OrderEndpoint::Modify],
+            1,
+            Duration::from_millis(100),
+        )
+        .per_symbol()])));
+        let (queue, mut outcomes) = AmendQueue::new(venue.clone(), pacer);
+
+        let started = std::time::Instant::now();
+        queue.amend("btc-bid", quote("BTCUSDT", 100));
+        queue.amend("btc-ask", quote("BTCUSDT", 101));
+        queue.amend("eth-bid", quote("ETHUSDT", 10));
+        assert!(queue.discard(&conversion::string_to_symbol("BTCUSDT"), "btc-ask"));
+        queue.amend("btc-ask", quote("BTCUSDT", 102));
+
+        let mut done = Vec::new();
+        for _ in 0..3 {
+            let outcome = outcomes.recv().await.unwrap();
+            done.push((outcome.order_id, started.elapsed()));
+        }
+        // ETH does not wait behind BTC; the second BTC order waits for pacing capacity
+        assert_eq!(done[2].0, "btc-ask");
+        assert!(done[2].1 >= Duration::from_millis(90));
+        assert!(done[..2]
+            .iter()
+            .all(|(_, elapsed)| *elapsed < Duration::from_millis(90)));
+        assert_eq!(queue.stats().conflated, 0);
+    }
+}
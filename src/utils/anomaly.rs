@@ -0,0 +1,274 @@
+use crate::core::types::{MarketDataType, OrderBook, Price, Symbol, Trade};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::{HashMap, VecDeque};
+use tokio::sync::mpsc;
+
+/// Market data anomaly flagged by `AnomalyDetector`
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnomalyEvent {
+    /// Trade printed below the best bid or above the best ask
+    TradeOutsideSpread {
+        symbol: Symbol,
+        price: Price,
+        best_bid: Price,
+        best_ask: Price,
+    },
+    /// Trade-to-trade return larger than the configured number of standard deviations
+    PriceJump {
+        symbol: Symbol,
+        previous: Price,
+        price: Price,
+        /// Size of the move in standard deviations of recent returns
+        sigma: f64,
+    },
+    /// Best bid at or above the best ask
+    CrossedBook {
+        symbol: Symbol,
+        best_bid: Price,
+        best_ask: Price,
+    },
+}
+
+impl AnomalyEvent {
+    pub const fn symbol(&self) -> &Symbol {
+        match self {
+            Self::TradeOutsideSpread { symbol, .. }
+            | Self::PriceJump { symbol, .. }
+            | Self::CrossedBook { symbol, .. } => symbol,
+        }
+    }
+}
+
+/// Thresholds for `AnomalyDetector`
+#[derive(Debug, Clone)]
+pub struct AnomalyConfig {
+    /// Moves beyond this many standard deviations raise `PriceJump`
+    pub sigma_threshold: f64,
+    /// Number of recent trade-to-trade returns used for the volatility estimate
+    pub window: usize,
+    /// Returns required before `PriceJump` can fire
+    pub min_samples: usize,
+    /// Fraction of the price a trade may sit outside the spread before it is flagged
+    pub spread_tolerance: Decimal,
+}
+
+impl Default for AnomalyConfig {
+    fn default() -> Self {
+        Self {
+            sigma_threshold: 6.0,
+            window: 200,
+            min_samples: 30,
+            spread_tolerance: Decimal::ZERO,
+        }
+    }
+}
+
+impl AnomalyConfig {
+    #[must_use]
+    pub const fn with_sigma_threshold(mut self, sigma: f64) -> Self {
+        self.sigma_threshold = sigma;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_window(mut self, window: usize, min_samples: usize) -> Self {
+        self.window = window;
+        self.min_samples = min_samples;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_spread_tolerance(mut self, tolerance: Decimal) -> Self {
+        self.spread_tolerance = tolerance;
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct SymbolState {
+    best_bid: Option<Price>,
+    best_ask: Option<Price>,
+    last_price: Option<Price>,
+    returns: VecDeque<f64>,
+}
+
+/// Flags suspicious prints and books on a unified market data stream
+///
+/// Checks are per symbol and use only the stream itself: trades are compared against
+/// the most recent order book seen, so a stale book can produce false positives on
+/// thinly updated feeds. Use `spread_tolerance` to absorb that.
+#[derive(Debug, Default)]
+pub struct AnomalyDetector {
+    config: AnomalyConfig,
+    symbols: HashMap<Symbol, SymbolState>,
+}
+
+impl AnomalyDetector {
+    pub fn new(config: AnomalyConfig) -> Self {
+        Self {
+            config,
+            symbols: HashMap::new(),
+        }
+    }
+
+    /// Inspect one market data message, returning any anomalies it reveals
+    pub fn observe(&mut self, data: &MarketDataType) -> Vec<AnomalyEvent> {
+        match data {
+            MarketDataType::OrderBook(book) => self.on_order_book(book).into_iter().collect(),
+            MarketDataType::Trade(trade) => self.on_trade(trade),
+            MarketDataType::Ticker(_) | MarketDataType::Kline(_) | MarketDataType::MarkPrice(_) => {
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn on_order_book(&mut self, book: &OrderBook) -> Option<AnomalyEvent> {
+        let state = self.symbols.entry(book.symbol.clone()).or_default();
+        state.best_bid = book.bids.first().map(|level| level.price);
+        state.best_ask = book.asks.first().map(|level| level.price);
+
+        match (state.best_bid, state.best_ask) {
+            (Some(best_bid), Some(best_ask)) if best_bid >= best_ask => {
+                Some(AnomalyEvent::CrossedBook {
+                    symbol: book.symbol.clone(),
+                    best_bid,
+                    best_ask,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    pub fn on_trade(&mut self, trade: &Trade) -> Vec<AnomalyEvent> {
+        let config = &self.config;
+        let state = self.symbols.entry(trade.symbol.clone()).or_default();
+        let mut events = Vec::new();
+
+        if let (Some(best_bid), Some(best_ask)) = (state.best_bid, state.best_ask) {
+            let tolerance = trade.price.value() * config.spread_tolerance;
+            if trade.price.value() < best_bid.value() - tolerance
+                || trade.price.value() > best_ask.value() + tolerance
+            {
+                events.push(AnomalyEvent::TradeOutsideSpread {
+                    symbol: trade.symbol.clone(),
+                    price: trade.price,
+                    best_bid,
+                    best_ask,
+                });
+            }
+        }
+
+        if let Some(previous) = state.last_price {
+            if let Some(ret) = simple_return(previous, trade.price) {
+                if state.returns.len() >= config.min_samples.max(2) {
+                    let (mean, std_dev) = mean_and_std_dev(&state.returns);
+                    if std_dev > 0.0 {
+                        let sigma = (ret - mean).abs() / std_dev;
+                        if sigma > config.sigma_threshold {
+                            events.push(AnomalyEvent::PriceJump {
+                                symbol: trade.symbol.clone(),
+                                previous,
+                                price: trade.price,
+                                sigma,
+                            });
+                        }
+                    }
+                }
+                state.returns.push_back(ret);
+                while state.returns.len() > config.window {
+                    state.returns.pop_front();
+                }
+            }
+        }
+        state.last_price = Some(trade.price);
+
+        events
+    }
+}
+
+fn simple_return(previous: Price, price: Price) -> Option<f64> {
+    if previous.value().is_zero() {
+        return None;
+    }
+    ((price.value() - previous.value()) / previous.value()).to_f64()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn mean_and_std_dev(samples: &VecDeque<f64>) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance.sqrt())
+}
+
+/// Run an `AnomalyDetector` over a unified market data stream
+///
+/// The task exits when the stream closes or the returned receiver is dropped.
+pub fn detect_anomalies(
+    config: AnomalyConfig,
+    mut stream: mpsc::Receiver<MarketDataType>,
+) -> mpsc::Receiver<AnomalyEvent> {
+    let (tx, rx) = mpsc::channel(256);
+
+    tokio::spawn(async move {
+        let mut detector = AnomalyDetector::new(config);
+        while let Some(data) = stream.recv().await {
+            for event in detector.observe(&data) {
+                if tx.send(event).await.is_err() {
+                    return; // Receiver dropped
+                }
+            }
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, btc_usdt};
+
+    fn book(bid: &str, ask: &str) -> MarketDataType {
+        MarketDataType::OrderBook(test_support::book(
+            &btc_usdt(),
+            &[(bid, "1")],
+            &[(ask, "1")],
+        ))
+    }
+
+    fn trade(price: &str) -> MarketDataType {
+        MarketDataType::Trade(test_support::trade(&btc_usdt(), price, "1"))
+    }
+
+    #[test]
+    fn test_book_checks() {
+        let mut detector = AnomalyDetector::default();
+        assert!(detector.observe(&book("100", "101")).is_empty());
+        assert!(detector.observe(&trade("100.5")).is_empty());
+        assert!(matches!(
+            detector.observe(&trade("102"))[..],
+            [AnomalyEvent::TradeOutsideSpread { .. }]
+        ));
+        assert!(matches!(
+            detector.observe(&book("101", "101"))[..],
+            [AnomalyEvent::CrossedBook { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_price_jump_needs_history() {
+        let mut detector = AnomalyDetector::new(AnomalyConfig::default().with_window(50, 10));
+        let prices = ["100", "100.1", "100", "100.1"];
+        for price in prices.iter().cycle().take(20) {
+            assert!(detector.observe(&trade(price)).is_empty());
+        }
+
+        let events = detector.observe(&trade("105"));
+        let [AnomalyEvent::PriceJump { sigma, .. }] = events[..] else {
+            panic!("expected a price jump, got {events:?}");
+        };
+        assert!(sigma > 6.0);
+    }
+}
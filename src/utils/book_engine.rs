@@ -0,0 +1,601 @@
+use crate::core::errors::ExchangeError;
+use crate::core::types::{MarketDataType, OrderBook, OrderBookEntry, Price, Quantity, Symbol};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+use tokio::time::{Instant, Interval};
+use tracing::warn;
+
+/// How each incoming `OrderBook` relates to the book already held
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BookFeed {
+    /// Changed levels only, with a zero quantity removing the level (e.g. Binance `depth`)
+    #[default]
+    Deltas,
+    /// Full views of the subscribed depth that replace the held book
+    Snapshots,
+}
+
+/// Periodic cross-check of held books against REST snapshots
+#[derive(Debug, Clone)]
+pub struct IntegrityCheck {
+    /// How often every held book is checked
+    pub interval: Duration,
+    /// Levels per side compared, from the top
+    pub levels: usize,
+    /// Largest relative quantity difference at a level that still counts as a match
+    pub tolerance: Decimal,
+}
+
+impl Default for IntegrityCheck {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(60),
+            levels: 10,
+            tolerance: Decimal::ZERO,
+        }
+    }
+}
+
+/// Settings for `OrderBookEngine`
+#[derive(Debug, Clone)]
+pub struct BookEngineConfig {
+    pub feed: BookFeed,
+    /// Levels per side in published snapshots
+    pub depth: usize,
+    /// Publish every held book on this period; `None` publishes updates only
+    pub snapshot_interval: Option<Duration>,
+    /// Cross-check held books against REST; only `run_verified_book_engine` acts on it
+    pub integrity: Option<IntegrityCheck>,
+}
+
+impl Default for BookEngineConfig {
+    fn default() -> Self {
+        Self {
+            feed: BookFeed::default(),
+            depth: 1000,
+            snapshot_interval: None,
+            integrity: None,
+        }
+    }
+}
+
+impl BookEngineConfig {
+    #[must_use]
+    pub const fn with_feed(mut self, feed: BookFeed) -> Self {
+        self.feed = feed;
+        self
+    }
+
+    #[must_use]
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = depth.max(1);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_snapshot_interval(mut self, interval: Duration) -> Self {
+        self.snapshot_interval = Some(interval);
+        self
+    }
+
+    #[must_use]
+    pub fn with_integrity_check(mut self, check: IntegrityCheck) -> Self {
+        self.integrity = Some(check);
+        self
+    }
+}
+
+/// Best level on each side after an update
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopOfBook {
+    pub symbol: Symbol,
+    pub best_bid: Option<OrderBookEntry>,
+    pub best_ask: Option<OrderBookEntry>,
+    pub last_update_id: i64,
+}
+
+/// Output of the order book engine
+#[derive(Debug, Clone)]
+pub enum BookEvent {
+    /// Update as received from the venue
+    Update(OrderBook),
+    /// Best bid or ask changed
+    TopOfBook(TopOfBook),
+    /// Full held book, published on the snapshot interval
+    Snapshot(OrderBook),
+    /// Held book replaced by a REST snapshot it had drifted from
+    Resync(OrderBook),
+}
+
+/// REST order book the integrity check compares held books against
+#[async_trait]
+pub trait BookSnapshotSource: Send + Sync {
+    async fn get_order_book(
+        &self,
+        symbol: &Symbol,
+        depth: usize,
+    ) -> Result<OrderBook, ExchangeError>;
+}
+
+/// Counters of the integrity check
+#[derive(Debug, Default)]
+pub struct IntegrityStats {
+    checks: AtomicU64,
+    resyncs: AtomicU64,
+}
+
+impl IntegrityStats {
+    /// Books compared against a REST snapshot
+    pub fn checks(&self) -> u64 {
+        self.checks.load(Ordering::Relaxed)
+    }
+
+    /// Books found drifted and replaced
+    pub fn resyncs(&self) -> u64 {
+        self.resyncs.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Book {
+    bids: BTreeMap<Price, Quantity>,
+    asks: BTreeMap<Price, Quantity>,
+    last_update_id: i64,
+}
+
+impl Book {
+    fn best_bid(&self) -> Option<OrderBookEntry> {
+        self.bids.iter().next_back().map(entry)
+    }
+
+    fn best_ask(&self) -> Option<OrderBookEntry> {
+        self.asks.iter().next().map(entry)
+    }
+}
+
+fn entry((price, quantity): (&Price, &Quantity)) -> OrderBookEntry {
+    OrderBookEntry {
+        price: *price,
+        quantity: *quantity,
+    }
+}
+
+/// Maintains L2 books per symbol from a unified market data stream
+#[derive(Debug, Default)]
+pub struct OrderBookEngine {
+    config: BookEngineConfig,
+    books: HashMap<Symbol, Book>,
+}
+
+impl OrderBookEngine {
+    pub fn new(config: BookEngineConfig) -> Self {
+        Self {
+            config,
+            books: HashMap::new(),
+        }
+    }
+
+    /// Apply an update, returning it plus a `TopOfBook` event if the best levels moved
+    pub fn apply(&mut self, update: &OrderBook) -> Vec<BookEvent> {
+        let book = self.books.entry(update.symbol.clone()).or_default();
+        let top = (book.best_bid(), book.best_ask());
+
+        if self.config.feed == BookFeed::Snapshots {
+            book.bids.clear();
+            book.asks.clear();
+        }
+        for (levels, entries) in [
+            (&mut book.bids, &update.bids),
+            (&mut book.asks, &update.asks),
+        ] {
+            for level in entries {
+                if level.quantity.value().is_zero() {
+                    levels.remove(&level.price);
+                } else {
+                    levels.insert(level.price, level.quantity);
+                }
+            }
+        }
+        book.last_update_id = update.last_update_id;
+
+        let mut events = vec![BookEvent::Update(update.clone())];
+        let (best_bid, best_ask) = (book.best_bid(), book.best_ask());
+        if (best_bid.as_ref(), best_ask.as_ref()) != (top.0.as_ref(), top.1.as_ref()) {
+            events.push(BookEvent::TopOfBook(TopOfBook {
+                symbol: update.symbol.clone(),
+                best_bid,
+                best_ask,
+                last_update_id: update.last_update_id,
+            }));
+        }
+        events
+    }
+
+    /// Held book for `symbol`, limited to the configured depth
+    pub fn book(&self, symbol: &Symbol) -> Option<OrderBook> {
+        let book = self.books.get(symbol)?;
+        Some(OrderBook {
+            symbol: symbol.clone(),
+            bids: book
+                .bids
+                .iter()
+                .rev()
+                .take(self.config.depth)
+                .map(entry)
+                .collect(),
+            asks: book
+                .asks
+                .iter()
+                .take(self.config.depth)
+                .map(entry)
+                .collect(),
+            last_update_id: book.last_update_id,
+        })
+    }
+
+    /// Every held book, limited to the configured depth
+    pub fn snapshots(&self) -> Vec<OrderBook> {
+        self.books
+            .keys()
+            .filter_map(|symbol| self.book(symbol))
+            .collect()
+    }
+
+    /// Levels among the top `check.levels` per side that differ from `snapshot`
+    ///
+    /// A level differs if its price differs or its quantity is off by more than the
+    /// tolerance. Snapshots older than the held book, by `last_update_id`, count as a match.
+    pub fn mismatched_levels(&self, snapshot: &OrderBook, check: &IntegrityCheck) -> usize {
+        let Some(held) = self.book(&snapshot.symbol) else {
+            return 0;
+        };
+        if snapshot.last_update_id != 0 && snapshot.last_update_id < held.last_update_id {
+            return 0;
+        }
+        let matches = |a: &OrderBookEntry, b: &OrderBookEntry| {
+            let (a_qty, b_qty) = (a.quantity.value(), b.quantity.value());
+            a.price == b.price && (a_qty - b_qty).abs() <= check.tolerance * a_qty.max(b_qty)
+        };
+        let side = |held: &[OrderBookEntry], rest: &[OrderBookEntry]| {
+            (0..check.levels.min(held.len().max(rest.len())))
+                .filter(|&i| match (held.get(i), rest.get(i)) {
+                    (Some(a), Some(b)) => !matches(a, b),
+                    _ => true,
+                })
+                .count()
+        };
+        side(&held.bids, &snapshot.bids) + side(&held.asks, &snapshot.asks)
+    }
+
+    /// Check the held book of `symbol` against a venue's CRC32 over its top `levels`
+    ///
+    /// See `OrderBook::crc32` for the layout. On a mismatch the book is dropped, so the
+    /// snapshot the venue sends on resubscribing rebuilds it rather than merging into
+    /// stale levels; `levels` should not exceed the configured depth.
+    pub fn verify_checksum(
+        &mut self,
+        symbol: &Symbol,
+        levels: usize,
+        expected: u32,
+    ) -> Result<(), ExchangeError> {
+        let Some(book) = self.book(symbol) else {
+            return Ok(());
+        };
+        let computed = book.crc32(levels);
+        if computed != expected {
+            self.books.remove(symbol);
+            return Err(ExchangeError::ChecksumMismatch {
+                stream: symbol.to_string(),
+                expected: i64::from(expected),
+                computed: i64::from(computed),
+            });
+        }
+        Ok(())
+    }
+
+    /// Replace the held book of `snapshot.symbol` with `snapshot`, fetched `levels` deep
+    ///
+    /// Held levels deeper than a full snapshot side are kept; a shorter side is the
+    /// whole side, so nothing deeper is.
+    pub fn resync(&mut self, snapshot: &OrderBook, levels: usize) -> BookEvent {
+        let book = self.books.entry(snapshot.symbol.clone()).or_default();
+        for (held, side, deeper) in [
+            (
+                &mut book.bids,
+                &snapshot.bids,
+                Price::lt as fn(&Price, &Price) -> bool,
+            ),
+            (&mut book.asks, &snapshot.asks, Price::gt),
+        ] {
+            let deepest = side
+                .last()
+                .filter(|_| side.len() >= levels)
+                .map(|l| l.price);
+            held.retain(|price, _| deepest.is_some_and(|deepest| deeper(price, &deepest)));
+            held.extend(side.iter().map(|l| (l.price, l.quantity)));
+        }
+        book.last_update_id = snapshot.last_update_id;
+        BookEvent::Resync(snapshot.clone())
+    }
+}
+
+/// Run an `OrderBookEngine` over a unified market data stream
+///
+/// Events go to a broadcast channel; call `subscribe()` on the returned sender to
+/// attach consumers at any time. With a snapshot interval, consumers that join late
+/// are in sync after the next `BookEvent::Snapshot` for each symbol, without a REST
+/// pull. Slow consumers skip ahead (`RecvError::Lagged`) rather than stall the engine.
+/// The task exits when the stream closes.
+pub fn run_book_engine(
+    config: BookEngineConfig,
+    stream: mpsc::Receiver<MarketDataType>,
+) -> broadcast::Sender<BookEvent> {
+    spawn_book_engine(config, stream, None, Arc::default())
+}
+
+/// Run an `OrderBookEngine` that cross-checks its books against `source`
+///
+/// On each integrity interval every held book is compared with a REST snapshot of the
+/// checked depth. A book with mismatched levels is replaced by the snapshot and a
+/// `BookEvent::Resync` is published, guarding against drift from missed deltas. Without
+/// an integrity check in `config` this behaves like `run_book_engine`.
+pub fn run_verified_book_engine(
+    config: BookEngineConfig,
+    stream: mpsc::Receiver<MarketDataType>,
+    source: Arc<dyn BookSnapshotSource>,
+) -> (broadcast::Sender<BookEvent>, Arc<IntegrityStats>) {
+    let stats = Arc::new(IntegrityStats::default());
+    let events = spawn_book_engine(config, stream, Some(source), stats.clone());
+    (events, stats)
+}
+
+fn spawn_book_engine(
+    config: BookEngineConfig,
+    mut stream: mpsc::Receiver<MarketDataType>,
+    source: Option<Arc<dyn BookSnapshotSource>>,
+    stats: Arc<IntegrityStats>,
+) -> broadcast::Sender<BookEvent> {
+    let (tx, _) = broadcast::channel(1024);
+    let events = tx.clone();
+
+    tokio::spawn(async move {
+        let every = |period: Duration| tokio::time::interval_at(Instant::now() + period, period);
+        let mut snapshots = config.snapshot_interval.map(every);
+        let check = config.integrity.clone().filter(|_| source.is_some());
+        let mut checks = check.as_ref().map(|check| every(check.interval));
+        // Snapshots are fetched off the loop so updates keep flowing meanwhile
+        let (fetched_tx, mut fetched) = mpsc::channel::<OrderBook>(64);
+        let mut engine = OrderBookEngine::new(config);
+        loop {
+            tokio::select! {
+                data = stream.recv() => match data {
+                    Some(MarketDataType::OrderBook(update)) => {
+                        for event in engine.apply(&update) {
+                            let _ = events.send(event);
+                        }
+                    }
+                    Some(_) => {}
+                    None => break,
+                },
+                () = tick(&mut snapshots) => {
+                    for book in engine.snapshots() {
+                        let _ = events.send(BookEvent::Snapshot(book));
+                    }
+                }
+                () = tick(&mut checks) => {
+                    let (Some(source), Some(check)) = (&source, &check) else {
+                        continue;
+                    };
+                    for symbol in engine.books.keys() {
+                        let (symbol, source, fetched_tx, levels) =
+                            (symbol.clone(), source.clone(), fetched_tx.clone(), check.levels);
+                        tokio::spawn(async move {
+                            match source.get_order_book(&symbol, levels).await {
+                                Ok(snapshot) => {
+                                    let _ = fetched_tx.send(snapshot).await;
+                                }
+                                Err(e) => {
+                                    warn!(%symbol, error = %e, "Failed to fetch order book for integrity check");
+                                }
+                            }
+                        });
+                    }
+                }
+                Some(snapshot) = fetched.recv() => {
+                    let Some(check) = &check else {
+                        continue;
+                    };
+                    stats.checks.fetch_add(1, Ordering::Relaxed);
+                    let mismatched = engine.mismatched_levels(&snapshot, check);
+                    if mismatched > 0 {
+                        warn!(symbol = %snapshot.symbol, mismatched, "Order book drifted from REST snapshot, resyncing");
+                        stats.resyncs.fetch_add(1, Ordering::Relaxed);
+                        let _ = events.send(engine.resync(&snapshot, check.levels));
+                    }
+                }
+            }
+        }
+    });
+
+    tx
+}
+
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{book, btc_usdt};
+
+    fn update(bids: &[(&str, &str)], asks: &[(&str, &str)], id: i64) -> OrderBook {
+        OrderBook {
+            last_update_id: id,
+            ..book(&btc_usdt(), bids, asks)
+        }
+    }
+
+    #[test]
+    fn test_deltas_merge_and_top_of_book_fires_on_change() {
+        let mut engine = OrderBookEngine::new(BookEngineConfig::default().with_depth(2));
+        let events = engine.apply(&update(&[("100", "1"), ("99", "2")], &[("101", "1")], 1));
+        assert!(matches!(events[1], BookEvent::TopOfBook(_)));
+
+        // Deeper bid added, best levels unchanged
+        let events = engine.apply(&update(&[("98", "4")], &[], 2));
+        assert_eq!(events.len(), 1);
+
+        // Best bid removed
+        let events = engine.apply(&update(&[("100", "0")], &[], 3));
+        let BookEvent::TopOfBook(top) = &events[1] else {
+            panic!("expected top of book");
+        };
+        assert_eq!(top.best_bid.as_ref().unwrap().price.to_string(), "99");
+
+        let book = engine.book(&Symbol::new("BTC", "USDT").unwrap()).unwrap();
+        let bids: Vec<_> = book.bids.iter().map(|l| l.price.to_string()).collect();
+        assert_eq!(bids, vec!["99", "98"]);
+        assert_eq!(book.last_update_id, 3);
+    }
+
+    #[test]
+    fn test_checksum_mismatch_drops_the_held_book() {
+        let mut engine = OrderBookEngine::new(BookEngineConfig::default());
+        let book = update(
+            &[("3366.1", "7"), ("3366", "6")],
+            &[("3366.8", "9"), ("3368", "8")],
+            1,
+        );
+        engine.apply(&book);
+
+        // CRC32 of `3366.1:7:3366.8:9:3366:6:3368:8`, OKX's worked example
+        assert!(engine
+            .verify_checksum(&book.symbol, 25, 2_413_953_002)
+            .is_ok());
+        let err = engine.verify_checksum(&book.symbol, 25, 1).unwrap_err();
+        assert!(matches!(
+            err,
+            ExchangeError::ChecksumMismatch {
+                expected: 1,
+                computed: 2_413_953_002,
+                ..
+            }
+        ));
+        assert!(engine.book(&book.symbol).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_snapshots_reach_late_subscribers() {
+        let (tx, rx) = mpsc::channel(8);
+        let events = run_book_engine(
+            BookEngineConfig::default()
+                .with_feed(BookFeed::Snapshots)
+                .with_snapshot_interval(Duration::from_millis(20)),
+            rx,
+        );
+        tx.send(MarketDataType::OrderBook(update(
+            &[("100", "1")],
+            &[("101", "1")],
+            7,
+        )))
+        .await
+        .unwrap();
+
+        let mut late = events.subscribe();
+        let book = loop {
+            if let BookEvent::Snapshot(book) = late.recv().await.unwrap() {
+                break book;
+            }
+        };
+        assert_eq!(book.last_update_id, 7);
+        assert_eq!(book.asks.len(), 1);
+    }
+
+    #[test]
+    fn test_drifted_book_is_detected_and_resynced() {
+        let check = IntegrityCheck {
+            levels: 2,
+            tolerance: Decimal::new(1, 2),
+            ..IntegrityCheck::default()
+        };
+        let mut engine = OrderBookEngine::new(BookEngineConfig::default());
+        engine.apply(&update(
+            &[("100", "1"), ("99", "2"), ("98", "3")],
+            &[("101", "1")],
+            5,
+        ));
+
+        // Within tolerance on the top two levels
+        let rest = update(&[("100", "1.005"), ("99", "2")], &[("101", "1")], 5);
+        assert_eq!(engine.mismatched_levels(&rest, &check), 0);
+
+        // A missed delta left a stale best bid
+        let rest = update(&[("99", "2"), ("98", "3")], &[("101", "1")], 6);
+        assert_eq!(engine.mismatched_levels(&rest, &check), 2);
+        assert!(matches!(engine.resync(&rest, 2), BookEvent::Resync(_)));
+
+        let book = engine.book(&rest.symbol).unwrap();
+        let bids: Vec<_> = book.bids.iter().map(|l| l.price.to_string()).collect();
+        assert_eq!(bids, vec!["99", "98"]);
+        assert_eq!(engine.mismatched_levels(&rest, &check), 0);
+
+        // Snapshots older than the held book are not compared
+        let stale = update(&[("50", "1")], &[], 1);
+        assert_eq!(engine.mismatched_levels(&stale, &check), 0);
+    }
+
+    struct FixedBook(OrderBook);
+
+    #[async_trait]
+    impl BookSnapshotSource for FixedBook {
+        async fn get_order_book(
+            &self,
+            _symbol: &Symbol,
+            _depth: usize,
+        ) -> Result<OrderBook, ExchangeError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verified_engine_resyncs_from_source() {
+        let rest = update(&[("99", "2")], &[("101", "1")], 9);
+        let (tx, rx) = mpsc::channel(8);
+        let (events, stats) = run_verified_book_engine(
+            BookEngineConfig::default().with_integrity_check(IntegrityCheck {
+                interval: Duration::from_millis(20),
+                ..IntegrityCheck::default()
+            }),
+            rx,
+            Arc::new(FixedBook(rest)),
+        );
+        let mut events = events.subscribe();
+        tx.send(MarketDataType::OrderBook(update(
+            &[("100", "1")],
+            &[("101", "1")],
+            8,
+        )))
+        .await
+        .unwrap();
+
+        let book = loop {
+            if let BookEvent::Resync(book) = events.recv().await.unwrap() {
+                break book;
+            }
+        };
+        assert_eq!(book.bids[0].price.to_string(), "99");
+        assert_eq!(stats.resyncs(), 1);
+        assert!(stats.checks() >= 1);
+    }
+}
@@ -0,0 +1,267 @@
+use crate::exchanges::factory::ExchangeId;
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Utc, Weekday};
+use std::collections::HashMap;
+
+/// Recurring funding schedule anchored at 00:00 UTC
+///
+/// Settlements happen every `interval` starting from midnight plus `offset`, e.g. an 8h
+/// schedule with no offset settles at 00:00, 08:00 and 16:00 UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FundingSchedule {
+    pub interval: Duration,
+    pub offset: Duration,
+}
+
+impl FundingSchedule {
+    pub const fn new(interval: Duration, offset: Duration) -> Self {
+        Self { interval, offset }
+    }
+
+    pub const fn every_hours(hours: i64) -> Self {
+        Self::new(Duration::hours(hours), Duration::zero())
+    }
+
+    /// First settlement strictly after `after`
+    pub fn next_after(&self, after: DateTime<Utc>) -> DateTime<Utc> {
+        self.last_at_or_before(after) + self.interval
+    }
+
+    /// Most recent settlement at or before `at`
+    pub fn last_at_or_before(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let interval = self.interval.num_seconds().max(1);
+        let offset = self.offset.num_seconds();
+        let elapsed = (at.timestamp() - offset).div_euclid(interval);
+        Utc.timestamp_opt(elapsed * interval + offset, 0)
+            .single()
+            .unwrap_or(at)
+    }
+}
+
+/// Recurring maintenance window in UTC
+///
+/// `weekday: None` repeats daily.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaintenanceWindow {
+    pub weekday: Option<Weekday>,
+    pub start: NaiveTime,
+    pub duration: Duration,
+    pub description: String,
+}
+
+impl MaintenanceWindow {
+    pub fn weekly(weekday: Weekday, start: NaiveTime, duration: Duration) -> Self {
+        Self {
+            weekday: Some(weekday),
+            start,
+            duration,
+            description: String::new(),
+        }
+    }
+
+    pub fn daily(start: NaiveTime, duration: Duration) -> Self {
+        Self {
+            weekday: None,
+            start,
+            duration,
+            description: String::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Start of the occurrence beginning on the day of `at`, if the window runs that day
+    fn occurrence_on(&self, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.weekday.is_some_and(|day| day != at.weekday()) {
+            return None;
+        }
+        Some(at.date_naive().and_time(self.start).and_utc())
+    }
+
+    /// Whether `at` falls inside an occurrence of this window
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        // An occurrence may have started on the previous day and run past midnight
+        [at - Duration::days(1), at]
+            .into_iter()
+            .filter_map(|day| self.occurrence_on(day))
+            .any(|start| start <= at && at < start + self.duration)
+    }
+
+    /// Start and end of the next occurrence beginning after `after`
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        (0..=7)
+            .filter_map(|days| self.occurrence_on(after + Duration::days(days)))
+            .find(|start| *start > after)
+            .map(|start| (start, start + self.duration))
+    }
+}
+
+/// Per-venue trading calendar
+///
+/// Holds the venue-wide funding schedule, per-symbol overrides (e.g. 4h-funded
+/// contracts), and known maintenance windows. Venues announce most maintenance ad hoc, so
+/// the defaults carry no windows; add them with `with_maintenance`.
+#[derive(Debug, Clone)]
+pub struct TradingCalendar {
+    exchange: ExchangeId,
+    funding: Option<FundingSchedule>,
+    funding_overrides: HashMap<String, FundingSchedule>,
+    maintenance: Vec<MaintenanceWindow>,
+}
+
+impl TradingCalendar {
+    /// Calendar with the venue's default funding schedule
+    ///
+    /// Spot venues have no funding. Hyperliquid, Backpack and dYdX fund hourly; Binance,
+    /// Bybit, OKX, Gate.io and Paradex perps default to 8h (Paradex accrues continuously
+    /// over an 8h period).
+    pub fn for_exchange(exchange: ExchangeId) -> Self {
+        let funding = match exchange {
+            ExchangeId::Binance
+            | ExchangeId::BinanceMargin
+            | ExchangeId::Bybit
+            | ExchangeId::Coinbase
+            | ExchangeId::Gateio
+            | ExchangeId::Kraken
+            | ExchangeId::Okx => None,
+            ExchangeId::Hyperliquid | ExchangeId::Backpack | ExchangeId::Dydx => {
+                Some(FundingSchedule::every_hours(1))
+            }
+            ExchangeId::BinancePerp
+            | ExchangeId::BybitPerp
+            | ExchangeId::GateioPerp
+            | ExchangeId::OkxSwap
+            | ExchangeId::Paradex => Some(FundingSchedule::every_hours(8)),
+        };
+
+        Self {
+            exchange,
+            funding,
+            funding_overrides: HashMap::new(),
+            maintenance: Vec::new(),
+        }
+    }
+
+    pub const fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+
+    /// Override the funding schedule for a single symbol
+    #[must_use]
+    pub fn with_funding_override(
+        mut self,
+        symbol: impl Into<String>,
+        schedule: FundingSchedule,
+    ) -> Self {
+        self.funding_overrides.insert(symbol.into(), schedule);
+        self
+    }
+
+    #[must_use]
+    pub fn with_maintenance(mut self, window: MaintenanceWindow) -> Self {
+        self.maintenance.push(window);
+        self
+    }
+
+    /// Funding schedule that applies to `symbol`, if the venue funds it
+    pub fn funding_schedule(&self, symbol: &str) -> Option<FundingSchedule> {
+        self.funding_overrides.get(symbol).copied().or(self.funding)
+    }
+
+    /// Next funding settlement for `symbol` strictly after `after`
+    pub fn next_funding_time_after(
+        &self,
+        symbol: &str,
+        after: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        self.funding_schedule(symbol)
+            .map(|schedule| schedule.next_after(after))
+    }
+
+    /// Whether `at` falls inside any known maintenance window
+    pub fn in_maintenance(&self, at: DateTime<Utc>) -> bool {
+        self.maintenance.iter().any(|window| window.contains(at))
+    }
+
+    /// Earliest known maintenance window starting after `after`
+    pub fn next_maintenance(&self, after: DateTime<Utc>) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        self.maintenance
+            .iter()
+            .filter_map(|window| window.next_after(after))
+            .min_by_key(|(start, _)| *start)
+    }
+}
+
+/// Next funding settlement for `symbol` on `exchange`, using the venue defaults
+pub fn next_funding_time(exchange: ExchangeId, symbol: &str) -> Option<DateTime<Utc>> {
+    TradingCalendar::for_exchange(exchange).next_funding_time_after(symbol, Utc::now())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        // 2024-01-01 was a Monday
+        Utc.with_ymd_and_hms(2024, 1, 1, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_funding_times_per_venue() {
+        assert_eq!(
+            TradingCalendar::for_exchange(ExchangeId::BinancePerp)
+                .next_funding_time_after("BTCUSDT", at(7, 59)),
+            Some(at(8, 0))
+        );
+        assert_eq!(
+            TradingCalendar::for_exchange(ExchangeId::OkxSwap)
+                .next_funding_time_after("BTC-USDT-SWAP", at(8, 0)),
+            Some(at(16, 0))
+        );
+        assert_eq!(
+            TradingCalendar::for_exchange(ExchangeId::Hyperliquid)
+                .next_funding_time_after("BTC", at(8, 30)),
+            Some(at(9, 0))
+        );
+        assert_eq!(
+            TradingCalendar::for_exchange(ExchangeId::Binance)
+                .next_funding_time_after("BTCUSDT", at(8, 30)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_symbol_override() {
+        let calendar = TradingCalendar::for_exchange(ExchangeId::BybitPerp)
+            .with_funding_override("ORDIUSDT", FundingSchedule::every_hours(4));
+        assert_eq!(
+            calendar.next_funding_time_after("ORDIUSDT", at(1, 0)),
+            Some(at(4, 0))
+        );
+        assert_eq!(
+            calendar.next_funding_time_after("BTCUSDT", at(1, 0)),
+            Some(at(8, 0))
+        );
+    }
+
+    #[test]
+    fn test_maintenance_window_crossing_midnight() {
+        let window = MaintenanceWindow::weekly(
+            Weekday::Sun,
+            NaiveTime::from_hms_opt(23, 0, 0).unwrap(),
+            Duration::hours(2),
+        );
+        let calendar = TradingCalendar::for_exchange(ExchangeId::Paradex).with_maintenance(window);
+
+        // Sunday 23:00 → Monday 01:00
+        assert!(calendar.in_maintenance(at(0, 30)));
+        assert!(!calendar.in_maintenance(at(1, 0)));
+
+        let (start, end) = calendar.next_maintenance(at(1, 0)).unwrap();
+        assert_eq!(start, at(23, 0) + Duration::days(6));
+        assert_eq!(end, start + Duration::hours(2));
+    }
+}
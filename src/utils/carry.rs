@@ -0,0 +1,260 @@
+use crate::core::types::{BorrowRate, FundingRate, Symbol};
+use crate::utils::paper::Liquidity;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Fee rates charged on traded notional
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeSchedule {
+    pub maker: Decimal,
+    pub taker: Decimal,
+}
+
+impl FeeSchedule {
+    pub const fn new(maker: Decimal, taker: Decimal) -> Self {
+        Self { maker, taker }
+    }
+
+    pub const fn rate(&self, liquidity: Liquidity) -> Decimal {
+        match liquidity {
+            Liquidity::Maker => self.maker,
+            Liquidity::Taker => self.taker,
+        }
+    }
+}
+
+/// Estimated cost of holding a position, in quote currency; negative means income
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CarryCost {
+    pub funding: Decimal,
+    pub borrow: Decimal,
+    /// Entering and exiting the position
+    pub fees: Decimal,
+    pub total: Decimal,
+}
+
+/// Holding costs for the instruments of one venue
+///
+/// Symbols with a funding rate are treated as perpetuals: longs pay positive funding and
+/// shorts receive it, prorated over the horizon without compounding. Other symbols are
+/// treated as margin positions that borrow the quote asset to go long and the base asset
+/// to go short, at the annual rates given with `with_borrow_rate`; assets without a rate
+/// are assumed to be owned. Fees cover one entry and one exit at the assumed liquidity.
+#[derive(Debug, Clone)]
+pub struct CarryCostModel {
+    venue: String,
+    fees: FeeSchedule,
+    liquidity: Liquidity,
+    funding: HashMap<Symbol, (Decimal, Duration)>,
+    borrow_rates: HashMap<String, Decimal>,
+}
+
+impl CarryCostModel {
+    pub fn new(venue: impl Into<String>) -> Self {
+        Self {
+            venue: venue.into(),
+            fees: FeeSchedule::default(),
+            liquidity: Liquidity::Taker,
+            funding: HashMap::new(),
+            borrow_rates: HashMap::new(),
+        }
+    }
+
+    pub fn venue(&self) -> &str {
+        &self.venue
+    }
+
+    #[must_use]
+    pub const fn with_fees(mut self, fees: FeeSchedule) -> Self {
+        self.fees = fees;
+        self
+    }
+
+    /// Liquidity assumed for the entry and exit fills; taker by default
+    #[must_use]
+    pub const fn with_liquidity(mut self, liquidity: Liquidity) -> Self {
+        self.liquidity = liquidity;
+        self
+    }
+
+    /// Use `rate` as the symbol's expected funding; ignored without a rate and interval
+    #[must_use]
+    pub fn with_funding(mut self, rate: &FundingRate) -> Self {
+        self.update_funding(rate);
+        self
+    }
+
+    /// Annual borrow rate for `asset`, e.g. `0.1` for 10% APR
+    #[must_use]
+    pub fn with_borrow_rate(mut self, asset: &str, apr: Decimal) -> Self {
+        self.borrow_rates.insert(asset.to_string(), apr);
+        self
+    }
+
+    /// Use a polled borrow rate for its asset; ignored without an interval
+    #[must_use]
+    pub fn with_borrow(mut self, rate: &BorrowRate) -> Self {
+        self.update_borrow_rate(rate);
+        self
+    }
+
+    /// Refresh funding from the latest poll
+    pub fn update_funding(&mut self, rate: &FundingRate) {
+        if let (Some(per_period), Some(interval)) = (rate.funding_rate, rate.funding_interval) {
+            if !interval.is_zero() {
+                self.funding
+                    .insert(rate.symbol.clone(), (per_period, interval));
+            }
+        }
+    }
+
+    /// Refresh an asset's borrow rate from the latest poll
+    pub fn update_borrow_rate(&mut self, rate: &BorrowRate) {
+        if let Some(apr) = rate.annualized() {
+            self.borrow_rates.insert(rate.asset.clone(), apr);
+        }
+    }
+
+    /// Cost of holding `size` quote notional of `symbol` (positive long, negative short)
+    /// for `horizon`
+    pub fn carry_cost(&self, symbol: &Symbol, size: Decimal, horizon: Duration) -> CarryCost {
+        let horizon_secs = Decimal::from(horizon.as_secs());
+
+        let (funding, borrow) = self.funding.get(symbol).map_or_else(
+            || {
+                let borrowed = if size > Decimal::ZERO {
+                    &symbol.quote
+                } else {
+                    &symbol.base
+                };
+                let apr = self.borrow_rates.get(borrowed).copied().unwrap_or_default();
+                let borrow =
+                    size.abs() * apr * horizon_secs / Decimal::from(FundingRate::SECONDS_PER_YEAR);
+                (Decimal::ZERO, borrow)
+            },
+            |(rate, interval)| {
+                let periods = horizon_secs / Decimal::from(interval.as_secs());
+                (size * rate * periods, Decimal::ZERO)
+            },
+        );
+        let fees = size.abs() * self.fees.rate(self.liquidity) * Decimal::TWO;
+
+        CarryCost {
+            funding,
+            borrow,
+            fees,
+            total: funding + borrow + fees,
+        }
+    }
+}
+
+/// The venue where holding `size` of `symbol` for `horizon` costs least
+pub fn cheapest_venue<'a>(
+    models: &'a [CarryCostModel],
+    symbol: &Symbol,
+    size: Decimal,
+    horizon: Duration,
+) -> Option<(&'a CarryCostModel, CarryCost)> {
+    models
+        .iter()
+        .map(|model| (model, model.carry_cost(symbol, size, horizon)))
+        .min_by_key(|(_, cost)| cost.total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(86_400);
+
+    fn funding(rate: Decimal, hours: u64) -> FundingRate {
+        FundingRate {
+            symbol: Symbol::new("BTC", "USDT").unwrap(),
+            funding_rate: Some(rate),
+            previous_funding_rate: None,
+            next_funding_rate: None,
+            funding_time: None,
+            next_funding_time: None,
+            mark_price: None,
+            index_price: None,
+            timestamp: 0,
+            funding_interval: Some(Duration::from_secs(hours * 3600)),
+        }
+    }
+
+    #[test]
+    fn test_perp_and_margin_costs() {
+        let btc = Symbol::new("BTC", "USDT").unwrap();
+        let notional = Decimal::from(10_000);
+
+        // 1 bp every 8h, taker 5 bps each way
+        let perp = CarryCostModel::new("binance_perp")
+            .with_fees(FeeSchedule::new(Decimal::new(2, 4), Decimal::new(5, 4)))
+            .with_funding(&funding(Decimal::new(1, 4), 8));
+        let long = perp.carry_cost(&btc, notional, DAY);
+        assert_eq!(long.funding, Decimal::from(3));
+        assert_eq!(long.fees, Decimal::from(10));
+        assert_eq!(long.total, Decimal::from(13));
+        // Shorts collect funding
+        assert_eq!(
+            perp.carry_cost(&btc, -notional, DAY).funding,
+            Decimal::from(-3)
+        );
+
+        // Shorting on margin borrows BTC at 36.5% APR
+        let margin = CarryCostModel::new("binance")
+            .with_borrow_rate("BTC", Decimal::new(365, 3))
+            .with_liquidity(Liquidity::Maker);
+        let short = margin.carry_cost(&btc, -notional, DAY);
+        assert_eq!(short.borrow, Decimal::from(10));
+        assert_eq!(short.funding, Decimal::ZERO);
+        // USDT is owned, so longs carry nothing
+        assert_eq!(margin.carry_cost(&btc, notional, DAY), CarryCost::default());
+    }
+
+    #[test]
+    fn test_cheapest_venue_normalizes_funding_intervals() {
+        let btc = Symbol::new("BTC", "USDT").unwrap();
+        let models = [
+            // 0.5 bp hourly is 12 bps a day
+            CarryCostModel::new("hyperliquid").with_funding(&funding(Decimal::new(5, 5), 1)),
+            // 1 bp every 8h is 3 bps a day
+            CarryCostModel::new("bybit_perp").with_funding(&funding(Decimal::new(1, 4), 8)),
+        ];
+        let (model, cost) = cheapest_venue(&models, &btc, Decimal::from(10_000), DAY).unwrap();
+        assert_eq!(model.venue(), "bybit_perp");
+        assert_eq!(cost.total, Decimal::from(3));
+
+        // Funding is income for shorts, so the hourly venue pays more
+        let (model, _) = cheapest_venue(&models, &btc, Decimal::from(-10_000), DAY).unwrap();
+        assert_eq!(model.venue(), "hyperliquid");
+    }
+
+    #[test]
+    fn test_polled_borrow_rate_compares_margin_with_perp() {
+        let btc = Symbol::new("BTC", "USDT").unwrap();
+        // 0.5 bp hourly to borrow BTC is 12 bps a day
+        let borrow = BorrowRate {
+            asset: "BTC".to_string(),
+            rate: Decimal::new(5, 5),
+            interval: Duration::from_secs(3600),
+            timestamp: 0,
+        };
+        assert_eq!(borrow.annualized(), Some(Decimal::new(438, 3)));
+
+        let models = [
+            CarryCostModel::new("binance").with_borrow(&borrow),
+            CarryCostModel::new("binance_perp").with_funding(&funding(Decimal::new(1, 4), 8)),
+        ];
+        let short = -Decimal::from(10_000);
+        assert_eq!(
+            models[0].carry_cost(&btc, short, DAY).borrow,
+            Decimal::from(12)
+        );
+        // Shorting the perp collects funding instead of paying interest
+        let (model, cost) = cheapest_venue(&models, &btc, short, DAY).unwrap();
+        assert_eq!(model.venue(), "binance_perp");
+        assert_eq!(cost.total, Decimal::from(-3));
+    }
+}
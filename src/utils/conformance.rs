@@ -0,0 +1,409 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::MarketDataSource;
+use crate::core::types::{
+    Kline, KlineInterval, Market, MarketDataType, OrderBook, OrderBookEntry, OrderBookMode, Price,
+    Quantity, SubscriptionType,
+};
+use rust_decimal::Decimal;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Result of a single conformance check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckOutcome {
+    Passed,
+    Failed(String),
+    /// The connector does not offer the capability under test
+    Skipped(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct ConformanceCheck {
+    pub name: &'static str,
+    pub outcome: CheckOutcome,
+}
+
+/// Outcome of running the suite against one connector
+#[derive(Debug, Clone)]
+pub struct ConformanceReport {
+    pub exchange: String,
+    pub checks: Vec<ConformanceCheck>,
+}
+
+impl ConformanceReport {
+    pub fn is_success(&self) -> bool {
+        !self
+            .checks
+            .iter()
+            .any(|check| matches!(check.outcome, CheckOutcome::Failed(_)))
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &ConformanceCheck> {
+        self.checks
+            .iter()
+            .filter(|check| matches!(check.outcome, CheckOutcome::Failed(_)))
+    }
+
+    fn record(&mut self, name: &'static str, result: Result<(), String>) {
+        let outcome = match result {
+            Ok(()) => CheckOutcome::Passed,
+            Err(reason) => CheckOutcome::Failed(reason),
+        };
+        self.checks.push(ConformanceCheck { name, outcome });
+    }
+
+    fn record_error(&mut self, name: &'static str, error: &ExchangeError) {
+        let outcome = match error {
+            ExchangeError::NotSupported(reason) => CheckOutcome::Skipped(reason.clone()),
+            other => CheckOutcome::Failed(other.to_string()),
+        };
+        self.checks.push(ConformanceCheck { name, outcome });
+    }
+}
+
+impl fmt::Display for ConformanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Conformance report for {}", self.exchange)?;
+        for check in &self.checks {
+            match &check.outcome {
+                CheckOutcome::Passed => writeln!(f, "  ✅ {}", check.name)?,
+                CheckOutcome::Failed(reason) => writeln!(f, "  ❌ {}: {}", check.name, reason)?,
+                CheckOutcome::Skipped(reason) => writeln!(f, "  ⏭️  {}: {}", check.name, reason)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Generic market data test battery for any connector
+///
+/// Exercises `get_markets`, `get_klines` and the order book stream, then validates the
+/// results with the `check_*` functions below. The symbol must be in the venue's native
+/// format (e.g. `BTCUSDT`, `BTC-USDT`, `BTC`).
+#[derive(Debug, Clone)]
+pub struct ConformanceSuite {
+    exchange: String,
+    symbol: String,
+    interval: KlineInterval,
+    kline_limit: u32,
+    order_book_timeout: Duration,
+}
+
+impl ConformanceSuite {
+    pub fn new(exchange: impl Into<String>, symbol: impl Into<String>) -> Self {
+        Self {
+            exchange: exchange.into(),
+            symbol: symbol.into(),
+            interval: KlineInterval::Minutes1,
+            kline_limit: 50,
+            order_book_timeout: Duration::from_secs(15),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_klines(mut self, interval: KlineInterval, limit: u32) -> Self {
+        self.interval = interval;
+        self.kline_limit = limit;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_order_book_timeout(mut self, timeout: Duration) -> Self {
+        self.order_book_timeout = timeout;
+        self
+    }
+
+    pub async fn run<C>(&self, connector: &C) -> ConformanceReport
+    where
+        C: MarketDataSource + Sync + ?Sized,
+    {
+        let mut report = ConformanceReport {
+            exchange: self.exchange.clone(),
+            checks: Vec::new(),
+        };
+
+        match connector.get_markets().await {
+            Ok(markets) => {
+                report.record("markets", check_markets(&markets));
+                report.record("decimal_round_trip", check_decimal_round_trip(&markets));
+            }
+            Err(e) => report.record_error("markets", &e),
+        }
+
+        match connector
+            .get_klines(
+                self.symbol.as_str().into(),
+                self.interval,
+                Some(self.kline_limit),
+                None,
+                None,
+            )
+            .await
+        {
+            Ok(klines) => report.record("klines", check_klines(&klines)),
+            Err(e) => report.record_error("klines", &e),
+        }
+
+        match self.first_order_book(connector).await {
+            Ok(book) => report.record("order_book", check_order_book(&book)),
+            Err(e) => report.record_error("order_book", &e),
+        }
+
+        report
+    }
+
+    async fn first_order_book<C>(&self, connector: &C) -> Result<OrderBook, ExchangeError>
+    where
+        C: MarketDataSource + Sync + ?Sized,
+    {
+        let mut rx = connector
+            .subscribe_market_data(
+                vec![self.symbol.as_str().into()],
+                vec![SubscriptionType::OrderBook {
+                    depth: Some(10),
+                    mode: OrderBookMode::Snapshot,
+                }],
+                None,
+            )
+            .await?;
+
+        let wait = async {
+            while let Some(data) = rx.recv().await {
+                // Some venues open with an empty delta before the first snapshot
+                if let MarketDataType::OrderBook(book) = data {
+                    if !book.bids.is_empty() || !book.asks.is_empty() {
+                        return Ok(book);
+                    }
+                }
+            }
+            Err(ExchangeError::WebSocketClosed(
+                "Stream closed before an order book arrived".to_string(),
+            ))
+        };
+
+        tokio::time::timeout(self.order_book_timeout, wait)
+            .await
+            .map_err(|_| {
+                ExchangeError::Other(format!(
+                    "No order book for {} within {:?}",
+                    self.symbol, self.order_book_timeout
+                ))
+            })?
+    }
+}
+
+/// Markets are non-empty and carry sane precisions and limits
+pub fn check_markets(markets: &[Market]) -> Result<(), String> {
+    if markets.is_empty() {
+        return Err("get_markets returned no markets".to_string());
+    }
+
+    for market in markets {
+        let name = market.symbol.to_string();
+        for (field, precision) in [
+            ("base_precision", market.base_precision),
+            ("quote_precision", market.quote_precision),
+        ] {
+            // rust_decimal supports at most 28 fractional digits
+            if !(0..=28).contains(&precision) {
+                return Err(format!("{name}: {field} {precision} out of range"));
+            }
+        }
+        // Several venues report a zero maximum to mean "no limit"
+        if let (Some(min), Some(max)) = (market.min_qty, market.max_qty) {
+            if !max.value().is_zero() && min > max {
+                return Err(format!("{name}: min_qty {min} exceeds max_qty {max}"));
+            }
+        }
+        if let (Some(min), Some(max)) = (market.min_price, market.max_price) {
+            if !max.value().is_zero() && min > max {
+                return Err(format!("{name}: min_price {min} exceeds max_price {max}"));
+            }
+        }
+        if market.min_qty.is_some_and(|q| q.value().is_sign_negative()) {
+            return Err(format!("{name}: negative min_qty"));
+        }
+    }
+    Ok(())
+}
+
+/// Each kline is internally consistent and the series is in time order
+pub fn check_klines(klines: &[Kline]) -> Result<(), String> {
+    if klines.is_empty() {
+        return Err("get_klines returned no klines".to_string());
+    }
+
+    for kline in klines {
+        let at = kline.open_time;
+        if kline.high_price < kline.low_price {
+            return Err(format!("kline {at}: high below low"));
+        }
+        for (field, price) in [("open", kline.open_price), ("close", kline.close_price)] {
+            if price > kline.high_price || price < kline.low_price {
+                return Err(format!(
+                    "kline {at}: {field} {price} outside high/low range"
+                ));
+            }
+        }
+        if kline.volume.value().is_sign_negative() {
+            return Err(format!("kline {at}: negative volume"));
+        }
+        if kline.close_time != 0 && kline.close_time < kline.open_time {
+            return Err(format!("kline {at}: closes before it opens"));
+        }
+    }
+
+    // Venues return klines oldest- or newest-first; either is fine as long as it is monotonic
+    let ascending = klines.windows(2).all(|w| w[0].open_time < w[1].open_time);
+    let descending = klines.windows(2).all(|w| w[0].open_time > w[1].open_time);
+    if !ascending && !descending {
+        return Err("klines are not ordered by open time".to_string());
+    }
+    Ok(())
+}
+
+/// Bids descend, asks ascend, sizes are positive and the book is not crossed
+pub fn check_order_book(book: &OrderBook) -> Result<(), String> {
+    check_levels("bids", &book.bids, |a, b| a > b)?;
+    check_levels("asks", &book.asks, |a, b| a < b)?;
+
+    if let (Some(bid), Some(ask)) = (book.bids.first(), book.asks.first()) {
+        if bid.price >= ask.price {
+            return Err(format!(
+                "book crossed: best bid {} >= best ask {}",
+                bid.price, ask.price
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn check_levels(
+    side: &str,
+    levels: &[OrderBookEntry],
+    ordered: impl Fn(Price, Price) -> bool,
+) -> Result<(), String> {
+    if let Some(level) = levels.iter().find(|l| l.quantity.value() <= Decimal::ZERO) {
+        return Err(format!("{side}: non-positive size at {}", level.price));
+    }
+    if !levels.windows(2).all(|w| ordered(w[0].price, w[1].price)) {
+        return Err(format!("{side} are not strictly sorted"));
+    }
+    Ok(())
+}
+
+/// Prices and quantities reported by the venue survive a string round trip unchanged
+///
+/// Connectors send these values back to the venue as strings when placing orders, so
+/// any loss here would turn into rejected or mispriced orders.
+pub fn check_decimal_round_trip(markets: &[Market]) -> Result<(), String> {
+    for market in markets {
+        let prices = [market.min_price, market.max_price].into_iter().flatten();
+        for price in prices {
+            let parsed = Price::from_str(&price.to_string()).map_err(|e| e.to_string())?;
+            if parsed != price || parsed.to_string() != price.to_string() {
+                return Err(format!(
+                    "{}: price {price} does not round-trip",
+                    market.symbol
+                ));
+            }
+        }
+        let quantities = [market.min_qty, market.max_qty].into_iter().flatten();
+        for quantity in quantities {
+            let parsed = Quantity::from_str(&quantity.to_string()).map_err(|e| e.to_string())?;
+            if parsed != quantity || parsed.to_string() != quantity.to_string() {
+                return Err(format!(
+                    "{}: quantity {quantity} does not round-trip",
+                    market.symbol
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{conversion, ContractStyle, MarketStatus};
+
+    fn market(min_qty: &str, max_qty: &str) -> Market {
+        Market {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            status: MarketStatus::Trading,
+            base_precision: 8,
+            quote_precision: 2,
+            min_qty: Some(conversion::string_to_quantity(min_qty)),
+            max_qty: Some(conversion::string_to_quantity(max_qty)),
+            min_price: Some(conversion::string_to_price("0.01")),
+            max_price: None,
+            contract_style: ContractStyle::Spot,
+            contract_size: None,
+            extensions: None,
+        }
+    }
+
+    fn kline(open_time: i64, open: &str, high: &str, low: &str, close: &str) -> Kline {
+        Kline {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            open_time,
+            close_time: open_time + 59_999,
+            interval: "1m".to_string(),
+            open_price: conversion::string_to_price(open),
+            high_price: conversion::string_to_price(high),
+            low_price: conversion::string_to_price(low),
+            close_price: conversion::string_to_price(close),
+            volume: conversion::string_to_volume("1"),
+            number_of_trades: 1,
+            final_bar: true,
+        }
+    }
+
+    fn level(price: &str, quantity: &str) -> OrderBookEntry {
+        OrderBookEntry {
+            price: conversion::string_to_price(price),
+            quantity: conversion::string_to_quantity(quantity),
+        }
+    }
+
+    #[test]
+    fn test_market_checks() {
+        assert!(check_markets(&[]).is_err());
+        assert!(check_markets(&[market("0.001", "100")]).is_ok());
+        assert!(check_markets(&[market("100", "0.001")]).is_err());
+        assert!(check_decimal_round_trip(&[market("0.00100", "100")]).is_ok());
+    }
+
+    #[test]
+    fn test_kline_checks() {
+        let good = [
+            kline(0, "100", "105", "99", "104"),
+            kline(60_000, "104", "104", "101", "102"),
+        ];
+        assert!(check_klines(&good).is_ok());
+        assert!(check_klines(&[kline(0, "100", "99", "101", "100")]).is_err());
+        assert!(check_klines(&[kline(0, "106", "105", "99", "104")]).is_err());
+
+        let unordered = [good[1].clone(), good[0].clone(), good[1].clone()];
+        assert!(check_klines(&unordered).is_err());
+    }
+
+    #[test]
+    fn test_order_book_checks() {
+        let mut book = OrderBook {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            bids: vec![level("100", "1"), level("99", "2")],
+            asks: vec![level("101", "1"), level("102", "2")],
+            last_update_id: 0,
+        };
+        assert!(check_order_book(&book).is_ok());
+
+        book.asks.insert(0, level("99.5", "1"));
+        assert!(check_order_book(&book).unwrap_err().contains("crossed"));
+
+        book.asks.remove(0);
+        book.bids.reverse();
+        assert!(check_order_book(&book).is_err());
+    }
+}
@@ -0,0 +1,389 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::AccountInfo;
+use crate::core::types::{OrderSide, PositionSide, Symbol};
+use crate::utils::paper::Fill;
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Balances (free plus locked) and signed positions read from a venue
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountSnapshot {
+    pub balances: BTreeMap<String, Decimal>,
+    pub positions: HashMap<Symbol, Decimal>,
+}
+
+impl AccountSnapshot {
+    pub async fn capture<A: AccountInfo + Sync + ?Sized>(
+        account: &A,
+    ) -> Result<Self, ExchangeError> {
+        let mut snapshot = Self::default();
+        for balance in account.get_account_balance().await? {
+            *snapshot.balances.entry(balance.asset).or_default() +=
+                balance.free.value() + balance.locked.value();
+        }
+        for position in account.get_positions().await? {
+            let amount = position.position_amount.value();
+            let signed = match position.position_side {
+                PositionSide::Long => amount.abs(),
+                PositionSide::Short => -amount.abs(),
+                PositionSide::Both => amount,
+            };
+            *snapshot.positions.entry(position.symbol).or_default() += signed;
+        }
+        Ok(snapshot)
+    }
+}
+
+/// What an account should hold, given a starting snapshot and everything tracked since
+///
+/// Spot fills move the base and quote balances, with the fee taken from the quote.
+/// Perp fills only move the position: funding, fees and realized profit settle into margin
+/// and are not modelled, so compare perp accounts by position.
+#[derive(Debug, Clone, Default)]
+pub struct AccountLedger {
+    expected: AccountSnapshot,
+}
+
+impl AccountLedger {
+    pub fn new(start: AccountSnapshot) -> Self {
+        Self { expected: start }
+    }
+
+    pub const fn expected(&self) -> &AccountSnapshot {
+        &self.expected
+    }
+
+    pub fn on_spot_fill(&mut self, fill: &Fill) {
+        let quantity = fill.quantity.value();
+        let notional = quantity * fill.price.value();
+        let (base, quote) = match fill.side {
+            OrderSide::Buy => (quantity, -notional - fill.fee),
+            OrderSide::Sell => (-quantity, notional - fill.fee),
+        };
+        let balances = &mut self.expected.balances;
+        *balances.entry(fill.symbol.base.clone()).or_default() += base;
+        *balances.entry(fill.symbol.quote.clone()).or_default() += quote;
+    }
+
+    pub fn on_perp_fill(&mut self, fill: &Fill) {
+        let quantity = match fill.side {
+            OrderSide::Buy => fill.quantity.value(),
+            OrderSide::Sell => -fill.quantity.value(),
+        };
+        *self
+            .expected
+            .positions
+            .entry(fill.symbol.clone())
+            .or_default() += quantity;
+    }
+
+    /// Deposits are positive, withdrawals negative
+    pub fn on_transfer(&mut self, asset: &str, amount: Decimal) {
+        *self.expected.balances.entry(asset.to_string()).or_default() += amount;
+    }
+
+    /// Everything whose actual amount differs from the expected one by more than its
+    /// tolerance
+    pub fn diff(&self, actual: &AccountSnapshot, config: &DriftConfig) -> Vec<Drift> {
+        let mut drifts = Vec::new();
+
+        let assets: BTreeSet<_> = self
+            .expected
+            .balances
+            .keys()
+            .chain(actual.balances.keys())
+            .collect();
+        for asset in assets {
+            let expected = self
+                .expected
+                .balances
+                .get(asset)
+                .copied()
+                .unwrap_or_default();
+            let actual = actual.balances.get(asset).copied().unwrap_or_default();
+            if (actual - expected).abs() > config.tolerance_for(asset) {
+                drifts.push(Drift {
+                    item: DriftItem::Balance(asset.clone()),
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        let mut symbols: Vec<_> = self
+            .expected
+            .positions
+            .keys()
+            .chain(actual.positions.keys())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        symbols.sort_by_key(|symbol| symbol.to_string());
+        for symbol in symbols {
+            let expected = self
+                .expected
+                .positions
+                .get(symbol)
+                .copied()
+                .unwrap_or_default();
+            let actual = actual.positions.get(symbol).copied().unwrap_or_default();
+            if (actual - expected).abs() > config.tolerance_for(&symbol.base) {
+                drifts.push(Drift {
+                    item: DriftItem::Position(symbol.clone()),
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        drifts
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DriftItem {
+    Balance(String),
+    Position(Symbol),
+}
+
+/// One balance or position that does not match the ledger
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Drift {
+    pub item: DriftItem,
+    pub expected: Decimal,
+    pub actual: Decimal,
+}
+
+/// Drift found by one run of the monitor
+#[derive(Debug, Clone)]
+pub struct DriftReport {
+    pub exchange: String,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: i64,
+    pub drifts: Vec<Drift>,
+}
+
+/// Settings for `spawn_drift_monitor`
+#[derive(Debug, Clone)]
+pub struct DriftConfig {
+    pub interval: Duration,
+    /// Largest absolute difference ignored, in units of the asset
+    pub tolerance: Decimal,
+    /// Per-asset tolerances replacing `tolerance`; positions use their base asset's
+    pub asset_tolerances: HashMap<String, Decimal>,
+}
+
+impl Default for DriftConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(60),
+            tolerance: Decimal::new(1, 8),
+            asset_tolerances: HashMap::new(),
+        }
+    }
+}
+
+impl DriftConfig {
+    #[must_use]
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_tolerance(mut self, tolerance: Decimal) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_asset_tolerance(mut self, asset: &str, tolerance: Decimal) -> Self {
+        self.asset_tolerances.insert(asset.to_string(), tolerance);
+        self
+    }
+
+    pub fn tolerance_for(&self, asset: &str) -> Decimal {
+        self.asset_tolerances
+            .get(asset)
+            .copied()
+            .unwrap_or(self.tolerance)
+    }
+}
+
+/// Snapshot `account` every interval and report drift from `ledger`
+///
+/// Only runs that find drift are reported, and each drift is also logged. Failed
+/// snapshots are logged and retried on the next tick. The task exits once the returned
+/// receiver is dropped.
+pub fn spawn_drift_monitor<A>(
+    exchange: impl Into<String>,
+    account: A,
+    ledger: Arc<Mutex<AccountLedger>>,
+    config: DriftConfig,
+) -> mpsc::Receiver<DriftReport>
+where
+    A: AccountInfo + Send + Sync + 'static,
+{
+    let exchange = exchange.into();
+    let (tx, rx) = mpsc::channel(16);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(config.interval);
+        loop {
+            ticker.tick().await;
+            if tx.is_closed() {
+                break;
+            }
+            let snapshot = match AccountSnapshot::capture(&account).await {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    warn!(%exchange, error = %e, "Failed to snapshot account for drift check");
+                    continue;
+                }
+            };
+            let drifts = ledger
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .diff(&snapshot, &config);
+            if drifts.is_empty() {
+                continue;
+            }
+            for drift in &drifts {
+                warn!(
+                    %exchange,
+                    item = ?drift.item,
+                    expected = %drift.expected,
+                    actual = %drift.actual,
+                    "Account drift detected"
+                );
+            }
+            let report = DriftReport {
+                exchange: exchange.clone(),
+                timestamp: chrono::Utc::now().timestamp_millis(),
+                drifts,
+            };
+            if tx.send(report).await.is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{conversion, Balance, Position, Price, Quantity};
+    use crate::utils::paper::Liquidity;
+    use async_trait::async_trait;
+
+    fn fill(side: OrderSide, quantity: i64, price: i64, fee: Decimal) -> Fill {
+        Fill {
+            order_id: "1".to_string(),
+            symbol: Symbol::new("BTC", "USDT").unwrap(),
+            side,
+            price: Price::new(Decimal::from(price)),
+            quantity: Quantity::new(Decimal::from(quantity)),
+            fee,
+            liquidity: Liquidity::Taker,
+            time: 0,
+        }
+    }
+
+    fn snapshot(balances: &[(&str, i64)]) -> AccountSnapshot {
+        AccountSnapshot {
+            balances: balances
+                .iter()
+                .map(|(asset, amount)| ((*asset).to_string(), Decimal::from(*amount)))
+                .collect(),
+            positions: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_ledger_tracks_fills_fees_and_transfers() {
+        let mut ledger = AccountLedger::new(snapshot(&[("USDT", 10_000)]));
+        ledger.on_spot_fill(&fill(OrderSide::Buy, 2, 100, Decimal::ONE));
+        ledger.on_transfer("USDT", Decimal::from(-500));
+        ledger.on_perp_fill(&fill(OrderSide::Sell, 3, 100, Decimal::ONE));
+
+        let config = DriftConfig::default().with_asset_tolerance("USDT", Decimal::new(5, 1));
+        let mut actual = snapshot(&[("BTC", 2)]);
+        actual
+            .balances
+            .insert("USDT".to_string(), Decimal::new(92_993, 1));
+        actual
+            .positions
+            .insert(Symbol::new("BTC", "USDT").unwrap(), Decimal::from(-3));
+        assert!(ledger.diff(&actual, &config).is_empty());
+
+        // An unexpected fee and a fill the ledger never saw
+        actual
+            .balances
+            .insert("USDT".to_string(), Decimal::from(9_290));
+        actual
+            .positions
+            .insert(Symbol::new("BTC", "USDT").unwrap(), Decimal::from(-4));
+        let drifts = ledger.diff(&actual, &config);
+        assert_eq!(
+            drifts,
+            [
+                Drift {
+                    item: DriftItem::Balance("USDT".to_string()),
+                    expected: Decimal::from(9_299),
+                    actual: Decimal::from(9_290),
+                },
+                Drift {
+                    item: DriftItem::Position(Symbol::new("BTC", "USDT").unwrap()),
+                    expected: Decimal::from(-3),
+                    actual: Decimal::from(-4),
+                },
+            ]
+        );
+    }
+
+    struct FixedAccount;
+
+    #[async_trait]
+    impl AccountInfo for FixedAccount {
+        async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
+            Ok(vec![Balance {
+                asset: "USDT".to_string(),
+                free: conversion::string_to_quantity("900"),
+                locked: conversion::string_to_quantity("50"),
+            }])
+        }
+
+        async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_monitor_reports_drift_beyond_tolerance() {
+        let ledger = Arc::new(Mutex::new(AccountLedger::new(snapshot(&[("USDT", 1_000)]))));
+        let mut reports = spawn_drift_monitor(
+            "binance",
+            FixedAccount,
+            ledger.clone(),
+            DriftConfig::default().with_interval(Duration::from_millis(10)),
+        );
+
+        let report = reports.recv().await.unwrap();
+        assert_eq!(report.exchange, "binance");
+        assert_eq!(report.drifts[0].actual, Decimal::from(950));
+
+        // Withdrawal recorded: once in-flight reports drain, nothing more arrives
+        ledger
+            .lock()
+            .unwrap()
+            .on_transfer("USDT", Decimal::from(-50));
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        while reports.try_recv().is_ok() {}
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(reports.try_recv().is_err());
+    }
+}
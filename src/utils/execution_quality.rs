@@ -0,0 +1,259 @@
+use crate::core::types::{OrderBook, OrderSide};
+use crate::utils::paper::{Fill, Liquidity};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Touch prices when an order was sent
+#[derive(Debug, Clone)]
+struct Arrival {
+    strategy: String,
+    exchange: String,
+    side: OrderSide,
+    mid: Decimal,
+    half_spread: Decimal,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Totals {
+    fills: u64,
+    quantity: Decimal,
+    maker_quantity: Decimal,
+    notional: Decimal,
+    slippage_bps_qty: Decimal,
+    capture_qty: Decimal,
+    capture_weight: Decimal,
+}
+
+/// Execution quality of one strategy on one venue over a reporting period
+///
+/// Averages are weighted by filled quantity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionReport {
+    pub strategy: String,
+    pub exchange: String,
+    pub fills: u64,
+    pub filled_quantity: Decimal,
+    pub notional: Decimal,
+    /// Share of filled quantity that rested on the book
+    pub maker_ratio: Decimal,
+    /// Fill price versus arrival mid in basis points; positive is a cost
+    pub slippage_bps: Decimal,
+    /// Half-spreads earned versus arrival mid: 1 buys at the bid, -1 pays the ask
+    ///
+    /// `None` when every order arrived on a locked or crossed book.
+    pub spread_capture: Option<Decimal>,
+}
+
+/// Joins fills with the book seen at order time to grade execution
+///
+/// Call `record_order` as each order is sent, then `record_fill` for its fills. Orders
+/// are remembered until `forget_order`, so late fills on resting orders are still
+/// matched; `take_report` resets the totals but not the orders.
+#[derive(Debug, Default)]
+pub struct ExecutionAnalyzer {
+    arrivals: HashMap<String, Arrival>,
+    totals: BTreeMap<(String, String), Totals>,
+}
+
+impl ExecutionAnalyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember the arrival book for `order_id`, returning false if it lacks a bid or ask
+    pub fn record_order(
+        &mut self,
+        strategy: &str,
+        exchange: &str,
+        order_id: &str,
+        side: OrderSide,
+        book: &OrderBook,
+    ) -> bool {
+        let (Some(bid), Some(ask)) = (book.bids.first(), book.asks.first()) else {
+            return false;
+        };
+        let (bid, ask) = (bid.price.value(), ask.price.value());
+        self.arrivals.insert(
+            order_id.to_string(),
+            Arrival {
+                strategy: strategy.to_string(),
+                exchange: exchange.to_string(),
+                side,
+                mid: (bid + ask) / Decimal::TWO,
+                half_spread: (ask - bid) / Decimal::TWO,
+            },
+        );
+        true
+    }
+
+    pub fn forget_order(&mut self, order_id: &str) {
+        self.arrivals.remove(order_id);
+    }
+
+    /// Grade a fill against its order's arrival book, returning false for unknown orders
+    pub fn record_fill(&mut self, fill: &Fill) -> bool {
+        let Some(arrival) = self.arrivals.get(&fill.order_id) else {
+            return false;
+        };
+        let (price, quantity) = (fill.price.value(), fill.quantity.value());
+        if arrival.mid.is_zero() || quantity.is_zero() {
+            return true;
+        }
+        // Positive when the fill was worse than mid for the order's side
+        let cost = match arrival.side {
+            OrderSide::Buy => price - arrival.mid,
+            OrderSide::Sell => arrival.mid - price,
+        };
+
+        let totals = self
+            .totals
+            .entry((arrival.strategy.clone(), arrival.exchange.clone()))
+            .or_default();
+        totals.fills += 1;
+        totals.quantity += quantity;
+        totals.notional += price * quantity;
+        if fill.liquidity == Liquidity::Maker {
+            totals.maker_quantity += quantity;
+        }
+        totals.slippage_bps_qty += cost / arrival.mid * Decimal::from(10_000) * quantity;
+        if arrival.half_spread > Decimal::ZERO {
+            totals.capture_qty += -cost / arrival.half_spread * quantity;
+            totals.capture_weight += quantity;
+        }
+        true
+    }
+
+    /// Figures since the last `take_report`
+    pub fn report(&self) -> Vec<ExecutionReport> {
+        self.totals
+            .iter()
+            .map(|((strategy, exchange), totals)| ExecutionReport {
+                strategy: strategy.clone(),
+                exchange: exchange.clone(),
+                fills: totals.fills,
+                filled_quantity: totals.quantity,
+                notional: totals.notional,
+                maker_ratio: totals.maker_quantity / totals.quantity,
+                slippage_bps: totals.slippage_bps_qty / totals.quantity,
+                spread_capture: (!totals.capture_weight.is_zero())
+                    .then(|| totals.capture_qty / totals.capture_weight),
+            })
+            .collect()
+    }
+
+    /// Report and start a new period
+    pub fn take_report(&mut self) -> Vec<ExecutionReport> {
+        let report = self.report();
+        self.totals.clear();
+        report
+    }
+}
+
+/// Publish `take_report` every `interval`, skipping periods without fills
+///
+/// The task exits once the returned receiver is dropped.
+pub fn spawn_execution_reporter(
+    analyzer: Arc<Mutex<ExecutionAnalyzer>>,
+    interval: Duration,
+) -> mpsc::Receiver<Vec<ExecutionReport>> {
+    let (tx, rx) = mpsc::channel(16);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        loop {
+            ticker.tick().await;
+            let report = match analyzer.lock() {
+                Ok(mut analyzer) => analyzer.take_report(),
+                Err(_) => break,
+            };
+            if !report.is_empty() && tx.send(report).await.is_err() {
+                break;
+            }
+            if tx.is_closed() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{book, btc_usdt, fill};
+
+    #[test]
+    fn test_grades_fills_against_arrival_book() {
+        let mut analyzer = ExecutionAnalyzer::new();
+        assert!(analyzer.record_order(
+            "mm",
+            "binance",
+            "1",
+            OrderSide::Buy,
+            &book(&btc_usdt(), &[("99", "10")], &[("101", "10")])
+        ));
+        assert!(analyzer.record_order(
+            "mm",
+            "binance",
+            "2",
+            OrderSide::Sell,
+            &book(&btc_usdt(), &[("99", "10")], &[("101", "10")])
+        ));
+        assert!(analyzer.record_order(
+            "twap",
+            "okx",
+            "3",
+            OrderSide::Buy,
+            &book(&btc_usdt(), &[("100", "10")], &[("100", "10")])
+        ));
+
+        // Bought at the bid: earned the half-spread
+        analyzer.record_fill(&fill("1", OrderSide::Buy, "99", "1", Liquidity::Maker));
+        // Sold through the bid: paid one and a half half-spreads
+        analyzer.record_fill(&fill("2", OrderSide::Sell, "98.5", "1", Liquidity::Taker));
+        analyzer.record_fill(&fill("3", OrderSide::Buy, "101", "1", Liquidity::Taker));
+        assert!(!analyzer.record_fill(&fill("9", OrderSide::Buy, "1", "1", Liquidity::Taker)));
+
+        let report = analyzer.take_report();
+        assert_eq!(report.len(), 2);
+        let mm = &report[0];
+        assert_eq!(
+            (mm.strategy.as_str(), mm.exchange.as_str()),
+            ("mm", "binance")
+        );
+        assert_eq!(mm.fills, 2);
+        assert_eq!(mm.maker_ratio, Decimal::new(5, 1));
+        assert_eq!(mm.slippage_bps, Decimal::from(25));
+        assert_eq!(mm.spread_capture, Some(Decimal::new(-25, 2)));
+
+        // Locked arrival book: slippage only
+        assert_eq!(report[1].slippage_bps, Decimal::from(100));
+        assert_eq!(report[1].spread_capture, None);
+
+        assert!(analyzer.take_report().is_empty());
+        assert!(analyzer.record_fill(&fill("1", OrderSide::Buy, "99", "1", Liquidity::Maker)));
+    }
+
+    #[tokio::test]
+    async fn test_reporter_publishes_each_period() {
+        let analyzer = Arc::new(Mutex::new(ExecutionAnalyzer::new()));
+        {
+            let mut analyzer = analyzer.lock().unwrap();
+            analyzer.record_order(
+                "mm",
+                "bybit",
+                "1",
+                OrderSide::Buy,
+                &book(&btc_usdt(), &[("99", "10")], &[("101", "10")]),
+            );
+            analyzer.record_fill(&fill("1", OrderSide::Buy, "99", "1", Liquidity::Maker));
+        }
+
+        let mut reports = spawn_execution_reporter(analyzer.clone(), Duration::from_millis(20));
+        let report = reports.recv().await.unwrap();
+        assert_eq!(report[0].maker_ratio, Decimal::ONE);
+        assert!(analyzer.lock().unwrap().report().is_empty());
+    }
+}
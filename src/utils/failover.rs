@@ -0,0 +1,420 @@
+use crate::core::traits::MarketDataSource;
+use crate::core::types::{
+    Kline, KlineInterval, MarketDataType, Price, SubscriptionType, Symbol, Ticker, Volume,
+};
+use crate::utils::symbol_filter::SymbolFilter;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+/// Timings for `subscribe_with_failover`
+#[derive(Debug, Clone)]
+pub struct FailoverConfig {
+    /// Silence on the WebSocket, or time without a working reconnect, before REST takes over
+    pub stale_after: Duration,
+    /// REST polling period while degraded
+    pub poll_interval: Duration,
+    /// How often to try the WebSocket again while degraded
+    pub reconnect_interval: Duration,
+    /// Symbols it rejects are neither subscribed nor polled
+    pub symbol_filter: SymbolFilter,
+}
+
+impl Default for FailoverConfig {
+    fn default() -> Self {
+        Self {
+            stale_after: Duration::from_secs(10),
+            poll_interval: Duration::from_secs(2),
+            reconnect_interval: Duration::from_secs(15),
+            symbol_filter: SymbolFilter::default(),
+        }
+    }
+}
+
+impl FailoverConfig {
+    #[must_use]
+    pub const fn with_stale_after(mut self, stale_after: Duration) -> Self {
+        self.stale_after = stale_after;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_reconnect_interval(mut self, reconnect_interval: Duration) -> Self {
+        self.reconnect_interval = reconnect_interval;
+        self
+    }
+
+    #[must_use]
+    pub fn with_symbol_filter(mut self, symbol_filter: SymbolFilter) -> Self {
+        self.symbol_filter = symbol_filter;
+        self
+    }
+}
+
+/// Item on a failover feed
+#[derive(Debug, Clone)]
+pub enum FeedEvent {
+    Data(MarketDataType),
+    /// WebSocket lost; data now comes from REST polling
+    Degraded {
+        reason: String,
+    },
+    /// WebSocket delivering again; REST polling stopped
+    Recovered,
+}
+
+/// Subscribe over WebSocket, falling back to REST polling while the stream is down
+///
+/// The stream counts as down once it closes, or goes silent, and no reconnect delivers
+/// data within `stale_after`. A `FeedEvent::Degraded` is then emitted and tickers and
+/// klines are polled every `poll_interval`, while the WebSocket is retried every
+/// `reconnect_interval`; the first message from a retried stream emits
+/// `FeedEvent::Recovered` and polling stops.
+///
+/// REST has no trade or order book feed in this crate, so those subscriptions pause
+/// while degraded. Polled tickers are built from the last 24 hourly klines, and their
+/// quote volume is approximated from each bar's close.
+///
+/// The task exits once the returned receiver is dropped.
+pub fn subscribe_with_failover<S>(
+    source: Arc<S>,
+    symbols: Vec<String>,
+    subscriptions: Vec<SubscriptionType>,
+    config: FailoverConfig,
+) -> mpsc::Receiver<FeedEvent>
+where
+    S: MarketDataSource + Send + Sync + ?Sized + 'static,
+{
+    let (tx, rx) = mpsc::channel(1000);
+
+    tokio::spawn(async move {
+        let feed = Feed {
+            source,
+            symbols: config
+                .symbol_filter
+                .apply(symbols)
+                .iter()
+                .map(Symbol::from)
+                .collect(),
+            subscriptions,
+            config,
+            tx,
+        };
+        feed.run().await;
+    });
+
+    rx
+}
+
+/// Subscription that has delivered its first message
+struct Live {
+    first: MarketDataType,
+    stream: mpsc::Receiver<MarketDataType>,
+}
+
+struct Feed<S: ?Sized> {
+    source: Arc<S>,
+    symbols: Vec<Symbol>,
+    subscriptions: Vec<SubscriptionType>,
+    config: FailoverConfig,
+    tx: mpsc::Sender<FeedEvent>,
+}
+
+impl<S> Feed<S>
+where
+    S: MarketDataSource + Send + Sync + ?Sized,
+{
+    async fn run(self) {
+        let mut stream = self.connect().await;
+        loop {
+            if let Some(live) = stream.take() {
+                if self.stream_live(live).await.is_err() {
+                    return; // Receiver dropped
+                }
+                // One immediate retry before declaring the feed degraded
+                if let Some(live) = self.connect().await {
+                    stream = Some(live);
+                    continue;
+                }
+            }
+
+            match self.poll_until_recovered().await {
+                Ok(live) => stream = Some(live),
+                Err(()) => return,
+            }
+        }
+    }
+
+    /// Forward messages until the stream closes or goes stale
+    async fn stream_live(&self, live: Live) -> Result<(), ()> {
+        let Live { first, mut stream } = live;
+        self.send(FeedEvent::Data(first)).await?;
+        while let Ok(Some(data)) =
+            tokio::time::timeout(self.config.stale_after, stream.recv()).await
+        {
+            self.send(FeedEvent::Data(data)).await?;
+        }
+        Ok(())
+    }
+
+    async fn poll_until_recovered(&self) -> Result<Live, ()> {
+        warn!("Market data stream down, falling back to REST polling");
+        self.send(FeedEvent::Degraded {
+            reason: format!("no data for {:?}", self.config.stale_after),
+        })
+        .await?;
+
+        let mut poll = tokio::time::interval(self.config.poll_interval);
+        let mut reconnect = tokio::time::interval(self.config.reconnect_interval);
+        reconnect.tick().await; // Just failed; skip the immediate retry
+        loop {
+            tokio::select! {
+                _ = poll.tick() => {
+                    for data in self.poll_rest().await {
+                        self.send(FeedEvent::Data(data)).await?;
+                    }
+                }
+                _ = reconnect.tick() => {
+                    if let Some(live) = self.connect().await {
+                        info!("Market data stream recovered");
+                        self.send(FeedEvent::Recovered).await?;
+                        return Ok(live);
+                    }
+                }
+            }
+            if self.tx.is_closed() {
+                return Err(());
+            }
+        }
+    }
+
+    /// Subscribe and wait for the first message
+    async fn connect(&self) -> Option<Live> {
+        let mut stream = match self
+            .source
+            .subscribe_market_data(self.symbols.clone(), self.subscriptions.clone(), None)
+            .await
+        {
+            Ok(stream) => stream,
+            Err(e) => {
+                warn!("Market data subscription failed: {}", e);
+                return None;
+            }
+        };
+        let first = tokio::time::timeout(self.config.stale_after, stream.recv())
+            .await
+            .ok()??;
+        Some(Live { first, stream })
+    }
+
+    async fn poll_rest(&self) -> Vec<MarketDataType> {
+        let mut data = Vec::new();
+        for symbol in &self.symbols {
+            for subscription in &self.subscriptions {
+                let polled = match subscription {
+                    SubscriptionType::Ticker => self
+                        .source
+                        .get_klines(symbol.clone(), KlineInterval::Hours1, Some(24), None, None)
+                        .await
+                        .map(|klines| ticker_from_klines(klines).map(MarketDataType::Ticker)),
+                    SubscriptionType::Klines { interval } => self
+                        .source
+                        .get_klines(symbol.clone(), *interval, Some(2), None, None)
+                        .await
+                        .map(|klines| {
+                            klines
+                                .into_iter()
+                                .max_by_key(|kline| kline.open_time)
+                                .map(MarketDataType::Kline)
+                        }),
+                    SubscriptionType::OrderBook { .. }
+                    | SubscriptionType::Trades
+                    | SubscriptionType::AggTrades
+                    | SubscriptionType::MarkPrice => Ok(None),
+                };
+                match polled {
+                    Ok(polled) => data.extend(polled),
+                    Err(e) => warn!("REST fallback poll failed for {}: {}", symbol, e),
+                }
+            }
+        }
+        data
+    }
+
+    async fn send(&self, event: FeedEvent) -> Result<(), ()> {
+        self.tx.send(event).await.map_err(|_| ())
+    }
+}
+
+/// Rolling ticker over the given bars
+fn ticker_from_klines(mut klines: Vec<Kline>) -> Option<Ticker> {
+    klines.sort_by_key(|kline| kline.open_time);
+    let (first, last) = (klines.first()?, klines.last()?);
+
+    let open = first.open_price.value();
+    let close = last.close_price.value();
+    let change_percent = if open.is_zero() {
+        Decimal::ZERO
+    } else {
+        (close - open) / open * Decimal::ONE_HUNDRED
+    };
+
+    Some(Ticker {
+        symbol: last.symbol.clone(),
+        price: last.close_price,
+        price_change: Price::new(close - open),
+        price_change_percent: change_percent,
+        high_price: klines.iter().map(|kline| kline.high_price).max()?,
+        low_price: klines.iter().map(|kline| kline.low_price).min()?,
+        volume: Volume::new(klines.iter().map(|kline| kline.volume.value()).sum()),
+        quote_volume: Volume::new(
+            klines
+                .iter()
+                .map(|kline| kline.volume.value() * kline.close_price.value())
+                .sum(),
+        ),
+        open_time: first.open_time,
+        close_time: last.close_time,
+        count: klines.iter().map(|kline| kline.number_of_trades).sum(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::errors::ExchangeError;
+    use crate::core::traits::{RestMarketData, StreamingMarketData};
+    use crate::core::types::{conversion, Market, WebSocketConfig};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn kline(open_time: i64, open: &str, close: &str) -> Kline {
+        Kline {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            open_time,
+            close_time: open_time + 59_999,
+            interval: "1h".to_string(),
+            open_price: conversion::string_to_price(open),
+            high_price: conversion::string_to_price(open.max(close)),
+            low_price: conversion::string_to_price(open.min(close)),
+            close_price: conversion::string_to_price(close),
+            volume: conversion::string_to_volume("2"),
+            number_of_trades: 5,
+            final_bar: true,
+        }
+    }
+
+    /// First subscription sends one message then goes silent; the second fails; later
+    /// ones work again
+    struct FlakySource {
+        subscriptions: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl RestMarketData for FlakySource {
+        async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_klines(
+            &self,
+            _symbol: Symbol,
+            _interval: KlineInterval,
+            _limit: Option<u32>,
+            _start_time: Option<i64>,
+            _end_time: Option<i64>,
+        ) -> Result<Vec<Kline>, ExchangeError> {
+            Ok(vec![kline(3_600_000, "110", "120"), kline(0, "100", "110")])
+        }
+    }
+
+    #[async_trait]
+    impl StreamingMarketData for FlakySource {
+        async fn subscribe_market_data(
+            &self,
+            _symbols: Vec<Symbol>,
+            _subscription_types: Vec<SubscriptionType>,
+            _config: Option<WebSocketConfig>,
+        ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+            let attempt = self.subscriptions.fetch_add(1, Ordering::SeqCst);
+            if attempt == 1 {
+                return Err(ExchangeError::NetworkError("refused".to_string()));
+            }
+            let (tx, rx) = mpsc::channel(8);
+            tx.send(MarketDataType::Kline(kline(0, "1", "1")))
+                .await
+                .unwrap();
+            // Keep the first stream open but silent
+            if attempt == 0 {
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                    drop(tx);
+                });
+            }
+            Ok(rx)
+        }
+
+        fn get_websocket_url(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn test_ticker_from_klines_covers_the_window() {
+        let ticker =
+            ticker_from_klines(vec![kline(3_600_000, "110", "120"), kline(0, "100", "110")])
+                .unwrap();
+        assert_eq!(ticker.price.to_string(), "120");
+        assert_eq!(ticker.price_change.to_string(), "20");
+        assert_eq!(ticker.price_change_percent, Decimal::from(20));
+        assert_eq!(ticker.low_price.to_string(), "100");
+        assert_eq!(ticker.count, 10);
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_rest_and_recovers() {
+        let source = Arc::new(FlakySource {
+            subscriptions: AtomicUsize::new(0),
+        });
+        let config = FailoverConfig::default()
+            .with_stale_after(Duration::from_millis(50))
+            .with_poll_interval(Duration::from_millis(10))
+            .with_reconnect_interval(Duration::from_millis(100));
+        let mut feed = subscribe_with_failover(
+            source,
+            vec!["BTCUSDT".to_string()],
+            vec![SubscriptionType::Ticker],
+            config,
+        );
+
+        assert!(matches!(feed.recv().await, Some(FeedEvent::Data(_))));
+        assert!(matches!(
+            feed.recv().await,
+            Some(FeedEvent::Degraded { .. })
+        ));
+        let Some(FeedEvent::Data(MarketDataType::Ticker(ticker))) = feed.recv().await else {
+            panic!("expected a polled ticker");
+        };
+        assert_eq!(ticker.price.to_string(), "120");
+
+        loop {
+            match feed.recv().await {
+                Some(FeedEvent::Data(MarketDataType::Ticker(_))) => {}
+                Some(FeedEvent::Recovered) => break,
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        assert!(matches!(
+            feed.recv().await,
+            Some(FeedEvent::Data(MarketDataType::Kline(_)))
+        ));
+    }
+}
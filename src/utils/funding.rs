@@ -0,0 +1,217 @@
+use crate::core::traits::FundingRateSource;
+use crate::core::types::{FundingRate, Symbol};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Funding settlements per 365-day year for a given interval
+pub fn periods_per_year(interval: Duration) -> Option<Decimal> {
+    let seconds = interval.as_secs();
+    if seconds == 0 {
+        return None;
+    }
+    Some(Decimal::from(FundingRate::SECONDS_PER_YEAR) / Decimal::from(seconds))
+}
+
+/// Rescale a per-period rate to a different funding interval (e.g. 1h → 8h)
+pub fn rescale(rate: Decimal, from: Duration, to: Duration) -> Option<Decimal> {
+    let from = from.as_secs();
+    if from == 0 {
+        return None;
+    }
+    Some(rate * Decimal::from(to.as_secs()) / Decimal::from(from))
+}
+
+/// Compounded annual yield, assuming each settlement is reinvested
+///
+/// Hourly funding compounds noticeably more than 8h funding at the same APR, so use this
+/// when comparing venues with different intervals over long holding periods.
+pub fn compounded_apy(rate: Decimal, interval: Duration) -> Option<f64> {
+    let periods = periods_per_year(interval)?.to_f64()?;
+    let rate = rate.to_f64()?;
+    Some((1.0 + rate).powf(periods) - 1.0)
+}
+
+/// Funding rate normalized for cross-venue comparison
+#[derive(Debug, Clone)]
+pub struct NormalizedFunding {
+    pub venue: String,
+    pub symbol: Symbol,
+    /// Rate per settlement as reported by the venue
+    pub rate: Decimal,
+    pub interval: Duration,
+    /// Simple annualized rate
+    pub apr: Decimal,
+    /// Compounded annualized yield
+    pub apy: f64,
+}
+
+impl NormalizedFunding {
+    /// Normalize a venue's funding rate; `None` if the rate or interval is missing
+    pub fn from_rate(venue: impl Into<String>, rate: &FundingRate) -> Option<Self> {
+        let interval = rate.funding_interval?;
+        let per_period = rate.funding_rate?;
+        Some(Self {
+            venue: venue.into(),
+            symbol: rate.symbol.clone(),
+            rate: per_period,
+            interval,
+            apr: rate.annualized()?,
+            apy: compounded_apy(per_period, interval)?,
+        })
+    }
+
+    /// Rate expressed per `interval`, e.g. every venue as an 8h-equivalent rate
+    pub fn rate_per(&self, interval: Duration) -> Option<Decimal> {
+        rescale(self.rate, self.interval, interval)
+    }
+}
+
+/// Normalize funding rates from several venues and sort by APR, highest first
+///
+/// Rates without a known interval are dropped rather than compared unfairly.
+pub fn rank_by_apr<'a, I>(rates: I) -> Vec<NormalizedFunding>
+where
+    I: IntoIterator<Item = (&'a str, &'a FundingRate)>,
+{
+    let mut normalized: Vec<_> = rates
+        .into_iter()
+        .filter_map(|(venue, rate)| NormalizedFunding::from_rate(venue, rate))
+        .collect();
+    normalized.sort_by_key(|n| std::cmp::Reverse(n.apr));
+    normalized
+}
+
+/// Annualized carry from shorting `short` and going long `long`
+pub fn apr_spread(short: &NormalizedFunding, long: &NormalizedFunding) -> Decimal {
+    short.apr - long.apr
+}
+
+/// Poll funding rates and mark prices, streaming each symbol when it changes
+///
+/// Covers venues without a funding WebSocket channel. Every symbol is sent on the first
+/// poll; after that a rate is only sent when its funding rate, mark price or next
+/// settlement time moves. `None` polls every symbol the venue lists. The task exits once
+/// the returned receiver is dropped.
+pub fn stream_funding_rates<S>(
+    source: Arc<S>,
+    symbols: Option<Vec<Symbol>>,
+    poll_interval: Duration,
+) -> mpsc::Receiver<FundingRate>
+where
+    S: FundingRateSource + Send + Sync + ?Sized + 'static,
+{
+    let (tx, rx) = mpsc::channel(1000);
+
+    tokio::spawn(async move {
+        let mut last = HashMap::new();
+        let mut ticker = tokio::time::interval(poll_interval);
+        loop {
+            ticker.tick().await;
+            if tx.is_closed() {
+                break;
+            }
+            let rates = match source.poll_funding_rates(symbols.clone()).await {
+                Ok(rates) => rates,
+                Err(e) => {
+                    warn!("Failed to poll funding rates: {}", e);
+                    continue;
+                }
+            };
+            for rate in rates {
+                let key = (rate.funding_rate, rate.mark_price, rate.next_funding_time);
+                if last.get(&rate.symbol) == Some(&key) {
+                    continue;
+                }
+                last.insert(rate.symbol.clone(), key);
+                if tx.send(rate).await.is_err() {
+                    return; // Receiver dropped
+                }
+            }
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::conversion;
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    fn rate(rate: &str, interval: Option<Duration>) -> FundingRate {
+        FundingRate {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            funding_rate: Some(conversion::string_to_decimal(rate)),
+            previous_funding_rate: None,
+            next_funding_rate: None,
+            funding_time: None,
+            next_funding_time: None,
+            mark_price: None,
+            index_price: None,
+            timestamp: 0,
+            funding_interval: interval,
+        }
+    }
+
+    #[test]
+    fn test_annualized_accounts_for_interval() {
+        let eight_hourly = rate("0.0001", Some(HOUR * 8));
+        let hourly = rate("0.0001", Some(HOUR));
+
+        assert_eq!(
+            eight_hourly.annualized(),
+            Some(conversion::string_to_decimal("0.1095"))
+        );
+        assert_eq!(
+            hourly.annualized(),
+            Some(conversion::string_to_decimal("0.876"))
+        );
+        assert_eq!(rate("0.0001", None).annualized(), None);
+    }
+
+    #[test]
+    fn test_derives_settlement_fields() {
+        let mut from_clock = rate("0.0001", Some(HOUR * 8));
+        from_clock.timestamp = 3_600_000 * 9;
+        from_clock.fill_derived_fields();
+        assert_eq!(from_clock.next_funding_time, Some(3_600_000 * 16));
+        assert_eq!(from_clock.funding_time, Some(3_600_000 * 8));
+
+        let mut from_times = rate("0.0001", None);
+        from_times.funding_time = Some(0);
+        from_times.next_funding_time = Some(3_600_000 * 4);
+        from_times.fill_derived_fields();
+        assert_eq!(from_times.funding_interval, Some(HOUR * 4));
+    }
+
+    #[test]
+    fn test_rank_and_rescale() {
+        let binance = rate("0.0008", Some(HOUR * 8));
+        let hyperliquid = rate("0.00005", Some(HOUR));
+        let unknown = rate("0.01", None);
+
+        let ranked = rank_by_apr([
+            ("hyperliquid", &hyperliquid),
+            ("binance_perp", &binance),
+            ("okx", &unknown),
+        ]);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].venue, "binance_perp");
+        assert_eq!(
+            ranked[1].rate_per(HOUR * 8),
+            Some(conversion::string_to_decimal("0.0004"))
+        );
+        assert_eq!(
+            apr_spread(&ranked[0], &ranked[1]),
+            conversion::string_to_decimal("0.438")
+        );
+        assert!(ranked[1].apy > ranked[1].apr.to_f64().unwrap());
+    }
+}
@@ -0,0 +1,613 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::OrderPlacer;
+use crate::core::types::{
+    MarketDataType, OrderBook, OrderRequest, OrderResponse, OrderSide, Symbol,
+};
+use crate::utils::paper::Fill;
+use crate::utils::scoped_orders::is_terminal;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use tracing::warn;
+
+/// Something a strategy saw or did
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum JournalEvent {
+    /// Market data a decision was based on; only journaled when enabled
+    MarketData(MarketDataType),
+    OrderSubmitted(OrderRequest),
+    OrderAcknowledged(OrderResponse),
+    OrderRejected {
+        order: OrderRequest,
+        error: String,
+    },
+    /// A modify that went through; venues may answer with a new order id
+    OrderAmended {
+        order_id: String,
+        response: OrderResponse,
+    },
+    CancelRequested {
+        symbol: Symbol,
+        order_id: String,
+    },
+    Cancelled {
+        symbol: Symbol,
+        order_id: String,
+    },
+    /// A cancel addressed by the `client_order_id` the order was placed with
+    CancelByClientIdRequested {
+        symbol: Symbol,
+        client_order_id: String,
+    },
+    CancelledByClientId {
+        symbol: Symbol,
+        client_order_id: String,
+    },
+    Fill(Fill),
+    Error {
+        context: String,
+        message: String,
+    },
+}
+
+/// One line of the journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Starts at 1 and increases by one per entry, across reopens
+    pub sequence: u64,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: i64,
+    pub exchange: String,
+    pub event: JournalEvent,
+}
+
+/// Append-only JSON-lines log of execution events
+///
+/// Every entry is flushed as it is written, so a crash loses at most the entry being
+/// written. Market data is skipped unless enabled with `with_market_data`, since it
+/// dwarfs everything else.
+pub struct ExecutionJournal<W: Write> {
+    writer: W,
+    next_sequence: u64,
+    market_data: bool,
+}
+
+impl<W: Write> ExecutionJournal<W> {
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            next_sequence: 1,
+            market_data: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_market_data(mut self, enabled: bool) -> Self {
+        self.market_data = enabled;
+        self
+    }
+
+    pub const fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
+    /// Write `event`, returning its sequence number
+    pub fn append(&mut self, exchange: &str, event: JournalEvent) -> Result<u64, ExchangeError> {
+        let entry = JournalEntry {
+            sequence: self.next_sequence,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            exchange: exchange.to_string(),
+            event,
+        };
+        let line = serde_json::to_string(&entry).map_err(|e| {
+            ExchangeError::SerializationError(format!("Failed to serialize journal entry: {}", e))
+        })?;
+        writeln!(self.writer, "{}", line).map_err(write_error)?;
+        self.writer.flush().map_err(write_error)?;
+        self.next_sequence += 1;
+        Ok(entry.sequence)
+    }
+
+    /// Write market data if enabled, returning its sequence number
+    pub fn append_market_data(
+        &mut self,
+        exchange: &str,
+        data: &MarketDataType,
+    ) -> Result<Option<u64>, ExchangeError> {
+        if !self.market_data {
+            return Ok(None);
+        }
+        self.append(exchange, JournalEvent::MarketData(data.clone()))
+            .map(Some)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl ExecutionJournal<BufWriter<File>> {
+    /// Open `path` for appending, continuing its sequence numbers
+    ///
+    /// A torn final line left by a crash is truncated away first.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ExchangeError> {
+        let path = path.as_ref();
+        let open_error = |e: std::io::Error| {
+            ExchangeError::Other(format!("Failed to open {}: {}", path.display(), e))
+        };
+        let file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)
+            .map_err(open_error)?;
+
+        let mut contents = String::new();
+        BufReader::new(&file)
+            .read_to_string(&mut contents)
+            .map_err(open_error)?;
+        let last = read_journal(contents.as_bytes())?
+            .last()
+            .map_or(0, |entry| entry.sequence);
+        if !contents.is_empty() && !contents.ends_with('\n') {
+            let complete = contents.rfind('\n').map_or(0, |index| index + 1);
+            file.set_len(complete as u64).map_err(open_error)?;
+        }
+
+        let mut journal = Self::new(BufWriter::new(file));
+        journal.next_sequence = last + 1;
+        Ok(journal)
+    }
+}
+
+/// Read every entry of a journal, checking the sequence has no gaps
+///
+/// A final line that fails to parse is taken to be torn by a crash and skipped.
+pub fn read_journal<R: BufRead>(reader: R) -> Result<Vec<JournalEntry>, ExchangeError> {
+    let lines = reader
+        .lines()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ExchangeError::Other(format!("Journal read failed: {}", e)))?;
+    let lines: Vec<_> = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+
+    let mut entries: Vec<JournalEntry> = Vec::with_capacity(lines.len());
+    for (index, line) in lines.iter().enumerate() {
+        let entry: JournalEntry = match serde_json::from_str(line) {
+            Ok(entry) => entry,
+            Err(e) if index + 1 == lines.len() => {
+                warn!(error = %e, "Skipping torn final journal line");
+                break;
+            }
+            Err(e) => {
+                return Err(ExchangeError::DeserializationError(format!(
+                    "Invalid journal line {}: {}",
+                    index + 1,
+                    e
+                )))
+            }
+        };
+        let expected = entries
+            .last()
+            .map_or(entry.sequence, |last| last.sequence + 1);
+        if entry.sequence != expected {
+            return Err(ExchangeError::Other(format!(
+                "Journal sequence gap: expected {}, found {}",
+                expected, entry.sequence
+            )));
+        }
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// An acknowledged order that has not yet filled, cancelled or been amended away
+#[derive(Debug, Clone)]
+pub struct OpenOrder {
+    pub exchange: String,
+    pub order: OrderResponse,
+    pub filled: Decimal,
+}
+
+/// What a strategy knew after some prefix of the journal
+#[derive(Debug, Clone, Default)]
+pub struct ReplayState {
+    /// Sequence of the last entry applied; zero before any
+    pub last_sequence: u64,
+    pub open_orders: HashMap<String, OpenOrder>,
+    /// Signed filled quantity per venue and symbol
+    pub positions: HashMap<(String, Symbol), Decimal>,
+    /// Latest journaled book per venue and symbol
+    pub books: HashMap<(String, Symbol), OrderBook>,
+    /// Orders submitted without an acknowledgement or rejection yet
+    pub in_flight: usize,
+    /// Sequence and message of every rejection and error
+    pub errors: Vec<(u64, String)>,
+}
+
+impl ReplayState {
+    pub fn apply(&mut self, entry: &JournalEntry) {
+        self.last_sequence = entry.sequence;
+        match &entry.event {
+            JournalEvent::MarketData(MarketDataType::OrderBook(book)) => {
+                self.books
+                    .insert((entry.exchange.clone(), book.symbol.clone()), book.clone());
+            }
+            JournalEvent::MarketData(_)
+            | JournalEvent::CancelRequested { .. }
+            | JournalEvent::CancelByClientIdRequested { .. } => {}
+            JournalEvent::OrderSubmitted(_) => self.in_flight += 1,
+            JournalEvent::OrderAcknowledged(response) => {
+                self.in_flight = self.in_flight.saturating_sub(1);
+                self.track(&entry.exchange, response);
+            }
+            JournalEvent::OrderRejected { error, .. } => {
+                self.in_flight = self.in_flight.saturating_sub(1);
+                self.errors.push((entry.sequence, error.clone()));
+            }
+            JournalEvent::OrderAmended { order_id, response } => {
+                self.open_orders.remove(order_id);
+                self.track(&entry.exchange, response);
+            }
+            JournalEvent::Cancelled { order_id, .. } => {
+                self.open_orders.remove(order_id);
+            }
+            JournalEvent::CancelledByClientId {
+                client_order_id, ..
+            } => {
+                self.open_orders
+                    .retain(|_, open| open.order.client_order_id != *client_order_id);
+            }
+            JournalEvent::Fill(fill) => self.fill(&entry.exchange, fill),
+            JournalEvent::Error { context, message } => {
+                self.errors
+                    .push((entry.sequence, format!("{}: {}", context, message)));
+            }
+        }
+    }
+
+    fn track(&mut self, exchange: &str, response: &OrderResponse) {
+        if !is_terminal(&response.status) {
+            self.open_orders.insert(
+                response.order_id.clone(),
+                OpenOrder {
+                    exchange: exchange.to_string(),
+                    order: response.clone(),
+                    filled: Decimal::ZERO,
+                },
+            );
+        }
+    }
+
+    fn fill(&mut self, exchange: &str, fill: &Fill) {
+        let quantity = fill.quantity.value();
+        let signed = match fill.side {
+            OrderSide::Buy => quantity,
+            OrderSide::Sell => -quantity,
+        };
+        *self
+            .positions
+            .entry((exchange.to_string(), fill.symbol.clone()))
+            .or_default() += signed;
+
+        if let Some(open) = self.open_orders.get_mut(&fill.order_id) {
+            open.filled += quantity;
+            if open.filled >= open.order.quantity.value() {
+                self.open_orders.remove(&fill.order_id);
+            }
+        }
+    }
+}
+
+/// Steps through journal entries, rebuilding state exactly as it was at each one
+///
+/// State depends only on the entries, never on the clock or the venue, so replaying the
+/// same journal always gives the same result.
+#[derive(Debug, Clone)]
+pub struct JournalReplayer {
+    entries: Vec<JournalEntry>,
+    position: usize,
+    state: ReplayState,
+}
+
+impl JournalReplayer {
+    pub fn new(entries: Vec<JournalEntry>) -> Self {
+        Self {
+            entries,
+            position: 0,
+            state: ReplayState::default(),
+        }
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, ExchangeError> {
+        read_journal(reader).map(Self::new)
+    }
+
+    pub const fn state(&self) -> &ReplayState {
+        &self.state
+    }
+
+    /// Apply the next entry, returning it
+    pub fn step(&mut self) -> Option<&JournalEntry> {
+        let entry = self.entries.get(self.position)?;
+        self.state.apply(entry);
+        self.position += 1;
+        Some(entry)
+    }
+
+    /// Apply entries up to and including `sequence`
+    pub fn run_until(&mut self, sequence: u64) -> &ReplayState {
+        while self
+            .entries
+            .get(self.position)
+            .is_some_and(|entry| entry.sequence <= sequence)
+        {
+            self.step();
+        }
+        &self.state
+    }
+
+    /// Apply every remaining entry
+    pub fn run(&mut self) -> &ReplayState {
+        self.run_until(u64::MAX)
+    }
+}
+
+/// `OrderPlacer` that journals every request and outcome before returning it
+///
+/// Journal write failures are logged and never fail the order call, which has already
+/// reached the venue. Fills arrive elsewhere; journal them with `append`.
+pub struct JournaledPlacer<P, W: Write> {
+    inner: P,
+    exchange: String,
+    journal: Arc<Mutex<ExecutionJournal<W>>>,
+}
+
+impl<P, W: Write> JournaledPlacer<P, W> {
+    pub fn new(
+        inner: P,
+        exchange: impl Into<String>,
+        journal: Arc<Mutex<ExecutionJournal<W>>>,
+    ) -> Self {
+        Self {
+            inner,
+            exchange: exchange.into(),
+            journal,
+        }
+    }
+
+    pub const fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    fn record(&self, event: JournalEvent) {
+        let result = self
+            .journal
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .append(&self.exchange, event);
+        if let Err(e) = result {
+            warn!(exchange = %self.exchange, error = %e, "Failed to journal execution event");
+        }
+    }
+}
+
+#[async_trait]
+impl<P: OrderPlacer + Send + Sync, W: Write + Send> OrderPlacer for JournaledPlacer<P, W> {
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        self.record(JournalEvent::OrderSubmitted(order.clone()));
+        match self.inner.place_order(order.clone()).await {
+            Ok(response) => {
+                self.record(JournalEvent::OrderAcknowledged(response.clone()));
+                Ok(response)
+            }
+            Err(e) => {
+                self.record(JournalEvent::OrderRejected {
+                    order,
+                    error: e.to_string(),
+                });
+                Err(e)
+            }
+        }
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        self.record(JournalEvent::CancelRequested {
+            symbol: symbol.clone(),
+            order_id: order_id.clone(),
+        });
+        match self
+            .inner
+            .cancel_order(symbol.clone(), order_id.clone())
+            .await
+        {
+            Ok(()) => {
+                self.record(JournalEvent::Cancelled { symbol, order_id });
+                Ok(())
+            }
+            Err(e) => {
+                self.record(JournalEvent::Error {
+                    context: format!("cancel {}", order_id),
+                    message: e.to_string(),
+                });
+                Err(e)
+            }
+        }
+    }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.record(JournalEvent::CancelByClientIdRequested {
+            symbol: symbol.clone(),
+            client_order_id: client_order_id.clone(),
+        });
+        match self
+            .inner
+            .cancel_order_by_client_id(symbol.clone(), client_order_id.clone())
+            .await
+        {
+            Ok(()) => {
+                self.record(JournalEvent::CancelledByClientId {
+                    symbol,
+                    client_order_id,
+                });
+                Ok(())
+            }
+            Err(e) => {
+                self.record(JournalEvent::Error {
+                    context: format!("cancel client order {}", client_order_id),
+                    message: e.to_string(),
+                });
+                Err(e)
+            }
+        }
+    }
+
+    async fn modify_order(
+        &self,
+        order_id: String,
+        order: OrderRequest,
+    ) -> Result<OrderResponse, ExchangeError> {
+        match self.inner.modify_order(order_id.clone(), order).await {
+            Ok(response) => {
+                self.record(JournalEvent::OrderAmended {
+                    order_id,
+                    response: response.clone(),
+                });
+                Ok(response)
+            }
+            Err(e) => {
+                self.record(JournalEvent::Error {
+                    context: format!("modify {}", order_id),
+                    message: e.to_string(),
+                });
+                Err(e)
+            }
+        }
+    }
+
+    fn supports_trading(&self) -> bool {
+        self.inner.supports_trading()
+    }
+}
+
+fn write_error(error: std::io::Error) -> ExchangeError {
+    ExchangeError::Other(format!("Journal write failed: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, btc_usdt, limit_order, FakeVenue};
+    use crate::utils::paper::Liquidity;
+
+    fn order(quantity: i64) -> OrderRequest {
+        limit_order(&btc_usdt(), OrderSide::Buy, "100", &quantity.to_string())
+    }
+
+    fn fill(quantity: i64) -> Fill {
+        test_support::fill(
+            "1",
+            OrderSide::Buy,
+            "100",
+            &quantity.to_string(),
+            Liquidity::Maker,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_replay_rebuilds_state_at_any_sequence() {
+        let journal = Arc::new(Mutex::new(ExecutionJournal::new(Vec::new())));
+        let placer = JournaledPlacer::new(
+            FakeVenue::default().with_max_quantity(Decimal::TEN),
+            "binance",
+            journal.clone(),
+        );
+        placer.place_order(order(4)).await.unwrap();
+        assert!(placer.place_order(order(50)).await.is_err());
+        drop(placer);
+
+        let mut journal = Arc::try_unwrap(journal).ok().unwrap().into_inner().unwrap();
+        journal
+            .append("binance", JournalEvent::Fill(fill(1)))
+            .unwrap();
+        journal
+            .append("binance", JournalEvent::Fill(fill(3)))
+            .unwrap();
+        // Not enabled, so not written
+        let book = MarketDataType::OrderBook(OrderBook {
+            symbol: btc_usdt(),
+            bids: Vec::new(),
+            asks: Vec::new(),
+            last_update_id: 0,
+        });
+        assert_eq!(journal.append_market_data("binance", &book).unwrap(), None);
+
+        let bytes = journal.into_inner();
+        let mut replayer = JournalReplayer::from_reader(bytes.as_slice()).unwrap();
+
+        // After the first fill the order is still working
+        let state = replayer.run_until(5).clone();
+        let btc = btc_usdt();
+        assert_eq!(state.open_orders["1"].filled, Decimal::ONE);
+        assert_eq!(state.errors.len(), 1);
+        assert_eq!(state.in_flight, 0);
+
+        let state = replayer.run();
+        assert_eq!(state.last_sequence, 6);
+        assert!(state.open_orders.is_empty());
+        assert_eq!(
+            state.positions[&("binance".to_string(), btc)],
+            Decimal::from(4)
+        );
+    }
+
+    #[test]
+    fn test_reopen_continues_sequence_and_skips_torn_line() {
+        let path =
+            std::env::temp_dir().join(format!("lotusx-journal-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut journal = ExecutionJournal::open(&path).unwrap();
+        journal.append("okx", JournalEvent::Fill(fill(1))).unwrap();
+        journal.append("okx", JournalEvent::Fill(fill(1))).unwrap();
+        drop(journal);
+
+        let mut journal = ExecutionJournal::open(&path).unwrap();
+        assert_eq!(journal.next_sequence(), 3);
+        journal.append("okx", JournalEvent::Fill(fill(1))).unwrap();
+        drop(journal);
+
+        // A crash mid-write leaves a partial last line
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, "{{\"sequence\":4,\"timest").unwrap();
+        drop(file);
+
+        let entries = read_journal(BufReader::new(File::open(&path).unwrap())).unwrap();
+        assert_eq!(entries.len(), 3);
+
+        // Reopening drops the torn line before appending
+        let mut journal = ExecutionJournal::open(&path).unwrap();
+        assert_eq!(
+            journal.append("okx", JournalEvent::Fill(fill(1))).unwrap(),
+            4
+        );
+        drop(journal);
+        let entries = read_journal(BufReader::new(File::open(&path).unwrap())).unwrap();
+        let sequences: Vec<_> = entries.iter().map(|entry| entry.sequence).collect();
+        assert_eq!(sequences, [1, 2, 3, 4]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
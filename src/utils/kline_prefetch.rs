@@ -0,0 +1,430 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::RestMarketData;
+use crate::core::types::{Kline, KlineInterval, Symbol};
+use crate::utils::symbol_filter::SymbolFilter;
+use async_trait::async_trait;
+use futures_util::stream::{self, StreamExt};
+use governor::{DefaultDirectRateLimiter, Quota, RateLimiter};
+use nonzero_ext::nonzero;
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Limits for `prefetch_klines`
+#[derive(Debug, Clone)]
+pub struct PrefetchConfig {
+    /// Symbols fetched at the same time
+    pub concurrency: usize,
+    /// Request budget shared by every symbol
+    pub requests_per_second: NonZeroU32,
+    /// Bars requested per call; keep it at or below the venue's maximum
+    pub page_limit: u32,
+    /// Retries per request for retryable errors
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on each further attempt
+    pub retry_backoff: Duration,
+    /// Receives an update after each symbol finishes
+    pub progress: Option<mpsc::UnboundedSender<PrefetchProgress>>,
+    /// Symbols it rejects are skipped and left out of the report
+    pub symbol_filter: SymbolFilter,
+}
+
+impl Default for PrefetchConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: 8,
+            requests_per_second: nonzero!(10u32),
+            page_limit: 500,
+            max_retries: 3,
+            retry_backoff: Duration::from_millis(500),
+            progress: None,
+            symbol_filter: SymbolFilter::default(),
+        }
+    }
+}
+
+impl PrefetchConfig {
+    #[must_use]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    #[must_use]
+    pub fn with_requests_per_second(mut self, requests_per_second: u32) -> Self {
+        self.requests_per_second =
+            NonZeroU32::new(requests_per_second).unwrap_or_else(|| nonzero!(1u32));
+        self
+    }
+
+    #[must_use]
+    pub fn with_page_limit(mut self, page_limit: u32) -> Self {
+        self.page_limit = page_limit.max(1);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_retries(mut self, max_retries: u32, backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_backoff = backoff;
+        self
+    }
+
+    #[must_use]
+    pub fn with_progress(mut self, progress: mpsc::UnboundedSender<PrefetchProgress>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    #[must_use]
+    pub fn with_symbol_filter(mut self, symbol_filter: SymbolFilter) -> Self {
+        self.symbol_filter = symbol_filter;
+        self
+    }
+}
+
+/// Sent after each symbol completes, successfully or not
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefetchProgress {
+    pub symbol: String,
+    pub completed: usize,
+    pub failed: usize,
+    pub total: usize,
+}
+
+/// Outcome of a prefetch; a symbol appears in exactly one of the two maps
+#[derive(Debug, Default)]
+pub struct PrefetchReport {
+    /// Bars per symbol, oldest first, without duplicates
+    pub klines: HashMap<String, Vec<Kline>>,
+    pub failures: HashMap<String, ExchangeError>,
+}
+
+/// Backfill `lookback` worth of candles for every symbol
+///
+/// The range is split into windows of `page_limit` bars and each window is requested
+/// with explicit start and end times, so paging works the same whether a venue returns
+/// bars oldest or newest first. A window the venue answers only in part, because its
+/// own cap is below `page_limit`, is requested again for the missing end. All requests
+/// draw from one rate budget, and retryable errors are retried with exponential backoff
+/// before a symbol is marked failed.
+pub async fn prefetch_klines<S>(
+    source: &S,
+    symbols: Vec<String>,
+    interval: KlineInterval,
+    lookback: Duration,
+    config: PrefetchConfig,
+) -> PrefetchReport
+where
+    S: RestMarketData + Sync + ?Sized,
+{
+    let end = chrono::Utc::now().timestamp_millis();
+    let start = end.saturating_sub(i64::try_from(lookback.as_millis()).unwrap_or(i64::MAX));
+    let limiter = RateLimiter::direct(Quota::per_second(config.requests_per_second));
+    let symbols = config.symbol_filter.apply(symbols);
+    let total = symbols.len();
+
+    let mut fetches = stream::iter(symbols)
+        .map(|symbol| {
+            let (limiter, config) = (&limiter, &config);
+            async move {
+                let result = fetch_range(
+                    source,
+                    limiter,
+                    config,
+                    &Symbol::from(symbol.as_str()),
+                    interval,
+                    start,
+                    end,
+                )
+                .await;
+                (symbol, result)
+            }
+        })
+        .buffer_unordered(config.concurrency.max(1));
+
+    let mut report = PrefetchReport::default();
+    while let Some((symbol, result)) = fetches.next().await {
+        match result {
+            Ok(klines) => {
+                report.klines.insert(symbol.clone(), klines);
+            }
+            Err(e) => {
+                warn!("Kline prefetch failed for {}: {}", symbol, e);
+                report.failures.insert(symbol.clone(), e);
+            }
+        }
+        if let Some(progress) = &config.progress {
+            let _ = progress.send(PrefetchProgress {
+                symbol,
+                completed: report.klines.len(),
+                failed: report.failures.len(),
+                total,
+            });
+        }
+    }
+    report
+}
+
+/// Paged range fetching over any `RestMarketData`
+///
+/// `get_klines` returns at most one venue page (500 to 1500 bars); these page through
+/// the whole range instead, oldest first and without duplicate boundary bars.
+#[async_trait]
+pub trait KlineRangeExt: RestMarketData + Sync {
+    /// Every bar opening in `[start, end)`, in milliseconds
+    async fn get_klines_range(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        self.get_klines_range_with(symbol, interval, start, end, &PrefetchConfig::default())
+            .await
+    }
+
+    /// `get_klines_range` with `config`'s page size, request rate and retries
+    async fn get_klines_range_with(
+        &self,
+        symbol: Symbol,
+        interval: KlineInterval,
+        start: i64,
+        end: i64,
+        config: &PrefetchConfig,
+    ) -> Result<Vec<Kline>, ExchangeError> {
+        let limiter = RateLimiter::direct(Quota::per_second(config.requests_per_second));
+        fetch_range(self, &limiter, config, &symbol, interval, start, end).await
+    }
+}
+
+impl<T: RestMarketData + Sync + ?Sized> KlineRangeExt for T {}
+
+async fn fetch_range<S>(
+    source: &S,
+    limiter: &DefaultDirectRateLimiter,
+    config: &PrefetchConfig,
+    symbol: &Symbol,
+    interval: KlineInterval,
+    start: i64,
+    end: i64,
+) -> Result<Vec<Kline>, ExchangeError>
+where
+    S: RestMarketData + Sync + ?Sized,
+{
+    let step = interval.duration_millis();
+    let window = step * i64::from(config.page_limit.max(1));
+    let mut windows = Vec::new();
+    let mut from = start;
+    while from < end {
+        let to = from.saturating_add(window).min(end);
+        windows.push((from, to));
+        from = to;
+    }
+
+    let mut klines = Vec::new();
+    while let Some((from, to)) = windows.pop() {
+        let page = fetch_page(source, limiter, config, symbol, interval, from, to).await?;
+        // Venues that ignore the time range return the same recent bars every time
+        let page: Vec<Kline> = page
+            .into_iter()
+            .filter(|kline| kline.open_time >= from && kline.open_time < to)
+            .collect();
+        let (Some(first), Some(last)) = (
+            page.iter().map(|kline| kline.open_time).min(),
+            page.iter().map(|kline| kline.open_time).max(),
+        ) else {
+            continue;
+        };
+        // A venue capped below `page_limit` drops one end of the window: the newest
+        // bars when it lists oldest first, the oldest when it lists newest first
+        if last + step < to {
+            windows.push((last + step, to));
+        }
+        if first - step >= from {
+            windows.push((from, first));
+        }
+        klines.extend(page);
+    }
+
+    klines.sort_by_key(|kline| kline.open_time);
+    klines.dedup_by_key(|kline| kline.open_time);
+    Ok(klines)
+}
+
+async fn fetch_page<S>(
+    source: &S,
+    limiter: &DefaultDirectRateLimiter,
+    config: &PrefetchConfig,
+    symbol: &Symbol,
+    interval: KlineInterval,
+    from: i64,
+    to: i64,
+) -> Result<Vec<Kline>, ExchangeError>
+where
+    S: RestMarketData + Sync + ?Sized,
+{
+    let mut attempt = 0;
+    loop {
+        limiter.until_ready().await;
+        let result = source
+            .get_klines(
+                symbol.clone(),
+                interval,
+                Some(config.page_limit),
+                Some(from),
+                Some(to - 1),
+            )
+            .await;
+        match result {
+            Err(e) if e.is_retryable() && attempt < config.max_retries => {
+                let delay = config
+                    .retry_backoff
+                    .saturating_mul(2u32.saturating_pow(attempt));
+                attempt += 1;
+                tokio::time::sleep(delay).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{conversion, Market, Symbol};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Serves one bar per minute in the requested range, newest first like Bybit
+    struct ScriptedKlines {
+        flaked: AtomicBool,
+        /// Venue page cap; the newest bars of the range are kept
+        cap: usize,
+    }
+
+    #[async_trait]
+    impl RestMarketData for ScriptedKlines {
+        async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_klines(
+            &self,
+            symbol: Symbol,
+            interval: KlineInterval,
+            limit: Option<u32>,
+            start_time: Option<i64>,
+            end_time: Option<i64>,
+        ) -> Result<Vec<Kline>, ExchangeError> {
+            let symbol = symbol.to_string();
+            match symbol.as_str() {
+                "BAD" => return Err(ExchangeError::InvalidParameters("unknown".to_string())),
+                "FLAKY" if !self.flaked.swap(true, Ordering::SeqCst) => {
+                    return Err(ExchangeError::NetworkError("reset".to_string()))
+                }
+                _ => {}
+            }
+
+            let step = interval.duration_millis();
+            let (start, end) = (start_time.unwrap(), end_time.unwrap());
+            let first = (start + step - 1) / step * step;
+            let mut klines: Vec<_> = (first..=end)
+                .step_by(usize::try_from(step).unwrap())
+                .map(|open_time| Kline {
+                    symbol: conversion::string_to_symbol(&symbol),
+                    open_time,
+                    close_time: open_time + step - 1,
+                    interval: "1m".to_string(),
+                    open_price: conversion::string_to_price("1"),
+                    high_price: conversion::string_to_price("1"),
+                    low_price: conversion::string_to_price("1"),
+                    close_price: conversion::string_to_price("1"),
+                    volume: conversion::string_to_volume("1"),
+                    number_of_trades: 1,
+                    final_bar: true,
+                })
+                .collect();
+            klines.reverse();
+            klines.truncate((limit.unwrap() as usize).min(self.cap));
+            Ok(klines)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_pages_retries_and_reports_failures() {
+        let source = ScriptedKlines {
+            flaked: AtomicBool::new(false),
+            cap: usize::MAX,
+        };
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+        let config = PrefetchConfig::default()
+            .with_page_limit(7)
+            .with_requests_per_second(1_000)
+            .with_retries(2, Duration::from_millis(1))
+            .with_progress(progress_tx);
+
+        let report = prefetch_klines(
+            &source,
+            vec![
+                "BTCUSDT".to_string(),
+                "FLAKY".to_string(),
+                "BAD".to_string(),
+            ],
+            KlineInterval::Minutes1,
+            Duration::from_secs(60 * 60),
+            config,
+        )
+        .await;
+
+        for symbol in ["BTCUSDT", "FLAKY"] {
+            let klines = &report.klines[symbol];
+            assert!((59..=60).contains(&klines.len()), "{}", klines.len());
+            assert!(klines
+                .windows(2)
+                .all(|pair| pair[1].open_time - pair[0].open_time == 60_000));
+        }
+        assert!(matches!(
+            report.failures["BAD"],
+            ExchangeError::InvalidParameters(_)
+        ));
+
+        let mut updates = Vec::new();
+        while let Ok(update) = progress_rx.try_recv() {
+            updates.push(update);
+        }
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates[2].completed + updates[2].failed, 3);
+    }
+
+    #[tokio::test]
+    async fn test_range_refetches_what_a_capped_venue_left_out() {
+        let source = ScriptedKlines {
+            flaked: AtomicBool::new(false),
+            cap: 7,
+        };
+        let start = 1_700_000_000_000 / 60_000 * 60_000;
+        let config = PrefetchConfig::default()
+            .with_page_limit(20)
+            .with_requests_per_second(1_000);
+
+        let klines = source
+            .get_klines_range_with(
+                Symbol::from("BTCUSDT"),
+                KlineInterval::Minutes1,
+                start,
+                start + 60 * 60_000,
+                &config,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(klines.len(), 60);
+        assert_eq!(klines[0].open_time, start);
+        assert!(klines
+            .windows(2)
+            .all(|pair| pair[1].open_time - pair[0].open_time == 60_000));
+    }
+}
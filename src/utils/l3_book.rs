@@ -0,0 +1,264 @@
+use crate::core::types::{
+    L3Order, L3Update, OrderBook, OrderBookEntry, OrderBookL3, OrderBookL3Event, OrderSide, Price,
+    Quantity, Symbol,
+};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use thiserror::Error;
+
+/// Update arrived out of order; the book must be rebuilt from a fresh snapshot
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("L3 sequence gap: expected {expected}, received {received}")]
+pub struct SequenceGap {
+    pub expected: i64,
+    pub received: i64,
+}
+
+/// Order-level book maintained from an `OrderBookL3` snapshot and `OrderBookL3Event`s
+///
+/// Keeps FIFO queues per price level so callers can see exactly how much size sits ahead
+/// of a given order.
+#[derive(Debug, Clone)]
+pub struct L3OrderBook {
+    symbol: Symbol,
+    sequence: i64,
+    orders: HashMap<String, L3Order>,
+    bids: BTreeMap<Price, VecDeque<String>>,
+    asks: BTreeMap<Price, VecDeque<String>>,
+}
+
+impl L3OrderBook {
+    pub fn from_snapshot(snapshot: OrderBookL3) -> Self {
+        let mut book = Self {
+            symbol: snapshot.symbol,
+            sequence: snapshot.sequence,
+            orders: HashMap::new(),
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+        };
+        for order in snapshot.bids.into_iter().chain(snapshot.asks) {
+            book.insert(order);
+        }
+        book
+    }
+
+    pub const fn symbol(&self) -> &Symbol {
+        &self.symbol
+    }
+
+    pub const fn sequence(&self) -> i64 {
+        self.sequence
+    }
+
+    pub fn order(&self, order_id: &str) -> Option<&L3Order> {
+        self.orders.get(order_id)
+    }
+
+    /// Apply an update; events at or below the current sequence are ignored
+    pub fn apply(&mut self, event: &OrderBookL3Event) -> Result<(), SequenceGap> {
+        if event.sequence <= self.sequence {
+            return Ok(());
+        }
+        if event.sequence != self.sequence + 1 {
+            return Err(SequenceGap {
+                expected: self.sequence + 1,
+                received: event.sequence,
+            });
+        }
+        self.sequence = event.sequence;
+
+        match &event.update {
+            L3Update::Open(order) => self.insert(order.clone()),
+            L3Update::Change { order_id, quantity } => {
+                if let Some(order) = self.orders.get_mut(order_id) {
+                    order.quantity = *quantity;
+                }
+            }
+            L3Update::Match { order_id, quantity } => {
+                let filled = self.orders.get_mut(order_id).is_some_and(|order| {
+                    order.quantity = Quantity::new(order.quantity.value() - quantity.value());
+                    order.quantity.value() <= Decimal::ZERO
+                });
+                if filled {
+                    self.remove(order_id);
+                }
+            }
+            L3Update::Done { order_id } => self.remove(order_id),
+            L3Update::Unchanged { .. } => {}
+        }
+        Ok(())
+    }
+
+    /// Total size queued ahead of `order_id` at its price level
+    pub fn queue_ahead(&self, order_id: &str) -> Option<Quantity> {
+        let order = self.orders.get(order_id)?;
+        let queue = self.levels(&order.side).get(&order.price)?;
+        let ahead = queue
+            .iter()
+            .take_while(|id| id.as_str() != order_id)
+            .filter_map(|id| self.orders.get(id))
+            .map(|o| o.quantity.value())
+            .sum();
+        Some(Quantity::new(ahead))
+    }
+
+    /// Aggregate into an L2 book with up to `depth` levels per side
+    pub fn to_l2(&self, depth: usize) -> OrderBook {
+        OrderBook {
+            symbol: self.symbol.clone(),
+            bids: self.aggregate(self.bids.iter().rev(), depth),
+            asks: self.aggregate(self.asks.iter(), depth),
+            last_update_id: self.sequence,
+        }
+    }
+
+    fn aggregate<'a>(
+        &self,
+        levels: impl Iterator<Item = (&'a Price, &'a VecDeque<String>)>,
+        depth: usize,
+    ) -> Vec<OrderBookEntry> {
+        levels
+            .take(depth)
+            .map(|(price, queue)| OrderBookEntry {
+                price: *price,
+                quantity: Quantity::new(
+                    queue
+                        .iter()
+                        .filter_map(|id| self.orders.get(id))
+                        .map(|o| o.quantity.value())
+                        .sum(),
+                ),
+            })
+            .collect()
+    }
+
+    const fn levels(&self, side: &OrderSide) -> &BTreeMap<Price, VecDeque<String>> {
+        match side {
+            OrderSide::Buy => &self.bids,
+            OrderSide::Sell => &self.asks,
+        }
+    }
+
+    fn levels_mut(&mut self, side: &OrderSide) -> &mut BTreeMap<Price, VecDeque<String>> {
+        match side {
+            OrderSide::Buy => &mut self.bids,
+            OrderSide::Sell => &mut self.asks,
+        }
+    }
+
+    fn insert(&mut self, order: L3Order) {
+        self.remove(&order.order_id);
+        self.levels_mut(&order.side)
+            .entry(order.price)
+            .or_default()
+            .push_back(order.order_id.clone());
+        self.orders.insert(order.order_id.clone(), order);
+    }
+
+    fn remove(&mut self, order_id: &str) {
+        let Some(order) = self.orders.remove(order_id) else {
+            return;
+        };
+        let levels = self.levels_mut(&order.side);
+        if let Some(queue) = levels.get_mut(&order.price) {
+            queue.retain(|id| id != order_id);
+            if queue.is_empty() {
+                levels.remove(&order.price);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::conversion;
+
+    fn order(id: &str, side: OrderSide, price: &str, quantity: &str) -> L3Order {
+        L3Order {
+            order_id: id.to_string(),
+            side,
+            price: conversion::string_to_price(price),
+            quantity: conversion::string_to_quantity(quantity),
+            timestamp: 0,
+        }
+    }
+
+    fn event(sequence: i64, update: L3Update) -> OrderBookL3Event {
+        OrderBookL3Event {
+            symbol: conversion::string_to_symbol("BTCUSD"),
+            sequence,
+            update,
+        }
+    }
+
+    fn book() -> L3OrderBook {
+        L3OrderBook::from_snapshot(OrderBookL3 {
+            symbol: conversion::string_to_symbol("BTCUSD"),
+            bids: vec![
+                order("a", OrderSide::Buy, "100", "1"),
+                order("b", OrderSide::Buy, "100", "2"),
+                order("c", OrderSide::Buy, "99", "5"),
+            ],
+            asks: vec![order("d", OrderSide::Sell, "101", "3")],
+            sequence: 10,
+        })
+    }
+
+    #[test]
+    fn test_queue_ahead_tracks_fills_and_cancels() {
+        let mut book = book();
+        book.apply(&event(
+            11,
+            L3Update::Open(order("mine", OrderSide::Buy, "100", "1")),
+        ))
+        .unwrap();
+        assert_eq!(
+            book.queue_ahead("mine"),
+            Some(conversion::string_to_quantity("3"))
+        );
+
+        book.apply(&event(
+            12,
+            L3Update::Match {
+                order_id: "a".to_string(),
+                quantity: conversion::string_to_quantity("1"),
+            },
+        ))
+        .unwrap();
+        book.apply(&event(
+            13,
+            L3Update::Done {
+                order_id: "b".to_string(),
+            },
+        ))
+        .unwrap();
+        assert_eq!(book.queue_ahead("mine"), Some(Quantity::ZERO));
+
+        let l2 = book.to_l2(5);
+        assert_eq!(l2.bids[0].quantity, conversion::string_to_quantity("1"));
+        assert_eq!(l2.bids[1].price, conversion::string_to_price("99"));
+        assert_eq!(l2.asks.len(), 1);
+    }
+
+    #[test]
+    fn test_sequence_gap_is_reported() {
+        let mut book = book();
+        let gap = book
+            .apply(&event(
+                12,
+                L3Update::Done {
+                    order_id: "a".to_string(),
+                },
+            ))
+            .unwrap_err();
+        assert_eq!(
+            gap,
+            SequenceGap {
+                expected: 11,
+                received: 12
+            }
+        );
+        assert!(book.order("a").is_some());
+    }
+}
@@ -1,5 +1,8 @@
-use crate::core::traits::MarketDataSource;
+use crate::core::config::ExchangeConfig;
+use crate::core::errors::ExchangeError;
+use crate::core::traits::{ExchangeConnector, MarketDataSource};
 use crate::core::types::KlineInterval;
+use crate::exchanges::factory::{build_connector, ExchangeId};
 use std::time::{Duration, Instant};
 
 /// Configuration for latency tests
@@ -58,6 +61,163 @@ impl LatencyTestConfig {
     }
 }
 
+/// An exchange to run the latency test against
+#[derive(Debug, Clone)]
+pub struct ExchangeTestConfig {
+    pub name: String,
+    pub exchange: ExchangeId,
+    pub testnet: bool,
+    pub base_url: Option<String>,
+    pub requires_auth: bool,
+    pub symbols: Vec<String>,
+}
+
+impl ExchangeTestConfig {
+    fn public(name: &str, exchange: ExchangeId, symbols: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            exchange,
+            testnet: false,
+            base_url: None,
+            requires_auth: false,
+            symbols: symbols.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    /// Read-only connector for this exchange, built through `build_connector`
+    pub fn connector(&self) -> Result<Box<dyn ExchangeConnector>, ExchangeError> {
+        build_connector(
+            self.exchange,
+            ExchangeConfig::read_only().testnet(self.testnet),
+        )
+    }
+}
+
+/// Exchanges that can be tested without credentials
+pub fn default_test_configs() -> Vec<ExchangeTestConfig> {
+    vec![
+        ExchangeTestConfig::public(
+            "Binance Spot",
+            ExchangeId::Binance,
+            &["BTCUSDT", "ETHUSDT", "ADAUSDT"],
+        ),
+        ExchangeTestConfig::public(
+            "Binance Perp",
+            ExchangeId::BinancePerp,
+            &["BTCUSDT", "ETHUSDT", "ADAUSDT"],
+        ),
+        ExchangeTestConfig::public(
+            "Bybit Spot",
+            ExchangeId::Bybit,
+            &["BTCUSDT", "ETHUSDT", "ADAUSDT"],
+        ),
+        ExchangeTestConfig::public(
+            "Bybit Perp",
+            ExchangeId::BybitPerp,
+            &["BTCUSDT", "ETHUSDT", "ADAUSDT"],
+        ),
+        ExchangeTestConfig::public(
+            "Hyperliquid",
+            ExchangeId::Hyperliquid,
+            &["BTC", "ETH", "SOL"],
+        ),
+        ExchangeTestConfig {
+            testnet: true, // Default to testnet for Paradex
+            ..ExchangeTestConfig::public(
+                "Paradex",
+                ExchangeId::Paradex,
+                &["BTC-USD", "ETH-USD", "SOL-USD"],
+            )
+        },
+        // Note: Backpack excluded from default config as it requires valid credentials
+    ]
+}
+
+/// Default configs plus the exchanges whose credentials are set in the environment
+pub fn test_configs_from_env() -> Vec<ExchangeTestConfig> {
+    let mut configs = default_test_configs();
+
+    if ExchangeConfig::from_env("BACKPACK").is_ok() {
+        configs.push(ExchangeTestConfig {
+            requires_auth: true,
+            ..ExchangeTestConfig::public(
+                "Backpack",
+                ExchangeId::Backpack,
+                &["SOL_USDC", "BTC_USDC"],
+            )
+        });
+    }
+
+    if ExchangeConfig::from_env("PARADEX").is_ok() {
+        configs.push(ExchangeTestConfig {
+            testnet: true,
+            requires_auth: true,
+            ..ExchangeTestConfig::public(
+                "Paradex (Auth)",
+                ExchangeId::Paradex,
+                &["BTC-USD", "ETH-USD"],
+            )
+        });
+    }
+
+    configs
+}
+
+/// Builder for custom exchange test configurations
+#[derive(Debug, Default)]
+pub struct ExchangeTestConfigBuilder {
+    configs: Vec<ExchangeTestConfig>,
+}
+
+impl ExchangeTestConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn add_exchange(mut self, name: String, exchange: ExchangeId, testnet: bool) -> Self {
+        let symbols: &[&str] = match exchange {
+            ExchangeId::Hyperliquid => &["BTC", "ETH"],
+            ExchangeId::Backpack => &["SOL_USDC", "BTC_USDC"],
+            ExchangeId::Paradex | ExchangeId::Dydx | ExchangeId::Coinbase => {
+                &["BTC-USD", "ETH-USD"]
+            }
+            ExchangeId::Okx => &["BTC-USDT", "ETH-USDT"],
+            ExchangeId::OkxSwap => &["BTC-USDT-SWAP", "ETH-USDT-SWAP"],
+            ExchangeId::Gateio | ExchangeId::GateioPerp => &["BTC_USDT", "ETH_USDT"],
+            ExchangeId::Kraken => &["BTC/USD", "ETH/USD"],
+            _ => &["BTCUSDT", "ETHUSDT"],
+        };
+
+        self.configs.push(ExchangeTestConfig {
+            testnet,
+            requires_auth: matches!(exchange, ExchangeId::Backpack),
+            ..ExchangeTestConfig::public(&name, exchange, symbols)
+        });
+        self
+    }
+
+    #[must_use]
+    pub fn with_symbols(mut self, symbols: Vec<String>) -> Self {
+        if let Some(last_config) = self.configs.last_mut() {
+            last_config.symbols = symbols;
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        if let Some(last_config) = self.configs.last_mut() {
+            last_config.base_url = Some(base_url);
+        }
+        self
+    }
+
+    pub fn build(self) -> Vec<ExchangeTestConfig> {
+        self.configs
+    }
+}
+
 /// Latency metrics with statistical analysis
 #[derive(Debug, Clone)]
 pub struct LatencyMetrics {
@@ -257,7 +417,7 @@ impl LatencyTester {
                 let start = Instant::now();
                 let result = client
                     .get_klines(
-                        symbol.clone(),
+                        symbol.as_str().into(),
                         KlineInterval::Minutes1,
                         Some(10),
                         None,
@@ -318,7 +478,7 @@ impl LatencyTester {
 
             match client
                 .subscribe_market_data(
-                    vec![test_symbol.to_string()],
+                    vec![test_symbol.into()],
                     vec![crate::core::types::SubscriptionType::Ticker],
                     None,
                 )
@@ -0,0 +1,196 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::{FundingRateSource, StreamingMarketData};
+use crate::core::types::{FundingRate, MarketDataType, SubscriptionType, Ticker};
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::warn;
+
+/// Latest-value view over a market data stream
+///
+/// Consumers that only care about the most recent ticker (UIs, risk checks) can hold a
+/// `watch::Receiver` instead of draining an `mpsc` queue. A background task keeps the
+/// underlying stream drained, so the queue never grows no matter how slowly the
+/// consumer reads. The task exits once every receiver has been dropped.
+#[async_trait]
+pub trait LatestValueExt: StreamingMarketData + Sync {
+    /// Subscribe to the latest ticker for a symbol
+    ///
+    /// Waits for the first ticker so the returned receiver always holds a real value.
+    async fn subscribe_latest(
+        &self,
+        symbol: String,
+    ) -> Result<watch::Receiver<Ticker>, ExchangeError> {
+        let mut stream = self
+            .subscribe_market_data(
+                vec![symbol.as_str().into()],
+                vec![SubscriptionType::Ticker],
+                None,
+            )
+            .await?;
+
+        let first = loop {
+            match stream.recv().await {
+                Some(MarketDataType::Ticker(ticker)) => break ticker,
+                Some(_) => {}
+                None => {
+                    return Err(ExchangeError::WebSocketClosed(format!(
+                        "Ticker stream for {} closed before the first update",
+                        symbol
+                    )));
+                }
+            }
+        };
+
+        let (tx, rx) = watch::channel(first);
+        tokio::spawn(async move {
+            while let Some(data) = stream.recv().await {
+                if let MarketDataType::Ticker(ticker) = data {
+                    if tx.send(ticker).is_err() {
+                        break; // All receivers dropped
+                    }
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
+
+impl<T: StreamingMarketData + Sync + ?Sized> LatestValueExt for T {}
+
+/// Latest-value view over funding/mark price data (PERPETUAL EXCHANGES ONLY)
+///
+/// Mark prices are carried on `FundingRate`, which connectors expose over REST, so the
+/// value is refreshed by polling at `poll_interval`.
+#[async_trait]
+pub trait LatestFundingExt: FundingRateSource + Send + Sync + Clone + 'static {
+    /// Subscribe to the latest funding rate and mark price for a symbol
+    async fn subscribe_latest_mark_price(
+        &self,
+        symbol: String,
+        poll_interval: Duration,
+    ) -> Result<watch::Receiver<FundingRate>, ExchangeError> {
+        let first = fetch_funding_rate(self, &symbol).await?;
+        let (tx, rx) = watch::channel(first);
+
+        let source = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(poll_interval);
+            ticker.tick().await; // First tick completes immediately
+            loop {
+                ticker.tick().await;
+                if tx.is_closed() {
+                    break;
+                }
+                match fetch_funding_rate(&source, &symbol).await {
+                    Ok(rate) => {
+                        if tx.send(rate).is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => warn!("Failed to refresh mark price for {}: {}", symbol, e),
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
+
+impl<T: FundingRateSource + Send + Sync + Clone + 'static> LatestFundingExt for T {}
+
+async fn fetch_funding_rate<S: FundingRateSource + Sync + ?Sized>(
+    source: &S,
+    symbol: &str,
+) -> Result<FundingRate, ExchangeError> {
+    source
+        .get_funding_rates(Some(vec![symbol.into()]))
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ExchangeError::Other(format!("No funding rate returned for {}", symbol)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{conversion, Price, Quantity, Symbol, Trade, Volume, WebSocketConfig};
+    use rust_decimal::Decimal;
+    use tokio::sync::mpsc;
+
+    struct ScriptedSource {
+        prices: Vec<&'static str>,
+    }
+
+    fn ticker(price: &str) -> Ticker {
+        Ticker {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            price: conversion::string_to_price(price),
+            price_change: Price::ZERO,
+            price_change_percent: Decimal::ZERO,
+            high_price: Price::ZERO,
+            low_price: Price::ZERO,
+            volume: Volume::ZERO,
+            quote_volume: Volume::ZERO,
+            open_time: 0,
+            close_time: 0,
+            count: 0,
+        }
+    }
+
+    #[async_trait]
+    impl StreamingMarketData for ScriptedSource {
+        async fn subscribe_market_data(
+            &self,
+            _symbols: Vec<Symbol>,
+            _subscription_types: Vec<SubscriptionType>,
+            _config: Option<WebSocketConfig>,
+        ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+            let (tx, rx) = mpsc::channel(16);
+            tx.send(MarketDataType::Trade(Trade {
+                symbol: conversion::string_to_symbol("BTCUSDT"),
+                id: 1,
+                price: Price::ZERO,
+                quantity: Quantity::ZERO,
+                time: 0,
+                is_buyer_maker: false,
+            }))
+            .await
+            .unwrap();
+            for price in &self.prices {
+                tx.send(MarketDataType::Ticker(ticker(price)))
+                    .await
+                    .unwrap();
+            }
+            Ok(rx)
+        }
+
+        fn get_websocket_url(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_latest_ticker_skips_other_data_and_keeps_newest() {
+        let source = ScriptedSource {
+            prices: vec!["100", "101", "102"],
+        };
+        let mut rx = source
+            .subscribe_latest("BTCUSDT".to_string())
+            .await
+            .unwrap();
+        assert_eq!(rx.borrow().price, conversion::string_to_price("100"));
+
+        // Stream closes after the last update, which closes the watch channel
+        while rx.changed().await.is_ok() {}
+        assert_eq!(rx.borrow().price, conversion::string_to_price("102"));
+    }
+
+    #[tokio::test]
+    async fn test_latest_ticker_errors_when_stream_ends_early() {
+        let source = ScriptedSource { prices: Vec::new() };
+        let result = source.subscribe_latest("BTCUSDT".to_string()).await;
+        assert!(matches!(result, Err(ExchangeError::WebSocketClosed(_))));
+    }
+}
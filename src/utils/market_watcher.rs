@@ -0,0 +1,234 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::{RestMarketData, StreamingMarketData};
+use crate::core::types::{Market, MarketStatus, MarketStatusUpdate, Symbol};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Instrument status transition observed by the market watcher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarketEvent {
+    /// Instrument left the trading state; `status` says where it went
+    MarketHalted {
+        symbol: Symbol,
+        status: MarketStatus,
+    },
+    /// Instrument returned to the trading state
+    MarketResumed {
+        symbol: Symbol,
+        previous: MarketStatus,
+    },
+    /// Any other status change (e.g. pre-trading → closed)
+    StatusChanged {
+        symbol: Symbol,
+        previous: MarketStatus,
+        status: MarketStatus,
+    },
+}
+
+impl MarketEvent {
+    pub const fn symbol(&self) -> &Symbol {
+        match self {
+            Self::MarketHalted { symbol, .. }
+            | Self::MarketResumed { symbol, .. }
+            | Self::StatusChanged { symbol, .. } => symbol,
+        }
+    }
+}
+
+/// Tracks the last known status per instrument and turns changes into `MarketEvent`s
+///
+/// The first status seen for a symbol only seeds the tracker; events are emitted on
+/// subsequent changes. Feed it from `get_markets()` snapshots, venue-pushed
+/// `MarketStatusUpdate`s, or both.
+#[derive(Debug, Default)]
+pub struct MarketStatusTracker {
+    statuses: HashMap<Symbol, MarketStatus>,
+}
+
+impl MarketStatusTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Last known status for a symbol
+    pub fn status(&self, symbol: &Symbol) -> Option<&MarketStatus> {
+        self.statuses.get(symbol)
+    }
+
+    /// Apply a single venue-pushed status update
+    pub fn apply_update(&mut self, update: &MarketStatusUpdate) -> Option<MarketEvent> {
+        self.apply(&update.symbol, update.status.clone())
+    }
+
+    /// Apply a full market snapshot, returning events for every instrument that changed
+    pub fn apply_markets(&mut self, markets: &[Market]) -> Vec<MarketEvent> {
+        markets
+            .iter()
+            .filter_map(|market| self.apply(&market.symbol, market.status.clone()))
+            .collect()
+    }
+
+    fn apply(&mut self, symbol: &Symbol, status: MarketStatus) -> Option<MarketEvent> {
+        let previous = self.statuses.insert(symbol.clone(), status.clone())?;
+        if previous == status {
+            return None;
+        }
+
+        let symbol = symbol.clone();
+        Some(match (previous.is_trading(), status.is_trading()) {
+            (true, false) => MarketEvent::MarketHalted { symbol, status },
+            (false, true) => MarketEvent::MarketResumed { symbol, previous },
+            _ => MarketEvent::StatusChanged {
+                symbol,
+                previous,
+                status,
+            },
+        })
+    }
+}
+
+/// Poll a connector's market list and stream status transitions
+///
+/// Venues that push instrument status changes are better served by
+/// `watch_market_status`; this watcher covers every connector through
+/// `get_markets()`. The task exits once the returned receiver is dropped.
+pub fn watch_markets<S>(source: Arc<S>, poll_interval: Duration) -> mpsc::Receiver<MarketEvent>
+where
+    S: RestMarketData + Send + Sync + ?Sized + 'static,
+{
+    let (tx, rx) = mpsc::channel(256);
+
+    tokio::spawn(async move {
+        let mut tracker = MarketStatusTracker::new();
+        let mut ticker = tokio::time::interval(poll_interval);
+        loop {
+            ticker.tick().await;
+            if tx.is_closed() {
+                break;
+            }
+            let markets = match source.get_markets().await {
+                Ok(markets) => markets,
+                Err(e) => {
+                    warn!("Failed to refresh market status: {}", e);
+                    continue;
+                }
+            };
+            for event in tracker.apply_markets(&markets) {
+                if tx.send(event).await.is_err() {
+                    return; // Receiver dropped
+                }
+            }
+        }
+    });
+
+    rx
+}
+
+/// Stream status transitions from a venue's pushed `MarketStatusUpdate`s
+///
+/// Fails with `NotSupported` on venues without a status feed; fall back to
+/// `watch_markets` there. The task exits once the returned receiver is dropped or the
+/// feed ends.
+pub async fn watch_market_status<S>(
+    source: &S,
+) -> Result<mpsc::Receiver<MarketEvent>, ExchangeError>
+where
+    S: StreamingMarketData + Sync + ?Sized,
+{
+    let mut updates = source.subscribe_market_status().await?;
+    let (tx, rx) = mpsc::channel(256);
+
+    tokio::spawn(async move {
+        let mut tracker = MarketStatusTracker::new();
+        while let Some(update) = updates.recv().await {
+            if let Some(event) = tracker.apply_update(&update) {
+                if tx.send(event).await.is_err() {
+                    return; // Receiver dropped
+                }
+            }
+        }
+    });
+
+    Ok(rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(status: &str) -> MarketStatusUpdate {
+        MarketStatusUpdate {
+            symbol: Symbol::new("BTC", "USDT").unwrap(),
+            status: MarketStatus::from_venue_str(status),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_venue_statuses_are_normalized() {
+        assert_eq!(
+            MarketStatus::from_venue_str("TRADING"),
+            MarketStatus::Trading
+        );
+        assert_eq!(MarketStatus::from_venue_str("live"), MarketStatus::Trading);
+        assert_eq!(
+            MarketStatus::from_venue_str("suspend"),
+            MarketStatus::Halted
+        );
+        assert_eq!(
+            MarketStatus::from_venue_str("PreTrading"),
+            MarketStatus::PreTrading
+        );
+        assert_eq!(
+            MarketStatus::from_venue_str("CANCEL_ONLY"),
+            MarketStatus::Restricted
+        );
+        assert_eq!(
+            MarketStatus::from_venue_str("weird"),
+            MarketStatus::Unknown("weird".to_string())
+        );
+    }
+
+    #[test]
+    fn test_tracker_emits_halt_and_resume() {
+        let mut tracker = MarketStatusTracker::new();
+        assert_eq!(tracker.apply_update(&update("live")), None);
+        assert_eq!(tracker.apply_update(&update("live")), None);
+
+        let halted = tracker.apply_update(&update("suspend")).unwrap();
+        assert!(matches!(
+            halted,
+            MarketEvent::MarketHalted {
+                status: MarketStatus::Halted,
+                ..
+            }
+        ));
+
+        let resumed = tracker.apply_update(&update("live")).unwrap();
+        assert!(matches!(
+            resumed,
+            MarketEvent::MarketResumed {
+                previous: MarketStatus::Halted,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_tracker_reports_non_trading_transitions() {
+        let mut tracker = MarketStatusTracker::new();
+        tracker.apply_update(&update("preopen"));
+        let event = tracker.apply_update(&update("expired")).unwrap();
+        assert_eq!(
+            event,
+            MarketEvent::StatusChanged {
+                symbol: Symbol::new("BTC", "USDT").unwrap(),
+                previous: MarketStatus::PreTrading,
+                status: MarketStatus::Closed,
+            }
+        );
+    }
+}
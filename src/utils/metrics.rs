@@ -0,0 +1,648 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::{RateLimitStatus, RestClient};
+use crate::core::traits::OrderPlacer;
+use crate::core::types::{OrderRequest, OrderResponse, Symbol};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, OpenOptions};
+use std::future::Future;
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+/// Weight of the newest sample in the rolling latency and error rate averages
+const HEALTH_SMOOTHING: f64 = 0.2;
+
+fn smooth(average: Option<f64>, sample: f64) -> f64 {
+    average.map_or(sample, |average| {
+        HEALTH_SMOOTHING.mul_add(sample - average, average)
+    })
+}
+
+/// Recent latency and error rate of one exchange
+///
+/// Values are exponentially weighted so roughly the last ten samples dominate.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VenueHealth {
+    pub rest_latency: Option<Duration>,
+    pub ws_latency: Option<Duration>,
+    /// Share of recent requests that failed, from 0 to 1
+    pub error_rate: f64,
+}
+
+#[derive(Debug, Default)]
+struct RollingHealth {
+    rest_latency_secs: Option<f64>,
+    ws_latency_secs: Option<f64>,
+    error_rate: Option<f64>,
+}
+
+/// Cumulative counters for one exchange
+///
+/// Update them directly, or wrap the venue's REST client in `MeteredRest` and its
+/// connector in `MeteredPlacer` to have requests and orders counted automatically.
+#[derive(Debug, Default)]
+pub struct ExchangeMetrics {
+    requests: AtomicU64,
+    request_errors: AtomicU64,
+    orders: AtomicU64,
+    order_errors: AtomicU64,
+    ws_messages: AtomicU64,
+    reconnects: AtomicU64,
+    health: Mutex<RollingHealth>,
+}
+
+impl ExchangeMetrics {
+    pub fn record_request(&self, ok: bool) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        if !ok {
+            self.request_errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.with_health(|health| {
+            health.error_rate = Some(smooth(health.error_rate, if ok { 0.0 } else { 1.0 }));
+        });
+    }
+
+    /// Count a request and fold its round-trip time into the rolling REST latency
+    pub fn record_request_latency(&self, latency: Duration, ok: bool) {
+        self.record_request(ok);
+        self.with_health(|health| {
+            health.rest_latency_secs =
+                Some(smooth(health.rest_latency_secs, latency.as_secs_f64()));
+        });
+    }
+
+    /// Fold the delay between a venue event and its arrival into the rolling WS latency
+    pub fn record_ws_latency(&self, latency: Duration) {
+        self.with_health(|health| {
+            health.ws_latency_secs = Some(smooth(health.ws_latency_secs, latency.as_secs_f64()));
+        });
+    }
+
+    pub fn health(&self) -> VenueHealth {
+        self.with_health(|health| VenueHealth {
+            rest_latency: health.rest_latency_secs.map(Duration::from_secs_f64),
+            ws_latency: health.ws_latency_secs.map(Duration::from_secs_f64),
+            error_rate: health.error_rate.unwrap_or_default(),
+        })
+    }
+
+    fn with_health<T>(&self, f: impl FnOnce(&mut RollingHealth) -> T) -> T {
+        let mut health = self
+            .health
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        f(&mut health)
+    }
+
+    pub fn record_order(&self, ok: bool) {
+        self.orders.fetch_add(1, Ordering::Relaxed);
+        if !ok {
+            self.order_errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn record_ws_messages(&self, count: u64) {
+        self.ws_messages.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub fn record_reconnect(&self) {
+        self.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self, exchange: &str, timestamp: i64) -> MetricsSnapshot {
+        MetricsSnapshot {
+            timestamp,
+            exchange: exchange.to_string(),
+            requests: self.requests.load(Ordering::Relaxed),
+            request_errors: self.request_errors.load(Ordering::Relaxed),
+            orders: self.orders.load(Ordering::Relaxed),
+            order_errors: self.order_errors.load(Ordering::Relaxed),
+            ws_messages: self.ws_messages.load(Ordering::Relaxed),
+            reconnects: self.reconnects.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Point-in-time copy of an exchange's cumulative counters
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    /// Milliseconds since the Unix epoch
+    pub timestamp: i64,
+    pub exchange: String,
+    pub requests: u64,
+    pub request_errors: u64,
+    pub orders: u64,
+    pub order_errors: u64,
+    pub ws_messages: u64,
+    pub reconnects: u64,
+}
+
+/// Activity for one exchange over one UTC day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyRollup {
+    /// UTC day as `YYYY-MM-DD`
+    pub date: String,
+    pub exchange: String,
+    pub snapshots: usize,
+    pub requests: u64,
+    pub request_errors: u64,
+    /// `request_errors / requests`, zero when there were no requests
+    pub error_rate: f64,
+    pub orders: u64,
+    pub order_errors: u64,
+    pub ws_messages: u64,
+    pub reconnects: u64,
+}
+
+/// Shared per-exchange counters
+#[derive(Debug, Clone, Default)]
+pub struct MetricsRegistry {
+    exchanges: Arc<Mutex<HashMap<String, Arc<ExchangeMetrics>>>>,
+}
+
+impl MetricsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counters for `exchange`, created on first use
+    pub fn exchange(&self, exchange: &str) -> Arc<ExchangeMetrics> {
+        let mut exchanges = self
+            .exchanges
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        exchanges.entry(exchange.to_string()).or_default().clone()
+    }
+
+    /// Rolling health of `exchange`, or `None` if nothing was recorded for it yet
+    pub fn health(&self, exchange: &str) -> Option<VenueHealth> {
+        self.exchanges
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(exchange)
+            .map(|metrics| metrics.health())
+    }
+
+    pub fn snapshot(&self, timestamp: i64) -> Vec<MetricsSnapshot> {
+        let mut snapshots: Vec<_> = self
+            .exchanges
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .map(|(exchange, metrics)| metrics.snapshot(exchange, timestamp))
+            .collect();
+        snapshots.sort_by(|a, b| a.exchange.cmp(&b.exchange));
+        snapshots
+    }
+}
+
+/// Snapshot and rollup files in a local directory
+///
+/// Snapshots are appended as JSON lines to `snapshots-YYYY-MM-DD.jsonl` (UTC) and
+/// rollups are written to `rollup-YYYY-MM-DD.json`, so the store can be inspected or
+/// shipped with ordinary file tools.
+#[derive(Debug, Clone)]
+pub struct MetricsStore {
+    dir: PathBuf,
+}
+
+impl MetricsStore {
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self, ExchangeError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|e| {
+            ExchangeError::Other(format!("Failed to create {}: {}", dir.display(), e))
+        })?;
+        Ok(Self { dir })
+    }
+
+    pub fn append(&self, snapshots: &[MetricsSnapshot]) -> Result<(), ExchangeError> {
+        let mut by_day: BTreeMap<NaiveDate, Vec<&MetricsSnapshot>> = BTreeMap::new();
+        for snapshot in snapshots {
+            by_day
+                .entry(date_of(snapshot.timestamp))
+                .or_default()
+                .push(snapshot);
+        }
+
+        for (date, snapshots) in by_day {
+            let path = self.snapshot_path(date);
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .map_err(|e| io_error(&path, &e))?;
+            for snapshot in snapshots {
+                let line = serde_json::to_string(snapshot)?;
+                writeln!(file, "{}", line).map_err(|e| io_error(&path, &e))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// All snapshots recorded on `date`, in file order
+    pub fn load_day(&self, date: NaiveDate) -> Result<Vec<MetricsSnapshot>, ExchangeError> {
+        let path = self.snapshot_path(date);
+        let file = match fs::File::open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(io_error(&path, &e)),
+        };
+
+        let mut snapshots = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(|e| io_error(&path, &e))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(&line) {
+                Ok(snapshot) => snapshots.push(snapshot),
+                // A crash mid-write leaves a torn last line; skip it
+                Err(e) => warn!(path = %path.display(), error = %e, "Skipping bad snapshot"),
+            }
+        }
+        Ok(snapshots)
+    }
+
+    /// Roll up `date` per exchange and write the rollup file
+    pub fn rollup_day(&self, date: NaiveDate) -> Result<Vec<DailyRollup>, ExchangeError> {
+        let rollups = rollup(date, &self.load_day(date)?);
+        let path = self.dir.join(format!("rollup-{}.json", date));
+        let json = serde_json::to_string_pretty(&rollups)?;
+        fs::write(&path, json).map_err(|e| io_error(&path, &e))?;
+        Ok(rollups)
+    }
+
+    fn snapshot_path(&self, date: NaiveDate) -> PathBuf {
+        self.dir.join(format!("snapshots-{}.jsonl", date))
+    }
+}
+
+/// Compute daily totals from cumulative snapshots
+///
+/// Totals are the sum of increases between consecutive snapshots of each exchange. A
+/// counter that goes backwards is treated as a process restart, so its new value
+/// counts in full. Activity before the day's first snapshot is not included.
+#[allow(clippy::cast_precision_loss)]
+pub fn rollup(date: NaiveDate, snapshots: &[MetricsSnapshot]) -> Vec<DailyRollup> {
+    let mut by_exchange: BTreeMap<&str, Vec<&MetricsSnapshot>> = BTreeMap::new();
+    for snapshot in snapshots {
+        by_exchange
+            .entry(snapshot.exchange.as_str())
+            .or_default()
+            .push(snapshot);
+    }
+
+    by_exchange
+        .into_iter()
+        .map(|(exchange, mut snapshots)| {
+            snapshots.sort_by_key(|snapshot| snapshot.timestamp);
+            let total = |field: fn(&MetricsSnapshot) -> u64| -> u64 {
+                snapshots
+                    .windows(2)
+                    .map(|pair| {
+                        let (previous, current) = (field(pair[0]), field(pair[1]));
+                        current.checked_sub(previous).unwrap_or(current)
+                    })
+                    .sum()
+            };
+
+            let requests = total(|s| s.requests);
+            let request_errors = total(|s| s.request_errors);
+            DailyRollup {
+                date: date.to_string(),
+                exchange: exchange.to_string(),
+                snapshots: snapshots.len(),
+                requests,
+                request_errors,
+                error_rate: if requests == 0 {
+                    0.0
+                } else {
+                    request_errors as f64 / requests as f64
+                },
+                orders: total(|s| s.orders),
+                order_errors: total(|s| s.order_errors),
+                ws_messages: total(|s| s.ws_messages),
+                reconnects: total(|s| s.reconnects),
+            }
+        })
+        .collect()
+}
+
+/// Periodically persist registry snapshots
+///
+/// On the first tick of each new UTC day the previous day is rolled up. Store errors
+/// are logged and the task keeps running; abort the handle to stop it.
+pub fn spawn_snapshotter(
+    registry: MetricsRegistry,
+    store: MetricsStore,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        let mut current_day: Option<NaiveDate> = None;
+
+        loop {
+            ticker.tick().await;
+            let now = Utc::now().timestamp_millis();
+            let today = date_of(now);
+
+            if let Err(e) = store.append(&registry.snapshot(now)) {
+                warn!(error = %e, "Failed to persist metrics snapshot");
+            }
+
+            if let Some(previous) = current_day.filter(|day| *day != today) {
+                if let Err(e) = store.rollup_day(previous) {
+                    warn!(date = %previous, error = %e, "Failed to roll up metrics");
+                }
+            }
+            current_day = Some(today);
+        }
+    })
+}
+
+fn date_of(timestamp: i64) -> NaiveDate {
+    DateTime::from_timestamp_millis(timestamp)
+        .unwrap_or_default()
+        .date_naive()
+}
+
+fn io_error(path: &std::path::Path, error: &std::io::Error) -> ExchangeError {
+    ExchangeError::Other(format!(
+        "Metrics store I/O on {}: {}",
+        path.display(),
+        error
+    ))
+}
+
+/// `RestClient` wrapper that counts requests and failures and times each request
+#[derive(Debug, Clone)]
+pub struct MeteredRest<R: RestClient> {
+    inner: R,
+    metrics: Arc<ExchangeMetrics>,
+}
+
+impl<R: RestClient> MeteredRest<R> {
+    pub fn new(inner: R, metrics: Arc<ExchangeMetrics>) -> Self {
+        Self { inner, metrics }
+    }
+
+    async fn record<T>(
+        &self,
+        request: impl Future<Output = Result<T, ExchangeError>> + Send,
+    ) -> Result<T, ExchangeError> {
+        let started = Instant::now();
+        let result = request.await;
+        self.metrics
+            .record_request_latency(started.elapsed(), result.is_ok());
+        result
+    }
+}
+
+#[async_trait]
+impl<R: RestClient> RestClient for MeteredRest<R> {
+    async fn get(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.record(self.inner.get(endpoint, query_params, authenticated))
+            .await
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.record(self.inner.get_json(endpoint, query_params, authenticated))
+            .await
+    }
+
+    async fn post(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.record(self.inner.post(endpoint, body, authenticated))
+            .await
+    }
+
+    async fn post_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.record(self.inner.post_json(endpoint, body, authenticated))
+            .await
+    }
+
+    async fn put(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.record(self.inner.put(endpoint, body, authenticated))
+            .await
+    }
+
+    async fn put_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &Value,
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.record(self.inner.put_json(endpoint, body, authenticated))
+            .await
+    }
+
+    async fn delete(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<Value, ExchangeError> {
+        self.record(self.inner.delete(endpoint, query_params, authenticated))
+            .await
+    }
+
+    async fn delete_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        authenticated: bool,
+    ) -> Result<T, ExchangeError> {
+        self.record(
+            self.inner
+                .delete_json(endpoint, query_params, authenticated),
+        )
+        .await
+    }
+
+    async fn signed_request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        body: &[u8],
+    ) -> Result<Value, ExchangeError> {
+        self.record(
+            self.inner
+                .signed_request(method, endpoint, query_params, body),
+        )
+        .await
+    }
+
+    async fn signed_request_json<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query_params: &[(&str, &str)],
+        body: &[u8],
+    ) -> Result<T, ExchangeError> {
+        self.record(
+            self.inner
+                .signed_request_json(method, endpoint, query_params, body),
+        )
+        .await
+    }
+
+    fn can_sign(&self) -> bool {
+        self.inner.can_sign()
+    }
+
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.inner.rate_limit_status()
+    }
+}
+
+/// `OrderPlacer` wrapper that counts order submissions and rejections
+pub struct MeteredPlacer<P> {
+    inner: P,
+    metrics: Arc<ExchangeMetrics>,
+}
+
+impl<P: OrderPlacer> MeteredPlacer<P> {
+    pub fn new(inner: P, metrics: Arc<ExchangeMetrics>) -> Self {
+        Self { inner, metrics }
+    }
+
+    pub const fn inner(&self) -> &P {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl<P: OrderPlacer + Send + Sync> OrderPlacer for MeteredPlacer<P> {
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let result = self.inner.place_order(order).await;
+        self.metrics.record_order(result.is_ok());
+        result
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        self.inner.cancel_order(symbol, order_id).await
+    }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.inner
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+
+    async fn modify_order(
+        &self,
+        order_id: String,
+        order: OrderRequest,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let result = self.inner.modify_order(order_id, order).await;
+        self.metrics.record_order(result.is_ok());
+        result
+    }
+
+    fn supports_trading(&self) -> bool {
+        self.inner.supports_trading()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(timestamp: i64, requests: u64, request_errors: u64) -> MetricsSnapshot {
+        MetricsSnapshot {
+            timestamp,
+            exchange: "binance".to_string(),
+            requests,
+            request_errors,
+            orders: 0,
+            order_errors: 0,
+            ws_messages: 0,
+            reconnects: 0,
+        }
+    }
+
+    #[test]
+    fn test_rollup_sums_increases_across_restarts() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        let snapshots = [
+            snapshot(1, 100, 0),
+            snapshot(2, 150, 5),
+            // Process restarted: counters start over
+            snapshot(3, 30, 1),
+            snapshot(4, 50, 1),
+        ];
+
+        let [rollup] = &rollup(date, &snapshots)[..] else {
+            panic!("expected one exchange");
+        };
+        assert_eq!(rollup.snapshots, 4);
+        assert_eq!(rollup.requests, 50 + 30 + 20);
+        assert_eq!(rollup.request_errors, 6);
+        assert!((rollup.error_rate - 0.06).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_store_round_trip_and_rollup_file() {
+        let dir = std::env::temp_dir().join(format!("lotusx-metrics-{}", std::process::id()));
+        let store = MetricsStore::open(&dir).unwrap();
+
+        let registry = MetricsRegistry::new();
+        let metrics = registry.exchange("okx");
+        // 2024-01-02T00:00:00Z
+        let midnight = 1_704_153_600_000;
+        store.append(&registry.snapshot(midnight)).unwrap();
+        metrics.record_request(true);
+        metrics.record_request(false);
+        metrics.record_order(true);
+        store.append(&registry.snapshot(midnight + 60_000)).unwrap();
+
+        let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        assert_eq!(store.load_day(date).unwrap().len(), 2);
+
+        let rollups = store.rollup_day(date).unwrap();
+        assert_eq!(rollups[0].requests, 2);
+        assert_eq!(rollups[0].orders, 1);
+        assert!(dir.join("rollup-2024-01-02.json").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
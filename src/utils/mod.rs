@@ -1,5 +1,36 @@
 // Utility functions for the exchange connectors
 // Future: rate limiting, HTTP client utilities, etc.
 
+pub mod amend_queue;
+pub mod anomaly;
+pub mod book_engine;
+pub mod calendar;
+pub mod carry;
+pub mod conformance;
+pub mod drift;
 pub mod exchange_factory;
+pub mod execution_quality;
+pub mod failover;
+pub mod funding;
+pub mod journal;
+pub mod kline_prefetch;
+pub mod l3_book;
 pub mod latency_testing;
+pub mod latest_value;
+pub mod market_watcher;
+pub mod metrics;
+#[cfg(feature = "notifications")]
+pub mod notifications;
+pub mod order_pacer;
+pub mod paper;
+pub mod queue_position;
+pub mod recorder;
+pub mod risk;
+pub mod rollover;
+pub mod router;
+pub mod scoped_orders;
+pub mod symbol_filter;
+#[cfg(feature = "ta")]
+pub mod ta;
+pub mod warmup;
+pub mod webhook;
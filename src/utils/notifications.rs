@@ -0,0 +1,372 @@
+use crate::core::config::ConfigError;
+use crate::core::errors::ExchangeError;
+use crate::core::types::{OrderSide, Symbol};
+use crate::utils::failover::FeedEvent;
+use crate::utils::paper::Fill;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use secrecy::{ExposeSecret, Secret};
+use serde::Deserialize;
+use std::fmt;
+use std::path::Path;
+use tracing::warn;
+
+/// Alerts that need a human's attention
+#[derive(Debug, Clone)]
+pub enum RiskEvent {
+    KillSwitch {
+        reason: String,
+    },
+    /// Local and venue state disagree
+    ReconcileDiscrepancy {
+        exchange: String,
+        symbol: Symbol,
+        expected: Decimal,
+        actual: Decimal,
+    },
+    StreamStale {
+        exchange: String,
+        reason: String,
+    },
+    LargeFill {
+        exchange: String,
+        symbol: Symbol,
+        side: OrderSide,
+        quantity: Decimal,
+        price: Decimal,
+    },
+}
+
+impl fmt::Display for RiskEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::KillSwitch { reason } => write!(f, "🛑 Kill switch triggered: {}", reason),
+            Self::ReconcileDiscrepancy {
+                exchange,
+                symbol,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "⚠️ Reconcile mismatch on {} {}: expected {}, venue reports {}",
+                exchange, symbol, expected, actual
+            ),
+            Self::StreamStale { exchange, reason } => {
+                write!(f, "📉 {} market data stale: {}", exchange, reason)
+            }
+            Self::LargeFill {
+                exchange,
+                symbol,
+                side,
+                quantity,
+                price,
+            } => write!(
+                f,
+                "💰 Large fill on {}: {:?} {} {} @ {} (notional {})",
+                exchange,
+                side,
+                quantity,
+                symbol,
+                price,
+                quantity * price
+            ),
+        }
+    }
+}
+
+/// Destination for notification text
+#[async_trait]
+pub trait NotificationSink: Send + Sync {
+    async fn send(&self, text: &str) -> Result<(), ExchangeError>;
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TelegramConfig {
+    pub bot_token: Secret<String>,
+    pub chat_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SlackConfig {
+    /// Incoming webhook URL
+    pub webhook_url: Secret<String>,
+}
+
+/// `[notifications]` table of the app config file
+///
+/// ```toml
+/// [notifications]
+/// large_fill_notional = "50000"
+///
+/// [notifications.telegram]
+/// bot_token = "123:abc"
+/// chat_id = "-100200300"
+///
+/// [notifications.slack]
+/// webhook_url = "https://hooks.slack.com/services/..."
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NotificationsConfig {
+    pub telegram: Option<TelegramConfig>,
+    pub slack: Option<SlackConfig>,
+    /// Fills at or above this quote notional raise `RiskEvent::LargeFill`
+    #[serde(default)]
+    pub large_fill_notional: Option<Decimal>,
+}
+
+impl NotificationsConfig {
+    /// Read the `[notifications]` table, defaulting to no sinks when it is absent
+    pub fn from_toml_str(text: &str) -> Result<Self, ConfigError> {
+        #[derive(Deserialize)]
+        struct AppConfig {
+            #[serde(default)]
+            notifications: NotificationsConfig,
+        }
+
+        toml::from_str::<AppConfig>(text)
+            .map(|config| config.notifications)
+            .map_err(|e| ConfigError::InvalidConfiguration(format!("notifications: {}", e)))
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|_| ConfigError::FileNotFound(path.display().to_string()))?;
+        Self::from_toml_str(&text)
+    }
+}
+
+pub struct TelegramSink {
+    client: reqwest::Client,
+    config: TelegramConfig,
+    api_base: String,
+}
+
+impl TelegramSink {
+    pub fn new(config: TelegramConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            config,
+            api_base: "https://api.telegram.org".to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into();
+        self
+    }
+}
+
+#[async_trait]
+impl NotificationSink for TelegramSink {
+    async fn send(&self, text: &str) -> Result<(), ExchangeError> {
+        let url = format!(
+            "{}/bot{}/sendMessage",
+            self.api_base,
+            self.config.bot_token.expose_secret()
+        );
+        let response = self
+            .client
+            .post(url)
+            .json(&serde_json::json!({ "chat_id": self.config.chat_id, "text": text }))
+            .send()
+            .await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(ExchangeError::ServerError(format!(
+                "Telegram returned {}",
+                response.status()
+            )))
+        }
+    }
+}
+
+pub struct SlackSink {
+    client: reqwest::Client,
+    config: SlackConfig,
+}
+
+impl SlackSink {
+    pub fn new(config: SlackConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationSink for SlackSink {
+    async fn send(&self, text: &str) -> Result<(), ExchangeError> {
+        let response = self
+            .client
+            .post(self.config.webhook_url.expose_secret())
+            .json(&serde_json::json!({ "text": text }))
+            .send()
+            .await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(ExchangeError::ServerError(format!(
+                "Slack returned {}",
+                response.status()
+            )))
+        }
+    }
+}
+
+/// Fans `RiskEvent`s out to every configured sink
+///
+/// Delivery is best effort: a failing sink is logged and the others still receive the
+/// event.
+#[derive(Default)]
+pub struct Notifier {
+    sinks: Vec<Box<dyn NotificationSink>>,
+    large_fill_notional: Option<Decimal>,
+}
+
+impl Notifier {
+    pub fn from_config(config: NotificationsConfig) -> Self {
+        let mut notifier = Self {
+            large_fill_notional: config.large_fill_notional,
+            ..Self::default()
+        };
+        if let Some(telegram) = config.telegram {
+            notifier.sinks.push(Box::new(TelegramSink::new(telegram)));
+        }
+        if let Some(slack) = config.slack {
+            notifier.sinks.push(Box::new(SlackSink::new(slack)));
+        }
+        notifier
+    }
+
+    #[must_use]
+    pub fn with_sink(mut self, sink: impl NotificationSink + 'static) -> Self {
+        self.sinks.push(Box::new(sink));
+        self
+    }
+
+    #[must_use]
+    pub const fn with_large_fill_notional(mut self, notional: Decimal) -> Self {
+        self.large_fill_notional = Some(notional);
+        self
+    }
+
+    pub async fn notify(&self, event: &RiskEvent) {
+        let text = event.to_string();
+        for sink in &self.sinks {
+            if let Err(e) = sink.send(&text).await {
+                warn!(error = %e, "Failed to deliver notification");
+            }
+        }
+    }
+
+    /// Raise `LargeFill` when a fill reaches the configured notional
+    pub async fn on_fill(&self, exchange: &str, fill: &Fill) {
+        let Some(threshold) = self.large_fill_notional else {
+            return;
+        };
+        let (quantity, price) = (fill.quantity.value(), fill.price.value());
+        if quantity * price >= threshold {
+            self.notify(&RiskEvent::LargeFill {
+                exchange: exchange.to_string(),
+                symbol: fill.symbol.clone(),
+                side: fill.side.clone(),
+                quantity,
+                price,
+            })
+            .await;
+        }
+    }
+
+    /// Raise `StreamStale` when a failover feed degrades to REST polling
+    pub async fn on_feed_event(&self, exchange: &str, event: &FeedEvent) {
+        if let FeedEvent::Degraded { reason } = event {
+            self.notify(&RiskEvent::StreamStale {
+                exchange: exchange.to_string(),
+                reason: reason.clone(),
+            })
+            .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{Price, Quantity};
+    use crate::utils::paper::Liquidity;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl NotificationSink for Recorder {
+        async fn send(&self, text: &str) -> Result<(), ExchangeError> {
+            self.0.lock().unwrap().push(text.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_reads_notifications_table() {
+        let config = NotificationsConfig::from_toml_str(
+            r#"
+            [exchange]
+            name = "binance"
+
+            [notifications]
+            large_fill_notional = "50000"
+
+            [notifications.slack]
+            webhook_url = "https://hooks.slack.com/services/T/B/X"
+            "#,
+        )
+        .unwrap();
+        assert!(config.telegram.is_none());
+        assert!(config.slack.is_some());
+        assert_eq!(config.large_fill_notional, Some(Decimal::from(50_000)));
+
+        let empty = NotificationsConfig::from_toml_str("").unwrap();
+        assert!(empty.slack.is_none() && empty.large_fill_notional.is_none());
+        assert!(NotificationsConfig::from_toml_str("[notifications.telegram]").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_large_fills_and_stale_feeds_are_reported() {
+        let recorder = Recorder::default();
+        let notifier = Notifier::default()
+            .with_sink(recorder.clone())
+            .with_large_fill_notional(Decimal::from(10_000));
+
+        let fill = |quantity: u32| Fill {
+            order_id: "1".to_string(),
+            symbol: Symbol::new("BTC", "USDT").unwrap(),
+            side: OrderSide::Sell,
+            price: Price::new(Decimal::from(50_000)),
+            quantity: Quantity::new(Decimal::from(quantity) / Decimal::from(10)),
+            fee: Decimal::ZERO,
+            liquidity: Liquidity::Taker,
+            time: 0,
+        };
+        notifier.on_fill("okx", &fill(1)).await;
+        notifier.on_fill("okx", &fill(2)).await;
+        notifier.on_feed_event("okx", &FeedEvent::Recovered).await;
+        notifier
+            .on_feed_event(
+                "okx",
+                &FeedEvent::Degraded {
+                    reason: "no data for 10s".to_string(),
+                },
+            )
+            .await;
+
+        let sent = recorder.0.lock().unwrap().clone();
+        assert_eq!(sent.len(), 2);
+        assert!(sent[0].contains("Large fill on okx") && sent[0].contains("notional 10000"));
+        assert!(sent[1].contains("okx market data stale: no data for 10s"));
+    }
+}
@@ -0,0 +1,411 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::OrderPlacer;
+use crate::core::types::{OrderRequest, OrderResponse, Symbol};
+use crate::exchanges::factory::ExchangeId;
+use async_trait::async_trait;
+use governor::{DefaultKeyedRateLimiter, Quota, RateLimiter};
+use nonzero_ext::nonzero;
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::warn;
+
+/// Order endpoints a pacing rule can cover
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderEndpoint {
+    Place,
+    Cancel,
+    Modify,
+}
+
+/// What happens to a call that would exceed a limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacePolicy {
+    /// Wait for capacity, failing if none frees up within `max_wait`
+    Queue { max_wait: Duration },
+    /// Fail at once with `RateLimitExceeded`
+    Drop,
+}
+
+impl Default for PacePolicy {
+    fn default() -> Self {
+        Self::Queue {
+            max_wait: Duration::from_secs(1),
+        }
+    }
+}
+
+/// At most `max` calls to `endpoints` in any `window`
+#[derive(Debug, Clone)]
+pub struct PaceRule {
+    pub endpoints: Vec<OrderEndpoint>,
+    pub max: NonZeroU32,
+    pub window: Duration,
+    /// Count each symbol separately instead of the whole account
+    pub per_symbol: bool,
+}
+
+impl PaceRule {
+    pub fn new(endpoints: &[OrderEndpoint], max: u32, window: Duration) -> Self {
+        Self {
+            endpoints: endpoints.to_vec(),
+            max: NonZeroU32::new(max).unwrap_or_else(|| nonzero!(1u32)),
+            window,
+            per_symbol: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn per_symbol(mut self) -> Self {
+        self.per_symbol = true;
+        self
+    }
+
+    fn quota(&self) -> Quota {
+        let period = (self.window / self.max.get()).max(Duration::from_nanos(1));
+        Quota::with_period(period)
+            .unwrap_or_else(|| Quota::per_second(self.max))
+            .allow_burst(self.max)
+    }
+}
+
+/// Settings for `OrderPacer`
+#[derive(Debug, Clone, Default)]
+pub struct PacerConfig {
+    pub rules: Vec<PaceRule>,
+    pub policy: PacePolicy,
+}
+
+impl PacerConfig {
+    pub fn new(rules: Vec<PaceRule>) -> Self {
+        Self {
+            rules,
+            policy: PacePolicy::default(),
+        }
+    }
+
+    /// Published order limits for `exchange`
+    ///
+    /// Backpack publishes no order limit, and Kraken and dYdX meter orders with decaying
+    /// counters and per-block quotas rather than fixed windows, so their presets have no
+    /// rules and never pace.
+    pub fn for_exchange(exchange: ExchangeId) -> Self {
+        use OrderEndpoint::{Cancel, Modify, Place};
+        let second = Duration::from_secs(1);
+        let rules = match exchange {
+            // Cancels only cost request weight
+            ExchangeId::Binance | ExchangeId::BinanceMargin => vec![
+                PaceRule::new(&[Place, Modify], 50, Duration::from_secs(10)),
+                PaceRule::new(&[Place, Modify], 160_000, Duration::from_secs(86_400)),
+            ],
+            ExchangeId::BinancePerp => vec![
+                PaceRule::new(&[Place, Modify], 300, Duration::from_secs(10)),
+                PaceRule::new(&[Place, Modify], 1200, Duration::from_secs(60)),
+            ],
+            ExchangeId::Bybit => [Place, Cancel, Modify]
+                .iter()
+                .map(|endpoint| PaceRule::new(&[*endpoint], 20, second))
+                .collect(),
+            ExchangeId::BybitPerp => [Place, Cancel, Modify]
+                .iter()
+                .map(|endpoint| PaceRule::new(&[*endpoint], 10, second))
+                .collect(),
+            ExchangeId::Okx | ExchangeId::OkxSwap => [Place, Cancel, Modify]
+                .iter()
+                .map(|endpoint| {
+                    PaceRule::new(&[*endpoint], 60, Duration::from_secs(2)).per_symbol()
+                })
+                .collect(),
+            ExchangeId::Hyperliquid => vec![PaceRule::new(
+                &[Place, Cancel, Modify],
+                1200,
+                Duration::from_secs(60),
+            )],
+            ExchangeId::Paradex => vec![PaceRule::new(&[Place, Cancel, Modify], 800, second)],
+            ExchangeId::Coinbase => vec![PaceRule::new(&[Place, Cancel, Modify], 30, second)],
+            ExchangeId::Gateio => vec![PaceRule::new(&[Place, Modify], 10, second).per_symbol()],
+            ExchangeId::GateioPerp => vec![PaceRule::new(&[Place, Modify], 100, second)],
+            ExchangeId::Backpack | ExchangeId::Kraken | ExchangeId::Dydx => Vec::new(),
+        };
+        Self::new(rules)
+    }
+
+    #[must_use]
+    pub const fn with_policy(mut self, policy: PacePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    #[must_use]
+    pub fn with_rule(mut self, rule: PaceRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+/// Counters kept by an `OrderPacer`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PacerStats {
+    /// Calls let through, including those that waited
+    pub admitted: u64,
+    /// Calls that waited for capacity before going through
+    pub queued: u64,
+    /// Calls refused by the `Drop` policy or after waiting too long
+    pub dropped: u64,
+}
+
+/// Client-side guard that keeps order traffic under venue limits
+///
+/// Every rule covering an endpoint must have capacity before a call goes through.
+/// Capacity refills evenly over each rule's window, so bursts up to `max` are allowed
+/// and sustained traffic is spread out rather than arriving in blocks.
+pub struct OrderPacer {
+    rules: Vec<(PaceRule, DefaultKeyedRateLimiter<String>)>,
+    policy: PacePolicy,
+    admitted: AtomicU64,
+    queued: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl OrderPacer {
+    pub fn new(config: PacerConfig) -> Self {
+        Self {
+            rules: config
+                .rules
+                .into_iter()
+                .map(|rule| {
+                    let limiter = RateLimiter::keyed(rule.quota());
+                    (rule, limiter)
+                })
+                .collect(),
+            policy: config.policy,
+            admitted: AtomicU64::new(0),
+            queued: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Take capacity for one call to `endpoint` on `symbol`, applying the policy
+    pub async fn acquire(
+        &self,
+        endpoint: OrderEndpoint,
+        symbol: &Symbol,
+    ) -> Result<(), ExchangeError> {
+        let deadline = match self.policy {
+            PacePolicy::Queue { max_wait } => Some(Instant::now() + max_wait),
+            PacePolicy::Drop => None,
+        };
+        let mut waited = false;
+
+        for (rule, limiter) in &self.rules {
+            if !rule.endpoints.contains(&endpoint) {
+                continue;
+            }
+            let key = if rule.per_symbol {
+                symbol.to_string()
+            } else {
+                String::new()
+            };
+            if limiter.check_key(&key).is_ok() {
+                continue;
+            }
+
+            let ready = match deadline {
+                Some(deadline) => {
+                    waited = true;
+                    tokio::time::timeout_at(deadline, limiter.until_key_ready(&key))
+                        .await
+                        .is_ok()
+                }
+                None => false,
+            };
+            if !ready {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                warn!(?endpoint, %symbol, "Order pacing limit reached");
+                return Err(ExchangeError::RateLimitExceeded(format!(
+                    "{:?} limit of {} per {:?} reached for {}",
+                    endpoint, rule.max, rule.window, symbol
+                )));
+            }
+        }
+
+        self.admitted.fetch_add(1, Ordering::Relaxed);
+        if waited {
+            self.queued.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    pub fn stats(&self) -> PacerStats {
+        PacerStats {
+            admitted: self.admitted.load(Ordering::Relaxed),
+            queued: self.queued.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// `OrderPlacer` wrapper that paces calls through an `OrderPacer`
+///
+/// Share one pacer between every placer trading the same account so they draw from
+/// the same limits.
+pub struct PacedPlacer<P> {
+    inner: P,
+    pacer: Arc<OrderPacer>,
+}
+
+impl<P: OrderPlacer> PacedPlacer<P> {
+    pub fn new(inner: P, pacer: Arc<OrderPacer>) -> Self {
+        Self { inner, pacer }
+    }
+
+    pub const fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    pub fn pacer(&self) -> &OrderPacer {
+        &self.pacer
+    }
+}
+
+#[async_trait]
+impl<P: OrderPlacer + Send + Sync> OrderPlacer for PacedPlacer<P> {
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        self.pacer
+            .acquire(OrderEndpoint::Place, &order.symbol)
+            .await?;
+        self.inner.place_order(order).await
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        self.pacer.acquire(OrderEndpoint::Cancel, &symbol).await?;
+        self.inner.cancel_order(symbol, order_id).await
+    }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.pacer.acquire(OrderEndpoint::Cancel, &symbol).await?;
+        self.inner
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+
+    async fn modify_order(
+        &self,
+        order_id: String,
+        order: OrderRequest,
+    ) -> Result<OrderResponse, ExchangeError> {
+        self.pacer
+            .acquire(OrderEndpoint::Modify, &order.symbol)
+            .await?;
+        self.inner.modify_order(order_id, order).await
+    }
+
+    fn supports_trading(&self) -> bool {
+        self.inner.supports_trading()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(base: &str) -> Symbol {
+        Symbol::new(base, "USDT").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_drop_policy_limits_each_symbol_separately() {
+        let pacer = OrderPacer::new(
+            PacerConfig::new(vec![PaceRule::new(
+                &[OrderEndpoint::Place],
+                2,
+                Duration::from_secs(60),
+            )
+            .per_symbol()])
+            .with_policy(PacePolicy::Drop),
+        );
+
+        for _ in 0..2 {
+            pacer
+                .acquire(OrderEndpoint::Place, &symbol("BTC"))
+                .await
+                .unwrap();
+        }
+        let err = pacer
+            .acquire(OrderEndpoint::Place, &symbol("BTC"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ExchangeError::RateLimitExceeded(_)));
+
+        // Other symbols and uncovered endpoints are unaffected
+        pacer
+            .acquire(OrderEndpoint::Place, &symbol("ETH"))
+            .await
+            .unwrap();
+        pacer
+            .acquire(OrderEndpoint::Cancel, &symbol("BTC"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            pacer.stats(),
+            PacerStats {
+                admitted: 4,
+                queued: 0,
+                dropped: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_queue_policy_waits_up_to_max_wait() {
+        let pacer = OrderPacer::new(
+            PacerConfig::new(vec![PaceRule::new(
+                &[OrderEndpoint::Place],
+                1,
+                Duration::from_millis(50),
+            )])
+            .with_policy(PacePolicy::Queue {
+                max_wait: Duration::from_millis(200),
+            }),
+        );
+
+        let started = std::time::Instant::now();
+        pacer
+            .acquire(OrderEndpoint::Place, &symbol("BTC"))
+            .await
+            .unwrap();
+        pacer
+            .acquire(OrderEndpoint::Place, &symbol("BTC"))
+            .await
+            .unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(40));
+        assert_eq!(pacer.stats().queued, 1);
+
+        let impatient = OrderPacer::new(
+            PacerConfig::new(vec![PaceRule::new(
+                &[OrderEndpoint::Place],
+                1,
+                Duration::from_secs(60),
+            )])
+            .with_policy(PacePolicy::Queue {
+                max_wait: Duration::from_millis(20),
+            }),
+        );
+        impatient
+            .acquire(OrderEndpoint::Place, &symbol("BTC"))
+            .await
+            .unwrap();
+        assert!(impatient
+            .acquire(OrderEndpoint::Place, &symbol("BTC"))
+            .await
+            .is_err());
+        assert_eq!(impatient.stats().dropped, 1);
+    }
+}
@@ -0,0 +1,622 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::{AccountInfo, OrderPlacer};
+use crate::core::types::{
+    Balance, MarketDataType, OrderBook, OrderBookEntry, OrderRequest, OrderResponse, OrderSide,
+    OrderType, Position, Price, Quantity, Symbol, TimeInForce, Trade,
+};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+
+/// Fee rates and starting balances for a paper account
+#[derive(Debug, Clone, Default)]
+pub struct PaperConfig {
+    /// Fee rate charged on fills that rested on the book
+    pub maker_fee: Decimal,
+    /// Fee rate charged on fills that took liquidity
+    pub taker_fee: Decimal,
+    pub balances: HashMap<String, Decimal>,
+}
+
+impl PaperConfig {
+    #[must_use]
+    pub const fn with_fees(mut self, maker_fee: Decimal, taker_fee: Decimal) -> Self {
+        self.maker_fee = maker_fee;
+        self.taker_fee = taker_fee;
+        self
+    }
+
+    #[must_use]
+    pub fn with_balance(mut self, asset: &str, amount: Decimal) -> Self {
+        self.balances.insert(asset.to_string(), amount);
+        self
+    }
+}
+
+/// Which side of the trade a simulated fill was on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Liquidity {
+    Maker,
+    Taker,
+}
+
+/// A simulated execution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fill {
+    pub order_id: String,
+    pub symbol: Symbol,
+    pub side: OrderSide,
+    pub price: Price,
+    pub quantity: Quantity,
+    /// Fee charged in the quote asset
+    pub fee: Decimal,
+    pub liquidity: Liquidity,
+    pub time: i64,
+}
+
+#[derive(Debug, Clone)]
+struct RestingOrder {
+    order_id: String,
+    client_order_id: Option<String>,
+    symbol: Symbol,
+    side: OrderSide,
+    price: Price,
+    remaining: Decimal,
+}
+
+/// Depth-aware order matching against live market data
+///
+/// - Market orders walk the latest L2 snapshot and pay the taker fee. Liquidity they
+///   consume is removed from the local book until the next snapshot replaces it.
+/// - Limit orders first take whatever the book offers at or better than their price;
+///   the remainder rests (GTC) or is dropped (IOC). FOK orders fill completely or not
+///   at all.
+/// - Resting orders fill as makers when a trade prints through their price, up to the
+///   printed quantity, so partial fills are common. Prints exactly at the price are
+///   ignored since queue position is unknown.
+///
+/// Order statuses are `FILLED`, `PARTIALLY_FILLED` (remainder resting), `NEW` (nothing
+/// filled yet) and `EXPIRED` (remainder dropped). Balances are tracked per asset but
+/// not enforced.
+#[derive(Debug, Default)]
+pub struct PaperEngine {
+    config: PaperConfig,
+    books: HashMap<Symbol, OrderBook>,
+    orders: Vec<RestingOrder>,
+    balances: HashMap<String, Decimal>,
+    fills: Vec<Fill>,
+    next_order_id: u64,
+}
+
+impl PaperEngine {
+    pub fn new(config: PaperConfig) -> Self {
+        Self {
+            balances: config.balances.clone(),
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Feed one market data message, returning any fills it triggers
+    pub fn observe(&mut self, data: &MarketDataType) -> Vec<Fill> {
+        match data {
+            MarketDataType::OrderBook(book) => {
+                self.on_order_book(book);
+                Vec::new()
+            }
+            MarketDataType::Trade(trade) => self.on_trade(trade),
+            MarketDataType::Ticker(_) | MarketDataType::Kline(_) | MarketDataType::MarkPrice(_) => {
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn on_order_book(&mut self, book: &OrderBook) {
+        self.books.insert(book.symbol.clone(), book.clone());
+    }
+
+    pub fn on_trade(&mut self, trade: &Trade) -> Vec<Fill> {
+        let mut available = trade.quantity.value();
+        let mut fills = Vec::new();
+
+        for index in 0..self.orders.len() {
+            if available.is_zero() {
+                break;
+            }
+            let order = &self.orders[index];
+            let through = order.symbol == trade.symbol
+                && match order.side {
+                    OrderSide::Buy => trade.price < order.price,
+                    OrderSide::Sell => trade.price > order.price,
+                };
+            if !through {
+                continue;
+            }
+
+            let quantity = order.remaining.min(available);
+            available -= quantity;
+            self.orders[index].remaining -= quantity;
+            let order = self.orders[index].clone();
+            fills.push(self.record_fill(
+                &order.order_id,
+                &order.symbol,
+                &order.side,
+                order.price,
+                quantity,
+                Liquidity::Maker,
+                trade.time,
+            ));
+        }
+
+        self.orders.retain(|order| !order.remaining.is_zero());
+        fills
+    }
+
+    /// Simulate an order at `time` (milliseconds)
+    pub fn place_order(
+        &mut self,
+        order: &OrderRequest,
+        time: i64,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let limit = match order.order_type {
+            OrderType::Market => None,
+            OrderType::Limit => Some(order.price.ok_or_else(|| {
+                ExchangeError::InvalidParameters("Limit orders require a price".to_string())
+            })?),
+            _ => {
+                return Err(ExchangeError::NotSupported(format!(
+                    "{:?} orders are not simulated",
+                    order.order_type
+                )))
+            }
+        };
+        if limit.is_none() && !self.books.contains_key(&order.symbol) {
+            return Err(ExchangeError::InvalidParameters(format!(
+                "No order book for {} yet",
+                order.symbol
+            )));
+        }
+
+        self.next_order_id += 1;
+        let order_id = format!("paper-{}", self.next_order_id);
+        let quantity = order.quantity.value();
+        let time_in_force = order.time_in_force.clone().unwrap_or(TimeInForce::GTC);
+
+        let fill_or_kill = matches!(time_in_force, TimeInForce::FOK);
+        let filled = if fill_or_kill
+            && self.available_liquidity(&order.symbol, &order.side, limit) < quantity
+        {
+            Decimal::ZERO
+        } else {
+            self.take_liquidity(&order_id, order, limit, time)
+        };
+        let remaining = quantity - filled;
+
+        let rests = remaining > Decimal::ZERO
+            && limit.is_some()
+            && matches!(time_in_force, TimeInForce::GTC);
+        if rests {
+            self.orders.push(RestingOrder {
+                order_id: order_id.clone(),
+                client_order_id: order.client_order_id.clone(),
+                symbol: order.symbol.clone(),
+                side: order.side.clone(),
+                price: limit.unwrap_or_default(),
+                remaining,
+            });
+        }
+
+        let status = if remaining.is_zero() {
+            "FILLED"
+        } else if !rests {
+            "EXPIRED"
+        } else if filled.is_zero() {
+            "NEW"
+        } else {
+            "PARTIALLY_FILLED"
+        };
+
+        Ok(OrderResponse {
+            order_id,
+            client_order_id: order.client_order_id.clone().unwrap_or_default(),
+            symbol: order.symbol.clone(),
+            side: order.side.clone(),
+            order_type: order.order_type.clone(),
+            quantity: order.quantity,
+            price: order.price,
+            status: status.to_string(),
+            timestamp: time,
+            extensions: None,
+        })
+    }
+
+    pub fn cancel_order(&mut self, order_id: &str) -> Result<(), ExchangeError> {
+        let before = self.orders.len();
+        self.orders.retain(|order| order.order_id != order_id);
+        if self.orders.len() == before {
+            return Err(ExchangeError::InvalidParameters(format!(
+                "Unknown or completed order: {}",
+                order_id
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn cancel_order_by_client_id(
+        &mut self,
+        client_order_id: &str,
+    ) -> Result<(), ExchangeError> {
+        let before = self.orders.len();
+        self.orders
+            .retain(|order| order.client_order_id.as_deref() != Some(client_order_id));
+        if self.orders.len() == before {
+            return Err(ExchangeError::InvalidParameters(format!(
+                "Unknown or completed client order: {}",
+                client_order_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Balances per asset; size reserved by resting orders is reported as locked
+    pub fn balances(&self) -> Vec<Balance> {
+        let mut locked: HashMap<&str, Decimal> = HashMap::new();
+        for order in &self.orders {
+            let (asset, amount) = match order.side {
+                OrderSide::Buy => (&order.symbol.quote, order.remaining * order.price.value()),
+                OrderSide::Sell => (&order.symbol.base, order.remaining),
+            };
+            *locked.entry(asset.as_str()).or_default() += amount;
+        }
+
+        let mut balances: Vec<Balance> = self
+            .balances
+            .iter()
+            .map(|(asset, total)| {
+                let locked = locked.get(asset.as_str()).copied().unwrap_or_default();
+                Balance {
+                    asset: asset.clone(),
+                    free: Quantity::new(*total - locked),
+                    locked: Quantity::new(locked),
+                }
+            })
+            .collect();
+        balances.sort_by(|a, b| a.asset.cmp(&b.asset));
+        balances
+    }
+
+    pub fn fills(&self) -> &[Fill] {
+        &self.fills
+    }
+
+    /// Remaining quantity of a resting order, if it is still open
+    pub fn open_quantity(&self, order_id: &str) -> Option<Quantity> {
+        self.orders
+            .iter()
+            .find(|order| order.order_id == order_id)
+            .map(|order| Quantity::new(order.remaining))
+    }
+
+    fn opposite_levels(&mut self, symbol: &Symbol, side: &OrderSide) -> &mut Vec<OrderBookEntry> {
+        let book = self
+            .books
+            .entry(symbol.clone())
+            .or_insert_with(|| OrderBook {
+                symbol: symbol.clone(),
+                bids: Vec::new(),
+                asks: Vec::new(),
+                last_update_id: 0,
+            });
+        match side {
+            OrderSide::Buy => &mut book.asks,
+            OrderSide::Sell => &mut book.bids,
+        }
+    }
+
+    fn available_liquidity(
+        &mut self,
+        symbol: &Symbol,
+        side: &OrderSide,
+        limit: Option<Price>,
+    ) -> Decimal {
+        self.opposite_levels(symbol, side)
+            .iter()
+            .take_while(|level| crosses(side, level.price, limit))
+            .map(|level| level.quantity.value())
+            .sum()
+    }
+
+    fn take_liquidity(
+        &mut self,
+        order_id: &str,
+        order: &OrderRequest,
+        limit: Option<Price>,
+        time: i64,
+    ) -> Decimal {
+        let levels = self.opposite_levels(&order.symbol, &order.side);
+        let mut remaining = order.quantity.value();
+        let mut executions = Vec::new();
+
+        for level in levels.iter_mut() {
+            if remaining.is_zero() || !crosses(&order.side, level.price, limit) {
+                break;
+            }
+            let quantity = remaining.min(level.quantity.value());
+            level.quantity = Quantity::new(level.quantity.value() - quantity);
+            remaining -= quantity;
+            executions.push((level.price, quantity));
+        }
+        levels.retain(|level| !level.quantity.value().is_zero());
+
+        let mut filled = Decimal::ZERO;
+        for (price, quantity) in executions {
+            filled += quantity;
+            self.record_fill(
+                order_id,
+                &order.symbol,
+                &order.side,
+                price,
+                quantity,
+                Liquidity::Taker,
+                time,
+            );
+        }
+        filled
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn record_fill(
+        &mut self,
+        order_id: &str,
+        symbol: &Symbol,
+        side: &OrderSide,
+        price: Price,
+        quantity: Decimal,
+        liquidity: Liquidity,
+        time: i64,
+    ) -> Fill {
+        let rate = match liquidity {
+            Liquidity::Maker => self.config.maker_fee,
+            Liquidity::Taker => self.config.taker_fee,
+        };
+        let notional = price.value() * quantity;
+        let fee = notional * rate;
+
+        let (base_delta, quote_delta) = match side {
+            OrderSide::Buy => (quantity, -notional - fee),
+            OrderSide::Sell => (-quantity, notional - fee),
+        };
+        *self.balances.entry(symbol.base.clone()).or_default() += base_delta;
+        *self.balances.entry(symbol.quote.clone()).or_default() += quote_delta;
+
+        let fill = Fill {
+            order_id: order_id.to_string(),
+            symbol: symbol.clone(),
+            side: side.clone(),
+            price,
+            quantity: Quantity::new(quantity),
+            fee,
+            liquidity,
+            time,
+        };
+        self.fills.push(fill.clone());
+        fill
+    }
+}
+
+/// Whether a book level at `price` is marketable for an order on `side`
+fn crosses(side: &OrderSide, price: Price, limit: Option<Price>) -> bool {
+    limit.map_or(true, |limit| match side {
+        OrderSide::Buy => price <= limit,
+        OrderSide::Sell => price >= limit,
+    })
+}
+
+/// Paper-trading connector backed by a `PaperEngine`
+///
+/// Implements `OrderPlacer` and `AccountInfo` so strategies can swap it in for a live
+/// connector. Market data comes from any venue's stream via `attach`.
+#[derive(Clone)]
+pub struct PaperConnector {
+    engine: Arc<Mutex<PaperEngine>>,
+}
+
+impl PaperConnector {
+    pub fn new(config: PaperConfig) -> Self {
+        Self {
+            engine: Arc::new(Mutex::new(PaperEngine::new(config))),
+        }
+    }
+
+    /// Drive the simulation from a unified market data stream
+    ///
+    /// Subscribe to both order book and trade data for every symbol traded. Returns the
+    /// fills triggered by the stream (resting orders); immediate fills from
+    /// `place_order` are available through `fills`. The task exits when the stream
+    /// closes.
+    pub fn attach(&self, mut stream: mpsc::Receiver<MarketDataType>) -> mpsc::Receiver<Fill> {
+        let (tx, rx) = mpsc::channel(256);
+        let connector = self.clone();
+
+        tokio::spawn(async move {
+            while let Some(data) = stream.recv().await {
+                let fills = connector.with_engine(|engine| engine.observe(&data));
+                for fill in fills {
+                    // Keep simulating even if nobody listens for fills
+                    let _ = tx.send(fill).await;
+                }
+            }
+        });
+
+        rx
+    }
+
+    /// Every fill so far, oldest first
+    pub fn fills(&self) -> Vec<Fill> {
+        self.with_engine(|engine| engine.fills().to_vec())
+    }
+
+    fn with_engine<T>(&self, f: impl FnOnce(&mut PaperEngine) -> T) -> T {
+        let mut engine = self
+            .engine
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        f(&mut engine)
+    }
+}
+
+#[async_trait]
+impl OrderPlacer for PaperConnector {
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let now = chrono::Utc::now().timestamp_millis();
+        self.with_engine(|engine| engine.place_order(&order, now))
+    }
+
+    async fn cancel_order(&self, _symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        self.with_engine(|engine| engine.cancel_order(&order_id))
+    }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        _symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.with_engine(|engine| engine.cancel_order_by_client_id(&client_order_id))
+    }
+}
+
+#[async_trait]
+impl AccountInfo for PaperConnector {
+    async fn get_account_balance(&self) -> Result<Vec<Balance>, ExchangeError> {
+        Ok(self.with_engine(|engine| engine.balances()))
+    }
+
+    async fn get_positions(&self) -> Result<Vec<Position>, ExchangeError> {
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::conversion;
+
+    fn level(price: &str, quantity: &str) -> OrderBookEntry {
+        OrderBookEntry {
+            price: conversion::string_to_price(price),
+            quantity: conversion::string_to_quantity(quantity),
+        }
+    }
+
+    fn engine() -> PaperEngine {
+        let config = PaperConfig::default()
+            .with_fees(Decimal::new(1, 4), Decimal::new(5, 4))
+            .with_balance("USDT", Decimal::from(10_000));
+        let mut engine = PaperEngine::new(config);
+        engine.on_order_book(&OrderBook {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            bids: vec![level("99", "1")],
+            asks: vec![level("100", "1"), level("101", "2")],
+            last_update_id: 1,
+        });
+        engine
+    }
+
+    fn order(order_type: OrderType, side: OrderSide, quantity: &str, price: &str) -> OrderRequest {
+        OrderRequest {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            side,
+            order_type,
+            quantity: conversion::string_to_quantity(quantity),
+            price: Some(conversion::string_to_price(price)),
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        }
+    }
+
+    #[test]
+    fn test_market_order_walks_the_book() {
+        let mut engine = engine();
+        let response = engine
+            .place_order(&order(OrderType::Market, OrderSide::Buy, "2", "0"), 0)
+            .unwrap();
+        assert_eq!(response.status, "FILLED");
+
+        let prices: Vec<_> = engine.fills().iter().map(|f| f.price.to_string()).collect();
+        assert_eq!(prices, ["100", "101"]);
+        assert!(engine
+            .fills()
+            .iter()
+            .all(|f| f.liquidity == Liquidity::Taker));
+
+        // 201 notional plus 5 bps taker fee
+        let usdt = engine.balances().into_iter().find(|b| b.asset == "USDT");
+        assert_eq!(
+            usdt.unwrap().free,
+            conversion::string_to_quantity("9798.8995")
+        );
+
+        // Consumed depth stays consumed until the next snapshot
+        let response = engine
+            .place_order(&order(OrderType::Market, OrderSide::Buy, "2", "0"), 0)
+            .unwrap();
+        assert_eq!(response.status, "EXPIRED");
+    }
+
+    #[test]
+    fn test_limit_order_rests_and_fills_on_prints_through() {
+        let mut engine = engine();
+        let response = engine
+            .place_order(&order(OrderType::Limit, OrderSide::Buy, "3", "98"), 0)
+            .unwrap();
+        assert_eq!(response.status, "NEW");
+
+        let print = |price: &str, quantity: &str| Trade {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            id: 0,
+            price: conversion::string_to_price(price),
+            quantity: conversion::string_to_quantity(quantity),
+            time: 5,
+            is_buyer_maker: true,
+        };
+
+        // At our price: queue position unknown, no fill
+        assert!(engine.on_trade(&print("98", "10")).is_empty());
+
+        let fills = engine.on_trade(&print("97.5", "2"));
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].liquidity, Liquidity::Maker);
+        assert_eq!(fills[0].price, conversion::string_to_price("98"));
+        assert_eq!(
+            engine.open_quantity(&response.order_id),
+            Some(conversion::string_to_quantity("1"))
+        );
+
+        let usdt = engine.balances().into_iter().find(|b| b.asset == "USDT");
+        assert_eq!(usdt.unwrap().locked, conversion::string_to_quantity("98"));
+
+        engine.cancel_order(&response.order_id).unwrap();
+        assert!(engine.open_quantity(&response.order_id).is_none());
+    }
+
+    #[test]
+    fn test_cancels_by_client_order_id() {
+        let mut engine = engine();
+        let request = OrderRequest {
+            client_order_id: Some("grid-1".to_string()),
+            ..order(OrderType::Limit, OrderSide::Buy, "1", "98")
+        };
+        let response = engine.place_order(&request, 0).unwrap();
+        assert_eq!(response.client_order_id, "grid-1");
+
+        assert!(engine.cancel_order_by_client_id("grid-2").is_err());
+        engine.cancel_order_by_client_id("grid-1").unwrap();
+        assert!(engine.open_quantity(&response.order_id).is_none());
+    }
+}
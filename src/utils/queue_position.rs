@@ -0,0 +1,286 @@
+use crate::core::types::{MarketDataType, OrderBook, OrderSide, Price, Quantity, Symbol, Trade};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use tokio::sync::mpsc;
+
+/// Own resting limit order whose queue position is being estimated
+#[derive(Debug, Clone)]
+pub struct RestingOrder {
+    pub order_id: String,
+    pub symbol: Symbol,
+    pub side: OrderSide,
+    pub price: Price,
+    /// Remaining (unfilled) quantity
+    pub quantity: Quantity,
+}
+
+/// Snapshot of an order's estimated place in the queue
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueEstimate {
+    pub order_id: String,
+    /// Estimated size resting ahead of the order at its price level
+    pub queue_ahead: Quantity,
+    /// Total size at the order's price level, including the order itself
+    pub level_size: Quantity,
+    /// Heuristic probability that the order fills, in `[0, 1]`
+    pub fill_probability: f64,
+}
+
+/// Estimates a resting order's queue position from L2 updates and trades
+///
+/// Without order-level data the estimator assumes:
+/// - the order joined the back of its level when it was placed,
+/// - size added to the level queues behind it,
+/// - size removed from the level (other than by trades already seen) is cancelled
+///   pro-rata between orders ahead of and behind it,
+/// - trades at the order's price consume the front of the queue.
+///
+/// The fill probability is `traded / (traded + ahead + own)`, where `traded` is the
+/// volume printed against the order's level since placement. It starts at zero and
+/// approaches one as the level trades through the queue.
+#[derive(Debug, Clone)]
+pub struct QueuePositionEstimator {
+    order: RestingOrder,
+    ahead: Decimal,
+    /// Level size excluding the order itself, as of the last book update
+    others_at_level: Decimal,
+    /// Volume consumed by trades since the last book update
+    unreconciled_trades: Decimal,
+    traded: Decimal,
+}
+
+impl QueuePositionEstimator {
+    /// Start tracking an order that was just placed
+    ///
+    /// `book` is the first snapshot that includes the order; everything else at the
+    /// level is treated as ahead of it.
+    pub fn new(order: RestingOrder, book: &OrderBook) -> Self {
+        let others = level_size(book, &order.side, order.price).map_or(Decimal::ZERO, |level| {
+            (level - order.quantity.value()).max(Decimal::ZERO)
+        });
+        Self {
+            order,
+            ahead: others,
+            others_at_level: others,
+            unreconciled_trades: Decimal::ZERO,
+            traded: Decimal::ZERO,
+        }
+    }
+
+    pub const fn order(&self) -> &RestingOrder {
+        &self.order
+    }
+
+    /// Update the remaining quantity after a partial fill of the order itself
+    pub fn set_remaining(&mut self, quantity: Quantity) {
+        self.order.quantity = quantity;
+    }
+
+    /// Incorporate a new L2 snapshot
+    pub fn on_order_book(&mut self, book: &OrderBook) -> QueueEstimate {
+        match level_size(book, &self.order.side, self.order.price) {
+            Some(level) => {
+                let others = (level - self.order.quantity.value()).max(Decimal::ZERO);
+                let removed = self.others_at_level - others;
+                // Trades already applied from the tape explain part of any decrease
+                let cancelled = removed - removed.min(self.unreconciled_trades);
+                if cancelled > Decimal::ZERO {
+                    let share = self.ahead / self.others_at_level;
+                    self.ahead = (self.ahead - cancelled * share).max(Decimal::ZERO);
+                }
+                self.ahead = self.ahead.min(others);
+                self.others_at_level = others;
+            }
+            None if self.traded_through(book) => {
+                self.ahead = Decimal::ZERO;
+                self.others_at_level = Decimal::ZERO;
+            }
+            // Level is outside the visible depth; keep the previous estimate
+            None => {}
+        }
+        self.unreconciled_trades = Decimal::ZERO;
+        self.estimate()
+    }
+
+    /// Incorporate a public trade
+    pub fn on_trade(&mut self, trade: &Trade) -> QueueEstimate {
+        // Sellers hitting bids print with `is_buyer_maker`, buyers lifting asks without
+        let against_our_side = match self.order.side {
+            OrderSide::Buy => trade.is_buyer_maker,
+            OrderSide::Sell => !trade.is_buyer_maker,
+        };
+        if against_our_side {
+            let quantity = trade.quantity.value();
+            let through = match self.order.side {
+                OrderSide::Buy => trade.price < self.order.price,
+                OrderSide::Sell => trade.price > self.order.price,
+            };
+            if through {
+                self.ahead = Decimal::ZERO;
+                self.traded += quantity;
+            } else if trade.price == self.order.price {
+                self.ahead = (self.ahead - quantity).max(Decimal::ZERO);
+                self.unreconciled_trades += quantity;
+                self.traded += quantity;
+            }
+        }
+        self.estimate()
+    }
+
+    /// Current estimate without new input
+    pub fn estimate(&self) -> QueueEstimate {
+        let own = self.order.quantity.value();
+        let denominator = self.traded + self.ahead + own;
+        let fill_probability = if denominator > Decimal::ZERO {
+            (self.traded / denominator).to_f64().unwrap_or(0.0)
+        } else {
+            0.0
+        };
+
+        QueueEstimate {
+            order_id: self.order.order_id.clone(),
+            queue_ahead: Quantity::new(self.ahead),
+            level_size: Quantity::new(self.others_at_level + own),
+            fill_probability: fill_probability.clamp(0.0, 1.0),
+        }
+    }
+
+    fn traded_through(&self, book: &OrderBook) -> bool {
+        match self.order.side {
+            OrderSide::Buy => book
+                .bids
+                .first()
+                .map_or(true, |best| best.price < self.order.price),
+            OrderSide::Sell => book
+                .asks
+                .first()
+                .map_or(true, |best| best.price > self.order.price),
+        }
+    }
+}
+
+fn level_size(book: &OrderBook, side: &OrderSide, price: Price) -> Option<Decimal> {
+    let levels = match side {
+        OrderSide::Buy => &book.bids,
+        OrderSide::Sell => &book.asks,
+    };
+    levels
+        .iter()
+        .find(|entry| entry.price == price)
+        .map(|entry| entry.quantity.value())
+}
+
+/// Drive an estimator from a unified market data stream
+///
+/// Order books and trades for other symbols are ignored. A new `QueueEstimate` is sent
+/// whenever the estimate changes; the task exits when the stream closes or the returned
+/// receiver is dropped.
+pub fn track_queue_position(
+    mut estimator: QueuePositionEstimator,
+    mut stream: mpsc::Receiver<MarketDataType>,
+) -> mpsc::Receiver<QueueEstimate> {
+    let (tx, rx) = mpsc::channel(64);
+
+    tokio::spawn(async move {
+        let mut last = estimator.estimate();
+        while let Some(data) = stream.recv().await {
+            let symbol = &estimator.order().symbol;
+            let estimate = match &data {
+                MarketDataType::OrderBook(book) if &book.symbol == symbol => {
+                    estimator.on_order_book(book)
+                }
+                MarketDataType::Trade(trade) if &trade.symbol == symbol => {
+                    estimator.on_trade(trade)
+                }
+                _ => continue,
+            };
+            if estimate != last {
+                if tx.send(estimate.clone()).await.is_err() {
+                    break; // Receiver dropped
+                }
+                last = estimate;
+            }
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{conversion, OrderBookEntry};
+
+    fn level(price: &str, quantity: &str) -> OrderBookEntry {
+        OrderBookEntry {
+            price: conversion::string_to_price(price),
+            quantity: conversion::string_to_quantity(quantity),
+        }
+    }
+
+    fn bids(levels: Vec<OrderBookEntry>) -> OrderBook {
+        OrderBook {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            bids: levels,
+            asks: vec![level("101", "5")],
+            last_update_id: 0,
+        }
+    }
+
+    fn sell_print(price: &str, quantity: &str) -> Trade {
+        Trade {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            id: 0,
+            price: conversion::string_to_price(price),
+            quantity: conversion::string_to_quantity(quantity),
+            time: 0,
+            is_buyer_maker: true,
+        }
+    }
+
+    fn estimator() -> QueuePositionEstimator {
+        let order = RestingOrder {
+            order_id: "mine".to_string(),
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            side: OrderSide::Buy,
+            price: conversion::string_to_price("100"),
+            quantity: conversion::string_to_quantity("1"),
+        };
+        // 4 ahead of us plus our own 1
+        QueuePositionEstimator::new(order, &bids(vec![level("100", "5")]))
+    }
+
+    #[test]
+    fn test_trades_and_cancels_advance_the_queue() {
+        let mut estimator = estimator();
+        assert_eq!(
+            estimator.estimate().queue_ahead,
+            conversion::string_to_quantity("4")
+        );
+
+        // New size joins behind us
+        let estimate = estimator.on_order_book(&bids(vec![level("100", "7")]));
+        assert_eq!(estimate.queue_ahead, conversion::string_to_quantity("4"));
+        assert!(estimate.fill_probability.abs() < f64::EPSILON);
+
+        // A 1-lot print takes from the front; the matching book decrease is not counted twice
+        estimator.on_trade(&sell_print("100", "1"));
+        let estimate = estimator.on_order_book(&bids(vec![level("100", "6")]));
+        assert_eq!(estimate.queue_ahead, conversion::string_to_quantity("3"));
+        assert!(estimate.fill_probability > 0.0);
+
+        // A 3-lot cancel is split pro-rata: 3 of the 5 others were ahead
+        let estimate = estimator.on_order_book(&bids(vec![level("100", "3")]));
+        assert_eq!(estimate.queue_ahead, conversion::string_to_quantity("1.2"));
+    }
+
+    #[test]
+    fn test_level_traded_through_clears_the_queue() {
+        let estimate = estimator().on_order_book(&bids(vec![level("99", "2")]));
+        assert_eq!(estimate.queue_ahead, Quantity::ZERO);
+
+        let estimate = estimator().on_trade(&sell_print("99.5", "6"));
+        assert_eq!(estimate.queue_ahead, Quantity::ZERO);
+        assert!(estimate.fill_probability > 0.8);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::core::errors::ExchangeError;
+use crate::core::types::{MarketDataType, OrderBook, OrderBookEntry, Symbol, Trade};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+
+const BOOK_HEADER: &str = "exchange,symbol,timestamp,local_timestamp,is_snapshot,side,price,amount";
+const TRADES_HEADER: &str = "exchange,symbol,timestamp,local_timestamp,id,side,price,amount";
+
+/// Price to amount for one side of a book
+type Levels = BTreeMap<Decimal, Decimal>;
+
+/// Order book writer in the tardis.dev `incremental_book_L2` CSV layout
+///
+/// The first book seen for a symbol is written as snapshot rows; every later book is
+/// diffed against the previous one, so only changed levels are written and removed
+/// levels get an amount of zero. Books must be full views of the subscribed depth, as
+/// delivered by depth-limited subscriptions, not venue-side deltas.
+pub struct TardisBookWriter<W: Write> {
+    writer: W,
+    exchange: String,
+    books: HashMap<Symbol, (Levels, Levels)>,
+}
+
+impl<W: Write> TardisBookWriter<W> {
+    pub fn new(mut writer: W, exchange: &str) -> Result<Self, ExchangeError> {
+        writeln!(writer, "{}", BOOK_HEADER).map_err(write_error)?;
+        Ok(Self {
+            writer,
+            exchange: exchange.to_string(),
+            books: HashMap::new(),
+        })
+    }
+
+    /// Write the rows for `book`, returning how many were written
+    ///
+    /// Our book updates carry no exchange timestamp, so `local_timestamp` (microseconds
+    /// since the Unix epoch) fills both timestamp columns.
+    pub fn write(
+        &mut self,
+        book: &OrderBook,
+        local_timestamp: i64,
+    ) -> Result<usize, ExchangeError> {
+        let bids = levels(&book.bids);
+        let asks = levels(&book.asks);
+        let previous = self.books.insert(book.symbol.clone(), (bids, asks));
+        let (bids, asks) = &self.books[&book.symbol];
+
+        let is_snapshot = previous.is_none();
+        let (previous_bids, previous_asks) = previous.unwrap_or_default();
+        let mut rows = diff(&previous_bids, bids)
+            .into_iter()
+            .map(|level| ("bid", level))
+            .collect::<Vec<_>>();
+        rows.extend(
+            diff(&previous_asks, asks)
+                .into_iter()
+                .map(|level| ("ask", level)),
+        );
+
+        for (side, (price, amount)) in &rows {
+            writeln!(
+                self.writer,
+                "{},{},{},{},{},{},{},{}",
+                self.exchange,
+                book.symbol,
+                local_timestamp,
+                local_timestamp,
+                is_snapshot,
+                side,
+                price,
+                amount
+            )
+            .map_err(write_error)?;
+        }
+        Ok(rows.len())
+    }
+
+    pub fn flush(&mut self) -> Result<(), ExchangeError> {
+        self.writer.flush().map_err(write_error)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Trade writer in the tardis.dev `trades` CSV layout
+///
+/// `side` is the aggressor side, derived from `is_buyer_maker`.
+pub struct TardisTradeWriter<W: Write> {
+    writer: W,
+    exchange: String,
+}
+
+impl<W: Write> TardisTradeWriter<W> {
+    pub fn new(mut writer: W, exchange: &str) -> Result<Self, ExchangeError> {
+        writeln!(writer, "{}", TRADES_HEADER).map_err(write_error)?;
+        Ok(Self {
+            writer,
+            exchange: exchange.to_string(),
+        })
+    }
+
+    /// Write one trade; `local_timestamp` is microseconds since the Unix epoch
+    pub fn write(&mut self, trade: &Trade, local_timestamp: i64) -> Result<(), ExchangeError> {
+        let side = if trade.is_buyer_maker { "sell" } else { "buy" };
+        writeln!(
+            self.writer,
+            "{},{},{},{},{},{},{},{}",
+            self.exchange,
+            trade.symbol,
+            trade.time.saturating_mul(1000),
+            local_timestamp,
+            trade.id,
+            side,
+            trade.price,
+            trade.quantity
+        )
+        .map_err(write_error)
+    }
+
+    pub fn flush(&mut self) -> Result<(), ExchangeError> {
+        self.writer.flush().map_err(write_error)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Counts from a finished recording
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RecordingSummary {
+    pub book_rows: usize,
+    pub trades: usize,
+    /// Tickers and klines, which have no tardis layout here
+    pub skipped: usize,
+}
+
+/// Record a market data stream into `dir` until the stream closes
+///
+/// Writes `{exchange}_incremental_book_L2.csv` and `{exchange}_trades.csv`, one pair
+/// per call, so a multi-venue capture runs one recorder per connector stream. Use a
+/// fresh directory (or distinct names) per session; existing files are truncated.
+pub async fn record_stream(
+    mut stream: mpsc::Receiver<MarketDataType>,
+    dir: impl AsRef<Path>,
+    exchange: &str,
+) -> Result<RecordingSummary, ExchangeError> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir).map_err(write_error)?;
+    let mut books = TardisBookWriter::new(
+        create(dir.join(format!("{}_incremental_book_L2.csv", exchange)))?,
+        exchange,
+    )?;
+    let mut trades = TardisTradeWriter::new(
+        create(dir.join(format!("{}_trades.csv", exchange)))?,
+        exchange,
+    )?;
+
+    let mut summary = RecordingSummary::default();
+    while let Some(data) = stream.recv().await {
+        let local_timestamp = chrono::Utc::now().timestamp_micros();
+        match &data {
+            MarketDataType::OrderBook(book) => {
+                summary.book_rows += books.write(book, local_timestamp)?;
+            }
+            MarketDataType::Trade(trade) => {
+                trades.write(trade, local_timestamp)?;
+                summary.trades += 1;
+            }
+            MarketDataType::Ticker(_) | MarketDataType::Kline(_) | MarketDataType::MarkPrice(_) => {
+                summary.skipped += 1;
+            }
+        }
+    }
+
+    books.flush()?;
+    trades.flush()?;
+    Ok(summary)
+}
+
+fn create(path: PathBuf) -> Result<BufWriter<File>, ExchangeError> {
+    File::create(&path)
+        .map(BufWriter::new)
+        .map_err(|e| ExchangeError::Other(format!("Failed to create {}: {}", path.display(), e)))
+}
+
+fn write_error(error: std::io::Error) -> ExchangeError {
+    ExchangeError::Other(format!("Recorder write failed: {}", error))
+}
+
+fn levels(entries: &[OrderBookEntry]) -> Levels {
+    entries
+        .iter()
+        .map(|entry| (entry.price.value(), entry.quantity.value()))
+        .collect()
+}
+
+/// Levels that changed from `previous` to `current`, with removed ones at zero
+fn diff(previous: &Levels, current: &Levels) -> Vec<(Decimal, Decimal)> {
+    let removed = previous
+        .keys()
+        .filter(|price| !current.contains_key(price))
+        .map(|price| (*price, Decimal::ZERO));
+    let changed = current
+        .iter()
+        .filter(|(price, amount)| previous.get(price) != Some(amount))
+        .map(|(price, amount)| (*price, *amount));
+    removed.chain(changed).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, book, btc_usdt};
+
+    #[test]
+    fn test_book_writer_emits_snapshot_then_diffs() {
+        let mut writer = TardisBookWriter::new(Vec::new(), "binance").unwrap();
+        let first = book(&btc_usdt(), &[("100", "1"), ("99", "2")], &[("101", "1")]);
+        assert_eq!(writer.write(&first, 1_000).unwrap(), 3);
+
+        // 99 removed, 100 unchanged, 98 added, ask resized
+        let second = book(&btc_usdt(), &[("100", "1"), ("98", "3")], &[("101", "0.5")]);
+        assert_eq!(writer.write(&second, 2_000).unwrap(), 3);
+
+        let csv = String::from_utf8(writer.into_inner()).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines[0], BOOK_HEADER);
+        assert_eq!(lines[1], "binance,BTCUSDT,1000,1000,true,bid,99,2");
+        assert_eq!(lines[4], "binance,BTCUSDT,2000,2000,false,bid,99,0");
+        assert_eq!(lines[5], "binance,BTCUSDT,2000,2000,false,bid,98,3");
+        assert_eq!(lines[6], "binance,BTCUSDT,2000,2000,false,ask,101,0.5");
+        assert_eq!(lines.len(), 7);
+    }
+
+    #[test]
+    fn test_trade_writer_uses_aggressor_side() {
+        let mut writer = TardisTradeWriter::new(Vec::new(), "bybit").unwrap();
+        let trade = Trade {
+            id: 7,
+            time: 1_700_000_000_000,
+            is_buyer_maker: true,
+            ..test_support::trade(&Symbol::new("ETH", "USDT").unwrap(), "2000.5", "0.1")
+        };
+        writer.write(&trade, 1_700_000_000_000_123).unwrap();
+
+        let csv = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(
+            csv.lines().nth(1),
+            Some("bybit,ETHUSDT,1700000000000000,1700000000000123,7,sell,2000.5,0.1")
+        );
+    }
+}
@@ -0,0 +1,539 @@
+use crate::core::errors::ExchangeError;
+use crate::core::kernel::Profile;
+use crate::core::traits::OrderPlacer;
+use crate::core::types::{
+    FundingRate, OrderRequest, OrderResponse, OrderSide, Position, PositionSide, Symbol, Ticker,
+};
+use crate::utils::paper::Fill;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::watch;
+use tracing::warn;
+
+/// Latest price an order is compared against, fed by the latest-value cache
+#[derive(Debug, Clone)]
+pub enum PriceReference {
+    /// Last traded price from `LatestValueExt::subscribe_latest`
+    Ticker(watch::Receiver<Ticker>),
+    /// Mark price from `LatestFundingExt::subscribe_latest_mark_price`
+    MarkPrice(watch::Receiver<FundingRate>),
+}
+
+impl PriceReference {
+    pub fn current(&self) -> Option<Decimal> {
+        let price = match self {
+            Self::Ticker(rx) => rx.borrow().price.value(),
+            Self::MarkPrice(rx) => rx.borrow().mark_price?.value(),
+        };
+        (price > Decimal::ZERO).then_some(price)
+    }
+}
+
+/// How far order prices may stray from the reference price
+#[derive(Debug, Clone)]
+pub struct PriceBandConfig {
+    /// Largest allowed deviation as a fraction, e.g. `0.05` for 5%
+    pub max_deviation: Decimal,
+    /// Per-symbol deviations replacing `max_deviation`
+    pub overrides: HashMap<Symbol, Decimal>,
+}
+
+impl Default for PriceBandConfig {
+    fn default() -> Self {
+        Self::new(Decimal::new(10, 2))
+    }
+}
+
+impl PriceBandConfig {
+    pub fn new(max_deviation: Decimal) -> Self {
+        Self {
+            max_deviation,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Bands starting from `profile`'s maximum deviation
+    pub fn for_profile(profile: Profile) -> Self {
+        Self::new(profile.max_price_deviation())
+    }
+
+    #[must_use]
+    pub fn with_override(mut self, symbol: Symbol, max_deviation: Decimal) -> Self {
+        self.overrides.insert(symbol, max_deviation);
+        self
+    }
+
+    pub fn max_deviation_for(&self, symbol: &Symbol) -> Decimal {
+        self.overrides
+            .get(symbol)
+            .copied()
+            .unwrap_or(self.max_deviation)
+    }
+}
+
+/// Symbols whose combined exposure is capped, such as all alts or stablecoin pairs
+#[derive(Debug, Clone)]
+pub struct ExposureGroup {
+    pub name: String,
+    /// Symbols with one of these base assets belong to the group
+    pub bases: Vec<String>,
+    /// Symbols quoted in one of these assets belong to the group
+    pub quotes: Vec<String>,
+    /// Largest summed absolute notional of the group's net positions across venues
+    pub max_notional: Decimal,
+}
+
+impl ExposureGroup {
+    pub fn new(name: impl Into<String>, max_notional: Decimal) -> Self {
+        Self {
+            name: name.into(),
+            bases: Vec::new(),
+            quotes: Vec::new(),
+            max_notional,
+        }
+    }
+
+    #[must_use]
+    pub fn with_bases(mut self, bases: &[&str]) -> Self {
+        self.bases.extend(bases.iter().map(ToString::to_string));
+        self
+    }
+
+    #[must_use]
+    pub fn with_quotes(mut self, quotes: &[&str]) -> Self {
+        self.quotes.extend(quotes.iter().map(ToString::to_string));
+        self
+    }
+
+    pub fn contains(&self, symbol: &Symbol) -> bool {
+        self.bases.contains(&symbol.base) || self.quotes.contains(&symbol.quote)
+    }
+}
+
+/// Signed position on one venue and the last price it was seen at
+#[derive(Debug, Clone, Copy)]
+struct Holding {
+    quantity: Decimal,
+    price: Decimal,
+}
+
+/// Pre-trade checks applied before an order reaches the venue
+///
+/// Price bands reject limit and stop prices more than the configured fraction away from
+/// the symbol's reference price. Market orders carry no price and symbols without a
+/// reference, or whose reference is not yet positive, are let through.
+///
+/// Exposure groups reject orders that would take a group past its notional cap.
+/// Positions are netted per symbol across venues and valued at the reference price,
+/// falling back to the last fill or entry price. Orders that shrink a group's exposure
+/// are always allowed so a breached group can still be unwound.
+#[derive(Debug, Default)]
+pub struct RiskEngine {
+    price_bands: Option<PriceBandConfig>,
+    references: HashMap<Symbol, PriceReference>,
+    groups: Vec<ExposureGroup>,
+    holdings: Mutex<HashMap<(String, Symbol), Holding>>,
+    rejected: AtomicU64,
+}
+
+impl RiskEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_price_bands(mut self, config: PriceBandConfig) -> Self {
+        self.price_bands = Some(config);
+        self
+    }
+
+    #[must_use]
+    pub fn with_reference(mut self, symbol: Symbol, reference: PriceReference) -> Self {
+        self.references.insert(symbol, reference);
+        self
+    }
+
+    #[must_use]
+    pub fn with_exposure_group(mut self, group: ExposureGroup) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// Replace the positions held on `exchange`, e.g. from `AccountInfo::get_positions`
+    pub fn set_positions(&self, exchange: &str, positions: &[Position]) {
+        self.with_holdings(|holdings| {
+            holdings.retain(|(venue, _), _| venue != exchange);
+            for position in positions {
+                let amount = position.position_amount.value();
+                let quantity = match position.position_side {
+                    PositionSide::Long => amount.abs(),
+                    PositionSide::Short => -amount.abs(),
+                    PositionSide::Both => amount,
+                };
+                holdings.insert(
+                    (exchange.to_string(), position.symbol.clone()),
+                    Holding {
+                        quantity,
+                        price: position.entry_price.value(),
+                    },
+                );
+            }
+        });
+    }
+
+    /// Apply a fill on `exchange` to the tracked positions
+    pub fn on_fill(&self, exchange: &str, fill: &Fill) {
+        let quantity = signed(&fill.side, fill.quantity.value());
+        self.with_holdings(|holdings| {
+            let holding = holdings
+                .entry((exchange.to_string(), fill.symbol.clone()))
+                .or_insert(Holding {
+                    quantity: Decimal::ZERO,
+                    price: Decimal::ZERO,
+                });
+            holding.quantity += quantity;
+            holding.price = fill.price.value();
+        });
+    }
+
+    /// Current notional of the named group, if it exists
+    pub fn group_exposure(&self, name: &str) -> Option<Decimal> {
+        let group = self.groups.iter().find(|group| group.name == name)?;
+        let (nets, prices) = self.netted_positions();
+        Some(group_exposure(group, &nets, &prices))
+    }
+
+    /// Orders refused so far
+    pub fn rejected(&self) -> u64 {
+        self.rejected.load(Ordering::Relaxed)
+    }
+
+    pub fn check_order(&self, order: &OrderRequest) -> Result<(), ExchangeError> {
+        let result = self
+            .check_price_band(order)
+            .and_then(|()| self.check_exposure(order));
+        if let Err(e) = &result {
+            self.rejected.fetch_add(1, Ordering::Relaxed);
+            warn!(symbol = %order.symbol, error = %e, "Order rejected by risk checks");
+        }
+        result
+    }
+
+    fn check_price_band(&self, order: &OrderRequest) -> Result<(), ExchangeError> {
+        let Some(config) = &self.price_bands else {
+            return Ok(());
+        };
+        let Some(reference) = self
+            .references
+            .get(&order.symbol)
+            .and_then(PriceReference::current)
+        else {
+            return Ok(());
+        };
+        let max_deviation = config.max_deviation_for(&order.symbol);
+
+        for price in [order.price, order.stop_price].into_iter().flatten() {
+            let deviation = (price.value() - reference).abs() / reference;
+            if deviation > max_deviation {
+                return Err(ExchangeError::InvalidParameters(format!(
+                    "{} price {} is {:.2}% from reference {}, band is {}%",
+                    order.symbol,
+                    price,
+                    deviation * Decimal::ONE_HUNDRED,
+                    reference,
+                    max_deviation * Decimal::ONE_HUNDRED
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_exposure(&self, order: &OrderRequest) -> Result<(), ExchangeError> {
+        if !self
+            .groups
+            .iter()
+            .any(|group| group.contains(&order.symbol))
+        {
+            return Ok(());
+        }
+        let (nets, mut prices) = self.netted_positions();
+        let reference = self
+            .references
+            .get(&order.symbol)
+            .and_then(PriceReference::current);
+        let Some(price) = reference
+            .or_else(|| order.price.map(|price| price.value()))
+            .or_else(|| prices.get(&order.symbol).copied())
+        else {
+            return Ok(());
+        };
+
+        let mut projected_nets = nets.clone();
+        *projected_nets.entry(order.symbol.clone()).or_default() +=
+            signed(&order.side, order.quantity.value());
+        prices.insert(order.symbol.clone(), price);
+
+        for group in self.groups.iter().filter(|g| g.contains(&order.symbol)) {
+            let current = group_exposure(group, &nets, &prices);
+            let projected = group_exposure(group, &projected_nets, &prices);
+            if projected > group.max_notional && projected > current {
+                return Err(ExchangeError::InvalidParameters(format!(
+                    "{} order would take {} exposure to {}, limit is {}",
+                    order.symbol,
+                    group.name,
+                    projected.round_dp(2),
+                    group.max_notional
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Net quantity per symbol across venues, and the price each is valued at
+    fn netted_positions(&self) -> (HashMap<Symbol, Decimal>, HashMap<Symbol, Decimal>) {
+        let mut nets: HashMap<Symbol, Decimal> = HashMap::new();
+        let mut prices = HashMap::new();
+        self.with_holdings(|holdings| {
+            for ((_, symbol), holding) in holdings.iter() {
+                *nets.entry(symbol.clone()).or_default() += holding.quantity;
+                if holding.price > Decimal::ZERO {
+                    prices.insert(symbol.clone(), holding.price);
+                }
+            }
+        });
+        for (symbol, reference) in &self.references {
+            if let Some(price) = reference.current() {
+                prices.insert(symbol.clone(), price);
+            }
+        }
+        (nets, prices)
+    }
+
+    fn with_holdings<T>(&self, f: impl FnOnce(&mut HashMap<(String, Symbol), Holding>) -> T) -> T {
+        let mut holdings = self.holdings.lock().unwrap_or_else(PoisonError::into_inner);
+        f(&mut holdings)
+    }
+}
+
+fn signed(side: &OrderSide, quantity: Decimal) -> Decimal {
+    match side {
+        OrderSide::Buy => quantity,
+        OrderSide::Sell => -quantity,
+    }
+}
+
+fn group_exposure(
+    group: &ExposureGroup,
+    nets: &HashMap<Symbol, Decimal>,
+    prices: &HashMap<Symbol, Decimal>,
+) -> Decimal {
+    nets.iter()
+        .filter(|(symbol, _)| group.contains(symbol))
+        .map(|(symbol, net)| net.abs() * prices.get(symbol).copied().unwrap_or_default())
+        .sum()
+}
+
+/// `OrderPlacer` wrapper that runs every new or modified order through a `RiskEngine`
+pub struct RiskCheckedPlacer<P> {
+    inner: P,
+    engine: Arc<RiskEngine>,
+}
+
+impl<P: OrderPlacer> RiskCheckedPlacer<P> {
+    pub fn new(inner: P, engine: Arc<RiskEngine>) -> Self {
+        Self { inner, engine }
+    }
+
+    pub const fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    pub fn engine(&self) -> &RiskEngine {
+        &self.engine
+    }
+}
+
+#[async_trait]
+impl<P: OrderPlacer + Send + Sync> OrderPlacer for RiskCheckedPlacer<P> {
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        self.engine.check_order(&order)?;
+        self.inner.place_order(order).await
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        self.inner.cancel_order(symbol, order_id).await
+    }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.inner
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+
+    async fn modify_order(
+        &self,
+        order_id: String,
+        order: OrderRequest,
+    ) -> Result<OrderResponse, ExchangeError> {
+        self.engine.check_order(&order)?;
+        self.inner.modify_order(order_id, order).await
+    }
+
+    fn supports_trading(&self) -> bool {
+        self.inner.supports_trading()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{conversion, OrderSide, OrderType, Price, Quantity};
+
+    fn symbol(base: &str) -> Symbol {
+        Symbol::new(base, "USDT").unwrap()
+    }
+
+    fn limit(base: &str, price: &str) -> OrderRequest {
+        OrderRequest {
+            symbol: symbol(base),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::new(Decimal::ONE),
+            price: Some(conversion::string_to_price(price)),
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        }
+    }
+
+    fn mark_price(base: &str, price: &str) -> watch::Sender<FundingRate> {
+        let (tx, _) = watch::channel(FundingRate {
+            symbol: symbol(base),
+            funding_rate: None,
+            previous_funding_rate: None,
+            next_funding_rate: None,
+            funding_time: None,
+            next_funding_time: None,
+            mark_price: Some(conversion::string_to_price(price)),
+            index_price: None,
+            timestamp: 0,
+            funding_interval: None,
+        });
+        tx
+    }
+
+    #[test]
+    fn test_price_bands_use_overrides_and_latest_reference() {
+        let btc = mark_price("BTC", "50000");
+        let eth = mark_price("ETH", "3000");
+        let engine = RiskEngine::new()
+            .with_price_bands(
+                PriceBandConfig::new(Decimal::new(5, 2))
+                    .with_override(symbol("ETH"), Decimal::new(1, 2)),
+            )
+            .with_reference(symbol("BTC"), PriceReference::MarkPrice(btc.subscribe()))
+            .with_reference(symbol("ETH"), PriceReference::MarkPrice(eth.subscribe()));
+
+        assert!(engine.check_order(&limit("BTC", "52000")).is_ok());
+        // Extra zero typed on a BTC buy
+        let err = engine.check_order(&limit("BTC", "500000")).unwrap_err();
+        assert!(matches!(err, ExchangeError::InvalidParameters(_)));
+        // 2% is fine for BTC but outside ETH's 1% override
+        assert!(engine.check_order(&limit("ETH", "3060")).is_err());
+
+        // Band follows the cache
+        btc.send_modify(|rate| rate.mark_price = Some(Price::new(Decimal::from(500_000))));
+        assert!(engine.check_order(&limit("BTC", "500000")).is_ok());
+
+        let mut market = limit("BTC", "1");
+        market.order_type = OrderType::Market;
+        market.price = None;
+        assert!(engine.check_order(&market).is_ok());
+        assert!(engine.check_order(&limit("SOL", "1")).is_ok());
+        assert_eq!(engine.rejected(), 2);
+    }
+
+    #[test]
+    fn test_exposure_groups_net_positions_across_venues() {
+        let sol = mark_price("SOL", "100");
+        let engine = RiskEngine::new()
+            .with_exposure_group(
+                ExposureGroup::new("alts", Decimal::from(10_000)).with_bases(&["SOL", "AVAX"]),
+            )
+            .with_reference(symbol("SOL"), PriceReference::MarkPrice(sol.subscribe()));
+        engine.set_positions(
+            "binance_perp",
+            &[Position {
+                symbol: symbol("SOL"),
+                position_side: PositionSide::Long,
+                entry_price: Price::new(Decimal::from(90)),
+                position_amount: Quantity::new(Decimal::from(80)),
+                unrealized_pnl: Decimal::ZERO,
+                liquidation_price: None,
+                leverage: Decimal::ONE,
+                margin_mode: None,
+                margin: None,
+                extensions: None,
+            }],
+        );
+        engine.on_fill(
+            "okx",
+            &Fill {
+                order_id: "1".to_string(),
+                symbol: symbol("AVAX"),
+                side: OrderSide::Sell,
+                price: Price::new(Decimal::from(20)),
+                quantity: Quantity::new(Decimal::from(50)),
+                fee: Decimal::ZERO,
+                liquidity: crate::utils::paper::Liquidity::Taker,
+                time: 0,
+            },
+        );
+        // 80 SOL at the 100 mark plus a 50 AVAX short at its 20 fill price
+        assert_eq!(engine.group_exposure("alts"), Some(Decimal::from(9_000)));
+
+        let mut buy = limit("SOL", "100");
+        buy.quantity = Quantity::new(Decimal::from(15));
+        assert!(engine.check_order(&buy).is_err());
+        buy.quantity = Quantity::new(Decimal::from(10));
+        assert!(engine.check_order(&buy).is_ok());
+
+        // Selling SOL on another venue nets against the long
+        let mut sell = limit("SOL", "100");
+        sell.side = OrderSide::Sell;
+        sell.quantity = Quantity::new(Decimal::from(100));
+        assert!(engine.check_order(&sell).is_ok());
+        assert!(engine.check_order(&limit("BTC", "50000")).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_placer_blocks_orders_outside_band() {
+        let btc = mark_price("BTC", "50000");
+        let engine = Arc::new(
+            RiskEngine::new()
+                .with_price_bands(PriceBandConfig::default())
+                .with_reference(symbol("BTC"), PriceReference::MarkPrice(btc.subscribe())),
+        );
+        let placer = RiskCheckedPlacer::new(
+            crate::utils::paper::PaperConnector::new(crate::utils::paper::PaperConfig::default()),
+            engine,
+        );
+
+        let err = placer.place_order(limit("BTC", "5000")).await.unwrap_err();
+        assert!(matches!(err, ExchangeError::InvalidParameters(_)));
+        assert!(placer
+            .modify_order("1".to_string(), limit("BTC", "60000"))
+            .await
+            .is_err());
+        assert_eq!(placer.engine().rejected(), 2);
+    }
+}
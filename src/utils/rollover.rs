@@ -0,0 +1,401 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::OrderPlacer;
+use crate::core::types::{
+    OrderBook, OrderRequest, OrderResponse, OrderSide, OrderType, Position, PositionSide, Price,
+    Quantity, Symbol,
+};
+use rust_decimal::Decimal;
+use std::time::Duration;
+use tracing::warn;
+
+/// A delivery future and when it expires
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatedContract {
+    pub symbol: Symbol,
+    /// Milliseconds since the Unix epoch
+    pub expiry: i64,
+}
+
+impl DatedContract {
+    pub const fn new(symbol: Symbol, expiry: i64) -> Self {
+        Self { symbol, expiry }
+    }
+}
+
+/// When and at what price a position may be rolled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RolloverSchedule {
+    /// How long before expiry rolling starts
+    pub roll_before: Duration,
+    /// Largest roll spread accepted, as a fraction of the front contract's mid
+    pub max_spread: Option<Decimal>,
+}
+
+impl Default for RolloverSchedule {
+    fn default() -> Self {
+        Self {
+            roll_before: Duration::from_secs(3 * 86_400),
+            max_spread: None,
+        }
+    }
+}
+
+impl RolloverSchedule {
+    #[must_use]
+    pub const fn with_roll_before(mut self, roll_before: Duration) -> Self {
+        self.roll_before = roll_before;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_spread(mut self, max_spread: Decimal) -> Self {
+        self.max_spread = Some(max_spread);
+        self
+    }
+}
+
+/// Orders that move a position and its resting orders to the next contract
+#[derive(Debug, Clone)]
+pub struct RollPlan {
+    pub front: DatedContract,
+    pub next: DatedContract,
+    /// Price paid per unit to roll, positive when rolling costs money
+    pub spread: Decimal,
+    /// Closes the front position
+    pub close: Option<OrderRequest>,
+    /// Reopens it in the next contract
+    pub open: Option<OrderRequest>,
+    /// Front contract orders to cancel
+    pub cancels: Vec<String>,
+    /// Those orders again on the next contract, shifted by the basis between mids
+    pub replacements: Vec<OrderRequest>,
+}
+
+/// What `RolloverAssistant::execute` did
+#[derive(Debug, Default)]
+pub struct RollResult {
+    pub placed: Vec<OrderResponse>,
+    pub cancelled: Vec<String>,
+}
+
+/// Rollover helper for one series of delivery futures
+///
+/// Contracts are not listed by the connectors yet, so the series is supplied by the
+/// caller. Once the front (earliest unexpired) contract is within `roll_before` of
+/// expiry, `plan` builds the orders to move a position and its resting orders to the
+/// following contract, unless the roll spread is wider than the schedule allows.
+#[derive(Debug, Clone)]
+pub struct RolloverAssistant {
+    series: Vec<DatedContract>,
+    schedule: RolloverSchedule,
+}
+
+impl RolloverAssistant {
+    pub fn new(mut series: Vec<DatedContract>, schedule: RolloverSchedule) -> Self {
+        series.sort_by_key(|contract| contract.expiry);
+        Self { series, schedule }
+    }
+
+    /// Earliest contract not yet expired at `now`, and the one after it
+    pub fn contracts(&self, now: i64) -> Option<(&DatedContract, Option<&DatedContract>)> {
+        let index = self
+            .series
+            .iter()
+            .position(|contract| contract.expiry > now)?;
+        Some((&self.series[index], self.series.get(index + 1)))
+    }
+
+    /// The front and next contracts, once the front is inside its roll window
+    pub fn due(&self, now: i64) -> Option<(&DatedContract, &DatedContract)> {
+        let (front, next) = self.contracts(now)?;
+        let roll_before = i64::try_from(self.schedule.roll_before.as_millis()).unwrap_or(i64::MAX);
+        if now < front.expiry.saturating_sub(roll_before) {
+            return None;
+        }
+        next.map(|next| (front, next))
+    }
+
+    /// Plan the roll of `position` and `open_orders` on the front contract
+    ///
+    /// Returns `Ok(None)` when no roll is due, and `InvalidParameters` when either book
+    /// is one-sided or the spread exceeds `max_spread`.
+    pub fn plan(
+        &self,
+        now: i64,
+        position: Option<&Position>,
+        open_orders: &[OrderResponse],
+        front_book: &OrderBook,
+        next_book: &OrderBook,
+    ) -> Result<Option<RollPlan>, ExchangeError> {
+        let Some((front, next)) = self.due(now) else {
+            return Ok(None);
+        };
+        let (front_bid, front_ask) = touch(front_book)?;
+        let (next_bid, next_ask) = touch(next_book)?;
+
+        let amount = position
+            .filter(|position| position.symbol == front.symbol)
+            .map_or(Decimal::ZERO, signed_amount);
+        let spread = roll_spread(amount, front_bid, front_ask, next_bid, next_ask);
+        let mid = (front_bid + front_ask) / Decimal::TWO;
+        if let Some(max_spread) = self.schedule.max_spread {
+            if spread > mid * max_spread {
+                return Err(ExchangeError::InvalidParameters(format!(
+                    "Roll spread {} from {} to {} exceeds {} of mid",
+                    spread, front.symbol, next.symbol, max_spread
+                )));
+            }
+        }
+
+        let (close, open) = if amount.is_zero() {
+            (None, None)
+        } else {
+            let (close_side, open_side) = if amount > Decimal::ZERO {
+                (OrderSide::Sell, OrderSide::Buy)
+            } else {
+                (OrderSide::Buy, OrderSide::Sell)
+            };
+            let close = OrderRequest {
+                reduce_only: Some(true),
+                ..market(&front.symbol, close_side, amount.abs())
+            };
+            (
+                Some(close),
+                Some(market(&next.symbol, open_side, amount.abs())),
+            )
+        };
+
+        let basis = (next_bid + next_ask) / Decimal::TWO - mid;
+        let resting: Vec<_> = open_orders
+            .iter()
+            .filter(|order| order.symbol == front.symbol)
+            .collect();
+        let replacements = resting
+            .iter()
+            .map(|order| OrderRequest {
+                symbol: next.symbol.clone(),
+                side: order.side.clone(),
+                order_type: order.order_type.clone(),
+                quantity: order.quantity,
+                price: order.price.map(|price| Price::new(price.value() + basis)),
+                time_in_force: None,
+                stop_price: None,
+                reduce_only: None,
+                close_position: None,
+                client_order_id: None,
+            })
+            .collect();
+
+        Ok(Some(RollPlan {
+            front: front.clone(),
+            next: next.clone(),
+            spread,
+            close,
+            open,
+            cancels: resting.iter().map(|order| order.order_id.clone()).collect(),
+            replacements,
+        }))
+    }
+
+    /// Carry out `plan`: cancel front orders, roll the position, then re-place orders
+    ///
+    /// Stops at the first failed close or open so a half-rolled position is never
+    /// doubled; failed cancels and replacements are logged and skipped.
+    pub async fn execute<P: OrderPlacer + Sync + ?Sized>(
+        plan: RollPlan,
+        placer: &P,
+    ) -> Result<RollResult, ExchangeError> {
+        let mut result = RollResult::default();
+        for order_id in plan.cancels {
+            match placer
+                .cancel_order(plan.front.symbol.clone(), order_id.clone())
+                .await
+            {
+                Ok(()) => result.cancelled.push(order_id),
+                Err(e) => warn!(%order_id, error = %e, "Failed to cancel order before roll"),
+            }
+        }
+        for order in [plan.close, plan.open].into_iter().flatten() {
+            result.placed.push(placer.place_order(order).await?);
+        }
+        for order in plan.replacements {
+            match placer.place_order(order).await {
+                Ok(response) => result.placed.push(response),
+                Err(e) => warn!(error = %e, "Failed to re-place order after roll"),
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Price paid per unit to move a signed position from the front to the next contract
+///
+/// Longs sell the front bid and buy the next ask; shorts buy the front ask and sell the
+/// next bid. With no position the long roll is quoted.
+pub fn roll_spread(
+    amount: Decimal,
+    front_bid: Decimal,
+    front_ask: Decimal,
+    next_bid: Decimal,
+    next_ask: Decimal,
+) -> Decimal {
+    if amount < Decimal::ZERO {
+        front_ask - next_bid
+    } else {
+        next_ask - front_bid
+    }
+}
+
+fn signed_amount(position: &Position) -> Decimal {
+    let amount = position.position_amount.value();
+    match position.position_side {
+        PositionSide::Long => amount.abs(),
+        PositionSide::Short => -amount.abs(),
+        PositionSide::Both => amount,
+    }
+}
+
+fn touch(book: &OrderBook) -> Result<(Decimal, Decimal), ExchangeError> {
+    match (book.bids.first(), book.asks.first()) {
+        (Some(bid), Some(ask)) => Ok((bid.price.value(), ask.price.value())),
+        _ => Err(ExchangeError::InvalidParameters(format!(
+            "No two-sided book for {}",
+            book.symbol
+        ))),
+    }
+}
+
+fn market(symbol: &Symbol, side: OrderSide, quantity: Decimal) -> OrderRequest {
+    OrderRequest {
+        symbol: symbol.clone(),
+        side,
+        order_type: OrderType::Market,
+        quantity: Quantity::new(quantity),
+        price: None,
+        time_in_force: None,
+        stop_price: None,
+        reduce_only: None,
+        close_position: None,
+        client_order_id: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{book, FakeVenue};
+
+    const DAY: i64 = 86_400_000;
+
+    fn contract(expiry_day: i64) -> DatedContract {
+        DatedContract::new(
+            Symbol::from(format!("BTC-USD-{}", expiry_day).as_str()),
+            expiry_day * DAY,
+        )
+    }
+
+    fn short(contract: &DatedContract, amount: i64) -> Position {
+        Position {
+            symbol: contract.symbol.clone(),
+            position_side: PositionSide::Short,
+            entry_price: Price::new(Decimal::from(100)),
+            position_amount: Quantity::new(Decimal::from(amount)),
+            unrealized_pnl: Decimal::ZERO,
+            liquidation_price: None,
+            leverage: Decimal::ONE,
+            margin_mode: None,
+            margin: None,
+            extensions: None,
+        }
+    }
+
+    fn resting(contract: &DatedContract) -> OrderResponse {
+        OrderResponse {
+            order_id: "7".to_string(),
+            client_order_id: String::new(),
+            symbol: contract.symbol.clone(),
+            side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            quantity: Quantity::new(Decimal::ONE),
+            price: Some(Price::new(Decimal::from(110))),
+            status: "NEW".to_string(),
+            timestamp: 0,
+            extensions: None,
+        }
+    }
+
+    #[test]
+    fn test_plan_waits_for_window_and_checks_spread() {
+        let (june, september, december) = (contract(30), contract(120), contract(210));
+        let assistant = RolloverAssistant::new(
+            vec![december, september.clone(), june.clone()],
+            RolloverSchedule::default().with_max_spread(Decimal::new(5, 2)),
+        );
+        let position = short(&june, 2);
+        let orders = [resting(&june)];
+        let front_book = book(&june.symbol, &[("100", "1")], &[("101", "1")]);
+        let next_book = book(&september.symbol, &[("103", "1")], &[("104", "1")]);
+
+        // Four days out is before the three-day window
+        let plan = assistant
+            .plan(26 * DAY, Some(&position), &orders, &front_book, &next_book)
+            .unwrap();
+        assert!(plan.is_none());
+
+        let plan = assistant
+            .plan(28 * DAY, Some(&position), &orders, &front_book, &next_book)
+            .unwrap()
+            .unwrap();
+        assert_eq!(plan.next, september);
+        // A short buys the front ask and sells the next bid, collecting 2
+        assert_eq!(plan.spread, Decimal::from(-2));
+        assert!(matches!(plan.close.unwrap().side, OrderSide::Buy));
+        assert!(matches!(plan.open.unwrap().side, OrderSide::Sell));
+        assert_eq!(plan.cancels, ["7"]);
+        assert_eq!(
+            plan.replacements[0].price,
+            Some(Price::new(Decimal::from(113)))
+        );
+
+        // Flat, the long roll is quoted: 8 is more than 5% of the mid
+        let wide = book(&september.symbol, &[("107", "1")], &[("108", "1")]);
+        assert!(assistant
+            .plan(28 * DAY, None, &[], &front_book, &wide)
+            .is_err());
+        // Once June expires, September is the front
+        assert_eq!(assistant.contracts(31 * DAY).unwrap().0, &september);
+    }
+
+    #[tokio::test]
+    async fn test_execute_cancels_rolls_then_replaces() {
+        let (june, september) = (contract(30), contract(120));
+        let assistant = RolloverAssistant::new(
+            vec![june.clone(), september.clone()],
+            RolloverSchedule::default(),
+        );
+        let plan = assistant
+            .plan(
+                29 * DAY,
+                Some(&short(&june, 1)),
+                &[resting(&june)],
+                &book(&june.symbol, &[("100", "1")], &[("101", "1")]),
+                &book(&september.symbol, &[("103", "1")], &[("104", "1")]),
+            )
+            .unwrap()
+            .unwrap();
+
+        let placer = FakeVenue::default();
+        let result = RolloverAssistant::execute(plan, &placer).await.unwrap();
+        assert_eq!(result.cancelled, ["7"]);
+        assert_eq!(result.placed.len(), 3);
+        assert_eq!(
+            placer.calls(),
+            [
+                "cancel 7 BTCUSD-30",
+                "place Buy BTCUSD-30",
+                "place Sell BTCUSD-120",
+                "place Sell BTCUSD-120",
+            ]
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use crate::core::types::{OrderSide, Price};
+use crate::utils::metrics::{MetricsRegistry, VenueHealth};
+use std::cmp::Ordering;
+
+/// How venue health is weighed when prices tie
+///
+/// Scores are in milliseconds: with the defaults a venue whose recent requests fail
+/// 10% of the time scores like one that is 100ms slower.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RouterConfig {
+    /// Score per millisecond of rolling REST latency
+    pub rest_latency_weight: f64,
+    /// Score per millisecond of rolling WS latency
+    pub ws_latency_weight: f64,
+    /// Score for an error rate of 1 (every request failing)
+    pub error_rate_weight: f64,
+}
+
+impl Default for RouterConfig {
+    fn default() -> Self {
+        Self {
+            rest_latency_weight: 1.0,
+            ws_latency_weight: 0.5,
+            error_rate_weight: 1000.0,
+        }
+    }
+}
+
+impl RouterConfig {
+    #[must_use]
+    pub const fn with_rest_latency_weight(mut self, weight: f64) -> Self {
+        self.rest_latency_weight = weight;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_ws_latency_weight(mut self, weight: f64) -> Self {
+        self.ws_latency_weight = weight;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_error_rate_weight(mut self, weight: f64) -> Self {
+        self.error_rate_weight = weight;
+        self
+    }
+
+    /// Lower is healthier; venues with no recorded samples score zero
+    pub fn score(&self, health: &VenueHealth) -> f64 {
+        let millis = |latency: Option<std::time::Duration>| {
+            latency.map_or(0.0, |latency| latency.as_secs_f64() * 1000.0)
+        };
+        self.error_rate_weight.mul_add(
+            health.error_rate,
+            self.rest_latency_weight.mul_add(
+                millis(health.rest_latency),
+                self.ws_latency_weight * millis(health.ws_latency),
+            ),
+        )
+    }
+}
+
+/// Price an order could get on one venue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VenueQuote {
+    pub exchange: String,
+    pub price: Price,
+}
+
+impl VenueQuote {
+    pub fn new(exchange: impl Into<String>, price: Price) -> Self {
+        Self {
+            exchange: exchange.into(),
+            price,
+        }
+    }
+}
+
+/// Picks the venue to send an order to
+///
+/// Price always wins: the lowest ask for buys, the highest bid for sells. Among venues
+/// quoting the same price, the one with the best rolling health from the metrics
+/// registry is preferred.
+#[derive(Debug, Clone, Default)]
+pub struct SmartRouter {
+    metrics: MetricsRegistry,
+    config: RouterConfig,
+}
+
+impl SmartRouter {
+    pub fn new(metrics: MetricsRegistry) -> Self {
+        Self {
+            metrics,
+            config: RouterConfig::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_config(mut self, config: RouterConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Quotes ordered best first
+    pub fn rank(&self, side: &OrderSide, quotes: &[VenueQuote]) -> Vec<VenueQuote> {
+        let mut scored: Vec<_> = quotes
+            .iter()
+            .map(|quote| {
+                let score = self
+                    .metrics
+                    .health(&quote.exchange)
+                    .map_or(0.0, |health| self.config.score(&health));
+                (quote.clone(), score)
+            })
+            .collect();
+        scored.sort_by(|(a, a_score), (b, b_score)| {
+            let by_price = match side {
+                OrderSide::Buy => a.price.cmp(&b.price),
+                OrderSide::Sell => b.price.cmp(&a.price),
+            };
+            by_price.then_with(|| a_score.partial_cmp(b_score).unwrap_or(Ordering::Equal))
+        });
+        scored.into_iter().map(|(quote, _)| quote).collect()
+    }
+
+    pub fn select(&self, side: &OrderSide, quotes: &[VenueQuote]) -> Option<VenueQuote> {
+        self.rank(side, quotes).into_iter().next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use std::time::Duration;
+
+    fn quote(exchange: &str, price: u32) -> VenueQuote {
+        VenueQuote::new(exchange, Price::new(Decimal::from(price)))
+    }
+
+    #[test]
+    fn test_health_breaks_price_ties_only() {
+        let registry = MetricsRegistry::new();
+        registry
+            .exchange("binance")
+            .record_request_latency(Duration::from_millis(200), true);
+        registry
+            .exchange("bybit")
+            .record_request_latency(Duration::from_millis(20), true);
+        let router = SmartRouter::new(registry);
+
+        let quotes = [quote("binance", 100), quote("bybit", 100), quote("okx", 99)];
+        let ranked: Vec<_> = router
+            .rank(&OrderSide::Buy, &quotes)
+            .into_iter()
+            .map(|quote| quote.exchange)
+            .collect();
+        assert_eq!(ranked, ["okx", "bybit", "binance"]);
+
+        let best_bid = router.select(&OrderSide::Sell, &quotes).unwrap();
+        assert_eq!(best_bid.exchange, "bybit");
+    }
+
+    #[test]
+    fn test_error_rate_weighting_is_configurable() {
+        let registry = MetricsRegistry::new();
+        let flaky = registry.exchange("flaky");
+        for ok in [true, false, false] {
+            flaky.record_request_latency(Duration::from_millis(10), ok);
+        }
+        registry
+            .exchange("slow")
+            .record_request_latency(Duration::from_millis(300), true);
+        let quotes = [quote("flaky", 100), quote("slow", 100)];
+
+        let router = SmartRouter::new(registry.clone());
+        assert_eq!(
+            router.select(&OrderSide::Buy, &quotes).unwrap().exchange,
+            "slow"
+        );
+
+        let latency_only = SmartRouter::new(registry)
+            .with_config(RouterConfig::default().with_error_rate_weight(0.0));
+        assert_eq!(
+            latency_only
+                .select(&OrderSide::Buy, &quotes)
+                .unwrap()
+                .exchange,
+            "flaky"
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::OrderPlacer;
+use crate::core::types::{OrderRequest, OrderResponse, Symbol};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use tracing::warn;
+
+/// Statuses after which an order can no longer be cancelled
+const TERMINAL_STATUSES: &[&str] = &["FILLED", "CANCELED", "CANCELLED", "REJECTED", "EXPIRED"];
+
+pub(crate) fn is_terminal(status: &str) -> bool {
+    TERMINAL_STATUSES
+        .iter()
+        .any(|terminal| status.eq_ignore_ascii_case(terminal))
+}
+
+/// Guard that cancels every order placed through it when it goes out of scope
+///
+/// Orders are tracked from a successful place or modify until they are cancelled
+/// through the guard or reported done with `mark_done`. On drop, including while
+/// unwinding from a panic, the remaining orders are cancelled on a spawned task; with
+/// no Tokio runtime available they are only logged. Await `cancel_all` instead when
+/// the caller needs to know the cancels went through.
+pub struct ScopedOrders<P: OrderPlacer + Send + Sync + 'static> {
+    placer: Arc<P>,
+    open: Mutex<HashMap<String, Symbol>>,
+    /// Order id of each tracked order placed with a client order id
+    client_ids: Mutex<HashMap<String, String>>,
+}
+
+impl<P: OrderPlacer + Send + Sync + 'static> ScopedOrders<P> {
+    pub fn new(placer: Arc<P>) -> Self {
+        Self {
+            placer,
+            open: Mutex::new(HashMap::new()),
+            client_ids: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn placer(&self) -> &P {
+        &self.placer
+    }
+
+    /// Stop tracking an order that filled or was cancelled elsewhere
+    pub fn mark_done(&self, order_id: &str) {
+        self.with_open(|open| open.remove(order_id));
+        self.with_client_ids(|client_ids| client_ids.retain(|_, id| id != order_id));
+    }
+
+    /// Orders the guard would cancel if dropped now
+    pub fn open_orders(&self) -> Vec<(Symbol, String)> {
+        self.with_open(|open| {
+            open.iter()
+                .map(|(order_id, symbol)| (symbol.clone(), order_id.clone()))
+                .collect()
+        })
+    }
+
+    /// Cancel every tracked order now, returning the last error if any cancel failed
+    ///
+    /// Orders whose cancel fails stay tracked and are retried on drop.
+    pub async fn cancel_all(&self) -> Result<(), ExchangeError> {
+        let mut result = Ok(());
+        for (symbol, order_id) in self.open_orders() {
+            match self.placer.cancel_order(symbol, order_id.clone()).await {
+                Ok(()) => self.mark_done(&order_id),
+                Err(e) => {
+                    warn!(%order_id, error = %e, "Failed to cancel scoped order");
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+
+    /// Disarm the guard, leaving its orders working, and return them
+    pub fn release(self) -> Vec<(Symbol, String)> {
+        let orders = self.open_orders();
+        self.with_open(HashMap::clear);
+        self.with_client_ids(HashMap::clear);
+        orders
+    }
+
+    fn track(&self, response: &OrderResponse) {
+        if !is_terminal(&response.status) {
+            self.with_open(|open| open.insert(response.order_id.clone(), response.symbol.clone()));
+            if !response.client_order_id.is_empty() {
+                self.with_client_ids(|client_ids| {
+                    client_ids.insert(response.client_order_id.clone(), response.order_id.clone())
+                });
+            }
+        }
+    }
+
+    fn with_open<T>(&self, f: impl FnOnce(&mut HashMap<String, Symbol>) -> T) -> T {
+        let mut open = self.open.lock().unwrap_or_else(PoisonError::into_inner);
+        f(&mut open)
+    }
+
+    fn with_client_ids<T>(&self, f: impl FnOnce(&mut HashMap<String, String>) -> T) -> T {
+        let mut client_ids = self
+            .client_ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        f(&mut client_ids)
+    }
+}
+
+#[async_trait]
+impl<P: OrderPlacer + Send + Sync + 'static> OrderPlacer for ScopedOrders<P> {
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let response = self.placer.place_order(order).await?;
+        self.track(&response);
+        Ok(response)
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        self.placer.cancel_order(symbol, order_id.clone()).await?;
+        self.mark_done(&order_id);
+        Ok(())
+    }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.placer
+            .cancel_order_by_client_id(symbol, client_order_id.clone())
+            .await?;
+        let order_id = self.with_client_ids(|client_ids| client_ids.remove(&client_order_id));
+        if let Some(order_id) = order_id {
+            self.mark_done(&order_id);
+        }
+        Ok(())
+    }
+
+    async fn modify_order(
+        &self,
+        order_id: String,
+        order: OrderRequest,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let response = self.placer.modify_order(order_id.clone(), order).await?;
+        // Some venues cancel-replace under a new id
+        self.mark_done(&order_id);
+        self.track(&response);
+        Ok(response)
+    }
+
+    fn supports_trading(&self) -> bool {
+        self.placer.supports_trading()
+    }
+}
+
+impl<P: OrderPlacer + Send + Sync + 'static> Drop for ScopedOrders<P> {
+    fn drop(&mut self) {
+        let orders: Vec<_> = self.with_open(std::mem::take).into_iter().collect();
+        if orders.is_empty() {
+            return;
+        }
+
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            for (order_id, symbol) in &orders {
+                warn!(%order_id, %symbol, "No runtime to cancel scoped order; left open");
+            }
+            return;
+        };
+        let placer = self.placer.clone();
+        runtime.spawn(async move {
+            for (order_id, symbol) in orders {
+                if let Err(e) = placer.cancel_order(symbol, order_id.clone()).await {
+                    warn!(%order_id, error = %e, "Failed to cancel scoped order on drop");
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{OrderSide, OrderType};
+    use crate::test_support::{btc_usdt, limit_order, FakeVenue};
+    use std::time::Duration;
+
+    fn order(order_type: OrderType) -> OrderRequest {
+        OrderRequest {
+            order_type,
+            ..limit_order(&btc_usdt(), OrderSide::Buy, "100", "1")
+        }
+    }
+
+    async fn cancelled_after_drop(venue: &FakeVenue) -> Vec<String> {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        venue.cancelled()
+    }
+
+    #[tokio::test]
+    async fn test_cancels_open_orders_on_drop() {
+        let venue = Arc::new(FakeVenue::default());
+        {
+            let scope = ScopedOrders::new(venue.clone());
+            scope.place_order(order(OrderType::Limit)).await.unwrap();
+            scope.place_order(order(OrderType::Market)).await.unwrap();
+            scope.place_order(order(OrderType::Limit)).await.unwrap();
+            scope.place_order(order(OrderType::Limit)).await.unwrap();
+            scope.mark_done("3");
+            assert_eq!(scope.open_orders().len(), 2);
+        }
+        assert_eq!(cancelled_after_drop(&venue).await, ["1", "4"]);
+
+        let released = ScopedOrders::new(venue.clone());
+        released.place_order(order(OrderType::Limit)).await.unwrap();
+        assert_eq!(released.release().len(), 1);
+        assert_eq!(cancelled_after_drop(&venue).await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cancels_open_orders_when_scope_panics() {
+        let venue = Arc::new(FakeVenue::default());
+        let task_venue = venue.clone();
+        let result = tokio::spawn(async move {
+            let scope = ScopedOrders::new(task_venue);
+            scope.place_order(order(OrderType::Limit)).await.unwrap();
+            panic!("strategy bug");
+        })
+        .await;
+        assert!(result.unwrap_err().is_panic());
+        assert_eq!(cancelled_after_drop(&venue).await, ["1"]);
+    }
+
+    #[tokio::test]
+    async fn test_done_orders_drop_their_client_ids() {
+        let venue = Arc::new(FakeVenue::default());
+        let scope = ScopedOrders::new(venue.clone());
+        let with_client_id = |client_order_id: &str| OrderRequest {
+            client_order_id: Some(client_order_id.to_string()),
+            ..order(OrderType::Limit)
+        };
+        let filled = scope.place_order(with_client_id("a")).await.unwrap();
+        let cancelled = scope.place_order(with_client_id("b")).await.unwrap();
+        assert_eq!(scope.client_ids.lock().unwrap().len(), 2);
+
+        scope.mark_done(&filled.order_id);
+        scope
+            .cancel_order(cancelled.symbol, cancelled.order_id)
+            .await
+            .unwrap();
+        assert!(scope.client_ids.lock().unwrap().is_empty());
+        assert!(scope.open_orders().is_empty());
+    }
+}
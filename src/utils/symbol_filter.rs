@@ -0,0 +1,166 @@
+use crate::core::types::MarketDataType;
+use crate::exchanges::factory::ExchangeId;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// Allow and deny lists of symbol patterns
+///
+/// Patterns match the venue symbol case-insensitively, and `*` matches any run of
+/// characters, so `"*DOWN*"` excludes every leveraged-token pair. A symbol passes when it
+/// matches no deny pattern and, if the allow list is non-empty, at least one allow pattern.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct SymbolFilter {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl SymbolFilter {
+    #[must_use]
+    pub fn with_allow(mut self, pattern: impl Into<String>) -> Self {
+        self.allow.push(pattern.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_deny(mut self, pattern: impl Into<String>) -> Self {
+        self.deny.push(pattern.into());
+        self
+    }
+
+    pub fn allows(&self, symbol: &str) -> bool {
+        let symbol = symbol.to_uppercase();
+        let matches = |pattern: &String| glob_match(&pattern.to_uppercase(), &symbol);
+        !self.deny.iter().any(matches) && (self.allow.is_empty() || self.allow.iter().any(matches))
+    }
+
+    /// Keep the symbols that pass, in order
+    pub fn apply(&self, symbols: Vec<String>) -> Vec<String> {
+        symbols.into_iter().filter(|s| self.allows(s)).collect()
+    }
+
+    /// Drop market data for symbols that do not pass
+    ///
+    /// For streams whose subscription cannot be narrowed up front, such as all-market
+    /// tickers. The task exits when either end closes.
+    pub fn filter_stream(
+        self,
+        mut stream: mpsc::Receiver<MarketDataType>,
+    ) -> mpsc::Receiver<MarketDataType> {
+        let (tx, rx) = mpsc::channel(1000);
+        tokio::spawn(async move {
+            while let Some(data) = stream.recv().await {
+                if self.allows(&data.symbol().to_string()) && tx.send(data).await.is_err() {
+                    return; // Receiver dropped
+                }
+            }
+        });
+        rx
+    }
+}
+
+/// `*` wildcard match over the whole string
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty(); // No wildcard
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// `[symbol_filters]` table of the app config file
+///
+/// ```toml
+/// [symbol_filters.global]
+/// deny = ["*UP*", "*DOWN*"]
+///
+/// [symbol_filters.exchanges.binance_perp]
+/// allow = ["BTCUSDT", "ETHUSDT", "SOL*"]
+/// ```
+///
+/// Exchanges are keyed as accepted by `ExchangeId::from_str`. An exchange's allow list
+/// replaces the global one, while deny lists add up.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SubscriptionFilters {
+    #[serde(default)]
+    pub global: SymbolFilter,
+    #[serde(default)]
+    pub exchanges: HashMap<String, SymbolFilter>,
+}
+
+impl SubscriptionFilters {
+    /// Effective filter for one exchange
+    pub fn for_exchange(&self, exchange: ExchangeId) -> SymbolFilter {
+        let mut filter = self.global.clone();
+        let overrides = self
+            .exchanges
+            .iter()
+            .filter(|(key, _)| key.parse::<ExchangeId>().ok() == Some(exchange));
+        for (_, exchange_filter) in overrides {
+            if !exchange_filter.allow.is_empty() {
+                filter.allow.clone_from(&exchange_filter.allow);
+            }
+            filter.deny.extend(exchange_filter.deny.iter().cloned());
+        }
+        filter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deny_wins_over_allow() {
+        let filter = SymbolFilter::default()
+            .with_allow("*usdt")
+            .with_deny("*DOWN*")
+            .with_deny("LUNAUSDT");
+
+        assert_eq!(
+            filter.apply(vec![
+                "BTCUSDT".to_string(),
+                "btcdownusdt".to_string(),
+                "LUNAUSDT".to_string(),
+                "ETHBTC".to_string(),
+            ]),
+            vec!["BTCUSDT".to_string()]
+        );
+        assert!(SymbolFilter::default().allows("ANYTHING"));
+        assert!(glob_match("A*B*C", "AXBYC"));
+        assert!(!glob_match("AB*B", "AB"));
+    }
+
+    #[test]
+    fn test_exchange_filters_merge_with_global() {
+        let filters = SubscriptionFilters {
+            global: SymbolFilter::default().with_deny("*DOWN*"),
+            exchanges: HashMap::from([(
+                "binance-perp".to_string(),
+                SymbolFilter::default().with_allow("BTC*"),
+            )]),
+        };
+
+        let perp = filters.for_exchange(ExchangeId::BinancePerp);
+        assert!(perp.allows("BTCUSDT"));
+        assert!(!perp.allows("BTCDOWNUSDT"));
+        assert!(!perp.allows("ETHUSDT"));
+
+        let spot = filters.for_exchange(ExchangeId::Binance);
+        assert!(spot.allows("ETHUSDT"));
+        assert!(!spot.allows("ETHDOWNUSDT"));
+    }
+}
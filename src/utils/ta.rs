@@ -0,0 +1,608 @@
+use crate::core::types::{Kline, MarketDataType, Symbol, Trade};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// A single price/volume point fed to the indicators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bar {
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+}
+
+impl Bar {
+    /// Typical price, `(high + low + close) / 3`
+    pub fn typical_price(&self) -> Decimal {
+        (self.high + self.low + self.close) / Decimal::from(3)
+    }
+}
+
+impl From<&Kline> for Bar {
+    fn from(kline: &Kline) -> Self {
+        Self {
+            high: kline.high_price.value(),
+            low: kline.low_price.value(),
+            close: kline.close_price.value(),
+            volume: kline.volume.value(),
+        }
+    }
+}
+
+impl From<&Trade> for Bar {
+    fn from(trade: &Trade) -> Self {
+        let price = trade.price.value();
+        Self {
+            high: price,
+            low: price,
+            close: price,
+            volume: trade.quantity.value(),
+        }
+    }
+}
+
+/// An incrementally updated indicator
+pub trait Indicator {
+    /// Commit a bar and return the new value, `None` while warming up
+    fn update(&mut self, bar: &Bar) -> Option<Decimal>;
+
+    /// Current value, `None` while warming up
+    fn value(&self) -> Option<Decimal>;
+}
+
+/// Exponential moving average of closes, seeded with the simple average of the first
+/// `period` bars
+#[derive(Debug, Clone)]
+pub struct Ema {
+    period: usize,
+    alpha: Decimal,
+    seed_sum: Decimal,
+    seen: usize,
+    value: Option<Decimal>,
+}
+
+impl Ema {
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "EMA period must be positive");
+        Self {
+            period,
+            alpha: Decimal::TWO / Decimal::from(period + 1),
+            seed_sum: Decimal::ZERO,
+            seen: 0,
+            value: None,
+        }
+    }
+}
+
+impl Indicator for Ema {
+    fn update(&mut self, bar: &Bar) -> Option<Decimal> {
+        if let Some(previous) = self.value {
+            self.value = Some(previous + self.alpha * (bar.close - previous));
+        } else {
+            self.seed_sum += bar.close;
+            self.seen += 1;
+            if self.seen == self.period {
+                self.value = Some(self.seed_sum / Decimal::from(self.period));
+            }
+        }
+        self.value
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        self.value
+    }
+}
+
+/// Wilder smoothing shared by RSI and ATR: a simple average over the first `period`
+/// samples, then `(previous * (period - 1) + sample) / period`
+#[derive(Debug, Clone)]
+struct Wilder {
+    period: usize,
+    seed_sum: Decimal,
+    seen: usize,
+    value: Option<Decimal>,
+}
+
+impl Wilder {
+    fn new(period: usize) -> Self {
+        Self {
+            period,
+            seed_sum: Decimal::ZERO,
+            seen: 0,
+            value: None,
+        }
+    }
+
+    fn update(&mut self, sample: Decimal) -> Option<Decimal> {
+        let period = Decimal::from(self.period);
+        if let Some(previous) = self.value {
+            self.value = Some((previous * (period - Decimal::ONE) + sample) / period);
+        } else {
+            self.seed_sum += sample;
+            self.seen += 1;
+            if self.seen == self.period {
+                self.value = Some(self.seed_sum / period);
+            }
+        }
+        self.value
+    }
+}
+
+/// Relative strength index of closes, 0 to 100
+#[derive(Debug, Clone)]
+pub struct Rsi {
+    gains: Wilder,
+    losses: Wilder,
+    previous_close: Option<Decimal>,
+    value: Option<Decimal>,
+}
+
+impl Rsi {
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "RSI period must be positive");
+        Self {
+            gains: Wilder::new(period),
+            losses: Wilder::new(period),
+            previous_close: None,
+            value: None,
+        }
+    }
+}
+
+impl Indicator for Rsi {
+    fn update(&mut self, bar: &Bar) -> Option<Decimal> {
+        let previous = self.previous_close.replace(bar.close)?;
+        let change = bar.close - previous;
+        let gain = self.gains.update(change.max(Decimal::ZERO));
+        let loss = self.losses.update((-change).max(Decimal::ZERO));
+
+        let hundred = Decimal::ONE_HUNDRED;
+        self.value = gain.zip(loss).map(|(gain, loss)| {
+            if loss.is_zero() {
+                hundred
+            } else {
+                hundred - hundred / (Decimal::ONE + gain / loss)
+            }
+        });
+        self.value
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        self.value
+    }
+}
+
+/// Average true range
+#[derive(Debug, Clone)]
+pub struct Atr {
+    ranges: Wilder,
+    previous_close: Option<Decimal>,
+}
+
+impl Atr {
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "ATR period must be positive");
+        Self {
+            ranges: Wilder::new(period),
+            previous_close: None,
+        }
+    }
+}
+
+impl Indicator for Atr {
+    fn update(&mut self, bar: &Bar) -> Option<Decimal> {
+        let range = bar.high - bar.low;
+        let true_range = self
+            .previous_close
+            .replace(bar.close)
+            .map_or(range, |close| {
+                range
+                    .max((bar.high - close).abs())
+                    .max((bar.low - close).abs())
+            });
+        self.ranges.update(true_range)
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        self.ranges.value
+    }
+}
+
+/// Volume weighted average price since creation or the last `reset`
+#[derive(Debug, Clone, Default)]
+pub struct Vwap {
+    price_volume: Decimal,
+    volume: Decimal,
+}
+
+impl Vwap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new session, e.g. at the daily open
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl Indicator for Vwap {
+    fn update(&mut self, bar: &Bar) -> Option<Decimal> {
+        self.price_volume += bar.typical_price() * bar.volume;
+        self.volume += bar.volume;
+        self.value()
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        (!self.volume.is_zero()).then(|| self.price_volume / self.volume)
+    }
+}
+
+/// Any of the built-in indicators, so an engine can hold a mixed set without boxing
+#[derive(Debug, Clone)]
+pub enum IndicatorKind {
+    Ema(Ema),
+    Rsi(Rsi),
+    Atr(Atr),
+    Vwap(Vwap),
+}
+
+impl Indicator for IndicatorKind {
+    fn update(&mut self, bar: &Bar) -> Option<Decimal> {
+        match self {
+            Self::Ema(indicator) => indicator.update(bar),
+            Self::Rsi(indicator) => indicator.update(bar),
+            Self::Atr(indicator) => indicator.update(bar),
+            Self::Vwap(indicator) => indicator.update(bar),
+        }
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        match self {
+            Self::Ema(indicator) => indicator.value(),
+            Self::Rsi(indicator) => indicator.value(),
+            Self::Atr(indicator) => indicator.value(),
+            Self::Vwap(indicator) => indicator.value(),
+        }
+    }
+}
+
+impl From<Ema> for IndicatorKind {
+    fn from(indicator: Ema) -> Self {
+        Self::Ema(indicator)
+    }
+}
+
+impl From<Rsi> for IndicatorKind {
+    fn from(indicator: Rsi) -> Self {
+        Self::Rsi(indicator)
+    }
+}
+
+impl From<Atr> for IndicatorKind {
+    fn from(indicator: Atr) -> Self {
+        Self::Atr(indicator)
+    }
+}
+
+impl From<Vwap> for IndicatorKind {
+    fn from(indicator: Vwap) -> Self {
+        Self::Vwap(indicator)
+    }
+}
+
+/// Single and two-candle patterns detected on closed klines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandlePattern {
+    /// Body at most a tenth of the range
+    Doji,
+    /// Lower wick at least twice the body with little upper wick
+    Hammer,
+    /// Up candle whose body engulfs the previous down candle's body
+    BullishEngulfing,
+    /// Down candle whose body engulfs the previous up candle's body
+    BearishEngulfing,
+}
+
+/// Patterns formed by `current`, using `previous` for the two-candle ones
+pub fn detect_patterns(previous: Option<&Kline>, current: &Kline) -> Vec<CandlePattern> {
+    let open = current.open_price.value();
+    let close = current.close_price.value();
+    let high = current.high_price.value();
+    let low = current.low_price.value();
+    let body = (close - open).abs();
+    let range = high - low;
+
+    let mut patterns = Vec::new();
+    if range.is_zero() {
+        return patterns;
+    }
+    if body * Decimal::TEN <= range {
+        patterns.push(CandlePattern::Doji);
+    }
+    let lower_wick = open.min(close) - low;
+    let upper_wick = high - open.max(close);
+    if !body.is_zero() && lower_wick >= body * Decimal::TWO && upper_wick <= body {
+        patterns.push(CandlePattern::Hammer);
+    }
+
+    if let Some(previous) = previous {
+        let prev_open = previous.open_price.value();
+        let prev_close = previous.close_price.value();
+        if prev_close < prev_open && close > open && open <= prev_close && close >= prev_open {
+            patterns.push(CandlePattern::BullishEngulfing);
+        }
+        if prev_close > prev_open && close < open && open >= prev_close && close <= prev_open {
+            patterns.push(CandlePattern::BearishEngulfing);
+        }
+    }
+    patterns
+}
+
+/// Indicator values for one symbol after a market data update
+#[derive(Debug, Clone)]
+pub struct IndicatorUpdate {
+    pub symbol: Symbol,
+    pub timestamp: i64,
+    /// False for previews of a kline that is still forming
+    pub committed: bool,
+    /// Values in the order the indicators were added, `None` while warming up
+    pub values: Vec<(String, Option<Decimal>)>,
+    /// Candle patterns, only detected on closed klines
+    pub patterns: Vec<CandlePattern>,
+}
+
+impl IndicatorUpdate {
+    /// Value of the indicator added under `name`
+    pub fn get(&self, name: &str) -> Option<Decimal> {
+        self.values
+            .iter()
+            .find(|(indicator, _)| indicator == name)
+            .and_then(|(_, value)| *value)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SymbolState {
+    indicators: Vec<IndicatorKind>,
+    last_closed: Option<Kline>,
+}
+
+/// Per-symbol indicator pipeline over a unified kline or trade stream
+///
+/// Indicators keep only the state they need, so an engine can run for days on a live
+/// stream. Closed klines and trades are committed; forming klines are previewed on a copy
+/// of the state so callers see intrabar values without the half-built bar polluting the
+/// history.
+#[derive(Debug, Clone, Default)]
+pub struct IndicatorEngine {
+    template: Vec<(String, IndicatorKind)>,
+    states: HashMap<Symbol, SymbolState>,
+}
+
+impl IndicatorEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track `indicator` under `name` for every symbol seen
+    #[must_use]
+    pub fn with_indicator(
+        mut self,
+        name: impl Into<String>,
+        indicator: impl Into<IndicatorKind>,
+    ) -> Self {
+        self.template.push((name.into(), indicator.into()));
+        self
+    }
+
+    /// Feed one market data event; tickers, order books and mark prices are ignored
+    pub fn on_market_data(&mut self, data: &MarketDataType) -> Option<IndicatorUpdate> {
+        match data {
+            MarketDataType::Kline(kline) => Some(self.on_kline(kline)),
+            MarketDataType::Trade(trade) => Some(self.on_trade(trade)),
+            MarketDataType::Ticker(_)
+            | MarketDataType::OrderBook(_)
+            | MarketDataType::MarkPrice(_) => None,
+        }
+    }
+
+    /// Commit a closed kline or preview a forming one
+    pub fn on_kline(&mut self, kline: &Kline) -> IndicatorUpdate {
+        let bar = Bar::from(kline);
+        let state = self.state(&kline.symbol);
+
+        if !kline.final_bar {
+            let values = state
+                .indicators
+                .iter()
+                .map(|indicator| indicator.clone().update(&bar))
+                .collect();
+            return self.snapshot(
+                kline.symbol.clone(),
+                kline.open_time,
+                false,
+                values,
+                Vec::new(),
+            );
+        }
+
+        let patterns = detect_patterns(state.last_closed.as_ref(), kline);
+        state.last_closed = Some(kline.clone());
+        let values = state
+            .indicators
+            .iter_mut()
+            .map(|indicator| indicator.update(&bar))
+            .collect();
+        self.snapshot(
+            kline.symbol.clone(),
+            kline.open_time,
+            true,
+            values,
+            patterns,
+        )
+    }
+
+    /// Commit a trade as a single-price bar
+    pub fn on_trade(&mut self, trade: &Trade) -> IndicatorUpdate {
+        let bar = Bar::from(trade);
+        let values = self
+            .state(&trade.symbol)
+            .indicators
+            .iter_mut()
+            .map(|indicator| indicator.update(&bar))
+            .collect();
+        self.snapshot(trade.symbol.clone(), trade.time, true, values, Vec::new())
+    }
+
+    /// Committed value of `name` for `symbol`
+    pub fn value(&self, symbol: &Symbol, name: &str) -> Option<Decimal> {
+        let index = self
+            .template
+            .iter()
+            .position(|(indicator, _)| indicator == name)?;
+        self.states.get(symbol)?.indicators[index].value()
+    }
+
+    /// Run the engine over `stream`, emitting an update per kline or trade
+    ///
+    /// The task exits when either the input stream closes or the receiver is dropped.
+    pub fn spawn(
+        mut self,
+        mut stream: mpsc::Receiver<MarketDataType>,
+    ) -> mpsc::Receiver<IndicatorUpdate> {
+        let (tx, rx) = mpsc::channel(1000);
+        tokio::spawn(async move {
+            while let Some(data) = stream.recv().await {
+                if let Some(update) = self.on_market_data(&data) {
+                    if tx.send(update).await.is_err() {
+                        break; // Receiver dropped
+                    }
+                }
+            }
+        });
+        rx
+    }
+
+    fn state(&mut self, symbol: &Symbol) -> &mut SymbolState {
+        let template = &self.template;
+        self.states
+            .entry(symbol.clone())
+            .or_insert_with(|| SymbolState {
+                indicators: template
+                    .iter()
+                    .map(|(_, indicator)| indicator.clone())
+                    .collect(),
+                last_closed: None,
+            })
+    }
+
+    fn snapshot(
+        &self,
+        symbol: Symbol,
+        timestamp: i64,
+        committed: bool,
+        values: Vec<Option<Decimal>>,
+        patterns: Vec<CandlePattern>,
+    ) -> IndicatorUpdate {
+        IndicatorUpdate {
+            symbol,
+            timestamp,
+            committed,
+            values: self
+                .template
+                .iter()
+                .map(|(name, _)| name.clone())
+                .zip(values)
+                .collect(),
+            patterns,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{conversion, Price, Volume};
+
+    fn dec(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn kline(open: Decimal, high: Decimal, low: Decimal, close: Decimal, final_bar: bool) -> Kline {
+        Kline {
+            symbol: conversion::string_to_symbol("BTCUSDT"),
+            open_time: 0,
+            close_time: 59_999,
+            interval: "1m".to_string(),
+            open_price: Price::new(open),
+            high_price: Price::new(high),
+            low_price: Price::new(low),
+            close_price: Price::new(close),
+            volume: Volume::new(Decimal::ONE),
+            number_of_trades: 1,
+            final_bar,
+        }
+    }
+
+    #[test]
+    fn test_indicators_warm_up_and_update() {
+        let mut ema = Ema::new(3);
+        let mut rsi = Rsi::new(2);
+        let mut atr = Atr::new(2);
+        let bars = [dec("10"), dec("11"), dec("12"), dec("11")].map(|close| Bar {
+            high: close + Decimal::ONE,
+            low: close - Decimal::ONE,
+            close,
+            volume: Decimal::ONE,
+        });
+
+        let emas: Vec<_> = bars.iter().map(|bar| ema.update(bar)).collect();
+        assert_eq!(emas, vec![None, None, Some(dec("11")), Some(dec("11"))]);
+
+        let rsis: Vec<_> = bars.iter().map(|bar| rsi.update(bar)).collect();
+        assert_eq!(rsis[..2], [None, None]);
+        assert_eq!(rsis[2], Some(dec("100")));
+        // Wilder averages after the drop: gain 0.5, loss 0.5
+        assert_eq!(rsis[3], Some(dec("50")));
+
+        let atrs: Vec<_> = bars.iter().map(|bar| atr.update(bar)).collect();
+        assert_eq!(
+            atrs,
+            vec![None, Some(dec("2")), Some(dec("2")), Some(dec("2"))]
+        );
+
+        let mut vwap = Vwap::new();
+        vwap.update(&Bar {
+            high: dec("10"),
+            low: dec("10"),
+            close: dec("10"),
+            volume: dec("1"),
+        });
+        vwap.update(&Bar {
+            high: dec("20"),
+            low: dec("20"),
+            close: dec("20"),
+            volume: dec("3"),
+        });
+        assert_eq!(vwap.value(), Some(dec("17.5")));
+    }
+
+    #[test]
+    fn test_engine_previews_forming_klines() {
+        let mut engine = IndicatorEngine::new().with_indicator("ema2", Ema::new(2));
+        let symbol = conversion::string_to_symbol("BTCUSDT");
+
+        engine.on_kline(&kline(dec("11"), dec("11"), dec("9"), dec("10"), true));
+        let preview = engine.on_kline(&kline(dec("10"), dec("12"), dec("10"), dec("12"), false));
+        assert!(!preview.committed);
+        assert_eq!(preview.get("ema2"), Some(dec("11")));
+        // The preview left the committed state untouched
+        assert_eq!(engine.value(&symbol, "ema2"), None);
+
+        let closed = engine.on_kline(&kline(dec("9"), dec("14"), dec("9"), dec("14"), true));
+        assert_eq!(closed.get("ema2"), Some(dec("12")));
+        assert_eq!(closed.patterns, vec![CandlePattern::BullishEngulfing]);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::{FundingRateSource, MarketDataSource};
+use crate::core::types::{
+    FundingRate, Kline, KlineInterval, Market, MarketDataType, OrderBookMode, SubscriptionType,
+    Symbol, WebSocketConfig,
+};
+use async_trait::async_trait;
+use futures_util::future::try_join_all;
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// What `warmup` fetches and how long it may take
+#[derive(Debug, Clone)]
+pub struct WarmupConfig {
+    /// Interval of the recent candles fetched per symbol; `None` skips candles
+    pub kline_interval: Option<KlineInterval>,
+    /// Candles fetched per symbol
+    pub kline_limit: u32,
+    /// Streams opened for the symbols; empty opens none
+    pub subscription_types: Vec<SubscriptionType>,
+    pub websocket_config: Option<WebSocketConfig>,
+    /// Bound on the whole warmup, so startup latency stays predictable
+    pub timeout: Duration,
+}
+
+impl Default for WarmupConfig {
+    fn default() -> Self {
+        Self {
+            kline_interval: Some(KlineInterval::Minutes1),
+            kline_limit: 100,
+            subscription_types: vec![
+                SubscriptionType::Ticker,
+                SubscriptionType::OrderBook {
+                    depth: None,
+                    mode: OrderBookMode::Snapshot,
+                },
+            ],
+            websocket_config: None,
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl WarmupConfig {
+    #[must_use]
+    pub fn with_klines(mut self, interval: KlineInterval, limit: u32) -> Self {
+        self.kline_interval = Some(interval);
+        self.kline_limit = limit.max(1);
+        self
+    }
+
+    #[must_use]
+    pub fn without_klines(mut self) -> Self {
+        self.kline_interval = None;
+        self
+    }
+
+    #[must_use]
+    pub fn with_subscriptions(mut self, subscription_types: Vec<SubscriptionType>) -> Self {
+        self.subscription_types = subscription_types;
+        self
+    }
+
+    #[must_use]
+    pub fn with_websocket_config(mut self, websocket_config: WebSocketConfig) -> Self {
+        self.websocket_config = Some(websocket_config);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Everything a strategy needs before its first order
+#[derive(Debug)]
+pub struct WarmupState {
+    /// Market, with its precision and size limits, of every requested symbol
+    pub markets: HashMap<Symbol, Market>,
+    /// Recent candles per symbol, oldest first
+    pub klines: HashMap<Symbol, Vec<Kline>>,
+    /// Current funding per symbol; empty for `WarmupExt::warmup`
+    pub funding_rates: HashMap<Symbol, FundingRate>,
+    /// Live data for the symbols, `None` when no subscriptions were requested
+    pub stream: Option<mpsc::Receiver<MarketDataType>>,
+    pub elapsed: Duration,
+}
+
+/// Single-call bootstrap of a connector for a set of symbols
+///
+/// Markets, candles and the WebSocket subscription are requested concurrently, and
+/// the call returns once all of them are in. Any failure, including a symbol the venue
+/// does not list, fails the whole warmup. Connectors built with a response cache keep
+/// the markets, so later lookups are served locally.
+#[async_trait]
+pub trait WarmupExt: MarketDataSource + Sync {
+    async fn warmup(
+        &self,
+        symbols: Vec<Symbol>,
+        config: WarmupConfig,
+    ) -> Result<WarmupState, ExchangeError> {
+        run_warmup(self, symbols, config, async { Ok(Vec::new()) }).await
+    }
+}
+
+impl<T: MarketDataSource + Sync + ?Sized> WarmupExt for T {}
+
+/// `WarmupExt` plus current funding rates (PERPETUAL EXCHANGES ONLY)
+#[async_trait]
+pub trait PerpWarmupExt: MarketDataSource + FundingRateSource + Sync {
+    async fn warmup_perp(
+        &self,
+        symbols: Vec<Symbol>,
+        config: WarmupConfig,
+    ) -> Result<WarmupState, ExchangeError> {
+        let funding = self.poll_funding_rates(Some(symbols.clone()));
+        run_warmup(self, symbols, config, funding).await
+    }
+}
+
+impl<T: MarketDataSource + FundingRateSource + Sync + ?Sized> PerpWarmupExt for T {}
+
+async fn run_warmup<S, F>(
+    source: &S,
+    symbols: Vec<Symbol>,
+    config: WarmupConfig,
+    funding: F,
+) -> Result<WarmupState, ExchangeError>
+where
+    S: MarketDataSource + Sync + ?Sized,
+    F: Future<Output = Result<Vec<FundingRate>, ExchangeError>> + Send,
+{
+    let started = Instant::now();
+    let markets = async {
+        let mut markets: HashMap<Symbol, Market> = source
+            .get_markets()
+            .await?
+            .into_iter()
+            .map(|market| (market.symbol.clone(), market))
+            .collect();
+        symbols
+            .iter()
+            .map(|symbol| {
+                markets.remove_entry(symbol).ok_or_else(|| {
+                    ExchangeError::InvalidParameters(format!("Unknown symbol: {}", symbol))
+                })
+            })
+            .collect::<Result<HashMap<_, _>, _>>()
+    };
+    let klines = async {
+        let Some(interval) = config.kline_interval else {
+            return Ok(HashMap::new());
+        };
+        try_join_all(symbols.iter().map(|symbol| async move {
+            let mut klines = source
+                .get_klines(
+                    symbol.clone(),
+                    interval,
+                    Some(config.kline_limit),
+                    None,
+                    None,
+                )
+                .await?;
+            klines.sort_by_key(|kline| kline.open_time);
+            Ok::<_, ExchangeError>((symbol.clone(), klines))
+        }))
+        .await
+        .map(|klines| klines.into_iter().collect())
+    };
+    let funding = async {
+        Ok::<_, ExchangeError>(
+            funding
+                .await?
+                .into_iter()
+                .filter(|rate| symbols.contains(&rate.symbol))
+                .map(|rate| (rate.symbol.clone(), rate))
+                .collect(),
+        )
+    };
+    let stream = async {
+        if config.subscription_types.is_empty() {
+            return Ok(None);
+        }
+        source
+            .subscribe_market_data(
+                symbols.clone(),
+                config.subscription_types.clone(),
+                config.websocket_config.clone(),
+            )
+            .await
+            .map(Some)
+    };
+
+    let (markets, klines, funding_rates, stream) = tokio::time::timeout(config.timeout, async {
+        tokio::try_join!(markets, klines, funding, stream)
+    })
+    .await
+    .map_err(|_| {
+        ExchangeError::ConnectionTimeout(format!(
+            "Warmup did not finish within {:?}",
+            config.timeout
+        ))
+    })??;
+
+    Ok(WarmupState {
+        markets,
+        klines,
+        funding_rates,
+        stream,
+        elapsed: started.elapsed(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::traits::{RestMarketData, StreamingMarketData};
+    use crate::core::types::{conversion, ContractStyle, MarketStatus};
+
+    struct StubSource {
+        listed: Vec<&'static str>,
+    }
+
+    #[async_trait]
+    impl RestMarketData for StubSource {
+        async fn get_markets(&self) -> Result<Vec<Market>, ExchangeError> {
+            Ok(self
+                .listed
+                .iter()
+                .map(|symbol| Market {
+                    symbol: conversion::string_to_symbol(symbol),
+                    status: MarketStatus::Trading,
+                    base_precision: 8,
+                    quote_precision: 2,
+                    min_qty: None,
+                    max_qty: None,
+                    min_price: None,
+                    max_price: None,
+                    contract_style: ContractStyle::default(),
+                    contract_size: None,
+                    extensions: None,
+                })
+                .collect())
+        }
+
+        async fn get_klines(
+            &self,
+            symbol: Symbol,
+            interval: KlineInterval,
+            _limit: Option<u32>,
+            _start_time: Option<i64>,
+            _end_time: Option<i64>,
+        ) -> Result<Vec<Kline>, ExchangeError> {
+            let kline = |open_time| Kline {
+                symbol: symbol.clone(),
+                open_time,
+                close_time: open_time + 59_999,
+                interval: interval.to_binance_format(),
+                open_price: conversion::string_to_price("1"),
+                high_price: conversion::string_to_price("1"),
+                low_price: conversion::string_to_price("1"),
+                close_price: conversion::string_to_price("1"),
+                volume: conversion::string_to_volume("1"),
+                number_of_trades: 1,
+                final_bar: true,
+            };
+            // Newest first, as some venues return them
+            Ok(vec![kline(60_000), kline(0)])
+        }
+    }
+
+    #[async_trait]
+    impl StreamingMarketData for StubSource {
+        async fn subscribe_market_data(
+            &self,
+            _symbols: Vec<Symbol>,
+            _subscription_types: Vec<SubscriptionType>,
+            _config: Option<WebSocketConfig>,
+        ) -> Result<mpsc::Receiver<MarketDataType>, ExchangeError> {
+            Ok(mpsc::channel(1).1)
+        }
+
+        fn get_websocket_url(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_warmup_primes_markets_klines_and_stream() {
+        let source = StubSource {
+            listed: vec!["BTCUSDT", "ETHUSDT", "SOLUSDT"],
+        };
+        let symbols = vec![Symbol::from("BTCUSDT"), Symbol::from("ETHUSDT")];
+        let state = source
+            .warmup(symbols.clone(), WarmupConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(state.markets.len(), 2);
+        assert!(state.markets.contains_key(&symbols[0]));
+        let opens: Vec<_> = state.klines[&symbols[1]]
+            .iter()
+            .map(|kline| kline.open_time)
+            .collect();
+        assert_eq!(opens, vec![0, 60_000]);
+        assert!(state.funding_rates.is_empty());
+        assert!(state.stream.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_warmup_rejects_unlisted_symbols() {
+        let source = StubSource {
+            listed: vec!["BTCUSDT"],
+        };
+        let result = source
+            .warmup(
+                vec![Symbol::from("DOGEUSDT")],
+                WarmupConfig::default().with_subscriptions(Vec::new()),
+            )
+            .await;
+        assert!(matches!(result, Err(ExchangeError::InvalidParameters(_))));
+    }
+}
@@ -0,0 +1,454 @@
+use crate::core::errors::ExchangeError;
+use crate::core::traits::OrderPlacer;
+use crate::core::types::{
+    OrderRequest, OrderResponse, OrderSide, Position, Price, Quantity, Symbol,
+};
+use crate::utils::paper::Fill;
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use reqwest::Url;
+use rust_decimal::Decimal;
+use secrecy::{ExposeSecret, Secret};
+use serde::Serialize;
+use sha2::Sha256;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Header carrying the hex HMAC-SHA256 of `"{timestamp}.{body}"`
+pub const SIGNATURE_HEADER: &str = "X-Lotusx-Signature";
+/// Header carrying the millisecond timestamp that was signed
+pub const TIMESTAMP_HEADER: &str = "X-Lotusx-Timestamp";
+
+/// Normalized trading activity posted to the webhook
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OrderEvent {
+    /// An order was accepted or modified
+    Order {
+        exchange: String,
+        order: OrderResponse,
+    },
+    Fill {
+        exchange: String,
+        order_id: String,
+        symbol: Symbol,
+        side: OrderSide,
+        price: Price,
+        quantity: Quantity,
+        fee: Decimal,
+        time: i64,
+    },
+    Position {
+        exchange: String,
+        position: Position,
+        time: i64,
+    },
+}
+
+impl OrderEvent {
+    pub fn fill(exchange: &str, fill: &Fill) -> Self {
+        Self::Fill {
+            exchange: exchange.to_string(),
+            order_id: fill.order_id.clone(),
+            symbol: fill.symbol.clone(),
+            side: fill.side.clone(),
+            price: fill.price,
+            quantity: fill.quantity,
+            fee: fill.fee,
+            time: fill.time,
+        }
+    }
+
+    pub fn position(exchange: &str, position: Position) -> Self {
+        Self::Position {
+            exchange: exchange.to_string(),
+            position,
+            time: chrono::Utc::now().timestamp_millis(),
+        }
+    }
+}
+
+/// Endpoint and delivery settings for a `WebhookSink`
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// Key for the HMAC signature header
+    pub secret: Secret<String>,
+    /// Delivery attempts per event before it is dropped
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubles on each further retry
+    pub retry_backoff: Duration,
+    /// Events waiting for delivery before `send` starts refusing them
+    pub queue_capacity: usize,
+    pub timeout: Duration,
+}
+
+impl WebhookConfig {
+    pub fn new(url: impl Into<String>, secret: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            secret: Secret::new(secret.into()),
+            max_attempts: 5,
+            retry_backoff: Duration::from_millis(500),
+            queue_capacity: 1000,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_queue_capacity(mut self, queue_capacity: usize) -> Self {
+        self.queue_capacity = queue_capacity;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Counters kept by a `WebhookSink`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WebhookStats {
+    pub delivered: u64,
+    /// Failed attempts that were retried
+    pub retried: u64,
+    /// Events given up on after `max_attempts`
+    pub failed: u64,
+    /// Events refused because the queue was full
+    pub dropped: u64,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    delivered: AtomicU64,
+    retried: AtomicU64,
+    failed: AtomicU64,
+    dropped: AtomicU64,
+}
+
+/// Hex HMAC-SHA256 signature for a webhook body sent at `timestamp`
+pub fn sign_payload(secret: &str, timestamp: i64, body: &[u8]) -> Result<String, ExchangeError> {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
+        .map_err(|e| ExchangeError::AuthError(format!("Failed to create HMAC: {}", e)))?;
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Background dispatcher posting `OrderEvent`s to an HTTPS endpoint
+///
+/// Events are delivered one at a time in the order they were sent. A failed post
+/// (transport error or non-2xx status) is retried with exponential backoff, holding
+/// back later events so the receiver never sees them out of order. Plain `http` is
+/// only accepted for loopback hosts.
+pub struct WebhookSink {
+    tx: mpsc::Sender<OrderEvent>,
+    counters: Arc<Counters>,
+}
+
+impl WebhookSink {
+    /// Validate the config and start the delivery task
+    pub fn spawn(config: WebhookConfig) -> Result<Self, ExchangeError> {
+        let url = Url::parse(&config.url).map_err(|e| {
+            ExchangeError::ConfigurationError(format!("Invalid webhook URL: {}", e))
+        })?;
+        let loopback = matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+        if url.scheme() != "https" && !(url.scheme() == "http" && loopback) {
+            return Err(ExchangeError::ConfigurationError(
+                "Webhook URL must use https".to_string(),
+            ));
+        }
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|e| {
+                ExchangeError::ConfigurationError(format!("Failed to build HTTP client: {}", e))
+            })?;
+
+        let (tx, rx) = mpsc::channel(config.queue_capacity.max(1));
+        let counters = Arc::new(Counters::default());
+        tokio::spawn(deliver(client, url, config, rx, counters.clone()));
+        Ok(Self { tx, counters })
+    }
+
+    /// Queue an event without waiting, failing if the queue is full
+    pub fn send(&self, event: OrderEvent) -> Result<(), ExchangeError> {
+        self.tx.try_send(event).map_err(|e| {
+            self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+            ExchangeError::Other(format!("Webhook queue unavailable: {}", e))
+        })
+    }
+
+    pub fn stats(&self) -> WebhookStats {
+        WebhookStats {
+            delivered: self.counters.delivered.load(Ordering::Relaxed),
+            retried: self.counters.retried.load(Ordering::Relaxed),
+            failed: self.counters.failed.load(Ordering::Relaxed),
+            dropped: self.counters.dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+async fn deliver(
+    client: reqwest::Client,
+    url: Url,
+    config: WebhookConfig,
+    mut rx: mpsc::Receiver<OrderEvent>,
+    counters: Arc<Counters>,
+) {
+    while let Some(event) = rx.recv().await {
+        let body = match serde_json::to_vec(&event) {
+            Ok(body) => body,
+            Err(e) => {
+                warn!(error = %e, "Failed to serialize webhook event");
+                counters.failed.fetch_add(1, Ordering::Relaxed);
+                continue;
+            }
+        };
+
+        let mut backoff = config.retry_backoff;
+        for attempt in 1..=config.max_attempts.max(1) {
+            match post(&client, &url, config.secret.expose_secret(), &body).await {
+                Ok(()) => {
+                    counters.delivered.fetch_add(1, Ordering::Relaxed);
+                    break;
+                }
+                Err(e) if attempt < config.max_attempts => {
+                    warn!(error = %e, attempt, "Webhook delivery failed, retrying");
+                    counters.retried.fetch_add(1, Ordering::Relaxed);
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                }
+                Err(e) => {
+                    warn!(error = %e, "Webhook delivery failed, dropping event");
+                    counters.failed.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+}
+
+async fn post(
+    client: &reqwest::Client,
+    url: &Url,
+    secret: &str,
+    body: &[u8],
+) -> Result<(), ExchangeError> {
+    let timestamp = chrono::Utc::now().timestamp_millis();
+    let signature = sign_payload(secret, timestamp, body)?;
+    let response = client
+        .post(url.clone())
+        .header("Content-Type", "application/json")
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header(SIGNATURE_HEADER, signature)
+        .body(body.to_vec())
+        .send()
+        .await?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(ExchangeError::ServerError(format!(
+            "Webhook endpoint returned {}",
+            response.status()
+        )))
+    }
+}
+
+/// `OrderPlacer` wrapper that reports accepted orders to a `WebhookSink`
+pub struct WebhookPlacer<P> {
+    inner: P,
+    exchange: String,
+    sink: Arc<WebhookSink>,
+}
+
+impl<P: OrderPlacer> WebhookPlacer<P> {
+    pub fn new(inner: P, exchange: impl Into<String>, sink: Arc<WebhookSink>) -> Self {
+        Self {
+            inner,
+            exchange: exchange.into(),
+            sink,
+        }
+    }
+
+    pub const fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    fn report(&self, order: &OrderResponse) {
+        let event = OrderEvent::Order {
+            exchange: self.exchange.clone(),
+            order: order.clone(),
+        };
+        if let Err(e) = self.sink.send(event) {
+            warn!(error = %e, order_id = %order.order_id, "Order event not queued");
+        }
+    }
+}
+
+#[async_trait]
+impl<P: OrderPlacer + Send + Sync> OrderPlacer for WebhookPlacer<P> {
+    async fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        let response = self.inner.place_order(order).await?;
+        self.report(&response);
+        Ok(response)
+    }
+
+    async fn cancel_order(&self, symbol: Symbol, order_id: String) -> Result<(), ExchangeError> {
+        self.inner.cancel_order(symbol, order_id).await
+    }
+
+    async fn cancel_order_by_client_id(
+        &self,
+        symbol: Symbol,
+        client_order_id: String,
+    ) -> Result<(), ExchangeError> {
+        self.inner
+            .cancel_order_by_client_id(symbol, client_order_id)
+            .await
+    }
+
+    async fn modify_order(
+        &self,
+        order_id: String,
+        order: OrderRequest,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let response = self.inner.modify_order(order_id, order).await?;
+        self.report(&response);
+        Ok(response)
+    }
+
+    fn supports_trading(&self) -> bool {
+        self.inner.supports_trading()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::paper::Liquidity;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Minimal HTTP server answering each request with the next status in `statuses`
+    /// and forwarding the raw request text
+    async fn serve(statuses: Vec<u16>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel(16);
+        tokio::spawn(async move {
+            for status in statuses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 4096];
+                loop {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&request);
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length = head
+                            .lines()
+                            .find_map(|line| {
+                                let (name, value) = line.split_once(':')?;
+                                name.eq_ignore_ascii_case("content-length")
+                                    .then(|| value.trim().parse::<usize>().ok())?
+                            })
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            break;
+                        }
+                    }
+                }
+                let reply = format!(
+                    "HTTP/1.1 {} X\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    status
+                );
+                socket.write_all(reply.as_bytes()).await.unwrap();
+                tx.send(String::from_utf8_lossy(&request).into_owned())
+                    .await
+                    .unwrap();
+            }
+        });
+        (url, rx)
+    }
+
+    fn header<'a>(request: &'a str, name: &str) -> &'a str {
+        request
+            .lines()
+            .find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then_some(value.trim())
+            })
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rejects_plain_http_to_remote_hosts() {
+        assert!(WebhookSink::spawn(WebhookConfig::new("http://example.com/hook", "s")).is_err());
+        assert!(WebhookSink::spawn(WebhookConfig::new("https://example.com/hook", "s")).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_delivered_with_valid_signature() {
+        let (url, mut requests) = serve(vec![500, 200]).await;
+        let sink = WebhookSink::spawn(
+            WebhookConfig::new(url, "topsecret").with_retry_backoff(Duration::from_millis(10)),
+        )
+        .unwrap();
+
+        let fill = Fill {
+            order_id: "42".to_string(),
+            symbol: Symbol::new("BTC", "USDT").unwrap(),
+            side: OrderSide::Buy,
+            price: Price::new(Decimal::from(50_000)),
+            quantity: Quantity::new(Decimal::ONE),
+            fee: Decimal::from(5),
+            liquidity: Liquidity::Taker,
+            time: 1,
+        };
+        sink.send(OrderEvent::fill("binance", &fill)).unwrap();
+
+        let _failed = requests.recv().await.unwrap();
+        let delivered = requests.recv().await.unwrap();
+        let body = delivered.split_once("\r\n\r\n").unwrap().1;
+        let timestamp: i64 = header(&delivered, TIMESTAMP_HEADER).parse().unwrap();
+        assert_eq!(
+            header(&delivered, SIGNATURE_HEADER),
+            sign_payload("topsecret", timestamp, body.as_bytes()).unwrap()
+        );
+        let json: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(json["type"], "fill");
+        assert_eq!(json["order_id"], "42");
+
+        // Counters are bumped after the response is read
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(
+            sink.stats(),
+            WebhookStats {
+                delivered: 1,
+                retried: 1,
+                failed: 0,
+                dropped: 0,
+            }
+        );
+    }
+}
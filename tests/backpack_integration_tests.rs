@@ -0,0 +1,209 @@
+use lotusx::core::config::ExchangeConfig;
+use lotusx::core::kernel::ReqwestRest;
+use lotusx::core::traits::{AccountInfo, FundingRateSource, OrderHistorySource};
+use lotusx::core::types::{
+    InstrumentType, MarginMode, OrderSide, OrderStatus, OrderType, PositionSide, Symbol,
+};
+use lotusx::exchanges::backpack::{build_connector, BackpackConnector};
+use rust_decimal::Decimal;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// RFC 8032 test 1 secret, as in `signer_tests`
+const BACKPACK_SECRET: &str = "nWGxne/9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A=";
+
+const MARK_PRICES: &str = r#"[
+    {"symbol": "BTC_USDC_PERP", "markPrice": "65010.5", "indexPrice": "65000.1",
+     "fundingRate": "0.0000125", "nextFundingTimestamp": 1718000000000},
+    {"symbol": "ETH_USDC_PERP", "markPrice": "3500.2", "indexPrice": "3499.8",
+     "fundingRate": "-0.00002", "nextFundingTimestamp": 1718000000000}
+]"#;
+
+const MARKETS: &str = r#"[
+    {"symbol": "BTC_USDC_PERP", "baseSymbol": "BTC", "quoteSymbol": "USDC",
+     "marketType": "PERP", "orderBookState": "Open", "fundingInterval": 3600000,
+     "filters": {"price": {"tickSize": "0.1"}, "quantity": {"stepSize": "0.00001"}}},
+    {"symbol": "ETH_USDC_PERP", "baseSymbol": "ETH", "quoteSymbol": "USDC",
+     "marketType": "PERP", "orderBookState": "Open", "fundingInterval": 3600000,
+     "filters": {"price": {"tickSize": "0.01"}, "quantity": {"stepSize": "0.0001"}}}
+]"#;
+
+// Newest first, as Backpack pages them
+const FUNDING_RATES: &str = r#"[
+    {"symbol": "BTC_USDC_PERP", "fundingRate": "0.00003", "intervalEndTimestamp": "2024-06-10T03:00:00"},
+    {"symbol": "BTC_USDC_PERP", "fundingRate": "0.00002", "intervalEndTimestamp": "2024-06-10T02:00:00"},
+    {"symbol": "BTC_USDC_PERP", "fundingRate": "-0.00001", "intervalEndTimestamp": "2024-06-10T01:00:00"}
+]"#;
+
+const POSITIONS: &str = r#"[
+    {"symbol": "BTC_USDC_PERP", "netQuantity": "-0.5", "entryPrice": "65100",
+     "markPrice": "65010.5", "pnlUnrealized": "44.75", "estLiquidationPrice": "90000",
+     "positionId": "1234", "breakEvenPrice": "65120", "imf": "0.1",
+     "pnlRealized": "0", "cumulativeFundingPayment": "-1.2"}
+]"#;
+
+const ORDER_HISTORY: &str = r#"[
+    {"id": "112", "clientId": 7, "symbol": "BTC_USDC_PERP", "side": "Ask",
+     "orderType": "Limit", "quantity": "0.5", "price": "66000", "timeInForce": "GTC",
+     "status": "Cancelled", "executedQuantity": "0.2", "executedQuoteQuantity": "13200",
+     "createdAt": "2024-06-10T03:00:00.250"},
+    {"id": "111", "symbol": "BTC_USDC_PERP", "side": "Bid", "orderType": "Market",
+     "quantity": "0.1", "status": "Filled", "executedQuantity": "0.1",
+     "executedQuoteQuantity": "6500", "createdAt": "2024-06-10T01:00:00"}
+]"#;
+
+/// HTTP server answering each request with the body routed to its path
+async fn serve_backpack() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let mut buf = [0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+                let body = match path.split('?').next().unwrap_or_default() {
+                    "/api/v1/markPrices" => MARK_PRICES,
+                    "/api/v1/markets" => MARKETS,
+                    "/api/v1/fundingRates" => FUNDING_RATES,
+                    "/api/v1/position" => POSITIONS,
+                    "/wapi/v1/history/orders" => ORDER_HISTORY,
+                    _ => "[]",
+                };
+                let reply = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                socket.write_all(reply.as_bytes()).await.unwrap();
+            });
+        }
+    });
+    url
+}
+
+fn btc_perp() -> Symbol {
+    Symbol::new("BTC", "USDC")
+        .unwrap()
+        .with_instrument_type(InstrumentType::Perpetual)
+}
+
+async fn mock_connector(config: ExchangeConfig) -> BackpackConnector<ReqwestRest, ()> {
+    let url = serve_backpack().await;
+    build_connector(config.base_url(url)).expect("Failed to create connector")
+}
+
+#[tokio::test]
+async fn test_funding_rates_join_mark_prices_and_markets() {
+    let connector = mock_connector(ExchangeConfig::read_only()).await;
+
+    let rates = connector.get_all_funding_rates().await.unwrap();
+    assert_eq!(rates.len(), 2);
+    let btc = &rates[0];
+    assert_eq!(btc.symbol, btc_perp());
+    assert_eq!(btc.funding_rate, Some(Decimal::new(125, 7)));
+    assert_eq!(btc.next_funding_time, Some(1_718_000_000_000));
+    assert_eq!(btc.mark_price.unwrap().value(), Decimal::new(650_105, 1));
+    assert_eq!(btc.funding_interval, Some(Duration::from_secs(3600)));
+
+    let eth = connector
+        .get_funding_rates(Some(vec![rates[1].symbol.clone()]))
+        .await
+        .unwrap();
+    assert_eq!(eth.len(), 1);
+    assert_eq!(eth[0].funding_rate, Some(Decimal::new(-2, 5)));
+}
+
+#[tokio::test]
+async fn test_funding_rate_history_is_oldest_first_and_filtered() {
+    let connector = mock_connector(ExchangeConfig::read_only()).await;
+    let history = connector
+        .get_funding_rate_history(btc_perp(), None, None, None)
+        .await
+        .unwrap();
+    let times: Vec<i64> = history.iter().map(|rate| rate.timestamp).collect();
+    assert_eq!(
+        times,
+        [1_717_981_200_000, 1_717_984_800_000, 1_717_988_400_000]
+    );
+    assert_eq!(history[0].funding_time, Some(1_717_981_200_000));
+
+    // Backpack takes no time range; it is applied to the fetched page
+    let history = connector
+        .get_funding_rate_history(btc_perp(), Some(1_717_984_800_000), None, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].funding_rate, Some(Decimal::new(3, 5)));
+}
+
+#[tokio::test]
+async fn test_positions_from_perp_endpoint() {
+    let connector = mock_connector(ExchangeConfig::new(
+        "api_key".to_string(),
+        BACKPACK_SECRET.to_string(),
+    ))
+    .await;
+    assert!(connector.supports_account());
+
+    let positions = connector.get_positions().await.unwrap();
+    assert_eq!(positions.len(), 1);
+    let position = &positions[0];
+    assert_eq!(position.symbol, btc_perp());
+    assert!(matches!(position.position_side, PositionSide::Short));
+    assert_eq!(position.position_amount.value(), Decimal::new(-5, 1));
+    assert_eq!(position.leverage, Decimal::from(10));
+    assert_eq!(position.margin_mode, Some(MarginMode::Cross));
+    assert_eq!(
+        position.extensions.as_ref().unwrap()["cumulativeFundingPayment"],
+        "-1.2"
+    );
+}
+
+#[tokio::test]
+async fn test_order_history_decodes_entries() {
+    let connector = mock_connector(ExchangeConfig::new(
+        "api_key".to_string(),
+        BACKPACK_SECRET.to_string(),
+    ))
+    .await;
+
+    let page = connector
+        .get_order_history(btc_perp(), None, None, None)
+        .await
+        .unwrap();
+    assert!(page.next_cursor.is_none());
+    assert_eq!(page.orders.len(), 2);
+
+    let cancelled = &page.orders[0];
+    assert_eq!(cancelled.order_id, "112");
+    assert_eq!(cancelled.client_order_id, "7");
+    assert_eq!(cancelled.symbol, btc_perp());
+    assert!(matches!(cancelled.side, OrderSide::Sell));
+    assert!(matches!(cancelled.order_type, OrderType::Limit));
+    assert_eq!(cancelled.status, OrderStatus::Canceled);
+    assert_eq!(cancelled.price.unwrap().value(), Decimal::from(66_000));
+    assert_eq!(cancelled.filled_quantity.value(), Decimal::new(2, 1));
+    assert_eq!(
+        cancelled.average_price.unwrap().value(),
+        Decimal::from(66_000)
+    );
+    assert_eq!(cancelled.created_time, 1_717_988_400_250);
+
+    let filled = &page.orders[1];
+    assert!(matches!(filled.side, OrderSide::Buy));
+    assert!(matches!(filled.order_type, OrderType::Market));
+    assert_eq!(filled.status, OrderStatus::Filled);
+    assert!(filled.price.is_none());
+
+    // The time range is applied to the fetched page
+    let page = connector
+        .get_order_history(btc_perp(), Some(1_717_984_800_000), None, None)
+        .await
+        .unwrap();
+    assert_eq!(page.orders.len(), 1);
+    assert_eq!(page.orders[0].order_id, "112");
+}
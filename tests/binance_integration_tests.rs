@@ -2,9 +2,13 @@
 #![allow(clippy::explicit_iter_loop)]
 
 use lotusx::core::config::ExchangeConfig;
-use lotusx::core::traits::{AccountInfo, MarketDataSource};
-use lotusx::exchanges::binance::build_connector;
-use lotusx::exchanges::binance_perp::build_connector as build_binance_perp_connector;
+use lotusx::core::kernel::{ReqwestRest, TungsteniteWs};
+use lotusx::core::traits::{AccountInfo, RestMarketData, StreamingMarketData};
+use lotusx::exchanges::binance::{build_connector_with_websocket, BinanceCodec, BinanceConnector};
+use lotusx::exchanges::binance_perp::{
+    build_connector_with_websocket as build_binance_perp_connector, BinancePerpCodec,
+    BinancePerpConnector,
+};
 use std::time::Duration;
 use tokio::time::timeout;
 
@@ -14,31 +18,29 @@ fn create_test_config() -> ExchangeConfig {
 }
 
 /// Create binance spot connector for testing
-fn create_binance_spot_connector(
-) -> lotusx::exchanges::binance::BinanceConnector<lotusx::core::kernel::ReqwestRest, ()> {
+fn create_binance_spot_connector() -> BinanceConnector<ReqwestRest, TungsteniteWs<BinanceCodec>> {
     let config = create_test_config();
-    build_connector(config).expect("Failed to create connector")
+    build_connector_with_websocket(config).expect("Failed to create connector")
 }
 
 /// Create binance perpetual connector for testing  
 fn create_binance_perp_connector(
-) -> lotusx::exchanges::binance_perp::BinancePerpConnector<lotusx::core::kernel::ReqwestRest, ()> {
+) -> BinancePerpConnector<ReqwestRest, TungsteniteWs<BinancePerpCodec>> {
     let config = create_test_config();
     build_binance_perp_connector(config).expect("Failed to create connector")
 }
 
 /// Create binance spot connector from environment
-fn create_binance_spot_from_env() -> Result<
-    lotusx::exchanges::binance::BinanceConnector<lotusx::core::kernel::ReqwestRest, ()>,
-    Box<dyn std::error::Error>,
-> {
+fn create_binance_spot_from_env(
+) -> Result<BinanceConnector<ReqwestRest, TungsteniteWs<BinanceCodec>>, Box<dyn std::error::Error>>
+{
     let config = ExchangeConfig::from_env_file("BINANCE")?;
-    Ok(build_connector(config)?)
+    Ok(build_connector_with_websocket(config)?)
 }
 
 /// Create binance perpetual connector from environment  
 fn create_binance_perp_from_env() -> Result<
-    lotusx::exchanges::binance_perp::BinancePerpConnector<lotusx::core::kernel::ReqwestRest, ()>,
+    BinancePerpConnector<ReqwestRest, TungsteniteWs<BinancePerpCodec>>,
     Box<dyn std::error::Error>,
 > {
     let config = ExchangeConfig::from_env_file("BINANCE_PERP")
@@ -129,7 +131,7 @@ mod binance_spot_tests {
         let result = timeout(
             Duration::from_secs(30),
             connector.get_klines(
-                "BTCUSDT".to_string(),
+                "BTCUSDT".into(),
                 lotusx::core::types::KlineInterval::Minutes1,
                 Some(10),
                 None,
@@ -183,10 +185,13 @@ mod binance_spot_tests {
     async fn test_subscribe_market_data_structure() {
         let connector = create_binance_spot_connector();
 
-        let symbols = vec!["btcusdt".to_string(), "ethusdt".to_string()];
+        let symbols = vec!["btcusdt".into(), "ethusdt".into()];
         let subscription_types = vec![
             lotusx::core::types::SubscriptionType::Ticker,
-            lotusx::core::types::SubscriptionType::OrderBook { depth: Some(10) },
+            lotusx::core::types::SubscriptionType::OrderBook {
+                depth: Some(10),
+                mode: lotusx::core::types::OrderBookMode::Snapshot,
+            },
             lotusx::core::types::SubscriptionType::Trades,
             lotusx::core::types::SubscriptionType::Klines {
                 interval: lotusx::core::types::KlineInterval::Minutes1,
@@ -435,7 +440,7 @@ mod binance_comprehensive_tests {
         )
         .testnet(true);
 
-        let connector = build_connector(config).expect("Failed to create connector");
+        let connector = build_connector_with_websocket(config).expect("Failed to create connector");
 
         let result = timeout(
             Duration::from_secs(15),
@@ -555,7 +560,7 @@ mod binance_comprehensive_tests {
         let result = timeout(
             Duration::from_secs(30),
             connector.get_klines(
-                "BTCUSDT".to_string(),
+                "BTCUSDT".into(),
                 lotusx::core::types::KlineInterval::Hours1,
                 Some(5),
                 None,
@@ -659,14 +664,133 @@ mod binance_config_tests {
         ];
 
         for (i, config) in configs.into_iter().enumerate() {
-            let spot = build_connector(config.clone()).expect("Failed to create connector");
+            let spot =
+                build_connector_with_websocket(config.clone()).expect("Failed to create connector");
             let perp = build_binance_perp_connector(config).expect("Failed to create connector");
 
             // Should not panic during creation
-            let _spot_ws = MarketDataSource::get_websocket_url(&spot);
-            let _perp_ws = MarketDataSource::get_websocket_url(&perp);
+            let _spot_ws = StreamingMarketData::get_websocket_url(&spot);
+            let _perp_ws = StreamingMarketData::get_websocket_url(&perp);
 
             println!("✅ Binance connector creation test {} passed", i);
         }
     }
 }
+
+mod depth_subscription_tests {
+    use lotusx::core::kernel::WsCodec;
+    use lotusx::core::types::{OrderBookMode, SubscriptionType};
+    use lotusx::exchanges::binance::codec::create_binance_stream_identifiers;
+    use lotusx::exchanges::binance::{BinanceCodec, BinanceMessage};
+    use tokio_tungstenite::tungstenite::Message;
+
+    #[test]
+    fn test_requested_depth_maps_to_partial_stream() {
+        let symbols = vec!["BTCUSDT".to_string()];
+        let streams = create_binance_stream_identifiers(
+            &symbols,
+            &[
+                SubscriptionType::OrderBook {
+                    depth: Some(7),
+                    mode: OrderBookMode::Snapshot,
+                },
+                SubscriptionType::OrderBook {
+                    depth: Some(500),
+                    mode: OrderBookMode::Snapshot,
+                },
+                SubscriptionType::OrderBook {
+                    depth: None,
+                    mode: OrderBookMode::Snapshot,
+                },
+                SubscriptionType::OrderBook {
+                    depth: Some(5),
+                    mode: OrderBookMode::Delta,
+                },
+                SubscriptionType::OrderBook {
+                    depth: Some(5),
+                    mode: OrderBookMode::Aggregated,
+                },
+            ],
+        );
+        assert_eq!(
+            streams,
+            vec![
+                "btcusdt@depth10@100ms",
+                "btcusdt@depth20@100ms",
+                "btcusdt@depth20@100ms",
+                "btcusdt@depth@100ms",
+                "btcusdt@depth5"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_partial_depth_snapshot_takes_symbol_from_stream() {
+        let message = serde_json::json!({
+            "stream": "btcusdt@depth5@100ms",
+            "data": {
+                "lastUpdateId": 160,
+                "bids": [["0.0024", "10"]],
+                "asks": [["0.0026", "100"]]
+            }
+        });
+        let decoded = BinanceCodec
+            .decode_message(Message::Text(message.to_string()))
+            .expect("decodes");
+
+        let Some(BinanceMessage::OrderBook(book)) = decoded else {
+            panic!("expected an order book");
+        };
+        assert_eq!(book.symbol, "BTCUSDT");
+        assert_eq!(book.final_update_id, 160);
+        assert_eq!(book.bids.len(), 1);
+    }
+}
+
+mod agg_trade_tests {
+    use lotusx::core::kernel::WsCodec;
+    use lotusx::core::types::SubscriptionType;
+    use lotusx::exchanges::binance::codec::create_binance_stream_identifiers;
+    use lotusx::exchanges::binance::{BinanceCodec, BinanceMessage};
+    use tokio_tungstenite::tungstenite::Message;
+
+    #[test]
+    fn test_agg_trades_subscribe_to_agg_trade_stream() {
+        let streams = create_binance_stream_identifiers(
+            &["ETHUSDT".to_string()],
+            &[SubscriptionType::AggTrades],
+        );
+        assert_eq!(streams, vec!["ethusdt@aggTrade"]);
+    }
+
+    #[test]
+    fn test_agg_trade_decodes_as_trade_with_aggregate_id() {
+        let message = serde_json::json!({
+            "stream": "ethusdt@aggTrade",
+            "data": {
+                "e": "aggTrade",
+                "E": 1_672_515_782_136_u64,
+                "s": "ETHUSDT",
+                "a": 26129,
+                "p": "1200.50",
+                "q": "3.2",
+                "f": 100,
+                "l": 105,
+                "T": 1_672_515_782_136_u64,
+                "m": true,
+                "M": true
+            }
+        });
+        let decoded = BinanceCodec
+            .decode_message(Message::Text(message.to_string()))
+            .expect("decodes");
+
+        let Some(BinanceMessage::Trade(trade)) = decoded else {
+            panic!("expected a trade");
+        };
+        assert_eq!(trade.symbol, "ETHUSDT");
+        assert_eq!(trade.id, 26129);
+        assert_eq!(trade.quantity, "3.2");
+        assert!(trade.is_buyer_maker);
+    }
+}
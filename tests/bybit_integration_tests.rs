@@ -2,8 +2,8 @@
 #![allow(clippy::explicit_iter_loop)]
 
 use lotusx::core::config::ExchangeConfig;
-use lotusx::core::traits::{AccountInfo, MarketDataSource};
-use lotusx::core::types::SubscriptionType;
+use lotusx::core::traits::{AccountInfo, RestMarketData, StreamingMarketData};
+use lotusx::core::types::{OrderBookMode, SubscriptionType};
 use lotusx::exchanges::bybit::build_connector;
 use std::time::Duration;
 use tokio::time::timeout;
@@ -116,10 +116,13 @@ mod bybit_spot_tests {
     async fn test_subscribe_market_data_structure() {
         let connector = create_bybit_spot_connector();
 
-        let symbols = vec!["BTCUSDT".to_string()];
+        let symbols = vec!["BTCUSDT".into()];
         let subscription_types = vec![
             SubscriptionType::Ticker,
-            SubscriptionType::OrderBook { depth: Some(20) },
+            SubscriptionType::OrderBook {
+                depth: Some(20),
+                mode: OrderBookMode::Snapshot,
+            },
             SubscriptionType::Trades,
         ];
 
@@ -431,3 +434,96 @@ mod configuration_tests {
         println!("✅ Connector creation test passed");
     }
 }
+
+#[cfg(test)]
+mod websocket_parsing_tests {
+    use lotusx::core::types::MarketDataType;
+    use lotusx::exchanges::bybit_perp::conversions::{
+        convert_bybit_perp_account_summary, parse_mark_price_message, parse_websocket_message,
+    };
+    use lotusx::exchanges::bybit_perp::types::BybitPerpAccountResult;
+
+    fn kline_message(confirm: bool) -> serde_json::Value {
+        serde_json::json!({
+            "topic": "kline.5.BTCUSDT",
+            "type": "snapshot",
+            "ts": 1_672_324_988_882_i64,
+            "data": [{
+                "start": 1_672_324_800_000_i64,
+                "end": 1_672_325_099_999_i64,
+                "interval": "5",
+                "open": "16649.5",
+                "close": "16677",
+                "high": "16677",
+                "low": "16608",
+                "volume": "2.081",
+                "turnover": "34666.4005",
+                "confirm": confirm,
+                "timestamp": 1_672_324_988_882_i64
+            }]
+        })
+    }
+
+    #[test]
+    fn test_kline_confirm_flag_marks_closed_bars() {
+        let forming = parse_websocket_message(kline_message(false)).expect("kline");
+        assert!(forming.is_open_bar());
+
+        let closed = parse_websocket_message(kline_message(true)).expect("kline");
+        assert!(!closed.is_open_bar());
+        let MarketDataType::Kline(kline) = closed else {
+            panic!("expected a kline");
+        };
+        assert_eq!(kline.symbol.to_string(), "BTCUSDT");
+        assert_eq!(kline.interval, "5");
+    }
+
+    #[test]
+    fn test_ticker_delta_carries_mark_price() {
+        let delta = serde_json::json!({
+            "topic": "tickers.BTCUSDT",
+            "type": "delta",
+            "ts": 1_673_853_746_003_i64,
+            "data": {
+                "symbol": "BTCUSDT",
+                "markPrice": "21121.34",
+                "indexPrice": "21119.20",
+                "fundingRate": "0.0001"
+            }
+        });
+
+        let mark = parse_mark_price_message(&delta).expect("mark price");
+        assert_eq!(mark.mark_price.to_string(), "21121.34");
+        assert_eq!(mark.funding_rate, Some("0.0001".parse().unwrap()));
+        assert_eq!(mark.next_funding_time, None);
+        assert_eq!(mark.timestamp, 1_673_853_746_003);
+        // Deltas lack the 24h fields a full ticker needs
+        assert!(parse_websocket_message(delta).is_none());
+    }
+
+    #[test]
+    fn test_unified_wallet_balance_summary() {
+        let result: BybitPerpAccountResult = serde_json::from_value(serde_json::json!({
+            "list": [{
+                "accountType": "UNIFIED",
+                "totalEquity": "3.31216591",
+                "totalWalletBalance": "3.00326056",
+                "totalMarginBalance": "3.00326056",
+                "totalAvailableBalance": "2.84932217",
+                "totalPerpUPL": "0.30890535",
+                "totalInitialMargin": "0.15393839",
+                "totalMaintenanceMargin": "0.03072281",
+                "coin": []
+            }]
+        }))
+        .unwrap();
+
+        let summary =
+            convert_bybit_perp_account_summary(&result.list[0], 1_672_324_988_882).unwrap();
+        assert_eq!(summary.currency, "USD");
+        assert_eq!(summary.total_equity.to_string(), "3.31216591");
+        assert_eq!(summary.available_margin.to_string(), "2.84932217");
+        assert_eq!(summary.initial_margin.to_string(), "0.15393839");
+        assert_eq!(summary.unrealized_pnl.to_string(), "0.30890535");
+    }
+}
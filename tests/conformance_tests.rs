@@ -0,0 +1,30 @@
+use lotusx::core::config::ExchangeConfig;
+use lotusx::exchanges::binance::build_public as build_binance_public;
+use lotusx::exchanges::bybit::build_public as build_bybit_public;
+use lotusx::utils::conformance::ConformanceSuite;
+
+fn create_public_config() -> ExchangeConfig {
+    ExchangeConfig::read_only().testnet(false)
+}
+
+#[tokio::test]
+#[ignore = "Requires network access"]
+async fn test_binance_market_data_conformance() {
+    let connector = build_binance_public(create_public_config()).unwrap();
+    let report = ConformanceSuite::new("binance", "BTCUSDT")
+        .run(&connector)
+        .await;
+    println!("{}", report);
+    assert!(report.is_success());
+}
+
+#[tokio::test]
+#[ignore = "Requires network access"]
+async fn test_bybit_market_data_conformance() {
+    let connector = build_bybit_public(create_public_config()).unwrap();
+    let report = ConformanceSuite::new("bybit", "BTCUSDT")
+        .run(&connector)
+        .await;
+    println!("{}", report);
+    assert!(report.is_success());
+}
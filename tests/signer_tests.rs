@@ -0,0 +1,204 @@
+//! Byte-exact signature vectors for each venue's request signing
+//!
+//! Binance vectors are the worked examples from the Binance spot and futures API docs.
+//! Bybit, OKX and Backpack publish the prehash layout but no signature output, so their
+//! vectors sign the documented sample payloads and were computed independently with
+//! Python's `hmac` and `cryptography` packages. The Backpack key is RFC 8032 test 1.
+
+use lotusx::core::config::ExchangeConfig;
+use lotusx::core::kernel::{HmacExchangeType, HmacSigner, Signer};
+use lotusx::exchanges::backpack::signer::BackpackAuth;
+use lotusx::exchanges::binance::signer::BinanceSigner;
+use lotusx::exchanges::binance_perp::signer::BinancePerpSigner;
+use lotusx::exchanges::bybit::signer::BybitSigner;
+use lotusx::exchanges::bybit_perp::signer::BybitPerpSigner;
+use lotusx::exchanges::okx::signer::OkxSigner;
+
+const BINANCE_API_KEY: &str = "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A";
+const BINANCE_SECRET: &str = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+const BINANCE_QUERY: &str =
+    "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000";
+const BINANCE_TIMESTAMP: u64 = 1_499_827_319_559;
+const BINANCE_SIGNATURE: &str = "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71";
+
+const BYBIT_API_KEY: &str = "XXXXXXXXXX";
+const BYBIT_SECRET: &str = "XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+const BYBIT_TIMESTAMP: u64 = 1_658_384_314_791;
+const BYBIT_QUERY: &str = "category=option&symbol=BTC-29JUL22-25000-C";
+const BYBIT_QUERY_SIGNATURE: &str =
+    "c4b59ee6add568ccee37372eb5cf6a54d02349863cc43102522c0e9d2654ce78";
+const BYBIT_BODY: &str = r#"{"category":"option","symbol":"BTC-29JUL22-25000-C","orderType":"Limit","side":"Buy","qty":"1","price":"1000","timeInForce":"GTC"}"#;
+const BYBIT_BODY_SIGNATURE: &str =
+    "0b8dde1c14c8db042879bbe4f3d6f282d3de51f823c5660873dba29863674bfc";
+
+const BACKPACK_SECRET: &str = "nWGxne/9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A=";
+const BACKPACK_TIMESTAMP: i64 = 1_614_550_000_000;
+
+fn signature_param(params: &[(String, String)]) -> &str {
+    params
+        .iter()
+        .find(|(key, _)| key == "signature")
+        .map(|(_, value)| value.as_str())
+        .expect("signature parameter")
+}
+
+#[test]
+fn test_binance_hmac_matches_docs_example() {
+    let signer = BinanceSigner::new(BINANCE_API_KEY.to_string(), BINANCE_SECRET.to_string());
+    let (headers, params) = signer
+        .sign_request(
+            "POST",
+            "/api/v3/order",
+            BINANCE_QUERY,
+            &[],
+            BINANCE_TIMESTAMP,
+        )
+        .unwrap();
+    assert_eq!(signature_param(&params), BINANCE_SIGNATURE);
+    assert_eq!(headers["X-MBX-APIKEY"], BINANCE_API_KEY);
+
+    let kernel = HmacSigner::new(
+        BINANCE_API_KEY.to_string(),
+        BINANCE_SECRET.to_string(),
+        HmacExchangeType::Binance,
+    );
+    let (_, params) = kernel
+        .sign_request(
+            "POST",
+            "/api/v3/order",
+            BINANCE_QUERY,
+            &[],
+            BINANCE_TIMESTAMP,
+        )
+        .unwrap();
+    assert_eq!(signature_param(&params), BINANCE_SIGNATURE);
+}
+
+#[test]
+fn test_binance_perp_hmac_matches_docs_example() {
+    let signer = BinancePerpSigner::new(
+        "dbefbc809e3e83c283a984c3a1459732ea7db1360ca80c5c2c8867408d28cc83".to_string(),
+        "2b5eb11e18796d12d88f13dc27dbbd02c2cc51ff7059765ed9821957d82bb4d9".to_string(),
+    );
+    let (_, params) = signer
+        .sign_request(
+            "POST",
+            "/fapi/v1/order",
+            "symbol=BTCUSDT&side=BUY&type=LIMIT&quantity=1&price=9000&timeInForce=GTC&recvWindow=5000",
+            &[],
+            1_591_702_613_943,
+        )
+        .unwrap();
+    assert_eq!(
+        signature_param(&params),
+        "3c661234138461fcc7a7d8746c6558c9842d4e10870d2ecbedf7777cad694af9"
+    );
+}
+
+#[test]
+fn test_bybit_v5_signatures() {
+    let signer = BybitSigner::new(BYBIT_API_KEY.to_string(), BYBIT_SECRET.to_string());
+    let (headers, _) = signer
+        .sign_request(
+            "GET",
+            "/v5/order/realtime",
+            BYBIT_QUERY,
+            &[],
+            BYBIT_TIMESTAMP,
+        )
+        .unwrap();
+    assert_eq!(headers["X-BAPI-SIGN"], BYBIT_QUERY_SIGNATURE);
+    assert_eq!(headers["X-BAPI-TIMESTAMP"], BYBIT_TIMESTAMP.to_string());
+
+    let (headers, _) = signer
+        .sign_request(
+            "POST",
+            "/v5/order/create",
+            "",
+            BYBIT_BODY.as_bytes(),
+            BYBIT_TIMESTAMP,
+        )
+        .unwrap();
+    assert_eq!(headers["X-BAPI-SIGN"], BYBIT_BODY_SIGNATURE);
+
+    let perp = BybitPerpSigner::new(BYBIT_API_KEY.to_string(), BYBIT_SECRET.to_string());
+    assert_eq!(
+        perp.sign_v5_request(BYBIT_BODY, BYBIT_TIMESTAMP).unwrap(),
+        BYBIT_BODY_SIGNATURE
+    );
+
+    let kernel = HmacSigner::new(
+        BYBIT_API_KEY.to_string(),
+        BYBIT_SECRET.to_string(),
+        HmacExchangeType::Bybit,
+    );
+    let (headers, _) = kernel
+        .sign_request(
+            "GET",
+            "/v5/order/realtime",
+            BYBIT_QUERY,
+            &[],
+            BYBIT_TIMESTAMP,
+        )
+        .unwrap();
+    assert_eq!(headers["X-BAPI-SIGN"], BYBIT_QUERY_SIGNATURE);
+}
+
+#[test]
+fn test_okx_prehash_signatures() {
+    let signer = OkxSigner::new(
+        "api-key".to_string(),
+        "22582BD0CFF14C41EDBF1AB98506286D".to_string(),
+        "passphrase".to_string(),
+    );
+    let timestamp = "2020-12-08T09:08:57.715Z";
+    assert_eq!(
+        signer
+            .generate_signature(timestamp, "GET", "/api/v5/account/balance?ccy=BTC", "")
+            .unwrap(),
+        "HiZhvSfMtWJA3uUIVXV3a/bSXNPCWvYFXoGCVS8V4zY="
+    );
+    assert_eq!(
+        signer
+            .generate_signature(
+                timestamp,
+                "POST",
+                "/api/v5/trade/order",
+                r#"{"instId":"BTC-USDT","tdMode":"cash","side":"buy","ordType":"limit","px":"2.15","sz":"2"}"#,
+            )
+            .unwrap(),
+        "iwrdSpN7pm4G4u8+fswZUyH6mOM48+sBF6WvKpj4Dvk="
+    );
+}
+
+#[test]
+fn test_backpack_ed25519_instruction_signatures() {
+    let auth = BackpackAuth::new(&ExchangeConfig::new(
+        String::new(),
+        BACKPACK_SECRET.to_string(),
+    ))
+    .unwrap();
+
+    // Public key derived from RFC 8032 test 1
+    let headers = auth.create_signed_headers("balanceQuery", "").unwrap();
+    assert_eq!(
+        headers["X-API-Key"],
+        "11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo="
+    );
+
+    assert_eq!(
+        auth.generate_signature("balanceQuery", "", BACKPACK_TIMESTAMP, 5000)
+            .unwrap(),
+        "0Xe7TkJWz9DGQ5TNj1mBNbiF5PTPIVch/B+5PzBZ0QdWQq/pmWAyP+AluwN5pPyKjz3SUaeL78eiy+TCcakEAQ=="
+    );
+    assert_eq!(
+        auth.generate_signature(
+            "orderExecute",
+            "orderType=Limit&price=30000&quantity=0.1&side=Bid&symbol=BTC_USDC&timeInForce=GTC",
+            BACKPACK_TIMESTAMP,
+            5000,
+        )
+        .unwrap(),
+        "0243K9AXWQIuyky8EKheGTWv7+Q2V2FT28aN3mFQLliftiijrGbb1FZLDpLX5C+Zs3nvdlHLkzbDs47l7tTkBA=="
+    );
+}
@@ -1,6 +1,7 @@
 use lotusx::core::config::ExchangeConfig;
-use lotusx::core::traits::MarketDataSource;
+use lotusx::core::traits::{AccountInfo, OrderPlacer, RestMarketData, StreamingMarketData};
 use lotusx::exchanges::binance::build_connector as build_binance_connector;
+use lotusx::exchanges::binance::build_connector_with_websocket as build_binance_ws_connector;
 use lotusx::exchanges::bybit::build_connector as build_bybit_connector;
 use tokio::time::{timeout, Duration};
 
@@ -11,8 +12,8 @@ fn create_test_config() -> ExchangeConfig {
 #[tokio::test]
 async fn test_binance_websocket_url() {
     let config = create_test_config();
-    if let Ok(connector) = build_binance_connector(config) {
-        let ws_url = MarketDataSource::get_websocket_url(&connector);
+    if let Ok(connector) = build_binance_ws_connector(config) {
+        let ws_url = StreamingMarketData::get_websocket_url(&connector);
         assert!(ws_url.contains("binance"));
         println!("✅ Binance WebSocket URL: {}", ws_url);
     }
@@ -22,7 +23,7 @@ async fn test_binance_websocket_url() {
 async fn test_bybit_websocket_url() {
     let config = create_test_config();
     if let Ok(connector) = build_bybit_connector(config) {
-        let ws_url = MarketDataSource::get_websocket_url(&connector);
+        let ws_url = StreamingMarketData::get_websocket_url(&connector);
         assert!(ws_url.contains("bybit"));
         println!("✅ Bybit WebSocket URL: {}", ws_url);
     }
@@ -34,7 +35,7 @@ async fn test_binance_markets() {
     if let Ok(connector) = build_binance_connector(config) {
         let result = timeout(
             Duration::from_secs(30),
-            MarketDataSource::get_markets(&connector),
+            RestMarketData::get_markets(&connector),
         )
         .await;
 
@@ -69,7 +70,7 @@ async fn test_bybit_markets() {
     if let Ok(connector) = build_bybit_connector(config) {
         let result = timeout(
             Duration::from_secs(30),
-            MarketDataSource::get_markets(&connector),
+            RestMarketData::get_markets(&connector),
         )
         .await;
 
@@ -89,3 +90,214 @@ async fn test_bybit_markets() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_public_connectors_ignore_credentials() {
+    // Malformed secrets would make the signer setup fail; public builders never build one
+    let config = ExchangeConfig::new("key".to_string(), "not-a-valid-key".to_string());
+
+    let paradex = lotusx::exchanges::paradex::build_public(config.clone()).unwrap();
+    assert!(!paradex.supports_trading());
+    assert!(!paradex.supports_account());
+
+    let backpack = lotusx::exchanges::backpack::build_public(config.clone()).unwrap();
+    assert!(!backpack.supports_trading());
+
+    let hyperliquid = lotusx::exchanges::hyperliquid::build_public(config.clone()).unwrap();
+    assert!(!hyperliquid.supports_account());
+
+    let binance = lotusx::exchanges::binance::build_public(config.clone()).unwrap();
+    assert!(!binance.supports_trading());
+    let result = binance.get_account_balance().await;
+    assert!(matches!(result, Err(lotusx::ExchangeError::AuthError(_))));
+
+    let authenticated = build_binance_connector(config).unwrap();
+    assert!(authenticated.supports_trading());
+}
+
+#[test]
+fn test_symbol_accepts_venue_spellings() {
+    use lotusx::core::types::Symbol;
+    use lotusx::exchanges::factory::ExchangeId;
+    use lotusx::exchanges::okx::conversions::convert_symbol_to_okx_inst_id;
+
+    for spelling in ["BTCUSDT", "BTC-USDT", "BTC_USDT", "BTC/USDT"] {
+        let symbol = Symbol::from(spelling);
+        assert_eq!(symbol.to_string(), "BTCUSDT");
+        assert_eq!(convert_symbol_to_okx_inst_id(&symbol).unwrap(), "BTC-USDT");
+    }
+
+    // Perpetual suffixes mark the instrument type and are rendered back per venue
+    let perp = Symbol::from("BTC-USD-PERP");
+    assert_eq!((perp.base.as_str(), perp.quote.as_str()), ("BTC", "USD"));
+    assert!(perp.is_perpetual());
+    assert_eq!(
+        ExchangeId::Paradex
+            .symbol_convention()
+            .to_exchange_symbol(&perp),
+        "BTC-USD-PERP"
+    );
+    assert_eq!(Symbol::from("BTC").joined("-"), "BTC");
+    assert_eq!(Symbol::from("BTC").joined("-"), "BTC");
+    assert_eq!(Symbol::from("btcusdt").to_string(), "btcusdt");
+}
+
+#[test]
+fn test_kline_close_time_is_inclusive_across_venues() {
+    use lotusx::core::types::KlineInterval;
+    use lotusx::exchanges::{backpack, hyperliquid, okx, paradex};
+
+    // 2024-01-02T00:00:00Z
+    let open = 1_704_153_600_000;
+    let close = open + 15 * 60_000 - 1;
+
+    let okx_kline = okx::conversions::convert_okx_kline(
+        okx::types::OkxKline {
+            ts: open.to_string(),
+            o: "1".to_string(),
+            h: "1".to_string(),
+            l: "1".to_string(),
+            c: "1".to_string(),
+            vol: "1".to_string(),
+            vol_ccy: "1".to_string(),
+            vol_ccy_quote: "1".to_string(),
+            confirm: "1".to_string(),
+        },
+        "BTC-USDT",
+        KlineInterval::Minutes15,
+    )
+    .unwrap();
+
+    let candle: hyperliquid::types::Candle = serde_json::from_value(serde_json::json!({
+        "t": open, "T": close, "o": "1", "h": "1", "l": "1", "c": "1", "v": "1", "n": 3
+    }))
+    .unwrap();
+    let hyperliquid_kline =
+        hyperliquid::conversions::convert_candle_to_kline(&candle, "BTC", KlineInterval::Minutes15)
+            .unwrap();
+
+    let paradex_kline = paradex::conversions::convert_paradex_kline(
+        &serde_json::json!([open, "1", "1", "1", "1", "1"]),
+        "BTC-USD-PERP",
+        KlineInterval::Minutes15,
+    )
+    .unwrap();
+
+    for kline in [okx_kline, hyperliquid_kline, paradex_kline] {
+        assert_eq!((kline.open_time, kline.close_time), (open, close));
+    }
+
+    assert_eq!(
+        backpack::conversions::parse_kline_time("2024-01-02 00:00:00"),
+        open
+    );
+    assert_eq!(
+        KlineInterval::from_venue_str("candle15m"),
+        Some(KlineInterval::Minutes15)
+    );
+    assert_eq!(
+        KlineInterval::from_venue_str("240"),
+        Some(KlineInterval::Hours4)
+    );
+    // Monthly bars end with the calendar month: February 2024 has 29 days
+    let february = 1_706_745_600_000;
+    assert_eq!(
+        KlineInterval::Months1.close_time(february),
+        february + 29 * 86_400_000 - 1
+    );
+}
+
+#[test]
+fn test_malformed_decimals_are_rejected() {
+    use lotusx::core::errors::ExchangeError;
+    use lotusx::core::types::conversion;
+    use lotusx::exchanges::binance_perp::conversions::parse_websocket_message;
+
+    assert!(matches!(
+        conversion::try_string_to_price("abc"),
+        Err(ExchangeError::DeserializationError(_))
+    ));
+    assert!(conversion::try_string_to_quantity("").is_err());
+    assert_eq!(
+        conversion::try_string_to_decimal("1e-8").unwrap(),
+        "0.00000001".parse().unwrap()
+    );
+
+    // A trade with a garbled price is dropped instead of surfacing as a zero-price print
+    let trade = |price: &str| {
+        serde_json::json!({
+            "e": "trade", "E": 1, "s": "BTCUSDT", "t": 7, "p": price, "q": "0.5",
+            "T": 1, "m": false
+        })
+    };
+    assert!(parse_websocket_message(trade("50000.1")).is_some());
+    assert!(parse_websocket_message(trade("not-a-price")).is_none());
+}
+
+#[test]
+fn test_rounding_policy_fits_orders_to_market_precision() {
+    use lotusx::core::types::{
+        ContractStyle, Market, MarketStatus, OrderRequest, OrderSide, OrderType, Price, Quantity,
+        RoundingPolicy, Symbol,
+    };
+    use rust_decimal::Decimal;
+
+    let market = Market {
+        symbol: Symbol::new("BTC", "USDT").unwrap(),
+        status: MarketStatus::Trading,
+        base_precision: 3,
+        quote_precision: 1,
+        min_qty: None,
+        max_qty: None,
+        min_price: None,
+        max_price: None,
+        contract_style: ContractStyle::Spot,
+        contract_size: None,
+        extensions: None,
+    };
+    let quantity = Quantity::new(Decimal::new(12_345, 4)); // 1.2345
+    let price = Price::new(Decimal::new(6_500_025, 2)); // 65000.25
+
+    assert_eq!(
+        market
+            .round_quantity(quantity, RoundingPolicy::Down)
+            .value(),
+        Decimal::new(1_234, 3)
+    );
+    assert_eq!(
+        market
+            .round_quantity(quantity, RoundingPolicy::HalfUp)
+            .value(),
+        Decimal::new(1_235, 3)
+    );
+    assert_eq!(
+        market.round_price(price, RoundingPolicy::Bankers).value(),
+        Decimal::new(650_002, 1)
+    );
+    assert_eq!(
+        RoundingPolicy::HalfUp.round(Decimal::from(1_250), -2),
+        Decimal::from(1_300)
+    );
+
+    let order = market.round_order(
+        OrderRequest {
+            symbol: market.symbol.clone(),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity,
+            price: Some(price),
+            time_in_force: None,
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        },
+        ExchangeConfig::read_only().rounding_policy,
+    );
+    assert_eq!(order.quantity.value(), Decimal::new(1_234, 3));
+    assert_eq!(order.price.unwrap().value(), Decimal::new(650_002, 1));
+    assert_eq!(
+        "half-up".parse::<RoundingPolicy>().unwrap(),
+        RoundingPolicy::HalfUp
+    );
+}
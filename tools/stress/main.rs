@@ -0,0 +1,270 @@
+#![allow(clippy::cast_precision_loss)]
+
+//! Order throughput stress test
+//!
+//! Fires place/cancel loops at a venue testnet through the unified `OrderPlacer` API and
+//! reports the latency distribution and error taxonomy for each operation.
+//!
+//! ```bash
+//! cargo run --features stress --bin stress -- \
+//!     --exchange binance_perp --symbol BTCUSDT --price 10000 --quantity 0.001 \
+//!     --iterations 200 --concurrency 4
+//! ```
+//!
+//! Credentials are read from `{EXCHANGE}_API_KEY` / `{EXCHANGE}_SECRET_KEY`. Testnet is
+//! always enabled; pick a price far from the market so orders rest instead of filling.
+
+use lotusx::core::config::ExchangeConfig;
+use lotusx::core::errors::ExchangeError;
+use lotusx::core::traits::OrderPlacer;
+use lotusx::core::types::{conversion, OrderRequest, OrderSide, OrderType, Symbol, TimeInForce};
+use lotusx::exchanges::{
+    backpack, binance, binance_perp, bybit, bybit_perp, hyperliquid, okx, paradex,
+};
+use lotusx::utils::latency_testing::LatencyMetrics;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+type Placer = Arc<dyn OrderPlacer + Send + Sync>;
+
+/// Command-line options for a stress run
+#[derive(Debug, Clone)]
+struct StressConfig {
+    exchange: String,
+    symbol: String,
+    price: String,
+    quantity: String,
+    side: OrderSide,
+    iterations: usize,
+    concurrency: usize,
+    pause_ms: u64,
+}
+
+impl Default for StressConfig {
+    fn default() -> Self {
+        Self {
+            exchange: "binance_perp".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            price: "10000".to_string(),
+            quantity: "0.001".to_string(),
+            side: OrderSide::Buy,
+            iterations: 100,
+            concurrency: 1,
+            pause_ms: 0,
+        }
+    }
+}
+
+impl StressConfig {
+    fn from_args() -> Result<Self, String> {
+        let mut config = Self::default();
+        let mut args = std::env::args().skip(1);
+
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for {}", flag))
+            };
+            match flag.as_str() {
+                "--exchange" => config.exchange = value()?,
+                "--symbol" => config.symbol = value()?,
+                "--price" => config.price = value()?,
+                "--quantity" => config.quantity = value()?,
+                "--side" => {
+                    config.side = match value()?.to_lowercase().as_str() {
+                        "buy" => OrderSide::Buy,
+                        "sell" => OrderSide::Sell,
+                        other => return Err(format!("Invalid side: {}", other)),
+                    }
+                }
+                "--iterations" => {
+                    config.iterations = value()?.parse().map_err(|e| format!("{}", e))?;
+                }
+                "--concurrency" => {
+                    config.concurrency = value()?.parse().map_err(|e| format!("{}", e))?;
+                }
+                "--pause-ms" => {
+                    config.pause_ms = value()?.parse().map_err(|e| format!("{}", e))?;
+                }
+                other => return Err(format!("Unknown argument: {}", other)),
+            }
+        }
+
+        if config.concurrency == 0 {
+            return Err("--concurrency must be at least 1".to_string());
+        }
+        Ok(config)
+    }
+
+    fn order(&self) -> OrderRequest {
+        OrderRequest {
+            symbol: Symbol::from_string(&self.symbol)
+                .unwrap_or_else(|_| conversion::string_to_symbol(&self.symbol)),
+            side: self.side.clone(),
+            order_type: OrderType::Limit,
+            quantity: conversion::string_to_quantity(&self.quantity),
+            price: Some(conversion::string_to_price(&self.price)),
+            time_in_force: Some(TimeInForce::GTC),
+            stop_price: None,
+            reduce_only: None,
+            close_position: None,
+            client_order_id: None,
+        }
+    }
+}
+
+/// Outcome of a single operation
+enum Sample {
+    Ok(Duration),
+    Err(ExchangeError),
+}
+
+/// Aggregated results for one operation type
+#[derive(Default)]
+struct OperationReport {
+    latencies: Vec<Duration>,
+    attempts: usize,
+    errors: BTreeMap<&'static str, usize>,
+    last_error: BTreeMap<&'static str, String>,
+}
+
+impl OperationReport {
+    fn record(&mut self, sample: Sample) {
+        self.attempts += 1;
+        match sample {
+            Sample::Ok(latency) => self.latencies.push(latency),
+            Sample::Err(error) => {
+                let kind = error.user_message();
+                *self.errors.entry(kind).or_default() += 1;
+                self.last_error.insert(kind, error.to_string());
+            }
+        }
+    }
+
+    fn print(&self, operation: &str, elapsed: Duration) {
+        let metrics = LatencyMetrics::new(&self.latencies, self.attempts.max(1));
+        println!(
+            "\n{} — {} attempts, {} ok, {:.1} ops/s",
+            operation,
+            self.attempts,
+            self.latencies.len(),
+            self.attempts as f64 / elapsed.as_secs_f64().max(f64::EPSILON)
+        );
+        metrics.print_summary(operation);
+
+        if !self.errors.is_empty() {
+            println!("  Errors:");
+            for (kind, count) in &self.errors {
+                println!("    {:<40} {}", kind, count);
+                if let Some(message) = self.last_error.get(kind) {
+                    println!("      last: {}", message);
+                }
+            }
+        }
+    }
+}
+
+fn build_placer(exchange: &str) -> Result<Placer, Box<dyn std::error::Error>> {
+    let prefix = match exchange {
+        "binance" | "binance_perp" => "BINANCE",
+        "bybit" | "bybit_perp" => "BYBIT",
+        other => other,
+    };
+    let config = ExchangeConfig::from_env(prefix)?.testnet(true);
+
+    let placer: Placer = match exchange {
+        "binance" => Arc::new(binance::build_connector(config)?),
+        "binance_perp" => Arc::new(binance_perp::build_connector(config)?),
+        "bybit" => Arc::new(bybit::build_connector(config)?),
+        "bybit_perp" => Arc::new(bybit_perp::build_connector(config)?),
+        "backpack" => Arc::new(backpack::build_connector(config)?),
+        "hyperliquid" => Arc::new(hyperliquid::build_hyperliquid_connector(config)?),
+        "okx" => Arc::new(okx::build_connector(config)?),
+        "paradex" => Arc::new(paradex::build_connector(config)?),
+        other => return Err(format!("Unsupported exchange: {}", other).into()),
+    };
+    Ok(placer)
+}
+
+async fn run_worker(
+    placer: Placer,
+    config: StressConfig,
+    iterations: usize,
+) -> (Vec<Sample>, Vec<Sample>) {
+    let mut place_samples = Vec::with_capacity(iterations);
+    let mut cancel_samples = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let order = config.order();
+        let symbol = Symbol::from(config.symbol.as_str());
+
+        let start = Instant::now();
+        match placer.place_order(order).await {
+            Ok(response) => {
+                place_samples.push(Sample::Ok(start.elapsed()));
+
+                let start = Instant::now();
+                let result = placer.cancel_order(symbol, response.order_id).await;
+                let latency = start.elapsed();
+                cancel_samples.push(match result {
+                    Ok(()) => Sample::Ok(latency),
+                    Err(e) => Sample::Err(e),
+                });
+            }
+            Err(e) => place_samples.push(Sample::Err(e)),
+        }
+
+        if config.pause_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(config.pause_ms)).await;
+        }
+    }
+
+    (place_samples, cancel_samples)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let config = StressConfig::from_args()?;
+    let placer = build_placer(&config.exchange)?;
+
+    println!("🔥 Order throughput stress test");
+    println!("  Exchange:    {} (testnet)", config.exchange);
+    println!("  Symbol:      {}", config.symbol);
+    println!(
+        "  Order:       {:?} {} @ {}",
+        config.side, config.quantity, config.price
+    );
+    println!("  Iterations:  {}", config.iterations);
+    println!("  Concurrency: {}", config.concurrency);
+
+    let per_worker = config.iterations / config.concurrency;
+    let remainder = config.iterations % config.concurrency;
+
+    let started = Instant::now();
+    let handles: Vec<_> = (0..config.concurrency)
+        .map(|worker| {
+            let iterations = per_worker + usize::from(worker < remainder);
+            tokio::spawn(run_worker(placer.clone(), config.clone(), iterations))
+        })
+        .collect();
+
+    let mut place_report = OperationReport::default();
+    let mut cancel_report = OperationReport::default();
+    for handle in handles {
+        let (place_samples, cancel_samples) = handle.await?;
+        for sample in place_samples {
+            place_report.record(sample);
+        }
+        for sample in cancel_samples {
+            cancel_report.record(sample);
+        }
+    }
+    let elapsed = started.elapsed();
+
+    println!("\n⏱️  Completed in {:.2}s", elapsed.as_secs_f64());
+    place_report.print("Place order", elapsed);
+    cancel_report.print("Cancel order", elapsed);
+
+    Ok(())
+}